@@ -7,33 +7,144 @@ Setup IR
   - segment len is 1/2 fft_size
   - segment IR buffer (pad with 0s to be fft_size)
   - FFT and hold onto each IR segment
+  - keep the first segment in the time domain too, for the direct convolution below
 Setup frame history Queue
   - queue for previous input frame buffers
   - len is same as # of IR segments
   - start with 0.s
-Process Input
-  - segment len is 1/2 fft_size
-  - segment Input buffer (pad with 0s to be fft_size)
-  - keep track of # of input segments (N)
-  - FFT each input segment
-  - convolve each input segment with the IR and the History (frequency domain)
-    - ???
-    - push/pop history queue
-  - take N output segments
-  - IFFT output segments
-  - Concat into time domain output vec
-  - overlap add output with output from previous frame
-  - hold on to output for overlap with next frame
-
-  - return output vec
+Process Input (any block size, no latency)
+  - input fills up the current segment a sample at a time
+  - each output sample is
+    - what the finished segments already worked out for this spot (pending)
+    - plus the current segment so far, convolved directly with the first IR segment
+  - when the current segment is full
+    - FFT it and push it onto the history
+    - convolve with the IR and the History (frequency domain)
+      - 𝑌𝑛(𝑧)=𝑋𝑛(𝑧)⋅𝐻0(𝑧)+𝑋𝑛−1(𝑧)⋅𝐻1(𝑧)+...+𝑋𝑛−15(𝑧)⋅𝐻15(𝑧), the back half of its IFFT spills
+        into the next segment
+      - 𝑋𝑛(𝑧)⋅𝐻1(𝑧)+...+𝑋𝑛−14(𝑧)⋅𝐻15(𝑧) is everything the finished segments put into the next
+        segment, the front half of its IFFT lands there, the rest is in the next 𝑌
+    - the two together are the pending output for the next segment
+  - so the FFT work happens on segment boundaries wherever the host's blocks fall, and the
+    output is the same however the input is cut up
+Swapping IRs on the audio thread
+  - `partition` builds a `ConvolverIr` off the audio thread: the segments, the head and every
+    buffer the IR is run in, so swapping it in only has to catch it up with the history
+  - the history is sized up front for the longest IR the convolver will be handed, see
+    `set_max_ir_len`, so a swap never grows it
+  - IRs the convolver is done with are kept for `take_retired`, to be freed off the audio thread
 Convolution
 */
 
+// how long a swap from one IR to the next is crossfaded for
+pub const IR_FADE_SAMPLES: usize = 2048;
+// IRs done with and waiting to be sent back, past this many they're dropped in place after all
+const MAX_RETIRED: usize = 4;
+
+/// One IR, segmented and transformed by `partition`, and what it has already worked out for the
+/// segment being filled.
+#[derive(Clone)]
+pub struct ConvolverIr {
+  ir_segments: Vec<Vec<Complex<f32>>>, // freq domain impulse response segments
+  head: Vec<f32>, // first IR segment in the time domain, scaled to match the unnormalized IFFT
+  upcoming: Vec<Complex<f32>>, // the finished segments' share of the next 𝑌
+  pending: Vec<f32>, // output for the current segment from the finished ones
+  output_frame: Vec<Complex<f32>>, // full output spectrum of the last finished segment, before the IFFT
+  convolved: Vec<Complex<f32>>, // scratch, the IFFT of output_frame
+  landing: Vec<Complex<f32>>, // scratch, the IFFT of upcoming
+}
+
+impl ConvolverIr {
+  // allocates everything the IR will ever need, silent until it's caught up with a history
+  fn new(ir_segments: Vec<Vec<Complex<f32>>>, ifft_processor: &Arc<dyn Fft<f32>>) -> Self {
+    let fft_size = ifft_processor.len();
+    let zeros = vec![Complex { re: 0., im: 0. }; fft_size];
+    let mut head = match ir_segments.first() {
+      Some(segment) => segment.clone(),
+      None => zeros.clone(),
+    };
+    ifft_processor.process(&mut head);
+    Self {
+      head: head.iter().take(fft_size / 2).map(|sample| sample.re).collect(),
+      upcoming: zeros.clone(),
+      pending: vec![0.; fft_size / 2],
+      output_frame: zeros.clone(),
+      convolved: zeros.clone(),
+      landing: zeros,
+      ir_segments,
+    }
+  }
+
+  /// How many segments the IR was cut into.
+  pub fn len(&self) -> usize {
+    self.ir_segments.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ir_segments.is_empty()
+  }
+
+  // picks up as if this IR had been running all along. works in the buffers it already has
+  fn catch_up(&mut self, history: &VecDeque<Vec<Complex<f32>>>, ifft_processor: &Arc<dyn Fft<f32>>, scratch: &mut [Complex<f32>]) {
+    // the newest finished segment is at the front of the history, catch up to just after it
+    clear_frame(&mut self.upcoming);
+    accumulate(&mut self.upcoming, history.iter().skip(1), self.ir_segments.iter().skip(1));
+    self.advance(history, ifft_processor, scratch);
+  }
+
+  // forgets what the finished segments worked out, for a silent history
+  fn clear(&mut self) {
+    clear_frame(&mut self.upcoming);
+    clear_frame(&mut self.output_frame);
+    for sample in self.pending.iter_mut() {
+      *sample = 0.;
+    }
+  }
+
+  // the output for sample `index` of the current segment
+  fn output(&self, current: &[f32], index: usize) -> f32 {
+    let mut sample = self.pending[index];
+    for (x, h) in current[..=index].iter().zip(self.head[..=index].iter().rev()) {
+      sample += x * h;
+    }
+    sample
+  }
+
+  // works out the next segment's pending output, once the newest segment is on the history, and
+  // keeps the full output spectrum of the finished segment in `output_frame`
+  fn advance(&mut self, history: &VecDeque<Vec<Complex<f32>>>, ifft_processor: &Arc<dyn Fft<f32>>, scratch: &mut [Complex<f32>]) {
+    let segment_size = self.pending.len();
+    self.output_frame.copy_from_slice(&self.upcoming);
+    accumulate(&mut self.output_frame, history.iter().take(1), self.ir_segments.iter().take(1));
+    self.convolved.copy_from_slice(&self.output_frame);
+    ifft_processor.process_with_scratch(&mut self.convolved, scratch);
+
+    clear_frame(&mut self.upcoming);
+    accumulate(&mut self.upcoming, history.iter(), self.ir_segments.iter().skip(1));
+    self.landing.copy_from_slice(&self.upcoming);
+    ifft_processor.process_with_scratch(&mut self.landing, scratch);
+
+    for (i, sample) in self.pending.iter_mut().enumerate() {
+      *sample = self.convolved[segment_size + i].re + self.landing[i].re;
+    }
+  }
+}
+
+// the outgoing IR while a swap is being crossfaded
+struct IrFade {
+  ir: ConvolverIr,
+  remaining: usize,
+}
+
 pub struct Convolver {
   fft_size: usize,
-  ir_segments: Vec<Vec<Complex<f32>>>, // freq domain impulse response segments
-  previous_frame_q: VecDeque<Vec<Complex<f32>>>, // previous freq domain input signals
-  previous_tail: Vec<f32>, // previous output frame (time domain) for overlap add
+  ir: ConvolverIr,
+  fade: Option<IrFade>,
+  pending_ir: Option<ConvolverIr>, // waiting for the current fade to finish
+  retired: Vec<ConvolverIr>, // done with, for `take_retired`
+  previous_frame_q: VecDeque<Vec<Complex<f32>>>, // previous freq domain input signals, newest first
+  current_segment: Vec<f32>, // time domain input, filling up towards the next frame
+  fft_scratch: Vec<Complex<f32>>, // for the FFTs to work in, so they don't allocate their own
   fft_processor: Arc<dyn Fft<f32>>,
   ifft_processor: Arc<dyn Fft<f32>>, //inverse ff
 }
@@ -44,107 +155,178 @@ impl Convolver {
     let mut planner = FftPlanner::<f32>::new();
     let fft_processor = planner.plan_fft_forward(fft_size);
     let ifft_processor = planner.plan_fft_inverse(fft_size);
+    let scratch_len = fft_processor.get_inplace_scratch_len().max(ifft_processor.get_inplace_scratch_len());
+    let mut fft_scratch = vec![Complex { re: 0., im: 0. }; scratch_len];
 
-    let ir_segments = segment_buffer(ir_signal, fft_size, &fft_processor);
-    let segment_count = ir_segments.len();
+    let mut ir = ConvolverIr::new(segment_buffer(ir_signal, fft_size, &fft_processor), &ifft_processor);
+    let previous_frame_q = init_previous_frame_q(history_len(&ir.ir_segments), fft_size);
+    ir.catch_up(&previous_frame_q, &ifft_processor, &mut fft_scratch);
     Self {
       fft_size,
-      ir_segments,
+      ir,
+      fade: None,
+      pending_ir: None,
+      retired: Vec::with_capacity(MAX_RETIRED),
       fft_processor,
       ifft_processor,
-      previous_frame_q: init_previous_frame_q(segment_count, fft_size),
-      previous_tail: init_previous_tail(fft_size/2),
+      previous_frame_q,
+      current_segment: Vec::with_capacity(fft_size / 2),
+      fft_scratch,
     }
   }
 
-  pub fn process(&mut self, input_buffer: &[f32]) -> Vec<f32> {
-    let io_len = input_buffer.len();
-    // segment and convert to freq domain
-    let input_segments = segment_buffer(input_buffer, self.fft_size, &self.fft_processor);
-
-
-    let mut output_segments: Vec<Vec<Complex<f32>>> = Vec::new();
-    // push front/ pop back
-    for segment in input_segments {
-      self.previous_frame_q.push_front(segment);
-      self.previous_frame_q.pop_back();
-      // multiply
-      output_segments.push(self.convolve_frame());
+  // forget all previous input, silencing the tail. everything is cleared where it is, so the
+  // audio thread can call it. an IR still waiting on a fade takes over at the next segment
+  pub fn reset(&mut self) {
+    if let Some(fade) = self.fade.take() {
+      self.retire(fade.ir);
+    }
+    for frame in self.previous_frame_q.iter_mut() {
+      clear_frame(frame);
     }
+    self.current_segment.clear();
+    self.ir.clear();
+  }
+
+  // makes room in the history for IRs up to `samples` long, so swapping one in never has to grow
+  // it. an IR longer than that loses the segments past the end. allocates, and forgets all
+  // previous input
+  pub fn set_max_ir_len(&mut self, samples: usize) {
+    let segments = samples.div_ceil(self.fft_size / 2).max(history_len(&self.ir.ir_segments));
+    self.previous_frame_q = init_previous_frame_q(segments, self.fft_size);
+    self.reset();
+  }
 
-    // go back to time domain
-    let mut time_domain: Vec<f32> = Vec::new();
-    for mut segment in output_segments {
-      self.ifft_processor.process(&mut segment);
-      for sample in segment {
-        time_domain.push(sample.re);
+  // swaps in a new IR, built by `partition`. the input history is kept, so the new IR rings out
+  // from everything already played, and the old one is crossfaded out
+  pub fn set_ir(&mut self, mut ir: ConvolverIr) {
+    // only one fade at a time, the latest IR waits its turn
+    if self.fade.is_some() {
+      if let Some(skipped) = self.pending_ir.replace(ir) {
+        self.retire(skipped);
       }
+      return;
     }
+    // the new IR's pending output has to cover what's already in the current segment too, so it
+    // is worked out from the history as of the start of the segment
+    ir.catch_up(&self.previous_frame_q, &self.ifft_processor, &mut self.fft_scratch);
+    let old_ir = std::mem::replace(&mut self.ir, ir);
+    self.fade = Some(IrFade {
+      ir: old_ir,
+      remaining: IR_FADE_SAMPLES,
+    });
+  }
 
-    // overlap add
-    for (i, sample) in self.previous_tail.iter().enumerate() {
-      match time_domain.get_mut(i) {
-        Some(out_sample) => *out_sample += sample,
-        None => break
-      }
+  // IRs the convolver is done with, one at a time, to be freed off the audio thread
+  pub fn take_retired(&mut self) -> Option<ConvolverIr> {
+    self.retired.pop()
+  }
+
+  // holds on to an IR that's done with until it's taken, or drops it if there's no room left
+  pub fn retire(&mut self, ir: ConvolverIr) {
+    if self.retired.len() < MAX_RETIRED {
+      self.retired.push(ir);
     }
+  }
+
+  // convolves `input_buffer` into `output`, which must be at least as long
+  pub fn process(&mut self, input_buffer: &[f32], output: &mut [f32]) {
+    let segment_size = self.fft_size / 2;
+    for (sample, out) in input_buffer.iter().zip(output.iter_mut()) {
+      let index = self.current_segment.len();
+      self.current_segment.push(*sample);
+      let mut out_sample = self.ir.output(&self.current_segment, index);
 
-    // everything outside of the buffer length is the tail for the next run
-    self.previous_tail = time_domain[io_len..time_domain.len()].to_vec();
+      // crossfade from the outgoing IR
+      if let Some(fade) = &mut self.fade {
+        let old_gain = fade.remaining as f32 / IR_FADE_SAMPLES as f32;
+        let old_sample = fade.ir.output(&self.current_segment, index);
+        out_sample = out_sample * (1. - old_gain) + old_sample * old_gain;
+        fade.remaining -= 1;
+        if fade.remaining == 0 {
+          if let Some(fade) = self.fade.take() {
+            self.retire(fade.ir);
+          }
+        }
+      }
+      *out = out_sample;
 
-    // return a buffers worth of signal
-    return time_domain[0..io_len].to_vec();
+      if self.current_segment.len() == segment_size {
+        self.finish_segment();
+      }
+    }
   }
- 
-  // in freq domain
-  // 𝑌𝑛(𝑧)=𝑋𝑛(𝑧)⋅𝐻0(𝑧)+𝑋𝑛−1(𝑧)⋅𝐻1(𝑧)+...+𝑋𝑛−15(𝑧)⋅𝐻15(𝑧)
-  fn convolve_frame(&mut self) -> Vec<Complex<f32>> {
-    //init output to accumulate onto
-    let mut convolved: Vec<Complex<f32>> = Vec::new();
-    for _ in 0..self.fft_size {
-      convolved.push(Complex {re: 0. , im: 0. });
+
+  // moves the full current segment onto the history
+  fn finish_segment(&mut self) {
+    // the oldest frame drops off the back, and its buffer is reused for the newest
+    let mut frame = self.previous_frame_q.pop_back().unwrap();
+    for (bin, sample) in frame.iter_mut().zip(self.current_segment.iter().chain(std::iter::repeat(&0.))) {
+      *bin = Complex { re: *sample, im: 0. };
     }
+    self.fft_processor.process_with_scratch(&mut frame, &mut self.fft_scratch);
+    self.current_segment.clear();
+    self.previous_frame_q.push_front(frame);
 
-    for i in 0..self.ir_segments.len() {
-      add_frames(&mut convolved, mult_frames(
-        &self.previous_frame_q[i],
-        &self.ir_segments[i]
-      ));
+    self.ir.advance(&self.previous_frame_q, &self.ifft_processor, &mut self.fft_scratch);
+    match &mut self.fade {
+      Some(fade) => {
+        fade.ir.advance(&self.previous_frame_q, &self.ifft_processor, &mut self.fft_scratch);
+      }
+      None => {
+        if let Some(ir) = self.pending_ir.take() {
+          self.set_ir(ir);
+        }
+      }
     }
-    convolved
+  }
+
+  // spectral content of what the convolver is currently putting out
+  pub fn current_spectrum(&self) -> &[Complex<f32>] {
+    &self.ir.output_frame
   }
 }
 
-// mutates the first frame!
-pub fn add_frames(f1: &mut [Complex<f32>], f2: Vec<Complex<f32>>) {
-  for (mut sample1, sample2) in f1.iter_mut().zip(f2) {
-    sample1.re = sample1.re + sample2.re;
-    sample1.im = sample1.im + sample2.im;
+// the history always holds at least the newest frame, even for an empty IR
+fn history_len(ir_segments: &[Vec<Complex<f32>>]) -> usize {
+  ir_segments.len().max(1)
+}
+
+fn clear_frame(frame: &mut [Complex<f32>]) {
+  for bin in frame.iter_mut() {
+    *bin = Complex { re: 0., im: 0. };
   }
 }
 
-//freq domain multiplication
-//ReY[f] = ReX[f]ReH[f]-ImX[f]ImH[f]
-//ImY[f] = ImX[f]ReH[f] + ReX[f]ImH[f]
-//
-// returns new vec
-pub fn mult_frames(f1: &[Complex<f32>], f2: &[Complex<f32>]) -> Vec<Complex<f32>> {
-  let mut out: Vec<Complex<f32>> = Vec::new();
-  for (sample1, sample2) in f1.iter().zip(f2) {
-    out.push(Complex {
-     re: (sample1.re * sample2.re) - (sample1.im * sample2.im),
-     im: (sample1.im * sample2.re) - (sample1.re * sample2.im)
-    });
+// in freq domain, adds up the products of each input frame with its IR segment
+fn accumulate<'a, X, H>(convolved: &mut [Complex<f32>], frames: X, ir_segments: H)
+where
+  X: Iterator<Item = &'a Vec<Complex<f32>>>,
+  H: Iterator<Item = &'a Vec<Complex<f32>>>,
+{
+  for (frame, ir_segment) in frames.zip(ir_segments) {
+    mult_add_frames(convolved, frame, ir_segment);
+  }
+}
+
+// mutates the first frame!
+pub fn add_frames(f1: &mut [Complex<f32>], f2: &[Complex<f32>]) {
+  for (sample1, sample2) in f1.iter_mut().zip(f2) {
+    sample1.re += sample2.re;
+    sample1.im += sample2.im;
   }
-  out
 }
 
-pub fn init_previous_tail(size: usize) -> Vec<f32> {
-  let mut tail = Vec::new();
-  for _ in 0..size {
-    tail.push(0.);
+//freq domain multiplication, added onto `out`
+//ReY[f] += ReX[f]ReH[f]-ImX[f]ImH[f]
+//ImY[f] += ImX[f]ReH[f] + ReX[f]ImH[f]
+//
+// mutates `out`!
+pub fn mult_add_frames(out: &mut [Complex<f32>], f1: &[Complex<f32>], f2: &[Complex<f32>]) {
+  for ((sample, sample1), sample2) in out.iter_mut().zip(f1).zip(f2) {
+    sample.re += (sample1.re * sample2.re) - (sample1.im * sample2.im);
+    sample.im += (sample1.im * sample2.re) + (sample1.re * sample2.im);
   }
-  tail
 }
 
 // - segment buffer (pad with 0s to be fft_size)
@@ -173,6 +355,15 @@ pub fn segment_buffer(buffer: &[f32], fft_size: usize, fft_processor: &Arc<dyn F
   segments
 }
 
+// segments and transforms an IR for `Convolver::set_ir`, and sets up the buffers it runs in.
+// heavy, so meant for use off the audio thread
+pub fn partition(ir_signal: &[f32], fft_size: usize) -> ConvolverIr {
+  let mut planner = FftPlanner::<f32>::new();
+  let fft_processor = planner.plan_fft_forward(fft_size);
+  let ifft_processor = planner.plan_fft_inverse(fft_size);
+  ConvolverIr::new(segment_buffer(ir_signal, fft_size, &fft_processor), &ifft_processor)
+}
+
 // queue of previous input segments in the frequency domain (polar notation)
 // init to 0s
 pub fn init_previous_frame_q(segment_count: usize, fft_size: usize) -> VecDeque<Vec<Complex<f32>>> {
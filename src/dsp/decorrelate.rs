@@ -0,0 +1,86 @@
+use std::f32::consts::PI;
+use rustfft::{FftPlanner, num_complex::Complex};
+use super::noise::Noise;
+
+/*
+IR decorrelation (time-frequency phase randomization)
+  - a mono IR is taken apart into short overlapping frames (STFT), and every bin of every frame
+    has its phase nudged by a random amount, then it's put back together with overlap add
+  - magnitudes aren't touched, so each frame keeps its spectrum, and the randomness is fresh
+    every frame, so two different seeds give two IRs that sound alike but barely correlate
+  - overlapping frames with scrambled phases partly cancel, so the result is pulled back onto
+    the original's short term energy envelope, keeping the decay exactly where it was
+  - amount scales the phase nudge, 0 hands back the input untouched
+*/
+
+const FRAME_SIZE: usize = 1024;
+const HOP: usize = FRAME_SIZE / 4;
+// sum of the squared hann windows at a quarter frame hop
+const OVERLAP_GAIN: f32 = 1.5;
+const ENVELOPE_BLOCK: usize = 512;
+
+/// Derives a decorrelated version of `impulse_response`. Heavy, so meant for use off the audio
+/// thread.
+pub fn decorrelate(impulse_response: &[f32], amount: f32, seed: u32) -> Vec<f32> {
+  if amount <= 0. || impulse_response.is_empty() {
+    return impulse_response.to_vec();
+  }
+  let mut planner = FftPlanner::<f32>::new();
+  let fft = planner.plan_fft_forward(FRAME_SIZE);
+  let ifft = planner.plan_fft_inverse(FRAME_SIZE);
+  let window: Vec<f32> = (0..FRAME_SIZE)
+    .map(|i| 0.5 - 0.5 * (2. * PI * i as f32 / FRAME_SIZE as f32).cos())
+    .collect();
+  let mut noise = Noise::new(seed);
+
+  // frames start before the IR does, so its first samples get the full overlap too
+  let len = impulse_response.len();
+  let padded = len + 2 * FRAME_SIZE;
+  let mut output = vec![0.; padded];
+  let mut frame = vec![Complex { re: 0., im: 0. }; FRAME_SIZE];
+  let mut start = 0;
+  while start + FRAME_SIZE <= padded {
+    for (i, bin) in frame.iter_mut().enumerate() {
+      // position in the IR, which sits one frame into the padded buffer
+      let sample = (start + i).checked_sub(FRAME_SIZE).and_then(|n| impulse_response.get(n));
+      *bin = Complex { re: sample.copied().unwrap_or(0.) * window[i], im: 0. };
+    }
+    fft.process(&mut frame);
+    // keep it real: bin k and N - k turn by opposite amounts, DC and nyquist stay put
+    for k in 1..FRAME_SIZE / 2 {
+      let turn = Complex::from_polar(1., amount * PI * (2. * noise.next_unipolar() - 1.));
+      frame[k] *= turn;
+      frame[FRAME_SIZE - k] *= turn.conj();
+    }
+    ifft.process(&mut frame);
+    for (i, bin) in frame.iter().enumerate() {
+      output[start + i] += bin.re * window[i] / (FRAME_SIZE as f32 * OVERLAP_GAIN);
+    }
+    start += HOP;
+  }
+  let mut output = output[FRAME_SIZE..FRAME_SIZE + len].to_vec();
+  match_envelope(&mut output, impulse_response);
+  output
+}
+
+// scales `signal` block by block onto the energy envelope of `reference`, interpolating the gain
+// between block centres
+fn match_envelope(signal: &mut [f32], reference: &[f32]) {
+  let energy = |s: &[f32]| s.iter().map(|x| x * x).sum::<f32>();
+  let gains: Vec<f32> = signal
+    .chunks(ENVELOPE_BLOCK)
+    .zip(reference.chunks(ENVELOPE_BLOCK))
+    .map(|(block, reference)| {
+      let block_energy = energy(block);
+      if block_energy > 0. { (energy(reference) / block_energy).sqrt() } else { 0. }
+    })
+    .collect();
+  for (i, sample) in signal.iter_mut().enumerate() {
+    let position = (i as f32 - ENVELOPE_BLOCK as f32 / 2.) / ENVELOPE_BLOCK as f32;
+    let block = (position.floor().max(0.) as usize).min(gains.len() - 1);
+    let next = (block + 1).min(gains.len() - 1);
+    // the first half block just holds the first gain
+    let t = if position < 0. { 0. } else { position - position.floor() };
+    *sample *= gains[block] + (gains[next] - gains[block]) * t;
+  }
+}
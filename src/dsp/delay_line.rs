@@ -0,0 +1,53 @@
+/// Fixed capacity circular delay line.
+pub struct DelayLine {
+  buffer: Vec<f32>,
+  write_index: usize,
+}
+
+impl DelayLine {
+  // capacity is the longest delay (in samples) that can be read back
+  pub fn new(max_delay: usize) -> Self {
+    Self {
+      buffer: vec![0.; max_delay + 1],
+      write_index: 0,
+    }
+  }
+
+  // grows or shrinks the line, dropping its contents
+  pub fn resize(&mut self, max_delay: usize) {
+    self.buffer = vec![0.; max_delay + 1];
+    self.write_index = 0;
+  }
+
+  pub fn clear(&mut self) {
+    for sample in self.buffer.iter_mut() {
+      *sample = 0.;
+    }
+  }
+
+  pub fn push(&mut self, sample: f32) {
+    self.write_index = (self.write_index + 1) % self.buffer.len();
+    self.buffer[self.write_index] = sample;
+  }
+
+  // a delay of 0 is the most recently pushed sample
+  pub fn read(&self, delay: usize) -> f32 {
+    let len = self.buffer.len();
+    let delay = delay.min(len - 1);
+    self.buffer[(self.write_index + len - delay) % len]
+  }
+
+  // linearly interpolated read between whole sample delays
+  pub fn read_linear(&self, delay: f32) -> f32 {
+    let whole = delay.floor();
+    let frac = delay - whole;
+    let a = self.read(whole as usize);
+    let b = self.read(whole as usize + 1);
+    a + (b - a) * frac
+  }
+
+  pub fn process(&mut self, sample: f32, delay: usize) -> f32 {
+    self.push(sample);
+    self.read(delay)
+  }
+}
@@ -0,0 +1,139 @@
+use super::db_to_gain;
+use super::filter::DcBlocker;
+use super::oversampling::Oversampler;
+use super::smoother::{Ramp, Smoother};
+
+/// Saturation curves modelled on the stages that drive real spring tanks.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DriveType {
+  /// Symmetric tanh, odd harmonics only.
+  SoftClip,
+  /// Biased tanh, clips one side earlier than the other for even harmonics.
+  Tube,
+  /// Gentle cubic knee that flattens out hard, like a saturating core.
+  Transformer,
+}
+
+const TUBE_BIAS: f32 = 0.35;
+
+impl DriveType {
+  fn shape(self, x: f32) -> f32 {
+    match self {
+      DriveType::SoftClip => x.tanh(),
+      DriveType::Tube => (x + TUBE_BIAS).tanh() - TUBE_BIAS.tanh(),
+      DriveType::Transformer => {
+        let x = x.clamp(-1., 1.);
+        1.5 * (x - x * x * x / 3.)
+      }
+    }
+  }
+}
+
+struct DriveChannel {
+  oversampler: Oversampler,
+  dc_blocker: DcBlocker,
+}
+
+impl DriveChannel {
+  fn new(oversampling: usize) -> Self {
+    Self {
+      oversampler: Oversampler::new(oversampling),
+      dc_blocker: DcBlocker::new(),
+    }
+  }
+}
+
+// every oversampling factor on offer, built up front so switching between them never allocates
+const FACTORS: [usize; 4] = [1, 2, 4, 8];
+
+/// Stereo drive stage that sits in front of the convolvers.
+pub struct Drive {
+  enabled: bool,
+  drive_type: DriveType,
+  gain: Smoother,
+  // per sample gains for the current block, shared by both sides
+  gains: Vec<f32>,
+  // a left and right channel for each of `FACTORS`
+  channels: Vec<[DriveChannel; 2]>,
+  // index into `FACTORS` of the one in use
+  current: usize,
+}
+
+impl Default for Drive {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Drive {
+  pub fn new() -> Self {
+    Self {
+      enabled: false,
+      drive_type: DriveType::SoftClip,
+      // at a typical rate until the host gives the real one
+      gain: Smoother::new(Ramp::Multiplicative, 1., 44100.),
+      gains: Vec::new(),
+      channels: FACTORS.iter().map(|&factor| [DriveChannel::new(factor), DriveChannel::new(factor)]).collect(),
+      current: 2,
+    }
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.gain.set_sample_rate(sample_rate);
+  }
+
+  pub fn set_enabled(&mut self, enabled: bool) {
+    self.enabled = enabled;
+  }
+
+  pub fn set_type(&mut self, drive_type: DriveType) {
+    self.drive_type = drive_type;
+  }
+
+  pub fn set_amount_db(&mut self, amount_db: f32) {
+    self.gain.set_target(db_to_gain(amount_db));
+  }
+
+  pub fn set_oversampling(&mut self, factor: usize) {
+    let Some(index) = FACTORS.iter().position(|&f| f == factor) else {
+      return;
+    };
+    if index != self.current {
+      // start from silence rather than whatever it held when it was last used
+      for channel in self.channels[index].iter_mut() {
+        channel.oversampler.reset();
+        channel.dc_blocker = DcBlocker::new();
+      }
+      self.current = index;
+    }
+  }
+
+  /// How many samples the drive holds its input back by, none while it's off.
+  pub fn latency(&self) -> usize {
+    if self.enabled {
+      self.channels[self.current][0].oversampler.latency()
+    } else {
+      0
+    }
+  }
+
+  pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) {
+    if !self.enabled {
+      // nothing to glide through while it's off
+      self.gain.reset(self.gain.target());
+      return;
+    }
+    self.gains.clear();
+    for _ in 0..left.len() {
+      self.gains.push(self.gain.next_value());
+    }
+    let drive_type = self.drive_type;
+    let [left_channel, right_channel] = &mut self.channels[self.current];
+    for (channel, buffer) in [(left_channel, left), (right_channel, right)] {
+      for (sample, gain) in buffer.iter_mut().zip(&self.gains) {
+        let shaped = channel.oversampler.process(*sample, |x| drive_type.shape(x * gain));
+        *sample = channel.dc_blocker.process(shaped);
+      }
+    }
+  }
+}
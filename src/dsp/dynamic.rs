@@ -0,0 +1,98 @@
+use super::db_to_gain;
+use super::envelope::EnvelopeFollower;
+
+/*
+Dynamic reverb amount
+  - an envelope follower on the dry input turns the wet level up or down as the playing gets
+    louder or quieter
+  - inverse: the wet signal ducks as the input rises above the threshold, so the reverb blooms in
+    the gaps and stays out of the way of busy passages
+  - direct: the wet signal sits range dB down while the input is quiet, opening up as it rises
+    above the threshold, so only the loud hits splash
+  - either way the modulation spans range dB over the range dB of input above the threshold,
+    1:1, and the ballistics come from the follower's attack and release
+*/
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DynamicMode {
+  Off,
+  Inverse,
+  Direct,
+}
+
+/// Wet gain that follows the input level.
+pub struct Dynamic {
+  mode: DynamicMode,
+  threshold_db: f32,
+  range_db: f32,
+  follower: EnvelopeFollower,
+  // the modulation on the last sample, for the meter
+  gain_db: f32,
+}
+
+impl Dynamic {
+  pub fn new(sample_rate: f32) -> Self {
+    Self {
+      mode: DynamicMode::Off,
+      threshold_db: -30.,
+      range_db: 12.,
+      follower: EnvelopeFollower::new(sample_rate),
+      gain_db: 0.,
+    }
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.follower.set_sample_rate(sample_rate);
+  }
+
+  pub fn set_mode(&mut self, mode: DynamicMode) {
+    if mode != self.mode {
+      self.follower.reset();
+    }
+    self.mode = mode;
+    if mode == DynamicMode::Off {
+      self.gain_db = 0.;
+    }
+  }
+
+  pub fn set_threshold_db(&mut self, threshold_db: f32) {
+    self.threshold_db = threshold_db;
+  }
+
+  pub fn set_range_db(&mut self, range_db: f32) {
+    self.range_db = range_db.max(0.);
+  }
+
+  pub fn set_attack_ms(&mut self, attack_ms: f32) {
+    self.follower.set_attack_ms(attack_ms);
+  }
+
+  pub fn set_release_ms(&mut self, release_ms: f32) {
+    self.follower.set_release_ms(release_ms);
+  }
+
+  /// The current modulation of the wet level, in dB. Never above 0.
+  pub fn gain_db(&self) -> f32 {
+    self.gain_db
+  }
+
+  // scales the wet buffers in place, keyed from the dry input
+  pub fn process(&mut self, dry_l: &[f32], dry_r: &[f32], wet_l: &mut [f32], wet_r: &mut [f32]) {
+    if self.mode == DynamicMode::Off {
+      return;
+    }
+
+    for i in 0..wet_l.len() {
+      let level = self.follower.process(dry_l[i].abs().max(dry_r[i].abs()));
+      let level_db = 20. * level.max(1e-6).log10();
+      let above = (level_db - self.threshold_db).clamp(0., self.range_db);
+      self.gain_db = match self.mode {
+        DynamicMode::Inverse => -above,
+        _ => above - self.range_db,
+      };
+      let gain = db_to_gain(self.gain_db);
+      wet_l[i] *= gain;
+      wet_r[i] *= gain;
+    }
+  }
+}
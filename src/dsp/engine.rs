@@ -0,0 +1,44 @@
+/// Which reverb algorithm turns the excitation into the wet signal. Must line up with
+/// `plugin_state::ENGINE_OPTIONS`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EngineKind {
+  /// The recorded spring, through the partitioned convolvers.
+  SpringIr,
+  /// Algorithmic spring built from dispersive allpass chains.
+  SpringModel,
+  /// Finite difference simulation of a helical spring.
+  PhysicalSpring,
+  /// General purpose feedback delay network room.
+  Room,
+  /// Dattorro style plate.
+  Plate,
+  /// Algorithmic early reflections with a convolution tail.
+  Hybrid,
+  /// Sparse velvet noise convolution.
+  Velvet,
+}
+
+impl EngineKind {
+  pub fn from_index(index: usize) -> Self {
+    match index {
+      0 => EngineKind::SpringIr,
+      1 => EngineKind::SpringModel,
+      2 => EngineKind::PhysicalSpring,
+      3 => EngineKind::Room,
+      4 => EngineKind::Plate,
+      5 => EngineKind::Hybrid,
+      _ => EngineKind::Velvet,
+    }
+  }
+}
+
+/// A stereo reverb algorithm living alongside the convolvers. Output levels should sit roughly
+/// where the (scaled) convolver output does, so switching engines does not jump in level.
+pub trait Engine {
+  fn set_sample_rate(&mut self, sample_rate: f32);
+
+  // clears any ringing state
+  fn reset(&mut self);
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]);
+}
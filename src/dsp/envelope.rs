@@ -0,0 +1,67 @@
+/*
+Envelope follower
+  - tracks the peak of a signal with separate attack and release times, the usual compressor
+    style ballistics: the level jumps up quickly and falls back slowly
+  - each time is how long a step takes to get about two thirds of the way there
+*/
+
+/// Peak envelope follower with attack and release ballistics.
+pub struct EnvelopeFollower {
+  sample_rate: f32,
+  attack_ms: f32,
+  release_ms: f32,
+  attack_coef: f32,
+  release_coef: f32,
+  level: f32,
+}
+
+impl EnvelopeFollower {
+  pub fn new(sample_rate: f32) -> Self {
+    let mut follower = Self {
+      sample_rate,
+      attack_ms: 10.,
+      release_ms: 200.,
+      attack_coef: 0.,
+      release_coef: 0.,
+      level: 0.,
+    };
+    follower.update_coefs();
+    follower
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.update_coefs();
+  }
+
+  pub fn set_attack_ms(&mut self, attack_ms: f32) {
+    self.attack_ms = attack_ms;
+    self.update_coefs();
+  }
+
+  pub fn set_release_ms(&mut self, release_ms: f32) {
+    self.release_ms = release_ms;
+    self.update_coefs();
+  }
+
+  pub fn reset(&mut self) {
+    self.level = 0.;
+  }
+
+  fn update_coefs(&mut self) {
+    let coef = |ms: f32| (-1. / (ms * 0.001 * self.sample_rate).max(1.)).exp();
+    self.attack_coef = coef(self.attack_ms);
+    self.release_coef = coef(self.release_ms);
+  }
+
+  /// Follows one more sample, returning the current level.
+  pub fn process(&mut self, input: f32) -> f32 {
+    let input = input.abs();
+    let coef = if input > self.level { self.attack_coef } else { self.release_coef };
+    self.level = input + coef * (self.level - input);
+    if self.level < 1e-20 {
+      self.level = 0.;
+    }
+    self.level
+  }
+}
@@ -0,0 +1,272 @@
+use std::f32::consts::TAU;
+use super::delay_line::DelayLine;
+use super::engine::Engine;
+use super::filter::OnePoleLowPass;
+
+/*
+Feedback delay network (after Jot & Chaigne)
+  - N delay lines whose outputs are mixed by a lossless (orthogonal) matrix and fed back into
+    their inputs, so every echo spreads into every line and the echo density keeps building
+  - each line ends in an absorption filter that loses exactly enough per pass for its band to
+    fall 60dB in the target RT60: gain = 10^(-3 * delay / (fs * RT60))
+      - the line is split into low / mid / high with two one pole low passes, the three bands
+        always summing back to the input, so equal gains leave the signal untouched
+  - lengths are spread out primes scaled by the room size, so the lines don't share modes
+  - each line's length wobbles slowly on its own LFO phase, smearing the remaining metallic
+    resonances
+*/
+
+pub const MAX_LINES: usize = 16;
+const MAX_SIZE_MS: f32 = 200.;
+const MAX_MOD_MS: f32 = 1.;
+const LOW_CROSSOVER: f32 = 250.;
+const HIGH_CROSSOVER: f32 = 4000.;
+// keeps the loop strictly lossy, whatever the band gains ask for
+const MAX_LOOP_GAIN: f32 = 0.9995;
+const OUTPUT_GAIN: f32 = 1.2;
+// geometrically spaced primes, the longest line is the room size
+const LINE_PRIMES: [f32; MAX_LINES] = [
+  1009., 1049., 1091., 1151., 1193., 1249., 1297., 1361.,
+  1423., 1481., 1543., 1613., 1693., 1759., 1847., 1931.,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FeedbackMatrix {
+  Hadamard,
+  Householder,
+}
+
+/// Three band absorption, set from an RT60 per band.
+struct Absorption {
+  low_split: OnePoleLowPass,
+  high_split: OnePoleLowPass,
+  gains: [f32; 3],
+}
+
+impl Absorption {
+  fn new(sample_rate: f32) -> Self {
+    Self {
+      low_split: OnePoleLowPass::new(LOW_CROSSOVER, sample_rate),
+      high_split: OnePoleLowPass::new(HIGH_CROSSOVER, sample_rate),
+      gains: [0.; 3],
+    }
+  }
+
+  fn set_rt60(&mut self, rt60: [f32; 3], delay_samples: f32, sample_rate: f32) {
+    for (gain, seconds) in self.gains.iter_mut().zip(rt60) {
+      *gain = 10f32.powf(-3. * delay_samples / (sample_rate * seconds));
+    }
+    // the bands overlap, so the filter can peak above its largest gain when the gains don't fall
+    // with frequency. this bounds the peak
+    let [low, mid, high] = self.gains;
+    let peak = high + (mid - high).abs() + (low - mid).abs();
+    if peak > MAX_LOOP_GAIN {
+      for gain in self.gains.iter_mut() {
+        *gain *= MAX_LOOP_GAIN / peak;
+      }
+    }
+  }
+
+  fn reset(&mut self) {
+    self.low_split.reset();
+    self.high_split.reset();
+  }
+
+  fn process(&mut self, input: f32) -> f32 {
+    let low = self.low_split.process(input);
+    let below_high = self.high_split.process(input);
+    let [low_gain, mid_gain, high_gain] = self.gains;
+    low * low_gain + (below_high - low) * mid_gain + (input - below_high) * high_gain
+  }
+}
+
+struct Line {
+  delay: DelayLine,
+  absorption: Absorption,
+  length_samples: f32,
+  lfo_phase: f32,
+}
+
+impl Line {
+  fn new(sample_rate: f32, lfo_phase: f32) -> Self {
+    // the wobble swings up to twice the depth past the length
+    let max_delay = ((MAX_SIZE_MS + 2. * MAX_MOD_MS) * 0.001 * sample_rate) as usize + 2;
+    Self {
+      delay: DelayLine::new(max_delay),
+      absorption: Absorption::new(sample_rate),
+      length_samples: 1.,
+      lfo_phase,
+    }
+  }
+
+  fn reset(&mut self) {
+    self.delay.clear();
+    self.absorption.reset();
+  }
+}
+
+/// General purpose room reverb: an 8 or 16 line feedback delay network.
+pub struct Fdn {
+  sample_rate: f32,
+  line_count: usize,
+  matrix: FeedbackMatrix,
+  size_ms: f32,
+  rt60: [f32; 3], // low, mid, high, in seconds
+  mod_rate: f32, // Hz
+  mod_depth: f32, // 0..1
+  lines: Vec<Line>,
+}
+
+impl Fdn {
+  pub fn new(sample_rate: f32) -> Self {
+    let mut fdn = Self {
+      sample_rate,
+      line_count: 8,
+      matrix: FeedbackMatrix::Hadamard,
+      size_ms: 80.,
+      rt60: [2., 1.6, 0.8],
+      mod_rate: 0.5,
+      mod_depth: 0.2,
+      lines: Vec::new(),
+    };
+    fdn.build_lines();
+    fdn
+  }
+
+  pub fn set_line_count(&mut self, line_count: usize) {
+    let line_count = if line_count > 8 { MAX_LINES } else { 8 };
+    if line_count != self.line_count {
+      self.line_count = line_count;
+      // the lengths are spread over a different set of primes
+      self.update();
+      self.reset();
+    }
+  }
+
+  pub fn set_matrix(&mut self, matrix: FeedbackMatrix) {
+    self.matrix = matrix;
+  }
+
+  pub fn set_size_ms(&mut self, size_ms: f32) {
+    self.size_ms = size_ms.clamp(1., MAX_SIZE_MS);
+    self.update();
+  }
+
+  pub fn set_rt60_low(&mut self, seconds: f32) {
+    self.rt60[0] = seconds;
+    self.update();
+  }
+
+  pub fn set_rt60_mid(&mut self, seconds: f32) {
+    self.rt60[1] = seconds;
+    self.update();
+  }
+
+  pub fn set_rt60_high(&mut self, seconds: f32) {
+    self.rt60[2] = seconds;
+    self.update();
+  }
+
+  /// Sets the low, mid and high RT60s in one go.
+  pub fn set_rt60(&mut self, seconds: [f32; 3]) {
+    self.rt60 = seconds;
+    self.update();
+  }
+
+  pub fn set_mod_rate(&mut self, hz: f32) {
+    self.mod_rate = hz;
+  }
+
+  pub fn set_mod_depth(&mut self, depth: f32) {
+    self.mod_depth = depth.clamp(0., 1.);
+  }
+
+  fn build_lines(&mut self) {
+    self.lines = (0..MAX_LINES)
+      .map(|i| Line::new(self.sample_rate, i as f32 / MAX_LINES as f32))
+      .collect();
+    self.update();
+  }
+
+  fn update(&mut self) {
+    let stride = MAX_LINES / self.line_count;
+    let size_samples = self.size_ms * 0.001 * self.sample_rate;
+    for (i, line) in self.lines.iter_mut().take(self.line_count).enumerate() {
+      let prime = LINE_PRIMES[i * stride];
+      line.length_samples = (size_samples * prime / LINE_PRIMES[MAX_LINES - 1]).max(1.);
+      line.absorption.set_rt60(self.rt60, line.length_samples, self.sample_rate);
+    }
+  }
+
+  // fast walsh hadamard transform, normalized so it stays lossless
+  fn hadamard(values: &mut [f32]) {
+    let mut half = 1;
+    while half < values.len() {
+      for start in (0..values.len()).step_by(half * 2) {
+        for i in start..start + half {
+          let (a, b) = (values[i], values[i + half]);
+          values[i] = a + b;
+          values[i + half] = a - b;
+        }
+      }
+      half *= 2;
+    }
+    let scale = 1. / (values.len() as f32).sqrt();
+    for value in values.iter_mut() {
+      *value *= scale;
+    }
+  }
+
+  // reflection about the all ones vector, I - 2/N 11^T
+  fn householder(values: &mut [f32]) {
+    let shift = 2. * values.iter().sum::<f32>() / values.len() as f32;
+    for value in values.iter_mut() {
+      *value -= shift;
+    }
+  }
+}
+
+impl Engine for Fdn {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.build_lines();
+  }
+
+  fn reset(&mut self) {
+    for line in self.lines.iter_mut() {
+      line.reset();
+    }
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    let count = self.line_count;
+    let mod_samples = self.mod_depth * MAX_MOD_MS * 0.001 * self.sample_rate;
+    let phase_step = self.mod_rate / self.sample_rate;
+    let level = OUTPUT_GAIN / (count as f32).sqrt();
+    let mut feedback = [0.; MAX_LINES];
+    for i in 0..input_l.len() {
+      let (mut l, mut r) = (0., 0.);
+      for (n, line) in self.lines.iter_mut().take(count).enumerate() {
+        let wobble = mod_samples * (1. + (TAU * line.lfo_phase).sin());
+        line.lfo_phase = (line.lfo_phase + phase_step).fract();
+        let out = line.absorption.process(line.delay.read_linear(line.length_samples - 1. + wobble));
+        feedback[n] = out;
+        // alternate signs, so the two sides don't share a mode
+        let sign = if n % 4 < 2 { 1. } else { -1. };
+        if n % 2 == 0 { l += out * sign } else { r += out * sign }
+      }
+
+      match self.matrix {
+        FeedbackMatrix::Hadamard => Self::hadamard(&mut feedback[..count]),
+        FeedbackMatrix::Householder => Self::householder(&mut feedback[..count]),
+      }
+
+      for (n, line) in self.lines.iter_mut().take(count).enumerate() {
+        let input = if n % 2 == 0 { input_l[i] } else { input_r[i] };
+        line.delay.push(feedback[n] + input);
+      }
+      output_l[i] = l * level;
+      output_r[i] = r * level;
+    }
+  }
+}
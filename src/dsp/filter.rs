@@ -0,0 +1,99 @@
+/// One pole high pass at a few Hz, used to strip the DC offset asymmetric shaping leaves behind.
+pub struct DcBlocker {
+  coef: f32,
+  previous_input: f32,
+  previous_output: f32,
+}
+
+impl Default for DcBlocker {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl DcBlocker {
+  pub fn new() -> Self {
+    Self {
+      coef: 0.995,
+      previous_input: 0.,
+      previous_output: 0.,
+    }
+  }
+
+  pub fn process(&mut self, input: f32) -> f32 {
+    let output = input - self.previous_input + self.coef * self.previous_output;
+    self.previous_input = input;
+    self.previous_output = output;
+    output
+  }
+}
+
+/// One pole low pass, used for gentle damping.
+#[derive(Clone)]
+pub struct OnePoleLowPass {
+  coef: f32,
+  state: f32,
+}
+
+impl OnePoleLowPass {
+  pub fn new(cutoff: f32, sample_rate: f32) -> Self {
+    let mut filter = Self { coef: 0., state: 0. };
+    filter.set_cutoff(cutoff, sample_rate);
+    filter
+  }
+
+  pub fn set_cutoff(&mut self, cutoff: f32, sample_rate: f32) {
+    self.coef = (-2. * std::f32::consts::PI * cutoff / sample_rate).exp();
+  }
+
+  pub fn reset(&mut self) {
+    self.state = 0.;
+  }
+
+  pub fn process(&mut self, input: f32) -> f32 {
+    self.state = input + self.coef * (self.state - input);
+    // left to decay on silence the state gets stuck on a denormal, which is very slow to work with
+    if self.state.abs() < 1e-20 {
+      self.state = 0.;
+    }
+    self.state
+  }
+}
+
+/// Tilts the spectrum about a pivot, lifting one side and cutting the other by the same dB.
+pub struct TiltEq {
+  low_pass: OnePoleLowPass,
+  low_gain: f32,
+  high_gain: f32,
+}
+
+impl TiltEq {
+  const PIVOT: f32 = 1000.;
+
+  pub fn new(sample_rate: f32) -> Self {
+    Self {
+      low_pass: OnePoleLowPass::new(Self::PIVOT, sample_rate),
+      low_gain: 1.,
+      high_gain: 1.,
+    }
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.low_pass.set_cutoff(Self::PIVOT, sample_rate);
+  }
+
+  // positive tilts towards the highs
+  pub fn set_tilt_db(&mut self, tilt_db: f32) {
+    self.low_gain = 10f32.powf(-tilt_db / 40.);
+    self.high_gain = 10f32.powf(tilt_db / 40.);
+  }
+
+  pub fn reset(&mut self) {
+    self.low_pass.reset();
+  }
+
+  pub fn process(&mut self, input: f32) -> f32 {
+    let low = self.low_pass.process(input);
+    low * self.low_gain + (input - low) * self.high_gain
+  }
+}
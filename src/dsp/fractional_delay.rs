@@ -0,0 +1,47 @@
+use super::delay_line::DelayLine;
+
+/*
+Fractional delay
+  - for delays that move every sample, so the interpolation has to be stateless. allpass
+    interpolation is flat in magnitude, but its recursive state rings whenever the delay jumps,
+    which under modulation is all the time
+  - 4 point, 3rd order Hermite instead. it keeps the highs far better than linear interpolation,
+    without the zipper noise, and needs one sample either side of the read point
+*/
+
+/// Delay line that can be read anywhere between whole samples.
+pub struct FractionalDelay {
+  line: DelayLine,
+}
+
+impl FractionalDelay {
+  pub fn new(max_delay: usize) -> Self {
+    Self { line: DelayLine::new(max_delay + 2) }
+  }
+
+  pub fn clear(&mut self) {
+    self.line.clear();
+  }
+
+  /// Writes a sample without reading one back.
+  pub fn push(&mut self, sample: f32) {
+    self.line.push(sample);
+  }
+
+  // delay in samples, at least 1
+  pub fn process(&mut self, sample: f32, delay: f32) -> f32 {
+    self.line.push(sample);
+    let delay = delay.max(1.);
+    let whole = delay.floor() as usize;
+    let t = delay - delay.floor();
+    let newer = self.line.read(whole - 1);
+    let current = self.line.read(whole);
+    let older = self.line.read(whole + 1);
+    let oldest = self.line.read(whole + 2);
+
+    let c1 = 0.5 * (older - newer);
+    let c2 = newer - 2.5 * current + 2. * older - 0.5 * oldest;
+    let c3 = 0.5 * (oldest - newer) + 1.5 * (current - older);
+    ((c3 * t + c2) * t + c1) * t + current
+  }
+}
@@ -0,0 +1,93 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+use rustfft::{Fft, FftPlanner, num_complex::Complex};
+
+use super::noise::Noise;
+
+/*
+Spectral freeze
+  - capture the magnitudes of the convolver's most recent freq domain output frame
+  - every hop (1/2 fft_size) build a new frame from those magnitudes with random phases
+  - IFFT, hann window, overlap add
+  - random phases keep the sustained tail from sounding like a 1024 sample loop
+*/
+
+// power lost to the hann window when overlapping uncorrelated frames at 50%
+const WINDOW_COMPENSATION: f32 = 1.154_700_5; // sqrt(4/3)
+
+pub struct SpectralFreeze {
+  fft_size: usize,
+  hop: usize,
+  magnitudes: Vec<f32>,
+  window: Vec<f32>,
+  ifft_processor: Arc<dyn Fft<f32>>,
+  frame: Vec<Complex<f32>>,
+  fft_scratch: Vec<Complex<f32>>, // for the IFFT to work in, so it doesn't allocate its own
+  overlap: Vec<f32>, // overlap added time domain output, the first hop is ready to be read
+  read_index: usize,
+  noise: Noise,
+}
+
+impl SpectralFreeze {
+  pub fn new(fft_size: usize, seed: u32) -> Self {
+    let mut planner = FftPlanner::<f32>::new();
+    let hop = fft_size / 2;
+    let ifft_processor = planner.plan_fft_inverse(fft_size);
+    // periodic hann, sums to 1 at 50% overlap
+    let window = (0..fft_size)
+      .map(|i| 0.5 - 0.5 * (2. * PI * i as f32 / fft_size as f32).cos())
+      .collect();
+    Self {
+      fft_size,
+      hop,
+      magnitudes: vec![0.; fft_size],
+      window,
+      fft_scratch: vec![Complex { re: 0., im: 0. }; ifft_processor.get_inplace_scratch_len()],
+      ifft_processor,
+      frame: vec![Complex { re: 0., im: 0. }; fft_size],
+      overlap: vec![0.; fft_size],
+      read_index: hop,
+      noise: Noise::new(seed),
+    }
+  }
+
+  // takes a snapshot of the spectral content to sustain
+  pub fn capture(&mut self, spectrum: &[Complex<f32>]) {
+    for (magnitude, bin) in self.magnitudes.iter_mut().zip(spectrum) {
+      *magnitude = bin.norm() * WINDOW_COMPENSATION;
+    }
+  }
+
+  pub fn next_sample(&mut self) -> f32 {
+    if self.read_index == self.hop {
+      self.synthesize_frame();
+      self.read_index = 0;
+    }
+    let sample = self.overlap[self.read_index];
+    self.read_index += 1;
+    sample
+  }
+
+  fn synthesize_frame(&mut self) {
+    let n = self.fft_size;
+    // only the lower half of the spectrum is free, the upper half mirrors it so the IFFT is real
+    self.frame[0] = Complex { re: self.magnitudes[0], im: 0. };
+    self.frame[n / 2] = Complex { re: self.magnitudes[n / 2], im: 0. };
+    for k in 1..n / 2 {
+      let phase = self.noise.next_unipolar() * 2. * PI;
+      let bin = Complex::from_polar(self.magnitudes[k], phase);
+      self.frame[k] = bin;
+      self.frame[n - k] = bin.conj();
+    }
+    self.ifft_processor.process_with_scratch(&mut self.frame, &mut self.fft_scratch);
+
+    // slide the overlap buffer along by a hop and add in the new frame
+    self.overlap.copy_within(self.hop.., 0);
+    for sample in self.overlap[n - self.hop..].iter_mut() {
+      *sample = 0.;
+    }
+    for ((out, sample), w) in self.overlap.iter_mut().zip(&self.frame).zip(&self.window) {
+      *out += sample.re * w;
+    }
+  }
+}
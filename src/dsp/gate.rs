@@ -0,0 +1,174 @@
+use super::delay_line::DelayLine;
+use super::{db_to_gain, ms_to_samples};
+
+/*
+Gate on the wet signal ('80s gated reverb)
+  - key is the peak of both channels, taken from either the dry input or the wet signal
+  - key above threshold opens the gate and restarts the hold timer
+  - once hold runs out the gain falls exponentially towards the range floor
+  - the gated signal is delayed by the lookahead, and the gain ramps open over that same
+    lookahead, so transients pass without a click
+  - the signal is always held back by the longest lookahead, and the key by whatever's left of
+    it, so the latency the gate reports never moves with the lookahead knob
+*/
+
+const MAX_LOOKAHEAD_MS: f32 = 10.;
+
+/// How far the gate holds back the wet signal while it's on.
+pub fn gate_latency(sample_rate: f32) -> usize {
+  ms_to_samples(MAX_LOOKAHEAD_MS, sample_rate)
+}
+
+/// Which signal opens the gate.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum GateKey {
+  Input,
+  Wet,
+}
+
+/// Stereo linked gate / downward expander.
+pub struct Gate {
+  enabled: bool,
+  key: GateKey,
+  sample_rate: f32,
+  threshold: f32, // linear
+  floor: f32, // linear gain when fully closed
+  hold_ms: f32,
+  release_ms: f32,
+  lookahead_ms: f32,
+  // derived from the times above
+  hold_samples: usize,
+  release_coef: f32,
+  lookahead_samples: usize,
+  max_lookahead_samples: usize,
+  attack_step: f32,
+  // running state
+  gain: f32,
+  hold_counter: usize,
+  key_delay: DelayLine,
+  delay_l: DelayLine,
+  delay_r: DelayLine,
+}
+
+impl Gate {
+  pub fn new(sample_rate: f32) -> Self {
+    let max_lookahead = gate_latency(sample_rate);
+    let mut gate = Self {
+      enabled: false,
+      key: GateKey::Input,
+      sample_rate,
+      threshold: db_to_gain(-24.),
+      floor: 0.,
+      hold_ms: 250.,
+      release_ms: 40.,
+      lookahead_ms: 2.,
+      hold_samples: 0,
+      release_coef: 0.,
+      lookahead_samples: 0,
+      max_lookahead_samples: max_lookahead,
+      attack_step: 1.,
+      gain: 0.,
+      hold_counter: 0,
+      key_delay: DelayLine::new(max_lookahead),
+      delay_l: DelayLine::new(max_lookahead),
+      delay_r: DelayLine::new(max_lookahead),
+    };
+    gate.update_times();
+    gate
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    let max_lookahead = gate_latency(sample_rate);
+    self.max_lookahead_samples = max_lookahead;
+    self.key_delay.resize(max_lookahead);
+    self.delay_l.resize(max_lookahead);
+    self.delay_r.resize(max_lookahead);
+    self.update_times();
+  }
+
+  pub fn set_enabled(&mut self, enabled: bool) {
+    if enabled && !self.enabled {
+      self.reset();
+    }
+    self.enabled = enabled;
+  }
+
+  /// How far the gate is holding back the wet signal.
+  pub fn latency(&self) -> usize {
+    if self.enabled { self.max_lookahead_samples } else { 0 }
+  }
+
+  pub fn set_key(&mut self, key: GateKey) {
+    self.key = key;
+  }
+
+  pub fn set_threshold_db(&mut self, threshold_db: f32) {
+    self.threshold = db_to_gain(threshold_db);
+  }
+
+  // anything at or below -90dB is treated as a hard gate
+  pub fn set_range_db(&mut self, range_db: f32) {
+    self.floor = if range_db <= -90. { 0. } else { db_to_gain(range_db) };
+  }
+
+  pub fn set_hold_ms(&mut self, hold_ms: f32) {
+    self.hold_ms = hold_ms;
+    self.update_times();
+  }
+
+  pub fn set_release_ms(&mut self, release_ms: f32) {
+    self.release_ms = release_ms;
+    self.update_times();
+  }
+
+  pub fn set_lookahead_ms(&mut self, lookahead_ms: f32) {
+    self.lookahead_ms = lookahead_ms.min(MAX_LOOKAHEAD_MS);
+    self.update_times();
+  }
+
+  pub fn reset(&mut self) {
+    self.gain = self.floor;
+    self.hold_counter = 0;
+    self.key_delay.clear();
+    self.delay_l.clear();
+    self.delay_r.clear();
+  }
+
+  fn update_times(&mut self) {
+    self.hold_samples = ms_to_samples(self.hold_ms, self.sample_rate);
+    self.release_coef = (-1. / (self.release_ms * 0.001 * self.sample_rate).max(1.)).exp();
+    self.lookahead_samples = ms_to_samples(self.lookahead_ms, self.sample_rate);
+    self.attack_step = 1. / (self.lookahead_samples.max(1) as f32);
+  }
+
+  // gates the wet buffers in place
+  pub fn process(&mut self, dry_l: &[f32], dry_r: &[f32], wet_l: &mut [f32], wet_r: &mut [f32]) {
+    if !self.enabled {
+      return;
+    }
+
+    // the key leads the held back signal by the lookahead
+    let key_delay = self.max_lookahead_samples - self.lookahead_samples.min(self.max_lookahead_samples);
+    for i in 0..wet_l.len() {
+      let key = match self.key {
+        GateKey::Input => dry_l[i].abs().max(dry_r[i].abs()),
+        GateKey::Wet => wet_l[i].abs().max(wet_r[i].abs()),
+      };
+      let key = self.key_delay.process(key, key_delay);
+
+      if key >= self.threshold {
+        self.hold_counter = self.hold_samples;
+        self.gain = (self.gain + self.attack_step).min(1.);
+      } else if self.hold_counter > 0 {
+        self.hold_counter -= 1;
+        self.gain = (self.gain + self.attack_step).min(1.);
+      } else {
+        self.gain = self.floor + (self.gain - self.floor) * self.release_coef;
+      }
+
+      wet_l[i] = self.delay_l.process(wet_l[i], self.max_lookahead_samples) * self.gain;
+      wet_r[i] = self.delay_r.process(wet_r[i], self.max_lookahead_samples) * self.gain;
+    }
+  }
+}
@@ -0,0 +1,358 @@
+use super::convolution::{partition, Convolver, ConvolverIr, IR_FADE_SAMPLES};
+use super::delay_line::DelayLine;
+use super::engine::Engine;
+use super::noise::Noise;
+use super::smoother::{Ramp, Smoother};
+use super::spring_impulse_response::SPRING_IMPULSE_RESPONSE;
+use super::spring_ir::{max_ir_length, PartitionedIr, FFT_SIZE, WET_DIVISOR};
+
+/*
+Hybrid reverb
+  - the IR is split in time at the crossover: everything before it is replaced by an algorithmic
+    early section, everything after it stays with the convolvers
+  - the tail is cut with a raised cosine crossfade centred on the crossover
+  - early section: a sparse tapped delay of reflections, then smeared by a couple of short
+    allpasses. it starts on the very first sample, so the attack has no latency whatever the
+    convolvers are doing
+  - level matching: each reflection carries the energy of the stretch of the IR nearest to it,
+    less what the tail keeps of that stretch. the reflections are uncorrelated with the tail, so
+    it's their powers that add up, and this way the early section follows the IR's own envelope
+    and the pair holds its level through the hand over without a dip or a bump
+  - the reflections are laid out on the IR worker along with the tail they stand in front of,
+    and the two are crossfaded over to together, so moving the crossover never leaves the early
+    section handing over to a tail split somewhere else
+  - what's swapped out, the reflections and the convolvers' IRs, is kept to be sent back to the
+    IR worker and freed there
+*/
+
+pub const MAX_CROSSOVER_MS: f32 = 300.;
+const DEFAULT_CROSSOVER_MS: f32 = 80.;
+const CROSSFADE_MS: f32 = 20.;
+// enough that even the longest crossover has a reflection every few milliseconds at the far end
+const TAP_COUNT: usize = 128;
+const DIFFUSER_MS: [f32; 2] = [3.1, 5.3];
+const DIFFUSER_COEF: f32 = 0.5;
+// reflections done with and waiting to be sent back, past this many they're dropped in place
+const MAX_RETIRED: usize = 4;
+
+/// The late part of an IR, and the early section's reflections to stand in for the rest. Built
+/// together, so the two always hand over at the same crossover.
+#[derive(Clone)]
+pub struct HybridTail {
+  pub impulse_response: PartitionedIr,
+  early: EarlyTaps,
+}
+
+// raised cosine from 0 (early) to 1 (late) around the crossover
+fn late_window(time: f32, crossover: f32, crossfade: f32) -> f32 {
+  let position = ((time - crossover) / crossfade + 0.5).clamp(0., 1.);
+  0.5 - 0.5 * (std::f32::consts::PI * position).cos()
+}
+
+/// Splits a stereo IR at the crossover time, and lays out the early reflections to match. Heavy,
+/// so meant for use off the audio thread.
+pub fn split_impulse_response(left: &[f32], right: &[f32], crossover_ms: f32, sample_rate: f32) -> HybridTail {
+  let crossover = crossover_ms.clamp(1., MAX_CROSSOVER_MS) * 0.001 * sample_rate;
+  let crossfade = CROSSFADE_MS * 0.001 * sample_rate;
+  let split = |impulse_response: &[f32]| {
+    let late: Vec<f32> = impulse_response
+      .iter()
+      .enumerate()
+      .map(|(i, sample)| sample * late_window(i as f32, crossover, crossfade))
+      .collect();
+    partition(&late, FFT_SIZE)
+  };
+  HybridTail {
+    impulse_response: PartitionedIr {
+      left: split(left),
+      right: split(right),
+    },
+    early: EarlyTaps {
+      left: early_side(left, crossover, crossfade, 1),
+      right: early_side(right, crossover, crossfade, 2),
+      sample_rate,
+    },
+  }
+}
+
+struct Allpass {
+  line: DelayLine,
+  length: usize,
+}
+
+impl Allpass {
+  fn new(ms: f32, sample_rate: f32) -> Self {
+    let length = ((ms * 0.001 * sample_rate) as usize).max(1);
+    Self { line: DelayLine::new(length), length }
+  }
+
+  fn process(&mut self, input: f32) -> f32 {
+    let delayed = self.line.read(self.length - 1);
+    let w = input + DIFFUSER_COEF * delayed;
+    self.line.push(w);
+    delayed - DIFFUSER_COEF * w
+  }
+}
+
+// a reflection: how long ago, and how loud
+#[derive(Clone)]
+struct EarlyTap {
+  delay: usize,
+  gain: f32,
+}
+
+/// Both sides' reflections for one crossover, level matched to the part of the IR they replace.
+#[derive(Clone)]
+pub struct EarlyTaps {
+  left: Vec<EarlyTap>,
+  right: Vec<EarlyTap>,
+  // the rate the delays are counted at
+  sample_rate: f32,
+}
+
+impl EarlyTaps {
+  // moves the reflections to the same times at a new sample rate, until the IR worker has laid
+  // out a new set
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    let ratio = sample_rate / self.sample_rate;
+    for tap in self.left.iter_mut().chain(self.right.iter_mut()) {
+      tap.delay = (tap.delay as f32 * ratio) as usize;
+    }
+    self.sample_rate = sample_rate;
+  }
+}
+
+// one side's reflections, standing in for `impulse_response` up to the crossover
+fn early_side(impulse_response: &[f32], crossover: f32, crossfade: f32, seed: u32) -> Vec<EarlyTap> {
+  let span = crossover + crossfade / 2.;
+  let mut noise = Noise::new(seed);
+  let mut taps: Vec<EarlyTap> = (0..TAP_COUNT)
+    .map(|k| {
+      // denser towards the start, like the first few bounces off nearby walls. one to each
+      // stretch, so there are no long gaps between them
+      let position = (k as f32 + noise.next_unipolar()) / TAP_COUNT as f32;
+      let delay = span * position.powi(2);
+      let sign = if noise.next_unipolar() < 0.5 { 1. } else { -1. };
+      EarlyTap { delay: delay as usize, gain: sign }
+    })
+    .collect();
+  taps.sort_by_key(|tap| tap.delay);
+
+  // each reflection takes the stretch of the IR closer to it than to its neighbours
+  let end = (span.ceil() as usize).min(impulse_response.len());
+  // the convolvers aren't normalized, see `SpringIr`
+  let output_scale = FFT_SIZE as f32 / WET_DIVISOR;
+  let bounds: Vec<usize> = taps.windows(2).map(|pair| (pair[0].delay + pair[1].delay).div_ceil(2)).collect();
+  let starts = std::iter::once(0).chain(bounds.iter().copied());
+  let ends = bounds.iter().copied().chain(std::iter::once(end));
+  for (tap, (start, end)) in taps.iter_mut().zip(starts.zip(ends)) {
+    let energy: f32 = (start.min(end)..end)
+      .map(|i| {
+        let late = late_window(i as f32, crossover, crossfade);
+        impulse_response[i].powi(2) * (1. - late * late)
+      })
+      .sum();
+    tap.gain *= energy.sqrt() * output_scale;
+  }
+  taps
+}
+
+// the reflections off the input history
+fn tap_sum(taps: &[EarlyTap], input: &DelayLine) -> f32 {
+  taps.iter().map(|tap| input.read(tap.delay) * tap.gain).sum()
+}
+
+/// One side of the early section's smearing.
+struct Diffusers {
+  allpasses: Vec<Allpass>,
+}
+
+impl Diffusers {
+  fn new(sample_rate: f32) -> Self {
+    Self {
+      allpasses: DIFFUSER_MS.iter().map(|ms| Allpass::new(*ms, sample_rate)).collect(),
+    }
+  }
+
+  fn reset(&mut self) {
+    for allpass in self.allpasses.iter_mut() {
+      allpass.line.clear();
+    }
+  }
+
+  fn process(&mut self, input: f32) -> f32 {
+    let mut out = input;
+    for allpass in self.allpasses.iter_mut() {
+      out = allpass.process(out);
+    }
+    out
+  }
+}
+
+// the outgoing reflections while a swap is being crossfaded
+struct EarlyFade {
+  taps: EarlyTaps,
+  remaining: usize,
+}
+
+/// Algorithmic early reflections handing over to a convolution tail.
+pub struct Hybrid {
+  sample_rate: f32,
+  balance: Smoother, // 0 all early .. 1 all late
+  input_l: DelayLine,
+  input_r: DelayLine,
+  early: EarlyTaps,
+  fade: Option<EarlyFade>,
+  pending: Option<HybridTail>, // waiting for the current fade to finish
+  retired: Vec<EarlyTaps>, // done with, for `take_retired_taps`
+  diffusers_l: Diffusers,
+  diffusers_r: Diffusers,
+  convolver_l: Convolver,
+  convolver_r: Convolver,
+}
+
+impl Hybrid {
+  pub fn new(sample_rate: f32) -> Self {
+    let tail = split_impulse_response(SPRING_IMPULSE_RESPONSE, SPRING_IMPULSE_RESPONSE, DEFAULT_CROSSOVER_MS, sample_rate);
+    let mut hybrid = Self {
+      sample_rate,
+      balance: Smoother::new(Ramp::Linear, 0.5, sample_rate),
+      input_l: DelayLine::new(0),
+      input_r: DelayLine::new(0),
+      early: EarlyTaps { left: Vec::new(), right: Vec::new(), sample_rate },
+      fade: None,
+      pending: None,
+      retired: Vec::with_capacity(MAX_RETIRED),
+      diffusers_l: Diffusers::new(sample_rate),
+      diffusers_r: Diffusers::new(sample_rate),
+      convolver_l: Convolver::new(&[], FFT_SIZE),
+      convolver_r: Convolver::new(&[], FFT_SIZE),
+    };
+    hybrid.make_room();
+    hybrid.set_tail(tail);
+    // nothing to fade in from yet
+    hybrid.reset();
+    hybrid
+  }
+
+  pub fn set_balance(&mut self, balance: f32) {
+    self.balance.set_target(balance.clamp(0., 1.));
+  }
+
+  /// Crossfades the early reflections and the late tail over to a new pair together, so they
+  /// never hand over at different crossovers. Only one fade at a time, the latest pair waits its
+  /// turn and starts with the first block after the fade.
+  pub fn set_tail(&mut self, tail: HybridTail) {
+    if self.fade.is_some() {
+      if let Some(skipped) = self.pending.replace(tail) {
+        self.convolver_l.retire(skipped.impulse_response.left);
+        self.convolver_r.retire(skipped.impulse_response.right);
+        self.retire(skipped.early);
+      }
+      return;
+    }
+    // the convolvers crossfade over the same stretch, from the same sample
+    self.convolver_l.set_ir(tail.impulse_response.left);
+    self.convolver_r.set_ir(tail.impulse_response.right);
+    let outgoing = std::mem::replace(&mut self.early, tail.early);
+    self.fade = Some(EarlyFade { taps: outgoing, remaining: IR_FADE_SAMPLES });
+  }
+
+  /// IRs the convolvers are done with, one at a time, to be freed off the audio thread.
+  pub fn take_retired_ir(&mut self) -> Option<ConvolverIr> {
+    self.convolver_l.take_retired().or_else(|| self.convolver_r.take_retired())
+  }
+
+  /// Reflections done with, one set at a time, to be freed off the audio thread.
+  pub fn take_retired_taps(&mut self) -> Option<EarlyTaps> {
+    self.retired.pop()
+  }
+
+  fn retire(&mut self, taps: EarlyTaps) {
+    if self.retired.len() < MAX_RETIRED {
+      self.retired.push(taps);
+    }
+  }
+
+  // sizes the input lines for the longest crossover, and the convolvers' histories for the
+  // longest tail. allocates
+  fn make_room(&mut self) {
+    let max_delay = ((MAX_CROSSOVER_MS + CROSSFADE_MS) * 0.001 * self.sample_rate) as usize;
+    self.input_l.resize(max_delay);
+    self.input_r.resize(max_delay);
+    self.convolver_l.set_max_ir_len(max_ir_length(self.sample_rate));
+    self.convolver_r.set_max_ir_len(max_ir_length(self.sample_rate));
+  }
+}
+
+impl Engine for Hybrid {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.balance.set_sample_rate(sample_rate);
+    self.make_room();
+    self.early.set_sample_rate(sample_rate);
+    if let Some(fade) = &mut self.fade {
+      fade.taps.set_sample_rate(sample_rate);
+    }
+    self.diffusers_l = Diffusers::new(sample_rate);
+    self.diffusers_r = Diffusers::new(sample_rate);
+  }
+
+  fn reset(&mut self) {
+    self.input_l.clear();
+    self.input_r.clear();
+    self.diffusers_l.reset();
+    self.diffusers_r.reset();
+    if let Some(fade) = self.fade.take() {
+      self.retire(fade.taps);
+    }
+    if let Some(tail) = self.pending.take() {
+      self.set_tail(tail);
+      if let Some(fade) = self.fade.take() {
+        self.retire(fade.taps);
+      }
+    }
+    self.convolver_l.reset();
+    self.convolver_r.reset();
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    // a pair that waited out the last fade starts here, before anything runs. the convolvers run
+    // the whole block before the reflections do, so switching mid-block would start the tail a
+    // block after the reflections
+    if self.fade.is_none() {
+      if let Some(tail) = self.pending.take() {
+        self.set_tail(tail);
+      }
+    }
+    // the tail goes straight into the outputs, and the early section is mixed in on top
+    self.convolver_l.process(input_l, output_l);
+    self.convolver_r.process(input_r, output_r);
+    for i in 0..input_l.len() {
+      // both sides sit at unity with an even balance, and each only fades out past it
+      let balance = self.balance.next_value();
+      let early_level = (2. * (1. - balance)).min(1.);
+      let late_level = (2. * balance).min(1.) / WET_DIVISOR;
+
+      self.input_l.push(input_l[i]);
+      self.input_r.push(input_r[i]);
+      let mut early_l = tap_sum(&self.early.left, &self.input_l);
+      let mut early_r = tap_sum(&self.early.right, &self.input_r);
+      if let Some(fade) = &mut self.fade {
+        let old_gain = fade.remaining as f32 / IR_FADE_SAMPLES as f32;
+        early_l = early_l * (1. - old_gain) + tap_sum(&fade.taps.left, &self.input_l) * old_gain;
+        early_r = early_r * (1. - old_gain) + tap_sum(&fade.taps.right, &self.input_r) * old_gain;
+        fade.remaining -= 1;
+        if fade.remaining == 0 {
+          if let Some(fade) = self.fade.take() {
+            self.retire(fade.taps);
+          }
+        }
+      }
+      let early_l = self.diffusers_l.process(early_l);
+      let early_r = self.diffusers_r.process(early_r);
+      output_l[i] = early_l * early_level + output_l[i] * late_level;
+      output_r[i] = early_r * early_level + output_r[i] * late_level;
+    }
+  }
+}
@@ -0,0 +1,272 @@
+use std::f32::consts::PI;
+use rustfft::{FftPlanner, num_complex::Complex};
+use super::multiband::split_bands;
+use super::noise::Noise;
+
+/*
+IR decay reshaping
+  - the decay is measured by fitting a line to the IR's short term level in dB, over the stretch
+    where its energy decay curve (what's left to come, summed backwards) falls from -5 to -25dB.
+    that line gives the RT60, and the level it starts from
+  - the fit is followed along the IR until the level strays more than a few dB from it, which is
+    where the recording hit its noise floor or was faded out. that's where the real tail ends
+  - shorter: the whole IR is multiplied by an exponential that makes up the difference between
+    the old decay rate and the new one, and trimmed once it's inaudible
+  - longer: the same exponential (now rising) is only trusted up to shortly before the end of
+    the real tail, since past that it would just be pumping up noise or the fade. the end is only
+    spotted once the floor is a few dB over the line, so the handover is pulled back to where the
+    floor was still well under it. from there on the tail is
+    made up: noise coloured like the last stretch of the real tail, following the new decay line,
+    crossfaded in
+  - band-wise: the IR is split into low, mid and high bands by masking its spectrum with
+    crossovers an octave wide, which add back up to exactly the original. each band gets its own
+    decay reshaped as above, with its own measurement, and they're summed again. a faster high
+    band makes for a darker tail without touching the early sound
+*/
+
+pub const MIN_DECAY_SCALE: f32 = 0.25;
+pub const MAX_DECAY_SCALE: f32 = 4.;
+// longest IR a lengthened tail can grow to
+pub const MAX_IR_SECONDS: f32 = 8.;
+const BLOCK_MS: f32 = 50.;
+const FIT_START_DB: f32 = -5.;
+const FIT_END_DB: f32 = -25.;
+// how far the level can stray from the fit while still being the real tail
+const TAIL_TOLERANCE_DB: f32 = 6.;
+// how far back from the end of the real tail a lengthened decay stops trusting it, in dB of decay
+const TAIL_MARGIN_DB: f32 = 16.;
+// the made up tail runs until it is this far below where the IR started
+const TAIL_RANGE_DB: f32 = 70.;
+const CROSSFADE_MS: f32 = 50.;
+const SPECTRUM_FRAME: usize = 4096;
+const SPECTRUM_FRAMES: usize = 4;
+// anything after the point where this little energy is left is dropped
+const TRIM_ENERGY: f64 = 1e-9;
+
+/// Decay multipliers for the low, mid and high bands of an IR, and where the bands meet.
+#[derive(Clone, Copy)]
+pub struct BandDecay {
+  /// Each held to `MIN_DECAY_SCALE..=MAX_DECAY_SCALE`.
+  pub scales: [f32; 3],
+  /// Low/mid and mid/high crossovers, in Hz.
+  pub crossovers: [f32; 2],
+}
+
+impl Default for BandDecay {
+  fn default() -> Self {
+    Self {
+      scales: [1.; 3],
+      crossovers: [300., 4000.],
+    }
+  }
+}
+
+/// The straight line decay an IR was measured to follow.
+pub struct DecayFit {
+  pub rt60: f32,
+  /// Short term level at the very start of the line, in dB.
+  pub start_db: f32,
+  /// Where the IR stops following the line, in samples.
+  pub tail_end: usize,
+}
+
+impl DecayFit {
+  // the line's level at a time, in dB
+  fn level_db(&self, seconds: f32) -> f32 {
+    self.start_db - 60. * seconds / self.rt60
+  }
+}
+
+/// Measures the decay of an IR. `None` if it doesn't decay far enough to tell.
+pub fn measure_decay(impulse_response: &[f32], sample_rate: f32) -> Option<DecayFit> {
+  let block = ((BLOCK_MS * 0.001 * sample_rate) as usize).max(1);
+  // short term level of each block, and the energy decay curve at its start
+  let energies: Vec<f64> = impulse_response
+    .chunks(block)
+    .map(|chunk| chunk.iter().map(|x| (*x as f64).powi(2)).sum::<f64>() / chunk.len() as f64)
+    .collect();
+  let total: f64 = energies.iter().sum();
+  if total <= 0. {
+    return None;
+  }
+  let mut remaining = total;
+  let mut points = Vec::new();
+  let mut last_fitted = 0;
+  for (i, energy) in energies.iter().enumerate() {
+    let decay_db = 10. * (remaining / total).log10() as f32;
+    remaining -= energy;
+    if decay_db > FIT_START_DB || *energy <= 0. {
+      continue;
+    }
+    if decay_db < FIT_END_DB {
+      break;
+    }
+    // time at the block centre
+    points.push(((i as f32 + 0.5) * block as f32 / sample_rate, 10. * energy.log10() as f32));
+    last_fitted = i;
+  }
+  if points.len() < 2 {
+    return None;
+  }
+
+  // least squares line through the levels
+  let count = points.len() as f32;
+  let mean_t = points.iter().map(|(t, _)| t).sum::<f32>() / count;
+  let mean_db = points.iter().map(|(_, db)| db).sum::<f32>() / count;
+  let covariance: f32 = points.iter().map(|(t, db)| (t - mean_t) * (db - mean_db)).sum();
+  let variance: f32 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+  let slope = covariance / variance;
+  if slope >= 0. {
+    return None;
+  }
+  let mut fit = DecayFit {
+    rt60: -60. / slope,
+    start_db: mean_db - slope * mean_t,
+    tail_end: impulse_response.len(),
+  };
+
+  for (i, energy) in energies.iter().enumerate().skip(last_fitted + 1) {
+    let level_db = 10. * energy.max(1e-30).log10() as f32;
+    let line_db = fit.level_db((i as f32 + 0.5) * block as f32 / sample_rate);
+    if (level_db - line_db).abs() > TAIL_TOLERANCE_DB {
+      fit.tail_end = i * block;
+      break;
+    }
+  }
+  Some(fit)
+}
+
+/// Stretches or shrinks the decay of an IR by `scale`, 1 leaves it untouched. Heavy, so meant for
+/// use off the audio thread.
+pub fn reshape_decay(impulse_response: &[f32], scale: f32, sample_rate: f32) -> Vec<f32> {
+  let scale = scale.clamp(MIN_DECAY_SCALE, MAX_DECAY_SCALE);
+  let fit = match measure_decay(impulse_response, sample_rate) {
+    Some(fit) if scale != 1. => fit,
+    _ => return impulse_response.to_vec(),
+  };
+  // what takes the old line onto the new one, in dB per second
+  let correction = 60. / fit.rt60 - 60. / (fit.rt60 * scale);
+  let gain = |i: usize| 10f32.powf(correction * i as f32 / sample_rate / 20.);
+
+  let mut output: Vec<f32> = if scale < 1. {
+    impulse_response.iter().enumerate().map(|(i, x)| x * gain(i)).collect()
+  } else {
+    extend_tail(impulse_response, &fit, scale, sample_rate, gain)
+  };
+  trim(&mut output);
+  output
+}
+
+/// Reshapes the decay of each band of an IR separately. Heavy, so meant for use off the audio
+/// thread.
+pub fn reshape_band_decay(impulse_response: &[f32], bands: &BandDecay, sample_rate: f32) -> Vec<f32> {
+  let [low, mid, high] = bands.scales;
+  if low == mid && mid == high {
+    return reshape_decay(impulse_response, mid, sample_rate);
+  }
+  let mut output: Vec<f32> = Vec::new();
+  for (band, scale) in split_bands(impulse_response, &bands.crossovers, sample_rate).iter().zip(bands.scales) {
+    let reshaped = reshape_decay(band, scale, sample_rate);
+    if reshaped.len() > output.len() {
+      output.resize(reshaped.len(), 0.);
+    }
+    for (out, sample) in output.iter_mut().zip(reshaped) {
+      *out += sample;
+    }
+  }
+  trim(&mut output);
+  output
+}
+
+fn extend_tail<G: Fn(usize) -> f32>(impulse_response: &[f32], fit: &DecayFit, scale: f32, sample_rate: f32, gain: G) -> Vec<f32> {
+  // a floor that's TAIL_TOLERANCE_DB over the line at the end is still 10dB under it here
+  let tail_end = fit.tail_end.saturating_sub((TAIL_MARGIN_DB / 60. * fit.rt60 * sample_rate) as usize);
+  let crossfade = ((CROSSFADE_MS * 0.001 * sample_rate) as usize).min(tail_end);
+  let fade_start = tail_end - crossfade;
+  let new_rt60 = fit.rt60 * scale;
+  let length = (((TAIL_RANGE_DB / 60. * new_rt60) * sample_rate) as usize)
+    .min((MAX_IR_SECONDS * sample_rate) as usize)
+    .max(tail_end);
+
+  let mut output = vec![0.; length];
+  for (i, sample) in output.iter_mut().enumerate().take(tail_end) {
+    let fade_out = if i < fade_start { 1. } else { (0.5 * PI * (i - fade_start) as f32 / crossfade as f32).cos() };
+    *sample = impulse_response[i] * gain(i) * fade_out;
+  }
+
+  let frame_start = fade_start.saturating_sub(SPECTRUM_FRAME * (SPECTRUM_FRAMES + 1) / 2);
+  let power = power_spectrum(&impulse_response[frame_start..fade_start.max(frame_start)]);
+  let noise = coloured_noise(&power, length - fade_start);
+  for (i, sample) in noise.iter().enumerate() {
+    let position = fade_start + i;
+    let seconds = position as f32 / sample_rate;
+    let level = 10f32.powf((fit.start_db - 60. * seconds / new_rt60) / 20.);
+    // the two are uncorrelated, so a sine and cosine pair keeps the power even through the fade
+    let fade_in = if i < crossfade { (0.5 * PI * i as f32 / crossfade as f32).sin() } else { 1. };
+    output[position] += sample * level * fade_in;
+  }
+  output
+}
+
+// averaged power spectrum over a few hann windowed frames, half overlapped
+fn power_spectrum(signal: &[f32]) -> Vec<f32> {
+  let fft = FftPlanner::<f32>::new().plan_fft_forward(SPECTRUM_FRAME);
+  let window = |i: usize| 0.5 - 0.5 * (2. * PI * i as f32 / SPECTRUM_FRAME as f32).cos();
+  let mut power = vec![0.; SPECTRUM_FRAME / 2 + 1];
+  let mut frame = vec![Complex { re: 0., im: 0. }; SPECTRUM_FRAME];
+  let mut start = 0;
+  loop {
+    for (i, bin) in frame.iter_mut().enumerate() {
+      *bin = Complex { re: signal.get(start + i).copied().unwrap_or(0.) * window(i), im: 0. };
+    }
+    fft.process(&mut frame);
+    for (total, bin) in power.iter_mut().zip(frame.iter()) {
+      *total += bin.norm_sqr();
+    }
+    start += SPECTRUM_FRAME / 2;
+    if start + SPECTRUM_FRAME > signal.len() {
+      break;
+    }
+  }
+  power
+}
+
+// `length` samples of unit rms noise with the given power spectrum, made in one big transform
+fn coloured_noise(power: &[f32], length: usize) -> Vec<f32> {
+  let size = length.next_power_of_two().max(SPECTRUM_FRAME);
+  let mut noise = Noise::new(7);
+  let mut spectrum = vec![Complex { re: 0., im: 0. }; size];
+  let resolution = (power.len() - 1) as f32 / (size / 2) as f32;
+  for k in 1..size / 2 {
+    let position = k as f32 * resolution;
+    let below = position as usize;
+    let above = (below + 1).min(power.len() - 1);
+    let magnitude = (power[below] + (power[above] - power[below]) * (position - below as f32)).sqrt();
+    let bin = Complex::from_polar(magnitude, 2. * PI * noise.next_unipolar());
+    spectrum[k] = bin;
+    spectrum[size - k] = bin.conj();
+  }
+  FftPlanner::<f32>::new().plan_fft_inverse(size).process(&mut spectrum);
+
+  let mut output: Vec<f32> = spectrum.iter().take(length).map(|bin| bin.re).collect();
+  let rms = (output.iter().map(|x| x * x).sum::<f32>() / length.max(1) as f32).sqrt();
+  if rms > 0. {
+    output.iter_mut().for_each(|sample| *sample /= rms);
+  }
+  output
+}
+
+// drops the stretch at the end that's too quiet to matter
+fn trim(signal: &mut Vec<f32>) {
+  let total: f64 = signal.iter().map(|x| (*x as f64).powi(2)).sum();
+  let mut remaining = 0.;
+  let mut end = signal.len();
+  while end > 0 {
+    remaining += (signal[end - 1] as f64).powi(2);
+    if remaining > total * TRIM_ENERGY {
+      break;
+    }
+    end -= 1;
+  }
+  signal.truncate(end);
+}
@@ -0,0 +1,99 @@
+use std::f32::consts::PI;
+
+use super::ms_to_samples;
+
+/*
+IR trim
+  - start skips into the IR, dropping the direct sound and the first bounces, length keeps only so
+    much of what's left, for a shorter spring without a new recording
+  - the end is faded out over a window so the cut doesn't click: linear, cosine (the gentlest into
+    silence) or exponential, which drops 60 dB over the window like a natural decay would
+  - a start past the beginning gets a couple of ms of fade in too, for the same reason
+  - the result is exactly as long as asked (or the IR, if that's shorter), so the convolvers carry
+    no silent partitions and their cost falls right along with the length
+*/
+
+// the fade in over a trimmed start
+const START_FADE_MS: f32 = 2.;
+// how far the exponential fade drops before it's pulled down onto silence, ln(1000) for 60 dB
+const EXPONENTIAL_DEPTH: f32 = 6.9078;
+
+/// Must line up with `plugin_state::IR_FADE_OPTIONS`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FadeShape {
+  Linear,
+  Cosine,
+  Exponential,
+}
+
+impl FadeShape {
+  pub fn from_index(index: usize) -> Self {
+    match index {
+      0 => FadeShape::Linear,
+      1 => FadeShape::Cosine,
+      _ => FadeShape::Exponential,
+    }
+  }
+
+  // gain at `position` through the fade out, 0..1
+  fn gain(self, position: f32) -> f32 {
+    match self {
+      FadeShape::Linear => 1. - position,
+      FadeShape::Cosine => 0.5 + 0.5 * (PI * position).cos(),
+      FadeShape::Exponential => {
+        let floor = (-EXPONENTIAL_DEPTH).exp();
+        ((-EXPONENTIAL_DEPTH * position).exp() - floor) / (1. - floor)
+      }
+    }
+  }
+}
+
+/// Which part of the IR is kept, all in ms.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Trim {
+  pub start: f32,
+  pub length: f32,
+  pub fade: f32,
+  pub fade_shape: FadeShape,
+}
+
+impl Default for Trim {
+  // keeps the whole of any IR
+  fn default() -> Self {
+    Self {
+      start: 0.,
+      length: f32::INFINITY,
+      fade: 50.,
+      fade_shape: FadeShape::Cosine,
+    }
+  }
+}
+
+/// Cuts `trim` out of `impulse_response`. An IR that already ends before the trimmed length is
+/// left to end on its own, with no fade.
+pub fn trim_impulse_response(impulse_response: &[f32], trim: &Trim, sample_rate: f32) -> Vec<f32> {
+  let start = ms_to_samples(trim.start, sample_rate).min(impulse_response.len());
+  let available = impulse_response.len() - start;
+  let length = if trim.length.is_finite() {
+    ms_to_samples(trim.length, sample_rate).clamp(1, available.max(1))
+  } else {
+    available
+  };
+  let mut trimmed = impulse_response[start..(start + length).min(impulse_response.len())].to_vec();
+
+  if start > 0 {
+    let fade_in = ms_to_samples(START_FADE_MS, sample_rate).min(trimmed.len() / 2);
+    for (i, sample) in trimmed.iter_mut().take(fade_in).enumerate() {
+      *sample *= i as f32 / fade_in as f32;
+    }
+  }
+  if length < available {
+    let fade = ms_to_samples(trim.fade, sample_rate).clamp(1, trimmed.len());
+    let fade_start = trimmed.len() - fade;
+    for (i, sample) in trimmed[fade_start..].iter_mut().enumerate() {
+      // the last sample lands on silence
+      *sample *= trim.fade_shape.gain((i + 1) as f32 / fade as f32);
+    }
+  }
+  trimmed
+}
@@ -0,0 +1,146 @@
+use std::f32::consts::TAU;
+use super::noise::Noise;
+
+/*
+Kick
+  - the knock a spring tank makes when the amp gets bumped, fired by MIDI notes
+  - every note starts a short percussive burst, scaled by velocity, that goes into the tank along
+    with the input. overlapping notes each get their own burst, up to a handful
+  - click: a sharp tick, thump: a low sine dropping in pitch, noise: a burst of crash
+*/
+
+pub const MAX_BURSTS: usize = 8;
+const BURST_SECONDS: f32 = 0.3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum KickType {
+  Click,
+  Thump,
+  Noise,
+}
+
+struct Burst {
+  kind: KickType,
+  age: usize, // samples since the note
+  gain: f32,
+  phase: f32,
+}
+
+impl Burst {
+  fn next_sample(&mut self, sample_rate: f32, noise: &mut Noise) -> f32 {
+    let time = self.age as f32 / sample_rate;
+    self.age += 1;
+    let shape = match self.kind {
+      KickType::Click => (-time / 0.0003).exp(),
+      KickType::Thump => {
+        // sweeps down from 120 to 40Hz
+        let frequency = 40. + 80. * (-time / 0.03).exp();
+        self.phase = (self.phase + frequency / sample_rate).fract();
+        (TAU * self.phase).sin() * (-time / 0.04).exp()
+      }
+      KickType::Noise => (2. * noise.next_unipolar() - 1.) * (-time / 0.06).exp(),
+    };
+    shape * self.gain
+  }
+}
+
+/// MIDI triggered excitation bursts.
+pub struct Kick {
+  sample_rate: f32,
+  kind: KickType,
+  level: f32,
+  dry: bool,
+  noise: Noise,
+  // notes for the next block, as (offset into the block, velocity 0..1)
+  pending: Vec<(usize, f32)>,
+  bursts: Vec<Burst>,
+  output: Vec<f32>,
+}
+
+impl Kick {
+  pub fn new(sample_rate: f32) -> Self {
+    Self {
+      sample_rate,
+      kind: KickType::Thump,
+      level: 1.,
+      dry: false,
+      noise: Noise::new(11),
+      pending: Vec::with_capacity(MAX_BURSTS),
+      bursts: Vec::with_capacity(MAX_BURSTS),
+      output: Vec::new(),
+    }
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+  }
+
+  pub fn set_type(&mut self, kind: KickType) {
+    self.kind = kind;
+  }
+
+  pub fn set_level(&mut self, level: f32) {
+    self.level = level;
+  }
+
+  pub fn set_dry(&mut self, dry: bool) {
+    self.dry = dry;
+  }
+
+  // whether the bursts are also heard without the reverb
+  pub fn dry(&self) -> bool {
+    self.dry
+  }
+
+  /// Queues a burst for the next block. Past `MAX_BURSTS` notes in a block, the earliest are
+  /// dropped, since the later ones would have cut them off anyway.
+  pub fn trigger(&mut self, offset: usize, velocity: f32) {
+    if self.pending.len() < MAX_BURSTS {
+      self.pending.push((offset, velocity));
+      return;
+    }
+    // full, so never grow past the capacity reserved up front
+    if let Some(earliest) = self.pending.iter_mut().min_by_key(|(pending, _)| *pending) {
+      if offset >= earliest.0 {
+        *earliest = (offset, velocity);
+      }
+    }
+  }
+
+  /// Renders the next block of bursts. Silent, and free, when nothing is playing.
+  pub fn process(&mut self, len: usize) -> Option<&[f32]> {
+    if self.pending.is_empty() && self.bursts.is_empty() {
+      return None;
+    }
+    for (offset, _) in self.pending.iter_mut() {
+      // notes the host placed past the end of the block still fire
+      *offset = (*offset).min(len.saturating_sub(1));
+    }
+    self.pending.sort_by_key(|(offset, _)| *offset);
+    self.output.clear();
+    self.output.resize(len, 0.);
+    let burst_length = (BURST_SECONDS * self.sample_rate) as usize;
+    let mut pending = self.pending.iter().peekable();
+    for i in 0..len {
+      while let Some((_, velocity)) = pending.next_if(|(offset, _)| *offset <= i) {
+        if self.bursts.len() == MAX_BURSTS {
+          // the oldest one has mostly died away anyway
+          self.bursts.remove(0);
+        }
+        self.bursts.push(Burst {
+          kind: self.kind,
+          age: 0,
+          // squared, so soft notes are properly soft
+          gain: velocity * velocity * self.level,
+          phase: 0.,
+        });
+      }
+      for burst in self.bursts.iter_mut() {
+        self.output[i] += burst.next_sample(self.sample_rate, &mut self.noise);
+      }
+      self.bursts.retain(|burst| burst.age < burst_length);
+    }
+    self.pending.clear();
+    Some(&self.output)
+  }
+}
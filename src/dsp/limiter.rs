@@ -0,0 +1,188 @@
+use std::collections::VecDeque;
+
+use super::delay_line::DelayLine;
+use super::oversampling::Oversampler;
+use super::{db_to_gain, ms_to_samples};
+
+/*
+Output limiter (lookahead brickwall, true peak)
+  - the last thing before the outputs, there so a bad IR or runaway feedback can't reach the
+    monitors at full scale
+  - detection runs 4x oversampled, so peaks that fall between samples (and would come out of a
+    DAC or a lossy encoder over the ceiling) are caught too
+  - each detected peak asks for the gain that would put it on the ceiling. the lowest ask over
+    the lookahead window is held, eased back up at the release rate, then smoothed with a moving
+    average as long as the lookahead
+  - the audio is delayed so every peak arrives after the average has fully settled onto it, so
+    nothing gets past the ceiling, and the gain never jumps, so the limiting doesn't click
+  - stereo linked, the louder side sets the gain for both
+*/
+
+const LOOKAHEAD_MS: f32 = 1.5;
+const OVERSAMPLING: usize = 4;
+// a longer filter than the drive's, so the detector stays flat most of the way up
+const DETECTOR_TAPS: usize = 32;
+// the oversampled detector lags the input by about half its filter. the delay and the hold
+// window are padded by a bit more than that, so peaks are covered whichever way they land
+const DETECTOR_SLACK: usize = DETECTOR_TAPS / 2 + 2;
+
+/// How far the limiter holds back the output while it's on.
+pub fn limiter_latency(sample_rate: f32) -> usize {
+  ms_to_samples(LOOKAHEAD_MS, sample_rate).max(1) + DETECTOR_SLACK
+}
+
+/// Stereo linked lookahead brickwall limiter with true peak detection.
+pub struct Limiter {
+  enabled: bool,
+  sample_rate: f32,
+  ceiling: f32, // linear
+  release_coef: f32,
+  release_ms: f32,
+  // derived from the lookahead
+  average_length: usize,
+  hold_length: usize,
+  delay: usize,
+  // running state
+  detector_l: Oversampler,
+  detector_r: Oversampler,
+  // lowest gain asks still in the hold window, as (sample count, gain), increasing
+  hold: VecDeque<(usize, f32)>,
+  count: usize,
+  released: f32,
+  average: DelayLine,
+  average_sum: f64,
+  delay_l: DelayLine,
+  delay_r: DelayLine,
+  // the deepest reduction in the last block, in dB
+  reduction_db: f32,
+}
+
+impl Limiter {
+  pub fn new(sample_rate: f32) -> Self {
+    let mut limiter = Self {
+      enabled: false,
+      sample_rate,
+      ceiling: db_to_gain(-0.3),
+      release_coef: 0.,
+      release_ms: 100.,
+      average_length: 1,
+      hold_length: 1,
+      delay: 0,
+      detector_l: Oversampler::with_taps(OVERSAMPLING, DETECTOR_TAPS),
+      detector_r: Oversampler::with_taps(OVERSAMPLING, DETECTOR_TAPS),
+      hold: VecDeque::new(),
+      count: 0,
+      released: 1.,
+      average: DelayLine::new(0),
+      average_sum: 0.,
+      delay_l: DelayLine::new(0),
+      delay_r: DelayLine::new(0),
+      reduction_db: 0.,
+    };
+    limiter.set_sample_rate(sample_rate);
+    limiter
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    let lookahead = ms_to_samples(LOOKAHEAD_MS, sample_rate).max(1);
+    self.average_length = lookahead;
+    self.hold_length = lookahead + 2 * DETECTOR_SLACK;
+    self.delay = limiter_latency(sample_rate);
+    self.hold = VecDeque::with_capacity(self.hold_length + 1);
+    self.average.resize(self.average_length);
+    self.delay_l.resize(self.delay);
+    self.delay_r.resize(self.delay);
+    self.update_release();
+    self.reset();
+  }
+
+  pub fn set_enabled(&mut self, enabled: bool) {
+    if enabled && !self.enabled {
+      self.reset();
+    }
+    self.enabled = enabled;
+    if !enabled {
+      self.reduction_db = 0.;
+    }
+  }
+
+  pub fn set_ceiling_db(&mut self, ceiling_db: f32) {
+    self.ceiling = db_to_gain(ceiling_db);
+  }
+
+  pub fn set_release_ms(&mut self, release_ms: f32) {
+    self.release_ms = release_ms;
+    self.update_release();
+  }
+
+  fn update_release(&mut self) {
+    self.release_coef = (-1. / (self.release_ms * 0.001 * self.sample_rate).max(1.)).exp();
+  }
+
+  pub fn reset(&mut self) {
+    // in place, since switching the limiter back on resets it from the audio thread
+    self.detector_l.reset();
+    self.detector_r.reset();
+    self.hold.clear();
+    self.count = 0;
+    self.released = 1.;
+    // the average starts out at unity
+    self.average.clear();
+    for _ in 0..self.average_length + 1 {
+      self.average.push(1.);
+    }
+    self.average_sum = self.average_length as f64;
+    self.delay_l.clear();
+    self.delay_r.clear();
+  }
+
+  /// The deepest gain reduction over the last block, in dB. 0 when the limiter is idle.
+  pub fn reduction_db(&self) -> f32 {
+    self.reduction_db
+  }
+
+  // the largest of the oversampled values behind one more input sample
+  fn true_peak(detector: &mut Oversampler, input: f32) -> f32 {
+    let mut peak = 0f32;
+    detector.upsample(input, |sample| peak = peak.max(sample.abs()));
+    peak
+  }
+
+  // limits the buffers in place
+  pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) {
+    if !self.enabled {
+      return;
+    }
+
+    let mut lowest = 1f32;
+    for i in 0..left.len() {
+      let peak = Self::true_peak(&mut self.detector_l, left[i])
+        .max(Self::true_peak(&mut self.detector_r, right[i]));
+      let ask = if peak > self.ceiling { self.ceiling / peak } else { 1. };
+
+      // lowest ask in the hold window
+      while matches!(self.hold.back(), Some((_, gain)) if *gain >= ask) {
+        self.hold.pop_back();
+      }
+      self.hold.push_back((self.count, ask));
+      while matches!(self.hold.front(), Some((count, _)) if count + self.hold_length <= self.count) {
+        self.hold.pop_front();
+      }
+      self.count += 1;
+      let held = self.hold.front().map_or(1., |(_, gain)| *gain);
+
+      // drops straight down, recovers at the release rate
+      self.released = held.min(1. - (1. - self.released) * self.release_coef);
+
+      self.average.push(self.released);
+      self.average_sum += self.released as f64 - self.average.read(self.average_length) as f64;
+      let gain = (self.average_sum / self.average_length as f64).min(1.) as f32;
+      lowest = lowest.min(gain);
+
+      left[i] = self.delay_l.process(left[i], self.delay) * gain;
+      right[i] = self.delay_r.process(right[i], self.delay) * gain;
+    }
+    self.reduction_db = 20. * lowest.max(1e-6).log10();
+  }
+}
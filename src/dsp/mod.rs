@@ -1,29 +1,655 @@
-use std::sync::mpsc::Receiver;
+use std::sync::{mpsc::{Receiver, SyncSender}, Arc};
 use vst::buffer::AudioBuffer;
-use crate::plugin_state::StateUpdate;
+use crate::ir_worker::Retired;
+use crate::plugin_state::{Mapping, Meter, Meters, Parameter, StateUpdate};
 
 pub mod convolution;
-use convolution::Convolver;
+
+pub mod decorrelate;
+
+pub mod delay_line;
+
+pub mod drive;
+use drive::{Drive, DriveType};
+
+pub mod dynamic;
+use dynamic::{Dynamic, DynamicMode};
+
+pub mod engine;
+use engine::{Engine, EngineKind};
+
+pub mod envelope;
+
+pub mod fdn;
+use fdn::{Fdn, FeedbackMatrix};
+
+pub mod filter;
+
+pub mod freeze;
+
+pub mod fractional_delay;
+
+pub mod gate;
+use gate::{gate_latency, Gate, GateKey};
+
+pub mod hybrid;
+use hybrid::Hybrid;
+
+pub mod ir_decay;
+
+pub mod ir_trim;
+
+pub mod kick;
+use kick::{Kick, KickType, MAX_BURSTS};
+
+pub mod limiter;
+use limiter::Limiter;
+
+pub mod multiband;
+use multiband::{crossover_latency, linear_phase_latency, CrossoverKind};
+
+pub mod noise;
+
+pub mod oversampling;
+use oversampling::oversampling_latency;
+
+pub mod physical_spring;
+use physical_spring::PhysicalSpring;
+
+pub mod pitch_shift;
+
+pub mod plate;
+use plate::Plate;
+
+pub mod pre_delay;
+use pre_delay::PreDelay;
+
+pub mod reverse;
+use reverse::{reverse_latency, true_reverse_latency, Lookahead, ReverseMode};
+
+pub mod shimmer;
+use shimmer::Shimmer;
+
+pub mod smoother;
+use smoother::{Ramp, Smoother};
+
+pub mod spring_model;
+use spring_model::SpringModel;
 
 pub mod spring_impulse_response;
-use spring_impulse_response::SPRING_IMPULSE_RESPONSE;
+
+pub mod spring_ir;
+use spring_ir::{FreezeInput, SpringIr};
+
+pub mod tempo;
+use tempo::{note_ms, SyncedRate, SyncedTime, DEFAULT_TEMPO};
+
+pub mod velvet;
+use velvet::Velvet;
+
+pub mod wobble;
+use wobble::Wobble;
+
+/// What the second stereo input does to the signal the engines hear.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SidechainMode {
+  /// The sidechain is ignored.
+  Off,
+  /// Only the sidechain is reverberated.
+  Replace,
+  /// The sidechain is reverberated along with the main input.
+  Add,
+}
 
 /// Entry point for audio processing algorithms for the plugin.
-pub(super) struct PluginDsp {
-  convolver_l: Convolver,
-  convolver_r: Convolver,
+pub struct PluginDsp {
+  engine: EngineKind,
+  // the engine being faded out after a switch, and how many samples of fade are left
+  engine_fade: Option<(EngineKind, usize)>,
+  engine_fade_samples: usize,
+  spring_ir: SpringIr,
+  spring_model: SpringModel,
+  physical_spring: PhysicalSpring,
+  fdn: Fdn,
+  plate: Plate,
+  hybrid: Hybrid,
+  velvet: Velvet,
+  drive: Drive,
+  pre_delay: PreDelay,
+  shimmer: Shimmer,
+  wobble: Wobble,
+  kick: Kick,
+  gate: Gate,
+  dynamic: Dynamic,
+  limiter: Limiter,
+  sidechain: SidechainMode,
+  reverse: ReverseMode,
+  reverse_length_ms: f32,
+  // only for the latency, the convolvers take up the layout along with their IRs
+  band_count: usize,
+  crossover: CrossoverKind,
+  // holds back everything but the spring convolvers, in true reverse and with the linear phase
+  // crossover. channels are the excitation's left and right
+  lookahead: Lookahead,
+  // holds the gate and dynamic key back as far as the excitation, the lookahead plus the drive's
+  // oversampling filters, so they open and duck in step with the tail
+  key_hold: Lookahead,
+  // holds the dry kick back by all of the above, plus the gate's lookahead it skips
+  kick_hold: Lookahead,
+  // holds the spring convolvers' excitation back by however much the reverse length is short of
+  // the longest, so the true reverse latency stays put
+  reverse_pad: Lookahead,
+  // the held back copies, the excitation's left and right, the key's, then the dry kick, kept to
+  // save allocating them every block
+  held: Vec<Vec<f32>>,
+  // the excitation, the current engine's output and the faded out engine's, left then right,
+  // kept for the same reason
+  scratch: [Vec<f32>; 6],
+  sample_rate: f32,
+  tempo: f32,
+  pre_delay_time: SyncedTime,
+  gate_hold_time: SyncedTime,
+  wobble_rate: SyncedRate,
+  // the room's low, mid and high RT60s as set, in seconds. locked to a note, the mid takes its
+  // length and the others keep their ratio to it
+  room_rt60: [f32; 3],
+  room_decay_division: usize,
+  room_mod_rate: SyncedRate,
+  // the plate's decay as set, 0..1. locked to a note, the note length is its RT60 instead
+  plate_decay: f32,
+  plate_decay_division: usize,
+  plate_mod_rate: SyncedRate,
+  velvet_decay: SyncedTime,
+  // control rate glides, indexed by `Parameter::index`, for the parameters that have one
+  smoothers: Vec<Option<Smoother>>,
+  // samples into the current control block
+  control_phase: usize,
+  // kicks for the next block, as (offset into the block, velocity), in the order they came in
+  kicks: Vec<(usize, f32)>,
   messages_from_params: Receiver<StateUpdate>,
+  // back to the IR worker, for what the engines swap out. none until the worker sends it
+  return_path: Option<SyncSender<Retired>>,
+  meters: Arc<Meters>,
 }
 
-const FFT_SIZE: usize = 1024;
+const DEFAULT_SAMPLE_RATE: f32 = 44100.;
+const ENGINE_FADE_MS: f32 = 30.;
+const CONTROL_RAMP_MS: f32 = 50.;
+// how often the control rate glides move on, in samples. counted from the start of playback, not
+// from each host block, so they land on the same samples whatever size blocks the host sends
+const CONTROL_BLOCK: usize = 64;
+// the main pair, then the sidechain pair
+const MAX_INPUTS: usize = 4;
+// notes kept for one block. no more than this many bursts ring at once anyway
+const MAX_KICKS: usize = MAX_BURSTS;
+
+/// How a parameter glides to a new value when it's applied at the control rate. `None` jumps
+/// straight there: switches and choices, the IR's shape and the velvet tail's, which are rebuilt
+/// whole and crossfaded rather than stepped through, and the few that glide per sample inside the
+/// block they feed.
+fn control_ramp(param: Parameter) -> Option<Ramp> {
+  match param {
+    Parameter::HybridBalance
+    | Parameter::WobbleDepth
+    | Parameter::DriveAmount
+    | Parameter::ShimmerFeedback
+    | Parameter::PreDelay => None,
+    // the linkwitz-riley crossovers glide here, the linear phase ones are cut into the IRs
+    Parameter::BandCross1 | Parameter::BandCross2 | Parameter::BandCross3 => Some(Ramp::Multiplicative),
+    _ if param.shapes_ir() || param.meter().is_some() => None,
+    _ => match param.info().mapping {
+      Mapping::Linear { .. } => Some(Ramp::Linear),
+      // times and frequencies, which should sweep evenly through the octaves
+      Mapping::Exponential { .. } => Some(Ramp::Multiplicative),
+      Mapping::Toggle | Mapping::Choice(_) => None,
+    },
+  }
+}
 
 impl PluginDsp {
-  pub fn new(incoming_messages: Receiver<StateUpdate>) -> Self {
-    let impulse_response = &SPRING_IMPULSE_RESPONSE;
-    Self {
-      convolver_l: Convolver::new(&impulse_response, FFT_SIZE),
-      convolver_r: Convolver::new(&impulse_response, FFT_SIZE),
-      messages_from_params: incoming_messages
+  pub fn new(incoming_messages: Receiver<StateUpdate>, meters: Arc<Meters>) -> Self {
+    let mut dsp = Self {
+      engine: EngineKind::SpringIr,
+      engine_fade: None,
+      engine_fade_samples: ms_to_samples(ENGINE_FADE_MS, DEFAULT_SAMPLE_RATE),
+      spring_ir: SpringIr::new(DEFAULT_SAMPLE_RATE),
+      spring_model: SpringModel::new(DEFAULT_SAMPLE_RATE),
+      physical_spring: PhysicalSpring::new(DEFAULT_SAMPLE_RATE),
+      fdn: Fdn::new(DEFAULT_SAMPLE_RATE),
+      plate: Plate::new(DEFAULT_SAMPLE_RATE),
+      hybrid: Hybrid::new(DEFAULT_SAMPLE_RATE),
+      velvet: Velvet::new(DEFAULT_SAMPLE_RATE),
+      drive: Drive::new(),
+      pre_delay: PreDelay::new(DEFAULT_SAMPLE_RATE),
+      shimmer: Shimmer::new(DEFAULT_SAMPLE_RATE),
+      wobble: Wobble::new(DEFAULT_SAMPLE_RATE),
+      kick: Kick::new(DEFAULT_SAMPLE_RATE),
+      gate: Gate::new(DEFAULT_SAMPLE_RATE),
+      dynamic: Dynamic::new(DEFAULT_SAMPLE_RATE),
+      limiter: Limiter::new(DEFAULT_SAMPLE_RATE),
+      sidechain: SidechainMode::Off,
+      reverse: ReverseMode::Off,
+      reverse_length_ms: Parameter::ReverseLength.info().default,
+      band_count: 1,
+      crossover: CrossoverKind::LinkwitzRiley,
+      lookahead: Lookahead::new(2),
+      key_hold: Lookahead::new(2),
+      kick_hold: Lookahead::new(1),
+      reverse_pad: Lookahead::new(2),
+      held: (0..5).map(|_| Vec::with_capacity(CONTROL_BLOCK)).collect(),
+      scratch: std::array::from_fn(|_| Vec::with_capacity(CONTROL_BLOCK)),
+      sample_rate: DEFAULT_SAMPLE_RATE,
+      tempo: DEFAULT_TEMPO,
+      pre_delay_time: SyncedTime::new(0.),
+      gate_hold_time: SyncedTime::new(0.),
+      wobble_rate: SyncedRate::new(0.),
+      room_rt60: [0.; 3],
+      room_decay_division: 0,
+      room_mod_rate: SyncedRate::new(0.),
+      plate_decay: 0.,
+      plate_decay_division: 0,
+      plate_mod_rate: SyncedRate::new(0.),
+      velvet_decay: SyncedTime::new(0.),
+      smoothers: Parameter::ALL
+        .iter()
+        .map(|param| {
+          control_ramp(*param).map(|ramp| {
+            let mut smoother = Smoother::new(ramp, param.info().default, DEFAULT_SAMPLE_RATE);
+            smoother.set_ramp_ms(CONTROL_RAMP_MS);
+            smoother
+          })
+        })
+        .collect(),
+      control_phase: 0,
+      kicks: Vec::with_capacity(MAX_KICKS),
+      messages_from_params: incoming_messages,
+      return_path: None,
+      meters,
+    };
+    dsp.make_lookahead_room();
+    for param in Parameter::ALL {
+      dsp.set_parameter(*param, param.info().default);
+    }
+    dsp
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.make_lookahead_room();
+    self.engine_fade_samples = ms_to_samples(ENGINE_FADE_MS, sample_rate);
+    self.spring_ir.set_sample_rate(sample_rate);
+    self.spring_model.set_sample_rate(sample_rate);
+    self.physical_spring.set_sample_rate(sample_rate);
+    self.fdn.set_sample_rate(sample_rate);
+    self.plate.set_sample_rate(sample_rate);
+    self.hybrid.set_sample_rate(sample_rate);
+    self.velvet.set_sample_rate(sample_rate);
+    self.pre_delay.set_sample_rate(sample_rate);
+    self.shimmer.set_sample_rate(sample_rate);
+    self.wobble.set_sample_rate(sample_rate);
+    self.kick.set_sample_rate(sample_rate);
+    self.gate.set_sample_rate(sample_rate);
+    self.dynamic.set_sample_rate(sample_rate);
+    self.limiter.set_sample_rate(sample_rate);
+    self.drive.set_sample_rate(sample_rate);
+    for smoother in self.smoothers.iter_mut().flatten() {
+      smoother.set_sample_rate(sample_rate);
+    }
+    // after the gate, whose lookahead is counted in
+    self.update_lookahead();
+  }
+
+  /// Follows the host's tempo, for any times and rates locked to note divisions.
+  pub fn set_tempo(&mut self, tempo: f32) {
+    if tempo > 0. && tempo != self.tempo {
+      self.tempo = tempo;
+      self.update_synced_times();
+    }
+  }
+
+  fn update_synced_times(&mut self) {
+    self.pre_delay.set_delay_ms(self.pre_delay_time.resolve_ms(self.tempo));
+    self.gate.set_hold_ms(self.gate_hold_time.resolve_ms(self.tempo));
+    self.wobble.set_rate_hz(self.wobble_rate.resolve_hz(self.tempo));
+    let room_scale = match note_ms(self.room_decay_division, self.tempo) {
+      Some(ms) => ms * 0.001 / self.room_rt60[1],
+      None => 1.,
+    };
+    self.fdn.set_rt60(self.room_rt60.map(|seconds| seconds * room_scale));
+    self.fdn.set_mod_rate(self.room_mod_rate.resolve_hz(self.tempo));
+    match note_ms(self.plate_decay_division, self.tempo) {
+      Some(ms) => self.plate.set_rt60(ms * 0.001),
+      None => self.plate.set_decay(self.plate_decay),
+    }
+    self.plate.set_mod_rate(self.plate_mod_rate.resolve_hz(self.tempo));
+    // kept for rebuilding at a new sample rate, the IR worker syncs the taps themselves
+    self.velvet.set_decay_seconds(self.velvet_decay.resolve_ms(self.tempo) * 0.001);
+  }
+
+  /// Applies a single parameter change, in real units, to the relevant processing block.
+  fn set_parameter(&mut self, param: Parameter, value: f32) {
+    match param {
+      Parameter::Engine => self.set_engine(EngineKind::from_index(value as usize)),
+      Parameter::SpringLength => self.spring_model.set_length_ms(value),
+      Parameter::SpringTension => self.spring_model.set_tension(value / 100.),
+      Parameter::SpringDamping => self.spring_model.set_damping(value / 100.),
+      Parameter::SpringCount => self.spring_model.set_spring_count(value as usize + 1),
+      // the IR worker takes care of these
+      Parameter::IrSource
+      | Parameter::IrWidth
+      | Parameter::IrDecay
+      | Parameter::IrDecayLow
+      | Parameter::IrDecayMid
+      | Parameter::IrDecayHigh
+      | Parameter::IrDecayLowCross
+      | Parameter::IrDecayHighCross
+      | Parameter::IrStart
+      | Parameter::IrLength
+      | Parameter::IrFade
+      | Parameter::IrFadeShape => {}
+      // the IR worker reverses the IR, the lookahead is held back here
+      Parameter::Reverse => {
+        self.reverse = ReverseMode::from_index(value as usize);
+        self.update_lookahead();
+      }
+      Parameter::ReverseLength => {
+        self.reverse_length_ms = value;
+        self.update_lookahead();
+      }
+      // the IR worker splits the IR, each half's sound is shaped here
+      Parameter::SplitTime => {}
+      Parameter::EarlyLevel => self.spring_ir.set_early_level_db(value),
+      Parameter::EarlyTone => self.spring_ir.set_early_tilt_db(value),
+      Parameter::EarlyWidth => self.spring_ir.set_early_width(value / 100.),
+      Parameter::LateLevel => self.spring_ir.set_late_level_db(value),
+      Parameter::LateTone => self.spring_ir.set_late_tilt_db(value),
+      Parameter::LateWidth => self.spring_ir.set_late_width(value / 100.),
+      // the IR worker cuts the band IRs, and brings the layout along with them
+      Parameter::BandCount => {
+        self.band_count = value as usize + 1;
+        self.update_lookahead();
+      }
+      Parameter::BandCrossover => {
+        self.crossover = CrossoverKind::from_index(value as usize);
+        self.update_lookahead();
+      }
+      Parameter::BandCross1 => self.spring_ir.set_band_crossover(0, value),
+      Parameter::BandCross2 => self.spring_ir.set_band_crossover(1, value),
+      Parameter::BandCross3 => self.spring_ir.set_band_crossover(2, value),
+      Parameter::BandIr1 | Parameter::BandIr2 | Parameter::BandIr3 | Parameter::BandIr4 => {}
+      Parameter::BandLevel1 => self.spring_ir.set_band_level_db(0, value),
+      Parameter::BandLevel2 => self.spring_ir.set_band_level_db(1, value),
+      Parameter::BandLevel3 => self.spring_ir.set_band_level_db(2, value),
+      Parameter::BandLevel4 => self.spring_ir.set_band_level_db(3, value),
+      // only noted here, the IR worker builds the meshes to match
+      Parameter::PhysWireRadius => self.physical_spring.set_wire_radius_mm(value),
+      Parameter::PhysCoilRadius => self.physical_spring.set_coil_radius_mm(value),
+      Parameter::PhysLength => self.physical_spring.set_length_cm(value),
+      Parameter::PhysDecay => self.physical_spring.set_decay_seconds(value),
+      Parameter::RoomSize => self.fdn.set_size_ms(value),
+      Parameter::RoomLines => self.fdn.set_line_count(if value as usize == 0 { 8 } else { 16 }),
+      Parameter::RoomMatrix => self.fdn.set_matrix(if value as usize == 0 { FeedbackMatrix::Hadamard } else { FeedbackMatrix::Householder }),
+      Parameter::RoomRt60Low | Parameter::RoomRt60Mid | Parameter::RoomRt60High => {
+        let band = match param {
+          Parameter::RoomRt60Low => 0,
+          Parameter::RoomRt60Mid => 1,
+          _ => 2,
+        };
+        self.room_rt60[band] = value;
+        self.update_synced_times();
+      }
+      Parameter::RoomDecaySync => {
+        self.room_decay_division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::RoomModRate => {
+        self.room_mod_rate.hz = value;
+        self.update_synced_times();
+      }
+      Parameter::RoomModRateSync => {
+        self.room_mod_rate.division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::RoomModDepth => self.fdn.set_mod_depth(value / 100.),
+      Parameter::PlateDecay => {
+        self.plate_decay = value / 100.;
+        self.update_synced_times();
+      }
+      Parameter::PlateDecaySync => {
+        self.plate_decay_division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::PlateDamping => self.plate.set_damping(value / 100.),
+      Parameter::PlateBandwidth => self.plate.set_bandwidth(value / 100.),
+      Parameter::PlateDiffusion => self.plate.set_input_diffusion(value / 100.),
+      Parameter::PlateExcursion => self.plate.set_excursion(value),
+      Parameter::PlateModRate => {
+        self.plate_mod_rate.hz = value;
+        self.update_synced_times();
+      }
+      Parameter::PlateModRateSync => {
+        self.plate_mod_rate.division = value as usize;
+        self.update_synced_times();
+      }
+      // the IR worker lays out the early reflections along with the tail they hand over to
+      Parameter::HybridCrossover => {}
+      Parameter::HybridBalance => self.hybrid.set_balance(value / 100.),
+      Parameter::VelvetDecay => {
+        self.velvet_decay.ms = value * 1000.;
+        self.update_synced_times();
+      }
+      Parameter::VelvetDecaySync => {
+        self.velvet_decay.division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::VelvetDamping => self.velvet.set_damping(value / 100.),
+      Parameter::VelvetDensity => self.velvet.set_density(value),
+      Parameter::WobbleEnabled => self.wobble.set_enabled(value >= 0.5),
+      Parameter::WobbleRate => {
+        self.wobble_rate.hz = value;
+        self.update_synced_times();
+      }
+      Parameter::WobbleRateSync => {
+        self.wobble_rate.division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::WobbleDepth => self.wobble.set_depth_ms(value),
+      Parameter::WobbleRandom => self.wobble.set_randomness(value / 100.),
+      Parameter::KickType => self.kick.set_type(match value as usize {
+        0 => KickType::Click,
+        1 => KickType::Thump,
+        _ => KickType::Noise,
+      }),
+      Parameter::KickLevel => self.kick.set_level(db_to_gain(value)),
+      Parameter::KickDry => self.kick.set_dry(value >= 0.5),
+      Parameter::Sidechain => self.sidechain = match value as usize {
+        0 => SidechainMode::Off,
+        1 => SidechainMode::Replace,
+        _ => SidechainMode::Add,
+      },
+      Parameter::GateEnabled => {
+        self.gate.set_enabled(value >= 0.5);
+        self.update_lookahead();
+      }
+      Parameter::GateKey => self.gate.set_key(if value as usize == 0 { GateKey::Input } else { GateKey::Wet }),
+      Parameter::GateThreshold => self.gate.set_threshold_db(value),
+      Parameter::GateHold => {
+        self.gate_hold_time.ms = value;
+        self.update_synced_times();
+      }
+      Parameter::GateHoldSync => {
+        self.gate_hold_time.division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::GateRelease => self.gate.set_release_ms(value),
+      Parameter::GateLookahead => self.gate.set_lookahead_ms(value),
+      Parameter::GateRange => self.gate.set_range_db(value),
+      Parameter::PreDelay => {
+        self.pre_delay_time.ms = value;
+        self.update_synced_times();
+      }
+      Parameter::PreDelaySync => {
+        self.pre_delay_time.division = value as usize;
+        self.update_synced_times();
+      }
+      Parameter::DriveEnabled => {
+        self.drive.set_enabled(value >= 0.5);
+        self.update_lookahead();
+      }
+      Parameter::DriveType => self.drive.set_type(match value as usize {
+        0 => DriveType::SoftClip,
+        1 => DriveType::Tube,
+        _ => DriveType::Transformer,
+      }),
+      Parameter::DriveAmount => self.drive.set_amount_db(value),
+      Parameter::DriveOversampling => {
+        self.drive.set_oversampling(1 << value as usize);
+        self.update_lookahead();
+      }
+      Parameter::ShimmerEnabled => self.shimmer.set_enabled(value >= 0.5),
+      Parameter::ShimmerPitch => self.shimmer.set_semitones(match value as usize {
+        0 => 12.,
+        1 => 7.,
+        _ => -12.,
+      }),
+      Parameter::ShimmerFeedback => self.shimmer.set_feedback(value / 100.),
+      Parameter::ShimmerDamping => self.shimmer.set_damping(value),
+      Parameter::Freeze => self.spring_ir.set_freeze(value >= 0.5),
+      Parameter::FreezeInput => self.spring_ir.set_freeze_input(if value as usize == 0 { FreezeInput::Mute } else { FreezeInput::Mix }),
+      Parameter::DynamicMode => self.dynamic.set_mode(match value as usize {
+        0 => DynamicMode::Off,
+        1 => DynamicMode::Inverse,
+        _ => DynamicMode::Direct,
+      }),
+      Parameter::DynamicThreshold => self.dynamic.set_threshold_db(value),
+      Parameter::DynamicRange => self.dynamic.set_range_db(value),
+      Parameter::DynamicAttack => self.dynamic.set_attack_ms(value),
+      Parameter::DynamicRelease => self.dynamic.set_release_ms(value),
+      Parameter::LimiterEnabled => self.limiter.set_enabled(value >= 0.5),
+      Parameter::LimiterCeiling => self.limiter.set_ceiling_db(value),
+      Parameter::LimiterRelease => self.limiter.set_release_ms(value),
+      // read only, written in `process`
+      Parameter::DynamicMeter | Parameter::LimiterReduction => {}
+    }
+  }
+
+  // room for the longest the lookaheads can be at this sample rate, so they never allocate later
+  fn make_lookahead_room(&mut self) {
+    let reverse = true_reverse_latency(self.sample_rate);
+    self.lookahead.set_max_delay(reverse + linear_phase_latency(self.sample_rate));
+    self.key_hold.set_max_delay(reverse + linear_phase_latency(self.sample_rate) + oversampling_latency(8));
+    self.kick_hold.set_max_delay(
+      reverse + linear_phase_latency(self.sample_rate) + gate_latency(self.sample_rate) + oversampling_latency(8),
+    );
+    self.reverse_pad.set_max_delay(reverse);
+  }
+
+  fn update_lookahead(&mut self) {
+    let (reverse, pad) = match self.reverse {
+      ReverseMode::TrueReverse => {
+        let latency = true_reverse_latency(self.sample_rate);
+        (latency, latency - reverse_latency(self.reverse_length_ms, self.sample_rate))
+      }
+      _ => (0, 0),
+    };
+    let held = reverse + crossover_latency(self.band_count, self.crossover, self.sample_rate);
+    self.lookahead.set_delay(held);
+    self.key_hold.set_delay(held + self.drive.latency());
+    // the kick skips the drive too, so it waits out the oversampling filters as well
+    self.kick_hold.set_delay(held + self.gate.latency() + self.drive.latency());
+    self.reverse_pad.set_delay(pad);
+  }
+
+  /// Takes a parameter change from the host, gliding to it if it has a control rate ramp.
+  fn receive_parameter(&mut self, param: Parameter, value: f32) {
+    match &mut self.smoothers[param.index()] {
+      Some(smoother) => smoother.set_target(value),
+      None => self.set_parameter(param, value),
+    }
+  }
+
+  /// Moves every gliding parameter on by `samples`, and applies where they've got to.
+  fn advance_smoothers(&mut self, samples: usize) {
+    for (index, param) in Parameter::ALL.iter().enumerate() {
+      let value = match &mut self.smoothers[index] {
+        Some(smoother) if smoother.is_smoothing() => smoother.advance(samples),
+        _ => continue,
+      };
+      self.set_parameter(*param, value);
+    }
+  }
+
+  /// Kicks the tank, `offset` samples into the next block, with velocity 0..1. MIDI notes carry
+  /// their offset, so kicks land on their sample. Parameter changes don't: VST2 automation says
+  /// nothing about where in the block it falls, so it lands at the start of the next one.
+  /// Past `MAX_KICKS` notes in a block, the earliest are dropped, see `Kick::trigger`.
+  pub fn trigger_kick(&mut self, offset: usize, velocity: f32) {
+    if self.kicks.len() < MAX_KICKS {
+      self.kicks.push((offset, velocity));
+      return;
+    }
+    // full, so never grow past the capacity reserved up front
+    if let Some(earliest) = self.kicks.iter_mut().min_by_key(|(kick, _)| *kick) {
+      if offset >= earliest.0 {
+        *earliest = (offset, velocity);
+      }
+    }
+  }
+
+  fn set_engine(&mut self, engine: EngineKind) {
+    if engine == self.engine {
+      return;
+    }
+    self.engine_mut(engine).reset();
+    self.engine_fade = Some((self.engine, self.engine_fade_samples));
+    self.engine = engine;
+  }
+
+  fn engine_mut(&mut self, engine: EngineKind) -> &mut dyn Engine {
+    match engine {
+      EngineKind::SpringIr => &mut self.spring_ir,
+      EngineKind::SpringModel => &mut self.spring_model,
+      EngineKind::PhysicalSpring => &mut self.physical_spring,
+      EngineKind::Room => &mut self.fdn,
+      EngineKind::Plate => &mut self.plate,
+      EngineKind::Hybrid => &mut self.hybrid,
+      EngineKind::Velvet => &mut self.velvet,
+    }
+  }
+
+  fn apply_state_updates(&mut self) {
+    while let Ok(update) = self.messages_from_params.try_recv() {
+      match update {
+        StateUpdate::SetParameter(param, value) => self.receive_parameter(param, value),
+        StateUpdate::SetImpulseResponse(impulse_response) => self.spring_ir.set_impulse_response(*impulse_response),
+        StateUpdate::SwapHybridTail(tail) => self.hybrid.set_tail(*tail),
+        StateUpdate::SwapVelvetTaps(taps) => self.velvet.set_taps(*taps),
+        StateUpdate::SwapSpringMeshes(meshes) => self.physical_spring.set_meshes(*meshes),
+        StateUpdate::SetReturnPath(return_path) => self.return_path = Some(return_path),
+      }
+    }
+  }
+
+  /// Sends what the engines swapped out back to the IR worker, to be freed off the audio thread.
+  fn send_back_retired(&mut self) {
+    while let Some(ir) = self.spring_ir.take_retired() {
+      self.send_back(Retired::Ir(ir));
+    }
+    while let Some(ir) = self.hybrid.take_retired_ir() {
+      self.send_back(Retired::Ir(ir));
+    }
+    while let Some(taps) = self.hybrid.take_retired_taps() {
+      self.send_back(Retired::EarlyTaps(taps));
+    }
+    while let Some(taps) = self.velvet.take_retired() {
+      self.send_back(Retired::VelvetTaps(taps));
+    }
+  }
+
+  fn send_back(&self, retired: Retired) {
+    // with the queue full, or no worker to send to, it's freed here after all
+    if let Some(return_path) = &self.return_path {
+      let _ = return_path.try_send(retired);
     }
   }
 
@@ -31,15 +657,197 @@ impl PluginDsp {
   /// processed audio into the output buffer.
   pub fn process(&mut self, buffer: &mut AudioBuffer<f32>) {
     let (inputs, mut outputs) = buffer.split();
+    let count = inputs.len().min(MAX_INPUTS);
+    let mut channels: [&[f32]; MAX_INPUTS] = [&[]; MAX_INPUTS];
+    for (i, channel) in channels.iter_mut().take(count).enumerate() {
+      *channel = inputs.get(i);
+    }
+    let (left, right) = (outputs.get_mut(0), outputs.get_mut(1));
+    self.process_block(&channels[..count], left, right);
+  }
+
+  /// Renders a block from the main inputs (and the sidechain, when there is one), splitting it
+  /// wherever a kick is due or the control rate glides move on, so each lands on exactly the
+  /// sample it was meant for.
+  ///
+  /// Parameter changes are the one thing not split at. VST2's `setParameter` carries no sample
+  /// offset, only MIDI events do, so there's no position in the block to split at: automation is
+  /// applied at the start of the block it arrives before, and the control rate glides take it on
+  /// from there. A host that wants it tighter sends smaller blocks, which render the same.
+  pub fn process_block(&mut self, inputs: &[&[f32]], left: &mut [f32], right: &mut [f32]) {
+    self.apply_state_updates();
+    let len = left.len();
+    let mut kicks = std::mem::take(&mut self.kicks);
+    for (offset, _) in kicks.iter_mut() {
+      // notes the host placed past the end of the block still fire
+      *offset = (*offset).min(len.saturating_sub(1));
+    }
+    // stable, so kicks on the same sample happen in the order they came in
+    kicks.sort_by_key(|(offset, _)| *offset);
+
+    let mut reduction_db = 0f32;
+    let mut pending = kicks.iter().peekable();
+    let count = inputs.len().min(MAX_INPUTS);
+    let mut stretch: [&[f32]; MAX_INPUTS] = [&[]; MAX_INPUTS];
+    let mut start = 0;
+    while start < len {
+      if self.control_phase == 0 {
+        self.advance_smoothers(CONTROL_BLOCK);
+      }
+      while let Some((_, velocity)) = pending.next_if(|(offset, _)| *offset <= start) {
+        self.kick.trigger(0, *velocity);
+      }
+      let mut end = len.min(start + CONTROL_BLOCK - self.control_phase);
+      if let Some((offset, _)) = pending.peek() {
+        end = end.min(*offset);
+      }
+
+      for (channel, input) in stretch.iter_mut().zip(inputs) {
+        *channel = &input[start..end];
+      }
+      self.render(&stretch[..count], &mut left[start..end], &mut right[start..end]);
+      reduction_db = reduction_db.min(self.limiter.reduction_db());
+      self.control_phase = (self.control_phase + end - start) % CONTROL_BLOCK;
+      start = end;
+    }
+    self.meters.set(Meter::DynamicGain, self.dynamic.gain_db());
+    self.meters.set(Meter::LimiterReduction, reduction_db);
+
+    // hand the allocation back for the next block
+    kicks.clear();
+    self.kicks = kicks;
+    self.send_back_retired();
+  }
+
+  /// Runs a stretch with no changes in it through the whole chain.
+  fn render(&mut self, inputs: &[&[f32]], left: &mut [f32], right: &mut [f32]) {
+    let mut scratch = std::mem::take(&mut self.scratch);
+    let [excite_l, excite_r, wet_l, wet_r, old_l, old_r] = &mut scratch;
+    // the signal that excites the tank
+    excite_l.clear();
+    excite_l.extend_from_slice(inputs[0]);
+    excite_r.clear();
+    excite_r.extend_from_slice(inputs[1]);
+    // hosts that don't hook up the sidechain bus may not pass it at all
+    if inputs.len() >= 4 && self.sidechain != SidechainMode::Off {
+      let (sidechain_l, sidechain_r) = (inputs[2], inputs[3]);
+      if self.sidechain == SidechainMode::Replace {
+        excite_l.copy_from_slice(sidechain_l);
+        excite_r.copy_from_slice(sidechain_r);
+      } else {
+        for i in 0..excite_l.len() {
+          excite_l[i] += sidechain_l[i];
+          excite_r[i] += sidechain_r[i];
+        }
+      }
+    }
+    self.drive.process(excite_l, excite_r);
+    self.pre_delay.process(excite_l, excite_r);
+    // in true reverse the reversed IR's swell already trails the input by the latency, as do the
+    // linear phase band IRs, so only everything else needs holding back to line up with them
+    let holding_back = self.lookahead.delay() > 0;
+    let mut held = std::mem::take(&mut self.held);
+    for buffer in held.iter_mut() {
+      buffer.clear();
+    }
+    // kicks shake the tank directly, so they skip the drive and the pre-delay
+    let heard_dry = self.kick.dry();
+    let mut kicked = false;
+    if let Some(kick) = self.kick.process(excite_l.len()) {
+      for i in 0..kick.len() {
+        excite_l[i] += kick[i];
+        excite_r[i] += kick[i];
+      }
+      if heard_dry {
+        held[4].extend_from_slice(kick);
+        kicked = true;
+      }
+    }
+    self.shimmer.add_feedback(excite_l, excite_r);
+
+    if holding_back {
+      held[0].extend_from_slice(excite_l);
+      held[1].extend_from_slice(excite_r);
+      for (channel, buffer) in held.iter_mut().take(2).enumerate() {
+        self.lookahead.process(channel, buffer);
+      }
+    }
+    // the key skips the drive, so it can be held back even when nothing else is
+    let holding_key = self.key_hold.delay() > 0;
+    if holding_key {
+      held[2].extend_from_slice(inputs[0]);
+      held[3].extend_from_slice(inputs[1]);
+      for (channel, buffer) in held[2..4].iter_mut().enumerate() {
+        self.key_hold.process(channel, buffer);
+      }
+    }
+    if self.reverse_pad.delay() > 0 {
+      self.reverse_pad.process(0, excite_l);
+      self.reverse_pad.process(1, excite_r);
+    }
+    let excitation = |engine: EngineKind| {
+      if holding_back && engine != EngineKind::SpringIr {
+        (held[0].as_slice(), held[1].as_slice())
+      } else {
+        (excite_l.as_slice(), excite_r.as_slice())
+      }
+    };
+
+    let (current_l, current_r) = excitation(self.engine);
+    self.render_engine(self.engine, current_l, current_r, wet_l, wet_r);
+    if let Some((previous, remaining)) = self.engine_fade {
+      let (previous_l, previous_r) = excitation(previous);
+      self.render_engine(previous, previous_l, previous_r, old_l, old_r);
+      let total = self.engine_fade_samples.max(1) as f32;
+      let mut remaining = remaining;
+      for i in 0..wet_l.len() {
+        let fade_out = remaining as f32 / total;
+        wet_l[i] = wet_l[i] * (1. - fade_out) + old_l[i] * fade_out;
+        wet_r[i] = wet_r[i] * (1. - fade_out) + old_r[i] * fade_out;
+        remaining = remaining.saturating_sub(1);
+      }
+      self.engine_fade = if remaining > 0 { Some((previous, remaining)) } else { None };
+    }
+    self.wobble.process(wet_l, wet_r);
+    self.shimmer.feed(wet_l, wet_r);
 
-    //TODO: DRY
-    let devisor = 5000.;
-    for (output_sample, result_sample) in outputs[0].iter_mut().zip(self.convolver_l.process(&inputs[0]).iter()) {
-      *output_sample = *result_sample / devisor;
+    let (key_l, key_r) = if holding_key { (held[2].as_slice(), held[3].as_slice()) } else { (inputs[0], inputs[1]) };
+    self.gate.process(key_l, key_r, wet_l, wet_r);
+    self.dynamic.process(key_l, key_r, wet_l, wet_r);
+    if self.kick_hold.delay() > 0 {
+      // kept running between kicks, so the held back ones still come out
+      held[4].resize(wet_l.len(), 0.);
+      self.kick_hold.process(0, &mut held[4]);
+      kicked = true;
     }
-    for (output_sample, result_sample) in outputs[1].iter_mut().zip(self.convolver_r.process(&inputs[1]).iter()) {
-      *output_sample = *result_sample / devisor;
+    if kicked {
+      for (i, kick) in held[4].iter().enumerate() {
+        wet_l[i] += kick;
+        wet_r[i] += kick;
+      }
     }
+    self.held = held;
+    self.limiter.process(wet_l, wet_r);
+
+    left.copy_from_slice(wet_l);
+    right.copy_from_slice(wet_r);
+    self.scratch = scratch;
+  }
+
+  /// Runs the excitation through one of the reverb engines, into `wet_l` and `wet_r`.
+  fn render_engine(&mut self, engine: EngineKind, excite_l: &[f32], excite_r: &[f32], wet_l: &mut Vec<f32>, wet_r: &mut Vec<f32>) {
+    wet_l.clear();
+    wet_l.resize(excite_l.len(), 0.);
+    wet_r.clear();
+    wet_r.resize(excite_r.len(), 0.);
+    self.engine_mut(engine).process(excite_l, excite_r, wet_l, wet_r);
   }
 }
 
+pub fn db_to_gain(db: f32) -> f32 {
+  10f32.powf(db / 20.)
+}
+
+pub fn ms_to_samples(ms: f32, sample_rate: f32) -> usize {
+  (ms * 0.001 * sample_rate).round() as usize
+}
@@ -0,0 +1,268 @@
+use std::f32::consts::{FRAC_1_SQRT_2, PI};
+use rustfft::{FftPlanner, num_complex::Complex};
+use super::ms_to_samples;
+
+/*
+Multiband convolution
+  - the input is split into 2 to 4 bands, each band goes through its own IR at its own level,
+    and they're summed back up, so a dark spring can carry the lows under a bright plate on top
+  - linkwitz-riley: 4th order crossovers on the input, in a tree from the bottom up (the lowest
+    band comes off first, the rest carries on to the next crossover). each band below the top
+    gets an allpass for every crossover above its own, so they all come out with the same phase
+    and sum back flat. no latency, but the phase turns over around every crossover
+  - linear phase: the input isn't touched, each band's IR is filtered instead, by masking its
+    spectrum with raised cosines an octave wide that add back up to exactly the whole IR. the
+    masks ring both ways in time, so the filtered IRs are pushed back by a fixed delay, which is
+    reported as latency. rings longer than that (below ~50Hz) are cut short
+  - crossovers are kept an octave apart, or the masks would overlap
+*/
+
+pub const MAX_BANDS: usize = 4;
+const LINEAR_PHASE_MS: f32 = 20.;
+const MIN_CROSSOVER_RATIO: f32 = 2.;
+
+/// Must line up with `plugin_state::CROSSOVER_OPTIONS`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CrossoverKind {
+  LinkwitzRiley,
+  LinearPhase,
+}
+
+impl CrossoverKind {
+  pub fn from_index(index: usize) -> Self {
+    if index == 0 { CrossoverKind::LinkwitzRiley } else { CrossoverKind::LinearPhase }
+  }
+}
+
+/// How far the linear phase band IRs are pushed back, and so the latency they add.
+pub fn linear_phase_latency(sample_rate: f32) -> usize {
+  ms_to_samples(LINEAR_PHASE_MS, sample_rate)
+}
+
+/// The latency `bands` add through a `kind` crossover, which only the linear phase one has.
+pub fn crossover_latency(bands: usize, kind: CrossoverKind, sample_rate: f32) -> usize {
+  if bands > 1 && kind == CrossoverKind::LinearPhase { linear_phase_latency(sample_rate) } else { 0 }
+}
+
+// the crossovers in use, each at least an octave above the last
+fn spaced(crossovers: &[f32]) -> Vec<f32> {
+  let mut spaced: Vec<f32> = Vec::with_capacity(crossovers.len());
+  for crossover in crossovers {
+    let lowest = spaced.last().map_or(0., |previous| previous * MIN_CROSSOVER_RATIO);
+    spaced.push(crossover.max(lowest));
+  }
+  spaced
+}
+
+/// Bands of `signal` split at `crossovers` (in Hz, rising), which sum back to exactly `signal`.
+/// Zero phase, so each band rings a little before it starts. Heavy, so meant for use off the
+/// audio thread.
+pub fn split_bands(signal: &[f32], crossovers: &[f32], sample_rate: f32) -> Vec<Vec<f32>> {
+  let crossovers = spaced(crossovers);
+  // a little room for the masks' ringing to wrap into
+  let size = (signal.len() + 4096).next_power_of_two();
+  let mut planner = FftPlanner::<f32>::new();
+  let mut spectrum: Vec<Complex<f32>> = (0..size)
+    .map(|i| Complex { re: signal.get(i).copied().unwrap_or(0.), im: 0. })
+    .collect();
+  planner.plan_fft_forward(size).process(&mut spectrum);
+  let ifft = planner.plan_fft_inverse(size);
+
+  // share of each bin below a crossover, an octave wide raised cosine in log frequency
+  let below = |bin: usize, crossover: Option<&f32>| {
+    let crossover = match crossover {
+      Some(crossover) => *crossover,
+      None => return 1.,
+    };
+    let frequency = bin.min(size - bin) as f32 * sample_rate / size as f32;
+    if frequency <= 0. {
+      return 1.;
+    }
+    let octaves = (frequency / crossover).log2().clamp(-0.5, 0.5);
+    0.5 - 0.5 * (PI * octaves).sin()
+  };
+  (0..=crossovers.len())
+    .map(|band| {
+      let mut masked: Vec<Complex<f32>> = spectrum
+        .iter()
+        .enumerate()
+        .map(|(i, bin)| {
+          let lower = if band == 0 { 0. } else { below(i, crossovers.get(band - 1)) };
+          bin * (below(i, crossovers.get(band)) - lower)
+        })
+        .collect();
+      ifft.process(&mut masked);
+      masked.iter().take(signal.len()).map(|bin| bin.re / size as f32).collect()
+    })
+    .collect()
+}
+
+/// One band of an IR for the linear phase crossover, pushed back by `linear_phase_latency` so
+/// its ringing comes after the start.
+pub fn linear_phase_band(impulse_response: &[f32], band: usize, crossovers: &[f32], sample_rate: f32) -> Vec<f32> {
+  let mut delayed = vec![0.; linear_phase_latency(sample_rate)];
+  delayed.extend_from_slice(impulse_response);
+  split_bands(&delayed, crossovers, sample_rate).swap_remove(band)
+}
+
+/// Second order section, transposed direct form II.
+#[derive(Clone, Copy, Default)]
+struct Biquad {
+  b0: f32,
+  b1: f32,
+  b2: f32,
+  a1: f32,
+  a2: f32,
+  z1: f32,
+  z2: f32,
+}
+
+#[derive(Clone, Copy)]
+enum Response {
+  Lowpass,
+  Highpass,
+  Allpass,
+}
+
+impl Biquad {
+  // butterworth Q, two of them in a row make a linkwitz-riley
+  fn set(&mut self, response: Response, frequency: f32, sample_rate: f32) {
+    let w0 = 2. * PI * (frequency / sample_rate).min(0.49);
+    let (sin, cos) = w0.sin_cos();
+    let alpha = sin / (2. * FRAC_1_SQRT_2);
+    let a0 = 1. + alpha;
+    let (b0, b1, b2) = match response {
+      Response::Lowpass => ((1. - cos) / 2., 1. - cos, (1. - cos) / 2.),
+      Response::Highpass => ((1. + cos) / 2., -(1. + cos), (1. + cos) / 2.),
+      Response::Allpass => (1. - alpha, -2. * cos, 1. + alpha),
+    };
+    self.b0 = b0 / a0;
+    self.b1 = b1 / a0;
+    self.b2 = b2 / a0;
+    self.a1 = -2. * cos / a0;
+    self.a2 = (1. - alpha) / a0;
+  }
+
+  fn reset(&mut self) {
+    self.z1 = 0.;
+    self.z2 = 0.;
+  }
+
+  fn process(&mut self, input: f32) -> f32 {
+    let output = self.b0 * input + self.z1;
+    self.z1 = self.b1 * input - self.a1 * output + self.z2;
+    self.z2 = self.b2 * input - self.a2 * output;
+    output
+  }
+}
+
+/// One 4th order linkwitz-riley split, as (low, high).
+#[derive(Clone, Copy, Default)]
+struct LinkwitzRiley {
+  low: [Biquad; 2],
+  high: [Biquad; 2],
+}
+
+impl LinkwitzRiley {
+  fn set_frequency(&mut self, frequency: f32, sample_rate: f32) {
+    for stage in 0..2 {
+      self.low[stage].set(Response::Lowpass, frequency, sample_rate);
+      self.high[stage].set(Response::Highpass, frequency, sample_rate);
+    }
+  }
+
+  fn reset(&mut self) {
+    for biquad in self.low.iter_mut().chain(self.high.iter_mut()) {
+      biquad.reset();
+    }
+  }
+
+  fn process(&mut self, input: f32) -> (f32, f32) {
+    let low = self.low[0].process(input);
+    let high = self.high[0].process(input);
+    (self.low[1].process(low), self.high[1].process(high))
+  }
+}
+
+/// Splits one channel into phase matched bands with linkwitz-riley crossovers.
+pub struct Crossover {
+  sample_rate: f32,
+  bands: usize,
+  frequencies: [f32; MAX_BANDS - 1],
+  splits: [LinkwitzRiley; MAX_BANDS - 1],
+  // for each band, an allpass at every crossover above its own
+  allpasses: [[Biquad; MAX_BANDS - 2]; MAX_BANDS - 1],
+}
+
+impl Crossover {
+  pub fn new(sample_rate: f32) -> Self {
+    let mut crossover = Self {
+      sample_rate,
+      bands: 1,
+      frequencies: [200., 1200., 5000.],
+      splits: Default::default(),
+      allpasses: Default::default(),
+    };
+    crossover.update();
+    crossover
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.update();
+  }
+
+  pub fn set_band_count(&mut self, bands: usize) {
+    let bands = bands.clamp(1, MAX_BANDS);
+    if bands != self.bands {
+      self.bands = bands;
+      self.reset();
+      self.update();
+    }
+  }
+
+  pub fn set_frequency(&mut self, index: usize, frequency: f32) {
+    self.frequencies[index] = frequency;
+    self.update();
+  }
+
+  fn update(&mut self) {
+    let frequencies = spaced(&self.frequencies[..self.bands - 1]);
+    for (split, frequency) in self.splits.iter_mut().zip(&frequencies) {
+      split.set_frequency(*frequency, self.sample_rate);
+    }
+    for (band, allpasses) in self.allpasses.iter_mut().enumerate() {
+      for (allpass, frequency) in allpasses.iter_mut().zip(frequencies.iter().skip(band + 1)) {
+        allpass.set(Response::Allpass, *frequency, self.sample_rate);
+      }
+    }
+  }
+
+  pub fn reset(&mut self) {
+    for split in self.splits.iter_mut() {
+      split.reset();
+    }
+    for allpass in self.allpasses.iter_mut().flatten() {
+      allpass.reset();
+    }
+  }
+
+  /// Splits `input` into `bands`, which must have a buffer per band, each at least as long as
+  /// `input`.
+  pub fn process(&mut self, input: &[f32], bands: &mut [Vec<f32>]) {
+    let last = self.bands - 1;
+    for (i, sample) in input.iter().enumerate() {
+      let mut rest = *sample;
+      let stages = self.splits.iter_mut().zip(self.allpasses.iter_mut()).zip(bands.iter_mut());
+      for (band, ((split, allpasses), output)) in stages.take(last).enumerate() {
+        let (mut low, high) = split.process(rest);
+        for allpass in allpasses.iter_mut().take(last - band - 1) {
+          low = allpass.process(low);
+        }
+        output[i] = low;
+        rest = high;
+      }
+      bands[last][i] = rest;
+    }
+  }
+}
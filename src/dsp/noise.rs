@@ -0,0 +1,26 @@
+/// Small, deterministic xorshift noise source. Good enough for audio, and cheap enough for the
+/// audio thread.
+pub struct Noise {
+  state: u32,
+}
+
+impl Noise {
+  pub fn new(seed: u32) -> Self {
+    // xorshift gets stuck on 0
+    Self { state: seed.max(1) }
+  }
+
+  pub fn next_u32(&mut self) -> u32 {
+    let mut x = self.state;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    self.state = x;
+    x
+  }
+
+  // uniform in 0..1
+  pub fn next_unipolar(&mut self) -> f32 {
+    (self.next_u32() >> 8) as f32 / (1 << 24) as f32
+  }
+}
@@ -0,0 +1,128 @@
+use std::f32::consts::PI;
+
+use super::delay_line::DelayLine;
+
+/*
+Oversampling around a nonlinearity
+  - zero stuff the input up to `factor` times the sample rate
+  - low pass (windowed sinc) to remove the images, done polyphase so the zeros cost nothing
+  - run the nonlinearity at the high rate
+  - low pass again to remove anything above the original nyquist, then keep every `factor`th sample
+  - `upsample` stops after the first low pass, for detectors that only need to look at the
+    oversampled signal
+  - both low passes are linear phase, so together they hold everything back by a whole number of
+    original rate samples, see `oversampling_latency`
+*/
+
+const TAPS_PER_PHASE: usize = 12;
+
+pub struct Oversampler {
+  factor: usize,
+  kernel: Vec<f32>, // shared by the up and down sampling filters
+  up_history: DelayLine, // original rate input
+  down_history: DelayLine, // oversampled shaper output
+}
+
+impl Oversampler {
+  pub fn new(factor: usize) -> Self {
+    Self::with_taps(factor, TAPS_PER_PHASE)
+  }
+
+  // longer filters are flatter further up, at the cost of more latency and work
+  pub fn with_taps(factor: usize, taps_per_phase: usize) -> Self {
+    let factor = factor.max(1);
+    let kernel = if factor == 1 { vec![1.] } else { low_pass_kernel(taps_per_phase * factor, factor) };
+    Self {
+      factor,
+      up_history: DelayLine::new(taps_per_phase),
+      down_history: DelayLine::new(kernel.len()),
+      kernel,
+    }
+  }
+
+  pub fn factor(&self) -> usize {
+    self.factor
+  }
+
+  /// How many original rate samples `process` trails its input by.
+  pub fn latency(&self) -> usize {
+    group_delay(self.kernel.len(), self.factor)
+  }
+
+  /// Forgets the filters' history without giving up their memory.
+  pub fn reset(&mut self) {
+    self.up_history.clear();
+    self.down_history.clear();
+  }
+
+  // runs one sample through `shaper` at the oversampled rate
+  pub fn process<F: FnMut(f32) -> f32>(&mut self, input: f32, mut shaper: F) -> f32 {
+    if self.factor == 1 {
+      return shaper(input);
+    }
+
+    let down_history = &mut self.down_history;
+    upsample(&self.kernel, self.factor, &mut self.up_history, input, |upsampled| {
+      down_history.push(shaper(upsampled))
+    });
+
+    let mut output = 0.;
+    for (k, tap) in self.kernel.iter().enumerate() {
+      output += tap * self.down_history.read(k);
+    }
+    output
+  }
+
+  // hands each of the `factor` oversampled values behind one more input sample to `each`
+  pub fn upsample<F: FnMut(f32)>(&mut self, input: f32, mut each: F) {
+    if self.factor == 1 {
+      return each(input);
+    }
+    upsample(&self.kernel, self.factor, &mut self.up_history, input, each);
+  }
+}
+
+/// How many samples an `Oversampler::new(factor)` holds its input back by.
+pub fn oversampling_latency(factor: usize) -> usize {
+  let factor = factor.max(1);
+  if factor == 1 { 0 } else { group_delay(TAPS_PER_PHASE * factor, factor) }
+}
+
+// half a kernel on the way up and half on the way down, at the oversampled rate, less the
+// `factor - 1` the down sampler gains by keeping the last of each group of oversampled values
+fn group_delay(taps: usize, factor: usize) -> usize {
+  (taps - factor) / factor
+}
+
+fn upsample<F: FnMut(f32)>(kernel: &[f32], factor: usize, history: &mut DelayLine, input: f32, mut each: F) {
+  history.push(input);
+  for phase in 0..factor {
+    // only every `factor`th tap lands on a non zero (un-stuffed) sample
+    let mut upsampled = 0.;
+    for (j, tap) in kernel.iter().skip(phase).step_by(factor).enumerate() {
+      upsampled += tap * history.read(j);
+    }
+    // make up for the energy lost to zero stuffing
+    each(upsampled * factor as f32);
+  }
+}
+
+// blackman windowed sinc with its cutoff just under the original nyquist, unity gain at DC
+fn low_pass_kernel(taps: usize, factor: usize) -> Vec<f32> {
+  let cutoff = 0.45 / factor as f32; // cycles per (oversampled) sample
+  let center = (taps - 1) as f32 / 2.;
+  let mut kernel: Vec<f32> = (0..taps)
+    .map(|i| {
+      let x = i as f32 - center;
+      let sinc = if x == 0. { 2. * cutoff } else { (2. * PI * cutoff * x).sin() / (PI * x) };
+      let phase = 2. * PI * i as f32 / (taps - 1) as f32;
+      let window = 0.42 - 0.5 * phase.cos() + 0.08 * (2. * phase).cos();
+      sinc * window
+    })
+    .collect();
+  let sum: f32 = kernel.iter().sum();
+  for tap in kernel.iter_mut() {
+    *tap /= sum;
+  }
+  kernel
+}
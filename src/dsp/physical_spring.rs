@@ -0,0 +1,349 @@
+use std::f64::consts::PI;
+
+use super::engine::Engine;
+
+/*
+Finite difference helical spring (after Bilbao & Parker)
+  - transverse displacement u and longitudinal displacement z along the spring's axis, scaled
+    to unit length, coupled through the coil curvature e:
+      u_tt = -k^2 (d_xx + e^2)^2 u + g^2 e (z_x - e u) - 2 s u_t
+      z_tt =  g^2 (z_xx - e u_x)                     - 2 s z_t
+  - k (stiffness) comes from the wire radius, e from length / coil radius, g (longitudinal wave
+    speed) from the coil's spring constant and mass, s from the decay time
+  - explicit scheme, simply supported ends. the grid is as fine as the time step allows
+    (stability), capped at `max_points`. tightly wound springs can need more than one step per
+    sample before even the coarsest grid is stable. past `MAX_STEPS_PER_SAMPLE` the spring's
+    waves are slowed down until the coarsest grid is, which lowers its pitch instead of blowing up
+  - the coupled operator has eigenvalues very close to zero, which f32 rounding can push negative
+    and slowly blow up, so the mesh runs in f64
+  - the input drives the transverse motion near one end, the output is read near the other
+  - building a mesh allocates and searches for the stable grid, so new settings are built into
+    `SpringMeshes` off the audio thread, by the IR worker, and the engine crossfades over to them
+*/
+
+// steel
+const YOUNGS_MODULUS: f64 = 2.0e11;
+const SHEAR_MODULUS: f64 = 7.9e10;
+const DENSITY: f64 = 7850.;
+// keep clear of the stability limit
+const STABILITY_MARGIN: f64 = 0.8;
+const MIN_POINTS: usize = 8;
+const MAX_STEPS_PER_SAMPLE: usize = 16;
+
+pub const REALTIME_MAX_POINTS: usize = 48;
+pub const OFFLINE_MAX_POINTS: usize = 400;
+pub const OFFLINE_OVERSAMPLING: usize = 4;
+
+const INPUT_POSITION: f64 = 0.13;
+const OUTPUT_POSITION: f64 = 0.81;
+
+/// Physical description of a single spring.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SpringPhysics {
+  pub wire_radius_mm: f32,
+  pub coil_radius_mm: f32,
+  pub length_cm: f32,
+  pub decay_seconds: f32,
+}
+
+impl Default for SpringPhysics {
+  fn default() -> Self {
+    Self {
+      wire_radius_mm: 0.2,
+      coil_radius_mm: 4.,
+      length_cm: 15.,
+      decay_seconds: 2.5,
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+struct Coefficients {
+  kappa: f64,
+  epsilon: f64,
+  gamma: f64,
+  sigma: f64,
+}
+
+impl SpringPhysics {
+  fn coefficients(&self) -> Coefficients {
+    let r = self.wire_radius_mm as f64 * 0.001;
+    let coil = self.coil_radius_mm as f64 * 0.001;
+    let length = self.length_cm as f64 * 0.01;
+
+    let kappa = r * (YOUNGS_MODULUS / DENSITY).sqrt() / (2. * length * length);
+    let epsilon = length / coil;
+    // coils wound two wire diameters apart
+    let turns = length / (4. * r);
+    let spring_constant = SHEAR_MODULUS * r.powi(4) / (4. * coil.powi(3) * turns);
+    let mass = DENSITY * PI * r * r * 2. * PI * coil * turns;
+    let gamma = (spring_constant / mass).sqrt();
+    let sigma = 3. * 10f64.ln() / self.decay_seconds as f64;
+    Coefficients { kappa, epsilon, gamma, sigma }
+  }
+}
+
+/// The discretized spring itself.
+#[derive(Clone)]
+pub struct SpringMesh {
+  points: usize, // intervals along the spring
+  steps_per_sample: usize,
+  k: f64, // time step
+  h: f64, // grid spacing
+  coefficients: Coefficients,
+  input_index: usize,
+  output_index: usize,
+  u: Vec<f64>,
+  u_prev: Vec<f64>,
+  u_next: Vec<f64>,
+  z: Vec<f64>,
+  z_prev: Vec<f64>,
+  z_next: Vec<f64>,
+  curvature: Vec<f64>, // scratch, d_xx u
+}
+
+impl SpringMesh {
+  pub fn new(physics: &SpringPhysics, sample_rate: f32, oversampling: usize, max_points: usize) -> Self {
+    let mut coefficients = physics.coefficients();
+    let mut steps_per_sample = oversampling.max(1);
+    let (points, k) = loop {
+      let k = 1. / (sample_rate as f64 * steps_per_sample as f64);
+      match stable_points(&coefficients, k, max_points) {
+        Some(points) => break (points, k),
+        None if steps_per_sample < MAX_STEPS_PER_SAMPLE => steps_per_sample += 1,
+        None => {
+          // too stiff to keep up with at any step we can afford. the highest frequency goes with
+          // the square of the wave speeds, so scale them back to bring it onto the limit
+          let scale = (4. * STABILITY_MARGIN / (k * k * highest_frequency(&coefficients, MIN_POINTS))).sqrt();
+          coefficients.kappa *= scale;
+          coefficients.gamma *= scale;
+          break (MIN_POINTS, k);
+        }
+      }
+    };
+    Self {
+      points,
+      steps_per_sample,
+      k,
+      h: 1. / points as f64,
+      input_index: ((points as f64 * INPUT_POSITION) as usize).max(1),
+      output_index: ((points as f64 * OUTPUT_POSITION) as usize).min(points - 1),
+      coefficients,
+      u: vec![0.; points + 1],
+      u_prev: vec![0.; points + 1],
+      u_next: vec![0.; points + 1],
+      z: vec![0.; points + 1],
+      z_prev: vec![0.; points + 1],
+      z_next: vec![0.; points + 1],
+      curvature: vec![0.; points + 1],
+    }
+  }
+
+  pub fn reset(&mut self) {
+    for state in [&mut self.u, &mut self.u_prev, &mut self.z, &mut self.z_prev] {
+      for value in state.iter_mut() {
+        *value = 0.;
+      }
+    }
+  }
+
+  pub fn process_sample(&mut self, input: f32) -> f32 {
+    let mut output = 0.;
+    for step in 0..self.steps_per_sample {
+      // the input lands on the first of the oversampled steps
+      self.step(if step == 0 { input as f64 } else { 0. });
+      output += (self.u[self.output_index] - self.u_prev[self.output_index]) / self.k;
+    }
+    (output / self.steps_per_sample as f64) as f32
+  }
+
+  fn step(&mut self, input: f64) {
+    let n = self.points;
+    let (k, h) = (self.k, self.h);
+    let Coefficients { kappa, epsilon, gamma, sigma } = self.coefficients;
+    let (e2, g2, k2) = (epsilon * epsilon, gamma * gamma, k * k);
+    let inv_h2 = 1. / (h * h);
+    let loss = sigma * k;
+
+    // simply supported ends: u and d_xx u are both zero there
+    for l in 1..n {
+      self.curvature[l] = (self.u[l + 1] - 2. * self.u[l] + self.u[l - 1]) * inv_h2;
+    }
+    self.curvature[0] = 0.;
+    self.curvature[n] = 0.;
+
+    for l in 1..n {
+      let w = &self.curvature;
+      let bending = (w[l + 1] - 2. * w[l] + w[l - 1]) * inv_h2 + 2. * e2 * w[l] + e2 * e2 * self.u[l];
+      let z_x = (self.z[l + 1] - self.z[l - 1]) / (2. * h);
+      let u_x = (self.u[l + 1] - self.u[l - 1]) / (2. * h);
+      let z_xx = (self.z[l + 1] - 2. * self.z[l] + self.z[l - 1]) * inv_h2;
+
+      let u_force = -kappa * kappa * bending + g2 * epsilon * (z_x - epsilon * self.u[l]);
+      let z_force = g2 * (z_xx - epsilon * u_x);
+      self.u_next[l] = (2. * self.u[l] - (1. - loss) * self.u_prev[l] + k2 * u_force) / (1. + loss);
+      self.z_next[l] = (2. * self.z[l] - (1. - loss) * self.z_prev[l] + k2 * z_force) / (1. + loss);
+    }
+    self.u_next[self.input_index] += k2 * input / h;
+
+    std::mem::swap(&mut self.u_prev, &mut self.u);
+    std::mem::swap(&mut self.u, &mut self.u_next);
+    std::mem::swap(&mut self.z_prev, &mut self.z);
+    std::mem::swap(&mut self.z, &mut self.z_next);
+  }
+}
+
+// the finest grid (up to `max_points`) whose highest frequency the time step can still carry
+fn stable_points(coefficients: &Coefficients, k: f64, max_points: usize) -> Option<usize> {
+  (MIN_POINTS..=max_points.max(MIN_POINTS))
+    .rev()
+    .find(|points| k * k * highest_frequency(coefficients, *points) <= 4. * STABILITY_MARGIN)
+}
+
+// bound on the squared angular frequency a grid can carry. it's the trace of the coupled operator
+// at the grid's highest wavenumber, which is positive semi definite so its largest eigenvalue can
+// not be any bigger
+fn highest_frequency(coefficients: &Coefficients, points: usize) -> f64 {
+  let Coefficients { kappa, epsilon, gamma, .. } = *coefficients;
+  let e2 = epsilon * epsilon;
+  let grid = 4. * (points * points) as f64;
+  let bending = (grid - e2).abs().max(e2);
+  kappa * kappa * bending * bending + gamma * gamma * (grid + e2)
+}
+
+/// Renders the spring's impulse response at high resolution, for the convolvers. The level is
+/// left as the mesh produces it.
+pub fn render_impulse_response(physics: &SpringPhysics, sample_rate: f32, seconds: f32) -> Vec<f32> {
+  let mut mesh = SpringMesh::new(physics, sample_rate, OFFLINE_OVERSAMPLING, OFFLINE_MAX_POINTS);
+  let length = (seconds * sample_rate) as usize;
+  (0..length)
+    .map(|i| mesh.process_sample(if i == 0 { 1. } else { 0. }))
+    .collect()
+}
+
+// brings the realtime mesh up to roughly the level of the other engines, for the default spring
+const REALTIME_GAIN: f32 = 100.;
+// the right spring is a little longer, so the two sides do not ring in lockstep
+const RIGHT_LENGTH_RATIO: f32 = 1.07;
+// how long a swap to new meshes is crossfaded for
+const MESH_FADE_SAMPLES: usize = 2048;
+
+/// A low resolution mesh per channel, built for one spring at one sample rate.
+#[derive(Clone)]
+pub struct SpringMeshes {
+  left: SpringMesh,
+  right: SpringMesh,
+}
+
+impl SpringPhysics {
+  /// Builds the realtime engine's meshes. Allocates, so it's meant for use off the audio thread.
+  pub fn build_realtime(&self, sample_rate: f32) -> SpringMeshes {
+    let right = SpringPhysics { length_cm: self.length_cm * RIGHT_LENGTH_RATIO, ..*self };
+    SpringMeshes {
+      left: SpringMesh::new(self, sample_rate, 1, REALTIME_MAX_POINTS),
+      right: SpringMesh::new(&right, sample_rate, 1, REALTIME_MAX_POINTS),
+    }
+  }
+}
+
+impl SpringMeshes {
+  fn reset(&mut self) {
+    self.left.reset();
+    self.right.reset();
+  }
+}
+
+// the outgoing meshes while a swap is being crossfaded
+struct MeshFade {
+  meshes: SpringMeshes,
+  remaining: usize,
+}
+
+/// Realtime engine: a low resolution mesh per channel.
+pub struct PhysicalSpring {
+  // kept up to date by the setters, for rebuilding at a new sample rate
+  physics: SpringPhysics,
+  meshes: SpringMeshes,
+  fade: Option<MeshFade>,
+  pending: Option<SpringMeshes>, // waiting for the current fade to finish
+}
+
+impl PhysicalSpring {
+  pub fn new(sample_rate: f32) -> Self {
+    let physics = SpringPhysics::default();
+    Self {
+      physics,
+      meshes: physics.build_realtime(sample_rate),
+      fade: None,
+      pending: None,
+    }
+  }
+
+  /// The setters only note the new spring. The meshes to match come separately, from the IR
+  /// worker.
+  pub fn set_wire_radius_mm(&mut self, wire_radius_mm: f32) {
+    self.physics.wire_radius_mm = wire_radius_mm;
+  }
+
+  pub fn set_coil_radius_mm(&mut self, coil_radius_mm: f32) {
+    self.physics.coil_radius_mm = coil_radius_mm;
+  }
+
+  pub fn set_length_cm(&mut self, length_cm: f32) {
+    self.physics.length_cm = length_cm;
+  }
+
+  pub fn set_decay_seconds(&mut self, decay_seconds: f32) {
+    self.physics.decay_seconds = decay_seconds;
+  }
+
+  /// Crossfades to new meshes. The new ones start out still and pick up the input as the old ones
+  /// fade. Only one fade at a time, the latest meshes wait their turn.
+  pub fn set_meshes(&mut self, meshes: SpringMeshes) {
+    if self.fade.is_some() {
+      self.pending = Some(meshes);
+      return;
+    }
+    let outgoing = std::mem::replace(&mut self.meshes, meshes);
+    self.fade = Some(MeshFade { meshes: outgoing, remaining: MESH_FADE_SAMPLES });
+  }
+}
+
+impl Engine for PhysicalSpring {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.meshes = self.physics.build_realtime(sample_rate);
+    self.fade = None;
+    self.pending = None;
+  }
+
+  fn reset(&mut self) {
+    if let Some(meshes) = self.pending.take() {
+      self.meshes = meshes;
+    }
+    self.meshes.reset();
+    self.fade = None;
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    for i in 0..input_l.len() {
+      output_l[i] = self.meshes.left.process_sample(input_l[i]) * REALTIME_GAIN;
+      output_r[i] = self.meshes.right.process_sample(input_r[i]) * REALTIME_GAIN;
+
+      // crossfade from the outgoing meshes
+      if let Some(fade) = &mut self.fade {
+        let old_gain = fade.remaining as f32 / MESH_FADE_SAMPLES as f32;
+        let old_l = fade.meshes.left.process_sample(input_l[i]) * REALTIME_GAIN;
+        let old_r = fade.meshes.right.process_sample(input_r[i]) * REALTIME_GAIN;
+        output_l[i] = output_l[i] * (1. - old_gain) + old_l * old_gain;
+        output_r[i] = output_r[i] * (1. - old_gain) + old_r * old_gain;
+        fade.remaining -= 1;
+        if fade.remaining == 0 {
+          self.fade = None;
+          if let Some(meshes) = self.pending.take() {
+            self.set_meshes(meshes);
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,60 @@
+use std::f32::consts::PI;
+
+use super::delay_line::DelayLine;
+
+/*
+Delay line pitch shifter
+  - two read taps sweep through a short window of the delay line, half a window apart
+  - sweeping at (1 - ratio) samples per sample resamples the signal by `ratio`
+  - each tap is faded with a hann window so the wrap around, where the tap jumps back across the
+    window, is silent while the other tap is at full level
+*/
+
+const WINDOW_MS: f32 = 50.;
+
+pub struct PitchShifter {
+  delay: DelayLine,
+  window_len: f32,
+  phase: f32, // 0..1 through the window, the second tap is half a window ahead
+  phase_step: f32,
+  ratio: f32,
+}
+
+impl PitchShifter {
+  pub fn new(sample_rate: f32) -> Self {
+    let window_len = (WINDOW_MS * 0.001 * sample_rate).round();
+    let mut shifter = Self {
+      delay: DelayLine::new(window_len as usize + 2),
+      window_len,
+      phase: 0.,
+      phase_step: 0.,
+      ratio: 1.,
+    };
+    shifter.set_semitones(0.);
+    shifter
+  }
+
+  pub fn set_semitones(&mut self, semitones: f32) {
+    self.ratio = 2f32.powf(semitones / 12.);
+    self.phase_step = (1. - self.ratio) / self.window_len;
+  }
+
+  pub fn reset(&mut self) {
+    self.delay.clear();
+    self.phase = 0.;
+  }
+
+  pub fn process(&mut self, input: f32) -> f32 {
+    self.delay.push(input);
+
+    let mut output = 0.;
+    for offset in [0., 0.5] {
+      let phase = (self.phase + offset).fract();
+      let gain = 0.5 - 0.5 * (2. * PI * phase).cos();
+      output += gain * self.delay.read_linear(phase * self.window_len);
+    }
+
+    self.phase = (self.phase + self.phase_step).rem_euclid(1.);
+    output
+  }
+}
@@ -0,0 +1,312 @@
+use std::f32::consts::TAU;
+use super::delay_line::DelayLine;
+use super::engine::Engine;
+
+/*
+Plate (after Dattorro, "Effect Design Part 1", 1997)
+  - mono input, band limited by a one pole low pass ("bandwidth")
+  - four allpasses in series diffuse the input into a wash before it reaches the tank
+  - the tank is two halves feeding each other in a figure eight, each half being
+      modulated allpass -> delay -> damping low pass -> decay -> allpass -> delay -> decay
+    the modulated allpasses wobble by a few samples ("excursion") so the tail doesn't ring
+  - stereo output is a sum of taps scattered around both halves of the tank
+  - every length and tap in the paper is for a 29761Hz sample rate, so they get scaled
+*/
+
+const REFERENCE_RATE: f32 = 29761.;
+const INPUT_DIFFUSER_LENGTHS: [usize; 4] = [142, 107, 379, 277];
+const MAX_EXCURSION: f32 = 32.;
+const DECAY_DIFFUSION_1: f32 = 0.7;
+const OUTPUT_GAIN: f32 = 0.6;
+// the two halves wobble out of step
+const RIGHT_LFO_PHASE: f32 = 0.25;
+
+// lengths of one half of the tank
+struct HalfLengths {
+  modulated_allpass: usize,
+  first_delay: usize,
+  allpass: usize,
+  second_delay: usize,
+}
+
+const LEFT_LENGTHS: HalfLengths = HalfLengths { modulated_allpass: 672, first_delay: 4453, allpass: 1800, second_delay: 3720 };
+const RIGHT_LENGTHS: HalfLengths = HalfLengths { modulated_allpass: 908, first_delay: 4217, allpass: 2656, second_delay: 3163 };
+
+// where in the tank an output tap reads from
+#[derive(Clone, Copy)]
+enum Tap {
+  LeftFirstDelay,
+  LeftAllpass,
+  LeftSecondDelay,
+  RightFirstDelay,
+  RightAllpass,
+  RightSecondDelay,
+}
+
+// output taps, as (where, offset, sign)
+const LEFT_TAPS: [(Tap, usize, f32); 7] = [
+  (Tap::RightFirstDelay, 266, 1.),
+  (Tap::RightFirstDelay, 2974, 1.),
+  (Tap::RightAllpass, 1913, -1.),
+  (Tap::RightSecondDelay, 1996, 1.),
+  (Tap::LeftFirstDelay, 1990, -1.),
+  (Tap::LeftAllpass, 187, -1.),
+  (Tap::LeftSecondDelay, 1066, -1.),
+];
+
+const RIGHT_TAPS: [(Tap, usize, f32); 7] = [
+  (Tap::LeftFirstDelay, 353, 1.),
+  (Tap::LeftFirstDelay, 3627, 1.),
+  (Tap::LeftAllpass, 1228, -1.),
+  (Tap::LeftSecondDelay, 2673, 1.),
+  (Tap::RightFirstDelay, 2111, -1.),
+  (Tap::RightAllpass, 335, -1.),
+  (Tap::RightSecondDelay, 121, -1.),
+];
+
+/// Schroeder allpass, with its internal line readable for output taps.
+struct Allpass {
+  line: DelayLine,
+  length: f32,
+}
+
+impl Allpass {
+  fn new(length: f32, max_excursion: f32) -> Self {
+    Self {
+      line: DelayLine::new((length + max_excursion) as usize + 2),
+      length: length.max(1.),
+    }
+  }
+
+  fn clear(&mut self) {
+    self.line.clear();
+  }
+
+  fn process(&mut self, input: f32, coef: f32, excursion: f32) -> f32 {
+    let delayed = self.line.read_linear(self.length - 1. + excursion);
+    let w = input + coef * delayed;
+    self.line.push(w);
+    delayed - coef * w
+  }
+}
+
+struct Delay {
+  line: DelayLine,
+  length: usize,
+}
+
+impl Delay {
+  fn new(length: usize) -> Self {
+    Self {
+      line: DelayLine::new(length),
+      length: length.max(1),
+    }
+  }
+
+  fn output(&self) -> f32 {
+    self.line.read(self.length - 1)
+  }
+}
+
+struct TankHalf {
+  modulated_allpass: Allpass,
+  first_delay: Delay,
+  damping_state: f32,
+  allpass: Allpass,
+  second_delay: Delay,
+  lfo_phase: f32,
+}
+
+impl TankHalf {
+  fn new(lengths: &HalfLengths, scale: f32, lfo_phase: f32) -> Self {
+    let scaled = |length: usize| length as f32 * scale;
+    Self {
+      modulated_allpass: Allpass::new(scaled(lengths.modulated_allpass), MAX_EXCURSION * 2. * scale),
+      first_delay: Delay::new(scaled(lengths.first_delay) as usize),
+      damping_state: 0.,
+      allpass: Allpass::new(scaled(lengths.allpass), 0.),
+      second_delay: Delay::new(scaled(lengths.second_delay) as usize),
+      lfo_phase,
+    }
+  }
+
+  // silences the half in place, back to where `new` starts it
+  fn clear(&mut self, lfo_phase: f32) {
+    self.modulated_allpass.clear();
+    self.first_delay.line.clear();
+    self.damping_state = 0.;
+    self.allpass.clear();
+    self.second_delay.line.clear();
+    self.lfo_phase = lfo_phase;
+  }
+
+  // what this half hands to the other one
+  fn output(&self, decay: f32) -> f32 {
+    self.second_delay.output() * decay
+  }
+
+  fn process(&mut self, input: f32, plate: &PlateSettings) {
+    let excursion = plate.excursion * (1. + (TAU * self.lfo_phase).sin());
+    self.lfo_phase = (self.lfo_phase + plate.lfo_step).fract();
+    // the paper flips the sign on this one
+    let diffused = self.modulated_allpass.process(input, -DECAY_DIFFUSION_1, excursion);
+
+    let delayed = self.first_delay.output();
+    self.first_delay.line.push(diffused);
+    self.damping_state = delayed * (1. - plate.damping) + self.damping_state * plate.damping;
+
+    let diffused = self.allpass.process(self.damping_state * plate.decay, plate.decay_diffusion_2, 0.);
+    self.second_delay.line.push(diffused);
+  }
+}
+
+// per sample coefficients, derived from the parameters
+struct PlateSettings {
+  decay: f32,
+  damping: f32,
+  decay_diffusion_2: f32,
+  excursion: f32, // samples
+  lfo_step: f32,
+}
+
+/// Dattorro style plate, with bandwidth, input diffusion, decay, damping and modulation.
+pub struct Plate {
+  sample_rate: f32,
+  bandwidth: f32, // 0..1, 1 lets everything through
+  input_diffusion: f32, // 0..1, scales the paper's diffusion coefficients
+  decay: f32, // 0..1
+  damping: f32, // 0..1
+  excursion: f32, // samples, at the reference rate
+  mod_rate: f32, // Hz
+  bandwidth_state: f32,
+  input_diffusers: Vec<Allpass>,
+  left: TankHalf,
+  right: TankHalf,
+}
+
+impl Plate {
+  pub fn new(sample_rate: f32) -> Self {
+    let scale = sample_rate / REFERENCE_RATE;
+    Self {
+      sample_rate,
+      bandwidth: 1.,
+      input_diffusion: 1.,
+      decay: 0.5,
+      damping: 0.1,
+      excursion: 16.,
+      mod_rate: 1.,
+      bandwidth_state: 0.,
+      input_diffusers: INPUT_DIFFUSER_LENGTHS
+        .iter()
+        .map(|length| Allpass::new(*length as f32 * scale, 0.))
+        .collect(),
+      left: TankHalf::new(&LEFT_LENGTHS, scale, 0.),
+      right: TankHalf::new(&RIGHT_LENGTHS, scale, RIGHT_LFO_PHASE),
+    }
+  }
+
+  pub fn set_bandwidth(&mut self, bandwidth: f32) {
+    self.bandwidth = bandwidth.clamp(0., 1.);
+  }
+
+  pub fn set_input_diffusion(&mut self, diffusion: f32) {
+    self.input_diffusion = diffusion.clamp(0., 1.);
+  }
+
+  pub fn set_decay(&mut self, decay: f32) {
+    self.decay = decay.clamp(0., 0.9999);
+  }
+
+  /// Sets the decay from how long the tank should take to die away by 60dB, before damping.
+  pub fn set_rt60(&mut self, seconds: f32) {
+    let half = |lengths: &HalfLengths| lengths.modulated_allpass + lengths.first_delay + lengths.allpass + lengths.second_delay;
+    let loop_seconds = (half(&LEFT_LENGTHS) + half(&RIGHT_LENGTHS)) as f32 / REFERENCE_RATE;
+    // a trip round the figure eight takes the decay four times, twice in each half
+    self.set_decay(10f32.powf(-3. * loop_seconds / (4. * seconds.max(0.01))));
+  }
+
+  pub fn set_damping(&mut self, damping: f32) {
+    self.damping = damping.clamp(0., 1.);
+  }
+
+  pub fn set_excursion(&mut self, samples: f32) {
+    self.excursion = samples.clamp(0., MAX_EXCURSION);
+  }
+
+  pub fn set_mod_rate(&mut self, hz: f32) {
+    self.mod_rate = hz;
+  }
+
+  // fresh, silent tank for the sample rate, keeping the settings
+  fn rebuild(&mut self, sample_rate: f32) {
+    let mut plate = Plate::new(sample_rate);
+    plate.bandwidth = self.bandwidth;
+    plate.input_diffusion = self.input_diffusion;
+    plate.decay = self.decay;
+    plate.damping = self.damping;
+    plate.excursion = self.excursion;
+    plate.mod_rate = self.mod_rate;
+    *self = plate;
+  }
+
+  fn tap(&self, tap: Tap, offset: usize) -> f32 {
+    let scale = self.sample_rate / REFERENCE_RATE;
+    let offset = (offset as f32 * scale) as usize;
+    match tap {
+      Tap::LeftFirstDelay => self.left.first_delay.line.read(offset),
+      Tap::LeftAllpass => self.left.allpass.line.read(offset),
+      Tap::LeftSecondDelay => self.left.second_delay.line.read(offset),
+      Tap::RightFirstDelay => self.right.first_delay.line.read(offset),
+      Tap::RightAllpass => self.right.allpass.line.read(offset),
+      Tap::RightSecondDelay => self.right.second_delay.line.read(offset),
+    }
+  }
+}
+
+impl Engine for Plate {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.rebuild(sample_rate);
+  }
+
+  fn reset(&mut self) {
+    // the lines are already the right length, so clear them rather than allocate new ones
+    self.bandwidth_state = 0.;
+    for diffuser in self.input_diffusers.iter_mut() {
+      diffuser.clear();
+    }
+    self.left.clear(0.);
+    self.right.clear(RIGHT_LFO_PHASE);
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    let settings = PlateSettings {
+      decay: self.decay,
+      damping: self.damping,
+      decay_diffusion_2: (self.decay + 0.15).clamp(0.25, 0.5),
+      excursion: self.excursion * self.sample_rate / REFERENCE_RATE,
+      lfo_step: self.mod_rate / self.sample_rate,
+    };
+    let diffusion = [0.75, 0.75, 0.625, 0.625].map(|coef| coef * self.input_diffusion);
+
+    for i in 0..input_l.len() {
+      let input = 0.5 * (input_l[i] + input_r[i]);
+      self.bandwidth_state = input * self.bandwidth + self.bandwidth_state * (1. - self.bandwidth);
+      let mut diffused = self.bandwidth_state;
+      for (diffuser, coef) in self.input_diffusers.iter_mut().zip(diffusion) {
+        diffused = diffuser.process(diffused, coef, 0.);
+      }
+
+      // each half is fed by where the other one ended up last sample
+      let from_left = self.left.output(settings.decay);
+      let from_right = self.right.output(settings.decay);
+      self.left.process(diffused + from_right, &settings);
+      self.right.process(diffused + from_left, &settings);
+
+      let sum_taps = |taps: &[(Tap, usize, f32)]| {
+        taps.iter().map(|(tap, offset, sign)| sign * self.tap(*tap, *offset)).sum::<f32>()
+      };
+      output_l[i] = sum_taps(&LEFT_TAPS) * OUTPUT_GAIN;
+      output_r[i] = sum_taps(&RIGHT_TAPS) * OUTPUT_GAIN;
+    }
+  }
+}
@@ -0,0 +1,56 @@
+use super::delay_line::DelayLine;
+use super::smoother::{Ramp, Smoother};
+
+// a whole note at 20bpm, about as slow as hosts go, so synced times don't get cut short
+const MAX_PRE_DELAY_MS: f32 = 12000.;
+// how long the delay glides to a new time for, so tempo changes do not click
+const GLIDE_MS: f32 = 250.;
+
+/// Stereo delay in front of the reverb. Changes in delay time glide rather than jump.
+pub struct PreDelay {
+  sample_rate: f32,
+  delay: Smoother, // samples
+  delay_l: DelayLine,
+  delay_r: DelayLine,
+}
+
+impl PreDelay {
+  pub fn new(sample_rate: f32) -> Self {
+    let max_delay = (MAX_PRE_DELAY_MS * 0.001 * sample_rate) as usize + 2;
+    Self {
+      sample_rate,
+      delay: delay_smoother(0., sample_rate),
+      delay_l: DelayLine::new(max_delay),
+      delay_r: DelayLine::new(max_delay),
+    }
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    let ms = self.delay.target() / (0.001 * self.sample_rate);
+    *self = Self::new(sample_rate);
+    self.delay = delay_smoother(ms * 0.001 * sample_rate, sample_rate);
+  }
+
+  pub fn set_delay_ms(&mut self, ms: f32) {
+    let target = ms.clamp(0., MAX_PRE_DELAY_MS) * 0.001 * self.sample_rate;
+    if target != self.delay.target() {
+      self.delay.set_target(target);
+    }
+  }
+
+  pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) {
+    for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+      let delay = self.delay.next_value();
+      self.delay_l.push(*l);
+      self.delay_r.push(*r);
+      *l = self.delay_l.read_linear(delay);
+      *r = self.delay_r.read_linear(delay);
+    }
+  }
+}
+
+fn delay_smoother(samples: f32, sample_rate: f32) -> Smoother {
+  let mut delay = Smoother::new(Ramp::Exponential, samples, sample_rate);
+  delay.set_ramp_ms(GLIDE_MS);
+  delay
+}
@@ -0,0 +1,112 @@
+use std::f32::consts::PI;
+
+use super::delay_line::DelayLine;
+use super::ms_to_samples;
+
+/*
+Reverse reverb
+  - the spring convolvers play the first `length` of the IR backwards, so the sound swells up out
+    of the tail and stops dead on the part that would normally come first
+  - the cut into the tail becomes the very start of the reversed IR, so it's faded in rather than
+    starting on a step
+  - reverse: the swell follows the sound that caused it, peaking `length` later, the classic
+    pre-echo played after the fact
+  - true reverse: the plugin reports the longest reverse length as latency, and everything else
+    it plays is held back by it. shorter lengths are padded out with a delay ahead of the spring
+    convolvers, so the latency only moves when the mode does, and once the host compensates the
+    swell builds into the transient and peaks right on it
+*/
+
+// the fade in over the cut, unless the IR is so short it would take up more than a quarter of it
+const FADE_IN_MS: f32 = 20.;
+/// The longest stretch that can be reversed.
+pub const MAX_REVERSE_MS: f32 = 2000.;
+
+/// Must line up with `plugin_state::REVERSE_OPTIONS`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReverseMode {
+  Off,
+  Reverse,
+  TrueReverse,
+}
+
+impl ReverseMode {
+  pub fn from_index(index: usize) -> Self {
+    match index {
+      0 => ReverseMode::Off,
+      1 => ReverseMode::Reverse,
+      _ => ReverseMode::TrueReverse,
+    }
+  }
+}
+
+/// How many samples of the IR are played backwards.
+pub fn reverse_length(length_ms: f32, sample_rate: f32) -> usize {
+  ms_to_samples(length_ms, sample_rate).max(1)
+}
+
+/// How far behind the sound that caused it the swell peaks, and so the latency in true reverse.
+pub fn reverse_latency(length_ms: f32, sample_rate: f32) -> usize {
+  reverse_length(length_ms, sample_rate) - 1
+}
+
+/// The latency in true reverse, whatever the reverse length.
+pub fn true_reverse_latency(sample_rate: f32) -> usize {
+  reverse_latency(MAX_REVERSE_MS, sample_rate)
+}
+
+/// The first `length_ms` of `impulse_response` backwards, padded out with silence when the IR is
+/// shorter so the swell always peaks on `reverse_latency`.
+pub fn reverse_impulse_response(impulse_response: &[f32], length_ms: f32, sample_rate: f32) -> Vec<f32> {
+  let length = reverse_length(length_ms, sample_rate);
+  let mut reversed: Vec<f32> = (0..length)
+    .rev()
+    .map(|i| impulse_response.get(i).copied().unwrap_or(0.))
+    .collect();
+  let fade = ms_to_samples(FADE_IN_MS, sample_rate).min(length / 4);
+  for (i, sample) in reversed.iter_mut().take(fade).enumerate() {
+    *sample *= 0.5 - 0.5 * (PI * i as f32 / fade as f32).cos();
+  }
+  reversed
+}
+
+/// Holds signals back by a whole number of samples, for everything that has to line up with a
+/// delayed path. Room is made up front, so moving the delay never allocates.
+pub struct Lookahead {
+  delay: usize,
+  lines: Vec<DelayLine>,
+}
+
+impl Lookahead {
+  pub fn new(channels: usize) -> Self {
+    Self {
+      delay: 0,
+      lines: (0..channels).map(|_| DelayLine::new(0)).collect(),
+    }
+  }
+
+  pub fn delay(&self) -> usize {
+    self.delay
+  }
+
+  /// Makes room for delays up to `max_delay`. Allocates, so not for the audio thread.
+  pub fn set_max_delay(&mut self, max_delay: usize) {
+    for line in self.lines.iter_mut() {
+      line.resize(max_delay);
+    }
+    self.delay = self.delay.min(max_delay);
+  }
+
+  // the lines keep running, so the held back audio just jumps to the new delay
+  pub fn set_delay(&mut self, delay: usize) {
+    self.delay = delay;
+  }
+
+  /// Holds back one channel's block in place.
+  pub fn process(&mut self, channel: usize, buffer: &mut [f32]) {
+    let line = &mut self.lines[channel];
+    for sample in buffer.iter_mut() {
+      *sample = line.process(*sample, self.delay);
+    }
+  }
+}
@@ -0,0 +1,141 @@
+use super::delay_line::DelayLine;
+use super::filter::{DcBlocker, OnePoleLowPass};
+use super::pitch_shift::PitchShifter;
+use super::smoother::{Ramp, Smoother};
+
+/*
+Shimmer feedback loop
+  - the wet output is pitch shifted, damped, and fed back into the convolver input
+  - the loop runs through a fixed length delay, so the feedback delay does not depend on the host
+    block size. a block can only take feedback that was fed at least a loop earlier, so blocks
+    can't be longer than the loop. the DSP renders `CONTROL_BLOCK` samples at a time, well short
+    of it
+  - a soft clip in the loop keeps high feedback from running away
+*/
+
+const LOOP_DELAY: usize = 4096;
+
+struct ShimmerChannel {
+  shifter: PitchShifter,
+  damping: OnePoleLowPass,
+  dc_blocker: DcBlocker,
+  // the loop's output so far, read back `LOOP_DELAY` samples after it went in
+  loop_line: DelayLine,
+}
+
+impl ShimmerChannel {
+  fn new(sample_rate: f32, damping: f32) -> Self {
+    Self {
+      shifter: PitchShifter::new(sample_rate),
+      damping: OnePoleLowPass::new(damping, sample_rate),
+      dc_blocker: DcBlocker::new(),
+      loop_line: DelayLine::new(LOOP_DELAY),
+    }
+  }
+
+  fn reset(&mut self) {
+    self.shifter.reset();
+    self.damping.reset();
+    self.loop_line.clear();
+  }
+
+  fn add_feedback(&mut self, excitation: &mut [f32]) {
+    debug_assert!(excitation.len() <= LOOP_DELAY, "shimmer blocks can't be longer than its loop");
+    // nothing from this block has been fed yet, so the newest in the line is a sample back
+    for (i, sample) in excitation.iter_mut().enumerate() {
+      *sample += self.loop_line.read(LOOP_DELAY.saturating_sub(i + 1));
+    }
+  }
+
+  fn feed(&mut self, wet: &[f32], feedback: &[f32]) {
+    for (sample, feedback) in wet.iter().zip(feedback) {
+      let shifted = self.shifter.process(*sample);
+      let damped = self.damping.process(self.dc_blocker.process(shifted));
+      self.loop_line.push((damped * feedback).tanh());
+    }
+  }
+}
+
+/// Stereo pitch shifted feedback around the convolvers.
+pub struct Shimmer {
+  enabled: bool,
+  semitones: f32,
+  feedback: Smoother,
+  // per sample feedback for the current block, shared by both sides
+  feedback_ramp: Vec<f32>,
+  damping: f32,
+  sample_rate: f32,
+  left: ShimmerChannel,
+  right: ShimmerChannel,
+}
+
+impl Shimmer {
+  pub fn new(sample_rate: f32) -> Self {
+    let damping = 6000.;
+    Self {
+      enabled: false,
+      semitones: 12.,
+      feedback: Smoother::new(Ramp::Linear, 0.5, sample_rate),
+      feedback_ramp: Vec::new(),
+      damping,
+      sample_rate,
+      left: ShimmerChannel::new(sample_rate, damping),
+      right: ShimmerChannel::new(sample_rate, damping),
+    }
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.feedback.set_sample_rate(sample_rate);
+    self.left = ShimmerChannel::new(sample_rate, self.damping);
+    self.right = ShimmerChannel::new(sample_rate, self.damping);
+    self.set_semitones(self.semitones);
+  }
+
+  pub fn set_enabled(&mut self, enabled: bool) {
+    if enabled && !self.enabled {
+      self.left.reset();
+      self.right.reset();
+      self.feedback.reset(self.feedback.target());
+    }
+    self.enabled = enabled;
+  }
+
+  pub fn set_semitones(&mut self, semitones: f32) {
+    self.semitones = semitones;
+    self.left.shifter.set_semitones(semitones);
+    self.right.shifter.set_semitones(semitones);
+  }
+
+  pub fn set_feedback(&mut self, feedback: f32) {
+    self.feedback.set_target(feedback);
+  }
+
+  pub fn set_damping(&mut self, cutoff: f32) {
+    self.damping = cutoff;
+    self.left.damping.set_cutoff(cutoff, self.sample_rate);
+    self.right.damping.set_cutoff(cutoff, self.sample_rate);
+  }
+
+  // mixes the loop's output into the signal headed for the convolvers
+  pub fn add_feedback(&mut self, excite_l: &mut [f32], excite_r: &mut [f32]) {
+    if !self.enabled {
+      return;
+    }
+    self.left.add_feedback(excite_l);
+    self.right.add_feedback(excite_r);
+  }
+
+  // takes the convolvers' wet output into the loop
+  pub fn feed(&mut self, wet_l: &[f32], wet_r: &[f32]) {
+    if !self.enabled {
+      return;
+    }
+    self.feedback_ramp.clear();
+    for _ in 0..wet_l.len() {
+      self.feedback_ramp.push(self.feedback.next_value());
+    }
+    self.left.feed(wet_l, &self.feedback_ramp);
+    self.right.feed(wet_r, &self.feedback_ramp);
+  }
+}
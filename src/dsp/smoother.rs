@@ -0,0 +1,133 @@
+/*
+Parameter smoothing
+  - a new value is never jumped to, it's ramped towards over a fixed time so nothing zippers
+  - linear: equal steps, for mixes and anything already in dB
+  - exponential: each step closes the same share of the gap, fast at first and easing in, like a
+    one pole low pass on the value. good for delay times, where it keeps pitch bends gentle
+  - multiplicative: equal ratios, for gains and frequencies, so a sweep spends as long on each
+    octave or dB. needs both ends above zero, and falls back to linear when they're not
+  - every ramp lands on the target exactly when its time is up
+  - `next_value` moves on a sample, for values used inside per sample loops. `advance` jumps a whole
+    stretch in one go, for values applied once per sub-block
+*/
+
+const DEFAULT_RAMP_MS: f32 = 20.;
+// an exponential ramp gets this many time constants, leaving under 1% of the gap for the snap
+const EXPONENTIAL_TIME_CONSTANTS: f32 = 5.;
+
+/// The shape of a ramp from one value to the next.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Ramp {
+  Linear,
+  Exponential,
+  Multiplicative,
+}
+
+/// Glides a value towards its latest target.
+pub struct Smoother {
+  ramp: Ramp,
+  ramp_ms: f32,
+  sample_rate: f32,
+  ramp_samples: usize,
+  current: f32,
+  target: f32,
+  // the shape of the ramp under way, which can differ from `ramp` after a fall back
+  running: Ramp,
+  // added (linear), multiplied by (multiplicative) or the gap is scaled by (exponential), each sample
+  step: f32,
+  remaining: usize,
+}
+
+impl Smoother {
+  pub fn new(ramp: Ramp, value: f32, sample_rate: f32) -> Self {
+    let mut smoother = Self {
+      ramp,
+      ramp_ms: DEFAULT_RAMP_MS,
+      sample_rate,
+      ramp_samples: 0,
+      current: value,
+      target: value,
+      running: ramp,
+      step: 0.,
+      remaining: 0,
+    };
+    smoother.update_ramp_samples();
+    smoother
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.update_ramp_samples();
+  }
+
+  pub fn set_ramp_ms(&mut self, ramp_ms: f32) {
+    self.ramp_ms = ramp_ms.max(0.);
+    self.update_ramp_samples();
+  }
+
+  fn update_ramp_samples(&mut self) {
+    self.ramp_samples = (self.ramp_ms * 0.001 * self.sample_rate).round() as usize;
+  }
+
+  /// Jumps straight to a value, for when there's nothing to glide from.
+  pub fn reset(&mut self, value: f32) {
+    self.current = value;
+    self.target = value;
+    self.remaining = 0;
+  }
+
+  /// Starts a ramp from wherever the value is now.
+  pub fn set_target(&mut self, target: f32) {
+    self.target = target;
+    if self.ramp_samples == 0 || target == self.current {
+      self.reset(target);
+      return;
+    }
+    self.remaining = self.ramp_samples;
+    let samples = self.ramp_samples as f32;
+    self.running = match self.ramp {
+      Ramp::Multiplicative if self.current <= 0. || target <= 0. => Ramp::Linear,
+      ramp => ramp,
+    };
+    self.step = match self.running {
+      Ramp::Linear => (target - self.current) / samples,
+      Ramp::Exponential => (-EXPONENTIAL_TIME_CONSTANTS / samples).exp(),
+      Ramp::Multiplicative => (target / self.current).powf(1. / samples),
+    };
+  }
+
+  pub fn value(&self) -> f32 {
+    self.current
+  }
+
+  pub fn target(&self) -> f32 {
+    self.target
+  }
+
+  pub fn is_smoothing(&self) -> bool {
+    self.remaining > 0
+  }
+
+  /// Moves on one sample, returning the new value.
+  pub fn next_value(&mut self) -> f32 {
+    self.advance(1)
+  }
+
+  /// Moves on `samples` samples at once, returning the value at the end.
+  pub fn advance(&mut self, samples: usize) -> f32 {
+    if self.remaining == 0 {
+      return self.current;
+    }
+    if samples >= self.remaining {
+      self.reset(self.target);
+      return self.current;
+    }
+    self.remaining -= samples;
+    self.current = match self.running {
+      Ramp::Linear => self.current + self.step * samples as f32,
+      Ramp::Exponential => self.target + (self.current - self.target) * self.step.powi(samples as i32),
+      Ramp::Multiplicative => self.current * self.step.powi(samples as i32),
+    };
+    self.current
+  }
+}
@@ -1 +1,2 @@
+#[allow(clippy::excessive_precision)]
 pub const SPRING_IMPULSE_RESPONSE: &[f32] = &[-0.0047913,0.0018311,0.0071106,0.0112305,0.0136108,0.0156860,0.0159302,0.0156860,0.0145264,0.0138550,0.0131836,0.0132141,0.0142517,0.0167847,0.0204773,0.0245667,0.0295715,0.0338440,0.0383911,0.0415344,0.0433350,0.0439148,0.0422974,0.0392456,0.0341492,0.0276489,0.0200195,0.0111694,0.0014648,-0.0094910,-0.0213013,-0.0326538,-0.0432129,-0.0519104,-0.0584412,-0.0625916,-0.0631409,-0.0614014,-0.0567932,-0.0497437,-0.0406799,-0.0307312,-0.0202637,-0.0098877,0.0002441,0.0089722,0.0165710,0.0222168,0.0264282,0.0275879,0.0271606,0.0248108,0.0217590,0.0170288,0.0111389,0.0044250,-0.0028381,-0.0103455,-0.0187378,-0.0265198,-0.0331116,-0.0391235,-0.0441284,-0.0482178,-0.0508118,-0.0529175,-0.0543213,-0.0558777,-0.0566711,-0.0572205,-0.0573120,-0.0563049,-0.0545349,-0.0517578,-0.0489197,-0.0451050,-0.0411377,-0.0365295,-0.0320129,-0.0269165,-0.0222168,-0.0175781,-0.0143433,-0.0109253,-0.0083008,-0.0065002,-0.0050354,-0.0043030,-0.0040588,-0.0047607,-0.0060120,-0.0076904,-0.0097961,-0.0126648,-0.0152893,-0.0177002,-0.0198059,-0.0213318,-0.0227051,-0.0231018,-0.0235596,-0.0236206,-0.0242615,-0.0252991,-0.0265198,-0.0288086,-0.0310974,-0.0337219,-0.0361023,-0.0380249,-0.0397339,-0.0398865,-0.0395508,-0.0377502,-0.0351562,-0.0310364,-0.0259705,-0.0200195,-0.0135803,-0.0065002,0.0005188,0.0068665,0.0130005,0.0189514,0.0249023,0.0307922,0.0372009,0.0442505,0.0516968,0.0593872,0.0670166,0.0747986,0.0816345,0.0874329,0.0913696,0.0945129,0.0960999,0.0963440,0.0956421,0.0939331,0.0917969,0.0885620,0.0841064,0.0789795,0.0726624,0.0653076,0.0564575,0.0470581,0.0373535,0.0274963,0.0185547,0.0101929,0.0037537,-0.0012207,-0.0045471,-0.0069580,-0.0079956,-0.0081177,-0.0075073,-0.0070496,-0.0062256,-0.0057068,-0.0056763,-0.0064392,-0.0080566,-0.0099182,-0.0136108,-0.0178528,-0.0228577,-0.0273438,-0.0311584,-0.0342712,-0.0349731,-0.0334473,-0.0292664,-0.0229492,-0.0140686,-0.0032043,0.0081787,0.0195312,0.0295105,0.0383911,0.0447693,0.0481262,0.0488892,0.0467834,0.0422363,0.0353699,0.0270386,0.0177612,0.0089722,0.0003967,-0.0074768,-0.0133972,-0.0180054,-0.0201416,-0.0209045,-0.0198364,-0.0169983,-0.0129395,-0.0085754,-0.0038147,0.0013123,0.0054626,0.0083313,0.0091553,0.0083313,0.0050049,-0.0006409,-0.0088806,-0.0183716,-0.0292664,-0.0409241,-0.0526428,-0.0631714,-0.0717468,-0.0783081,-0.0822144,-0.0825806,-0.0798035,-0.0735779,-0.0648193,-0.0534668,-0.0405579,-0.0269470,-0.0132751,-0.0005798,0.0105286,0.0193787,0.0259705,0.0296631,0.0311584,0.0295105,0.0256958,0.0194092,0.0112610,0.0020447,-0.0083923,-0.0193176,-0.0297241,-0.0388489,-0.0464478,-0.0519104,-0.0555115,-0.0557251,-0.0547791,-0.0512085,-0.0472412,-0.0414429,-0.0355225,-0.0289612,-0.0229797,-0.0171814,-0.0121155,-0.0076599,-0.0047302,-0.0028076,-0.0021973,-0.0026550,-0.0043030,-0.0071106,-0.0099792,-0.0136719,-0.0169678,-0.0201721,-0.0225525,-0.0236816,-0.0238647,-0.0219421,-0.0185547,-0.0136414,-0.0071411,0.0003967,0.0093079,0.0180054,0.0266724,0.0345154,0.0417786,0.0471802,0.0503540,0.0507202,0.0486450,0.0432129,0.0345764,0.0242310,0.0122681,0.0011292,-0.0092163,-0.0170288,-0.0223694,-0.0251465,-0.0258484,-0.0242004,-0.0210266,-0.0162354,-0.0109558,-0.0056763,-0.0012817,0.0020752,0.0047607,0.0068359,0.0082092,0.0088806,0.0091553,0.0094299,0.0092163,0.0089722,0.0076904,0.0065613,0.0056152,0.0049438,0.0053406,0.0064697,0.0092773,0.0135193,0.0191956,0.0265808,0.0349731,0.0449219,0.0556335,0.0675049,0.0790100,0.0903320,0.1002197,0.1090088,0.1152344,0.1192627,0.1200256,0.1183472,0.1136169,0.1064758,0.0981140,0.0882263,0.0787354,0.0691528,0.0606995,0.0532532,0.0471497,0.0421143,0.0392151,0.0367432,0.0358582,0.0351562,0.0353088,0.0353394,0.0352478,0.0345459,0.0330505,0.0307007,0.0275269,0.0236206,0.0190430,0.0137329,0.0086975,0.0035095,-0.0013428,-0.0063477,-0.0111694,-0.0149841,-0.0184631,-0.0210571,-0.0224609,-0.0228577,-0.0214844,-0.0187378,-0.0132751,-0.0061035,0.0027466,0.0120544,0.0226440,0.0323486,0.0413208,0.0475769,0.0517883,0.0532227,0.0510864,0.0458069,0.0374146,0.0266418,0.0134583,-0.0006714,-0.0156860,-0.0306396,-0.0452271,-0.0586548,-0.0706482,-0.0811768,-0.0902405,-0.0978699,-0.1040955,-0.1096497,-0.1141968,-0.1185608,-0.1223145,-0.1265869,-0.1298523,-0.1333923,-0.1361084,-0.1386719,-0.1402588,-0.1417542,-0.1428223,-0.1442871,-0.1448669,-0.1458740,-0.1463013,-0.1465454,-0.1459351,-0.1443787,-0.1412048,-0.1366882,-0.1291809,-0.1206055,-0.1098022,-0.0979919,-0.0842285,-0.0695801,-0.0537415,-0.0372314,-0.0204163,-0.0043640,0.0100708,0.0222778,0.0319519,0.0379333,0.0403137,0.0389099,0.0338440,0.0251770,0.0131531,-0.0013123,-0.0168152,-0.0336609,-0.0498657,-0.0653687,-0.0787354,-0.0900269,-0.0978394,-0.1025085,-0.1030273,-0.1007996,-0.0943298,-0.0853271,-0.0734558,-0.0596313,-0.0447693,-0.0289307,-0.0137024,0.0006714,0.0135803,0.0248718,0.0342102,0.0411987,0.0459290,0.0488281,0.0494385,0.0484009,0.0463257,0.0440979,0.0421143,0.0409851,0.0409546,0.0428162,0.0462341,0.0513000,0.0579834,0.0661621,0.0747375,0.0835266,0.0921326,0.1001587,0.1072998,0.1132202,0.1176453,0.1206665,0.1216431,0.1202698,0.1174011,0.1126709,0.1068420,0.0997009,0.0925903,0.0853271,0.0785217,0.0726013,0.0676575,0.0642700,0.0616150,0.0602112,0.0599976,0.0611267,0.0626221,0.0646667,0.0664368,0.0687256,0.0697632,0.0711670,0.0716553,0.0723572,0.0727234,0.0728760,0.0738220,0.0750122,0.0774231,0.0805664,0.0854492,0.0914307,0.0985413,0.1066895,0.1163940,0.1267395,0.1376953,0.1484680,0.1591492,0.1691589,0.1779175,0.1846008,0.1898499,0.1918640,0.1918945,0.1891479,0.1843567,0.1770020,0.1668701,0.1550903,0.1411438,0.1259460,0.1094971,0.0924988,0.0758972,0.0593262,0.0436096,0.0286560,0.0154724,0.0040894,-0.0055847,-0.0133667,-0.0186768,-0.0227356,-0.0243530,-0.0257568,-0.0248108,-0.0240173,-0.0222778,-0.0213318,-0.0203552,-0.0204163,-0.0218201,-0.0246582,-0.0293579,-0.0356750,-0.0438232,-0.0527954,-0.0624084,-0.0720215,-0.0810852,-0.0889282,-0.0947876,-0.0989075,-0.1006165,-0.1005554,-0.0973206,-0.0924377,-0.0854797,-0.0768127,-0.0674133,-0.0571289,-0.0474854,-0.0387878,-0.0323181,-0.0291443,-0.0293579,-0.0343933,-0.0434875,-0.0577393,-0.0754089,-0.0964661,-0.1191406,-0.1423645,-0.1648254,-0.1853638,-0.2032776,-0.2167358,-0.2264404,-0.2312317,-0.2318115,-0.2270508,-0.2177124,-0.2046509,-0.1880493,-0.1688538,-0.1485291,-0.1275024,-0.1073608,-0.0875854,-0.0704651,-0.0549927,-0.0425110,-0.0322266,-0.0256348,-0.0216980,-0.0211487,-0.0231018,-0.0271912,-0.0326233,-0.0392761,-0.0458679,-0.0520935,-0.0567017,-0.0600891,-0.0614319,-0.0611572,-0.0584106,-0.0539856,-0.0484619,-0.0422974,-0.0358887,-0.0304260,-0.0256958,-0.0233154,-0.0223389,-0.0238953,-0.0282288,-0.0339966,-0.0424500,-0.0515137,-0.0620117,-0.0721436,-0.0816650,-0.0894775,-0.0954285,-0.0984497,-0.0984192,-0.0956421,-0.0895081,-0.0814209,-0.0710144,-0.0595093,-0.0466919,-0.0336304,-0.0201111,-0.0069275,0.0063171,0.0186157,0.0302124,0.0403442,0.0489502,0.0555420,0.0601196,0.0628357,0.0635376,0.0631104,0.0610962,0.0587463,0.0559692,0.0534668,0.0509033,0.0490417,0.0471802,0.0460815,0.0446777,0.0433655,0.0413513,0.0388184,0.0350037,0.0306091,0.0246277,0.0186157,0.0115356,0.0047607,-0.0020752,-0.0080261,-0.0125427,-0.0158691,-0.0170898,-0.0163269,-0.0131531,-0.0078735,0.0001221,0.0098877,0.0217896,0.0343018,0.0479126,0.0611267,0.0740356,0.0856934,0.0958862,0.1047668,0.1111450,0.1158447,0.1183777,0.1192932,0.1178589,0.1149902,0.1107483,0.1053162,0.0994263,0.0927429,0.0870667,0.0811462,0.0768127,0.0733643,0.0711670,0.0705261,0.0711975,0.0729980,0.0756531,0.0791016,0.0826111,0.0866699,0.0901184,0.0934143,0.0959473,0.0972595,0.0975037,0.0956726,0.0926514,0.0878296,0.0817261,0.0742188,0.0667114,0.0588684,0.0520935,0.0457458,0.0414429,0.0384521,0.0375977,0.0385132,0.0409546,0.0449829,0.0505066,0.0569458,0.0640869,0.0711060,0.0786133,0.0847778,0.0903931,0.0938721,0.0959778,0.0957031,0.0937195,0.0894775,0.0832825,0.0751953,0.0656128,0.0556946,0.0447693,0.0331726,0.0208740,0.0085754,-0.0037537,-0.0156250,-0.0274353,-0.0383606,-0.0486450,-0.0574036,-0.0646667,-0.0703125,-0.0742798,-0.0769958,-0.0780640,-0.0773926,-0.0755005,-0.0713196,-0.0662842,-0.0595398,-0.0525513,-0.0439453,-0.0345459,-0.0241699,-0.0134277,-0.0027161,0.0074768,0.0171814,0.0249023,0.0315247,0.0356445,0.0366516,0.0343628,0.0298767,0.0224915,0.0134888,0.0031738,-0.0077515,-0.0184937,-0.0287781,-0.0379944,-0.0449829,-0.0506287,-0.0539856,-0.0558472,-0.0558777,-0.0550232,-0.0531006,-0.0501404,-0.0463867,-0.0424805,-0.0384827,-0.0355835,-0.0337830,-0.0333862,-0.0341492,-0.0359802,-0.0397949,-0.0444031,-0.0497742,-0.0552673,-0.0609741,-0.0659180,-0.0694580,-0.0704651,-0.0692444,-0.0642395,-0.0559998,-0.0438232,-0.0291748,-0.0125732,0.0052795,0.0231628,0.0408630,0.0566101,0.0703735,0.0813904,0.0888062,0.0925598,0.0927734,0.0903015,0.0847168,0.0763855,0.0663147,0.0556030,0.0444946,0.0327759,0.0208130,0.0084229,-0.0029602,-0.0137939,-0.0233154,-0.0322266,-0.0403442,-0.0478516,-0.0546265,-0.0610046,-0.0674438,-0.0729065,-0.0779419,-0.0813293,-0.0832825,-0.0835266,-0.0817261,-0.0786743,-0.0733643,-0.0675049,-0.0601196,-0.0530701,-0.0453796,-0.0380554,-0.0311890,-0.0252075,-0.0202026,-0.0160522,-0.0133972,-0.0121765,-0.0125732,-0.0147095,-0.0188599,-0.0248413,-0.0320740,-0.0401001,-0.0483704,-0.0564270,-0.0637512,-0.0694580,-0.0739136,-0.0761414,-0.0767517,-0.0751648,-0.0720520,-0.0681763,-0.0629578,-0.0575256,-0.0513916,-0.0457458,-0.0399475,-0.0350037,-0.0301819,-0.0266113,-0.0230713,-0.0200806,-0.0172729,-0.0148010,-0.0117798,-0.0086060,-0.0043945,0.0003967,0.0061646,0.0126648,0.0197144,0.0271301,0.0349426,0.0428772,0.0507507,0.0578918,0.0645447,0.0697327,0.0737915,0.0761414,0.0769043,0.0761719,0.0732422,0.0692139,0.0634155,0.0570984,0.0499573,0.0427551,0.0357056,0.0292969,0.0233154,0.0191345,0.0159607,0.0152283,0.0159607,0.0189819,0.0234680,0.0293884,0.0359497,0.0424805,0.0487976,0.0534973,0.0563660,0.0566711,0.0544128,0.0497131,0.0426636,0.0339050,0.0241089,0.0139465,0.0041504,-0.0049438,-0.0122070,-0.0181274,-0.0216064,-0.0233765,-0.0223389,-0.0193176,-0.0136414,-0.0060120,0.0034485,0.0146790,0.0260620,0.0378113,0.0485229,0.0576477,0.0644531,0.0683899,0.0697327,0.0674438,0.0624084,0.0545654,0.0455933,0.0354614,0.0255432,0.0156555,0.0078735,0.0011902,-0.0031433,-0.0053406,-0.0049438,-0.0018005,0.0033875,0.0104370,0.0188904,0.0272522,0.0360413,0.0429993,0.0485840,0.0520935,0.0529175,0.0520935,0.0487366,0.0441284,0.0384827,0.0322266,0.0254517,0.0192566,0.0130920,0.0076599,0.0028687,-0.0010986,-0.0036621,-0.0057373,-0.0064392,-0.0060730,-0.0044250,-0.0025330,0.0001831,0.0026855,0.0050964,0.0064697,0.0067139,0.0058289,0.0037842,0.0008240,-0.0036011,-0.0092468,-0.0155029,-0.0227661,-0.0298767,-0.0377808,-0.0449219,-0.0526733,-0.0589600,-0.0652466,-0.0704651,-0.0747681,-0.0786133,-0.0812683,-0.0834656,-0.0842285,-0.0843201,-0.0830383,-0.0804138,-0.0755615,-0.0688171,-0.0596619,-0.0485229,-0.0352478,-0.0207214,-0.0061951,0.0084839,0.0209656,0.0321045,0.0393982,0.0439148,0.0443115,0.0410156,0.0344543,0.0250854,0.0140076,0.0021057,-0.0105591,-0.0221252,-0.0326843,-0.0411987,-0.0473938,-0.0518188,-0.0539246,-0.0542603,-0.0531311,-0.0507202,-0.0483704,-0.0455017,-0.0440369,-0.0428467,-0.0439453,-0.0457153,-0.0496216,-0.0547791,-0.0617981,-0.0697632,-0.0782166,-0.0869141,-0.0949402,-0.1014404,-0.1058044,-0.1073303,-0.1061707,-0.1018677,-0.0948181,-0.0845947,-0.0718689,-0.0567627,-0.0404053,-0.0231628,-0.0061340,0.0094299,0.0230713,0.0340881,0.0414124,0.0452271,0.0447693,0.0409851,0.0333252,0.0234680,0.0109863,-0.0025940,-0.0173035,-0.0321960,-0.0463867,-0.0589905,-0.0694580,-0.0780334,-0.0830078,-0.0852356,-0.0841064,-0.0796204,-0.0727844,-0.0627747,-0.0510864,-0.0382996,-0.0242615,-0.0106201,0.0028687,0.0155334,0.0269470,0.0372314,0.0455322,0.0522461,0.0574646,0.0610657,0.0634155,0.0647583,0.0653381,0.0658264,0.0667114,0.0677795,0.0702820,0.0735779,0.0783997,0.0838623,0.0907288,0.0982971,0.1067505,0.1153564,0.1235657,0.1317749,0.1387024,0.1447144,0.1493835,0.1525879,0.1543274,0.1546936,0.1536255,0.1513977,0.1483154,0.1442871,0.1405334,0.1364136,0.1329346,0.1298218,0.1271057,0.1248474,0.1222229,0.1195068,0.1156616,0.1113892,0.1059570,0.0997314,0.0930786,0.0863953,0.0793762,0.0728760,0.0663147,0.0598145,0.0538635,0.0472717,0.0414124,0.0349121,0.0293579,0.0240173,0.0198669,0.0164185,0.0143433,0.0135498,0.0136108,0.0146790,0.0160828,0.0176697,0.0192871,0.0199890,0.0202637,0.0192871,0.0169678,0.0133057,0.0074768,0.0000610,-0.0097961,-0.0207214,-0.0339355,-0.0473633,-0.0623779,-0.0763550,-0.0906982,-0.1031189,-0.1144714,-0.1231384,-0.1294250,-0.1329041,-0.1340027,-0.1323853,-0.1288452,-0.1238708,-0.1172485,-0.1094055,-0.1009827,-0.0920410,-0.0830994,-0.0743713,-0.0665283,-0.0602722,-0.0558777,-0.0537109,-0.0538635,-0.0566101,-0.0617676,-0.0686951,-0.0774231,-0.0864868,-0.0959167,-0.1045532,-0.1116943,-0.1169128,-0.1192017,-0.1195679,-0.1168518,-0.1122131,-0.1056213,-0.0981140,-0.0904846,-0.0833130,-0.0773926,-0.0732117,-0.0708313,-0.0705566,-0.0717773,-0.0751648,-0.0789795,-0.0831604,-0.0871277,-0.0901794,-0.0923157,-0.0923157,-0.0906067,-0.0864868,-0.0803528,-0.0721130,-0.0621643,-0.0508423,-0.0384216,-0.0255127,-0.0127563,-0.0010681,0.0091858,0.0174255,0.0234070,0.0267944,0.0277710,0.0269470,0.0244141,0.0212402,0.0175171,0.0143433,0.0116577,0.0095825,0.0085144,0.0071106,0.0062866,0.0045776,0.0030823,0.0001221,-0.0030518,-0.0076294,-0.0123901,-0.0181580,-0.0238037,-0.0300598,-0.0357666,-0.0419922,-0.0473022,-0.0519714,-0.0551147,-0.0568237,-0.0568237,-0.0546570,-0.0505981,-0.0447693,-0.0379639,-0.0301819,-0.0214844,-0.0127869,-0.0045776,0.0029297,0.0102234,0.0168457,0.0231628,0.0284424,0.0331726,0.0370789,0.0398560,0.0420227,0.0430908,0.0435486,0.0435486,0.0437012,0.0440674,0.0448303,0.0451050,0.0458374,0.0462952,0.0463562,0.0452271,0.0434265,0.0402527,0.0366821,0.0318604,0.0267029,0.0212097,0.0154114,0.0099487,0.0045471,0.0000916,-0.0033875,-0.0056152,-0.0055847,-0.0039673,0.0000000,0.0057983,0.0134583,0.0221558,0.0309753,0.0394592,0.0472107,0.0536194,0.0591125,0.0631104,0.0660095,0.0681458,0.0692749,0.0699768,0.0704346,0.0712280,0.0719910,0.0736694,0.0750122,0.0767212,0.0780029,0.0784607,0.0786438,0.0778503,0.0765686,0.0744934,0.0718384,0.0691223,0.0660095,0.0635681,0.0614929,0.0608826,0.0610352,0.0620117,0.0641479,0.0664368,0.0689087,0.0707092,0.0720825,0.0726624,0.0722961,0.0707703,0.0684509,0.0646973,0.0597229,0.0527649,0.0453491,0.0361938,0.0269470,0.0172729,0.0081177,0.0001221,-0.0069885,-0.0117798,-0.0143433,-0.0143127,-0.0112915,-0.0054932,0.0026855,0.0126648,0.0236206,0.0346680,0.0451660,0.0536804,0.0600891,0.0630493,0.0630798,0.0590820,0.0519104,0.0409241,0.0271301,0.0106506,-0.0072021,-0.0264893,-0.0454712,-0.0638428,-0.0803833,-0.0951843,-0.1070557,-0.1159973,-0.1214600,-0.1236877,-0.1227417,-0.1189270,-0.1123352,-0.1041870,-0.0944214,-0.0841064,-0.0732422,-0.0631714,-0.0540161,-0.0462036,-0.0399475,-0.0352478,-0.0320129,-0.0300293,-0.0293274,-0.0291443,-0.0298462,-0.0307922,-0.0319824,-0.0327454,-0.0329285,-0.0324707,-0.0311584,-0.0287476,-0.0252075,-0.0208130,-0.0161133,-0.0105286,-0.0053406,0.0001526,0.0042419,0.0073853,0.0092773,0.0096436,0.0093079,0.0069580,0.0034790,-0.0014648,-0.0072632,-0.0139160,-0.0212708,-0.0287170,-0.0361633,-0.0429077,-0.0487671,-0.0531921,-0.0556641,-0.0558472,-0.0534363,-0.0486145,-0.0418396,-0.0328064,-0.0231628,-0.0121765,-0.0017700,0.0089417,0.0184631,0.0270691,0.0343933,0.0396423,0.0433960,0.0452881,0.0452576,0.0434265,0.0401001,0.0350647,0.0293884,0.0226746,0.0159607,0.0088196,0.0019226,-0.0046387,-0.0105591,-0.0161133,-0.0212402,-0.0266113,-0.0317688,-0.0377502,-0.0440979,-0.0514221,-0.0595093,-0.0683899,-0.0775452,-0.0875854,-0.0971985,-0.1072693,-0.1165161,-0.1253967,-0.1333313,-0.1398926,-0.1449890,-0.1478882,-0.1486816,-0.1470337,-0.1429138,-0.1361694,-0.1269226,-0.1151123,-0.1010132,-0.0850525,-0.0675049,-0.0491333,-0.0302124,-0.0118713,0.0057678,0.0223389,0.0370789,0.0498352,0.0599365,0.0675049,0.0722351,0.0735779,0.0724792,0.0681763,0.0619202,0.0534668,0.0437012,0.0329590,0.0221558,0.0112000,0.0017090,-0.0076294,-0.0148621,-0.0217896,-0.0267639,-0.0316162,-0.0351562,-0.0387573,-0.0415344,-0.0444031,-0.0468140,-0.0490723,-0.0513000,-0.0526428,-0.0541687,-0.0543823,-0.0542603,-0.0534973,-0.0519409,-0.0494995,-0.0463257,-0.0420227,-0.0368652,-0.0296936,-0.0210876,-0.0098267,0.0026245,0.0178833,0.0343323,0.0526428,0.0714111,0.0906067,0.1087952,0.1262817,0.1412048,0.1544189,0.1641235,0.1709595,0.1742859,0.1738281,0.1701050,0.1624451,0.1521606,0.1389771,0.1235352,0.1065979,0.0888977,0.0707092,0.0537109,0.0377502,0.0233459,0.0112305,0.0010071,-0.0069580,-0.0128784,-0.0169678,-0.0196533,-0.0210876,-0.0221863,-0.0223694,-0.0230408,-0.0235596,-0.0248718,-0.0264282,-0.0289612,-0.0317078,-0.0355530,-0.0388489,-0.0429077,-0.0463562,-0.0491028,-0.0513000,-0.0516663,-0.0509644,-0.0481567,-0.0434570,-0.0365601,-0.0279541,-0.0171509,-0.0051880,0.0082397,0.0218201,0.0358582,0.0491028,0.0609436,0.0708008,0.0778198,0.0818176,0.0827026,0.0803223,0.0745850,0.0664368,0.0554810,0.0428162,0.0287476,0.0141296,-0.0008240,-0.0153809,-0.0288086,-0.0411682,-0.0512390,-0.0595703,-0.0651245,-0.0687561,-0.0697632,-0.0687561,-0.0659790,-0.0618591,-0.0570068,-0.0515442,-0.0457764,-0.0404968,-0.0355835,-0.0315552,-0.0280151,-0.0256958,-0.0239258,-0.0229492,-0.0221558,-0.0213318,-0.0203552,-0.0180664,-0.0152283,-0.0114441,-0.0061951,-0.0003357,0.0065002,0.0139465,0.0216064,0.0291748,0.0363464,0.0423889,0.0473328,0.0505981,0.0521545,0.0515747,0.0491943,0.0448608,0.0392456,0.0320435,0.0236816,0.0148621,0.0046997,-0.0050354,-0.0156555,-0.0256653,-0.0357971,-0.0449829,-0.0534973,-0.0603027,-0.0659790,-0.0694885,-0.0711975,-0.0710144,-0.0688477,-0.0654297,-0.0599670,-0.0538330,-0.0464783,-0.0389709,-0.0313416,-0.0242310,-0.0176392,-0.0116577,-0.0063782,-0.0018616,0.0021057,0.0053406,0.0080872,0.0100403,0.0114441,0.0124512,0.0125427,0.0123596,0.0113525,0.0099487,0.0085144,0.0064697,0.0048218,0.0029297,0.0015259,0.0001221,-0.0009460,-0.0019531,-0.0030518,-0.0042725,-0.0063171,-0.0086365,-0.0119629,-0.0163879,-0.0217896,-0.0282288,-0.0350037,-0.0418701,-0.0481873,-0.0536194,-0.0573425,-0.0590210,-0.0584717,-0.0552673,-0.0498657,-0.0419617,-0.0322571,-0.0206604,-0.0077820,0.0061951,0.0208435,0.0355835,0.0497742,0.0632935,0.0750427,0.0859680,0.0946350,0.1021423,0.1074219,0.1112061,0.1130066,0.1132507,0.1115723,0.1087036,0.1041565,0.0984497,0.0921021,0.0850525,0.0783997,0.0714722,0.0655212,0.0599060,0.0555725,0.0516052,0.0490112,0.0465393,0.0447693,0.0427856,0.0411377,0.0388794,0.0371094,0.0347290,0.0331116,0.0313416,0.0303040,0.0293274,0.0294800,0.0296631,0.0306091,0.0315552,0.0328064,0.0347290,0.0363464,0.0389404,0.0410461,0.0437927,0.0457458,0.0477600,0.0489807,0.0491638,0.0485229,0.0466309,0.0441895,0.0402832,0.0360107,0.0310059,0.0257568,0.0204468,0.0148621,0.0100403,0.0050659,0.0009155,-0.0025635,-0.0056152,-0.0074768,-0.0090332,-0.0094299,-0.0093689,-0.0093079,-0.0087280,-0.0088501,-0.0092773,-0.0109558,-0.0137024,-0.0182800,-0.0245972,-0.0330505,-0.0435181,-0.0556641,-0.0691833,-0.0836182,-0.0979614,-0.1121216,-0.1247253,-0.1353455,-0.1431885,-0.1478577,-0.1490173,-0.1466370,-0.1407471,-0.1315308,-0.1198730,-0.1059875,-0.0905762,-0.0743713,-0.0580750,-0.0423279,-0.0278931,-0.0150146,-0.0042114,0.0035706,0.0088196,0.0109253,0.0106201,0.0077515,0.0027466,-0.0038147,-0.0114746,-0.0201721,-0.0289612,-0.0380249,-0.0462646,-0.0543518,-0.0613098,-0.0681458,-0.0735779,-0.0784607,-0.0822754,-0.0850830,-0.0868530,-0.0875244,-0.0872803,-0.0859070,-0.0837708,-0.0807190,-0.0773010,-0.0737915,-0.0704956,-0.0678406,-0.0661316,-0.0652466,-0.0654602,-0.0660095,-0.0673828,-0.0681763,-0.0695801,-0.0699158,-0.0701904,-0.0694275,-0.0679932,-0.0646057,-0.0597229,-0.0523682,-0.0425110,-0.0302124,-0.0152588,0.0006714,0.0186462,0.0362244,0.0542297,0.0706787,0.0854492,0.0982971,0.1077271,0.1143799,0.1173706,0.1170654,0.1136780,0.1071472,0.0990601,0.0891418,0.0789185,0.0686951,0.0596313,0.0520630,0.0469666,0.0440063,0.0438232,0.0457153,0.0497131,0.0551147,0.0615234,0.0682983,0.0747375,0.0809021,0.0861206,0.0903015,0.0929871,0.0944519,0.0944214,0.0935364,0.0920105,0.0904541,0.0895386,0.0895386,0.0910645,0.0941162,0.0982056,0.1036987,0.1098633,0.1159058,0.1218262,0.1263428,0.1296387,0.1309204,0.1301880,0.1275330,0.1225281,0.1163025,0.1085510,0.1006165,0.0926514,0.0859070,0.0802307,0.0765686,0.0745850,0.0744019,0.0755310,0.0780640,0.0812988,0.0846252,0.0880127,0.0903931,0.0921021,0.0922546,0.0915833,0.0892944,0.0864868,0.0827332,0.0785828,0.0742798,0.0695190,0.0653076,0.0612488,0.0574951,0.0539551,0.0505676,0.0472717,0.0437317,0.0401917,0.0357971,0.0314331,0.0263062,0.0207825,0.0148621,0.0083008,0.0018005,-0.0044250,-0.0106506,-0.0158386,-0.0207214,-0.0242310,-0.0273132,-0.0293884,-0.0314941,-0.0326843,-0.0345459,-0.0365295,-0.0386963,-0.0413818,-0.0442505,-0.0477600,-0.0515137,-0.0559692,-0.0600891,-0.0648804,-0.0688782,-0.0728149,-0.0756226,-0.0773315,-0.0776062,-0.0759583,-0.0725098,-0.0672302,-0.0600891,-0.0517578,-0.0427246,-0.0335693,-0.0254211,-0.0185547,-0.0143433,-0.0121765,-0.0133972,-0.0169983,-0.0235291,-0.0323486,-0.0428162,-0.0543823,-0.0660400,-0.0777588,-0.0880432,-0.0967407,-0.1034851,-0.1083679,-0.1113586,-0.1128845,-0.1128235,-0.1121521,-0.1108093,-0.1092529,-0.1079407,-0.1064758,-0.1056824,-0.1047058,-0.1042786,-0.1037598,-0.1037903,-0.1035767,-0.1038513,-0.1040955,-0.1044922,-0.1047363,-0.1046448,-0.1042175,-0.1038513,-0.1017151,-0.0986938,-0.0939941,-0.0891418,-0.0832214,-0.0771179,-0.0707092,-0.0641479,-0.0572510,-0.0505066,-0.0436707,-0.0369568,-0.0297546,-0.0227661,-0.0151978,-0.0072937,0.0006409,0.0090637,0.0166931,0.0248413,0.0325012,0.0400391,0.0471497,0.0530090,0.0582581,0.0618591,0.0640564,0.0643005,0.0625000,0.0585022,0.0524902,0.0445251,0.0352783,0.0248108,0.0136719,0.0025940,-0.0083313,-0.0183411,-0.0275269,-0.0352478,-0.0415344,-0.0460510,-0.0491638,-0.0505676,-0.0503235,-0.0484314,-0.0447998,-0.0397034,-0.0332642,-0.0255432,-0.0169983,-0.0077820,0.0016174,0.0105591,0.0190430,0.0268555,0.0333252,0.0384521,0.0416565,0.0437622,0.0439758,0.0432129,0.0404358,0.0370789,0.0323792,0.0272827,0.0222473,0.0173035,0.0133667,0.0102234,0.0090942,0.0090942,0.0113525,0.0146179,0.0198059,0.0258179,0.0325623,0.0394287,0.0460815,0.0520325,0.0571594,0.0610352,0.0637512,0.0650635,0.0654602,0.0646362,0.0636292,0.0619812,0.0604553,0.0593262,0.0585632,0.0589294,0.0598755,0.0620728,0.0652161,0.0693665,0.0741882,0.0794373,0.0845337,0.0900574,0.0942383,0.0980835,0.1007690,0.1024780,0.1031799,0.1028137,0.1017151,0.0998535,0.0979614,0.0955200,0.0934448,0.0911560,0.0893250,0.0874329,0.0863953,0.0848694,0.0843506,0.0832214,0.0826721,0.0818481,0.0810547,0.0798035,0.0781250,0.0761719,0.0732422,0.0697937,0.0654602,0.0606995,0.0550842,0.0492249,0.0431824,0.0372009,0.0312805,0.0258789,0.0208130,0.0166016,0.0123596,0.0093384,0.0058289,0.0034790,0.0004578,-0.0016479,-0.0037231,-0.0053406,-0.0061035,-0.0068665,-0.0062256,-0.0055542,-0.0038147,-0.0017090,0.0005493,0.0031738,0.0055847,0.0078735,0.0099792,0.0113525,0.0122375,0.0125122,0.0124817,0.0115051,0.0103455,0.0087891,0.0072937,0.0062866,0.0054932,0.0054321,0.0057678,0.0068359,0.0080261,0.0098877,0.0116882,0.0134277,0.0152893,0.0166016,0.0169373,0.0166321,0.0146179,0.0113220,0.0065308,-0.0001221,-0.0077515,-0.0168762,-0.0261841,-0.0356750,-0.0448914,-0.0531921,-0.0606995,-0.0666504,-0.0710754,-0.0737915,-0.0747375,-0.0741577,-0.0720825,-0.0691833,-0.0654297,-0.0613708,-0.0573425,-0.0532227,-0.0493469,-0.0457153,-0.0422058,-0.0383911,-0.0343628,-0.0301514,-0.0251160,-0.0200195,-0.0139771,-0.0081482,-0.0019836,0.0037231,0.0087891,0.0134888,0.0167542,0.0191650,0.0198975,0.0193787,0.0175476,0.0141602,0.0097351,0.0041809,-0.0015869,-0.0070190,-0.0123291,-0.0160828,-0.0186462,-0.0191345,-0.0175476,-0.0141296,-0.0084229,-0.0018005,0.0061035,0.0138855,0.0213013,0.0272827,0.0314636,0.0335083,0.0330200,0.0303650,0.0250854,0.0182495,0.0096436,0.0007019,-0.0086670,-0.0169678,-0.0240173,-0.0289001,-0.0316162,-0.0315247,-0.0290527,-0.0242615,-0.0177002,-0.0106506,-0.0027771,0.0039978,0.0097961,0.0139771,0.0154724,0.0153503,0.0120850,0.0068054,-0.0009766,-0.0103760,-0.0211182,-0.0324097,-0.0434875,-0.0539551,-0.0630798,-0.0700684,-0.0752563,-0.0776062,-0.0780945,-0.0758362,-0.0719604,-0.0661011,-0.0591125,-0.0515442,-0.0436707,-0.0364685,-0.0299377,-0.0247192,-0.0207520,-0.0182495,-0.0174866,-0.0175476,-0.0194092,-0.0217896,-0.0250854,-0.0283813,-0.0314636,-0.0343933,-0.0362854,-0.0376282,-0.0376587,-0.0370483,-0.0349121,-0.0319214,-0.0275269,-0.0221863,-0.0152588,-0.0074768,0.0019836,0.0122070,0.0233765,0.0350037,0.0466614,0.0578003,0.0679321,0.0767822,0.0837097,0.0891113,0.0917358,0.0932312,0.0920410,0.0894470,0.0852966,0.0797119,0.0737305,0.0670776,0.0601196,0.0538330,0.0477600,0.0429688,0.0387268,0.0361633,0.0340576,0.0331726,0.0325928,0.0324707,0.0323792,0.0315247,0.0303955,0.0278320,0.0244141,0.0194702,0.0136108,0.0062561,-0.0018311,-0.0102844,-0.0188293,-0.0263977,-0.0331421,-0.0376587,-0.0404968,-0.0402527,-0.0376282,-0.0320740,-0.0239563,-0.0134583,-0.0014038,0.0116272,0.0249329,0.0373535,0.0484619,0.0571899,0.0635071,0.0669250,0.0675659,0.0654602,0.0607605,0.0539246,0.0454102,0.0360413,0.0258789,0.0161743,0.0067139,-0.0014954,-0.0086060,-0.0143738,-0.0182495,-0.0209045,-0.0216064,-0.0208740,-0.0187378,-0.0150146,-0.0109253,-0.0057678,-0.0010986,0.0035706,0.0075684,0.0107727,0.0130310,0.0139160,0.0133972,0.0121460,0.0089722,0.0052185,-0.0008850,-0.0066223,-0.0138550,-0.0199890,-0.0264893,-0.0315857,-0.0362549,-0.0391541,-0.0411682,-0.0415344,-0.0409851,-0.0389709,-0.0361328,-0.0323486,-0.0277100,-0.0228577,-0.0178833,-0.0137329,-0.0101318,-0.0079956,-0.0070801,-0.0081787,-0.0106812,-0.0144043,-0.0191956,-0.0248108,-0.0305786,-0.0367126,-0.0419312,-0.0470886,-0.0514832,-0.0546875,-0.0570679,-0.0578003,-0.0570984,-0.0549622,-0.0515747,-0.0466919,-0.0417786,-0.0361633,-0.0317688,-0.0279236,-0.0254211,-0.0251160,-0.0262146,-0.0295105,-0.0339355,-0.0395203,-0.0453796,-0.0515442,-0.0565491,-0.0610962,-0.0635986,-0.0646667,-0.0631104,-0.0600281,-0.0542603,-0.0475159,-0.0385132,-0.0291748,-0.0192566,-0.0101318,-0.0018311,0.0052795,0.0105591,0.0134888,0.0142517,0.0124817,0.0087585,0.0029907,-0.0043945,-0.0134583,-0.0229187,-0.0326843,-0.0415649,-0.0498047,-0.0564575,-0.0615234,-0.0643921,-0.0650635,-0.0643311,-0.0615845,-0.0580139,-0.0530090,-0.0478821,-0.0428467,-0.0379639,-0.0340576,-0.0308228,-0.0287476,-0.0274963,-0.0268250,-0.0268555,-0.0270081,-0.0269470,-0.0267029,-0.0253296,-0.0234070,-0.0198975,-0.0148010,-0.0087891,-0.0004883,0.0087585,0.0195923,0.0316162,0.0440369,0.0568848,0.0692749,0.0806885,0.0910339,0.0996094,0.1062012,0.1108093,0.1131592,0.1138916,0.1128540,0.1105347,0.1074524,0.1035767,0.1002197,0.0963440,0.0935974,0.0911865,0.0897827,0.0895691,0.0897217,0.0912170,0.0926514,0.0949707,0.0969543,0.0991516,0.1006165,0.1014709,0.1014099,0.0999756,0.0975647,0.0938721,0.0886230,0.0829468,0.0755920,0.0685425,0.0602112,0.0526123,0.0448914,0.0380554,0.0319824,0.0271606,0.0238953,0.0217285,0.0219727,0.0229187,0.0262756,0.0300903,0.0353088,0.0410767,0.0469360,0.0528564,0.0581665,0.0626831,0.0657043,0.0668030,0.0663452,0.0636597,0.0591125,0.0522156,0.0437012,0.0338440,0.0223694,0.0104980,-0.0021362,-0.0148010,-0.0269775,-0.0390015,-0.0496521,-0.0593872,-0.0675964,-0.0741882,-0.0789490,-0.0821838,-0.0834961,-0.0833435,-0.0818481,-0.0794373,-0.0764771,-0.0730286,-0.0702820,-0.0671387,-0.0655823,-0.0642090,-0.0643311,-0.0653992,-0.0674133,-0.0704956,-0.0741577,-0.0779114,-0.0819702,-0.0852356,-0.0883789,-0.0904541,-0.0919800,-0.0925293,-0.0927124,-0.0924072,-0.0916443,-0.0911865,-0.0901489,-0.0892029,-0.0877991,-0.0860901,-0.0836487,-0.0805359,-0.0765381,-0.0718079,-0.0659485,-0.0597534,-0.0523376,-0.0451050,-0.0371399,-0.0291443,-0.0212402,-0.0133667,-0.0065308,-0.0008240,0.0036621,0.0061951,0.0066223,0.0051880,0.0009460,-0.0048828,-0.0126038,-0.0213623,-0.0307312,-0.0400391,-0.0486755,-0.0562134,-0.0621643,-0.0666199,-0.0690002,-0.0697327,-0.0684814,-0.0659485,-0.0619812,-0.0572815,-0.0518188,-0.0460815,-0.0406799,-0.0353699,-0.0317078,-0.0286865,-0.0279541,-0.0284119,-0.0308533,-0.0347595,-0.0397949,-0.0456848,-0.0513611,-0.0568237,-0.0610657,-0.0641785,-0.0651855,-0.0647278,-0.0618286,-0.0579529,-0.0520630,-0.0452576,-0.0377502,-0.0293274,-0.0216980,-0.0132141,-0.0062561,0.0003052,0.0057373,0.0095825,0.0122986,0.0135803,0.0132141,0.0113831,0.0080261,0.0036621,-0.0021667,-0.0082092,-0.0151672,-0.0221558,-0.0293884,-0.0359802,-0.0418701,-0.0469055,-0.0503235,-0.0526428,-0.0527344,-0.0518799,-0.0484314,-0.0443726,-0.0378723,-0.0312195,-0.0229187,-0.0146484,-0.0060120,0.0026550,0.0110168,0.0188293,0.0262451,0.0324402,0.0384216,0.0431519,0.0475159,0.0508423,0.0538940,0.0559387,0.0577393,0.0588989,0.0599365,0.0607910,0.0620728,0.0632629,0.0650024,0.0670166,0.0696411,0.0726318,0.0758972,0.0794983,0.0826721,0.0859680,0.0884399,0.0906067,0.0916748,0.0919495,0.0913696,0.0901184,0.0882263,0.0860596,0.0837402,0.0812073,0.0788879,0.0760498,0.0736389,0.0708313,0.0683899,0.0654907,0.0626831,0.0597534,0.0563965,0.0531921,0.0491638,0.0450439,0.0406799,0.0357971,0.0303955,0.0246582,0.0185547,0.0128174,0.0065613,0.0014343,-0.0037231,-0.0072937,-0.0101013,-0.0114441,-0.0111084,-0.0091248,-0.0051270,0.0003357,0.0076904,0.0156860,0.0250854,0.0341492,0.0433350,0.0514526,0.0584717,0.0634155,0.0661316,0.0665283,0.0643616,0.0600586,0.0532837,0.0444031,0.0339661,0.0222168,0.0104065,-0.0017395,-0.0128784,-0.0232239,-0.0319824,-0.0390625,-0.0447083,-0.0481567,-0.0500183,-0.0502625,-0.0490723,-0.0469666,-0.0440369,-0.0404358,-0.0365601,-0.0324402,-0.0282898,-0.0248413,-0.0213013,-0.0189514,-0.0171204,-0.0165405,-0.0165405,-0.0174561,-0.0191040,-0.0211792,-0.0238037,-0.0263062,-0.0287476,-0.0307922,-0.0324402,-0.0335388,-0.0339050,-0.0332031,-0.0316772,-0.0289001,-0.0254517,-0.0210876,-0.0162048,-0.0113831,-0.0064697,-0.0025330,0.0008240,0.0025330,0.0030212,0.0015259,-0.0014954,-0.0065918,-0.0130920,-0.0211182,-0.0303650,-0.0400696,-0.0504761,-0.0604248,-0.0694885,-0.0776062,-0.0834656,-0.0878601,-0.0894775,-0.0890503,-0.0859375,-0.0810242,-0.0736694,-0.0649719,-0.0549316,-0.0438538,-0.0323792,-0.0208740,-0.0093079,0.0015564,0.0116577,0.0205688,0.0278015,0.0333557,0.0368347,0.0379028,0.0373535,0.0345459,0.0302734,0.0244751,0.0178223,0.0105591,0.0031433,-0.0038147,-0.0104065,-0.0161133,-0.0210266,-0.0249023,-0.0283203,-0.0311584,-0.0339661,-0.0371399,-0.0406494,-0.0454102,-0.0508728,-0.0572815,-0.0647888,-0.0728149,-0.0813599,-0.0895996,-0.0973816,-0.1040649,-0.1096497,-0.1133423,-0.1151428,-0.1145630,-0.1118774,-0.1070557,-0.0996399,-0.0905457,-0.0792847,-0.0673218,-0.0539551,-0.0402832,-0.0266113,-0.0134277,-0.0016174,0.0094299,0.0185547,0.0260010,0.0313416,0.0346375,0.0362244,0.0359497,0.0336304,0.0306702,0.0258789,0.0213928,0.0161743,0.0115967,0.0073853,0.0040588,0.0018921,0.0006714,0.0004883,0.0010071,0.0019836,0.0030212,0.0037231,0.0036316,0.0025940,0.0006104,-0.0026550,-0.0068970,-0.0121155,-0.0180359,-0.0246582,-0.0316467,-0.0388184,-0.0459290,-0.0524292,-0.0578003,-0.0621948,-0.0643005,-0.0651245,-0.0634460,-0.0596924,-0.0542603,-0.0464478,-0.0373840,-0.0265808,-0.0147705,-0.0020142,0.0118103,0.0255432,0.0398560,0.0538940,0.0676880,0.0807800,0.0931396,0.1037292,0.1127319,0.1195374,0.1235657,0.1246948,0.1228943,0.1178589,0.1100464,0.0997314,0.0870972,0.0732117,0.0581970,0.0431519,0.0282593,0.0145569,0.0023499,-0.0080566,-0.0162354,-0.0225830,-0.0264282,-0.0286865,-0.0290833,-0.0284424,-0.0268555,-0.0251465,-0.0235291,-0.0222168,-0.0220337,-0.0224915,-0.0240784,-0.0265808,-0.0299988,-0.0339661,-0.0385742,-0.0430908,-0.0477905,-0.0514221,-0.0542908,-0.0557556,-0.0550842,-0.0527344,-0.0484314,-0.0421753,-0.0348511,-0.0262451,-0.0171509,-0.0083923,-0.0002441,0.0068970,0.0122375,0.0164795,0.0185242,0.0194702,0.0183716,0.0163879,0.0127869,0.0080872,0.0029297,-0.0028687,-0.0084839,-0.0137939,-0.0183411,-0.0219116,-0.0244751,-0.0266418,-0.0277710,-0.0290833,-0.0298767,-0.0312500,-0.0324707,-0.0344849,-0.0363464,-0.0384521,-0.0405579,-0.0422668,-0.0438538,-0.0447693,-0.0448914,-0.0443420,-0.0423584,-0.0390930,-0.0347595,-0.0286255,-0.0216064,-0.0131531,-0.0041504,0.0053711,0.0147400,0.0235901,0.0316772,0.0380859,0.0430908,0.0461121,0.0473022,0.0466309,0.0442810,0.0405579,0.0354919,0.0298462,0.0234985,0.0171204,0.0105286,0.0042419,-0.0016785,-0.0071716,-0.0120239,-0.0163879,-0.0198364,-0.0227356,-0.0251465,-0.0268555,-0.0285034,-0.0292664,-0.0302124,-0.0304565,-0.0304565,-0.0300903,-0.0289612,-0.0274048,-0.0252686,-0.0222473,-0.0189514,-0.0150146,-0.0108643,-0.0067139,-0.0025940,0.0010986,0.0043945,0.0072327,0.0094910,0.0112915,0.0125427,0.0132751,0.0137329,0.0136414,0.0136414,0.0135498,0.0135803,0.0135498,0.0138855,0.0140686,0.0142517,0.0142822,0.0144958,0.0140991,0.0135193,0.0125122,0.0110779,0.0094299,0.0072632,0.0049133,0.0023193,-0.0003357,-0.0033569,-0.0062561,-0.0093994,-0.0119629,-0.0146484,-0.0165100,-0.0182190,-0.0188293,-0.0185547,-0.0173645,-0.0149231,-0.0119324,-0.0078430,-0.0032654,0.0016174,0.0066528,0.0112915,0.0158081,0.0196533,0.0233459,0.0260315,0.0290527,0.0309753,0.0332642,0.0351257,0.0370483,0.0389709,0.0412903,0.0437317,0.0466309,0.0499573,0.0534668,0.0573730,0.0611877,0.0649414,0.0684204,0.0713501,0.0736389,0.0749512,0.0752869,0.0742188,0.0722351,0.0687866,0.0644226,0.0586243,0.0521240,0.0451355,0.0371704,0.0298767,0.0222778,0.0162048,0.0103760,0.0062256,0.0031433,0.0014038,0.0007324,0.0012207,0.0023499,0.0044250,0.0069275,0.0095520,0.0124512,0.0151672,0.0180664,0.0204468,0.0228271,0.0246582,0.0264587,0.0278931,0.0292358,0.0301208,0.0309753,0.0315247,0.0322571,0.0322571,0.0330811,0.0330200,0.0336609,0.0334167,0.0332642,0.0324097,0.0312195,0.0290527,0.0261230,0.0221252,0.0173035,0.0117798,0.0056763,-0.0008850,-0.0077820,-0.0145264,-0.0209351,-0.0270386,-0.0326538,-0.0375977,-0.0420837,-0.0459595,-0.0493469,-0.0522461,-0.0550842,-0.0572205,-0.0596313,-0.0612793,-0.0630798,-0.0646667,-0.0659790,-0.0671082,-0.0680237,-0.0683594,-0.0687561,-0.0682983,-0.0683289,-0.0675659,-0.0671997,-0.0664978,-0.0662537,-0.0659180,-0.0662231,-0.0664978,-0.0672913,-0.0684204,-0.0691528,-0.0700073,-0.0702820,-0.0697327,-0.0682983,-0.0659180,-0.0624390,-0.0582581,-0.0532837,-0.0480652,-0.0431824,-0.0388489,-0.0352783,-0.0333252,-0.0322876,-0.0334167,-0.0355530,-0.0390015,-0.0433044,-0.0477905,-0.0524902,-0.0567932,-0.0604858,-0.0630493,-0.0644836,-0.0641785,-0.0623169,-0.0584717,-0.0532227,-0.0461731,-0.0381775,-0.0291443,-0.0197754,-0.0105896,-0.0024109,0.0046082,0.0092468,0.0118713,0.0112915,0.0084534,0.0023499,-0.0056152,-0.0155640,-0.0263977,-0.0373840,-0.0476379,-0.0562744,-0.0626831,-0.0661316,-0.0666199,-0.0633545,-0.0569458,-0.0471802,-0.0351562,-0.0206604,-0.0052795,0.0107727,0.0265198,0.0410156,0.0542297,0.0649109,0.0733643,0.0789490,0.0818481,0.0824890,0.0810242,0.0779724,0.0738525,0.0693054,0.0649109,0.0608215,0.0579529,0.0560913,0.0559082,0.0570068,0.0597534,0.0632629,0.0681458,0.0731506,0.0790100,0.0839539,0.0890198,0.0928650,0.0959167,0.0974731,0.0971375,0.0955200,0.0921631,0.0876465,0.0818481,0.0755005,0.0690613,0.0632019,0.0579834,0.0544434,0.0518494,0.0514832,0.0522766,0.0553894,0.0597534,0.0658569,0.0731812,0.0810547,0.0900269,0.0984497,0.1069031,0.1143494,0.1202393,0.1249084,0.1272583,0.1281433,0.1266785,0.1238403,0.1192627,0.1136169,0.1073303,0.1005859,0.0942078,0.0880127,0.0828552,0.0785522,0.0757141,0.0741272,0.0742188,0.0752563,0.0775146,0.0800171,0.0826111,0.0842285,0.0844116,0.0826111,0.0780640,0.0707397,0.0603027,0.0472412,0.0319519,0.0152283,-0.0025940,-0.0201111,-0.0373230,-0.0523376,-0.0655212,-0.0755920,-0.0822754,-0.0857239,-0.0852966,-0.0820007,-0.0756836,-0.0669861,-0.0567322,-0.0451965,-0.0340576,-0.0228577,-0.0134277,-0.0051880,0.0002136,0.0036316,0.0035095,0.0013428,-0.0040588,-0.0110474,-0.0202942,-0.0302429,-0.0408630,-0.0509033,-0.0599670,-0.0671692,-0.0719910,-0.0741272,-0.0730286,-0.0690613,-0.0622559,-0.0530396,-0.0421448,-0.0296326,-0.0171204,-0.0044250,0.0068970,0.0166931,0.0242004,0.0292053,0.0307922,0.0300598,0.0257568,0.0193176,0.0104675,-0.0002136,-0.0120544,-0.0243835,-0.0370789,-0.0489502,-0.0600891,-0.0693970,-0.0765991,-0.0817261,-0.0838928,-0.0836792,-0.0808105,-0.0757446,-0.0686340,-0.0603943,-0.0509338,-0.0418701,-0.0332031,-0.0256042,-0.0200500,-0.0162964,-0.0153503,-0.0168457,-0.0208435,-0.0271301,-0.0354004,-0.0447388,-0.0552673,-0.0653381,-0.0754395,-0.0833435,-0.0899963,-0.0939331,-0.0952454,-0.0938110,-0.0898132,-0.0833740,-0.0752869,-0.0654297,-0.0553894,-0.0444641,-0.0346375,-0.0253296,-0.0176392,-0.0118713,-0.0080872,-0.0068054,-0.0072021,-0.0102234,-0.0140991,-0.0199280,-0.0258789,-0.0322266,-0.0382385,-0.0434875,-0.0473633,-0.0498047,-0.0504150,-0.0494385,-0.0465698,-0.0424500,-0.0366211,-0.0302124,-0.0227661,-0.0154419,-0.0074158,-0.0002441,0.0064392,0.0119934,0.0162048,0.0187683,0.0202026,0.0194397,0.0180664,0.0152893,0.0122986,0.0092163,0.0062256,0.0039062,0.0025635,0.0024414,0.0037842,0.0062866,0.0099487,0.0146179,0.0200500,0.0260010,0.0317383,0.0371094,0.0416565,0.0450745,0.0466003,0.0463867,0.0443115,0.0401306,0.0348816,0.0276794,0.0198364,0.0115051,0.0028381,-0.0048523,-0.0120239,-0.0173950,-0.0209351,-0.0225830,-0.0213928,-0.0182190,-0.0121155,-0.0040894,0.0061951,0.0177002,0.0305481,0.0431824,0.0560608,0.0675049,0.0780945,0.0864868,0.0930481,0.0973511,0.0994263,0.0996704,0.0977783,0.0949402,0.0909729,0.0865479,0.0823975,0.0784302,0.0760193,0.0746765,0.0752563,0.0780029,0.0823975,0.0893250,0.0973816,0.1073608,0.1175232,0.1287842,0.1388245,0.1483765,0.1554260,0.1606750,0.1625671,0.1614075,0.1563721,0.1481934,0.1365662,0.1217651,0.1044617,0.0851135,0.0646362,0.0435791,0.0227966,0.0032349,-0.0142212,-0.0289612,-0.0402832,-0.0481262,-0.0519714,-0.0523682,-0.0491638,-0.0431519,-0.0347900,-0.0244446,-0.0136108,-0.0020142,0.0086670,0.0182495,0.0263062,0.0314941,0.0346985,0.0346985,0.0326538,0.0281067,0.0223999,0.0155029,0.0087585,0.0022888,-0.0028076,-0.0065308,-0.0084534,-0.0084839,-0.0069580,-0.0036011,0.0006714,0.0059814,0.0114441,0.0168457,0.0217285,0.0254822,0.0282288,0.0292969,0.0288086,0.0267639,0.0233765,0.0189514,0.0144348,0.0097656,0.0061340,0.0030212,0.0010986,-0.0000916,-0.0006104,-0.0002747,-0.0001221,0.0006409,0.0011292,0.0015869,0.0019836,0.0023193,0.0027161,0.0028687,0.0036926,0.0039978,0.0049133,0.0055542,0.0063171,0.0071411,0.0076904,0.0085449,0.0090637,0.0096741,0.0099792,0.0099182,0.0097046,0.0087891,0.0075073,0.0054932,0.0032959,0.0006714,-0.0021973,-0.0047302,-0.0072632,-0.0090027,-0.0102234,-0.0102539,-0.0095825,-0.0073242,-0.0046387,-0.0005798,0.0037842,0.0088501,0.0135498,0.0178223,0.0214844,0.0235596,0.0247803,0.0238953,0.0215759,0.0177917,0.0123291,0.0062256,-0.0005798,-0.0076294,-0.0138550,-0.0195618,-0.0237732,-0.0259094,-0.0266418,-0.0249939,-0.0216064,-0.0168152,-0.0105896,-0.0041504,0.0027771,0.0087280,0.0136108,0.0164185,0.0172119,0.0156860,0.0112915,0.0052490,-0.0030518,-0.0126953,-0.0226440,-0.0332031,-0.0424194,-0.0507812,-0.0570984,-0.0613708,-0.0634766,-0.0632935,-0.0613403,-0.0573730,-0.0524597,-0.0464478,-0.0401917,-0.0337830,-0.0279846,-0.0223694,-0.0183411,-0.0144348,-0.0126953,-0.0112305,-0.0115356,-0.0122681,-0.0141296,-0.0164185,-0.0191345,-0.0220337,-0.0248718,-0.0275879,-0.0296631,-0.0311279,-0.0318604,-0.0315857,-0.0301514,-0.0275879,-0.0237732,-0.0192566,-0.0133057,-0.0070801,0.0003357,0.0072937,0.0147400,0.0215454,0.0277405,0.0335693,0.0378113,0.0416260,0.0438843,0.0452881,0.0455933,0.0449219,0.0432434,0.0410767,0.0382690,0.0353394,0.0322876,0.0293274,0.0268860,0.0247803,0.0232544,0.0221863,0.0214844,0.0214844,0.0217896,0.0220032,0.0224915,0.0222778,0.0224915,0.0216064,0.0212708,0.0197449,0.0184021,0.0165405,0.0146484,0.0128479,0.0115662,0.0107117,0.0107117,0.0120850,0.0145569,0.0183716,0.0236511,0.0298157,0.0371094,0.0447388,0.0524292,0.0595703,0.0658264,0.0709229,0.0744629,0.0762329,0.0761719,0.0744019,0.0711670,0.0664978,0.0606384,0.0538025,0.0465698,0.0388489,0.0311584,0.0238342,0.0168762,0.0109863,0.0053711,0.0010986,-0.0027466,-0.0052795,-0.0080566,-0.0097351,-0.0124512,-0.0146179,-0.0177002,-0.0206299,-0.0242615,-0.0279541,-0.0318604,-0.0357056,-0.0386658,-0.0414429,-0.0433044,-0.0443420,-0.0441284,-0.0432434,-0.0411377,-0.0384216,-0.0351562,-0.0312805,-0.0278320,-0.0241394,-0.0208435,-0.0181885,-0.0160522,-0.0152283,-0.0149231,-0.0154419,-0.0165405,-0.0181580,-0.0199890,-0.0217590,-0.0228882,-0.0234375,-0.0230408,-0.0215759,-0.0190735,-0.0150452,-0.0100708,-0.0046387,0.0016479,0.0073853,0.0132141,0.0178833,0.0216064,0.0238037,0.0243835,0.0232544,0.0203247,0.0160522,0.0102539,0.0041199,-0.0028992,-0.0092468,-0.0153198,-0.0202332,-0.0242004,-0.0267944,-0.0282593,-0.0279846,-0.0270996,-0.0248718,-0.0224304,-0.0192261,-0.0161743,-0.0132446,-0.0108032,-0.0091248,-0.0083618,-0.0086670,-0.0101624,-0.0125122,-0.0158386,-0.0200195,-0.0242920,-0.0290527,-0.0335693,-0.0375061,-0.0408325,-0.0429382,-0.0439758,-0.0437317,-0.0422058,-0.0396729,-0.0357056,-0.0311279,-0.0256348,-0.0198364,-0.0137329,-0.0079346,-0.0029297,0.0015564,0.0040283,0.0057068,0.0051575,0.0040588,0.0011597,-0.0020447,-0.0064392,-0.0106812,-0.0151978,-0.0191040,-0.0228882,-0.0257568,-0.0283813,-0.0297241,-0.0308838,-0.0312500,-0.0311584,-0.0306702,-0.0295715,-0.0285339,-0.0273438,-0.0260010,-0.0253601,-0.0243835,-0.0244141,-0.0244446,-0.0253296,-0.0262146,-0.0273132,-0.0285034,-0.0289917,-0.0291443,-0.0282593,-0.0263672,-0.0236206,-0.0195923,-0.0143127,-0.0079041,-0.0004883,0.0079041,0.0169067,0.0268250,0.0368042,0.0469971,0.0564575,0.0656128,0.0734558,0.0807190,0.0862122,0.0904846,0.0935364,0.0948792,0.0955505,0.0940857,0.0923462,0.0887451,0.0849915,0.0800781,0.0747681,0.0691528,0.0635071,0.0582581,0.0535278,0.0492249,0.0460815,0.0431519,0.0414124,0.0397339,0.0388184,0.0376587,0.0371399,0.0362244,0.0352478,0.0343323,0.0329590,0.0319214,0.0305786,0.0291748,0.0278931,0.0264282,0.0251770,0.0240173,0.0230408,0.0220032,0.0210876,0.0203552,0.0196838,0.0191956,0.0190735,0.0190430,0.0195618,0.0203552,0.0217590,0.0235291,0.0260925,0.0294189,0.0330811,0.0378113,0.0424194,0.0476685,0.0525513,0.0572205,0.0610962,0.0637817,0.0650940,0.0644226,0.0621643,0.0572205,0.0506287,0.0419312,0.0314331,0.0197144,0.0071411,-0.0054932,-0.0183105,-0.0299377,-0.0408020,-0.0495911,-0.0568237,-0.0614929,-0.0639954,-0.0643921,-0.0630798,-0.0598755,-0.0550842,-0.0492554,-0.0446472,-0.0397339,-0.0351868,-0.0313721,-0.0294800,-0.0298462,-0.0317688,-0.0347595,-0.0393066,-0.0450134,-0.0516663,-0.0585938,-0.0649414,-0.0704041,-0.0749817,-0.0782776,-0.0798340,-0.0796204,-0.0776367,-0.0744934,-0.0701294,-0.0652161,-0.0595093,-0.0537720,-0.0485840,-0.0436401,-0.0396423,-0.0360413,-0.0332336,-0.0307922,-0.0290527,-0.0273438,-0.0257263,-0.0242004,-0.0225525,-0.0212097,-0.0195312,-0.0183411,-0.0170593,-0.0164185,-0.0160828,-0.0165710,-0.0176392,-0.0197754,-0.0223083,-0.0264893,-0.0307007,-0.0361023,-0.0413818,-0.0470886,-0.0524597,-0.0571594,-0.0610657,-0.0637207,-0.0651855,-0.0653992,-0.0636902,-0.0611572,-0.0570679,-0.0524292,-0.0470886,-0.0415039,-0.0360718,-0.0311584,-0.0273743,-0.0243530,-0.0231323,-0.0231323,-0.0248413,-0.0276184,-0.0315857,-0.0360107,-0.0410767,-0.0457764,-0.0504761,-0.0540771,-0.0570068,-0.0589294,-0.0596619,-0.0593567,-0.0577698,-0.0553589,-0.0515747,-0.0473328,-0.0421143,-0.0366211,-0.0310059,-0.0252991,-0.0201111,-0.0154724,-0.0117493,-0.0091858,-0.0078125,-0.0077209,-0.0089417,-0.0116272,-0.0149841,-0.0201416,-0.0253601,-0.0315857,-0.0375061,-0.0434265,-0.0487366,-0.0530090,-0.0560913,-0.0578613,-0.0578308,-0.0562134,-0.0527954,-0.0476685,-0.0413513,-0.0335388,-0.0253601,-0.0164795,-0.0076599,0.0009766,0.0089417,0.0162659,0.0230408,0.0287170,0.0336609,0.0377197,0.0412292,0.0443726,0.0473328,0.0498657,0.0531311,0.0560913,0.0597534,0.0632935,0.0671387,0.0709534,0.0744934,0.0776367,0.0799255,0.0814514,0.0817261,0.0807800,0.0787048,0.0755310,0.0718384,0.0673523,0.0630493,0.0583801,0.0543213,0.0501404,0.0468445,0.0438538,0.0415955,0.0402222,0.0396729,0.0396729,0.0405579,0.0415344,0.0429993,0.0443726,0.0454712,0.0461731,0.0466003,0.0464172,0.0460205,0.0452881,0.0440979,0.0433350,0.0421143,0.0412903,0.0405884,0.0398865,0.0398254,0.0394287,0.0396118,0.0397339,0.0399170,0.0399475,0.0397034,0.0389099,0.0377502,0.0356445,0.0332031,0.0299988,0.0268555,0.0228577,0.0197449,0.0161743,0.0137329,0.0112610,0.0099487,0.0085449,0.0083313,0.0080261,0.0080261,0.0082092,0.0079346,0.0075684,0.0063171,0.0047607,0.0021667,-0.0008545,-0.0048523,-0.0095520,-0.0146790,-0.0203247,-0.0261230,-0.0315552,-0.0370178,-0.0410767,-0.0446472,-0.0461731,-0.0465393,-0.0446167,-0.0410767,-0.0358582,-0.0293884,-0.0217896,-0.0135193,-0.0051880,0.0034180,0.0107422,0.0175476,0.0226135,0.0265198,0.0286255,0.0289001,0.0275879,0.0242004,0.0197449,0.0131531,0.0057678,-0.0027771,-0.0118103,-0.0207520,-0.0302124,-0.0384216,-0.0462341,-0.0523071,-0.0573120,-0.0605164,-0.0622559,-0.0621033,-0.0606995,-0.0577393,-0.0536804,-0.0488281,-0.0436401,-0.0381165,-0.0329895,-0.0284424,-0.0247803,-0.0222778,-0.0202026,-0.0193176,-0.0188904,-0.0206909,-0.0220032,-0.0227356,-0.0236206,-0.0256958,-0.0277710,-0.0283813,-0.0272217,-0.0262146,-0.0259705,-0.0253601,-0.0241089,-0.0223999,-0.0211792,-0.0213623,-0.0227051,-0.0241699,-0.0270081,-0.0300598,-0.0348206,-0.0401611,-0.0456848,-0.0513916,-0.0570984,-0.0631409,-0.0686035,-0.0738220,-0.0780029,-0.0813599,-0.0840759,-0.0849915,-0.0853882,-0.0835571,-0.0811462,-0.0773315,-0.0732117,-0.0684814,-0.0635071,-0.0585632,-0.0539856,-0.0502930,-0.0470581,-0.0447388,-0.0430298,-0.0420837,-0.0418701,-0.0419006,-0.0422974,-0.0425720,-0.0427551,-0.0422974,-0.0414124,-0.0395813,-0.0371094,-0.0338440,-0.0299988,-0.0260620,-0.0214844,-0.0174255,-0.0133057,-0.0101318,-0.0073853,-0.0055237,-0.0041199,-0.0034485,-0.0033875,-0.0036316,-0.0043335,-0.0051270,-0.0057373,-0.0067139,-0.0071411,-0.0076294,-0.0078735,-0.0075684,-0.0072021,-0.0066528,-0.0059814,-0.0055847,-0.0047913,-0.0046692,-0.0042725,-0.0045471,-0.0045776,-0.0048828,-0.0046387,-0.0043640,-0.0035095,-0.0018921,0.0001526,0.0033264,0.0071106,0.0115967,0.0174866,0.0240173,0.0314636,0.0398254,0.0484314,0.0579529,0.0670776,0.0762634,0.0845337,0.0915833,0.0970154,0.1002197,0.1015015,0.0997009,0.0957947,0.0890503,0.0804749,0.0694580,0.0576477,0.0443420,0.0312195,0.0181580,0.0059814,-0.0043945,-0.0135498,-0.0198669,-0.0246277,-0.0266724,-0.0272827,-0.0259094,-0.0235291,-0.0205078,-0.0172119,-0.0144958,-0.0121765,-0.0113525,-0.0115967,-0.0136719,-0.0167542,-0.0218201,-0.0274048,-0.0342407,-0.0410156,-0.0481567,-0.0544128,-0.0597839,-0.0642090,-0.0665894,-0.0675354,-0.0664978,-0.0636292,-0.0591125,-0.0527954,-0.0453491,-0.0370178,-0.0281372,-0.0188904,-0.0100403,-0.0017395,0.0053711,0.0112000,0.0155029,0.0175476,0.0181274,0.0167236,0.0137634,0.0093079,0.0036926,-0.0024414,-0.0088806,-0.0156555,-0.0218506,-0.0279541,-0.0331726,-0.0377197,-0.0416565,-0.0444641,-0.0465393,-0.0476074,-0.0480957,-0.0476685,-0.0468750,-0.0453186,-0.0434875,-0.0418396,-0.0394592,-0.0374756,-0.0354614,-0.0333557,-0.0319519,-0.0302124,-0.0291138,-0.0278931,-0.0270996,-0.0263062,-0.0254517,-0.0246887,-0.0237732,-0.0227356,-0.0214233,-0.0196533,-0.0173950,-0.0151978,-0.0122375,-0.0093994,-0.0056763,-0.0023804,0.0009155,0.0036621,0.0058594,0.0073853,0.0076294,0.0067139,0.0043640,0.0012817,-0.0030823,-0.0082092,-0.0140381,-0.0201111,-0.0254211,-0.0303345,-0.0339355,-0.0364685,-0.0375366,-0.0369873,-0.0349426,-0.0313110,-0.0266724,-0.0212097,-0.0147705,-0.0074768,-0.0005188,0.0066223,0.0127563,0.0183411,0.0225830,0.0250549,0.0260010,0.0250854,0.0225220,0.0189209,0.0136414,0.0077515,0.0017395,-0.0042725,-0.0089722,-0.0133057,-0.0158081,-0.0171204,-0.0165100,-0.0142822,-0.0100098,-0.0048218,0.0019226,0.0092468,0.0164185,0.0237122,0.0290833,0.0336304,0.0357361,0.0359802,0.0337219,0.0290222,0.0226440,0.0144043,0.0051270,-0.0046997,-0.0143433,-0.0232849,-0.0304260,-0.0357971,-0.0384521,-0.0386658,-0.0357056,-0.0299072,-0.0213928,-0.0107727,0.0018616,0.0151062,0.0289001,0.0416870,0.0534363,0.0628052,0.0700378,0.0739746,0.0749817,0.0728149,0.0676880,0.0599365,0.0501709,0.0390930,0.0277405,0.0160522,0.0061035,-0.0027466,-0.0088196,-0.0124512,-0.0131226,-0.0105286,-0.0056152,0.0023804,0.0112610,0.0221252,0.0321960,0.0425110,0.0511169,0.0582581,0.0635376,0.0659485,0.0666809,0.0646973,0.0612488,0.0565186,0.0504456,0.0445251,0.0382996,0.0326843,0.0278015,0.0241699,0.0218201,0.0208435,0.0207214,0.0217896,0.0235291,0.0263367,0.0289917,0.0317688,0.0335693,0.0348206,0.0341492,0.0324097,0.0288391,0.0238647,0.0172119,0.0097351,0.0009460,-0.0073547,-0.0162659,-0.0238037,-0.0307922,-0.0361938,-0.0404053,-0.0428467,-0.0438843,-0.0436401,-0.0419312,-0.0396118,-0.0364990,-0.0328979,-0.0295410,-0.0263367,-0.0237732,-0.0220947,-0.0212097,-0.0214844,-0.0223389,-0.0242310,-0.0260620,-0.0284424,-0.0303040,-0.0320435,-0.0327454,-0.0332336,-0.0326233,-0.0318604,-0.0301819,-0.0285950,-0.0264587,-0.0246277,-0.0230408,-0.0216370,-0.0211792,-0.0214844,-0.0225830,-0.0247803,-0.0275879,-0.0311584,-0.0352478,-0.0393677,-0.0434570,-0.0471802,-0.0501099,-0.0523376,-0.0537415,-0.0542297,-0.0541077,-0.0531311,-0.0519714,-0.0505066,-0.0487976,-0.0474854,-0.0458679,-0.0452271,-0.0443420,-0.0444946,-0.0448914,-0.0455627,-0.0465393,-0.0473938,-0.0484314,-0.0487976,-0.0489197,-0.0484924,-0.0473633,-0.0462646,-0.0441284,-0.0425110,-0.0403748,-0.0387268,-0.0372925,-0.0367737,-0.0368347,-0.0377808,-0.0393372,-0.0418091,-0.0446777,-0.0476685,-0.0507202,-0.0536194,-0.0557861,-0.0571289,-0.0572510,-0.0558472,-0.0530396,-0.0484619,-0.0425720,-0.0354919,-0.0274353,-0.0188293,-0.0100403,-0.0018616,0.0057373,0.0122375,0.0172424,0.0205688,0.0220337,0.0216980,0.0201416,0.0170288,0.0131836,0.0090027,0.0045471,0.0012512,-0.0018005,-0.0030518,-0.0034790,-0.0017395,0.0016479,0.0063782,0.0126038,0.0195007,0.0271606,0.0351562,0.0421143,0.0485535,0.0532227,0.0568848,0.0583801,0.0578918,0.0555115,0.0509949,0.0458984,0.0384827,0.0303345,0.0215759,0.0128479,0.0050659,-0.0019226,-0.0079041,-0.0119324,-0.0137939,-0.0131836,-0.0107727,-0.0068054,-0.0012207,0.0060425,0.0143127,0.0231628,0.0321045,0.0405579,0.0494080,0.0574951,0.0652161,0.0717773,0.0772400,0.0823059,0.0862732,0.0897827,0.0921936,0.0944519,0.0962219,0.0977173,0.0991211,0.0999756,0.1009827,0.1017761,0.1026917,0.1029968,0.1034241,0.1030884,0.1029663,0.1022644,0.1012268,0.0993042,0.0969543,0.0939941,0.0906372,0.0867615,0.0826416,0.0782471,0.0740051,0.0697632,0.0660706,0.0628357,0.0602417,0.0583191,0.0572510,0.0562439,0.0563660,0.0560913,0.0567932,0.0570984,0.0577087,0.0578613,0.0576782,0.0573730,0.0561829,0.0549316,0.0525513,0.0501709,0.0467834,0.0435181,0.0391541,0.0356140,0.0309753,0.0270386,0.0226746,0.0186768,0.0149536,0.0114136,0.0079346,0.0047302,0.0012817,-0.0021057,-0.0059204,-0.0096741,-0.0142212,-0.0190125,-0.0243530,-0.0304871,-0.0369568,-0.0442200,-0.0518799,-0.0597534,-0.0677795,-0.0754089,-0.0827026,-0.0890198,-0.0942993,-0.0983276,-0.1005859,-0.1012268,-0.1000061,-0.0965576,-0.0916443,-0.0845947,-0.0763550,-0.0663757,-0.0555420,-0.0438232,-0.0321655,-0.0205383,-0.0095520,-0.0002136,0.0077820,0.0133972,0.0168457,0.0179749,0.0164795,0.0131531,0.0075684,0.0011292,-0.0065613,-0.0145264,-0.0225220,-0.0302124,-0.0370178,-0.0427246,-0.0474243,-0.0504761,-0.0520020,-0.0523376,-0.0509644,-0.0488892,-0.0455322,-0.0417786,-0.0374451,-0.0335693,-0.0297852,-0.0270691,-0.0250854,-0.0241699,-0.0246582,-0.0260925,-0.0286865,-0.0321960,-0.0365601,-0.0411377,-0.0460205,-0.0504150,-0.0546265,-0.0580444,-0.0605469,-0.0621948,-0.0625305,-0.0621338,-0.0604248,-0.0581360,-0.0548096,-0.0510559,-0.0472107,-0.0424500,-0.0381470,-0.0335999,-0.0292664,-0.0251160,-0.0216675,-0.0188599,-0.0165405,-0.0151978,-0.0146790,-0.0150146,-0.0161438,-0.0179138,-0.0204163,-0.0236511,-0.0265808,-0.0303040,-0.0339050,-0.0374756,-0.0411072,-0.0440674,-0.0471802,-0.0494690,-0.0513000,-0.0522461,-0.0524597,-0.0514832,-0.0498962,-0.0468140,-0.0430298,-0.0383301,-0.0328369,-0.0267639,-0.0203857,-0.0135498,-0.0068359,-0.0001831,0.0063782,0.0121460,0.0175171,0.0218506,0.0256958,0.0285339,0.0308228,0.0324402,0.0333252,0.0343628,0.0348816,0.0355530,0.0359497,0.0365295,0.0369873,0.0375366,0.0375366,0.0379028,0.0375366,0.0373230,0.0364685,0.0352783,0.0335083,0.0313110,0.0287781,0.0254822,0.0221558,0.0182495,0.0148621,0.0110779,0.0083313,0.0055847,0.0040894,0.0035400,0.0043030,0.0062866,0.0097961,0.0146179,0.0211792,0.0287170,0.0374451,0.0470581,0.0573120,0.0680237,0.0789185,0.0894775,0.0997314,0.1088257,0.1170349,0.1243591,0.1299133,0.1343994,0.1377563,0.1394043,0.1406250,0.1401672,0.1392517,0.1373596,0.1349792,0.1316223,0.1275635,0.1226807,0.1173706,0.1108093,0.1039124,0.0959778,0.0875244,0.0786438,0.0689392,0.0596008,0.0496521,0.0401917,0.0309143,0.0222168,0.0144043,0.0075684,0.0017700,-0.0026245,-0.0056458,-0.0073853,-0.0072937,-0.0062866,-0.0036316,-0.0000305,0.0039978,0.0089722,0.0138550,0.0188293,0.0237732,0.0279541,0.0317993,0.0345764,0.0368347,0.0381165,0.0386963,0.0385437,0.0379028,0.0365906,0.0351562,0.0333862,0.0313416,0.0295715,0.0273743,0.0254822,0.0237732,0.0213623,0.0196228,0.0171204,0.0145874,0.0117493,0.0083923,0.0045166,0.0003662,-0.0043335,-0.0088806,-0.0142212,-0.0188293,-0.0239563,-0.0280151,-0.0324707,-0.0354309,-0.0386658,-0.0404968,-0.0415955,-0.0417480,-0.0408325,-0.0387878,-0.0356750,-0.0313416,-0.0259399,-0.0197144,-0.0127258,-0.0054321,0.0022278,0.0094299,0.0164185,0.0223999,0.0273438,0.0306396,0.0325317,0.0321960,0.0304871,0.0266418,0.0214539,0.0147400,0.0074158,-0.0006104,-0.0084534,-0.0158997,-0.0223694,-0.0278015,-0.0311890,-0.0333557,-0.0329285,-0.0311584,-0.0270996,-0.0218506,-0.0150452,-0.0081177,-0.0003662,0.0064392,0.0128784,0.0173340,0.0205383,0.0215149,0.0207520,0.0184326,0.0141602,0.0092773,0.0031738,-0.0026245,-0.0088501,-0.0143738,-0.0194397,-0.0232239,-0.0263672,-0.0277710,-0.0283813,-0.0274048,-0.0257263,-0.0231018,-0.0201416,-0.0169678,-0.0140381,-0.0118103,-0.0103455,-0.0096436,-0.0103760,-0.0117493,-0.0143738,-0.0172729,-0.0206299,-0.0244751,-0.0278320,-0.0312805,-0.0339050,-0.0361633,-0.0377197,-0.0386353,-0.0389709,-0.0390015,-0.0387878,-0.0384827,-0.0379333,-0.0375977,-0.0372620,-0.0373230,-0.0372314,-0.0375977,-0.0374756,-0.0375671,-0.0370789,-0.0360413,-0.0345459,-0.0320740,-0.0286255,-0.0240173,-0.0184937,-0.0117493,-0.0041809,0.0043335,0.0132751,0.0225220,0.0317078,0.0407104,0.0488892,0.0563660,0.0626526,0.0674438,0.0711670,0.0728149,0.0732422,0.0718079,0.0690002,0.0653381,0.0609436,0.0561829,0.0513611,0.0468445,0.0431519,0.0403137,0.0382385,0.0376587,0.0379944,0.0395203,0.0422058,0.0456848,0.0497437,0.0543213,0.0589600,0.0633545,0.0671692,0.0698547,0.0715942,0.0715027,0.0701294,0.0668640,0.0623169,0.0563660,0.0495911,0.0421143,0.0347595,0.0276489,0.0212402,0.0161133,0.0122986,0.0104675,0.0101013,0.0119934,0.0149536,0.0200806,0.0258789,0.0324402,0.0392151,0.0451660,0.0506287,0.0538940,0.0559692,0.0551758,0.0522156,0.0465698,0.0389099,0.0290222,0.0178833,0.0058899,-0.0067139,-0.0188599,-0.0305786,-0.0407104,-0.0492554,-0.0555115,-0.0596619,-0.0609436,-0.0599365,-0.0561523,-0.0502319,-0.0424194,-0.0333252,-0.0233154,-0.0129700,-0.0029297,0.0066223,0.0145874,0.0214844,0.0260925,0.0292969,0.0301514,0.0294495,0.0267029,0.0227051,0.0174255,0.0114441,0.0049744,-0.0015259,-0.0074158,-0.0128784,-0.0169067,-0.0199280,-0.0214539,-0.0215454,-0.0205078,-0.0185242,-0.0155640,-0.0126343,-0.0092468,-0.0059509,-0.0031128,-0.0007324,0.0006714,0.0007629,0.0000000,-0.0021973,-0.0059204,-0.0102539,-0.0162048,-0.0225525,-0.0294189,-0.0361023,-0.0423279,-0.0473938,-0.0513000,-0.0536804,-0.0542297,-0.0531921,-0.0501099,-0.0453491,-0.0393066,-0.0314941,-0.0233459,-0.0142822,-0.0052490,0.0035400,0.0118408,0.0187378,0.0246277,0.0282898,0.0307312,0.0308228,0.0293579,0.0261536,0.0213318,0.0155029,0.0087891,0.0015259,-0.0057373,-0.0127258,-0.0192261,-0.0249634,-0.0296936,-0.0333252,-0.0356445,-0.0369873,-0.0369568,-0.0358887,-0.0339661,-0.0314636,-0.0285950,-0.0254822,-0.0227966,-0.0198669,-0.0182495,-0.0162964,-0.0157166,-0.0149231,-0.0149841,-0.0150146,-0.0154419,-0.0159302,-0.0160217,-0.0165405,-0.0166016,-0.0163879,-0.0164795,-0.0156860,-0.0156860,-0.0149841,-0.0149536,-0.0151062,-0.0153198,-0.0163574,-0.0172424,-0.0187073,-0.0204468,-0.0219421,-0.0238647,-0.0252075,-0.0262451,-0.0270386,-0.0270081,-0.0265808,-0.0254211,-0.0238953,-0.0217896,-0.0193176,-0.0163879,-0.0133667,-0.0101013,-0.0068359,-0.0035706,-0.0004272,0.0025635,0.0053101,0.0078430,0.0103149,0.0129089,0.0153503,0.0185852,0.0213013,0.0251770,0.0291443,0.0335083,0.0387268,0.0435486,0.0491333,0.0541382,0.0591431,0.0633240,0.0668335,0.0693359,0.0710449,0.0717468,0.0713501,0.0704651,0.0687866,0.0670166,0.0652466,0.0634766,0.0627747,0.0622864,0.0632324,0.0646057,0.0671692,0.0701294,0.0737000,0.0771484,0.0805969,0.0830688,0.0848389,0.0850220,0.0841980,0.0812073,0.0771790,0.0713196,0.0644836,0.0568237,0.0482788,0.0400696,0.0317383,0.0248108,0.0185547,0.0140991,0.0108948,0.0093994,0.0094910,0.0111694,0.0137024,0.0171814,0.0208740,0.0244446,0.0276794,0.0301208,0.0314941,0.0318298,0.0310059,0.0290222,0.0263062,0.0226135,0.0189514,0.0147400,0.0113525,0.0076904,0.0053711,0.0034180,0.0029602,0.0030212,0.0045776,0.0064087,0.0091553,0.0119019,0.0146790,0.0170593,0.0187683,0.0194092,0.0190125,0.0173950,0.0145569,0.0106812,0.0057678,0.0003052,-0.0056763,-0.0117188,-0.0174255,-0.0233459,-0.0279846,-0.0323792,-0.0355225,-0.0382080,-0.0395203,-0.0403748,-0.0403137,-0.0395508,-0.0388184,-0.0370789,-0.0356750,-0.0338440,-0.0320129,-0.0304565,-0.0280457,-0.0264893,-0.0239563,-0.0223389,-0.0198364,-0.0178833,-0.0156555,-0.0140991,-0.0123901,-0.0115356,-0.0108948,-0.0111084,-0.0119934,-0.0133057,-0.0153809,-0.0178223,-0.0209656,-0.0239563,-0.0275879,-0.0306091,-0.0340271,-0.0372314,-0.0402527,-0.0432129,-0.0454712,-0.0480347,-0.0493164,-0.0509949,-0.0513306,-0.0514526,-0.0508118,-0.0497131,-0.0480042,-0.0458984,-0.0433655,-0.0408020,-0.0378113,-0.0355835,-0.0328674,-0.0312500,-0.0296021,-0.0290527,-0.0287781,-0.0294189,-0.0305786,-0.0320129,-0.0342407,-0.0363770,-0.0391541,-0.0412292,-0.0441589,-0.0458679,-0.0478210,-0.0488586,-0.0493469,-0.0490723,-0.0480347,-0.0462952,-0.0442200,-0.0412598,-0.0387573,-0.0355530,-0.0333862,-0.0308838,-0.0296936,-0.0289307,-0.0292664,-0.0301819,-0.0318604,-0.0340271,-0.0362244,-0.0386658,-0.0405273,-0.0423584,-0.0432739,-0.0439148,-0.0433350,-0.0426636,-0.0408325,-0.0390625,-0.0364685,-0.0343018,-0.0323181,-0.0304871,-0.0296936,-0.0291138,-0.0300293,-0.0314026,-0.0339355,-0.0368652,-0.0404968,-0.0440369,-0.0478821,-0.0509949,-0.0535583,-0.0549622,-0.0550842,-0.0538025,-0.0508728,-0.0462646,-0.0401306,-0.0329285,-0.0248413,-0.0161438,-0.0071716,0.0017090,0.0095520,0.0172424,0.0233459,0.0292358,0.0332947,0.0372620,0.0400696,0.0427551,0.0453491,0.0476074,0.0505066,0.0533142,0.0570984,0.0607605,0.0653381,0.0698547,0.0747070,0.0793762,0.0836182,0.0872803,0.0896301,0.0910339,0.0905457,0.0884399,0.0847778,0.0790710,0.0721741,0.0636292,0.0543823,0.0448608,0.0350952,0.0261841,0.0177307,0.0109253,0.0056763,0.0022278,0.0007935,0.0011597,0.0036011,0.0080566,0.0136108,0.0208740,0.0288086,0.0376282,0.0466003,0.0552368,0.0632935,0.0704346,0.0763855,0.0805054,0.0833435,0.0836792,0.0830078,0.0805359,0.0767212,0.0719910,0.0661926,0.0604553,0.0546265,0.0490417,0.0439453,0.0394287,0.0361023,0.0334473,0.0320740,0.0311890,0.0312195,0.0317383,0.0323486,0.0332642,0.0333557,0.0333252,0.0322266,0.0305481,0.0277100,0.0239868,0.0195007,0.0143433,0.0086975,0.0026550,-0.0034485,-0.0094604,-0.0149536,-0.0201111,-0.0242310,-0.0277405,-0.0298767,-0.0310669,-0.0305481,-0.0292969,-0.0264893,-0.0228577,-0.0181274,-0.0128174,-0.0070190,-0.0010681,0.0048523,0.0103149,0.0151367,0.0187073,0.0215149,0.0225525,0.0223083,0.0204163,0.0172119,0.0127869,0.0070801,0.0010681,-0.0059509,-0.0122375,-0.0191650,-0.0250244,-0.0303650,-0.0348511,-0.0378418,-0.0399780,-0.0404053,-0.0395203,-0.0374146,-0.0339966,-0.0298157,-0.0249023,-0.0203247,-0.0156555,-0.0121460,-0.0094299,-0.0085754,-0.0090637,-0.0112610,-0.0149841,-0.0200806,-0.0264282,-0.0335693,-0.0413513,-0.0489197,-0.0565186,-0.0633545,-0.0690613,-0.0733948,-0.0759888,-0.0771790,-0.0767212,-0.0747070,-0.0718994,-0.0676880,-0.0641174,-0.0602417,-0.0573425,-0.0551147,-0.0544128,-0.0546875,-0.0567017,-0.0595093,-0.0634155,-0.0679932,-0.0727234,-0.0775146,-0.0820312,-0.0853577,-0.0881958,-0.0895081,-0.0896912,-0.0884094,-0.0855103,-0.0815735,-0.0765991,-0.0705261,-0.0641785,-0.0572510,-0.0503235,-0.0438538,-0.0376587,-0.0325012,-0.0278931,-0.0244141,-0.0217590,-0.0199585,-0.0190735,-0.0187073,-0.0189819,-0.0194702,-0.0199585,-0.0203857,-0.0204163,-0.0199585,-0.0188904,-0.0170898,-0.0146484,-0.0111084,-0.0071716,-0.0025330,0.0023193,0.0075378,0.0123901,0.0172729,0.0211182,0.0244446,0.0258179,0.0266113,0.0253906,0.0227356,0.0182495,0.0125122,0.0065002,-0.0003052,-0.0078430,-0.0153198,-0.0217896,-0.0265503,-0.0303955,-0.0326233,-0.0335693,-0.0317993,-0.0283813,-0.0229797,-0.0167542,-0.0090637,-0.0008545,0.0079651,0.0164185,0.0243530,0.0308838,0.0367737,0.0407715,0.0433960,0.0441589,0.0433044,0.0413208,0.0385437,0.0347595,0.0312805,0.0274658,0.0247803,0.0230408,0.0225525,0.0234070,0.0256348,0.0293884,0.0337830,0.0392761,0.0445862,0.0496826,0.0543823,0.0572510,0.0593567,0.0590210,0.0571594,0.0532532,0.0472412,0.0399475,0.0307617,0.0208740,0.0101624,-0.0005493,-0.0109253,-0.0207520,-0.0290222,-0.0359192,-0.0408630,-0.0439453,-0.0447693,-0.0441589,-0.0415344,-0.0375061,-0.0318909,-0.0256348,-0.0191956,-0.0127563,-0.0061646,-0.0003357,0.0045471,0.0072632,0.0089722,0.0086975,0.0071106,0.0036316,-0.0016174,-0.0081482,-0.0153198,-0.0233459,-0.0312500,-0.0397644,-0.0468750,-0.0534363,-0.0583801,-0.0619812,-0.0636902,-0.0638733,-0.0617676,-0.0583191,-0.0536194,-0.0474243,-0.0409546,-0.0334778,-0.0264893,-0.0196838,-0.0139771,-0.0088806,-0.0051575,-0.0025024,-0.0014038,-0.0013123,-0.0025024,-0.0046997,-0.0076599,-0.0117188,-0.0157471,-0.0205688,-0.0249634,-0.0296326,-0.0334167,-0.0370483,-0.0395203,-0.0413513,-0.0422058,-0.0421753,-0.0413513,-0.0396423,-0.0369873,-0.0343018,-0.0305176,-0.0268555,-0.0228271,-0.0185547,-0.0147400,-0.0106812,-0.0076294,-0.0047607,-0.0032043,-0.0023804,-0.0024109,-0.0041809,-0.0064087,-0.0098267,-0.0141296,-0.0183716,-0.0237427,-0.0283203,-0.0330200,-0.0370789,-0.0401001,-0.0424500,-0.0436096,-0.0433960,-0.0422363,-0.0397339,-0.0363464,-0.0323486,-0.0277710,-0.0229187,-0.0184021,-0.0138855,-0.0104065,-0.0068665,-0.0047302,-0.0027466,-0.0019836,-0.0013123,-0.0013733,-0.0014648,-0.0021057,-0.0023804,-0.0029297,-0.0028381,-0.0027771,-0.0021362,-0.0015259,-0.0004578,0.0003662,0.0012817,0.0015564,0.0016174,0.0007324,-0.0005798,-0.0027161,-0.0052490,-0.0082703,-0.0112305,-0.0140686,-0.0163879,-0.0179443,-0.0185547,-0.0177307,-0.0158386,-0.0123291,-0.0075989,-0.0015869,0.0055847,0.0134277,0.0220947,0.0307922,0.0395813,0.0477600,0.0549927,0.0613708,0.0661926,0.0694885,0.0714722,0.0716553,0.0706482,0.0682373,0.0649414,0.0607605,0.0558167,0.0506897,0.0450134,0.0398560,0.0344238,0.0298767,0.0258789,0.0227356,0.0206909,0.0193481,0.0193176,0.0197754,0.0209656,0.0223083,0.0239563,0.0252075,0.0262756,0.0264893,0.0259094,0.0242310,0.0212097,0.0173035,0.0118103,0.0056152,-0.0020447,-0.0095215,-0.0178528,-0.0252686,-0.0323181,-0.0377197,-0.0411682,-0.0425415,-0.0412292,-0.0374451,-0.0309753,-0.0220947,-0.0111694,0.0012817,0.0147400,0.0285950,0.0420837,0.0544434,0.0652161,0.0733643,0.0787964,0.0809021,0.0795288,0.0747375,0.0665894,0.0559998,0.0431824,0.0290222,0.0142212,-0.0002441,-0.0137329,-0.0256653,-0.0354919,-0.0430908,-0.0481873,-0.0511780,-0.0519714,-0.0512085,-0.0492249,-0.0465393,-0.0435181,-0.0411987,-0.0390320,-0.0381470,-0.0377502,-0.0389099,-0.0403748,-0.0426331,-0.0448303,-0.0474243,-0.0498962,-0.0513306,-0.0515747,-0.0513916,-0.0503845,-0.0477905,-0.0431824,-0.0377502,-0.0320129,-0.0258789,-0.0195007,-0.0125732,-0.0064697,-0.0009766,0.0031433,0.0067444,0.0089417,0.0106506,0.0106201,0.0099182,0.0077515,0.0052795,0.0018311,-0.0020447,-0.0067749,-0.0115662,-0.0158386,-0.0204468,-0.0246277,-0.0287476,-0.0317383,-0.0339661,-0.0358887,-0.0373230,-0.0386353,-0.0391541,-0.0393982,-0.0395508,-0.0393372,-0.0392456,-0.0389709,-0.0383911,-0.0378113,-0.0375061,-0.0368347,-0.0366516,-0.0365295,-0.0364990,-0.0366821,-0.0370178,-0.0371704,-0.0373230,-0.0371399,-0.0366821,-0.0359802,-0.0347290,-0.0330505,-0.0310364,-0.0284119,-0.0261841,-0.0235596,-0.0214844,-0.0196838,-0.0187378,-0.0189209,-0.0202026,-0.0226135,-0.0266113,-0.0313110,-0.0374451,-0.0440979,-0.0512390,-0.0581360,-0.0647583,-0.0707703,-0.0753174,-0.0788574,-0.0803833,-0.0802307,-0.0784302,-0.0746155,-0.0691528,-0.0626526,-0.0547791,-0.0467224,-0.0382690,-0.0303345,-0.0227356,-0.0164490,-0.0109863,-0.0071716,-0.0043640,-0.0029297,-0.0024414,-0.0028381,-0.0036316,-0.0045471,-0.0054321,-0.0056152,-0.0054016,-0.0042725,-0.0020447,0.0010986,0.0053711,0.0105896,0.0165405,0.0229797,0.0298157,0.0364685,0.0428772,0.0486755,0.0532227,0.0573120,0.0595093,0.0613708,0.0613098,0.0608826,0.0594177,0.0578308,0.0556030,0.0540161,0.0520630,0.0512390,0.0502625,0.0505981,0.0511169,0.0526428,0.0542297,0.0566406,0.0589294,0.0614014,0.0636292,0.0652161,0.0661621,0.0664062,0.0656738,0.0639648,0.0614014,0.0581970,0.0542908,0.0502014,0.0458069,0.0417175,0.0382385,0.0351257,0.0332031,0.0322876,0.0325623,0.0341797,0.0368958,0.0408325,0.0455017,0.0505371,0.0557556,0.0605164,0.0649414,0.0680542,0.0703735,0.0711365,0.0710144,0.0696716,0.0673523,0.0643921,0.0608215,0.0574036,0.0537109,0.0503235,0.0472412,0.0447083,0.0432434,0.0420837,0.0419922,0.0421448,0.0433960,0.0446777,0.0464172,0.0474243,0.0484924,0.0487061,0.0486145,0.0471191,0.0446777,0.0416565,0.0377808,0.0333252,0.0280762,0.0224915,0.0170288,0.0119019,0.0069885,0.0022278,-0.0020447,-0.0053711,-0.0078735,-0.0100098,-0.0113525,-0.0132446,-0.0141907,-0.0154724,-0.0172119,-0.0190735,-0.0221863,-0.0251770,-0.0289001,-0.0329895,-0.0373840,-0.0417786,-0.0460815,-0.0498962,-0.0533752,-0.0558472,-0.0576782,-0.0581055,-0.0572815,-0.0554199,-0.0524902,-0.0486450,-0.0439758,-0.0385437,-0.0331421,-0.0273132,-0.0220337,-0.0167236,-0.0123901,-0.0082703,-0.0050659,-0.0027466,-0.0011902,-0.0009460,-0.0012207,-0.0029602,-0.0050964,-0.0087891,-0.0126648,-0.0171509,-0.0219116,-0.0264282,-0.0310364,-0.0347900,-0.0379639,-0.0402832,-0.0419617,-0.0426025,-0.0422668,-0.0406799,-0.0381165,-0.0346985,-0.0309143,-0.0257874,-0.0209656,-0.0157471,-0.0111084,-0.0071106,-0.0041199,-0.0021973,-0.0020447,-0.0034485,-0.0068054,-0.0115967,-0.0181274,-0.0257874,-0.0347595,-0.0444336,-0.0542908,-0.0641479,-0.0732727,-0.0814819,-0.0883179,-0.0932312,-0.0962524,-0.0969543,-0.0957947,-0.0924377,-0.0873718,-0.0811462,-0.0740662,-0.0669556,-0.0599670,-0.0538025,-0.0491638,-0.0460815,-0.0451660,-0.0462036,-0.0491028,-0.0539856,-0.0604248,-0.0676270,-0.0759583,-0.0833740,-0.0908508,-0.0962830,-0.1005859,-0.1019897,-0.1013489,-0.0975952,-0.0914917,-0.0828552,-0.0723572,-0.0602722,-0.0470886,-0.0338135,-0.0208130,-0.0085754,0.0016174,0.0106812,0.0165710,0.0207825,0.0222473,0.0215149,0.0191345,0.0146179,0.0095215,0.0034790,-0.0024719,-0.0079651,-0.0126038,-0.0159912,-0.0176697,-0.0176086,-0.0157471,-0.0119934,-0.0068054,-0.0001526,0.0074158,0.0158081,0.0241089,0.0323486,0.0395813,0.0461121,0.0510864,0.0550842,0.0573425,0.0584717,0.0586243,0.0578918,0.0566711,0.0556641,0.0541992,0.0536499,0.0534363,0.0539856,0.0559082,0.0577393,0.0613403,0.0646973,0.0690308,0.0734253,0.0775452,0.0821533,0.0856323,0.0892944,0.0920410,0.0943604,0.0962830,0.0975037,0.0985107,0.0990601,0.0993652,0.0995789,0.0996094,0.0996704,0.0997314,0.0994568,0.0991821,0.0985413,0.0971985,0.0955200,0.0926819,0.0892639,0.0847168,0.0794373,0.0730591,0.0660095,0.0586548,0.0508118,0.0435181,0.0356445,0.0290222,0.0223389,0.0171814,0.0125427,0.0089722,0.0064392,0.0049438,0.0042725,0.0044250,0.0054016,0.0066833,0.0085449,0.0106201,0.0129089,0.0149231,0.0170593,0.0190735,0.0209656,0.0227051,0.0243530,0.0258179,0.0273132,0.0287476,0.0298767,0.0314941,0.0325012,0.0338745,0.0346680,0.0354614,0.0358582,0.0356445,0.0356445,0.0345154,0.0335083,0.0318298,0.0297852,0.0274963,0.0249939,0.0223083,0.0197754,0.0172424,0.0148621,0.0130615,0.0108948,0.0097961,0.0077820,0.0066833,0.0048828,0.0030518,0.0008850,-0.0018005,-0.0047607,-0.0081482,-0.0118408,-0.0155334,-0.0190735,-0.0224915,-0.0253296,-0.0276794,-0.0290222,-0.0295715,-0.0290833,-0.0271606,-0.0243530,-0.0202332,-0.0150757,-0.0090942,-0.0022583,0.0047913,0.0116577,0.0183716,0.0239868,0.0292969,0.0323181,0.0343323,0.0343628,0.0330200,0.0297546,0.0252991,0.0191040,0.0123291,0.0046387,-0.0029907,-0.0108643,-0.0184021,-0.0250244,-0.0305786,-0.0346985,-0.0378418,-0.0393372,-0.0398865,-0.0385742,-0.0366211,-0.0339966,-0.0311279,-0.0283813,-0.0253296,-0.0229492,-0.0212402,-0.0202942,-0.0203552,-0.0204773,-0.0218811,-0.0234985,-0.0257263,-0.0278015,-0.0293884,-0.0310059,-0.0315552,-0.0312805,-0.0299377,-0.0271606,-0.0235901,-0.0193787,-0.0140991,-0.0086060,-0.0028992,0.0027771,0.0073242,0.0118408,0.0145569,0.0166626,0.0167847,0.0156555,0.0124512,0.0077820,0.0015869,-0.0058899,-0.0141907,-0.0232544,-0.0322266,-0.0411987,-0.0489807,-0.0559998,-0.0614624,-0.0650024,-0.0668945,-0.0667419,-0.0646057,-0.0610046,-0.0556030,-0.0489502,-0.0415649,-0.0332031,-0.0253296,-0.0169373,-0.0098267,-0.0028992,0.0024109,0.0070190,0.0103760,0.0128174,0.0144348,0.0155334,0.0164490,0.0175171,0.0186157,0.0205078,0.0226440,0.0257874,0.0297546,0.0343628,0.0401917,0.0460510,0.0529785,0.0594177,0.0661621,0.0721436,0.0776672,0.0818481,0.0853271,0.0876160,0.0882263,0.0881653,0.0863953,0.0839233,0.0804443,0.0761719,0.0716858,0.0666504,0.0617065,0.0571289,0.0523987,0.0486450,0.0448303,0.0418091,0.0391846,0.0369873,0.0351562,0.0336914,0.0321350,0.0310669,0.0295105,0.0282898,0.0265198,0.0248413,0.0228882,0.0205994,0.0184937,0.0155945,0.0135498,0.0104980,0.0081177,0.0052490,0.0026245,-0.0000916,-0.0028076,-0.0055847,-0.0083008,-0.0109558,-0.0135803,-0.0162659,-0.0189514,-0.0214539,-0.0237732,-0.0258179,-0.0274353,-0.0286865,-0.0291748,-0.0289307,-0.0278625,-0.0256958,-0.0228577,-0.0191345,-0.0144043,-0.0094604,-0.0038147,0.0014038,0.0071716,0.0121765,0.0169373,0.0206909,0.0235291,0.0257874,0.0266418,0.0263672,0.0252686,0.0228577,0.0197754,0.0157166,0.0111694,0.0063171,0.0011902,-0.0038452,-0.0086670,-0.0128784,-0.0166626,-0.0196228,-0.0219727,-0.0235291,-0.0242310,-0.0242920,-0.0237122,-0.0228577,-0.0217285,-0.0202942,-0.0191956,-0.0179443,-0.0174255,-0.0169983,-0.0169983,-0.0176697,-0.0180664,-0.0192261,-0.0199890,-0.0209656,-0.0216675,-0.0219727,-0.0219727,-0.0212402,-0.0199890,-0.0180359,-0.0155029,-0.0124207,-0.0087585,-0.0050964,-0.0010681,0.0023804,0.0054932,0.0076904,0.0093384,0.0093994,0.0089722,0.0070496,0.0048218,0.0015564,-0.0018616,-0.0053406,-0.0086365,-0.0115356,-0.0138245,-0.0155334,-0.0163269,-0.0164490,-0.0158081,-0.0146179,-0.0133057,-0.0113525,-0.0100708,-0.0083923,-0.0079651,-0.0079346,-0.0090637,-0.0111084,-0.0142212,-0.0177612,-0.0225830,-0.0269165,-0.0319824,-0.0360107,-0.0397339,-0.0420227,-0.0434875,-0.0433044,-0.0419006,-0.0390930,-0.0352783,-0.0305786,-0.0248718,-0.0191956,-0.0127869,-0.0072937,-0.0016785,0.0029297,0.0069275,0.0099792,0.0119019,0.0126343,0.0122986,0.0108337,0.0088196,0.0057068,0.0023193,-0.0016785,-0.0052490,-0.0092468,-0.0123291,-0.0153198,-0.0173645,-0.0189819,-0.0196838,-0.0198364,-0.0186768,-0.0173340,-0.0147400,-0.0117493,-0.0081787,-0.0041809,0.0004883,0.0049133,0.0097961,0.0140991,0.0185547,0.0223999,0.0258789,0.0287781,0.0306091,0.0322571,0.0326538,0.0328674,0.0320129,0.0311890,0.0298462,0.0288086,0.0278015,0.0274963,0.0278931,0.0293579,0.0323792,0.0363159,0.0415649,0.0480347,0.0549927,0.0629578,0.0702515,0.0779724,0.0841370,0.0897522,0.0933533,0.0955505,0.0955505,0.0935974,0.0896912,0.0838318,0.0766907,0.0681458,0.0593262,0.0498352,0.0408936,0.0323181,0.0253296,0.0189209,0.0147095,0.0111389,0.0095215,0.0086060,0.0087891,0.0096130,0.0106201,0.0122681,0.0132141,0.0143433,0.0146484,0.0149536,0.0144348,0.0136108,0.0124207,0.0105591,0.0094299,0.0077209,0.0067749,0.0064087,0.0062561,0.0070801,0.0082703,0.0099487,0.0120850,0.0143738,0.0165710,0.0187378,0.0202637,0.0219421,0.0224304,0.0228577,0.0224915,0.0218201,0.0207520,0.0195312,0.0183411,0.0171204,0.0164795,0.0157471,0.0159607,0.0160217,0.0167847,0.0177612,0.0185852,0.0194092,0.0198364,0.0199585,0.0193481,0.0184021,0.0167236,0.0145874,0.0120239,0.0088196,0.0055237,0.0018311,-0.0017700,-0.0054626,-0.0087891,-0.0117798,-0.0143127,-0.0159607,-0.0173035,-0.0170898,-0.0166321,-0.0145874,-0.0124817,-0.0090942,-0.0052795,-0.0011597,0.0034790,0.0081787,0.0128784,0.0175781,0.0218506,0.0255432,0.0286255,0.0304871,0.0314941,0.0310974,0.0292053,0.0260620,0.0212708,0.0153503,0.0082703,0.0003052,-0.0082092,-0.0170593,-0.0257874,-0.0340576,-0.0419312,-0.0486145,-0.0546875,-0.0590820,-0.0627136,-0.0647888,-0.0657654,-0.0659485,-0.0646362,-0.0631714,-0.0604858,-0.0578918,-0.0548096,-0.0516663,-0.0485840,-0.0458069,-0.0433350,-0.0411682,-0.0393372,-0.0379333,-0.0369873,-0.0362244,-0.0358887,-0.0354614,-0.0353699,-0.0354004,-0.0353699,-0.0354614,-0.0354919,-0.0360107,-0.0360718,-0.0365601,-0.0369263,-0.0372314,-0.0374756,-0.0372925,-0.0371704,-0.0362854,-0.0357056,-0.0343018,-0.0333557,-0.0321045,-0.0311279,-0.0304565,-0.0303650,-0.0309753,-0.0323181,-0.0347900,-0.0379944,-0.0424500,-0.0480042,-0.0540161,-0.0610352,-0.0678101,-0.0748291,-0.0810242,-0.0864258,-0.0903931,-0.0926819,-0.0932922,-0.0917053,-0.0882568,-0.0829468,-0.0760498,-0.0677185,-0.0584412,-0.0480652,-0.0377808,-0.0271301,-0.0172424,-0.0076904,0.0007019,0.0080872,0.0141602,0.0190125,0.0225525,0.0254211,0.0270386,0.0286560,0.0294189,0.0304871,0.0313110,0.0325623,0.0339355,0.0355530,0.0377502,0.0396729,0.0422058,0.0441284,0.0462341,0.0474243,0.0483093,0.0485840,0.0477295,0.0461121,0.0435181,0.0403748,0.0365295,0.0321655,0.0279846,0.0235291,0.0197144,0.0161438,0.0130615,0.0110474,0.0094604,0.0088196,0.0089722,0.0097656,0.0110779,0.0131836,0.0156250,0.0184326,0.0216370,0.0248413,0.0282593,0.0316467,0.0353088,0.0385742,0.0423584,0.0459290,0.0496826,0.0535889,0.0574341,0.0616455,0.0653381,0.0693359,0.0726929,0.0758667,0.0786438,0.0806885,0.0822754,0.0831604,0.0829773,0.0821533,0.0803833,0.0777893,0.0744629,0.0700378,0.0654602,0.0596619,0.0544739,0.0482483,0.0428772,0.0372925,0.0323792,0.0279846,0.0242920,0.0213013,0.0191345,0.0177917,0.0172424,0.0173340,0.0180359,0.0191650,0.0207520,0.0224915,0.0244446,0.0261536,0.0277710,0.0289001,0.0300903,0.0303345,0.0307007,0.0300903,0.0298462,0.0287476,0.0281067,0.0270081,0.0263062,0.0251465,0.0245361,0.0232239,0.0223694,0.0209351,0.0194397,0.0176697,0.0154114,0.0130920,0.0104370,0.0075989,0.0049133,0.0022583,-0.0001831,-0.0020752,-0.0038147,-0.0045471,-0.0051880,-0.0048218,-0.0043030,-0.0030212,-0.0018616,-0.0005188,0.0006104,0.0010681,0.0010071,-0.0002747,-0.0021667,-0.0051270,-0.0089111,-0.0130615,-0.0178833,-0.0222168,-0.0263367,-0.0300598,-0.0325317,-0.0341797,-0.0346680,-0.0337830,-0.0325317,-0.0296936,-0.0270996,-0.0235901,-0.0207520,-0.0181274,-0.0164490,-0.0161438,-0.0169373,-0.0198364,-0.0241394,-0.0301819,-0.0372009,-0.0456238,-0.0543518,-0.0632935,-0.0717773,-0.0791931,-0.0855713,-0.0899963,-0.0928650,-0.0934753,-0.0918579,-0.0887146,-0.0834045,-0.0773010,-0.0697021,-0.0622559,-0.0546570,-0.0481262,-0.0423584,-0.0382690,-0.0352783,-0.0343018,-0.0344238,-0.0358276,-0.0382385,-0.0410156,-0.0442505,-0.0469666,-0.0493774,-0.0507812,-0.0511780,-0.0501404,-0.0481567,-0.0442505,-0.0401917,-0.0341187,-0.0284119,-0.0216980,-0.0153809,-0.0093994,-0.0038757,0.0005493,0.0045471,0.0067139,0.0084229,0.0079651,0.0076294,0.0051880,0.0025940,-0.0011902,-0.0050964,-0.0095825,-0.0136414,-0.0180969,-0.0214844,-0.0248108,-0.0271301,-0.0285950,-0.0295105,-0.0293579,-0.0285645,-0.0270996,-0.0246887,-0.0222473,-0.0187988,-0.0155334,-0.0118103,-0.0079041,-0.0043030,-0.0005188,0.0027771,0.0063782,0.0093079,0.0125122,0.0155945,0.0184937,0.0220337,0.0251465,0.0288086,0.0322876,0.0361938,0.0398254,0.0434875,0.0465088,0.0494080,0.0515137,0.0530090,0.0534058,0.0530396,0.0515442,0.0490723,0.0459595,0.0418396,0.0377808,0.0331116,0.0285034,0.0244751,0.0206604,0.0181274,0.0161133,0.0153198,0.0152588,0.0164795,0.0181580,0.0207214,0.0234680,0.0260010,0.0283813,0.0298157,0.0303650,0.0296631,0.0277100,0.0243530,0.0199890,0.0144348,0.0082092,0.0016174,-0.0049438,-0.0111694,-0.0165405,-0.0213013,-0.0244751,-0.0266724,-0.0272217,-0.0267334,-0.0247192,-0.0221863,-0.0186462,-0.0154419,-0.0117798,-0.0093384,-0.0071716,-0.0063782,-0.0066223,-0.0082092,-0.0108337,-0.0145874,-0.0188599,-0.0242004,-0.0292969,-0.0347900,-0.0400085,-0.0447693,-0.0488586,-0.0523682,-0.0549011,-0.0566406,-0.0576782,-0.0576477,-0.0572205,-0.0559387,-0.0542908,-0.0522766,-0.0498657,-0.0473328,-0.0444641,-0.0413818,-0.0380554,-0.0344543,-0.0307922,-0.0267334,-0.0228882,-0.0188904,-0.0154724,-0.0118713,-0.0091858,-0.0065308,-0.0048828,-0.0038452,-0.0034180,-0.0041809,-0.0056458,-0.0084229,-0.0119019,-0.0162048,-0.0210876,-0.0263977,-0.0317993,-0.0368347,-0.0415649,-0.0455627,-0.0486450,-0.0509338,-0.0518494,-0.0519104,-0.0509338,-0.0490723,-0.0465088,-0.0432739,-0.0396118,-0.0359192,-0.0319214,-0.0284729,-0.0252075,-0.0227661,-0.0209961,-0.0199890,-0.0198669,-0.0201416,-0.0211792,-0.0222473,-0.0238037,-0.0249634,-0.0260925,-0.0268555,-0.0274048,-0.0270996,-0.0270996,-0.0261230,-0.0251465,-0.0237732,-0.0221252,-0.0203552,-0.0183411,-0.0162659,-0.0139465,-0.0122070,-0.0098572,-0.0079346,-0.0059814,-0.0040588,-0.0025940,-0.0007629,0.0005493,0.0020142,0.0032349,0.0047607,0.0061035,0.0078430,0.0093994,0.0114441,0.0134583,0.0156250,0.0180664,0.0202026,0.0228271,0.0249023,0.0270691,0.0289001,0.0308533,0.0324097,0.0339050,0.0352478,0.0365906,0.0379639,0.0395203,0.0411072,0.0430908,0.0452576,0.0480652,0.0507812,0.0542908,0.0572510,0.0604248,0.0630188,0.0651550,0.0663147,0.0664673,0.0652466,0.0627747,0.0590820,0.0540161,0.0477295,0.0403748,0.0322876,0.0240479,0.0155640,0.0078430,0.0007019,-0.0047913,-0.0087585,-0.0108948,-0.0108643,-0.0088196,-0.0052185,0.0005493,0.0070801,0.0149231,0.0228882,0.0308228,0.0384216,0.0448303,0.0498352,0.0531616,0.0547180,0.0539856,0.0517578,0.0472717,0.0416260,0.0350037,0.0274048,0.0207214,0.0132446,0.0079041,0.0030518,0.0001526,-0.0010376,-0.0007324,0.0011902,0.0044861,0.0085754,0.0132141,0.0180054,0.0220642,0.0252991,0.0272522,0.0274963,0.0260925,0.0225525,0.0172119,0.0104980,0.0020447,-0.0066528,-0.0162048,-0.0253601,-0.0340881,-0.0415344,-0.0476685,-0.0518799,-0.0542603,-0.0540771,-0.0524292,-0.0485229,-0.0433044,-0.0369568,-0.0293884,-0.0221558,-0.0144958,-0.0082092,-0.0025330,0.0017395,0.0043640,0.0050049,0.0042725,0.0015869,-0.0023193,-0.0076599,-0.0137329,-0.0203247,-0.0268250,-0.0330505,-0.0383911,-0.0425720,-0.0455322,-0.0466919,-0.0466309,-0.0447388,-0.0416870,-0.0374146,-0.0322266,-0.0268555,-0.0210266,-0.0157471,-0.0108032,-0.0071106,-0.0040894,-0.0029297,-0.0025940,-0.0039673,-0.0062866,-0.0095520,-0.0137634,-0.0180359,-0.0227051,-0.0268860,-0.0305786,-0.0331726,-0.0346680,-0.0347290,-0.0333252,-0.0306702,-0.0263367,-0.0212708,-0.0149536,-0.0084839,-0.0017395,0.0043335,0.0098267,0.0140991,0.0170898,0.0184937,0.0181885,0.0164490,0.0130920,0.0086365,0.0032349,-0.0026855,-0.0091248,-0.0153809,-0.0213928,-0.0266113,-0.0310669,-0.0343933,-0.0365295,-0.0373230,-0.0369873,-0.0356750,-0.0333862,-0.0305786,-0.0277405,-0.0248413,-0.0228882,-0.0214233,-0.0213928,-0.0220337,-0.0245972,-0.0277100,-0.0325928,-0.0379639,-0.0440979,-0.0507507,-0.0572205,-0.0635986,-0.0697021,-0.0746155,-0.0788574,-0.0815735,-0.0830383,-0.0830383,-0.0813293,-0.0784302,-0.0738831,-0.0684204,-0.0620117,-0.0545349,-0.0474243,-0.0397644,-0.0328064,-0.0263672,-0.0208435,-0.0167236,-0.0137939,-0.0124512,-0.0125427,-0.0140686,-0.0168762,-0.0203552,-0.0247498,-0.0290222,-0.0331421,-0.0364685,-0.0387573,-0.0394287,-0.0384521,-0.0352478,-0.0303040,-0.0231323,-0.0145569,-0.0042419,0.0070190,0.0188293,0.0306702,0.0419312,0.0522156,0.0611267,0.0681763,0.0732727,0.0760193,0.0769348,0.0756226,0.0728149,0.0684814,0.0635986,0.0582275,0.0531311,0.0489197,0.0453491,0.0440063,0.0435181,0.0451355,0.0481567,0.0523987,0.0578308,0.0637512,0.0696411,0.0752563,0.0798645,0.0831909,0.0849915,0.0846863,0.0826111,0.0787659,0.0729065,0.0659485,0.0578003,0.0489807,0.0403748,0.0318604,0.0246277,0.0183411,0.0137634,0.0106201,0.0096436,0.0098572,0.0123291,0.0152893,0.0197144,0.0243530,0.0292969,0.0342102,0.0382996,0.0419006,0.0439453,0.0454102,0.0449829,0.0439148,0.0414124,0.0382080,0.0344543,0.0301208,0.0261536,0.0221558,0.0187988,0.0160828,0.0141907,0.0132751,0.0133667,0.0140686,0.0157776,0.0180969,0.0209351,0.0240784,0.0271301,0.0300903,0.0326538,0.0347900,0.0360107,0.0367432,0.0364380,0.0355835,0.0339050,0.0316772,0.0288696,0.0257263,0.0224915,0.0191956,0.0160522,0.0133362,0.0110474,0.0094299,0.0086365,0.0084534,0.0089417,0.0102844,0.0118103,0.0140686,0.0162659,0.0187073,0.0208130,0.0226440,0.0233154,0.0240479,0.0235596,0.0221863,0.0193176,0.0159302,0.0119019,0.0074463,0.0019836,-0.0039368,-0.0098267,-0.0155945,-0.0207825,-0.0256958,-0.0301208,-0.0338135,-0.0364990,-0.0385742,-0.0397949,-0.0408936,-0.0411072,-0.0413208,-0.0407715,-0.0408020,-0.0401917,-0.0399170,-0.0393372,-0.0386658,-0.0380249,-0.0372620,-0.0362854,-0.0351257,-0.0337219,-0.0320740,-0.0303345,-0.0289001,-0.0271606,-0.0255432,-0.0236511,-0.0227356,-0.0220337,-0.0219727,-0.0222778,-0.0230713,-0.0246887,-0.0268250,-0.0296326,-0.0327454,-0.0362549,-0.0402527,-0.0443115,-0.0487061,-0.0528259,-0.0571899,-0.0612793,-0.0656128,-0.0693665,-0.0735779,-0.0768433,-0.0805359,-0.0840149,-0.0872192,-0.0903931,-0.0928955,-0.0955811,-0.0975952,-0.0996094,-0.1007080,-0.1015625,-0.1016846,-0.1011353,-0.1001587,-0.0984192,-0.0958862,-0.0930176,-0.0894165,-0.0856323,-0.0814209,-0.0769653,-0.0724487,-0.0679932,-0.0635071,-0.0591431,-0.0549927,-0.0506897,-0.0469055,-0.0426331,-0.0386047,-0.0341797,-0.0296021,-0.0248413,-0.0199280,-0.0150452,-0.0099182,-0.0053711,-0.0008850,0.0029297,0.0058289,0.0082397,0.0091248,0.0096436,0.0084229,0.0065002,0.0039368,-0.0000305,-0.0035400,-0.0082397,-0.0119629,-0.0158691,-0.0186462,-0.0207520,-0.0214539,-0.0209351,-0.0186157,-0.0152283,-0.0095825,-0.0032959,0.0049133,0.0135803,0.0233765,0.0330505,0.0432129,0.0526733,0.0617676,0.0700378,0.0769043,0.0831299,0.0875854,0.0914612,0.0938721,0.0953674,0.0962524,0.0961304,0.0956421,0.0946655,0.0932922,0.0918274,0.0900574,0.0881958,0.0858154,0.0834656,0.0805359,0.0773621,0.0737915,0.0696716,0.0653992,0.0605469,0.0554810,0.0499268,0.0445557,0.0387268,0.0332336,0.0275574,0.0221252,0.0170593,0.0122070,0.0077209,0.0038452,0.0003662,-0.0019226,-0.0039062,-0.0045471,-0.0043335,-0.0031738,-0.0007019,0.0027466,0.0069885,0.0124207,0.0182495,0.0249023,0.0316467,0.0385437,0.0453186,0.0513916,0.0567322,0.0608826,0.0636292,0.0649414,0.0646057,0.0626221,0.0592346,0.0542603,0.0489502,0.0420532,0.0355835,0.0284119,0.0221252,0.0161133,0.0114136,0.0073547,0.0052185,0.0037842,0.0044556,0.0055237,0.0081177,0.0112305,0.0148621,0.0185852,0.0220337,0.0249939,0.0273438,0.0284424,0.0292358,0.0285034,0.0272217,0.0252686,0.0225525,0.0199890,0.0169983,0.0147400,0.0127869,0.0116577,0.0116882,0.0125122,0.0148926,0.0176392,0.0216980,0.0258179,0.0304871,0.0349121,0.0390015,0.0426025,0.0453491,0.0472717,0.0480347,0.0476685,0.0463257,0.0438232,0.0404358,0.0359497,0.0308228,0.0248108,0.0185547,0.0117188,0.0050354,-0.0019531,-0.0086060,-0.0150757,-0.0213318,-0.0270386,-0.0323792,-0.0372620,-0.0416260,-0.0452576,-0.0484619,-0.0506287,-0.0523376,-0.0529785,-0.0530701,-0.0524292,-0.0509949,-0.0492554,-0.0470276,-0.0446167,-0.0420532,-0.0398254,-0.0373230,-0.0357361,-0.0338440,-0.0330811,-0.0321045,-0.0318909,-0.0318604,-0.0321045,-0.0325623,-0.0328979,-0.0334778,-0.0335999,-0.0336609,-0.0331726,-0.0325928,-0.0312500,-0.0299377,-0.0278625,-0.0258179,-0.0233154,-0.0210876,-0.0183411,-0.0166016,-0.0144653,-0.0129089,-0.0121765,-0.0113220,-0.0118713,-0.0123596,-0.0137634,-0.0156250,-0.0177002,-0.0202332,-0.0227051,-0.0252075,-0.0274048,-0.0290527,-0.0303040,-0.0306091,-0.0304260,-0.0290833,-0.0270996,-0.0242004,-0.0205078,-0.0163269,-0.0115051,-0.0062561,-0.0008545,0.0047913,0.0101013,0.0154114,0.0199890,0.0241699,0.0275574,0.0298767,0.0313721,0.0318604,0.0310669,0.0294495,0.0267944,0.0236511,0.0197754,0.0161133,0.0120850,0.0089722,0.0061951,0.0046082,0.0041504,0.0048218,0.0068054,0.0098877,0.0142517,0.0191956,0.0246582,0.0304260,0.0359192,0.0409546,0.0453186,0.0484314,0.0506592,0.0515442,0.0513000,0.0501099,0.0480957,0.0454102,0.0426941,0.0399780,0.0377197,0.0359497,0.0350342,0.0350037,0.0359192,0.0377197,0.0401306,0.0433350,0.0463257,0.0496826,0.0521545,0.0539551,0.0544128,0.0541077,0.0519409,0.0484009,0.0429688,0.0360718,0.0283508,0.0193787,0.0104980,0.0008850,-0.0079651,-0.0166626,-0.0240784,-0.0303345,-0.0351562,-0.0387268,-0.0403137,-0.0408020,-0.0392761,-0.0372314,-0.0336304,-0.0296021,-0.0248718,-0.0199890,-0.0151978,-0.0108643,-0.0064697,-0.0031738,0.0000000,0.0020142,0.0037842,0.0044250,0.0052795,0.0047913,0.0050659,0.0042114,0.0040894,0.0031738,0.0032654,0.0028687,0.0031433,0.0034180,0.0038147,0.0043640,0.0050659,0.0055542,0.0060425,0.0066223,0.0064087,0.0061035,0.0052490,0.0038452,0.0017395,-0.0010071,-0.0043945,-0.0082397,-0.0125732,-0.0170593,-0.0215759,-0.0256042,-0.0294189,-0.0320129,-0.0340881,-0.0345154,-0.0340881,-0.0321655,-0.0290527,-0.0246887,-0.0194702,-0.0132446,-0.0068359,-0.0003052,0.0059814,0.0117798,0.0161743,0.0198059,0.0217896,0.0224915,0.0214844,0.0194397,0.0158691,0.0117493,0.0067444,0.0017090,-0.0033569,-0.0077820,-0.0119934,-0.0148621,-0.0172119,-0.0184326,-0.0188293,-0.0186768,-0.0178528,-0.0166931,-0.0154114,-0.0142212,-0.0136719,-0.0131531,-0.0134583,-0.0141602,-0.0158691,-0.0182495,-0.0205688,-0.0238953,-0.0267639,-0.0301514,-0.0332031,-0.0356140,-0.0372925,-0.0382690,-0.0381165,-0.0375061,-0.0352478,-0.0331421,-0.0292969,-0.0260925,-0.0217896,-0.0182495,-0.0139465,-0.0106506,-0.0073547,-0.0050354,-0.0032959,-0.0021973,-0.0021362,-0.0026855,-0.0039062,-0.0059204,-0.0079346,-0.0106812,-0.0128784,-0.0156555,-0.0175476,-0.0195618,-0.0202637,-0.0211487,-0.0208130,-0.0203247,-0.0188293,-0.0167847,-0.0143433,-0.0113831,-0.0083618,-0.0052490,-0.0022888,0.0004883,0.0025024,0.0038757,0.0043335,0.0041199,0.0029297,0.0010071,-0.0016785,-0.0049438,-0.0082092,-0.0119324,-0.0149231,-0.0179138,-0.0195312,-0.0202332,-0.0195618,-0.0172729,-0.0136108,-0.0084229,-0.0015259,0.0060120,0.0147400,0.0234680,0.0321960,0.0402832,0.0475159,0.0533752,0.0575867,0.0598755,0.0604858,0.0591431,0.0563965,0.0520935,0.0468140,0.0404053,0.0340271,0.0271301,0.0209961,0.0152893,0.0108337,0.0073242,0.0054321,0.0044861,0.0051270,0.0067139,0.0094604,0.0129395,0.0164185,0.0207214,0.0244751,0.0285339,0.0315247,0.0346375,0.0367737,0.0384827,0.0397034,0.0402832,0.0407715,0.0409241,0.0411682,0.0415955,0.0418396,0.0428162,0.0437927,0.0453796,0.0470886,0.0485229,0.0506897,0.0518188,0.0530396,0.0534973,0.0530701,0.0520630,0.0497742,0.0467224,0.0426331,0.0378723,0.0324707,0.0266724,0.0206299,0.0148010,0.0090637,0.0039062,-0.0009766,-0.0046997,-0.0082092,-0.0107422,-0.0127258,-0.0139465,-0.0147400,-0.0149536,-0.0150757,-0.0144653,-0.0143127,-0.0134277,-0.0126953,-0.0115967,-0.0102844,-0.0088501,-0.0066528,-0.0043640,-0.0012512,0.0019836,0.0059204,0.0101013,0.0143433,0.0191956,0.0232239,0.0280762,0.0315857,0.0355835,0.0378723,0.0402222,0.0409851,0.0414429,0.0405579,0.0389709,0.0366211,0.0334778,0.0303650,0.0262451,0.0228882,0.0187683,0.0154114,0.0119629,0.0085754,0.0055847,0.0022278,-0.0006409,-0.0040894,-0.0075378,-0.0113831,-0.0154419,-0.0197449,-0.0243225,-0.0288391,-0.0334473,-0.0377197,-0.0415955,-0.0449524,-0.0475159,-0.0490417,-0.0494995,-0.0489502,-0.0468445,-0.0441284,-0.0400391,-0.0351868,-0.0300293,-0.0243835,-0.0191345,-0.0138855,-0.0098877,-0.0062866,-0.0043335,-0.0029907,-0.0034790,-0.0044861,-0.0073853,-0.0102539,-0.0146179,-0.0186157,-0.0234070,-0.0276184,-0.0317383,-0.0354004,-0.0381775,-0.0407104,-0.0422363,-0.0434570,-0.0439758,-0.0445862,-0.0450134,-0.0455627,-0.0464172,-0.0475159,-0.0490112,-0.0509033,-0.0531006,-0.0554504,-0.0578918,-0.0601196,-0.0620728,-0.0634155,-0.0638733,-0.0637207,-0.0620117,-0.0597839,-0.0559692,-0.0517273,-0.0465698,-0.0413208,-0.0356750,-0.0307617,-0.0258484,-0.0225525,-0.0196838,-0.0187378,-0.0185852,-0.0200806,-0.0223694,-0.0259094,-0.0298157,-0.0341797,-0.0382690,-0.0421143,-0.0452576,-0.0471497,-0.0483704,-0.0473328,-0.0458984,-0.0420837,-0.0378723,-0.0319214,-0.0258484,-0.0190125,-0.0120850,-0.0056458,0.0005188,0.0056458,0.0094299,0.0123596,0.0134583,0.0135803,0.0121765,0.0099487,0.0066223,0.0030518,-0.0006714,-0.0040588,-0.0070801,-0.0087585,-0.0096741,-0.0090637,-0.0069275,-0.0035400,0.0012207,0.0072632,0.0138855,0.0213623,0.0289917,0.0363770,0.0435181,0.0494690,0.0545654,0.0582886,0.0604248,0.0610046,0.0603027,0.0579224,0.0548096,0.0503845,0.0458374,0.0409241,0.0360718,0.0317383,0.0280151,0.0251770,0.0230713,0.0223999,0.0223083,0.0234680,0.0249634,0.0272827,0.0296021,0.0322571,0.0341797,0.0362854,0.0372009,0.0383301,0.0379944,0.0377502,0.0366516,0.0355530,0.0343933,0.0333252,0.0326843,0.0324097,0.0326843,0.0334167,0.0350342,0.0368958,0.0393066,0.0419617,0.0448914,0.0477295,0.0505981,0.0528259,0.0548096,0.0559387,0.0565491,0.0561829,0.0551758,0.0536194,0.0513306,0.0488281,0.0458374,0.0429382,0.0397034,0.0369263,0.0343628,0.0318604,0.0299683,0.0282288,0.0271301,0.0259705,0.0255127,0.0245667,0.0242920,0.0235901,0.0226746,0.0216064,0.0199280,0.0181885,0.0164795,0.0142517,0.0123596,0.0105286,0.0092163,0.0085754,0.0082397,0.0090332,0.0097046,0.0118408,0.0137329,0.0166931,0.0194702,0.0225525,0.0254822,0.0281372,0.0302124,0.0314636,0.0319214,0.0312500,0.0290833,0.0260925,0.0211792,0.0159607,0.0092468,0.0021362,-0.0054626,-0.0131836,-0.0207825,-0.0275574,-0.0337219,-0.0386658,-0.0423584,-0.0449524,-0.0458679,-0.0456238,-0.0440674,-0.0412598,-0.0378418,-0.0336304,-0.0293884,-0.0252075,-0.0214539,-0.0184937,-0.0161133,-0.0153809,-0.0152588,-0.0165100,-0.0187683,-0.0217896,-0.0256958,-0.0296631,-0.0338745,-0.0381470,-0.0417786,-0.0450439,-0.0476074,-0.0490723,-0.0503235,-0.0505066,-0.0504456,-0.0498352,-0.0488892,-0.0480042,-0.0474243,-0.0468445,-0.0469360,-0.0473328,-0.0483398,-0.0496521,-0.0513000,-0.0532227,-0.0551147,-0.0572205,-0.0587463,-0.0604553,-0.0612793,-0.0621338,-0.0620422,-0.0614014,-0.0603333,-0.0583191,-0.0558472,-0.0525818,-0.0489807,-0.0446472,-0.0401001,-0.0350647,-0.0298767,-0.0243225,-0.0190735,-0.0132446,-0.0079041,-0.0023193,0.0030212,0.0082092,0.0128479,0.0175171,0.0211792,0.0245667,0.0270996,0.0288086,0.0296631,0.0297546,0.0287170,0.0273132,0.0250244,0.0223999,0.0193481,0.0163269,0.0132141,0.0107422,0.0081177,0.0064392,0.0048828,0.0042725,0.0037537,0.0040894,0.0041809,0.0049744,0.0055237,0.0062866,0.0066528,0.0066833,0.0066833,0.0061340,0.0053406,0.0043640,0.0032349,0.0021667,0.0014343,0.0010376,0.0014648,0.0026245,0.0043030,0.0071106,0.0106506,0.0147095,0.0196228,0.0240784,0.0291748,0.0335693,0.0375061,0.0405273,0.0426636,0.0433350,0.0430908,0.0412292,0.0385742,0.0346375,0.0302429,0.0254822,0.0204773,0.0156860,0.0117798,0.0083313,0.0063782,0.0051575,0.0055847,0.0067749,0.0093994,0.0126038,0.0166016,0.0207214,0.0248108,0.0283813,0.0312805,0.0329895,0.0337524,0.0332336,0.0314026,0.0285950,0.0245056,0.0197449,0.0144653,0.0090027,0.0035095,-0.0013733,-0.0060425,-0.0094604,-0.0122375,-0.0136414,-0.0141907,-0.0137024,-0.0125122,-0.0106506,-0.0086365,-0.0064392,-0.0047913,-0.0036621,-0.0033875,-0.0041504,-0.0060730,-0.0087891,-0.0127869,-0.0173035,-0.0226440,-0.0285034,-0.0340576,-0.0400696,-0.0452881,-0.0500793,-0.0541382,-0.0574036,-0.0594482,-0.0608826,-0.0610046,-0.0603638,-0.0590515,-0.0569458,-0.0546265,-0.0519104,-0.0490417,-0.0464478,-0.0437317,-0.0414734,-0.0393982,-0.0376892,-0.0361328,-0.0349426,-0.0337219,-0.0328064,-0.0317383,-0.0308838,-0.0297852,-0.0286560,-0.0276489,-0.0260925,-0.0251160,-0.0233154,-0.0222168,-0.0206299,-0.0192871,-0.0181885,-0.0169678,-0.0162964,-0.0154724,-0.0153503,-0.0151672,-0.0158997,-0.0163879,-0.0181274,-0.0195923,-0.0218811,-0.0244751,-0.0270691,-0.0304565,-0.0334167,-0.0369568,-0.0402222,-0.0433044,-0.0465393,-0.0486450,-0.0511169,-0.0519409,-0.0526428,-0.0516663,-0.0498657,-0.0467224,-0.0422058,-0.0367737,-0.0298157,-0.0224915,-0.0141602,-0.0058594,0.0025330,0.0106201,0.0176697,0.0240784,0.0287170,0.0323486,0.0339966,0.0342102,0.0326233,0.0295410,0.0250854,0.0198669,0.0136719,0.0073853,0.0012207,-0.0046082,-0.0091858,-0.0129395,-0.0149536,-0.0155945,-0.0144958,-0.0118103,-0.0074463,-0.0019836,0.0048218,0.0119934,0.0197754,0.0274353,0.0349426,0.0413818,0.0470886,0.0515137,0.0544434,0.0563965,0.0564270,0.0557251,0.0538940,0.0510559,0.0482483,0.0449829,0.0421753,0.0400391,0.0386963,0.0387878,0.0403748,0.0432739,0.0477905,0.0535889,0.0601501,0.0676270,0.0749207,0.0821838,0.0884399,0.0934448,0.0968323,0.0981140,0.0970764,0.0940247,0.0881653,0.0808411,0.0711365,0.0606995,0.0487671,0.0366821,0.0249939,0.0137024,0.0039978,-0.0046082,-0.0107727,-0.0154114,-0.0177002,-0.0183105,-0.0169678,-0.0146179,-0.0110779,-0.0073853,-0.0032349,0.0002136,0.0028992,0.0045471,0.0048523,0.0039062,0.0014038,-0.0021362,-0.0067139,-0.0122375,-0.0179749,-0.0236816,-0.0291748,-0.0336914,-0.0372925,-0.0397339,-0.0406189,-0.0401611,-0.0384216,-0.0352173,-0.0312195,-0.0265198,-0.0212708,-0.0163574,-0.0109558,-0.0067444,-0.0023804,0.0006714,0.0031738,0.0048828,0.0058289,0.0060120,0.0056763,0.0047607,0.0035400,0.0020752,0.0007324,-0.0009766,-0.0021362,-0.0034485,-0.0043335,-0.0051270,-0.0054321,-0.0057678,-0.0054016,-0.0052185,-0.0046387,-0.0039978,-0.0033569,-0.0024109,-0.0018616,-0.0011292,-0.0007019,-0.0003662,-0.0005493,-0.0008850,-0.0018005,-0.0028381,-0.0046997,-0.0068054,-0.0090332,-0.0118103,-0.0143127,-0.0171204,-0.0194092,-0.0215454,-0.0230713,-0.0242004,-0.0244751,-0.0241394,-0.0231018,-0.0214844,-0.0193176,-0.0168457,-0.0139465,-0.0111084,-0.0084229,-0.0062866,-0.0046997,-0.0040894,-0.0043335,-0.0057068,-0.0082092,-0.0115967,-0.0157471,-0.0205078,-0.0252991,-0.0303650,-0.0351257,-0.0392456,-0.0426941,-0.0450134,-0.0464172,-0.0466309,-0.0455933,-0.0433960,-0.0401001,-0.0363159,-0.0312805,-0.0264587,-0.0209351,-0.0158691,-0.0111389,-0.0070496,-0.0039368,-0.0015564,-0.0003967,-0.0003052,-0.0013428,-0.0029907,-0.0057373,-0.0087280,-0.0123901,-0.0159607,-0.0194397,-0.0230408,-0.0254822,-0.0282593,-0.0294800,-0.0309143,-0.0311584,-0.0311279,-0.0306396,-0.0298157,-0.0287781,-0.0276184,-0.0268860,-0.0260315,-0.0256653,-0.0254822,-0.0257874,-0.0262756,-0.0271301,-0.0282898,-0.0292969,-0.0306396,-0.0317078,-0.0325317,-0.0333252,-0.0334167,-0.0337524,-0.0330200,-0.0324707,-0.0311584,-0.0298767,-0.0279846,-0.0259705,-0.0237732,-0.0213013,-0.0188904,-0.0163269,-0.0139160,-0.0114136,-0.0091248,-0.0069275,-0.0047607,-0.0029907,-0.0011597,0.0002441,0.0018311,0.0028076,0.0041199,0.0048523,0.0059509,0.0065002,0.0073547,0.0083618,0.0092468,0.0105896,0.0119934,0.0137329,0.0158081,0.0182495,0.0208130,0.0240173,0.0271301,0.0309753,0.0348206,0.0389709,0.0434265,0.0475159,0.0519409,0.0557861,0.0594788,0.0626526,0.0652161,0.0670776,0.0685425,0.0689392,0.0689697,0.0684509,0.0675964,0.0663147,0.0649719,0.0633850,0.0617676,0.0603943,0.0588684,0.0573425,0.0559998,0.0545044,0.0529175,0.0513916,0.0495911,0.0477295,0.0458069,0.0437012,0.0416870,0.0393982,0.0375977,0.0355835,0.0342407,0.0327759,0.0319519,0.0310669,0.0310059,0.0304871,0.0309143,0.0303040,0.0303650,0.0296326,0.0287476,0.0272522,0.0252075,0.0225830,0.0193176,0.0158691,0.0119019,0.0081177,0.0044556,0.0011902,-0.0012207,-0.0029602,-0.0032043,-0.0026245,-0.0004883,0.0025635,0.0068054,0.0118408,0.0173645,0.0229187,0.0285950,0.0335083,0.0378723,0.0410767,0.0433655,0.0444336,0.0444031,0.0434875,0.0415649,0.0391235,0.0364075,0.0335083,0.0308228,0.0286255,0.0267944,0.0258484,0.0248413,0.0250244,0.0247192,0.0252380,0.0253601,0.0253296,0.0245056,0.0230713,0.0206909,0.0173950,0.0134888,0.0084229,0.0032959,-0.0025330,-0.0081482,-0.0139160,-0.0189209,-0.0236206,-0.0275574,-0.0303650,-0.0325928,-0.0333252,-0.0338135,-0.0328674,-0.0316467,-0.0297546,-0.0274963,-0.0250549,-0.0228271,-0.0202637,-0.0183411,-0.0163574,-0.0145264,-0.0134583,-0.0122375,-0.0114441,-0.0105591,-0.0102844,-0.0098572,-0.0101929,-0.0104370,-0.0111694,-0.0124512,-0.0139160,-0.0163879,-0.0190430,-0.0224915,-0.0262756,-0.0307922,-0.0354919,-0.0407410,-0.0458679,-0.0514526,-0.0563354,-0.0614014,-0.0654907,-0.0691833,-0.0722351,-0.0743713,-0.0759277,-0.0767212,-0.0769348,-0.0767212,-0.0761414,-0.0753479,-0.0744324,-0.0734558,-0.0726929,-0.0717163,-0.0712280,-0.0704346,-0.0701599,-0.0695801,-0.0686340,-0.0682983,-0.0668030,-0.0657959,-0.0639954,-0.0622253,-0.0600281,-0.0576172,-0.0552979,-0.0526123,-0.0502625,-0.0478516,-0.0454102,-0.0430603,-0.0408936,-0.0381470,-0.0359192,-0.0330200,-0.0302734,-0.0272217,-0.0240173,-0.0208130,-0.0175781,-0.0143433,-0.0116272,-0.0087891,-0.0068665,-0.0051270,-0.0045471,-0.0042725,-0.0052490,-0.0069580,-0.0089111,-0.0120544,-0.0146179,-0.0177917,-0.0204468,-0.0225525,-0.0240784,-0.0242920,-0.0236206,-0.0216675,-0.0184326,-0.0141602,-0.0084534,-0.0025024,0.0046082,0.0116882,0.0186768,0.0254211,0.0312500,0.0363464,0.0401306,0.0429077,0.0442505,0.0447998,0.0439148,0.0429382,0.0408020,0.0389099,0.0367737,0.0348511,0.0338135,0.0329590,0.0332642,0.0345154,0.0364380,0.0395813,0.0435181,0.0476379,0.0527649,0.0575562,0.0625000,0.0670471,0.0707703,0.0736084,0.0753479,0.0759277,0.0749817,0.0732422,0.0696106,0.0655823,0.0602112,0.0543823,0.0480957,0.0417786,0.0352783,0.0293579,0.0237732,0.0191345,0.0150452,0.0122681,0.0100403,0.0093384,0.0093994,0.0109253,0.0131226,0.0164795,0.0203247,0.0249329,0.0298767,0.0346375,0.0395508,0.0433960,0.0472412,0.0497131,0.0511475,0.0516052,0.0506897,0.0484314,0.0452271,0.0408325,0.0359497,0.0303955,0.0247803,0.0192871,0.0143127,0.0100708,0.0069580,0.0050354,0.0044556,0.0051575,0.0072937,0.0104370,0.0145874,0.0192871,0.0241699,0.0292969,0.0337830,0.0377502,0.0404968,0.0422974,0.0428467,0.0420837,0.0402527,0.0372314,0.0336304,0.0294495,0.0249634,0.0208740,0.0168152,0.0137024,0.0113220,0.0097961,0.0096130,0.0100403,0.0116882,0.0138550,0.0166321,0.0195618,0.0223999,0.0249634,0.0270386,0.0279236,0.0282593,0.0272217,0.0252380,0.0222473,0.0183105,0.0133972,0.0083923,0.0026855,-0.0025940,-0.0082703,-0.0128479,-0.0173340,-0.0206909,-0.0234680,-0.0250244,-0.0260315,-0.0257874,-0.0252686,-0.0239258,-0.0222168,-0.0203247,-0.0182800,-0.0165405,-0.0147400,-0.0136719,-0.0124512,-0.0121155,-0.0118408,-0.0121155,-0.0126953,-0.0132141,-0.0146179,-0.0152283,-0.0170288,-0.0177917,-0.0195618,-0.0205994,-0.0222778,-0.0235596,-0.0250244,-0.0263367,-0.0276489,-0.0289612,-0.0300903,-0.0313110,-0.0324402,-0.0334473,-0.0345764,-0.0356140,-0.0365295,-0.0375061,-0.0384216,-0.0390930,-0.0398254,-0.0398560,-0.0400085,-0.0393066,-0.0386353,-0.0369263,-0.0353394,-0.0327148,-0.0297241,-0.0263672,-0.0223999,-0.0181885,-0.0138550,-0.0093079,-0.0051880,-0.0008240,0.0025940,0.0055237,0.0079346,0.0096436,0.0106506,0.0110474,0.0107117,0.0103149,0.0096436,0.0088501,0.0080872,0.0081177,0.0078735,0.0087280,0.0093689,0.0112915,0.0133057,0.0156860,0.0180664,0.0209961,0.0236511,0.0262756,0.0280762,0.0295410,0.0301819,0.0303345,0.0296021,0.0279541,0.0256348,0.0225220,0.0195312,0.0162048,0.0130310,0.0103149,0.0083313,0.0068054,0.0063171,0.0064392,0.0078735,0.0101318,0.0133362,0.0168762,0.0212097,0.0254822,0.0299988,0.0341492,0.0380249,0.0411987,0.0434875,0.0454102,0.0459290,0.0462646,0.0451660,0.0439148,0.0417480,0.0389404,0.0361023,0.0326538,0.0295410,0.0259094,0.0227966,0.0196533,0.0167542,0.0139771,0.0115356,0.0088806,0.0066833,0.0039062,0.0014954,-0.0014954,-0.0044861,-0.0075378,-0.0107727,-0.0140076,-0.0172729,-0.0202026,-0.0229797,-0.0252686,-0.0273132,-0.0286560,-0.0294495,-0.0294800,-0.0288391,-0.0273438,-0.0252380,-0.0222473,-0.0188904,-0.0149841,-0.0108948,-0.0067444,-0.0022583,0.0014648,0.0054321,0.0082703,0.0111694,0.0127869,0.0141602,0.0142517,0.0140991,0.0130615,0.0116577,0.0097046,0.0075684,0.0055237,0.0037537,0.0022888,0.0010986,0.0005188,0.0003662,0.0010376,0.0017090,0.0026550,0.0034485,0.0040588,0.0044861,0.0042114,0.0030823,0.0014038,-0.0010986,-0.0041504,-0.0076904,-0.0116272,-0.0155029,-0.0193176,-0.0223389,-0.0250854,-0.0264893,-0.0275574,-0.0265198,-0.0252991,-0.0220642,-0.0180969,-0.0134583,-0.0078735,-0.0023499,0.0036011,0.0087585,0.0135193,0.0170898,0.0194702,0.0207214,0.0201416,0.0186157,0.0153198,0.0112305,0.0061035,0.0003662,-0.0056152,-0.0116882,-0.0173645,-0.0222168,-0.0264893,-0.0292664,-0.0313110,-0.0321960,-0.0323181,-0.0310364,-0.0293579,-0.0280762,-0.0267334,-0.0253906,-0.0240173,-0.0234985,-0.0243530,-0.0257263,-0.0276794,-0.0299072,-0.0327148,-0.0359192,-0.0391235,-0.0416565,-0.0437927,-0.0447998,-0.0454712,-0.0445862,-0.0429993,-0.0395508,-0.0357056,-0.0308838,-0.0255127,-0.0196838,-0.0139160,-0.0083923,-0.0037842,-0.0000305,0.0025940,0.0037537,0.0037842,0.0020752,-0.0007019,-0.0046692,-0.0093994,-0.0144043,-0.0196228,-0.0244141,-0.0287781,-0.0320435,-0.0341492,-0.0349731,-0.0343018,-0.0327454,-0.0293884,-0.0256653,-0.0208740,-0.0159607,-0.0111694,-0.0064697,-0.0027466,0.0004883,0.0021667,0.0029297,0.0024719,0.0008850,-0.0014648,-0.0046082,-0.0081787,-0.0117188,-0.0147705,-0.0173035,-0.0187988,-0.0191956,-0.0183716,-0.0159912,-0.0125427,-0.0075378,-0.0017700,0.0048828,0.0120544,0.0191650,0.0263672,0.0326233,0.0384827,0.0430298,0.0466919,0.0490723,0.0502930,0.0504456,0.0495300,0.0475159,0.0450134,0.0417786,0.0380859,0.0345154,0.0306396,0.0272217,0.0240173,0.0214539,0.0193787,0.0179749,0.0171814,0.0168762,0.0173645,0.0180054,0.0192871,0.0210266,0.0226135,0.0248718,0.0265808,0.0287170,0.0303650,0.0316467,0.0325928,0.0326538,0.0326538,0.0313110,0.0299072,0.0275574,0.0249634,0.0220642,0.0185547,0.0155640,0.0123291,0.0096130,0.0075073,0.0057983,0.0051880,0.0053406,0.0063171,0.0082703,0.0108948,0.0137634,0.0171204,0.0201721,0.0232239,0.0255737,0.0273132,0.0282288,0.0280457,0.0272827,0.0254211,0.0231018,0.0198975,0.0166321,0.0131531,0.0099182,0.0070801,0.0049133,0.0032043,0.0029907,0.0030823,0.0044861,0.0064087,0.0090332,0.0121765,0.0155029,0.0187073,0.0219421,0.0244751,0.0268860,0.0282898,0.0292664,0.0294189,0.0291138,0.0280762,0.0267334,0.0248108,0.0230408,0.0209656,0.0192261,0.0172729,0.0160828,0.0144958,0.0138245,0.0128479,0.0122986,0.0115356,0.0109863,0.0102844,0.0092468,0.0085144,0.0069275,0.0057678,0.0041504,0.0023193,0.0007935,-0.0011597,-0.0025940,-0.0044250,-0.0059204,-0.0073853,-0.0087585,-0.0099792,-0.0114441,-0.0124817,-0.0138550,-0.0147705,-0.0160828,-0.0168152,-0.0175781,-0.0180359,-0.0184326,-0.0182190,-0.0184326,-0.0173950,-0.0173645,-0.0162354,-0.0158386,-0.0153809,-0.0149536,-0.0152588,-0.0155640,-0.0167542,-0.0181885,-0.0202942,-0.0227051,-0.0258789,-0.0289917,-0.0328369,-0.0364075,-0.0402832,-0.0437012,-0.0472107,-0.0500793,-0.0525818,-0.0547485,-0.0560913,-0.0575256,-0.0581055,-0.0585632,-0.0586548,-0.0586243,-0.0583496,-0.0580444,-0.0577698,-0.0573730,-0.0569763,-0.0567322,-0.0561218,-0.0556946,-0.0550232,-0.0542297,-0.0534668,-0.0523987,-0.0512695,-0.0501099,-0.0489197,-0.0479431,-0.0467834,-0.0458069,-0.0452881,-0.0444641,-0.0442505,-0.0439758,-0.0439148,-0.0439453,-0.0440369,-0.0440674,-0.0440063,-0.0440369,-0.0437012,-0.0435181,-0.0428467,-0.0424500,-0.0416565,-0.0410156,-0.0402527,-0.0396423,-0.0388794,-0.0385437,-0.0379639,-0.0373840,-0.0366516,-0.0355530,-0.0345764,-0.0324097,-0.0300293,-0.0267334,-0.0231934,-0.0185547,-0.0132446,-0.0076599,-0.0017700,0.0043335,0.0105286,0.0162964,0.0214539,0.0255432,0.0290222,0.0312500,0.0325317,0.0322571,0.0309448,0.0286560,0.0254517,0.0220947,0.0179749,0.0141907,0.0104980,0.0075989,0.0052490,0.0039978,0.0035706,0.0042725,0.0058899,0.0083313,0.0113525,0.0147705,0.0180359,0.0213318,0.0240479,0.0260925,0.0271606,0.0276489,0.0270996,0.0256958,0.0238342,0.0213013,0.0189209,0.0165100,0.0144348,0.0128784,0.0119324,0.0122986,0.0130005,0.0151978,0.0175476,0.0212708,0.0251770,0.0301208,0.0347290,0.0397339,0.0444031,0.0488892,0.0527344,0.0558167,0.0578918,0.0593262,0.0595703,0.0589600,0.0574036,0.0551453,0.0522156,0.0487976,0.0452576,0.0415344,0.0381775,0.0349121,0.0319519,0.0298157,0.0279236,0.0269775,0.0265198,0.0266418,0.0272827,0.0281372,0.0294189,0.0306702,0.0319519,0.0328979,0.0332947,0.0334167,0.0325928,0.0315857,0.0299377,0.0277405,0.0256042,0.0226440,0.0205994,0.0177307,0.0163574,0.0145874,0.0138550,0.0137939,0.0139160,0.0149231,0.0160828,0.0175781,0.0191956,0.0206299,0.0217590,0.0224915,0.0227051,0.0223083,0.0212708,0.0196838,0.0176392,0.0151367,0.0126648,0.0098877,0.0077820,0.0056152,0.0044250,0.0033875,0.0034485,0.0039368,0.0050049,0.0069580,0.0087280,0.0108948,0.0126648,0.0139771,0.0147400,0.0145874,0.0133667,0.0111389,0.0076599,0.0034790,-0.0016785,-0.0072327,-0.0131836,-0.0189819,-0.0244141,-0.0296631,-0.0339355,-0.0375366,-0.0402527,-0.0415039,-0.0420532,-0.0413818,-0.0401917,-0.0380859,-0.0357971,-0.0329590,-0.0304871,-0.0281677,-0.0263977,-0.0252380,-0.0249939,-0.0256653,-0.0270386,-0.0294495,-0.0324402,-0.0360718,-0.0404053,-0.0447083,-0.0494080,-0.0539551,-0.0583191,-0.0625000,-0.0659485,-0.0687256,-0.0707703,-0.0716858,-0.0717468,-0.0706787,-0.0686951,-0.0657043,-0.0620117,-0.0573120,-0.0523376,-0.0467224,-0.0413208,-0.0356445,-0.0303650,-0.0254211,-0.0214233,-0.0180664,-0.0158081,-0.0145569,-0.0140991,-0.0150146,-0.0163574,-0.0186462,-0.0208435,-0.0233154,-0.0256042,-0.0274658,-0.0281067,-0.0280151,-0.0266113,-0.0244141,-0.0206604,-0.0162048,-0.0103149,-0.0043335,0.0021667,0.0083313,0.0143127,0.0195007,0.0237732,0.0267944,0.0285645,0.0287170,0.0282288,0.0261536,0.0235596,0.0204163,0.0167542,0.0138245,0.0108948,0.0092163,0.0085144,0.0089722,0.0110168,0.0139160,0.0182495,0.0232849,0.0288391,0.0346985,0.0397034,0.0442505,0.0472107,0.0487671,0.0484619,0.0460510,0.0419312,0.0356445,0.0280762,0.0188293,0.0090332,-0.0011902,-0.0111389,-0.0203552,-0.0282593,-0.0344238,-0.0386047,-0.0404663,-0.0401001,-0.0375366,-0.0327759,-0.0265503,-0.0187378,-0.0104065,-0.0014954,0.0071106,0.0151062,0.0223389,0.0280457,0.0327454,0.0354919,0.0370789,0.0370483,0.0359192,0.0335388,0.0306702,0.0271301,0.0239563,0.0205078,0.0177917,0.0156555,0.0143433,0.0138550,0.0143433,0.0155029,0.0175781,0.0199280,0.0226135,0.0252380,0.0277405,0.0294800,0.0309753,0.0312195,0.0305481,0.0289612,0.0259705,0.0221863,0.0170593,0.0112915,0.0045471,-0.0025940,-0.0102539,-0.0176392,-0.0252686,-0.0318909,-0.0383301,-0.0437927,-0.0480042,-0.0515442,-0.0534668,-0.0543518,-0.0541382,-0.0525513,-0.0501404,-0.0463867,-0.0420532,-0.0370483,-0.0316772,-0.0261230,-0.0208435,-0.0158081,-0.0116272,-0.0082397,-0.0058899,-0.0047913,-0.0047302,-0.0062256,-0.0085144,-0.0121155,-0.0162659,-0.0212097,-0.0263062,-0.0315857,-0.0365601,-0.0412292,-0.0444946,-0.0477600,-0.0490723,-0.0499878,-0.0493469,-0.0481262,-0.0458374,-0.0431824,-0.0401001,-0.0368042,-0.0338745,-0.0311584,-0.0290222,-0.0277100,-0.0269165,-0.0273438,-0.0281372,-0.0300293,-0.0321350,-0.0350342,-0.0378113,-0.0408936,-0.0436096,-0.0458984,-0.0475464,-0.0483704,-0.0485840,-0.0476990,-0.0460815,-0.0435486,-0.0402832,-0.0364075,-0.0319824,-0.0271912,-0.0224304,-0.0173950,-0.0129700,-0.0086670,-0.0052490,-0.0024414,-0.0006104,0.0003967,0.0000916,-0.0007629,-0.0029602,-0.0052795,-0.0087280,-0.0118103,-0.0154724,-0.0185547,-0.0212097,-0.0232849,-0.0238647,-0.0241699,-0.0227051,-0.0203247,-0.0169067,-0.0124512,-0.0075378,-0.0017395,0.0038147,0.0096741,0.0147705,0.0195923,0.0232239,0.0260620,0.0278320,0.0284729,0.0281677,0.0273132,0.0255127,0.0238647,0.0220642,0.0205994,0.0200806,0.0202026,0.0217285,0.0241089,0.0277710,0.0325623,0.0383301,0.0443420,0.0513000,0.0575562,0.0640564,0.0692749,0.0736389,0.0767517,0.0780029,0.0778809,0.0758057,0.0726929,0.0677490,0.0621948,0.0558472,0.0495911,0.0433960,0.0376587,0.0332031,0.0294800,0.0273438,0.0260925,0.0263062,0.0274048,0.0297546,0.0322876,0.0353699,0.0384521,0.0413818,0.0437012,0.0452881,0.0458984,0.0454712,0.0442810,0.0417786,0.0387268,0.0346069,0.0305786,0.0257568,0.0214233,0.0169983,0.0132751,0.0102234,0.0077515,0.0057373,0.0051270,0.0046387,0.0054321,0.0063782,0.0077209,0.0093994,0.0108032,0.0122681,0.0131531,0.0134277,0.0132751,0.0120850,0.0107117,0.0081177,0.0055237,0.0020142,-0.0011292,-0.0047607,-0.0083313,-0.0115356,-0.0147095,-0.0170593,-0.0194702,-0.0209961,-0.0226440,-0.0231934,-0.0239868,-0.0240173,-0.0241699,-0.0240173,-0.0238953,-0.0236816,-0.0234375,-0.0234985,-0.0232849,-0.0229492,-0.0224915,-0.0213928,-0.0203857,-0.0184326,-0.0163879,-0.0135803,-0.0104675,-0.0070190,-0.0032349,0.0005798,0.0045776,0.0082092,0.0112000,0.0143127,0.0158997,0.0174255,0.0176086,0.0170898,0.0156860,0.0133362,0.0106812,0.0075684,0.0039368,0.0010071,-0.0025330,-0.0049438,-0.0074768,-0.0090942,-0.0103455,-0.0108337,-0.0111389,-0.0108948,-0.0107422,-0.0103455,-0.0100098,-0.0102844,-0.0105896,-0.0120544,-0.0132751,-0.0161438,-0.0182190,-0.0217590,-0.0244446,-0.0277405,-0.0303650,-0.0326538,-0.0339355,-0.0346680,-0.0340576,-0.0326843,-0.0302429,-0.0270996,-0.0233154,-0.0194702,-0.0151062,-0.0112915,-0.0078735,-0.0050354,-0.0034790,-0.0027466,-0.0031433,-0.0050049,-0.0077820,-0.0116577,-0.0162659,-0.0216675,-0.0272522,-0.0332642,-0.0388184,-0.0441589,-0.0493164,-0.0533142,-0.0572510,-0.0596619,-0.0616760,-0.0629272,-0.0631714,-0.0631104,-0.0617981,-0.0605774,-0.0582581,-0.0559692,-0.0529785,-0.0498047,-0.0464783,-0.0431519,-0.0394592,-0.0362244,-0.0325928,-0.0298462,-0.0266113,-0.0243225,-0.0217590,-0.0198669,-0.0182495,-0.0168762,-0.0159302,-0.0151367,-0.0144958,-0.0138855,-0.0130615,-0.0117493,-0.0102844,-0.0077820,-0.0052490,-0.0014954,0.0022888,0.0068054,0.0115356,0.0160522,0.0207825,0.0248718,0.0284119,0.0312500,0.0328369,0.0336914,0.0329285,0.0315552,0.0283813,0.0251465,0.0203857,0.0160828,0.0110474,0.0068665,0.0027161,-0.0000305,-0.0020142,-0.0025024,-0.0021057,0.0000610,0.0027771,0.0069885,0.0116882,0.0169373,0.0225830,0.0281067,0.0333252,0.0380859,0.0419312,0.0450439,0.0471802,0.0480042,0.0484314,0.0476685,0.0465088,0.0450439,0.0429688,0.0414124,0.0394897,0.0382996,0.0371399,0.0367432,0.0362854,0.0369568,0.0371704,0.0385437,0.0395203,0.0410461,0.0423584,0.0435486,0.0447388,0.0455627,0.0458374,0.0462036,0.0457153,0.0454102,0.0444946,0.0436707,0.0424500,0.0413208,0.0400085,0.0389404,0.0376587,0.0364990,0.0353699,0.0339966,0.0328979,0.0317078,0.0303650,0.0290833,0.0275269,0.0257263,0.0240784,0.0216980,0.0198975,0.0173950,0.0152283,0.0132751,0.0112915,0.0100098,0.0089111,0.0086975,0.0089417,0.0101624,0.0121155,0.0149231,0.0186768,0.0228882,0.0281372,0.0333557,0.0391846,0.0447693,0.0501099,0.0550232,0.0588074,0.0618286,0.0633545,0.0638428,0.0626831,0.0603638,0.0567322,0.0519714,0.0466919,0.0405579,0.0344543,0.0281982,0.0226746,0.0176392,0.0136108,0.0105286,0.0087280,0.0078735,0.0082397,0.0090942,0.0106506,0.0125732,0.0141907,0.0157166,0.0165100,0.0164795,0.0151672,0.0130005,0.0094910,0.0050049,-0.0003967,-0.0066833,-0.0134583,-0.0201721,-0.0269470,-0.0329590,-0.0385437,-0.0426636,-0.0459595,-0.0475464,-0.0476379,-0.0464478,-0.0436401,-0.0397034,-0.0346985,-0.0286560,-0.0223694,-0.0154419,-0.0090637,-0.0028687,0.0024719,0.0068359,0.0101013,0.0117798,0.0124817,0.0116882,0.0097351,0.0063171,0.0021362,-0.0033569,-0.0093994,-0.0162354,-0.0232239,-0.0308533,-0.0378723,-0.0451050,-0.0516357,-0.0576477,-0.0630798,-0.0675049,-0.0716248,-0.0745239,-0.0771484,-0.0787659,-0.0802917,-0.0808411,-0.0814819,-0.0814514,-0.0815125,-0.0810852,-0.0807190,-0.0803223,-0.0798035,-0.0792236,-0.0788879,-0.0776978,-0.0770264,-0.0755310,-0.0738525,-0.0714111,-0.0686951,-0.0649414,-0.0611267,-0.0562134,-0.0513611,-0.0458069,-0.0401306,-0.0347595,-0.0289917,-0.0241394,-0.0194092,-0.0154724,-0.0124817,-0.0100098,-0.0089111,-0.0082703,-0.0085449,-0.0095215,-0.0109558,-0.0125122,-0.0144043,-0.0158691,-0.0172424,-0.0180664,-0.0181885,-0.0179443,-0.0167236,-0.0152893,-0.0129700,-0.0104980,-0.0075989,-0.0045166,-0.0019836,0.0007629,0.0028687,0.0044556,0.0058594,0.0062561,0.0069275,0.0066223,0.0065308,0.0063477,0.0065918,0.0070190,0.0083313,0.0101013,0.0130005,0.0168152,0.0215454,0.0269775,0.0331421,0.0396729,0.0461731,0.0524597,0.0580139,0.0629272,0.0660400,0.0683594,0.0688782,0.0678711,0.0653992,0.0615234,0.0565796,0.0509949,0.0446472,0.0386353,0.0327454,0.0275269,0.0233459,0.0200500,0.0181580,0.0175476,0.0180054,0.0198364,0.0221252,0.0253906,0.0286560,0.0317993,0.0350647,0.0371704,0.0390015,0.0393372,0.0392456,0.0376282,0.0354614,0.0322571,0.0287781,0.0248108,0.0211792,0.0178528,0.0150452,0.0133667,0.0125732,0.0129700,0.0145569,0.0170898,0.0206604,0.0249023,0.0298157,0.0347595,0.0398254,0.0445251,0.0485535,0.0521851,0.0544434,0.0561523,0.0563660,0.0561218,0.0547180,0.0528870,0.0504150,0.0475769,0.0447998,0.0419312,0.0390320,0.0366821,0.0343628,0.0328064,0.0311279,0.0301514,0.0293274,0.0287476,0.0286865,0.0285645,0.0285645,0.0285950,0.0283508,0.0284424,0.0278931,0.0276794,0.0264282,0.0261536,0.0245667,0.0238342,0.0223083,0.0212402,0.0198364,0.0186157,0.0171204,0.0160217,0.0145874,0.0139160,0.0127869,0.0122681,0.0116577,0.0113525,0.0112305,0.0111084,0.0111084,0.0109558,0.0109253,0.0106201,0.0102844,0.0098267,0.0090027,0.0084229,0.0072327,0.0061035,0.0046997,0.0030518,0.0016174,-0.0003357,-0.0015869,-0.0033875,-0.0043030,-0.0054626,-0.0056458,-0.0059204,-0.0053101,-0.0046997,-0.0034485,-0.0021973,-0.0007019,0.0007324,0.0020752,0.0031128,0.0035400,0.0034485,0.0028076,0.0012817,-0.0007324,-0.0036316,-0.0071411,-0.0107422,-0.0151062,-0.0190430,-0.0232239,-0.0270386,-0.0303345,-0.0332336,-0.0353088,-0.0368042,-0.0377502,-0.0377808,-0.0376892,-0.0366821,-0.0358582,-0.0342102,-0.0330200,-0.0311890,-0.0298767,-0.0282288,-0.0270691,-0.0256042,-0.0246277,-0.0235291,-0.0226746,-0.0219421,-0.0212708,-0.0203857,-0.0198059,-0.0187378,-0.0178528,-0.0167236,-0.0152588,-0.0139465,-0.0121765,-0.0103760,-0.0086975,-0.0068054,-0.0052185,-0.0034485,-0.0020752,-0.0006714,0.0003662,0.0013428,0.0020447,0.0023499,0.0029907,0.0027771,0.0031738,0.0031433,0.0034790,0.0036316,0.0043945,0.0048218,0.0062256,0.0073853,0.0087891,0.0105896,0.0121460,0.0139465,0.0158997,0.0170593,0.0191040,0.0202332,0.0217590,0.0228577,0.0239868,0.0252075,0.0262146,0.0277100,0.0287170,0.0303345,0.0317078,0.0335388,0.0352478,0.0372009,0.0388184,0.0408325,0.0422058,0.0433655,0.0440979,0.0439453,0.0434875,0.0418396,0.0397644,0.0364380,0.0327148,0.0281677,0.0229797,0.0175476,0.0118103,0.0059814,0.0008850,-0.0042725,-0.0082092,-0.0116577,-0.0137329,-0.0147095,-0.0145874,-0.0132751,-0.0109253,-0.0079041,-0.0039978,0.0001526,0.0043945,0.0086670,0.0124512,0.0158081,0.0182800,0.0200806,0.0207214,0.0207825,0.0198669,0.0183716,0.0158997,0.0135803,0.0105286,0.0083313,0.0057678,0.0042419,0.0029602,0.0027161,0.0029297,0.0042114,0.0057983,0.0081787,0.0107117,0.0134277,0.0159607,0.0183716,0.0201416,0.0213928,0.0216064,0.0211487,0.0196838,0.0170898,0.0141907,0.0098267,0.0057678,0.0006409,-0.0040283,-0.0090637,-0.0135803,-0.0174866,-0.0209656,-0.0230103,-0.0247498,-0.0248108,-0.0240784,-0.0223083,-0.0192261,-0.0158386,-0.0112305,-0.0066223,-0.0016785,0.0032654,0.0078125,0.0120544,0.0153503,0.0181885,0.0195923,0.0206909,0.0202026,0.0194092,0.0178223,0.0156555,0.0135193,0.0107422,0.0086975,0.0064392,0.0046082,0.0032349,0.0018921,0.0013123,0.0007019,0.0003662,-0.0000610,-0.0002441,-0.0011597,-0.0020447,-0.0038147,-0.0059204,-0.0086060,-0.0121460,-0.0159607,-0.0205383,-0.0251770,-0.0300903,-0.0350037,-0.0396118,-0.0437622,-0.0473328,-0.0505371,-0.0523071,-0.0538940,-0.0539246,-0.0534668,-0.0517273,-0.0492554,-0.0459290,-0.0420227,-0.0378113,-0.0330505,-0.0286865,-0.0241699,-0.0203857,-0.0168457,-0.0140686,-0.0118713,-0.0104980,-0.0093689,-0.0094604,-0.0095520,-0.0104675,-0.0115967,-0.0129395,-0.0147705,-0.0162964,-0.0178528,-0.0190430,-0.0202332,-0.0206909,-0.0209961,-0.0209045,-0.0204773,-0.0200806,-0.0192871,-0.0187378,-0.0181580,-0.0177917,-0.0174866,-0.0177612,-0.0180969,-0.0188293,-0.0202026,-0.0215759,-0.0234680,-0.0257263,-0.0278931,-0.0301208,-0.0321045,-0.0336609,-0.0349121,-0.0350037,-0.0345764,-0.0329895,-0.0305786,-0.0271606,-0.0228882,-0.0179138,-0.0126343,-0.0065002,-0.0007629,0.0054626,0.0111084,0.0166626,0.0211487,0.0255127,0.0281372,0.0307312,0.0317383,0.0324402,0.0320435,0.0314636,0.0301819,0.0289307,0.0275269,0.0260010,0.0246582,0.0233765,0.0224609,0.0216064,0.0209961,0.0204773,0.0202942,0.0201416,0.0200195,0.0200195,0.0198669,0.0196533,0.0193787,0.0187988,0.0182800,0.0175171,0.0165405,0.0155640,0.0144653,0.0134277,0.0121155,0.0112305,0.0103149,0.0097046,0.0093384,0.0092468,0.0098572,0.0104980,0.0117798,0.0132446,0.0151672,0.0173950,0.0194702,0.0218506,0.0237122,0.0257874,0.0270996,0.0280151,0.0283508,0.0278625,0.0268555,0.0250549,0.0223694,0.0196838,0.0158081,0.0123901,0.0085449,0.0050354,0.0021973,-0.0003662,-0.0019531,-0.0026245,-0.0025024,-0.0012207,0.0010681,0.0039062,0.0079346,0.0119934,0.0167542,0.0217590,0.0261230,0.0309143,0.0346069,0.0379333,0.0404663,0.0420227,0.0427856,0.0427551,0.0419006,0.0404358,0.0386047,0.0360413,0.0339050,0.0310059,0.0289307,0.0265198,0.0247498,0.0231934,0.0219727,0.0214233,0.0208130,0.0209351,0.0206909,0.0210876,0.0210571,0.0212097,0.0209961,0.0205994,0.0200500,0.0192871,0.0182495,0.0168762,0.0154114,0.0135193,0.0115356,0.0092773,0.0071716,0.0050354,0.0031738,0.0016174,0.0000305,-0.0010071,-0.0020142,-0.0023804,-0.0026550,-0.0028381,-0.0025330,-0.0025024,-0.0020752,-0.0018311,-0.0017700,-0.0015869,-0.0017395,-0.0022278,-0.0032349,-0.0046082,-0.0067749,-0.0089417,-0.0123901,-0.0156860,-0.0194397,-0.0233459,-0.0270691,-0.0312195,-0.0349731,-0.0383606,-0.0414734,-0.0440979,-0.0466919,-0.0488281,-0.0506592,-0.0519409,-0.0531311,-0.0541077,-0.0548401,-0.0554810,-0.0558472,-0.0560608,-0.0564880,-0.0563660,-0.0564575,-0.0558472,-0.0551147,-0.0539246,-0.0520935,-0.0501709,-0.0472107,-0.0445251,-0.0409851,-0.0378418,-0.0345154,-0.0314636,-0.0288086,-0.0266418,-0.0249634,-0.0240479,-0.0237427,-0.0241699,-0.0250244,-0.0265198,-0.0285645,-0.0309448,-0.0335693,-0.0362244,-0.0387268,-0.0411987,-0.0432434,-0.0447998,-0.0462646,-0.0468445,-0.0474243,-0.0472717,-0.0471802,-0.0468750,-0.0464478,-0.0462341,-0.0457458,-0.0455322,-0.0452881,-0.0448914,-0.0446167,-0.0440979,-0.0436096,-0.0428162,-0.0419922,-0.0407104,-0.0394287,-0.0375671,-0.0359192,-0.0335083,-0.0310974,-0.0282593,-0.0256042,-0.0222778,-0.0192566,-0.0158691,-0.0124207,-0.0088501,-0.0050659,-0.0010071,0.0032654,0.0075073,0.0121155,0.0163269,0.0206909,0.0246887,0.0280457,0.0308533,0.0325623,0.0334167,0.0330505,0.0315857,0.0290222,0.0252686,0.0205688,0.0148010,0.0089111,0.0022278,-0.0039368,-0.0097656,-0.0148926,-0.0187073,-0.0213013,-0.0223083,-0.0217285,-0.0195618,-0.0163574,-0.0118408,-0.0065613,-0.0013428,0.0043335,0.0090332,0.0133362,0.0164490,0.0184326,0.0190735,0.0186462,0.0168152,0.0141296,0.0108032,0.0071411,0.0035400,0.0007629,-0.0016479,-0.0021973,-0.0019226,0.0001831,0.0035095,0.0083008,0.0139465,0.0203552,0.0269165,0.0334167,0.0393982,0.0441284,0.0483398,0.0505066,0.0519104,0.0515137,0.0500183,0.0472717,0.0437927,0.0396423,0.0354004,0.0311279,0.0275879,0.0245667,0.0226440,0.0219727,0.0223999,0.0241089,0.0267334,0.0304871,0.0343628,0.0392151,0.0433960,0.0480652,0.0513306,0.0544434,0.0560913,0.0569458,0.0565491,0.0548706,0.0521851,0.0485535,0.0441284,0.0389709,0.0339355,0.0283508,0.0235901,0.0187683,0.0149231,0.0115967,0.0092468,0.0079041,0.0072327,0.0074768,0.0080872,0.0092773,0.0104675,0.0121460,0.0132751,0.0141602,0.0144653,0.0140991,0.0129395,0.0106506,0.0078430,0.0036926,-0.0006714,-0.0058289,-0.0110779,-0.0167542,-0.0220032,-0.0267334,-0.0311279,-0.0340576,-0.0365295,-0.0375977,-0.0374451,-0.0364990,-0.0339966,-0.0311890,-0.0273132,-0.0230713,-0.0187073,-0.0143433,-0.0101013,-0.0069275,-0.0036316,-0.0020447,-0.0007629,-0.0008545,-0.0017395,-0.0032043,-0.0057678,-0.0085449,-0.0119324,-0.0154419,-0.0191345,-0.0226746,-0.0261536,-0.0292664,-0.0321045,-0.0345154,-0.0366211,-0.0383301,-0.0394592,-0.0403137,-0.0406494,-0.0408936,-0.0407104,-0.0403442,-0.0397034,-0.0391235,-0.0382690,-0.0375366,-0.0365906,-0.0356140,-0.0347595,-0.0339966,-0.0333557,-0.0328674,-0.0324707,-0.0324402,-0.0326233,-0.0328979,-0.0334778,-0.0339966,-0.0346375,-0.0350952,-0.0355530,-0.0355530,-0.0353088,-0.0346985,-0.0335999,-0.0321350,-0.0299988,-0.0275879,-0.0246277,-0.0212708,-0.0180359,-0.0140381,-0.0109863,-0.0073242,-0.0046692,-0.0023499,-0.0006714,0.0002441,0.0004578,0.0000610,-0.0009155,-0.0021362,-0.0036011,-0.0049133,-0.0061951,-0.0069885,-0.0072021,-0.0070801,-0.0061035,-0.0046997,-0.0026855,-0.0002136,0.0026855,0.0056152,0.0088196,0.0116577,0.0143433,0.0162964,0.0181580,0.0191040,0.0198059,0.0194397,0.0192566,0.0179443,0.0169983,0.0155334,0.0141602,0.0130615,0.0117188,0.0109253,0.0102844,0.0095825,0.0095215,0.0094299,0.0094604,0.0097351,0.0096741,0.0096741,0.0092163,0.0083618,0.0072021,0.0053101,0.0035400,0.0009155,-0.0018005,-0.0045166,-0.0072327,-0.0097961,-0.0119629,-0.0139160,-0.0150757,-0.0158997,-0.0161438,-0.0157776,-0.0149231,-0.0135803,-0.0119629,-0.0101624,-0.0082397,-0.0062866,-0.0045776,-0.0029907,-0.0020447,-0.0010376,-0.0006104,-0.0002136,-0.0002136,-0.0000610,-0.0000305,0.0003357,0.0005798,0.0014343,0.0019531,0.0031433,0.0042419,0.0054626,0.0067139,0.0078735,0.0087891,0.0097046,0.0099487,0.0102539,0.0097351,0.0091858,0.0083618,0.0067139,0.0056458,0.0036316,0.0022278,0.0005188,-0.0006714,-0.0018311,-0.0024414,-0.0027771,-0.0025940,-0.0022278,-0.0015259,-0.0006104,0.0003967,0.0011597,0.0019226,0.0020142,0.0017700,0.0009460,-0.0006409,-0.0027466,-0.0057373,-0.0090332,-0.0131836,-0.0176392,-0.0221558,-0.0268250,-0.0310669,-0.0351562,-0.0386047,-0.0414734,-0.0435181,-0.0448303,-0.0454712,-0.0451965,-0.0446777,-0.0435486,-0.0420837,-0.0408325,-0.0393066,-0.0382385,-0.0375977,-0.0372314,-0.0379944,-0.0388184,-0.0409241,-0.0432129,-0.0463257,-0.0497742,-0.0532532,-0.0569458,-0.0600281,-0.0630798,-0.0649109,-0.0663147,-0.0662231,-0.0654602,-0.0631409,-0.0600891,-0.0555420,-0.0503235,-0.0438843,-0.0373230,-0.0303040,-0.0234070,-0.0170593,-0.0113525,-0.0070496,-0.0038147,-0.0022888,-0.0023804,-0.0038147,-0.0066833,-0.0109558,-0.0157166,-0.0215454,-0.0274963,-0.0332642,-0.0388184,-0.0433350,-0.0470276,-0.0493469,-0.0502014,-0.0492859,-0.0473022,-0.0434265,-0.0389099,-0.0330505,-0.0269775,-0.0202637,-0.0140686,-0.0076904,-0.0023499,0.0028992,0.0067749,0.0099487,0.0120544,0.0138550,0.0143738,0.0148010,0.0143738,0.0140991,0.0138245,0.0138550,0.0142822,0.0151062,0.0169678,0.0192566,0.0224609,0.0260010,0.0301514,0.0347290,0.0392151,0.0439148,0.0482178,0.0522766,0.0556946,0.0586853,0.0609436,0.0623169,0.0628967,0.0626221,0.0615234,0.0598755,0.0573120,0.0545654,0.0511780,0.0476074,0.0440063,0.0401611,0.0367126,0.0331116,0.0302429,0.0273438,0.0249939,0.0229797,0.0216675,0.0205688,0.0203247,0.0200806,0.0207214,0.0216675,0.0227356,0.0242310,0.0258789,0.0274963,0.0291443,0.0303650,0.0316162,0.0324097,0.0327759,0.0326538,0.0321350,0.0310669,0.0297852,0.0279236,0.0260315,0.0238647,0.0217896,0.0198059,0.0178833,0.0163269,0.0150452,0.0141296,0.0135498,0.0130615,0.0131226,0.0130005,0.0130005,0.0130005,0.0124207,0.0119934,0.0104370,0.0091858,0.0068054,0.0043335,0.0012512,-0.0020752,-0.0056458,-0.0091858,-0.0125732,-0.0155945,-0.0182495,-0.0200806,-0.0214844,-0.0217896,-0.0213013,-0.0199280,-0.0176392,-0.0147095,-0.0112305,-0.0070496,-0.0029297,0.0014954,0.0056763,0.0095825,0.0126953,0.0154114,0.0171204,0.0182190,0.0184021,0.0178528,0.0168762,0.0152283,0.0138550,0.0119934,0.0107422,0.0094604,0.0088806,0.0085449,0.0087585,0.0094299,0.0100708,0.0108337,0.0115662,0.0115356,0.0114746,0.0101929,0.0086060,0.0054626,0.0021362,-0.0025635,-0.0077515,-0.0133667,-0.0194702,-0.0254211,-0.0310974,-0.0361328,-0.0402832,-0.0433655,-0.0451660,-0.0454102,-0.0445251,-0.0423279,-0.0391235,-0.0349426,-0.0303345,-0.0253906,-0.0205994,-0.0161743,-0.0122986,-0.0094299,-0.0073853,-0.0067139,-0.0066833,-0.0080872,-0.0098267,-0.0126953,-0.0154724,-0.0188293,-0.0215149,-0.0241089,-0.0257263,-0.0268860,-0.0270996,-0.0268250,-0.0256348,-0.0242615,-0.0225830,-0.0208740,-0.0194702,-0.0183105,-0.0179749,-0.0181580,-0.0194092,-0.0212097,-0.0239563,-0.0273743,-0.0312195,-0.0355835,-0.0398560,-0.0440063,-0.0476685,-0.0509033,-0.0529785,-0.0544434,-0.0546570,-0.0542297,-0.0526733,-0.0506897,-0.0479126,-0.0451050,-0.0417175,-0.0387573,-0.0358887,-0.0333862,-0.0314026,-0.0299072,-0.0291138,-0.0288696,-0.0291443,-0.0299072,-0.0307617,-0.0319824,-0.0329285,-0.0336609,-0.0341187,-0.0339050,-0.0332031,-0.0318298,-0.0297546,-0.0272217,-0.0240479,-0.0205688,-0.0166931,-0.0127563,-0.0086365,-0.0044250,-0.0007629,0.0032349,0.0063477,0.0095520,0.0121155,0.0146790,0.0167542,0.0187683,0.0204773,0.0222473,0.0240173,0.0256958,0.0276489,0.0295410,0.0316467,0.0335083,0.0355530,0.0371094,0.0389099,0.0397949,0.0408325,0.0410156,0.0411987,0.0407410,0.0401917,0.0389404,0.0379944,0.0364685,0.0353088,0.0339355,0.0330811,0.0322876,0.0317383,0.0317993,0.0321045,0.0328979,0.0339050,0.0350037,0.0362244,0.0373230,0.0382996,0.0389099,0.0389709,0.0387878,0.0380859,0.0367432,0.0352173,0.0332642,0.0310669,0.0290222,0.0266113,0.0247803,0.0228882,0.0216064,0.0206909,0.0204163,0.0205078,0.0210266,0.0220032,0.0229797,0.0240479,0.0248413,0.0254822,0.0257568,0.0255432,0.0250854,0.0241394,0.0231323,0.0219421,0.0208435,0.0201416,0.0193787,0.0196228,0.0197754,0.0210876,0.0228577,0.0252991,0.0284424,0.0318604,0.0360107,0.0401917,0.0442200,0.0483704,0.0518494,0.0550842,0.0573425,0.0591125,0.0600891,0.0603027,0.0597229,0.0586853,0.0570068,0.0547180,0.0522766,0.0491943,0.0462646,0.0430603,0.0399780,0.0368042,0.0339050,0.0310974,0.0283203,0.0259705,0.0234375,0.0212402,0.0189819,0.0169067,0.0148315,0.0129395,0.0113220,0.0099182,0.0086365,0.0078125,0.0071411,0.0069885,0.0067749,0.0071411,0.0074463,0.0080872,0.0086670,0.0092468,0.0098572,0.0100403,0.0103760,0.0099182,0.0094604,0.0084534,0.0070190,0.0054016,0.0032349,0.0012512,-0.0014343,-0.0036621,-0.0060730,-0.0081787,-0.0101013,-0.0114746,-0.0130310,-0.0136108,-0.0145874,-0.0148315,-0.0150452,-0.0152893,-0.0153503,-0.0156250,-0.0163879,-0.0172119,-0.0184631,-0.0203857,-0.0225220,-0.0254211,-0.0287781,-0.0323181,-0.0362854,-0.0403137,-0.0443726,-0.0480652,-0.0516968,-0.0545654,-0.0570984,-0.0586853,-0.0595398,-0.0597839,-0.0594482,-0.0582886,-0.0567017,-0.0550232,-0.0525513,-0.0509949,-0.0485535,-0.0473633,-0.0459290,-0.0452881,-0.0452881,-0.0455933,-0.0467529,-0.0478821,-0.0494690,-0.0509949,-0.0519409,-0.0530090,-0.0527344,-0.0522156,-0.0505371,-0.0480042,-0.0447388,-0.0407715,-0.0363464,-0.0315857,-0.0269775,-0.0224609,-0.0183716,-0.0151672,-0.0124512,-0.0113831,-0.0108643,-0.0117798,-0.0134888,-0.0159912,-0.0192566,-0.0227661,-0.0262756,-0.0296021,-0.0323486,-0.0343323,-0.0354919,-0.0352783,-0.0347595,-0.0322876,-0.0300293,-0.0259399,-0.0223389,-0.0176086,-0.0133362,-0.0088501,-0.0048523,-0.0014343,0.0013428,0.0036316,0.0048218,0.0058289,0.0059204,0.0059204,0.0055847,0.0055237,0.0056763,0.0061340,0.0073242,0.0089111,0.0111084,0.0140076,0.0169373,0.0205383,0.0239258,0.0274963,0.0303955,0.0332031,0.0354004,0.0366516,0.0371704,0.0368652,0.0354919,0.0337830,0.0313721,0.0284119,0.0255127,0.0220947,0.0192871,0.0162964,0.0140686,0.0122681,0.0109558,0.0104980,0.0104980,0.0108643,0.0120850,0.0133972,0.0154419,0.0174255,0.0194702,0.0220642,0.0242004,0.0266724,0.0283203,0.0302429,0.0313416,0.0325012,0.0328979,0.0332031,0.0327759,0.0325928,0.0316162,0.0308838,0.0295715,0.0285645,0.0276184,0.0268250,0.0261841,0.0258484,0.0259399,0.0263062,0.0274048,0.0286560,0.0304260,0.0323486,0.0346680,0.0367737,0.0392456,0.0409241,0.0427246,0.0435791,0.0443115,0.0441284,0.0437012,0.0424500,0.0411377,0.0392456,0.0374756,0.0356140,0.0340881,0.0327454,0.0324707,0.0319824,0.0330811,0.0342102,0.0364380,0.0390320,0.0419617,0.0452576,0.0482483,0.0512695,0.0534363,0.0551758,0.0558777,0.0554810,0.0540466,0.0514221,0.0478821,0.0433350,0.0381165,0.0322876,0.0262756,0.0204773,0.0148926,0.0100098,0.0059814,0.0032349,0.0012512,0.0009766,0.0014343,0.0029907,0.0057068,0.0084534,0.0119324,0.0151978,0.0182495,0.0209351,0.0225220,0.0234985,0.0231018,0.0217285,0.0191956,0.0157471,0.0112305,0.0065002,0.0012817,-0.0038147,-0.0086060,-0.0128479,-0.0161743,-0.0184937,-0.0199890,-0.0202332,-0.0195618,-0.0181580,-0.0158997,-0.0133972,-0.0105286,-0.0079041,-0.0053101,-0.0035095,-0.0019836,-0.0016785,-0.0019226,-0.0034790,-0.0054321,-0.0083923,-0.0116882,-0.0153503,-0.0189514,-0.0223999,-0.0253296,-0.0277405,-0.0292969,-0.0299988,-0.0297852,-0.0284424,-0.0265808,-0.0238342,-0.0204163,-0.0168457,-0.0126953,-0.0090942,-0.0052185,-0.0023804,0.0002136,0.0018616,0.0025024,0.0024109,0.0012512,-0.0002136,-0.0026855,-0.0052795,-0.0079956,-0.0109253,-0.0132446,-0.0154419,-0.0168762,-0.0176697,-0.0178223,-0.0170898,-0.0157471,-0.0134888,-0.0112305,-0.0078125,-0.0048523,-0.0011292,0.0021973,0.0054321,0.0086365,0.0110779,0.0134277,0.0151672,0.0163574,0.0170288,0.0173645,0.0168457,0.0164490,0.0153503,0.0143433,0.0130920,0.0118713,0.0107727,0.0100708,0.0093994,0.0093079,0.0094910,0.0101318,0.0111389,0.0126953,0.0144653,0.0162964,0.0185547,0.0205383,0.0222168,0.0239258,0.0246277,0.0252380,0.0249329,0.0239563,0.0225220,0.0203552,0.0180054,0.0152283,0.0122681,0.0093994,0.0069275,0.0045471,0.0031738,0.0020752,0.0021362,0.0028687,0.0044250,0.0068970,0.0097656,0.0135803,0.0173035,0.0214844,0.0251770,0.0288391,0.0316467,0.0339050,0.0353394,0.0357666,0.0353088,0.0338745,0.0318298,0.0290527,0.0261841,0.0228271,0.0198669,0.0172424,0.0152588,0.0139160,0.0134583,0.0138245,0.0148621,0.0167236,0.0188293,0.0211792,0.0234680,0.0254822,0.0269775,0.0278015,0.0274963,0.0264282,0.0242615,0.0210876,0.0171204,0.0124207,0.0072327,0.0020447,-0.0029907,-0.0076294,-0.0116882,-0.0146179,-0.0166321,-0.0172424,-0.0169373,-0.0151978,-0.0127869,-0.0092163,-0.0053101,-0.0010071,0.0034790,0.0076904,0.0118103,0.0149536,0.0177002,0.0194092,0.0201721,0.0204773,0.0195007,0.0182800,0.0164795,0.0143738,0.0127563,0.0107422,0.0097046,0.0085144,0.0085754,0.0087891,0.0099487,0.0112000,0.0131531,0.0151367,0.0173645,0.0190125,0.0207520,0.0212402,0.0214844,0.0202637,0.0182190,0.0148010,0.0104370,0.0050964,-0.0009460,-0.0075684,-0.0143433,-0.0209045,-0.0272522,-0.0328064,-0.0375977,-0.0413818,-0.0435791,-0.0450134,-0.0447083,-0.0437317,-0.0413513,-0.0382996,-0.0344543,-0.0303650,-0.0260315,-0.0219727,-0.0183105,-0.0149536,-0.0129089,-0.0109863,-0.0106201,-0.0105896,-0.0116577,-0.0134583,-0.0156250,-0.0183716,-0.0212708,-0.0241699,-0.0270996,-0.0293884,-0.0314941,-0.0328979,-0.0335693,-0.0339355,-0.0332947,-0.0325928,-0.0311279,-0.0295410,-0.0281677,-0.0263672,-0.0254822,-0.0242615,-0.0238953,-0.0236816,-0.0238037,-0.0244446,-0.0250549,-0.0259399,-0.0267944,-0.0275269,-0.0280151,-0.0285950,-0.0282898,-0.0283203,-0.0274048,-0.0265808,-0.0253296,-0.0236511,-0.0220032,-0.0198669,-0.0177307,-0.0157471,-0.0132751,-0.0114136,-0.0090027,-0.0069580,-0.0050354,-0.0029602,-0.0012817,0.0007324,0.0020142,0.0038757,0.0046997,0.0061340,0.0064697,0.0069580,0.0069580,0.0065613,0.0060120,0.0048828,0.0038757,0.0022583,0.0011597,-0.0006104,-0.0017395,-0.0028687,-0.0037231,-0.0040894,-0.0041504,-0.0035706,-0.0026245,-0.0009766,0.0007019,0.0031433,0.0053101,0.0082092,0.0103455,0.0128784,0.0147400,0.0165405,0.0178223,0.0187378,0.0191956,0.0194092,0.0194397,0.0192871,0.0192261,0.0191040,0.0193787,0.0199890,0.0208435,0.0220947,0.0238342,0.0256348,0.0280457,0.0299683,0.0322266,0.0339355,0.0353394,0.0362549,0.0363464,0.0360413,0.0347595,0.0331116,0.0308533,0.0282898,0.0254211,0.0224609,0.0198975,0.0174255,0.0156250,0.0141296,0.0133972,0.0131226,0.0133667,0.0142517,0.0152283,0.0162659,0.0174866,0.0181274,0.0186157,0.0183716,0.0179138,0.0166626,0.0151367,0.0130310,0.0108948,0.0088196,0.0069275,0.0055542,0.0047913,0.0051880,0.0061951,0.0086060,0.0115051,0.0158691,0.0205383,0.0261536,0.0316772,0.0372620,0.0427856,0.0472412,0.0512085,0.0536499,0.0550537,0.0549011,0.0535583,0.0509644,0.0471802,0.0424500,0.0372925,0.0316772,0.0263977,0.0211182,0.0166321,0.0128174,0.0099182,0.0079041,0.0069275,0.0068970,0.0075684,0.0088806,0.0104065,0.0122070,0.0135498,0.0149536,0.0155640,0.0155334,0.0148621,0.0130310,0.0111084,0.0080872,0.0047913,0.0014648,-0.0023804,-0.0056458,-0.0090027,-0.0116577,-0.0137024,-0.0149841,-0.0155334,-0.0149536,-0.0138245,-0.0117798,-0.0091553,-0.0061646,-0.0028381,0.0002441,0.0032959,0.0057373,0.0072937,0.0082397,0.0079346,0.0065918,0.0042419,0.0006409,-0.0041809,-0.0094299,-0.0158386,-0.0221863,-0.0291443,-0.0358887,-0.0421448,-0.0476379,-0.0524902,-0.0559692,-0.0583496,-0.0594788,-0.0592957,-0.0580444,-0.0556030,-0.0523376,-0.0486145,-0.0442505,-0.0400391,-0.0359802,-0.0319519,-0.0291748,-0.0262451,-0.0250854,-0.0237122,-0.0241699,-0.0243835,-0.0257568,-0.0272217,-0.0288391,-0.0304260,-0.0317688,-0.0326233,-0.0331116,-0.0329590,-0.0323486,-0.0313721,-0.0300293,-0.0286865,-0.0271301,-0.0263062,-0.0252991,-0.0253296,-0.0259705,-0.0270081,-0.0292053,-0.0313721,-0.0344543,-0.0375366,-0.0408020,-0.0436707,-0.0462952,-0.0481567,-0.0489197,-0.0491638,-0.0479126,-0.0461426,-0.0430908,-0.0392456,-0.0350952,-0.0302734,-0.0254517,-0.0208130,-0.0162964,-0.0126648,-0.0091248,-0.0069580,-0.0052795,-0.0044556,-0.0044556,-0.0049133,-0.0059509,-0.0074768,-0.0089111,-0.0104980,-0.0120850,-0.0133057,-0.0143433,-0.0151367,-0.0153198,-0.0156250,-0.0151978,-0.0148621,-0.0142822,-0.0134888,-0.0127869,-0.0119019,-0.0110779,-0.0102844,-0.0092468,-0.0084534,-0.0074463,-0.0065002,-0.0058594,-0.0048218,-0.0042725,-0.0036926,-0.0031128,-0.0028992,-0.0025635,-0.0022888,-0.0021667,-0.0019836,-0.0017700,-0.0014648,-0.0012512,-0.0006104,-0.0001221,0.0007935,0.0018616,0.0030212,0.0047302,0.0063477,0.0083313,0.0103455,0.0123291,0.0141296,0.0158386,0.0169983,0.0180969,0.0182800,0.0185852,0.0180054,0.0174561,0.0164795,0.0153809,0.0143127,0.0129395,0.0121155,0.0110474,0.0105896,0.0101318,0.0104370,0.0106201,0.0112610,0.0122986,0.0132751,0.0145874,0.0156555,0.0167542,0.0178833,0.0188904,0.0197449,0.0208435,0.0216675,0.0229797,0.0241394,0.0260010,0.0277710,0.0303040,0.0326843,0.0357666,0.0386047,0.0419922,0.0448608,0.0479431,0.0502625,0.0523376,0.0536194,0.0540466,0.0541077,0.0525513,0.0510864,0.0481262,0.0454407,0.0417480,0.0383911,0.0343933,0.0310974,0.0275574,0.0244751,0.0216370,0.0191040,0.0169678,0.0149536,0.0134583,0.0119019,0.0105896,0.0091858,0.0078125,0.0060730,0.0044250,0.0020752,0.0000000,-0.0029297,-0.0053406,-0.0085144,-0.0108643,-0.0136414,-0.0157776,-0.0176392,-0.0191956,-0.0199280,-0.0206604,-0.0204468,-0.0201721,-0.0192566,-0.0178833,-0.0164795,-0.0142822,-0.0123901,-0.0101929,-0.0081482,-0.0062256,-0.0048218,-0.0035095,-0.0032349,-0.0032959,-0.0039978,-0.0057678,-0.0078430,-0.0108643,-0.0142517,-0.0184021,-0.0229187,-0.0276184,-0.0325928,-0.0373840,-0.0419922,-0.0458984,-0.0493469,-0.0519104,-0.0534973,-0.0540771,-0.0533752,-0.0518188,-0.0492249,-0.0456543,-0.0416565,-0.0368958,-0.0319824,-0.0270386,-0.0220642,-0.0176086,-0.0134888,-0.0101013,-0.0071106,-0.0052185,-0.0036621,-0.0029297,-0.0026550,-0.0026550,-0.0030212,-0.0032043,-0.0036011,-0.0034485,-0.0032959,-0.0024719,-0.0016174,-0.0002441,0.0011292,0.0027771,0.0042419,0.0054932,0.0066223,0.0067749,0.0069275,0.0057983,0.0044861,0.0023193,-0.0007019,-0.0035400,-0.0075684,-0.0108337,-0.0148926,-0.0180969,-0.0215454,-0.0240173,-0.0262756,-0.0275879,-0.0283813,-0.0283203,-0.0276184,-0.0261230,-0.0244446,-0.0219116,-0.0194702,-0.0163574,-0.0133057,-0.0103149,-0.0070496,-0.0041809,-0.0013428,0.0011902,0.0035706,0.0056763,0.0074158,0.0086975,0.0097046,0.0102234,0.0102539,0.0098267,0.0090027,0.0077515,0.0059509,0.0039062,0.0014343,-0.0011902,-0.0039673,-0.0070801,-0.0097656,-0.0126648,-0.0150146,-0.0172424,-0.0190735,-0.0203552,-0.0213318,-0.0215454,-0.0214233,-0.0209351,-0.0199890,-0.0187988,-0.0171814,-0.0155640,-0.0140076,-0.0124817,-0.0109253,-0.0098572,-0.0087585,-0.0082397,-0.0077820,-0.0075378,-0.0074463,-0.0074768,-0.0072327,-0.0070801,-0.0063171,-0.0053406,-0.0036011,-0.0018311,0.0007629,0.0033264,0.0065308,0.0099182,0.0133667,0.0167542,0.0195007,0.0219421,0.0237427,0.0253601,0.0256653,0.0251160,0.0236511,0.0221252,0.0198975,0.0172729,0.0139160,0.0107117,0.0077820,0.0051880,0.0031128,0.0011292,0.0000610,-0.0007629,-0.0004578,-0.0001526,0.0006714,0.0016174,0.0028992,0.0039978,0.0053101,0.0057678,0.0062256,0.0062256,0.0057373,0.0050354,0.0035400,0.0020142,0.0001221,-0.0016785,-0.0036316,-0.0054321,-0.0071106,-0.0085449,-0.0095825,-0.0105286,-0.0113525,-0.0122375,-0.0126648,-0.0137634,-0.0143127,-0.0157776,-0.0170593,-0.0189209,-0.0209961,-0.0235901,-0.0264282,-0.0296631,-0.0329895,-0.0365906,-0.0400391,-0.0437012,-0.0468750,-0.0500488,-0.0526733,-0.0551758,-0.0573120,-0.0587158,-0.0601501,-0.0608215,-0.0616455,-0.0617371,-0.0618591,-0.0614929,-0.0610046,-0.0603638,-0.0592957,-0.0583801,-0.0569153,-0.0554810,-0.0536804,-0.0517578,-0.0496216,-0.0472717,-0.0447388,-0.0422974,-0.0392456,-0.0366821,-0.0337524,-0.0311279,-0.0284424,-0.0262451,-0.0238342,-0.0221558,-0.0205994,-0.0191956,-0.0181580,-0.0174255,-0.0169373,-0.0164185,-0.0167542,-0.0164490,-0.0171509,-0.0174255,-0.0181274,-0.0189819,-0.0198975,-0.0210876,-0.0221863,-0.0235901,-0.0250244,-0.0263977,-0.0281677,-0.0295715,-0.0310059,-0.0321960,-0.0328674,-0.0334473,-0.0328064,-0.0320435,-0.0301819,-0.0279846,-0.0246887,-0.0210876,-0.0163879,-0.0115967,-0.0059204,-0.0001526,0.0057678,0.0118713,0.0175476,0.0232239,0.0282288,0.0326843,0.0362244,0.0392761,0.0414124,0.0429688,0.0437622,0.0443115,0.0443420,0.0441589,0.0440369,0.0437012,0.0436096,0.0435486,0.0433044,0.0436096,0.0435181,0.0435791,0.0432739,0.0427551,0.0417786,0.0404358,0.0382690,0.0357056,0.0326233,0.0292053,0.0252686,0.0213623,0.0171509,0.0136108,0.0098267,0.0072021,0.0045471,0.0032043,0.0020752,0.0020752,0.0025330,0.0036926,0.0054016,0.0074463,0.0096130,0.0120239,0.0140991,0.0161743,0.0173035,0.0187378,0.0188599,0.0189819,0.0184021,0.0173340,0.0161133,0.0144348,0.0129395,0.0114136,0.0100098,0.0089111,0.0081482,0.0079346,0.0079651,0.0085144,0.0091248,0.0103760,0.0113220,0.0125732,0.0133972,0.0143738,0.0147705,0.0151062,0.0146484,0.0142822,0.0130310,0.0121460,0.0104675,0.0090942,0.0075073,0.0059814,0.0049133,0.0035400,0.0032043,0.0023804,0.0025635,0.0028076,0.0033875,0.0044556,0.0056458,0.0072937,0.0090637,0.0111389,0.0133972,0.0156555,0.0182800,0.0203857,0.0231018,0.0251160,0.0273132,0.0289001,0.0304565,0.0311890,0.0316772,0.0314941,0.0304871,0.0289917,0.0268250,0.0239258,0.0208435,0.0169678,0.0130615,0.0088196,0.0046997,0.0004578,-0.0033264,-0.0070496,-0.0101929,-0.0130920,-0.0151978,-0.0170898,-0.0181885,-0.0193176,-0.0197449,-0.0202026,-0.0203857,-0.0205078,-0.0209351,-0.0211792,-0.0220337,-0.0228271,-0.0240479,-0.0255127,-0.0271301,-0.0291138,-0.0308838,-0.0331116,-0.0349731,-0.0370178,-0.0386963,-0.0404968,-0.0418091,-0.0432434,-0.0442200,-0.0452576,-0.0458374,-0.0466003,-0.0470276,-0.0473938,-0.0476379,-0.0477600,-0.0477295,-0.0475159,-0.0472107,-0.0466003,-0.0457153,-0.0447693,-0.0433044,-0.0418396,-0.0398254,-0.0377808,-0.0353088,-0.0328369,-0.0303955,-0.0274963,-0.0254822,-0.0228577,-0.0212097,-0.0195007,-0.0182190,-0.0177612,-0.0171204,-0.0175171,-0.0178528,-0.0188904,-0.0201111,-0.0215759,-0.0233154,-0.0248108,-0.0265198,-0.0279846,-0.0290222,-0.0300598,-0.0302429,-0.0303650,-0.0296936,-0.0288086,-0.0271606,-0.0257874,-0.0236511,-0.0219727,-0.0201721,-0.0182190,-0.0173035,-0.0162659,-0.0162659,-0.0166931,-0.0176086,-0.0195007,-0.0214233,-0.0239868,-0.0263367,-0.0290833,-0.0308838,-0.0328674,-0.0332642,-0.0335999,-0.0320740,-0.0299988,-0.0265198,-0.0219727,-0.0167542,-0.0103455,-0.0039062,0.0030518,0.0096741,0.0162964,0.0219727,0.0271301,0.0310364,0.0340881,0.0358582,0.0368958,0.0366821,0.0360413,0.0345154,0.0329895,0.0311279,0.0296021,0.0283203,0.0274658,0.0271301,0.0277710,0.0282898,0.0299072,0.0316772,0.0337524,0.0358276,0.0378418,0.0393066,0.0404053,0.0409241,0.0404358,0.0397034,0.0374451,0.0350952,0.0318604,0.0281982,0.0242920,0.0205078,0.0165710,0.0132751,0.0101318,0.0076599,0.0057983,0.0046082,0.0041199,0.0043335,0.0050964,0.0064087,0.0081482,0.0102844,0.0127563,0.0151367,0.0175781,0.0199280,0.0220947,0.0240479,0.0256042,0.0266724,0.0274963,0.0280457,0.0281372,0.0281372,0.0278015,0.0275574,0.0273132,0.0270081,0.0270996,0.0271912,0.0278320,0.0286255,0.0299988,0.0316772,0.0335083,0.0358887,0.0378723,0.0406189,0.0424805,0.0448914,0.0460510,0.0475464,0.0477905,0.0479431,0.0469971,0.0459290,0.0440369,0.0419617,0.0397339,0.0370789,0.0349426,0.0326233,0.0312500,0.0297241,0.0293884,0.0292358,0.0296936,0.0308228,0.0317993,0.0333252,0.0344238,0.0356140,0.0361328,0.0362854,0.0356445,0.0344849,0.0325317,0.0301208,0.0269470,0.0234375,0.0198059,0.0159302,0.0122681,0.0088196,0.0059814,0.0035706,0.0016785,0.0006104,0.0000305,0.0000610,0.0006409,0.0009460,0.0017090,0.0021667,0.0028381,0.0026855,0.0022583,0.0012512,-0.0000610,-0.0015869,-0.0038147,-0.0060425,-0.0088501,-0.0112305,-0.0135803,-0.0159302,-0.0179749,-0.0197449,-0.0209045,-0.0216370,-0.0221558,-0.0223694,-0.0223389,-0.0223083,-0.0219727,-0.0220947,-0.0221252,-0.0224609,-0.0231934,-0.0239563,-0.0251770,-0.0263977,-0.0280457,-0.0297546,-0.0313416,-0.0330811,-0.0344849,-0.0361023,-0.0372314,-0.0381470,-0.0387573,-0.0390625,-0.0394592,-0.0390015,-0.0387878,-0.0381165,-0.0375061,-0.0368347,-0.0361633,-0.0353699,-0.0348816,-0.0345154,-0.0343018,-0.0343323,-0.0346680,-0.0350647,-0.0357361,-0.0363770,-0.0370483,-0.0379028,-0.0382996,-0.0389404,-0.0388184,-0.0389404,-0.0381470,-0.0373535,-0.0361328,-0.0346680,-0.0329895,-0.0311279,-0.0296326,-0.0278625,-0.0266113,-0.0254517,-0.0249329,-0.0248718,-0.0249329,-0.0259705,-0.0267334,-0.0282593,-0.0296936,-0.0309143,-0.0323792,-0.0329285,-0.0335388,-0.0330200,-0.0321350,-0.0303040,-0.0278320,-0.0244751,-0.0207825,-0.0162659,-0.0119324,-0.0071716,-0.0028687,0.0014038,0.0050354,0.0079651,0.0104370,0.0118103,0.0127258,0.0125732,0.0119324,0.0108337,0.0093384,0.0077209,0.0060425,0.0050049,0.0041504,0.0040588,0.0044250,0.0054932,0.0073547,0.0096130,0.0124512,0.0153198,0.0186462,0.0213318,0.0242615,0.0262451,0.0279846,0.0287170,0.0286865,0.0278625,0.0260925,0.0237122,0.0204163,0.0169373,0.0131531,0.0091553,0.0056458,0.0020142,-0.0004883,-0.0031128,-0.0041199,-0.0048828,-0.0043335,-0.0029602,-0.0011597,0.0017090,0.0049133,0.0084229,0.0122986,0.0160217,0.0196228,0.0225525,0.0252380,0.0273132,0.0287170,0.0295715,0.0296936,0.0296631,0.0292664,0.0288391,0.0283813,0.0282288,0.0281982,0.0289001,0.0295410,0.0311890,0.0329590,0.0351868,0.0379333,0.0404358,0.0433350,0.0457153,0.0483093,0.0500488,0.0517273,0.0525208,0.0530396,0.0527344,0.0518799,0.0507812,0.0488892,0.0472107,0.0447693,0.0428772,0.0404968,0.0387878,0.0368042,0.0356750,0.0343933,0.0339050,0.0333557,0.0334778,0.0335693,0.0341187,0.0345459,0.0351868,0.0357361,0.0363464,0.0365906,0.0368347,0.0367737,0.0363159,0.0356445,0.0347290,0.0332947,0.0320129,0.0297546,0.0278625,0.0256042,0.0234680,0.0211487,0.0189819,0.0167236,0.0150452,0.0132446,0.0123291,0.0114441,0.0115662,0.0115356,0.0126648,0.0137024,0.0155029,0.0169373,0.0189819,0.0202332,0.0216370,0.0222168,0.0223083,0.0216370,0.0200500,0.0177612,0.0147095,0.0109253,0.0067444,0.0020752,-0.0025635,-0.0071411,-0.0112915,-0.0147095,-0.0174866,-0.0191040,-0.0200806,-0.0198059,-0.0189209,-0.0173340,-0.0151367,-0.0131226,-0.0107727,-0.0088501,-0.0073242,-0.0062561,-0.0061951,-0.0066528,-0.0078125,-0.0097656,-0.0122070,-0.0148010,-0.0179443,-0.0205688,-0.0231628,-0.0249939,-0.0265198,-0.0269470,-0.0269775,-0.0259399,-0.0242310,-0.0220947,-0.0193787,-0.0164185,-0.0134277,-0.0102844,-0.0075989,-0.0050964,-0.0029602,-0.0015869,-0.0003662,0.0000000,0.0001526,-0.0003052,-0.0008545,-0.0016785,-0.0027161,-0.0034485,-0.0042419,-0.0046997,-0.0048523,-0.0047913,-0.0044250,-0.0036621,-0.0027466,-0.0015869,-0.0002441,0.0011292,0.0024719,0.0040894,0.0052490,0.0066223,0.0076599,0.0083923,0.0090332,0.0092468,0.0088196,0.0086060,0.0074158,0.0065613,0.0048828,0.0034485,0.0016479,0.0001526,-0.0014954,-0.0025635,-0.0036011,-0.0038452,-0.0038757,-0.0033264,-0.0019836,-0.0005493,0.0019836,0.0041504,0.0069885,0.0097351,0.0123291,0.0150757,0.0167847,0.0186768,0.0193481,0.0201721,0.0199280,0.0196228,0.0189819,0.0179749,0.0173950,0.0165405,0.0163574,0.0161133,0.0166321,0.0173035,0.0185852,0.0201416,0.0216675,0.0232544,0.0245361,0.0256653,0.0259094,0.0258789,0.0248718,0.0234070,0.0211182,0.0185547,0.0155945,0.0127258,0.0098572,0.0074463,0.0056458,0.0047913,0.0046692,0.0054626,0.0072327,0.0097656,0.0130615,0.0167236,0.0207214,0.0244751,0.0282288,0.0312195,0.0335693,0.0350952,0.0356445,0.0352173,0.0342407,0.0323792,0.0299988,0.0274353,0.0247803,0.0223083,0.0204468,0.0185852,0.0180664,0.0175476,0.0183716,0.0192566,0.0212097,0.0229492,0.0254211,0.0275574,0.0296326,0.0312195,0.0323181,0.0327759,0.0327148,0.0315247,0.0299988,0.0275879,0.0248413,0.0215454,0.0179138,0.0142212,0.0103149,0.0066833,0.0029907,-0.0001526,-0.0031433,-0.0055542,-0.0076599,-0.0092468,-0.0100708,-0.0108643,-0.0105896,-0.0104675,-0.0095825,-0.0084229,-0.0072327,-0.0055237,-0.0041504,-0.0024719,-0.0012512,-0.0003967,0.0003662,0.0000305,-0.0002136,-0.0018616,-0.0036011,-0.0061035,-0.0095215,-0.0126343,-0.0167236,-0.0203247,-0.0240479,-0.0274658,-0.0300903,-0.0324707,-0.0336304,-0.0347290,-0.0342407,-0.0335999,-0.0319519,-0.0299683,-0.0275879,-0.0252686,-0.0226135,-0.0207214,-0.0187378,-0.0177612,-0.0171509,-0.0174561,-0.0183105,-0.0199585,-0.0220947,-0.0246277,-0.0275269,-0.0304565,-0.0333252,-0.0361023,-0.0381165,-0.0397949,-0.0408936,-0.0411377,-0.0407104,-0.0396423,-0.0375366,-0.0355225,-0.0323181,-0.0293884,-0.0260010,-0.0224915,-0.0195312,-0.0162354,-0.0137634,-0.0111389,-0.0092468,-0.0076599,-0.0061646,-0.0054321,-0.0044556,-0.0040894,-0.0036621,-0.0032349,-0.0030823,-0.0025330,-0.0021362,-0.0014038,-0.0006409,0.0000305,0.0011597,0.0019836,0.0029297,0.0035706,0.0041199,0.0044861,0.0043945,0.0040588,0.0031738,0.0019836,0.0003357,-0.0017395,-0.0039368,-0.0064392,-0.0089417,-0.0113831,-0.0137024,-0.0156250,-0.0170898,-0.0183105,-0.0185242,-0.0187073,-0.0175781,-0.0164795,-0.0144043,-0.0120544,-0.0093384,-0.0061646,-0.0031433,0.0003967,0.0033264,0.0068359,0.0094299,0.0123291,0.0147705,0.0169983,0.0189819,0.0206604,0.0223083,0.0234985,0.0248718,0.0258179,0.0268250,0.0277405,0.0285645,0.0294189,0.0301208,0.0308838,0.0316162,0.0324097,0.0330200,0.0337524,0.0343018,0.0347595,0.0352478,0.0352478,0.0352478,0.0348816,0.0342407,0.0334473,0.0318604,0.0306702,0.0281982,0.0264587,0.0234375,0.0209656,0.0178528,0.0150452,0.0121460,0.0095215,0.0071716,0.0053101,0.0040283,0.0032654,0.0033264,0.0041504,0.0055237,0.0076904,0.0103149,0.0134888,0.0168152,0.0201111,0.0236511,0.0265808,0.0292358,0.0314331,0.0326233,0.0335999,0.0335083,0.0329590,0.0319214,0.0303345,0.0287781,0.0268860,0.0253296,0.0239258,0.0227966,0.0226135,0.0223999,0.0233459,0.0245056,0.0262146,0.0284729,0.0307312,0.0332336,0.0354614,0.0376282,0.0390320,0.0402222,0.0403748,0.0399780,0.0387878,0.0370483,0.0344238,0.0317383,0.0280151,0.0248413,0.0209351,0.0173645,0.0138550,0.0104065,0.0074768,0.0049744,0.0031128,0.0016174,0.0009766,0.0005493,0.0008850,0.0015869,0.0025635,0.0035095,0.0050354,0.0056763,0.0070496,0.0069885,0.0075073,0.0065613,0.0057983,0.0036926,0.0018616,-0.0014343,-0.0045776,-0.0083618,-0.0124207,-0.0164185,-0.0204773,-0.0241699,-0.0277405,-0.0307312,-0.0332031,-0.0351562,-0.0364075,-0.0373840,-0.0376892,-0.0378723,-0.0374451,-0.0371399,-0.0367126,-0.0361328,-0.0359192,-0.0357056,-0.0357666,-0.0358887,-0.0359497,-0.0362244,-0.0360107,-0.0359497,-0.0351257,-0.0340576,-0.0324097,-0.0304565,-0.0277405,-0.0251465,-0.0218506,-0.0189209,-0.0158997,-0.0130310,-0.0110474,-0.0093994,-0.0085144,-0.0088196,-0.0092773,-0.0113831,-0.0135498,-0.0167236,-0.0200195,-0.0234375,-0.0269165,-0.0300293,-0.0325317,-0.0344543,-0.0355530,-0.0357361,-0.0353088,-0.0338135,-0.0319519,-0.0295410,-0.0269165,-0.0241089,-0.0217896,-0.0192566,-0.0179443,-0.0165100,-0.0163879,-0.0163574,-0.0174255,-0.0187378,-0.0205078,-0.0226440,-0.0245667,-0.0267334,-0.0284729,-0.0297852,-0.0309143,-0.0310364,-0.0310364,-0.0303040,-0.0292664,-0.0278015,-0.0262756,-0.0242310,-0.0227661,-0.0208435,-0.0195923,-0.0182190,-0.0174561,-0.0166931,-0.0165710,-0.0163269,-0.0164185,-0.0164795,-0.0165710,-0.0169067,-0.0167236,-0.0167847,-0.0163879,-0.0159302,-0.0151367,-0.0141907,-0.0131836,-0.0117493,-0.0103760,-0.0088806,-0.0072327,-0.0057373,-0.0040894,-0.0027771,-0.0014038,-0.0003052,0.0007629,0.0015259,0.0021973,0.0024719,0.0027466,0.0028076,0.0027771,0.0025330,0.0023804,0.0022583,0.0022278,0.0023499,0.0026550,0.0033569,0.0041199,0.0051575,0.0064087,0.0076599,0.0091858,0.0105591,0.0121460,0.0135498,0.0149231,0.0161743,0.0171814,0.0181580,0.0188904,0.0194702,0.0200500,0.0203247,0.0208435,0.0210876,0.0215454,0.0218506,0.0225525,0.0230713,0.0239868,0.0247192,0.0256653,0.0266724,0.0274658,0.0286560,0.0292664,0.0303040,0.0310059,0.0315857,0.0321960,0.0325012,0.0327454,0.0327148,0.0324402,0.0319214,0.0310059,0.0300903,0.0284729,0.0267334,0.0245056,0.0221558,0.0195007,0.0166016,0.0137329,0.0106506,0.0077209,0.0048523,0.0025330,0.0003357,-0.0010681,-0.0021057,-0.0021057,-0.0015564,-0.0001831,0.0017700,0.0043030,0.0072937,0.0105591,0.0135803,0.0169067,0.0192871,0.0216980,0.0229187,0.0236816,0.0235596,0.0226440,0.0210571,0.0188904,0.0161438,0.0133667,0.0102844,0.0075378,0.0050964,0.0030212,0.0016479,0.0006409,0.0003357,0.0002136,0.0008240,0.0010986,0.0019836,0.0021667,0.0025330,0.0021973,0.0014648,0.0003357,-0.0018005,-0.0039368,-0.0069580,-0.0099792,-0.0134277,-0.0169983,-0.0204163,-0.0235901,-0.0266418,-0.0289307,-0.0310059,-0.0321045,-0.0329895,-0.0325012,-0.0321960,-0.0307312,-0.0292053,-0.0270081,-0.0246582,-0.0220947,-0.0194702,-0.0168152,-0.0142212,-0.0116272,-0.0092163,-0.0072327,-0.0052490,-0.0040588,-0.0027161,-0.0021973,-0.0016174,-0.0018921,-0.0021973,-0.0031738,-0.0039368,-0.0052490,-0.0064392,-0.0079651,-0.0090942,-0.0104065,-0.0112305,-0.0120239,-0.0122986,-0.0124512,-0.0120544,-0.0118408,-0.0109253,-0.0104370,-0.0095520,-0.0093689,-0.0089111,-0.0091858,-0.0097046,-0.0106812,-0.0118713,-0.0136108,-0.0152283,-0.0172119,-0.0189514,-0.0205383,-0.0218811,-0.0225220,-0.0227051,-0.0220337,-0.0209961,-0.0188904,-0.0166016,-0.0131226,-0.0099487,-0.0062256,-0.0025940,0.0008240,0.0040894,0.0068054,0.0087585,0.0104065,0.0111084,0.0116272,0.0111084,0.0105896,0.0093994,0.0083618,0.0068359,0.0057983,0.0044250,0.0034485,0.0026245,0.0018005,0.0013733,0.0003662,0.0000000,-0.0015259,-0.0022583,-0.0044861,-0.0064087,-0.0091553,-0.0122986,-0.0157166,-0.0194397,-0.0234070,-0.0270691,-0.0309753,-0.0339050,-0.0367737,-0.0384216,-0.0394897,-0.0396729,-0.0385132,-0.0367432,-0.0336304,-0.0301819,-0.0255432,-0.0207520,-0.0155334,-0.0101929,-0.0051880,-0.0003357,0.0039978,0.0075378,0.0105591,0.0125732,0.0141602,0.0148315,0.0148315,0.0142517,0.0133667,0.0120850,0.0108948,0.0093994,0.0083008,0.0072327,0.0065308,0.0061340,0.0062256,0.0061951,0.0068665,0.0070496,0.0079041,0.0082092,0.0086060,0.0087585,0.0085144,0.0080261,0.0071411,0.0057983,0.0043945,0.0027466,0.0009766,-0.0006104,-0.0022583,-0.0032654,-0.0042114,-0.0043335,-0.0043335,-0.0036011,-0.0022888,-0.0007324,0.0014954,0.0036011,0.0059509,0.0083008,0.0103149,0.0121765,0.0131531,0.0141296,0.0140381,0.0137939,0.0125427,0.0113220,0.0092773,0.0074158,0.0049438,0.0029297,0.0005798,-0.0012207,-0.0028687,-0.0043335,-0.0054016,-0.0065308,-0.0072632,-0.0082703,-0.0091858,-0.0105896,-0.0121765,-0.0144348,-0.0167847,-0.0198975,-0.0233154,-0.0270996,-0.0310669,-0.0352783,-0.0392456,-0.0433350,-0.0467529,-0.0501709,-0.0524902,-0.0547791,-0.0557556,-0.0565796,-0.0565491,-0.0558167,-0.0546570,-0.0529785,-0.0509338,-0.0490723,-0.0466919,-0.0445862,-0.0424194,-0.0404968,-0.0386963,-0.0370483,-0.0356140,-0.0343628,-0.0331726,-0.0322266,-0.0311890,-0.0302734,-0.0296936,-0.0286255,-0.0282593,-0.0272217,-0.0267334,-0.0259705,-0.0252991,-0.0245056,-0.0241089,-0.0233459,-0.0231018,-0.0226440,-0.0223999,-0.0225830,-0.0227051,-0.0233154,-0.0239258,-0.0248108,-0.0260315,-0.0272522,-0.0287170,-0.0302429,-0.0317078,-0.0332336,-0.0342712,-0.0352173,-0.0355835,-0.0354919,-0.0349426,-0.0332642,-0.0314026,-0.0283813,-0.0250854,-0.0210876,-0.0166626,-0.0120850,-0.0071106,-0.0025024,0.0020752,0.0062561,0.0095825,0.0125122,0.0143127,0.0156555,0.0155945,0.0152893,0.0136414,0.0122375,0.0097046,0.0080566,0.0054932,0.0042419,0.0028381,0.0028687,0.0029602,0.0046692,0.0065613,0.0099182,0.0133972,0.0176086,0.0219421,0.0262146,0.0302734,0.0338440,0.0366211,0.0386353,0.0396729,0.0393066,0.0386047,0.0359497,0.0333862,0.0293274,0.0255127,0.0213623,0.0172424,0.0138245,0.0106201,0.0087891,0.0072021,0.0074158,0.0080566,0.0101013,0.0126953,0.0162354,0.0200806,0.0242615,0.0284119,0.0322571,0.0356750,0.0382690,0.0400696,0.0407410,0.0406189,0.0390015,0.0368958,0.0336304,0.0299377,0.0254211,0.0209656,0.0162048,0.0116272,0.0075684,0.0036621,0.0007629,-0.0017090,-0.0032043,-0.0038147,-0.0037842,-0.0029907,-0.0014343,0.0004883,0.0027771,0.0052185,0.0078125,0.0099487,0.0123291,0.0136719,0.0154114,0.0161438,0.0167847,0.0170593,0.0169373,0.0165710,0.0162964,0.0154724,0.0152893,0.0147095,0.0146179,0.0147095,0.0149536,0.0155029,0.0163879,0.0171509,0.0184326,0.0193787,0.0205994,0.0214539,0.0223389,0.0228577,0.0233459,0.0232849,0.0233459,0.0225525,0.0218201,0.0207214,0.0193787,0.0179749,0.0162048,0.0146179,0.0129089,0.0113220,0.0098267,0.0084534,0.0070801,0.0060120,0.0048828,0.0038757,0.0028687,0.0018616,0.0010681,-0.0003052,-0.0012817,-0.0026855,-0.0041809,-0.0060120,-0.0078125,-0.0102539,-0.0124512,-0.0154724,-0.0182190,-0.0216064,-0.0248108,-0.0283203,-0.0318909,-0.0354309,-0.0388794,-0.0421753,-0.0449524,-0.0477905,-0.0497437,-0.0515137,-0.0522766,-0.0528259,-0.0524597,-0.0517883,-0.0503540,-0.0486755,-0.0464783,-0.0441589,-0.0416870,-0.0392761,-0.0369873,-0.0350037,-0.0332642,-0.0321960,-0.0310974,-0.0310059,-0.0307007,-0.0314331,-0.0319824,-0.0329590,-0.0337219,-0.0345459,-0.0349731,-0.0354614,-0.0351257,-0.0349426,-0.0338440,-0.0329590,-0.0314331,-0.0299377,-0.0284729,-0.0267944,-0.0256042,-0.0245972,-0.0242004,-0.0239868,-0.0244141,-0.0252686,-0.0266418,-0.0285034,-0.0304565,-0.0327454,-0.0348206,-0.0368347,-0.0386963,-0.0401001,-0.0408020,-0.0407104,-0.0403748,-0.0388184,-0.0373230,-0.0340271,-0.0315247,-0.0276489,-0.0243530,-0.0203552,-0.0168762,-0.0132751,-0.0103149,-0.0077209,-0.0053101,-0.0037842,-0.0022583,-0.0015869,-0.0010071,-0.0008850,-0.0008545,-0.0009155,-0.0014954,-0.0014954,-0.0021057,-0.0020752,-0.0022583,-0.0023499,-0.0019531,-0.0019226,-0.0012817,-0.0009766,-0.0002747,0.0003357,0.0014038,0.0022278,0.0034180,0.0045166,0.0059509,0.0073853,0.0090332,0.0107422,0.0124512,0.0142517,0.0159607,0.0175781,0.0191650,0.0205078,0.0217590,0.0229492,0.0235596,0.0242310,0.0242920,0.0246582,0.0246582,0.0241089,0.0237122,0.0236511,0.0236816,0.0237122,0.0236206,0.0236816,0.0241394,0.0247192,0.0250549,0.0253601,0.0254822,0.0253906,0.0253296,0.0244751,0.0236816,0.0222168,0.0208130,0.0190125,0.0172424,0.0153809,0.0135193,0.0122070,0.0110168,0.0101013,0.0100098,0.0098267,0.0106201,0.0114746,0.0126648,0.0141602,0.0155334,0.0172424,0.0184937,0.0199585,0.0208740,0.0217285,0.0222778,0.0227051,0.0231628,0.0234070,0.0240173,0.0248108,0.0260010,0.0272827,0.0293884,0.0312195,0.0343018,0.0365601,0.0398254,0.0426636,0.0456238,0.0480652,0.0503235,0.0519104,0.0529785,0.0534058,0.0527954,0.0520325,0.0502625,0.0482483,0.0458679,0.0432739,0.0408020,0.0382690,0.0364380,0.0343018,0.0330200,0.0319824,0.0309143,0.0307922,0.0302124,0.0299683,0.0294189,0.0287781,0.0276489,0.0263367,0.0241699,0.0216980,0.0186157,0.0149841,0.0113831,0.0072632,0.0035400,-0.0002441,-0.0034180,-0.0062561,-0.0080872,-0.0094604,-0.0099487,-0.0095215,-0.0087585,-0.0070190,-0.0049744,-0.0024719,0.0000000,0.0022583,0.0044861,0.0060425,0.0068970,0.0073242,0.0062256,0.0049744,0.0021362,-0.0007019,-0.0045471,-0.0086975,-0.0129395,-0.0172424,-0.0214539,-0.0250854,-0.0285034,-0.0310669,-0.0331421,-0.0344849,-0.0350037,-0.0350952,-0.0343933,-0.0334167,-0.0319214,-0.0306091,-0.0291138,-0.0281372,-0.0272217,-0.0269470,-0.0271301,-0.0276794,-0.0287476,-0.0299377,-0.0315857,-0.0332031,-0.0346069,-0.0361328,-0.0366516,-0.0371704,-0.0366516,-0.0359192,-0.0340271,-0.0319824,-0.0289917,-0.0258789,-0.0223694,-0.0190735,-0.0155029,-0.0130310,-0.0102844,-0.0092773,-0.0084229,-0.0088501,-0.0101318,-0.0120850,-0.0151672,-0.0183716,-0.0223999,-0.0264893,-0.0303345,-0.0342102,-0.0373840,-0.0400696,-0.0419922,-0.0428467,-0.0429993,-0.0421753,-0.0403748,-0.0381470,-0.0350037,-0.0316162,-0.0279541,-0.0244141,-0.0209351,-0.0179749,-0.0152893,-0.0131226,-0.0118103,-0.0109253,-0.0109253,-0.0112000,-0.0121460,-0.0134277,-0.0147400,-0.0161743,-0.0175171,-0.0180969,-0.0189514,-0.0184021,-0.0175781,-0.0161133,-0.0136719,-0.0111084,-0.0076599,-0.0043030,-0.0006104,0.0027466,0.0060120,0.0084534,0.0103760,0.0113831,0.0115356,0.0105591,0.0090332,0.0062256,0.0030823,-0.0004272,-0.0046387,-0.0081787,-0.0120850,-0.0149536,-0.0173645,-0.0186462,-0.0190125,-0.0183105,-0.0164185,-0.0135803,-0.0100098,-0.0056458,-0.0011292,0.0036316,0.0081787,0.0120850,0.0154419,0.0175476,0.0189514,0.0191345,0.0184631,0.0167847,0.0143433,0.0115051,0.0083923,0.0052795,0.0024109,0.0002441,-0.0013733,-0.0018921,-0.0016785,-0.0003662,0.0018921,0.0047607,0.0085144,0.0122681,0.0166016,0.0202332,0.0241394,0.0267029,0.0293274,0.0305786,0.0314331,0.0314026,0.0308533,0.0299072,0.0286865,0.0275269,0.0264282,0.0259094,0.0259094,0.0263977,0.0277405,0.0298462,0.0326538,0.0357361,0.0394287,0.0428467,0.0465393,0.0493469,0.0520020,0.0533447,0.0542603,0.0539246,0.0527344,0.0505981,0.0479126,0.0445251,0.0408936,0.0373230,0.0333252,0.0303955,0.0272522,0.0252686,0.0236816,0.0229797,0.0231934,0.0237427,0.0252380,0.0266724,0.0286865,0.0303955,0.0320435,0.0331421,0.0339355,0.0339355,0.0333862,0.0318909,0.0302429,0.0278015,0.0253601,0.0223389,0.0195923,0.0170898,0.0148621,0.0132446,0.0120239,0.0116577,0.0117798,0.0126648,0.0138550,0.0156555,0.0176086,0.0195923,0.0215454,0.0231628,0.0244751,0.0250244,0.0252075,0.0242615,0.0229492,0.0205688,0.0180664,0.0146179,0.0113220,0.0073242,0.0037842,-0.0000305,-0.0032349,-0.0065002,-0.0089722,-0.0112610,-0.0127869,-0.0141602,-0.0148315,-0.0153809,-0.0152893,-0.0152588,-0.0149231,-0.0147705,-0.0146179,-0.0144043,-0.0146790,-0.0148010,-0.0154419,-0.0160217,-0.0167847,-0.0174561,-0.0184631,-0.0189209,-0.0195312,-0.0195923,-0.0194397,-0.0189514,-0.0177307,-0.0164795,-0.0141907,-0.0120850,-0.0091248,-0.0061646,-0.0029602,0.0002136,0.0034485,0.0065002,0.0089417,0.0113220,0.0125732,0.0139465,0.0140686,0.0139771,0.0129395,0.0113831,0.0094910,0.0067444,0.0040588,0.0008240,-0.0022888,-0.0056458,-0.0083008,-0.0113220,-0.0130310,-0.0151978,-0.0157166,-0.0164185,-0.0160828,-0.0150757,-0.0137329,-0.0116882,-0.0090942,-0.0066223,-0.0033875,-0.0002747,0.0027466,0.0057678,0.0085144,0.0108337,0.0128479,0.0141907,0.0148926,0.0155029,0.0151672,0.0146790,0.0135193,0.0121765,0.0107117,0.0091553,0.0076294,0.0063171,0.0051270,0.0045471,0.0044250,0.0046692,0.0054016,0.0065308,0.0081787,0.0100098,0.0121155,0.0140076,0.0159302,0.0173950,0.0184021,0.0191345,0.0187378,0.0180969,0.0168152,0.0149231,0.0129700,0.0104980,0.0082092,0.0063171,0.0044250,0.0036621,0.0031433,0.0037842,0.0050659,0.0075073,0.0104065,0.0143127,0.0183716,0.0230713,0.0272217,0.0316772,0.0350037,0.0381775,0.0399780,0.0408325,0.0407715,0.0396423,0.0378723,0.0350647,0.0318604,0.0284729,0.0249329,0.0219421,0.0190430,0.0170288,0.0154724,0.0153198,0.0152893,0.0165405,0.0180359,0.0201111,0.0229187,0.0251465,0.0275879,0.0291748,0.0306396,0.0312195,0.0312195,0.0301819,0.0285034,0.0262451,0.0236511,0.0206909,0.0175171,0.0144958,0.0117188,0.0092773,0.0073853,0.0059509,0.0052490,0.0050049,0.0055237,0.0063171,0.0072937,0.0087585,0.0100098,0.0113220,0.0125427,0.0132141,0.0138245,0.0139160,0.0138245,0.0129700,0.0121155,0.0105286,0.0091248,0.0070190,0.0051575,0.0028992,0.0005493,-0.0018005,-0.0042419,-0.0063477,-0.0085144,-0.0104675,-0.0122375,-0.0138245,-0.0150452,-0.0158997,-0.0165405,-0.0168457,-0.0169373,-0.0167542,-0.0164490,-0.0158386,-0.0155945,-0.0151367,-0.0150757,-0.0150452,-0.0158386,-0.0162964,-0.0179443,-0.0192871,-0.0213928,-0.0235901,-0.0259705,-0.0282593,-0.0304565,-0.0323181,-0.0340881,-0.0352173,-0.0357666,-0.0362244,-0.0354614,-0.0350037,-0.0334473,-0.0321655,-0.0301819,-0.0285645,-0.0267944,-0.0253296,-0.0241394,-0.0232239,-0.0231934,-0.0229187,-0.0238037,-0.0241699,-0.0253296,-0.0263062,-0.0272522,-0.0282593,-0.0286865,-0.0288391,-0.0287781,-0.0279846,-0.0273438,-0.0257568,-0.0241394,-0.0223083,-0.0201416,-0.0180359,-0.0158997,-0.0137634,-0.0121155,-0.0104370,-0.0092163,-0.0084229,-0.0074158,-0.0072937,-0.0065613,-0.0064392,-0.0059204,-0.0055237,-0.0047607,-0.0039062,-0.0029297,-0.0017090,-0.0005188,0.0011902,0.0025635,0.0041504,0.0054321,0.0067444,0.0076599,0.0084229,0.0086975,0.0087585,0.0083008,0.0076904,0.0065918,0.0054321,0.0037842,0.0023499,0.0004272,-0.0011597,-0.0028687,-0.0044556,-0.0057983,-0.0070801,-0.0078125,-0.0084229,-0.0086365,-0.0083313,-0.0079346,-0.0068054,-0.0056458,-0.0041809,-0.0026245,-0.0009155,0.0007935,0.0021667,0.0038147,0.0046082,0.0055847,0.0060120,0.0060730,0.0060120,0.0057678,0.0054321,0.0052795,0.0051575,0.0054321,0.0061951,0.0074158,0.0093689,0.0118408,0.0150146,0.0186157,0.0226746,0.0267639,0.0312805,0.0353088,0.0392151,0.0423279,0.0447998,0.0463257,0.0468445,0.0465393,0.0448914,0.0429993,0.0399170,0.0368042,0.0333862,0.0298462,0.0270081,0.0241699,0.0225220,0.0210876,0.0209656,0.0212708,0.0227966,0.0246582,0.0272827,0.0301819,0.0330200,0.0360718,0.0384827,0.0405273,0.0416870,0.0422058,0.0414429,0.0401611,0.0377197,0.0347290,0.0311584,0.0270996,0.0227661,0.0186768,0.0144348,0.0110474,0.0081177,0.0056152,0.0045776,0.0036926,0.0042725,0.0051880,0.0072327,0.0095825,0.0123291,0.0152893,0.0181580,0.0208435,0.0231934,0.0249634,0.0262756,0.0267944,0.0267944,0.0259705,0.0248108,0.0227051,0.0205383,0.0177307,0.0151978,0.0125122,0.0101624,0.0083313,0.0070190,0.0064392,0.0064392,0.0072021,0.0086060,0.0104980,0.0127563,0.0151062,0.0173035,0.0194702,0.0211182,0.0216980,0.0221863,0.0206299,0.0190430,0.0155640,0.0114746,0.0060730,0.0001831,-0.0064087,-0.0129089,-0.0196838,-0.0256042,-0.0311890,-0.0354614,-0.0386963,-0.0406189,-0.0411682,-0.0407104,-0.0386047,-0.0358887,-0.0322266,-0.0280457,-0.0235901,-0.0192871,-0.0153503,-0.0120850,-0.0097046,-0.0083008,-0.0081177,-0.0090637,-0.0109863,-0.0137329,-0.0168762,-0.0208435,-0.0242920,-0.0281067,-0.0307312,-0.0332031,-0.0345154,-0.0348511,-0.0344543,-0.0327759,-0.0305176,-0.0275574,-0.0241394,-0.0205688,-0.0172729,-0.0139771,-0.0118103,-0.0100098,-0.0094299,-0.0097961,-0.0111389,-0.0132751,-0.0162964,-0.0195312,-0.0235291,-0.0269470,-0.0306702,-0.0333557,-0.0358276,-0.0370483,-0.0377502,-0.0371399,-0.0358887,-0.0335999,-0.0307922,-0.0274353,-0.0240479,-0.0205078,-0.0171814,-0.0144043,-0.0120850,-0.0105591,-0.0098877,-0.0095215,-0.0104370,-0.0111694,-0.0130615,-0.0144348,-0.0162964,-0.0177307,-0.0189819,-0.0195618,-0.0199280,-0.0194092,-0.0185852,-0.0169983,-0.0151978,-0.0129089,-0.0106812,-0.0084534,-0.0062561,-0.0046082,-0.0033569,-0.0026550,-0.0028076,-0.0033875,-0.0047607,-0.0065613,-0.0089417,-0.0115051,-0.0143738,-0.0170288,-0.0199280,-0.0219116,-0.0242004,-0.0253296,-0.0263977,-0.0267334,-0.0265198,-0.0257263,-0.0246887,-0.0229492,-0.0213013,-0.0189819,-0.0172119,-0.0146484,-0.0127869,-0.0105286,-0.0088196,-0.0070190,-0.0055847,-0.0041504,-0.0032349,-0.0018005,-0.0011902,0.0001221,0.0005188,0.0016479,0.0024109,0.0037537,0.0048218,0.0064392,0.0078125,0.0098267,0.0115356,0.0140381,0.0162048,0.0191040,0.0211792,0.0239563,0.0260315,0.0281982,0.0299377,0.0311890,0.0318909,0.0322266,0.0318909,0.0309753,0.0296631,0.0278320,0.0258179,0.0235291,0.0213928,0.0191650,0.0175171,0.0161438,0.0154114,0.0150146,0.0156250,0.0166016,0.0184021,0.0202637,0.0229797,0.0253906,0.0282593,0.0307007,0.0328979,0.0346375,0.0356750,0.0360107,0.0356445,0.0344543,0.0325012,0.0301819,0.0269470,0.0237122,0.0198975,0.0165710,0.0128479,0.0097961,0.0067749,0.0046692,0.0028076,0.0017090,0.0009460,0.0009460,0.0011292,0.0019836,0.0027161,0.0039062,0.0046082,0.0056763,0.0057678,0.0063171,0.0054321,0.0051575,0.0034790,0.0022583,-0.0000916,-0.0020752,-0.0046082,-0.0069885,-0.0092468,-0.0115662,-0.0133972,-0.0148621,-0.0158997,-0.0161743,-0.0161438,-0.0153809,-0.0140991,-0.0125122,-0.0103760,-0.0083618,-0.0061646,-0.0040894,-0.0023193,-0.0010986,-0.0003052,-0.0002441,-0.0009460,-0.0020142,-0.0042114,-0.0066528,-0.0094604,-0.0127563,-0.0155640,-0.0187378,-0.0208130,-0.0228882,-0.0236206,-0.0238953,-0.0227356,-0.0209045,-0.0180054,-0.0144348,-0.0105591,-0.0059814,-0.0017395,0.0028381,0.0064087,0.0098267,0.0121765,0.0138855,0.0144043,0.0142517,0.0130615,0.0113220,0.0088501,0.0063782,0.0033264,0.0008850,-0.0019531,-0.0038147,-0.0057678,-0.0068359,-0.0077820,-0.0079346,-0.0080872,-0.0076599,-0.0074463,-0.0070801,-0.0070190,-0.0071411,-0.0078125,-0.0083618,-0.0097961,-0.0110779,-0.0127869,-0.0144348,-0.0159607,-0.0172119,-0.0184021,-0.0186768,-0.0189819,-0.0183716,-0.0172729,-0.0159302,-0.0138245,-0.0118713,-0.0094604,-0.0070801,-0.0051575,-0.0031738,-0.0019531,-0.0007019,-0.0003967,0.0000000,-0.0003052,-0.0005798,-0.0013428,-0.0019226,-0.0026550,-0.0033875,-0.0038757,-0.0044250,-0.0045166,-0.0046692,-0.0043640,-0.0043640,-0.0038452,-0.0036621,-0.0032043,-0.0031433,-0.0030823,-0.0033264,-0.0038147,-0.0046387,-0.0055847,-0.0066833,-0.0078735,-0.0091553,-0.0103455,-0.0113831,-0.0122070,-0.0129089,-0.0132141,-0.0133057,-0.0130615,-0.0125122,-0.0119019,-0.0107727,-0.0095520,-0.0082703,-0.0067749,-0.0055542,-0.0039978,-0.0030212,-0.0018005,-0.0008850,-0.0003967,0.0006714,0.0006714,0.0014038,0.0014954,0.0018005,0.0020447,0.0021973,0.0023193,0.0023804,0.0022888,0.0024414,0.0022583,0.0024719,0.0025330,0.0027466,0.0030518,0.0033875,0.0037537,0.0043335,0.0046692,0.0054016,0.0057373,0.0061646,0.0065613,0.0066833,0.0072021,0.0069275,0.0073547,0.0068970,0.0068665,0.0066528,0.0063171,0.0061340,0.0059814,0.0056458,0.0060425,0.0057373,0.0063782,0.0066223,0.0072937,0.0079041,0.0084839,0.0093079,0.0098572,0.0105896,0.0109863,0.0115051,0.0115356,0.0117493,0.0114746,0.0109863,0.0105591,0.0095520,0.0085754,0.0073242,0.0056763,0.0042114,0.0019836,0.0000610,-0.0024414,-0.0048523,-0.0078430,-0.0104980,-0.0137939,-0.0166931,-0.0197754,-0.0230408,-0.0256348,-0.0285034,-0.0310059,-0.0329590,-0.0350037,-0.0360413,-0.0372620,-0.0377808,-0.0381165,-0.0380249,-0.0378418,-0.0374146,-0.0368958,-0.0364990,-0.0359802,-0.0360718,-0.0359192,-0.0364075,-0.0368347,-0.0375977,-0.0386047,-0.0398560,-0.0408325,-0.0421753,-0.0429077,-0.0438538,-0.0439758,-0.0441895,-0.0435181,-0.0428162,-0.0414734,-0.0398560,-0.0382385,-0.0361023,-0.0343628,-0.0321350,-0.0306396,-0.0290833,-0.0279236,-0.0275574,-0.0272217,-0.0276489,-0.0284424,-0.0295410,-0.0311279,-0.0326233,-0.0342102,-0.0356445,-0.0367432,-0.0373840,-0.0375977,-0.0371399,-0.0361023,-0.0342407,-0.0321655,-0.0289307,-0.0258789,-0.0221558,-0.0182800,-0.0144348,-0.0104065,-0.0069580,-0.0034790,-0.0005188,0.0019531,0.0039062,0.0050659,0.0059204,0.0059814,0.0057678,0.0048218,0.0038452,0.0021667,0.0009155,-0.0010071,-0.0024719,-0.0041809,-0.0057678,-0.0072937,-0.0086060,-0.0098267,-0.0109863,-0.0118103,-0.0125732,-0.0131836,-0.0134583,-0.0139160,-0.0135803,-0.0136108,-0.0129089,-0.0123291,-0.0111084,-0.0097961,-0.0078430,-0.0060730,-0.0032959,-0.0010681,0.0021667,0.0049438,0.0082397,0.0112610,0.0143127,0.0170593,0.0199280,0.0222473,0.0244446,0.0262451,0.0277405,0.0291443,0.0298157,0.0307312,0.0311279,0.0315857,0.0318298,0.0320435,0.0324402,0.0326233,0.0329590,0.0331726,0.0335388,0.0338440,0.0338135,0.0338135,0.0333252,0.0329590,0.0318298,0.0308838,0.0290527,0.0277100,0.0255127,0.0235901,0.0213928,0.0192871,0.0171204,0.0154419,0.0135803,0.0121460,0.0110474,0.0102234,0.0097046,0.0096436,0.0095825,0.0100098,0.0102234,0.0112000,0.0112915,0.0120544,0.0124512,0.0126953,0.0133362,0.0133057,0.0137329,0.0140686,0.0142517,0.0149231,0.0151367,0.0159607,0.0166626,0.0176392,0.0185852,0.0198059,0.0212402,0.0226135,0.0242004,0.0256653,0.0271912,0.0287781,0.0299072,0.0309448,0.0318909,0.0322266,0.0326538,0.0323792,0.0319824,0.0309448,0.0298767,0.0280457,0.0260620,0.0238953,0.0210571,0.0186157,0.0155945,0.0130310,0.0104065,0.0079041,0.0060730,0.0041504,0.0030518,0.0019226,0.0014648,0.0012207,0.0008240,0.0010071,0.0009155,0.0008240,0.0007324,-0.0002136,-0.0010071,-0.0027161,-0.0046082,-0.0071716,-0.0103455,-0.0136414,-0.0175476,-0.0212708,-0.0253296,-0.0287781,-0.0322266,-0.0349731,-0.0372925,-0.0388794,-0.0398865,-0.0400696,-0.0397644,-0.0388794,-0.0375366,-0.0359497,-0.0342712,-0.0324097,-0.0310364,-0.0296326,-0.0291748,-0.0287781,-0.0293579,-0.0302429,-0.0316162,-0.0334778,-0.0355225,-0.0375671,-0.0400391,-0.0417786,-0.0436096,-0.0448608,-0.0455017,-0.0460510,-0.0457764,-0.0451355,-0.0438232,-0.0424805,-0.0406189,-0.0391235,-0.0369568,-0.0356445,-0.0339661,-0.0330811,-0.0321960,-0.0316772,-0.0314941,-0.0316162,-0.0317688,-0.0322571,-0.0322266,-0.0324402,-0.0324097,-0.0319214,-0.0312195,-0.0297852,-0.0286255,-0.0264893,-0.0248413,-0.0224304,-0.0203247,-0.0180664,-0.0159607,-0.0140991,-0.0123291,-0.0108948,-0.0098877,-0.0089111,-0.0087891,-0.0082397,-0.0085754,-0.0085754,-0.0090027,-0.0093994,-0.0098572,-0.0103149,-0.0107727,-0.0111389,-0.0116272,-0.0115967,-0.0121155,-0.0120850,-0.0124512,-0.0122986,-0.0124207,-0.0122375,-0.0120850,-0.0118408,-0.0112000,-0.0106812,-0.0097046,-0.0086060,-0.0071716,-0.0054016,-0.0038452,-0.0014038,0.0003967,0.0026245,0.0046997,0.0066223,0.0083313,0.0098572,0.0106812,0.0114136,0.0116272,0.0115051,0.0111389,0.0104675,0.0099792,0.0092468,0.0091858,0.0091858,0.0100708,0.0110779,0.0131226,0.0156860,0.0188599,0.0224915,0.0263977,0.0301514,0.0341187,0.0371399,0.0400085,0.0414734,0.0422363,0.0416870,0.0399475,0.0370483,0.0331726,0.0284119,0.0233459,0.0177612,0.0125122,0.0074463,0.0033569,0.0000305,-0.0019531,-0.0028076,-0.0023193,-0.0003662,0.0023804,0.0062256,0.0105591,0.0152283,0.0199890,0.0243225,0.0280457,0.0312805,0.0330811,0.0343323,0.0341187,0.0333862,0.0316467,0.0291443,0.0268250,0.0241089,0.0217896,0.0200806,0.0189514,0.0188293,0.0198669,0.0215454,0.0245361,0.0281067,0.0323792,0.0369263,0.0415039,0.0456543,0.0496521,0.0523071,0.0545959,0.0551453,0.0550842,0.0535583,0.0510864,0.0476990,0.0436096,0.0389099,0.0344238,0.0295410,0.0252686,0.0215149,0.0182190,0.0162964,0.0145569,0.0144958,0.0145569,0.0158081,0.0172729,0.0191345,0.0212097,0.0230713,0.0245667,0.0259705,0.0261230,0.0263062,0.0250244,0.0233765,0.0208130,0.0174561,0.0137024,0.0094910,0.0046997,0.0003357,-0.0040588,-0.0080872,-0.0113831,-0.0142822,-0.0159912,-0.0173340,-0.0172729,-0.0171204,-0.0156555,-0.0143738,-0.0121765,-0.0102234,-0.0083008,-0.0065613,-0.0054932,-0.0048828,-0.0049438,-0.0060425,-0.0073853,-0.0099182,-0.0127258,-0.0158691,-0.0195618,-0.0226135,-0.0262146,-0.0285950,-0.0310669,-0.0322266,-0.0329285,-0.0326843,-0.0313721,-0.0295105,-0.0267944,-0.0237732,-0.0202026,-0.0167542,-0.0131836,-0.0101013,-0.0075378,-0.0057068,-0.0044250,-0.0041809,-0.0047302,-0.0059204,-0.0081787,-0.0104675,-0.0135498,-0.0167236,-0.0198059,-0.0229187,-0.0254822,-0.0278015,-0.0294189,-0.0305176,-0.0308533,-0.0307922,-0.0299683,-0.0287476,-0.0273438,-0.0254211,-0.0237732,-0.0219421,-0.0204468,-0.0192261,-0.0183105,-0.0180054,-0.0179138,-0.0184021,-0.0190125,-0.0202942,-0.0212402,-0.0227661,-0.0238342,-0.0251160,-0.0260010,-0.0266418,-0.0270386,-0.0267944,-0.0265198,-0.0253601,-0.0241394,-0.0223694,-0.0205383,-0.0183716,-0.0162964,-0.0140991,-0.0122070,-0.0106506,-0.0091553,-0.0084229,-0.0078735,-0.0078125,-0.0084229,-0.0088196,-0.0101624,-0.0112305,-0.0125427,-0.0139160,-0.0148621,-0.0159607,-0.0162354,-0.0167236,-0.0163269,-0.0158691,-0.0148010,-0.0136719,-0.0123901,-0.0109253,-0.0097656,-0.0085754,-0.0078125,-0.0076294,-0.0074158,-0.0082092,-0.0088501,-0.0102539,-0.0117493,-0.0132446,-0.0150452,-0.0164185,-0.0177612,-0.0186157,-0.0189819,-0.0189209,-0.0181580,-0.0165405,-0.0146179,-0.0118713,-0.0089722,-0.0054626,-0.0019226,0.0014954,0.0047607,0.0074463,0.0097656,0.0112000,0.0120850,0.0117798,0.0114746,0.0095825,0.0082703,0.0055847,0.0035095,0.0010071,-0.0008240,-0.0022888,-0.0029907,-0.0027466,-0.0018005,0.0004578,0.0033875,0.0074463,0.0121155,0.0173035,0.0229187,0.0284729,0.0337830,0.0386963,0.0428467,0.0460205,0.0483704,0.0494690,0.0492859,0.0484924,0.0464478,0.0439758,0.0407410,0.0375977,0.0341797,0.0315857,0.0288696,0.0277100,0.0268860,0.0273743,0.0288086,0.0310364,0.0340271,0.0375671,0.0409546,0.0446777,0.0476990,0.0501709,0.0515442,0.0519104,0.0510254,0.0487366,0.0455933,0.0407410,0.0357056,0.0294189,0.0234375,0.0170288,0.0113831,0.0061035,0.0020447,-0.0011597,-0.0026550,-0.0031738,-0.0022583,-0.0003967,0.0024414,0.0060120,0.0099182,0.0139465,0.0176086,0.0210571,0.0235596,0.0254822,0.0263367,0.0262451,0.0254822,0.0234680,0.0216675,0.0187378,0.0162048,0.0134583,0.0110474,0.0092468,0.0078125,0.0071106,0.0068665,0.0075684,0.0084839,0.0100708,0.0118713,0.0137939,0.0158081,0.0176392,0.0190735,0.0202332,0.0204468,0.0205383,0.0196838,0.0183716,0.0162659,0.0137634,0.0109558,0.0075378,0.0042725,0.0007629,-0.0025024,-0.0055847,-0.0085449,-0.0110168,-0.0129395,-0.0146484,-0.0158081,-0.0165405,-0.0168457,-0.0166016,-0.0163269,-0.0154114,-0.0148010,-0.0135498,-0.0128784,-0.0114441,-0.0107422,-0.0097961,-0.0090332,-0.0083618,-0.0076294,-0.0072021,-0.0066528,-0.0061035,-0.0054932,-0.0046692,-0.0039062,-0.0025940,-0.0017090,0.0000000,0.0012207,0.0028992,0.0041809,0.0057373,0.0067139,0.0079956,0.0084534,0.0088806,0.0087585,0.0085144,0.0077209,0.0067444,0.0051575,0.0035706,0.0020752,0.0003662,-0.0020447,-0.0036316,-0.0052185,-0.0061340,-0.0072327,-0.0084229,-0.0089417,-0.0092468,-0.0090332,-0.0091858,-0.0090027,-0.0090332,-0.0088196,-0.0084534,-0.0084229,-0.0082397,-0.0081177,-0.0077820,-0.0074158,-0.0068665,-0.0066833,-0.0058289,-0.0050659,-0.0040588,-0.0030823,-0.0019531,-0.0008850,0.0002441,0.0014038,0.0020752,0.0030212,0.0033875,0.0039368,0.0041504,0.0045776,0.0046082,0.0051270,0.0053406,0.0061646,0.0067139,0.0080261,0.0092163,0.0109558,0.0128174,0.0148926,0.0171204,0.0190735,0.0213623,0.0229797,0.0246277,0.0257263,0.0263672,0.0267944,0.0265503,0.0263977,0.0257874,0.0252075,0.0246582,0.0242004,0.0240784,0.0242615,0.0246582,0.0256042,0.0264893,0.0281982,0.0294189,0.0313110,0.0327148,0.0339050,0.0349121,0.0351562,0.0349731,0.0340576,0.0325623,0.0304565,0.0279541,0.0252380,0.0222168,0.0195312,0.0169067,0.0150146,0.0132751,0.0125732,0.0123291,0.0130005,0.0143433,0.0162964,0.0184021,0.0213013,0.0234680,0.0262451,0.0277405,0.0293884,0.0297241,0.0297241,0.0285950,0.0269470,0.0244751,0.0220032,0.0188293,0.0160217,0.0130310,0.0108643,0.0086365,0.0073547,0.0067749,0.0063782,0.0074158,0.0079956,0.0097961,0.0110474,0.0128174,0.0138245,0.0146484,0.0146790,0.0140076,0.0126648,0.0101013,0.0074463,0.0036621,-0.0003662,-0.0044861,-0.0090942,-0.0128174,-0.0167236,-0.0197754,-0.0223999,-0.0238953,-0.0250549,-0.0249634,-0.0244141,-0.0231934,-0.0214539,-0.0194397,-0.0174561,-0.0154114,-0.0138855,-0.0126343,-0.0121460,-0.0120239,-0.0127869,-0.0139160,-0.0156555,-0.0177612,-0.0199280,-0.0225525,-0.0248718,-0.0270996,-0.0291443,-0.0305176,-0.0316772,-0.0321960,-0.0322266,-0.0318604,-0.0310669,-0.0298462,-0.0287781,-0.0272522,-0.0260010,-0.0250549,-0.0238342,-0.0234375,-0.0229492,-0.0229187,-0.0230713,-0.0235291,-0.0239563,-0.0246277,-0.0251465,-0.0255737,-0.0259399,-0.0258789,-0.0256042,-0.0251465,-0.0241089,-0.0232239,-0.0215454,-0.0200806,-0.0181885,-0.0164490,-0.0145874,-0.0129089,-0.0112305,-0.0096130,-0.0085449,-0.0072021,-0.0064392,-0.0056763,-0.0052185,-0.0050049,-0.0048523,-0.0049438,-0.0050659,-0.0056458,-0.0057373,-0.0062561,-0.0065308,-0.0068970,-0.0072021,-0.0072937,-0.0072937,-0.0071106,-0.0066833,-0.0062866,-0.0055542,-0.0049133,-0.0039978,-0.0031738,-0.0022888,-0.0016479,-0.0007324,-0.0003967,0.0001831,0.0005493,0.0002441,0.0004578,-0.0003662,-0.0007935,-0.0014954,-0.0024719,-0.0032654,-0.0040588,-0.0046692,-0.0049744,-0.0052795,-0.0050049,-0.0045776,-0.0036621,-0.0025330,-0.0009155,0.0006409,0.0029907,0.0047607,0.0072327,0.0091858,0.0112610,0.0131836,0.0145569,0.0159607,0.0165710,0.0172424,0.0172119,0.0171509,0.0166931,0.0161438,0.0154114,0.0148315,0.0141602,0.0138855,0.0134583,0.0137634,0.0140686,0.0149841,0.0158081,0.0174255,0.0187683,0.0209045,0.0223083,0.0246277,0.0260925,0.0279541,0.0292664,0.0303955,0.0313721,0.0317993,0.0325623,0.0323792,0.0326538,0.0325928,0.0323181,0.0324707,0.0321960,0.0323181,0.0324097,0.0325623,0.0329590,0.0332031,0.0337219,0.0339661,0.0342712,0.0344849,0.0342712,0.0341492,0.0332947,0.0324097,0.0311584,0.0293884,0.0277405,0.0254822,0.0234985,0.0214539,0.0192871,0.0174866,0.0159302,0.0145874,0.0137939,0.0132446,0.0131531,0.0135803,0.0140686,0.0151367,0.0161438,0.0173340,0.0184326,0.0191345,0.0198669,0.0198669,0.0197754,0.0187988,0.0177612,0.0159302,0.0139160,0.0115967,0.0091248,0.0063782,0.0039978,0.0016479,-0.0004883,-0.0018921,-0.0034790,-0.0038757,-0.0043335,-0.0038757,-0.0032654,-0.0023499,-0.0010071,0.0002136,0.0014648,0.0027466,0.0034485,0.0041199,0.0041199,0.0037537,0.0028381,0.0016785,-0.0003357,-0.0022278,-0.0047607,-0.0070496,-0.0100403,-0.0122681,-0.0149536,-0.0169983,-0.0188904,-0.0204163,-0.0212402,-0.0218201,-0.0217590,-0.0214539,-0.0206604,-0.0194397,-0.0184021,-0.0169678,-0.0158386,-0.0146484,-0.0137024,-0.0130615,-0.0126343,-0.0128174,-0.0130920,-0.0138855,-0.0151978,-0.0163574,-0.0183105,-0.0200195,-0.0218811,-0.0239563,-0.0252991,-0.0268555,-0.0277100,-0.0282593,-0.0283508,-0.0276794,-0.0267029,-0.0249939,-0.0229492,-0.0205688,-0.0179138,-0.0150146,-0.0122986,-0.0094299,-0.0069580,-0.0047913,-0.0031738,-0.0021057,-0.0014954,-0.0019836,-0.0024719,-0.0039368,-0.0057068,-0.0077820,-0.0101318,-0.0126343,-0.0146790,-0.0171814,-0.0187683,-0.0205383,-0.0215759,-0.0223999,-0.0227966,-0.0227966,-0.0226135,-0.0221558,-0.0217590,-0.0211182,-0.0209045,-0.0206299,-0.0210266,-0.0213013,-0.0226135,-0.0236206,-0.0253601,-0.0270081,-0.0288391,-0.0308228,-0.0324097,-0.0338440,-0.0346680,-0.0351562,-0.0348816,-0.0340271,-0.0323792,-0.0301208,-0.0273438,-0.0240173,-0.0202942,-0.0164185,-0.0124817,-0.0088806,-0.0054321,-0.0028992,-0.0004883,0.0006409,0.0016174,0.0010986,0.0004883,-0.0010681,-0.0030518,-0.0053101,-0.0077820,-0.0098877,-0.0119324,-0.0134888,-0.0146484,-0.0149231,-0.0147095,-0.0136719,-0.0122375,-0.0102844,-0.0079041,-0.0052795,-0.0028076,-0.0004272,0.0016174,0.0032959,0.0046692,0.0051880,0.0053711,0.0051575,0.0045776,0.0038757,0.0031433,0.0022278,0.0018311,0.0014343,0.0020142,0.0024109,0.0036621,0.0050354,0.0072937,0.0096436,0.0122681,0.0150757,0.0176392,0.0203857,0.0225220,0.0245361,0.0258179,0.0267639,0.0272522,0.0270691,0.0265808,0.0257874,0.0247803,0.0235596,0.0225525,0.0212708,0.0206604,0.0198059,0.0197144,0.0195618,0.0198975,0.0201416,0.0209045,0.0211182,0.0220642,0.0221863,0.0225830,0.0225220,0.0223083,0.0217590,0.0209656,0.0200195,0.0189514,0.0176697,0.0165710,0.0151672,0.0140076,0.0128174,0.0118103,0.0108948,0.0100098,0.0094299,0.0088196,0.0081482,0.0077820,0.0070190,0.0066223,0.0060730,0.0054626,0.0048523,0.0043030,0.0037537,0.0031738,0.0026245,0.0022888,0.0017395,0.0016479,0.0014343,0.0012817,0.0011902,0.0008850,0.0008850,0.0002441,0.0000916,-0.0009155,-0.0015564,-0.0026855,-0.0040283,-0.0055237,-0.0072021,-0.0091858,-0.0109863,-0.0130615,-0.0148621,-0.0163574,-0.0181885,-0.0190735,-0.0202026,-0.0205994,-0.0208435,-0.0205688,-0.0200500,-0.0192566,-0.0180969,-0.0167542,-0.0153198,-0.0137939,-0.0121460,-0.0108337,-0.0092468,-0.0080261,-0.0066528,-0.0055542,-0.0043030,-0.0033569,-0.0020142,-0.0010071,0.0003662,0.0016174,0.0030518,0.0044250,0.0058594,0.0073547,0.0086670,0.0100403,0.0105896,0.0113525,0.0112610,0.0110474,0.0099487,0.0085449,0.0066528,0.0043945,0.0019836,-0.0008850,-0.0032043,-0.0057373,-0.0077209,-0.0095215,-0.0105286,-0.0112610,-0.0109863,-0.0104370,-0.0090942,-0.0073853,-0.0052185,-0.0029297,-0.0006104,0.0014038,0.0034485,0.0045776,0.0056763,0.0056458,0.0053711,0.0042114,0.0028992,0.0008545,-0.0012817,-0.0036926,-0.0059814,-0.0079956,-0.0098572,-0.0113525,-0.0122986,-0.0127258,-0.0126953,-0.0122681,-0.0115356,-0.0103455,-0.0094910,-0.0081787,-0.0075989,-0.0069275,-0.0068359,-0.0070190,-0.0076599,-0.0086975,-0.0098267,-0.0113831,-0.0129395,-0.0146484,-0.0159607,-0.0173950,-0.0182495,-0.0189819,-0.0191650,-0.0188904,-0.0182495,-0.0171814,-0.0158386,-0.0141602,-0.0124207,-0.0105896,-0.0087585,-0.0071411,-0.0054321,-0.0041809,-0.0027771,-0.0018005,-0.0009155,-0.0000610,0.0007019,0.0011597,0.0018921,0.0020447,0.0026550,0.0027466,0.0031128,0.0032654,0.0035706,0.0036316,0.0039062,0.0039062,0.0041809,0.0043030,0.0046387,0.0046692,0.0052490,0.0053101,0.0060730,0.0064087,0.0070496,0.0076599,0.0083313,0.0088196,0.0093689,0.0097656,0.0098267,0.0101318,0.0100403,0.0098572,0.0097656,0.0094604,0.0092468,0.0091248,0.0089722,0.0090637,0.0091553,0.0097351,0.0101624,0.0109558,0.0118408,0.0125122,0.0136719,0.0141907,0.0147400,0.0149841,0.0145569,0.0141602,0.0129395,0.0113525,0.0095215,0.0070496,0.0043335,0.0017700,-0.0011902,-0.0040588,-0.0065002,-0.0090332,-0.0111084,-0.0127563,-0.0140381,-0.0150757,-0.0155029,-0.0159912,-0.0159912,-0.0159607,-0.0158081,-0.0160828,-0.0159607,-0.0163879,-0.0168457,-0.0175781,-0.0184937,-0.0194092,-0.0206909,-0.0216980,-0.0229492,-0.0240784,-0.0249023,-0.0256958,-0.0263062,-0.0267944,-0.0270996,-0.0270996,-0.0271301,-0.0269470,-0.0270081,-0.0268555,-0.0267029,-0.0266418,-0.0267334,-0.0268250,-0.0271912,-0.0274048,-0.0279541,-0.0285339,-0.0291443,-0.0299072,-0.0305176,-0.0315552,-0.0322266,-0.0333557,-0.0340576,-0.0353394,-0.0358887,-0.0372925,-0.0378723,-0.0389709,-0.0394592,-0.0402222,-0.0405884,-0.0408936,-0.0408020,-0.0407715,-0.0400696,-0.0393982,-0.0383911,-0.0369873,-0.0356445,-0.0336304,-0.0318909,-0.0296631,-0.0276184,-0.0254822,-0.0230713,-0.0211182,-0.0187683,-0.0169678,-0.0150146,-0.0133057,-0.0117798,-0.0103760,-0.0089722,-0.0079956,-0.0068970,-0.0062256,-0.0056763,-0.0052795,-0.0051575,-0.0053406,-0.0056763,-0.0061646,-0.0070190,-0.0078430,-0.0091553,-0.0100708,-0.0114136,-0.0123901,-0.0133667,-0.0140991,-0.0146179,-0.0148926,-0.0146790,-0.0142822,-0.0135803,-0.0124817,-0.0113525,-0.0096741,-0.0084839,-0.0068970,-0.0055237,-0.0043030,-0.0032959,-0.0023804,-0.0019226,-0.0012512,-0.0011597,-0.0007629,-0.0008240,-0.0002136,-0.0001831,0.0006409,0.0012207,0.0024719,0.0036011,0.0054321,0.0070801,0.0093079,0.0113831,0.0135498,0.0156250,0.0174866,0.0190735,0.0202942,0.0208435,0.0212402,0.0209045,0.0200806,0.0190735,0.0171204,0.0158386,0.0133972,0.0117493,0.0095825,0.0080872,0.0066528,0.0058289,0.0055542,0.0056763,0.0065002,0.0079651,0.0097351,0.0122986,0.0148315,0.0179749,0.0209961,0.0241699,0.0270081,0.0297241,0.0318909,0.0339661,0.0350037,0.0360107,0.0360718,0.0357666,0.0351562,0.0336914,0.0326538,0.0306702,0.0294495,0.0278015,0.0265198,0.0255432,0.0250549,0.0245972,0.0250854,0.0253601,0.0265808,0.0274353,0.0289001,0.0303040,0.0312195,0.0328369,0.0329895,0.0338745,0.0332642,0.0330505,0.0318909,0.0304260,0.0288696,0.0266113,0.0248108,0.0225525,0.0207520,0.0191345,0.0179138,0.0170898,0.0166321,0.0166321,0.0170288,0.0177307,0.0185242,0.0194092,0.0202332,0.0206299,0.0208130,0.0202026,0.0196838,0.0178833,0.0161438,0.0133667,0.0105286,0.0070801,0.0036621,-0.0001526,-0.0035706,-0.0069885,-0.0099182,-0.0123901,-0.0144348,-0.0157471,-0.0166626,-0.0169373,-0.0166626,-0.0163879,-0.0152588,-0.0146179,-0.0135193,-0.0127563,-0.0122070,-0.0119629,-0.0122375,-0.0126648,-0.0139160,-0.0151978,-0.0173340,-0.0194702,-0.0220642,-0.0247498,-0.0274658,-0.0302124,-0.0325623,-0.0350342,-0.0368347,-0.0382690,-0.0393982,-0.0400391,-0.0400696,-0.0399780,-0.0390320,-0.0380859,-0.0365906,-0.0352478,-0.0332642,-0.0318909,-0.0299988,-0.0288086,-0.0273743,-0.0267639,-0.0260315,-0.0260010,-0.0260620,-0.0267639,-0.0275879,-0.0288086,-0.0300293,-0.0314941,-0.0329895,-0.0343628,-0.0356140,-0.0366211,-0.0371399,-0.0375977,-0.0372009,-0.0366516,-0.0356140,-0.0343323,-0.0324402,-0.0309143,-0.0288086,-0.0270691,-0.0249939,-0.0234375,-0.0221252,-0.0208130,-0.0203857,-0.0199585,-0.0199890,-0.0205688,-0.0212402,-0.0221252,-0.0235291,-0.0243530,-0.0255737,-0.0263367,-0.0268860,-0.0269775,-0.0267029,-0.0256958,-0.0245972,-0.0226135,-0.0206604,-0.0178833,-0.0150757,-0.0119934,-0.0088806,-0.0057678,-0.0031433,-0.0004883,0.0012512,0.0026245,0.0032349,0.0032959,0.0026245,0.0013733,-0.0002747,-0.0025024,-0.0046082,-0.0070801,-0.0092163,-0.0114136,-0.0127563,-0.0141296,-0.0143738,-0.0145569,-0.0135498,-0.0122681,-0.0101013,-0.0076599,-0.0048218,-0.0016479,0.0013428,0.0044861,0.0071716,0.0095520,0.0116272,0.0130005,0.0139771,0.0144958,0.0143433,0.0142822,0.0135498,0.0130310,0.0123291,0.0120544,0.0118408,0.0121765,0.0128784,0.0141296,0.0157166,0.0180054,0.0201111,0.0230103,0.0256653,0.0283203,0.0307617,0.0327454,0.0343323,0.0354004,0.0356140,0.0355530,0.0345154,0.0332947,0.0313110,0.0290527,0.0266113,0.0238342,0.0215759,0.0189819,0.0170898,0.0152893,0.0143127,0.0136414,0.0136108,0.0140686,0.0151672,0.0163879,0.0181580,0.0201111,0.0222473,0.0244751,0.0264587,0.0287170,0.0306091,0.0325012,0.0340881,0.0354004,0.0365601,0.0375671,0.0383301,0.0388489,0.0393066,0.0394897,0.0398254,0.0397034,0.0396729,0.0395813,0.0390930,0.0388489,0.0380859,0.0374451,0.0366516,0.0355225,0.0344543,0.0331726,0.0318604,0.0305176,0.0290222,0.0276184,0.0259399,0.0248413,0.0232849,0.0223999,0.0210266,0.0203247,0.0193787,0.0188904,0.0182495,0.0177307,0.0170593,0.0166321,0.0156555,0.0150452,0.0137939,0.0124817,0.0108643,0.0090332,0.0068359,0.0045166,0.0020142,-0.0007324,-0.0033569,-0.0060425,-0.0086365,-0.0109253,-0.0130615,-0.0145569,-0.0158386,-0.0167236,-0.0168457,-0.0168457,-0.0160522,-0.0149536,-0.0135193,-0.0117493,-0.0099487,-0.0079346,-0.0063782,-0.0044861,-0.0037537,-0.0026550,-0.0027161,-0.0030212,-0.0038147,-0.0053101,-0.0070496,-0.0091248,-0.0113525,-0.0136108,-0.0157166,-0.0176392,-0.0190430,-0.0200500,-0.0204468,-0.0202332,-0.0196533,-0.0180969,-0.0167542,-0.0144043,-0.0126038,-0.0104675,-0.0088196,-0.0075073,-0.0065918,-0.0066528,-0.0068665,-0.0082397,-0.0096741,-0.0120544,-0.0143433,-0.0171509,-0.0198364,-0.0222168,-0.0245361,-0.0260315,-0.0272522,-0.0275574,-0.0271301,-0.0259399,-0.0242004,-0.0218201,-0.0190430,-0.0162659,-0.0134583,-0.0108337,-0.0085144,-0.0070190,-0.0056458,-0.0055847,-0.0056152,-0.0067444,-0.0083008,-0.0103760,-0.0127869,-0.0153503,-0.0178223,-0.0202637,-0.0220032,-0.0239258,-0.0246277,-0.0252075,-0.0250549,-0.0245361,-0.0234985,-0.0223999,-0.0210266,-0.0195923,-0.0185852,-0.0174561,-0.0168152,-0.0164795,-0.0165100,-0.0168762,-0.0177307,-0.0185547,-0.0197754,-0.0211487,-0.0219727,-0.0232544,-0.0236511,-0.0241089,-0.0238037,-0.0234070,-0.0222168,-0.0206604,-0.0188293,-0.0165710,-0.0142212,-0.0116577,-0.0089111,-0.0065308,-0.0040283,-0.0018616,0.0002747,0.0018005,0.0032959,0.0040588,0.0046692,0.0047302,0.0045776,0.0039368,0.0032959,0.0022583,0.0013428,0.0001526,-0.0008545,-0.0017700,-0.0025940,-0.0031128,-0.0038452,-0.0037537,-0.0039673,-0.0036011,-0.0032043,-0.0025330,-0.0018311,-0.0008850,-0.0002441,0.0006104,0.0011902,0.0018005,0.0020447,0.0022888,0.0022888,0.0023804,0.0023499,0.0022278,0.0024109,0.0025635,0.0030518,0.0038757,0.0048523,0.0063477,0.0081482,0.0103149,0.0128174,0.0156250,0.0186157,0.0217896,0.0248108,0.0277710,0.0304871,0.0327759,0.0347595,0.0361633,0.0372925,0.0377197,0.0378723,0.0373230,0.0366211,0.0354919,0.0340576,0.0326233,0.0310059,0.0294495,0.0279541,0.0267639,0.0255737,0.0246887,0.0240173,0.0233459,0.0230103,0.0225525,0.0221863,0.0217896,0.0212708,0.0205688,0.0197449,0.0187683,0.0173950,0.0163879,0.0146790,0.0132751,0.0114746,0.0101013,0.0083008,0.0075684,0.0061951,0.0058899,0.0057678,0.0059814,0.0069580,0.0083923,0.0100403,0.0123901,0.0147400,0.0174255,0.0204468,0.0227356,0.0256958,0.0274963,0.0292969,0.0303650,0.0306702,0.0304565,0.0294495,0.0276794,0.0255737,0.0224915,0.0194092,0.0159607,0.0125427,0.0091248,0.0060730,0.0033875,0.0011292,-0.0003967,-0.0015259,-0.0018921,-0.0018921,-0.0012512,-0.0005493,0.0007019,0.0018005,0.0030518,0.0038757,0.0047913,0.0048523,0.0052185,0.0048218,0.0043335,0.0037231,0.0027161,0.0020142,0.0009460,0.0006104,0.0000610,0.0001221,0.0002747,0.0010681,0.0018005,0.0031738,0.0043640,0.0056458,0.0070190,0.0079041,0.0088501,0.0091248,0.0091248,0.0087585,0.0075989,0.0065918,0.0048218,0.0029297,0.0010071,-0.0012817,-0.0031433,-0.0051575,-0.0068665,-0.0082092,-0.0093079,-0.0102539,-0.0104065,-0.0108032,-0.0102844,-0.0099792,-0.0092773,-0.0083618,-0.0076294,-0.0062866,-0.0055542,-0.0039673,-0.0030823,-0.0017395,-0.0005798,0.0006409,0.0017700,0.0026855,0.0032959,0.0038757,0.0038757,0.0037231,0.0030212,0.0020447,0.0004883,-0.0010376,-0.0032349,-0.0051575,-0.0075378,-0.0094299,-0.0110779,-0.0125427,-0.0131836,-0.0135803,-0.0130310,-0.0118713,-0.0103149,-0.0081177,-0.0055542,-0.0030212,-0.0001221,0.0020752,0.0045471,0.0057983,0.0068665,0.0068359,0.0062561,0.0052490,0.0031128,0.0011292,-0.0014343,-0.0039673,-0.0058594,-0.0077820,-0.0087280,-0.0085754,-0.0082092,-0.0059204,-0.0033569,0.0005798,0.0050964,0.0100708,0.0153809,0.0208130,0.0257568,0.0305481,0.0342102,0.0375366,0.0393066,0.0404968,0.0405579,0.0398560,0.0383606,0.0366211,0.0343628,0.0324097,0.0304260,0.0290833,0.0280151,0.0281372,0.0283508,0.0297546,0.0316467,0.0337219,0.0364380,0.0390930,0.0414734,0.0437622,0.0453186,0.0462646,0.0465698,0.0458374,0.0445862,0.0422668,0.0394592,0.0361023,0.0323486,0.0283813,0.0245667,0.0207520,0.0176697,0.0147400,0.0127258,0.0111084,0.0103455,0.0101318,0.0104980,0.0112305,0.0123291,0.0134888,0.0147095,0.0155945,0.0162659,0.0164795,0.0162354,0.0153809,0.0139771,0.0119934,0.0094299,0.0067139,0.0034485,0.0003967,-0.0029907,-0.0056152,-0.0084534,-0.0104370,-0.0118713,-0.0127563,-0.0126343,-0.0121460,-0.0109863,-0.0090027,-0.0069580,-0.0044250,-0.0019226,0.0006104,0.0026550,0.0046082,0.0056152,0.0061951,0.0058594,0.0046997,0.0029297,0.0001831,-0.0028992,-0.0065613,-0.0103149,-0.0144043,-0.0179138,-0.0213928,-0.0241089,-0.0261536,-0.0276184,-0.0278625,-0.0275269,-0.0263672,-0.0243835,-0.0221558,-0.0191345,-0.0165710,-0.0134888,-0.0113525,-0.0091553,-0.0079956,-0.0074768,-0.0077820,-0.0090332,-0.0108032,-0.0132751,-0.0164490,-0.0196228,-0.0229187,-0.0261841,-0.0288086,-0.0312500,-0.0325012,-0.0331116,-0.0330811,-0.0318909,-0.0298767,-0.0273438,-0.0241089,-0.0208435,-0.0173645,-0.0139465,-0.0108948,-0.0084839,-0.0063782,-0.0054016,-0.0046082,-0.0050049,-0.0056152,-0.0070496,-0.0085144,-0.0107117,-0.0123596,-0.0144348,-0.0159302,-0.0174866,-0.0181885,-0.0190430,-0.0187988,-0.0186462,-0.0180664,-0.0170898,-0.0162659,-0.0151062,-0.0144348,-0.0135498,-0.0132751,-0.0130310,-0.0132751,-0.0137939,-0.0143127,-0.0156250,-0.0163574,-0.0177307,-0.0186157,-0.0197449,-0.0202942,-0.0207825,-0.0205994,-0.0202942,-0.0191650,-0.0178528,-0.0159607,-0.0136414,-0.0112610,-0.0084839,-0.0056458,-0.0030212,-0.0000916,0.0021362,0.0043945,0.0061646,0.0076599,0.0085144,0.0090942,0.0091553,0.0089111,0.0083923,0.0075684,0.0066528,0.0056763,0.0047913,0.0039062,0.0034790,0.0028992,0.0029297,0.0029602,0.0035095,0.0041504,0.0048828,0.0060730,0.0069885,0.0083618,0.0091553,0.0102234,0.0108948,0.0113220,0.0116882,0.0115356,0.0113831,0.0110779,0.0104370,0.0103149,0.0096741,0.0099792,0.0099487,0.0107727,0.0118103,0.0136719,0.0157471,0.0185547,0.0216675,0.0252991,0.0291138,0.0331421,0.0370789,0.0409851,0.0444336,0.0474854,0.0500488,0.0516357,0.0530090,0.0531616,0.0529480,0.0522461,0.0505676,0.0488892,0.0463257,0.0440979,0.0413513,0.0389099,0.0363464,0.0342102,0.0320435,0.0304871,0.0289307,0.0279236,0.0268860,0.0263672,0.0256042,0.0251770,0.0245361,0.0237122,0.0229492,0.0215759,0.0204163,0.0185852,0.0166016,0.0145569,0.0121155,0.0094910,0.0068970,0.0041199,0.0016174,-0.0007935,-0.0028687,-0.0044861,-0.0057373,-0.0062866,-0.0064087,-0.0058899,-0.0048828,-0.0031738,-0.0013733,0.0010681,0.0035095,0.0059814,0.0084839,0.0106812,0.0125732,0.0138855,0.0146484,0.0148926,0.0144348,0.0132141,0.0117798,0.0097046,0.0075073,0.0049744,0.0025635,0.0002136,-0.0019226,-0.0035400,-0.0050964,-0.0058289,-0.0064392,-0.0065613,-0.0064087,-0.0058594,-0.0054626,-0.0050354,-0.0044556,-0.0047302,-0.0045471,-0.0053101,-0.0063782,-0.0075378,-0.0090027,-0.0105286,-0.0124512,-0.0140991,-0.0154114,-0.0162048,-0.0167542,-0.0170898,-0.0166321,-0.0160217,-0.0144653,-0.0131531,-0.0114746,-0.0098267,-0.0083923,-0.0066223,-0.0058289,-0.0050964,-0.0049744,-0.0052490,-0.0057373,-0.0067139,-0.0077515,-0.0089417,-0.0097656,-0.0106201,-0.0109253,-0.0111694,-0.0106506,-0.0100708,-0.0088806,-0.0075989,-0.0063171,-0.0046997,-0.0035706,-0.0026855,-0.0019226,-0.0023193,-0.0027466,-0.0039368,-0.0059204,-0.0080261,-0.0108643,-0.0136719,-0.0167236,-0.0196838,-0.0224304,-0.0249023,-0.0267639,-0.0281677,-0.0289917,-0.0293274,-0.0290222,-0.0285645,-0.0273438,-0.0262756,-0.0249023,-0.0233154,-0.0223083,-0.0210571,-0.0205994,-0.0199890,-0.0201111,-0.0204773,-0.0209656,-0.0220337,-0.0228882,-0.0239563,-0.0250244,-0.0258484,-0.0267029,-0.0270691,-0.0274658,-0.0274658,-0.0274658,-0.0270386,-0.0264893,-0.0257568,-0.0249939,-0.0240173,-0.0230713,-0.0221252,-0.0211487,-0.0203247,-0.0194397,-0.0185852,-0.0179443,-0.0170593,-0.0163574,-0.0151672,-0.0145874,-0.0131531,-0.0122986,-0.0110779,-0.0097046,-0.0088196,-0.0074463,-0.0065613,-0.0054626,-0.0045471,-0.0038757,-0.0030823,-0.0026550,-0.0021667,-0.0018005,-0.0014343,-0.0012817,-0.0007935,-0.0006104,0.0000610,0.0006714,0.0014954,0.0027771,0.0038757,0.0056152,0.0070190,0.0090637,0.0105896,0.0127563,0.0143127,0.0162659,0.0177917,0.0192566,0.0204163,0.0216370,0.0221252,0.0227661,0.0227661,0.0228882,0.0223999,0.0220032,0.0213623,0.0207520,0.0202026,0.0195923,0.0192566,0.0189209,0.0187683,0.0185852,0.0187073,0.0188293,0.0190735,0.0191345,0.0193176,0.0192871,0.0191345,0.0189819,0.0181580,0.0176086,0.0162048,0.0152283,0.0135193,0.0120850,0.0104065,0.0085754,0.0070801,0.0054626,0.0040588,0.0028992,0.0017090,0.0009155,0.0002136,-0.0001526,-0.0007019,-0.0005188,-0.0007935,-0.0003967,-0.0003662,0.0000000,0.0004883,0.0007324,0.0012512,0.0018005,0.0022278,0.0027161,0.0032349,0.0037842,0.0039978,0.0046387,0.0046997,0.0048218,0.0046387,0.0042419,0.0037537,0.0028687,0.0017395,0.0005493,-0.0008240,-0.0024414,-0.0039368,-0.0055237,-0.0070190,-0.0082397,-0.0095825,-0.0102539,-0.0109863,-0.0111389,-0.0109558,-0.0108337,-0.0099487,-0.0092163,-0.0082397,-0.0070190,-0.0060425,-0.0048523,-0.0042114,-0.0032349,-0.0029297,-0.0025024,-0.0025330,-0.0026550,-0.0030518,-0.0035706,-0.0042114,-0.0048828,-0.0054321,-0.0061951,-0.0065613,-0.0071106,-0.0070801,-0.0070496,-0.0067749,-0.0063477,-0.0057068,-0.0048523,-0.0041504,-0.0028992,-0.0020752,-0.0010071,-0.0001221,0.0007019,0.0011902,0.0018616,0.0017395,0.0021362,0.0014954,0.0014648,0.0004578,-0.0000305,-0.0011902,-0.0020447,-0.0031738,-0.0039978,-0.0048218,-0.0054626,-0.0059509,-0.0061646,-0.0060120,-0.0055847,-0.0050659,-0.0041199,-0.0031738,-0.0020447,-0.0007629,0.0003967,0.0012207,0.0021057,0.0025940,0.0026550,0.0027466,0.0019836,0.0013428,0.0001221,-0.0010681,-0.0027771,-0.0040283,-0.0058289,-0.0068359,-0.0081177,-0.0087891,-0.0094604,-0.0093689,-0.0093079,-0.0087585,-0.0079651,-0.0072021,-0.0062866,-0.0052795,-0.0049133,-0.0043945,-0.0045166,-0.0048828,-0.0056458,-0.0067139,-0.0081177,-0.0098572,-0.0114746,-0.0134888,-0.0147400,-0.0162048,-0.0169067,-0.0172729,-0.0169983,-0.0162354,-0.0147400,-0.0127258,-0.0101929,-0.0071411,-0.0040894,-0.0007629,0.0022278,0.0054016,0.0079651,0.0101929,0.0117188,0.0128479,0.0131836,0.0131226,0.0125122,0.0111694,0.0099182,0.0080261,0.0064087,0.0048523,0.0034790,0.0021667,0.0017090,0.0013123,0.0016174,0.0023804,0.0032959,0.0047302,0.0062866,0.0080261,0.0096130,0.0112000,0.0123291,0.0133362,0.0136414,0.0135498,0.0130310,0.0115356,0.0102234,0.0076904,0.0055237,0.0025940,-0.0002136,-0.0030823,-0.0055847,-0.0080872,-0.0097961,-0.0112610,-0.0119629,-0.0119324,-0.0114746,-0.0101318,-0.0083618,-0.0059814,-0.0031738,-0.0002136,0.0029297,0.0059204,0.0087585,0.0113525,0.0130310,0.0145874,0.0150452,0.0151978,0.0141602,0.0128784,0.0107117,0.0083618,0.0053406,0.0023804,-0.0008240,-0.0037231,-0.0064087,-0.0089722,-0.0106201,-0.0124512,-0.0129395,-0.0135193,-0.0134583,-0.0129089,-0.0125122,-0.0112915,-0.0108337,-0.0098267,-0.0096741,-0.0094910,-0.0102234,-0.0111084,-0.0129089,-0.0147095,-0.0176392,-0.0203247,-0.0234680,-0.0266418,-0.0297241,-0.0323486,-0.0347290,-0.0365906,-0.0377197,-0.0382080,-0.0381470,-0.0372314,-0.0358887,-0.0341492,-0.0319824,-0.0298767,-0.0278320,-0.0256653,-0.0243530,-0.0229797,-0.0225220,-0.0222168,-0.0226746,-0.0235901,-0.0245972,-0.0262146,-0.0274963,-0.0291748,-0.0303040,-0.0313416,-0.0320129,-0.0320740,-0.0319214,-0.0311584,-0.0300903,-0.0287781,-0.0271606,-0.0254211,-0.0238953,-0.0221252,-0.0209351,-0.0196228,-0.0190735,-0.0185547,-0.0186462,-0.0186462,-0.0191650,-0.0199280,-0.0205383,-0.0213928,-0.0219116,-0.0223999,-0.0226440,-0.0225220,-0.0221252,-0.0215149,-0.0205994,-0.0195923,-0.0183105,-0.0170593,-0.0156250,-0.0144653,-0.0132751,-0.0120850,-0.0111084,-0.0101929,-0.0097351,-0.0090332,-0.0086060,-0.0083618,-0.0081787,-0.0080872,-0.0083313,-0.0083618,-0.0086365,-0.0090942,-0.0093689,-0.0098572,-0.0103149,-0.0106201,-0.0111694,-0.0116882,-0.0118713,-0.0120544,-0.0118408,-0.0117493,-0.0112610,-0.0106812,-0.0096436,-0.0087280,-0.0075989,-0.0066833,-0.0054626,-0.0048218,-0.0038452,-0.0038147,-0.0034485,-0.0037537,-0.0043030,-0.0050049,-0.0059814,-0.0069885,-0.0077515,-0.0085449,-0.0086975,-0.0085754,-0.0077820,-0.0062256,-0.0039978,-0.0009766,0.0025330,0.0065918,0.0111084,0.0155945,0.0202332,0.0245667,0.0282898,0.0316467,0.0338135,0.0356140,0.0359802,0.0357056,0.0345459,0.0325317,0.0302429,0.0270996,0.0243225,0.0213318,0.0187378,0.0166321,0.0149536,0.0142822,0.0140076,0.0146790,0.0158997,0.0176697,0.0198059,0.0222473,0.0246277,0.0268555,0.0288696,0.0302429,0.0310669,0.0313721,0.0307007,0.0298157,0.0279846,0.0260010,0.0238342,0.0212708,0.0192261,0.0170898,0.0155334,0.0144653,0.0140076,0.0142212,0.0151672,0.0164795,0.0184937,0.0206604,0.0230408,0.0255127,0.0278320,0.0299377,0.0315857,0.0327454,0.0333557,0.0333862,0.0328369,0.0317993,0.0303955,0.0285034,0.0266418,0.0244446,0.0224915,0.0203857,0.0185852,0.0169983,0.0156250,0.0144348,0.0134583,0.0126038,0.0119019,0.0109558,0.0102539,0.0090027,0.0079346,0.0061951,0.0044556,0.0024414,0.0002136,-0.0021362,-0.0043030,-0.0069580,-0.0089111,-0.0111694,-0.0127869,-0.0144348,-0.0155945,-0.0165405,-0.0170288,-0.0176086,-0.0175781,-0.0177002,-0.0175781,-0.0178833,-0.0179443,-0.0184631,-0.0191345,-0.0199890,-0.0212708,-0.0224915,-0.0238647,-0.0252075,-0.0263062,-0.0275269,-0.0280457,-0.0286255,-0.0285950,-0.0283203,-0.0277100,-0.0267944,-0.0257874,-0.0244141,-0.0232239,-0.0221252,-0.0211792,-0.0205994,-0.0202942,-0.0205078,-0.0209961,-0.0220642,-0.0231323,-0.0250549,-0.0264282,-0.0283813,-0.0298767,-0.0313721,-0.0327454,-0.0335083,-0.0343323,-0.0341797,-0.0341492,-0.0332336,-0.0324402,-0.0310059,-0.0295105,-0.0276184,-0.0259094,-0.0242004,-0.0225220,-0.0211487,-0.0198669,-0.0188293,-0.0182190,-0.0177917,-0.0175476,-0.0177612,-0.0179749,-0.0185242,-0.0191956,-0.0199280,-0.0207214,-0.0214233,-0.0222168,-0.0226440,-0.0230713,-0.0232849,-0.0230103,-0.0229492,-0.0220032,-0.0215149,-0.0201721,-0.0193787,-0.0177612,-0.0167236,-0.0152588,-0.0139771,-0.0130920,-0.0119934,-0.0117188,-0.0113525,-0.0116577,-0.0124207,-0.0133667,-0.0146179,-0.0162354,-0.0177307,-0.0195618,-0.0210266,-0.0221863,-0.0231934,-0.0234985,-0.0234985,-0.0227966,-0.0213928,-0.0195923,-0.0169983,-0.0140381,-0.0107117,-0.0070496,-0.0033875,0.0003052,0.0037537,0.0068970,0.0095520,0.0119019,0.0132751,0.0145569,0.0151062,0.0150757,0.0149841,0.0140991,0.0135193,0.0125122,0.0115051,0.0108337,0.0100098,0.0099182,0.0096436,0.0101929,0.0108032,0.0119019,0.0135193,0.0151367,0.0172119,0.0193787,0.0214844,0.0238647,0.0257874,0.0277710,0.0296021,0.0309448,0.0321045,0.0329590,0.0330505,0.0332947,0.0327759,0.0321960,0.0310974,0.0300293,0.0285950,0.0274353,0.0259094,0.0247803,0.0238037,0.0230103,0.0227356,0.0224609,0.0227966,0.0232544,0.0241394,0.0251160,0.0264282,0.0274963,0.0288696,0.0299377,0.0308838,0.0316162,0.0318298,0.0320740,0.0316162,0.0313416,0.0302429,0.0295105,0.0282593,0.0272827,0.0263672,0.0254822,0.0251465,0.0247498,0.0251465,0.0252991,0.0261536,0.0269165,0.0279236,0.0289917,0.0298767,0.0307007,0.0312805,0.0310974,0.0308533,0.0296936,0.0285339,0.0263977,0.0241089,0.0212708,0.0183716,0.0154114,0.0122070,0.0094910,0.0066833,0.0045471,0.0025635,0.0014343,0.0007019,0.0004883,0.0007324,0.0011597,0.0022888,0.0032654,0.0044556,0.0054932,0.0062561,0.0066833,0.0067139,0.0063477,0.0054321,0.0041504,0.0024719,0.0004883,-0.0017700,-0.0036926,-0.0060120,-0.0078735,-0.0094299,-0.0106812,-0.0111389,-0.0113525,-0.0110474,-0.0098267,-0.0087280,-0.0066528,-0.0049744,-0.0025940,-0.0009460,0.0010986,0.0021362,0.0030823,0.0032349,0.0028076,0.0017090,-0.0000610,-0.0023193,-0.0050049,-0.0078125,-0.0108032,-0.0137024,-0.0163574,-0.0184937,-0.0202637,-0.0213928,-0.0217590,-0.0218506,-0.0207825,-0.0197144,-0.0181274,-0.0162964,-0.0144653,-0.0127563,-0.0112000,-0.0102539,-0.0095825,-0.0096130,-0.0100708,-0.0109558,-0.0123901,-0.0137939,-0.0156555,-0.0173340,-0.0187988,-0.0203247,-0.0210266,-0.0214233,-0.0215149,-0.0205994,-0.0199890,-0.0180969,-0.0166626,-0.0146179,-0.0127869,-0.0110168,-0.0094299,-0.0083313,-0.0075989,-0.0076294,-0.0078125,-0.0088196,-0.0103149,-0.0119934,-0.0144348,-0.0165100,-0.0191650,-0.0213928,-0.0236511,-0.0256348,-0.0270081,-0.0284119,-0.0287476,-0.0290222,-0.0285339,-0.0275879,-0.0265198,-0.0246277,-0.0228882,-0.0207214,-0.0185242,-0.0164795,-0.0142822,-0.0126343,-0.0110779,-0.0098877,-0.0091553,-0.0088501,-0.0089111,-0.0096741,-0.0101929,-0.0118713,-0.0128784,-0.0148315,-0.0162354,-0.0177307,-0.0190735,-0.0197144,-0.0204773,-0.0200806,-0.0196228,-0.0181885,-0.0166016,-0.0140381,-0.0117188,-0.0084229,-0.0057983,-0.0027466,-0.0003052,0.0019531,0.0034790,0.0045471,0.0046692,0.0043640,0.0031128,0.0014648,-0.0006104,-0.0029907,-0.0051575,-0.0074463,-0.0091553,-0.0102234,-0.0106812,-0.0103149,-0.0088501,-0.0068665,-0.0036926,-0.0001831,0.0039978,0.0083923,0.0129395,0.0174561,0.0213013,0.0251465,0.0275574,0.0301208,0.0308838,0.0314636,0.0311279,0.0300598,0.0286255,0.0266113,0.0248413,0.0228882,0.0213623,0.0202026,0.0192566,0.0193787,0.0192261,0.0204773,0.0214233,0.0229797,0.0245667,0.0259399,0.0275269,0.0281982,0.0288696,0.0284729,0.0275574,0.0261536,0.0236816,0.0210876,0.0178223,0.0142517,0.0108337,0.0072632,0.0041809,0.0014343,-0.0006714,-0.0019836,-0.0026245,-0.0023499,-0.0013428,0.0004272,0.0027466,0.0055542,0.0086060,0.0117493,0.0148010,0.0175171,0.0197754,0.0215149,0.0226440,0.0229187,0.0228271,0.0218201,0.0206299,0.0188293,0.0172119,0.0150146,0.0135498,0.0117493,0.0109863,0.0102539,0.0103760,0.0111084,0.0121460,0.0141296,0.0160217,0.0184021,0.0207520,0.0229187,0.0247498,0.0262451,0.0267944,0.0270996,0.0261536,0.0248413,0.0228271,0.0202026,0.0171814,0.0139465,0.0106506,0.0074158,0.0046692,0.0023193,0.0006104,-0.0003052,-0.0006409,0.0000305,0.0010071,0.0028381,0.0050659,0.0073242,0.0100708,0.0123291,0.0147095,0.0161743,0.0175781,0.0180054,0.0180969,0.0171814,0.0158997,0.0137939,0.0115356,0.0086975,0.0056152,0.0025940,-0.0003357,-0.0034180,-0.0057068,-0.0079041,-0.0095825,-0.0104675,-0.0115356,-0.0112915,-0.0113220,-0.0104980,-0.0097656,-0.0087280,-0.0076294,-0.0065613,-0.0058899,-0.0050049,-0.0046082,-0.0043335,-0.0044556,-0.0049744,-0.0056458,-0.0066223,-0.0076904,-0.0087585,-0.0100403,-0.0109863,-0.0119019,-0.0124207,-0.0126343,-0.0126343,-0.0117798,-0.0110779,-0.0093079,-0.0078430,-0.0053406,-0.0032654,-0.0005188,0.0017700,0.0043640,0.0064087,0.0084534,0.0098572,0.0109253,0.0114746,0.0114441,0.0108337,0.0099487,0.0083008,0.0066223,0.0043335,0.0021973,-0.0002136,-0.0024414,-0.0047607,-0.0064087,-0.0081482,-0.0092468,-0.0097961,-0.0103149,-0.0097961,-0.0094604,-0.0085144,-0.0072327,-0.0059509,-0.0041199,-0.0028076,-0.0008240,0.0006409,0.0025330,0.0038452,0.0055237,0.0068359,0.0080566,0.0093994,0.0103760,0.0115356,0.0128784,0.0137024,0.0152893,0.0165100,0.0181885,0.0196533,0.0215454,0.0229797,0.0252991,0.0265198,0.0285950,0.0298157,0.0314026,0.0323792,0.0332336,0.0337219,0.0337830,0.0333862,0.0326843,0.0314636,0.0301819,0.0282288,0.0265808,0.0245667,0.0228577,0.0212402,0.0197754,0.0189514,0.0183411,0.0180359,0.0185852,0.0191040,0.0204163,0.0216370,0.0234375,0.0248718,0.0267334,0.0278015,0.0292053,0.0293579,0.0297546,0.0290527,0.0281982,0.0266724,0.0248413,0.0226135,0.0205383,0.0179443,0.0162048,0.0140076,0.0125732,0.0116272,0.0109863,0.0110779,0.0115662,0.0124817,0.0138245,0.0151672,0.0167542,0.0179138,0.0190735,0.0194397,0.0194092,0.0187073,0.0172729,0.0151672,0.0126648,0.0094604,0.0061340,0.0024414,-0.0010071,-0.0045776,-0.0074463,-0.0100403,-0.0118713,-0.0132751,-0.0133362,-0.0133667,-0.0120850,-0.0105896,-0.0085144,-0.0061340,-0.0036011,-0.0013428,0.0007935,0.0025024,0.0036621,0.0041809,0.0042419,0.0032654,0.0020447,0.0000305,-0.0024414,-0.0050964,-0.0081787,-0.0111389,-0.0139160,-0.0167236,-0.0187378,-0.0205383,-0.0215454,-0.0218506,-0.0219116,-0.0209045,-0.0200195,-0.0181885,-0.0166016,-0.0146484,-0.0130615,-0.0113220,-0.0101929,-0.0094299,-0.0092773,-0.0095825,-0.0106812,-0.0120850,-0.0141296,-0.0162659,-0.0187378,-0.0212708,-0.0234375,-0.0256653,-0.0268555,-0.0281677,-0.0282898,-0.0282288,-0.0270691,-0.0258179,-0.0237122,-0.0215454,-0.0191345,-0.0165710,-0.0144653,-0.0123901,-0.0110779,-0.0100098,-0.0099792,-0.0101318,-0.0112610,-0.0125732,-0.0143127,-0.0162354,-0.0179443,-0.0196838,-0.0208740,-0.0217285,-0.0222168,-0.0216980,-0.0212402,-0.0198975,-0.0183105,-0.0165405,-0.0144958,-0.0127869,-0.0111084,-0.0099182,-0.0090942,-0.0090637,-0.0093384,-0.0104675,-0.0119019,-0.0137939,-0.0157776,-0.0180359,-0.0197449,-0.0215759,-0.0225525,-0.0232544,-0.0231628,-0.0224915,-0.0209961,-0.0192871,-0.0164795,-0.0138245,-0.0105591,-0.0073547,-0.0041199,-0.0011292,0.0016174,0.0037842,0.0057373,0.0068054,0.0078125,0.0078125,0.0078430,0.0072632,0.0063477,0.0055542,0.0044556,0.0034485,0.0023804,0.0016479,0.0008240,0.0003357,0.0000916,0.0001526,0.0005188,0.0012207,0.0020752,0.0035095,0.0050964,0.0069580,0.0090942,0.0112915,0.0137939,0.0163574,0.0187988,0.0214844,0.0235291,0.0260925,0.0276184,0.0294189,0.0305786,0.0315552,0.0321960,0.0324707,0.0325623,0.0325317,0.0322876,0.0324402,0.0320435,0.0324402,0.0323792,0.0329590,0.0336609,0.0344238,0.0354309,0.0363464,0.0373840,0.0381165,0.0386963,0.0388794,0.0386047,0.0378418,0.0365601,0.0346680,0.0324707,0.0296936,0.0268555,0.0238647,0.0208130,0.0181274,0.0155640,0.0134583,0.0122681,0.0111084,0.0110474,0.0111389,0.0121765,0.0130615,0.0148315,0.0161133,0.0178223,0.0191345,0.0201721,0.0211487,0.0211487,0.0212708,0.0203857,0.0194092,0.0180969,0.0163879,0.0146790,0.0129089,0.0111389,0.0097046,0.0085144,0.0075989,0.0070190,0.0068054,0.0068665,0.0071411,0.0076904,0.0081482,0.0088501,0.0090942,0.0095825,0.0094299,0.0094604,0.0086975,0.0079651,0.0068359,0.0053711,0.0036926,0.0021362,0.0002747,-0.0014038,-0.0029907,-0.0043945,-0.0054626,-0.0062866,-0.0068359,-0.0068665,-0.0068359,-0.0062561,-0.0057373,-0.0045776,-0.0037231,-0.0023499,-0.0012207,0.0000305,0.0010681,0.0021667,0.0027771,0.0033875,0.0035400,0.0036316,0.0032349,0.0028992,0.0020142,0.0012512,0.0000000,-0.0010071,-0.0023193,-0.0036316,-0.0046692,-0.0062561,-0.0067749,-0.0079956,-0.0086060,-0.0092163,-0.0096741,-0.0098267,-0.0101013,-0.0100708,-0.0102539,-0.0101013,-0.0104370,-0.0103455,-0.0107117,-0.0109863,-0.0115967,-0.0122986,-0.0130005,-0.0138855,-0.0147095,-0.0157166,-0.0164490,-0.0172424,-0.0178223,-0.0181885,-0.0184021,-0.0182800,-0.0180054,-0.0173950,-0.0165100,-0.0154724,-0.0144043,-0.0130920,-0.0117493,-0.0105896,-0.0094299,-0.0084534,-0.0078735,-0.0072632,-0.0073853,-0.0074768,-0.0080566,-0.0088806,-0.0100403,-0.0113220,-0.0129395,-0.0144653,-0.0162659,-0.0179443,-0.0194092,-0.0209351,-0.0222473,-0.0231934,-0.0242004,-0.0248108,-0.0253601,-0.0259399,-0.0262756,-0.0267029,-0.0271606,-0.0276794,-0.0281067,-0.0288696,-0.0293274,-0.0299683,-0.0307007,-0.0309753,-0.0315247,-0.0314331,-0.0312500,-0.0308838,-0.0298157,-0.0285034,-0.0268250,-0.0245056,-0.0222168,-0.0193176,-0.0165710,-0.0134277,-0.0107727,-0.0077515,-0.0052795,-0.0032349,-0.0012207,-0.0000916,0.0009460,0.0013733,0.0013733,0.0011902,0.0007629,0.0000000,-0.0006104,-0.0011597,-0.0015869,-0.0014648,-0.0014343,-0.0006409,0.0002747,0.0017395,0.0033875,0.0053711,0.0072327,0.0093994,0.0111694,0.0130920,0.0146484,0.0156860,0.0167236,0.0173340,0.0173340,0.0175781,0.0169678,0.0168457,0.0162659,0.0158997,0.0155334,0.0156555,0.0154724,0.0161438,0.0166931,0.0177002,0.0187683,0.0199585,0.0213623,0.0224304,0.0238037,0.0244751,0.0254211,0.0256653,0.0256958,0.0252991,0.0244446,0.0234375,0.0216064,0.0200500,0.0176086,0.0154724,0.0129395,0.0105286,0.0083008,0.0057068,0.0039368,0.0018005,0.0003662,-0.0009766,-0.0020752,-0.0027161,-0.0033264,-0.0033264,-0.0035095,-0.0030518,-0.0029297,-0.0021667,-0.0019531,-0.0013123,-0.0009155,-0.0006714,-0.0002441,-0.0003967,-0.0002136,-0.0005188,-0.0006714,-0.0009155,-0.0013428,-0.0016479,-0.0019836,-0.0025330,-0.0026245,-0.0031433,-0.0031738,-0.0032654,-0.0033264,-0.0031128,-0.0028687,-0.0027161,-0.0021057,-0.0018616,-0.0013123,-0.0011902,-0.0009155,-0.0008545,-0.0007935,-0.0010376,-0.0010986,-0.0018616,-0.0020447,-0.0029907,-0.0032654,-0.0041809,-0.0046997,-0.0053711,-0.0057068,-0.0060425,-0.0061646,-0.0059204,-0.0058289,-0.0050964,-0.0044861,-0.0037537,-0.0027466,-0.0019531,-0.0007324,-0.0000305,0.0008545,0.0012207,0.0016479,0.0018921,0.0015259,0.0014648,0.0004883,-0.0000305,-0.0011292,-0.0021973,-0.0031433,-0.0042725,-0.0050659,-0.0055237,-0.0062866,-0.0062561,-0.0061646,-0.0057068,-0.0048218,-0.0041199,-0.0028076,-0.0018616,-0.0004578,0.0003967,0.0015869,0.0020752,0.0027161,0.0028381,0.0028992,0.0025330,0.0019836,0.0014954,0.0007324,0.0000916,-0.0004578,-0.0009766,-0.0009460,-0.0008850,-0.0003357,0.0002136,0.0011902,0.0020752,0.0032959,0.0043945,0.0051880,0.0059204,0.0061951,0.0061035,0.0057068,0.0044556,0.0031433,0.0010681,-0.0009460,-0.0034485,-0.0056458,-0.0083313,-0.0101929,-0.0122070,-0.0135193,-0.0143433,-0.0147705,-0.0144958,-0.0137634,-0.0126953,-0.0111694,-0.0095520,-0.0078430,-0.0061035,-0.0046692,-0.0033264,-0.0023193,-0.0018311,-0.0016174,-0.0016174,-0.0022888,-0.0025940,-0.0036926,-0.0043030,-0.0049744,-0.0053406,-0.0054932,-0.0052795,-0.0047302,-0.0037231,-0.0024109,-0.0007324,0.0008240,0.0027161,0.0046692,0.0060730,0.0078735,0.0087891,0.0096741,0.0102234,0.0102234,0.0101624,0.0095215,0.0090942,0.0079651,0.0075073,0.0064087,0.0060425,0.0054016,0.0052490,0.0054016,0.0056458,0.0062256,0.0067749,0.0075684,0.0084229,0.0093384,0.0097961,0.0104675,0.0108948,0.0111694,0.0111694,0.0107117,0.0103149,0.0097961,0.0092468,0.0082703,0.0078125,0.0070190,0.0067444,0.0063782,0.0061951,0.0060120,0.0061340,0.0061951,0.0065002,0.0067139,0.0067749,0.0069885,0.0068054,0.0069275,0.0063477,0.0058899,0.0049438,0.0039978,0.0026245,0.0011292,-0.0005493,-0.0023804,-0.0043640,-0.0061035,-0.0082397,-0.0098572,-0.0117798,-0.0132446,-0.0147705,-0.0159607,-0.0169373,-0.0179749,-0.0184021,-0.0191040,-0.0192261,-0.0195923,-0.0196838,-0.0196533,-0.0199280,-0.0195923,-0.0198669,-0.0196533,-0.0196838,-0.0195618,-0.0195312,-0.0192566,-0.0192566,-0.0189514,-0.0189209,-0.0184631,-0.0183716,-0.0177612,-0.0177917,-0.0172119,-0.0171204,-0.0168457,-0.0168152,-0.0167542,-0.0170288,-0.0171814,-0.0177612,-0.0183105,-0.0190735,-0.0199585,-0.0208130,-0.0220337,-0.0229492,-0.0241699,-0.0251465,-0.0262451,-0.0271606,-0.0281067,-0.0285645,-0.0292053,-0.0294495,-0.0295410,-0.0296021,-0.0289917,-0.0289001,-0.0281982,-0.0277710,-0.0269775,-0.0264893,-0.0255737,-0.0252380,-0.0244751,-0.0239563,-0.0234985,-0.0231628,-0.0226440,-0.0226440,-0.0221558,-0.0222473,-0.0221558,-0.0220947,-0.0220642,-0.0220032,-0.0218506,-0.0216675,-0.0213928,-0.0211487,-0.0207825,-0.0202332,-0.0197449,-0.0190735,-0.0185547,-0.0177917,-0.0170898,-0.0166321,-0.0157166,-0.0155640,-0.0148315,-0.0149536,-0.0147705,-0.0149231,-0.0153809,-0.0158386,-0.0165405,-0.0171814,-0.0179749,-0.0184021,-0.0191650,-0.0193481,-0.0196533,-0.0194397,-0.0192261,-0.0184021,-0.0177307,-0.0164185,-0.0151978,-0.0135803,-0.0121155,-0.0104065,-0.0088806,-0.0074463,-0.0061951,-0.0050964,-0.0044861,-0.0039062,-0.0036621,-0.0036011,-0.0036926,-0.0039368,-0.0041199,-0.0043030,-0.0045166,-0.0043640,-0.0041199,-0.0035706,-0.0028076,-0.0015869,-0.0004883,0.0013123,0.0030212,0.0047913,0.0068970,0.0086060,0.0106506,0.0119019,0.0136108,0.0143433,0.0153503,0.0155334,0.0155334,0.0153198,0.0145874,0.0139465,0.0128784,0.0120850,0.0108337,0.0101318,0.0092468,0.0089417,0.0086365,0.0090637,0.0096130,0.0108032,0.0122070,0.0140076,0.0162354,0.0186768,0.0212708,0.0238342,0.0264587,0.0288391,0.0309448,0.0327759,0.0340271,0.0350037,0.0355530,0.0353699,0.0350647,0.0339966,0.0330200,0.0313416,0.0298462,0.0280762,0.0263672,0.0249939,0.0234680,0.0227051,0.0216675,0.0216370,0.0213318,0.0219116,0.0224915,0.0233459,0.0245361,0.0255432,0.0268250,0.0277100,0.0287781,0.0291748,0.0295715,0.0292664,0.0286560,0.0276184,0.0261841,0.0241089,0.0221863,0.0193481,0.0170288,0.0141907,0.0115967,0.0093079,0.0070801,0.0053711,0.0041199,0.0031738,0.0030212,0.0031433,0.0039062,0.0049438,0.0063477,0.0079956,0.0095520,0.0112000,0.0124512,0.0134888,0.0138855,0.0137939,0.0129089,0.0114441,0.0092773,0.0066528,0.0033569,-0.0002136,-0.0040588,-0.0080261,-0.0116577,-0.0153198,-0.0183105,-0.0208130,-0.0228577,-0.0237732,-0.0244751,-0.0242004,-0.0234070,-0.0223694,-0.0205994,-0.0192871,-0.0173950,-0.0160522,-0.0148621,-0.0140381,-0.0137024,-0.0137939,-0.0144653,-0.0154419,-0.0167542,-0.0184631,-0.0199890,-0.0217896,-0.0231628,-0.0245667,-0.0253601,-0.0258789,-0.0260010,-0.0257263,-0.0251465,-0.0242310,-0.0232544,-0.0221558,-0.0213623,-0.0203247,-0.0198364,-0.0195007,-0.0196533,-0.0202026,-0.0208130,-0.0219421,-0.0233154,-0.0245667,-0.0263367,-0.0278015,-0.0292358,-0.0305176,-0.0314941,-0.0320740,-0.0325623,-0.0324402,-0.0322266,-0.0313416,-0.0303040,-0.0290222,-0.0274963,-0.0258789,-0.0241089,-0.0223999,-0.0206604,-0.0192871,-0.0177002,-0.0168457,-0.0159607,-0.0155945,-0.0154724,-0.0158386,-0.0165710,-0.0175476,-0.0188293,-0.0203857,-0.0219727,-0.0234680,-0.0251465,-0.0262756,-0.0273132,-0.0278625,-0.0278931,-0.0275574,-0.0263672,-0.0249329,-0.0229187,-0.0205078,-0.0180359,-0.0150452,-0.0125122,-0.0102234,-0.0081177,-0.0066528,-0.0057373,-0.0054016,-0.0057678,-0.0066833,-0.0080872,-0.0097351,-0.0115662,-0.0133362,-0.0149536,-0.0160217,-0.0164490,-0.0164185,-0.0153809,-0.0137634,-0.0112000,-0.0081482,-0.0043640,-0.0003662,0.0036926,0.0078430,0.0115051,0.0147705,0.0172729,0.0190430,0.0200806,0.0202026,0.0195312,0.0184326,0.0163269,0.0144043,0.0119629,0.0099182,0.0078430,0.0062561,0.0053711,0.0048218,0.0050964,0.0060730,0.0075073,0.0098877,0.0124207,0.0153198,0.0186768,0.0217590,0.0250244,0.0277710,0.0303040,0.0324402,0.0338135,0.0347595,0.0350952,0.0346680,0.0338135,0.0324402,0.0306091,0.0283508,0.0260010,0.0234985,0.0212708,0.0189819,0.0173035,0.0160522,0.0151062,0.0150452,0.0154114,0.0166321,0.0183411,0.0205078,0.0232849,0.0259705,0.0290527,0.0317688,0.0344849,0.0363159,0.0380554,0.0385437,0.0385437,0.0376282,0.0357971,0.0335083,0.0303955,0.0272217,0.0236206,0.0202637,0.0170288,0.0143433,0.0124512,0.0110779,0.0107117,0.0111084,0.0122070,0.0140686,0.0162659,0.0186768,0.0212402,0.0233765,0.0252686,0.0264893,0.0267639,0.0263062,0.0250549,0.0228271,0.0199890,0.0167847,0.0128784,0.0092163,0.0055237,0.0022888,-0.0004578,-0.0026550,-0.0039062,-0.0043945,-0.0042114,-0.0032654,-0.0017090,0.0001221,0.0025940,0.0046082,0.0066833,0.0084229,0.0097351,0.0102844,0.0104065,0.0097046,0.0085449,0.0068359,0.0050049,0.0025330,0.0004272,-0.0018921,-0.0039368,-0.0054626,-0.0067749,-0.0074768,-0.0077515,-0.0075073,-0.0068665,-0.0058289,-0.0046997,-0.0031433,-0.0020447,-0.0004883,0.0001831,0.0008545,0.0010376,0.0007324,-0.0000916,-0.0010376,-0.0028687,-0.0043335,-0.0065613,-0.0083923,-0.0104980,-0.0123901,-0.0140076,-0.0153198,-0.0162354,-0.0169678,-0.0168457,-0.0166321,-0.0161133,-0.0151062,-0.0142517,-0.0130005,-0.0119629,-0.0109863,-0.0103760,-0.0098572,-0.0099182,-0.0102539,-0.0109863,-0.0120544,-0.0133667,-0.0151062,-0.0166321,-0.0187073,-0.0200500,-0.0219727,-0.0231018,-0.0242310,-0.0247192,-0.0249023,-0.0247803,-0.0240173,-0.0231323,-0.0218811,-0.0203857,-0.0191650,-0.0178528,-0.0166016,-0.0158997,-0.0152283,-0.0151062,-0.0151672,-0.0157776,-0.0165405,-0.0177612,-0.0189209,-0.0204163,-0.0216675,-0.0229492,-0.0238037,-0.0245361,-0.0247192,-0.0247192,-0.0240173,-0.0231628,-0.0217896,-0.0199585,-0.0182190,-0.0158081,-0.0137939,-0.0114441,-0.0092773,-0.0073242,-0.0051880,-0.0038147,-0.0021667,-0.0014038,-0.0004883,-0.0001526,-0.0001221,-0.0001526,-0.0008850,-0.0012207,-0.0021362,-0.0030518,-0.0037842,-0.0050964,-0.0055237,-0.0066223,-0.0073242,-0.0076904,-0.0082397,-0.0082397,-0.0083008,-0.0082092,-0.0078125,-0.0074768,-0.0069275,-0.0064087,-0.0057678,-0.0052185,-0.0046997,-0.0042114,-0.0037537,-0.0035706,-0.0030518,-0.0029297,-0.0024414,-0.0023193,-0.0018921,-0.0015564,-0.0008240,-0.0001526,0.0008240,0.0020142,0.0032349,0.0049744,0.0063477,0.0084534,0.0100708,0.0121460,0.0138855,0.0157776,0.0173950,0.0188599,0.0201721,0.0210876,0.0220337,0.0224609,0.0227661,0.0228271,0.0225220,0.0222168,0.0213318,0.0207520,0.0195007,0.0184631,0.0173645,0.0160828,0.0149231,0.0136414,0.0122681,0.0113831,0.0101013,0.0094299,0.0085449,0.0079346,0.0076904,0.0072021,0.0072937,0.0073547,0.0075989,0.0080872,0.0085754,0.0092163,0.0099792,0.0106506,0.0114136,0.0121460,0.0126648,0.0133667,0.0137024,0.0142517,0.0143738,0.0146484,0.0146790,0.0147400,0.0146484,0.0146790,0.0143127,0.0143738,0.0139465,0.0138550,0.0134277,0.0132141,0.0126953,0.0124817,0.0118408,0.0115051,0.0108337,0.0106201,0.0098572,0.0096130,0.0089417,0.0087891,0.0083923,0.0084534,0.0083923,0.0086975,0.0090637,0.0095215,0.0104370,0.0110779,0.0120239,0.0129395,0.0137939,0.0145874,0.0153503,0.0158691,0.0163269,0.0165405,0.0166016,0.0163574,0.0160522,0.0153198,0.0146484,0.0136719,0.0126343,0.0115356,0.0103455,0.0091858,0.0082397,0.0071106,0.0061951,0.0053711,0.0046692,0.0039368,0.0034790,0.0027771,0.0024109,0.0020447,0.0016479,0.0014954,0.0011902,0.0010986,0.0007629,0.0006409,0.0003967,0.0003052,0.0000916,-0.0000305,-0.0000305,-0.0002441,-0.0000305,-0.0001831,0.0000916,0.0002747,0.0003052,0.0007324,0.0004272,0.0008545,0.0005188,0.0004272,-0.0000610,-0.0004883,-0.0014343,-0.0021057,-0.0032654,-0.0043640,-0.0054321,-0.0068665,-0.0078430,-0.0089111,-0.0098267,-0.0103455,-0.0107727,-0.0107727,-0.0105896,-0.0099792,-0.0092773,-0.0083923,-0.0075684,-0.0065613,-0.0060730,-0.0051575,-0.0051270,-0.0049438,-0.0053101,-0.0058289,-0.0068054,-0.0077209,-0.0089417,-0.0100403,-0.0111084,-0.0119324,-0.0123901,-0.0124512,-0.0119629,-0.0110474,-0.0096436,-0.0074768,-0.0050354,-0.0023804,0.0009460,0.0038452,0.0070801,0.0101013,0.0126953,0.0151367,0.0170898,0.0185242,0.0195923,0.0203857,0.0206604,0.0207825,0.0206604,0.0203247,0.0201721,0.0199280,0.0198975,0.0200500,0.0203857,0.0207520,0.0214844,0.0219421,0.0229492,0.0233459,0.0241394,0.0244141,0.0249023,0.0246887,0.0246277,0.0241699,0.0237122,0.0230713,0.0223999,0.0215759,0.0209656,0.0200806,0.0196533,0.0190125,0.0186462,0.0183716,0.0181274,0.0180054,0.0180359,0.0179443,0.0182495,0.0184021,0.0185547,0.0191040,0.0194397,0.0200195,0.0206909,0.0211487,0.0222778,0.0229492,0.0239258,0.0248108,0.0255737,0.0262146,0.0266113,0.0267334,0.0263367,0.0256958,0.0247803,0.0231323,0.0216064,0.0193481,0.0171509,0.0148010,0.0124207,0.0101318,0.0082092,0.0062561,0.0050354,0.0038452,0.0030518,0.0029297,0.0024719,0.0030518,0.0030212,0.0034485,0.0035400,0.0036011,0.0034180,0.0028992,0.0021973,0.0011902,0.0003357,-0.0010376,-0.0018616,-0.0030212,-0.0035095,-0.0039673,-0.0037231,-0.0034180,-0.0022583,-0.0011597,0.0007629,0.0025635,0.0047913,0.0067749,0.0088196,0.0102844,0.0117493,0.0123596,0.0127258,0.0120850,0.0111389,0.0096436,0.0075073,0.0054016,0.0025024,0.0000000,-0.0028076,-0.0053406,-0.0076599,-0.0096741,-0.0115051,-0.0124817,-0.0137329,-0.0140076,-0.0143738,-0.0143738,-0.0144348,-0.0141296,-0.0140686,-0.0140076,-0.0141602,-0.0145874,-0.0150452,-0.0161133,-0.0170288,-0.0185242,-0.0197144,-0.0214233,-0.0227051,-0.0242920,-0.0254517,-0.0264893,-0.0273438,-0.0277405,-0.0276489,-0.0276794,-0.0265198,-0.0260620,-0.0242920,-0.0229187,-0.0211792,-0.0192261,-0.0175171,-0.0156860,-0.0140381,-0.0129395,-0.0114441,-0.0111694,-0.0105896,-0.0108337,-0.0112000,-0.0121460,-0.0129700,-0.0145569,-0.0155945,-0.0172424,-0.0183411,-0.0198059,-0.0205994,-0.0216064,-0.0217590,-0.0220947,-0.0218201,-0.0214844,-0.0207520,-0.0199890,-0.0189514,-0.0183105,-0.0170898,-0.0166016,-0.0157776,-0.0153198,-0.0150452,-0.0148010,-0.0148315,-0.0147095,-0.0149536,-0.0149231,-0.0150757,-0.0148926,-0.0146790,-0.0142212,-0.0137634,-0.0127258,-0.0121460,-0.0107422,-0.0097961,-0.0083008,-0.0070801,-0.0055847,-0.0042419,-0.0028381,-0.0014343,-0.0002441,0.0010071,0.0020142,0.0031738,0.0039673,0.0051880,0.0057678,0.0067139,0.0073547,0.0081787,0.0086365,0.0093994,0.0097351,0.0104980,0.0108948,0.0114746,0.0119019,0.0126343,0.0130615,0.0140381,0.0145874,0.0156555,0.0166321,0.0178528,0.0189819,0.0205383,0.0215454,0.0231018,0.0240173,0.0253906,0.0260925,0.0270691,0.0274658,0.0279541,0.0279846,0.0281677,0.0277710,0.0277710,0.0272522,0.0269165,0.0268250,0.0262451,0.0263977,0.0259094,0.0262756,0.0260010,0.0262756,0.0260925,0.0261841,0.0259705,0.0257263,0.0252686,0.0247192,0.0239258,0.0231018,0.0219727,0.0209961,0.0199890,0.0189209,0.0180664,0.0171204,0.0166626,0.0162659,0.0162964,0.0161133,0.0166321,0.0167236,0.0173035,0.0177917,0.0181274,0.0183105,0.0185852,0.0180359,0.0179138,0.0170898,0.0160828,0.0150452,0.0134277,0.0121155,0.0103149,0.0087585,0.0069580,0.0053101,0.0038757,0.0022888,0.0010986,-0.0001831,-0.0009460,-0.0019531,-0.0024109,-0.0029602,-0.0031128,-0.0031433,-0.0030518,-0.0025940,-0.0022278,-0.0013428,-0.0006409,0.0006409,0.0015259,0.0029907,0.0040588,0.0052795,0.0064087,0.0072937,0.0081787,0.0086060,0.0090027,0.0088501,0.0087585,0.0082092,0.0076599,0.0066223,0.0058289,0.0047913,0.0037537,0.0030212,0.0021362,0.0017700,0.0014648,0.0013123,0.0015869,0.0017700,0.0024109,0.0026855,0.0034790,0.0035095,0.0040588,0.0037537,0.0036316,0.0029297,0.0019836,0.0006714,-0.0007324,-0.0025940,-0.0044861,-0.0065613,-0.0085754,-0.0104065,-0.0122681,-0.0135193,-0.0148315,-0.0157166,-0.0161743,-0.0164490,-0.0162048,-0.0158081,-0.0153198,-0.0144653,-0.0137329,-0.0130310,-0.0121155,-0.0118408,-0.0110779,-0.0111389,-0.0110474,-0.0112305,-0.0118408,-0.0123596,-0.0132141,-0.0141602,-0.0151367,-0.0160828,-0.0169983,-0.0179443,-0.0186157,-0.0193176,-0.0197754,-0.0204163,-0.0204773,-0.0209045,-0.0209045,-0.0211792,-0.0210876,-0.0214233,-0.0211792,-0.0215149,-0.0213623,-0.0215759,-0.0214844,-0.0214233,-0.0212402,-0.0210266,-0.0207520,-0.0203247,-0.0196228,-0.0189819,-0.0180969,-0.0171204,-0.0162048,-0.0149231,-0.0140381,-0.0129089,-0.0119019,-0.0112305,-0.0102844,-0.0100708,-0.0095520,-0.0096130,-0.0095825,-0.0099182,-0.0102539,-0.0109253,-0.0112000,-0.0120850,-0.0122986,-0.0129395,-0.0130615,-0.0132751,-0.0132141,-0.0130310,-0.0125122,-0.0122986,-0.0113831,-0.0107422,-0.0098877,-0.0087280,-0.0081482,-0.0068665,-0.0061646,-0.0052185,-0.0041809,-0.0036011,-0.0024414,-0.0018921,-0.0008545,-0.0000916,0.0010986,0.0019226,0.0031128,0.0042114,0.0052795,0.0067444,0.0076904,0.0092468,0.0103455,0.0118408,0.0129395,0.0142517,0.0154724,0.0167847,0.0178528,0.0190125,0.0199280,0.0208740,0.0216370,0.0223694,0.0227661,0.0232544,0.0231323,0.0232849,0.0227356,0.0222778,0.0213318,0.0204773,0.0189819,0.0178833,0.0161743,0.0148926,0.0133362,0.0120544,0.0105896,0.0095825,0.0082397,0.0077515,0.0067444,0.0063171,0.0057373,0.0053406,0.0047607,0.0043640,0.0035095,0.0027771,0.0020447,0.0009155,-0.0000916,-0.0014038,-0.0025940,-0.0036011,-0.0046997,-0.0053406,-0.0059814,-0.0059814,-0.0061340,-0.0054626,-0.0049744,-0.0038147,-0.0028687,-0.0015259,-0.0004883,0.0009766,0.0014954,0.0026550,0.0025024,0.0027771,0.0022583,0.0015564,0.0003967,-0.0008240,-0.0024109,-0.0038452,-0.0053406,-0.0066833,-0.0075989,-0.0083618,-0.0086670,-0.0086975,-0.0082092,-0.0075073,-0.0066223,-0.0054321,-0.0041809,-0.0028076,-0.0015869,-0.0007629,0.0001831,0.0004883,0.0010986,0.0006409,0.0003967,-0.0003357,-0.0010376,-0.0019531,-0.0027771,-0.0036926,-0.0042419,-0.0050354,-0.0051575,-0.0053406,-0.0050049,-0.0048523,-0.0040894,-0.0035706,-0.0026855,-0.0017395,-0.0010071,0.0000000,0.0005493,0.0012817,0.0014343,0.0018311,0.0015259,0.0013123,0.0007019,0.0000000,-0.0007629,-0.0018311,-0.0028076,-0.0036926,-0.0046997,-0.0053406,-0.0058899,-0.0064697,-0.0064392,-0.0066833,-0.0062866,-0.0059509,-0.0054626,-0.0047913,-0.0042114,-0.0035400,-0.0031433,-0.0027771,-0.0025330,-0.0025024,-0.0026855,-0.0030212,-0.0034180,-0.0040283,-0.0044556,-0.0052185,-0.0055237,-0.0061035,-0.0061340,-0.0061646,-0.0060730,-0.0056458,-0.0050659,-0.0044250,-0.0034180,-0.0027771,-0.0019531,-0.0012512,-0.0007019,-0.0003967,-0.0001526,-0.0002747,-0.0005188,-0.0010376,-0.0014954,-0.0021973,-0.0027161,-0.0032959,-0.0036621,-0.0037537,-0.0037537,-0.0033264,-0.0028687,-0.0019226,-0.0011597,0.0000305,0.0009460,0.0022583,0.0029297,0.0039673,0.0043030,0.0047607,0.0048218,0.0046387,0.0043945,0.0040283,0.0034485,0.0030212,0.0023499,0.0022888,0.0019226,0.0022583,0.0023193,0.0029602,0.0034790,0.0044250,0.0051270,0.0062561,0.0070190,0.0079041,0.0087585,0.0093079,0.0099792,0.0102539,0.0105896,0.0108643,0.0110168,0.0110474,0.0113525,0.0112000,0.0118103,0.0114746,0.0122681,0.0122070,0.0126953,0.0128784,0.0132751,0.0136414,0.0138550,0.0143127,0.0142517,0.0148621,0.0147095,0.0151672,0.0151672,0.0155029,0.0153809,0.0156860,0.0153503,0.0154419,0.0148010,0.0144958,0.0135193,0.0123901,0.0110168,0.0091858,0.0072327,0.0050049,0.0024719,-0.0000305,-0.0026550,-0.0051575,-0.0073853,-0.0094910,-0.0111084,-0.0124512,-0.0132446,-0.0133972,-0.0133972,-0.0127563,-0.0117188,-0.0107117,-0.0090942,-0.0079956,-0.0066223,-0.0055237,-0.0048828,-0.0043640,-0.0043030,-0.0047913,-0.0053101,-0.0065002,-0.0077515,-0.0089417,-0.0107727,-0.0119324,-0.0135193,-0.0142517,-0.0154114,-0.0155029,-0.0159912,-0.0158081,-0.0154419,-0.0151062,-0.0142822,-0.0139160,-0.0131226,-0.0129700,-0.0125427,-0.0126953,-0.0128174,-0.0133667,-0.0141907,-0.0150757,-0.0163269,-0.0175171,-0.0190735,-0.0204773,-0.0220032,-0.0233459,-0.0246582,-0.0257874,-0.0267944,-0.0275269,-0.0279846,-0.0282898,-0.0282593,-0.0279846,-0.0274963,-0.0268250,-0.0258484,-0.0249023,-0.0236511,-0.0222778,-0.0210571,-0.0198364,-0.0184937,-0.0174866,-0.0165100,-0.0158386,-0.0153809,-0.0151672,-0.0151978,-0.0154114,-0.0159302,-0.0163574,-0.0169983,-0.0176697,-0.0181580,-0.0185852,-0.0187683,-0.0189819,-0.0187988,-0.0187073,-0.0180054,-0.0177917,-0.0170898,-0.0166931,-0.0163269,-0.0159302,-0.0162048,-0.0164185,-0.0169983,-0.0180359,-0.0190735,-0.0207214,-0.0220642,-0.0238037,-0.0251770,-0.0267334,-0.0278320,-0.0287170,-0.0289307,-0.0290833,-0.0282288,-0.0275269,-0.0257263,-0.0242920,-0.0219421,-0.0198059,-0.0173340,-0.0150757,-0.0128479,-0.0110168,-0.0093689,-0.0084229,-0.0074158,-0.0072937,-0.0072937,-0.0076599,-0.0084534,-0.0092773,-0.0101929,-0.0112610,-0.0118713,-0.0128174,-0.0129395,-0.0132446,-0.0130005,-0.0122986,-0.0117798,-0.0102844,-0.0093384,-0.0078125,-0.0065308,-0.0051880,-0.0041199,-0.0033569,-0.0028381,-0.0028076,-0.0028076,-0.0034790,-0.0042725,-0.0050964,-0.0064392,-0.0069580,-0.0082703,-0.0083313,-0.0087891,-0.0080566,-0.0072021,-0.0054016,-0.0032043,-0.0002441,0.0031433,0.0069275,0.0110168,0.0152283,0.0192871,0.0233154,0.0268860,0.0300293,0.0325623,0.0343628,0.0357056,0.0359802,0.0361633,0.0351562,0.0340576,0.0326233,0.0308228,0.0292053,0.0273743,0.0261230,0.0247192,0.0239258,0.0234375,0.0231018,0.0232239,0.0231628,0.0236816,0.0239258,0.0243835,0.0245056,0.0245361,0.0244446,0.0239868,0.0234985,0.0224609,0.0215759,0.0202637,0.0190125,0.0177917,0.0166016,0.0155640,0.0146484,0.0139160,0.0136414,0.0131531,0.0135498,0.0133057,0.0140381,0.0143433,0.0151062,0.0159302,0.0165100,0.0177002,0.0181580,0.0191956,0.0197144,0.0203552,0.0208130,0.0211792,0.0212097,0.0210571,0.0207520,0.0201721,0.0193176,0.0181580,0.0166931,0.0150757,0.0131226,0.0111389,0.0088196,0.0068054,0.0047607,0.0025635,0.0010986,-0.0005493,-0.0014648,-0.0023804,-0.0026855,-0.0028381,-0.0025940,-0.0022583,-0.0017700,-0.0012207,-0.0006409,-0.0003967,-0.0000610,-0.0003662,-0.0005493,-0.0012817,-0.0021362,-0.0032959,-0.0044556,-0.0058594,-0.0071106,-0.0082703,-0.0093079,-0.0101318,-0.0105286,-0.0109558,-0.0108948,-0.0108643,-0.0105286,-0.0102844,-0.0101013,-0.0099182,-0.0100708,-0.0102539,-0.0108337,-0.0116577,-0.0127869,-0.0140991,-0.0157471,-0.0171814,-0.0190735,-0.0205994,-0.0220947,-0.0233459,-0.0242920,-0.0251160,-0.0252991,-0.0255127,-0.0250854,-0.0248413,-0.0242004,-0.0233765,-0.0227356,-0.0220032,-0.0214844,-0.0210876,-0.0206604,-0.0208435,-0.0207520,-0.0213013,-0.0215759,-0.0223083,-0.0227051,-0.0235291,-0.0239868,-0.0244446,-0.0246887,-0.0247803,-0.0246582,-0.0243835,-0.0238647,-0.0232239,-0.0223999,-0.0215454,-0.0205078,-0.0196533,-0.0185242,-0.0178528,-0.0169373,-0.0165405,-0.0161743,-0.0160217,-0.0164185,-0.0166321,-0.0177002,-0.0183411,-0.0198364,-0.0209656,-0.0223999,-0.0240173,-0.0251465,-0.0267029,-0.0274963,-0.0284424,-0.0287170,-0.0289001,-0.0284119,-0.0276184,-0.0264893,-0.0248413,-0.0231934,-0.0209045,-0.0192566,-0.0167542,-0.0149536,-0.0128784,-0.0114136,-0.0099487,-0.0089722,-0.0082703,-0.0079651,-0.0078125,-0.0080872,-0.0082397,-0.0086365,-0.0088196,-0.0089417,-0.0086060,-0.0081787,-0.0072327,-0.0058899,-0.0044861,-0.0023193,-0.0002441,0.0021973,0.0047302,0.0071716,0.0097046,0.0118408,0.0139465,0.0155029,0.0170288,0.0177002,0.0185852,0.0186768,0.0187988,0.0186157,0.0183105,0.0179443,0.0175476,0.0173035,0.0171509,0.0172424,0.0173035,0.0177917,0.0181580,0.0190125,0.0193481,0.0205078,0.0209045,0.0217896,0.0221863,0.0227051,0.0227661,0.0228577,0.0226746,0.0223389,0.0220032,0.0213623,0.0209656,0.0205078,0.0202637,0.0201721,0.0202332,0.0205688,0.0210266,0.0220337,0.0227966,0.0242310,0.0253296,0.0269165,0.0280457,0.0293884,0.0303955,0.0310974,0.0318298,0.0314636,0.0317078,0.0305176,0.0300293,0.0285339,0.0272827,0.0257568,0.0239258,0.0227051,0.0211182,0.0199585,0.0191040,0.0183411,0.0182495,0.0179443,0.0184021,0.0186462,0.0194092,0.0200806,0.0205383,0.0212097,0.0213623,0.0214539,0.0211487,0.0204163,0.0195923,0.0180664,0.0166626,0.0147400,0.0126038,0.0106506,0.0082703,0.0062256,0.0042114,0.0024719,0.0007324,-0.0003052,-0.0014038,-0.0017700,-0.0019531,-0.0017090,-0.0011902,-0.0003967,0.0007935,0.0020752,0.0036316,0.0049133,0.0062256,0.0078735,0.0088196,0.0098267,0.0102844,0.0105591,0.0107727,0.0102234,0.0094910,0.0083008,0.0067444,0.0054626,0.0034485,0.0016479,-0.0000916,-0.0018616,-0.0032043,-0.0045166,-0.0051575,-0.0057983,-0.0057068,-0.0054321,-0.0048828,-0.0040894,-0.0031433,-0.0023499,-0.0011597,-0.0007935,-0.0002441,-0.0003357,-0.0007019,-0.0013733,-0.0025940,-0.0040588,-0.0057068,-0.0077515,-0.0094604,-0.0116272,-0.0130615,-0.0147095,-0.0155334,-0.0164490,-0.0165100,-0.0166321,-0.0160217,-0.0155640,-0.0144653,-0.0139160,-0.0128174,-0.0122681,-0.0118103,-0.0115662,-0.0117493,-0.0121765,-0.0128784,-0.0136719,-0.0148926,-0.0157471,-0.0169067,-0.0179443,-0.0185852,-0.0192566,-0.0195312,-0.0196533,-0.0193176,-0.0191650,-0.0184326,-0.0179749,-0.0174255,-0.0169983,-0.0169067,-0.0167236,-0.0170593,-0.0174561,-0.0180664,-0.0189819,-0.0198364,-0.0207520,-0.0218506,-0.0223694,-0.0230408,-0.0232239,-0.0230713,-0.0227966,-0.0219116,-0.0208740,-0.0195007,-0.0179749,-0.0162964,-0.0144043,-0.0127869,-0.0108032,-0.0094910,-0.0079651,-0.0068970,-0.0057983,-0.0051270,-0.0044861,-0.0043640,-0.0041199,-0.0041809,-0.0043640,-0.0046082,-0.0051880,-0.0054626,-0.0059814,-0.0064697,-0.0068359,-0.0072327,-0.0073853,-0.0075378,-0.0072632,-0.0072937,-0.0065308,-0.0061035,-0.0050354,-0.0042725,-0.0029907,-0.0018311,-0.0005493,0.0007324,0.0020142,0.0028687,0.0041504,0.0046387,0.0051880,0.0053406,0.0051575,0.0048828,0.0044556,0.0038452,0.0030823,0.0026245,0.0017395,0.0017090,0.0011597,0.0014954,0.0018616,0.0025940,0.0038147,0.0051270,0.0067749,0.0086365,0.0103455,0.0124207,0.0140686,0.0154724,0.0167542,0.0174255,0.0177612,0.0178223,0.0169678,0.0162048,0.0144043,0.0130310,0.0106812,0.0088196,0.0064392,0.0043640,0.0023193,0.0007629,-0.0007019,-0.0015564,-0.0019531,-0.0020142,-0.0014038,-0.0005798,0.0007629,0.0023804,0.0043335,0.0060730,0.0081177,0.0098572,0.0114441,0.0126343,0.0135498,0.0137329,0.0136414,0.0130615,0.0118408,0.0106812,0.0087280,0.0071716,0.0053101,0.0038757,0.0024109,0.0018921,0.0012817,0.0018921,0.0025940,0.0042725,0.0060425,0.0087585,0.0109558,0.0141602,0.0162048,0.0191040,0.0205078,0.0221558,0.0227356,0.0226440,0.0220337,0.0205688,0.0186462,0.0164795,0.0137939,0.0114441,0.0089722,0.0071411,0.0057678,0.0049133,0.0049438,0.0054321,0.0069580,0.0087891,0.0111084,0.0137024,0.0163269,0.0188904,0.0208130,0.0225830,0.0231628,0.0235291,0.0226135,0.0212097,0.0189514,0.0160217,0.0127258,0.0093079,0.0053711,0.0021362,-0.0011902,-0.0038147,-0.0057373,-0.0072632,-0.0075378,-0.0075989,-0.0065308,-0.0051575,-0.0032349,-0.0010071,0.0011597,0.0034180,0.0052490,0.0066833,0.0077209,0.0079651,0.0078430,0.0068970,0.0054932,0.0036926,0.0014954,-0.0008545,-0.0032654,-0.0054016,-0.0073547,-0.0087585,-0.0095825,-0.0098267,-0.0093689,-0.0083923,-0.0065613,-0.0046997,-0.0020752,0.0005493,0.0031433,0.0057983,0.0078430,0.0095520,0.0107117,0.0109863,0.0107727,0.0097046,0.0079651,0.0056458,0.0026550,-0.0003357,-0.0035400,-0.0068054,-0.0095825,-0.0121460,-0.0140991,-0.0152588,-0.0159607,-0.0156555,-0.0148315,-0.0133667,-0.0112000,-0.0089722,-0.0061951,-0.0035095,-0.0008850,0.0015564,0.0034790,0.0050659,0.0061340,0.0065918,0.0068054,0.0062256,0.0057373,0.0047302,0.0039673,0.0028381,0.0021667,0.0014954,0.0012512,0.0013123,0.0014954,0.0024719,0.0032959,0.0046997,0.0062256,0.0078430,0.0096741,0.0115356,0.0130920,0.0148621,0.0160217,0.0174866,0.0182800,0.0191650,0.0194702,0.0198975,0.0197754,0.0195923,0.0194092,0.0187378,0.0184021,0.0177612,0.0172119,0.0168762,0.0163574,0.0164795,0.0163269,0.0168457,0.0173340,0.0181885,0.0192566,0.0205078,0.0219421,0.0235291,0.0249939,0.0267639,0.0279541,0.0295105,0.0301208,0.0310364,0.0309143,0.0309143,0.0300903,0.0291138,0.0275574,0.0260315,0.0239563,0.0219727,0.0198669,0.0179749,0.0159912,0.0144958,0.0130920,0.0119324,0.0112000,0.0104370,0.0101013,0.0096130,0.0094604,0.0089722,0.0087585,0.0079346,0.0073853,0.0061951,0.0054016,0.0039062,0.0028076,0.0014343,0.0001831,-0.0008240,-0.0018616,-0.0022583,-0.0027466,-0.0025330,-0.0023804,-0.0013733,-0.0006409,0.0007324,0.0019531,0.0033264,0.0046997,0.0057373,0.0068359,0.0072632,0.0079346,0.0077515,0.0077515,0.0072327,0.0065918,0.0059204,0.0049438,0.0041504,0.0033264,0.0023193,0.0018311,0.0013123,0.0009155,0.0008850,0.0007324,0.0010681,0.0011902,0.0014954,0.0017395,0.0019226,0.0021057,0.0019836,0.0018921,0.0014954,0.0010376,0.0001526,-0.0006104,-0.0019226,-0.0028687,-0.0045471,-0.0058594,-0.0072937,-0.0088196,-0.0098877,-0.0113525,-0.0120850,-0.0128784,-0.0133667,-0.0133972,-0.0133362,-0.0129089,-0.0123901,-0.0115051,-0.0108948,-0.0097351,-0.0092163,-0.0084229,-0.0081787,-0.0078735,-0.0083923,-0.0086975,-0.0101013,-0.0112305,-0.0132141,-0.0150146,-0.0174561,-0.0193481,-0.0219116,-0.0238342,-0.0257568,-0.0275269,-0.0287476,-0.0297241,-0.0302429,-0.0305481,-0.0302124,-0.0298767,-0.0290833,-0.0282288,-0.0274353,-0.0264282,-0.0255127,-0.0248413,-0.0241089,-0.0235291,-0.0231628,-0.0227051,-0.0223694,-0.0220642,-0.0216980,-0.0211792,-0.0206299,-0.0198669,-0.0190430,-0.0180969,-0.0170288,-0.0159912,-0.0146484,-0.0135498,-0.0125427,-0.0112610,-0.0106506,-0.0096130,-0.0091858,-0.0087280,-0.0081177,-0.0082397,-0.0077515,-0.0077820,-0.0076294,-0.0075073,-0.0074768,-0.0071106,-0.0071411,-0.0068665,-0.0067444,-0.0063477,-0.0063171,-0.0057373,-0.0058594,-0.0052795,-0.0052185,-0.0046387,-0.0044556,-0.0036926,-0.0033875,-0.0023804,-0.0016174,-0.0003967,0.0007629,0.0021667,0.0038147,0.0056152,0.0073853,0.0094299,0.0112305,0.0134583,0.0150757,0.0171204,0.0186768,0.0202942,0.0217590,0.0229187,0.0238037,0.0247803,0.0252686,0.0257568,0.0259094,0.0260315,0.0258484,0.0256348,0.0252686,0.0245056,0.0240173,0.0230103,0.0225220,0.0215149,0.0208130,0.0200806,0.0193787,0.0190125,0.0186768,0.0184937,0.0187683,0.0188904,0.0194092,0.0198669,0.0203857,0.0209045,0.0214539,0.0215759,0.0218201,0.0215454,0.0212402,0.0206604,0.0197754,0.0187073,0.0174866,0.0162354,0.0149536,0.0139160,0.0129700,0.0121460,0.0119629,0.0117188,0.0121460,0.0126343,0.0134277,0.0143738,0.0154114,0.0161438,0.0171509,0.0174866,0.0177612,0.0176392,0.0171509,0.0161438,0.0150452,0.0133972,0.0116882,0.0096741,0.0076599,0.0058289,0.0040588,0.0024414,0.0013733,0.0003357,0.0001221,-0.0000305,0.0001831,0.0006409,0.0012207,0.0021362,0.0029602,0.0038147,0.0046082,0.0051270,0.0057373,0.0060730,0.0061646,0.0061646,0.0060425,0.0055847,0.0053101,0.0047607,0.0043640,0.0038147,0.0034180,0.0030212,0.0026855,0.0025635,0.0023193,0.0023499,0.0024719,0.0027771,0.0029907,0.0036621,0.0039062,0.0047607,0.0051575,0.0058899,0.0064697,0.0070496,0.0074463,0.0076599,0.0078430,0.0076294,0.0073853,0.0066223,0.0057678,0.0047913,0.0031738,0.0019836,0.0001526,-0.0012817,-0.0030518,-0.0045471,-0.0058289,-0.0068970,-0.0078735,-0.0082703,-0.0087585,-0.0086365,-0.0084229,-0.0081482,-0.0075684,-0.0072021,-0.0067444,-0.0065308,-0.0065918,-0.0069275,-0.0076904,-0.0086365,-0.0101318,-0.0117188,-0.0138550,-0.0157776,-0.0181885,-0.0203857,-0.0223389,-0.0245361,-0.0256653,-0.0273132,-0.0278015,-0.0282898,-0.0279541,-0.0272827,-0.0262756,-0.0249939,-0.0232849,-0.0215759,-0.0196838,-0.0180359,-0.0165710,-0.0152283,-0.0143738,-0.0137329,-0.0138245,-0.0137634,-0.0146484,-0.0152588,-0.0165405,-0.0176086,-0.0188599,-0.0199280,-0.0207825,-0.0215454,-0.0217896,-0.0217590,-0.0211182,-0.0202637,-0.0187988,-0.0171509,-0.0151672,-0.0130310,-0.0110779,-0.0088501,-0.0071106,-0.0054016,-0.0042725,-0.0036316,-0.0031738,-0.0035095,-0.0040588,-0.0049438,-0.0063782,-0.0076904,-0.0094299,-0.0106201,-0.0122070,-0.0129395,-0.0139160,-0.0139771,-0.0139465,-0.0133972,-0.0123596,-0.0113220,-0.0097656,-0.0082703,-0.0066528,-0.0051270,-0.0037231,-0.0026245,-0.0014954,-0.0007324,-0.0001221,0.0002136,0.0006409,0.0008240,0.0011597,0.0016479,0.0022583,0.0030518,0.0038452,0.0053101,0.0064697,0.0083313,0.0097961,0.0115356,0.0130920,0.0145264,0.0157166,0.0165405,0.0169983,0.0171814,0.0168762,0.0162354,0.0152283,0.0139160,0.0124817,0.0106506,0.0090332,0.0071106,0.0054321,0.0037231,0.0025024,0.0010986,0.0003967,-0.0003357,-0.0005493,-0.0005188,-0.0002136,0.0003357,0.0012207,0.0021973,0.0033264,0.0046387,0.0056763,0.0071106,0.0079651,0.0089111,0.0096130,0.0099487,0.0101318,0.0098267,0.0092468,0.0084229,0.0072021,0.0059814,0.0043335,0.0028992,0.0012817,-0.0000610,-0.0013123,-0.0021362,-0.0030212,-0.0031738,-0.0032349,-0.0027771,-0.0021973,-0.0014954,-0.0005188,0.0002136,0.0010681,0.0015564,0.0017700,0.0015259,0.0009155,-0.0002136,-0.0015869,-0.0036926,-0.0055542,-0.0080566,-0.0101318,-0.0123291,-0.0141602,-0.0154419,-0.0163574,-0.0165100,-0.0162964,-0.0151978,-0.0139465,-0.0115662,-0.0094910,-0.0067139,-0.0039978,-0.0016174,0.0011902,0.0028381,0.0048828,0.0057678,0.0063782,0.0064697,0.0059204,0.0052185,0.0038757,0.0026245,0.0010376,-0.0003052,-0.0019226,-0.0028687,-0.0039368,-0.0045471,-0.0050354,-0.0050964,-0.0050354,-0.0047607,-0.0043640,-0.0040894,-0.0036011,-0.0034180,-0.0031738,-0.0030823,-0.0034485,-0.0036621,-0.0044556,-0.0050659,-0.0060425,-0.0070190,-0.0082092,-0.0091553,-0.0102539,-0.0109253,-0.0120239,-0.0125122,-0.0130005,-0.0133667,-0.0132446,-0.0131836,-0.0127258,-0.0121460,-0.0112915,-0.0104065,-0.0091248,-0.0080261,-0.0065613,-0.0053101,-0.0039673,-0.0028381,-0.0015564,-0.0007019,0.0003967,0.0007629,0.0015259,0.0014648,0.0018921,0.0013733,0.0014038,0.0006409,0.0002747,-0.0004272,-0.0009460,-0.0015564,-0.0020752,-0.0023499,-0.0026855,-0.0028076,-0.0027466,-0.0025330,-0.0023193,-0.0016785,-0.0014954,-0.0006409,-0.0003357,0.0005188,0.0009155,0.0016479,0.0020752,0.0024414,0.0026550,0.0030518,0.0029297,0.0033569,0.0029907,0.0032349,0.0030518,0.0031433,0.0032043,0.0033569,0.0035095,0.0041504,0.0043640,0.0053406,0.0058594,0.0070190,0.0078735,0.0090332,0.0099792,0.0110779,0.0120239,0.0128174,0.0133667,0.0140991,0.0139771,0.0143127,0.0138550,0.0134583,0.0129089,0.0122681,0.0114136,0.0108643,0.0100403,0.0097046,0.0093079,0.0093689,0.0094299,0.0096741,0.0104065,0.0108032,0.0119934,0.0123596,0.0133972,0.0137634,0.0143738,0.0144653,0.0144043,0.0140991,0.0132751,0.0125122,0.0112610,0.0100403,0.0084534,0.0074158,0.0056763,0.0048523,0.0033875,0.0028992,0.0021362,0.0018311,0.0017090,0.0016174,0.0019531,0.0019226,0.0024414,0.0023499,0.0026245,0.0024109,0.0021057,0.0016785,0.0009460,0.0001221,-0.0009460,-0.0020447,-0.0031433,-0.0046387,-0.0054016,-0.0068359,-0.0075073,-0.0086975,-0.0092163,-0.0099182,-0.0102234,-0.0106812,-0.0109253,-0.0111389,-0.0112000,-0.0115356,-0.0114746,-0.0117493,-0.0119019,-0.0121460,-0.0122070,-0.0125122,-0.0126343,-0.0126648,-0.0130005,-0.0129089,-0.0130005,-0.0132141,-0.0130920,-0.0134888,-0.0135803,-0.0138855,-0.0143738,-0.0148315,-0.0154724,-0.0160828,-0.0168762,-0.0176086,-0.0183411,-0.0191650,-0.0195618,-0.0203857,-0.0202026,-0.0206604,-0.0202026,-0.0201111,-0.0195007,-0.0189209,-0.0180664,-0.0174866,-0.0165710,-0.0160522,-0.0154724,-0.0151062,-0.0150757,-0.0150146,-0.0154114,-0.0158081,-0.0164185,-0.0171509,-0.0179443,-0.0186768,-0.0194702,-0.0201721,-0.0205383,-0.0209961,-0.0210571,-0.0211792,-0.0209961,-0.0206909,-0.0203857,-0.0198364,-0.0195312,-0.0191650,-0.0190735,-0.0190430,-0.0192871,-0.0195618,-0.0202332,-0.0209961,-0.0220032,-0.0230103,-0.0242310,-0.0251770,-0.0263672,-0.0273132,-0.0282593,-0.0288086,-0.0291748,-0.0293579,-0.0292358,-0.0288391,-0.0281372,-0.0275269,-0.0263977,-0.0255432,-0.0242615,-0.0230103,-0.0221863,-0.0207825,-0.0200806,-0.0188904,-0.0182190,-0.0174561,-0.0169067,-0.0163574,-0.0158997,-0.0155029,-0.0150452,-0.0146179,-0.0140991,-0.0136108,-0.0130005,-0.0123596,-0.0115967,-0.0112000,-0.0103455,-0.0100403,-0.0093689,-0.0091858,-0.0088196,-0.0089722,-0.0088806,-0.0089111,-0.0094604,-0.0092468,-0.0100403,-0.0097961,-0.0102539,-0.0101624,-0.0099487,-0.0095520,-0.0088806,-0.0080566,-0.0070496,-0.0056458,-0.0044556,-0.0028076,-0.0015259,0.0001221,0.0012817,0.0025635,0.0036011,0.0046082,0.0052490,0.0059814,0.0065308,0.0069580,0.0075684,0.0078430,0.0084839,0.0092163,0.0098267,0.0109863,0.0120239,0.0131836,0.0147705,0.0157471,0.0175781,0.0185242,0.0201111,0.0209656,0.0218506,0.0222473,0.0228577,0.0225830,0.0227966,0.0222473,0.0216370,0.0211792,0.0203247,0.0197754,0.0189819,0.0186462,0.0180054,0.0179443,0.0176392,0.0177307,0.0178528,0.0180969,0.0184021,0.0187073,0.0191956,0.0195007,0.0198059,0.0202637,0.0204468,0.0207214,0.0207825,0.0211182,0.0211487,0.0214539,0.0213928,0.0216064,0.0217285,0.0218201,0.0219727,0.0221252,0.0221863,0.0224304,0.0224304,0.0224609,0.0223999,0.0221558,0.0219727,0.0214233,0.0208435,0.0201416,0.0190735,0.0181274,0.0164490,0.0153503,0.0134888,0.0118713,0.0097046,0.0080872,0.0058899,0.0041809,0.0022583,0.0005798,-0.0007019,-0.0020142,-0.0026245,-0.0033264,-0.0033569,-0.0030518,-0.0026855,-0.0014954,-0.0005493,0.0007629,0.0022278,0.0035095,0.0049133,0.0059814,0.0069275,0.0075378,0.0077515,0.0078735,0.0072632,0.0068359,0.0055847,0.0047302,0.0033264,0.0021973,0.0007935,-0.0000916,-0.0013428,-0.0018005,-0.0026245,-0.0026855,-0.0028687,-0.0028381,-0.0027161,-0.0027466,-0.0027771,-0.0028992,-0.0034790,-0.0040588,-0.0050964,-0.0064697,-0.0079041,-0.0099487,-0.0116577,-0.0140381,-0.0159302,-0.0182190,-0.0198975,-0.0218506,-0.0229187,-0.0243225,-0.0249634,-0.0253296,-0.0253906,-0.0251160,-0.0244751,-0.0238037,-0.0229797,-0.0219421,-0.0211487,-0.0201416,-0.0196228,-0.0189819,-0.0187988,-0.0185547,-0.0188599,-0.0191345,-0.0196838,-0.0202026,-0.0210876,-0.0216675,-0.0225525,-0.0230713,-0.0237122,-0.0237732,-0.0243530,-0.0239563,-0.0241394,-0.0235291,-0.0232239,-0.0224609,-0.0219116,-0.0211792,-0.0206299,-0.0201721,-0.0197754,-0.0196838,-0.0197144,-0.0202332,-0.0207520,-0.0218201,-0.0227356,-0.0243225,-0.0254211,-0.0271301,-0.0282898,-0.0296936,-0.0306091,-0.0312805,-0.0317383,-0.0315247,-0.0313110,-0.0301514,-0.0290527,-0.0274048,-0.0254211,-0.0236511,-0.0211487,-0.0191040,-0.0169373,-0.0148010,-0.0131226,-0.0112305,-0.0100403,-0.0087585,-0.0079651,-0.0071106,-0.0065918,-0.0059509,-0.0053406,-0.0046997,-0.0038452,-0.0027466,-0.0015564,0.0001221,0.0017395,0.0039673,0.0059814,0.0083313,0.0105286,0.0127563,0.0148621,0.0165710,0.0181580,0.0192566,0.0200500,0.0204468,0.0205383,0.0201111,0.0195618,0.0186462,0.0175476,0.0165100,0.0154419,0.0145264,0.0136414,0.0130310,0.0126648,0.0127869,0.0128479,0.0135498,0.0143127,0.0152283,0.0165100,0.0174866,0.0187683,0.0196533,0.0207214,0.0212097,0.0219116,0.0217896,0.0218201,0.0210876,0.0204773,0.0195007,0.0181274,0.0169678,0.0154114,0.0144043,0.0130615,0.0123596,0.0117798,0.0116577,0.0121460,0.0127563,0.0141907,0.0155334,0.0177917,0.0195312,0.0220947,0.0241089,0.0261536,0.0278320,0.0291443,0.0299072,0.0301514,0.0296936,0.0287476,0.0274048,0.0252380,0.0232849,0.0205688,0.0183716,0.0157166,0.0140076,0.0117798,0.0111694,0.0100098,0.0101624,0.0107422,0.0114746,0.0131226,0.0144958,0.0163879,0.0179443,0.0198669,0.0207520,0.0220642,0.0223083,0.0223083,0.0218506,0.0206909,0.0193787,0.0175171,0.0156250,0.0134888,0.0115356,0.0095825,0.0081177,0.0067444,0.0060730,0.0055237,0.0056152,0.0059509,0.0069275,0.0076599,0.0092468,0.0102234,0.0115967,0.0126343,0.0135498,0.0140991,0.0144043,0.0142822,0.0138245,0.0130005,0.0118408,0.0107422,0.0087585,0.0075073,0.0050659,0.0037537,0.0016174,0.0002747,-0.0014343,-0.0025635,-0.0036926,-0.0043335,-0.0048828,-0.0050659,-0.0050659,-0.0046082,-0.0041809,-0.0035400,-0.0027161,-0.0021057,-0.0012817,-0.0006714,-0.0001831,-0.0000610,-0.0002136,-0.0006714,-0.0014954,-0.0026245,-0.0042114,-0.0059509,-0.0079956,-0.0100708,-0.0122986,-0.0143127,-0.0162048,-0.0176086,-0.0185242,-0.0191956,-0.0190735,-0.0185547,-0.0174561,-0.0157776,-0.0140076,-0.0119934,-0.0097961,-0.0079651,-0.0063477,-0.0049744,-0.0047607,-0.0044556,-0.0055542,-0.0069275,-0.0092468,-0.0118103,-0.0151978,-0.0181580,-0.0218201,-0.0245361,-0.0276489,-0.0295105,-0.0310669,-0.0316162,-0.0313110,-0.0301819,-0.0281982,-0.0255737,-0.0224304,-0.0188293,-0.0152283,-0.0117188,-0.0084229,-0.0055542,-0.0035400,-0.0019531,-0.0013733,-0.0014343,-0.0020447,-0.0037537,-0.0052795,-0.0078125,-0.0098877,-0.0123596,-0.0143433,-0.0158997,-0.0173950,-0.0175781,-0.0178833,-0.0168457,-0.0156250,-0.0139160,-0.0113831,-0.0090942,-0.0063782,-0.0036621,-0.0012817,0.0012512,0.0029907,0.0045776,0.0056152,0.0062561,0.0064087,0.0062256,0.0056763,0.0050659,0.0039978,0.0033569,0.0023193,0.0016174,0.0010986,0.0007019,0.0007935,0.0007324,0.0014343,0.0018311,0.0027771,0.0036926,0.0046997,0.0056458,0.0066223,0.0073242,0.0079956,0.0083618,0.0085754,0.0083618,0.0079651,0.0072937,0.0065002,0.0054626,0.0045166,0.0030518,0.0021973,0.0009155,0.0000305,-0.0007324,-0.0014038,-0.0014648,-0.0016785,-0.0014343,-0.0007324,-0.0003967,0.0009766,0.0017090,0.0028381,0.0041199,0.0049133,0.0061340,0.0067139,0.0074768,0.0079041,0.0081482,0.0084229,0.0080872,0.0082703,0.0078430,0.0078125,0.0075073,0.0073853,0.0074158,0.0072632,0.0074768,0.0074463,0.0077820,0.0079956,0.0081482,0.0083923,0.0084534,0.0084534,0.0083923,0.0081177,0.0079041,0.0074768,0.0067749,0.0064697,0.0056152,0.0053101,0.0046692,0.0044861,0.0042725,0.0041199,0.0044556,0.0046082,0.0052490,0.0059814,0.0068054,0.0080872,0.0087891,0.0104370,0.0112915,0.0127869,0.0139771,0.0151672,0.0163574,0.0172729,0.0181274,0.0187988,0.0192871,0.0195312,0.0196228,0.0193481,0.0191650,0.0185547,0.0178833,0.0170593,0.0161133,0.0151367,0.0140991,0.0133362,0.0124817,0.0117493,0.0115051,0.0108643,0.0111084,0.0107117,0.0110474,0.0110474,0.0112000,0.0112000,0.0111694,0.0109558,0.0103455,0.0096741,0.0085449,0.0071411,0.0056458,0.0039978,0.0020447,0.0001831,-0.0014648,-0.0033569,-0.0043945,-0.0056152,-0.0062866,-0.0064087,-0.0064697,-0.0058899,-0.0052185,-0.0043030,-0.0032349,-0.0022583,-0.0012817,-0.0006409,-0.0000305,0.0000000,-0.0000305,-0.0007019,-0.0014648,-0.0026550,-0.0037842,-0.0052490,-0.0061951,-0.0075684,-0.0080872,-0.0086975,-0.0087585,-0.0083008,-0.0077209,-0.0065613,-0.0052490,-0.0037231,-0.0021057,-0.0005188,0.0008240,0.0021057,0.0029297,0.0035400,0.0036621,0.0034180,0.0027771,0.0018921,0.0006714,-0.0004272,-0.0017090,-0.0026245,-0.0038147,-0.0042419,-0.0047302,-0.0046082,-0.0042725,-0.0036316,-0.0025635,-0.0014954,0.0001221,0.0012207,0.0029602,0.0043640,0.0057068,0.0069580,0.0080566,0.0087891,0.0097046,0.0097656,0.0103760,0.0101929,0.0104065,0.0103149,0.0103149,0.0104065,0.0104675,0.0106812,0.0109863,0.0114746,0.0119324,0.0129089,0.0135498,0.0144653,0.0154114,0.0162048,0.0170593,0.0177612,0.0182800,0.0186462,0.0189209,0.0186157,0.0187073,0.0180664,0.0176086,0.0167847,0.0162048,0.0154419,0.0148010,0.0143738,0.0139465,0.0138245,0.0140381,0.0142822,0.0150757,0.0158081,0.0169678,0.0179749,0.0191956,0.0202332,0.0212402,0.0219421,0.0224915,0.0224609,0.0224609,0.0218506,0.0211487,0.0201416,0.0188293,0.0176086,0.0161133,0.0149536,0.0139160,0.0127869,0.0124207,0.0118103,0.0120239,0.0119629,0.0125732,0.0129700,0.0136719,0.0143738,0.0145874,0.0151062,0.0149841,0.0148621,0.0142212,0.0135803,0.0122986,0.0113525,0.0098572,0.0088196,0.0075989,0.0066223,0.0059509,0.0055542,0.0054932,0.0058594,0.0062561,0.0072021,0.0081482,0.0091248,0.0104065,0.0111389,0.0119629,0.0124207,0.0126343,0.0123901,0.0118103,0.0111084,0.0098267,0.0087891,0.0071411,0.0059814,0.0046692,0.0033569,0.0025635,0.0017700,0.0016785,0.0014343,0.0017090,0.0020142,0.0028381,0.0032654,0.0041504,0.0044861,0.0049744,0.0050964,0.0049744,0.0047607,0.0039062,0.0030518,0.0018311,0.0004883,-0.0010986,-0.0025940,-0.0042725,-0.0057068,-0.0070801,-0.0083313,-0.0093689,-0.0102844,-0.0107422,-0.0111084,-0.0113831,-0.0111694,-0.0113525,-0.0107727,-0.0106506,-0.0101624,-0.0098877,-0.0096130,-0.0093689,-0.0093994,-0.0092163,-0.0098267,-0.0099792,-0.0109863,-0.0115662,-0.0129089,-0.0142822,-0.0156250,-0.0173645,-0.0188293,-0.0208130,-0.0223389,-0.0240173,-0.0252075,-0.0263062,-0.0271606,-0.0274963,-0.0275574,-0.0270691,-0.0263977,-0.0253601,-0.0239563,-0.0224609,-0.0207825,-0.0193787,-0.0172729,-0.0161743,-0.0147400,-0.0142212,-0.0132751,-0.0127869,-0.0135193,-0.0143738,-0.0151978,-0.0159607,-0.0170288,-0.0187683,-0.0201721,-0.0213318,-0.0219727,-0.0225525,-0.0230103,-0.0229187,-0.0225830,-0.0214539,-0.0203857,-0.0191040,-0.0175476,-0.0159607,-0.0141907,-0.0127563,-0.0115051,-0.0104675,-0.0097046,-0.0091553,-0.0090942,-0.0092468,-0.0094604,-0.0101318,-0.0104980,-0.0111084,-0.0115662,-0.0117798,-0.0118408,-0.0114136,-0.0108337,-0.0097351,-0.0084229,-0.0068054,-0.0047607,-0.0027771,-0.0005188,0.0018616,0.0038452,0.0063171,0.0080261,0.0099487,0.0115356,0.0129395,0.0142212,0.0152588,0.0161438,0.0169067,0.0175171,0.0182190,0.0186768,0.0190735,0.0196228,0.0198975,0.0200500,0.0203857,0.0201111,0.0201111,0.0196838,0.0190430,0.0185852,0.0173645,0.0167542,0.0154724,0.0146484,0.0135803,0.0126648,0.0119629,0.0112915,0.0111084,0.0108337,0.0111389,0.0113831,0.0119324,0.0126343,0.0133972,0.0141602,0.0151062,0.0156555,0.0165100,0.0169678,0.0171814,0.0176086,0.0173035,0.0175476,0.0169983,0.0169373,0.0164490,0.0162659,0.0158081,0.0157776,0.0154724,0.0155029,0.0154419,0.0156250,0.0156555,0.0158691,0.0158386,0.0161133,0.0157471,0.0159912,0.0152283,0.0148621,0.0142212,0.0130615,0.0123901,0.0109253,0.0100708,0.0086060,0.0074158,0.0061340,0.0050659,0.0042114,0.0033875,0.0029907,0.0024109,0.0026245,0.0025024,0.0030212,0.0034485,0.0041809,0.0049438,0.0058594,0.0068054,0.0076294,0.0085754,0.0092468,0.0098267,0.0103149,0.0104980,0.0105896,0.0104675,0.0100403,0.0096436,0.0090637,0.0082092,0.0078430,0.0066528,0.0063477,0.0054626,0.0051575,0.0046692,0.0045471,0.0042419,0.0043945,0.0043640,0.0044861,0.0043945,0.0044250,0.0041809,0.0039368,0.0035400,0.0028381,0.0021057,0.0010681,0.0001221,-0.0010986,-0.0021362,-0.0031433,-0.0040588,-0.0046692,-0.0054626,-0.0055237,-0.0057068,-0.0054321,-0.0052490,-0.0047302,-0.0044556,-0.0037842,-0.0034790,-0.0033569,-0.0032959,-0.0036621,-0.0043335,-0.0047913,-0.0065002,-0.0073242,-0.0092163,-0.0104065,-0.0121460,-0.0135498,-0.0146179,-0.0158081,-0.0163269,-0.0167847,-0.0167542,-0.0164185,-0.0159912,-0.0153198,-0.0144348,-0.0136719,-0.0128479,-0.0123596,-0.0119324,-0.0118408,-0.0119629,-0.0124207,-0.0131836,-0.0140686,-0.0152893,-0.0164185,-0.0177002,-0.0188293,-0.0198975,-0.0209045,-0.0214844,-0.0219116,-0.0220947,-0.0221252,-0.0217896,-0.0213623,-0.0207825,-0.0199585,-0.0194092,-0.0184326,-0.0177307,-0.0170898,-0.0163879,-0.0157471,-0.0153198,-0.0146790,-0.0144043,-0.0139160,-0.0133362,-0.0132141,-0.0123291,-0.0122681,-0.0113220,-0.0108948,-0.0101318,-0.0094604,-0.0086975,-0.0080261,-0.0071106,-0.0065613,-0.0058289,-0.0050659,-0.0046692,-0.0039978,-0.0037231,-0.0032043,-0.0030212,-0.0026550,-0.0024719,-0.0023193,-0.0021667,-0.0020752,-0.0018921,-0.0016479,-0.0014648,-0.0012207,-0.0009460,-0.0004883,-0.0001221,0.0003967,0.0008240,0.0014038,0.0018921,0.0025330,0.0030823,0.0035706,0.0041199,0.0045166,0.0048523,0.0053101,0.0054626,0.0056763,0.0058594,0.0057678,0.0059814,0.0060120,0.0059814,0.0061340,0.0061951,0.0061646,0.0064392,0.0065613,0.0067139,0.0068054,0.0070496,0.0070801,0.0070801,0.0069275,0.0068054,0.0066223,0.0060425,0.0057373,0.0051575,0.0045776,0.0040588,0.0033264,0.0027771,0.0025330,0.0017700,0.0018921,0.0013733,0.0014648,0.0014648,0.0018005,0.0019226,0.0023193,0.0025940,0.0028381,0.0034180,0.0033569,0.0035706,0.0035706,0.0032349,0.0032349,0.0024414,0.0021362,0.0013123,0.0005493,-0.0004578,-0.0011902,-0.0022888,-0.0030518,-0.0039368,-0.0045471,-0.0053101,-0.0056763,-0.0062866,-0.0063171,-0.0065613,-0.0063782,-0.0064087,-0.0060120,-0.0058289,-0.0053101,-0.0050659,-0.0043030,-0.0041199,-0.0033569,-0.0030518,-0.0026855,-0.0021667,-0.0020752,-0.0016479,-0.0016479,-0.0014648,-0.0015259,-0.0014038,-0.0014954,-0.0014954,-0.0015564,-0.0015259,-0.0015564,-0.0016174,-0.0016174,-0.0017700,-0.0017700,-0.0021057,-0.0023499,-0.0026855,-0.0033875,-0.0037842,-0.0047302,-0.0053711,-0.0065613,-0.0072327,-0.0084839,-0.0090332,-0.0102539,-0.0105591,-0.0112000,-0.0113220,-0.0112915,-0.0111389,-0.0107727,-0.0101624,-0.0095215,-0.0089417,-0.0081177,-0.0076904,-0.0073853,-0.0070190,-0.0071411,-0.0073242,-0.0077515,-0.0083923,-0.0091553,-0.0098572,-0.0107727,-0.0116272,-0.0121765,-0.0124512,-0.0126953,-0.0122375,-0.0119629,-0.0110474,-0.0099487,-0.0087585,-0.0072632,-0.0059814,-0.0044250,-0.0032959,-0.0021667,-0.0013733,-0.0008850,-0.0006409,-0.0008545,-0.0014954,-0.0022278,-0.0034485,-0.0046082,-0.0060425,-0.0074158,-0.0086365,-0.0099487,-0.0107422,-0.0114746,-0.0116882,-0.0116882,-0.0111389,-0.0104980,-0.0092468,-0.0079346,-0.0061951,-0.0044861,-0.0023193,-0.0004578,0.0017090,0.0035400,0.0052490,0.0066528,0.0079041,0.0086060,0.0093079,0.0093079,0.0093384,0.0088501,0.0082092,0.0074463,0.0065308,0.0057373,0.0049133,0.0041809,0.0039062,0.0035095,0.0037231,0.0039673,0.0048218,0.0056458,0.0068359,0.0080261,0.0091858,0.0106201,0.0114746,0.0124207,0.0126648,0.0129700,0.0127869,0.0122375,0.0112915,0.0101624,0.0086670,0.0076599,0.0060730,0.0046997,0.0036926,0.0028381,0.0026855,0.0025635,0.0029297,0.0035400,0.0046692,0.0058289,0.0073853,0.0084534,0.0099487,0.0108032,0.0118713,0.0123901,0.0123901,0.0123596,0.0117798,0.0109863,0.0100098,0.0085449,0.0075684,0.0061035,0.0051880,0.0040588,0.0034180,0.0028687,0.0028076,0.0027771,0.0032959,0.0036011,0.0043640,0.0049744,0.0054626,0.0059814,0.0062866,0.0065613,0.0062256,0.0059814,0.0051270,0.0043030,0.0032349,0.0016479,0.0005493,-0.0014038,-0.0027161,-0.0044556,-0.0059509,-0.0072937,-0.0087585,-0.0097656,-0.0108337,-0.0115967,-0.0119934,-0.0124817,-0.0123596,-0.0123291,-0.0120239,-0.0113525,-0.0107727,-0.0098877,-0.0090332,-0.0081177,-0.0073853,-0.0064697,-0.0059204,-0.0056458,-0.0052795,-0.0055542,-0.0058289,-0.0065613,-0.0076599,-0.0088196,-0.0102234,-0.0119019,-0.0132446,-0.0149231,-0.0162048,-0.0175781,-0.0186157,-0.0192566,-0.0196838,-0.0196838,-0.0191650,-0.0187988,-0.0175171,-0.0167542,-0.0152588,-0.0141296,-0.0129700,-0.0118713,-0.0111084,-0.0105591,-0.0102234,-0.0105896,-0.0107117,-0.0117188,-0.0126648,-0.0138245,-0.0152283,-0.0164795,-0.0177612,-0.0189514,-0.0195923,-0.0204163,-0.0205383,-0.0204773,-0.0200806,-0.0194702,-0.0185242,-0.0176086,-0.0164795,-0.0153809,-0.0147400,-0.0139771,-0.0139160,-0.0140381,-0.0145264,-0.0157776,-0.0169983,-0.0192566,-0.0212402,-0.0238953,-0.0263672,-0.0289917,-0.0314026,-0.0335083,-0.0350952,-0.0364990,-0.0368652,-0.0371094,-0.0363159,-0.0353088,-0.0334778,-0.0314636,-0.0291443,-0.0264893,-0.0242615,-0.0216370,-0.0198059,-0.0179138,-0.0168457,-0.0161438,-0.0161438,-0.0163879,-0.0172119,-0.0186157,-0.0198975,-0.0216064,-0.0231323,-0.0244446,-0.0256958,-0.0262451,-0.0267029,-0.0263977,-0.0256958,-0.0244751,-0.0228577,-0.0209045,-0.0187988,-0.0162964,-0.0141907,-0.0119324,-0.0100708,-0.0083923,-0.0072937,-0.0065002,-0.0061035,-0.0061646,-0.0064087,-0.0072632,-0.0079956,-0.0089722,-0.0098267,-0.0105591,-0.0109863,-0.0112915,-0.0109558,-0.0103149,-0.0093994,-0.0077515,-0.0061646,-0.0038757,-0.0017090,0.0008545,0.0032043,0.0057068,0.0077515,0.0097656,0.0112000,0.0122681,0.0128784,0.0129700,0.0127563,0.0118713,0.0111389,0.0094299,0.0084229,0.0065002,0.0055237,0.0041199,0.0032959,0.0028381,0.0025024,0.0031433,0.0037231,0.0051575,0.0064392,0.0085144,0.0101318,0.0126038,0.0140991,0.0162048,0.0175171,0.0187683,0.0194397,0.0198364,0.0196533,0.0192871,0.0186462,0.0173950,0.0165100,0.0153809,0.0143433,0.0133972,0.0127563,0.0124817,0.0122681,0.0126953,0.0130005,0.0138855,0.0147705,0.0156250,0.0166626,0.0174561,0.0182800,0.0186462,0.0188904,0.0187073,0.0184326,0.0175781,0.0169067,0.0156860,0.0144958,0.0131836,0.0121460,0.0108643,0.0101929,0.0094299,0.0090027,0.0088806,0.0090637,0.0093689,0.0101929,0.0106506,0.0118103,0.0126648,0.0136108,0.0148926,0.0153503,0.0166016,0.0168762,0.0175476,0.0177002,0.0177002,0.0175781,0.0170593,0.0166016,0.0158081,0.0148926,0.0140991,0.0127869,0.0120544,0.0106506,0.0099182,0.0086365,0.0080261,0.0070190,0.0066223,0.0060120,0.0058899,0.0053711,0.0054932,0.0052185,0.0051880,0.0050354,0.0045471,0.0044556,0.0034180,0.0027771,0.0015259,0.0002441,-0.0010681,-0.0028076,-0.0042419,-0.0060730,-0.0073853,-0.0089111,-0.0101318,-0.0109253,-0.0115662,-0.0120544,-0.0120239,-0.0120544,-0.0117493,-0.0114136,-0.0112915,-0.0107727,-0.0109863,-0.0108643,-0.0114746,-0.0118713,-0.0129089,-0.0139771,-0.0153809,-0.0167236,-0.0183716,-0.0197144,-0.0211792,-0.0226135,-0.0235291,-0.0244446,-0.0248718,-0.0250854,-0.0252075,-0.0246887,-0.0244751,-0.0236206,-0.0228882,-0.0222473,-0.0213318,-0.0208130,-0.0203247,-0.0198975,-0.0198364,-0.0195923,-0.0199280,-0.0199890,-0.0207214,-0.0209045,-0.0216675,-0.0221863,-0.0227051,-0.0235291,-0.0238342,-0.0244141,-0.0247803,-0.0250549,-0.0250854,-0.0250854,-0.0249023,-0.0244446,-0.0241089,-0.0232239,-0.0223694,-0.0214844,-0.0201416,-0.0193481,-0.0178528,-0.0170288,-0.0158691,-0.0151978,-0.0146484,-0.0143738,-0.0144348,-0.0147705,-0.0153809,-0.0163574,-0.0173950,-0.0186768,-0.0195923,-0.0209351,-0.0216980,-0.0221863,-0.0226440,-0.0220337,-0.0216980,-0.0203552,-0.0187683,-0.0168457,-0.0144958,-0.0121460,-0.0095825,-0.0070801,-0.0046997,-0.0024414,-0.0008545,0.0009155,0.0015869,0.0026855,0.0027771,0.0030212,0.0028076,0.0026550,0.0022583,0.0021667,0.0021667,0.0024109,0.0027771,0.0037231,0.0046692,0.0061340,0.0077515,0.0095825,0.0115967,0.0135498,0.0155945,0.0173340,0.0190430,0.0203552,0.0214539,0.0222778,0.0223694,0.0225220,0.0218506,0.0211487,0.0200806,0.0186157,0.0172119,0.0153809,0.0138855,0.0121765,0.0108032,0.0094299,0.0086670,0.0075989,0.0077820,0.0074158,0.0083008,0.0089111,0.0103760,0.0116272,0.0136414,0.0150146,0.0171509,0.0184631,0.0201416,0.0212097,0.0220642,0.0224609,0.0224915,0.0220947,0.0214844,0.0205383,0.0194092,0.0181580,0.0171509,0.0160522,0.0155334,0.0148926,0.0150146,0.0152893,0.0160217,0.0170288,0.0183105,0.0197754,0.0211182,0.0225830,0.0237122,0.0245361,0.0251770,0.0251465,0.0250244,0.0242920,0.0233459,0.0219421,0.0205688,0.0189819,0.0176086,0.0161133,0.0150146,0.0141296,0.0133972,0.0136719,0.0131531,0.0141907,0.0141602,0.0153503,0.0159912,0.0166931,0.0177917,0.0180054,0.0187378,0.0185547,0.0187683,0.0183411,0.0180054,0.0172729,0.0165710,0.0158386,0.0148926,0.0142517,0.0133972,0.0128174,0.0122681,0.0115662,0.0111694,0.0105286,0.0101929,0.0095215,0.0090332,0.0084534,0.0077820,0.0072937,0.0065308,0.0060730,0.0052185,0.0048218,0.0040588,0.0037842,0.0030212,0.0028381,0.0021667,0.0017395,0.0010986,0.0006104,-0.0003662,-0.0009155,-0.0021057,-0.0029297,-0.0042114,-0.0052795,-0.0065002,-0.0076294,-0.0087585,-0.0097351,-0.0105286,-0.0112000,-0.0115967,-0.0119019,-0.0118713,-0.0117798,-0.0114441,-0.0110474,-0.0106201,-0.0100098,-0.0096436,-0.0090942,-0.0089417,-0.0087585,-0.0086365,-0.0089417,-0.0090332,-0.0095825,-0.0099487,-0.0104675,-0.0109558,-0.0114441,-0.0121155,-0.0123901,-0.0129395,-0.0133667,-0.0134888,-0.0140381,-0.0140991,-0.0145874,-0.0146179,-0.0150757,-0.0151672,-0.0155640,-0.0157776,-0.0159912,-0.0162964,-0.0163269,-0.0165405,-0.0165100,-0.0162964,-0.0161133,-0.0156860,-0.0151062,-0.0144348,-0.0135803,-0.0127869,-0.0118103,-0.0108032,-0.0099182,-0.0087585,-0.0079651,-0.0069275,-0.0061646,-0.0054626,-0.0045166,-0.0043030,-0.0033875,-0.0033264,-0.0026855,-0.0025635,-0.0021667,-0.0019531,-0.0017090,-0.0014343,-0.0012207,-0.0008240,-0.0007019,-0.0002136,-0.0000916,0.0003662,0.0004578,0.0008240,0.0008850,0.0011902,0.0013428,0.0012207,0.0013123,0.0011597,0.0010071,0.0008545,0.0004883,0.0004883,0.0000305,0.0003052,-0.0003662,0.0001526,-0.0000610,0.0004272,0.0004883,0.0011597,0.0012207,0.0019531,0.0022278,0.0026245,0.0029602,0.0030823,0.0030823,0.0030212,0.0026550,0.0022888,0.0017090,0.0010986,0.0004883,-0.0000610,-0.0007019,-0.0009155,-0.0013733,-0.0008850,-0.0009155,-0.0000610,0.0006409,0.0018005,0.0030823,0.0044556,0.0057678,0.0072327,0.0083618,0.0097656,0.0102539,0.0111694,0.0113525,0.0113525,0.0111694,0.0105896,0.0096741,0.0090637,0.0077515,0.0070801,0.0060120,0.0053101,0.0046692,0.0044250,0.0040588,0.0043945,0.0043335,0.0049133,0.0052795,0.0060120,0.0064697,0.0072021,0.0075684,0.0081482,0.0082397,0.0085449,0.0085144,0.0084839,0.0084229,0.0080566,0.0081177,0.0076904,0.0078430,0.0075684,0.0077820,0.0079346,0.0081787,0.0085754,0.0089722,0.0095825,0.0100708,0.0106812,0.0113831,0.0119019,0.0124207,0.0131531,0.0131531,0.0139771,0.0138550,0.0142822,0.0141907,0.0142822,0.0142517,0.0140991,0.0140076,0.0137634,0.0138245,0.0135803,0.0135193,0.0135498,0.0132751,0.0135498,0.0133362,0.0136108,0.0133667,0.0134277,0.0131836,0.0130615,0.0124817,0.0120850,0.0111389,0.0103760,0.0091858,0.0079041,0.0064087,0.0049744,0.0033264,0.0018311,0.0003052,-0.0009460,-0.0019531,-0.0029907,-0.0030518,-0.0036926,-0.0030823,-0.0028076,-0.0020142,-0.0009460,0.0000305,0.0014343,0.0022888,0.0036621,0.0041199,0.0048523,0.0048828,0.0047302,0.0042419,0.0032349,0.0021973,0.0007019,-0.0007019,-0.0021973,-0.0036926,-0.0050354,-0.0060730,-0.0069885,-0.0072632,-0.0076599,-0.0072937,-0.0069885,-0.0059814,-0.0051575,-0.0039368,-0.0027466,-0.0015869,-0.0004272,0.0006104,0.0013123,0.0020447,0.0022583,0.0026245,0.0024109,0.0022583,0.0017700,0.0012817,0.0006714,0.0000610,-0.0005493,-0.0009155,-0.0014038,-0.0014038,-0.0014648,-0.0010681,-0.0005798,0.0003357,0.0014648,0.0026855,0.0046082,0.0060425,0.0082092,0.0102234,0.0120239,0.0143738,0.0158997,0.0178833,0.0190735,0.0202942,0.0209351,0.0213318,0.0211792,0.0206909,0.0198975,0.0187683,0.0174561,0.0159912,0.0144043,0.0130310,0.0119019,0.0106812,0.0101624,0.0095825,0.0098267,0.0100403,0.0111084,0.0119019,0.0135193,0.0147705,0.0162354,0.0178833,0.0188599,0.0201721,0.0209045,0.0211182,0.0214233,0.0209045,0.0203247,0.0195618,0.0183105,0.0172424,0.0159607,0.0150452,0.0139465,0.0134277,0.0129395,0.0130920,0.0133362,0.0141296,0.0151672,0.0163879,0.0179138,0.0193176,0.0209351,0.0222473,0.0234375,0.0242310,0.0247498,0.0249023,0.0242920,0.0238953,0.0223999,0.0211487,0.0190430,0.0171814,0.0150146,0.0130310,0.0109863,0.0092163,0.0077209,0.0066528,0.0060730,0.0056763,0.0059814,0.0064392,0.0074463,0.0083313,0.0097046,0.0108337,0.0121765,0.0131531,0.0139160,0.0144958,0.0147095,0.0144043,0.0141296,0.0132141,0.0124817,0.0112610,0.0104675,0.0092468,0.0084534,0.0078735,0.0074463,0.0075073,0.0078125,0.0083923,0.0091858,0.0102844,0.0111389,0.0124817,0.0130615,0.0140381,0.0143127,0.0143433,0.0140686,0.0134277,0.0123596,0.0111084,0.0093689,0.0076904,0.0059509,0.0041504,0.0025635,0.0011902,0.0000000,-0.0007324,-0.0012207,-0.0014038,-0.0014038,-0.0009766,-0.0006714,0.0001221,0.0003967,0.0010986,0.0013123,0.0016785,0.0017090,0.0015259,0.0012207,0.0007324,0.0000916,-0.0007019,-0.0015869,-0.0025024,-0.0033875,-0.0043335,-0.0052795,-0.0062866,-0.0070190,-0.0083008,-0.0090027,-0.0103455,-0.0112915,-0.0125732,-0.0137939,-0.0149841,-0.0161743,-0.0174561,-0.0184631,-0.0194397,-0.0201721,-0.0205994,-0.0212097,-0.0209656,-0.0212708,-0.0207825,-0.0204773,-0.0200195,-0.0196228,-0.0191040,-0.0190430,-0.0186157,-0.0189209,-0.0191956,-0.0197449,-0.0204468,-0.0212097,-0.0222473,-0.0231323,-0.0241089,-0.0248108,-0.0253296,-0.0256348,-0.0255432,-0.0253296,-0.0244446,-0.0237732,-0.0223389,-0.0212097,-0.0192871,-0.0178528,-0.0162048,-0.0146790,-0.0133972,-0.0120850,-0.0112305,-0.0105591,-0.0102539,-0.0101013,-0.0103149,-0.0105591,-0.0110474,-0.0116272,-0.0119324,-0.0127869,-0.0125122,-0.0131226,-0.0127258,-0.0124817,-0.0118713,-0.0108948,-0.0098267,-0.0084229,-0.0068665,-0.0052185,-0.0034790,-0.0017700,-0.0000916,0.0014038,0.0028687,0.0038757,0.0049133,0.0054932,0.0058899,0.0061340,0.0060730,0.0060120,0.0055542,0.0052795,0.0048218,0.0046692,0.0043335,0.0044250,0.0043030,0.0048828,0.0052795,0.0061646,0.0070190,0.0081787,0.0095215,0.0107117,0.0122375,0.0133972,0.0146179,0.0158691,0.0164490,0.0174866,0.0177612,0.0181274,0.0181885,0.0180359,0.0178223,0.0173950,0.0168762,0.0165405,0.0158386,0.0157776,0.0152588,0.0152588,0.0150757,0.0151367,0.0154114,0.0157166,0.0160217,0.0165710,0.0167236,0.0173035,0.0175171,0.0178528,0.0178833,0.0183105,0.0178833,0.0182495,0.0175171,0.0176392,0.0170288,0.0167847,0.0163269,0.0158386,0.0155029,0.0150757,0.0147705,0.0142517,0.0140991,0.0136108,0.0135193,0.0130310,0.0129700,0.0125122,0.0123596,0.0119934,0.0117493,0.0115662,0.0111084,0.0108948,0.0104675,0.0102539,0.0100098,0.0097046,0.0096130,0.0094299,0.0095215,0.0095520,0.0097656,0.0098267,0.0102539,0.0104675,0.0108032,0.0111389,0.0113525,0.0116272,0.0117188,0.0118103,0.0116272,0.0114441,0.0111389,0.0106201,0.0101318,0.0095520,0.0088196,0.0081177,0.0076294,0.0069275,0.0067139,0.0060730,0.0059509,0.0055847,0.0055542,0.0054626,0.0053101,0.0053406,0.0050964,0.0048828,0.0045166,0.0042419,0.0034485,0.0030518,0.0022888,0.0013123,0.0007019,-0.0004883,-0.0011292,-0.0020752,-0.0025940,-0.0032959,-0.0035095,-0.0039673,-0.0040283,-0.0040894,-0.0040588,-0.0039673,-0.0038757,-0.0037842,-0.0036926,-0.0038452,-0.0037842,-0.0040588,-0.0042725,-0.0045471,-0.0049744,-0.0052490,-0.0055847,-0.0059814,-0.0062561,-0.0063171,-0.0067444,-0.0067139,-0.0072937,-0.0072327,-0.0076599,-0.0081482,-0.0086670,-0.0093994,-0.0102844,-0.0112915,-0.0126648,-0.0138855,-0.0151978,-0.0165405,-0.0177917,-0.0190735,-0.0200806,-0.0209961,-0.0215454,-0.0220032,-0.0222778,-0.0221252,-0.0216980,-0.0211487,-0.0205994,-0.0197449,-0.0188599,-0.0177612,-0.0169678,-0.0161438,-0.0154114,-0.0145569,-0.0140381,-0.0133667,-0.0131226,-0.0125732,-0.0122986,-0.0118713,-0.0117493,-0.0111389,-0.0109253,-0.0102234,-0.0100098,-0.0092468,-0.0089417,-0.0082092,-0.0075378,-0.0069885,-0.0062256,-0.0056763,-0.0049438,-0.0042114,-0.0036011,-0.0028992,-0.0024414,-0.0018921,-0.0016479,-0.0013123,-0.0011902,-0.0013123,-0.0015869,-0.0019531,-0.0025330,-0.0031128,-0.0041504,-0.0049438,-0.0055847,-0.0061035,-0.0067444,-0.0073242,-0.0073242,-0.0067749,-0.0059814,-0.0051880,-0.0040588,-0.0027161,-0.0007019,0.0009155,0.0028381,0.0040894,0.0055847,0.0066528,0.0072937,0.0076294,0.0072632,0.0066223,0.0058899,0.0044861,0.0033569,0.0016785,0.0005493,-0.0005188,-0.0011597,-0.0017700,-0.0017395,-0.0013733,-0.0005188,0.0007324,0.0020752,0.0035095,0.0052795,0.0067749,0.0079956,0.0093079,0.0096741,0.0099792,0.0097961,0.0089417,0.0080566,0.0063782,0.0050049,0.0029602,0.0012207,-0.0003662,-0.0021973,-0.0032654,-0.0043335,-0.0049133,-0.0050659,-0.0048218,-0.0043945,-0.0034180,-0.0023804,-0.0011292,0.0002441,0.0014343,0.0027161,0.0036926,0.0043640,0.0049133,0.0050049,0.0047302,0.0044250,0.0033875,0.0025024,0.0011597,-0.0002136,-0.0016174,-0.0032043,-0.0043640,-0.0057373,-0.0065002,-0.0073547,-0.0075989,-0.0077515,-0.0072937,-0.0066223,-0.0054932,-0.0043640,-0.0029297,-0.0014038,-0.0000916,0.0013123,0.0022278,0.0031738,0.0031738,0.0033875,0.0025940,0.0016174,0.0002441,-0.0016479,-0.0034790,-0.0055847,-0.0076294,-0.0096741,-0.0111389,-0.0125427,-0.0132751,-0.0134277,-0.0134277,-0.0126343,-0.0113831,-0.0104065,-0.0083008,-0.0070190,-0.0051575,-0.0038757,-0.0028381,-0.0019836,-0.0018311,-0.0019226,-0.0024719,-0.0036316,-0.0047913,-0.0063782,-0.0079041,-0.0094299,-0.0107727,-0.0117798,-0.0126343,-0.0127258,-0.0130310,-0.0122070,-0.0117493,-0.0104675,-0.0093994,-0.0081177,-0.0068970,-0.0056763,-0.0048523,-0.0040894,-0.0037842,-0.0036316,-0.0040894,-0.0043335,-0.0052490,-0.0063171,-0.0072327,-0.0087280,-0.0097656,-0.0109863,-0.0119629,-0.0127563,-0.0135498,-0.0137939,-0.0140381,-0.0140686,-0.0136108,-0.0133667,-0.0123291,-0.0116577,-0.0103149,-0.0091858,-0.0077515,-0.0062256,-0.0049438,-0.0031738,-0.0019226,-0.0004272,0.0007019,0.0017395,0.0024719,0.0031738,0.0033569,0.0035706,0.0034180,0.0032043,0.0027771,0.0022888,0.0017090,0.0013428,0.0010376,0.0007629,0.0008545,0.0007935,0.0013733,0.0018921,0.0027161,0.0034790,0.0044861,0.0053101,0.0061340,0.0068970,0.0074158,0.0078125,0.0078735,0.0078735,0.0074768,0.0071411,0.0063477,0.0058594,0.0050354,0.0045776,0.0038757,0.0036621,0.0034790,0.0036926,0.0039673,0.0044861,0.0052795,0.0060120,0.0071716,0.0081787,0.0090637,0.0103455,0.0110168,0.0121460,0.0126343,0.0132751,0.0136108,0.0140381,0.0139160,0.0138855,0.0137329,0.0130920,0.0127869,0.0119629,0.0114136,0.0105896,0.0097046,0.0088196,0.0079041,0.0070801,0.0062561,0.0053711,0.0047302,0.0039978,0.0035400,0.0032043,0.0026855,0.0026855,0.0022888,0.0023499,0.0020142,0.0018616,0.0014343,0.0011292,0.0004272,-0.0000610,-0.0011597,-0.0017090,-0.0030212,-0.0036926,-0.0047302,-0.0056152,-0.0061646,-0.0066833,-0.0069580,-0.0066833,-0.0064087,-0.0056458,-0.0044556,-0.0034485,-0.0016785,-0.0003357,0.0013428,0.0027466,0.0041199,0.0051575,0.0060425,0.0064087,0.0065918,0.0062866,0.0057068,0.0047607,0.0035706,0.0021973,0.0006409,-0.0008240,-0.0025940,-0.0040588,-0.0056152,-0.0068054,-0.0081482,-0.0087891,-0.0100708,-0.0101929,-0.0111084,-0.0111694,-0.0116882,-0.0118103,-0.0123291,-0.0121460,-0.0129395,-0.0129395,-0.0135803,-0.0138550,-0.0145569,-0.0149231,-0.0156860,-0.0163269,-0.0170593,-0.0176697,-0.0180664,-0.0188599,-0.0190430,-0.0195007,-0.0197449,-0.0196838,-0.0197449,-0.0193787,-0.0191956,-0.0186462,-0.0180969,-0.0173950,-0.0166626,-0.0158691,-0.0152588,-0.0144958,-0.0140686,-0.0136414,-0.0135498,-0.0135498,-0.0137024,-0.0144348,-0.0150452,-0.0161438,-0.0171814,-0.0184937,-0.0197144,-0.0210876,-0.0223999,-0.0234680,-0.0247192,-0.0255432,-0.0263367,-0.0270386,-0.0273132,-0.0278015,-0.0278015,-0.0278625,-0.0278320,-0.0277710,-0.0276794,-0.0276489,-0.0276489,-0.0275879,-0.0277405,-0.0275879,-0.0277100,-0.0275879,-0.0273743,-0.0272827,-0.0266724,-0.0263672,-0.0254517,-0.0247192,-0.0236816,-0.0226135,-0.0212097,-0.0202637,-0.0187073,-0.0177612,-0.0165405,-0.0158081,-0.0149536,-0.0146179,-0.0144043,-0.0143127,-0.0147400,-0.0149841,-0.0157776,-0.0162964,-0.0171204,-0.0177917,-0.0184326,-0.0188293,-0.0192261,-0.0193176,-0.0191040,-0.0188599,-0.0180664,-0.0176086,-0.0166016,-0.0155945,-0.0146179,-0.0135193,-0.0128174,-0.0119019,-0.0113831,-0.0110474,-0.0108337,-0.0108948,-0.0111084,-0.0115967,-0.0121155,-0.0125732,-0.0131226,-0.0133667,-0.0135498,-0.0131836,-0.0129700,-0.0119324,-0.0109558,-0.0091553,-0.0074463,-0.0052490,-0.0029297,-0.0006104,0.0018921,0.0041504,0.0061340,0.0080261,0.0093079,0.0103760,0.0108948,0.0110168,0.0107727,0.0102234,0.0094910,0.0084839,0.0073853,0.0066223,0.0057068,0.0053101,0.0048828,0.0051575,0.0054016,0.0063171,0.0073547,0.0085144,0.0100098,0.0114441,0.0128174,0.0142822,0.0151672,0.0160522,0.0164795,0.0166321,0.0162964,0.0159302,0.0148010,0.0138550,0.0125122,0.0111084,0.0098572,0.0083923,0.0073853,0.0064087,0.0058289,0.0055847,0.0052795,0.0057678,0.0060730,0.0071716,0.0078735,0.0090332,0.0100708,0.0112610,0.0125122,0.0132446,0.0141907,0.0146484,0.0151978,0.0154419,0.0153198,0.0150452,0.0148621,0.0143738,0.0139160,0.0133362,0.0127869,0.0124817,0.0120239,0.0120850,0.0117188,0.0117798,0.0118408,0.0120544,0.0123901,0.0127258,0.0127869,0.0131226,0.0132446,0.0133667,0.0131226,0.0128784,0.0125122,0.0119934,0.0113831,0.0105591,0.0098572,0.0091248,0.0084534,0.0076904,0.0072021,0.0065308,0.0061340,0.0057068,0.0055237,0.0051575,0.0050354,0.0046692,0.0045166,0.0042114,0.0038452,0.0035706,0.0028992,0.0025024,0.0017090,0.0011902,0.0003052,-0.0002136,-0.0009155,-0.0015564,-0.0018616,-0.0026245,-0.0027466,-0.0033875,-0.0034485,-0.0039978,-0.0041809,-0.0048218,-0.0052185,-0.0061035,-0.0068665,-0.0078125,-0.0090332,-0.0101929,-0.0116577,-0.0129395,-0.0145264,-0.0157776,-0.0174561,-0.0185547,-0.0198364,-0.0209351,-0.0217590,-0.0227661,-0.0230713,-0.0239868,-0.0242310,-0.0247498,-0.0250244,-0.0252686,-0.0254517,-0.0258789,-0.0257874,-0.0262756,-0.0261841,-0.0263977,-0.0262146,-0.0261841,-0.0258789,-0.0256348,-0.0251465,-0.0247498,-0.0241699,-0.0235291,-0.0229797,-0.0222168,-0.0218811,-0.0211182,-0.0207520,-0.0203857,-0.0199890,-0.0197144,-0.0195312,-0.0192261,-0.0191650,-0.0187988,-0.0184937,-0.0181274,-0.0177002,-0.0171814,-0.0167236,-0.0161743,-0.0156250,-0.0151978,-0.0148010,-0.0144348,-0.0144043,-0.0140381,-0.0144043,-0.0141907,-0.0147095,-0.0148315,-0.0152893,-0.0157166,-0.0159302,-0.0163269,-0.0163574,-0.0164795,-0.0163269,-0.0160828,-0.0156860,-0.0148315,-0.0143433,-0.0132141,-0.0124817,-0.0113831,-0.0102844,-0.0092468,-0.0081787,-0.0070190,-0.0060120,-0.0050049,-0.0039673,-0.0030212,-0.0020447,-0.0011902,-0.0002136,0.0006104,0.0014648,0.0023193,0.0030518,0.0039673,0.0045166,0.0054016,0.0058594,0.0065918,0.0070190,0.0075684,0.0077209,0.0082092,0.0082703,0.0085754,0.0084839,0.0085449,0.0083923,0.0081177,0.0079041,0.0072937,0.0068665,0.0062256,0.0054626,0.0048218,0.0040588,0.0036011,0.0030823,0.0029907,0.0027771,0.0032349,0.0036926,0.0045776,0.0058594,0.0070190,0.0090332,0.0105896,0.0125427,0.0144958,0.0160828,0.0176697,0.0187073,0.0196533,0.0199280,0.0199280,0.0195618,0.0186157,0.0178833,0.0161438,0.0154114,0.0136719,0.0130615,0.0121765,0.0116272,0.0117798,0.0119934,0.0129089,0.0141296,0.0154114,0.0172424,0.0188599,0.0205383,0.0220032,0.0233765,0.0238953,0.0245972,0.0242310,0.0238647,0.0227661,0.0214844,0.0197144,0.0180969,0.0162354,0.0144043,0.0130310,0.0115967,0.0109558,0.0103455,0.0104980,0.0108337,0.0118103,0.0131836,0.0145874,0.0163574,0.0180359,0.0198059,0.0212097,0.0226746,0.0233765,0.0240173,0.0241394,0.0238342,0.0231323,0.0220337,0.0204468,0.0186157,0.0166321,0.0144043,0.0121155,0.0097961,0.0076904,0.0055542,0.0039673,0.0023804,0.0013123,0.0006714,0.0002136,0.0002747,0.0005493,0.0012207,0.0018005,0.0028381,0.0036316,0.0044250,0.0051575,0.0054626,0.0056152,0.0052795,0.0046692,0.0037231,0.0022888,0.0007629,-0.0010986,-0.0027161,-0.0046997,-0.0061035,-0.0077209,-0.0087585,-0.0093079,-0.0097351,-0.0095215,-0.0089417,-0.0080872,-0.0067444,-0.0056152,-0.0041504,-0.0027466,-0.0017395,-0.0007324,-0.0004272,-0.0003662,-0.0006714,-0.0017090,-0.0028076,-0.0046082,-0.0062256,-0.0083923,-0.0101929,-0.0122681,-0.0139465,-0.0152283,-0.0166626,-0.0170898,-0.0175171,-0.0175171,-0.0171204,-0.0165710,-0.0154724,-0.0147095,-0.0134888,-0.0127869,-0.0117798,-0.0112000,-0.0107117,-0.0104370,-0.0105591,-0.0107117,-0.0111084,-0.0115356,-0.0122070,-0.0124817,-0.0132446,-0.0134583,-0.0138855,-0.0137939,-0.0138550,-0.0133362,-0.0129395,-0.0122070,-0.0112915,-0.0104065,-0.0091858,-0.0082397,-0.0069275,-0.0058594,-0.0048523,-0.0037537,-0.0030518,-0.0022583,-0.0016479,-0.0012512,-0.0010071,-0.0009155,-0.0011292,-0.0010071,-0.0016174,-0.0016174,-0.0021362,-0.0021057,-0.0025024,-0.0022888,-0.0020447,-0.0018311,-0.0008850,-0.0004578,0.0008240,0.0017090,0.0027466,0.0039062,0.0048828,0.0056763,0.0063782,0.0065308,0.0066528,0.0061951,0.0056763,0.0045471,0.0035095,0.0021667,0.0006409,-0.0007629,-0.0020447,-0.0032654,-0.0039062,-0.0045166,-0.0045166,-0.0043335,-0.0037231,-0.0028076,-0.0015869,-0.0003662,0.0009460,0.0021973,0.0033875,0.0041809,0.0045471,0.0047607,0.0043945,0.0037537,0.0026855,0.0014648,-0.0001526,-0.0013733,-0.0032043,-0.0041809,-0.0052185,-0.0058899,-0.0057678,-0.0058289,-0.0045471,-0.0036926,-0.0018616,-0.0000305,0.0021057,0.0042725,0.0066223,0.0084229,0.0104980,0.0117493,0.0130310,0.0138245,0.0141602,0.0142822,0.0137329,0.0134583,0.0124512,0.0119019,0.0108948,0.0103760,0.0096130,0.0093384,0.0090332,0.0091248,0.0094604,0.0098572,0.0103760,0.0111389,0.0119324,0.0126038,0.0133362,0.0137329,0.0143127,0.0143433,0.0143433,0.0139771,0.0136108,0.0127869,0.0122070,0.0110474,0.0101929,0.0091248,0.0082092,0.0074158,0.0065918,0.0060120,0.0055847,0.0052490,0.0052185,0.0053101,0.0055847,0.0060425,0.0065002,0.0071106,0.0076294,0.0084229,0.0089111,0.0094604,0.0097656,0.0100708,0.0101624,0.0101624,0.0099792,0.0096741,0.0092163,0.0087280,0.0081482,0.0075073,0.0067749,0.0061646,0.0055847,0.0050049,0.0046692,0.0043335,0.0041809,0.0040894,0.0039978,0.0040894,0.0041199,0.0041504,0.0041504,0.0039978,0.0037537,0.0033264,0.0028992,0.0021667,0.0014038,0.0006714,-0.0003662,-0.0010376,-0.0020142,-0.0026245,-0.0032959,-0.0036926,-0.0039673,-0.0039062,-0.0036316,-0.0032654,-0.0026550,-0.0021057,-0.0010986,-0.0005188,0.0005188,0.0009155,0.0015259,0.0016785,0.0019836,0.0017700,0.0015869,0.0009155,0.0003967,-0.0004883,-0.0011902,-0.0022278,-0.0027161,-0.0036926,-0.0038147,-0.0045471,-0.0044556,-0.0045166,-0.0042725,-0.0036011,-0.0032959,-0.0023193,-0.0016479,-0.0005798,0.0002747,0.0014038,0.0021667,0.0033569,0.0040588,0.0050049,0.0058899,0.0064697,0.0074768,0.0078430,0.0086975,0.0090027,0.0097656,0.0102539,0.0106506,0.0112915,0.0114746,0.0120544,0.0121460,0.0125427,0.0125732,0.0127258,0.0128174,0.0128479,0.0129395,0.0128784,0.0132141,0.0130310,0.0136719,0.0135803,0.0142822,0.0145264,0.0150757,0.0156555,0.0160522,0.0167236,0.0171814,0.0176086,0.0181274,0.0182800,0.0184937,0.0186157,0.0183105,0.0182800,0.0178223,0.0174866,0.0169983,0.0166016,0.0158081,0.0155029,0.0145569,0.0144348,0.0136414,0.0133972,0.0130310,0.0130005,0.0127258,0.0128479,0.0130005,0.0132751,0.0135498,0.0140381,0.0144043,0.0149231,0.0154114,0.0156555,0.0162659,0.0162048,0.0164490,0.0162964,0.0160828,0.0158081,0.0151978,0.0145874,0.0137329,0.0130310,0.0120239,0.0113831,0.0104370,0.0099792,0.0093689,0.0093079,0.0092163,0.0095520,0.0101624,0.0107117,0.0120850,0.0128479,0.0144958,0.0156250,0.0169678,0.0183411,0.0193176,0.0204163,0.0207214,0.0212402,0.0210266,0.0206604,0.0201721,0.0190430,0.0180054,0.0165100,0.0153809,0.0139771,0.0126648,0.0117493,0.0104980,0.0103149,0.0096130,0.0098572,0.0098572,0.0104980,0.0110168,0.0119629,0.0129395,0.0135803,0.0145874,0.0148621,0.0155640,0.0153503,0.0153198,0.0145264,0.0138550,0.0123596,0.0112610,0.0093689,0.0078735,0.0058594,0.0044250,0.0026245,0.0016174,0.0003052,-0.0000610,-0.0005188,-0.0003052,0.0000916,0.0009460,0.0018616,0.0030518,0.0043030,0.0054626,0.0065002,0.0072632,0.0076904,0.0077209,0.0072327,0.0064392,0.0050354,0.0034790,0.0012817,-0.0007935,-0.0034485,-0.0056152,-0.0081787,-0.0100708,-0.0120850,-0.0133972,-0.0145264,-0.0150146,-0.0152588,-0.0150146,-0.0146484,-0.0138245,-0.0132446,-0.0122681,-0.0117493,-0.0111694,-0.0110474,-0.0111084,-0.0115967,-0.0124817,-0.0133972,-0.0149231,-0.0162354,-0.0177917,-0.0194397,-0.0205383,-0.0219727,-0.0225525,-0.0231628,-0.0230408,-0.0230103,-0.0221558,-0.0213928,-0.0202637,-0.0188904,-0.0176086,-0.0163574,-0.0150146,-0.0140991,-0.0130920,-0.0126953,-0.0121155,-0.0121460,-0.0120239,-0.0122681,-0.0126648,-0.0129089,-0.0134277,-0.0135193,-0.0139465,-0.0137329,-0.0137939,-0.0133362,-0.0130920,-0.0123596,-0.0119019,-0.0108643,-0.0102234,-0.0092468,-0.0085449,-0.0076294,-0.0070190,-0.0062866,-0.0055847,-0.0051270,-0.0044556,-0.0040588,-0.0036316,-0.0029907,-0.0026855,-0.0020447,-0.0016174,-0.0010986,-0.0005493,-0.0000916,0.0005798,0.0010071,0.0016174,0.0020447,0.0026550,0.0032043,0.0036621,0.0043335,0.0048828,0.0055542,0.0060425,0.0069580,0.0073547,0.0083313,0.0086365,0.0095825,0.0097961,0.0106506,0.0107422,0.0113220,0.0114136,0.0117493,0.0117798,0.0119629,0.0119629,0.0120544,0.0121765,0.0122375,0.0124512,0.0127563,0.0131531,0.0136719,0.0141602,0.0147705,0.0153809,0.0158691,0.0166321,0.0169067,0.0174866,0.0174255,0.0176086,0.0173035,0.0169067,0.0164185,0.0153809,0.0148315,0.0134277,0.0126953,0.0114441,0.0104370,0.0096130,0.0087585,0.0084229,0.0080872,0.0082092,0.0084534,0.0091553,0.0098572,0.0108948,0.0121460,0.0133057,0.0146179,0.0157471,0.0168152,0.0177917,0.0182800,0.0187378,0.0185852,0.0184937,0.0177612,0.0172119,0.0160217,0.0148315,0.0137024,0.0122375,0.0115662,0.0101013,0.0099487,0.0090637,0.0093384,0.0093689,0.0100098,0.0106812,0.0119019,0.0127869,0.0142212,0.0152893,0.0163269,0.0172119,0.0176086,0.0178223,0.0175781,0.0169983,0.0159912,0.0147400,0.0131836,0.0114441,0.0097656,0.0079956,0.0065308,0.0053101,0.0042419,0.0038452,0.0035095,0.0039673,0.0044556,0.0054626,0.0067139,0.0078735,0.0094299,0.0104675,0.0115662,0.0122681,0.0124207,0.0123596,0.0115356,0.0103455,0.0087280,0.0066528,0.0043030,0.0020447,-0.0006409,-0.0030212,-0.0054016,-0.0071716,-0.0085754,-0.0099182,-0.0100403,-0.0105286,-0.0097046,-0.0090332,-0.0077515,-0.0064087,-0.0047607,-0.0033875,-0.0018616,-0.0010071,-0.0000610,0.0000916,0.0000610,-0.0007019,-0.0015869,-0.0029907,-0.0047913,-0.0066223,-0.0089111,-0.0107727,-0.0127869,-0.0145264,-0.0158691,-0.0170288,-0.0173645,-0.0176086,-0.0170288,-0.0164795,-0.0151978,-0.0138855,-0.0122986,-0.0107117,-0.0092773,-0.0077820,-0.0067139,-0.0058289,-0.0054321,-0.0050964,-0.0054626,-0.0058899,-0.0067139,-0.0076904,-0.0088501,-0.0098877,-0.0110779,-0.0119019,-0.0126648,-0.0131226,-0.0132446,-0.0129700,-0.0126648,-0.0117188,-0.0108948,-0.0094910,-0.0086060,-0.0070190,-0.0061035,-0.0050659,-0.0039978,-0.0036926,-0.0032043,-0.0031738,-0.0034485,-0.0037842,-0.0045166,-0.0052795,-0.0060120,-0.0068665,-0.0076294,-0.0081482,-0.0087585,-0.0088196,-0.0088501,-0.0086975,-0.0079651,-0.0075684,-0.0062866,-0.0055542,-0.0040894,-0.0029907,-0.0014648,-0.0003662,0.0010071,0.0021973,0.0031433,0.0040283,0.0047302,0.0051575,0.0056152,0.0054321,0.0055847,0.0051880,0.0050049,0.0045166,0.0039673,0.0034180,0.0028381,0.0024719,0.0019531,0.0018311,0.0016479,0.0017395,0.0019531,0.0021667,0.0027466,0.0032043,0.0038757,0.0044861,0.0049133,0.0054321,0.0056152,0.0058899,0.0055847,0.0055542,0.0048523,0.0043640,0.0032349,0.0025940,0.0014954,0.0006104,-0.0002747,-0.0010681,-0.0014954,-0.0017700,-0.0018005,-0.0013733,-0.0008850,0.0001221,0.0009155,0.0023499,0.0034180,0.0047913,0.0058899,0.0069580,0.0075073,0.0081482,0.0082092,0.0081482,0.0074768,0.0067749,0.0055542,0.0044250,0.0028381,0.0017090,0.0000610,-0.0009460,-0.0022888,-0.0027771,-0.0036316,-0.0037842,-0.0039368,-0.0036621,-0.0031433,-0.0027771,-0.0017700,-0.0012817,-0.0001221,0.0003662,0.0012512,0.0016479,0.0020447,0.0023193,0.0022278,0.0021667,0.0016174,0.0013123,0.0006714,-0.0001831,-0.0007324,-0.0017090,-0.0025330,-0.0031128,-0.0040283,-0.0044556,-0.0050659,-0.0054626,-0.0056152,-0.0059814,-0.0057983,-0.0059814,-0.0055847,-0.0055847,-0.0051880,-0.0049744,-0.0046997,-0.0043945,-0.0040588,-0.0041809,-0.0037231,-0.0040894,-0.0038757,-0.0043335,-0.0044861,-0.0049438,-0.0053711,-0.0058899,-0.0064087,-0.0071411,-0.0077209,-0.0083008,-0.0089722,-0.0093689,-0.0097656,-0.0101013,-0.0103149,-0.0102539,-0.0105591,-0.0101624,-0.0103455,-0.0098877,-0.0097961,-0.0093994,-0.0091248,-0.0089722,-0.0086975,-0.0086670,-0.0083923,-0.0085144,-0.0084839,-0.0087585,-0.0088806,-0.0093384,-0.0093994,-0.0101013,-0.0101624,-0.0108337,-0.0107117,-0.0112000,-0.0110474,-0.0112305,-0.0107727,-0.0105286,-0.0101318,-0.0092773,-0.0087891,-0.0075989,-0.0067139,-0.0056152,-0.0045471,-0.0034790,-0.0025024,-0.0015564,-0.0007935,-0.0002747,0.0002747,0.0003967,0.0005188,0.0003967,0.0000610,-0.0003052,-0.0007935,-0.0015259,-0.0018921,-0.0026245,-0.0028076,-0.0033569,-0.0033569,-0.0034790,-0.0028992,-0.0025940,-0.0017395,-0.0009460,0.0001526,0.0013733,0.0026550,0.0040894,0.0051880,0.0067444,0.0075989,0.0088196,0.0092773,0.0099487,0.0101013,0.0100708,0.0097656,0.0093689,0.0086365,0.0079346,0.0070801,0.0062866,0.0056763,0.0049438,0.0047913,0.0044556,0.0045166,0.0049744,0.0053406,0.0065613,0.0071716,0.0087585,0.0094910,0.0110474,0.0119934,0.0130310,0.0136719,0.0141907,0.0142822,0.0141296,0.0135803,0.0128174,0.0117493,0.0106812,0.0091553,0.0081482,0.0065308,0.0057678,0.0045471,0.0041809,0.0035400,0.0037231,0.0036926,0.0041809,0.0048523,0.0052490,0.0063782,0.0068054,0.0074158,0.0079956,0.0078735,0.0081787,0.0076599,0.0074158,0.0066223,0.0058899,0.0049133,0.0039978,0.0030518,0.0023193,0.0013733,0.0011292,0.0005798,0.0005798,0.0006409,0.0008240,0.0013123,0.0016174,0.0021973,0.0024109,0.0029907,0.0030518,0.0034790,0.0032654,0.0033875,0.0030212,0.0027466,0.0023804,0.0021057,0.0015259,0.0012512,0.0005798,0.0005798,-0.0001831,-0.0002136,-0.0010986,-0.0011597,-0.0020142,-0.0023499,-0.0034790,-0.0039368,-0.0052490,-0.0060425,-0.0071411,-0.0082703,-0.0092163,-0.0102844,-0.0110474,-0.0117188,-0.0120239,-0.0125122,-0.0123901,-0.0121765,-0.0117798,-0.0112915,-0.0106201,-0.0098572,-0.0091553,-0.0084839,-0.0079346,-0.0075073,-0.0072632,-0.0070190,-0.0072937,-0.0074768,-0.0079346,-0.0084839,-0.0090027,-0.0097046,-0.0104675,-0.0110779,-0.0116577,-0.0122681,-0.0125732,-0.0132446,-0.0135498,-0.0140381,-0.0144043,-0.0148926,-0.0155945,-0.0160217,-0.0170288,-0.0177002,-0.0187378,-0.0198059,-0.0207520,-0.0219116,-0.0228271,-0.0238342,-0.0247498,-0.0256958,-0.0262451,-0.0266113,-0.0270996,-0.0271606,-0.0274963,-0.0270996,-0.0270386,-0.0267334,-0.0266113,-0.0262146,-0.0256348,-0.0253906,-0.0255737,-0.0252075,-0.0249329,-0.0244751,-0.0246277,-0.0247803,-0.0247192,-0.0247498,-0.0245361,-0.0249023,-0.0249329,-0.0252991,-0.0250549,-0.0251160,-0.0252075,-0.0247192,-0.0250244,-0.0241089,-0.0240784,-0.0233154,-0.0229187,-0.0223083,-0.0215454,-0.0206909,-0.0202942,-0.0194702,-0.0192566,-0.0186462,-0.0183716,-0.0184021,-0.0181580,-0.0184021,-0.0184021,-0.0184631,-0.0186768,-0.0184631,-0.0184021,-0.0180054,-0.0173340,-0.0165100,-0.0154724,-0.0142822,-0.0128479,-0.0113220,-0.0097351,-0.0081177,-0.0068054,-0.0053406,-0.0043945,-0.0034180,-0.0032043,-0.0029297,-0.0032043,-0.0037537,-0.0042419,-0.0053711,-0.0059204,-0.0069885,-0.0077209,-0.0080566,-0.0087280,-0.0083618,-0.0082397,-0.0075378,-0.0064697,-0.0054932,-0.0038757,-0.0026245,-0.0009155,0.0002747,0.0018005,0.0026855,0.0034485,0.0039673,0.0041504,0.0039978,0.0036316,0.0030212,0.0024109,0.0016785,0.0010681,0.0004883,0.0001831,0.0000916,0.0001221,0.0007019,0.0013733,0.0022888,0.0036316,0.0047607,0.0061951,0.0077820,0.0089111,0.0103149,0.0112305,0.0119324,0.0124207,0.0126343,0.0123596,0.0119934,0.0113525,0.0105896,0.0096741,0.0086975,0.0079651,0.0071716,0.0066223,0.0065002,0.0062561,0.0068970,0.0072632,0.0085754,0.0096130,0.0112915,0.0126343,0.0146484,0.0160217,0.0177307,0.0188599,0.0199280,0.0204468,0.0206604,0.0204163,0.0196838,0.0187378,0.0170288,0.0155029,0.0134583,0.0115662,0.0097351,0.0080261,0.0067749,0.0056458,0.0052795,0.0053406,0.0057373,0.0068665,0.0079346,0.0099182,0.0114746,0.0135498,0.0150757,0.0166016,0.0177612,0.0182800,0.0185852,0.0179138,0.0171204,0.0154114,0.0136108,0.0112610,0.0088196,0.0062866,0.0036621,0.0014954,-0.0006409,-0.0021362,-0.0033875,-0.0039673,-0.0042114,-0.0039673,-0.0033264,-0.0023499,-0.0013123,0.0000305,0.0010071,0.0022888,0.0028687,0.0034180,0.0034485,0.0031738,0.0024414,0.0013428,0.0000610,-0.0017700,-0.0032959,-0.0054626,-0.0068665,-0.0088806,-0.0101624,-0.0116577,-0.0124207,-0.0133057,-0.0135193,-0.0138550,-0.0135803,-0.0133057,-0.0130005,-0.0122681,-0.0120239,-0.0114441,-0.0113525,-0.0112000,-0.0114441,-0.0119934,-0.0127563,-0.0138245,-0.0150452,-0.0164795,-0.0181580,-0.0198975,-0.0213928,-0.0232544,-0.0244751,-0.0259094,-0.0268860,-0.0277100,-0.0279236,-0.0281982,-0.0278320,-0.0273132,-0.0266724,-0.0254822,-0.0247803,-0.0232849,-0.0223999,-0.0213318,-0.0204163,-0.0197449,-0.0192871,-0.0189209,-0.0191040,-0.0190125,-0.0197449,-0.0197754,-0.0208740,-0.0210571,-0.0220947,-0.0222473,-0.0228577,-0.0228271,-0.0229797,-0.0227356,-0.0222168,-0.0218201,-0.0209351,-0.0202332,-0.0192566,-0.0182495,-0.0175476,-0.0165100,-0.0156860,-0.0152283,-0.0143433,-0.0143433,-0.0136719,-0.0137329,-0.0135803,-0.0135803,-0.0136719,-0.0135498,-0.0137024,-0.0135498,-0.0133362,-0.0131226,-0.0125732,-0.0121155,-0.0114136,-0.0105896,-0.0097046,-0.0084229,-0.0075684,-0.0062256,-0.0050354,-0.0038452,-0.0024414,-0.0014648,-0.0003967,0.0005493,0.0010376,0.0019531,0.0018616,0.0022278,0.0019836,0.0017395,0.0013123,0.0007019,0.0000916,-0.0005798,-0.0010681,-0.0017700,-0.0019531,-0.0023193,-0.0020142,-0.0019531,-0.0013123,-0.0008240,0.0001831,0.0012512,0.0024414,0.0035706,0.0046082,0.0057068,0.0065002,0.0070496,0.0073242,0.0074158,0.0070801,0.0067749,0.0057678,0.0052185,0.0040588,0.0032959,0.0023804,0.0016479,0.0013123,0.0011597,0.0012512,0.0019531,0.0025635,0.0038452,0.0052490,0.0067749,0.0086670,0.0103149,0.0120850,0.0136108,0.0150757,0.0161438,0.0169373,0.0173340,0.0177002,0.0173340,0.0172119,0.0162964,0.0158386,0.0148926,0.0143433,0.0134888,0.0133057,0.0127563,0.0130920,0.0131836,0.0140991,0.0145874,0.0160522,0.0169067,0.0186157,0.0196838,0.0212402,0.0221252,0.0234070,0.0239868,0.0245667,0.0246887,0.0245667,0.0240784,0.0233459,0.0224915,0.0213928,0.0201111,0.0190430,0.0177307,0.0166321,0.0157166,0.0147705,0.0143433,0.0136108,0.0137329,0.0132446,0.0137634,0.0136719,0.0140076,0.0143738,0.0144043,0.0147400,0.0145264,0.0143738,0.0138855,0.0133972,0.0123291,0.0114441,0.0100403,0.0086670,0.0071716,0.0056458,0.0039673,0.0025024,0.0010071,-0.0001831,-0.0012817,-0.0022278,-0.0029602,-0.0036621,-0.0039062,-0.0041809,-0.0041504,-0.0042114,-0.0040283,-0.0040283,-0.0038452,-0.0038757,-0.0038452,-0.0040894,-0.0042114,-0.0045471,-0.0049133,-0.0054626,-0.0059204,-0.0064392,-0.0069580,-0.0072937,-0.0079651,-0.0080261,-0.0084534,-0.0082397,-0.0085144,-0.0082092,-0.0081787,-0.0076599,-0.0076599,-0.0071716,-0.0071411,-0.0067749,-0.0070190,-0.0067444,-0.0073242,-0.0072632,-0.0081482,-0.0083008,-0.0092163,-0.0096436,-0.0103455,-0.0109558,-0.0116272,-0.0117188,-0.0121460,-0.0120850,-0.0119629,-0.0116577,-0.0111694,-0.0103455,-0.0097961,-0.0086975,-0.0079956,-0.0071411,-0.0063171,-0.0057068,-0.0053711,-0.0049133,-0.0051270,-0.0051880,-0.0056763,-0.0061951,-0.0070190,-0.0078125,-0.0087585,-0.0096436,-0.0104065,-0.0112915,-0.0115967,-0.0119324,-0.0121155,-0.0119019,-0.0114136,-0.0108032,-0.0098267,-0.0091553,-0.0076599,-0.0067749,-0.0052490,-0.0044556,-0.0033875,-0.0025635,-0.0018921,-0.0011902,-0.0010986,-0.0009766,-0.0009460,-0.0012207,-0.0014038,-0.0018311,-0.0021667,-0.0025330,-0.0027466,-0.0028381,-0.0028992,-0.0028076,-0.0024719,-0.0020447,-0.0014038,-0.0007019,-0.0000305,0.0008240,0.0015869,0.0022583,0.0028687,0.0031128,0.0034180,0.0034180,0.0032043,0.0029297,0.0022278,0.0015259,0.0006714,-0.0002136,-0.0013123,-0.0018616,-0.0031738,-0.0033875,-0.0043335,-0.0045776,-0.0047607,-0.0048218,-0.0044250,-0.0045776,-0.0036621,-0.0037231,-0.0026855,-0.0025024,-0.0017700,-0.0013123,-0.0011292,-0.0004883,-0.0005798,-0.0002136,-0.0003052,-0.0000610,-0.0000916,-0.0000610,0.0001831,0.0002136,0.0006409,0.0008850,0.0016174,0.0017700,0.0028687,0.0032043,0.0043030,0.0047607,0.0056763,0.0063477,0.0070190,0.0074158,0.0080566,0.0081177,0.0086060,0.0088196,0.0088806,0.0092773,0.0092163,0.0096130,0.0097961,0.0101624,0.0106201,0.0110168,0.0114746,0.0119324,0.0123291,0.0126038,0.0128784,0.0127869,0.0127869,0.0124207,0.0117493,0.0113525,0.0101624,0.0096130,0.0082397,0.0076904,0.0065308,0.0059814,0.0054321,0.0050049,0.0050964,0.0050964,0.0056152,0.0061340,0.0067749,0.0073853,0.0081482,0.0085754,0.0090942,0.0091858,0.0088806,0.0086670,0.0075989,0.0066528,0.0050659,0.0037842,0.0017700,0.0003662,-0.0016174,-0.0029297,-0.0041504,-0.0052490,-0.0054932,-0.0059814,-0.0054626,-0.0049744,-0.0040283,-0.0029297,-0.0016174,-0.0003967,0.0010681,0.0019531,0.0031128,0.0034180,0.0038147,0.0036011,0.0029602,0.0022278,0.0010376,-0.0003967,-0.0016785,-0.0031738,-0.0046387,-0.0055237,-0.0066223,-0.0069275,-0.0072327,-0.0068665,-0.0063477,-0.0054321,-0.0040283,-0.0029297,-0.0011902,0.0002136,0.0018005,0.0030212,0.0042419,0.0048218,0.0054932,0.0055847,0.0054321,0.0049438,0.0042114,0.0032654,0.0021362,0.0009460,-0.0000916,-0.0012512,-0.0021057,-0.0028076,-0.0033264,-0.0034485,-0.0033569,-0.0029602,-0.0024109,-0.0014038,-0.0004883,0.0007935,0.0019531,0.0031433,0.0042725,0.0052795,0.0062256,0.0068054,0.0073242,0.0074768,0.0077820,0.0073242,0.0074463,0.0068970,0.0067444,0.0065308,0.0061035,0.0062256,0.0061646,0.0066223,0.0071106,0.0079651,0.0088501,0.0101929,0.0113525,0.0129089,0.0142517,0.0157471,0.0169067,0.0179749,0.0189209,0.0193481,0.0197449,0.0195618,0.0193481,0.0187378,0.0180969,0.0171204,0.0161438,0.0152893,0.0142212,0.0137024,0.0129089,0.0127258,0.0125732,0.0127258,0.0132141,0.0137329,0.0143433,0.0152588,0.0157166,0.0166321,0.0168762,0.0173950,0.0172424,0.0172119,0.0165710,0.0161133,0.0149536,0.0142212,0.0126648,0.0117798,0.0103455,0.0092773,0.0083008,0.0075073,0.0069275,0.0067139,0.0066528,0.0068970,0.0075378,0.0079956,0.0091858,0.0099792,0.0112610,0.0124512,0.0133972,0.0147400,0.0156250,0.0165405,0.0174866,0.0178528,0.0186157,0.0187378,0.0189819,0.0191650,0.0189819,0.0190125,0.0187988,0.0184937,0.0182495,0.0178833,0.0174561,0.0170898,0.0167847,0.0162354,0.0159912,0.0157166,0.0151978,0.0151978,0.0148010,0.0148926,0.0145874,0.0145874,0.0144043,0.0143433,0.0141602,0.0138855,0.0137024,0.0132446,0.0130310,0.0124207,0.0120544,0.0115051,0.0109863,0.0105286,0.0102234,0.0096436,0.0097656,0.0093689,0.0096741,0.0097351,0.0100098,0.0105286,0.0107422,0.0111694,0.0113525,0.0116272,0.0116272,0.0114136,0.0110474,0.0104065,0.0095215,0.0085449,0.0072632,0.0058594,0.0044861,0.0029602,0.0016174,0.0003662,-0.0007019,-0.0016174,-0.0021973,-0.0027161,-0.0027771,-0.0028076,-0.0026245,-0.0022583,-0.0018005,-0.0014038,-0.0011597,-0.0008850,-0.0009155,-0.0010681,-0.0016479,-0.0022278,-0.0032959,-0.0043640,-0.0057983,-0.0072632,-0.0087891,-0.0103760,-0.0115967,-0.0130920,-0.0139465,-0.0147705,-0.0151062,-0.0154419,-0.0151367,-0.0148315,-0.0142517,-0.0133667,-0.0126038,-0.0116272,-0.0111084,-0.0102539,-0.0101929,-0.0097656,-0.0103455,-0.0105591,-0.0116272,-0.0125122,-0.0140381,-0.0153198,-0.0168762,-0.0182800,-0.0196533,-0.0207214,-0.0216064,-0.0219421,-0.0223389,-0.0218201,-0.0215149,-0.0202026,-0.0192261,-0.0175781,-0.0161438,-0.0143433,-0.0129395,-0.0113831,-0.0103149,-0.0091553,-0.0086670,-0.0082397,-0.0081482,-0.0083923,-0.0086975,-0.0093079,-0.0098572,-0.0103455,-0.0108032,-0.0110168,-0.0110474,-0.0107422,-0.0102539,-0.0092468,-0.0082397,-0.0067139,-0.0053101,-0.0034180,-0.0019836,-0.0000610,0.0012512,0.0028076,0.0036926,0.0046082,0.0049744,0.0052795,0.0052490,0.0049133,0.0044861,0.0039978,0.0030823,0.0028076,0.0018005,0.0018005,0.0013123,0.0011902,0.0012817,0.0013733,0.0020142,0.0022888,0.0030212,0.0034790,0.0040894,0.0045776,0.0051575,0.0053406,0.0056458,0.0056458,0.0055237,0.0054626,0.0051575,0.0046387,0.0046387,0.0037842,0.0039978,0.0034180,0.0034180,0.0036011,0.0034485,0.0041809,0.0043945,0.0051880,0.0059509,0.0068665,0.0077515,0.0089417,0.0098572,0.0110474,0.0121460,0.0130005,0.0140381,0.0149231,0.0154724,0.0162354,0.0166321,0.0170288,0.0171814,0.0174561,0.0173340,0.0173950,0.0173340,0.0170593,0.0170898,0.0168457,0.0166931,0.0165710,0.0163269,0.0163574,0.0161133,0.0161133,0.0159607,0.0157776,0.0158997,0.0157166,0.0157166,0.0156250,0.0154419,0.0154419,0.0151978,0.0151062,0.0147705,0.0145569,0.0143127,0.0138855,0.0137329,0.0133057,0.0130310,0.0128479,0.0126343,0.0124207,0.0123596,0.0121460,0.0122070,0.0122375,0.0122375,0.0123901,0.0125732,0.0125732,0.0129395,0.0127258,0.0129395,0.0127563,0.0125732,0.0122681,0.0120239,0.0112915,0.0108643,0.0100403,0.0093994,0.0085754,0.0078735,0.0070190,0.0065918,0.0057068,0.0054016,0.0049438,0.0048523,0.0047302,0.0048828,0.0048523,0.0052490,0.0053406,0.0056763,0.0058594,0.0060120,0.0061035,0.0058899,0.0058289,0.0054321,0.0049438,0.0045776,0.0035706,0.0034180,0.0022583,0.0018311,0.0009460,0.0003662,-0.0001831,-0.0007629,-0.0011292,-0.0015869,-0.0018921,-0.0020752,-0.0026245,-0.0026855,-0.0031738,-0.0037231,-0.0040588,-0.0049744,-0.0054016,-0.0063782,-0.0069580,-0.0077820,-0.0085144,-0.0092468,-0.0097351,-0.0101929,-0.0104370,-0.0106506,-0.0107117,-0.0104370,-0.0101624,-0.0097656,-0.0092773,-0.0090027,-0.0083618,-0.0081177,-0.0076599,-0.0075989,-0.0074158,-0.0075684,-0.0076294,-0.0079346,-0.0083008,-0.0085754,-0.0090637,-0.0092773,-0.0095215,-0.0095825,-0.0095825,-0.0092773,-0.0088196,-0.0084229,-0.0075073,-0.0067749,-0.0058899,-0.0047302,-0.0039978,-0.0029602,-0.0022888,-0.0016785,-0.0010986,-0.0009766,-0.0008850,-0.0010681,-0.0014648,-0.0018921,-0.0026855,-0.0032959,-0.0039673,-0.0046082,-0.0052185,-0.0054016,-0.0057373,-0.0054321,-0.0052490,-0.0043640,-0.0036621,-0.0023193,-0.0012512,0.0003357,0.0016479,0.0030518,0.0043335,0.0051880,0.0061035,0.0063171,0.0065002,0.0059814,0.0054626,0.0041504,0.0029602,0.0014954,-0.0002747,-0.0016479,-0.0033264,-0.0043945,-0.0055542,-0.0060120,-0.0064087,-0.0060730,-0.0056152,-0.0045776,-0.0033569,-0.0018311,-0.0002747,0.0014343,0.0030212,0.0043335,0.0057068,0.0062866,0.0072327,0.0069275,0.0070190,0.0062256,0.0056763,0.0046082,0.0037231,0.0024414,0.0014038,0.0005493,-0.0002441,-0.0006409,-0.0010071,-0.0007629,-0.0006714,0.0000916,0.0005798,0.0017700,0.0024719,0.0036316,0.0043640,0.0054016,0.0061035,0.0065002,0.0072021,0.0070190,0.0073242,0.0068970,0.0065613,0.0058899,0.0051880,0.0042725,0.0033875,0.0024109,0.0015564,0.0004578,-0.0001526,-0.0009766,-0.0013733,-0.0017395,-0.0019226,-0.0018005,-0.0014648,-0.0007629,-0.0001831,0.0010071,0.0019226,0.0033264,0.0045471,0.0057068,0.0068970,0.0077209,0.0085754,0.0088501,0.0089417,0.0087585,0.0079956,0.0072937,0.0057678,0.0046082,0.0028076,0.0013123,-0.0004272,-0.0018311,-0.0032959,-0.0041199,-0.0050354,-0.0053101,-0.0055237,-0.0053406,-0.0047302,-0.0043030,-0.0034790,-0.0029297,-0.0021057,-0.0019226,-0.0014038,-0.0018921,-0.0019531,-0.0028687,-0.0038757,-0.0050354,-0.0067139,-0.0080566,-0.0098572,-0.0112305,-0.0125122,-0.0138245,-0.0141907,-0.0150146,-0.0146790,-0.0144348,-0.0136719,-0.0124817,-0.0113831,-0.0098572,-0.0083923,-0.0068970,-0.0056763,-0.0043640,-0.0037842,-0.0029602,-0.0030212,-0.0031128,-0.0036011,-0.0045471,-0.0053711,-0.0066528,-0.0079651,-0.0092163,-0.0105591,-0.0116882,-0.0124817,-0.0132446,-0.0135803,-0.0135803,-0.0135193,-0.0129395,-0.0122986,-0.0115662,-0.0104370,-0.0096130,-0.0083313,-0.0077515,-0.0065918,-0.0061646,-0.0053101,-0.0050964,-0.0048828,-0.0047607,-0.0048523,-0.0049133,-0.0050964,-0.0052795,-0.0053101,-0.0051880,-0.0052490,-0.0047302,-0.0044556,-0.0036926,-0.0029602,-0.0020752,-0.0009766,0.0000305,0.0013428,0.0023804,0.0036926,0.0046082,0.0057678,0.0064087,0.0074158,0.0076294,0.0083618,0.0083618,0.0086975,0.0086365,0.0086060,0.0083313,0.0083618,0.0078735,0.0078735,0.0074463,0.0072632,0.0070496,0.0067749,0.0067444,0.0066528,0.0065002,0.0067139,0.0066528,0.0069275,0.0070496,0.0073853,0.0075378,0.0079651,0.0080566,0.0085449,0.0085449,0.0088806,0.0087280,0.0090332,0.0087280,0.0086365,0.0082703,0.0079041,0.0076904,0.0070190,0.0068970,0.0064087,0.0062866,0.0061646,0.0063477,0.0064697,0.0070190,0.0075684,0.0082397,0.0092773,0.0098877,0.0108948,0.0116577,0.0124207,0.0128174,0.0132751,0.0132446,0.0130615,0.0126038,0.0119934,0.0109863,0.0101013,0.0089417,0.0079651,0.0069885,0.0060425,0.0053101,0.0047913,0.0045776,0.0044250,0.0046082,0.0049438,0.0053406,0.0059814,0.0064392,0.0070496,0.0074158,0.0079041,0.0079041,0.0079346,0.0076294,0.0072937,0.0066223,0.0059814,0.0049744,0.0042419,0.0032043,0.0025940,0.0019226,0.0012512,0.0010376,0.0007629,0.0009460,0.0010986,0.0016174,0.0019226,0.0028076,0.0032654,0.0041504,0.0045471,0.0052185,0.0054016,0.0056763,0.0055847,0.0053711,0.0048218,0.0042725,0.0033264,0.0023804,0.0012817,-0.0000305,-0.0011597,-0.0025024,-0.0036011,-0.0046387,-0.0056458,-0.0063171,-0.0069275,-0.0071106,-0.0072632,-0.0071716,-0.0068054,-0.0065613,-0.0060425,-0.0055542,-0.0053406,-0.0050354,-0.0051575,-0.0054016,-0.0060730,-0.0068054,-0.0083618,-0.0094604,-0.0116882,-0.0133057,-0.0155640,-0.0173645,-0.0194702,-0.0208435,-0.0224915,-0.0232544,-0.0239258,-0.0239868,-0.0236816,-0.0227661,-0.0218201,-0.0203247,-0.0188293,-0.0173035,-0.0157776,-0.0144958,-0.0134888,-0.0128784,-0.0127563,-0.0131531,-0.0139160,-0.0151672,-0.0167542,-0.0187073,-0.0206604,-0.0228271,-0.0245667,-0.0264893,-0.0277405,-0.0289001,-0.0292664,-0.0295105,-0.0289612,-0.0281677,-0.0268860,-0.0254822,-0.0239258,-0.0222778,-0.0207825,-0.0194702,-0.0184021,-0.0176697,-0.0174255,-0.0174866,-0.0179749,-0.0187683,-0.0196228,-0.0209045,-0.0221252,-0.0232849,-0.0242615,-0.0250854,-0.0256653,-0.0256653,-0.0255737,-0.0246582,-0.0238953,-0.0221863,-0.0207825,-0.0187378,-0.0168457,-0.0148010,-0.0128479,-0.0111389,-0.0096741,-0.0083923,-0.0074463,-0.0070801,-0.0067444,-0.0072632,-0.0074768,-0.0084534,-0.0092773,-0.0104065,-0.0114441,-0.0125122,-0.0133972,-0.0141602,-0.0147095,-0.0150146,-0.0149231,-0.0148926,-0.0141907,-0.0137329,-0.0126648,-0.0119324,-0.0108948,-0.0099487,-0.0090027,-0.0082092,-0.0075073,-0.0069275,-0.0066528,-0.0063782,-0.0062866,-0.0062866,-0.0064087,-0.0064087,-0.0066528,-0.0064392,-0.0065613,-0.0060730,-0.0058594,-0.0051270,-0.0042419,-0.0034485,-0.0021057,-0.0011292,0.0003052,0.0015869,0.0028992,0.0039062,0.0052185,0.0059204,0.0068359,0.0071411,0.0075989,0.0075989,0.0075073,0.0073547,0.0068665,0.0065308,0.0058594,0.0056152,0.0050049,0.0048523,0.0045776,0.0044556,0.0046692,0.0046082,0.0052490,0.0055237,0.0060425,0.0066528,0.0072632,0.0078125,0.0082397,0.0088196,0.0087891,0.0092468,0.0089417,0.0090637,0.0086670,0.0084534,0.0082092,0.0077820,0.0077515,0.0074463,0.0076599,0.0075989,0.0080566,0.0083313,0.0091248,0.0099182,0.0107727,0.0118408,0.0129395,0.0138855,0.0152893,0.0161133,0.0171814,0.0178223,0.0185852,0.0188904,0.0194397,0.0191650,0.0193176,0.0188293,0.0186157,0.0177612,0.0173645,0.0164795,0.0158081,0.0151672,0.0141907,0.0137939,0.0129089,0.0125122,0.0117188,0.0115051,0.0108643,0.0107422,0.0102234,0.0100098,0.0094910,0.0092773,0.0087280,0.0083923,0.0077515,0.0071716,0.0064392,0.0058594,0.0049744,0.0042114,0.0032959,0.0023193,0.0015564,0.0003967,-0.0004883,-0.0014648,-0.0024719,-0.0032959,-0.0040894,-0.0050964,-0.0056458,-0.0063477,-0.0068665,-0.0072327,-0.0075684,-0.0079041,-0.0078430,-0.0080872,-0.0079346,-0.0079651,-0.0079956,-0.0078125,-0.0079651,-0.0080872,-0.0080566,-0.0087280,-0.0087280,-0.0097046,-0.0101624,-0.0111694,-0.0120850,-0.0131836,-0.0142517,-0.0155334,-0.0166016,-0.0177307,-0.0188293,-0.0196533,-0.0204468,-0.0211182,-0.0212402,-0.0217285,-0.0214233,-0.0211792,-0.0208740,-0.0201111,-0.0196228,-0.0184937,-0.0178223,-0.0170593,-0.0159912,-0.0155945,-0.0146790,-0.0144043,-0.0140076,-0.0139771,-0.0137329,-0.0142212,-0.0142517,-0.0147400,-0.0151978,-0.0154724,-0.0161133,-0.0162964,-0.0168152,-0.0167847,-0.0173340,-0.0169067,-0.0172729,-0.0168457,-0.0166931,-0.0165405,-0.0161438,-0.0159607,-0.0156555,-0.0154724,-0.0153809,-0.0154419,-0.0153503,-0.0157471,-0.0156250,-0.0161743,-0.0162354,-0.0166016,-0.0167236,-0.0166931,-0.0169373,-0.0166016,-0.0165710,-0.0160522,-0.0156250,-0.0149841,-0.0144653,-0.0134583,-0.0129089,-0.0119629,-0.0112610,-0.0107117,-0.0097351,-0.0094910,-0.0088806,-0.0084229,-0.0083313,-0.0077515,-0.0078430,-0.0074158,-0.0072937,-0.0069580,-0.0067139,-0.0064392,-0.0060425,-0.0054626,-0.0050354,-0.0044250,-0.0038147,-0.0032043,-0.0026550,-0.0019226,-0.0014954,-0.0007629,-0.0004578,0.0001831,0.0003357,0.0008545,0.0009766,0.0011902,0.0014038,0.0014648,0.0016785,0.0015869,0.0017395,0.0017395,0.0018616,0.0018921,0.0021057,0.0020752,0.0023499,0.0025940,0.0026550,0.0030518,0.0031433,0.0035095,0.0039368,0.0041199,0.0046082,0.0050659,0.0051880,0.0058289,0.0061035,0.0066528,0.0069885,0.0075378,0.0079651,0.0084534,0.0091553,0.0093689,0.0102539,0.0106812,0.0113525,0.0120544,0.0126343,0.0133362,0.0139771,0.0147095,0.0153809,0.0161133,0.0166016,0.0170898,0.0177307,0.0179138,0.0184326,0.0184021,0.0186462,0.0186462,0.0184937,0.0184631,0.0181885,0.0179749,0.0178223,0.0174866,0.0173340,0.0168457,0.0166931,0.0163269,0.0160828,0.0156555,0.0155945,0.0149536,0.0149841,0.0143127,0.0141296,0.0134888,0.0133667,0.0127258,0.0123901,0.0119934,0.0115967,0.0113525,0.0110474,0.0108032,0.0107117,0.0105896,0.0104675,0.0105286,0.0103455,0.0102234,0.0101013,0.0097656,0.0096130,0.0089722,0.0085754,0.0077820,0.0072327,0.0063171,0.0055847,0.0047607,0.0039062,0.0033264,0.0025330,0.0020142,0.0016479,0.0011292,0.0010376,0.0007935,0.0007629,0.0005188,0.0003967,0.0001221,-0.0002441,-0.0007019,-0.0014038,-0.0020142,-0.0032043,-0.0039368,-0.0053101,-0.0063477,-0.0076599,-0.0087891,-0.0097656,-0.0107117,-0.0112305,-0.0117798,-0.0119629,-0.0118408,-0.0115356,-0.0109863,-0.0102234,-0.0096741,-0.0085144,-0.0079346,-0.0069885,-0.0065002,-0.0059814,-0.0056152,-0.0056152,-0.0056458,-0.0059509,-0.0065308,-0.0069275,-0.0076904,-0.0083313,-0.0089417,-0.0095825,-0.0098267,-0.0101013,-0.0100403,-0.0099487,-0.0095520,-0.0090027,-0.0083618,-0.0076294,-0.0066223,-0.0061035,-0.0050964,-0.0047302,-0.0040894,-0.0039062,-0.0038147,-0.0040283,-0.0043640,-0.0051575,-0.0057068,-0.0070190,-0.0076904,-0.0090332,-0.0099182,-0.0108948,-0.0116577,-0.0122070,-0.0125122,-0.0126343,-0.0122375,-0.0119934,-0.0110474,-0.0102234,-0.0090027,-0.0078125,-0.0064087,-0.0053101,-0.0039368,-0.0032654,-0.0021057,-0.0018005,-0.0013428,-0.0014343,-0.0016174,-0.0021057,-0.0027466,-0.0035095,-0.0044556,-0.0052185,-0.0058899,-0.0064697,-0.0068054,-0.0069275,-0.0066223,-0.0061340,-0.0053711,-0.0041809,-0.0032043,-0.0015564,-0.0003052,0.0009155,0.0023499,0.0028992,0.0037842,0.0037231,0.0037231,0.0030212,0.0019531,0.0006714,-0.0011597,-0.0029297,-0.0050354,-0.0068054,-0.0087891,-0.0103455,-0.0115051,-0.0124817,-0.0126038,-0.0124512,-0.0116272,-0.0104370,-0.0087891,-0.0065918,-0.0044861,-0.0019531,0.0004578,0.0025024,0.0047607,0.0061951,0.0077515,0.0083313,0.0088806,0.0084839,0.0080872,0.0070496,0.0059204,0.0046997,0.0030823,0.0021057,0.0006104,0.0001221,-0.0006409,-0.0006104,-0.0003052,0.0004578,0.0016479,0.0031128,0.0047302,0.0065002,0.0083313,0.0100403,0.0115662,0.0126953,0.0135803,0.0140076,0.0137329,0.0136414,0.0121765,0.0115662,0.0094604,0.0082397,0.0063171,0.0048828,0.0032959,0.0023804,0.0014954,0.0012512,0.0013428,0.0017090,0.0028076,0.0039978,0.0054016,0.0071411,0.0085754,0.0102539,0.0115662,0.0125732,0.0133362,0.0135803,0.0132751,0.0128174,0.0115356,0.0103760,0.0086060,0.0067444,0.0048218,0.0030212,0.0011597,-0.0003052,-0.0015564,-0.0024414,-0.0029602,-0.0031738,-0.0030823,-0.0026855,-0.0021973,-0.0014648,-0.0010071,-0.0003357,0.0001526,0.0002441,0.0003967,-0.0002441,-0.0004578,-0.0015869,-0.0024109,-0.0036621,-0.0048523,-0.0059204,-0.0069275,-0.0079041,-0.0083923,-0.0087280,-0.0087891,-0.0084534,-0.0076294,-0.0070496,-0.0056458,-0.0048218,-0.0032959,-0.0022583,-0.0010986,-0.0002136,0.0004578,0.0010071,0.0010986,0.0011597,0.0007324,0.0004272,-0.0003967,-0.0007935,-0.0016479,-0.0023193,-0.0026245,-0.0034485,-0.0032349,-0.0037842,-0.0032654,-0.0034790,-0.0028992,-0.0027771,-0.0021057,-0.0018616,-0.0014343,-0.0010986,-0.0008545,-0.0007019,-0.0006714,-0.0005188,-0.0006409,-0.0004578,-0.0006409,-0.0005798,-0.0005188,-0.0004883,-0.0001221,0.0001526,0.0006104,0.0009766,0.0015869,0.0019531,0.0026855,0.0030823,0.0036621,0.0039978,0.0045471,0.0046997,0.0053406,0.0053101,0.0059509,0.0059204,0.0066528,0.0067749,0.0074463,0.0078125,0.0083923,0.0089417,0.0096741,0.0102234,0.0107422,0.0112915,0.0117798,0.0119629,0.0123901,0.0120850,0.0125732,0.0120239,0.0122375,0.0116882,0.0117493,0.0114136,0.0114136,0.0112610,0.0114136,0.0114441,0.0117493,0.0119629,0.0123291,0.0127869,0.0130920,0.0137024,0.0136719,0.0141602,0.0140381,0.0140686,0.0139160,0.0135803,0.0132446,0.0126648,0.0121765,0.0115662,0.0111694,0.0106506,0.0104675,0.0100708,0.0101013,0.0100403,0.0102844,0.0104065,0.0109253,0.0109863,0.0115662,0.0117188,0.0119934,0.0121460,0.0122070,0.0123291,0.0119324,0.0120544,0.0114746,0.0114746,0.0109863,0.0107727,0.0106812,0.0105896,0.0106506,0.0108948,0.0111694,0.0116577,0.0122375,0.0129089,0.0137024,0.0144043,0.0152283,0.0157471,0.0163879,0.0169067,0.0171509,0.0175781,0.0173340,0.0173950,0.0170288,0.0166321,0.0163269,0.0155640,0.0152893,0.0144958,0.0141907,0.0136108,0.0134277,0.0130005,0.0130005,0.0127563,0.0129395,0.0126343,0.0130310,0.0127869,0.0129089,0.0128174,0.0126343,0.0123291,0.0122375,0.0113831,0.0111694,0.0102539,0.0097656,0.0089111,0.0081482,0.0075684,0.0067139,0.0063477,0.0054932,0.0053711,0.0048218,0.0047607,0.0044861,0.0045166,0.0043640,0.0045471,0.0043335,0.0043640,0.0042114,0.0040588,0.0036011,0.0032654,0.0025940,0.0020447,0.0011597,0.0004272,-0.0004272,-0.0014648,-0.0021667,-0.0031738,-0.0036926,-0.0045776,-0.0047913,-0.0055847,-0.0054932,-0.0058899,-0.0058594,-0.0058899,-0.0057983,-0.0057068,-0.0056763,-0.0055542,-0.0056458,-0.0056458,-0.0057983,-0.0061035,-0.0061951,-0.0067749,-0.0070190,-0.0077209,-0.0080566,-0.0087891,-0.0092163,-0.0097961,-0.0102234,-0.0106506,-0.0111084,-0.0114746,-0.0117493,-0.0120850,-0.0122375,-0.0124817,-0.0124817,-0.0128174,-0.0125427,-0.0128479,-0.0125732,-0.0127563,-0.0126343,-0.0126648,-0.0125732,-0.0125122,-0.0125427,-0.0124207,-0.0124512,-0.0123291,-0.0124512,-0.0123596,-0.0124207,-0.0124207,-0.0124817,-0.0126953,-0.0125427,-0.0128784,-0.0126953,-0.0129089,-0.0126038,-0.0126038,-0.0122070,-0.0120239,-0.0114441,-0.0108948,-0.0103455,-0.0095215,-0.0089417,-0.0079651,-0.0071106,-0.0062561,-0.0054016,-0.0046082,-0.0038452,-0.0032349,-0.0026855,-0.0022583,-0.0018311,-0.0017700,-0.0012817,-0.0017700,-0.0012512,-0.0017090,-0.0016785,-0.0018311,-0.0020447,-0.0022583,-0.0022278,-0.0025940,-0.0022278,-0.0025635,-0.0023193,-0.0021362,-0.0021057,-0.0015869,-0.0012817,-0.0008240,-0.0002747,0.0003357,0.0009766,0.0015869,0.0023804,0.0029297,0.0038757,0.0042419,0.0050354,0.0053711,0.0058289,0.0060730,0.0062866,0.0060425,0.0062256,0.0056458,0.0056152,0.0051575,0.0046997,0.0043945,0.0039368,0.0038452,0.0035400,0.0036621,0.0036621,0.0040283,0.0046082,0.0049438,0.0059814,0.0066833,0.0077209,0.0086060,0.0095520,0.0104980,0.0110779,0.0118713,0.0121460,0.0125427,0.0126038,0.0123291,0.0122681,0.0116577,0.0113831,0.0105591,0.0102234,0.0093994,0.0090637,0.0087280,0.0084534,0.0085754,0.0086060,0.0092773,0.0098267,0.0107422,0.0117798,0.0129089,0.0142517,0.0153809,0.0167542,0.0176392,0.0188599,0.0194092,0.0201111,0.0201721,0.0202942,0.0198364,0.0194397,0.0182800,0.0173950,0.0162048,0.0148010,0.0137024,0.0122375,0.0112305,0.0102234,0.0095215,0.0088501,0.0087585,0.0085144,0.0087891,0.0090332,0.0093689,0.0099792,0.0102844,0.0107117,0.0109253,0.0108032,0.0105286,0.0098572,0.0090637,0.0078430,0.0064697,0.0048218,0.0032349,0.0015869,0.0000305,-0.0014038,-0.0023804,-0.0032349,-0.0033875,-0.0035095,-0.0028992,-0.0021057,-0.0009155,0.0004883,0.0021057,0.0036011,0.0052795,0.0065613,0.0077515,0.0083618,0.0088196,0.0084534,0.0079346,0.0066833,0.0051880,0.0033569,0.0013123,-0.0010071,-0.0030823,-0.0053711,-0.0071411,-0.0086975,-0.0099487,-0.0105591,-0.0110474,-0.0107727,-0.0103455,-0.0091858,-0.0083313,-0.0067749,-0.0055847,-0.0041199,-0.0029907,-0.0022583,-0.0014648,-0.0014648,-0.0015259,-0.0021973,-0.0031433,-0.0043335,-0.0058594,-0.0073242,-0.0088806,-0.0102844,-0.0116272,-0.0124817,-0.0130615,-0.0131531,-0.0126343,-0.0119629,-0.0104675,-0.0088196,-0.0068970,-0.0045776,-0.0024414,-0.0000610,0.0017395,0.0037842,0.0047607,0.0057983,0.0060730,0.0058289,0.0050659,0.0038147,0.0021973,0.0001831,-0.0017395,-0.0041199,-0.0060120,-0.0080566,-0.0095215,-0.0104370,-0.0112610,-0.0109558,-0.0107422,-0.0094910,-0.0080872,-0.0062866,-0.0044250,-0.0022583,-0.0005493,0.0013733,0.0025940,0.0035400,0.0039978,0.0038452,0.0033875,0.0021057,0.0007629,-0.0011902,-0.0029907,-0.0051880,-0.0069580,-0.0088196,-0.0098572,-0.0110168,-0.0111694,-0.0110779,-0.0103455,-0.0090942,-0.0075378,-0.0056152,-0.0036316,-0.0014038,0.0006409,0.0024414,0.0042419,0.0051575,0.0061951,0.0063477,0.0063477,0.0057373,0.0050964,0.0036926,0.0028687,0.0012817,0.0003967,-0.0006104,-0.0014038,-0.0018616,-0.0017700,-0.0017090,-0.0009460,-0.0001526,0.0008240,0.0021973,0.0033569,0.0046387,0.0057373,0.0067139,0.0074463,0.0078430,0.0082092,0.0077515,0.0076904,0.0067749,0.0062256,0.0050659,0.0043030,0.0032959,0.0025940,0.0018616,0.0014038,0.0011902,0.0011292,0.0013733,0.0016785,0.0023804,0.0028992,0.0038147,0.0045776,0.0052490,0.0061340,0.0065613,0.0071411,0.0073242,0.0074768,0.0073547,0.0071716,0.0067749,0.0062866,0.0056152,0.0051575,0.0043335,0.0039062,0.0032043,0.0027466,0.0025330,0.0019226,0.0021667,0.0017395,0.0018005,0.0018921,0.0018311,0.0019226,0.0020142,0.0018616,0.0018005,0.0015869,0.0012207,0.0008545,0.0001831,-0.0002747,-0.0013123,-0.0018311,-0.0028381,-0.0035400,-0.0043640,-0.0050964,-0.0055847,-0.0063171,-0.0064697,-0.0068359,-0.0069275,-0.0068970,-0.0067139,-0.0065002,-0.0061340,-0.0058899,-0.0055237,-0.0050354,-0.0050049,-0.0044861,-0.0046082,-0.0044861,-0.0047302,-0.0050049,-0.0055847,-0.0060120,-0.0067139,-0.0075073,-0.0080872,-0.0090637,-0.0095825,-0.0103149,-0.0107727,-0.0110474,-0.0115051,-0.0112305,-0.0113220,-0.0107422,-0.0103455,-0.0097961,-0.0088196,-0.0081787,-0.0072327,-0.0064697,-0.0057678,-0.0050049,-0.0046387,-0.0043030,-0.0042114,-0.0044556,-0.0047607,-0.0052795,-0.0061646,-0.0068665,-0.0081482,-0.0090332,-0.0102539,-0.0110474,-0.0120544,-0.0126038,-0.0130615,-0.0133362,-0.0129089,-0.0128784,-0.0116882,-0.0108643,-0.0095215,-0.0081177,-0.0065002,-0.0047913,-0.0032654,-0.0015869,-0.0000916,0.0010986,0.0024109,0.0028992,0.0036011,0.0039062,0.0038147,0.0038147,0.0032043,0.0030212,0.0023499,0.0020142,0.0014343,0.0012207,0.0010986,0.0010986,0.0014038,0.0018005,0.0024109,0.0032043,0.0040283,0.0050964,0.0059204,0.0070190,0.0077820,0.0085144,0.0091248,0.0094604,0.0095215,0.0095520,0.0091248,0.0088501,0.0081482,0.0075684,0.0066833,0.0060120,0.0053406,0.0047302,0.0043335,0.0038452,0.0039978,0.0037231,0.0042419,0.0046082,0.0051880,0.0060425,0.0066528,0.0075684,0.0084839,0.0090332,0.0100098,0.0104065,0.0110168,0.0112610,0.0115051,0.0114441,0.0114136,0.0112305,0.0108948,0.0105896,0.0101624,0.0097351,0.0093689,0.0089722,0.0087585,0.0083923,0.0084534,0.0082397,0.0083923,0.0083618,0.0085449,0.0087585,0.0087280,0.0091553,0.0089722,0.0093079,0.0092163,0.0092773,0.0088501,0.0091248,0.0085144,0.0086365,0.0080566,0.0080566,0.0076599,0.0077209,0.0074158,0.0076294,0.0074463,0.0080872,0.0080566,0.0086365,0.0089722,0.0094299,0.0101624,0.0105591,0.0109863,0.0114441,0.0114746,0.0119324,0.0117188,0.0117493,0.0113525,0.0110168,0.0104370,0.0097961,0.0090942,0.0080566,0.0073853,0.0063477,0.0055542,0.0046082,0.0037537,0.0031738,0.0023499,0.0019531,0.0014038,0.0010986,0.0008545,0.0006104,0.0004272,0.0003357,0.0001526,0.0000916,-0.0002441,-0.0004578,-0.0008850,-0.0015259,-0.0020142,-0.0029602,-0.0037231,-0.0048218,-0.0058594,-0.0070801,-0.0082092,-0.0092468,-0.0103455,-0.0111389,-0.0118713,-0.0125122,-0.0125122,-0.0128784,-0.0124817,-0.0122681,-0.0117493,-0.0110474,-0.0104065,-0.0097656,-0.0091248,-0.0086670,-0.0083008,-0.0082703,-0.0083923,-0.0089417,-0.0093689,-0.0103455,-0.0112610,-0.0123901,-0.0136108,-0.0148315,-0.0158386,-0.0168762,-0.0176086,-0.0182190,-0.0186462,-0.0187378,-0.0187988,-0.0184021,-0.0182190,-0.0177612,-0.0174866,-0.0170593,-0.0170898,-0.0169067,-0.0171814,-0.0176086,-0.0180969,-0.0190430,-0.0197754,-0.0206604,-0.0217590,-0.0223999,-0.0234070,-0.0237122,-0.0241394,-0.0242615,-0.0240479,-0.0237427,-0.0229797,-0.0221863,-0.0213318,-0.0202026,-0.0193176,-0.0181885,-0.0174561,-0.0166931,-0.0161438,-0.0155945,-0.0156250,-0.0153198,-0.0156555,-0.0154419,-0.0159607,-0.0158081,-0.0161743,-0.0160828,-0.0160828,-0.0159912,-0.0156250,-0.0154114,-0.0147095,-0.0146179,-0.0138855,-0.0134888,-0.0132751,-0.0126038,-0.0129395,-0.0123596,-0.0127563,-0.0126648,-0.0129395,-0.0130005,-0.0132751,-0.0133972,-0.0133057,-0.0133972,-0.0131531,-0.0128479,-0.0123291,-0.0116577,-0.0109558,-0.0101929,-0.0093079,-0.0084229,-0.0076294,-0.0067139,-0.0060425,-0.0054016,-0.0048218,-0.0046692,-0.0041504,-0.0043945,-0.0040283,-0.0044250,-0.0044250,-0.0046997,-0.0048828,-0.0051270,-0.0050964,-0.0052490,-0.0050354,-0.0048828,-0.0046082,-0.0041199,-0.0035400,-0.0028992,-0.0021667,-0.0012512,-0.0006104,0.0002747,0.0010376,0.0016785,0.0023804,0.0027161,0.0029602,0.0033264,0.0031128,0.0032654,0.0030212,0.0026550,0.0025330,0.0021057,0.0019836,0.0018311,0.0016174,0.0019531,0.0018616,0.0025940,0.0028992,0.0039062,0.0046082,0.0057068,0.0067139,0.0078430,0.0089417,0.0099182,0.0108337,0.0116272,0.0123291,0.0127563,0.0130920,0.0131531,0.0133667,0.0131531,0.0130310,0.0127563,0.0126038,0.0123596,0.0124207,0.0120544,0.0125732,0.0123901,0.0131226,0.0132141,0.0139160,0.0143433,0.0150146,0.0155029,0.0160217,0.0164490,0.0166321,0.0169678,0.0167542,0.0168152,0.0164795,0.0161133,0.0155029,0.0152283,0.0141602,0.0139160,0.0128784,0.0123291,0.0116272,0.0108643,0.0104370,0.0096741,0.0093689,0.0087891,0.0084229,0.0082092,0.0077515,0.0077515,0.0073853,0.0073242,0.0071411,0.0070190,0.0068359,0.0067749,0.0064697,0.0065002,0.0058594,0.0060120,0.0052795,0.0051270,0.0045776,0.0041504,0.0036621,0.0031433,0.0025940,0.0021667,0.0016479,0.0011597,0.0006714,0.0003052,-0.0001831,-0.0006104,-0.0010376,-0.0016174,-0.0020142,-0.0027161,-0.0034180,-0.0042114,-0.0050049,-0.0059509,-0.0068970,-0.0079956,-0.0091248,-0.0101318,-0.0112915,-0.0120850,-0.0132141,-0.0137024,-0.0144348,-0.0147095,-0.0150452,-0.0150452,-0.0149536,-0.0147400,-0.0142822,-0.0138550,-0.0133362,-0.0128479,-0.0123901,-0.0121155,-0.0116577,-0.0116272,-0.0113220,-0.0116577,-0.0116272,-0.0121155,-0.0122681,-0.0128479,-0.0131531,-0.0135498,-0.0140381,-0.0140991,-0.0146790,-0.0144348,-0.0147095,-0.0145264,-0.0144348,-0.0141296,-0.0136719,-0.0134277,-0.0130310,-0.0126648,-0.0124512,-0.0122375,-0.0120544,-0.0122681,-0.0122681,-0.0125732,-0.0130005,-0.0133362,-0.0140686,-0.0145569,-0.0153198,-0.0157776,-0.0164795,-0.0168457,-0.0171509,-0.0174255,-0.0172119,-0.0172424,-0.0166931,-0.0162048,-0.0155029,-0.0144653,-0.0136108,-0.0125427,-0.0115051,-0.0105591,-0.0097351,-0.0090332,-0.0084839,-0.0081787,-0.0080566,-0.0081177,-0.0085754,-0.0087280,-0.0093384,-0.0099182,-0.0103455,-0.0108032,-0.0110168,-0.0111694,-0.0109863,-0.0107422,-0.0099792,-0.0092773,-0.0082397,-0.0072327,-0.0060425,-0.0049438,-0.0037842,-0.0031128,-0.0020142,-0.0019531,-0.0013428,-0.0017700,-0.0017395,-0.0025940,-0.0030212,-0.0042114,-0.0048828,-0.0059814,-0.0066223,-0.0072021,-0.0074768,-0.0076294,-0.0072937,-0.0068970,-0.0059509,-0.0050659,-0.0036316,-0.0025024,-0.0008850,0.0003967,0.0018311,0.0028381,0.0039673,0.0046387,0.0052795,0.0054016,0.0057068,0.0053101,0.0053406,0.0047913,0.0045776,0.0044556,0.0040588,0.0044556,0.0044250,0.0049744,0.0056763,0.0065308,0.0075989,0.0088501,0.0101013,0.0115051,0.0127563,0.0141907,0.0151978,0.0162354,0.0169067,0.0174866,0.0177612,0.0177917,0.0175476,0.0171204,0.0167542,0.0158386,0.0154724,0.0144043,0.0141296,0.0132446,0.0130920,0.0126953,0.0126343,0.0128479,0.0130615,0.0135498,0.0141602,0.0148010,0.0158386,0.0162659,0.0173035,0.0176697,0.0184937,0.0185852,0.0189209,0.0186462,0.0186462,0.0178833,0.0173645,0.0164185,0.0154419,0.0142517,0.0132446,0.0121765,0.0109558,0.0102234,0.0092468,0.0087891,0.0081787,0.0080566,0.0080566,0.0081177,0.0086670,0.0088806,0.0095215,0.0100708,0.0104370,0.0111084,0.0111084,0.0113525,0.0111694,0.0108032,0.0101624,0.0092773,0.0081482,0.0069275,0.0054016,0.0040588,0.0022888,0.0010376,-0.0004272,-0.0016174,-0.0025940,-0.0032043,-0.0040588,-0.0039673,-0.0042725,-0.0039978,-0.0036926,-0.0034485,-0.0028076,-0.0026245,-0.0020447,-0.0020447,-0.0018616,-0.0022278,-0.0023499,-0.0030823,-0.0035400,-0.0045776,-0.0052490,-0.0062256,-0.0068970,-0.0076294,-0.0079956,-0.0081787,-0.0082703,-0.0076904,-0.0072937,-0.0063477,-0.0052490,-0.0041199,-0.0028687,-0.0014343,-0.0005798,0.0005798,0.0011597,0.0015564,0.0018005,0.0013428,0.0009460,-0.0001221,-0.0011292,-0.0025330,-0.0038757,-0.0053711,-0.0067139,-0.0080872,-0.0089417,-0.0101318,-0.0103149,-0.0108643,-0.0107727,-0.0104675,-0.0101013,-0.0092773,-0.0086975,-0.0079346,-0.0072021,-0.0066528,-0.0061340,-0.0059509,-0.0058899,-0.0059204,-0.0063477,-0.0067749,-0.0072327,-0.0079651,-0.0085449,-0.0090027,-0.0094299,-0.0096130,-0.0095825,-0.0094604,-0.0090332,-0.0082703,-0.0074768,-0.0064392,-0.0054016,-0.0043335,-0.0031738,-0.0023193,-0.0011597,-0.0009155,0.0001221,-0.0001526,0.0003052,-0.0003052,-0.0004883,-0.0013733,-0.0018921,-0.0028992,-0.0035095,-0.0046082,-0.0051270,-0.0058899,-0.0062561,-0.0065002,-0.0064697,-0.0064392,-0.0060425,-0.0055237,-0.0050354,-0.0043640,-0.0036621,-0.0029602,-0.0024414,-0.0017395,-0.0016479,-0.0010681,-0.0012207,-0.0009766,-0.0012512,-0.0013733,-0.0015869,-0.0018616,-0.0020447,-0.0021057,-0.0022278,-0.0020142,-0.0016785,-0.0012512,-0.0006104,0.0000000,0.0007935,0.0017700,0.0025024,0.0033569,0.0042114,0.0046997,0.0054016,0.0055237,0.0056763,0.0055237,0.0053101,0.0049744,0.0043335,0.0038757,0.0032043,0.0026245,0.0022278,0.0018005,0.0017090,0.0016785,0.0019226,0.0023193,0.0028076,0.0036011,0.0042419,0.0052795,0.0059509,0.0067444,0.0073853,0.0078430,0.0080872,0.0083313,0.0078735,0.0079956,0.0071106,0.0068054,0.0058899,0.0053711,0.0047913,0.0043335,0.0040588,0.0039062,0.0042114,0.0044861,0.0052185,0.0059814,0.0069580,0.0078735,0.0089417,0.0097046,0.0104675,0.0108643,0.0110779,0.0108032,0.0102539,0.0093079,0.0079956,0.0064697,0.0045776,0.0028076,0.0007324,-0.0011292,-0.0028076,-0.0042419,-0.0053711,-0.0061646,-0.0065002,-0.0065308,-0.0061035,-0.0053711,-0.0045471,-0.0035706,-0.0022888,-0.0015869,-0.0003662,-0.0001526,0.0003662,0.0001831,-0.0002747,-0.0009766,-0.0021667,-0.0035095,-0.0051880,-0.0066528,-0.0084229,-0.0097961,-0.0111084,-0.0120544,-0.0126648,-0.0128174,-0.0126343,-0.0121460,-0.0108643,-0.0097961,-0.0080261,-0.0065308,-0.0048218,-0.0032043,-0.0016479,-0.0005493,0.0005798,0.0010376,0.0012817,0.0012817,0.0007019,0.0001221,-0.0007629,-0.0018616,-0.0027771,-0.0038757,-0.0046692,-0.0053711,-0.0058594,-0.0058594,-0.0058594,-0.0052490,-0.0046082,-0.0035095,-0.0024109,-0.0010071,0.0001831,0.0017395,0.0028076,0.0039673,0.0047302,0.0054016,0.0055847,0.0056763,0.0054016,0.0047913,0.0042114,0.0032043,0.0023499,0.0014648,0.0003357,-0.0001831,-0.0009155,-0.0012207,-0.0012817,-0.0010071,-0.0007324,0.0003052,0.0008240,0.0022583,0.0031433,0.0045166,0.0055847,0.0065613,0.0075378,0.0080261,0.0086365,0.0087280,0.0088806,0.0085144,0.0084534,0.0078125,0.0075989,0.0072632,0.0069580,0.0070190,0.0072327,0.0074463,0.0083008,0.0089417,0.0098877,0.0111084,0.0121155,0.0133362,0.0144043,0.0151367,0.0159912,0.0163574,0.0164185,0.0164490,0.0158997,0.0153503,0.0144958,0.0135193,0.0126038,0.0115662,0.0107422,0.0099487,0.0094299,0.0091248,0.0089722,0.0092163,0.0094299,0.0101318,0.0106812,0.0113831,0.0120544,0.0128174,0.0133667,0.0137634,0.0140381,0.0141602,0.0140381,0.0139465,0.0134277,0.0131531,0.0124817,0.0119934,0.0115662,0.0109558,0.0109558,0.0103760,0.0107117,0.0103760,0.0109253,0.0111084,0.0115967,0.0120239,0.0126343,0.0130920,0.0138245,0.0140686,0.0149231,0.0149231,0.0155945,0.0156555,0.0159607,0.0160828,0.0161133,0.0160217,0.0160217,0.0157776,0.0156860,0.0153198,0.0151672,0.0147095,0.0144653,0.0141602,0.0137939,0.0135803,0.0133057,0.0129700,0.0128174,0.0126038,0.0124817,0.0122986,0.0122681,0.0120850,0.0119934,0.0120239,0.0114441,0.0114136,0.0107422,0.0104370,0.0097046,0.0090332,0.0080261,0.0072632,0.0061951,0.0052185,0.0043335,0.0032349,0.0025024,0.0016479,0.0012512,0.0006714,0.0005188,0.0003662,0.0005188,0.0007019,0.0010986,0.0014648,0.0018311,0.0023193,0.0025024,0.0027161,0.0027161,0.0025635,0.0021667,0.0017090,0.0008545,-0.0002441,-0.0011902,-0.0025024,-0.0037842,-0.0049744,-0.0062256,-0.0073242,-0.0081787,-0.0089722,-0.0093079,-0.0096130,-0.0094299,-0.0093689,-0.0085449,-0.0082703,-0.0072021,-0.0067444,-0.0059204,-0.0052185,-0.0050049,-0.0044861,-0.0047913,-0.0048523,-0.0055542,-0.0062561,-0.0073853,-0.0084839,-0.0098267,-0.0109863,-0.0122375,-0.0132446,-0.0139465,-0.0144958,-0.0145264,-0.0144653,-0.0138855,-0.0130615,-0.0118713,-0.0106201,-0.0093079,-0.0078735,-0.0067444,-0.0055237,-0.0048218,-0.0045471,-0.0043030,-0.0051270,-0.0055847,-0.0070801,-0.0084534,-0.0102234,-0.0120850,-0.0137329,-0.0156860,-0.0168152,-0.0182495,-0.0185242,-0.0191650,-0.0185547,-0.0181274,-0.0167847,-0.0152588,-0.0136108,-0.0117493,-0.0096436,-0.0079956,-0.0061340,-0.0048828,-0.0037537,-0.0031738,-0.0028992,-0.0031128,-0.0036011,-0.0044250,-0.0055237,-0.0066528,-0.0079346,-0.0088806,-0.0098877,-0.0105896,-0.0107727,-0.0110168,-0.0104370,-0.0100098,-0.0089417,-0.0076599,-0.0063782,-0.0046997,-0.0033875,-0.0016785,-0.0005798,0.0007935,0.0014648,0.0021057,0.0022583,0.0023499,0.0019531,0.0014343,0.0007935,-0.0001831,-0.0008850,-0.0019226,-0.0026245,-0.0033264,-0.0038147,-0.0039673,-0.0041199,-0.0035400,-0.0032043,-0.0022278,-0.0012512,0.0000000,0.0013428,0.0028076,0.0041199,0.0057678,0.0067749,0.0082703,0.0089111,0.0097046,0.0101929,0.0103455,0.0102844,0.0100403,0.0093384,0.0087891,0.0078735,0.0071106,0.0061646,0.0053711,0.0046997,0.0042419,0.0038757,0.0038757,0.0040283,0.0046692,0.0053711,0.0063782,0.0075989,0.0087891,0.0103760,0.0118408,0.0131226,0.0146790,0.0153198,0.0165710,0.0168762,0.0174561,0.0171509,0.0171204,0.0162354,0.0157471,0.0146179,0.0134888,0.0126038,0.0112305,0.0104370,0.0095825,0.0087280,0.0085754,0.0082092,0.0084229,0.0086060,0.0091248,0.0095520,0.0102844,0.0108032,0.0114441,0.0118103,0.0121155,0.0120239,0.0117798,0.0114136,0.0105286,0.0098267,0.0085449,0.0073547,0.0062256,0.0047607,0.0039978,0.0027466,0.0021973,0.0016479,0.0013428,0.0014954,0.0016174,0.0019531,0.0025940,0.0031738,0.0039368,0.0043945,0.0049744,0.0052490,0.0054321,0.0054932,0.0050049,0.0048218,0.0040588,0.0033875,0.0026245,0.0018311,0.0010071,0.0004272,-0.0002136,-0.0005188,-0.0005188,-0.0006714,-0.0003052,-0.0002136,0.0003052,0.0007629,0.0010986,0.0014343,0.0016785,0.0017090,0.0015564,0.0011902,0.0004883,-0.0001831,-0.0012512,-0.0023193,-0.0032959,-0.0047302,-0.0054016,-0.0066528,-0.0071716,-0.0079651,-0.0080872,-0.0083618,-0.0079651,-0.0078735,-0.0071411,-0.0067444,-0.0058289,-0.0054321,-0.0045471,-0.0042725,-0.0038757,-0.0036011,-0.0038147,-0.0038757,-0.0043030,-0.0049744,-0.0052795,-0.0063477,-0.0066528,-0.0075684,-0.0079651,-0.0083313,-0.0086670,-0.0086365,-0.0085754,-0.0082092,-0.0077820,-0.0072327,-0.0065918,-0.0059204,-0.0051270,-0.0046692,-0.0040283,-0.0036926,-0.0033264,-0.0031433,-0.0030823,-0.0030823,-0.0032654,-0.0035706,-0.0037231,-0.0039673,-0.0040283,-0.0042725,-0.0042419,-0.0043335,-0.0038757,-0.0040283,-0.0032349,-0.0032654,-0.0024109,-0.0022583,-0.0017395,-0.0012817,-0.0011902,-0.0007629,-0.0007935,-0.0008850,-0.0009155,-0.0011902,-0.0014343,-0.0017700,-0.0021667,-0.0024719,-0.0028076,-0.0029602,-0.0031738,-0.0032349,-0.0031738,-0.0030518,-0.0028076,-0.0025024,-0.0022888,-0.0017700,-0.0018005,-0.0010986,-0.0013123,-0.0008240,-0.0008850,-0.0008850,-0.0008240,-0.0009155,-0.0011292,-0.0011597,-0.0013123,-0.0011902,-0.0013733,-0.0010986,-0.0010071,-0.0006714,-0.0003357,0.0002441,0.0007324,0.0014648,0.0018005,0.0027771,0.0030518,0.0039062,0.0042419,0.0048218,0.0051575,0.0053101,0.0056763,0.0055847,0.0057068,0.0056763,0.0054321,0.0056763,0.0052490,0.0053406,0.0051575,0.0051575,0.0049744,0.0051270,0.0047913,0.0051270,0.0049744,0.0049744,0.0051880,0.0049133,0.0051880,0.0050049,0.0049438,0.0050964,0.0047607,0.0048218,0.0046387,0.0044861,0.0043030,0.0040894,0.0040283,0.0036316,0.0035095,0.0032349,0.0029297,0.0027771,0.0024414,0.0020752,0.0020447,0.0014954,0.0015564,0.0008545,0.0008545,0.0002747,0.0001526,-0.0005798,-0.0006409,-0.0014038,-0.0016174,-0.0022888,-0.0025940,-0.0032959,-0.0035095,-0.0043335,-0.0044861,-0.0050049,-0.0053711,-0.0057373,-0.0060730,-0.0062256,-0.0064087,-0.0064697,-0.0066528,-0.0067139,-0.0066528,-0.0069275,-0.0067444,-0.0070190,-0.0071716,-0.0072327,-0.0076294,-0.0076904,-0.0082092,-0.0085754,-0.0088806,-0.0094299,-0.0097656,-0.0103149,-0.0106506,-0.0110168,-0.0112915,-0.0113831,-0.0115967,-0.0115662,-0.0114136,-0.0115051,-0.0110168,-0.0110779,-0.0105286,-0.0103760,-0.0100708,-0.0097656,-0.0094604,-0.0093079,-0.0090027,-0.0090332,-0.0089417,-0.0087891,-0.0090332,-0.0089111,-0.0091858,-0.0089417,-0.0090332,-0.0089417,-0.0088806,-0.0087280,-0.0085449,-0.0082092,-0.0079651,-0.0075073,-0.0071106,-0.0066223,-0.0061646,-0.0056763,-0.0052490,-0.0048218,-0.0043335,-0.0040894,-0.0038147,-0.0036316,-0.0035400,-0.0034485,-0.0034180,-0.0034790,-0.0035095,-0.0036621,-0.0037537,-0.0038452,-0.0040283,-0.0038147,-0.0040283,-0.0037537,-0.0036316,-0.0033569,-0.0030823,-0.0026855,-0.0022278,-0.0018616,-0.0012207,-0.0009460,-0.0002136,-0.0000916,0.0006714,0.0007629,0.0012207,0.0013733,0.0016479,0.0017700,0.0019226,0.0020142,0.0023193,0.0021973,0.0026550,0.0025024,0.0030518,0.0030518,0.0034180,0.0038147,0.0040283,0.0045471,0.0049133,0.0052185,0.0057068,0.0058594,0.0065308,0.0066528,0.0071411,0.0073547,0.0076599,0.0080872,0.0083618,0.0084229,0.0089111,0.0088806,0.0091248,0.0090027,0.0092468,0.0090332,0.0090637,0.0086060,0.0085144,0.0080261,0.0076294,0.0071716,0.0065918,0.0062866,0.0058289,0.0055847,0.0054626,0.0053101,0.0056152,0.0058594,0.0064087,0.0072327,0.0077820,0.0090332,0.0097961,0.0108643,0.0118713,0.0126038,0.0134888,0.0136719,0.0144653,0.0141602,0.0144958,0.0137329,0.0135193,0.0127869,0.0120850,0.0113220,0.0106506,0.0100403,0.0094604,0.0091858,0.0089111,0.0090637,0.0093079,0.0095520,0.0102234,0.0105896,0.0112915,0.0117493,0.0120239,0.0124207,0.0123596,0.0122375,0.0117493,0.0111694,0.0102234,0.0092773,0.0079651,0.0068970,0.0055847,0.0044556,0.0034790,0.0024414,0.0019226,0.0014343,0.0012817,0.0013123,0.0015564,0.0019836,0.0025330,0.0033569,0.0036926,0.0047302,0.0047607,0.0055237,0.0054626,0.0055237,0.0050964,0.0047913,0.0038452,0.0030823,0.0019836,0.0007324,-0.0002441,-0.0015869,-0.0026855,-0.0037231,-0.0047302,-0.0052490,-0.0060425,-0.0064087,-0.0067444,-0.0067749,-0.0069275,-0.0067139,-0.0068665,-0.0067139,-0.0070496,-0.0070190,-0.0075989,-0.0077820,-0.0086975,-0.0094299,-0.0105286,-0.0117493,-0.0127563,-0.0142212,-0.0153198,-0.0166626,-0.0177002,-0.0186768,-0.0193481,-0.0200500,-0.0200806,-0.0202637,-0.0199280,-0.0196228,-0.0187378,-0.0181885,-0.0169678,-0.0162659,-0.0152283,-0.0144043,-0.0136414,-0.0132141,-0.0125122,-0.0127258,-0.0124207,-0.0130615,-0.0133057,-0.0140686,-0.0148621,-0.0157776,-0.0167542,-0.0176697,-0.0186462,-0.0195007,-0.0201416,-0.0205994,-0.0209045,-0.0209656,-0.0208435,-0.0205383,-0.0198364,-0.0193176,-0.0184021,-0.0175171,-0.0167542,-0.0155945,-0.0150452,-0.0140991,-0.0134888,-0.0131836,-0.0127869,-0.0127869,-0.0128479,-0.0129395,-0.0135803,-0.0136719,-0.0144958,-0.0147400,-0.0153503,-0.0155945,-0.0158386,-0.0159302,-0.0157471,-0.0155029,-0.0148315,-0.0143127,-0.0134583,-0.0125427,-0.0115662,-0.0105591,-0.0097656,-0.0090027,-0.0084534,-0.0079651,-0.0079041,-0.0080566,-0.0084229,-0.0090637,-0.0096741,-0.0105591,-0.0114746,-0.0123596,-0.0131531,-0.0138550,-0.0142517,-0.0145264,-0.0144653,-0.0141296,-0.0134888,-0.0128784,-0.0115662,-0.0108337,-0.0093689,-0.0083923,-0.0072937,-0.0063477,-0.0057373,-0.0051575,-0.0049133,-0.0048523,-0.0049744,-0.0054321,-0.0054932,-0.0063171,-0.0062256,-0.0068970,-0.0066223,-0.0066223,-0.0061035,-0.0053406,-0.0044556,-0.0032043,-0.0016785,-0.0002747,0.0014343,0.0028687,0.0044250,0.0056152,0.0068970,0.0075073,0.0080566,0.0082703,0.0081177,0.0078430,0.0074158,0.0064697,0.0060425,0.0048828,0.0045471,0.0037537,0.0035095,0.0032959,0.0032349,0.0038452,0.0040588,0.0050049,0.0058899,0.0070190,0.0079346,0.0094604,0.0102234,0.0116577,0.0124207,0.0132751,0.0139465,0.0142517,0.0146790,0.0145264,0.0145874,0.0140381,0.0139465,0.0132446,0.0129700,0.0122986,0.0120850,0.0115051,0.0115356,0.0112000,0.0113831,0.0114746,0.0117188,0.0124207,0.0124817,0.0134888,0.0137634,0.0144653,0.0148926,0.0151978,0.0154419,0.0153809,0.0152283,0.0147400,0.0141907,0.0132141,0.0123291,0.0110779,0.0098877,0.0085754,0.0074463,0.0061340,0.0053711,0.0041809,0.0040588,0.0033875,0.0036316,0.0035706,0.0043335,0.0045776,0.0054321,0.0062866,0.0067444,0.0075989,0.0079346,0.0079956,0.0083618,0.0075684,0.0073853,0.0061035,0.0052490,0.0036621,0.0023499,0.0005188,-0.0008240,-0.0026855,-0.0039062,-0.0052490,-0.0062866,-0.0070496,-0.0075378,-0.0080566,-0.0077820,-0.0078125,-0.0073853,-0.0069885,-0.0066528,-0.0059509,-0.0056763,-0.0053101,-0.0050354,-0.0050354,-0.0050354,-0.0053101,-0.0057373,-0.0063171,-0.0068970,-0.0078125,-0.0084534,-0.0093079,-0.0099487,-0.0106201,-0.0112000,-0.0117188,-0.0119019,-0.0119629,-0.0121155,-0.0117493,-0.0116272,-0.0111694,-0.0105286,-0.0102234,-0.0094299,-0.0090332,-0.0083618,-0.0082703,-0.0076599,-0.0078430,-0.0076599,-0.0080872,-0.0082703,-0.0089722,-0.0093689,-0.0101929,-0.0108032,-0.0116577,-0.0121765,-0.0130310,-0.0132141,-0.0137939,-0.0137024,-0.0139160,-0.0133667,-0.0133057,-0.0123291,-0.0120850,-0.0110168,-0.0105591,-0.0097046,-0.0091858,-0.0086975,-0.0084229,-0.0081787,-0.0083313,-0.0085144,-0.0088196,-0.0094299,-0.0098877,-0.0105896,-0.0111084,-0.0118408,-0.0122681,-0.0126648,-0.0129089,-0.0129395,-0.0130005,-0.0127258,-0.0122681,-0.0120239,-0.0112610,-0.0109558,-0.0101929,-0.0098572,-0.0092163,-0.0089722,-0.0085754,-0.0083923,-0.0083008,-0.0082703,-0.0082397,-0.0082703,-0.0083618,-0.0083313,-0.0086365,-0.0082092,-0.0086365,-0.0079346,-0.0080566,-0.0075684,-0.0071106,-0.0066223,-0.0060730,-0.0054321,-0.0047913,-0.0041504,-0.0034180,-0.0028076,-0.0022583,-0.0016479,-0.0012512,-0.0008545,-0.0005798,-0.0003052,-0.0003357,-0.0000916,-0.0003357,-0.0004272,-0.0004578,-0.0009155,-0.0006104,-0.0010986,-0.0008850,-0.0009460,-0.0005493,-0.0003357,0.0003662,0.0008545,0.0018005,0.0028381,0.0037842,0.0051575,0.0062866,0.0075378,0.0086975,0.0097351,0.0107422,0.0114441,0.0121155,0.0122986,0.0126343,0.0126648,0.0124207,0.0121155,0.0118103,0.0114441,0.0111084,0.0108643,0.0106201,0.0106812,0.0107727,0.0111389,0.0115662,0.0121460,0.0130005,0.0136719,0.0146484,0.0154419,0.0163574,0.0169983,0.0176392,0.0180054,0.0182800,0.0181885,0.0181274,0.0177002,0.0171204,0.0163574,0.0155945,0.0146484,0.0138550,0.0127869,0.0120850,0.0111389,0.0106201,0.0100098,0.0097351,0.0093994,0.0094910,0.0095825,0.0098877,0.0102844,0.0104980,0.0112305,0.0113525,0.0120850,0.0119934,0.0125122,0.0122681,0.0121765,0.0119934,0.0113525,0.0108337,0.0099487,0.0091248,0.0082397,0.0070801,0.0062256,0.0052185,0.0044250,0.0039368,0.0030823,0.0030212,0.0024414,0.0027161,0.0025330,0.0027771,0.0030212,0.0032959,0.0034790,0.0038452,0.0038147,0.0040588,0.0038757,0.0037537,0.0036011,0.0030518,0.0027161,0.0019836,0.0015869,0.0007324,0.0000610,-0.0005188,-0.0013123,-0.0016479,-0.0025330,-0.0028381,-0.0032959,-0.0037842,-0.0040588,-0.0043640,-0.0047607,-0.0046997,-0.0051880,-0.0048523,-0.0051880,-0.0047913,-0.0047302,-0.0043945,-0.0040588,-0.0035706,-0.0030518,-0.0025330,-0.0019836,-0.0015259,-0.0009460,-0.0008545,-0.0003357,-0.0007019,-0.0005798,-0.0010681,-0.0018311,-0.0022888,-0.0033569,-0.0042114,-0.0052185,-0.0063171,-0.0069580,-0.0078735,-0.0082397,-0.0086670,-0.0084839,-0.0083923,-0.0077820,-0.0071716,-0.0063171,-0.0052490,-0.0044250,-0.0033264,-0.0026855,-0.0018616,-0.0015259,-0.0012817,-0.0012512,-0.0015869,-0.0018311,-0.0028076,-0.0032043,-0.0041809,-0.0047913,-0.0053711,-0.0059814,-0.0061646,-0.0062866,-0.0060730,-0.0057068,-0.0050659,-0.0044556,-0.0034485,-0.0027161,-0.0017395,-0.0012207,-0.0004578,-0.0001831,0.0002136,-0.0000916,-0.0001526,-0.0009155,-0.0013733,-0.0024719,-0.0033264,-0.0043640,-0.0053406,-0.0061951,-0.0069275,-0.0075378,-0.0078125,-0.0078735,-0.0076904,-0.0074463,-0.0067749,-0.0061646,-0.0051575,-0.0041809,-0.0032043,-0.0020447,-0.0012817,-0.0001831,0.0004883,0.0010986,0.0015869,0.0018005,0.0020447,0.0019836,0.0018311,0.0017090,0.0013428,0.0013123,0.0009460,0.0010986,0.0010071,0.0012817,0.0015869,0.0020447,0.0029602,0.0035706,0.0046692,0.0054932,0.0066528,0.0076599,0.0087280,0.0094299,0.0101624,0.0105896,0.0108337,0.0108032,0.0105896,0.0099487,0.0094910,0.0084839,0.0076599,0.0065918,0.0057983,0.0048828,0.0041809,0.0038147,0.0033569,0.0034790,0.0036926,0.0039368,0.0048218,0.0053101,0.0065002,0.0070801,0.0081482,0.0086365,0.0093994,0.0097046,0.0097961,0.0097351,0.0091858,0.0088196,0.0078735,0.0071716,0.0060120,0.0051575,0.0041504,0.0034790,0.0026550,0.0024414,0.0019226,0.0021057,0.0021973,0.0026550,0.0031433,0.0037537,0.0043030,0.0047913,0.0053406,0.0054626,0.0055237,0.0053711,0.0048218,0.0041809,0.0029907,0.0021973,0.0005798,-0.0003967,-0.0019531,-0.0029907,-0.0042419,-0.0050964,-0.0056763,-0.0062561,-0.0061646,-0.0062561,-0.0057983,-0.0052490,-0.0046997,-0.0038147,-0.0032959,-0.0023499,-0.0020447,-0.0015259,-0.0012207,-0.0013428,-0.0013733,-0.0017700,-0.0022583,-0.0027771,-0.0034180,-0.0039368,-0.0046997,-0.0048218,-0.0054932,-0.0054626,-0.0056458,-0.0053711,-0.0051270,-0.0049133,-0.0043030,-0.0040283,-0.0034485,-0.0031433,-0.0029297,-0.0026245,-0.0027161,-0.0025940,-0.0030823,-0.0029602,-0.0036926,-0.0037537,-0.0042419,-0.0044250,-0.0046692,-0.0046387,-0.0046692,-0.0043335,-0.0041199,-0.0036011,-0.0029602,-0.0025940,-0.0015564,-0.0013123,-0.0004272,0.0000916,0.0004883,0.0009766,0.0011902,0.0015869,0.0016785,0.0018921,0.0020142,0.0020447,0.0022888,0.0024414,0.0025940,0.0028687,0.0032654,0.0033875,0.0038452,0.0041199,0.0042114,0.0048218,0.0046692,0.0052185,0.0052490,0.0053711,0.0054932,0.0057678,0.0058594,0.0063477,0.0064392,0.0068665,0.0072937,0.0076599,0.0083313,0.0086060,0.0092163,0.0094299,0.0097351,0.0101624,0.0097656,0.0100098,0.0094299,0.0092163,0.0087280,0.0079956,0.0078430,0.0070190,0.0070190,0.0067749,0.0067139,0.0070801,0.0075684,0.0083618,0.0093689,0.0103760,0.0117798,0.0128479,0.0143127,0.0152283,0.0164185,0.0168457,0.0174866,0.0173645,0.0173340,0.0166931,0.0159912,0.0148315,0.0139160,0.0125427,0.0115356,0.0103760,0.0097961,0.0089111,0.0089417,0.0088806,0.0092773,0.0101318,0.0108032,0.0122986,0.0134277,0.0149231,0.0162048,0.0173950,0.0184937,0.0191956,0.0197144,0.0198669,0.0195312,0.0191040,0.0181274,0.0172119,0.0158386,0.0146179,0.0131531,0.0119629,0.0107422,0.0097351,0.0090637,0.0085449,0.0085449,0.0086365,0.0091248,0.0097351,0.0107727,0.0116272,0.0127258,0.0138245,0.0146484,0.0156860,0.0160828,0.0167236,0.0167847,0.0166016,0.0162659,0.0156860,0.0147400,0.0140686,0.0128784,0.0118408,0.0108948,0.0097961,0.0092163,0.0086365,0.0081787,0.0081177,0.0080872,0.0083008,0.0086060,0.0090332,0.0096130,0.0099182,0.0103760,0.0105591,0.0107422,0.0104675,0.0102844,0.0094604,0.0089417,0.0078125,0.0066833,0.0056152,0.0040894,0.0030518,0.0015869,0.0005798,-0.0004578,-0.0010986,-0.0019836,-0.0020752,-0.0026245,-0.0023804,-0.0024414,-0.0020142,-0.0017395,-0.0013123,-0.0009460,-0.0005493,-0.0002441,-0.0000305,0.0000610,-0.0000916,-0.0002441,-0.0007629,-0.0012207,-0.0020142,-0.0026855,-0.0037231,-0.0045166,-0.0054626,-0.0061340,-0.0071411,-0.0073547,-0.0081787,-0.0081787,-0.0084229,-0.0081482,-0.0079041,-0.0074158,-0.0069580,-0.0063477,-0.0057068,-0.0051880,-0.0048218,-0.0043945,-0.0046387,-0.0046082,-0.0051880,-0.0057983,-0.0066833,-0.0078735,-0.0089417,-0.0102844,-0.0115356,-0.0125427,-0.0136719,-0.0142517,-0.0148621,-0.0148010,-0.0147095,-0.0140381,-0.0133667,-0.0121765,-0.0110474,-0.0098267,-0.0084839,-0.0073853,-0.0063782,-0.0057068,-0.0053101,-0.0051270,-0.0053711,-0.0059814,-0.0066528,-0.0077820,-0.0086670,-0.0099487,-0.0107422,-0.0118713,-0.0121460,-0.0128479,-0.0126343,-0.0125427,-0.0119629,-0.0110474,-0.0103455,-0.0088806,-0.0081787,-0.0067749,-0.0062256,-0.0051575,-0.0049133,-0.0045776,-0.0045776,-0.0048218,-0.0053101,-0.0058594,-0.0066223,-0.0072937,-0.0079651,-0.0085754,-0.0088806,-0.0091553,-0.0088806,-0.0088196,-0.0080566,-0.0073547,-0.0064697,-0.0054016,-0.0043945,-0.0032654,-0.0022888,-0.0014954,-0.0005798,-0.0002441,0.0003357,0.0004272,0.0004883,0.0004578,0.0000305,-0.0000610,-0.0005798,-0.0006409,-0.0010986,-0.0010071,-0.0012207,-0.0008545,-0.0007019,-0.0000916,0.0004272,0.0012512,0.0019531,0.0030212,0.0036926,0.0046997,0.0053406,0.0061340,0.0065613,0.0069885,0.0072937,0.0071716,0.0072021,0.0070190,0.0065613,0.0063477,0.0058594,0.0055237,0.0053711,0.0051575,0.0053711,0.0054321,0.0060425,0.0065308,0.0073853,0.0084229,0.0093689,0.0105286,0.0117188,0.0127258,0.0136719,0.0144653,0.0148621,0.0153809,0.0151062,0.0151978,0.0143738,0.0139160,0.0128174,0.0120544,0.0109558,0.0102234,0.0091248,0.0086365,0.0079956,0.0078430,0.0076294,0.0078735,0.0080872,0.0087280,0.0090942,0.0098877,0.0101624,0.0110168,0.0111084,0.0115662,0.0112305,0.0113220,0.0106812,0.0103760,0.0093384,0.0086670,0.0076904,0.0069275,0.0058594,0.0052490,0.0043030,0.0039673,0.0033264,0.0032043,0.0028076,0.0031433,0.0028992,0.0032959,0.0032959,0.0035706,0.0036926,0.0039368,0.0036621,0.0039062,0.0034790,0.0033264,0.0029297,0.0022583,0.0018311,0.0009766,0.0003967,-0.0004883,-0.0011292,-0.0020142,-0.0025635,-0.0032349,-0.0036011,-0.0040283,-0.0042725,-0.0041504,-0.0041504,-0.0036621,-0.0034485,-0.0026550,-0.0021362,-0.0012512,-0.0005798,0.0000610,0.0007019,0.0012512,0.0015259,0.0018311,0.0016174,0.0016479,0.0010376,0.0004883,-0.0003662,-0.0012512,-0.0022583,-0.0030518,-0.0042114,-0.0048828,-0.0056458,-0.0062561,-0.0064697,-0.0068054,-0.0067444,-0.0065613,-0.0064392,-0.0060730,-0.0056458,-0.0055847,-0.0050354,-0.0052490,-0.0049438,-0.0052490,-0.0054626,-0.0058594,-0.0063782,-0.0069580,-0.0074768,-0.0083313,-0.0085449,-0.0091858,-0.0092468,-0.0094910,-0.0093994,-0.0090027,-0.0086670,-0.0079346,-0.0074463,-0.0064392,-0.0059204,-0.0049133,-0.0043640,-0.0036316,-0.0031433,-0.0028992,-0.0025635,-0.0024414,-0.0026550,-0.0026245,-0.0029297,-0.0031128,-0.0034180,-0.0036926,-0.0038452,-0.0040894,-0.0040894,-0.0041199,-0.0040894,-0.0038452,-0.0037537,-0.0034485,-0.0032959,-0.0029602,-0.0028381,-0.0025940,-0.0025330,-0.0026245,-0.0026550,-0.0027161,-0.0032043,-0.0033875,-0.0039673,-0.0042725,-0.0048218,-0.0051880,-0.0055847,-0.0059509,-0.0060120,-0.0062256,-0.0059814,-0.0057983,-0.0053711,-0.0047607,-0.0041809,-0.0032349,-0.0023499,-0.0015259,-0.0003357,0.0002747,0.0014343,0.0018616,0.0026855,0.0029907,0.0033264,0.0033569,0.0033569,0.0033264,0.0030212,0.0029297,0.0024414,0.0025330,0.0023193,0.0022888,0.0025330,0.0025635,0.0031128,0.0034485,0.0039673,0.0044250,0.0050964,0.0054016,0.0059204,0.0060120,0.0061646,0.0061646,0.0058289,0.0056152,0.0050049,0.0044861,0.0037231,0.0031738,0.0023193,0.0019226,0.0012512,0.0009766,0.0007019,0.0005798,0.0007019,0.0006409,0.0012207,0.0011597,0.0018005,0.0017700,0.0024109,0.0024109,0.0026245,0.0027161,0.0026245,0.0024719,0.0024109,0.0019226,0.0018311,0.0014038,0.0011902,0.0007935,0.0006409,0.0002441,0.0001526,-0.0001221,-0.0002747,-0.0005493,-0.0007019,-0.0009460,-0.0013733,-0.0015564,-0.0021057,-0.0023499,-0.0030823,-0.0032654,-0.0040588,-0.0043335,-0.0049133,-0.0054016,-0.0057678,-0.0062866,-0.0065918,-0.0069275,-0.0072937,-0.0074463,-0.0080261,-0.0080261,-0.0086060,-0.0086975,-0.0089722,-0.0096436,-0.0093994,-0.0102234,-0.0100403,-0.0104980,-0.0104675,-0.0106506,-0.0105286,-0.0105591,-0.0101624,-0.0099792,-0.0097656,-0.0092163,-0.0090637,-0.0084839,-0.0084229,-0.0081177,-0.0081177,-0.0079041,-0.0081482,-0.0082703,-0.0086060,-0.0090637,-0.0095215,-0.0101929,-0.0105896,-0.0114441,-0.0115967,-0.0123901,-0.0124512,-0.0128479,-0.0128479,-0.0128174,-0.0125732,-0.0122375,-0.0117188,-0.0111389,-0.0105591,-0.0096436,-0.0090637,-0.0082397,-0.0076294,-0.0070801,-0.0065308,-0.0061035,-0.0058899,-0.0056763,-0.0055847,-0.0057983,-0.0055847,-0.0059814,-0.0057983,-0.0062561,-0.0060120,-0.0064087,-0.0059509,-0.0061951,-0.0055237,-0.0055237,-0.0046997,-0.0045166,-0.0035400,-0.0030823,-0.0021667,-0.0015869,-0.0007324,-0.0003357,0.0003662,0.0007324,0.0011292,0.0014648,0.0013733,0.0015259,0.0012817,0.0013733,0.0007629,0.0007324,0.0000916,-0.0000305,-0.0004883,-0.0008240,-0.0009460,-0.0013123,-0.0012817,-0.0013733,-0.0013123,-0.0011292,-0.0009460,-0.0005493,-0.0001526,0.0002747,0.0008240,0.0012817,0.0017395,0.0022888,0.0026855,0.0030518,0.0033264,0.0034790,0.0036011,0.0036011,0.0033569,0.0035095,0.0028992,0.0030518,0.0023804,0.0025940,0.0021973,0.0022278,0.0025635,0.0026245,0.0034180,0.0039062,0.0047607,0.0057983,0.0068054,0.0079651,0.0091248,0.0102844,0.0112610,0.0121460,0.0128784,0.0133362,0.0135498,0.0136719,0.0132751,0.0130920,0.0123901,0.0116882,0.0110474,0.0102844,0.0097351,0.0090942,0.0088806,0.0086365,0.0087280,0.0087891,0.0094299,0.0096436,0.0103760,0.0108643,0.0115051,0.0120544,0.0122986,0.0126343,0.0126038,0.0124817,0.0121460,0.0116577,0.0109253,0.0103760,0.0095520,0.0088196,0.0082703,0.0075073,0.0072937,0.0068359,0.0067749,0.0066833,0.0068359,0.0071106,0.0073547,0.0076904,0.0078735,0.0080566,0.0082703,0.0081177,0.0080872,0.0076294,0.0072021,0.0065308,0.0058594,0.0048523,0.0041504,0.0030212,0.0022278,0.0013428,0.0004883,-0.0002441,-0.0008850,-0.0014954,-0.0018616,-0.0021973,-0.0026855,-0.0027466,-0.0030823,-0.0031738,-0.0035400,-0.0036926,-0.0042725,-0.0045166,-0.0049744,-0.0057678,-0.0061340,-0.0071411,-0.0076294,-0.0086975,-0.0090332,-0.0100708,-0.0104065,-0.0110168,-0.0114746,-0.0115356,-0.0117493,-0.0116882,-0.0114441,-0.0111389,-0.0107422,-0.0101318,-0.0098267,-0.0092163,-0.0088196,-0.0085449,-0.0081787,-0.0082703,-0.0082092,-0.0085449,-0.0088501,-0.0094604,-0.0100403,-0.0108643,-0.0115356,-0.0123596,-0.0130920,-0.0137634,-0.0143738,-0.0148621,-0.0151367,-0.0154724,-0.0154724,-0.0155334,-0.0154419,-0.0152588,-0.0151672,-0.0151978,-0.0150452,-0.0152588,-0.0154419,-0.0156555,-0.0162659,-0.0166016,-0.0174255,-0.0179138,-0.0186157,-0.0193176,-0.0197449,-0.0203552,-0.0204773,-0.0207214,-0.0205383,-0.0204468,-0.0199585,-0.0193787,-0.0187073,-0.0178833,-0.0170288,-0.0162048,-0.0151672,-0.0143738,-0.0137024,-0.0129089,-0.0126648,-0.0120850,-0.0118408,-0.0118408,-0.0117798,-0.0119934,-0.0121155,-0.0124207,-0.0129089,-0.0130920,-0.0135498,-0.0137634,-0.0139771,-0.0144653,-0.0142822,-0.0147095,-0.0145569,-0.0145874,-0.0145569,-0.0143127,-0.0143738,-0.0140686,-0.0140991,-0.0139771,-0.0139465,-0.0139771,-0.0139771,-0.0142212,-0.0140991,-0.0146179,-0.0143127,-0.0149536,-0.0144653,-0.0147705,-0.0143738,-0.0141296,-0.0136719,-0.0129700,-0.0122375,-0.0112915,-0.0103760,-0.0090332,-0.0081787,-0.0068359,-0.0060730,-0.0049133,-0.0042725,-0.0035706,-0.0031433,-0.0028687,-0.0027771,-0.0027771,-0.0030212,-0.0030212,-0.0034790,-0.0032349,-0.0036926,-0.0034180,-0.0033875,-0.0028076,-0.0024109,-0.0016479,-0.0008545,-0.0001526,0.0009766,0.0016174,0.0026550,0.0032654,0.0036926,0.0042114,0.0041809,0.0043030,0.0039978,0.0038757,0.0033264,0.0032654,0.0027466,0.0025635,0.0025635,0.0025024,0.0028687,0.0034180,0.0039368,0.0050964,0.0059204,0.0072632,0.0082397,0.0097351,0.0106506,0.0119019,0.0129700,0.0137634,0.0145569,0.0149841,0.0155945,0.0156555,0.0161438,0.0159607,0.0160522,0.0159912,0.0158081,0.0158386,0.0154724,0.0153198,0.0152283,0.0146790,0.0145874,0.0138855,0.0137329,0.0129089,0.0125122,0.0116882,0.0110779,0.0101929,0.0096741,0.0087280,0.0082703,0.0074768,0.0070496,0.0065918,0.0061646,0.0061646,0.0057068,0.0058289,0.0055847,0.0056763,0.0056458,0.0056458,0.0056152,0.0054321,0.0054016,0.0049133,0.0048523,0.0043335,0.0039978,0.0035095,0.0030212,0.0025940,0.0021057,0.0015869,0.0013733,0.0010071,0.0005798,0.0005493,0.0001221,0.0001831,-0.0000305,0.0001831,-0.0000916,0.0002747,0.0000305,0.0003357,0.0001831,0.0004272,0.0000916,0.0003357,-0.0001221,-0.0000916,-0.0004578,-0.0009155,-0.0012207,-0.0017700,-0.0024719,-0.0028992,-0.0037842,-0.0044861,-0.0051575,-0.0057983,-0.0066223,-0.0069580,-0.0076294,-0.0078735,-0.0083008,-0.0083618,-0.0086670,-0.0085449,-0.0083923,-0.0084229,-0.0080872,-0.0079346,-0.0076904,-0.0074463,-0.0073242,-0.0070496,-0.0071411,-0.0068054,-0.0072021,-0.0069885,-0.0074158,-0.0075684,-0.0078430,-0.0081787,-0.0083618,-0.0089111,-0.0089417,-0.0095215,-0.0094910,-0.0100098,-0.0101013,-0.0102539,-0.0104065,-0.0104065,-0.0104065,-0.0105896,-0.0104370,-0.0104370,-0.0104980,-0.0104065,-0.0104980,-0.0104065,-0.0104065,-0.0105591,-0.0105591,-0.0108032,-0.0109558,-0.0111694,-0.0115662,-0.0117493,-0.0122375,-0.0122986,-0.0128174,-0.0129089,-0.0132141,-0.0132446,-0.0134583,-0.0131226,-0.0132446,-0.0127258,-0.0125732,-0.0120850,-0.0115356,-0.0112000,-0.0104675,-0.0101318,-0.0095215,-0.0090027,-0.0089722,-0.0083618,-0.0086060,-0.0083618,-0.0086060,-0.0086975,-0.0090637,-0.0093384,-0.0097351,-0.0101013,-0.0103455,-0.0107422,-0.0106812,-0.0107727,-0.0105591,-0.0102844,-0.0097656,-0.0090332,-0.0084534,-0.0071716,-0.0066833,-0.0050964,-0.0046082,-0.0033264,-0.0025940,-0.0019226,-0.0012207,-0.0012512,-0.0007935,-0.0010681,-0.0012512,-0.0017090,-0.0021973,-0.0027771,-0.0033264,-0.0038147,-0.0041504,-0.0042725,-0.0041199,-0.0038452,-0.0030823,-0.0023804,-0.0009460,0.0003052,0.0019226,0.0035706,0.0052795,0.0066833,0.0083008,0.0092773,0.0104675,0.0108643,0.0111694,0.0114136,0.0108032,0.0105896,0.0096741,0.0091248,0.0082092,0.0075684,0.0071716,0.0068054,0.0071106,0.0071716,0.0080566,0.0089111,0.0101318,0.0116272,0.0126953,0.0144653,0.0153503,0.0169373,0.0172729,0.0182190,0.0180359,0.0178833,0.0172729,0.0162354,0.0152283,0.0136414,0.0123901,0.0107117,0.0094910,0.0083923,0.0072327,0.0069885,0.0064087,0.0066528,0.0069275,0.0075073,0.0085144,0.0093384,0.0105591,0.0115356,0.0124207,0.0132751,0.0135803,0.0140991,0.0138245,0.0137329,0.0128784,0.0122070,0.0109558,0.0098877,0.0085449,0.0072632,0.0059509,0.0048523,0.0038147,0.0030823,0.0024719,0.0021973,0.0021667,0.0022888,0.0028992,0.0034180,0.0042419,0.0049744,0.0059814,0.0067139,0.0077209,0.0081787,0.0087280,0.0089417,0.0087585,0.0086975,0.0079956,0.0072632,0.0063171,0.0049744,0.0037231,0.0023804,0.0009460,-0.0002136,-0.0012207,-0.0022583,-0.0026245,-0.0030518,-0.0028992,-0.0025330,-0.0019226,-0.0008850,0.0001221,0.0013428,0.0026855,0.0038147,0.0049438,0.0057068,0.0061951,0.0064697,0.0063171,0.0057678,0.0049438,0.0039368,0.0024719,0.0012817,-0.0005798,-0.0018311,-0.0033569,-0.0044556,-0.0054016,-0.0060120,-0.0062866,-0.0062561,-0.0058594,-0.0053406,-0.0044556,-0.0036621,-0.0026550,-0.0017395,-0.0009460,-0.0002747,0.0001221,0.0003052,0.0000916,-0.0002747,-0.0008545,-0.0017395,-0.0026855,-0.0038147,-0.0047913,-0.0059509,-0.0066223,-0.0075073,-0.0077515,-0.0080872,-0.0079346,-0.0076904,-0.0070496,-0.0063782,-0.0054016,-0.0046082,-0.0034790,-0.0026550,-0.0020752,-0.0011597,-0.0010681,-0.0007019,-0.0010376,-0.0010986,-0.0018921,-0.0022888,-0.0032654,-0.0040283,-0.0048218,-0.0055542,-0.0061951,-0.0066223,-0.0067749,-0.0068054,-0.0064087,-0.0060730,-0.0053406,-0.0045776,-0.0036011,-0.0027466,-0.0016785,-0.0010986,-0.0003052,0.0002136,0.0004883,0.0005493,0.0004578,0.0001831,-0.0002747,-0.0007019,-0.0013733,-0.0017395,-0.0021362,-0.0024719,-0.0024109,-0.0024109,-0.0019531,-0.0015564,-0.0007935,0.0000916,0.0007629,0.0021057,0.0026855,0.0039368,0.0043335,0.0051575,0.0053711,0.0057678,0.0054321,0.0055847,0.0049438,0.0045471,0.0040588,0.0032959,0.0030518,0.0023499,0.0021973,0.0020447,0.0019226,0.0021667,0.0024719,0.0029907,0.0035706,0.0043030,0.0048523,0.0060120,0.0063477,0.0073242,0.0078125,0.0082397,0.0086670,0.0088501,0.0089417,0.0088806,0.0086975,0.0083618,0.0082703,0.0075684,0.0075684,0.0069580,0.0066223,0.0063782,0.0058594,0.0058289,0.0056458,0.0055847,0.0056763,0.0056458,0.0059509,0.0060425,0.0063477,0.0065918,0.0068359,0.0068359,0.0072632,0.0067749,0.0072021,0.0065918,0.0066223,0.0059204,0.0055847,0.0048523,0.0041809,0.0035095,0.0026550,0.0021057,0.0013123,0.0008850,0.0002441,0.0001831,-0.0002747,-0.0001221,-0.0002747,0.0000000,0.0001526,0.0003967,0.0006104,0.0007019,0.0008545,0.0007629,0.0007019,0.0002441,0.0001221,-0.0007935,-0.0010986,-0.0020142,-0.0024719,-0.0032349,-0.0038452,-0.0043640,-0.0048828,-0.0050354,-0.0052185,-0.0051270,-0.0049744,-0.0046387,-0.0044250,-0.0037842,-0.0037842,-0.0028992,-0.0031738,-0.0025024,-0.0027771,-0.0027161,-0.0026550,-0.0032349,-0.0032959,-0.0039673,-0.0040894,-0.0046387,-0.0049438,-0.0052185,-0.0053711,-0.0053101,-0.0053406,-0.0050049,-0.0047607,-0.0041504,-0.0037537,-0.0030823,-0.0024719,-0.0019531,-0.0012207,-0.0009155,-0.0003052,0.0000305,0.0000305,0.0003052,0.0001221,0.0000916,0.0000000,-0.0004883,-0.0006104,-0.0010071,-0.0012512,-0.0014648,-0.0017090,-0.0016479,-0.0018005,-0.0014038,-0.0012512,-0.0008240,-0.0002136,0.0001831,0.0011902,0.0016174,0.0025940,0.0033569,0.0039062,0.0049744,0.0052185,0.0060730,0.0061035,0.0068054,0.0065918,0.0069275,0.0067444,0.0065918,0.0063477,0.0061035,0.0057678,0.0053711,0.0052185,0.0048218,0.0047607,0.0047607,0.0044556,0.0048523,0.0047607,0.0052490,0.0055237,0.0058594,0.0064392,0.0067444,0.0072632,0.0076904,0.0080566,0.0085144,0.0084839,0.0090027,0.0087891,0.0090942,0.0090027,0.0090637,0.0089417,0.0090637,0.0088501,0.0091553,0.0089722,0.0094910,0.0094299,0.0100403,0.0104065,0.0110168,0.0116577,0.0122681,0.0130310,0.0137939,0.0144043,0.0150452,0.0156860,0.0161743,0.0166016,0.0166931,0.0170288,0.0169678,0.0170593,0.0167236,0.0167542,0.0163879,0.0162964,0.0160522,0.0159302,0.0159912,0.0158997,0.0162354,0.0162048,0.0166626,0.0170288,0.0172424,0.0178528,0.0178223,0.0183716,0.0182190,0.0185852,0.0182800,0.0182800,0.0178223,0.0175476,0.0170288,0.0165710,0.0161133,0.0154724,0.0152283,0.0148315,0.0146179,0.0145569,0.0143433,0.0147705,0.0145569,0.0151062,0.0150452,0.0153503,0.0153503,0.0152588,0.0151978,0.0147400,0.0143127,0.0136108,0.0129395,0.0119934,0.0113220,0.0102844,0.0094604,0.0088196,0.0082397,0.0077820,0.0077209,0.0075073,0.0077515,0.0082092,0.0082703,0.0091858,0.0093384,0.0100403,0.0102844,0.0106812,0.0104675,0.0108032,0.0101013,0.0098572,0.0089722,0.0082092,0.0071716,0.0061340,0.0049744,0.0038757,0.0028076,0.0019836,0.0010681,0.0006409,-0.0000610,0.0001221,-0.0002136,0.0002441,0.0003052,0.0010681,0.0012817,0.0023499,0.0025024,0.0035095,0.0036316,0.0043335,0.0043335,0.0045166,0.0042114,0.0040588,0.0032349,0.0027161,0.0014038,0.0006104,-0.0008240,-0.0018921,-0.0031738,-0.0044556,-0.0054626,-0.0065308,-0.0071411,-0.0076904,-0.0079346,-0.0078735,-0.0078125,-0.0071716,-0.0067139,-0.0058594,-0.0050049,-0.0042419,-0.0035400,-0.0028687,-0.0025940,-0.0022278,-0.0024719,-0.0026245,-0.0033875,-0.0039673,-0.0050354,-0.0058594,-0.0069885,-0.0079346,-0.0087280,-0.0093079,-0.0097961,-0.0097351,-0.0096741,-0.0091248,-0.0085449,-0.0074463,-0.0066528,-0.0053406,-0.0045166,-0.0033875,-0.0027771,-0.0022583,-0.0021057,-0.0021667,-0.0027466,-0.0033264,-0.0044861,-0.0054626,-0.0070801,-0.0082092,-0.0097046,-0.0107422,-0.0117798,-0.0125122,-0.0129395,-0.0130310,-0.0128479,-0.0121765,-0.0116272,-0.0105286,-0.0096130,-0.0083923,-0.0074158,-0.0063171,-0.0055237,-0.0049438,-0.0043640,-0.0044861,-0.0042725,-0.0047302,-0.0050354,-0.0057373,-0.0062256,-0.0069580,-0.0073242,-0.0078125,-0.0081177,-0.0081177,-0.0078430,-0.0073547,-0.0067749,-0.0056458,-0.0048828,-0.0035095,-0.0023804,-0.0010681,0.0000916,0.0012207,0.0019531,0.0029297,0.0030212,0.0035400,0.0033875,0.0032654,0.0027466,0.0022583,0.0015564,0.0010986,0.0003052,0.0000000,-0.0005493,-0.0005188,-0.0006104,-0.0002441,0.0003357,0.0010071,0.0020447,0.0030518,0.0043030,0.0055847,0.0066833,0.0079956,0.0087280,0.0097961,0.0101013,0.0108643,0.0106506,0.0107727,0.0104370,0.0099182,0.0096130,0.0087585,0.0082703,0.0076904,0.0071716,0.0069275,0.0065918,0.0067139,0.0067749,0.0071716,0.0076599,0.0081177,0.0089417,0.0092773,0.0102539,0.0105286,0.0111694,0.0113831,0.0115662,0.0115967,0.0114136,0.0108643,0.0106201,0.0097046,0.0092468,0.0084839,0.0076294,0.0071106,0.0063477,0.0060120,0.0055542,0.0054016,0.0051575,0.0054932,0.0053711,0.0060120,0.0061340,0.0068359,0.0070801,0.0077820,0.0079651,0.0083923,0.0085754,0.0085449,0.0086060,0.0082397,0.0079346,0.0072327,0.0066833,0.0059814,0.0050964,0.0043335,0.0035095,0.0027771,0.0021667,0.0016479,0.0012817,0.0011597,0.0010681,0.0011902,0.0014038,0.0018005,0.0021362,0.0027161,0.0028992,0.0034790,0.0035095,0.0038147,0.0036011,0.0035400,0.0031128,0.0027771,0.0020752,0.0014648,0.0006409,-0.0000916,-0.0007019,-0.0014343,-0.0018921,-0.0021362,-0.0024109,-0.0024719,-0.0020752,-0.0021362,-0.0014343,-0.0010986,-0.0006104,0.0000000,0.0003967,0.0008545,0.0010376,0.0012207,0.0010681,0.0008850,0.0007019,0.0000916,-0.0003967,-0.0010376,-0.0018311,-0.0025330,-0.0032349,-0.0038452,-0.0044250,-0.0048218,-0.0051575,-0.0054626,-0.0054626,-0.0054321,-0.0053101,-0.0050049,-0.0048523,-0.0044250,-0.0040894,-0.0037231,-0.0032654,-0.0030823,-0.0027161,-0.0025330,-0.0024719,-0.0023499,-0.0025940,-0.0026550,-0.0029297,-0.0033569,-0.0037231,-0.0042725,-0.0047913,-0.0051880,-0.0057373,-0.0060120,-0.0064392,-0.0065308,-0.0064392,-0.0064087,-0.0059814,-0.0055542,-0.0049744,-0.0041809,-0.0034180,-0.0027161,-0.0018921,-0.0014648,-0.0008850,-0.0006104,-0.0006104,-0.0007019,-0.0012207,-0.0018616,-0.0027161,-0.0036621,-0.0048523,-0.0058594,-0.0069275,-0.0078735,-0.0084839,-0.0090637,-0.0091248,-0.0091248,-0.0084839,-0.0079346,-0.0067139,-0.0057068,-0.0042419,-0.0028992,-0.0015564,-0.0002136,0.0007019,0.0017700,0.0020752,0.0026245,0.0024719,0.0024414,0.0020447,0.0014038,0.0008240,0.0002747,-0.0003662,-0.0007324,-0.0009155,-0.0010681,-0.0006714,-0.0003357,0.0003357,0.0010681,0.0021057,0.0028992,0.0039368,0.0048218,0.0054321,0.0060730,0.0064087,0.0064697,0.0066223,0.0060120,0.0059509,0.0050659,0.0047302,0.0039978,0.0034790,0.0028992,0.0026245,0.0023193,0.0023499,0.0021973,0.0026550,0.0027161,0.0032959,0.0036926,0.0040894,0.0047913,0.0050354,0.0055237,0.0057373,0.0059509,0.0059509,0.0060425,0.0058899,0.0056763,0.0055237,0.0051270,0.0048523,0.0044250,0.0041199,0.0035706,0.0032043,0.0025635,0.0021362,0.0014954,0.0008545,0.0003052,-0.0004578,-0.0010376,-0.0017090,-0.0024414,-0.0029602,-0.0037842,-0.0040283,-0.0048523,-0.0049133,-0.0054932,-0.0055237,-0.0057373,-0.0059509,-0.0059204,-0.0061035,-0.0060730,-0.0062561,-0.0063477,-0.0067139,-0.0069275,-0.0072327,-0.0076599,-0.0079651,-0.0083618,-0.0086670,-0.0090637,-0.0090942,-0.0093689,-0.0091553,-0.0090637,-0.0087891,-0.0082092,-0.0078735,-0.0072021,-0.0066528,-0.0060120,-0.0054626,-0.0050964,-0.0046692,-0.0045471,-0.0044556,-0.0045776,-0.0048828,-0.0051880,-0.0056458,-0.0062866,-0.0066528,-0.0074768,-0.0078125,-0.0083008,-0.0087585,-0.0089722,-0.0090942,-0.0092163,-0.0092163,-0.0091248,-0.0090637,-0.0088501,-0.0088196,-0.0086975,-0.0087891,-0.0088806,-0.0090332,-0.0094604,-0.0097351,-0.0101318,-0.0107422,-0.0110474,-0.0116272,-0.0119629,-0.0122070,-0.0123596,-0.0124512,-0.0122375,-0.0120544,-0.0115662,-0.0110168,-0.0104065,-0.0096130,-0.0089111,-0.0082092,-0.0072632,-0.0067444,-0.0059814,-0.0054932,-0.0051270,-0.0048218,-0.0045776,-0.0046692,-0.0044861,-0.0049744,-0.0048523,-0.0051880,-0.0054321,-0.0056152,-0.0059814,-0.0059814,-0.0062866,-0.0061646,-0.0062256,-0.0061035,-0.0059204,-0.0057678,-0.0052185,-0.0050659,-0.0044861,-0.0039978,-0.0036316,-0.0029907,-0.0027161,-0.0020752,-0.0018005,-0.0013733,-0.0011902,-0.0009155,-0.0007629,-0.0006714,-0.0005798,-0.0006714,-0.0004883,-0.0008240,-0.0005493,-0.0008545,-0.0006104,-0.0007629,-0.0004578,-0.0004883,-0.0000610,0.0000610,0.0004578,0.0007935,0.0012512,0.0014954,0.0019836,0.0021973,0.0025940,0.0027466,0.0031128,0.0030212,0.0033875,0.0031433,0.0034180,0.0032043,0.0033569,0.0032043,0.0034485,0.0033264,0.0036316,0.0036621,0.0041504,0.0043335,0.0049133,0.0052490,0.0057373,0.0063171,0.0067139,0.0074158,0.0077515,0.0081482,0.0086365,0.0088501,0.0093689,0.0095215,0.0097961,0.0099182,0.0102844,0.0103149,0.0106506,0.0108643,0.0110474,0.0113831,0.0116272,0.0117798,0.0123901,0.0123596,0.0128784,0.0128479,0.0130615,0.0130615,0.0130310,0.0127869,0.0124207,0.0120544,0.0112915,0.0110779,0.0100403,0.0095215,0.0086670,0.0078430,0.0073853,0.0065918,0.0063477,0.0056763,0.0057068,0.0053101,0.0056152,0.0054016,0.0057678,0.0057678,0.0060730,0.0061951,0.0063782,0.0064697,0.0062561,0.0063171,0.0056763,0.0055542,0.0047913,0.0042114,0.0032654,0.0025330,0.0015869,0.0007019,-0.0000916,-0.0010071,-0.0014648,-0.0023193,-0.0025635,-0.0031433,-0.0032043,-0.0035706,-0.0034180,-0.0036926,-0.0035095,-0.0037231,-0.0036316,-0.0038452,-0.0038147,-0.0041809,-0.0043945,-0.0048218,-0.0052490,-0.0056763,-0.0063782,-0.0066528,-0.0074463,-0.0078125,-0.0083313,-0.0086670,-0.0091553,-0.0092163,-0.0096436,-0.0095215,-0.0097351,-0.0096741,-0.0095520,-0.0095215,-0.0093994,-0.0094299,-0.0092163,-0.0093994,-0.0093689,-0.0092773,-0.0098267,-0.0097656,-0.0101929,-0.0104980,-0.0108337,-0.0114746,-0.0118408,-0.0123596,-0.0127258,-0.0133057,-0.0137939,-0.0141602,-0.0147400,-0.0148010,-0.0152588,-0.0153809,-0.0155945,-0.0156555,-0.0156860,-0.0155640,-0.0156250,-0.0154419,-0.0154419,-0.0152588,-0.0152588,-0.0151367,-0.0151367,-0.0152893,-0.0151978,-0.0153809,-0.0155029,-0.0156250,-0.0160217,-0.0159912,-0.0162354,-0.0162964,-0.0165710,-0.0163879,-0.0166016,-0.0164490,-0.0163574,-0.0163269,-0.0160217,-0.0160522,-0.0156555,-0.0157471,-0.0155029,-0.0155945,-0.0156555,-0.0155640,-0.0160522,-0.0159302,-0.0163879,-0.0163879,-0.0168762,-0.0169067,-0.0171204,-0.0171204,-0.0172424,-0.0170593,-0.0170593,-0.0166931,-0.0165710,-0.0161743,-0.0159302,-0.0155640,-0.0154114,-0.0150452,-0.0149841,-0.0146790,-0.0147095,-0.0144958,-0.0143433,-0.0142822,-0.0141907,-0.0138855,-0.0137329,-0.0132446,-0.0127869,-0.0124207,-0.0115356,-0.0110474,-0.0100708,-0.0095215,-0.0085144,-0.0079346,-0.0071411,-0.0067139,-0.0060425,-0.0059204,-0.0052185,-0.0055542,-0.0052185,-0.0054016,-0.0055237,-0.0054626,-0.0058899,-0.0055847,-0.0059204,-0.0056458,-0.0055847,-0.0051880,-0.0049438,-0.0041504,-0.0037842,-0.0028381,-0.0022278,-0.0013428,-0.0004272,0.0002747,0.0012512,0.0018311,0.0026245,0.0031738,0.0037231,0.0041504,0.0045166,0.0047302,0.0051880,0.0051880,0.0054932,0.0055542,0.0056763,0.0060425,0.0059509,0.0063782,0.0064697,0.0068665,0.0071716,0.0075378,0.0079041,0.0083618,0.0085754,0.0092163,0.0093384,0.0096741,0.0098877,0.0100708,0.0100708,0.0100708,0.0101013,0.0095825,0.0096130,0.0089722,0.0086365,0.0083313,0.0077820,0.0074158,0.0071411,0.0067444,0.0066528,0.0065002,0.0065002,0.0066223,0.0066833,0.0069275,0.0072632,0.0074463,0.0076599,0.0080261,0.0078430,0.0081177,0.0078125,0.0078125,0.0073853,0.0068970,0.0065308,0.0057678,0.0051880,0.0043335,0.0038452,0.0030518,0.0025330,0.0020142,0.0015259,0.0012207,0.0010681,0.0008545,0.0008240,0.0009460,0.0008850,0.0011292,0.0012207,0.0013123,0.0014038,0.0014954,0.0013123,0.0015564,0.0009460,0.0011597,0.0003662,0.0003052,-0.0003662,-0.0008240,-0.0014343,-0.0019531,-0.0026245,-0.0032349,-0.0037231,-0.0043335,-0.0045776,-0.0050659,-0.0050354,-0.0054626,-0.0051270,-0.0051880,-0.0046692,-0.0044556,-0.0039978,-0.0034485,-0.0030518,-0.0026245,-0.0022583,-0.0020752,-0.0020142,-0.0019836,-0.0025024,-0.0027771,-0.0036621,-0.0042114,-0.0053711,-0.0062561,-0.0072021,-0.0082703,-0.0088806,-0.0097961,-0.0102539,-0.0104370,-0.0105896,-0.0104065,-0.0100098,-0.0094604,-0.0087891,-0.0081482,-0.0072021,-0.0067444,-0.0061035,-0.0056458,-0.0057678,-0.0055847,-0.0059814,-0.0065918,-0.0072632,-0.0083313,-0.0092468,-0.0104675,-0.0113831,-0.0125122,-0.0132751,-0.0139771,-0.0144348,-0.0147095,-0.0146179,-0.0144958,-0.0142517,-0.0135193,-0.0132446,-0.0123596,-0.0119629,-0.0113831,-0.0109863,-0.0104980,-0.0106201,-0.0102234,-0.0105896,-0.0105896,-0.0110168,-0.0112305,-0.0115662,-0.0118713,-0.0120850,-0.0122681,-0.0122986,-0.0122375,-0.0121765,-0.0117188,-0.0115356,-0.0110168,-0.0104980,-0.0101318,-0.0095215,-0.0091858,-0.0085754,-0.0085144,-0.0080566,-0.0079651,-0.0079956,-0.0076904,-0.0081177,-0.0078735,-0.0082703,-0.0082397,-0.0085754,-0.0084534,-0.0087585,-0.0086365,-0.0087891,-0.0086060,-0.0084534,-0.0080872,-0.0077820,-0.0071716,-0.0066528,-0.0059204,-0.0051880,-0.0043335,-0.0035095,-0.0024719,-0.0018921,-0.0007935,-0.0002136,0.0005798,0.0012512,0.0014343,0.0021057,0.0021057,0.0023499,0.0023193,0.0022583,0.0020752,0.0020752,0.0018921,0.0020142,0.0017700,0.0022583,0.0020752,0.0028076,0.0029907,0.0037842,0.0042725,0.0050964,0.0057678,0.0065308,0.0072327,0.0077820,0.0083008,0.0087280,0.0089722,0.0090332,0.0089722,0.0087585,0.0086060,0.0081787,0.0079651,0.0075073,0.0072632,0.0071411,0.0068665,0.0070801,0.0070190,0.0073547,0.0077515,0.0080566,0.0086975,0.0091858,0.0096130,0.0101929,0.0104370,0.0108643,0.0108948,0.0110474,0.0107422,0.0106812,0.0101929,0.0099487,0.0093689,0.0088196,0.0084534,0.0078735,0.0076599,0.0073242,0.0070801,0.0070801,0.0069885,0.0072021,0.0072632,0.0075378,0.0076599,0.0079346,0.0078125,0.0079956,0.0077820,0.0075684,0.0074768,0.0068054,0.0066223,0.0058594,0.0053101,0.0048218,0.0041809,0.0037842,0.0033875,0.0030518,0.0029602,0.0029602,0.0028992,0.0032349,0.0033264,0.0037231,0.0040283,0.0043335,0.0046082,0.0047302,0.0049744,0.0047607,0.0048828,0.0043030,0.0043335,0.0035706,0.0032043,0.0027161,0.0020752,0.0016785,0.0012207,0.0008850,0.0007935,0.0006409,0.0006409,0.0008850,0.0010986,0.0015259,0.0018921,0.0022583,0.0027771,0.0029602,0.0033569,0.0034180,0.0035400,0.0032959,0.0029602,0.0025940,0.0018005,0.0013123,0.0004272,-0.0004272,-0.0010376,-0.0020142,-0.0025330,-0.0032043,-0.0035095,-0.0039978,-0.0039673,-0.0041199,-0.0039062,-0.0036011,-0.0033875,-0.0028992,-0.0024719,-0.0022278,-0.0016479,-0.0016785,-0.0013123,-0.0015259,-0.0014343,-0.0019226,-0.0021057,-0.0027771,-0.0030823,-0.0038452,-0.0041809,-0.0047607,-0.0051575,-0.0053406,-0.0055237,-0.0055847,-0.0053711,-0.0053406,-0.0047607,-0.0043945,-0.0039978,-0.0033569,-0.0031128,-0.0024109,-0.0024414,-0.0020142,-0.0022583,-0.0021362,-0.0024719,-0.0029602,-0.0033875,-0.0039368,-0.0046387,-0.0050354,-0.0059204,-0.0062256,-0.0065918,-0.0071716,-0.0068970,-0.0072937,-0.0066833,-0.0066223,-0.0059509,-0.0054016,-0.0047607,-0.0038147,-0.0031738,-0.0023499,-0.0015259,-0.0009155,-0.0003357,0.0003052,0.0005188,0.0007935,0.0010986,0.0007935,0.0010376,0.0006409,0.0005493,0.0003967,-0.0000916,0.0001526,-0.0003662,0.0000610,-0.0002136,0.0002747,0.0006104,0.0009460,0.0017395,0.0023499,0.0029602,0.0041504,0.0045471,0.0056763,0.0061035,0.0067749,0.0071716,0.0076294,0.0075989,0.0077209,0.0075073,0.0073242,0.0067749,0.0063782,0.0057983,0.0053101,0.0048828,0.0044250,0.0041199,0.0039673,0.0040283,0.0041809,0.0044556,0.0047607,0.0053101,0.0057678,0.0063171,0.0066528,0.0072327,0.0071716,0.0075684,0.0073242,0.0074158,0.0068665,0.0066528,0.0060425,0.0054321,0.0049744,0.0043030,0.0039978,0.0034485,0.0033569,0.0029602,0.0032959,0.0029907,0.0034790,0.0033875,0.0038147,0.0040283,0.0040283,0.0044861,0.0039978,0.0042419,0.0036011,0.0034790,0.0028687,0.0021362,0.0015564,0.0005798,0.0000305,-0.0007629,-0.0014954,-0.0020447,-0.0024719,-0.0028076,-0.0027466,-0.0029602,-0.0026550,-0.0024719,-0.0018921,-0.0015259,-0.0008240,-0.0004272,0.0002747,0.0007629,0.0011902,0.0014954,0.0018616,0.0018616,0.0019836,0.0016174,0.0015564,0.0010376,0.0006104,0.0001526,-0.0005493,-0.0009460,-0.0015869,-0.0018921,-0.0023499,-0.0025940,-0.0028076,-0.0028687,-0.0027161,-0.0025635,-0.0022888,-0.0021057,-0.0016174,-0.0013733,-0.0010681,-0.0008850,-0.0008240,-0.0007629,-0.0010376,-0.0011902,-0.0018005,-0.0021057,-0.0028687,-0.0033875,-0.0040894,-0.0046692,-0.0051575,-0.0056152,-0.0058289,-0.0057983,-0.0059204,-0.0053711,-0.0052795,-0.0044861,-0.0039978,-0.0032654,-0.0026550,-0.0020752,-0.0014038,-0.0011292,-0.0007019,-0.0005493,-0.0006409,-0.0006104,-0.0010986,-0.0011292,-0.0016785,-0.0018921,-0.0023499,-0.0024109,-0.0027771,-0.0025024,-0.0027771,-0.0021973,-0.0021973,-0.0014038,-0.0010071,-0.0002441,0.0004272,0.0011597,0.0018311,0.0025024,0.0032349,0.0035706,0.0043640,0.0044861,0.0051575,0.0052185,0.0054932,0.0056763,0.0057068,0.0057678,0.0056458,0.0057068,0.0056152,0.0055847,0.0055542,0.0056152,0.0055542,0.0057983,0.0058594,0.0061646,0.0066833,0.0069580,0.0075684,0.0081787,0.0088196,0.0096130,0.0101624,0.0110168,0.0114746,0.0121765,0.0122375,0.0126343,0.0125427,0.0124817,0.0120850,0.0116577,0.0110779,0.0105591,0.0098877,0.0094604,0.0089417,0.0088806,0.0086365,0.0090942,0.0093079,0.0102844,0.0110474,0.0123901,0.0133972,0.0149536,0.0161133,0.0173950,0.0184021,0.0192261,0.0197144,0.0201111,0.0199585,0.0196838,0.0191345,0.0181580,0.0175476,0.0163269,0.0156555,0.0146484,0.0140991,0.0136414,0.0136108,0.0135498,0.0141296,0.0147095,0.0155334,0.0166626,0.0175171,0.0187683,0.0194702,0.0205078,0.0206909,0.0211182,0.0209351,0.0205383,0.0199890,0.0188293,0.0178528,0.0165100,0.0151978,0.0137939,0.0126038,0.0114441,0.0105591,0.0098877,0.0095215,0.0093994,0.0095215,0.0098572,0.0103455,0.0110168,0.0116272,0.0122986,0.0129700,0.0132446,0.0136108,0.0135803,0.0134583,0.0131531,0.0123901,0.0117798,0.0106506,0.0096741,0.0086670,0.0075989,0.0067444,0.0058594,0.0052490,0.0047302,0.0045166,0.0046082,0.0045471,0.0051575,0.0054016,0.0062561,0.0065613,0.0074158,0.0077820,0.0084229,0.0086060,0.0088501,0.0087585,0.0085754,0.0081787,0.0075684,0.0069580,0.0059814,0.0051880,0.0042114,0.0033875,0.0025024,0.0017700,0.0011292,0.0007324,0.0002136,0.0001526,0.0000610,0.0002136,0.0002747,0.0005493,0.0006104,0.0011292,0.0009766,0.0014038,0.0011902,0.0013733,0.0009460,0.0007629,0.0003967,-0.0002747,-0.0006409,-0.0014648,-0.0019226,-0.0027161,-0.0032654,-0.0038452,-0.0042725,-0.0045776,-0.0046997,-0.0049438,-0.0047607,-0.0048523,-0.0043640,-0.0043945,-0.0037842,-0.0038757,-0.0032349,-0.0033569,-0.0029602,-0.0032043,-0.0031128,-0.0035400,-0.0037842,-0.0042114,-0.0049438,-0.0053101,-0.0060120,-0.0065918,-0.0071106,-0.0075684,-0.0079041,-0.0080261,-0.0081177,-0.0080566,-0.0077515,-0.0074463,-0.0069580,-0.0064087,-0.0059509,-0.0053406,-0.0048828,-0.0045776,-0.0042419,-0.0043640,-0.0042419,-0.0046082,-0.0049133,-0.0054626,-0.0060425,-0.0066833,-0.0072327,-0.0077209,-0.0083313,-0.0083923,-0.0086975,-0.0084534,-0.0081482,-0.0079346,-0.0069580,-0.0064697,-0.0054932,-0.0046997,-0.0039673,-0.0030518,-0.0026245,-0.0021362,-0.0018005,-0.0019531,-0.0018311,-0.0022583,-0.0025940,-0.0032043,-0.0036621,-0.0042419,-0.0046082,-0.0049133,-0.0051575,-0.0051575,-0.0048828,-0.0045776,-0.0038147,-0.0032349,-0.0021973,-0.0014648,-0.0001526,0.0005493,0.0016479,0.0025940,0.0031128,0.0039673,0.0040894,0.0046082,0.0046387,0.0046692,0.0046692,0.0046082,0.0043335,0.0045471,0.0042725,0.0045471,0.0045471,0.0049438,0.0050354,0.0058594,0.0059814,0.0068970,0.0071716,0.0078735,0.0083008,0.0089111,0.0091858,0.0096436,0.0097656,0.0098267,0.0100098,0.0097961,0.0097046,0.0095520,0.0093994,0.0092163,0.0089722,0.0088501,0.0086365,0.0086670,0.0086060,0.0086060,0.0086365,0.0087280,0.0089111,0.0088501,0.0090332,0.0088501,0.0089417,0.0088501,0.0087585,0.0084229,0.0083313,0.0078735,0.0075989,0.0073242,0.0068054,0.0065613,0.0063171,0.0059509,0.0059204,0.0056763,0.0057373,0.0057068,0.0059204,0.0060120,0.0062256,0.0065613,0.0066833,0.0072021,0.0071411,0.0075989,0.0074463,0.0076599,0.0075378,0.0073853,0.0072327,0.0067139,0.0066223,0.0058594,0.0056763,0.0049438,0.0047913,0.0040894,0.0040283,0.0036621,0.0035706,0.0033875,0.0035706,0.0035095,0.0038452,0.0041199,0.0043640,0.0046997,0.0050964,0.0051575,0.0055847,0.0054626,0.0056763,0.0052795,0.0053101,0.0047607,0.0043335,0.0038147,0.0030518,0.0026550,0.0017395,0.0013123,0.0006714,0.0002136,-0.0002136,-0.0003662,-0.0006714,-0.0005188,-0.0004272,-0.0003662,0.0001221,0.0002747,0.0006104,0.0010071,0.0010986,0.0014343,0.0012817,0.0014343,0.0012207,0.0010376,0.0006714,0.0002441,-0.0002747,-0.0007629,-0.0012512,-0.0017090,-0.0021362,-0.0025024,-0.0028992,-0.0029297,-0.0032959,-0.0030823,-0.0034180,-0.0031128,-0.0031738,-0.0032043,-0.0031738,-0.0031738,-0.0033875,-0.0032654,-0.0037231,-0.0035400,-0.0040283,-0.0039368,-0.0041809,-0.0042114,-0.0041504,-0.0040894,-0.0039673,-0.0036011,-0.0032654,-0.0028992,-0.0023804,-0.0019836,-0.0014648,-0.0012817,-0.0008545,-0.0007935,-0.0006409,-0.0007324,-0.0009155,-0.0011597,-0.0015564,-0.0017700,-0.0025635,-0.0025330,-0.0033569,-0.0032654,-0.0038147,-0.0037231,-0.0039062,-0.0036926,-0.0038147,-0.0032959,-0.0032959,-0.0029297,-0.0028076,-0.0024414,-0.0023193,-0.0020447,-0.0020447,-0.0019836,-0.0019836,-0.0020142,-0.0021057,-0.0021362,-0.0021667,-0.0021057,-0.0021057,-0.0019226,-0.0017700,-0.0014954,-0.0011902,-0.0009155,-0.0005188,-0.0002747,0.0002441,0.0003662,0.0007324,0.0010681,0.0012817,0.0015869,0.0018005,0.0019531,0.0021667,0.0025024,0.0026550,0.0029602,0.0033875,0.0035706,0.0041809,0.0042725,0.0048828,0.0049133,0.0053101,0.0053101,0.0054016,0.0051270,0.0050964,0.0045776,0.0043640,0.0038147,0.0033875,0.0028992,0.0025330,0.0022888,0.0020142,0.0020752,0.0020752,0.0024109,0.0027771,0.0032043,0.0037537,0.0043030,0.0050049,0.0053711,0.0059814,0.0061646,0.0064087,0.0064087,0.0061646,0.0056763,0.0055542,0.0042725,0.0041199,0.0027466,0.0022888,0.0010681,0.0006409,-0.0003967,-0.0007324,-0.0015259,-0.0017395,-0.0019836,-0.0020142,-0.0018921,-0.0019226,-0.0016479,-0.0014648,-0.0012512,-0.0010376,-0.0009766,-0.0009155,-0.0010071,-0.0011902,-0.0014648,-0.0019226,-0.0022583,-0.0030823,-0.0034485,-0.0043640,-0.0049133,-0.0056152,-0.0061340,-0.0068054,-0.0070496,-0.0074768,-0.0076294,-0.0075684,-0.0075378,-0.0074158,-0.0070496,-0.0067749,-0.0063477,-0.0059509,-0.0056152,-0.0052185,-0.0050354,-0.0048218,-0.0049438,-0.0050354,-0.0051270,-0.0057068,-0.0057983,-0.0065918,-0.0067749,-0.0075989,-0.0078430,-0.0084534,-0.0086670,-0.0089722,-0.0090027,-0.0088196,-0.0087585,-0.0082397,-0.0079346,-0.0072632,-0.0067749,-0.0059814,-0.0055847,-0.0048828,-0.0045166,-0.0042419,-0.0038757,-0.0041199,-0.0039673,-0.0044556,-0.0046997,-0.0053711,-0.0057068,-0.0065613,-0.0070190,-0.0077209,-0.0082703,-0.0087891,-0.0091248,-0.0095520,-0.0095520,-0.0097961,-0.0097351,-0.0096741,-0.0095520,-0.0092773,-0.0091858,-0.0087585,-0.0086060,-0.0083618,-0.0080872,-0.0079956,-0.0077515,-0.0076904,-0.0075378,-0.0075073,-0.0072937,-0.0074463,-0.0071106,-0.0072632,-0.0069275,-0.0068665,-0.0066528,-0.0065613,-0.0061340,-0.0060425,-0.0056152,-0.0052795,-0.0050354,-0.0044556,-0.0041809,-0.0035400,-0.0032959,-0.0027771,-0.0022278,-0.0020142,-0.0014954,-0.0012207,-0.0009766,-0.0005798,-0.0004578,-0.0003357,-0.0001831,-0.0001526,-0.0001526,-0.0002136,-0.0002136,-0.0005493,-0.0004578,-0.0007629,-0.0007935,-0.0008850,-0.0010376,-0.0010681,-0.0010376,-0.0010071,-0.0009460,-0.0007019,-0.0006409,-0.0002136,-0.0001526,0.0004883,0.0006409,0.0012817,0.0014648,0.0020447,0.0025024,0.0027161,0.0033569,0.0033264,0.0039368,0.0040894,0.0044250,0.0047607,0.0048828,0.0054321,0.0054626,0.0061035,0.0064392,0.0068665,0.0075989,0.0079651,0.0087280,0.0093994,0.0102539,0.0108643,0.0115662,0.0122681,0.0127258,0.0134277,0.0134583,0.0138855,0.0137329,0.0136719,0.0133057,0.0130310,0.0122375,0.0118408,0.0109558,0.0103760,0.0096130,0.0090942,0.0084839,0.0082397,0.0078125,0.0079651,0.0076599,0.0083008,0.0081177,0.0089111,0.0089417,0.0094604,0.0097351,0.0098267,0.0100403,0.0099487,0.0097351,0.0095215,0.0089722,0.0084534,0.0078125,0.0070496,0.0064697,0.0057373,0.0052490,0.0046692,0.0045166,0.0039673,0.0041809,0.0038452,0.0042419,0.0040283,0.0043945,0.0043030,0.0045166,0.0041504,0.0043030,0.0036621,0.0034180,0.0026550,0.0019836,0.0012512,0.0003357,-0.0005798,-0.0013123,-0.0021973,-0.0026550,-0.0034790,-0.0036621,-0.0041809,-0.0041504,-0.0042419,-0.0042419,-0.0040588,-0.0039062,-0.0036621,-0.0037537,-0.0035095,-0.0036926,-0.0036011,-0.0040588,-0.0041809,-0.0047913,-0.0050049,-0.0056763,-0.0061035,-0.0066528,-0.0069580,-0.0076294,-0.0077209,-0.0080566,-0.0083008,-0.0083008,-0.0083008,-0.0082703,-0.0081177,-0.0081177,-0.0079346,-0.0079346,-0.0078735,-0.0079956,-0.0081177,-0.0083313,-0.0087891,-0.0089111,-0.0096741,-0.0099487,-0.0107727,-0.0112000,-0.0119324,-0.0125122,-0.0130615,-0.0136108,-0.0140381,-0.0144653,-0.0148010,-0.0150452,-0.0151978,-0.0152893,-0.0151672,-0.0152283,-0.0149536,-0.0149231,-0.0144043,-0.0146179,-0.0140686,-0.0141296,-0.0139160,-0.0138855,-0.0139465,-0.0140991,-0.0141602,-0.0145874,-0.0149536,-0.0153809,-0.0159912,-0.0163574,-0.0169678,-0.0174255,-0.0178223,-0.0182190,-0.0183105,-0.0185242,-0.0182495,-0.0183411,-0.0177612,-0.0175476,-0.0168152,-0.0163574,-0.0157471,-0.0151367,-0.0146484,-0.0140686,-0.0139465,-0.0135193,-0.0136108,-0.0136719,-0.0138245,-0.0143127,-0.0145569,-0.0152893,-0.0156250,-0.0162964,-0.0165405,-0.0170593,-0.0171509,-0.0173035,-0.0173340,-0.0167847,-0.0167236,-0.0157776,-0.0154419,-0.0144348,-0.0138245,-0.0129700,-0.0122375,-0.0117188,-0.0109863,-0.0107117,-0.0104065,-0.0102539,-0.0102539,-0.0102539,-0.0104675,-0.0104065,-0.0107422,-0.0105591,-0.0106812,-0.0104675,-0.0101624,-0.0098267,-0.0091248,-0.0085449,-0.0076904,-0.0066223,-0.0057373,-0.0045166,-0.0037842,-0.0025024,-0.0018921,-0.0011292,-0.0003357,-0.0001221,0.0004883,0.0004883,0.0005798,0.0007324,0.0005798,0.0005188,0.0005493,0.0003357,0.0006104,0.0003967,0.0008545,0.0010986,0.0014648,0.0020752,0.0024414,0.0031433,0.0036926,0.0042419,0.0049438,0.0051880,0.0057373,0.0060120,0.0062256,0.0064392,0.0063477,0.0064697,0.0063171,0.0063171,0.0061646,0.0062561,0.0059204,0.0061951,0.0059814,0.0060730,0.0063477,0.0062256,0.0066833,0.0066833,0.0069580,0.0071716,0.0072327,0.0075073,0.0076294,0.0076904,0.0077515,0.0077209,0.0079041,0.0075073,0.0076599,0.0074158,0.0073853,0.0071716,0.0070496,0.0066223,0.0066528,0.0061035,0.0061035,0.0055237,0.0054016,0.0048523,0.0046692,0.0042725,0.0039062,0.0036316,0.0032349,0.0030212,0.0027161,0.0025024,0.0021973,0.0021973,0.0018311,0.0019531,0.0015564,0.0016479,0.0012512,0.0012817,0.0009155,0.0006714,0.0004578,-0.0000305,-0.0001221,-0.0006714,-0.0009155,-0.0012207,-0.0016174,-0.0016785,-0.0020142,-0.0019226,-0.0020142,-0.0019531,-0.0016174,-0.0017090,-0.0012207,-0.0011292,-0.0006714,-0.0004883,-0.0003662,-0.0000916,-0.0002747,-0.0001221,-0.0003052,-0.0006104,-0.0008545,-0.0013733,-0.0017700,-0.0021973,-0.0028076,-0.0032349,-0.0038147,-0.0041504,-0.0045776,-0.0049133,-0.0050964,-0.0054016,-0.0054321,-0.0056152,-0.0057373,-0.0056763,-0.0059204,-0.0058289,-0.0061646,-0.0062561,-0.0064087,-0.0068359,-0.0070801,-0.0074463,-0.0078735,-0.0081482,-0.0086975,-0.0089111,-0.0093384,-0.0096130,-0.0098877,-0.0100708,-0.0102539,-0.0103455,-0.0104370,-0.0104065,-0.0104980,-0.0103149,-0.0105591,-0.0103455,-0.0103149,-0.0105286,-0.0102539,-0.0107117,-0.0106201,-0.0105896,-0.0111084,-0.0109558,-0.0114136,-0.0114136,-0.0116272,-0.0117188,-0.0119324,-0.0119629,-0.0121460,-0.0121765,-0.0122375,-0.0123901,-0.0122070,-0.0125732,-0.0123596,-0.0126038,-0.0125732,-0.0126343,-0.0126343,-0.0127563,-0.0127563,-0.0125427,-0.0126953,-0.0123291,-0.0123291,-0.0119324,-0.0114746,-0.0112610,-0.0105591,-0.0101624,-0.0095520,-0.0089722,-0.0084534,-0.0078735,-0.0075989,-0.0068970,-0.0069275,-0.0064087,-0.0065308,-0.0064087,-0.0064697,-0.0066223,-0.0068665,-0.0070801,-0.0071411,-0.0073853,-0.0073547,-0.0073853,-0.0071716,-0.0069580,-0.0064087,-0.0061035,-0.0054016,-0.0047607,-0.0040588,-0.0032349,-0.0026245,-0.0019836,-0.0013733,-0.0010986,-0.0005493,-0.0005188,-0.0004272,-0.0005493,-0.0007019,-0.0009766,-0.0013123,-0.0016479,-0.0017090,-0.0021973,-0.0019226,-0.0021973,-0.0018311,-0.0015259,-0.0010986,-0.0003052,0.0003662,0.0012207,0.0021973,0.0029907,0.0039368,0.0048218,0.0054016,0.0063171,0.0064697,0.0070801,0.0070801,0.0071106,0.0071106,0.0068054,0.0065002,0.0061951,0.0057678,0.0054932,0.0052185,0.0048523,0.0049133,0.0046692,0.0048523,0.0048523,0.0050659,0.0054321,0.0055542,0.0061035,0.0063171,0.0066528,0.0068665,0.0070190,0.0069885,0.0069885,0.0068359,0.0065308,0.0063782,0.0056763,0.0054932,0.0046997,0.0045776,0.0037842,0.0036621,0.0032959,0.0031433,0.0031128,0.0032654,0.0034180,0.0036926,0.0042419,0.0043335,0.0053711,0.0054932,0.0063477,0.0065613,0.0070801,0.0071716,0.0073853,0.0073547,0.0070801,0.0068970,0.0064697,0.0058594,0.0054016,0.0046082,0.0042114,0.0034790,0.0031738,0.0026245,0.0025330,0.0022583,0.0024719,0.0024109,0.0028992,0.0031433,0.0035706,0.0042419,0.0043945,0.0051575,0.0050964,0.0056458,0.0054626,0.0057373,0.0051575,0.0051575,0.0042419,0.0039978,0.0028381,0.0023804,0.0012207,0.0005493,-0.0001526,-0.0009155,-0.0013123,-0.0018921,-0.0020447,-0.0022583,-0.0019531,-0.0019226,-0.0013123,-0.0011597,-0.0005188,-0.0001526,0.0002747,0.0007629,0.0009460,0.0010071,0.0010681,0.0008240,0.0004578,0.0000000,-0.0006714,-0.0012817,-0.0021362,-0.0028076,-0.0036316,-0.0040588,-0.0046997,-0.0049438,-0.0051575,-0.0050964,-0.0050049,-0.0045166,-0.0041199,-0.0034485,-0.0029297,-0.0022278,-0.0016479,-0.0011902,-0.0006409,-0.0006714,-0.0004578,-0.0008545,-0.0009460,-0.0017395,-0.0023193,-0.0032043,-0.0039368,-0.0048218,-0.0055847,-0.0062866,-0.0069580,-0.0069885,-0.0075378,-0.0072021,-0.0070801,-0.0066223,-0.0058899,-0.0053101,-0.0043335,-0.0036621,-0.0028687,-0.0021362,-0.0016174,-0.0011292,-0.0009460,-0.0008240,-0.0010986,-0.0010681,-0.0017395,-0.0019226,-0.0026245,-0.0029602,-0.0034485,-0.0035706,-0.0038452,-0.0036621,-0.0034485,-0.0031128,-0.0022583,-0.0017700,-0.0006714,0.0001831,0.0011902,0.0021667,0.0027771,0.0037842,0.0038757,0.0045471,0.0042114,0.0042419,0.0036011,0.0031738,0.0021667,0.0014343,0.0004883,-0.0004883,-0.0011597,-0.0020142,-0.0024414,-0.0026550,-0.0027771,-0.0024109,-0.0022278,-0.0012817,-0.0006714,0.0004272,0.0014038,0.0024109,0.0034485,0.0041504,0.0049744,0.0053406,0.0057068,0.0056458,0.0056152,0.0051575,0.0048523,0.0041504,0.0036011,0.0031433,0.0024719,0.0021667,0.0019226,0.0017700,0.0020752,0.0022278,0.0028687,0.0034790,0.0043335,0.0049744,0.0059814,0.0065002,0.0073547,0.0075989,0.0081482,0.0080261,0.0081177,0.0076904,0.0072937,0.0065002,0.0057983,0.0048828,0.0039978,0.0032043,0.0022278,0.0015869,0.0010376,0.0005493,0.0003662,0.0001831,0.0003662,0.0005493,0.0010681,0.0010986,0.0019836,0.0021973,0.0027771,0.0030823,0.0033875,0.0034790,0.0036011,0.0033569,0.0031128,0.0026855,0.0021362,0.0014954,0.0008850,0.0001526,-0.0005493,-0.0013123,-0.0017090,-0.0023804,-0.0025024,-0.0028687,-0.0027466,-0.0027771,-0.0023499,-0.0021973,-0.0015259,-0.0011902,-0.0004883,-0.0000610,0.0006104,0.0008240,0.0012207,0.0012817,0.0012207,0.0010376,0.0006104,-0.0000916,-0.0007019,-0.0015564,-0.0023804,-0.0033264,-0.0041809,-0.0050049,-0.0056458,-0.0062561,-0.0065002,-0.0068054,-0.0066528,-0.0064697,-0.0061646,-0.0055542,-0.0050964,-0.0042725,-0.0037231,-0.0029907,-0.0024719,-0.0019836,-0.0016479,-0.0015259,-0.0014038,-0.0016174,-0.0017090,-0.0022583,-0.0026550,-0.0029602,-0.0037231,-0.0039978,-0.0045471,-0.0049438,-0.0050964,-0.0053406,-0.0052490,-0.0052490,-0.0048828,-0.0046692,-0.0042419,-0.0036926,-0.0033264,-0.0027771,-0.0023499,-0.0019836,-0.0016785,-0.0014038,-0.0013733,-0.0014038,-0.0014648,-0.0016785,-0.0019226,-0.0021667,-0.0026550,-0.0026550,-0.0032043,-0.0031433,-0.0033875,-0.0031128,-0.0029907,-0.0026245,-0.0020447,-0.0014954,-0.0005188,0.0001526,0.0014343,0.0020142,0.0033875,0.0038757,0.0049133,0.0055847,0.0060730,0.0065308,0.0066223,0.0066833,0.0065918,0.0063477,0.0061035,0.0057983,0.0054626,0.0052185,0.0050049,0.0050964,0.0049438,0.0055542,0.0056458,0.0065002,0.0071411,0.0080872,0.0088806,0.0099487,0.0106201,0.0117493,0.0122681,0.0130005,0.0133057,0.0137634,0.0134888,0.0138245,0.0133057,0.0132446,0.0127258,0.0122375,0.0121765,0.0115662,0.0116272,0.0114746,0.0116577,0.0120239,0.0123901,0.0131226,0.0137634,0.0148010,0.0154114,0.0166016,0.0173035,0.0180664,0.0188293,0.0191956,0.0195923,0.0196533,0.0195312,0.0192566,0.0186462,0.0181274,0.0172424,0.0163574,0.0155334,0.0145874,0.0137939,0.0130615,0.0124817,0.0121155,0.0118103,0.0118103,0.0119629,0.0122070,0.0127869,0.0131226,0.0140076,0.0144958,0.0150452,0.0157166,0.0159607,0.0162964,0.0161743,0.0162048,0.0157166,0.0153503,0.0144958,0.0137024,0.0129395,0.0119019,0.0111389,0.0102844,0.0096741,0.0091553,0.0088196,0.0087891,0.0086975,0.0091858,0.0093689,0.0101013,0.0104065,0.0109863,0.0113831,0.0116882,0.0119324,0.0115662,0.0115662,0.0109558,0.0103149,0.0096436,0.0086670,0.0079346,0.0070190,0.0061951,0.0057373,0.0051575,0.0050354,0.0050354,0.0052185,0.0056763,0.0062561,0.0068665,0.0075378,0.0081482,0.0086975,0.0090027,0.0090942,0.0091248,0.0086365,0.0081482,0.0072632,0.0064392,0.0053711,0.0043335,0.0031738,0.0023499,0.0014343,0.0010681,0.0005188,0.0005493,0.0006104,0.0009155,0.0014648,0.0018921,0.0025635,0.0030823,0.0035706,0.0037842,0.0038147,0.0036621,0.0032349,0.0025330,0.0015564,0.0006714,-0.0007629,-0.0016785,-0.0032043,-0.0039673,-0.0052795,-0.0057983,-0.0065918,-0.0069275,-0.0069580,-0.0069885,-0.0066528,-0.0063171,-0.0057373,-0.0053406,-0.0045776,-0.0043640,-0.0037231,-0.0036926,-0.0035706,-0.0033875,-0.0039062,-0.0038452,-0.0045776,-0.0048523,-0.0052795,-0.0057678,-0.0062256,-0.0064087,-0.0067749,-0.0066833,-0.0067139,-0.0064697,-0.0061340,-0.0057373,-0.0051575,-0.0046387,-0.0041504,-0.0036011,-0.0030518,-0.0026245,-0.0023804,-0.0019836,-0.0022278,-0.0018921,-0.0023804,-0.0022888,-0.0029297,-0.0031433,-0.0038452,-0.0041504,-0.0048523,-0.0051880,-0.0057678,-0.0061035,-0.0064392,-0.0065918,-0.0068359,-0.0065918,-0.0067444,-0.0063171,-0.0064087,-0.0056458,-0.0056458,-0.0048523,-0.0046692,-0.0042419,-0.0036316,-0.0036316,-0.0030518,-0.0029602,-0.0027466,-0.0024719,-0.0025330,-0.0021973,-0.0022278,-0.0019531,-0.0018616,-0.0016174,-0.0014038,-0.0008545,-0.0006104,0.0000610,0.0004578,0.0011902,0.0016785,0.0024414,0.0029907,0.0036011,0.0040894,0.0046997,0.0049438,0.0054321,0.0056458,0.0059204,0.0061035,0.0061035,0.0063782,0.0063171,0.0065002,0.0065308,0.0067749,0.0068054,0.0070496,0.0072021,0.0073547,0.0076599,0.0076599,0.0079651,0.0080261,0.0081482,0.0081177,0.0079956,0.0080261,0.0077515,0.0076904,0.0074463,0.0071106,0.0071716,0.0066528,0.0067444,0.0065002,0.0065308,0.0063477,0.0065613,0.0063782,0.0067749,0.0066833,0.0068970,0.0070496,0.0070496,0.0072327,0.0070190,0.0072327,0.0067749,0.0069275,0.0063782,0.0062866,0.0058899,0.0056152,0.0053406,0.0049744,0.0048218,0.0045776,0.0043640,0.0043030,0.0043335,0.0042419,0.0044861,0.0044250,0.0046692,0.0046997,0.0050964,0.0049438,0.0054932,0.0051880,0.0055542,0.0054016,0.0054932,0.0054321,0.0054016,0.0052490,0.0051575,0.0050964,0.0047913,0.0049133,0.0045166,0.0045776,0.0043640,0.0043030,0.0043335,0.0041504,0.0042419,0.0042725,0.0042419,0.0043640,0.0043030,0.0045471,0.0043945,0.0046692,0.0046997,0.0047913,0.0048828,0.0049438,0.0049744,0.0050964,0.0050659,0.0050354,0.0050659,0.0049133,0.0048218,0.0046692,0.0044861,0.0041809,0.0039368,0.0035706,0.0032654,0.0027161,0.0022888,0.0017395,0.0012817,0.0006714,0.0001526,-0.0002441,-0.0008545,-0.0011597,-0.0015564,-0.0019836,-0.0020142,-0.0023804,-0.0023499,-0.0024414,-0.0025024,-0.0024414,-0.0023499,-0.0022888,-0.0021973,-0.0021057,-0.0020447,-0.0019531,-0.0020142,-0.0020447,-0.0021362,-0.0021667,-0.0023499,-0.0024109,-0.0024414,-0.0026855,-0.0024719,-0.0027466,-0.0024414,-0.0025330,-0.0020752,-0.0021057,-0.0016785,-0.0015564,-0.0011292,-0.0010376,-0.0007629,-0.0007324,-0.0006714,-0.0006714,-0.0008545,-0.0009766,-0.0014343,-0.0017090,-0.0021973,-0.0025635,-0.0029907,-0.0033569,-0.0036316,-0.0038757,-0.0039673,-0.0039062,-0.0037842,-0.0033264,-0.0031128,-0.0025024,-0.0020752,-0.0014954,-0.0009766,-0.0003967,-0.0000610,0.0004272,0.0003967,0.0006714,0.0005493,0.0003967,0.0003052,-0.0002441,-0.0004272,-0.0007935,-0.0012817,-0.0013428,-0.0016785,-0.0016785,-0.0016174,-0.0014954,-0.0010071,-0.0007629,-0.0000305,0.0004272,0.0012817,0.0020142,0.0027161,0.0034485,0.0041504,0.0046692,0.0052185,0.0055542,0.0058899,0.0060425,0.0060730,0.0060120,0.0058899,0.0057373,0.0054932,0.0051270,0.0050049,0.0046997,0.0044556,0.0043945,0.0040283,0.0041809,0.0040894,0.0041199,0.0044556,0.0043640,0.0049438,0.0048828,0.0054932,0.0057373,0.0059204,0.0063782,0.0063477,0.0067139,0.0067139,0.0066223,0.0067139,0.0063782,0.0061951,0.0058899,0.0053406,0.0050049,0.0043335,0.0040283,0.0032654,0.0029602,0.0024719,0.0019836,0.0018005,0.0014038,0.0013428,0.0012207,0.0011902,0.0012512,0.0013123,0.0014648,0.0015564,0.0016479,0.0018616,0.0017090,0.0018921,0.0015259,0.0015564,0.0011292,0.0009460,0.0004578,-0.0000916,-0.0004272,-0.0012207,-0.0016174,-0.0022278,-0.0028687,-0.0032043,-0.0038147,-0.0040894,-0.0043945,-0.0046387,-0.0047302,-0.0049744,-0.0046997,-0.0047913,-0.0045776,-0.0042725,-0.0042114,-0.0038452,-0.0036621,-0.0035706,-0.0032043,-0.0032654,-0.0030212,-0.0031128,-0.0031128,-0.0032654,-0.0033569,-0.0036926,-0.0038452,-0.0042114,-0.0044861,-0.0046997,-0.0051880,-0.0051270,-0.0055237,-0.0055237,-0.0055237,-0.0056152,-0.0054016,-0.0053406,-0.0052490,-0.0049133,-0.0047607,-0.0046387,-0.0043030,-0.0043945,-0.0041504,-0.0043640,-0.0043640,-0.0046997,-0.0048523,-0.0052795,-0.0056458,-0.0061951,-0.0064697,-0.0072021,-0.0074158,-0.0079956,-0.0083923,-0.0086365,-0.0090637,-0.0089722,-0.0094604,-0.0091553,-0.0095215,-0.0092773,-0.0093079,-0.0092468,-0.0091248,-0.0090027,-0.0090027,-0.0088196,-0.0088501,-0.0086670,-0.0086975,-0.0087891,-0.0085754,-0.0086975,-0.0086365,-0.0086060,-0.0086365,-0.0086060,-0.0084534,-0.0084839,-0.0083313,-0.0081787,-0.0079956,-0.0077515,-0.0074768,-0.0072327,-0.0068665,-0.0064087,-0.0061035,-0.0055847,-0.0051575,-0.0046997,-0.0043335,-0.0038147,-0.0035706,-0.0031738,-0.0028992,-0.0028992,-0.0025635,-0.0026550,-0.0025635,-0.0025940,-0.0026855,-0.0028076,-0.0028076,-0.0029602,-0.0027466,-0.0029602,-0.0025635,-0.0027161,-0.0021057,-0.0021057,-0.0014343,-0.0013428,-0.0004578,-0.0005493,0.0003052,0.0002747,0.0008545,0.0010376,0.0011597,0.0013733,0.0015259,0.0014954,0.0017090,0.0016785,0.0019531,0.0020447,0.0025024,0.0028076,0.0032654,0.0039673,0.0044861,0.0054321,0.0060730,0.0068359,0.0075378,0.0082092,0.0087891,0.0091248,0.0093689,0.0097351,0.0094299,0.0097351,0.0091858,0.0092163,0.0087280,0.0085754,0.0082092,0.0081787,0.0079041,0.0079956,0.0080566,0.0082703,0.0086365,0.0088806,0.0093994,0.0097046,0.0103149,0.0104370,0.0108643,0.0110779,0.0111084,0.0111694,0.0110779,0.0109253,0.0107422,0.0106506,0.0101013,0.0104370,0.0098267,0.0101013,0.0099487,0.0101013,0.0103455,0.0104065,0.0106812,0.0109253,0.0112000,0.0111389,0.0114136,0.0110779,0.0111389,0.0106201,0.0102539,0.0097046,0.0088196,0.0083008,0.0072632,0.0066223,0.0055542,0.0049744,0.0040283,0.0034790,0.0028381,0.0024414,0.0020447,0.0017395,0.0015259,0.0014648,0.0012512,0.0012817,0.0009155,0.0009155,0.0007019,0.0003967,0.0001831,-0.0003052,-0.0005798,-0.0010681,-0.0014343,-0.0018311,-0.0021973,-0.0025024,-0.0026550,-0.0029297,-0.0026245,-0.0028992,-0.0025024,-0.0023499,-0.0021362,-0.0015869,-0.0014648,-0.0010071,-0.0008545,-0.0007324,-0.0004272,-0.0006409,-0.0006104,-0.0010071,-0.0013428,-0.0018921,-0.0022278,-0.0031128,-0.0036316,-0.0043030,-0.0050354,-0.0054932,-0.0061951,-0.0065308,-0.0069275,-0.0072632,-0.0073853,-0.0077209,-0.0075378,-0.0080261,-0.0078125,-0.0080872,-0.0081787,-0.0083618,-0.0086365,-0.0090942,-0.0094910,-0.0099487,-0.0105591,-0.0110779,-0.0116272,-0.0122681,-0.0126648,-0.0131836,-0.0133667,-0.0137634,-0.0137939,-0.0137634,-0.0138855,-0.0133057,-0.0135803,-0.0128479,-0.0129395,-0.0124207,-0.0124207,-0.0122070,-0.0121765,-0.0122681,-0.0122375,-0.0126953,-0.0127869,-0.0133667,-0.0137024,-0.0141296,-0.0146179,-0.0149231,-0.0154114,-0.0156860,-0.0159302,-0.0161133,-0.0162659,-0.0162964,-0.0162354,-0.0163879,-0.0161743,-0.0163574,-0.0159912,-0.0163269,-0.0158386,-0.0163574,-0.0159607,-0.0162659,-0.0162048,-0.0161133,-0.0162659,-0.0161743,-0.0160522,-0.0160828,-0.0157471,-0.0155334,-0.0153503,-0.0147705,-0.0145874,-0.0140076,-0.0136108,-0.0130920,-0.0125427,-0.0121155,-0.0115662,-0.0110779,-0.0105896,-0.0101929,-0.0097961,-0.0095215,-0.0092163,-0.0088806,-0.0087891,-0.0084534,-0.0085449,-0.0082703,-0.0083008,-0.0081177,-0.0080261,-0.0079346,-0.0078125,-0.0076904,-0.0074463,-0.0071716,-0.0068359,-0.0065308,-0.0059204,-0.0056763,-0.0049744,-0.0046997,-0.0041199,-0.0036316,-0.0031738,-0.0027771,-0.0024719,-0.0021973,-0.0019836,-0.0019226,-0.0018005,-0.0017395,-0.0018005,-0.0017090,-0.0018311,-0.0015869,-0.0016785,-0.0012512,-0.0010986,-0.0004272,-0.0000916,0.0005493,0.0013733,0.0020142,0.0029602,0.0036621,0.0042725,0.0052185,0.0055237,0.0062866,0.0064392,0.0067749,0.0067749,0.0067749,0.0066528,0.0063782,0.0061340,0.0056763,0.0055847,0.0050354,0.0050659,0.0046692,0.0046997,0.0046082,0.0046387,0.0049744,0.0049133,0.0053406,0.0055847,0.0058289,0.0060120,0.0062561,0.0062866,0.0064392,0.0062866,0.0061035,0.0059509,0.0054932,0.0051880,0.0046692,0.0041199,0.0037842,0.0030823,0.0028381,0.0023804,0.0020142,0.0019531,0.0016174,0.0018311,0.0018005,0.0020752,0.0022888,0.0026550,0.0029602,0.0034790,0.0037231,0.0042419,0.0043335,0.0046082,0.0046082,0.0045776,0.0043030,0.0040894,0.0035706,0.0030212,0.0024719,0.0018311,0.0011597,0.0007019,-0.0000610,-0.0002136,-0.0007935,-0.0008850,-0.0007935,-0.0008545,-0.0003967,-0.0003052,0.0002136,0.0004272,0.0010681,0.0011292,0.0014648,0.0015259,0.0012817,0.0011292,0.0004578,0.0000000,-0.0008850,-0.0017395,-0.0026245,-0.0037842,-0.0043945,-0.0054626,-0.0059204,-0.0066528,-0.0066528,-0.0069275,-0.0067444,-0.0064392,-0.0060120,-0.0054016,-0.0047607,-0.0040283,-0.0036011,-0.0028381,-0.0027161,-0.0024109,-0.0025330,-0.0026855,-0.0031738,-0.0038757,-0.0044250,-0.0056152,-0.0061951,-0.0073547,-0.0081177,-0.0088196,-0.0094604,-0.0100098,-0.0099487,-0.0103149,-0.0097351,-0.0098572,-0.0090942,-0.0087585,-0.0082092,-0.0075378,-0.0072327,-0.0069580,-0.0065308,-0.0068054,-0.0066833,-0.0072632,-0.0076294,-0.0083618,-0.0090637,-0.0099182,-0.0106506,-0.0115051,-0.0120544,-0.0127258,-0.0130920,-0.0133667,-0.0134583,-0.0133972,-0.0130310,-0.0127258,-0.0122375,-0.0115662,-0.0109863,-0.0102234,-0.0097046,-0.0091248,-0.0087585,-0.0083313,-0.0083923,-0.0080261,-0.0082092,-0.0083313,-0.0083618,-0.0089111,-0.0090332,-0.0093689,-0.0096130,-0.0098267,-0.0098572,-0.0100708,-0.0098572,-0.0098877,-0.0093384,-0.0092773,-0.0085144,-0.0082397,-0.0075378,-0.0069275,-0.0064697,-0.0057373,-0.0053406,-0.0047913,-0.0044250,-0.0041504,-0.0038452,-0.0038452,-0.0038452,-0.0039368,-0.0041504,-0.0044250,-0.0046692,-0.0051575,-0.0053711,-0.0057373,-0.0059814,-0.0061340,-0.0061646,-0.0061340,-0.0057983,-0.0054932,-0.0049744,-0.0041199,-0.0036621,-0.0025330,-0.0018921,-0.0007324,-0.0001526,0.0009460,0.0013123,0.0022278,0.0023499,0.0027466,0.0027466,0.0028381,0.0025635,0.0026245,0.0021362,0.0020447,0.0018311,0.0015869,0.0016785,0.0015564,0.0019531,0.0021667,0.0026245,0.0031738,0.0036621,0.0043335,0.0047607,0.0052795,0.0055542,0.0057678,0.0058289,0.0056458,0.0054321,0.0048523,0.0043640,0.0037842,0.0030212,0.0025635,0.0018616,0.0016174,0.0011902,0.0011902,0.0011292,0.0015869,0.0018311,0.0026245,0.0030518,0.0039368,0.0045166,0.0052490,0.0058594,0.0061646,0.0065918,0.0066528,0.0065613,0.0065308,0.0059509,0.0057373,0.0050964,0.0046082,0.0041199,0.0035706,0.0033569,0.0030823,0.0030518,0.0029907,0.0032959,0.0035095,0.0039673,0.0044250,0.0048828,0.0053711,0.0057068,0.0059814,0.0062256,0.0062256,0.0060730,0.0060425,0.0053406,0.0051575,0.0042725,0.0038147,0.0031738,0.0024414,0.0019836,0.0013123,0.0011292,0.0006714,0.0007324,0.0005798,0.0007019,0.0010071,0.0011902,0.0017700,0.0019836,0.0026855,0.0027161,0.0032654,0.0032654,0.0035706,0.0033875,0.0032349,0.0030518,0.0024719,0.0023193,0.0014343,0.0011292,0.0004272,-0.0000305,-0.0005188,-0.0009460,-0.0012207,-0.0012817,-0.0015259,-0.0012207,-0.0013733,-0.0009155,-0.0008850,-0.0005188,-0.0002747,-0.0000610,0.0002136,0.0001526,0.0002747,0.0001526,-0.0000305,-0.0002747,-0.0006409,-0.0010376,-0.0014343,-0.0018311,-0.0022278,-0.0026245,-0.0029297,-0.0031128,-0.0034790,-0.0033875,-0.0035095,-0.0035400,-0.0033875,-0.0034180,-0.0033264,-0.0032959,-0.0032959,-0.0032959,-0.0033569,-0.0033875,-0.0036011,-0.0038147,-0.0040894,-0.0043030,-0.0044556,-0.0047913,-0.0048523,-0.0052490,-0.0050049,-0.0054016,-0.0050659,-0.0051880,-0.0049438,-0.0047302,-0.0044861,-0.0042419,-0.0039673,-0.0036621,-0.0033569,-0.0030823,-0.0028381,-0.0026550,-0.0024414,-0.0023193,-0.0022888,-0.0022583,-0.0022583,-0.0023499,-0.0024109,-0.0025940,-0.0025940,-0.0028076,-0.0028687,-0.0027466,-0.0029602,-0.0025024,-0.0025635,-0.0021057,-0.0017700,-0.0012817,-0.0007019,0.0000916,0.0005188,0.0015564,0.0017700,0.0027771,0.0030518,0.0036621,0.0037537,0.0040894,0.0038147,0.0038147,0.0034485,0.0031128,0.0025635,0.0020142,0.0015259,0.0009460,0.0007019,0.0001831,0.0000916,0.0001221,0.0000916,0.0006409,0.0008240,0.0014343,0.0019531,0.0026855,0.0030823,0.0038147,0.0040894,0.0045471,0.0047607,0.0045776,0.0047607,0.0042725,0.0040588,0.0034790,0.0030823,0.0024109,0.0020142,0.0013428,0.0010986,0.0007324,0.0003967,0.0004272,0.0001831,0.0005798,0.0003967,0.0008240,0.0009460,0.0013428,0.0015564,0.0018005,0.0019836,0.0022888,0.0023193,0.0025940,0.0023804,0.0025635,0.0024719,0.0023193,0.0023804,0.0021667,0.0020447,0.0019531,0.0018005,0.0017395,0.0016174,0.0014954,0.0014038,0.0014343,0.0012512,0.0015564,0.0012512,0.0015869,0.0015259,0.0015259,0.0017700,0.0016785,0.0018311,0.0017395,0.0018311,0.0016479,0.0016479,0.0012817,0.0011597,0.0007324,0.0004578,-0.0000916,-0.0003967,-0.0009155,-0.0013428,-0.0015869,-0.0021362,-0.0021973,-0.0026550,-0.0025024,-0.0028076,-0.0025024,-0.0026855,-0.0023804,-0.0023804,-0.0022278,-0.0020447,-0.0021057,-0.0021362,-0.0021362,-0.0024414,-0.0026550,-0.0030212,-0.0034790,-0.0037231,-0.0043640,-0.0046387,-0.0051575,-0.0053406,-0.0056458,-0.0057068,-0.0057373,-0.0056763,-0.0054932,-0.0051880,-0.0050659,-0.0045166,-0.0045471,-0.0039368,-0.0040894,-0.0035706,-0.0039673,-0.0038147,-0.0041199,-0.0044861,-0.0046692,-0.0052490,-0.0055542,-0.0060120,-0.0063477,-0.0066528,-0.0068359,-0.0068054,-0.0068054,-0.0064697,-0.0060425,-0.0056152,-0.0048828,-0.0042725,-0.0035095,-0.0027771,-0.0020447,-0.0014954,-0.0008240,-0.0006409,-0.0003052,-0.0001221,-0.0004272,-0.0003967,-0.0009766,-0.0012817,-0.0017700,-0.0022888,-0.0025940,-0.0030518,-0.0031433,-0.0032959,-0.0031128,-0.0026855,-0.0023193,-0.0015564,-0.0007324,0.0000610,0.0012207,0.0020447,0.0030823,0.0038757,0.0046082,0.0050049,0.0055237,0.0053101,0.0054626,0.0049438,0.0046082,0.0038757,0.0034790,0.0026550,0.0023499,0.0016785,0.0017090,0.0014648,0.0018921,0.0022888,0.0030212,0.0040894,0.0051880,0.0065002,0.0078735,0.0092773,0.0105591,0.0118713,0.0128784,0.0137024,0.0143738,0.0146179,0.0147095,0.0145264,0.0140686,0.0137024,0.0129089,0.0122986,0.0115967,0.0109253,0.0104980,0.0100403,0.0099487,0.0098572,0.0102844,0.0105591,0.0113525,0.0119629,0.0128479,0.0138245,0.0146179,0.0156555,0.0162659,0.0169678,0.0172729,0.0175476,0.0176392,0.0173645,0.0170288,0.0165405,0.0157776,0.0151062,0.0143127,0.0135193,0.0129395,0.0121155,0.0119324,0.0112915,0.0115356,0.0112915,0.0117493,0.0120544,0.0125427,0.0132141,0.0136414,0.0144043,0.0146790,0.0151978,0.0152588,0.0154419,0.0150452,0.0148621,0.0141296,0.0134888,0.0126343,0.0116882,0.0108643,0.0098267,0.0090942,0.0084229,0.0080566,0.0077515,0.0077820,0.0080566,0.0082397,0.0090637,0.0093994,0.0105286,0.0108032,0.0118103,0.0119019,0.0126343,0.0123596,0.0124817,0.0118713,0.0113525,0.0104370,0.0094604,0.0083923,0.0072327,0.0061340,0.0051880,0.0043030,0.0037231,0.0032959,0.0032349,0.0032959,0.0036926,0.0042114,0.0050049,0.0057068,0.0066833,0.0073853,0.0080872,0.0086670,0.0089417,0.0090942,0.0089722,0.0086670,0.0079956,0.0072937,0.0064392,0.0053101,0.0044861,0.0033569,0.0025940,0.0018005,0.0012207,0.0007324,0.0007019,0.0003662,0.0008850,0.0008545,0.0014038,0.0016479,0.0021667,0.0022888,0.0027466,0.0025635,0.0027466,0.0021973,0.0019531,0.0010986,0.0005493,-0.0004883,-0.0013428,-0.0023804,-0.0032654,-0.0042419,-0.0047913,-0.0057068,-0.0059814,-0.0064087,-0.0064697,-0.0064392,-0.0064392,-0.0060730,-0.0058899,-0.0056458,-0.0051880,-0.0052185,-0.0048218,-0.0050049,-0.0048828,-0.0052185,-0.0054016,-0.0058594,-0.0062256,-0.0065918,-0.0071106,-0.0073547,-0.0078125,-0.0077820,-0.0079956,-0.0079651,-0.0075378,-0.0074768,-0.0068665,-0.0063782,-0.0059204,-0.0053101,-0.0047607,-0.0043030,-0.0037537,-0.0035400,-0.0033875,-0.0031433,-0.0032654,-0.0031128,-0.0035400,-0.0033569,-0.0040588,-0.0038757,-0.0043945,-0.0044250,-0.0045471,-0.0046387,-0.0046387,-0.0044861,-0.0044861,-0.0041504,-0.0039978,-0.0036316,-0.0033875,-0.0029602,-0.0027466,-0.0023193,-0.0019531,-0.0016479,-0.0012817,-0.0010071,-0.0007019,-0.0004578,-0.0000916,-0.0001831,0.0003052,0.0001831,0.0003357,0.0003967,0.0002441,0.0003662,0.0002136,0.0001526,-0.0000610,0.0001221,-0.0002747,0.0000916,0.0000610,0.0002136,0.0007324,0.0007629,0.0016174,0.0019226,0.0027161,0.0033264,0.0039673,0.0047302,0.0052795,0.0057373,0.0062561,0.0065002,0.0067749,0.0066833,0.0068665,0.0063171,0.0065002,0.0059204,0.0057068,0.0052490,0.0050964,0.0047302,0.0047607,0.0045166,0.0047302,0.0046692,0.0050659,0.0052490,0.0057068,0.0058899,0.0064087,0.0065613,0.0068970,0.0070801,0.0071106,0.0073547,0.0070801,0.0070496,0.0067749,0.0064697,0.0063477,0.0058594,0.0055542,0.0053101,0.0048523,0.0048828,0.0044861,0.0046082,0.0043945,0.0044250,0.0045776,0.0046082,0.0048218,0.0050659,0.0050964,0.0056152,0.0054932,0.0060730,0.0058594,0.0064087,0.0061951,0.0063477,0.0063171,0.0061951,0.0060730,0.0058899,0.0055847,0.0052795,0.0049133,0.0045776,0.0041199,0.0038757,0.0034485,0.0032654,0.0031128,0.0029297,0.0030212,0.0029907,0.0033264,0.0034485,0.0039062,0.0042725,0.0047302,0.0050964,0.0057068,0.0057678,0.0064087,0.0064392,0.0065918,0.0068665,0.0064087,0.0066528,0.0060730,0.0059509,0.0054016,0.0049744,0.0044250,0.0040894,0.0034485,0.0033264,0.0026855,0.0025635,0.0023804,0.0020142,0.0023193,0.0019226,0.0023193,0.0020142,0.0025330,0.0022278,0.0025330,0.0025940,0.0022583,0.0025330,0.0019531,0.0018311,0.0014648,0.0009155,0.0005493,-0.0000916,-0.0006104,-0.0012207,-0.0018005,-0.0022583,-0.0028381,-0.0030212,-0.0034790,-0.0033875,-0.0036621,-0.0033875,-0.0033875,-0.0028381,-0.0027466,-0.0020447,-0.0018311,-0.0012512,-0.0007935,-0.0005798,-0.0001221,-0.0001831,0.0000305,-0.0000916,-0.0003357,-0.0004883,-0.0010986,-0.0012817,-0.0019531,-0.0021973,-0.0028992,-0.0030823,-0.0036926,-0.0037231,-0.0041199,-0.0039368,-0.0041199,-0.0037842,-0.0038147,-0.0033264,-0.0030823,-0.0028381,-0.0022278,-0.0022888,-0.0015259,-0.0017090,-0.0010376,-0.0010376,-0.0008850,-0.0006714,-0.0007324,-0.0005493,-0.0005798,-0.0005798,-0.0004272,-0.0005188,-0.0003357,-0.0003662,-0.0001526,-0.0002136,0.0000916,0.0001221,0.0003662,0.0006104,0.0007629,0.0011597,0.0012512,0.0016174,0.0017700,0.0021362,0.0022888,0.0024719,0.0027161,0.0028076,0.0030518,0.0030518,0.0032043,0.0029907,0.0031128,0.0030518,0.0029602,0.0028381,0.0028076,0.0028381,0.0025940,0.0029297,0.0026550,0.0031738,0.0030823,0.0035095,0.0036316,0.0041199,0.0042725,0.0047607,0.0049744,0.0052490,0.0055237,0.0056152,0.0057983,0.0057068,0.0057678,0.0055847,0.0055237,0.0052185,0.0051880,0.0047302,0.0047302,0.0044556,0.0041809,0.0043030,0.0039978,0.0040894,0.0039368,0.0039368,0.0039368,0.0038757,0.0037842,0.0037537,0.0035706,0.0035706,0.0031128,0.0030518,0.0027771,0.0024414,0.0023499,0.0018005,0.0018005,0.0013428,0.0012817,0.0008545,0.0008545,0.0005493,0.0004578,0.0001526,0.0000916,-0.0002747,-0.0003662,-0.0006104,-0.0010071,-0.0009460,-0.0014954,-0.0015869,-0.0017090,-0.0020447,-0.0020142,-0.0021973,-0.0021362,-0.0021362,-0.0019226,-0.0019531,-0.0016479,-0.0017090,-0.0014343,-0.0014038,-0.0013123,-0.0013733,-0.0013123,-0.0014954,-0.0016479,-0.0018616,-0.0021057,-0.0024414,-0.0026245,-0.0028992,-0.0033264,-0.0031738,-0.0037231,-0.0035400,-0.0036926,-0.0037537,-0.0035706,-0.0037537,-0.0035095,-0.0035400,-0.0034790,-0.0034485,-0.0034485,-0.0035706,-0.0036011,-0.0036926,-0.0039978,-0.0039368,-0.0045166,-0.0044250,-0.0049744,-0.0050049,-0.0053711,-0.0055542,-0.0057068,-0.0060120,-0.0060730,-0.0061646,-0.0062256,-0.0063477,-0.0063782,-0.0063782,-0.0065002,-0.0064392,-0.0065002,-0.0065002,-0.0065918,-0.0065918,-0.0066528,-0.0068359,-0.0067444,-0.0070496,-0.0069275,-0.0072327,-0.0073547,-0.0073853,-0.0078125,-0.0076294,-0.0081482,-0.0079651,-0.0083618,-0.0083923,-0.0084229,-0.0087280,-0.0083923,-0.0086975,-0.0084534,-0.0085449,-0.0082703,-0.0083923,-0.0080872,-0.0081482,-0.0079346,-0.0079346,-0.0078125,-0.0076294,-0.0077515,-0.0075073,-0.0074463,-0.0074158,-0.0069885,-0.0071106,-0.0065918,-0.0064392,-0.0059509,-0.0055542,-0.0051270,-0.0045776,-0.0042725,-0.0036621,-0.0032959,-0.0030212,-0.0025635,-0.0024414,-0.0022278,-0.0020142,-0.0021667,-0.0018005,-0.0021667,-0.0019226,-0.0021362,-0.0018311,-0.0018311,-0.0016479,-0.0014343,-0.0009460,-0.0007324,-0.0000610,0.0003967,0.0008240,0.0015869,0.0019226,0.0025024,0.0029907,0.0032349,0.0036621,0.0037842,0.0040283,0.0040894,0.0041809,0.0042114,0.0042114,0.0042419,0.0044250,0.0044556,0.0047302,0.0048828,0.0053101,0.0055237,0.0060120,0.0062561,0.0066833,0.0070496,0.0074768,0.0075684,0.0081787,0.0080566,0.0085144,0.0086060,0.0086365,0.0090027,0.0088501,0.0091858,0.0091248,0.0093384,0.0094604,0.0097351,0.0097351,0.0100403,0.0102539,0.0102844,0.0107117,0.0104980,0.0109863,0.0107117,0.0111084,0.0107422,0.0111084,0.0107422,0.0109253,0.0107117,0.0104980,0.0106506,0.0101929,0.0102844,0.0098267,0.0097961,0.0094604,0.0091248,0.0089417,0.0083313,0.0083008,0.0075378,0.0073853,0.0066833,0.0065613,0.0058594,0.0057678,0.0051575,0.0049744,0.0047607,0.0044556,0.0043030,0.0041809,0.0041199,0.0039978,0.0041809,0.0038452,0.0039673,0.0040283,0.0036926,0.0040894,0.0035706,0.0038757,0.0035706,0.0035706,0.0033875,0.0033569,0.0032043,0.0031738,0.0029297,0.0028687,0.0025940,0.0025024,0.0023804,0.0019836,0.0019226,0.0015259,0.0010986,0.0010071,0.0004578,0.0003357,-0.0001831,-0.0004578,-0.0007629,-0.0010681,-0.0013123,-0.0016479,-0.0018005,-0.0020447,-0.0021362,-0.0025330,-0.0024719,-0.0031128,-0.0029602,-0.0037231,-0.0035706,-0.0043640,-0.0044250,-0.0050964,-0.0053406,-0.0058899,-0.0061646,-0.0064697,-0.0068054,-0.0069275,-0.0071716,-0.0072327,-0.0071716,-0.0073853,-0.0071411,-0.0073853,-0.0071716,-0.0072937,-0.0074158,-0.0076294,-0.0077515,-0.0082397,-0.0083923,-0.0090942,-0.0093689,-0.0100098,-0.0105591,-0.0110168,-0.0115662,-0.0119629,-0.0122681,-0.0124817,-0.0126648,-0.0127563,-0.0127258,-0.0127563,-0.0125732,-0.0125427,-0.0123901,-0.0123596,-0.0122375,-0.0123901,-0.0123291,-0.0126648,-0.0126038,-0.0132141,-0.0132446,-0.0138550,-0.0140076,-0.0146179,-0.0146179,-0.0152283,-0.0151367,-0.0154419,-0.0154114,-0.0152588,-0.0152588,-0.0150146,-0.0147705,-0.0145264,-0.0140381,-0.0138855,-0.0133057,-0.0132446,-0.0127869,-0.0126343,-0.0125122,-0.0122681,-0.0122986,-0.0122375,-0.0121765,-0.0124512,-0.0121765,-0.0126343,-0.0123291,-0.0126648,-0.0123291,-0.0123596,-0.0122681,-0.0120239,-0.0117493,-0.0115356,-0.0111389,-0.0107727,-0.0104370,-0.0098267,-0.0097656,-0.0091248,-0.0089722,-0.0086060,-0.0084229,-0.0082397,-0.0081787,-0.0080566,-0.0080872,-0.0079651,-0.0081482,-0.0079956,-0.0080261,-0.0077820,-0.0076904,-0.0074768,-0.0071716,-0.0066223,-0.0061951,-0.0055847,-0.0050049,-0.0044556,-0.0035706,-0.0029602,-0.0024109,-0.0014954,-0.0011597,-0.0004272,-0.0000916,0.0002441,0.0008240,0.0008240,0.0012207,0.0013428,0.0014648,0.0016479,0.0017090,0.0018921,0.0019836,0.0021973,0.0022278,0.0024719,0.0025635,0.0027161,0.0028992,0.0029907,0.0030823,0.0032349,0.0032349,0.0033569,0.0032654,0.0032654,0.0032654,0.0032043,0.0032349,0.0031433,0.0031128,0.0032043,0.0032043,0.0033264,0.0033569,0.0033875,0.0036011,0.0037231,0.0038147,0.0040588,0.0040588,0.0041809,0.0043640,0.0043945,0.0044556,0.0045471,0.0046082,0.0043945,0.0047913,0.0042419,0.0046997,0.0042114,0.0044250,0.0041199,0.0041504,0.0039368,0.0038757,0.0038147,0.0034485,0.0036621,0.0031128,0.0032654,0.0029297,0.0029907,0.0025940,0.0028687,0.0023804,0.0026855,0.0022583,0.0025024,0.0021667,0.0023193,0.0021667,0.0021057,0.0021362,0.0019531,0.0019836,0.0017700,0.0017395,0.0014954,0.0014954,0.0011292,0.0011292,0.0007629,0.0007629,0.0004578,0.0004578,0.0003357,0.0001831,0.0003052,0.0001221,0.0003357,0.0003052,0.0004883,0.0006104,0.0007935,0.0008545,0.0010986,0.0010986,0.0011902,0.0010986,0.0010986,0.0009155,0.0007019,0.0004272,0.0000000,-0.0004272,-0.0008240,-0.0014038,-0.0017395,-0.0024109,-0.0026855,-0.0033264,-0.0034180,-0.0038757,-0.0039368,-0.0042114,-0.0041199,-0.0043030,-0.0041199,-0.0042419,-0.0040588,-0.0042114,-0.0042419,-0.0043640,-0.0046692,-0.0047607,-0.0053711,-0.0057068,-0.0064087,-0.0068054,-0.0075073,-0.0078735,-0.0086365,-0.0088806,-0.0093689,-0.0095825,-0.0097656,-0.0097656,-0.0097656,-0.0094910,-0.0093079,-0.0089111,-0.0087280,-0.0081482,-0.0081787,-0.0076599,-0.0077209,-0.0075684,-0.0076599,-0.0078430,-0.0080261,-0.0085449,-0.0086975,-0.0091553,-0.0095825,-0.0097046,-0.0101624,-0.0100708,-0.0103455,-0.0101013,-0.0099182,-0.0097656,-0.0091248,-0.0090332,-0.0083313,-0.0080566,-0.0075073,-0.0072937,-0.0070190,-0.0068054,-0.0068665,-0.0067139,-0.0071106,-0.0072021,-0.0075073,-0.0077515,-0.0079346,-0.0081787,-0.0084229,-0.0082397,-0.0083923,-0.0080261,-0.0076904,-0.0073547,-0.0066223,-0.0060730,-0.0054016,-0.0045471,-0.0039673,-0.0033264,-0.0025940,-0.0024414,-0.0018311,-0.0018921,-0.0016174,-0.0019531,-0.0020142,-0.0024719,-0.0026245,-0.0032959,-0.0033569,-0.0041504,-0.0039978,-0.0044861,-0.0042419,-0.0044250,-0.0039062,-0.0038452,-0.0030823,-0.0026550,-0.0019531,-0.0012512,-0.0007629,0.0000610,0.0005493,0.0009155,0.0013428,0.0012512,0.0015259,0.0012817,0.0011292,0.0007629,0.0003052,-0.0000305,-0.0004578,-0.0007935,-0.0010071,-0.0013123,-0.0010376,-0.0013428,-0.0007324,-0.0006104,0.0000305,0.0005493,0.0012207,0.0018311,0.0025024,0.0031433,0.0035400,0.0041199,0.0043030,0.0045166,0.0044861,0.0045166,0.0042725,0.0041504,0.0036926,0.0034790,0.0029907,0.0028992,0.0024414,0.0025940,0.0022583,0.0023804,0.0025940,0.0025635,0.0031128,0.0032349,0.0037231,0.0040283,0.0045776,0.0047302,0.0051575,0.0053711,0.0055237,0.0056763,0.0055237,0.0056152,0.0050964,0.0051880,0.0044861,0.0044250,0.0037231,0.0035095,0.0029297,0.0025635,0.0023804,0.0018921,0.0019226,0.0017395,0.0016785,0.0018616,0.0019531,0.0023804,0.0025635,0.0030518,0.0032654,0.0037537,0.0041504,0.0043335,0.0046387,0.0046997,0.0047302,0.0047302,0.0043640,0.0043335,0.0036926,0.0035400,0.0028687,0.0025635,0.0020142,0.0017090,0.0012817,0.0010376,0.0007935,0.0005798,0.0006104,0.0005188,0.0005798,0.0007019,0.0007629,0.0007629,0.0008850,0.0007019,0.0009155,0.0005798,0.0006409,0.0002441,0.0001526,-0.0001221,-0.0004883,-0.0007629,-0.0010376,-0.0013123,-0.0015259,-0.0017700,-0.0018005,-0.0020142,-0.0020142,-0.0019531,-0.0021362,-0.0018616,-0.0021057,-0.0018616,-0.0019531,-0.0019226,-0.0018921,-0.0019836,-0.0019836,-0.0020752,-0.0021667,-0.0023499,-0.0023499,-0.0025940,-0.0026245,-0.0027771,-0.0027466,-0.0029602,-0.0028687,-0.0028992,-0.0029297,-0.0027466,-0.0027466,-0.0025330,-0.0022888,-0.0021057,-0.0017395,-0.0016785,-0.0010986,-0.0012207,-0.0006714,-0.0007324,-0.0005188,-0.0004883,-0.0005188,-0.0005798,-0.0008850,-0.0009766,-0.0015564,-0.0015259,-0.0022583,-0.0023193,-0.0028687,-0.0029602,-0.0032654,-0.0032959,-0.0033875,-0.0030518,-0.0031738,-0.0025024,-0.0022278,-0.0017700,-0.0011292,-0.0009766,-0.0001526,0.0000000,0.0005188,0.0005493,0.0008240,0.0006409,0.0007019,0.0003967,0.0003357,-0.0000305,-0.0003052,-0.0003357,-0.0007324,-0.0005188,-0.0005493,-0.0003967,-0.0000305,0.0003357,0.0009155,0.0014954,0.0021057,0.0026855,0.0032959,0.0037537,0.0042419,0.0043945,0.0048218,0.0046082,0.0049133,0.0044250,0.0043945,0.0039673,0.0035095,0.0033569,0.0026245,0.0024414,0.0019226,0.0017090,0.0012207,0.0013123,0.0007935,0.0011292,0.0006714,0.0009460,0.0008545,0.0010071,0.0010986,0.0012512,0.0013733,0.0015259,0.0016479,0.0018311,0.0018616,0.0019836,0.0020142,0.0019531,0.0020752,0.0017395,0.0018311,0.0015259,0.0014343,0.0011597,0.0010071,0.0008240,0.0007629,0.0005798,0.0006409,0.0007019,0.0007019,0.0011597,0.0012512,0.0017090,0.0021057,0.0024109,0.0028076,0.0030823,0.0034485,0.0035400,0.0036011,0.0033569,0.0033875,0.0028381,0.0025330,0.0020142,0.0014343,0.0009155,0.0003662,-0.0001221,-0.0004883,-0.0007324,-0.0010376,-0.0008545,-0.0008240,-0.0004883,-0.0001831,0.0003052,0.0005798,0.0013123,0.0012207,0.0019226,0.0017090,0.0020447,0.0016479,0.0014954,0.0008240,0.0005188,-0.0003967,-0.0010376,-0.0017395,-0.0027161,-0.0031128,-0.0039673,-0.0042419,-0.0046387,-0.0047607,-0.0047302,-0.0047302,-0.0041199,-0.0040283,-0.0031738,-0.0029907,-0.0022583,-0.0020142,-0.0014343,-0.0014038,-0.0010681,-0.0013733,-0.0014038,-0.0019531,-0.0023804,-0.0031433,-0.0037537,-0.0047302,-0.0054626,-0.0062866,-0.0070190,-0.0075378,-0.0080872,-0.0083618,-0.0084839,-0.0086060,-0.0082092,-0.0080566,-0.0074463,-0.0070496,-0.0062561,-0.0058289,-0.0050354,-0.0047913,-0.0042419,-0.0040283,-0.0039062,-0.0038147,-0.0040283,-0.0042114,-0.0045471,-0.0048828,-0.0051880,-0.0056152,-0.0057678,-0.0060120,-0.0060120,-0.0058289,-0.0056458,-0.0051575,-0.0047302,-0.0039978,-0.0034180,-0.0026245,-0.0020752,-0.0012207,-0.0007629,-0.0003052,0.0001526,0.0001526,0.0003662,0.0002441,0.0000916,-0.0001221,-0.0003967,-0.0007019,-0.0009460,-0.0013123,-0.0011902,-0.0016174,-0.0012512,-0.0013733,-0.0009766,-0.0008850,-0.0002441,-0.0002136,0.0005188,0.0009460,0.0011902,0.0019531,0.0019836,0.0026550,0.0027161,0.0032654,0.0033569,0.0038757,0.0039978,0.0045166,0.0047302,0.0051575,0.0056763,0.0061035,0.0066223,0.0072632,0.0076904,0.0083923,0.0088196,0.0095520,0.0098572,0.0104980,0.0107422,0.0112305,0.0113831,0.0117798,0.0116882,0.0120239,0.0117798,0.0120850,0.0118103,0.0118408,0.0116272,0.0115662,0.0113831,0.0112610,0.0109558,0.0111084,0.0108337,0.0108337,0.0108337,0.0107117,0.0109558,0.0109558,0.0111694,0.0112610,0.0114746,0.0116272,0.0118713,0.0118713,0.0121460,0.0119629,0.0120850,0.0119019,0.0117493,0.0115356,0.0111694,0.0109863,0.0104370,0.0102234,0.0099487,0.0095825,0.0096130,0.0093079,0.0094910,0.0096436,0.0098267,0.0101929,0.0105896,0.0109863,0.0114746,0.0118103,0.0123901,0.0124512,0.0128479,0.0127258,0.0128174,0.0125122,0.0123901,0.0119324,0.0114441,0.0111694,0.0104980,0.0103455,0.0097961,0.0097656,0.0094299,0.0096741,0.0095520,0.0100098,0.0101318,0.0106201,0.0108032,0.0112915,0.0114441,0.0118103,0.0117493,0.0117188,0.0115051,0.0110474,0.0108032,0.0099487,0.0096130,0.0086060,0.0081482,0.0073242,0.0068359,0.0062561,0.0059204,0.0056152,0.0054321,0.0055542,0.0055237,0.0059509,0.0059509,0.0063477,0.0064392,0.0066528,0.0067444,0.0066223,0.0065002,0.0062256,0.0057373,0.0053711,0.0045776,0.0040588,0.0033875,0.0027466,0.0021057,0.0014648,0.0009766,0.0007324,0.0002747,0.0003357,-0.0000916,0.0001526,0.0000000,0.0002136,0.0001831,0.0001526,0.0002136,0.0001831,0.0000305,-0.0000916,-0.0003967,-0.0006409,-0.0010071,-0.0013428,-0.0016479,-0.0020752,-0.0023499,-0.0026245,-0.0028687,-0.0029297,-0.0031433,-0.0030823,-0.0032043,-0.0031738,-0.0031128,-0.0031128,-0.0031738,-0.0031128,-0.0033569,-0.0032349,-0.0036926,-0.0037842,-0.0041504,-0.0045471,-0.0047607,-0.0051880,-0.0055237,-0.0057068,-0.0060425,-0.0061951,-0.0063171,-0.0064697,-0.0063477,-0.0062866,-0.0060730,-0.0059814,-0.0055847,-0.0055542,-0.0050049,-0.0050354,-0.0046082,-0.0044250,-0.0042114,-0.0040588,-0.0039062,-0.0037231,-0.0038147,-0.0035400,-0.0036926,-0.0034790,-0.0035400,-0.0035400,-0.0033875,-0.0034790,-0.0034485,-0.0032654,-0.0034790,-0.0032349,-0.0032349,-0.0031738,-0.0029907,-0.0030212,-0.0026855,-0.0027466,-0.0023499,-0.0024109,-0.0020142,-0.0019531,-0.0016785,-0.0014648,-0.0013428,-0.0009460,-0.0009155,-0.0005188,-0.0004272,-0.0000916,0.0000610,0.0003967,0.0004883,0.0008545,0.0011292,0.0013733,0.0018005,0.0021057,0.0025024,0.0027771,0.0033569,0.0034790,0.0039062,0.0041199,0.0043335,0.0045471,0.0047302,0.0046692,0.0046692,0.0044861,0.0044250,0.0041809,0.0040283,0.0039368,0.0035400,0.0035706,0.0032959,0.0032654,0.0033264,0.0032349,0.0035095,0.0033875,0.0038147,0.0036926,0.0040894,0.0040894,0.0043335,0.0043945,0.0044861,0.0043945,0.0044861,0.0042419,0.0041504,0.0040588,0.0037537,0.0037231,0.0035095,0.0033875,0.0033875,0.0033264,0.0033569,0.0034180,0.0035400,0.0036926,0.0039978,0.0041809,0.0044556,0.0046692,0.0050049,0.0051575,0.0054016,0.0054626,0.0055237,0.0056763,0.0053406,0.0056152,0.0050964,0.0051880,0.0047607,0.0044556,0.0043030,0.0038452,0.0035706,0.0033875,0.0029602,0.0029297,0.0025940,0.0028076,0.0025330,0.0028687,0.0028687,0.0032043,0.0035095,0.0037842,0.0042114,0.0048218,0.0051270,0.0057373,0.0059509,0.0064392,0.0066528,0.0067749,0.0068970,0.0067139,0.0066833,0.0062866,0.0060120,0.0054932,0.0050659,0.0046082,0.0040588,0.0035706,0.0031738,0.0027771,0.0024109,0.0021973,0.0018921,0.0019531,0.0017395,0.0018311,0.0017395,0.0020142,0.0018311,0.0019836,0.0019531,0.0018921,0.0019226,0.0016479,0.0015564,0.0012207,0.0009155,0.0005493,0.0000305,-0.0002136,-0.0008850,-0.0011902,-0.0017700,-0.0021057,-0.0025024,-0.0027771,-0.0030823,-0.0030823,-0.0034790,-0.0031738,-0.0033875,-0.0031738,-0.0030212,-0.0028381,-0.0026245,-0.0022583,-0.0022888,-0.0018616,-0.0018005,-0.0017090,-0.0015564,-0.0017090,-0.0016174,-0.0019531,-0.0018921,-0.0022583,-0.0023193,-0.0026855,-0.0028076,-0.0028687,-0.0031128,-0.0029297,-0.0030823,-0.0027466,-0.0026550,-0.0022888,-0.0019226,-0.0014648,-0.0011292,-0.0006409,-0.0002747,-0.0000305,0.0003357,0.0003967,0.0007019,0.0005798,0.0007324,0.0004272,0.0004272,0.0000305,-0.0001221,-0.0003357,-0.0006409,-0.0005493,-0.0010376,-0.0007935,-0.0011292,-0.0007324,-0.0009155,-0.0005493,-0.0004578,-0.0003052,0.0001831,0.0001221,0.0006104,0.0006409,0.0010071,0.0010986,0.0013123,0.0015869,0.0015564,0.0019836,0.0017700,0.0021973,0.0020142,0.0022583,0.0023193,0.0023804,0.0024414,0.0024414,0.0024414,0.0024414,0.0025330,0.0024109,0.0024719,0.0024414,0.0025940,0.0025635,0.0027466,0.0028992,0.0029907,0.0033569,0.0035400,0.0038452,0.0043030,0.0043945,0.0048828,0.0048828,0.0053711,0.0052185,0.0056152,0.0054016,0.0054626,0.0054016,0.0052795,0.0050659,0.0049438,0.0046082,0.0045776,0.0041504,0.0042419,0.0038147,0.0039368,0.0035706,0.0037842,0.0035400,0.0036926,0.0035400,0.0034485,0.0034485,0.0031738,0.0032654,0.0030518,0.0027771,0.0025635,0.0023193,0.0020447,0.0019531,0.0016174,0.0014038,0.0011292,0.0010376,0.0007935,0.0006409,0.0004883,0.0003662,0.0002441,0.0000610,0.0001221,-0.0001221,-0.0000916,-0.0002441,-0.0003357,-0.0003662,-0.0004272,-0.0004272,-0.0004578,-0.0004578,-0.0004272,-0.0004272,-0.0003052,-0.0004272,-0.0003052,-0.0002747,-0.0003662,-0.0003662,-0.0004578,-0.0005493,-0.0006714,-0.0007629,-0.0009460,-0.0011902,-0.0012512,-0.0014954,-0.0016479,-0.0016479,-0.0020142,-0.0018005,-0.0021667,-0.0020447,-0.0021667,-0.0023193,-0.0022888,-0.0026245,-0.0024719,-0.0028687,-0.0029297,-0.0032043,-0.0035400,-0.0036621,-0.0040283,-0.0040588,-0.0044556,-0.0044250,-0.0046692,-0.0046082,-0.0046082,-0.0045471,-0.0044250,-0.0042419,-0.0042725,-0.0041199,-0.0039062,-0.0039673,-0.0038757,-0.0039368,-0.0042419,-0.0043335,-0.0048523,-0.0050964,-0.0055542,-0.0061035,-0.0066833,-0.0071716,-0.0079956,-0.0083008,-0.0089722,-0.0094299,-0.0098572,-0.0101929,-0.0106506,-0.0108032,-0.0110168,-0.0110474,-0.0112610,-0.0110168,-0.0112305,-0.0108032,-0.0108948,-0.0104065,-0.0102539,-0.0099792,-0.0096130,-0.0094604,-0.0089722,-0.0089111,-0.0085754,-0.0084534,-0.0083008,-0.0081482,-0.0080872,-0.0081177,-0.0078430,-0.0081177,-0.0077820,-0.0079346,-0.0075989,-0.0076294,-0.0072021,-0.0070496,-0.0065613,-0.0060730,-0.0057373,-0.0049744,-0.0046387,-0.0039368,-0.0034180,-0.0031738,-0.0026245,-0.0026245,-0.0022583,-0.0023193,-0.0023193,-0.0024414,-0.0027161,-0.0028076,-0.0031433,-0.0033264,-0.0034485,-0.0036011,-0.0034180,-0.0034790,-0.0030212,-0.0027466,-0.0022888,-0.0016174,-0.0010986,-0.0003967,0.0001526,0.0008545,0.0012512,0.0018921,0.0021667,0.0024719,0.0027161,0.0027771,0.0030212,0.0028992,0.0030518,0.0030518,0.0030518,0.0034180,0.0034485,0.0038757,0.0042114,0.0046692,0.0052185,0.0057678,0.0064087,0.0069275,0.0075989,0.0079956,0.0086060,0.0089111,0.0091248,0.0093994,0.0092163,0.0093994,0.0090332,0.0088501,0.0085449,0.0080566,0.0078430,0.0072937,0.0069885,0.0067139,0.0063477,0.0064087,0.0060425,0.0065002,0.0061646,0.0067444,0.0069275,0.0073242,0.0078125,0.0081177,0.0087585,0.0089722,0.0095825,0.0097351,0.0100098,0.0101013,0.0100098,0.0100098,0.0096130,0.0093994,0.0088501,0.0083923,0.0079346,0.0073853,0.0068054,0.0065613,0.0059509,0.0060730,0.0056152,0.0058899,0.0058899,0.0060730,0.0064697,0.0065613,0.0069885,0.0072632,0.0074463,0.0074768,0.0074768,0.0072327,0.0069885,0.0063477,0.0058594,0.0049438,0.0043335,0.0032959,0.0025024,0.0016479,0.0009155,0.0003662,-0.0001831,-0.0003967,-0.0006104,-0.0005493,-0.0003052,-0.0002441,0.0004883,0.0004883,0.0013428,0.0014343,0.0019531,0.0020752,0.0022888,0.0021667,0.0021973,0.0017700,0.0015869,0.0010681,0.0005188,0.0001526,-0.0005188,-0.0007935,-0.0013428,-0.0015259,-0.0017395,-0.0018921,-0.0018616,-0.0019226,-0.0015564,-0.0015869,-0.0012207,-0.0011902,-0.0009460,-0.0009155,-0.0009460,-0.0010071,-0.0013428,-0.0016479,-0.0021667,-0.0027161,-0.0033875,-0.0040894,-0.0048523,-0.0057068,-0.0062866,-0.0071716,-0.0077515,-0.0083313,-0.0089111,-0.0091553,-0.0096741,-0.0098267,-0.0098572,-0.0101929,-0.0100708,-0.0102539,-0.0102539,-0.0102844,-0.0104065,-0.0105591,-0.0106812,-0.0109863,-0.0111694,-0.0115662,-0.0117493,-0.0122375,-0.0124207,-0.0128784,-0.0130920,-0.0134277,-0.0135803,-0.0137329,-0.0138855,-0.0138550,-0.0139771,-0.0139465,-0.0138550,-0.0139465,-0.0137024,-0.0137939,-0.0136414,-0.0135803,-0.0136719,-0.0135498,-0.0135193,-0.0134583,-0.0133667,-0.0133972,-0.0132446,-0.0130615,-0.0130920,-0.0126953,-0.0127869,-0.0123291,-0.0122070,-0.0120239,-0.0117493,-0.0116882,-0.0113831,-0.0112000,-0.0111084,-0.0109863,-0.0108337,-0.0106812,-0.0105896,-0.0104065,-0.0102539,-0.0099792,-0.0098267,-0.0094604,-0.0093079,-0.0086365,-0.0086365,-0.0078125,-0.0078125,-0.0070190,-0.0069885,-0.0064087,-0.0061951,-0.0059204,-0.0057373,-0.0057068,-0.0056152,-0.0056458,-0.0056763,-0.0057678,-0.0058899,-0.0058899,-0.0059509,-0.0060425,-0.0058289,-0.0058899,-0.0056152,-0.0053406,-0.0050354,-0.0046387,-0.0041809,-0.0038147,-0.0032959,-0.0028687,-0.0025024,-0.0021057,-0.0018921,-0.0015564,-0.0014954,-0.0014648,-0.0013733,-0.0015564,-0.0015259,-0.0016785,-0.0018921,-0.0018921,-0.0021362,-0.0020752,-0.0021973,-0.0018311,-0.0020447,-0.0014038,-0.0013123,-0.0007019,-0.0003662,0.0002747,0.0007019,0.0013428,0.0019226,0.0022888,0.0029297,0.0032043,0.0034790,0.0037842,0.0036926,0.0038452,0.0037537,0.0033875,0.0035706,0.0028687,0.0030823,0.0025635,0.0027161,0.0023499,0.0025940,0.0025024,0.0027771,0.0030518,0.0034180,0.0037842,0.0044250,0.0046692,0.0054932,0.0056152,0.0063171,0.0063782,0.0067139,0.0066528,0.0067139,0.0063477,0.0061035,0.0056152,0.0049438,0.0046082,0.0036926,0.0032349,0.0025330,0.0019836,0.0014954,0.0012817,0.0009155,0.0010681,0.0009155,0.0012817,0.0015564,0.0019531,0.0024109,0.0028381,0.0033569,0.0037231,0.0042114,0.0043030,0.0044861,0.0043640,0.0042419,0.0039062,0.0035095,0.0029602,0.0023499,0.0017395,0.0010681,0.0003357,-0.0001526,-0.0007019,-0.0010376,-0.0012512,-0.0013428,-0.0014038,-0.0011902,-0.0010376,-0.0005493,-0.0003052,0.0002136,0.0005188,0.0008240,0.0011597,0.0012207,0.0011902,0.0010681,0.0007019,0.0003662,-0.0003052,-0.0008240,-0.0017090,-0.0022888,-0.0033264,-0.0036621,-0.0046082,-0.0049133,-0.0054321,-0.0056458,-0.0057373,-0.0057678,-0.0055847,-0.0054626,-0.0050354,-0.0047913,-0.0044861,-0.0041504,-0.0039062,-0.0037231,-0.0038452,-0.0038452,-0.0041199,-0.0043640,-0.0049744,-0.0053406,-0.0061340,-0.0065308,-0.0073547,-0.0076599,-0.0084839,-0.0086060,-0.0090942,-0.0091248,-0.0091858,-0.0090027,-0.0089722,-0.0084534,-0.0081482,-0.0076904,-0.0072021,-0.0069885,-0.0063782,-0.0063171,-0.0058899,-0.0060425,-0.0060425,-0.0062256,-0.0066528,-0.0068970,-0.0075989,-0.0079346,-0.0086365,-0.0090942,-0.0095215,-0.0098877,-0.0101013,-0.0101624,-0.0100708,-0.0098267,-0.0093384,-0.0088806,-0.0081787,-0.0074158,-0.0066223,-0.0058594,-0.0051270,-0.0046387,-0.0038757,-0.0038147,-0.0034180,-0.0037231,-0.0037537,-0.0040894,-0.0046387,-0.0050964,-0.0057373,-0.0061340,-0.0068665,-0.0069275,-0.0074463,-0.0072327,-0.0072021,-0.0068054,-0.0063477,-0.0054626,-0.0048523,-0.0037842,-0.0030212,-0.0020447,-0.0014343,-0.0005798,-0.0002136,0.0000916,0.0002136,-0.0000305,-0.0001526,-0.0007019,-0.0011902,-0.0018616,-0.0024414,-0.0030212,-0.0035095,-0.0039368,-0.0040283,-0.0042114,-0.0038452,-0.0036011,-0.0030212,-0.0023804,-0.0017700,-0.0008545,-0.0002747,0.0006104,0.0010376,0.0016479,0.0019836,0.0019836,0.0021973,0.0018311,0.0016174,0.0012817,0.0006104,0.0003662,-0.0003662,-0.0005188,-0.0011292,-0.0010376,-0.0011902,-0.0010681,-0.0005798,-0.0004272,0.0003357,0.0009460,0.0015869,0.0025940,0.0030823,0.0039978,0.0043945,0.0049133,0.0052490,0.0054321,0.0054016,0.0053101,0.0050049,0.0044861,0.0041199,0.0034180,0.0030212,0.0024719,0.0019836,0.0017090,0.0015259,0.0013123,0.0016785,0.0017700,0.0022888,0.0028687,0.0032654,0.0041199,0.0047607,0.0052185,0.0060120,0.0062256,0.0066528,0.0066833,0.0067139,0.0064392,0.0060730,0.0055847,0.0048828,0.0043945,0.0035095,0.0029297,0.0022583,0.0019836,0.0014038,0.0014648,0.0011292,0.0015869,0.0015259,0.0021667,0.0023804,0.0030518,0.0035095,0.0039673,0.0044556,0.0045776,0.0049438,0.0046997,0.0047302,0.0043945,0.0038757,0.0033875,0.0026245,0.0019836,0.0012512,0.0005188,-0.0001526,-0.0006714,-0.0012512,-0.0013123,-0.0017395,-0.0016479,-0.0016785,-0.0012512,-0.0012207,-0.0006104,-0.0005188,0.0001221,0.0003967,0.0009155,0.0008850,0.0012512,0.0011292,0.0011902,0.0009155,0.0007935,0.0001831,0.0000000,-0.0007935,-0.0011292,-0.0017700,-0.0021362,-0.0027466,-0.0029297,-0.0034180,-0.0032959,-0.0034180,-0.0032654,-0.0030518,-0.0027466,-0.0021973,-0.0018311,-0.0012207,-0.0008545,-0.0002441,0.0001221,0.0003662,0.0007019,0.0005798,0.0006104,0.0003662,0.0001526,-0.0003967,-0.0006714,-0.0014648,-0.0016479,-0.0024109,-0.0026245,-0.0030518,-0.0031128,-0.0033875,-0.0030823,-0.0029907,-0.0025635,-0.0021362,-0.0016479,-0.0010376,-0.0006104,0.0002136,0.0003662,0.0010986,0.0011902,0.0014343,0.0013733,0.0014038,0.0010376,0.0008240,0.0003662,0.0000916,-0.0005798,-0.0007324,-0.0013123,-0.0013733,-0.0017090,-0.0015869,-0.0016785,-0.0012817,-0.0012512,-0.0006409,-0.0003967,0.0001831,0.0006104,0.0009766,0.0016174,0.0017395,0.0021973,0.0023499,0.0022583,0.0025635,0.0020142,0.0023193,0.0016785,0.0018005,0.0013733,0.0011902,0.0010986,0.0009460,0.0008850,0.0010681,0.0009766,0.0014038,0.0015564,0.0019836,0.0023499,0.0025940,0.0030823,0.0032959,0.0036011,0.0038147,0.0038147,0.0039062,0.0038452,0.0035706,0.0035095,0.0029602,0.0027771,0.0022278,0.0019531,0.0015869,0.0012207,0.0010071,0.0007019,0.0007324,0.0004578,0.0006104,0.0006104,0.0006714,0.0009460,0.0009766,0.0012817,0.0012207,0.0014343,0.0014648,0.0015564,0.0015259,0.0013123,0.0014343,0.0010681,0.0011292,0.0008240,0.0006714,0.0006714,0.0002747,0.0006714,0.0003357,0.0006409,0.0006409,0.0009155,0.0009460,0.0014038,0.0014038,0.0019836,0.0019226,0.0023804,0.0023804,0.0026245,0.0026855,0.0026245,0.0026855,0.0024109,0.0024414,0.0020142,0.0019531,0.0013733,0.0013123,0.0007324,0.0006409,0.0003052,0.0000000,-0.0001526,-0.0001831,-0.0003357,-0.0002136,-0.0003357,-0.0001526,-0.0000916,0.0001526,0.0002747,0.0003357,0.0004883,0.0006409,0.0004578,0.0005188,0.0001831,-0.0000916,-0.0003967,-0.0009766,-0.0012512,-0.0019836,-0.0023193,-0.0030518,-0.0033264,-0.0039062,-0.0041504,-0.0044556,-0.0046997,-0.0046692,-0.0046997,-0.0047302,-0.0044556,-0.0044250,-0.0042725,-0.0040588,-0.0041504,-0.0039978,-0.0043030,-0.0043945,-0.0046387,-0.0051575,-0.0053711,-0.0060730,-0.0064392,-0.0068359,-0.0074463,-0.0075378,-0.0079651,-0.0080261,-0.0079346,-0.0079041,-0.0076599,-0.0071411,-0.0067444,-0.0062561,-0.0055847,-0.0052490,-0.0045776,-0.0041199,-0.0038452,-0.0034485,-0.0033569,-0.0032043,-0.0033264,-0.0033569,-0.0035095,-0.0037231,-0.0038452,-0.0042725,-0.0040283,-0.0043640,-0.0041504,-0.0040894,-0.0038452,-0.0035095,-0.0030518,-0.0026855,-0.0019836,-0.0016174,-0.0010376,-0.0006104,-0.0001831,0.0000916,0.0003967,0.0003967,0.0004272,0.0003052,0.0001221,-0.0001831,-0.0005493,-0.0010376,-0.0011597,-0.0017090,-0.0017395,-0.0021057,-0.0020752,-0.0019836,-0.0018005,-0.0013428,-0.0009155,-0.0003357,0.0004272,0.0010681,0.0019226,0.0026550,0.0033569,0.0040588,0.0046082,0.0048828,0.0054016,0.0053406,0.0055847,0.0055237,0.0053101,0.0053101,0.0049133,0.0050964,0.0046997,0.0049744,0.0049744,0.0052490,0.0057068,0.0060730,0.0068970,0.0073242,0.0084229,0.0089417,0.0098877,0.0105591,0.0112610,0.0118103,0.0122070,0.0125732,0.0125122,0.0128479,0.0123901,0.0123901,0.0118713,0.0116577,0.0111389,0.0107727,0.0104980,0.0101013,0.0100098,0.0097046,0.0097656,0.0097046,0.0098572,0.0099182,0.0100708,0.0103149,0.0104065,0.0108032,0.0107422,0.0110474,0.0109253,0.0110779,0.0110474,0.0108643,0.0110168,0.0106201,0.0107727,0.0104065,0.0106812,0.0101929,0.0105286,0.0102539,0.0103149,0.0103760,0.0103455,0.0104675,0.0104370,0.0103760,0.0106506,0.0103149,0.0106201,0.0103455,0.0104675,0.0103760,0.0102539,0.0101929,0.0101318,0.0101013,0.0100708,0.0100403,0.0100403,0.0101318,0.0101929,0.0102234,0.0103760,0.0106506,0.0105591,0.0109558,0.0108948,0.0111694,0.0112000,0.0112915,0.0112305,0.0113220,0.0111084,0.0109558,0.0107727,0.0104980,0.0102539,0.0097351,0.0094604,0.0090027,0.0086060,0.0083313,0.0078735,0.0075989,0.0074158,0.0070190,0.0070496,0.0068359,0.0068054,0.0067749,0.0066833,0.0066528,0.0066528,0.0064697,0.0062561,0.0060425,0.0055847,0.0053101,0.0046387,0.0041199,0.0033875,0.0027466,0.0022278,0.0013428,0.0008850,0.0000000,-0.0001526,-0.0008240,-0.0009460,-0.0012207,-0.0013733,-0.0013733,-0.0013428,-0.0012512,-0.0011292,-0.0009766,-0.0008545,-0.0007324,-0.0007629,-0.0006104,-0.0008850,-0.0008240,-0.0012512,-0.0013428,-0.0018311,-0.0019531,-0.0024719,-0.0026550,-0.0031433,-0.0032043,-0.0037842,-0.0035706,-0.0040588,-0.0039368,-0.0039978,-0.0040894,-0.0039062,-0.0039673,-0.0036926,-0.0037842,-0.0036011,-0.0035400,-0.0035095,-0.0034485,-0.0035706,-0.0035706,-0.0037537,-0.0038147,-0.0041199,-0.0042419,-0.0045776,-0.0046997,-0.0048828,-0.0050659,-0.0053711,-0.0053711,-0.0055237,-0.0054626,-0.0053711,-0.0053406,-0.0051575,-0.0049133,-0.0046692,-0.0042725,-0.0040894,-0.0036621,-0.0033875,-0.0031738,-0.0027161,-0.0027466,-0.0023804,-0.0022888,-0.0022888,-0.0021057,-0.0022888,-0.0021973,-0.0022888,-0.0022888,-0.0024414,-0.0024109,-0.0023193,-0.0023804,-0.0021362,-0.0021973,-0.0018616,-0.0017090,-0.0014343,-0.0011902,-0.0008850,-0.0007019,-0.0003052,-0.0001526,0.0002441,0.0003357,0.0007935,0.0009155,0.0011292,0.0014343,0.0015259,0.0019531,0.0019836,0.0022888,0.0023804,0.0025635,0.0026550,0.0028076,0.0027161,0.0028076,0.0026245,0.0026245,0.0024109,0.0021057,0.0021057,0.0017090,0.0016479,0.0013123,0.0013428,0.0011292,0.0012817,0.0010986,0.0014038,0.0014648,0.0017395,0.0019531,0.0021667,0.0026245,0.0026855,0.0031433,0.0032043,0.0033875,0.0035095,0.0036011,0.0034485,0.0036011,0.0034180,0.0033264,0.0032654,0.0030518,0.0031433,0.0029907,0.0031128,0.0031738,0.0033569,0.0033875,0.0038452,0.0039368,0.0042725,0.0046082,0.0046997,0.0051575,0.0052185,0.0054932,0.0053711,0.0055542,0.0053711,0.0053406,0.0051880,0.0048828,0.0046692,0.0044250,0.0041504,0.0039062,0.0037231,0.0035400,0.0034485,0.0034485,0.0033875,0.0033875,0.0036621,0.0036926,0.0040588,0.0041199,0.0044861,0.0045776,0.0049438,0.0048218,0.0050659,0.0050354,0.0049133,0.0049438,0.0045471,0.0044556,0.0040894,0.0038757,0.0034485,0.0032959,0.0030518,0.0027466,0.0028687,0.0025635,0.0028687,0.0026855,0.0031433,0.0032043,0.0034485,0.0037231,0.0039673,0.0040588,0.0043335,0.0040588,0.0043335,0.0039368,0.0038757,0.0034790,0.0030823,0.0026855,0.0022583,0.0016174,0.0014648,0.0007019,0.0006104,0.0000305,0.0000000,-0.0003662,-0.0003357,-0.0005798,-0.0003967,-0.0006714,-0.0003967,-0.0006409,-0.0005493,-0.0007019,-0.0007629,-0.0009155,-0.0010376,-0.0012817,-0.0014038,-0.0016479,-0.0018311,-0.0019531,-0.0020752,-0.0022278,-0.0021973,-0.0023499,-0.0022583,-0.0024414,-0.0021667,-0.0025024,-0.0021362,-0.0025330,-0.0022888,-0.0025330,-0.0025024,-0.0026855,-0.0026855,-0.0027771,-0.0028381,-0.0026855,-0.0027466,-0.0025635,-0.0023804,-0.0021057,-0.0018005,-0.0014954,-0.0011597,-0.0008240,-0.0005188,-0.0002747,0.0000000,0.0000916,0.0003052,0.0002136,0.0002747,0.0000610,0.0000610,-0.0002136,-0.0003357,-0.0005188,-0.0005798,-0.0007935,-0.0006714,-0.0008850,-0.0006104,-0.0006714,-0.0002747,-0.0001221,0.0003357,0.0004883,0.0009155,0.0011902,0.0015259,0.0019836,0.0019836,0.0026550,0.0024109,0.0030212,0.0028992,0.0031128,0.0031433,0.0031128,0.0032959,0.0030212,0.0033264,0.0028992,0.0029907,0.0027771,0.0026550,0.0025635,0.0025330,0.0022278,0.0023499,0.0021057,0.0022278,0.0021362,0.0021973,0.0022278,0.0022888,0.0025635,0.0025635,0.0027771,0.0029297,0.0029907,0.0031128,0.0032349,0.0031738,0.0031738,0.0031128,0.0029602,0.0028992,0.0028381,0.0025940,0.0025635,0.0024414,0.0023193,0.0024719,0.0022278,0.0025635,0.0024719,0.0026550,0.0030518,0.0028381,0.0034180,0.0032654,0.0036316,0.0035095,0.0036621,0.0034790,0.0034485,0.0031738,0.0031128,0.0026245,0.0025940,0.0020142,0.0019531,0.0016174,0.0013428,0.0012817,0.0011597,0.0011597,0.0011292,0.0012207,0.0012817,0.0014954,0.0015869,0.0017395,0.0018616,0.0018616,0.0019531,0.0018311,0.0018005,0.0015259,0.0013733,0.0010376,0.0007629,0.0004272,0.0000305,-0.0003357,-0.0006409,-0.0009766,-0.0012207,-0.0014343,-0.0014648,-0.0016479,-0.0014648,-0.0015869,-0.0011597,-0.0012817,-0.0008850,-0.0009155,-0.0004578,-0.0006409,-0.0003357,-0.0005493,-0.0003967,-0.0007629,-0.0009460,-0.0011902,-0.0017090,-0.0019226,-0.0024719,-0.0028687,-0.0032043,-0.0036316,-0.0038452,-0.0039368,-0.0040894,-0.0039978,-0.0039673,-0.0037537,-0.0035400,-0.0032043,-0.0030518,-0.0028076,-0.0026855,-0.0027161,-0.0026550,-0.0029907,-0.0032043,-0.0037231,-0.0041809,-0.0049133,-0.0055237,-0.0063782,-0.0067749,-0.0077820,-0.0080566,-0.0087891,-0.0089111,-0.0093079,-0.0093079,-0.0093689,-0.0092773,-0.0090027,-0.0088806,-0.0085754,-0.0085449,-0.0081482,-0.0083008,-0.0080261,-0.0083923,-0.0084229,-0.0087585,-0.0090332,-0.0092773,-0.0097961,-0.0099182,-0.0103149,-0.0104980,-0.0104980,-0.0105896,-0.0103760,-0.0101318,-0.0097961,-0.0093384,-0.0088501,-0.0084534,-0.0078125,-0.0074768,-0.0068970,-0.0067444,-0.0063477,-0.0063171,-0.0061035,-0.0061951,-0.0064087,-0.0063782,-0.0067444,-0.0067749,-0.0071716,-0.0070801,-0.0072937,-0.0072327,-0.0071716,-0.0070801,-0.0066833,-0.0063782,-0.0061035,-0.0054626,-0.0050964,-0.0045166,-0.0039978,-0.0036011,-0.0028687,-0.0027161,-0.0019531,-0.0019836,-0.0013428,-0.0014038,-0.0010681,-0.0009155,-0.0007324,-0.0006104,-0.0005493,-0.0003967,-0.0002441,-0.0000916,0.0000305,0.0003357,0.0005798,0.0009155,0.0010986,0.0015564,0.0017395,0.0021973,0.0023804,0.0027771,0.0028687,0.0032654,0.0031433,0.0035706,0.0033569,0.0035400,0.0036316,0.0033264,0.0036926,0.0032349,0.0036621,0.0034180,0.0036621,0.0036621,0.0038757,0.0041504,0.0042114,0.0046692,0.0049133,0.0051880,0.0056458,0.0057983,0.0062256,0.0063782,0.0066833,0.0068054,0.0069275,0.0070801,0.0071716,0.0071106,0.0072632,0.0070801,0.0071411,0.0071106,0.0070190,0.0071106,0.0070801,0.0069885,0.0071716,0.0069580,0.0072632,0.0069580,0.0073853,0.0069885,0.0074158,0.0072021,0.0073853,0.0074158,0.0075378,0.0075378,0.0078125,0.0075684,0.0079956,0.0076904,0.0081177,0.0078430,0.0080261,0.0079346,0.0077209,0.0077209,0.0073242,0.0071411,0.0067749,0.0063782,0.0059509,0.0054932,0.0050354,0.0047302,0.0041199,0.0040588,0.0036621,0.0035400,0.0036011,0.0034790,0.0037537,0.0039368,0.0041809,0.0045471,0.0049438,0.0053101,0.0055542,0.0058899,0.0057678,0.0062561,0.0057983,0.0058289,0.0052795,0.0049133,0.0043030,0.0037231,0.0027161,0.0021667,0.0010986,0.0005798,-0.0002747,-0.0008850,-0.0014038,-0.0018311,-0.0021667,-0.0021973,-0.0023193,-0.0022278,-0.0019836,-0.0019531,-0.0014648,-0.0014343,-0.0010986,-0.0010376,-0.0009155,-0.0011902,-0.0011292,-0.0018005,-0.0020447,-0.0028076,-0.0034790,-0.0042725,-0.0050659,-0.0058899,-0.0066528,-0.0072937,-0.0078735,-0.0083313,-0.0084839,-0.0086365,-0.0086060,-0.0081177,-0.0080872,-0.0073853,-0.0072021,-0.0065308,-0.0063782,-0.0058289,-0.0058594,-0.0057068,-0.0057068,-0.0062866,-0.0064392,-0.0074158,-0.0077515,-0.0087891,-0.0094604,-0.0104980,-0.0110474,-0.0119629,-0.0123291,-0.0129700,-0.0130310,-0.0133362,-0.0131836,-0.0129089,-0.0130615,-0.0120850,-0.0122986,-0.0114441,-0.0113220,-0.0111084,-0.0105896,-0.0107727,-0.0105286,-0.0108032,-0.0107117,-0.0111694,-0.0113525,-0.0116272,-0.0121155,-0.0121155,-0.0126038,-0.0124817,-0.0128174,-0.0127258,-0.0126648,-0.0123901,-0.0122375,-0.0118408,-0.0116272,-0.0109558,-0.0108337,-0.0101013,-0.0101013,-0.0093384,-0.0093079,-0.0088501,-0.0088501,-0.0086975,-0.0086975,-0.0087891,-0.0088196,-0.0090332,-0.0093689,-0.0093689,-0.0099182,-0.0098877,-0.0101929,-0.0103455,-0.0101929,-0.0104065,-0.0100708,-0.0099487,-0.0096436,-0.0090332,-0.0086975,-0.0081177,-0.0073547,-0.0070190,-0.0059509,-0.0057983,-0.0048828,-0.0047913,-0.0041199,-0.0041809,-0.0037842,-0.0038452,-0.0039368,-0.0038757,-0.0042419,-0.0042419,-0.0045471,-0.0046387,-0.0048523,-0.0046387,-0.0049438,-0.0042114,-0.0046692,-0.0036316,-0.0037842,-0.0028076,-0.0026855,-0.0018616,-0.0016479,-0.0009155,-0.0007019,-0.0002441,0.0000610,0.0001831,0.0004578,0.0003052,0.0005188,0.0002136,0.0003967,0.0000610,0.0001526,-0.0000305,0.0000000,-0.0000610,-0.0000305,0.0001221,0.0001526,0.0004272,0.0005493,0.0007629,0.0010681,0.0012512,0.0014648,0.0018921,0.0018921,0.0022888,0.0022888,0.0026245,0.0025330,0.0028076,0.0025940,0.0028992,0.0025940,0.0027771,0.0025330,0.0024414,0.0023193,0.0022583,0.0022278,0.0021973,0.0021667,0.0023193,0.0023499,0.0026550,0.0028381,0.0031433,0.0035095,0.0037842,0.0042419,0.0043945,0.0049133,0.0048828,0.0052490,0.0051270,0.0051575,0.0051575,0.0047302,0.0047302,0.0041504,0.0039062,0.0035095,0.0030212,0.0027466,0.0022888,0.0021667,0.0017090,0.0017700,0.0016174,0.0014648,0.0017090,0.0015259,0.0017700,0.0015869,0.0016785,0.0018005,0.0014343,0.0015869,0.0010681,0.0012512,0.0007019,0.0005798,0.0000916,-0.0001526,-0.0004578,-0.0007019,-0.0009766,-0.0012512,-0.0013733,-0.0014648,-0.0016785,-0.0015564,-0.0017090,-0.0016174,-0.0016479,-0.0016785,-0.0017090,-0.0017395,-0.0017090,-0.0020142,-0.0018311,-0.0022888,-0.0021362,-0.0025940,-0.0025635,-0.0029297,-0.0030212,-0.0033569,-0.0035095,-0.0037537,-0.0038452,-0.0042419,-0.0042419,-0.0046997,-0.0044556,-0.0049744,-0.0047302,-0.0051270,-0.0050659,-0.0051270,-0.0051880,-0.0052185,-0.0052795,-0.0052795,-0.0054321,-0.0054016,-0.0056152,-0.0056763,-0.0058289,-0.0060425,-0.0063477,-0.0063782,-0.0067444,-0.0069275,-0.0070190,-0.0072632,-0.0072632,-0.0074768,-0.0071411,-0.0072632,-0.0068054,-0.0067749,-0.0063477,-0.0060425,-0.0057373,-0.0052795,-0.0051880,-0.0047913,-0.0047607,-0.0045471,-0.0046997,-0.0046082,-0.0050659,-0.0048523,-0.0056152,-0.0053711,-0.0061646,-0.0060425,-0.0064392,-0.0064697,-0.0065918,-0.0065002,-0.0064697,-0.0061951,-0.0060425,-0.0057068,-0.0054932,-0.0052185,-0.0048828,-0.0047913,-0.0044250,-0.0046387,-0.0043335,-0.0046692,-0.0043640,-0.0049438,-0.0046692,-0.0051575,-0.0050964,-0.0052185,-0.0052795,-0.0052795,-0.0051270,-0.0050049,-0.0047302,-0.0043640,-0.0039978,-0.0037231,-0.0031128,-0.0028992,-0.0022583,-0.0021362,-0.0016174,-0.0014954,-0.0011597,-0.0011902,-0.0009766,-0.0010681,-0.0009155,-0.0010986,-0.0011292,-0.0011292,-0.0013428,-0.0011292,-0.0013428,-0.0012207,-0.0011902,-0.0011597,-0.0009155,-0.0007019,-0.0006714,-0.0003052,-0.0003357,-0.0000610,0.0000916,0.0000916,0.0002747,0.0002747,0.0001526,0.0001221,0.0000610,-0.0001526,-0.0001221,-0.0002441,-0.0003967,-0.0003052,-0.0002747,-0.0001831,0.0001831,0.0002441,0.0008850,0.0010986,0.0015869,0.0022583,0.0024719,0.0031738,0.0035400,0.0037842,0.0043030,0.0042725,0.0043945,0.0043640,0.0042114,0.0040894,0.0037537,0.0036316,0.0031738,0.0031738,0.0029602,0.0028381,0.0029297,0.0030212,0.0033875,0.0036926,0.0039368,0.0045471,0.0047607,0.0051880,0.0056458,0.0055847,0.0059204,0.0056458,0.0055237,0.0051270,0.0046692,0.0041199,0.0033264,0.0027771,0.0019531,0.0014954,0.0008545,0.0003967,0.0001526,-0.0000305,-0.0000305,0.0000610,0.0002747,0.0006104,0.0009155,0.0012512,0.0016479,0.0018311,0.0021362,0.0022583,0.0021667,0.0022583,0.0018005,0.0017700,0.0013428,0.0009766,0.0007629,0.0001221,0.0002747,-0.0004272,-0.0001526,-0.0005798,-0.0003052,-0.0003052,-0.0002136,0.0001221,0.0000916,0.0006104,0.0005493,0.0008545,0.0009155,0.0008545,0.0009460,0.0007629,0.0007019,0.0003662,0.0000305,-0.0002136,-0.0007324,-0.0008545,-0.0015259,-0.0015564,-0.0020752,-0.0022278,-0.0025024,-0.0026245,-0.0027466,-0.0026245,-0.0026855,-0.0025024,-0.0022888,-0.0021667,-0.0018311,-0.0016174,-0.0013733,-0.0009766,-0.0007019,-0.0005188,-0.0002747,-0.0002136,-0.0001221,-0.0000610,-0.0001831,-0.0002136,-0.0003967,-0.0006104,-0.0006714,-0.0011597,-0.0010986,-0.0014648,-0.0015564,-0.0015259,-0.0016785,-0.0015259,-0.0013123,-0.0013123,-0.0008545,-0.0005188,-0.0003662,0.0002747,0.0003357,0.0009460,0.0009460,0.0014038,0.0013123,0.0015869,0.0013428,0.0014343,0.0010376,0.0009766,0.0004883,0.0003357,0.0000305,-0.0004578,-0.0004883,-0.0009766,-0.0008545,-0.0010376,-0.0008545,-0.0008545,-0.0004578,-0.0002747,0.0002136,0.0005188,0.0011597,0.0014954,0.0021667,0.0025024,0.0029602,0.0033875,0.0034485,0.0038757,0.0036621,0.0038757,0.0035706,0.0034485,0.0029907,0.0028076,0.0021362,0.0019836,0.0013428,0.0011597,0.0007019,0.0006409,0.0004578,0.0004578,0.0006104,0.0008545,0.0011597,0.0014954,0.0020142,0.0023804,0.0030212,0.0031738,0.0037842,0.0036316,0.0040588,0.0037842,0.0036621,0.0033264,0.0028381,0.0023193,0.0016785,0.0009766,0.0003662,-0.0001831,-0.0007019,-0.0010986,-0.0013123,-0.0013733,-0.0012207,-0.0009155,-0.0005493,0.0000305,0.0006714,0.0013733,0.0020142,0.0028381,0.0032349,0.0038757,0.0040588,0.0043640,0.0043030,0.0043030,0.0038757,0.0036316,0.0030518,0.0026550,0.0020447,0.0016479,0.0010681,0.0008850,0.0006409,0.0006104,0.0008240,0.0008850,0.0014038,0.0017700,0.0022583,0.0028687,0.0032349,0.0038147,0.0041199,0.0043640,0.0044861,0.0044861,0.0041504,0.0039673,0.0032349,0.0029297,0.0019836,0.0014343,0.0007324,-0.0000610,-0.0004578,-0.0010376,-0.0014954,-0.0016174,-0.0018616,-0.0017700,-0.0016174,-0.0014954,-0.0010071,-0.0008545,-0.0003052,0.0000000,0.0001526,0.0006714,0.0004883,0.0007324,0.0005798,0.0002747,0.0001526,-0.0003357,-0.0007629,-0.0012512,-0.0018616,-0.0022583,-0.0028992,-0.0033264,-0.0037537,-0.0042114,-0.0043945,-0.0047913,-0.0049438,-0.0050354,-0.0051880,-0.0051575,-0.0052490,-0.0052490,-0.0053406,-0.0051880,-0.0054626,-0.0053711,-0.0055542,-0.0057373,-0.0057068,-0.0061035,-0.0060120,-0.0064087,-0.0063782,-0.0067139,-0.0065918,-0.0068359,-0.0067749,-0.0068054,-0.0066223,-0.0065918,-0.0061951,-0.0060730,-0.0055542,-0.0054016,-0.0047302,-0.0045166,-0.0038147,-0.0035400,-0.0030518,-0.0027161,-0.0024109,-0.0021667,-0.0020752,-0.0018921,-0.0019836,-0.0019226,-0.0020752,-0.0024414,-0.0022583,-0.0028381,-0.0027161,-0.0029907,-0.0030823,-0.0028992,-0.0029907,-0.0026245,-0.0024719,-0.0020447,-0.0017395,-0.0012512,-0.0009155,-0.0004578,-0.0000916,0.0001526,0.0003357,0.0004578,0.0002441,0.0003052,-0.0000610,-0.0002136,-0.0007019,-0.0009155,-0.0013428,-0.0015869,-0.0015869,-0.0016785,-0.0015564,-0.0012207,-0.0009460,-0.0002747,0.0003052,0.0012207,0.0018616,0.0028381,0.0035095,0.0043335,0.0050049,0.0054321,0.0058899,0.0061340,0.0061951,0.0064087,0.0060120,0.0061035,0.0057068,0.0056763,0.0054626,0.0054016,0.0053711,0.0053711,0.0057983,0.0058289,0.0065613,0.0068665,0.0075073,0.0081482,0.0085449,0.0093384,0.0096130,0.0102539,0.0102234,0.0106201,0.0103455,0.0105591,0.0100098,0.0100098,0.0093384,0.0092163,0.0087280,0.0083923,0.0081787,0.0078735,0.0079041,0.0077820,0.0081482,0.0081177,0.0087891,0.0089417,0.0095825,0.0099182,0.0103149,0.0108643,0.0108948,0.0114136,0.0112305,0.0113831,0.0112305,0.0108948,0.0107117,0.0103149,0.0098877,0.0094910,0.0091858,0.0088196,0.0085754,0.0084534,0.0084229,0.0086060,0.0085449,0.0091553,0.0089722,0.0098572,0.0097656,0.0106201,0.0105286,0.0111389,0.0112305,0.0115051,0.0113831,0.0115967,0.0112915,0.0112610,0.0110779,0.0108032,0.0106812,0.0104065,0.0103760,0.0101318,0.0102234,0.0101624,0.0102844,0.0102844,0.0105286,0.0105286,0.0108337,0.0108643,0.0108643,0.0109863,0.0108337,0.0107727,0.0104675,0.0101929,0.0098572,0.0093384,0.0091248,0.0084229,0.0080872,0.0075684,0.0071106,0.0068054,0.0062866,0.0061035,0.0056458,0.0055542,0.0050659,0.0049133,0.0045471,0.0042725,0.0039062,0.0035706,0.0031738,0.0027771,0.0023499,0.0020752,0.0015869,0.0012512,0.0009460,0.0006714,0.0005798,0.0003662,0.0003967,0.0003052,0.0002441,0.0004272,0.0002441,0.0004883,0.0002441,0.0002441,0.0002136,-0.0002136,-0.0001831,-0.0008850,-0.0008850,-0.0015564,-0.0018005,-0.0022583,-0.0027466,-0.0029907,-0.0032654,-0.0035095,-0.0035706,-0.0037537,-0.0035706,-0.0033875,-0.0032654,-0.0028076,-0.0026855,-0.0021362,-0.0018921,-0.0015869,-0.0012817,-0.0011292,-0.0009460,-0.0008545,-0.0010376,-0.0010376,-0.0013733,-0.0016479,-0.0019531,-0.0024109,-0.0027466,-0.0031433,-0.0035400,-0.0037842,-0.0041199,-0.0042114,-0.0043335,-0.0043335,-0.0041504,-0.0040588,-0.0038147,-0.0034180,-0.0032654,-0.0027466,-0.0027161,-0.0022278,-0.0022583,-0.0018921,-0.0021362,-0.0018616,-0.0023804,-0.0021973,-0.0027161,-0.0027466,-0.0031738,-0.0035400,-0.0035400,-0.0038757,-0.0037537,-0.0038757,-0.0036011,-0.0032959,-0.0030212,-0.0024109,-0.0020447,-0.0012512,-0.0007629,-0.0000610,0.0004883,0.0009155,0.0014648,0.0016785,0.0019531,0.0021362,0.0020752,0.0018921,0.0019531,0.0015259,0.0014343,0.0011597,0.0009460,0.0007629,0.0007019,0.0006409,0.0006714,0.0008240,0.0010071,0.0012207,0.0015869,0.0018005,0.0022888,0.0024109,0.0027771,0.0029602,0.0031128,0.0031738,0.0033264,0.0031738,0.0031738,0.0029297,0.0028381,0.0026855,0.0023804,0.0022888,0.0020447,0.0019836,0.0018616,0.0018311,0.0017700,0.0018616,0.0017395,0.0021362,0.0019226,0.0023804,0.0023804,0.0025635,0.0028076,0.0028687,0.0031738,0.0031128,0.0033569,0.0035095,0.0033569,0.0036316,0.0033264,0.0035095,0.0032959,0.0032959,0.0030823,0.0031128,0.0029602,0.0029602,0.0027771,0.0028992,0.0027466,0.0031128,0.0030212,0.0033569,0.0036316,0.0037842,0.0042419,0.0045166,0.0048218,0.0052490,0.0054016,0.0057983,0.0057678,0.0059814,0.0059814,0.0057983,0.0058289,0.0054016,0.0052795,0.0048523,0.0046082,0.0040283,0.0038147,0.0032654,0.0031433,0.0027466,0.0025940,0.0024719,0.0023499,0.0023193,0.0023804,0.0024109,0.0025330,0.0026550,0.0026550,0.0028687,0.0028992,0.0028992,0.0028992,0.0027771,0.0027771,0.0024719,0.0024109,0.0020752,0.0018616,0.0015564,0.0012817,0.0008545,0.0007629,0.0003662,0.0002136,-0.0001526,-0.0000916,-0.0004272,-0.0003052,-0.0004578,-0.0004272,-0.0004272,-0.0004272,-0.0002441,-0.0004272,-0.0000610,-0.0003967,-0.0000916,-0.0002747,-0.0003052,-0.0005188,-0.0007629,-0.0007935,-0.0013733,-0.0012512,-0.0020142,-0.0018616,-0.0025024,-0.0024719,-0.0028992,-0.0027466,-0.0031433,-0.0027771,-0.0030518,-0.0025635,-0.0024109,-0.0021667,-0.0016479,-0.0015564,-0.0010071,-0.0009460,-0.0004883,-0.0003662,-0.0002441,-0.0002136,-0.0003662,-0.0003052,-0.0007935,-0.0006714,-0.0013733,-0.0013733,-0.0018005,-0.0019836,-0.0021973,-0.0022583,-0.0022278,-0.0023193,-0.0017700,-0.0019226,-0.0012207,-0.0009155,-0.0004272,0.0001221,0.0004272,0.0010071,0.0013733,0.0015564,0.0019836,0.0019226,0.0020752,0.0020142,0.0018005,0.0018005,0.0015259,0.0013733,0.0011902,0.0009766,0.0008850,0.0007935,0.0007629,0.0009155,0.0009460,0.0011597,0.0012817,0.0016174,0.0018311,0.0020447,0.0022888,0.0022888,0.0026550,0.0023804,0.0026855,0.0023804,0.0024414,0.0020752,0.0020447,0.0017700,0.0016785,0.0014648,0.0014343,0.0012512,0.0014954,0.0013428,0.0017395,0.0018311,0.0022888,0.0025024,0.0030212,0.0032959,0.0036926,0.0040894,0.0042725,0.0044861,0.0045471,0.0045776,0.0045471,0.0043335,0.0040894,0.0036926,0.0034790,0.0029297,0.0027771,0.0022888,0.0021057,0.0018311,0.0018616,0.0016479,0.0020752,0.0019531,0.0024719,0.0026550,0.0030823,0.0035400,0.0038452,0.0041809,0.0044556,0.0045471,0.0045776,0.0045776,0.0042114,0.0040894,0.0033569,0.0031738,0.0023499,0.0019836,0.0014038,0.0007935,0.0005798,0.0000916,0.0000610,-0.0000610,0.0001221,0.0003967,0.0005798,0.0011902,0.0015259,0.0021973,0.0024719,0.0030212,0.0031738,0.0036011,0.0034485,0.0035400,0.0031128,0.0028381,0.0022278,0.0016174,0.0010376,0.0002136,-0.0003662,-0.0011597,-0.0016479,-0.0021973,-0.0025024,-0.0027466,-0.0027771,-0.0028687,-0.0023804,-0.0024109,-0.0016479,-0.0016174,-0.0008850,-0.0007629,-0.0001526,0.0000916,0.0000916,0.0004272,0.0001221,-0.0000305,-0.0002747,-0.0007935,-0.0011292,-0.0017700,-0.0022888,-0.0028992,-0.0032654,-0.0039062,-0.0039062,-0.0044861,-0.0044250,-0.0045776,-0.0045776,-0.0042114,-0.0043030,-0.0038147,-0.0038757,-0.0034485,-0.0033264,-0.0032654,-0.0031433,-0.0033569,-0.0034180,-0.0036926,-0.0040588,-0.0045776,-0.0049133,-0.0056152,-0.0060120,-0.0066223,-0.0070801,-0.0075378,-0.0079041,-0.0082397,-0.0083618,-0.0084534,-0.0085449,-0.0085144,-0.0083618,-0.0083008,-0.0080872,-0.0078430,-0.0079346,-0.0075684,-0.0076599,-0.0074463,-0.0075378,-0.0074768,-0.0077820,-0.0075989,-0.0078735,-0.0079041,-0.0079956,-0.0083313,-0.0080566,-0.0085449,-0.0082092,-0.0085754,-0.0083923,-0.0084534,-0.0084229,-0.0082397,-0.0083008,-0.0080872,-0.0080566,-0.0078125,-0.0076904,-0.0074158,-0.0071106,-0.0070801,-0.0064697,-0.0065002,-0.0059509,-0.0056763,-0.0054321,-0.0048828,-0.0048828,-0.0041809,-0.0043640,-0.0038147,-0.0038147,-0.0036011,-0.0035706,-0.0034790,-0.0035706,-0.0035095,-0.0035706,-0.0036011,-0.0035706,-0.0035706,-0.0032654,-0.0033875,-0.0028381,-0.0028076,-0.0022583,-0.0019226,-0.0013428,-0.0010681,-0.0003662,-0.0001221,0.0004883,0.0007019,0.0012207,0.0012817,0.0015869,0.0015869,0.0014954,0.0015564,0.0013428,0.0012817,0.0010681,0.0008850,0.0008850,0.0007324,0.0008240,0.0008240,0.0010681,0.0012817,0.0016479,0.0019836,0.0024414,0.0029297,0.0035400,0.0039368,0.0046082,0.0049744,0.0055542,0.0059204,0.0061951,0.0065002,0.0065918,0.0067444,0.0066528,0.0066528,0.0065002,0.0063477,0.0061646,0.0058594,0.0056763,0.0054932,0.0052795,0.0051880,0.0051270,0.0051270,0.0051880,0.0054016,0.0055542,0.0058899,0.0061951,0.0065002,0.0068054,0.0073242,0.0073242,0.0078125,0.0077820,0.0079346,0.0079346,0.0077209,0.0076294,0.0072632,0.0069580,0.0066528,0.0062866,0.0059204,0.0057068,0.0052490,0.0052490,0.0050964,0.0050659,0.0052795,0.0053101,0.0056152,0.0060425,0.0059509,0.0066528,0.0066528,0.0068970,0.0070801,0.0071411,0.0069885,0.0069885,0.0065308,0.0064087,0.0058594,0.0054626,0.0049133,0.0043030,0.0040283,0.0033875,0.0030823,0.0026855,0.0022888,0.0022278,0.0019531,0.0018311,0.0017700,0.0017395,0.0017395,0.0015564,0.0016785,0.0014038,0.0015259,0.0011902,0.0010681,0.0008545,0.0004883,0.0003357,-0.0001221,-0.0003662,-0.0005798,-0.0010376,-0.0010681,-0.0015259,-0.0014648,-0.0018005,-0.0018311,-0.0019226,-0.0020752,-0.0019531,-0.0021667,-0.0021057,-0.0022278,-0.0022583,-0.0023499,-0.0024414,-0.0026855,-0.0028076,-0.0030212,-0.0033569,-0.0035400,-0.0039368,-0.0041809,-0.0045776,-0.0048523,-0.0053406,-0.0056152,-0.0060425,-0.0064697,-0.0066223,-0.0072327,-0.0073242,-0.0079346,-0.0079346,-0.0085449,-0.0085449,-0.0090027,-0.0092773,-0.0094604,-0.0097656,-0.0099487,-0.0101318,-0.0104675,-0.0105896,-0.0107422,-0.0109558,-0.0108337,-0.0112305,-0.0108948,-0.0112305,-0.0108948,-0.0110168,-0.0108337,-0.0107727,-0.0106506,-0.0105591,-0.0104065,-0.0102844,-0.0101013,-0.0101624,-0.0099487,-0.0100708,-0.0099792,-0.0098877,-0.0102234,-0.0098572,-0.0103455,-0.0100708,-0.0103760,-0.0101624,-0.0103455,-0.0102844,-0.0102539,-0.0101624,-0.0101318,-0.0097656,-0.0100403,-0.0094299,-0.0097351,-0.0091858,-0.0093994,-0.0090027,-0.0091553,-0.0087585,-0.0090942,-0.0085754,-0.0089417,-0.0086975,-0.0087585,-0.0087280,-0.0086975,-0.0086670,-0.0085754,-0.0084839,-0.0082397,-0.0083618,-0.0078430,-0.0079651,-0.0074158,-0.0075073,-0.0069275,-0.0070496,-0.0064697,-0.0064697,-0.0061035,-0.0059814,-0.0056458,-0.0055847,-0.0051575,-0.0052490,-0.0047913,-0.0048523,-0.0045471,-0.0045776,-0.0042419,-0.0044250,-0.0040588,-0.0043945,-0.0039978,-0.0043335,-0.0040588,-0.0042725,-0.0042725,-0.0042725,-0.0043640,-0.0042114,-0.0042419,-0.0042114,-0.0039978,-0.0039062,-0.0036926,-0.0033569,-0.0032043,-0.0028076,-0.0024719,-0.0021362,-0.0017395,-0.0013733,-0.0011597,-0.0005493,-0.0005493,0.0000305,0.0000305,0.0003967,0.0004272,0.0007629,0.0007324,0.0009460,0.0011597,0.0012207,0.0014648,0.0015259,0.0017700,0.0020142,0.0020752,0.0024414,0.0024719,0.0027771,0.0028381,0.0030212,0.0031128,0.0031433,0.0031738,0.0033264,0.0030212,0.0034485,0.0028992,0.0032959,0.0028687,0.0032349,0.0029602,0.0032349,0.0030518,0.0033875,0.0032043,0.0035095,0.0035095,0.0035400,0.0035706,0.0036316,0.0035095,0.0035095,0.0033264,0.0030823,0.0027161,0.0025635,0.0020142,0.0018005,0.0014038,0.0008545,0.0007935,0.0002441,0.0002136,-0.0001526,-0.0001526,-0.0002747,-0.0001526,-0.0001526,-0.0000305,0.0002136,0.0003357,0.0007019,0.0007019,0.0010681,0.0010681,0.0012817,0.0011292,0.0011902,0.0010681,0.0008240,0.0007019,0.0002747,0.0001526,-0.0003662,-0.0005493,-0.0008850,-0.0011292,-0.0014648,-0.0015259,-0.0017700,-0.0018921,-0.0016479,-0.0020142,-0.0015564,-0.0018921,-0.0014648,-0.0016174,-0.0013428,-0.0015564,-0.0013733,-0.0015869,-0.0015869,-0.0019836,-0.0020142,-0.0025024,-0.0025940,-0.0032349,-0.0032959,-0.0038757,-0.0039978,-0.0043945,-0.0047607,-0.0046692,-0.0051880,-0.0049133,-0.0053406,-0.0049744,-0.0051880,-0.0048828,-0.0049744,-0.0047607,-0.0046997,-0.0046692,-0.0046692,-0.0044556,-0.0048828,-0.0044861,-0.0051270,-0.0048828,-0.0053711,-0.0054321,-0.0056763,-0.0059204,-0.0060425,-0.0061340,-0.0063171,-0.0061035,-0.0063477,-0.0059814,-0.0060730,-0.0056458,-0.0055847,-0.0052185,-0.0050659,-0.0047607,-0.0046387,-0.0046082,-0.0043030,-0.0046387,-0.0044861,-0.0049133,-0.0050659,-0.0053101,-0.0057068,-0.0059814,-0.0063171,-0.0065613,-0.0066833,-0.0069275,-0.0067444,-0.0068970,-0.0065613,-0.0063171,-0.0060120,-0.0054321,-0.0052490,-0.0046082,-0.0043030,-0.0038757,-0.0034485,-0.0033569,-0.0030823,-0.0031738,-0.0029297,-0.0034180,-0.0031433,-0.0037537,-0.0038147,-0.0040894,-0.0044250,-0.0043030,-0.0046387,-0.0043945,-0.0044861,-0.0041199,-0.0039368,-0.0034790,-0.0030823,-0.0025330,-0.0021667,-0.0016785,-0.0013123,-0.0008545,-0.0007629,-0.0004883,-0.0006409,-0.0006104,-0.0008850,-0.0011902,-0.0016174,-0.0019226,-0.0025635,-0.0028076,-0.0033569,-0.0036011,-0.0039673,-0.0039368,-0.0042419,-0.0038452,-0.0038757,-0.0034180,-0.0030823,-0.0025330,-0.0020142,-0.0013428,-0.0007935,-0.0002136,0.0002441,0.0007629,0.0010681,0.0013428,0.0013733,0.0015564,0.0014038,0.0013733,0.0009460,0.0009155,0.0006104,0.0004578,0.0002747,0.0000916,0.0002441,0.0003357,0.0006104,0.0008850,0.0015564,0.0019226,0.0026855,0.0033569,0.0039368,0.0047913,0.0051575,0.0057678,0.0060120,0.0063477,0.0062561,0.0061646,0.0058899,0.0054016,0.0049744,0.0043335,0.0036926,0.0030823,0.0025024,0.0018616,0.0015869,0.0010376,0.0010681,0.0008545,0.0010986,0.0012512,0.0015869,0.0019836,0.0024109,0.0028992,0.0034485,0.0036621,0.0041199,0.0042114,0.0043335,0.0043640,0.0040894,0.0039062,0.0034485,0.0029297,0.0025024,0.0017700,0.0012817,0.0006409,0.0001831,-0.0002747,-0.0006409,-0.0009460,-0.0010071,-0.0011597,-0.0010376,-0.0009766,-0.0007629,-0.0006409,-0.0003052,-0.0002136,0.0000916,0.0002747,0.0003357,0.0004272,0.0003662,0.0002747,0.0001221,-0.0000916,-0.0004578,-0.0006714,-0.0011292,-0.0013123,-0.0017090,-0.0020142,-0.0021057,-0.0023193,-0.0022583,-0.0021362,-0.0019836,-0.0018311,-0.0012817,-0.0011597,-0.0004578,-0.0003052,0.0002136,0.0005493,0.0008240,0.0010681,0.0012512,0.0011597,0.0013123,0.0010376,0.0008545,0.0005493,0.0003357,0.0000000,-0.0002747,-0.0003967,-0.0007019,-0.0007019,-0.0007019,-0.0006714,-0.0003967,-0.0001526,0.0000610,0.0005493,0.0007324,0.0012512,0.0013123,0.0017700,0.0017090,0.0018921,0.0017395,0.0016785,0.0013733,0.0010986,0.0006714,0.0002441,-0.0001526,-0.0005493,-0.0008850,-0.0012207,-0.0013733,-0.0016479,-0.0015869,-0.0016174,-0.0015259,-0.0012817,-0.0011597,-0.0007324,-0.0005188,0.0000305,0.0001831,0.0007019,0.0007935,0.0013123,0.0013733,0.0015869,0.0018616,0.0017090,0.0019531,0.0018616,0.0017395,0.0019531,0.0014954,0.0018005,0.0013428,0.0016479,0.0013428,0.0014954,0.0014038,0.0016174,0.0016174,0.0017700,0.0019836,0.0021973,0.0025940,0.0025635,0.0029907,0.0031433,0.0033569,0.0036316,0.0035095,0.0036926,0.0034485,0.0035400,0.0031738,0.0031128,0.0026245,0.0023804,0.0020752,0.0016479,0.0014648,0.0009766,0.0010376,0.0006104,0.0008545,0.0005188,0.0009155,0.0007324,0.0011902,0.0013733,0.0015869,0.0018921,0.0020752,0.0022888,0.0024719,0.0025940,0.0025635,0.0026855,0.0025330,0.0025635,0.0023193,0.0022278,0.0020447,0.0020142,0.0018921,0.0019226,0.0018005,0.0019531,0.0018311,0.0021057,0.0020752,0.0022888,0.0022888,0.0025940,0.0024414,0.0026550,0.0025024,0.0026245,0.0023193,0.0024719,0.0020447,0.0021057,0.0017700,0.0017090,0.0015869,0.0013428,0.0014954,0.0011902,0.0014343,0.0013733,0.0015564,0.0016479,0.0017700,0.0019531,0.0020447,0.0020752,0.0022888,0.0021667,0.0022583,0.0019531,0.0019531,0.0015869,0.0014954,0.0011292,0.0009155,0.0004578,0.0004578,-0.0001221,0.0000305,-0.0004578,-0.0003357,-0.0006409,-0.0004578,-0.0007019,-0.0004578,-0.0006104,-0.0005188,-0.0006409,-0.0005798,-0.0008850,-0.0009155,-0.0012817,-0.0016479,-0.0019226,-0.0025635,-0.0029297,-0.0035095,-0.0040588,-0.0045776,-0.0050049,-0.0054626,-0.0058289,-0.0060730,-0.0063171,-0.0063171,-0.0063477,-0.0062866,-0.0061340,-0.0060425,-0.0057068,-0.0057983,-0.0053101,-0.0055847,-0.0050964,-0.0054016,-0.0053101,-0.0053406,-0.0056152,-0.0055847,-0.0058594,-0.0059814,-0.0061035,-0.0061340,-0.0061340,-0.0061646,-0.0060425,-0.0057678,-0.0056458,-0.0050964,-0.0051270,-0.0043945,-0.0042725,-0.0036621,-0.0036011,-0.0031738,-0.0030823,-0.0028076,-0.0027466,-0.0026550,-0.0027771,-0.0025635,-0.0028992,-0.0025330,-0.0030212,-0.0026550,-0.0028381,-0.0027161,-0.0026245,-0.0025330,-0.0024719,-0.0021057,-0.0020142,-0.0017090,-0.0015869,-0.0013733,-0.0012817,-0.0009460,-0.0010071,-0.0007935,-0.0006714,-0.0007324,-0.0005188,-0.0005188,-0.0004578,-0.0004883,-0.0002441,-0.0003662,-0.0001221,-0.0001526,0.0000305,0.0002441,0.0003052,0.0005188,0.0006409,0.0008240,0.0011292,0.0011902,0.0015869,0.0015564,0.0020447,0.0021362,0.0023499,0.0027466,0.0027771,0.0031738,0.0033569,0.0035400,0.0037231,0.0039978,0.0040283,0.0043640,0.0042725,0.0045776,0.0046997,0.0046387,0.0050964,0.0048523,0.0054016,0.0053406,0.0058594,0.0058899,0.0062866,0.0066223,0.0068665,0.0072937,0.0075684,0.0077515,0.0082703,0.0080872,0.0086060,0.0082092,0.0085449,0.0083923,0.0083313,0.0083008,0.0079041,0.0080872,0.0077515,0.0079956,0.0077820,0.0079956,0.0081177,0.0081787,0.0086975,0.0086670,0.0092468,0.0095215,0.0098267,0.0100708,0.0103760,0.0104370,0.0105591,0.0105286,0.0104065,0.0102539,0.0100708,0.0098267,0.0093689,0.0093689,0.0089111,0.0088501,0.0086975,0.0085754,0.0087280,0.0086670,0.0090637,0.0091248,0.0095825,0.0099792,0.0102234,0.0107422,0.0110168,0.0113220,0.0115967,0.0115967,0.0118103,0.0116882,0.0117188,0.0114441,0.0113220,0.0109863,0.0106201,0.0105591,0.0099487,0.0101318,0.0095520,0.0095825,0.0094299,0.0093384,0.0094299,0.0094910,0.0095825,0.0096436,0.0097961,0.0097961,0.0100708,0.0098877,0.0101013,0.0097351,0.0096130,0.0095215,0.0088501,0.0088501,0.0079956,0.0077209,0.0071716,0.0066223,0.0061951,0.0056152,0.0052795,0.0049438,0.0047913,0.0043640,0.0045166,0.0041809,0.0043335,0.0040894,0.0042419,0.0040894,0.0040588,0.0038147,0.0036621,0.0032959,0.0031433,0.0025024,0.0021973,0.0014648,0.0010986,0.0004578,-0.0001221,-0.0005188,-0.0011597,-0.0012817,-0.0018005,-0.0018921,-0.0021362,-0.0020447,-0.0021362,-0.0018616,-0.0019836,-0.0015869,-0.0015259,-0.0013428,-0.0011292,-0.0011292,-0.0009155,-0.0010986,-0.0010071,-0.0010681,-0.0014343,-0.0013428,-0.0018616,-0.0017700,-0.0021973,-0.0022583,-0.0025024,-0.0026550,-0.0025330,-0.0028381,-0.0026245,-0.0026855,-0.0025940,-0.0024414,-0.0022888,-0.0021362,-0.0018921,-0.0018005,-0.0014954,-0.0015259,-0.0011292,-0.0013428,-0.0009460,-0.0013123,-0.0008850,-0.0014648,-0.0010986,-0.0017395,-0.0015564,-0.0020752,-0.0021362,-0.0025024,-0.0027771,-0.0029602,-0.0032349,-0.0033875,-0.0035706,-0.0037231,-0.0037231,-0.0038147,-0.0035706,-0.0036621,-0.0032654,-0.0032349,-0.0028076,-0.0025635,-0.0022583,-0.0018921,-0.0015869,-0.0012512,-0.0009766,-0.0007324,-0.0005493,-0.0003662,-0.0001831,-0.0001526,0.0000000,-0.0000916,-0.0000916,-0.0000305,-0.0002441,-0.0000916,-0.0003967,-0.0001831,-0.0004272,-0.0002747,-0.0003662,-0.0001831,-0.0002136,0.0000305,0.0001526,0.0003662,0.0006409,0.0009766,0.0011902,0.0016479,0.0017395,0.0022583,0.0023804,0.0026245,0.0028992,0.0027161,0.0030518,0.0027466,0.0028687,0.0025330,0.0025635,0.0020447,0.0020752,0.0016479,0.0015869,0.0014038,0.0013733,0.0013123,0.0014954,0.0014038,0.0019531,0.0018921,0.0024414,0.0025940,0.0030518,0.0033569,0.0036011,0.0038452,0.0039673,0.0041504,0.0039978,0.0039978,0.0037842,0.0035706,0.0033875,0.0029907,0.0028381,0.0024719,0.0022888,0.0021667,0.0020447,0.0022583,0.0021057,0.0025940,0.0026855,0.0031433,0.0034180,0.0039673,0.0041504,0.0046997,0.0048523,0.0053101,0.0052490,0.0054626,0.0053406,0.0053406,0.0051575,0.0049133,0.0046082,0.0044556,0.0039978,0.0038757,0.0035400,0.0032654,0.0032959,0.0028992,0.0030212,0.0028687,0.0028687,0.0030518,0.0028992,0.0031128,0.0030518,0.0031433,0.0030518,0.0031128,0.0030212,0.0028992,0.0028687,0.0024719,0.0025635,0.0021667,0.0020142,0.0018616,0.0016479,0.0014954,0.0014343,0.0011292,0.0011597,0.0010071,0.0008545,0.0008545,0.0007019,0.0005798,0.0004578,0.0003662,0.0001526,-0.0000305,-0.0001831,-0.0003967,-0.0004883,-0.0007019,-0.0008545,-0.0008240,-0.0010681,-0.0008850,-0.0010986,-0.0008240,-0.0008850,-0.0007019,-0.0005798,-0.0004883,-0.0002441,-0.0003052,-0.0001526,-0.0002136,-0.0003967,-0.0003052,-0.0007324,-0.0008240,-0.0010376,-0.0015259,-0.0017090,-0.0021057,-0.0024414,-0.0024109,-0.0027466,-0.0027466,-0.0028076,-0.0027466,-0.0026550,-0.0022278,-0.0022278,-0.0018005,-0.0015259,-0.0013733,-0.0009460,-0.0009460,-0.0007019,-0.0006714,-0.0007324,-0.0007629,-0.0008850,-0.0009766,-0.0013123,-0.0011597,-0.0017090,-0.0014038,-0.0018921,-0.0015564,-0.0017090,-0.0013123,-0.0012817,-0.0008545,-0.0005798,-0.0001526,0.0001526,0.0007324,0.0008545,0.0014954,0.0014954,0.0019226,0.0020447,0.0021667,0.0022583,0.0022888,0.0022888,0.0021057,0.0023193,0.0018005,0.0021057,0.0015564,0.0019531,0.0014343,0.0017700,0.0014343,0.0015564,0.0017090,0.0016174,0.0018616,0.0018921,0.0021362,0.0022888,0.0025330,0.0027161,0.0028687,0.0032349,0.0032043,0.0036621,0.0036011,0.0038147,0.0039368,0.0038452,0.0039673,0.0037231,0.0038757,0.0035400,0.0036316,0.0032349,0.0031738,0.0031433,0.0027466,0.0029602,0.0025024,0.0028076,0.0025330,0.0027771,0.0025635,0.0028381,0.0026550,0.0028687,0.0028687,0.0027771,0.0029602,0.0027161,0.0028381,0.0025940,0.0026245,0.0025024,0.0023499,0.0022583,0.0021973,0.0021667,0.0021667,0.0020752,0.0022583,0.0022583,0.0023499,0.0026855,0.0025330,0.0030518,0.0029602,0.0031433,0.0033569,0.0033264,0.0034790,0.0034485,0.0034790,0.0035095,0.0033569,0.0035400,0.0032959,0.0033264,0.0034180,0.0031433,0.0034790,0.0030823,0.0032959,0.0031433,0.0030823,0.0030823,0.0028687,0.0027161,0.0025940,0.0022888,0.0021057,0.0016785,0.0014343,0.0011292,0.0007935,0.0006409,0.0001831,0.0001526,-0.0001526,-0.0003662,-0.0002136,-0.0006409,-0.0003662,-0.0004578,-0.0005188,-0.0002441,-0.0004578,-0.0001831,-0.0003052,-0.0002441,-0.0001831,-0.0003357,-0.0003357,-0.0005188,-0.0007324,-0.0008545,-0.0012512,-0.0013428,-0.0018921,-0.0019836,-0.0025330,-0.0027161,-0.0031433,-0.0033875,-0.0036926,-0.0038757,-0.0042114,-0.0042419,-0.0043945,-0.0044250,-0.0043945,-0.0044556,-0.0041809,-0.0043640,-0.0040283,-0.0042114,-0.0040894,-0.0041199,-0.0043640,-0.0044250,-0.0047607,-0.0050659,-0.0054321,-0.0060120,-0.0063782,-0.0070190,-0.0073547,-0.0080566,-0.0081482,-0.0086975,-0.0088196,-0.0088501,-0.0090942,-0.0086975,-0.0086060,-0.0083008,-0.0079041,-0.0075989,-0.0072327,-0.0066833,-0.0066528,-0.0062561,-0.0063171,-0.0061951,-0.0063477,-0.0065308,-0.0068054,-0.0072327,-0.0075073,-0.0080872,-0.0083008,-0.0087891,-0.0088501,-0.0093079,-0.0091858,-0.0092468,-0.0091553,-0.0088501,-0.0086060,-0.0080872,-0.0076599,-0.0069885,-0.0066528,-0.0059509,-0.0057373,-0.0051880,-0.0050659,-0.0047607,-0.0047607,-0.0046082,-0.0046997,-0.0048523,-0.0049744,-0.0051575,-0.0053406,-0.0055237,-0.0056458,-0.0058289,-0.0058289,-0.0056458,-0.0057983,-0.0053101,-0.0052490,-0.0048218,-0.0042114,-0.0040283,-0.0032043,-0.0028381,-0.0021667,-0.0017700,-0.0012817,-0.0008850,-0.0005493,-0.0003052,-0.0000610,-0.0000610,0.0001221,-0.0000610,-0.0000610,-0.0002136,-0.0002747,-0.0004578,-0.0003052,-0.0004272,-0.0003357,-0.0000916,-0.0001526,0.0003967,0.0005798,0.0008850,0.0013733,0.0014954,0.0021667,0.0022583,0.0026245,0.0028381,0.0030212,0.0031128,0.0033264,0.0031433,0.0032349,0.0031128,0.0030823,0.0030518,0.0029602,0.0029602,0.0029907,0.0031128,0.0031738,0.0035095,0.0035400,0.0039062,0.0042419,0.0043335,0.0049133,0.0049744,0.0053711,0.0055847,0.0058899,0.0061646,0.0061951,0.0066528,0.0063171,0.0069580,0.0065308,0.0070801,0.0067749,0.0069885,0.0068665,0.0068359,0.0068359,0.0067444,0.0068054,0.0065308,0.0065613,0.0065002,0.0064087,0.0065308,0.0064087,0.0066223,0.0066223,0.0068054,0.0069885,0.0070801,0.0073547,0.0076294,0.0075684,0.0079651,0.0077515,0.0080566,0.0076904,0.0079041,0.0072937,0.0072632,0.0066833,0.0062256,0.0058899,0.0051880,0.0049438,0.0043640,0.0039673,0.0036316,0.0036316,0.0031738,0.0033875,0.0032349,0.0033569,0.0035400,0.0036621,0.0037842,0.0040283,0.0040283,0.0041504,0.0039978,0.0038452,0.0036926,0.0032959,0.0029907,0.0025024,0.0019531,0.0014954,0.0009155,0.0005493,-0.0000305,-0.0003967,-0.0007324,-0.0010986,-0.0011597,-0.0014954,-0.0014038,-0.0015259,-0.0015869,-0.0014343,-0.0015259,-0.0013733,-0.0015259,-0.0013428,-0.0016174,-0.0014954,-0.0018311,-0.0019531,-0.0023804,-0.0026245,-0.0030823,-0.0034790,-0.0039368,-0.0044861,-0.0049133,-0.0054016,-0.0058289,-0.0062256,-0.0066223,-0.0068359,-0.0070496,-0.0071716,-0.0071411,-0.0073547,-0.0069580,-0.0071411,-0.0067444,-0.0067139,-0.0064697,-0.0063477,-0.0060730,-0.0062256,-0.0060120,-0.0061646,-0.0062866,-0.0065308,-0.0065918,-0.0071716,-0.0072937,-0.0078125,-0.0081787,-0.0083923,-0.0089722,-0.0090027,-0.0093384,-0.0093994,-0.0094604,-0.0096130,-0.0093689,-0.0094910,-0.0092468,-0.0092163,-0.0090942,-0.0090637,-0.0089722,-0.0091248,-0.0091248,-0.0094299,-0.0094299,-0.0097656,-0.0099792,-0.0102844,-0.0105591,-0.0107117,-0.0109558,-0.0111084,-0.0111084,-0.0110474,-0.0110168,-0.0107117,-0.0105591,-0.0100708,-0.0097351,-0.0093384,-0.0087891,-0.0084229,-0.0078430,-0.0075989,-0.0071411,-0.0069580,-0.0066223,-0.0065308,-0.0063782,-0.0064697,-0.0065613,-0.0065613,-0.0067749,-0.0068665,-0.0071716,-0.0072632,-0.0075073,-0.0074158,-0.0076599,-0.0075378,-0.0075684,-0.0073242,-0.0072327,-0.0068054,-0.0067139,-0.0064087,-0.0059814,-0.0058899,-0.0053711,-0.0052490,-0.0051575,-0.0048828,-0.0049133,-0.0048523,-0.0048523,-0.0050659,-0.0050354,-0.0052490,-0.0053711,-0.0053711,-0.0054626,-0.0053711,-0.0052795,-0.0050964,-0.0049133,-0.0044250,-0.0042114,-0.0035400,-0.0030823,-0.0027771,-0.0019531,-0.0019836,-0.0010986,-0.0011292,-0.0006409,-0.0004578,-0.0005798,-0.0002136,-0.0007019,-0.0003357,-0.0007935,-0.0009766,-0.0008240,-0.0014648,-0.0010376,-0.0014954,-0.0012512,-0.0012207,-0.0010376,-0.0007935,-0.0004883,-0.0000610,0.0003052,0.0007019,0.0013428,0.0014954,0.0021362,0.0023193,0.0026855,0.0028076,0.0029297,0.0028687,0.0028687,0.0025024,0.0025635,0.0019531,0.0018311,0.0013428,0.0010071,0.0007629,0.0003967,0.0003357,0.0002136,0.0002136,0.0004272,0.0005798,0.0010681,0.0014648,0.0019531,0.0025024,0.0028687,0.0036926,0.0037842,0.0043335,0.0043640,0.0043945,0.0043945,0.0040588,0.0036011,0.0030518,0.0024414,0.0016785,0.0010681,0.0000916,-0.0003357,-0.0011597,-0.0014343,-0.0019226,-0.0018921,-0.0021667,-0.0016479,-0.0017395,-0.0011597,-0.0008545,-0.0004272,0.0000610,0.0004272,0.0005798,0.0008850,0.0007935,0.0007935,0.0004883,0.0000916,-0.0003052,-0.0010681,-0.0014343,-0.0023804,-0.0026245,-0.0034180,-0.0036621,-0.0040588,-0.0041199,-0.0042419,-0.0040283,-0.0038757,-0.0035095,-0.0030823,-0.0026245,-0.0021667,-0.0017395,-0.0013428,-0.0010071,-0.0008240,-0.0005798,-0.0007324,-0.0006104,-0.0010376,-0.0013123,-0.0016479,-0.0021667,-0.0026550,-0.0029907,-0.0036011,-0.0037537,-0.0043640,-0.0043030,-0.0048523,-0.0045471,-0.0047302,-0.0044861,-0.0043640,-0.0041199,-0.0039062,-0.0036011,-0.0032654,-0.0031433,-0.0028992,-0.0028381,-0.0028381,-0.0028381,-0.0031128,-0.0032959,-0.0035095,-0.0040283,-0.0042114,-0.0048523,-0.0050659,-0.0055542,-0.0057983,-0.0060730,-0.0060730,-0.0062256,-0.0060730,-0.0059204,-0.0057983,-0.0051575,-0.0051880,-0.0043945,-0.0043335,-0.0037537,-0.0034790,-0.0032654,-0.0030518,-0.0029602,-0.0029907,-0.0032349,-0.0032959,-0.0038452,-0.0039368,-0.0045776,-0.0048218,-0.0053406,-0.0057068,-0.0059204,-0.0063782,-0.0062561,-0.0066833,-0.0062256,-0.0065308,-0.0058594,-0.0058289,-0.0053711,-0.0048218,-0.0046387,-0.0038757,-0.0036926,-0.0031128,-0.0027771,-0.0025940,-0.0022278,-0.0023804,-0.0021057,-0.0023499,-0.0023499,-0.0025635,-0.0027771,-0.0030212,-0.0033264,-0.0034180,-0.0037231,-0.0037537,-0.0037842,-0.0036621,-0.0036011,-0.0031128,-0.0030823,-0.0023193,-0.0021667,-0.0013733,-0.0010986,-0.0005188,-0.0000916,0.0001221,0.0006409,0.0005493,0.0008240,0.0006714,0.0004883,0.0004578,0.0000000,-0.0002136,-0.0005798,-0.0008850,-0.0010986,-0.0013123,-0.0014038,-0.0014038,-0.0012207,-0.0011292,-0.0008240,-0.0004578,-0.0001831,0.0004578,0.0006714,0.0011292,0.0014343,0.0016174,0.0019226,0.0020142,0.0018921,0.0021057,0.0018311,0.0018311,0.0017395,0.0014954,0.0014648,0.0013733,0.0013428,0.0014648,0.0014954,0.0017090,0.0018616,0.0021057,0.0023804,0.0025940,0.0028992,0.0031433,0.0031738,0.0035706,0.0032654,0.0036621,0.0033264,0.0033875,0.0032043,0.0030212,0.0028381,0.0027466,0.0024414,0.0023804,0.0021362,0.0019226,0.0020447,0.0016479,0.0018616,0.0016174,0.0016174,0.0016479,0.0015869,0.0014954,0.0015259,0.0013733,0.0014648,0.0012512,0.0011902,0.0010681,0.0009460,0.0008240,0.0006714,0.0005493,0.0003357,0.0001221,0.0000305,-0.0002136,-0.0002441,-0.0006104,-0.0005188,-0.0008850,-0.0008545,-0.0009766,-0.0012207,-0.0009155,-0.0014038,-0.0010071,-0.0012817,-0.0011597,-0.0011292,-0.0012817,-0.0009766,-0.0013733,-0.0010071,-0.0013428,-0.0011292,-0.0014343,-0.0011902,-0.0015259,-0.0013428,-0.0016174,-0.0014343,-0.0015259,-0.0015259,-0.0013428,-0.0015564,-0.0012207,-0.0012512,-0.0010071,-0.0009155,-0.0008545,-0.0006409,-0.0004272,-0.0005493,-0.0002136,-0.0003967,-0.0001221,-0.0003052,-0.0000305,-0.0003052,-0.0000610,-0.0002747,-0.0001526,-0.0001526,-0.0001526,0.0000000,-0.0000305,0.0002136,0.0001526,0.0003967,0.0003662,0.0004883,0.0006409,0.0005798,0.0007629,0.0007019,0.0007629,0.0006714,0.0007019,0.0004272,0.0006409,0.0002441,0.0004883,0.0000916,0.0002441,0.0000610,0.0000000,0.0000610,-0.0001221,0.0000305,-0.0000610,-0.0000305,0.0002441,-0.0000610,0.0003662,0.0001831,0.0004883,0.0004272,0.0006409,0.0006714,0.0009155,0.0008850,0.0011292,0.0011597,0.0012817,0.0013428,0.0013428,0.0015869,0.0014954,0.0017090,0.0015869,0.0018005,0.0019531,0.0019226,0.0020752,0.0021973,0.0023804,0.0025330,0.0026855,0.0028076,0.0029602,0.0031738,0.0031128,0.0033264,0.0032349,0.0032654,0.0031128,0.0030823,0.0028992,0.0027771,0.0024719,0.0024414,0.0020752,0.0020752,0.0020142,0.0018921,0.0020447,0.0020142,0.0022888,0.0024109,0.0027466,0.0028992,0.0032043,0.0032959,0.0035400,0.0036011,0.0035706,0.0036011,0.0034180,0.0032349,0.0030212,0.0026550,0.0025024,0.0020752,0.0019226,0.0017090,0.0016785,0.0014954,0.0017395,0.0016785,0.0021057,0.0022888,0.0026245,0.0029602,0.0032349,0.0036621,0.0037537,0.0041199,0.0041199,0.0041504,0.0041504,0.0039368,0.0037842,0.0035706,0.0032043,0.0029602,0.0027466,0.0023193,0.0022888,0.0019531,0.0019226,0.0018616,0.0016785,0.0018921,0.0017395,0.0019226,0.0019226,0.0020142,0.0020447,0.0019531,0.0020447,0.0018921,0.0018616,0.0016785,0.0014648,0.0013733,0.0010681,0.0009155,0.0007019,0.0004578,0.0002747,-0.0000305,-0.0001526,-0.0005798,-0.0004883,-0.0010376,-0.0008850,-0.0014648,-0.0013123,-0.0018921,-0.0017700,-0.0022278,-0.0021362,-0.0025330,-0.0023804,-0.0028076,-0.0026550,-0.0029907,-0.0028992,-0.0031738,-0.0032349,-0.0033264,-0.0036316,-0.0036316,-0.0039368,-0.0039978,-0.0043030,-0.0043335,-0.0046692,-0.0046082,-0.0049133,-0.0048218,-0.0048828,-0.0049744,-0.0046082,-0.0048218,-0.0044250,-0.0044250,-0.0042725,-0.0039368,-0.0040283,-0.0038147,-0.0038452,-0.0039062,-0.0037537,-0.0042419,-0.0040894,-0.0044556,-0.0046692,-0.0047607,-0.0051575,-0.0051270,-0.0052490,-0.0052185,-0.0050964,-0.0051575,-0.0046692,-0.0044861,-0.0041504,-0.0036621,-0.0034790,-0.0028992,-0.0026855,-0.0024109,-0.0021057,-0.0020752,-0.0019531,-0.0021057,-0.0020447,-0.0024109,-0.0025330,-0.0029297,-0.0030212,-0.0033569,-0.0034180,-0.0037537,-0.0035400,-0.0037537,-0.0032959,-0.0033264,-0.0028687,-0.0026245,-0.0020447,-0.0016785,-0.0010681,-0.0007629,-0.0000916,0.0000916,0.0006104,0.0007935,0.0008240,0.0010986,0.0010071,0.0008850,0.0008545,0.0005188,0.0003357,0.0001526,-0.0001831,-0.0003052,-0.0004578,-0.0005493,-0.0005493,-0.0004578,-0.0004272,0.0000305,0.0001526,0.0007019,0.0010376,0.0016785,0.0020447,0.0025940,0.0029907,0.0034180,0.0039062,0.0040588,0.0043640,0.0044250,0.0044861,0.0044861,0.0044250,0.0042114,0.0041809,0.0040283,0.0039978,0.0039673,0.0038147,0.0042419,0.0042419,0.0046387,0.0050964,0.0053101,0.0061035,0.0064087,0.0070496,0.0074768,0.0080261,0.0083008,0.0087280,0.0088196,0.0089722,0.0090942,0.0088196,0.0088196,0.0082703,0.0082092,0.0075989,0.0075684,0.0069580,0.0068970,0.0065002,0.0066223,0.0065613,0.0065308,0.0068970,0.0069580,0.0075989,0.0077209,0.0082703,0.0086975,0.0090637,0.0094910,0.0097656,0.0101013,0.0101929,0.0104980,0.0103149,0.0104675,0.0104065,0.0101624,0.0103149,0.0097961,0.0099792,0.0096436,0.0096436,0.0096436,0.0094910,0.0095825,0.0097046,0.0096130,0.0100708,0.0097961,0.0103760,0.0101013,0.0104675,0.0104980,0.0105591,0.0104980,0.0106506,0.0102539,0.0104370,0.0100708,0.0098877,0.0097656,0.0093384,0.0092773,0.0087891,0.0088196,0.0082397,0.0082703,0.0078735,0.0078125,0.0075684,0.0074463,0.0072937,0.0072021,0.0070801,0.0068359,0.0068970,0.0066223,0.0065613,0.0063782,0.0062866,0.0061340,0.0059509,0.0057983,0.0056152,0.0055237,0.0052795,0.0052185,0.0048218,0.0047913,0.0044861,0.0044250,0.0040588,0.0037842,0.0035706,0.0031128,0.0029297,0.0024414,0.0021057,0.0017090,0.0014038,0.0007629,0.0006714,0.0001221,-0.0000916,-0.0003662,-0.0005798,-0.0007324,-0.0007935,-0.0007935,-0.0008545,-0.0005798,-0.0008545,-0.0003662,-0.0006714,-0.0002136,-0.0005188,-0.0001526,-0.0003662,-0.0004272,-0.0003357,-0.0007935,-0.0006104,-0.0011292,-0.0012817,-0.0013428,-0.0017090,-0.0017700,-0.0019531,-0.0019836,-0.0019836,-0.0019531,-0.0018616,-0.0018005,-0.0015564,-0.0013428,-0.0011902,-0.0009460,-0.0006714,-0.0007019,-0.0003052,-0.0005493,-0.0002441,-0.0003662,-0.0004578,-0.0004883,-0.0007935,-0.0008240,-0.0010071,-0.0012817,-0.0014038,-0.0015869,-0.0017395,-0.0018311,-0.0019226,-0.0019836,-0.0019226,-0.0019226,-0.0018311,-0.0019226,-0.0015259,-0.0016479,-0.0013428,-0.0013428,-0.0011597,-0.0011292,-0.0009460,-0.0010071,-0.0008240,-0.0009155,-0.0007935,-0.0008545,-0.0009155,-0.0008240,-0.0011292,-0.0009460,-0.0011292,-0.0011902,-0.0012207,-0.0013428,-0.0011597,-0.0013123,-0.0010986,-0.0011292,-0.0008545,-0.0007019,-0.0004272,-0.0001831,0.0002441,0.0003967,0.0009155,0.0010986,0.0014343,0.0017395,0.0018921,0.0020142,0.0021362,0.0021057,0.0021057,0.0018616,0.0017700,0.0015564,0.0011597,0.0012512,0.0006409,0.0007324,0.0004883,0.0002747,0.0005798,0.0002136,0.0008545,0.0005493,0.0011902,0.0012207,0.0017700,0.0020142,0.0024109,0.0027161,0.0031128,0.0031738,0.0035095,0.0034485,0.0036926,0.0035095,0.0036316,0.0033569,0.0032349,0.0031738,0.0027466,0.0028381,0.0025635,0.0024719,0.0023499,0.0024414,0.0023499,0.0024719,0.0025635,0.0026550,0.0030823,0.0029602,0.0035095,0.0034790,0.0038452,0.0040588,0.0043030,0.0044556,0.0045471,0.0047302,0.0046692,0.0049438,0.0046082,0.0047607,0.0044556,0.0043640,0.0041809,0.0039368,0.0037842,0.0035400,0.0033569,0.0030518,0.0030518,0.0026550,0.0028687,0.0025635,0.0028687,0.0027466,0.0029602,0.0031738,0.0031128,0.0036011,0.0035400,0.0037842,0.0038452,0.0038757,0.0038452,0.0037231,0.0034180,0.0032654,0.0028687,0.0022583,0.0020752,0.0011597,0.0010071,0.0003662,-0.0001831,-0.0002441,-0.0010376,-0.0007019,-0.0013733,-0.0010071,-0.0012512,-0.0009155,-0.0009155,-0.0006409,-0.0004883,-0.0003357,-0.0000610,-0.0000610,0.0000305,0.0000610,-0.0001221,-0.0001831,-0.0004578,-0.0007019,-0.0008240,-0.0012512,-0.0012817,-0.0016785,-0.0015564,-0.0018921,-0.0016479,-0.0017395,-0.0016174,-0.0012512,-0.0012512,-0.0008850,-0.0007629,-0.0006104,-0.0003357,-0.0002747,-0.0002747,-0.0003357,-0.0003357,-0.0006104,-0.0005798,-0.0010376,-0.0010376,-0.0014343,-0.0014038,-0.0016785,-0.0016479,-0.0016479,-0.0014954,-0.0013428,-0.0011292,-0.0008240,-0.0006104,-0.0000916,0.0000305,0.0004883,0.0006104,0.0009155,0.0008545,0.0010681,0.0008545,0.0009460,0.0007019,0.0005493,0.0002747,0.0001831,-0.0001221,-0.0000610,-0.0004578,-0.0002747,-0.0003967,-0.0001221,-0.0000916,0.0002136,0.0003662,0.0007629,0.0009155,0.0013428,0.0014648,0.0017090,0.0018005,0.0018921,0.0018921,0.0016785,0.0018005,0.0014648,0.0013123,0.0011597,0.0007935,0.0008545,0.0005798,0.0004883,0.0005493,0.0005493,0.0006714,0.0008850,0.0010071,0.0013428,0.0017395,0.0019226,0.0024414,0.0025330,0.0028992,0.0030518,0.0032349,0.0033264,0.0033264,0.0033264,0.0031738,0.0030518,0.0028076,0.0027466,0.0024719,0.0022278,0.0020447,0.0018921,0.0018005,0.0018311,0.0016174,0.0019531,0.0017700,0.0021057,0.0021973,0.0024109,0.0025635,0.0028992,0.0029297,0.0033264,0.0032654,0.0035095,0.0035095,0.0035706,0.0034790,0.0034790,0.0032349,0.0032959,0.0030518,0.0029297,0.0028381,0.0026550,0.0026245,0.0025330,0.0026245,0.0025330,0.0027771,0.0027466,0.0029907,0.0032043,0.0033569,0.0035706,0.0036926,0.0039978,0.0039062,0.0042419,0.0039673,0.0039978,0.0040283,0.0035706,0.0036011,0.0031433,0.0028992,0.0026855,0.0021973,0.0020447,0.0015564,0.0015259,0.0011902,0.0011292,0.0010376,0.0010681,0.0010681,0.0011902,0.0013428,0.0014038,0.0016479,0.0015869,0.0019226,0.0017395,0.0019531,0.0016785,0.0018616,0.0013428,0.0014343,0.0007935,0.0007019,0.0001526,-0.0000305,-0.0004578,-0.0007324,-0.0010681,-0.0013428,-0.0015259,-0.0015869,-0.0018005,-0.0015869,-0.0018921,-0.0015869,-0.0016785,-0.0015259,-0.0015259,-0.0014954,-0.0015564,-0.0015259,-0.0018311,-0.0018921,-0.0022888,-0.0024109,-0.0028381,-0.0031128,-0.0035706,-0.0038757,-0.0042419,-0.0046082,-0.0048218,-0.0051270,-0.0054626,-0.0052795,-0.0057068,-0.0054321,-0.0056763,-0.0054932,-0.0054016,-0.0053406,-0.0050659,-0.0050964,-0.0049438,-0.0047913,-0.0048218,-0.0046997,-0.0048828,-0.0047913,-0.0050964,-0.0051575,-0.0054626,-0.0056763,-0.0059204,-0.0062866,-0.0065613,-0.0067749,-0.0071716,-0.0072021,-0.0075989,-0.0075378,-0.0078125,-0.0077515,-0.0077820,-0.0076599,-0.0076294,-0.0074768,-0.0073242,-0.0071106,-0.0069275,-0.0068054,-0.0065918,-0.0065613,-0.0065002,-0.0063477,-0.0064392,-0.0064392,-0.0064087,-0.0067749,-0.0065613,-0.0068970,-0.0069580,-0.0069580,-0.0072021,-0.0069885,-0.0071106,-0.0069885,-0.0068665,-0.0066833,-0.0064087,-0.0061340,-0.0057068,-0.0053711,-0.0049438,-0.0045776,-0.0042419,-0.0037537,-0.0035095,-0.0029602,-0.0029907,-0.0024109,-0.0025330,-0.0021362,-0.0021973,-0.0021362,-0.0021362,-0.0021362,-0.0020752,-0.0023193,-0.0021667,-0.0025330,-0.0022278,-0.0025024,-0.0023499,-0.0023499,-0.0024109,-0.0020447,-0.0022583,-0.0018005,-0.0018616,-0.0013123,-0.0013123,-0.0008240,-0.0007019,-0.0002441,-0.0000916,0.0002747,0.0005188,0.0007629,0.0009155,0.0011902,0.0013428,0.0013428,0.0016479,0.0014954,0.0017395,0.0016174,0.0018616,0.0017090,0.0019836,0.0019836,0.0022278,0.0024109,0.0026245,0.0028992,0.0032959,0.0034180,0.0040894,0.0041809,0.0046692,0.0049438,0.0052795,0.0054626,0.0056763,0.0059509,0.0058289,0.0061340,0.0058594,0.0060120,0.0059509,0.0056763,0.0059204,0.0054321,0.0057983,0.0054626,0.0056458,0.0056152,0.0057068,0.0059204,0.0058899,0.0061340,0.0061646,0.0064392,0.0062866,0.0066833,0.0063477,0.0066833,0.0062561,0.0064087,0.0061340,0.0060120,0.0057983,0.0057373,0.0054932,0.0054016,0.0052185,0.0053406,0.0050964,0.0054932,0.0051575,0.0056458,0.0054626,0.0058594,0.0057068,0.0060730,0.0059204,0.0061035,0.0058899,0.0059204,0.0055847,0.0054932,0.0049744,0.0047913,0.0042114,0.0039368,0.0033569,0.0029602,0.0026550,0.0020447,0.0019531,0.0014954,0.0014038,0.0011597,0.0010986,0.0008240,0.0009766,0.0006409,0.0007935,0.0005798,0.0005188,0.0004578,0.0001831,0.0000305,-0.0001221,-0.0005188,-0.0007019,-0.0010986,-0.0014954,-0.0017395,-0.0020142,-0.0024414,-0.0025635,-0.0028687,-0.0029602,-0.0031128,-0.0032654,-0.0031433,-0.0033569,-0.0031433,-0.0031128,-0.0031433,-0.0029602,-0.0031128,-0.0028381,-0.0030823,-0.0028076,-0.0032349,-0.0031128,-0.0034180,-0.0036316,-0.0038147,-0.0042419,-0.0043640,-0.0049133,-0.0050659,-0.0054626,-0.0056152,-0.0060730,-0.0060730,-0.0064697,-0.0063477,-0.0066223,-0.0065002,-0.0066833,-0.0066528,-0.0065918,-0.0067444,-0.0065918,-0.0068054,-0.0067444,-0.0070190,-0.0070801,-0.0074158,-0.0075684,-0.0080261,-0.0081482,-0.0086365,-0.0088501,-0.0091248,-0.0095215,-0.0095520,-0.0097961,-0.0097961,-0.0099487,-0.0097351,-0.0097961,-0.0093689,-0.0093079,-0.0088806,-0.0088501,-0.0083313,-0.0083313,-0.0078125,-0.0079041,-0.0075378,-0.0076599,-0.0073547,-0.0076599,-0.0074463,-0.0077515,-0.0076904,-0.0079041,-0.0079956,-0.0081177,-0.0081482,-0.0083313,-0.0083618,-0.0083008,-0.0083008,-0.0081177,-0.0082092,-0.0078735,-0.0080566,-0.0075989,-0.0077820,-0.0073242,-0.0074768,-0.0071716,-0.0072021,-0.0070801,-0.0069275,-0.0070801,-0.0068054,-0.0069885,-0.0067444,-0.0067749,-0.0067749,-0.0065613,-0.0065613,-0.0065308,-0.0061951,-0.0064087,-0.0059814,-0.0059814,-0.0057068,-0.0055847,-0.0052490,-0.0051880,-0.0047913,-0.0046387,-0.0043640,-0.0040283,-0.0038452,-0.0035400,-0.0033569,-0.0031433,-0.0029602,-0.0028381,-0.0027466,-0.0025940,-0.0026245,-0.0026245,-0.0026550,-0.0026855,-0.0028381,-0.0028381,-0.0029602,-0.0029907,-0.0029297,-0.0030518,-0.0029297,-0.0028992,-0.0027466,-0.0026245,-0.0022583,-0.0022278,-0.0016785,-0.0014954,-0.0011902,-0.0007629,-0.0006409,-0.0001221,-0.0000916,0.0002441,0.0003052,0.0006714,0.0004272,0.0009460,0.0005798,0.0009460,0.0005798,0.0009460,0.0006714,0.0008850,0.0008545,0.0009155,0.0010376,0.0011902,0.0011292,0.0014954,0.0014954,0.0017395,0.0019226,0.0021362,0.0021667,0.0024414,0.0023499,0.0025940,0.0025330,0.0024719,0.0024109,0.0021973,0.0021667,0.0017395,0.0018005,0.0011597,0.0011902,0.0007019,0.0006104,0.0001831,0.0000610,-0.0002136,-0.0003662,-0.0003662,-0.0006714,-0.0004272,-0.0007935,-0.0005798,-0.0008545,-0.0005188,-0.0008240,-0.0006409,-0.0007324,-0.0007629,-0.0007629,-0.0009460,-0.0010071,-0.0011902,-0.0010986,-0.0013733,-0.0012817,-0.0014343,-0.0015564,-0.0013733,-0.0015259,-0.0014648,-0.0013428,-0.0014648,-0.0011902,-0.0012817,-0.0010071,-0.0012817,-0.0010071,-0.0012512,-0.0010986,-0.0011597,-0.0013428,-0.0011292,-0.0014343,-0.0014038,-0.0014648,-0.0015564,-0.0014954,-0.0016174,-0.0014343,-0.0016479,-0.0015869,-0.0015869,-0.0017700,-0.0015259,-0.0019531,-0.0018921,-0.0021973,-0.0023193,-0.0025024,-0.0029297,-0.0030518,-0.0035400,-0.0036011,-0.0038147,-0.0041504,-0.0039978,-0.0043945,-0.0040588,-0.0042419,-0.0038757,-0.0039368,-0.0034790,-0.0035095,-0.0031738,-0.0028992,-0.0029907,-0.0027771,-0.0028687,-0.0028381,-0.0031433,-0.0031738,-0.0037231,-0.0038452,-0.0042725,-0.0046082,-0.0048828,-0.0052490,-0.0053406,-0.0056763,-0.0054321,-0.0057373,-0.0053406,-0.0053711,-0.0050659,-0.0047607,-0.0046387,-0.0042725,-0.0041504,-0.0039062,-0.0038757,-0.0038147,-0.0038147,-0.0040283,-0.0041199,-0.0044556,-0.0046387,-0.0050659,-0.0050659,-0.0056152,-0.0055847,-0.0057373,-0.0059204,-0.0056763,-0.0057678,-0.0052795,-0.0052490,-0.0046692,-0.0044861,-0.0039673,-0.0034485,-0.0032654,-0.0027161,-0.0025635,-0.0022888,-0.0020447,-0.0020447,-0.0019836,-0.0020752,-0.0022888,-0.0022888,-0.0028381,-0.0026550,-0.0034180,-0.0032043,-0.0036926,-0.0037231,-0.0038147,-0.0038757,-0.0037231,-0.0036621,-0.0035095,-0.0032654,-0.0028992,-0.0026855,-0.0023499,-0.0020142,-0.0018311,-0.0014343,-0.0014038,-0.0010986,-0.0012512,-0.0010071,-0.0012207,-0.0011292,-0.0013428,-0.0015259,-0.0015564,-0.0017395,-0.0018005,-0.0018005,-0.0018921,-0.0015869,-0.0015259,-0.0013428,-0.0008850,-0.0007324,-0.0000305,0.0001221,0.0007935,0.0009766,0.0016174,0.0016479,0.0022888,0.0020447,0.0025940,0.0022278,0.0025330,0.0020752,0.0022583,0.0017700,0.0018005,0.0015564,0.0012817,0.0013428,0.0010376,0.0012817,0.0010986,0.0014648,0.0014954,0.0018616,0.0020752,0.0024719,0.0027161,0.0031738,0.0032654,0.0036011,0.0036011,0.0037231,0.0036926,0.0035400,0.0032349,0.0028992,0.0025024,0.0019531,0.0015564,0.0007629,0.0004883,-0.0001526,-0.0005493,-0.0009766,-0.0011902,-0.0015259,-0.0013733,-0.0015869,-0.0013123,-0.0011597,-0.0009155,-0.0005798,-0.0002747,-0.0000916,0.0004272,0.0003662,0.0008545,0.0006714,0.0009155,0.0006409,0.0006714,0.0003662,0.0000916,-0.0001221,-0.0006409,-0.0006714,-0.0011902,-0.0012817,-0.0014038,-0.0017090,-0.0014648,-0.0016479,-0.0014343,-0.0012512,-0.0010986,-0.0007935,-0.0005798,-0.0003357,-0.0001221,-0.0000305,0.0001221,0.0000305,0.0000305,-0.0000305,-0.0003662,-0.0003967,-0.0008240,-0.0009766,-0.0012817,-0.0014954,-0.0017395,-0.0018311,-0.0018616,-0.0018921,-0.0018311,-0.0015869,-0.0014954,-0.0010071,-0.0008850,-0.0003357,-0.0001221,0.0003967,0.0007629,0.0010071,0.0013733,0.0014343,0.0016785,0.0016785,0.0015564,0.0015869,0.0012817,0.0012207,0.0009155,0.0006104,0.0005188,0.0001221,0.0001831,-0.0001831,-0.0001221,-0.0001831,-0.0000610,0.0000000,0.0002747,0.0003967,0.0005798,0.0009460,0.0009766,0.0014343,0.0012817,0.0015564,0.0014648,0.0013733,0.0013428,0.0009155,0.0008240,0.0003967,0.0001526,-0.0002747,-0.0003662,-0.0008850,-0.0008850,-0.0012207,-0.0009766,-0.0011597,-0.0007935,-0.0007935,-0.0002441,-0.0000305,0.0004272,0.0008545,0.0011597,0.0016479,0.0018921,0.0021973,0.0024414,0.0023804,0.0027161,0.0024719,0.0025940,0.0024414,0.0023193,0.0022583,0.0021973,0.0019836,0.0021973,0.0019226,0.0023499,0.0020447,0.0026245,0.0025024,0.0028992,0.0031433,0.0032043,0.0036926,0.0035706,0.0039368,0.0038757,0.0038147,0.0039062,0.0037231,0.0035706,0.0034180,0.0031433,0.0029297,0.0026855,0.0025024,0.0021973,0.0021057,0.0018311,0.0018311,0.0018311,0.0017395,0.0018921,0.0018616,0.0020142,0.0021362,0.0023193,0.0024414,0.0026550,0.0027466,0.0028992,0.0030518,0.0030823,0.0031433,0.0031433,0.0031433,0.0032654,0.0029602,0.0031433,0.0028381,0.0027771,0.0028076,0.0024109,0.0026245,0.0023804,0.0025330,0.0023499,0.0026550,0.0025024,0.0028381,0.0029297,0.0030823,0.0033569,0.0034790,0.0036316,0.0038147,0.0039368,0.0038452,0.0039062,0.0037231,0.0034790,0.0033569,0.0027771,0.0026550,0.0019836,0.0017395,0.0011292,0.0007629,0.0004883,0.0000610,-0.0000610,-0.0003967,-0.0003967,-0.0003357,-0.0004578,-0.0000916,-0.0002441,0.0001831,0.0002441,0.0004883,0.0005188,0.0006409,0.0005798,0.0005493,0.0002441,0.0001526,-0.0003967,-0.0004883,-0.0011902,-0.0014648,-0.0021973,-0.0024414,-0.0030518,-0.0032959,-0.0036316,-0.0040283,-0.0039673,-0.0042419,-0.0042114,-0.0040894,-0.0041504,-0.0038147,-0.0038452,-0.0035400,-0.0035400,-0.0033569,-0.0033569,-0.0033264,-0.0033569,-0.0036011,-0.0036621,-0.0039062,-0.0040283,-0.0043945,-0.0045776,-0.0049133,-0.0050354,-0.0052185,-0.0053406,-0.0053406,-0.0053406,-0.0052490,-0.0049744,-0.0049133,-0.0043640,-0.0043640,-0.0037537,-0.0035400,-0.0032349,-0.0027771,-0.0026550,-0.0022583,-0.0021667,-0.0020142,-0.0019226,-0.0019226,-0.0019531,-0.0020752,-0.0021362,-0.0022888,-0.0024414,-0.0025635,-0.0026855,-0.0028687,-0.0027466,-0.0030823,-0.0027466,-0.0029602,-0.0026550,-0.0026855,-0.0023804,-0.0024414,-0.0019836,-0.0021362,-0.0015869,-0.0016479,-0.0013428,-0.0013428,-0.0011902,-0.0011902,-0.0010071,-0.0011292,-0.0011597,-0.0011902,-0.0012207,-0.0014954,-0.0014648,-0.0017395,-0.0017700,-0.0019226,-0.0021057,-0.0020752,-0.0021973,-0.0021667,-0.0021057,-0.0020447,-0.0017700,-0.0015564,-0.0012207,-0.0009155,-0.0003967,-0.0002136,0.0005493,0.0005798,0.0015259,0.0014648,0.0021362,0.0022888,0.0024719,0.0028076,0.0027466,0.0028992,0.0028076,0.0026855,0.0028381,0.0022278,0.0026550,0.0020752,0.0023804,0.0021973,0.0022888,0.0025024,0.0026855,0.0030518,0.0034180,0.0037842,0.0043640,0.0049133,0.0053101,0.0059509,0.0062561,0.0067749,0.0068970,0.0073853,0.0072937,0.0073547,0.0074158,0.0070496,0.0070801,0.0066833,0.0062866,0.0062561,0.0057068,0.0057983,0.0053406,0.0053406,0.0054626,0.0054016,0.0058289,0.0059509,0.0064392,0.0068665,0.0074463,0.0078430,0.0085144,0.0088806,0.0094604,0.0097351,0.0101929,0.0103149,0.0105591,0.0104675,0.0103760,0.0103149,0.0100403,0.0097656,0.0094910,0.0091858,0.0088501,0.0086975,0.0084534,0.0083923,0.0082703,0.0083923,0.0086060,0.0086975,0.0091858,0.0093079,0.0098267,0.0100708,0.0105591,0.0106812,0.0110168,0.0110474,0.0111084,0.0110474,0.0107727,0.0104980,0.0100708,0.0096436,0.0090942,0.0086365,0.0079651,0.0076294,0.0070190,0.0067749,0.0063782,0.0062256,0.0060730,0.0062256,0.0061035,0.0065308,0.0065002,0.0068970,0.0070496,0.0072021,0.0074463,0.0075684,0.0074768,0.0075989,0.0071411,0.0072327,0.0065918,0.0065613,0.0058594,0.0056458,0.0050964,0.0046387,0.0043335,0.0039978,0.0036926,0.0036316,0.0033264,0.0034790,0.0031433,0.0034790,0.0032349,0.0035706,0.0033264,0.0035706,0.0032959,0.0032654,0.0032959,0.0028381,0.0028381,0.0024414,0.0020447,0.0018616,0.0013428,0.0010681,0.0008240,0.0003967,0.0003357,0.0000000,-0.0000610,-0.0002136,-0.0001526,-0.0001831,-0.0000916,-0.0000305,0.0000305,0.0003052,0.0002136,0.0005188,0.0005493,0.0007019,0.0006409,0.0008240,0.0005798,0.0008240,0.0005493,0.0006104,0.0003967,0.0003662,0.0001526,0.0001831,-0.0001831,-0.0001221,-0.0005188,-0.0003967,-0.0007629,-0.0007935,-0.0009460,-0.0011597,-0.0010986,-0.0012817,-0.0012512,-0.0011902,-0.0013123,-0.0010376,-0.0010986,-0.0008545,-0.0007935,-0.0006409,-0.0005493,-0.0004578,-0.0003357,-0.0004578,-0.0001526,-0.0006104,-0.0002747,-0.0009155,-0.0006409,-0.0013123,-0.0011902,-0.0015869,-0.0018616,-0.0018005,-0.0022278,-0.0020752,-0.0021973,-0.0020447,-0.0019836,-0.0017395,-0.0014648,-0.0012817,-0.0008545,-0.0006409,-0.0003967,0.0000610,0.0001221,0.0002441,0.0004883,0.0003662,0.0002441,0.0003967,-0.0000916,0.0000000,-0.0002747,-0.0006104,-0.0005188,-0.0007019,-0.0007324,-0.0006104,-0.0005188,-0.0003357,0.0000305,0.0002441,0.0008240,0.0009460,0.0015869,0.0017395,0.0021362,0.0023804,0.0025330,0.0025940,0.0027161,0.0024109,0.0025024,0.0022888,0.0020447,0.0019226,0.0016174,0.0014954,0.0014954,0.0014038,0.0015869,0.0015564,0.0019836,0.0020142,0.0026550,0.0027466,0.0034180,0.0036011,0.0040283,0.0043030,0.0044861,0.0045776,0.0047607,0.0043945,0.0046387,0.0039978,0.0039978,0.0034485,0.0031738,0.0028381,0.0023804,0.0021973,0.0018921,0.0018005,0.0017700,0.0017090,0.0019226,0.0019531,0.0023193,0.0025330,0.0028381,0.0031128,0.0033875,0.0035706,0.0036926,0.0038757,0.0038147,0.0037842,0.0037231,0.0033875,0.0033569,0.0029602,0.0027161,0.0024719,0.0021057,0.0021057,0.0016785,0.0018616,0.0015259,0.0017700,0.0018005,0.0019531,0.0021057,0.0023193,0.0024719,0.0027161,0.0028381,0.0028992,0.0028687,0.0028992,0.0026855,0.0025940,0.0021973,0.0019226,0.0014954,0.0011597,0.0007935,0.0003052,0.0000610,-0.0003357,-0.0003967,-0.0006104,-0.0005493,-0.0005798,-0.0003662,-0.0001526,0.0000305,0.0003967,0.0006409,0.0009460,0.0012207,0.0013733,0.0014343,0.0016174,0.0012207,0.0014038,0.0008240,0.0006714,0.0000916,-0.0002441,-0.0007935,-0.0012817,-0.0016479,-0.0021362,-0.0023193,-0.0025024,-0.0025940,-0.0025635,-0.0023499,-0.0020752,-0.0018005,-0.0012207,-0.0009155,-0.0004272,0.0000000,0.0004272,0.0007324,0.0010071,0.0010681,0.0010681,0.0010071,0.0005798,0.0005493,-0.0002136,-0.0002747,-0.0010681,-0.0012817,-0.0018005,-0.0019836,-0.0022583,-0.0021362,-0.0024109,-0.0018616,-0.0018616,-0.0011902,-0.0009460,-0.0001831,0.0001526,0.0010986,0.0012207,0.0019836,0.0022278,0.0023804,0.0028687,0.0024719,0.0026550,0.0024109,0.0019836,0.0018005,0.0013123,0.0010071,0.0006104,0.0003052,0.0001221,-0.0000610,-0.0000916,-0.0000916,0.0001526,0.0001831,0.0007324,0.0008240,0.0012817,0.0016174,0.0019226,0.0022583,0.0024414,0.0025635,0.0027161,0.0026550,0.0026550,0.0023193,0.0024414,0.0020142,0.0020142,0.0018005,0.0015259,0.0016174,0.0012817,0.0015869,0.0013123,0.0017090,0.0015259,0.0018616,0.0019836,0.0021362,0.0023193,0.0025330,0.0025024,0.0027161,0.0027161,0.0027771,0.0028381,0.0027466,0.0028381,0.0026245,0.0028076,0.0026855,0.0026855,0.0028381,0.0026855,0.0029602,0.0029297,0.0031128,0.0032043,0.0033569,0.0034180,0.0036316,0.0036621,0.0037842,0.0037537,0.0038147,0.0037842,0.0036926,0.0037537,0.0035706,0.0035400,0.0034485,0.0032654,0.0032654,0.0031128,0.0031433,0.0029602,0.0030823,0.0029602,0.0030212,0.0029907,0.0030823,0.0030518,0.0032043,0.0031738,0.0032654,0.0033264,0.0032349,0.0033569,0.0032043,0.0032959,0.0032349,0.0031738,0.0032043,0.0031128,0.0031128,0.0029297,0.0029602,0.0029602,0.0028381,0.0027771,0.0027771,0.0026245,0.0027771,0.0025024,0.0026245,0.0023499,0.0025024,0.0021362,0.0022278,0.0018616,0.0016174,0.0016174,0.0010986,0.0010681,0.0006104,0.0004272,0.0000610,-0.0002441,-0.0004578,-0.0007019,-0.0008850,-0.0011292,-0.0011597,-0.0013428,-0.0012817,-0.0014038,-0.0013733,-0.0012817,-0.0014038,-0.0011902,-0.0014038,-0.0011902,-0.0014648,-0.0013123,-0.0015869,-0.0017090,-0.0018311,-0.0021667,-0.0021973,-0.0026855,-0.0027466,-0.0030823,-0.0032654,-0.0033264,-0.0036316,-0.0035706,-0.0037537,-0.0037537,-0.0036926,-0.0038452,-0.0035706,-0.0038147,-0.0035400,-0.0036316,-0.0037537,-0.0036926,-0.0039673,-0.0039368,-0.0043030,-0.0043640,-0.0047302,-0.0049133,-0.0051270,-0.0054626,-0.0055237,-0.0058899,-0.0058289,-0.0060730,-0.0061035,-0.0060730,-0.0061646,-0.0060730,-0.0060425,-0.0059509,-0.0061035,-0.0057983,-0.0061340,-0.0058289,-0.0062561,-0.0060425,-0.0064392,-0.0063782,-0.0066528,-0.0067444,-0.0068054,-0.0070801,-0.0070190,-0.0072632,-0.0069275,-0.0071411,-0.0069580,-0.0068665,-0.0067444,-0.0065613,-0.0064392,-0.0062256,-0.0061035,-0.0058899,-0.0058594,-0.0056763,-0.0057068,-0.0055237,-0.0056763,-0.0054626,-0.0055847,-0.0055847,-0.0054626,-0.0054932,-0.0053101,-0.0052185,-0.0051270,-0.0048828,-0.0048218,-0.0044861,-0.0043945,-0.0040894,-0.0039368,-0.0038452,-0.0035706,-0.0035095,-0.0032654,-0.0034180,-0.0030518,-0.0032654,-0.0029602,-0.0029907,-0.0028381,-0.0026550,-0.0026550,-0.0022278,-0.0022888,-0.0018005,-0.0016785,-0.0013733,-0.0011292,-0.0007935,-0.0005493,-0.0003357,-0.0001831,0.0000916,0.0002441,0.0003052,0.0003357,0.0005188,0.0006104,0.0005493,0.0007935,0.0006409,0.0009460,0.0010376,0.0010681,0.0013428,0.0015869,0.0015564,0.0021667,0.0019226,0.0025024,0.0024109,0.0028076,0.0027466,0.0029907,0.0030518,0.0031128,0.0033264,0.0032654,0.0035095,0.0036316,0.0036316,0.0038452,0.0039673,0.0041809,0.0044250,0.0045471,0.0047607,0.0048828,0.0051575,0.0051575,0.0054626,0.0052795,0.0056152,0.0053101,0.0055542,0.0054626,0.0054321,0.0055237,0.0055237,0.0054626,0.0058594,0.0055542,0.0061340,0.0058899,0.0064392,0.0063782,0.0065613,0.0067444,0.0068054,0.0068970,0.0068054,0.0067139,0.0065918,0.0063782,0.0060425,0.0058289,0.0053711,0.0051270,0.0047302,0.0043945,0.0041199,0.0039062,0.0037537,0.0035095,0.0036926,0.0034180,0.0037231,0.0035400,0.0038147,0.0037231,0.0038757,0.0039368,0.0038147,0.0037842,0.0037231,0.0034790,0.0034180,0.0029297,0.0028076,0.0023193,0.0021667,0.0016785,0.0015259,0.0010986,0.0008545,0.0006409,0.0004578,0.0001831,0.0002441,-0.0002441,0.0000305,-0.0003967,-0.0002136,-0.0004883,-0.0005188,-0.0007019,-0.0007935,-0.0009155,-0.0011292,-0.0011597,-0.0013428,-0.0015259,-0.0015259,-0.0018921,-0.0018005,-0.0021057,-0.0021057,-0.0022583,-0.0023499,-0.0025330,-0.0026855,-0.0028381,-0.0029907,-0.0030823,-0.0033875,-0.0034790,-0.0038147,-0.0037842,-0.0040588,-0.0043030,-0.0043335,-0.0046997,-0.0045166,-0.0047302,-0.0048218,-0.0047302,-0.0050354,-0.0048218,-0.0049438,-0.0049133,-0.0050354,-0.0050964,-0.0051270,-0.0052795,-0.0054016,-0.0056458,-0.0057678,-0.0061035,-0.0062256,-0.0066528,-0.0068054,-0.0069580,-0.0072937,-0.0073853,-0.0075989,-0.0076294,-0.0077209,-0.0077209,-0.0077209,-0.0075989,-0.0076294,-0.0072327,-0.0075073,-0.0069580,-0.0072632,-0.0069275,-0.0070496,-0.0069275,-0.0071411,-0.0072021,-0.0073547,-0.0075989,-0.0075989,-0.0080566,-0.0080261,-0.0084839,-0.0083618,-0.0087280,-0.0086365,-0.0086060,-0.0087891,-0.0084534,-0.0085449,-0.0082397,-0.0082397,-0.0078735,-0.0079041,-0.0075684,-0.0075684,-0.0073547,-0.0072327,-0.0072632,-0.0072021,-0.0071411,-0.0072632,-0.0071411,-0.0071411,-0.0072327,-0.0069275,-0.0072021,-0.0068054,-0.0068054,-0.0066833,-0.0063171,-0.0063477,-0.0059509,-0.0058594,-0.0055542,-0.0054626,-0.0051575,-0.0050354,-0.0050049,-0.0046387,-0.0047913,-0.0045471,-0.0045166,-0.0044250,-0.0043945,-0.0041809,-0.0043335,-0.0039673,-0.0041504,-0.0038147,-0.0039978,-0.0036011,-0.0038147,-0.0035095,-0.0036316,-0.0034180,-0.0034790,-0.0034485,-0.0032654,-0.0035706,-0.0030518,-0.0033875,-0.0028992,-0.0031738,-0.0026550,-0.0027161,-0.0023193,-0.0022278,-0.0018311,-0.0016479,-0.0014343,-0.0011292,-0.0010376,-0.0007629,-0.0007935,-0.0005188,-0.0007324,-0.0005188,-0.0006714,-0.0006409,-0.0007019,-0.0008545,-0.0007629,-0.0008545,-0.0008545,-0.0007019,-0.0007629,-0.0003662,-0.0004578,0.0000610,0.0000610,0.0004883,0.0005798,0.0009155,0.0009766,0.0012817,0.0012207,0.0013733,0.0012512,0.0011597,0.0011292,0.0006714,0.0006714,0.0002747,-0.0000610,-0.0001526,-0.0007019,-0.0008240,-0.0010376,-0.0013123,-0.0013123,-0.0014954,-0.0014954,-0.0013733,-0.0014038,-0.0011597,-0.0010376,-0.0008545,-0.0007629,-0.0003662,-0.0004883,-0.0000610,-0.0002747,-0.0000610,-0.0003052,-0.0003662,-0.0005188,-0.0008545,-0.0010376,-0.0014038,-0.0017700,-0.0020752,-0.0023499,-0.0026855,-0.0027466,-0.0029907,-0.0028992,-0.0029907,-0.0026550,-0.0025940,-0.0021973,-0.0020142,-0.0016479,-0.0013123,-0.0009766,-0.0008240,-0.0006104,-0.0007019,-0.0005798,-0.0008240,-0.0010376,-0.0013733,-0.0018311,-0.0021057,-0.0028076,-0.0028992,-0.0036621,-0.0036316,-0.0041504,-0.0039978,-0.0041809,-0.0039368,-0.0038452,-0.0034180,-0.0031738,-0.0025940,-0.0024414,-0.0017700,-0.0016479,-0.0013428,-0.0010681,-0.0011902,-0.0010681,-0.0013428,-0.0014954,-0.0018921,-0.0022583,-0.0025940,-0.0030823,-0.0033875,-0.0036926,-0.0041199,-0.0040588,-0.0043945,-0.0041504,-0.0042114,-0.0041199,-0.0037537,-0.0037537,-0.0032654,-0.0032349,-0.0028687,-0.0029297,-0.0026550,-0.0027161,-0.0027771,-0.0029907,-0.0032654,-0.0035095,-0.0039368,-0.0041504,-0.0046997,-0.0048828,-0.0054321,-0.0055847,-0.0057983,-0.0060425,-0.0059204,-0.0061340,-0.0057068,-0.0057983,-0.0053406,-0.0051270,-0.0047302,-0.0044250,-0.0040283,-0.0038757,-0.0034180,-0.0034180,-0.0031738,-0.0031738,-0.0031433,-0.0030823,-0.0035095,-0.0032043,-0.0039368,-0.0035400,-0.0040894,-0.0040283,-0.0041199,-0.0042419,-0.0040588,-0.0040588,-0.0039062,-0.0035095,-0.0035400,-0.0030212,-0.0028687,-0.0026550,-0.0022583,-0.0022583,-0.0019531,-0.0020447,-0.0018921,-0.0018921,-0.0020447,-0.0020142,-0.0021973,-0.0022888,-0.0023804,-0.0025635,-0.0024719,-0.0025940,-0.0025024,-0.0025330,-0.0023499,-0.0022278,-0.0020142,-0.0018616,-0.0016479,-0.0014343,-0.0011292,-0.0010376,-0.0007935,-0.0007324,-0.0005493,-0.0005493,-0.0004578,-0.0003967,-0.0004272,-0.0002441,-0.0004883,-0.0001526,-0.0004883,-0.0000610,-0.0003052,-0.0001221,0.0000305,-0.0000305,0.0001526,0.0001831,0.0003662,0.0003357,0.0005798,0.0005188,0.0007935,0.0007324,0.0010071,0.0008850,0.0012207,0.0011292,0.0013428,0.0013733,0.0014343,0.0014343,0.0015259,0.0013428,0.0015259,0.0011902,0.0013123,0.0010681,0.0010681,0.0007629,0.0009155,0.0004883,0.0007324,0.0005493,0.0004578,0.0006714,0.0004883,0.0006714,0.0007324,0.0007324,0.0010681,0.0008240,0.0010681,0.0010071,0.0008545,0.0010376,0.0005493,0.0007324,0.0002136,0.0001831,-0.0001221,-0.0003967,-0.0005493,-0.0007324,-0.0009155,-0.0010681,-0.0010071,-0.0010681,-0.0010071,-0.0009155,-0.0008850,-0.0006409,-0.0006409,-0.0003662,-0.0004578,-0.0001831,-0.0003967,-0.0002441,-0.0004272,-0.0003967,-0.0007324,-0.0007324,-0.0010986,-0.0010986,-0.0015259,-0.0014038,-0.0019836,-0.0016479,-0.0021362,-0.0018921,-0.0020142,-0.0020142,-0.0019531,-0.0017090,-0.0018005,-0.0014038,-0.0015869,-0.0011292,-0.0012512,-0.0009155,-0.0010071,-0.0007935,-0.0007629,-0.0007935,-0.0006714,-0.0007324,-0.0007324,-0.0007324,-0.0008240,-0.0007629,-0.0009766,-0.0008240,-0.0009460,-0.0008545,-0.0008545,-0.0008545,-0.0007629,-0.0005798,-0.0005493,-0.0003052,-0.0002441,0.0000000,0.0001831,0.0003357,0.0003662,0.0007324,0.0005188,0.0008545,0.0005493,0.0007019,0.0004578,0.0003662,0.0002136,-0.0000305,-0.0001221,-0.0004272,-0.0003967,-0.0005798,-0.0005798,-0.0005493,-0.0006714,-0.0003357,-0.0003357,-0.0001221,0.0002441,0.0001831,0.0007324,0.0006104,0.0009155,0.0009460,0.0009155,0.0010681,0.0007935,0.0009155,0.0004578,0.0005798,0.0001526,0.0000610,-0.0001221,-0.0001831,-0.0003662,-0.0003052,-0.0002747,-0.0001831,0.0000610,0.0002136,0.0004272,0.0007629,0.0010681,0.0011597,0.0017395,0.0016479,0.0020142,0.0022583,0.0021973,0.0024719,0.0022888,0.0025024,0.0022278,0.0025635,0.0021362,0.0024719,0.0021362,0.0024719,0.0021667,0.0024109,0.0022278,0.0024719,0.0024414,0.0025635,0.0025635,0.0028687,0.0026855,0.0029602,0.0028381,0.0030212,0.0030823,0.0031433,0.0032043,0.0034180,0.0032043,0.0037231,0.0033875,0.0037537,0.0036621,0.0037842,0.0039368,0.0038452,0.0040894,0.0038452,0.0039673,0.0038452,0.0038147,0.0036926,0.0036011,0.0034485,0.0033264,0.0032349,0.0030518,0.0029602,0.0028687,0.0028076,0.0026550,0.0027161,0.0026550,0.0027161,0.0027466,0.0028076,0.0028687,0.0029297,0.0029297,0.0031128,0.0029297,0.0032349,0.0029297,0.0030518,0.0029297,0.0027161,0.0027466,0.0024109,0.0022583,0.0020447,0.0018311,0.0015869,0.0014343,0.0011292,0.0010376,0.0009460,0.0007935,0.0007935,0.0007019,0.0008545,0.0008240,0.0009460,0.0010071,0.0010986,0.0013123,0.0012512,0.0014343,0.0013123,0.0013428,0.0011292,0.0010681,0.0007019,0.0005493,0.0000610,-0.0002747,-0.0006104,-0.0010376,-0.0014954,-0.0017395,-0.0022888,-0.0022583,-0.0027161,-0.0026855,-0.0028687,-0.0028076,-0.0028992,-0.0026245,-0.0026550,-0.0023193,-0.0023193,-0.0020142,-0.0020142,-0.0019226,-0.0018311,-0.0020447,-0.0020142,-0.0021973,-0.0025940,-0.0025940,-0.0030823,-0.0033569,-0.0035400,-0.0039978,-0.0040894,-0.0043030,-0.0044556,-0.0044250,-0.0043640,-0.0042419,-0.0040588,-0.0038147,-0.0035400,-0.0031738,-0.0028992,-0.0025635,-0.0023804,-0.0021973,-0.0019836,-0.0020447,-0.0019531,-0.0022583,-0.0022583,-0.0027466,-0.0027771,-0.0034180,-0.0034485,-0.0039673,-0.0041504,-0.0043335,-0.0045471,-0.0044250,-0.0045776,-0.0043030,-0.0042114,-0.0038757,-0.0034180,-0.0032959,-0.0025635,-0.0024719,-0.0018616,-0.0016785,-0.0011902,-0.0012207,-0.0007935,-0.0010376,-0.0008545,-0.0010986,-0.0011292,-0.0014343,-0.0015869,-0.0018921,-0.0021362,-0.0023804,-0.0025024,-0.0027161,-0.0027466,-0.0027161,-0.0027466,-0.0023804,-0.0023804,-0.0019226,-0.0017090,-0.0013733,-0.0009766,-0.0005798,-0.0003357,0.0000305,0.0002136,0.0003967,0.0004883,0.0005798,0.0006104,0.0004272,0.0004578,0.0002136,0.0002441,0.0000305,0.0001526,-0.0000610,0.0002136,0.0001831,0.0004578,0.0007935,0.0010986,0.0014648,0.0020447,0.0023804,0.0029907,0.0033569,0.0038147,0.0041504,0.0044861,0.0046082,0.0047607,0.0047302,0.0047302,0.0046082,0.0044250,0.0043335,0.0039673,0.0039978,0.0036926,0.0037842,0.0036926,0.0038757,0.0038757,0.0041809,0.0045166,0.0047607,0.0052795,0.0055847,0.0060120,0.0063477,0.0067139,0.0070190,0.0072021,0.0074768,0.0074158,0.0074768,0.0075378,0.0073547,0.0072937,0.0071411,0.0071106,0.0068665,0.0070190,0.0066833,0.0068359,0.0068359,0.0068054,0.0070496,0.0070801,0.0073242,0.0074463,0.0076904,0.0078735,0.0080872,0.0083923,0.0084839,0.0087280,0.0088806,0.0091248,0.0092468,0.0092773,0.0094604,0.0094604,0.0095215,0.0094299,0.0094299,0.0092468,0.0092468,0.0089111,0.0089417,0.0085449,0.0085144,0.0081787,0.0079651,0.0078125,0.0075684,0.0075684,0.0072937,0.0073547,0.0072632,0.0072937,0.0073242,0.0073547,0.0073853,0.0074158,0.0075073,0.0074158,0.0076294,0.0073242,0.0074768,0.0072327,0.0071106,0.0070496,0.0066833,0.0065918,0.0063171,0.0061340,0.0059204,0.0056458,0.0054932,0.0052490,0.0052185,0.0048828,0.0050659,0.0046692,0.0049438,0.0045471,0.0048218,0.0043945,0.0048523,0.0043335,0.0046387,0.0043640,0.0043945,0.0043640,0.0041199,0.0042419,0.0038757,0.0039368,0.0036011,0.0036011,0.0032959,0.0032043,0.0029907,0.0027161,0.0027161,0.0022583,0.0023193,0.0019836,0.0019531,0.0017700,0.0016174,0.0015259,0.0014954,0.0013733,0.0012817,0.0012512,0.0012817,0.0010986,0.0011902,0.0009460,0.0010376,0.0008240,0.0007324,0.0007629,0.0004272,0.0006104,0.0002747,0.0003967,0.0001831,0.0002747,0.0002441,0.0001831,0.0003967,0.0001831,0.0004578,0.0003662,0.0003357,0.0005493,0.0003052,0.0003662,0.0002441,0.0001221,-0.0000610,-0.0002136,-0.0003662,-0.0006104,-0.0007324,-0.0008545,-0.0010376,-0.0009766,-0.0010681,-0.0010376,-0.0008545,-0.0008545,-0.0004883,-0.0003662,-0.0001221,0.0001221,0.0003052,0.0004578,0.0006104,0.0007019,0.0006409,0.0007324,0.0005188,0.0004272,0.0003967,0.0000610,-0.0000610,-0.0002441,-0.0005188,-0.0004272,-0.0007629,-0.0005188,-0.0008240,-0.0005188,-0.0004883,-0.0003662,-0.0001831,-0.0000610,0.0002747,0.0002747,0.0005188,0.0005188,0.0007629,0.0007019,0.0006714,0.0007324,0.0005798,0.0006409,0.0003662,0.0003967,0.0002441,0.0002136,0.0001221,0.0001831,0.0001831,0.0002747,0.0003052,0.0006104,0.0005798,0.0010681,0.0010376,0.0015259,0.0016174,0.0020752,0.0021057,0.0025635,0.0025635,0.0028687,0.0029602,0.0029602,0.0031128,0.0030518,0.0030823,0.0029297,0.0029907,0.0027771,0.0028076,0.0027771,0.0026550,0.0027771,0.0027161,0.0028992,0.0029297,0.0032043,0.0031128,0.0035706,0.0036011,0.0037537,0.0040283,0.0039673,0.0042114,0.0040283,0.0041809,0.0040283,0.0040283,0.0036926,0.0038147,0.0033875,0.0033875,0.0031738,0.0029602,0.0029602,0.0028381,0.0027161,0.0027466,0.0027466,0.0027161,0.0028687,0.0027466,0.0029602,0.0028076,0.0028992,0.0027161,0.0028687,0.0025635,0.0026245,0.0022888,0.0022888,0.0020447,0.0019226,0.0018616,0.0015564,0.0017395,0.0014648,0.0015869,0.0015869,0.0014648,0.0017090,0.0013733,0.0017395,0.0014343,0.0016785,0.0013428,0.0015564,0.0012207,0.0012817,0.0010376,0.0010071,0.0007324,0.0008240,0.0004578,0.0006714,0.0004578,0.0005493,0.0005798,0.0006104,0.0007935,0.0007629,0.0010376,0.0009766,0.0013123,0.0010071,0.0013123,0.0011902,0.0010986,0.0011902,0.0007935,0.0007935,0.0005188,0.0003052,0.0001221,-0.0000916,-0.0002136,-0.0006409,-0.0003967,-0.0009460,-0.0006409,-0.0008850,-0.0007629,-0.0005798,-0.0006714,-0.0004272,-0.0003357,-0.0001831,0.0001221,0.0000305,0.0003967,0.0002441,0.0005493,0.0002747,0.0004883,0.0002441,0.0002747,-0.0000305,0.0000305,-0.0004272,-0.0003662,-0.0007019,-0.0007019,-0.0009766,-0.0007935,-0.0010071,-0.0007935,-0.0007629,-0.0006714,-0.0002747,-0.0002747,0.0001831,0.0002747,0.0006714,0.0008545,0.0010071,0.0012817,0.0011597,0.0014648,0.0013123,0.0013428,0.0010681,0.0011597,0.0007324,0.0008240,0.0005493,0.0003662,0.0004272,0.0002747,0.0003357,0.0004578,0.0006104,0.0008240,0.0010986,0.0013428,0.0017395,0.0018921,0.0023804,0.0023804,0.0028076,0.0028076,0.0030212,0.0028992,0.0029602,0.0028076,0.0026550,0.0025024,0.0021362,0.0021057,0.0017090,0.0017090,0.0014038,0.0014343,0.0012207,0.0012512,0.0015259,0.0013428,0.0018616,0.0017090,0.0022278,0.0023804,0.0026855,0.0028687,0.0031433,0.0033569,0.0036316,0.0035400,0.0037842,0.0037537,0.0037842,0.0036316,0.0035706,0.0033264,0.0033569,0.0029602,0.0030823,0.0026245,0.0028381,0.0025330,0.0025940,0.0026550,0.0025330,0.0029907,0.0028687,0.0032959,0.0034485,0.0036926,0.0039978,0.0043030,0.0044250,0.0047607,0.0048218,0.0049133,0.0050354,0.0048218,0.0048828,0.0045776,0.0043640,0.0041809,0.0037537,0.0037842,0.0031433,0.0031738,0.0027161,0.0027161,0.0025024,0.0023804,0.0023804,0.0024109,0.0025024,0.0025330,0.0028076,0.0027161,0.0030518,0.0030823,0.0032959,0.0032959,0.0034180,0.0032043,0.0033569,0.0030823,0.0030518,0.0027466,0.0024719,0.0023804,0.0019836,0.0017090,0.0015869,0.0011597,0.0010986,0.0008545,0.0007324,0.0007324,0.0004578,0.0007629,0.0003052,0.0008240,0.0003357,0.0007629,0.0005188,0.0006409,0.0006409,0.0004883,0.0004578,0.0003662,0.0001526,0.0000000,-0.0003052,-0.0004578,-0.0007019,-0.0009766,-0.0011292,-0.0015259,-0.0015564,-0.0018616,-0.0018616,-0.0020447,-0.0020142,-0.0021973,-0.0021057,-0.0020447,-0.0021667,-0.0019226,-0.0022278,-0.0020447,-0.0022583,-0.0022278,-0.0024109,-0.0025635,-0.0027771,-0.0028992,-0.0032654,-0.0032959,-0.0037231,-0.0038452,-0.0039673,-0.0043945,-0.0041809,-0.0046692,-0.0044250,-0.0045776,-0.0046692,-0.0045166,-0.0046082,-0.0044556,-0.0043640,-0.0043945,-0.0041199,-0.0043335,-0.0040588,-0.0042114,-0.0040894,-0.0042419,-0.0042114,-0.0043640,-0.0044250,-0.0045471,-0.0048218,-0.0048523,-0.0051575,-0.0052795,-0.0054932,-0.0056458,-0.0057983,-0.0059509,-0.0059509,-0.0061340,-0.0060730,-0.0061951,-0.0059204,-0.0061340,-0.0057373,-0.0057983,-0.0056152,-0.0052795,-0.0054321,-0.0049744,-0.0051270,-0.0049133,-0.0048523,-0.0050049,-0.0046997,-0.0052795,-0.0047913,-0.0053711,-0.0051880,-0.0053711,-0.0055542,-0.0053711,-0.0056152,-0.0053406,-0.0053406,-0.0050354,-0.0050049,-0.0045166,-0.0043945,-0.0038452,-0.0036926,-0.0031128,-0.0030212,-0.0025635,-0.0023193,-0.0021667,-0.0020142,-0.0018921,-0.0019226,-0.0018005,-0.0020142,-0.0018005,-0.0022278,-0.0019226,-0.0022278,-0.0020142,-0.0020142,-0.0019836,-0.0016479,-0.0016174,-0.0012207,-0.0010071,-0.0006409,-0.0003052,-0.0000305,0.0003357,0.0006409,0.0008240,0.0011292,0.0011292,0.0014038,0.0013123,0.0014648,0.0013733,0.0014648,0.0012817,0.0014343,0.0013428,0.0013123,0.0015869,0.0015564,0.0018311,0.0021057,0.0022583,0.0027466,0.0029907,0.0033875,0.0038452,0.0041504,0.0046082,0.0047607,0.0051880,0.0051880,0.0055237,0.0054626,0.0054932,0.0053711,0.0051880,0.0051270,0.0048218,0.0047607,0.0044556,0.0043945,0.0042725,0.0042114,0.0043640,0.0043945,0.0046082,0.0047913,0.0050659,0.0054626,0.0056763,0.0060730,0.0062561,0.0064087,0.0067139,0.0065308,0.0067749,0.0062561,0.0063782,0.0057678,0.0057068,0.0050964,0.0049133,0.0042114,0.0042419,0.0036011,0.0035400,0.0032959,0.0032349,0.0032349,0.0033875,0.0034485,0.0037231,0.0037842,0.0042419,0.0042114,0.0046387,0.0045166,0.0046387,0.0046082,0.0044250,0.0042725,0.0038757,0.0037231,0.0031128,0.0028687,0.0023499,0.0019531,0.0015259,0.0012512,0.0007629,0.0007324,0.0003662,0.0003357,0.0001831,0.0002747,0.0002136,0.0003357,0.0004578,0.0005188,0.0006104,0.0007019,0.0007324,0.0007019,0.0007935,0.0004578,0.0006409,0.0001221,0.0001221,-0.0003052,-0.0005493,-0.0008850,-0.0011292,-0.0016785,-0.0017090,-0.0022278,-0.0022583,-0.0026245,-0.0026245,-0.0028076,-0.0026855,-0.0028381,-0.0026855,-0.0025330,-0.0025940,-0.0021667,-0.0024109,-0.0018921,-0.0022278,-0.0019836,-0.0021362,-0.0022888,-0.0024109,-0.0026855,-0.0031128,-0.0034790,-0.0039062,-0.0044250,-0.0047607,-0.0054321,-0.0056458,-0.0062256,-0.0063477,-0.0067139,-0.0068359,-0.0068970,-0.0069580,-0.0068665,-0.0066528,-0.0065918,-0.0063171,-0.0061340,-0.0059509,-0.0056763,-0.0054932,-0.0054626,-0.0054016,-0.0053406,-0.0054932,-0.0055847,-0.0058289,-0.0061646,-0.0063171,-0.0067444,-0.0070190,-0.0073242,-0.0076904,-0.0078430,-0.0082397,-0.0081482,-0.0083313,-0.0082703,-0.0081482,-0.0080261,-0.0077820,-0.0074768,-0.0072327,-0.0068665,-0.0065613,-0.0062866,-0.0060425,-0.0058289,-0.0057983,-0.0057068,-0.0057678,-0.0057678,-0.0061646,-0.0062256,-0.0066528,-0.0069580,-0.0072327,-0.0075073,-0.0078125,-0.0078125,-0.0081177,-0.0079346,-0.0078735,-0.0076599,-0.0074463,-0.0070496,-0.0066833,-0.0061646,-0.0056458,-0.0053711,-0.0048828,-0.0046387,-0.0041809,-0.0042114,-0.0039368,-0.0040894,-0.0039978,-0.0040588,-0.0043640,-0.0043640,-0.0046387,-0.0046997,-0.0048523,-0.0048218,-0.0047302,-0.0047607,-0.0044861,-0.0043030,-0.0039978,-0.0036011,-0.0033569,-0.0028381,-0.0026245,-0.0021667,-0.0019836,-0.0016479,-0.0015564,-0.0014954,-0.0013733,-0.0015869,-0.0014648,-0.0017700,-0.0018921,-0.0019836,-0.0022278,-0.0023499,-0.0025635,-0.0026855,-0.0026855,-0.0028381,-0.0026245,-0.0027161,-0.0024719,-0.0023804,-0.0020752,-0.0019226,-0.0015564,-0.0013123,-0.0010376,-0.0007629,-0.0004883,-0.0003662,-0.0000610,0.0000916,-0.0000305,0.0002136,0.0001221,-0.0001526,0.0001526,-0.0004272,-0.0002136,-0.0006714,-0.0006409,-0.0008545,-0.0008545,-0.0010681,-0.0008850,-0.0010376,-0.0006714,-0.0007629,-0.0005493,-0.0002441,-0.0001526,0.0000916,0.0003662,0.0002136,0.0005798,0.0003967,0.0004578,0.0003662,0.0000305,-0.0002136,-0.0004578,-0.0008850,-0.0012207,-0.0016479,-0.0019531,-0.0023499,-0.0025024,-0.0027771,-0.0028687,-0.0028381,-0.0029297,-0.0026550,-0.0027161,-0.0023499,-0.0021973,-0.0018616,-0.0016785,-0.0014648,-0.0014343,-0.0011292,-0.0013733,-0.0010681,-0.0014038,-0.0013428,-0.0016785,-0.0018311,-0.0021973,-0.0023499,-0.0026245,-0.0027771,-0.0029297,-0.0029907,-0.0031128,-0.0030212,-0.0029602,-0.0028992,-0.0025635,-0.0025940,-0.0021057,-0.0021057,-0.0018616,-0.0016479,-0.0016174,-0.0015259,-0.0014038,-0.0016479,-0.0015564,-0.0018311,-0.0019226,-0.0022278,-0.0022278,-0.0025330,-0.0026550,-0.0026855,-0.0029297,-0.0027466,-0.0028992,-0.0025940,-0.0027161,-0.0023499,-0.0024109,-0.0021973,-0.0021057,-0.0020447,-0.0020142,-0.0021362,-0.0022278,-0.0025024,-0.0026245,-0.0030212,-0.0032654,-0.0036316,-0.0039978,-0.0042114,-0.0044861,-0.0046692,-0.0047302,-0.0047607,-0.0047302,-0.0046082,-0.0044861,-0.0042419,-0.0040894,-0.0038452,-0.0037537,-0.0035400,-0.0036621,-0.0034180,-0.0036926,-0.0037231,-0.0039978,-0.0041809,-0.0044556,-0.0045776,-0.0049744,-0.0050354,-0.0052490,-0.0052490,-0.0052795,-0.0052185,-0.0051880,-0.0048523,-0.0048218,-0.0045166,-0.0044250,-0.0040283,-0.0040894,-0.0037537,-0.0038757,-0.0037842,-0.0038147,-0.0039368,-0.0039978,-0.0041809,-0.0042114,-0.0044250,-0.0042419,-0.0045776,-0.0041809,-0.0043640,-0.0039368,-0.0039673,-0.0035095,-0.0033569,-0.0030518,-0.0027466,-0.0025024,-0.0023499,-0.0019226,-0.0020447,-0.0016479,-0.0018311,-0.0015869,-0.0017395,-0.0015564,-0.0017395,-0.0015869,-0.0016785,-0.0016785,-0.0015869,-0.0015259,-0.0014954,-0.0014038,-0.0013733,-0.0013428,-0.0012512,-0.0013123,-0.0011292,-0.0013733,-0.0012817,-0.0014038,-0.0014954,-0.0016785,-0.0015869,-0.0019836,-0.0016174,-0.0021667,-0.0017395,-0.0020142,-0.0016785,-0.0017700,-0.0013733,-0.0014954,-0.0010071,-0.0010376,-0.0006409,-0.0004578,-0.0003967,-0.0001221,-0.0000916,0.0000916,0.0001221,0.0001831,0.0002136,0.0000916,0.0002136,0.0000610,0.0001526,0.0000000,0.0001526,0.0000610,0.0001221,0.0002136,0.0002747,0.0003662,0.0005493,0.0005798,0.0007629,0.0007935,0.0010376,0.0009155,0.0010986,0.0011292,0.0008545,0.0011902,0.0005493,0.0008240,0.0004578,0.0002441,0.0001831,-0.0002747,-0.0002441,-0.0007019,-0.0006409,-0.0010986,-0.0009155,-0.0013123,-0.0011597,-0.0012512,-0.0013123,-0.0011597,-0.0012512,-0.0011292,-0.0012207,-0.0010376,-0.0012207,-0.0010681,-0.0011292,-0.0011902,-0.0013123,-0.0012817,-0.0015259,-0.0014038,-0.0016174,-0.0017090,-0.0016174,-0.0019226,-0.0016479,-0.0019836,-0.0016785,-0.0019531,-0.0015564,-0.0018311,-0.0014954,-0.0016479,-0.0014954,-0.0014038,-0.0013123,-0.0011902,-0.0011902,-0.0010376,-0.0009766,-0.0008850,-0.0006714,-0.0007324,-0.0004272,-0.0004883,-0.0003967,-0.0002747,-0.0003357,-0.0002747,-0.0003662,-0.0004883,-0.0005798,-0.0007935,-0.0009155,-0.0011902,-0.0014038,-0.0016174,-0.0018311,-0.0018921,-0.0021362,-0.0019531,-0.0022278,-0.0019531,-0.0018311,-0.0017090,-0.0013733,-0.0010986,-0.0010071,-0.0004883,-0.0005188,-0.0001526,-0.0001526,-0.0000916,-0.0001221,-0.0000916,-0.0003357,-0.0003967,-0.0006409,-0.0008545,-0.0010986,-0.0010681,-0.0013733,-0.0011902,-0.0014038,-0.0011597,-0.0010681,-0.0008850,-0.0006714,-0.0003967,-0.0001221,0.0000916,0.0002441,0.0004883,0.0005493,0.0005798,0.0006714,0.0003052,0.0004272,0.0000305,-0.0000916,-0.0003662,-0.0006409,-0.0007324,-0.0010071,-0.0010071,-0.0012207,-0.0010071,-0.0010986,-0.0007324,-0.0006714,-0.0002441,-0.0001221,0.0004883,0.0005798,0.0012207,0.0013733,0.0017700,0.0021362,0.0021973,0.0025635,0.0024109,0.0026550,0.0025330,0.0025330,0.0023193,0.0023499,0.0020447,0.0020142,0.0018616,0.0015869,0.0017700,0.0014954,0.0018005,0.0016174,0.0020142,0.0021362,0.0024719,0.0028076,0.0030823,0.0034485,0.0037537,0.0040894,0.0042725,0.0045166,0.0044861,0.0045471,0.0044861,0.0041199,0.0040283,0.0034790,0.0032654,0.0027466,0.0024109,0.0020447,0.0016785,0.0015564,0.0012512,0.0014343,0.0013428,0.0017090,0.0017700,0.0023804,0.0024719,0.0031738,0.0034180,0.0039673,0.0042114,0.0046082,0.0045776,0.0047913,0.0046997,0.0045471,0.0043335,0.0040283,0.0036011,0.0033264,0.0027771,0.0024719,0.0020447,0.0017395,0.0015869,0.0013428,0.0014343,0.0013123,0.0016174,0.0016785,0.0020142,0.0021973,0.0025330,0.0027466,0.0030518,0.0030518,0.0033264,0.0032043,0.0031128,0.0030823,0.0026550,0.0025635,0.0020142,0.0018005,0.0011902,0.0009460,0.0003662,0.0000610,-0.0002747,-0.0006409,-0.0007324,-0.0010071,-0.0009766,-0.0010681,-0.0010071,-0.0008545,-0.0008545,-0.0005493,-0.0006409,-0.0003052,-0.0004272,-0.0002136,-0.0003052,-0.0003967,-0.0003357,-0.0006714,-0.0007019,-0.0011292,-0.0011292,-0.0017395,-0.0016785,-0.0023499,-0.0022278,-0.0028381,-0.0027771,-0.0030823,-0.0031433,-0.0033264,-0.0032959,-0.0034790,-0.0033875,-0.0034790,-0.0033569,-0.0034180,-0.0034485,-0.0032654,-0.0034790,-0.0032349,-0.0033875,-0.0033875,-0.0033569,-0.0034485,-0.0034790,-0.0034180,-0.0036316,-0.0035706,-0.0037537,-0.0036926,-0.0038452,-0.0037231,-0.0039673,-0.0037842,-0.0038757,-0.0037842,-0.0037231,-0.0036926,-0.0034485,-0.0034180,-0.0030518,-0.0030518,-0.0026855,-0.0025635,-0.0022888,-0.0021667,-0.0019226,-0.0020142,-0.0016174,-0.0019226,-0.0017700,-0.0018311,-0.0021057,-0.0018921,-0.0023499,-0.0022583,-0.0025635,-0.0025024,-0.0027466,-0.0025940,-0.0027771,-0.0025330,-0.0025940,-0.0024414,-0.0021973,-0.0022888,-0.0018005,-0.0020447,-0.0016785,-0.0018616,-0.0016174,-0.0019226,-0.0018005,-0.0020447,-0.0021362,-0.0023193,-0.0024414,-0.0025635,-0.0027466,-0.0026245,-0.0026855,-0.0024719,-0.0024414,-0.0022583,-0.0018005,-0.0016785,-0.0012512,-0.0009460,-0.0006104,-0.0003052,0.0000610,0.0000916,0.0004578,0.0004272,0.0007324,0.0004883,0.0008545,0.0004578,0.0008545,0.0005188,0.0008240,0.0007019,0.0008240,0.0011597,0.0010376,0.0015869,0.0016785,0.0020752,0.0023804,0.0026550,0.0030518,0.0032349,0.0036621,0.0038147,0.0040588,0.0042419,0.0042419,0.0045471,0.0044556,0.0046082,0.0044861,0.0044861,0.0045776,0.0045776,0.0045166,0.0045166,0.0046387,0.0046692,0.0048828,0.0050049,0.0050964,0.0054932,0.0054932,0.0059204,0.0061035,0.0064697,0.0065613,0.0068665,0.0068970,0.0072021,0.0072632,0.0072937,0.0072937,0.0073547,0.0071716,0.0072327,0.0070496,0.0068665,0.0070190,0.0066223,0.0068970,0.0066528,0.0068359,0.0067749,0.0070496,0.0069885,0.0073242,0.0074158,0.0074768,0.0078125,0.0077515,0.0079956,0.0080261,0.0078735,0.0079651,0.0078125,0.0076599,0.0076294,0.0073547,0.0072632,0.0072021,0.0069580,0.0070190,0.0068970,0.0069885,0.0068359,0.0071106,0.0070190,0.0072327,0.0072937,0.0073547,0.0074768,0.0074768,0.0074158,0.0074768,0.0072021,0.0073242,0.0069275,0.0069275,0.0066528,0.0064392,0.0063477,0.0060120,0.0061035,0.0056458,0.0058289,0.0055237,0.0054626,0.0054932,0.0051575,0.0053711,0.0049744,0.0051575,0.0048828,0.0048218,0.0046997,0.0045166,0.0045166,0.0043030,0.0042114,0.0040894,0.0040283,0.0039062,0.0039062,0.0036926,0.0037842,0.0035095,0.0037231,0.0034790,0.0033569,0.0035095,0.0031128,0.0032959,0.0029907,0.0030212,0.0028381,0.0029297,0.0026855,0.0028381,0.0025940,0.0028076,0.0025330,0.0027771,0.0025940,0.0027161,0.0024414,0.0026245,0.0022278,0.0023193,0.0019226,0.0018921,0.0015259,0.0014038,0.0010681,0.0008240,0.0007324,0.0004272,0.0004578,0.0002136,0.0002441,0.0003052,0.0003052,0.0004272,0.0004883,0.0005798,0.0006714,0.0007935,0.0007935,0.0009155,0.0007935,0.0007935,0.0007324,0.0005188,0.0004578,0.0002747,0.0001831,-0.0000305,0.0000305,-0.0002747,-0.0000916,-0.0002441,-0.0000916,-0.0000916,-0.0000610,0.0002136,0.0000916,0.0003967,0.0003052,0.0005188,0.0004883,0.0004578,0.0005798,0.0002441,0.0004883,0.0001526,0.0001526,0.0001831,-0.0001221,0.0000610,-0.0002136,-0.0000305,-0.0002136,-0.0000610,-0.0000305,0.0000610,0.0002747,0.0000916,0.0004883,0.0003967,0.0006104,0.0007324,0.0007019,0.0009460,0.0007935,0.0009460,0.0010071,0.0009155,0.0009155,0.0008850,0.0007019,0.0007629,0.0006409,0.0005188,0.0005188,0.0005493,0.0003662,0.0005798,0.0005188,0.0007629,0.0009155,0.0010986,0.0014648,0.0016174,0.0020142,0.0023193,0.0026550,0.0028687,0.0031128,0.0032959,0.0032349,0.0035095,0.0031738,0.0033264,0.0029297,0.0028076,0.0025024,0.0022888,0.0020447,0.0019226,0.0016174,0.0017395,0.0015869,0.0017700,0.0019836,0.0021667,0.0026245,0.0028687,0.0032654,0.0036621,0.0039673,0.0042419,0.0044556,0.0045471,0.0045471,0.0044861,0.0042419,0.0040588,0.0037842,0.0032349,0.0030212,0.0025024,0.0021973,0.0018616,0.0014954,0.0014038,0.0011902,0.0012207,0.0011597,0.0013123,0.0014648,0.0015259,0.0019226,0.0019226,0.0024109,0.0021362,0.0027161,0.0024719,0.0025940,0.0025330,0.0022888,0.0022583,0.0019836,0.0017700,0.0013428,0.0012817,0.0007324,0.0008240,0.0003357,0.0004272,0.0001831,0.0001526,0.0002441,0.0002136,0.0005188,0.0005798,0.0009155,0.0009766,0.0014038,0.0014343,0.0019226,0.0016785,0.0021973,0.0018921,0.0020752,0.0018616,0.0017090,0.0014648,0.0012207,0.0009460,0.0004883,0.0003662,-0.0000305,-0.0001831,-0.0003357,-0.0006409,-0.0003357,-0.0006714,-0.0002441,-0.0003662,0.0000916,0.0000916,0.0004578,0.0004578,0.0008545,0.0008545,0.0009460,0.0008545,0.0009155,0.0006104,0.0004578,0.0002136,-0.0001526,-0.0003052,-0.0007019,-0.0008850,-0.0012207,-0.0012512,-0.0014343,-0.0013428,-0.0013428,-0.0012512,-0.0011597,-0.0009460,-0.0006409,-0.0005188,-0.0001831,-0.0000305,0.0001221,0.0003967,0.0003662,0.0005188,0.0004272,0.0004578,0.0004272,0.0002136,0.0001526,-0.0000610,-0.0000610,-0.0004272,-0.0002441,-0.0005188,-0.0003967,-0.0004883,-0.0003357,-0.0003357,0.0000610,-0.0000305,0.0004578,0.0004883,0.0009155,0.0010681,0.0014648,0.0015869,0.0018921,0.0019836,0.0022278,0.0022583,0.0023499,0.0022583,0.0023804,0.0021057,0.0021667,0.0018616,0.0019836,0.0016174,0.0017700,0.0014343,0.0015564,0.0014648,0.0014954,0.0016479,0.0017090,0.0017700,0.0020752,0.0020447,0.0023499,0.0024719,0.0025635,0.0026855,0.0027466,0.0026550,0.0028687,0.0027161,0.0026855,0.0026855,0.0025635,0.0025635,0.0025330,0.0024109,0.0026855,0.0024719,0.0027771,0.0026855,0.0029297,0.0030823,0.0031128,0.0035095,0.0032654,0.0037231,0.0035706,0.0037537,0.0037537,0.0038147,0.0038452,0.0038452,0.0038757,0.0037537,0.0040283,0.0038147,0.0040588,0.0039673,0.0042114,0.0041504,0.0044250,0.0043335,0.0045776,0.0046082,0.0046997,0.0047302,0.0048218,0.0047607,0.0048828,0.0046692,0.0047607,0.0046387,0.0046082,0.0046082,0.0044250,0.0044556,0.0043335,0.0042725,0.0042725,0.0041199,0.0040588,0.0040588,0.0039062,0.0038757,0.0037537,0.0036011,0.0035095,0.0033569,0.0032043,0.0030518,0.0029907,0.0027466,0.0027466,0.0024719,0.0024719,0.0023499,0.0021667,0.0021667,0.0020142,0.0019531,0.0019226,0.0018311,0.0017395,0.0017395,0.0017090,0.0015869,0.0016479,0.0014648,0.0015259,0.0014038,0.0013733,0.0013428,0.0011902,0.0012207,0.0010681,0.0009460,0.0009155,0.0006714,0.0006714,0.0003662,0.0002747,0.0001221,-0.0001221,-0.0002136,-0.0003967,-0.0005798,-0.0004883,-0.0007935,-0.0006409,-0.0008545,-0.0007629,-0.0007935,-0.0007935,-0.0006714,-0.0007629,-0.0007019,-0.0006714,-0.0007324,-0.0007935,-0.0007935,-0.0009460,-0.0009766,-0.0010681,-0.0011597,-0.0014648,-0.0012817,-0.0018005,-0.0014954,-0.0020142,-0.0018311,-0.0023193,-0.0021057,-0.0025635,-0.0025024,-0.0028381,-0.0028992,-0.0031738,-0.0033264,-0.0036621,-0.0036621,-0.0039978,-0.0039673,-0.0043335,-0.0043640,-0.0044250,-0.0046082,-0.0044250,-0.0046387,-0.0045776,-0.0044556,-0.0046997,-0.0043335,-0.0047913,-0.0045166,-0.0048218,-0.0048523,-0.0050659,-0.0051575,-0.0055237,-0.0054321,-0.0059814,-0.0057983,-0.0062866,-0.0060730,-0.0064697,-0.0062561,-0.0061951,-0.0063477,-0.0057678,-0.0060730,-0.0053406,-0.0056152,-0.0049744,-0.0051270,-0.0045471,-0.0048828,-0.0042725,-0.0046387,-0.0042725,-0.0044250,-0.0043945,-0.0044250,-0.0044250,-0.0045166,-0.0043945,-0.0045166,-0.0044250,-0.0043335,-0.0044250,-0.0041809,-0.0041504,-0.0040588,-0.0037842,-0.0039368,-0.0035706,-0.0036926,-0.0034485,-0.0033875,-0.0032654,-0.0030823,-0.0030212,-0.0027466,-0.0026245,-0.0024109,-0.0021973,-0.0020142,-0.0017090,-0.0015259,-0.0013123,-0.0010681,-0.0009766,-0.0006714,-0.0007324,-0.0004272,-0.0006104,-0.0002441,-0.0004578,-0.0002136,-0.0003052,-0.0001831,-0.0000305,0.0000610,0.0002747,0.0005493,0.0006104,0.0011292,0.0012207,0.0017090,0.0018005,0.0022583,0.0023804,0.0026550,0.0028687,0.0028992,0.0030823,0.0031433,0.0030212,0.0031433,0.0030518,0.0031128,0.0030212,0.0030823,0.0030823,0.0032043,0.0033264,0.0033569,0.0036621,0.0036926,0.0040588,0.0042419,0.0043640,0.0047302,0.0046692,0.0051575,0.0049438,0.0053101,0.0052185,0.0053406,0.0053406,0.0052185,0.0053711,0.0051270,0.0052185,0.0051270,0.0050354,0.0051575,0.0049438,0.0051880,0.0049744,0.0051575,0.0050964,0.0052185,0.0052490,0.0053406,0.0053711,0.0054932,0.0055237,0.0054321,0.0057068,0.0053406,0.0057678,0.0052795,0.0055847,0.0051270,0.0052490,0.0048828,0.0048523,0.0043945,0.0043945,0.0038757,0.0038147,0.0034180,0.0031128,0.0029297,0.0025635,0.0025330,0.0020447,0.0021362,0.0018311,0.0018005,0.0017090,0.0016785,0.0016174,0.0015869,0.0016479,0.0015869,0.0016479,0.0016479,0.0016785,0.0016785,0.0017395,0.0015259,0.0017700,0.0014038,0.0015564,0.0012817,0.0013123,0.0010681,0.0009766,0.0007019,0.0005798,0.0002747,0.0002441,0.0000305,-0.0001831,-0.0001831,-0.0005188,-0.0003662,-0.0006409,-0.0004883,-0.0005188,-0.0004272,-0.0004578,-0.0002136,-0.0003357,-0.0001526,-0.0002747,-0.0002747,-0.0004272,-0.0004578,-0.0009155,-0.0009766,-0.0015259,-0.0017700,-0.0022888,-0.0026245,-0.0031738,-0.0033569,-0.0039673,-0.0040283,-0.0045166,-0.0044861,-0.0046997,-0.0046692,-0.0045776,-0.0046692,-0.0042725,-0.0045166,-0.0040588,-0.0042114,-0.0039673,-0.0041199,-0.0040588,-0.0043030,-0.0043945,-0.0046692,-0.0049744,-0.0051880,-0.0054932,-0.0057678,-0.0061340,-0.0061340,-0.0065613,-0.0063171,-0.0066223,-0.0064697,-0.0064392,-0.0063477,-0.0062561,-0.0058899,-0.0061035,-0.0055847,-0.0058594,-0.0055542,-0.0056763,-0.0056458,-0.0057068,-0.0057983,-0.0058594,-0.0061035,-0.0062256,-0.0062866,-0.0065308,-0.0064087,-0.0067749,-0.0065613,-0.0067139,-0.0066528,-0.0066223,-0.0066528,-0.0064087,-0.0062866,-0.0062866,-0.0060120,-0.0060730,-0.0057983,-0.0057983,-0.0054932,-0.0055847,-0.0053406,-0.0053406,-0.0050964,-0.0051575,-0.0050049,-0.0048218,-0.0049133,-0.0046082,-0.0047607,-0.0044556,-0.0046082,-0.0042725,-0.0044556,-0.0041809,-0.0041199,-0.0042114,-0.0038757,-0.0040283,-0.0038147,-0.0036621,-0.0039368,-0.0033875,-0.0037231,-0.0034180,-0.0033569,-0.0034485,-0.0030212,-0.0033264,-0.0029297,-0.0029907,-0.0028992,-0.0027161,-0.0027466,-0.0025635,-0.0025635,-0.0024109,-0.0024109,-0.0024109,-0.0021667,-0.0024109,-0.0021057,-0.0024414,-0.0021362,-0.0023499,-0.0023804,-0.0022583,-0.0024719,-0.0023193,-0.0023499,-0.0023804,-0.0022278,-0.0020752,-0.0020142,-0.0018005,-0.0015259,-0.0013733,-0.0010986,-0.0008240,-0.0007324,-0.0003662,-0.0003052,-0.0000610,-0.0001221,0.0000610,-0.0001831,-0.0000916,-0.0003967,-0.0005188,-0.0007324,-0.0009766,-0.0012512,-0.0013733,-0.0014954,-0.0017700,-0.0016479,-0.0017700,-0.0015869,-0.0015564,-0.0014038,-0.0012207,-0.0010681,-0.0008545,-0.0007324,-0.0007019,-0.0004578,-0.0006104,-0.0005798,-0.0006714,-0.0007629,-0.0010376,-0.0010681,-0.0015869,-0.0014954,-0.0019531,-0.0019836,-0.0022278,-0.0022888,-0.0024109,-0.0023499,-0.0024109,-0.0021973,-0.0021667,-0.0020142,-0.0017700,-0.0018005,-0.0013123,-0.0015259,-0.0010986,-0.0012512,-0.0010376,-0.0011902,-0.0010986,-0.0013428,-0.0012512,-0.0015259,-0.0015869,-0.0018311,-0.0018005,-0.0022278,-0.0021057,-0.0024109,-0.0023499,-0.0024109,-0.0024719,-0.0023193,-0.0024109,-0.0020752,-0.0021667,-0.0018311,-0.0018005,-0.0015564,-0.0015564,-0.0013733,-0.0014038,-0.0013733,-0.0014343,-0.0015564,-0.0017090,-0.0018311,-0.0021667,-0.0023804,-0.0026550,-0.0029297,-0.0031738,-0.0033264,-0.0035400,-0.0036316,-0.0035706,-0.0036621,-0.0034485,-0.0033569,-0.0031128,-0.0029602,-0.0026855,-0.0024109,-0.0022888,-0.0020447,-0.0021667,-0.0019836,-0.0021362,-0.0022583,-0.0024414,-0.0027161,-0.0030212,-0.0032959,-0.0036011,-0.0040894,-0.0040894,-0.0046387,-0.0045471,-0.0047302,-0.0047302,-0.0046692,-0.0045776,-0.0044250,-0.0040894,-0.0041809,-0.0036011,-0.0037231,-0.0034180,-0.0032654,-0.0033875,-0.0032959,-0.0034485,-0.0035706,-0.0037537,-0.0040894,-0.0041504,-0.0046082,-0.0046692,-0.0050049,-0.0052185,-0.0050964,-0.0054626,-0.0050964,-0.0052490,-0.0049744,-0.0047607,-0.0046387,-0.0042114,-0.0040283,-0.0037537,-0.0034180,-0.0033875,-0.0029907,-0.0031433,-0.0028992,-0.0028992,-0.0029297,-0.0028687,-0.0029602,-0.0030823,-0.0030212,-0.0032043,-0.0030212,-0.0032349,-0.0028687,-0.0029602,-0.0025940,-0.0025330,-0.0022583,-0.0019226,-0.0018921,-0.0014648,-0.0013428,-0.0012817,-0.0008850,-0.0012817,-0.0008850,-0.0012817,-0.0011597,-0.0014038,-0.0016174,-0.0017700,-0.0019226,-0.0020752,-0.0021973,-0.0022583,-0.0022888,-0.0021973,-0.0020447,-0.0018616,-0.0015564,-0.0014038,-0.0009766,-0.0007324,-0.0003967,-0.0000916,0.0000000,0.0002747,0.0003662,0.0003052,0.0004578,0.0002441,0.0001221,-0.0000610,-0.0003052,-0.0003967,-0.0007629,-0.0007935,-0.0010376,-0.0010681,-0.0010986,-0.0010986,-0.0010681,-0.0008850,-0.0006714,-0.0007019,-0.0001526,-0.0003357,0.0002441,0.0001526,0.0003662,0.0005798,0.0003662,0.0006714,0.0003662,0.0003052,0.0002747,-0.0000610,-0.0001526,-0.0004272,-0.0006714,-0.0008850,-0.0010071,-0.0013123,-0.0012512,-0.0016174,-0.0013733,-0.0015259,-0.0013733,-0.0013733,-0.0011292,-0.0010681,-0.0008850,-0.0006104,-0.0006409,-0.0003357,-0.0004272,-0.0002136,-0.0003662,-0.0003662,-0.0004272,-0.0007324,-0.0007019,-0.0011597,-0.0010681,-0.0015869,-0.0015869,-0.0018921,-0.0020752,-0.0021057,-0.0022583,-0.0021667,-0.0022278,-0.0020142,-0.0020752,-0.0018005,-0.0016785,-0.0015259,-0.0013733,-0.0010986,-0.0012512,-0.0008240,-0.0011902,-0.0008545,-0.0011902,-0.0010681,-0.0012512,-0.0014648,-0.0015259,-0.0018311,-0.0018005,-0.0020447,-0.0020752,-0.0021667,-0.0023193,-0.0020142,-0.0024109,-0.0019226,-0.0022278,-0.0017700,-0.0018921,-0.0014648,-0.0016174,-0.0011597,-0.0013123,-0.0008850,-0.0009460,-0.0006104,-0.0008240,-0.0004883,-0.0006104,-0.0004883,-0.0004272,-0.0006409,-0.0004883,-0.0006714,-0.0007629,-0.0007019,-0.0010986,-0.0008240,-0.0012207,-0.0011597,-0.0012512,-0.0013733,-0.0013123,-0.0013733,-0.0013123,-0.0012817,-0.0012817,-0.0009766,-0.0012817,-0.0007629,-0.0010071,-0.0006409,-0.0007629,-0.0006714,-0.0007324,-0.0006409,-0.0007324,-0.0007324,-0.0009155,-0.0009460,-0.0010681,-0.0011292,-0.0012207,-0.0012207,-0.0012512,-0.0012817,-0.0011902,-0.0010986,-0.0009460,-0.0008850,-0.0007324,-0.0004883,-0.0003967,-0.0001221,0.0000000,0.0001221,0.0003052,0.0004883,0.0005188,0.0006409,0.0007935,0.0006409,0.0008545,0.0007629,0.0008545,0.0008240,0.0009155,0.0008545,0.0010071,0.0008240,0.0011597,0.0008240,0.0011902,0.0009766,0.0012817,0.0011902,0.0013428,0.0014954,0.0013733,0.0018311,0.0015869,0.0020142,0.0020142,0.0021362,0.0024719,0.0022583,0.0027771,0.0026245,0.0028381,0.0029602,0.0029297,0.0029602,0.0031433,0.0029602,0.0032043,0.0029907,0.0031128,0.0030823,0.0031128,0.0032349,0.0031128,0.0032959,0.0032654,0.0035400,0.0033569,0.0036621,0.0036316,0.0036926,0.0037842,0.0037231,0.0037231,0.0036926,0.0036316,0.0033875,0.0033569,0.0032043,0.0028992,0.0028687,0.0024414,0.0025024,0.0021362,0.0021362,0.0018921,0.0018921,0.0016479,0.0018616,0.0015259,0.0018005,0.0016174,0.0016785,0.0017395,0.0016785,0.0018311,0.0016785,0.0018005,0.0017090,0.0016785,0.0016479,0.0015564,0.0013428,0.0012817,0.0011292,0.0008545,0.0007629,0.0004883,0.0003052,0.0000916,-0.0000610,-0.0003662,-0.0003357,-0.0005798,-0.0006409,-0.0007019,-0.0007629,-0.0006714,-0.0005493,-0.0005798,-0.0003662,-0.0003662,-0.0001526,-0.0002441,0.0000610,-0.0000916,0.0000000,-0.0001526,-0.0002136,-0.0003967,-0.0005188,-0.0007629,-0.0010376,-0.0012207,-0.0014648,-0.0017700,-0.0018005,-0.0021057,-0.0021362,-0.0021667,-0.0023499,-0.0021057,-0.0023193,-0.0020752,-0.0021667,-0.0019531,-0.0020752,-0.0019836,-0.0020752,-0.0019226,-0.0021973,-0.0021667,-0.0024414,-0.0025024,-0.0027771,-0.0028687,-0.0031433,-0.0032043,-0.0034485,-0.0034790,-0.0036621,-0.0036316,-0.0037231,-0.0037537,-0.0036316,-0.0038147,-0.0034180,-0.0037537,-0.0032959,-0.0035400,-0.0032349,-0.0033264,-0.0031433,-0.0032043,-0.0030823,-0.0030518,-0.0031433,-0.0029297,-0.0031738,-0.0029907,-0.0031433,-0.0030823,-0.0032043,-0.0031738,-0.0033264,-0.0032959,-0.0034790,-0.0033569,-0.0036316,-0.0034790,-0.0036316,-0.0036316,-0.0035095,-0.0036011,-0.0033875,-0.0033569,-0.0032043,-0.0030823,-0.0027466,-0.0027771,-0.0023499,-0.0023499,-0.0021057,-0.0019531,-0.0019226,-0.0016479,-0.0018311,-0.0015259,-0.0019226,-0.0015564,-0.0018005,-0.0017395,-0.0017090,-0.0019531,-0.0016174,-0.0017090,-0.0016785,-0.0014343,-0.0015259,-0.0010376,-0.0010376,-0.0007324,-0.0005493,-0.0003662,-0.0001526,-0.0001221,0.0002441,0.0000610,0.0003357,0.0002136,0.0003357,0.0002441,0.0002441,0.0001526,0.0001526,0.0001526,0.0001831,0.0001221,0.0004578,0.0002441,0.0008545,0.0007324,0.0012207,0.0013733,0.0017395,0.0021362,0.0023499,0.0027161,0.0028687,0.0032043,0.0033264,0.0034790,0.0036011,0.0036011,0.0036621,0.0036621,0.0035095,0.0035400,0.0034180,0.0034790,0.0033875,0.0036011,0.0034180,0.0038452,0.0038147,0.0042419,0.0044250,0.0047607,0.0050964,0.0054626,0.0057373,0.0061646,0.0064087,0.0065613,0.0067749,0.0069275,0.0068970,0.0070496,0.0067139,0.0067749,0.0064392,0.0062866,0.0061035,0.0057983,0.0056458,0.0056152,0.0052795,0.0054626,0.0052185,0.0055237,0.0055237,0.0057068,0.0059204,0.0061035,0.0064087,0.0065918,0.0067139,0.0069885,0.0069580,0.0071716,0.0070190,0.0071411,0.0068359,0.0068665,0.0064392,0.0064392,0.0060120,0.0058899,0.0056763,0.0052795,0.0054626,0.0050659,0.0052490,0.0051270,0.0052490,0.0054016,0.0055237,0.0057678,0.0058594,0.0062561,0.0062256,0.0066833,0.0064697,0.0068665,0.0065308,0.0068054,0.0064087,0.0063782,0.0061035,0.0056458,0.0056763,0.0049133,0.0050659,0.0043335,0.0043640,0.0039978,0.0038757,0.0039062,0.0036316,0.0039062,0.0038147,0.0039368,0.0042114,0.0040588,0.0045776,0.0043335,0.0046387,0.0046082,0.0045776,0.0045776,0.0043640,0.0042114,0.0040283,0.0037537,0.0035400,0.0032349,0.0028687,0.0027466,0.0024719,0.0023193,0.0021362,0.0021362,0.0020142,0.0020447,0.0021973,0.0021362,0.0023499,0.0023193,0.0025024,0.0025635,0.0025940,0.0024719,0.0025940,0.0022888,0.0023804,0.0018921,0.0018311,0.0015259,0.0011292,0.0009766,0.0004578,0.0003967,-0.0001221,-0.0000916,-0.0005798,-0.0003967,-0.0007019,-0.0005493,-0.0006714,-0.0004272,-0.0005798,-0.0000916,-0.0002747,0.0001831,0.0002441,0.0003662,0.0006104,0.0006104,0.0007324,0.0007324,0.0007629,0.0006104,0.0005798,0.0003967,0.0001526,0.0000000,-0.0001831,-0.0003662,-0.0006409,-0.0005798,-0.0009766,-0.0006409,-0.0010376,-0.0005188,-0.0007324,-0.0004883,-0.0002441,0.0000000,0.0003052,0.0005188,0.0007324,0.0009766,0.0011597,0.0013123,0.0012512,0.0014038,0.0011902,0.0011292,0.0008850,0.0008545,0.0004578,0.0003967,-0.0000916,-0.0000610,-0.0004272,-0.0002441,-0.0005188,-0.0003662,-0.0004272,-0.0000916,-0.0000610,0.0003052,0.0005188,0.0008545,0.0011597,0.0012817,0.0017700,0.0017700,0.0020142,0.0021667,0.0019226,0.0021667,0.0018616,0.0017700,0.0015869,0.0013428,0.0011597,0.0007629,0.0008850,0.0003662,0.0005798,0.0003357,0.0005493,0.0005798,0.0008850,0.0010071,0.0014343,0.0017090,0.0021057,0.0025330,0.0027771,0.0032349,0.0033569,0.0036316,0.0036926,0.0036621,0.0036926,0.0035095,0.0032654,0.0031433,0.0026245,0.0026550,0.0021057,0.0019531,0.0017090,0.0015564,0.0014648,0.0015869,0.0015259,0.0018005,0.0019531,0.0022888,0.0024719,0.0029602,0.0030212,0.0034180,0.0035095,0.0036926,0.0036621,0.0036316,0.0035095,0.0033264,0.0029907,0.0027466,0.0023499,0.0020752,0.0017395,0.0014343,0.0010986,0.0009460,0.0007324,0.0007324,0.0005493,0.0006714,0.0006409,0.0007935,0.0007935,0.0009766,0.0010376,0.0010986,0.0012817,0.0011902,0.0012817,0.0012817,0.0011902,0.0011902,0.0010071,0.0010071,0.0009460,0.0007019,0.0008850,0.0004883,0.0007629,0.0005493,0.0006104,0.0005493,0.0006104,0.0005188,0.0006714,0.0005798,0.0005798,0.0007019,0.0005493,0.0007935,0.0004578,0.0008240,0.0004578,0.0007019,0.0005798,0.0005188,0.0006104,0.0004883,0.0004578,0.0005188,0.0003052,0.0004883,0.0002136,0.0003052,0.0002441,0.0001831,0.0000916,0.0001221,-0.0000305,-0.0000305,-0.0000916,-0.0001831,-0.0001526,-0.0003662,-0.0002136,-0.0006104,-0.0003662,-0.0006714,-0.0006714,-0.0006714,-0.0008545,-0.0007629,-0.0008545,-0.0008545,-0.0009155,-0.0008545,-0.0009155,-0.0007629,-0.0009460,-0.0006714,-0.0008240,-0.0007019,-0.0005798,-0.0007629,-0.0005188,-0.0005798,-0.0006104,-0.0005493,-0.0006409,-0.0004883,-0.0005493,-0.0004883,-0.0004578,-0.0003662,-0.0002747,-0.0003052,0.0000610,-0.0001221,0.0002747,0.0002747,0.0003967,0.0006104,0.0006409,0.0007324,0.0009460,0.0007935,0.0010681,0.0009766,0.0010681,0.0010986,0.0011902,0.0011597,0.0013123,0.0012512,0.0015259,0.0014648,0.0017090,0.0017395,0.0019226,0.0021057,0.0021057,0.0022888,0.0023804,0.0024109,0.0024719,0.0026245,0.0023804,0.0025940,0.0023804,0.0025024,0.0022888,0.0023804,0.0022583,0.0023499,0.0023193,0.0024109,0.0024719,0.0025024,0.0026855,0.0027161,0.0028992,0.0029907,0.0030518,0.0030518,0.0033264,0.0032043,0.0032654,0.0032654,0.0032349,0.0032654,0.0032959,0.0031128,0.0033264,0.0030518,0.0032959,0.0032043,0.0033569,0.0032959,0.0034790,0.0033569,0.0036926,0.0035706,0.0038452,0.0036621,0.0039673,0.0036926,0.0039978,0.0037842,0.0039368,0.0037537,0.0039062,0.0037537,0.0037537,0.0038147,0.0036621,0.0038147,0.0037842,0.0037537,0.0039368,0.0037842,0.0039673,0.0039368,0.0039368,0.0040283,0.0039673,0.0038452,0.0040588,0.0037842,0.0039062,0.0037231,0.0036316,0.0036316,0.0034180,0.0033569,0.0032349,0.0029602,0.0028992,0.0027161,0.0026245,0.0024719,0.0022278,0.0021973,0.0019531,0.0020752,0.0016785,0.0018921,0.0016479,0.0017700,0.0015869,0.0017395,0.0015564,0.0017090,0.0016785,0.0016174,0.0016479,0.0015564,0.0014343,0.0013733,0.0012512,0.0010376,0.0010986,0.0005493,0.0006409,0.0003052,0.0000916,0.0000610,-0.0003052,-0.0002441,-0.0004272,-0.0005188,-0.0005493,-0.0005493,-0.0005798,-0.0004883,-0.0005188,-0.0004883,-0.0003967,-0.0004883,-0.0003052,-0.0003967,-0.0003357,-0.0005188,-0.0004272,-0.0006409,-0.0006104,-0.0007935,-0.0009460,-0.0010071,-0.0012817,-0.0012512,-0.0015564,-0.0015869,-0.0018005,-0.0020447,-0.0019836,-0.0022888,-0.0023804,-0.0023193,-0.0028076,-0.0024109,-0.0028992,-0.0027161,-0.0028381,-0.0029907,-0.0028992,-0.0031433,-0.0031128,-0.0031128,-0.0032959,-0.0032349,-0.0034180,-0.0036011,-0.0035095,-0.0039978,-0.0037537,-0.0041504,-0.0042725,-0.0043335,-0.0046082,-0.0046692,-0.0048218,-0.0049744,-0.0048828,-0.0050964,-0.0049438,-0.0051880,-0.0048218,-0.0050659,-0.0047302,-0.0048218,-0.0046387,-0.0046082,-0.0044861,-0.0044250,-0.0043335,-0.0043945,-0.0042419,-0.0044556,-0.0043945,-0.0043945,-0.0044861,-0.0045776,-0.0044861,-0.0046997,-0.0046387,-0.0046997,-0.0047302,-0.0045776,-0.0046997,-0.0045471,-0.0045166,-0.0043030,-0.0042725,-0.0039673,-0.0039368,-0.0037231,-0.0034180,-0.0033875,-0.0030212,-0.0028687,-0.0027466,-0.0023499,-0.0025024,-0.0020447,-0.0021973,-0.0018311,-0.0019836,-0.0016479,-0.0019226,-0.0014954,-0.0017700,-0.0016174,-0.0015259,-0.0015564,-0.0014038,-0.0012512,-0.0011902,-0.0008850,-0.0007629,-0.0005188,-0.0002136,0.0000916,0.0003052,0.0007324,0.0008545,0.0012817,0.0013428,0.0017090,0.0016785,0.0019836,0.0019531,0.0020447,0.0020752,0.0019531,0.0020447,0.0018921,0.0018311,0.0019531,0.0017090,0.0019531,0.0017090,0.0019531,0.0020752,0.0020752,0.0024109,0.0025330,0.0027771,0.0031433,0.0030518,0.0036926,0.0035706,0.0041199,0.0040588,0.0044556,0.0043030,0.0046997,0.0045166,0.0048218,0.0046387,0.0046692,0.0045471,0.0045471,0.0044250,0.0043945,0.0042419,0.0041504,0.0041199,0.0040283,0.0040894,0.0039978,0.0040283,0.0041504,0.0040894,0.0043640,0.0043030,0.0045166,0.0046692,0.0046387,0.0047913,0.0048828,0.0048828,0.0049744,0.0047913,0.0048218,0.0046692,0.0045776,0.0043945,0.0042114,0.0039978,0.0039062,0.0036011,0.0037231,0.0032654,0.0034790,0.0031738,0.0033264,0.0032654,0.0032654,0.0033264,0.0034180,0.0034485,0.0033875,0.0036011,0.0033875,0.0036011,0.0032959,0.0033569,0.0032043,0.0030212,0.0029602,0.0026855,0.0025635,0.0023804,0.0021362,0.0020752,0.0018921,0.0017700,0.0016785,0.0014954,0.0016174,0.0013428,0.0014343,0.0012817,0.0013428,0.0011292,0.0013428,0.0009155,0.0012207,0.0007324,0.0009155,0.0006104,0.0005798,0.0003662,0.0002747,0.0000305,-0.0000610,-0.0003052,-0.0003967,-0.0006104,-0.0006714,-0.0008240,-0.0009460,-0.0011597,-0.0010986,-0.0013733,-0.0012207,-0.0015259,-0.0014343,-0.0015259,-0.0016174,-0.0015564,-0.0016785,-0.0017700,-0.0016174,-0.0020142,-0.0017090,-0.0021057,-0.0021667,-0.0021362,-0.0027161,-0.0023804,-0.0030823,-0.0028687,-0.0035095,-0.0034790,-0.0039368,-0.0041199,-0.0043945,-0.0046387,-0.0047913,-0.0050354,-0.0051880,-0.0052795,-0.0055237,-0.0053711,-0.0056152,-0.0053406,-0.0056152,-0.0052490,-0.0054626,-0.0051270,-0.0051575,-0.0050659,-0.0050354,-0.0049744,-0.0049744,-0.0049438,-0.0050354,-0.0050659,-0.0051880,-0.0052795,-0.0054626,-0.0055542,-0.0057068,-0.0059814,-0.0058594,-0.0063171,-0.0060120,-0.0061646,-0.0062256,-0.0058899,-0.0060425,-0.0055847,-0.0055847,-0.0052490,-0.0050964,-0.0047913,-0.0045471,-0.0043640,-0.0041809,-0.0040283,-0.0040894,-0.0038757,-0.0041504,-0.0040588,-0.0043640,-0.0043640,-0.0045471,-0.0048218,-0.0046997,-0.0050354,-0.0048523,-0.0050354,-0.0048218,-0.0047913,-0.0044861,-0.0043945,-0.0039978,-0.0037842,-0.0033875,-0.0032654,-0.0028076,-0.0028076,-0.0023499,-0.0024719,-0.0022278,-0.0022888,-0.0022278,-0.0024414,-0.0023499,-0.0026245,-0.0026245,-0.0027771,-0.0027771,-0.0029602,-0.0027771,-0.0028687,-0.0026855,-0.0025635,-0.0023804,-0.0021973,-0.0018311,-0.0018005,-0.0013733,-0.0013123,-0.0009460,-0.0009766,-0.0006714,-0.0007629,-0.0006104,-0.0007935,-0.0006409,-0.0010681,-0.0009155,-0.0014038,-0.0012207,-0.0017090,-0.0015564,-0.0020142,-0.0018921,-0.0021057,-0.0020142,-0.0021057,-0.0019836,-0.0019531,-0.0018616,-0.0016479,-0.0015869,-0.0014038,-0.0013733,-0.0010376,-0.0011597,-0.0008850,-0.0010986,-0.0008850,-0.0010376,-0.0010376,-0.0011292,-0.0012512,-0.0013428,-0.0013733,-0.0015564,-0.0014648,-0.0016479,-0.0014343,-0.0016174,-0.0013428,-0.0013123,-0.0010986,-0.0009155,-0.0008850,-0.0006104,-0.0006104,-0.0003662,-0.0003662,-0.0003967,-0.0003662,-0.0005493,-0.0004578,-0.0008850,-0.0009766,-0.0011292,-0.0014954,-0.0014343,-0.0018005,-0.0018005,-0.0018921,-0.0019836,-0.0019836,-0.0018005,-0.0018311,-0.0015564,-0.0014343,-0.0014343,-0.0010376,-0.0013123,-0.0007935,-0.0012512,-0.0008240,-0.0012512,-0.0011292,-0.0014038,-0.0014954,-0.0016174,-0.0018921,-0.0018921,-0.0021667,-0.0020447,-0.0022888,-0.0020142,-0.0021973,-0.0018616,-0.0019531,-0.0015869,-0.0015869,-0.0012207,-0.0012512,-0.0010071,-0.0010071,-0.0009460,-0.0010376,-0.0009155,-0.0012817,-0.0012817,-0.0016174,-0.0019226,-0.0020142,-0.0025024,-0.0026245,-0.0029602,-0.0031738,-0.0032349,-0.0035400,-0.0034485,-0.0035706,-0.0033875,-0.0035706,-0.0030823,-0.0033875,-0.0028687,-0.0029907,-0.0027466,-0.0026550,-0.0026245,-0.0025330,-0.0025940,-0.0025635,-0.0026855,-0.0028687,-0.0030212,-0.0031128,-0.0034180,-0.0034485,-0.0038147,-0.0038452,-0.0039978,-0.0041504,-0.0041504,-0.0041809,-0.0041199,-0.0040588,-0.0039368,-0.0037842,-0.0036926,-0.0035095,-0.0034485,-0.0032349,-0.0032043,-0.0030212,-0.0030823,-0.0030212,-0.0030823,-0.0031738,-0.0031128,-0.0033569,-0.0033264,-0.0034180,-0.0035400,-0.0035706,-0.0036011,-0.0035706,-0.0036011,-0.0035095,-0.0033875,-0.0033264,-0.0031128,-0.0031128,-0.0028381,-0.0027771,-0.0025635,-0.0025024,-0.0024109,-0.0022583,-0.0023193,-0.0020752,-0.0023193,-0.0019531,-0.0022278,-0.0019226,-0.0021362,-0.0020142,-0.0019531,-0.0020447,-0.0018311,-0.0018921,-0.0017090,-0.0016785,-0.0015564,-0.0014343,-0.0013428,-0.0011597,-0.0010986,-0.0009460,-0.0007935,-0.0008240,-0.0005188,-0.0007019,-0.0003967,-0.0006409,-0.0002747,-0.0005493,-0.0002747,-0.0005798,-0.0002441,-0.0007019,-0.0003357,-0.0007019,-0.0004883,-0.0007324,-0.0006714,-0.0007019,-0.0007935,-0.0007324,-0.0007935,-0.0007019,-0.0007935,-0.0006409,-0.0008240,-0.0006104,-0.0007629,-0.0006714,-0.0007324,-0.0007019,-0.0008240,-0.0007324,-0.0008240,-0.0009460,-0.0008545,-0.0010376,-0.0009460,-0.0010986,-0.0010071,-0.0010071,-0.0009460,-0.0009460,-0.0007324,-0.0009155,-0.0005493,-0.0007019,-0.0004578,-0.0004272,-0.0004883,-0.0003357,-0.0004883,-0.0003357,-0.0005188,-0.0004883,-0.0007019,-0.0006409,-0.0008850,-0.0007324,-0.0010681,-0.0010071,-0.0010071,-0.0011902,-0.0008850,-0.0011902,-0.0009155,-0.0010376,-0.0009155,-0.0007935,-0.0010071,-0.0007324,-0.0009460,-0.0009460,-0.0009460,-0.0010986,-0.0011597,-0.0012512,-0.0014648,-0.0014038,-0.0017700,-0.0014648,-0.0019226,-0.0014954,-0.0018616,-0.0015869,-0.0015869,-0.0015259,-0.0014343,-0.0012817,-0.0013123,-0.0010071,-0.0011902,-0.0009766,-0.0011292,-0.0010681,-0.0011292,-0.0012207,-0.0013428,-0.0015564,-0.0017395,-0.0017395,-0.0020142,-0.0022278,-0.0021667,-0.0024109,-0.0022888,-0.0024414,-0.0023804,-0.0021667,-0.0023193,-0.0018311,-0.0021667,-0.0015869,-0.0017395,-0.0013733,-0.0012817,-0.0011597,-0.0010681,-0.0010376,-0.0009766,-0.0010071,-0.0010986,-0.0012207,-0.0013428,-0.0013733,-0.0016785,-0.0016174,-0.0020752,-0.0018616,-0.0022278,-0.0020447,-0.0022278,-0.0021057,-0.0022278,-0.0019226,-0.0021057,-0.0017090,-0.0017395,-0.0014648,-0.0014038,-0.0013123,-0.0011292,-0.0011292,-0.0010071,-0.0010376,-0.0010681,-0.0010071,-0.0011597,-0.0010681,-0.0012207,-0.0012512,-0.0011902,-0.0013123,-0.0011292,-0.0012207,-0.0009155,-0.0010681,-0.0007324,-0.0006409,-0.0004578,-0.0003052,-0.0001221,-0.0000610,0.0001221,0.0002441,0.0003357,0.0003052,0.0004883,0.0004883,0.0004272,0.0004578,0.0005188,0.0003357,0.0005188,0.0004578,0.0004578,0.0006409,0.0003967,0.0007019,0.0006409,0.0007629,0.0008850,0.0009155,0.0011292,0.0011902,0.0013123,0.0014954,0.0015259,0.0018311,0.0018311,0.0021362,0.0020447,0.0023193,0.0023193,0.0024719,0.0026245,0.0025024,0.0025940,0.0025940,0.0025024,0.0025635,0.0025330,0.0024109,0.0025330,0.0023804,0.0024414,0.0025024,0.0025024,0.0026550,0.0026855,0.0028687,0.0029907,0.0031738,0.0032654,0.0035400,0.0034790,0.0036316,0.0036926,0.0035706,0.0036621,0.0034485,0.0033569,0.0032349,0.0029907,0.0028076,0.0027161,0.0024109,0.0024109,0.0021973,0.0021362,0.0021667,0.0020142,0.0022278,0.0021667,0.0023804,0.0024109,0.0025635,0.0026855,0.0026855,0.0028381,0.0027771,0.0028687,0.0027466,0.0027161,0.0025330,0.0025635,0.0022278,0.0021973,0.0019836,0.0017700,0.0016785,0.0014648,0.0013428,0.0011597,0.0010986,0.0009460,0.0010376,0.0008240,0.0009460,0.0007629,0.0008545,0.0007935,0.0008240,0.0007629,0.0007019,0.0007629,0.0005493,0.0007019,0.0003357,0.0004272,0.0002136,0.0000610,-0.0000916,-0.0003052,-0.0004578,-0.0007629,-0.0007935,-0.0011597,-0.0010986,-0.0012512,-0.0015259,-0.0013123,-0.0015869,-0.0013733,-0.0014038,-0.0013733,-0.0011292,-0.0012512,-0.0010376,-0.0010071,-0.0010071,-0.0009460,-0.0010681,-0.0011597,-0.0012817,-0.0014343,-0.0016785,-0.0019531,-0.0021973,-0.0024109,-0.0026550,-0.0028076,-0.0030518,-0.0031738,-0.0031738,-0.0031433,-0.0032959,-0.0029297,-0.0032043,-0.0027161,-0.0028992,-0.0025940,-0.0025635,-0.0025940,-0.0023499,-0.0026245,-0.0025024,-0.0027161,-0.0028992,-0.0029297,-0.0033264,-0.0032654,-0.0038147,-0.0036621,-0.0041199,-0.0040283,-0.0042419,-0.0041809,-0.0043030,-0.0041199,-0.0040894,-0.0039673,-0.0037537,-0.0036621,-0.0034180,-0.0033569,-0.0030518,-0.0031128,-0.0028076,-0.0029602,-0.0025635,-0.0028992,-0.0025940,-0.0028381,-0.0027161,-0.0028076,-0.0028687,-0.0029297,-0.0028992,-0.0029907,-0.0029907,-0.0030212,-0.0030212,-0.0028381,-0.0029602,-0.0027771,-0.0026245,-0.0026550,-0.0023193,-0.0023499,-0.0021667,-0.0020447,-0.0019226,-0.0019226,-0.0016785,-0.0018616,-0.0016479,-0.0018311,-0.0016785,-0.0018311,-0.0018921,-0.0018616,-0.0020752,-0.0018005,-0.0020752,-0.0018005,-0.0017700,-0.0016785,-0.0013733,-0.0011902,-0.0010071,-0.0005493,-0.0004883,0.0001526,0.0001221,0.0006714,0.0006714,0.0010071,0.0010681,0.0012207,0.0011902,0.0013123,0.0009766,0.0012512,0.0007324,0.0010986,0.0006409,0.0008850,0.0007019,0.0009155,0.0008240,0.0013123,0.0013123,0.0018005,0.0020142,0.0024414,0.0028076,0.0031433,0.0035400,0.0037842,0.0041199,0.0043030,0.0043640,0.0044556,0.0044861,0.0043030,0.0042725,0.0040894,0.0039062,0.0037842,0.0036316,0.0035095,0.0034180,0.0034180,0.0033264,0.0036011,0.0036011,0.0038757,0.0040588,0.0043945,0.0045471,0.0050659,0.0049744,0.0056458,0.0053711,0.0058289,0.0056763,0.0058899,0.0057068,0.0056458,0.0055847,0.0052185,0.0052490,0.0049438,0.0046387,0.0047913,0.0043030,0.0045166,0.0043335,0.0044556,0.0044250,0.0046692,0.0046387,0.0048828,0.0051880,0.0051575,0.0055237,0.0056152,0.0056763,0.0058594,0.0058289,0.0057678,0.0058289,0.0056152,0.0056152,0.0054016,0.0052185,0.0051880,0.0048828,0.0049133,0.0047302,0.0046692,0.0048218,0.0045471,0.0048828,0.0046997,0.0050049,0.0049744,0.0050354,0.0052490,0.0051880,0.0053406,0.0052795,0.0054016,0.0051575,0.0052795,0.0051270,0.0048523,0.0050964,0.0045776,0.0047302,0.0045471,0.0043030,0.0043335,0.0040894,0.0041199,0.0039368,0.0039673,0.0037842,0.0038757,0.0036621,0.0036926,0.0035706,0.0034180,0.0035400,0.0031433,0.0032654,0.0029907,0.0029297,0.0027771,0.0025940,0.0024719,0.0023499,0.0022583,0.0021057,0.0020142,0.0018616,0.0018616,0.0016785,0.0017700,0.0016174,0.0015564,0.0015259,0.0014648,0.0014954,0.0014038,0.0013123,0.0013428,0.0011902,0.0012512,0.0011597,0.0011902,0.0010071,0.0010986,0.0008850,0.0010071,0.0008850,0.0009766,0.0008850,0.0008545,0.0009155,0.0008850,0.0010071,0.0008240,0.0010376,0.0008545,0.0009766,0.0010071,0.0008240,0.0009766,0.0007324,0.0008850,0.0007019,0.0006714,0.0005798,0.0004578,0.0004578,0.0003967,0.0001831,0.0004578,0.0000000,0.0003662,0.0000916,0.0003357,0.0000305,0.0003967,0.0001526,0.0003052,0.0003967,0.0001526,0.0004272,0.0002747,0.0003357,0.0002747,0.0003052,0.0001221,0.0001831,0.0001831,-0.0000305,0.0002441,-0.0002747,0.0002136,-0.0002747,0.0000305,0.0000000,-0.0000916,0.0001526,0.0000305,0.0001526,0.0003662,0.0002441,0.0006104,0.0004578,0.0007935,0.0007324,0.0010071,0.0010071,0.0011597,0.0012817,0.0013123,0.0013733,0.0013733,0.0014954,0.0013733,0.0015259,0.0012512,0.0013428,0.0012512,0.0011292,0.0010986,0.0011597,0.0009460,0.0011292,0.0011597,0.0011292,0.0014648,0.0014343,0.0017090,0.0018311,0.0021667,0.0021667,0.0025024,0.0025330,0.0026550,0.0027771,0.0026855,0.0026245,0.0025940,0.0022583,0.0021973,0.0018311,0.0016785,0.0014038,0.0011902,0.0010071,0.0009155,0.0008240,0.0009155,0.0008545,0.0011902,0.0012512,0.0015564,0.0018921,0.0019836,0.0024109,0.0025024,0.0026855,0.0028381,0.0027466,0.0028076,0.0025635,0.0025024,0.0020447,0.0019226,0.0015564,0.0012207,0.0010376,0.0005493,0.0006409,0.0001831,0.0003052,0.0001526,0.0003357,0.0002747,0.0005798,0.0004883,0.0009766,0.0008850,0.0012207,0.0012512,0.0013733,0.0014954,0.0013733,0.0014343,0.0012512,0.0012207,0.0009155,0.0008850,0.0005188,0.0004578,0.0001526,0.0000916,-0.0001221,-0.0001221,-0.0003357,-0.0000916,-0.0004272,0.0000305,-0.0002441,0.0003052,0.0000916,0.0004578,0.0004883,0.0006714,0.0008240,0.0008545,0.0009155,0.0009155,0.0009766,0.0007935,0.0008545,0.0004578,0.0005188,0.0001221,0.0000610,-0.0003052,-0.0004883,-0.0006104,-0.0010071,-0.0008240,-0.0012207,-0.0012207,-0.0011597,-0.0013123,-0.0011902,-0.0011597,-0.0011292,-0.0010071,-0.0009460,-0.0009155,-0.0008850,-0.0007935,-0.0009155,-0.0007629,-0.0011292,-0.0008850,-0.0013428,-0.0011597,-0.0015259,-0.0015259,-0.0018311,-0.0016174,-0.0020752,-0.0017395,-0.0021362,-0.0018005,-0.0019836,-0.0017395,-0.0018616,-0.0014648,-0.0017700,-0.0011292,-0.0014038,-0.0010681,-0.0010376,-0.0008850,-0.0008545,-0.0006714,-0.0007629,-0.0005188,-0.0005188,-0.0006104,-0.0003052,-0.0005493,-0.0002441,-0.0003052,-0.0002441,-0.0001221,-0.0000610,-0.0000305,0.0001221,0.0001831,0.0003662,0.0004272,0.0006409,0.0007324,0.0009155,0.0010071,0.0010681,0.0014343,0.0012207,0.0017090,0.0014038,0.0018005,0.0016479,0.0018005,0.0017700,0.0017700,0.0018005,0.0018311,0.0016174,0.0020142,0.0014648,0.0020752,0.0014954,0.0021057,0.0016479,0.0022278,0.0017700,0.0022583,0.0021362,0.0023193,0.0023804,0.0024719,0.0025024,0.0025635,0.0027466,0.0024109,0.0028687,0.0023499,0.0027161,0.0024414,0.0025940,0.0023499,0.0025940,0.0023499,0.0026550,0.0023804,0.0027466,0.0026550,0.0028381,0.0030212,0.0030212,0.0033569,0.0034180,0.0036011,0.0037842,0.0038452,0.0040283,0.0041504,0.0040588,0.0043030,0.0041199,0.0042419,0.0040894,0.0040588,0.0040283,0.0038452,0.0038147,0.0036316,0.0034790,0.0035095,0.0032654,0.0033875,0.0030823,0.0033875,0.0029907,0.0034180,0.0029907,0.0035400,0.0032349,0.0035400,0.0035095,0.0035706,0.0036926,0.0036621,0.0036621,0.0036316,0.0036011,0.0033875,0.0033569,0.0030823,0.0028076,0.0026855,0.0021973,0.0021973,0.0017395,0.0016785,0.0014648,0.0013733,0.0013428,0.0011902,0.0013123,0.0013733,0.0014343,0.0016174,0.0018005,0.0017700,0.0021057,0.0018921,0.0021667,0.0019836,0.0019226,0.0017700,0.0014954,0.0014343,0.0009155,0.0008545,0.0004272,0.0002441,-0.0000305,-0.0001221,-0.0003662,-0.0002441,-0.0004272,-0.0002136,-0.0001526,-0.0000916,0.0002441,0.0002136,0.0005493,0.0004883,0.0006104,0.0006104,0.0004883,0.0004272,0.0001221,-0.0000305,-0.0003662,-0.0006104,-0.0010071,-0.0012512,-0.0015564,-0.0017700,-0.0019836,-0.0020142,-0.0023193,-0.0020142,-0.0023499,-0.0018921,-0.0020142,-0.0017395,-0.0016785,-0.0015869,-0.0014038,-0.0014343,-0.0014038,-0.0015259,-0.0015869,-0.0017700,-0.0020752,-0.0022583,-0.0027771,-0.0028992,-0.0034180,-0.0036316,-0.0040894,-0.0042725,-0.0046082,-0.0047913,-0.0049133,-0.0050659,-0.0050354,-0.0050659,-0.0050354,-0.0049133,-0.0048828,-0.0046082,-0.0047607,-0.0043945,-0.0045776,-0.0042725,-0.0043640,-0.0044556,-0.0043640,-0.0045471,-0.0045776,-0.0046997,-0.0050049,-0.0049438,-0.0050659,-0.0053406,-0.0051575,-0.0054626,-0.0052185,-0.0052185,-0.0051575,-0.0050354,-0.0048218,-0.0046692,-0.0043335,-0.0041504,-0.0038757,-0.0036316,-0.0032959,-0.0032349,-0.0027161,-0.0029907,-0.0023804,-0.0026550,-0.0023193,-0.0024414,-0.0022888,-0.0024109,-0.0022888,-0.0024719,-0.0025024,-0.0025635,-0.0025635,-0.0027466,-0.0026550,-0.0028687,-0.0026855,-0.0028076,-0.0026855,-0.0027161,-0.0026245,-0.0023193,-0.0024414,-0.0018921,-0.0020142,-0.0014954,-0.0014343,-0.0010376,-0.0008850,-0.0005798,-0.0004272,-0.0001831,0.0000305,0.0000610,0.0002136,0.0000610,0.0003967,0.0000000,0.0002441,0.0001221,-0.0000305,0.0002136,-0.0000916,0.0002136,0.0001526,0.0003662,0.0006714,0.0007935,0.0012512,0.0014954,0.0018616,0.0023499,0.0025024,0.0030823,0.0031128,0.0036011,0.0035095,0.0038452,0.0036011,0.0037231,0.0034790,0.0033875,0.0031433,0.0029297,0.0026855,0.0024109,0.0023499,0.0021362,0.0021973,0.0021667,0.0022278,0.0024414,0.0025024,0.0030212,0.0030212,0.0036316,0.0036621,0.0041199,0.0042725,0.0044861,0.0046692,0.0046387,0.0047607,0.0045471,0.0045471,0.0043335,0.0040894,0.0039978,0.0035095,0.0035095,0.0032043,0.0030823,0.0029907,0.0029297,0.0028076,0.0031433,0.0028381,0.0033875,0.0032349,0.0036011,0.0036926,0.0038757,0.0040588,0.0041809,0.0042114,0.0042419,0.0041504,0.0041504,0.0038452,0.0038452,0.0034180,0.0034485,0.0028992,0.0028076,0.0025330,0.0021667,0.0021973,0.0018005,0.0018005,0.0016785,0.0015869,0.0017395,0.0016479,0.0020142,0.0016479,0.0022583,0.0020142,0.0023499,0.0024109,0.0023499,0.0025635,0.0024414,0.0025024,0.0023499,0.0022888,0.0020752,0.0019226,0.0018921,0.0014038,0.0014954,0.0010071,0.0010376,0.0007629,0.0005493,0.0005493,0.0002441,0.0004578,0.0000610,0.0003052,0.0001526,0.0000916,0.0002136,-0.0000305,0.0001831,-0.0000610,-0.0000305,-0.0001831,-0.0001831,-0.0004883,-0.0005493,-0.0009460,-0.0010071,-0.0013428,-0.0016174,-0.0018005,-0.0022583,-0.0022583,-0.0026855,-0.0027161,-0.0028992,-0.0031433,-0.0030212,-0.0033264,-0.0031433,-0.0032959,-0.0031433,-0.0032349,-0.0031433,-0.0031128,-0.0032349,-0.0031128,-0.0033264,-0.0032349,-0.0034485,-0.0036621,-0.0037231,-0.0040588,-0.0041504,-0.0044250,-0.0045776,-0.0047302,-0.0050049,-0.0050049,-0.0050659,-0.0051880,-0.0047607,-0.0052490,-0.0046387,-0.0048523,-0.0044556,-0.0043640,-0.0041504,-0.0041504,-0.0037842,-0.0039673,-0.0036621,-0.0038757,-0.0038147,-0.0038147,-0.0040894,-0.0039673,-0.0043640,-0.0042419,-0.0044556,-0.0046692,-0.0043640,-0.0048218,-0.0042725,-0.0046692,-0.0041504,-0.0042419,-0.0038757,-0.0036926,-0.0036011,-0.0032043,-0.0032654,-0.0028381,-0.0028992,-0.0027161,-0.0026855,-0.0028076,-0.0027161,-0.0028687,-0.0030212,-0.0030212,-0.0032043,-0.0032654,-0.0032959,-0.0035095,-0.0032959,-0.0035095,-0.0032654,-0.0033569,-0.0030823,-0.0030518,-0.0028381,-0.0026550,-0.0026245,-0.0023499,-0.0022888,-0.0021667,-0.0021057,-0.0020752,-0.0019531,-0.0021973,-0.0019226,-0.0023193,-0.0021057,-0.0023193,-0.0023193,-0.0023804,-0.0025024,-0.0024109,-0.0024719,-0.0023804,-0.0023193,-0.0023804,-0.0020752,-0.0021667,-0.0019226,-0.0018005,-0.0017395,-0.0014648,-0.0015259,-0.0013733,-0.0012512,-0.0013733,-0.0010681,-0.0013428,-0.0011902,-0.0012207,-0.0013733,-0.0011902,-0.0014038,-0.0013733,-0.0013123,-0.0014038,-0.0012512,-0.0012207,-0.0012512,-0.0010071,-0.0010071,-0.0008240,-0.0007019,-0.0005798,-0.0004272,-0.0003357,-0.0002136,-0.0001526,-0.0000610,-0.0001526,-0.0000916,-0.0001526,-0.0003052,-0.0002136,-0.0007019,-0.0003662,-0.0010071,-0.0007324,-0.0011597,-0.0010986,-0.0013428,-0.0012817,-0.0014343,-0.0014648,-0.0013428,-0.0015564,-0.0014038,-0.0014343,-0.0013428,-0.0014038,-0.0012207,-0.0013428,-0.0011292,-0.0013733,-0.0012207,-0.0012817,-0.0014038,-0.0011902,-0.0016479,-0.0013428,-0.0016479,-0.0014954,-0.0017090,-0.0016174,-0.0017700,-0.0016174,-0.0017395,-0.0016785,-0.0017395,-0.0015869,-0.0015869,-0.0015869,-0.0014954,-0.0014648,-0.0014343,-0.0013733,-0.0014343,-0.0013123,-0.0013428,-0.0013428,-0.0013428,-0.0014038,-0.0013428,-0.0014343,-0.0014038,-0.0015564,-0.0014343,-0.0017090,-0.0015869,-0.0018005,-0.0018616,-0.0018005,-0.0021667,-0.0021057,-0.0022278,-0.0025024,-0.0023804,-0.0027161,-0.0026550,-0.0028687,-0.0028992,-0.0029907,-0.0031128,-0.0028992,-0.0031128,-0.0029907,-0.0029297,-0.0029297,-0.0028076,-0.0026550,-0.0027161,-0.0025024,-0.0025940,-0.0024414,-0.0025330,-0.0024414,-0.0025940,-0.0025330,-0.0028992,-0.0027466,-0.0031128,-0.0032043,-0.0032654,-0.0035095,-0.0034790,-0.0036011,-0.0036926,-0.0034485,-0.0036621,-0.0033264,-0.0034790,-0.0030823,-0.0030518,-0.0028687,-0.0026245,-0.0026550,-0.0023804,-0.0024414,-0.0023499,-0.0023804,-0.0023804,-0.0025024,-0.0025940,-0.0027466,-0.0029297,-0.0030212,-0.0030823,-0.0032959,-0.0031738,-0.0033569,-0.0031128,-0.0032043,-0.0028381,-0.0027466,-0.0025940,-0.0021362,-0.0021973,-0.0016479,-0.0015869,-0.0013123,-0.0010376,-0.0011902,-0.0008545,-0.0009460,-0.0009155,-0.0009155,-0.0011597,-0.0011597,-0.0012207,-0.0014038,-0.0014954,-0.0014954,-0.0015869,-0.0015259,-0.0016174,-0.0014648,-0.0014038,-0.0012512,-0.0011292,-0.0008850,-0.0008545,-0.0004883,-0.0005493,-0.0002441,-0.0003052,-0.0000916,-0.0001831,-0.0001221,-0.0002441,-0.0002747,-0.0004578,-0.0004578,-0.0009460,-0.0006409,-0.0012817,-0.0010071,-0.0013428,-0.0014343,-0.0012817,-0.0014954,-0.0012817,-0.0012817,-0.0010986,-0.0008240,-0.0007324,-0.0004578,-0.0002136,-0.0000305,0.0002747,0.0004272,0.0004883,0.0007324,0.0005493,0.0007324,0.0005493,0.0004272,0.0003357,0.0000000,-0.0000610,-0.0003662,-0.0005798,-0.0006104,-0.0009766,-0.0007324,-0.0011597,-0.0007629,-0.0009766,-0.0007019,-0.0006104,-0.0005188,-0.0002136,-0.0002136,-0.0000305,0.0000610,0.0000305,0.0000000,0.0000000,-0.0003052,-0.0003357,-0.0006409,-0.0008545,-0.0010376,-0.0013733,-0.0014954,-0.0016479,-0.0017395,-0.0018616,-0.0017090,-0.0017700,-0.0014343,-0.0014648,-0.0011597,-0.0009155,-0.0007324,-0.0005493,-0.0002441,-0.0002747,0.0000305,-0.0001526,-0.0000916,-0.0001831,-0.0005188,-0.0004272,-0.0009766,-0.0009155,-0.0013733,-0.0014648,-0.0016479,-0.0018921,-0.0019226,-0.0021362,-0.0020142,-0.0020142,-0.0020142,-0.0017700,-0.0018311,-0.0015564,-0.0014343,-0.0013428,-0.0010986,-0.0010681,-0.0010071,-0.0008240,-0.0009766,-0.0009460,-0.0010071,-0.0012207,-0.0010986,-0.0015564,-0.0014343,-0.0019226,-0.0017700,-0.0020447,-0.0021973,-0.0021667,-0.0024414,-0.0021973,-0.0024109,-0.0021057,-0.0021667,-0.0019226,-0.0018616,-0.0016479,-0.0014343,-0.0012207,-0.0010376,-0.0009155,-0.0007324,-0.0007019,-0.0005798,-0.0006409,-0.0005493,-0.0007935,-0.0007019,-0.0009766,-0.0010681,-0.0011597,-0.0014648,-0.0015564,-0.0015564,-0.0018311,-0.0015869,-0.0019531,-0.0016174,-0.0017395,-0.0014954,-0.0013428,-0.0012817,-0.0009460,-0.0009155,-0.0007019,-0.0005188,-0.0004578,-0.0002747,-0.0003052,-0.0003357,-0.0001221,-0.0004272,-0.0001221,-0.0005798,-0.0002136,-0.0006714,-0.0004272,-0.0004883,-0.0006409,-0.0003357,-0.0005493,-0.0002441,-0.0003357,-0.0000916,0.0000000,0.0001526,0.0003662,0.0004578,0.0006104,0.0008240,0.0007935,0.0010986,0.0010071,0.0012817,0.0012817,0.0013428,0.0014648,0.0014648,0.0015259,0.0016785,0.0016479,0.0018005,0.0018616,0.0019531,0.0020447,0.0021362,0.0022888,0.0023193,0.0024719,0.0025940,0.0025940,0.0028381,0.0027466,0.0029297,0.0029297,0.0029907,0.0028687,0.0030823,0.0028381,0.0028992,0.0028992,0.0026855,0.0027771,0.0027161,0.0024719,0.0027466,0.0024414,0.0025940,0.0026245,0.0024719,0.0028076,0.0025940,0.0029907,0.0027771,0.0031738,0.0029602,0.0033264,0.0032959,0.0032654,0.0035400,0.0032654,0.0035095,0.0033264,0.0033264,0.0032349,0.0032043,0.0029602,0.0029907,0.0027161,0.0027771,0.0025330,0.0025940,0.0023499,0.0023804,0.0022583,0.0022278,0.0021973,0.0021362,0.0020752,0.0021667,0.0020447,0.0021057,0.0020447,0.0020752,0.0019531,0.0020142,0.0018311,0.0018921,0.0017395,0.0016785,0.0015259,0.0014343,0.0013428,0.0010376,0.0011597,0.0007629,0.0007935,0.0006409,0.0005493,0.0004578,0.0003967,0.0003052,0.0003662,0.0003357,0.0003967,0.0003052,0.0005493,0.0003967,0.0006409,0.0005493,0.0005798,0.0005493,0.0005493,0.0003967,0.0003357,0.0001221,-0.0000916,-0.0001831,-0.0005188,-0.0006409,-0.0007935,-0.0010376,-0.0010681,-0.0014038,-0.0011902,-0.0015564,-0.0011597,-0.0015564,-0.0010681,-0.0013733,-0.0010071,-0.0011902,-0.0010071,-0.0010681,-0.0010986,-0.0012207,-0.0014038,-0.0014954,-0.0016785,-0.0021057,-0.0021057,-0.0026245,-0.0025940,-0.0030518,-0.0030823,-0.0034180,-0.0032959,-0.0035095,-0.0034790,-0.0033264,-0.0034485,-0.0031128,-0.0032654,-0.0029907,-0.0030518,-0.0027466,-0.0028381,-0.0028076,-0.0028381,-0.0028992,-0.0028687,-0.0030823,-0.0030823,-0.0032654,-0.0032654,-0.0034180,-0.0034180,-0.0034485,-0.0034180,-0.0033569,-0.0033569,-0.0031738,-0.0030823,-0.0030518,-0.0027466,-0.0028076,-0.0025024,-0.0025330,-0.0024414,-0.0023193,-0.0024109,-0.0022278,-0.0024414,-0.0023804,-0.0023499,-0.0025330,-0.0024109,-0.0026245,-0.0025635,-0.0025024,-0.0026245,-0.0025940,-0.0024414,-0.0025635,-0.0023193,-0.0024414,-0.0021973,-0.0021973,-0.0021057,-0.0019531,-0.0019836,-0.0017090,-0.0017700,-0.0015259,-0.0015564,-0.0013428,-0.0012817,-0.0012207,-0.0009155,-0.0010681,-0.0006714,-0.0008240,-0.0005493,-0.0005188,-0.0003662,-0.0003052,-0.0001526,-0.0001526,-0.0000305,-0.0000305,0.0001221,0.0001831,0.0001831,0.0003357,0.0003357,0.0003662,0.0005493,0.0005493,0.0006409,0.0007324,0.0007935,0.0009766,0.0009460,0.0013123,0.0010071,0.0016174,0.0012207,0.0016174,0.0016479,0.0017395,0.0018921,0.0020447,0.0019836,0.0023193,0.0022888,0.0024719,0.0026245,0.0027161,0.0029297,0.0030518,0.0032349,0.0033264,0.0034180,0.0037537,0.0036011,0.0039978,0.0039062,0.0041504,0.0040894,0.0043945,0.0042419,0.0044861,0.0044250,0.0045166,0.0046082,0.0045776,0.0046997,0.0046692,0.0046997,0.0048523,0.0046082,0.0049438,0.0047607,0.0048523,0.0048828,0.0047607,0.0048218,0.0047607,0.0048218,0.0046692,0.0046692,0.0046692,0.0045471,0.0046082,0.0046387,0.0044861,0.0048218,0.0044556,0.0049133,0.0045776,0.0049744,0.0048218,0.0049438,0.0050659,0.0049438,0.0052185,0.0049438,0.0050964,0.0049438,0.0048218,0.0048218,0.0045471,0.0045471,0.0043640,0.0042419,0.0042419,0.0040588,0.0040283,0.0041504,0.0041504,0.0042725,0.0044250,0.0044861,0.0048523,0.0049438,0.0051880,0.0053101,0.0054626,0.0056458,0.0056152,0.0056152,0.0056763,0.0052795,0.0054321,0.0049133,0.0048218,0.0045471,0.0041199,0.0040283,0.0036316,0.0036926,0.0031738,0.0035095,0.0029907,0.0034790,0.0031433,0.0034485,0.0035095,0.0035095,0.0038147,0.0036926,0.0039062,0.0038147,0.0038452,0.0036926,0.0035095,0.0034180,0.0029907,0.0029907,0.0023193,0.0023499,0.0017700,0.0017090,0.0012817,0.0011597,0.0008850,0.0008850,0.0007324,0.0007629,0.0007935,0.0007629,0.0009155,0.0010071,0.0010986,0.0011597,0.0013428,0.0011902,0.0014648,0.0012207,0.0014343,0.0010986,0.0011597,0.0009155,0.0008240,0.0006409,0.0004883,0.0003357,0.0002747,0.0000610,0.0001526,-0.0001221,0.0001831,-0.0000305,0.0002441,0.0002136,0.0003662,0.0005493,0.0005798,0.0008545,0.0008545,0.0010376,0.0010986,0.0011292,0.0011597,0.0010986,0.0010681,0.0009155,0.0009155,0.0007324,0.0007324,0.0003357,0.0004883,0.0002136,0.0002136,0.0002136,0.0000610,0.0002136,0.0000610,0.0003357,0.0001831,0.0003967,0.0003662,0.0005188,0.0005798,0.0007019,0.0006409,0.0007324,0.0007629,0.0006714,0.0007629,0.0006104,0.0005493,0.0006104,0.0003357,0.0004883,0.0003052,0.0002747,0.0003662,0.0002136,0.0003967,0.0003662,0.0004272,0.0005493,0.0004883,0.0007935,0.0006714,0.0009155,0.0010376,0.0010681,0.0012207,0.0014038,0.0011902,0.0016479,0.0014038,0.0015564,0.0016174,0.0014954,0.0016174,0.0014954,0.0015564,0.0013733,0.0014343,0.0013123,0.0012817,0.0011902,0.0012207,0.0010986,0.0012512,0.0010376,0.0013428,0.0010681,0.0014648,0.0013123,0.0014954,0.0015564,0.0015259,0.0018616,0.0016479,0.0019836,0.0016785,0.0019836,0.0018311,0.0018616,0.0018616,0.0015564,0.0018311,0.0012817,0.0016785,0.0012512,0.0013123,0.0012512,0.0011597,0.0012207,0.0011292,0.0013123,0.0011902,0.0013123,0.0014038,0.0013733,0.0015564,0.0014648,0.0016174,0.0015564,0.0016174,0.0016174,0.0015259,0.0015564,0.0014038,0.0014343,0.0012512,0.0011902,0.0011902,0.0010376,0.0010986,0.0009460,0.0009766,0.0008545,0.0009460,0.0008545,0.0008545,0.0009155,0.0007324,0.0007935,0.0007629,0.0006104,0.0007324,0.0004883,0.0004883,0.0004272,0.0002441,0.0003662,0.0000610,0.0000916,0.0000000,-0.0001526,0.0000000,-0.0002747,-0.0000916,-0.0003052,-0.0000610,-0.0002747,-0.0000610,-0.0002441,-0.0001221,-0.0000305,-0.0000916,0.0000000,-0.0000305,-0.0001221,0.0000305,-0.0001526,-0.0001221,-0.0001526,-0.0003357,-0.0002136,-0.0005188,-0.0005188,-0.0005798,-0.0007935,-0.0006714,-0.0008545,-0.0009460,-0.0009460,-0.0010376,-0.0010681,-0.0010376,-0.0012207,-0.0010681,-0.0011902,-0.0010986,-0.0012512,-0.0011597,-0.0014343,-0.0011597,-0.0015869,-0.0012512,-0.0016479,-0.0015259,-0.0016174,-0.0017395,-0.0015564,-0.0018921,-0.0016479,-0.0018311,-0.0017090,-0.0016479,-0.0017090,-0.0015564,-0.0015259,-0.0013733,-0.0014038,-0.0010986,-0.0012207,-0.0008850,-0.0009766,-0.0007324,-0.0007935,-0.0006409,-0.0005188,-0.0007019,-0.0002441,-0.0005493,-0.0002441,-0.0003357,-0.0002747,-0.0001831,-0.0002747,-0.0000916,-0.0001526,-0.0001221,0.0000000,-0.0001221,0.0001221,0.0000305,0.0001526,0.0003052,0.0002747,0.0005188,0.0006409,0.0007019,0.0009766,0.0010071,0.0012512,0.0014343,0.0014038,0.0017395,0.0016174,0.0017700,0.0018616,0.0017090,0.0018921,0.0016479,0.0018005,0.0015564,0.0016174,0.0015259,0.0014343,0.0016174,0.0013428,0.0017700,0.0015564,0.0018616,0.0018921,0.0021057,0.0023193,0.0025330,0.0026550,0.0028687,0.0028992,0.0031433,0.0031128,0.0031738,0.0031433,0.0029907,0.0030518,0.0027466,0.0027161,0.0024719,0.0024414,0.0022888,0.0021667,0.0021362,0.0020447,0.0021362,0.0020752,0.0023193,0.0022583,0.0025635,0.0025635,0.0028687,0.0028687,0.0031738,0.0031738,0.0032043,0.0033875,0.0032349,0.0033569,0.0032349,0.0030212,0.0031128,0.0028381,0.0027161,0.0025940,0.0024719,0.0022888,0.0022583,0.0021667,0.0020752,0.0021362,0.0021667,0.0020142,0.0022888,0.0021973,0.0023499,0.0024414,0.0024414,0.0026550,0.0025024,0.0027466,0.0025940,0.0026550,0.0025940,0.0025635,0.0024414,0.0022583,0.0023193,0.0019226,0.0020447,0.0017395,0.0015869,0.0016174,0.0011902,0.0014648,0.0010071,0.0012207,0.0010681,0.0009155,0.0010071,0.0007935,0.0008850,0.0007629,0.0007324,0.0007324,0.0006409,0.0006409,0.0006104,0.0004272,0.0006104,0.0004272,0.0004578,0.0003052,0.0003357,0.0001831,0.0002136,0.0001221,-0.0001526,0.0000000,-0.0004578,-0.0002747,-0.0006104,-0.0007324,-0.0007629,-0.0010681,-0.0010986,-0.0011902,-0.0014038,-0.0012512,-0.0014954,-0.0014038,-0.0014648,-0.0014038,-0.0014038,-0.0013123,-0.0013123,-0.0012817,-0.0013733,-0.0012817,-0.0014648,-0.0015259,-0.0016174,-0.0019226,-0.0020447,-0.0022583,-0.0026855,-0.0026855,-0.0032654,-0.0031433,-0.0037842,-0.0034790,-0.0040588,-0.0038147,-0.0040283,-0.0040588,-0.0038757,-0.0039978,-0.0037842,-0.0038147,-0.0037231,-0.0036011,-0.0036926,-0.0035706,-0.0037842,-0.0036926,-0.0039978,-0.0039673,-0.0043640,-0.0042419,-0.0047607,-0.0047302,-0.0049438,-0.0051270,-0.0051880,-0.0052795,-0.0053711,-0.0052185,-0.0053101,-0.0051270,-0.0050049,-0.0048218,-0.0046997,-0.0044250,-0.0043030,-0.0040283,-0.0039062,-0.0038147,-0.0035400,-0.0036316,-0.0033569,-0.0033569,-0.0035400,-0.0032959,-0.0035400,-0.0034790,-0.0036011,-0.0035095,-0.0038452,-0.0034485,-0.0039368,-0.0034790,-0.0037231,-0.0035706,-0.0032654,-0.0033569,-0.0028992,-0.0028992,-0.0025635,-0.0022583,-0.0021973,-0.0017700,-0.0018311,-0.0014343,-0.0013733,-0.0013733,-0.0010986,-0.0012817,-0.0011292,-0.0011902,-0.0013428,-0.0012207,-0.0015259,-0.0013123,-0.0015259,-0.0012817,-0.0014648,-0.0011292,-0.0012817,-0.0008545,-0.0008240,-0.0004883,-0.0003357,-0.0000610,0.0001221,0.0004578,0.0005798,0.0007019,0.0008850,0.0008545,0.0009766,0.0010376,0.0009460,0.0010071,0.0008850,0.0008850,0.0009766,0.0008240,0.0010071,0.0009460,0.0011597,0.0011292,0.0013428,0.0014038,0.0016785,0.0016785,0.0018921,0.0021362,0.0019836,0.0024414,0.0022583,0.0025024,0.0025635,0.0024109,0.0027161,0.0025330,0.0026855,0.0027771,0.0026245,0.0029297,0.0027466,0.0029602,0.0029907,0.0029297,0.0031738,0.0030823,0.0031433,0.0032349,0.0030212,0.0032654,0.0030823,0.0031738,0.0031128,0.0030823,0.0030518,0.0030823,0.0030212,0.0030518,0.0030823,0.0030518,0.0030823,0.0030823,0.0031433,0.0031738,0.0031738,0.0031738,0.0031738,0.0032043,0.0031128,0.0031433,0.0030212,0.0030823,0.0028687,0.0029602,0.0027771,0.0028076,0.0026855,0.0027466,0.0025330,0.0025635,0.0025635,0.0023804,0.0025635,0.0022888,0.0024109,0.0022888,0.0023193,0.0021362,0.0022888,0.0020142,0.0021362,0.0020142,0.0019226,0.0019531,0.0018005,0.0018005,0.0017700,0.0016174,0.0015564,0.0014954,0.0013428,0.0013428,0.0010681,0.0010986,0.0008240,0.0008850,0.0005798,0.0005188,0.0004578,0.0001831,0.0001526,-0.0000305,-0.0001831,-0.0001831,-0.0005188,-0.0003357,-0.0006714,-0.0005798,-0.0008850,-0.0008545,-0.0008850,-0.0011902,-0.0009460,-0.0014038,-0.0011597,-0.0014343,-0.0013733,-0.0015259,-0.0015564,-0.0016174,-0.0016785,-0.0017090,-0.0018005,-0.0018005,-0.0019531,-0.0019226,-0.0020142,-0.0021667,-0.0021057,-0.0023804,-0.0023499,-0.0023804,-0.0027161,-0.0025024,-0.0028992,-0.0027161,-0.0030212,-0.0029602,-0.0031128,-0.0030518,-0.0031738,-0.0032654,-0.0032349,-0.0033264,-0.0033569,-0.0034180,-0.0035095,-0.0036011,-0.0035400,-0.0037537,-0.0037231,-0.0038147,-0.0039062,-0.0038147,-0.0039978,-0.0039978,-0.0039368,-0.0039368,-0.0039062,-0.0038147,-0.0038757,-0.0037537,-0.0036926,-0.0037537,-0.0036316,-0.0037842,-0.0036011,-0.0037537,-0.0036316,-0.0038147,-0.0036926,-0.0038147,-0.0037842,-0.0037231,-0.0039062,-0.0036011,-0.0037842,-0.0035706,-0.0034485,-0.0035400,-0.0031738,-0.0032959,-0.0030823,-0.0030518,-0.0028992,-0.0030518,-0.0027466,-0.0029297,-0.0028687,-0.0028076,-0.0029602,-0.0028992,-0.0029907,-0.0028687,-0.0028992,-0.0028992,-0.0027771,-0.0027466,-0.0026550,-0.0025024,-0.0023499,-0.0022278,-0.0021057,-0.0019226,-0.0018921,-0.0017700,-0.0016479,-0.0018311,-0.0015564,-0.0018311,-0.0017090,-0.0018616,-0.0019531,-0.0019531,-0.0021057,-0.0021667,-0.0021973,-0.0022888,-0.0022583,-0.0022583,-0.0021667,-0.0020752,-0.0020447,-0.0017700,-0.0017090,-0.0015259,-0.0013733,-0.0013428,-0.0010986,-0.0011597,-0.0010071,-0.0010071,-0.0011292,-0.0010986,-0.0011902,-0.0014954,-0.0013733,-0.0018311,-0.0016479,-0.0020142,-0.0020142,-0.0021667,-0.0021057,-0.0021362,-0.0020447,-0.0019836,-0.0018616,-0.0015564,-0.0014954,-0.0012512,-0.0010681,-0.0008240,-0.0008850,-0.0005188,-0.0006714,-0.0006104,-0.0005493,-0.0009460,-0.0007935,-0.0011902,-0.0014038,-0.0014038,-0.0018005,-0.0018005,-0.0019226,-0.0021362,-0.0019226,-0.0021057,-0.0017395,-0.0017090,-0.0014954,-0.0011597,-0.0010681,-0.0007324,-0.0005493,-0.0003357,-0.0003967,-0.0000916,-0.0003357,-0.0002747,-0.0004272,-0.0006409,-0.0009155,-0.0010681,-0.0012817,-0.0015564,-0.0016479,-0.0018616,-0.0018921,-0.0018616,-0.0019226,-0.0017090,-0.0017700,-0.0013123,-0.0013733,-0.0011292,-0.0009155,-0.0009766,-0.0007019,-0.0008850,-0.0007629,-0.0010681,-0.0009766,-0.0013428,-0.0015869,-0.0016785,-0.0021667,-0.0021973,-0.0025024,-0.0027161,-0.0027161,-0.0029602,-0.0028381,-0.0028687,-0.0027771,-0.0025940,-0.0025330,-0.0021973,-0.0022278,-0.0018311,-0.0018311,-0.0017090,-0.0014954,-0.0016479,-0.0015259,-0.0016174,-0.0018005,-0.0018311,-0.0021667,-0.0022278,-0.0025635,-0.0026550,-0.0029907,-0.0030823,-0.0032959,-0.0034790,-0.0034180,-0.0035400,-0.0036011,-0.0033264,-0.0035095,-0.0031433,-0.0032043,-0.0028992,-0.0027466,-0.0025635,-0.0023804,-0.0023499,-0.0021362,-0.0021973,-0.0020447,-0.0021057,-0.0022888,-0.0021362,-0.0025024,-0.0025024,-0.0025635,-0.0031128,-0.0026550,-0.0033569,-0.0029907,-0.0033264,-0.0032349,-0.0031128,-0.0031128,-0.0030212,-0.0027771,-0.0027466,-0.0024414,-0.0023193,-0.0020752,-0.0019226,-0.0017395,-0.0016785,-0.0014954,-0.0015869,-0.0013428,-0.0016785,-0.0012817,-0.0016785,-0.0015259,-0.0015869,-0.0018005,-0.0014954,-0.0018311,-0.0015259,-0.0017090,-0.0014954,-0.0015564,-0.0012817,-0.0013123,-0.0011902,-0.0009766,-0.0010376,-0.0007935,-0.0007019,-0.0007935,-0.0004883,-0.0007629,-0.0003967,-0.0006714,-0.0005493,-0.0006104,-0.0006409,-0.0006714,-0.0007019,-0.0008850,-0.0006714,-0.0009460,-0.0007629,-0.0007629,-0.0008240,-0.0007324,-0.0006714,-0.0006714,-0.0003967,-0.0005188,-0.0002747,-0.0002747,-0.0001221,-0.0000305,0.0000000,0.0001221,0.0000916,0.0001831,0.0000916,0.0002441,0.0000610,0.0001526,0.0000610,-0.0000610,0.0000610,-0.0001526,-0.0000305,-0.0002441,-0.0000610,-0.0003052,-0.0000305,-0.0004272,-0.0000610,-0.0004272,-0.0001221,-0.0002441,-0.0003357,-0.0001526,-0.0005188,-0.0002441,-0.0005798,-0.0003662,-0.0007019,-0.0005493,-0.0007324,-0.0007019,-0.0007324,-0.0007935,-0.0007629,-0.0007935,-0.0007324,-0.0006409,-0.0007324,-0.0005493,-0.0006409,-0.0004883,-0.0006104,-0.0004883,-0.0007019,-0.0004272,-0.0006409,-0.0006409,-0.0006104,-0.0008850,-0.0005493,-0.0009766,-0.0006409,-0.0009460,-0.0006714,-0.0008545,-0.0006714,-0.0007324,-0.0006409,-0.0006409,-0.0006104,-0.0005798,-0.0006104,-0.0006714,-0.0006409,-0.0008850,-0.0008545,-0.0009766,-0.0012207,-0.0011902,-0.0014343,-0.0015259,-0.0015869,-0.0016479,-0.0017700,-0.0016174,-0.0018616,-0.0015259,-0.0018311,-0.0014648,-0.0016479,-0.0013733,-0.0015564,-0.0012817,-0.0015259,-0.0012207,-0.0015564,-0.0014343,-0.0015564,-0.0016479,-0.0018005,-0.0018005,-0.0021057,-0.0019836,-0.0022583,-0.0021973,-0.0023193,-0.0022583,-0.0022583,-0.0023193,-0.0021362,-0.0021667,-0.0019531,-0.0020142,-0.0018005,-0.0017090,-0.0016479,-0.0015259,-0.0015564,-0.0015259,-0.0014954,-0.0014648,-0.0016479,-0.0014648,-0.0017395,-0.0015564,-0.0017700,-0.0017700,-0.0018921,-0.0017395,-0.0019226,-0.0017395,-0.0018005,-0.0017090,-0.0015564,-0.0015259,-0.0013733,-0.0011902,-0.0011292,-0.0008545,-0.0008545,-0.0007324,-0.0005493,-0.0004883,-0.0003662,-0.0003967,-0.0002441,-0.0003967,-0.0002747,-0.0003967,-0.0003052,-0.0004883,-0.0003052,-0.0005493,-0.0003967,-0.0003662,-0.0004883,-0.0002441,-0.0003052,-0.0001831,0.0000000,0.0000916,0.0002747,0.0003662,0.0005188,0.0007324,0.0007324,0.0009460,0.0010071,0.0011597,0.0011902,0.0012207,0.0012512,0.0012512,0.0012817,0.0012207,0.0012512,0.0012207,0.0012512,0.0012207,0.0011902,0.0012817,0.0012512,0.0012817,0.0014954,0.0014343,0.0016174,0.0017700,0.0018311,0.0021362,0.0021362,0.0023804,0.0024719,0.0026550,0.0027466,0.0029907,0.0028687,0.0031433,0.0031128,0.0030823,0.0032654,0.0030212,0.0031738,0.0029907,0.0030212,0.0028992,0.0029602,0.0028992,0.0029297,0.0028687,0.0029602,0.0028992,0.0031128,0.0030823,0.0031738,0.0032959,0.0032959,0.0034485,0.0033875,0.0035400,0.0033875,0.0035095,0.0033569,0.0033264,0.0031738,0.0031738,0.0028992,0.0029297,0.0026550,0.0027771,0.0024719,0.0026245,0.0023193,0.0025330,0.0024109,0.0024414,0.0025330,0.0025024,0.0025635,0.0025635,0.0025330,0.0026245,0.0026245,0.0024719,0.0025635,0.0023499,0.0024109,0.0022583,0.0021667,0.0021362,0.0019531,0.0018921,0.0018311,0.0015869,0.0016785,0.0015259,0.0015259,0.0015259,0.0014038,0.0014954,0.0013428,0.0015564,0.0012512,0.0015869,0.0012817,0.0013733,0.0012817,0.0012817,0.0011597,0.0011597,0.0009460,0.0009155,0.0007019,0.0005798,0.0004883,0.0001526,0.0002441,-0.0001831,-0.0001221,-0.0003357,-0.0005493,-0.0003967,-0.0007324,-0.0005493,-0.0006714,-0.0005493,-0.0005188,-0.0004272,-0.0003357,-0.0002441,-0.0002136,-0.0001526,-0.0000916,-0.0002441,-0.0001526,-0.0003662,-0.0005493,-0.0006104,-0.0010681,-0.0011292,-0.0015259,-0.0018005,-0.0020447,-0.0023193,-0.0024719,-0.0027161,-0.0026550,-0.0028381,-0.0027466,-0.0026550,-0.0025940,-0.0023499,-0.0022278,-0.0020447,-0.0018311,-0.0017395,-0.0016479,-0.0015259,-0.0016174,-0.0016479,-0.0018005,-0.0019226,-0.0022278,-0.0023804,-0.0027466,-0.0029602,-0.0031128,-0.0034180,-0.0036011,-0.0035400,-0.0037842,-0.0035400,-0.0035400,-0.0033569,-0.0032043,-0.0027466,-0.0028381,-0.0021057,-0.0021973,-0.0018311,-0.0015259,-0.0014648,-0.0013428,-0.0012817,-0.0012817,-0.0013733,-0.0014038,-0.0016174,-0.0017090,-0.0018616,-0.0020447,-0.0021667,-0.0023193,-0.0022583,-0.0024414,-0.0022278,-0.0022583,-0.0020752,-0.0018311,-0.0017395,-0.0014038,-0.0011902,-0.0008545,-0.0008545,-0.0004883,-0.0004578,-0.0003052,-0.0001831,-0.0004578,-0.0001221,-0.0007629,-0.0003357,-0.0009460,-0.0007629,-0.0010681,-0.0011902,-0.0012817,-0.0011902,-0.0013733,-0.0010071,-0.0011292,-0.0006409,-0.0005798,-0.0001831,0.0000305,0.0004578,0.0007019,0.0011597,0.0012207,0.0015869,0.0016174,0.0018005,0.0018311,0.0018005,0.0017090,0.0017090,0.0014343,0.0014343,0.0011902,0.0010071,0.0011597,0.0008545,0.0010986,0.0010071,0.0011597,0.0013733,0.0016479,0.0018005,0.0022583,0.0023499,0.0028381,0.0029907,0.0033875,0.0035095,0.0036621,0.0038757,0.0037231,0.0040283,0.0037537,0.0038147,0.0037231,0.0034485,0.0035400,0.0032349,0.0032043,0.0031738,0.0030518,0.0031433,0.0032043,0.0032349,0.0034180,0.0035706,0.0038452,0.0039673,0.0043335,0.0045166,0.0046387,0.0049744,0.0050354,0.0051880,0.0052490,0.0052490,0.0051270,0.0052185,0.0049438,0.0048828,0.0047302,0.0044250,0.0044250,0.0041199,0.0040894,0.0039062,0.0039978,0.0038147,0.0039978,0.0039673,0.0041809,0.0041199,0.0045776,0.0043640,0.0048828,0.0048218,0.0050049,0.0051880,0.0050964,0.0052490,0.0051575,0.0051880,0.0050659,0.0048828,0.0048218,0.0047302,0.0045471,0.0045776,0.0043030,0.0043640,0.0042725,0.0042725,0.0043030,0.0043030,0.0043945,0.0043640,0.0046082,0.0044861,0.0046387,0.0046692,0.0046692,0.0046692,0.0047302,0.0046082,0.0046387,0.0045471,0.0043640,0.0043030,0.0041504,0.0040894,0.0038147,0.0037537,0.0035706,0.0033875,0.0033875,0.0030518,0.0030518,0.0028687,0.0027161,0.0026550,0.0025940,0.0023804,0.0025635,0.0021667,0.0025940,0.0021362,0.0025024,0.0022583,0.0024109,0.0024719,0.0023499,0.0024719,0.0024719,0.0023499,0.0023804,0.0021973,0.0021667,0.0019531,0.0019836,0.0016174,0.0015869,0.0013123,0.0013123,0.0009766,0.0011292,0.0007935,0.0010071,0.0008545,0.0009460,0.0010681,0.0011902,0.0013428,0.0013123,0.0016174,0.0016479,0.0018616,0.0020142,0.0018921,0.0020447,0.0020142,0.0019531,0.0019226,0.0018921,0.0016785,0.0017395,0.0014954,0.0015259,0.0013123,0.0014038,0.0012207,0.0013123,0.0012207,0.0012207,0.0013733,0.0011597,0.0014648,0.0012512,0.0013733,0.0014343,0.0011597,0.0014954,0.0010376,0.0013123,0.0009766,0.0011292,0.0008850,0.0009155,0.0007629,0.0008240,0.0007019,0.0007324,0.0006714,0.0007324,0.0007935,0.0007019,0.0009460,0.0007629,0.0010376,0.0009766,0.0009766,0.0010681,0.0010376,0.0010986,0.0010376,0.0011597,0.0009460,0.0010986,0.0009766,0.0010376,0.0010376,0.0010071,0.0010071,0.0011292,0.0010376,0.0012817,0.0011597,0.0013428,0.0014648,0.0014038,0.0017395,0.0015259,0.0018616,0.0017395,0.0019531,0.0018921,0.0020142,0.0018921,0.0020752,0.0018921,0.0020142,0.0018616,0.0018616,0.0018311,0.0016785,0.0018005,0.0015564,0.0016785,0.0014648,0.0015564,0.0014343,0.0014954,0.0014954,0.0014343,0.0014954,0.0014954,0.0014038,0.0016785,0.0014038,0.0017090,0.0014954,0.0016174,0.0015869,0.0014954,0.0015869,0.0014954,0.0013733,0.0014343,0.0012512,0.0013428,0.0011292,0.0011902,0.0008240,0.0010986,0.0008850,0.0008240,0.0009766,0.0007324,0.0009155,0.0009155,0.0008850,0.0009766,0.0010986,0.0010376,0.0012512,0.0011902,0.0012817,0.0014343,0.0013733,0.0013733,0.0014648,0.0012817,0.0013428,0.0012817,0.0009766,0.0012207,0.0006714,0.0009460,0.0004883,0.0006409,0.0003662,0.0003357,0.0003052,0.0002136,0.0003052,0.0002747,0.0002747,0.0003967,0.0003967,0.0005798,0.0004272,0.0006409,0.0005493,0.0005493,0.0004883,0.0004272,0.0003052,0.0001831,0.0000916,-0.0001526,-0.0001831,-0.0005188,-0.0005493,-0.0007629,-0.0008545,-0.0008850,-0.0011292,-0.0009460,-0.0011902,-0.0009460,-0.0011292,-0.0009460,-0.0010986,-0.0008545,-0.0010071,-0.0009155,-0.0008850,-0.0010376,-0.0009155,-0.0010986,-0.0010681,-0.0012817,-0.0012817,-0.0014648,-0.0015259,-0.0016479,-0.0017395,-0.0018921,-0.0018311,-0.0020447,-0.0019531,-0.0019531,-0.0020447,-0.0019226,-0.0018616,-0.0018311,-0.0016174,-0.0016174,-0.0014343,-0.0013428,-0.0010986,-0.0010986,-0.0007935,-0.0009155,-0.0005493,-0.0006714,-0.0004883,-0.0005493,-0.0004883,-0.0004883,-0.0004883,-0.0005493,-0.0005493,-0.0006714,-0.0005493,-0.0008240,-0.0004883,-0.0007935,-0.0005188,-0.0006104,-0.0004578,-0.0003967,-0.0000916,-0.0002136,0.0001526,0.0002747,0.0003662,0.0006409,0.0006714,0.0008240,0.0009460,0.0009155,0.0010681,0.0009460,0.0011292,0.0008240,0.0011292,0.0006714,0.0010376,0.0006104,0.0009766,0.0006104,0.0009766,0.0007324,0.0010376,0.0009460,0.0011292,0.0013733,0.0012817,0.0016479,0.0015564,0.0018311,0.0019226,0.0019836,0.0021973,0.0020447,0.0022278,0.0021667,0.0021362,0.0024414,0.0019226,0.0024109,0.0018616,0.0021973,0.0019836,0.0019836,0.0019531,0.0020142,0.0018616,0.0020142,0.0019836,0.0019531,0.0021973,0.0019836,0.0024414,0.0021057,0.0025940,0.0024414,0.0027161,0.0026855,0.0028381,0.0028381,0.0030518,0.0029297,0.0030823,0.0029602,0.0030212,0.0029602,0.0029297,0.0028381,0.0027161,0.0027466,0.0025635,0.0025635,0.0024719,0.0023193,0.0023499,0.0023193,0.0023193,0.0022583,0.0023499,0.0023499,0.0024719,0.0024719,0.0024719,0.0026550,0.0024719,0.0027771,0.0025330,0.0026855,0.0025635,0.0025330,0.0023499,0.0024109,0.0021362,0.0021667,0.0018921,0.0018005,0.0016785,0.0015259,0.0014648,0.0013733,0.0012512,0.0013733,0.0011597,0.0012817,0.0012512,0.0014038,0.0013428,0.0015259,0.0013733,0.0015564,0.0015259,0.0014954,0.0015259,0.0013123,0.0014648,0.0010681,0.0012207,0.0007935,0.0008240,0.0005493,0.0003662,0.0000916,0.0000916,-0.0002441,-0.0001221,-0.0005798,-0.0002747,-0.0005493,-0.0004578,-0.0003967,-0.0005493,-0.0003967,-0.0002136,-0.0004272,-0.0001221,-0.0003052,-0.0002441,-0.0002747,-0.0003967,-0.0004883,-0.0006409,-0.0007019,-0.0009460,-0.0011292,-0.0013123,-0.0015564,-0.0016785,-0.0018921,-0.0020142,-0.0023193,-0.0021667,-0.0025024,-0.0023499,-0.0024719,-0.0026245,-0.0023804,-0.0025940,-0.0024719,-0.0024719,-0.0025635,-0.0023804,-0.0026855,-0.0024719,-0.0028381,-0.0027161,-0.0029602,-0.0030823,-0.0032043,-0.0034485,-0.0035095,-0.0037537,-0.0038757,-0.0038757,-0.0043335,-0.0039673,-0.0045776,-0.0041809,-0.0045166,-0.0043640,-0.0045471,-0.0042725,-0.0046082,-0.0041504,-0.0045166,-0.0042419,-0.0043030,-0.0043030,-0.0041504,-0.0042419,-0.0042419,-0.0041504,-0.0042419,-0.0041809,-0.0041809,-0.0043335,-0.0040588,-0.0042725,-0.0039673,-0.0041809,-0.0038452,-0.0039673,-0.0037231,-0.0036621,-0.0035400,-0.0032959,-0.0033264,-0.0030518,-0.0030212,-0.0029297,-0.0027161,-0.0027771,-0.0027161,-0.0026245,-0.0027161,-0.0026550,-0.0025940,-0.0027466,-0.0025024,-0.0026855,-0.0025635,-0.0025024,-0.0024719,-0.0023499,-0.0021362,-0.0022888,-0.0018005,-0.0020447,-0.0015259,-0.0016785,-0.0014038,-0.0013123,-0.0013428,-0.0010376,-0.0011902,-0.0009460,-0.0010986,-0.0009460,-0.0010376,-0.0008545,-0.0009155,-0.0008850,-0.0007324,-0.0007324,-0.0005798,-0.0005188,-0.0003967,-0.0003052,-0.0002136,-0.0000305,-0.0000305,0.0001831,0.0000916,0.0003357,0.0001831,0.0004272,0.0003052,0.0004578,0.0003662,0.0005798,0.0005493,0.0005798,0.0007324,0.0007935,0.0009460,0.0010681,0.0011597,0.0013123,0.0014343,0.0015869,0.0015259,0.0018005,0.0017090,0.0018005,0.0018311,0.0018005,0.0018616,0.0018311,0.0017090,0.0019226,0.0016174,0.0018921,0.0017395,0.0018311,0.0018921,0.0019836,0.0019226,0.0022278,0.0020752,0.0024109,0.0023499,0.0024109,0.0025940,0.0024414,0.0026550,0.0025024,0.0025940,0.0025330,0.0025024,0.0023804,0.0024719,0.0021973,0.0023804,0.0021057,0.0021667,0.0021667,0.0020447,0.0020752,0.0020752,0.0020142,0.0020447,0.0021057,0.0020447,0.0021667,0.0021057,0.0022278,0.0020752,0.0023804,0.0020447,0.0022888,0.0022278,0.0020752,0.0023193,0.0019531,0.0021667,0.0019531,0.0018616,0.0018616,0.0017090,0.0017090,0.0015869,0.0015869,0.0013428,0.0015564,0.0012817,0.0014038,0.0012512,0.0012817,0.0012512,0.0012817,0.0010986,0.0012512,0.0009460,0.0011597,0.0007324,0.0010071,0.0006104,0.0006714,0.0004883,0.0002747,0.0003357,0.0001221,-0.0000916,0.0000610,-0.0003662,-0.0001221,-0.0003052,-0.0005188,-0.0001526,-0.0006409,-0.0003052,-0.0005493,-0.0004883,-0.0006714,-0.0005188,-0.0009460,-0.0006714,-0.0011902,-0.0009766,-0.0014038,-0.0014343,-0.0017395,-0.0018005,-0.0021362,-0.0021362,-0.0023804,-0.0025635,-0.0025635,-0.0028381,-0.0027466,-0.0028381,-0.0028992,-0.0028076,-0.0029602,-0.0027466,-0.0029297,-0.0027466,-0.0028381,-0.0027771,-0.0028076,-0.0026855,-0.0028687,-0.0028687,-0.0028381,-0.0030823,-0.0028687,-0.0033569,-0.0031433,-0.0032959,-0.0034790,-0.0033264,-0.0036011,-0.0034790,-0.0036011,-0.0035095,-0.0034485,-0.0035095,-0.0032349,-0.0033264,-0.0029297,-0.0031128,-0.0026855,-0.0028687,-0.0025635,-0.0025330,-0.0026245,-0.0024109,-0.0027161,-0.0025024,-0.0027771,-0.0028381,-0.0029907,-0.0031433,-0.0031738,-0.0034180,-0.0033569,-0.0036011,-0.0034180,-0.0034485,-0.0034180,-0.0031128,-0.0032654,-0.0028687,-0.0027771,-0.0025635,-0.0023499,-0.0021973,-0.0021362,-0.0019531,-0.0020142,-0.0018311,-0.0021057,-0.0019836,-0.0022583,-0.0023193,-0.0025330,-0.0027161,-0.0028076,-0.0030518,-0.0028992,-0.0032654,-0.0030518,-0.0031738,-0.0029297,-0.0028992,-0.0026550,-0.0025635,-0.0023193,-0.0021667,-0.0019226,-0.0018311,-0.0017090,-0.0014648,-0.0016479,-0.0013733,-0.0015869,-0.0016479,-0.0016174,-0.0019531,-0.0018311,-0.0022278,-0.0021362,-0.0025635,-0.0023499,-0.0026550,-0.0025330,-0.0026245,-0.0025635,-0.0025024,-0.0023193,-0.0022888,-0.0019531,-0.0019836,-0.0015869,-0.0016174,-0.0013123,-0.0011902,-0.0012207,-0.0008545,-0.0011292,-0.0008850,-0.0010681,-0.0009766,-0.0011902,-0.0010986,-0.0014648,-0.0012207,-0.0017090,-0.0013428,-0.0017700,-0.0014038,-0.0016479,-0.0014648,-0.0012512,-0.0014038,-0.0008850,-0.0011292,-0.0006104,-0.0007935,-0.0004272,-0.0005188,-0.0004272,-0.0003662,-0.0005493,-0.0004578,-0.0007324,-0.0008545,-0.0009766,-0.0012512,-0.0013123,-0.0015869,-0.0016785,-0.0017395,-0.0018921,-0.0018005,-0.0019226,-0.0017395,-0.0018005,-0.0015869,-0.0015869,-0.0014343,-0.0014343,-0.0011902,-0.0014343,-0.0011902,-0.0013733,-0.0013733,-0.0015259,-0.0016174,-0.0018616,-0.0019531,-0.0020142,-0.0023193,-0.0021973,-0.0024719,-0.0023499,-0.0025330,-0.0022583,-0.0024414,-0.0020752,-0.0023499,-0.0018921,-0.0020752,-0.0018005,-0.0019226,-0.0018005,-0.0018005,-0.0018921,-0.0018616,-0.0020752,-0.0019226,-0.0023193,-0.0021362,-0.0025024,-0.0023193,-0.0025024,-0.0025635,-0.0025635,-0.0025635,-0.0024719,-0.0025635,-0.0024109,-0.0024414,-0.0024109,-0.0023193,-0.0023499,-0.0024719,-0.0022278,-0.0026245,-0.0024109,-0.0026855,-0.0025940,-0.0028687,-0.0027161,-0.0031128,-0.0028381,-0.0031433,-0.0029602,-0.0030518,-0.0029907,-0.0028992,-0.0028687,-0.0027466,-0.0027466,-0.0025635,-0.0025635,-0.0024414,-0.0024719,-0.0025024,-0.0024414,-0.0025940,-0.0024719,-0.0027771,-0.0026550,-0.0030212,-0.0028687,-0.0031738,-0.0029907,-0.0032043,-0.0031738,-0.0031128,-0.0031738,-0.0029907,-0.0028076,-0.0029297,-0.0025330,-0.0025940,-0.0023193,-0.0021057,-0.0022583,-0.0017700,-0.0021973,-0.0016479,-0.0019836,-0.0018311,-0.0018921,-0.0019226,-0.0020142,-0.0021057,-0.0021057,-0.0021667,-0.0021362,-0.0021362,-0.0020752,-0.0021362,-0.0017700,-0.0019531,-0.0015564,-0.0014954,-0.0013733,-0.0010071,-0.0010376,-0.0007629,-0.0007019,-0.0006104,-0.0004883,-0.0004272,-0.0005493,-0.0004272,-0.0006409,-0.0005798,-0.0007324,-0.0008240,-0.0007935,-0.0010071,-0.0009766,-0.0010681,-0.0010681,-0.0009766,-0.0011292,-0.0007629,-0.0010376,-0.0006409,-0.0008240,-0.0004883,-0.0005493,-0.0003357,-0.0003967,-0.0002747,-0.0002136,-0.0002441,-0.0003662,-0.0003052,-0.0005188,-0.0003967,-0.0007324,-0.0006409,-0.0009766,-0.0008545,-0.0010986,-0.0010681,-0.0010986,-0.0011902,-0.0011292,-0.0010986,-0.0010376,-0.0009460,-0.0008850,-0.0007935,-0.0006714,-0.0005188,-0.0004883,-0.0003357,-0.0002747,-0.0002441,-0.0002136,-0.0001221,-0.0002136,-0.0001221,-0.0002441,-0.0002136,-0.0003357,-0.0002441,-0.0004272,-0.0002747,-0.0004883,-0.0003052,-0.0004883,-0.0002747,-0.0005493,-0.0002136,-0.0004578,-0.0002441,-0.0002747,-0.0004272,-0.0002747,-0.0004272,-0.0003662,-0.0005188,-0.0005798,-0.0006409,-0.0007935,-0.0008240,-0.0010071,-0.0009766,-0.0011902,-0.0012512,-0.0012512,-0.0013733,-0.0013733,-0.0012817,-0.0014648,-0.0012817,-0.0014648,-0.0012512,-0.0013733,-0.0011292,-0.0013733,-0.0011292,-0.0012817,-0.0011902,-0.0011597,-0.0012817,-0.0012512,-0.0012207,-0.0013733,-0.0012512,-0.0015259,-0.0012512,-0.0016785,-0.0014038,-0.0017395,-0.0014954,-0.0018311,-0.0016174,-0.0018311,-0.0018005,-0.0017090,-0.0019531,-0.0016785,-0.0019531,-0.0017090,-0.0018311,-0.0017700,-0.0017090,-0.0018311,-0.0016479,-0.0018005,-0.0016785,-0.0018005,-0.0017395,-0.0018311,-0.0018311,-0.0020142,-0.0018616,-0.0021362,-0.0020447,-0.0021667,-0.0021362,-0.0021973,-0.0020752,-0.0022888,-0.0018005,-0.0021973,-0.0018311,-0.0017395,-0.0017700,-0.0013428,-0.0014954,-0.0010986,-0.0012207,-0.0008545,-0.0010071,-0.0007629,-0.0007629,-0.0008240,-0.0007019,-0.0008240,-0.0007935,-0.0008240,-0.0008850,-0.0008850,-0.0010071,-0.0009460,-0.0010071,-0.0010986,-0.0008850,-0.0010681,-0.0007629,-0.0009460,-0.0007019,-0.0007324,-0.0004578,-0.0005798,-0.0003662,-0.0003967,-0.0001831,-0.0002747,-0.0001831,-0.0001526,-0.0001526,-0.0000610,-0.0001831,-0.0000916,-0.0001526,-0.0000916,-0.0001221,-0.0001831,0.0000916,-0.0001526,0.0002441,0.0000305,0.0003662,0.0003662,0.0005493,0.0007324,0.0007629,0.0011292,0.0010681,0.0013428,0.0014343,0.0014648,0.0016785,0.0016785,0.0017090,0.0018616,0.0016479,0.0018616,0.0016479,0.0017700,0.0016174,0.0018005,0.0017090,0.0016174,0.0019226,0.0017090,0.0020752,0.0019836,0.0022583,0.0022583,0.0025940,0.0025330,0.0028381,0.0028076,0.0029602,0.0029602,0.0030518,0.0030212,0.0029602,0.0028687,0.0027771,0.0027466,0.0025024,0.0024719,0.0023804,0.0022583,0.0021973,0.0021973,0.0021057,0.0022278,0.0021667,0.0023499,0.0022888,0.0025330,0.0025635,0.0025635,0.0028687,0.0027161,0.0028992,0.0029907,0.0029297,0.0029297,0.0029297,0.0028687,0.0027771,0.0027161,0.0026550,0.0024719,0.0024414,0.0023804,0.0021057,0.0023499,0.0019531,0.0021057,0.0020752,0.0019226,0.0020447,0.0019836,0.0020752,0.0021057,0.0021667,0.0021362,0.0022888,0.0021667,0.0024414,0.0021057,0.0023499,0.0020752,0.0021667,0.0018921,0.0019226,0.0015564,0.0015869,0.0012512,0.0012512,0.0008545,0.0010376,0.0005798,0.0007324,0.0007019,0.0004883,0.0007629,0.0005798,0.0008545,0.0008240,0.0010071,0.0010681,0.0011902,0.0011902,0.0013123,0.0011597,0.0011902,0.0010681,0.0007629,0.0006409,0.0002441,0.0000916,-0.0003052,-0.0005493,-0.0008850,-0.0011292,-0.0012512,-0.0015564,-0.0015869,-0.0015564,-0.0017700,-0.0014038,-0.0016785,-0.0011292,-0.0014343,-0.0010071,-0.0011292,-0.0009460,-0.0009155,-0.0010376,-0.0010376,-0.0012207,-0.0012817,-0.0016174,-0.0015564,-0.0021057,-0.0019836,-0.0024414,-0.0024414,-0.0027161,-0.0026855,-0.0027771,-0.0027161,-0.0026855,-0.0026855,-0.0024109,-0.0025024,-0.0020447,-0.0023193,-0.0017700,-0.0020142,-0.0016479,-0.0018005,-0.0016479,-0.0017700,-0.0018005,-0.0018005,-0.0019836,-0.0019836,-0.0020752,-0.0022583,-0.0021973,-0.0023804,-0.0023499,-0.0023499,-0.0023499,-0.0022278,-0.0023499,-0.0020447,-0.0021667,-0.0018921,-0.0019226,-0.0017395,-0.0017700,-0.0015869,-0.0016174,-0.0014954,-0.0014954,-0.0014038,-0.0014648,-0.0013733,-0.0014648,-0.0014038,-0.0013733,-0.0014343,-0.0012207,-0.0014038,-0.0011292,-0.0013123,-0.0010071,-0.0011597,-0.0009766,-0.0009155,-0.0009155,-0.0006409,-0.0009155,-0.0005188,-0.0008240,-0.0004883,-0.0006714,-0.0005493,-0.0005188,-0.0006104,-0.0003967,-0.0005493,-0.0003662,-0.0003357,-0.0001526,-0.0003052,0.0002136,-0.0001221,0.0003967,0.0003357,0.0004883,0.0007629,0.0007935,0.0008850,0.0012207,0.0010681,0.0013733,0.0012512,0.0014954,0.0014038,0.0014648,0.0016174,0.0013733,0.0017395,0.0014648,0.0016479,0.0016785,0.0017395,0.0017700,0.0019531,0.0020142,0.0021362,0.0023193,0.0023804,0.0025330,0.0027161,0.0028076,0.0029907,0.0030212,0.0030823,0.0033569,0.0031738,0.0034485,0.0032959,0.0033264,0.0034790,0.0032043,0.0035706,0.0031433,0.0034790,0.0032654,0.0033569,0.0034180,0.0034180,0.0034485,0.0036011,0.0035706,0.0038147,0.0036621,0.0039978,0.0039368,0.0040894,0.0042114,0.0041199,0.0044250,0.0040588,0.0045776,0.0040588,0.0044556,0.0040894,0.0043030,0.0040588,0.0040894,0.0040588,0.0039368,0.0040283,0.0039368,0.0039978,0.0039673,0.0041199,0.0040894,0.0043335,0.0042725,0.0045471,0.0044861,0.0047913,0.0045776,0.0049744,0.0046692,0.0050354,0.0046082,0.0048828,0.0046082,0.0045166,0.0045776,0.0042114,0.0043335,0.0039673,0.0040588,0.0038147,0.0039368,0.0038452,0.0038757,0.0039062,0.0040588,0.0039978,0.0043030,0.0042725,0.0043640,0.0044861,0.0045166,0.0044556,0.0046692,0.0043945,0.0043640,0.0043640,0.0040588,0.0039673,0.0037537,0.0035706,0.0034485,0.0031738,0.0032349,0.0027771,0.0029907,0.0026855,0.0027771,0.0027771,0.0025635,0.0028381,0.0026855,0.0027161,0.0029297,0.0025940,0.0030212,0.0026245,0.0029297,0.0026550,0.0027466,0.0025940,0.0025635,0.0023193,0.0024719,0.0020752,0.0022888,0.0019226,0.0020447,0.0018616,0.0018005,0.0018616,0.0017395,0.0018311,0.0017395,0.0018616,0.0017090,0.0020142,0.0017700,0.0021057,0.0019226,0.0021362,0.0020752,0.0021973,0.0021057,0.0022278,0.0021973,0.0022278,0.0021667,0.0022583,0.0021057,0.0021667,0.0021667,0.0020142,0.0021057,0.0019531,0.0020142,0.0019226,0.0019226,0.0018005,0.0018921,0.0018311,0.0018616,0.0018616,0.0018005,0.0018921,0.0018005,0.0019836,0.0018921,0.0019226,0.0019226,0.0020142,0.0019226,0.0020142,0.0019531,0.0018311,0.0020752,0.0017395,0.0018616,0.0017700,0.0016174,0.0018005,0.0014954,0.0015564,0.0014954,0.0013733,0.0015259,0.0013428,0.0013733,0.0013733,0.0012817,0.0014343,0.0013123,0.0014038,0.0014038,0.0014038,0.0014648,0.0014038,0.0014343,0.0014343,0.0014954,0.0013428,0.0014954,0.0013123,0.0013428,0.0014038,0.0013123,0.0013428,0.0014038,0.0014038,0.0014954,0.0015869,0.0015869,0.0017090,0.0018005,0.0018005,0.0020142,0.0019836,0.0020447,0.0021973,0.0020447,0.0022583,0.0021057,0.0020447,0.0021667,0.0018921,0.0021362,0.0017395,0.0019531,0.0017090,0.0018005,0.0017090,0.0017090,0.0017700,0.0016174,0.0018311,0.0015869,0.0019531,0.0017090,0.0018921,0.0017700,0.0018616,0.0018616,0.0017395,0.0018921,0.0016174,0.0017700,0.0015259,0.0016174,0.0013733,0.0016174,0.0013123,0.0014648,0.0014343,0.0013428,0.0015564,0.0013428,0.0015564,0.0014648,0.0016174,0.0015564,0.0016479,0.0016174,0.0016174,0.0015869,0.0015869,0.0013733,0.0015259,0.0012207,0.0012512,0.0010681,0.0010681,0.0008545,0.0009155,0.0007629,0.0007019,0.0007935,0.0007019,0.0007019,0.0008240,0.0007935,0.0008850,0.0010376,0.0009460,0.0011597,0.0011292,0.0011902,0.0011597,0.0012512,0.0010071,0.0012512,0.0007629,0.0010376,0.0006409,0.0005798,0.0004578,0.0001831,0.0001526,-0.0000305,-0.0002136,-0.0002747,-0.0004578,-0.0004272,-0.0006409,-0.0004883,-0.0007019,-0.0005493,-0.0006409,-0.0006104,-0.0005493,-0.0005798,-0.0005188,-0.0005188,-0.0005798,-0.0004883,-0.0006409,-0.0006104,-0.0007629,-0.0007019,-0.0009766,-0.0009155,-0.0010986,-0.0011597,-0.0011902,-0.0014343,-0.0013123,-0.0015259,-0.0013733,-0.0014954,-0.0014648,-0.0015259,-0.0013428,-0.0014648,-0.0012512,-0.0013123,-0.0010986,-0.0011597,-0.0008850,-0.0010986,-0.0007629,-0.0009155,-0.0007019,-0.0007324,-0.0007019,-0.0007019,-0.0007019,-0.0006104,-0.0007019,-0.0006409,-0.0007019,-0.0005798,-0.0006409,-0.0004578,-0.0005798,-0.0003967,-0.0004883,-0.0002747,-0.0002441,-0.0000916,-0.0000305,0.0000610,0.0001221,0.0003357,0.0002136,0.0005493,0.0003662,0.0007019,0.0004578,0.0007629,0.0006714,0.0006409,0.0008545,0.0005798,0.0009460,0.0006714,0.0008850,0.0007324,0.0009155,0.0008850,0.0009766,0.0010681,0.0010376,0.0013123,0.0012512,0.0014648,0.0013733,0.0017395,0.0015564,0.0019226,0.0017700,0.0018921,0.0020447,0.0018311,0.0021667,0.0018616,0.0020142,0.0019531,0.0018921,0.0019836,0.0018921,0.0018921,0.0018921,0.0019226,0.0020142,0.0019836,0.0021667,0.0021667,0.0024109,0.0023193,0.0026855,0.0025940,0.0028992,0.0028992,0.0030212,0.0029907,0.0031433,0.0030823,0.0030212,0.0030823,0.0028687,0.0028992,0.0027466,0.0025330,0.0027161,0.0022278,0.0024414,0.0021667,0.0021973,0.0021667,0.0021362,0.0021973,0.0021973,0.0023499,0.0023193,0.0025330,0.0025940,0.0025635,0.0028992,0.0027161,0.0028687,0.0029907,0.0028076,0.0030823,0.0027771,0.0030823,0.0025940,0.0028687,0.0025330,0.0026855,0.0024719,0.0024414,0.0023804,0.0022888,0.0023499,0.0021973,0.0022583,0.0021362,0.0022583,0.0021667,0.0022583,0.0021667,0.0022583,0.0022278,0.0022278,0.0022278,0.0022278,0.0022278,0.0022888,0.0021362,0.0022888,0.0020142,0.0022278,0.0018616,0.0018921,0.0018616,0.0015869,0.0016479,0.0013428,0.0012207,0.0010681,0.0009460,0.0006409,0.0008240,0.0003357,0.0005188,0.0002441,0.0002136,0.0003052,0.0001221,0.0002441,0.0001221,0.0002441,0.0001221,0.0003357,0.0000000,0.0002747,0.0000000,-0.0001221,0.0000000,-0.0005798,-0.0003052,-0.0008850,-0.0008240,-0.0013123,-0.0012512,-0.0016785,-0.0016479,-0.0019531,-0.0020447,-0.0020447,-0.0021667,-0.0021057,-0.0021057,-0.0021667,-0.0019531,-0.0020447,-0.0018921,-0.0018616,-0.0019836,-0.0018311,-0.0021057,-0.0019226,-0.0022888,-0.0021973,-0.0026550,-0.0025940,-0.0029297,-0.0031433,-0.0032043,-0.0036316,-0.0034485,-0.0039062,-0.0037231,-0.0039062,-0.0039673,-0.0036926,-0.0040588,-0.0034485,-0.0039368,-0.0032959,-0.0036316,-0.0032349,-0.0032654,-0.0032959,-0.0030518,-0.0032654,-0.0031433,-0.0031738,-0.0034180,-0.0032349,-0.0034790,-0.0036316,-0.0035400,-0.0038452,-0.0037231,-0.0038452,-0.0039673,-0.0039062,-0.0039062,-0.0038147,-0.0038147,-0.0036926,-0.0035706,-0.0034485,-0.0033569,-0.0031433,-0.0031433,-0.0028076,-0.0029602,-0.0027161,-0.0027161,-0.0026245,-0.0026550,-0.0025635,-0.0026855,-0.0025024,-0.0027771,-0.0025024,-0.0027466,-0.0026550,-0.0026550,-0.0026550,-0.0025330,-0.0025330,-0.0024414,-0.0023193,-0.0021973,-0.0019836,-0.0018616,-0.0018311,-0.0015259,-0.0014954,-0.0013123,-0.0011902,-0.0011597,-0.0010376,-0.0010071,-0.0009460,-0.0010376,-0.0008240,-0.0010681,-0.0008240,-0.0009766,-0.0009766,-0.0007935,-0.0009766,-0.0007019,-0.0007629,-0.0005493,-0.0005188,-0.0003357,-0.0002441,-0.0000610,0.0000305,0.0002441,0.0002136,0.0004578,0.0004578,0.0004578,0.0006409,0.0004272,0.0006409,0.0004272,0.0004272,0.0004272,0.0002441,0.0003662,0.0002136,0.0003357,0.0003052,0.0003357,0.0005188,0.0005798,0.0007019,0.0010376,0.0010071,0.0014343,0.0014648,0.0018311,0.0018616,0.0020752,0.0021362,0.0022888,0.0022888,0.0022278,0.0021667,0.0021362,0.0020142,0.0018616,0.0018311,0.0017090,0.0014954,0.0016174,0.0014648,0.0015259,0.0016785,0.0016174,0.0018311,0.0020447,0.0021667,0.0023499,0.0025635,0.0027771,0.0027466,0.0031128,0.0029297,0.0031128,0.0029297,0.0028992,0.0028076,0.0025330,0.0025635,0.0021667,0.0021362,0.0018616,0.0018616,0.0015869,0.0016174,0.0015564,0.0015259,0.0016785,0.0016174,0.0017395,0.0018311,0.0019226,0.0019531,0.0020752,0.0019836,0.0021667,0.0018616,0.0021057,0.0016785,0.0018005,0.0014954,0.0013733,0.0013123,0.0009155,0.0010376,0.0007019,0.0006714,0.0006104,0.0005798,0.0004272,0.0006409,0.0004578,0.0005493,0.0007019,0.0004272,0.0008545,0.0005188,0.0007935,0.0006104,0.0006104,0.0005493,0.0005188,0.0003052,0.0002747,0.0000305,-0.0000610,-0.0001526,-0.0004883,-0.0004883,-0.0007324,-0.0008545,-0.0009460,-0.0010376,-0.0012512,-0.0010986,-0.0013733,-0.0012512,-0.0013123,-0.0013428,-0.0012512,-0.0013123,-0.0012207,-0.0014038,-0.0011902,-0.0013428,-0.0014038,-0.0013428,-0.0015564,-0.0015869,-0.0017090,-0.0017700,-0.0020447,-0.0019226,-0.0023193,-0.0022278,-0.0025024,-0.0025330,-0.0025635,-0.0025635,-0.0026855,-0.0025940,-0.0026245,-0.0025330,-0.0023804,-0.0025635,-0.0022278,-0.0024109,-0.0021667,-0.0022583,-0.0022583,-0.0022278,-0.0022888,-0.0023193,-0.0023499,-0.0025940,-0.0025024,-0.0027466,-0.0027466,-0.0028992,-0.0030212,-0.0030823,-0.0031433,-0.0032349,-0.0032043,-0.0033264,-0.0032349,-0.0032654,-0.0031738,-0.0032349,-0.0029907,-0.0031738,-0.0028076,-0.0029602,-0.0028076,-0.0027771,-0.0027466,-0.0025940,-0.0026855,-0.0025024,-0.0026245,-0.0025024,-0.0025330,-0.0025330,-0.0025024,-0.0025024,-0.0025330,-0.0025635,-0.0024719,-0.0026245,-0.0024719,-0.0026855,-0.0024414,-0.0027161,-0.0024414,-0.0025940,-0.0025024,-0.0023804,-0.0026245,-0.0021057,-0.0026245,-0.0019836,-0.0024414,-0.0020752,-0.0020752,-0.0021362,-0.0019226,-0.0020447,-0.0019226,-0.0019226,-0.0019531,-0.0018921,-0.0020447,-0.0018921,-0.0021362,-0.0020447,-0.0020752,-0.0022888,-0.0020447,-0.0023193,-0.0022888,-0.0021057,-0.0023499,-0.0020142,-0.0022583,-0.0018921,-0.0021057,-0.0017395,-0.0018311,-0.0016785,-0.0015259,-0.0015869,-0.0013428,-0.0013428,-0.0014343,-0.0011292,-0.0015259,-0.0011902,-0.0015564,-0.0014343,-0.0016174,-0.0016174,-0.0018005,-0.0018616,-0.0018921,-0.0020752,-0.0018311,-0.0021667,-0.0017700,-0.0020752,-0.0017090,-0.0017700,-0.0015564,-0.0014648,-0.0012817,-0.0013123,-0.0010376,-0.0010986,-0.0010986,-0.0009155,-0.0010986,-0.0010376,-0.0011902,-0.0013123,-0.0014038,-0.0014954,-0.0016785,-0.0017700,-0.0019226,-0.0018616,-0.0021362,-0.0019531,-0.0020142,-0.0020142,-0.0018616,-0.0018616,-0.0017090,-0.0015259,-0.0014954,-0.0012512,-0.0011902,-0.0011292,-0.0010681,-0.0010071,-0.0010071,-0.0010681,-0.0011292,-0.0013123,-0.0013428,-0.0016174,-0.0015869,-0.0019836,-0.0018921,-0.0022278,-0.0021667,-0.0024414,-0.0022583,-0.0025024,-0.0023804,-0.0024414,-0.0022888,-0.0024414,-0.0020752,-0.0024109,-0.0020142,-0.0021667,-0.0020447,-0.0020142,-0.0020752,-0.0021667,-0.0021057,-0.0024109,-0.0022888,-0.0026245,-0.0026855,-0.0028687,-0.0029602,-0.0030518,-0.0032654,-0.0032654,-0.0033264,-0.0034180,-0.0031433,-0.0034180,-0.0029602,-0.0031433,-0.0028076,-0.0026855,-0.0025635,-0.0023804,-0.0022888,-0.0022278,-0.0020752,-0.0021667,-0.0021057,-0.0021973,-0.0022583,-0.0023193,-0.0025940,-0.0026550,-0.0028076,-0.0029907,-0.0029602,-0.0032043,-0.0030823,-0.0033569,-0.0028992,-0.0032654,-0.0027466,-0.0028992,-0.0026550,-0.0024109,-0.0023804,-0.0020752,-0.0021667,-0.0018921,-0.0019226,-0.0019836,-0.0018311,-0.0021057,-0.0018921,-0.0023499,-0.0021973,-0.0025940,-0.0023804,-0.0027161,-0.0026550,-0.0028076,-0.0026550,-0.0028076,-0.0024414,-0.0027161,-0.0021973,-0.0022583,-0.0020142,-0.0018921,-0.0016479,-0.0016479,-0.0013428,-0.0015259,-0.0011902,-0.0014343,-0.0012207,-0.0015259,-0.0014038,-0.0015869,-0.0016479,-0.0017700,-0.0018005,-0.0020447,-0.0018616,-0.0020447,-0.0019836,-0.0019226,-0.0018921,-0.0017700,-0.0014954,-0.0016785,-0.0011597,-0.0014038,-0.0009155,-0.0010376,-0.0007324,-0.0008240,-0.0006714,-0.0006409,-0.0006714,-0.0006714,-0.0006714,-0.0008240,-0.0007019,-0.0009155,-0.0008545,-0.0009766,-0.0010376,-0.0008545,-0.0011292,-0.0008240,-0.0009460,-0.0008850,-0.0006104,-0.0008545,-0.0003967,-0.0006104,-0.0003662,-0.0004578,-0.0001831,-0.0003662,-0.0000610,-0.0003662,-0.0002136,-0.0002747,-0.0004272,-0.0003662,-0.0004883,-0.0005798,-0.0005493,-0.0007324,-0.0007019,-0.0007935,-0.0007935,-0.0008545,-0.0007935,-0.0007935,-0.0007324,-0.0007629,-0.0006714,-0.0006714,-0.0005798,-0.0005493,-0.0006104,-0.0004883,-0.0005188,-0.0005493,-0.0006409,-0.0006409,-0.0007629,-0.0007019,-0.0009766,-0.0009460,-0.0010681,-0.0012207,-0.0010681,-0.0014038,-0.0011597,-0.0013733,-0.0012817,-0.0013123,-0.0012207,-0.0011902,-0.0011597,-0.0009155,-0.0011292,-0.0007324,-0.0009766,-0.0008545,-0.0006714,-0.0010071,-0.0005493,-0.0011292,-0.0007935,-0.0010986,-0.0011597,-0.0011292,-0.0014343,-0.0014648,-0.0014648,-0.0017395,-0.0016174,-0.0017395,-0.0017700,-0.0017700,-0.0018005,-0.0017395,-0.0017090,-0.0015869,-0.0016174,-0.0015259,-0.0015564,-0.0014954,-0.0014648,-0.0015564,-0.0014648,-0.0016785,-0.0015564,-0.0017700,-0.0017395,-0.0019531,-0.0019531,-0.0021362,-0.0021667,-0.0021362,-0.0025024,-0.0021973,-0.0025330,-0.0023193,-0.0024414,-0.0023193,-0.0025330,-0.0020752,-0.0025024,-0.0019531,-0.0022278,-0.0019836,-0.0019836,-0.0019226,-0.0018616,-0.0018005,-0.0019531,-0.0017395,-0.0019531,-0.0017700,-0.0019531,-0.0020447,-0.0019836,-0.0022278,-0.0020752,-0.0022888,-0.0022888,-0.0023499,-0.0022583,-0.0023499,-0.0021362,-0.0021667,-0.0019531,-0.0019531,-0.0017090,-0.0016479,-0.0014038,-0.0012817,-0.0011902,-0.0010986,-0.0009460,-0.0008545,-0.0009155,-0.0007629,-0.0009460,-0.0007935,-0.0010376,-0.0008240,-0.0011597,-0.0009155,-0.0013123,-0.0009155,-0.0013123,-0.0009460,-0.0011597,-0.0009766,-0.0008240,-0.0008545,-0.0006409,-0.0005188,-0.0005188,-0.0001831,-0.0002747,-0.0000305,0.0000000,0.0000305,0.0001526,0.0001221,0.0001831,0.0002441,0.0001221,0.0002747,0.0001831,0.0002136,0.0001526,0.0001831,0.0001831,0.0002441,0.0002747,0.0002136,0.0005493,0.0001526,0.0007324,0.0004578,0.0007324,0.0007324,0.0009155,0.0008850,0.0011597,0.0010681,0.0012817,0.0013123,0.0013733,0.0014343,0.0015564,0.0014954,0.0016785,0.0015869,0.0017090,0.0016785,0.0016785,0.0017090,0.0018005,0.0017395,0.0018616,0.0018616,0.0018921,0.0021362,0.0019226,0.0023193,0.0021057,0.0023804,0.0024719,0.0024109,0.0026550,0.0025635,0.0027466,0.0026855,0.0026855,0.0027771,0.0025635,0.0027161,0.0026550,0.0023804,0.0026245,0.0022583,0.0024414,0.0022583,0.0022278,0.0021973,0.0022278,0.0020752,0.0021973,0.0020752,0.0022888,0.0020752,0.0022583,0.0021667,0.0021057,0.0023193,0.0020447,0.0023193,0.0021362,0.0021667,0.0021667,0.0020447,0.0021973,0.0019531,0.0020752,0.0019531,0.0020447,0.0018311,0.0020142,0.0017395,0.0017700,0.0018311,0.0015259,0.0018005,0.0014648,0.0015259,0.0015259,0.0013428,0.0015564,0.0012207,0.0013733,0.0012512,0.0012207,0.0012512,0.0011597,0.0009766,0.0012207,0.0007935,0.0010986,0.0007324,0.0007324,0.0007324,0.0003357,0.0006409,0.0001526,0.0001831,0.0000916,-0.0002136,-0.0000305,-0.0003967,-0.0004272,-0.0004883,-0.0006409,-0.0007324,-0.0006714,-0.0010071,-0.0007629,-0.0011597,-0.0008545,-0.0010986,-0.0009766,-0.0010376,-0.0011292,-0.0008850,-0.0011292,-0.0009155,-0.0010986,-0.0009460,-0.0011597,-0.0008850,-0.0013428,-0.0009766,-0.0014038,-0.0013123,-0.0014343,-0.0016785,-0.0018311,-0.0017700,-0.0021667,-0.0020752,-0.0022583,-0.0023499,-0.0023193,-0.0022278,-0.0025024,-0.0020752,-0.0022583,-0.0020447,-0.0018616,-0.0019531,-0.0016785,-0.0017090,-0.0016479,-0.0015869,-0.0016785,-0.0015869,-0.0019226,-0.0017700,-0.0021667,-0.0020752,-0.0023804,-0.0024109,-0.0025635,-0.0026855,-0.0025940,-0.0027466,-0.0025635,-0.0024414,-0.0024109,-0.0020447,-0.0020447,-0.0015869,-0.0016479,-0.0011902,-0.0013733,-0.0009460,-0.0011292,-0.0008850,-0.0010681,-0.0010681,-0.0011902,-0.0013123,-0.0014954,-0.0015564,-0.0017395,-0.0017700,-0.0018311,-0.0018921,-0.0017395,-0.0019226,-0.0014648,-0.0016479,-0.0010986,-0.0013428,-0.0006714,-0.0009155,-0.0002747,-0.0003967,-0.0002136,-0.0000916,-0.0000916,-0.0000305,0.0000916,-0.0003662,0.0000305,-0.0005493,-0.0001526,-0.0007019,-0.0004272,-0.0007324,-0.0005798,-0.0006409,-0.0005188,-0.0004883,-0.0002747,-0.0001221,0.0000916,0.0003052,0.0005188,0.0007935,0.0009155,0.0012207,0.0012207,0.0015564,0.0014038,0.0017395,0.0014648,0.0017395,0.0014648,0.0017395,0.0013733,0.0016174,0.0013428,0.0014343,0.0014343,0.0014648,0.0015869,0.0016174,0.0017700,0.0018921,0.0020142,0.0022888,0.0024109,0.0025024,0.0027161,0.0027466,0.0029297,0.0029602,0.0029907,0.0029907,0.0029907,0.0028687,0.0029297,0.0027466,0.0028381,0.0026550,0.0027771,0.0027771,0.0028076,0.0028992,0.0028687,0.0031433,0.0030823,0.0034485,0.0032654,0.0037231,0.0035400,0.0038452,0.0037537,0.0038757,0.0037537,0.0039062,0.0036926,0.0038147,0.0036621,0.0035706,0.0036316,0.0034180,0.0036011,0.0035706,0.0034180,0.0037231,0.0034790,0.0038757,0.0036926,0.0039673,0.0039978,0.0039673,0.0042725,0.0039368,0.0043030,0.0040588,0.0040283,0.0040588,0.0038452,0.0038452,0.0037537,0.0035400,0.0036621,0.0032349,0.0036011,0.0030823,0.0035095,0.0031738,0.0033569,0.0032349,0.0033569,0.0032654,0.0035400,0.0032349,0.0036316,0.0033569,0.0035706,0.0033569,0.0035095,0.0032959,0.0035095,0.0031433,0.0033264,0.0031128,0.0030823,0.0031128,0.0029907,0.0029297,0.0029907,0.0028381,0.0028992,0.0029907,0.0027466,0.0029602,0.0027466,0.0029602,0.0027466,0.0028687,0.0026855,0.0026855,0.0026855,0.0025024,0.0025940,0.0022278,0.0024719,0.0020447,0.0024109,0.0018921,0.0022583,0.0019226,0.0020752,0.0020447,0.0020142,0.0021057,0.0021667,0.0021362,0.0022888,0.0022583,0.0023804,0.0022888,0.0023804,0.0023193,0.0023193,0.0023193,0.0022278,0.0021667,0.0021667,0.0020447,0.0019226,0.0019836,0.0018921,0.0018616,0.0019226,0.0018311,0.0019836,0.0019226,0.0020752,0.0020142,0.0022278,0.0021973,0.0023193,0.0021973,0.0025024,0.0021667,0.0025940,0.0021057,0.0024719,0.0021973,0.0022278,0.0023499,0.0019531,0.0023499,0.0019836,0.0021362,0.0022278,0.0020447,0.0022888,0.0021057,0.0022583,0.0022278,0.0022583,0.0022278,0.0022583,0.0022278,0.0021057,0.0021973,0.0019531,0.0021362,0.0018005,0.0019531,0.0017700,0.0018005,0.0016785,0.0017395,0.0016174,0.0017700,0.0016479,0.0018616,0.0016785,0.0019226,0.0018616,0.0019531,0.0020447,0.0019226,0.0020447,0.0019531,0.0020142,0.0019531,0.0019226,0.0018616,0.0018311,0.0018005,0.0017700,0.0016174,0.0017090,0.0016785,0.0016479,0.0016785,0.0016479,0.0017395,0.0017395,0.0018616,0.0018616,0.0019531,0.0020142,0.0020752,0.0021667,0.0021667,0.0022583,0.0022583,0.0022888,0.0022583,0.0022278,0.0022583,0.0021057,0.0021362,0.0020447,0.0019836,0.0018921,0.0019531,0.0016479,0.0019531,0.0016174,0.0019226,0.0017090,0.0018921,0.0018311,0.0020447,0.0019531,0.0022278,0.0021057,0.0023804,0.0023804,0.0024109,0.0024414,0.0024109,0.0024719,0.0022888,0.0023804,0.0020752,0.0021362,0.0019226,0.0018005,0.0016174,0.0016479,0.0013428,0.0015259,0.0012207,0.0013428,0.0011597,0.0014038,0.0011902,0.0013733,0.0013428,0.0013428,0.0015869,0.0013733,0.0015564,0.0015564,0.0014038,0.0016785,0.0012817,0.0014648,0.0012817,0.0012207,0.0011597,0.0010681,0.0008545,0.0008850,0.0007324,0.0006409,0.0006104,0.0004578,0.0004883,0.0003662,0.0003662,0.0003052,0.0003967,0.0001831,0.0004578,0.0000610,0.0004883,0.0000916,0.0003662,0.0000916,0.0002441,0.0000916,0.0001221,-0.0000305,-0.0000305,-0.0002136,-0.0001831,-0.0004883,-0.0003967,-0.0005798,-0.0007324,-0.0006104,-0.0010681,-0.0006409,-0.0011902,-0.0008240,-0.0010681,-0.0009460,-0.0010071,-0.0009155,-0.0009460,-0.0007629,-0.0007935,-0.0007324,-0.0006714,-0.0006409,-0.0006409,-0.0004883,-0.0006714,-0.0004883,-0.0006409,-0.0005493,-0.0007019,-0.0006104,-0.0007935,-0.0007019,-0.0009155,-0.0006714,-0.0009766,-0.0007019,-0.0009155,-0.0007019,-0.0008545,-0.0006409,-0.0007324,-0.0006104,-0.0004272,-0.0005798,-0.0002441,-0.0003052,-0.0001526,-0.0000610,0.0001526,0.0000000,0.0004272,0.0001831,0.0005188,0.0004578,0.0005493,0.0005493,0.0005798,0.0005798,0.0005798,0.0006409,0.0004578,0.0006409,0.0004578,0.0003967,0.0005188,0.0003662,0.0004883,0.0004883,0.0003967,0.0006714,0.0004883,0.0008850,0.0006714,0.0010376,0.0009460,0.0011902,0.0011292,0.0013428,0.0013428,0.0013428,0.0014343,0.0014343,0.0013733,0.0014954,0.0013428,0.0013733,0.0014648,0.0012512,0.0015564,0.0013733,0.0015259,0.0014343,0.0018311,0.0015259,0.0021057,0.0016174,0.0023193,0.0018921,0.0025024,0.0021667,0.0025330,0.0024109,0.0025024,0.0025635,0.0025330,0.0026245,0.0025024,0.0026245,0.0025024,0.0026550,0.0025024,0.0025940,0.0025940,0.0026550,0.0026245,0.0027466,0.0026245,0.0028076,0.0027466,0.0028076,0.0028381,0.0028381,0.0028687,0.0029907,0.0028076,0.0031738,0.0027161,0.0032654,0.0027771,0.0032654,0.0029297,0.0030823,0.0029907,0.0029907,0.0029602,0.0029907,0.0029297,0.0028076,0.0029602,0.0025940,0.0029602,0.0025330,0.0028687,0.0025330,0.0027771,0.0026550,0.0027161,0.0027466,0.0027466,0.0027466,0.0028687,0.0028381,0.0027466,0.0029602,0.0026550,0.0028687,0.0025330,0.0027161,0.0023804,0.0025330,0.0020752,0.0022278,0.0018921,0.0019836,0.0016785,0.0017395,0.0016479,0.0015259,0.0016479,0.0014648,0.0016174,0.0015259,0.0016785,0.0014954,0.0018005,0.0014648,0.0017700,0.0015259,0.0015564,0.0014648,0.0012817,0.0013428,0.0010071,0.0010681,0.0006409,0.0007629,0.0003967,0.0003357,0.0002747,0.0000000,0.0000916,-0.0001831,-0.0000916,-0.0002136,-0.0002441,-0.0001526,-0.0003967,-0.0001526,-0.0004272,-0.0003052,-0.0004578,-0.0005188,-0.0005188,-0.0007324,-0.0008545,-0.0008545,-0.0011902,-0.0011597,-0.0014038,-0.0014648,-0.0015869,-0.0017395,-0.0017090,-0.0019226,-0.0018005,-0.0019531,-0.0019226,-0.0019531,-0.0019531,-0.0020447,-0.0019531,-0.0020447,-0.0021973,-0.0020752,-0.0023499,-0.0023499,-0.0024109,-0.0026855,-0.0025330,-0.0029297,-0.0027771,-0.0030212,-0.0028687,-0.0030823,-0.0027771,-0.0030823,-0.0026550,-0.0028687,-0.0025330,-0.0026245,-0.0023804,-0.0024719,-0.0022278,-0.0024109,-0.0022888,-0.0023804,-0.0025635,-0.0025024,-0.0027161,-0.0028687,-0.0029602,-0.0032043,-0.0032349,-0.0034485,-0.0033569,-0.0036316,-0.0033264,-0.0035095,-0.0032959,-0.0032349,-0.0030823,-0.0028992,-0.0027466,-0.0025330,-0.0024719,-0.0022888,-0.0022583,-0.0020752,-0.0021973,-0.0019836,-0.0021973,-0.0020752,-0.0021362,-0.0022278,-0.0021973,-0.0022888,-0.0021667,-0.0023499,-0.0019836,-0.0022888,-0.0018005,-0.0020447,-0.0016785,-0.0016479,-0.0015259,-0.0012817,-0.0012817,-0.0010986,-0.0010071,-0.0008850,-0.0009460,-0.0007324,-0.0008850,-0.0007935,-0.0006104,-0.0009460,-0.0005188,-0.0009155,-0.0005188,-0.0007629,-0.0006409,-0.0006104,-0.0005493,-0.0005188,-0.0004272,-0.0003967,-0.0003357,-0.0002441,-0.0001526,-0.0001526,-0.0000305,0.0000000,0.0001221,0.0002136,0.0002747,0.0003357,0.0005493,0.0004883,0.0007019,0.0006104,0.0008240,0.0007935,0.0009155,0.0009460,0.0008850,0.0010681,0.0008850,0.0010986,0.0009766,0.0010681,0.0010376,0.0010986,0.0011597,0.0010681,0.0012817,0.0012207,0.0013733,0.0014343,0.0014954,0.0015869,0.0016174,0.0017700,0.0017090,0.0018311,0.0018311,0.0018921,0.0018005,0.0019531,0.0017700,0.0018616,0.0018921,0.0017090,0.0019531,0.0017700,0.0019531,0.0018311,0.0021057,0.0018921,0.0021667,0.0021667,0.0021667,0.0023193,0.0023193,0.0022583,0.0025330,0.0021973,0.0024719,0.0022278,0.0023193,0.0021667,0.0021362,0.0020752,0.0020752,0.0018616,0.0020447,0.0017090,0.0019836,0.0017090,0.0018005,0.0017395,0.0018005,0.0017090,0.0018005,0.0015869,0.0017090,0.0015259,0.0016174,0.0014038,0.0014343,0.0011902,0.0012207,0.0010986,0.0009766,0.0008545,0.0008545,0.0007019,0.0007019,0.0006409,0.0005493,0.0005493,0.0006714,0.0005188,0.0005798,0.0005798,0.0004883,0.0005493,0.0004578,0.0004883,0.0003357,0.0003967,0.0001831,0.0001221,0.0000305,-0.0000610,-0.0002441,-0.0001831,-0.0004883,-0.0003662,-0.0006409,-0.0005493,-0.0007019,-0.0006409,-0.0007324,-0.0007019,-0.0007935,-0.0005798,-0.0009155,-0.0005493,-0.0009155,-0.0006409,-0.0007935,-0.0009155,-0.0007324,-0.0011597,-0.0009766,-0.0011902,-0.0013123,-0.0012512,-0.0015869,-0.0016174,-0.0016785,-0.0018921,-0.0018616,-0.0020142,-0.0020142,-0.0021057,-0.0020447,-0.0021667,-0.0020447,-0.0020142,-0.0021057,-0.0018921,-0.0020447,-0.0018616,-0.0020142,-0.0018005,-0.0019531,-0.0018005,-0.0019226,-0.0018921,-0.0019531,-0.0020752,-0.0018616,-0.0023193,-0.0019226,-0.0023804,-0.0020447,-0.0024109,-0.0021667,-0.0024414,-0.0022278,-0.0022583,-0.0022278,-0.0021973,-0.0022583,-0.0020142,-0.0021667,-0.0020752,-0.0019226,-0.0021667,-0.0018005,-0.0021973,-0.0018921,-0.0021057,-0.0021667,-0.0021362,-0.0023193,-0.0022888,-0.0023804,-0.0024719,-0.0024719,-0.0025330,-0.0025330,-0.0025635,-0.0024414,-0.0025330,-0.0021973,-0.0023804,-0.0020752,-0.0020752,-0.0018921,-0.0017395,-0.0017090,-0.0015564,-0.0014954,-0.0014648,-0.0014038,-0.0014343,-0.0015869,-0.0014038,-0.0017395,-0.0016785,-0.0019226,-0.0019226,-0.0021057,-0.0021057,-0.0022583,-0.0021973,-0.0022888,-0.0022278,-0.0022278,-0.0021667,-0.0019836,-0.0019226,-0.0018311,-0.0015564,-0.0016174,-0.0013123,-0.0013123,-0.0012207,-0.0011902,-0.0012207,-0.0011597,-0.0012207,-0.0012817,-0.0014343,-0.0014343,-0.0016785,-0.0016174,-0.0017700,-0.0018921,-0.0018311,-0.0020142,-0.0018005,-0.0019836,-0.0017090,-0.0018005,-0.0016174,-0.0014343,-0.0014343,-0.0011902,-0.0010986,-0.0011292,-0.0007019,-0.0009766,-0.0007019,-0.0007935,-0.0007935,-0.0007629,-0.0008240,-0.0009460,-0.0009766,-0.0010986,-0.0010986,-0.0013733,-0.0011902,-0.0015259,-0.0013733,-0.0014954,-0.0014954,-0.0015259,-0.0013733,-0.0014954,-0.0012817,-0.0013428,-0.0013123,-0.0011902,-0.0012512,-0.0010986,-0.0011597,-0.0011597,-0.0011292,-0.0012817,-0.0012207,-0.0013428,-0.0014038,-0.0014038,-0.0015869,-0.0014648,-0.0017700,-0.0016479,-0.0017090,-0.0018311,-0.0017395,-0.0018921,-0.0017700,-0.0019226,-0.0018311,-0.0019226,-0.0017700,-0.0020142,-0.0017700,-0.0021057,-0.0018311,-0.0020752,-0.0019836,-0.0021667,-0.0020447,-0.0021667,-0.0022583,-0.0021057,-0.0023499,-0.0021057,-0.0024109,-0.0020752,-0.0023804,-0.0021057,-0.0022583,-0.0022583,-0.0021057,-0.0021973,-0.0021667,-0.0021667,-0.0022278,-0.0021667,-0.0022278,-0.0023193,-0.0021362,-0.0024719,-0.0021362,-0.0025330,-0.0023193,-0.0024414,-0.0024109,-0.0025330,-0.0024109,-0.0025635,-0.0024414,-0.0025330,-0.0023804,-0.0025940,-0.0023193,-0.0025330,-0.0024109,-0.0025024,-0.0023804,-0.0025940,-0.0023499,-0.0027161,-0.0023804,-0.0027771,-0.0024719,-0.0027771,-0.0025330,-0.0028076,-0.0025940,-0.0027466,-0.0026245,-0.0026245,-0.0025635,-0.0025024,-0.0024414,-0.0023499,-0.0022278,-0.0022888,-0.0021057,-0.0021362,-0.0021057,-0.0019531,-0.0021362,-0.0020142,-0.0020752,-0.0021362,-0.0021362,-0.0021667,-0.0023193,-0.0021973,-0.0023804,-0.0023499,-0.0023499,-0.0023193,-0.0022583,-0.0021973,-0.0021667,-0.0021362,-0.0018616,-0.0019226,-0.0017700,-0.0016479,-0.0016785,-0.0014954,-0.0015564,-0.0014343,-0.0015869,-0.0014343,-0.0015564,-0.0016479,-0.0015259,-0.0017395,-0.0016174,-0.0017090,-0.0015869,-0.0018005,-0.0014343,-0.0017090,-0.0012512,-0.0014343,-0.0011597,-0.0010986,-0.0010376,-0.0008545,-0.0009155,-0.0006409,-0.0008545,-0.0006409,-0.0007935,-0.0007629,-0.0007019,-0.0010071,-0.0007019,-0.0011902,-0.0007629,-0.0012512,-0.0007935,-0.0012817,-0.0007935,-0.0012207,-0.0007935,-0.0010071,-0.0007324,-0.0009460,-0.0005493,-0.0009460,-0.0005188,-0.0008240,-0.0006409,-0.0008240,-0.0007935,-0.0008850,-0.0010071,-0.0009155,-0.0011902,-0.0010986,-0.0011902,-0.0012512,-0.0011597,-0.0013428,-0.0010986,-0.0011902,-0.0010376,-0.0009460,-0.0009766,-0.0007019,-0.0008240,-0.0006714,-0.0005188,-0.0006714,-0.0004272,-0.0005493,-0.0005798,-0.0004272,-0.0006714,-0.0005188,-0.0006714,-0.0006714,-0.0006714,-0.0008240,-0.0006714,-0.0009460,-0.0006104,-0.0009766,-0.0006714,-0.0008545,-0.0008545,-0.0006714,-0.0009155,-0.0006104,-0.0008545,-0.0007019,-0.0008850,-0.0006714,-0.0009766,-0.0007629,-0.0010376,-0.0010071,-0.0010986,-0.0013123,-0.0012207,-0.0015259,-0.0014343,-0.0016785,-0.0016785,-0.0017395,-0.0018921,-0.0018921,-0.0019531,-0.0019531,-0.0019836,-0.0019531,-0.0021057,-0.0018311,-0.0021057,-0.0018616,-0.0019531,-0.0019836,-0.0018616,-0.0019836,-0.0018921,-0.0020447,-0.0018616,-0.0021667,-0.0018921,-0.0022583,-0.0019836,-0.0023499,-0.0020447,-0.0024109,-0.0021667,-0.0022888,-0.0022583,-0.0022278,-0.0022583,-0.0021667,-0.0021973,-0.0020752,-0.0021362,-0.0020142,-0.0019836,-0.0020142,-0.0018616,-0.0020142,-0.0018921,-0.0019226,-0.0020142,-0.0018921,-0.0020752,-0.0019836,-0.0020752,-0.0021667,-0.0020752,-0.0023193,-0.0020142,-0.0023499,-0.0020752,-0.0022278,-0.0021362,-0.0021057,-0.0019836,-0.0019531,-0.0018311,-0.0017700,-0.0016479,-0.0015259,-0.0014038,-0.0012817,-0.0013428,-0.0010376,-0.0012207,-0.0009460,-0.0010986,-0.0010681,-0.0010071,-0.0011292,-0.0010986,-0.0011597,-0.0012817,-0.0012207,-0.0013733,-0.0013123,-0.0014038,-0.0014954,-0.0013123,-0.0014343,-0.0013123,-0.0011902,-0.0012207,-0.0010071,-0.0008850,-0.0008850,-0.0005493,-0.0006714,-0.0004272,-0.0003662,-0.0004272,-0.0002441,-0.0003052,-0.0003357,-0.0003052,-0.0004578,-0.0003662,-0.0005493,-0.0005493,-0.0005798,-0.0006104,-0.0006104,-0.0005493,-0.0006104,-0.0003052,-0.0003357,0.0000000,-0.0000305,0.0003967,0.0004578,0.0007935,0.0009460,0.0011292,0.0014038,0.0014038,0.0016174,0.0016785,0.0016174,0.0016785,0.0015869,0.0014648,0.0015259,0.0012207,0.0012512,0.0010681,0.0011597,0.0008850,0.0010681,0.0009155,0.0011292,0.0011292,0.0013123,0.0014648,0.0015564,0.0018005,0.0019836,0.0021057,0.0023804,0.0022278,0.0025635,0.0022888,0.0025940,0.0022278,0.0023499,0.0020752,0.0019531,0.0018005,0.0015564,0.0014648,0.0012207,0.0012207,0.0009460,0.0011597,0.0008850,0.0012207,0.0010986,0.0013123,0.0014954,0.0015259,0.0018311,0.0018921,0.0020752,0.0021362,0.0022583,0.0022583,0.0022583,0.0021362,0.0021667,0.0018921,0.0019531,0.0015869,0.0014954,0.0013428,0.0011292,0.0011292,0.0008850,0.0009155,0.0009460,0.0007935,0.0011292,0.0008850,0.0012817,0.0012512,0.0013733,0.0015869,0.0015869,0.0017090,0.0017090,0.0017700,0.0016174,0.0015564,0.0014038,0.0011902,0.0010376,0.0008240,0.0005188,0.0003357,0.0000916,-0.0000305,-0.0003357,-0.0002136,-0.0005188,-0.0004272,-0.0003662,-0.0005188,-0.0000610,-0.0004272,0.0000610,-0.0000610,0.0000610,0.0002441,-0.0000610,0.0003357,-0.0001221,0.0000916,-0.0002441,-0.0004883,-0.0004578,-0.0010376,-0.0009460,-0.0014648,-0.0015564,-0.0017700,-0.0018921,-0.0020752,-0.0021057,-0.0021667,-0.0021362,-0.0021667,-0.0019836,-0.0020142,-0.0017700,-0.0017700,-0.0016174,-0.0015869,-0.0014954,-0.0015259,-0.0014038,-0.0016785,-0.0015564,-0.0018005,-0.0018311,-0.0019836,-0.0021973,-0.0022888,-0.0023804,-0.0025940,-0.0025330,-0.0027161,-0.0026245,-0.0026550,-0.0025024,-0.0025024,-0.0023193,-0.0021973,-0.0020752,-0.0018616,-0.0017090,-0.0016479,-0.0014343,-0.0014954,-0.0013123,-0.0014648,-0.0012207,-0.0015259,-0.0014343,-0.0015259,-0.0017395,-0.0017090,-0.0019226,-0.0018921,-0.0019531,-0.0020752,-0.0020142,-0.0020142,-0.0020142,-0.0019226,-0.0019226,-0.0018005,-0.0016479,-0.0016785,-0.0014954,-0.0015564,-0.0012817,-0.0015869,-0.0012512,-0.0014954,-0.0013733,-0.0014954,-0.0014343,-0.0016479,-0.0015259,-0.0017395,-0.0016479,-0.0017090,-0.0017090,-0.0016174,-0.0016479,-0.0014648,-0.0015564,-0.0012817,-0.0013123,-0.0010681,-0.0010071,-0.0009155,-0.0007629,-0.0008240,-0.0005798,-0.0006104,-0.0005798,-0.0003967,-0.0005493,-0.0002747,-0.0003662,-0.0003357,-0.0001221,-0.0001221,0.0001526,0.0000610,0.0003967,0.0003052,0.0007019,0.0005798,0.0009460,0.0008240,0.0011597,0.0010376,0.0011597,0.0012207,0.0012207,0.0012207,0.0012817,0.0011597,0.0013123,0.0010986,0.0013428,0.0012817,0.0012817,0.0014648,0.0014343,0.0016174,0.0017700,0.0017700,0.0020142,0.0020142,0.0022278,0.0021362,0.0024109,0.0022583,0.0024414,0.0022583,0.0023193,0.0023193,0.0021973,0.0022278,0.0021362,0.0021057,0.0022278,0.0020447,0.0022278,0.0022888,0.0022278,0.0025330,0.0024414,0.0027161,0.0026855,0.0029907,0.0028992,0.0031738,0.0030518,0.0032349,0.0032043,0.0032654,0.0031128,0.0031738,0.0031738,0.0029602,0.0031433,0.0027466,0.0030518,0.0027466,0.0028381,0.0028687,0.0027161,0.0030212,0.0028687,0.0029907,0.0030823,0.0029907,0.0033264,0.0031128,0.0034180,0.0032959,0.0032959,0.0033875,0.0032043,0.0033264,0.0031738,0.0031738,0.0030212,0.0029602,0.0029297,0.0028381,0.0028687,0.0027161,0.0028381,0.0027161,0.0028687,0.0028076,0.0028381,0.0029297,0.0028076,0.0030212,0.0028687,0.0029297,0.0029297,0.0026855,0.0028076,0.0025635,0.0025024,0.0024414,0.0021362,0.0022888,0.0019531,0.0020752,0.0018005,0.0018921,0.0019226,0.0018005,0.0020752,0.0018005,0.0023193,0.0020142,0.0024109,0.0021973,0.0024414,0.0024414,0.0024719,0.0024719,0.0024109,0.0022583,0.0024109,0.0019226,0.0022278,0.0018005,0.0018616,0.0017700,0.0015869,0.0016479,0.0016785,0.0015869,0.0018005,0.0017700,0.0019836,0.0020752,0.0021667,0.0022888,0.0024109,0.0025940,0.0025330,0.0027161,0.0025330,0.0027466,0.0025024,0.0025330,0.0023804,0.0023499,0.0021362,0.0021973,0.0019226,0.0019836,0.0017395,0.0018921,0.0016479,0.0019226,0.0017395,0.0018921,0.0018921,0.0019226,0.0020447,0.0020752,0.0022278,0.0019836,0.0024109,0.0018616,0.0024414,0.0018616,0.0022278,0.0018005,0.0020447,0.0016785,0.0017700,0.0016479,0.0014954,0.0016174,0.0013733,0.0015869,0.0013428,0.0016479,0.0013733,0.0017090,0.0015259,0.0017395,0.0018005,0.0017395,0.0019226,0.0018616,0.0019226,0.0020142,0.0019836,0.0019531,0.0020752,0.0019531,0.0019836,0.0020142,0.0019531,0.0018921,0.0020752,0.0018005,0.0020142,0.0018311,0.0019531,0.0019226,0.0018616,0.0019226,0.0018311,0.0018921,0.0019836,0.0017700,0.0019531,0.0018616,0.0018311,0.0020142,0.0017700,0.0019226,0.0018616,0.0019836,0.0018311,0.0021057,0.0018616,0.0021057,0.0019836,0.0020752,0.0020447,0.0021057,0.0020752,0.0020752,0.0021362,0.0019531,0.0020752,0.0019531,0.0019531,0.0019531,0.0018921,0.0018311,0.0019836,0.0017700,0.0020447,0.0017700,0.0021362,0.0019226,0.0021362,0.0020447,0.0020447,0.0022888,0.0019531,0.0022583,0.0018616,0.0021362,0.0018921,0.0018616,0.0017395,0.0015564,0.0015259,0.0014038,0.0013123,0.0012512,0.0011597,0.0011292,0.0012207,0.0010681,0.0012512,0.0012512,0.0013123,0.0014648,0.0014038,0.0015869,0.0016174,0.0016174,0.0017090,0.0016479,0.0016479,0.0016174,0.0014343,0.0014343,0.0013428,0.0010376,0.0011597,0.0007935,0.0008240,0.0006714,0.0005188,0.0005188,0.0005188,0.0003357,0.0004883,0.0003967,0.0004272,0.0006104,0.0004272,0.0007019,0.0004883,0.0007629,0.0005493,0.0008240,0.0004883,0.0007629,0.0003662,0.0007019,0.0001526,0.0003967,0.0000000,0.0000610,-0.0001221,-0.0003357,-0.0003052,-0.0006104,-0.0004883,-0.0007019,-0.0006714,-0.0006714,-0.0008240,-0.0005798,-0.0007935,-0.0005188,-0.0006409,-0.0003662,-0.0004883,-0.0002441,-0.0003662,-0.0002441,-0.0002441,-0.0002441,-0.0003967,-0.0003052,-0.0005798,-0.0004578,-0.0007935,-0.0007629,-0.0009460,-0.0010071,-0.0011597,-0.0010376,-0.0013733,-0.0010986,-0.0012817,-0.0011597,-0.0010071,-0.0010681,-0.0008240,-0.0008850,-0.0005188,-0.0006409,-0.0002747,-0.0004272,-0.0001526,-0.0001831,-0.0002441,0.0000610,-0.0003357,0.0000610,-0.0003052,-0.0001526,-0.0002136,-0.0002747,-0.0001526,-0.0002747,-0.0002441,-0.0000916,-0.0001221,0.0000916,0.0000000,0.0003357,0.0002136,0.0006104,0.0003967,0.0007935,0.0006714,0.0008545,0.0008545,0.0010071,0.0008240,0.0011597,0.0007629,0.0011292,0.0007629,0.0010681,0.0008545,0.0009766,0.0009766,0.0009766,0.0010376,0.0010986,0.0012207,0.0011292,0.0014038,0.0013428,0.0014343,0.0016785,0.0014648,0.0018311,0.0016174,0.0018005,0.0017395,0.0018616,0.0017700,0.0018921,0.0017090,0.0018921,0.0017090,0.0019226,0.0017700,0.0019531,0.0017395,0.0021057,0.0018616,0.0021057,0.0020447,0.0022278,0.0022583,0.0023193,0.0024109,0.0023193,0.0025940,0.0024719,0.0026245,0.0025940,0.0025940,0.0026550,0.0026855,0.0027161,0.0027161,0.0027161,0.0027161,0.0027466,0.0028687,0.0027771,0.0029297,0.0028687,0.0028992,0.0030212,0.0029297,0.0030518,0.0029602,0.0030518,0.0029297,0.0030823,0.0028381,0.0030212,0.0028076,0.0029297,0.0027466,0.0028076,0.0027466,0.0027161,0.0027161,0.0026245,0.0027161,0.0026855,0.0026550,0.0027771,0.0026550,0.0028076,0.0027466,0.0027466,0.0028687,0.0028076,0.0028687,0.0028076,0.0028381,0.0027771,0.0028381,0.0026550,0.0027466,0.0025940,0.0025330,0.0024414,0.0024414,0.0021667,0.0023499,0.0019531,0.0021973,0.0018311,0.0019836,0.0017395,0.0018921,0.0016174,0.0018311,0.0014648,0.0018005,0.0014038,0.0016479,0.0013428,0.0015259,0.0012817,0.0012817,0.0012512,0.0010986,0.0010986,0.0008850,0.0008850,0.0007019,0.0006714,0.0004272,0.0005493,0.0002441,0.0002441,0.0002136,0.0000000,0.0001221,-0.0000305,0.0000305,-0.0001831,-0.0000305,-0.0002747,-0.0001221,-0.0003052,-0.0003662,-0.0003357,-0.0005798,-0.0004883,-0.0006714,-0.0007935,-0.0008545,-0.0010376,-0.0010681,-0.0011597,-0.0014038,-0.0012512,-0.0015869,-0.0014038,-0.0017395,-0.0015259,-0.0017700,-0.0015564,-0.0017090,-0.0017700,-0.0016785,-0.0018005,-0.0017090,-0.0018311,-0.0018005,-0.0019531,-0.0019531,-0.0019531,-0.0021667,-0.0020752,-0.0022888,-0.0022278,-0.0023804,-0.0023193,-0.0024109,-0.0024719,-0.0023193,-0.0025024,-0.0023193,-0.0024109,-0.0023193,-0.0021973,-0.0023499,-0.0020447,-0.0023499,-0.0019226,-0.0023193,-0.0018921,-0.0023193,-0.0020447,-0.0021973,-0.0022278,-0.0021667,-0.0024414,-0.0020752,-0.0025940,-0.0021362,-0.0025330,-0.0022583,-0.0023804,-0.0023804,-0.0022888,-0.0022583,-0.0022583,-0.0021362,-0.0022583,-0.0020142,-0.0021057,-0.0019836,-0.0020752,-0.0018921,-0.0021362,-0.0018005,-0.0020142,-0.0018311,-0.0019531,-0.0018921,-0.0018311,-0.0018921,-0.0016174,-0.0018616,-0.0014954,-0.0017090,-0.0013733,-0.0014343,-0.0012512,-0.0012512,-0.0011597,-0.0011292,-0.0009766,-0.0010376,-0.0009155,-0.0010376,-0.0008240,-0.0010071,-0.0007019,-0.0010986,-0.0006714,-0.0010071,-0.0006409,-0.0008850,-0.0006104,-0.0006409,-0.0004883,-0.0003967,-0.0002441,-0.0001221,0.0000000,0.0001526,0.0002747,0.0003967,0.0005798,0.0005188,0.0007324,0.0007324,0.0007324,0.0008240,0.0007324,0.0007629,0.0007324,0.0006714,0.0006104,0.0005493,0.0006104,0.0003967,0.0005798,0.0005493,0.0004272,0.0007629,0.0004883,0.0008545,0.0007935,0.0010376,0.0010376,0.0013123,0.0013123,0.0015259,0.0015869,0.0017090,0.0018005,0.0018311,0.0019836,0.0018311,0.0020447,0.0019226,0.0018311,0.0019531,0.0017395,0.0018005,0.0017700,0.0016174,0.0017090,0.0015869,0.0017395,0.0015564,0.0017090,0.0017395,0.0018616,0.0018616,0.0021362,0.0019226,0.0023193,0.0021667,0.0023804,0.0023193,0.0024109,0.0023193,0.0023499,0.0022888,0.0022278,0.0020752,0.0020447,0.0018921,0.0017700,0.0017090,0.0014954,0.0015564,0.0013733,0.0013428,0.0013733,0.0012817,0.0014038,0.0012817,0.0014954,0.0013123,0.0015869,0.0014648,0.0015869,0.0015869,0.0015564,0.0015259,0.0014954,0.0014954,0.0013123,0.0013733,0.0010986,0.0011292,0.0008850,0.0009460,0.0006409,0.0007629,0.0005188,0.0005493,0.0003967,0.0004883,0.0003357,0.0004883,0.0003357,0.0004883,0.0003662,0.0005188,0.0004578,0.0004578,0.0004883,0.0004883,0.0003662,0.0004272,0.0002441,0.0001526,0.0002747,-0.0000916,0.0000305,-0.0002441,-0.0003357,-0.0003357,-0.0005798,-0.0005493,-0.0007019,-0.0007324,-0.0008240,-0.0008240,-0.0007935,-0.0009460,-0.0007324,-0.0009460,-0.0006104,-0.0008850,-0.0006714,-0.0007935,-0.0007629,-0.0006714,-0.0008240,-0.0007324,-0.0009460,-0.0009155,-0.0009766,-0.0011292,-0.0011597,-0.0013123,-0.0013733,-0.0014343,-0.0014954,-0.0016174,-0.0015564,-0.0016785,-0.0016785,-0.0015564,-0.0017700,-0.0013733,-0.0017395,-0.0013428,-0.0016785,-0.0013733,-0.0014954,-0.0014343,-0.0014343,-0.0014954,-0.0014954,-0.0014648,-0.0015869,-0.0015869,-0.0016479,-0.0017090,-0.0017090,-0.0018005,-0.0018005,-0.0018311,-0.0018921,-0.0017700,-0.0019836,-0.0017395,-0.0019836,-0.0016479,-0.0018616,-0.0017090,-0.0017700,-0.0017090,-0.0015869,-0.0017700,-0.0015259,-0.0017395,-0.0015259,-0.0015869,-0.0015869,-0.0015259,-0.0015564,-0.0014954,-0.0014954,-0.0014343,-0.0014648,-0.0013428,-0.0014648,-0.0012817,-0.0014343,-0.0013123,-0.0014038,-0.0013428,-0.0013733,-0.0014038,-0.0013733,-0.0015259,-0.0013428,-0.0016785,-0.0013733,-0.0016785,-0.0015564,-0.0015564,-0.0016479,-0.0014954,-0.0016174,-0.0014954,-0.0014954,-0.0014954,-0.0013428,-0.0013733,-0.0012817,-0.0012512,-0.0012207,-0.0011597,-0.0012207,-0.0010986,-0.0011902,-0.0011902,-0.0010986,-0.0013428,-0.0011597,-0.0013733,-0.0011902,-0.0013428,-0.0012817,-0.0012817,-0.0014038,-0.0010986,-0.0014038,-0.0010376,-0.0011902,-0.0010986,-0.0008545,-0.0010681,-0.0008545,-0.0008545,-0.0009766,-0.0006714,-0.0010376,-0.0007629,-0.0010681,-0.0010071,-0.0010071,-0.0011597,-0.0011902,-0.0011902,-0.0014038,-0.0011902,-0.0014343,-0.0011902,-0.0013733,-0.0012817,-0.0011597,-0.0012817,-0.0010376,-0.0011292,-0.0010071,-0.0009766,-0.0010071,-0.0009460,-0.0011292,-0.0009155,-0.0012512,-0.0010986,-0.0013123,-0.0013123,-0.0014954,-0.0014648,-0.0017700,-0.0015869,-0.0018311,-0.0018005,-0.0018005,-0.0019226,-0.0017700,-0.0019226,-0.0017090,-0.0018311,-0.0016785,-0.0016479,-0.0016785,-0.0015564,-0.0015869,-0.0016174,-0.0015259,-0.0015869,-0.0016174,-0.0016479,-0.0016785,-0.0017395,-0.0018005,-0.0018005,-0.0019836,-0.0019226,-0.0021057,-0.0020752,-0.0020447,-0.0021973,-0.0020752,-0.0022278,-0.0021057,-0.0021362,-0.0021362,-0.0020447,-0.0021667,-0.0019531,-0.0022278,-0.0018616,-0.0021973,-0.0019836,-0.0020447,-0.0021362,-0.0021362,-0.0020142,-0.0022888,-0.0020752,-0.0023193,-0.0022888,-0.0021667,-0.0024414,-0.0021667,-0.0022888,-0.0023193,-0.0021667,-0.0023193,-0.0021362,-0.0021667,-0.0021057,-0.0021362,-0.0019836,-0.0021362,-0.0020447,-0.0019836,-0.0021973,-0.0019531,-0.0022888,-0.0019836,-0.0022888,-0.0020447,-0.0023193,-0.0021667,-0.0022278,-0.0021362,-0.0021667,-0.0020752,-0.0020752,-0.0018921,-0.0019531,-0.0017395,-0.0018616,-0.0016174,-0.0016785,-0.0016479,-0.0015564,-0.0016785,-0.0016479,-0.0017090,-0.0017700,-0.0018616,-0.0017700,-0.0021057,-0.0018921,-0.0020447,-0.0019836,-0.0020447,-0.0019226,-0.0020447,-0.0016785,-0.0018616,-0.0015259,-0.0016174,-0.0013123,-0.0012817,-0.0011597,-0.0009766,-0.0010071,-0.0008240,-0.0009155,-0.0007935,-0.0009460,-0.0007629,-0.0010681,-0.0009155,-0.0011292,-0.0011292,-0.0012207,-0.0012817,-0.0013123,-0.0012207,-0.0014343,-0.0011597,-0.0013123,-0.0011597,-0.0009460,-0.0010681,-0.0007019,-0.0007935,-0.0005188,-0.0005493,-0.0003662,-0.0002441,-0.0003052,-0.0001221,-0.0002747,-0.0001526,-0.0003052,-0.0002441,-0.0004272,-0.0004272,-0.0005798,-0.0005798,-0.0006409,-0.0007629,-0.0007019,-0.0007935,-0.0007324,-0.0007019,-0.0007935,-0.0004883,-0.0007019,-0.0003357,-0.0006104,-0.0001831,-0.0004578,-0.0000610,-0.0004272,-0.0000610,-0.0004272,-0.0001526,-0.0004272,-0.0003662,-0.0005188,-0.0006104,-0.0007019,-0.0007019,-0.0007935,-0.0008545,-0.0007935,-0.0010986,-0.0007019,-0.0010071,-0.0007324,-0.0007324,-0.0007629,-0.0006714,-0.0005493,-0.0007324,-0.0004272,-0.0006714,-0.0005188,-0.0005493,-0.0006714,-0.0007019,-0.0006714,-0.0010071,-0.0008850,-0.0010376,-0.0012207,-0.0011292,-0.0013733,-0.0014343,-0.0013733,-0.0016174,-0.0014038,-0.0017090,-0.0014038,-0.0016785,-0.0014038,-0.0014954,-0.0014343,-0.0013123,-0.0014343,-0.0011597,-0.0013428,-0.0011597,-0.0012817,-0.0011292,-0.0012512,-0.0013123,-0.0012512,-0.0014954,-0.0013733,-0.0016479,-0.0015869,-0.0018616,-0.0017700,-0.0021057,-0.0019531,-0.0021057,-0.0021362,-0.0021362,-0.0021667,-0.0021667,-0.0019836,-0.0021973,-0.0018616,-0.0020447,-0.0018005,-0.0018616,-0.0017700,-0.0018311,-0.0018005,-0.0018005,-0.0019226,-0.0018616,-0.0021057,-0.0021362,-0.0021362,-0.0024109,-0.0022583,-0.0025635,-0.0024414,-0.0025330,-0.0025940,-0.0024109,-0.0024719,-0.0023193,-0.0021973,-0.0021667,-0.0019531,-0.0019836,-0.0017090,-0.0018311,-0.0015259,-0.0016785,-0.0015564,-0.0014954,-0.0017395,-0.0014343,-0.0018921,-0.0015259,-0.0019531,-0.0017090,-0.0019226,-0.0018616,-0.0019226,-0.0019226,-0.0018921,-0.0018616,-0.0017395,-0.0018311,-0.0015869,-0.0017395,-0.0014038,-0.0014954,-0.0014038,-0.0011902,-0.0013428,-0.0011292,-0.0011292,-0.0011902,-0.0009460,-0.0012207,-0.0009155,-0.0012207,-0.0010071,-0.0012207,-0.0011292,-0.0012207,-0.0011902,-0.0012207,-0.0012512,-0.0012817,-0.0012207,-0.0012512,-0.0011292,-0.0010376,-0.0010986,-0.0008545,-0.0008240,-0.0006714,-0.0005188,-0.0003357,-0.0003662,-0.0000305,-0.0001526,0.0000610,0.0001526,0.0001221,0.0001526,0.0001526,0.0001831,0.0000305,0.0001831,-0.0000916,0.0000610,-0.0000610,-0.0000305,-0.0000305,0.0000305,0.0000916,0.0001831,0.0002441,0.0004883,0.0004272,0.0008240,0.0007019,0.0010681,0.0010986,0.0010681,0.0013733,0.0010986,0.0015259,0.0010986,0.0014038,0.0011292,0.0012817,0.0010986,0.0011292,0.0010071,0.0012207,0.0009155,0.0013123,0.0009766,0.0013428,0.0012207,0.0014038,0.0014343,0.0015869,0.0015259,0.0018311,0.0015869,0.0019226,0.0016479,0.0019836,0.0016174,0.0018311,0.0017700,0.0016174,0.0018311,0.0015564,0.0018005,0.0015564,0.0017395,0.0017090,0.0017090,0.0018311,0.0017395,0.0018311,0.0018616,0.0018005,0.0018921,0.0017395,0.0017700,0.0016785,0.0015564,0.0014954,0.0014343,0.0011902,0.0013123,0.0010071,0.0010986,0.0008850,0.0009155,0.0007935,0.0009460,0.0008240,0.0009155,0.0008545,0.0008545,0.0010681,0.0009155,0.0010681,0.0009766,0.0010071,0.0009460,0.0009155,0.0007629,0.0007629,0.0006714,0.0004578,0.0004578,0.0002747,0.0001526,0.0001526,0.0000000,-0.0000610,-0.0000305,-0.0002747,-0.0000305,-0.0002441,-0.0000916,-0.0001526,-0.0001526,-0.0001526,-0.0001221,-0.0003052,-0.0001526,-0.0003662,-0.0003662,-0.0004272,-0.0007324,-0.0006104,-0.0009155,-0.0010071,-0.0010681,-0.0013123,-0.0013123,-0.0014343,-0.0015869,-0.0015564,-0.0016785,-0.0016174,-0.0016174,-0.0016479,-0.0015259,-0.0016174,-0.0015564,-0.0014648,-0.0015259,-0.0014343,-0.0014954,-0.0014648,-0.0015259,-0.0015259,-0.0015869,-0.0016174,-0.0017395,-0.0017700,-0.0018921,-0.0018005,-0.0020447,-0.0018921,-0.0022278,-0.0019531,-0.0021362,-0.0021057,-0.0019531,-0.0021362,-0.0020447,-0.0018311,-0.0021362,-0.0016479,-0.0021057,-0.0016785,-0.0018616,-0.0018921,-0.0017700,-0.0019531,-0.0018616,-0.0019531,-0.0021057,-0.0019836,-0.0022278,-0.0021667,-0.0023193,-0.0023193,-0.0024109,-0.0022888,-0.0024414,-0.0021973,-0.0024414,-0.0021667,-0.0021973,-0.0020142,-0.0020142,-0.0018311,-0.0018616,-0.0016174,-0.0017090,-0.0014648,-0.0016174,-0.0014343,-0.0015564,-0.0014954,-0.0015259,-0.0015564,-0.0016785,-0.0016479,-0.0016479,-0.0017090,-0.0016785,-0.0016785,-0.0016785,-0.0014954,-0.0015259,-0.0014343,-0.0010986,-0.0013123,-0.0007935,-0.0009766,-0.0007935,-0.0005188,-0.0006409,-0.0003967,-0.0004578,-0.0003967,-0.0003357,-0.0004578,-0.0004272,-0.0003967,-0.0004578,-0.0004883,-0.0003967,-0.0005493,-0.0003357,-0.0003662,-0.0002747,-0.0001526,0.0000000,0.0000916,0.0001831,0.0004578,0.0003967,0.0008545,0.0006104,0.0009766,0.0008850,0.0010681,0.0010681,0.0010986,0.0010681,0.0011902,0.0010681,0.0011597,0.0010681,0.0011597,0.0011597,0.0012512,0.0011902,0.0013733,0.0013123,0.0015259,0.0014648,0.0016785,0.0016174,0.0017700,0.0018005,0.0017700,0.0019836,0.0017700,0.0019531,0.0018616,0.0018616,0.0018921,0.0018311,0.0018311,0.0019226,0.0018005,0.0019531,0.0018921,0.0020142,0.0021057,0.0020447,0.0023804,0.0021057,0.0025635,0.0023193,0.0025635,0.0025024,0.0025940,0.0025330,0.0025940,0.0025940,0.0024109,0.0025635,0.0023499,0.0024109,0.0022583,0.0023804,0.0021973,0.0023499,0.0022583,0.0023499,0.0022888,0.0025024,0.0022888,0.0026550,0.0024109,0.0026855,0.0025940,0.0026855,0.0027466,0.0025330,0.0026550,0.0025330,0.0025330,0.0024414,0.0023193,0.0022278,0.0020447,0.0021973,0.0018005,0.0020752,0.0017395,0.0018311,0.0018005,0.0017395,0.0019226,0.0016174,0.0019836,0.0016785,0.0019836,0.0018921,0.0017700,0.0019531,0.0018311,0.0018005,0.0019226,0.0015564,0.0019226,0.0015259,0.0017700,0.0015259,0.0016174,0.0015564,0.0015869,0.0015869,0.0015564,0.0015869,0.0016174,0.0015869,0.0016785,0.0016174,0.0017395,0.0015869,0.0018311,0.0014954,0.0018616,0.0015564,0.0017395,0.0015869,0.0016479,0.0016785,0.0015259,0.0017395,0.0014954,0.0017700,0.0015869,0.0017395,0.0016479,0.0018311,0.0017090,0.0018311,0.0017395,0.0018311,0.0018005,0.0018921,0.0017090,0.0019226,0.0016174,0.0018921,0.0015564,0.0018005,0.0016479,0.0016479,0.0017700,0.0014954,0.0018921,0.0015869,0.0017395,0.0018005,0.0016785,0.0019836,0.0016785,0.0019836,0.0017700,0.0018921,0.0018921,0.0017700,0.0018616,0.0017700,0.0016479,0.0017700,0.0014038,0.0016785,0.0013733,0.0014954,0.0013123,0.0014038,0.0012817,0.0014038,0.0012512,0.0014954,0.0014038,0.0015259,0.0016174,0.0016479,0.0018616,0.0018005,0.0020142,0.0019836,0.0021057,0.0020752,0.0021057,0.0021362,0.0019531,0.0021057,0.0018311,0.0019836,0.0016785,0.0017700,0.0015259,0.0016479,0.0014648,0.0014648,0.0015564,0.0014343,0.0016174,0.0014954,0.0017090,0.0017090,0.0018005,0.0018311,0.0019836,0.0018921,0.0021057,0.0019531,0.0020142,0.0019531,0.0019531,0.0018311,0.0018005,0.0017700,0.0015259,0.0017395,0.0013428,0.0016174,0.0013123,0.0015869,0.0013428,0.0014954,0.0014954,0.0016174,0.0016174,0.0017395,0.0017395,0.0018311,0.0019531,0.0018616,0.0020142,0.0019531,0.0019226,0.0020142,0.0018921,0.0018311,0.0019226,0.0018005,0.0017700,0.0018311,0.0015869,0.0017700,0.0016479,0.0017090,0.0016479,0.0016785,0.0018005,0.0015259,0.0019226,0.0015869,0.0018311,0.0017395,0.0017090,0.0018005,0.0017090,0.0017700,0.0017090,0.0016785,0.0016785,0.0016174,0.0016174,0.0015259,0.0015564,0.0014343,0.0014648,0.0012817,0.0013428,0.0011902,0.0011902,0.0010986,0.0009155,0.0010986,0.0007324,0.0009155,0.0006104,0.0007935,0.0005188,0.0005493,0.0005188,0.0004272,0.0004578,0.0003662,0.0002441,0.0004272,0.0001221,0.0004272,0.0000610,0.0002441,0.0000305,0.0001221,-0.0001526,0.0001526,-0.0003967,0.0000305,-0.0004578,-0.0002441,-0.0004272,-0.0004578,-0.0005188,-0.0005188,-0.0005493,-0.0005798,-0.0005493,-0.0005188,-0.0006104,-0.0003967,-0.0005493,-0.0003662,-0.0003662,-0.0004272,-0.0002441,-0.0003357,-0.0002747,-0.0003052,-0.0003052,-0.0003967,-0.0003967,-0.0005493,-0.0005188,-0.0006714,-0.0006409,-0.0008240,-0.0007935,-0.0009155,-0.0009766,-0.0007935,-0.0011292,-0.0007019,-0.0010071,-0.0006409,-0.0008240,-0.0004578,-0.0006714,-0.0002136,-0.0004272,-0.0002441,-0.0001221,-0.0001831,-0.0000610,-0.0001221,-0.0001526,-0.0001526,-0.0002747,-0.0002441,-0.0004272,-0.0004883,-0.0005493,-0.0007324,-0.0005798,-0.0007935,-0.0006104,-0.0008545,-0.0005188,-0.0007629,-0.0003967,-0.0004578,-0.0003052,-0.0002136,0.0000305,0.0000000,0.0003662,0.0001221,0.0004578,0.0003967,0.0004883,0.0005188,0.0004578,0.0005493,0.0003967,0.0004883,0.0004272,0.0002441,0.0004578,0.0001831,0.0003357,0.0002747,0.0002441,0.0004578,0.0003052,0.0005798,0.0006104,0.0007019,0.0008850,0.0009460,0.0011292,0.0012817,0.0012817,0.0014954,0.0014954,0.0016174,0.0017090,0.0016174,0.0017090,0.0016785,0.0016785,0.0015869,0.0016785,0.0014648,0.0017090,0.0014648,0.0016174,0.0015564,0.0015869,0.0017700,0.0017090,0.0018616,0.0019836,0.0020142,0.0022278,0.0021973,0.0023804,0.0024414,0.0025024,0.0025940,0.0025635,0.0025940,0.0025635,0.0025635,0.0024109,0.0025024,0.0022888,0.0023499,0.0022278,0.0021362,0.0021973,0.0020447,0.0021973,0.0020752,0.0021973,0.0022583,0.0022583,0.0024719,0.0023193,0.0026245,0.0025330,0.0027161,0.0027466,0.0026855,0.0028687,0.0027466,0.0027771,0.0026855,0.0027771,0.0025635,0.0027466,0.0023804,0.0025940,0.0023499,0.0024719,0.0023499,0.0023804,0.0024109,0.0023193,0.0025635,0.0023193,0.0026245,0.0024414,0.0025330,0.0026245,0.0023499,0.0026855,0.0023499,0.0023499,0.0023804,0.0020447,0.0021057,0.0019226,0.0017700,0.0016785,0.0014648,0.0014343,0.0013123,0.0011902,0.0012512,0.0010376,0.0012512,0.0010986,0.0011597,0.0012207,0.0011597,0.0013428,0.0011902,0.0012817,0.0012817,0.0012207,0.0010681,0.0012512,0.0008545,0.0009460,0.0006714,0.0004883,0.0004883,0.0001526,0.0002136,-0.0001221,0.0000000,-0.0003052,-0.0002441,-0.0003662,-0.0002441,-0.0003967,-0.0001831,-0.0003662,-0.0002136,-0.0002441,-0.0002136,-0.0001831,-0.0002441,-0.0003052,-0.0003662,-0.0004578,-0.0006714,-0.0007019,-0.0010071,-0.0009766,-0.0013123,-0.0013428,-0.0015564,-0.0015869,-0.0016785,-0.0017700,-0.0017395,-0.0017395,-0.0017090,-0.0015869,-0.0015869,-0.0014343,-0.0014648,-0.0011902,-0.0014038,-0.0010681,-0.0013733,-0.0011292,-0.0013123,-0.0013733,-0.0014343,-0.0016479,-0.0016174,-0.0019836,-0.0018005,-0.0023193,-0.0019226,-0.0024414,-0.0022583,-0.0023193,-0.0024414,-0.0021057,-0.0023804,-0.0019836,-0.0022278,-0.0018005,-0.0018921,-0.0017090,-0.0016479,-0.0016174,-0.0015259,-0.0015259,-0.0015259,-0.0015564,-0.0016174,-0.0016785,-0.0017700,-0.0017700,-0.0020447,-0.0018311,-0.0022888,-0.0018921,-0.0023804,-0.0020142,-0.0022888,-0.0021057,-0.0021057,-0.0021057,-0.0019531,-0.0019226,-0.0017395,-0.0016785,-0.0016785,-0.0014343,-0.0015564,-0.0011597,-0.0014038,-0.0011597,-0.0012207,-0.0011597,-0.0011292,-0.0010681,-0.0011597,-0.0010376,-0.0011902,-0.0010681,-0.0010986,-0.0011292,-0.0010376,-0.0010681,-0.0010376,-0.0008240,-0.0009460,-0.0006409,-0.0008850,-0.0004272,-0.0006409,-0.0002747,-0.0003967,-0.0001831,-0.0000916,-0.0001831,0.0001831,-0.0000916,0.0002441,0.0000610,0.0002441,0.0000916,0.0003052,0.0000000,0.0002441,0.0001831,0.0000610,0.0003662,-0.0001221,0.0004272,0.0000305,0.0003662,0.0003052,0.0003357,0.0005493,0.0005188,0.0007019,0.0007629,0.0008545,0.0009460,0.0010681,0.0010681,0.0011597,0.0012207,0.0011902,0.0013123,0.0011597,0.0013123,0.0010986,0.0012512,0.0011902,0.0010071,0.0012512,0.0009155,0.0012207,0.0009766,0.0011597,0.0010986,0.0012207,0.0012207,0.0013123,0.0013733,0.0014648,0.0014648,0.0016479,0.0016174,0.0018005,0.0016785,0.0018921,0.0017700,0.0018616,0.0018616,0.0017395,0.0018921,0.0016785,0.0017395,0.0016785,0.0016479,0.0015869,0.0015869,0.0014343,0.0015869,0.0013428,0.0015564,0.0013428,0.0015259,0.0015259,0.0014343,0.0015564,0.0015869,0.0014954,0.0017090,0.0015564,0.0016174,0.0016785,0.0014038,0.0017700,0.0012817,0.0016174,0.0012512,0.0013428,0.0011292,0.0010681,0.0009155,0.0008545,0.0007935,0.0006409,0.0005798,0.0004578,0.0004883,0.0003662,0.0004883,0.0003052,0.0004272,0.0003967,0.0004578,0.0005188,0.0005188,0.0005798,0.0005798,0.0005493,0.0005798,0.0006104,0.0004883,0.0005798,0.0003662,0.0004578,0.0001831,0.0003357,-0.0000916,0.0002441,-0.0003052,0.0000305,-0.0003967,-0.0003357,-0.0003357,-0.0005188,-0.0003967,-0.0005188,-0.0005493,-0.0004578,-0.0005798,-0.0003967,-0.0005798,-0.0003357,-0.0004883,-0.0003662,-0.0003662,-0.0004883,-0.0003662,-0.0004272,-0.0004578,-0.0005493,-0.0004883,-0.0007019,-0.0006104,-0.0007935,-0.0007935,-0.0009155,-0.0009155,-0.0010071,-0.0010681,-0.0010376,-0.0010681,-0.0011597,-0.0010071,-0.0012817,-0.0009155,-0.0012512,-0.0010071,-0.0010986,-0.0011597,-0.0010071,-0.0011597,-0.0011292,-0.0011597,-0.0012207,-0.0012207,-0.0013123,-0.0012207,-0.0015564,-0.0012207,-0.0016479,-0.0012207,-0.0016785,-0.0014343,-0.0014343,-0.0015259,-0.0012817,-0.0015564,-0.0012817,-0.0013733,-0.0012207,-0.0012512,-0.0011292,-0.0012512,-0.0010681,-0.0011902,-0.0010986,-0.0011902,-0.0010681,-0.0011902,-0.0011597,-0.0012817,-0.0011597,-0.0013733,-0.0011902,-0.0014343,-0.0012817,-0.0013123,-0.0013733,-0.0012207,-0.0013428,-0.0011902,-0.0011597,-0.0011902,-0.0010681,-0.0010071,-0.0009460,-0.0009155,-0.0009155,-0.0008545,-0.0007629,-0.0007935,-0.0007629,-0.0008240,-0.0008850,-0.0007629,-0.0009155,-0.0008545,-0.0010071,-0.0009460,-0.0010681,-0.0009460,-0.0011292,-0.0009766,-0.0010376,-0.0009155,-0.0010071,-0.0008240,-0.0008850,-0.0007019,-0.0007324,-0.0006104,-0.0005798,-0.0006409,-0.0004883,-0.0006104,-0.0005188,-0.0006409,-0.0006409,-0.0007629,-0.0007019,-0.0009766,-0.0007629,-0.0010681,-0.0010071,-0.0010376,-0.0011292,-0.0010376,-0.0010681,-0.0010681,-0.0009155,-0.0009460,-0.0008240,-0.0007629,-0.0007324,-0.0007019,-0.0005798,-0.0007019,-0.0005798,-0.0006409,-0.0007324,-0.0006714,-0.0008545,-0.0008545,-0.0009460,-0.0010376,-0.0010681,-0.0010986,-0.0011292,-0.0011902,-0.0011292,-0.0011597,-0.0010376,-0.0010376,-0.0009460,-0.0009155,-0.0008850,-0.0007629,-0.0008240,-0.0007935,-0.0006714,-0.0009766,-0.0007019,-0.0010376,-0.0009460,-0.0010986,-0.0012207,-0.0012207,-0.0014343,-0.0014343,-0.0015869,-0.0015259,-0.0016785,-0.0016479,-0.0016479,-0.0016479,-0.0016174,-0.0014954,-0.0016479,-0.0014343,-0.0014343,-0.0014648,-0.0013428,-0.0014343,-0.0013733,-0.0014343,-0.0014648,-0.0015259,-0.0015564,-0.0016785,-0.0017395,-0.0017090,-0.0020142,-0.0018616,-0.0021057,-0.0020142,-0.0021057,-0.0020447,-0.0021667,-0.0020447,-0.0021057,-0.0019226,-0.0020142,-0.0017700,-0.0019531,-0.0017090,-0.0016785,-0.0017700,-0.0014954,-0.0018005,-0.0015564,-0.0016785,-0.0016479,-0.0017700,-0.0017090,-0.0020142,-0.0017090,-0.0021667,-0.0018616,-0.0021667,-0.0021057,-0.0020447,-0.0021973,-0.0020752,-0.0020447,-0.0020752,-0.0018921,-0.0019531,-0.0018311,-0.0018005,-0.0017395,-0.0017090,-0.0016479,-0.0017395,-0.0015869,-0.0017700,-0.0015564,-0.0018311,-0.0017395,-0.0018616,-0.0018311,-0.0019226,-0.0018921,-0.0020142,-0.0019836,-0.0019531,-0.0019836,-0.0018921,-0.0018311,-0.0018921,-0.0015869,-0.0017090,-0.0015259,-0.0014343,-0.0014343,-0.0012512,-0.0012817,-0.0012512,-0.0010681,-0.0012512,-0.0010376,-0.0012207,-0.0011597,-0.0012512,-0.0012512,-0.0012817,-0.0012817,-0.0013733,-0.0014038,-0.0013733,-0.0014343,-0.0013428,-0.0014343,-0.0013123,-0.0012817,-0.0010681,-0.0013123,-0.0008240,-0.0011292,-0.0006409,-0.0008850,-0.0004883,-0.0007324,-0.0003967,-0.0004883,-0.0004272,-0.0003662,-0.0004272,-0.0003967,-0.0004578,-0.0003967,-0.0005188,-0.0005188,-0.0005798,-0.0005798,-0.0005493,-0.0007324,-0.0003967,-0.0007629,-0.0003662,-0.0006104,-0.0003357,-0.0003662,-0.0003052,-0.0001526,-0.0001526,-0.0000610,0.0000916,-0.0000305,0.0000916,0.0000916,-0.0000305,0.0002441,-0.0001526,0.0000000,-0.0001526,-0.0002441,-0.0001526,-0.0005493,-0.0003357,-0.0006409,-0.0004578,-0.0006409,-0.0006409,-0.0005493,-0.0007324,-0.0005188,-0.0006714,-0.0004272,-0.0005493,-0.0003967,-0.0004578,-0.0003357,-0.0003662,-0.0003662,-0.0003052,-0.0003967,-0.0003357,-0.0005493,-0.0003967,-0.0006104,-0.0005798,-0.0006714,-0.0007935,-0.0007019,-0.0009460,-0.0007629,-0.0009155,-0.0009460,-0.0008545,-0.0008850,-0.0008850,-0.0007629,-0.0009460,-0.0007019,-0.0007935,-0.0007629,-0.0007935,-0.0007324,-0.0008545,-0.0007629,-0.0009155,-0.0008850,-0.0010376,-0.0010071,-0.0011597,-0.0011292,-0.0012512,-0.0012512,-0.0013428,-0.0014038,-0.0013123,-0.0014648,-0.0013428,-0.0015564,-0.0013428,-0.0014954,-0.0015869,-0.0013123,-0.0017395,-0.0013733,-0.0017090,-0.0015564,-0.0017395,-0.0016785,-0.0017090,-0.0018311,-0.0017395,-0.0018921,-0.0018005,-0.0018921,-0.0018311,-0.0019531,-0.0017395,-0.0019531,-0.0017090,-0.0019226,-0.0017395,-0.0018921,-0.0017395,-0.0018921,-0.0016785,-0.0019226,-0.0017090,-0.0018921,-0.0017395,-0.0017700,-0.0018921,-0.0017395,-0.0018005,-0.0017395,-0.0016785,-0.0017700,-0.0015259,-0.0016785,-0.0015564,-0.0016174,-0.0014648,-0.0016174,-0.0014343,-0.0016479,-0.0015259,-0.0015259,-0.0016785,-0.0014954,-0.0017700,-0.0015869,-0.0017090,-0.0017090,-0.0016479,-0.0017395,-0.0016174,-0.0016785,-0.0015259,-0.0016479,-0.0013428,-0.0015564,-0.0013123,-0.0012817,-0.0013123,-0.0011292,-0.0012207,-0.0011292,-0.0011597,-0.0010071,-0.0011597,-0.0010376,-0.0011597,-0.0010681,-0.0012207,-0.0010376,-0.0012207,-0.0011902,-0.0010986,-0.0013123,-0.0010376,-0.0012207,-0.0010376,-0.0011902,-0.0008240,-0.0011292,-0.0006409,-0.0009155,-0.0006104,-0.0007019,-0.0003662,-0.0005493,-0.0002441,-0.0003357,-0.0002747,-0.0001831,-0.0001831,-0.0002136,0.0000305,-0.0003967,0.0000000,-0.0002441,-0.0001831,-0.0000610,-0.0002441,-0.0000916,-0.0000610,-0.0000610,0.0001526,0.0000916,0.0002747,0.0004578,0.0004272,0.0007019,0.0007629,0.0008240,0.0011292,0.0010071,0.0011902,0.0012207,0.0012817,0.0012207,0.0013733,0.0010681,0.0012817,0.0010986,0.0010681,0.0010986,0.0009766,0.0009766,0.0010376,0.0008850,0.0010986,0.0009766,0.0011292,0.0011292,0.0012512,0.0012817,0.0014038,0.0014648,0.0015259,0.0015259,0.0016785,0.0015564,0.0017090,0.0015869,0.0016174,0.0015869,0.0014648,0.0015564,0.0012817,0.0014038,0.0013123,0.0011597,0.0013733,0.0011292,0.0012207,0.0012817,0.0012512,0.0013428,0.0014343,0.0014343,0.0014954,0.0016479,0.0015259,0.0018311,0.0015869,0.0018311,0.0016174,0.0017700,0.0015869,0.0015869,0.0014038,0.0014648,0.0011597,0.0012207,0.0010071,0.0010376,0.0008850,0.0008545,0.0008545,0.0007324,0.0009766,0.0006409,0.0010376,0.0007019,0.0010376,0.0008545,0.0010071,0.0009155,0.0009766,0.0007935,0.0008545,0.0007629,0.0005798,0.0007019,0.0002136,0.0003967,0.0000916,0.0000305,0.0000000,-0.0003052,-0.0001526,-0.0003662,-0.0003967,-0.0003357,-0.0004883,-0.0003052,-0.0004578,-0.0004883,-0.0003662,-0.0004578,-0.0003662,-0.0004578,-0.0004578,-0.0005493,-0.0005493,-0.0007324,-0.0006409,-0.0009155,-0.0008545,-0.0010986,-0.0010376,-0.0012512,-0.0012207,-0.0013123,-0.0014648,-0.0013123,-0.0015259,-0.0014038,-0.0013733,-0.0015869,-0.0013733,-0.0015259,-0.0014038,-0.0014954,-0.0014343,-0.0015869,-0.0014038,-0.0017395,-0.0014038,-0.0017700,-0.0014954,-0.0018616,-0.0016174,-0.0018921,-0.0017395,-0.0019226,-0.0018311,-0.0019531,-0.0018311,-0.0019531,-0.0017700,-0.0019226,-0.0016785,-0.0018921,-0.0016174,-0.0017395,-0.0014954,-0.0017395,-0.0014038,-0.0016174,-0.0014954,-0.0014954,-0.0016174,-0.0015869,-0.0016174,-0.0017700,-0.0017700,-0.0018921,-0.0018616,-0.0021057,-0.0019226,-0.0022583,-0.0020142,-0.0021667,-0.0020752,-0.0021362,-0.0019531,-0.0020752,-0.0018311,-0.0018311,-0.0018005,-0.0016479,-0.0016174,-0.0016479,-0.0014648,-0.0015869,-0.0014954,-0.0015259,-0.0016479,-0.0015869,-0.0017090,-0.0016479,-0.0017700,-0.0017090,-0.0017700,-0.0017395,-0.0016785,-0.0016174,-0.0015564,-0.0013733,-0.0014648,-0.0010681,-0.0011902,-0.0008850,-0.0009460,-0.0006409,-0.0007019,-0.0004578,-0.0005188,-0.0003662,-0.0004272,-0.0002441,-0.0003662,-0.0002441,-0.0002747,-0.0002136,-0.0001831,-0.0001526,-0.0000610,-0.0000305,0.0001221,0.0001221,0.0003052,0.0003357,0.0004883,0.0005798,0.0006409,0.0007629,0.0008240,0.0009155,0.0009460,0.0009766,0.0010376,0.0010376,0.0011292,0.0010071,0.0011597,0.0010071,0.0012512,0.0009460,0.0012817,0.0010376,0.0013428,0.0011292,0.0014038,0.0012817,0.0014038,0.0014954,0.0014343,0.0016174,0.0015564,0.0016479,0.0015869,0.0016785,0.0016785,0.0016174,0.0017700,0.0015564,0.0017700,0.0015869,0.0016479,0.0017395,0.0015259,0.0017700,0.0016479,0.0015869,0.0018616,0.0016174,0.0018311,0.0017090,0.0018311,0.0017090,0.0020142,0.0017700,0.0019226,0.0018921,0.0019226,0.0019226,0.0019836,0.0018616,0.0020142,0.0018921,0.0020142,0.0018616,0.0020752,0.0018311,0.0021057,0.0017700,0.0021362,0.0017700,0.0020752,0.0018005,0.0018616,0.0019226,0.0017395,0.0018616,0.0017395,0.0017090,0.0017090,0.0016785,0.0016479,0.0016479,0.0016479,0.0016479,0.0016479,0.0017395,0.0016785,0.0017090,0.0018005,0.0017700,0.0018921,0.0017700,0.0018311,0.0018311,0.0017395,0.0018311,0.0015869,0.0017090,0.0014343,0.0016174,0.0012207,0.0014648,0.0010681,0.0012817,0.0010071,0.0011902,0.0009460,0.0011292,0.0010376,0.0010681,0.0012817,0.0011597,0.0013428,0.0014038,0.0014343,0.0015564,0.0016479,0.0016174,0.0016785,0.0017700,0.0016174,0.0017395,0.0016479,0.0014954,0.0018005,0.0012817,0.0017090,0.0011902,0.0015564,0.0011597,0.0015869,0.0011902,0.0014343,0.0014343,0.0014343,0.0015564,0.0015564,0.0017395,0.0015869,0.0019836,0.0016785,0.0020752,0.0018921,0.0019226,0.0020447,0.0018616,0.0019836,0.0018616,0.0017090,0.0018311,0.0014954,0.0016479,0.0014038,0.0013733,0.0013733,0.0013123,0.0011902,0.0012817,0.0012512,0.0012817,0.0013123,0.0013733,0.0013123,0.0015869,0.0014038,0.0015869,0.0016174,0.0015564,0.0017700,0.0015259,0.0017090,0.0015564,0.0016174,0.0015259,0.0015259,0.0014038,0.0014038,0.0012817,0.0013733,0.0012512,0.0012512,0.0012817,0.0012817,0.0013428,0.0013428,0.0013733,0.0014954,0.0014954,0.0016785,0.0015564,0.0017700,0.0018005,0.0018311,0.0018616,0.0019226,0.0018311,0.0021057,0.0017395,0.0022278,0.0016479,0.0022583,0.0017090,0.0020752,0.0018616,0.0019226,0.0019226,0.0018311,0.0018311,0.0018921,0.0017090,0.0018921,0.0016785,0.0018005,0.0016785,0.0017700,0.0016785,0.0018311,0.0016174,0.0017700,0.0016785,0.0017700,0.0018005,0.0017395,0.0017395,0.0018005,0.0017395,0.0018616,0.0016479,0.0018311,0.0015869,0.0017700,0.0015869,0.0015869,0.0015259,0.0015259,0.0014343,0.0014343,0.0014343,0.0012817,0.0015259,0.0011902,0.0015564,0.0013123,0.0014343,0.0014954,0.0013428,0.0016785,0.0013733,0.0016479,0.0014648,0.0015259,0.0015564,0.0014343,0.0014648,0.0013428,0.0012817,0.0010986,0.0012207,0.0009766,0.0009155,0.0010071,0.0006104,0.0008850,0.0006104,0.0006409,0.0006714,0.0005493,0.0006409,0.0005798,0.0006104,0.0006714,0.0005798,0.0006714,0.0006104,0.0006409,0.0005798,0.0005798,0.0005188,0.0005188,0.0003662,0.0004272,0.0001831,0.0002747,0.0001221,-0.0000305,0.0001831,-0.0002441,0.0000305,-0.0002441,-0.0001221,-0.0001831,-0.0002747,-0.0001221,-0.0003357,-0.0000916,-0.0003052,-0.0002136,-0.0002747,-0.0002747,-0.0003357,-0.0004272,-0.0003357,-0.0005798,-0.0004272,-0.0006714,-0.0006409,-0.0007935,-0.0006714,-0.0009460,-0.0007324,-0.0010071,-0.0008240,-0.0009460,-0.0008240,-0.0008545,-0.0007935,-0.0008240,-0.0006714,-0.0007629,-0.0006104,-0.0006714,-0.0006104,-0.0006714,-0.0005798,-0.0007629,-0.0006409,-0.0007324,-0.0008545,-0.0007324,-0.0009766,-0.0007629,-0.0010071,-0.0007935,-0.0009766,-0.0008850,-0.0008545,-0.0007629,-0.0009155,-0.0005493,-0.0008850,-0.0003967,-0.0006714,-0.0004578,-0.0004578,-0.0004578,-0.0003052,-0.0004578,-0.0003662,-0.0003662,-0.0004578,-0.0002441,-0.0005493,-0.0002747,-0.0005798,-0.0002441,-0.0005493,-0.0001526,-0.0004578,-0.0001526,-0.0002747,-0.0000610,-0.0000916,0.0000610,0.0001526,0.0000916,0.0004272,0.0001526,0.0005188,0.0003662,0.0004578,0.0004883,0.0004883,0.0005188,0.0004883,0.0005188,0.0004272,0.0005188,0.0004883,0.0005188,0.0005798,0.0005798,0.0006104,0.0007935,0.0006104,0.0010376,0.0008545,0.0010986,0.0011902,0.0011597,0.0014038,0.0012207,0.0015259,0.0013123,0.0015259,0.0013733,0.0015869,0.0012817,0.0016174,0.0013123,0.0014038,0.0014954,0.0012817,0.0015869,0.0014343,0.0014954,0.0017090,0.0015564,0.0018921,0.0018311,0.0019226,0.0021362,0.0021057,0.0022278,0.0022278,0.0022888,0.0022278,0.0023499,0.0021362,0.0023499,0.0019531,0.0023193,0.0018616,0.0021362,0.0018616,0.0020142,0.0018616,0.0020752,0.0019226,0.0021362,0.0020752,0.0022583,0.0022278,0.0025024,0.0023499,0.0026855,0.0025940,0.0027466,0.0026855,0.0027161,0.0028076,0.0025940,0.0028687,0.0024414,0.0027161,0.0023193,0.0025330,0.0022888,0.0022278,0.0022888,0.0020142,0.0021973,0.0019531,0.0020752,0.0019531,0.0019836,0.0019531,0.0019531,0.0018921,0.0019836,0.0018921,0.0018616,0.0019226,0.0018311,0.0018616,0.0018005,0.0017700,0.0017090,0.0017090,0.0016174,0.0015564,0.0014648,0.0014648,0.0013123,0.0013123,0.0011597,0.0010986,0.0010071,0.0009766,0.0008545,0.0008545,0.0006714,0.0007629,0.0005493,0.0007324,0.0005493,0.0005188,0.0006409,0.0004272,0.0006409,0.0004272,0.0005493,0.0003967,0.0004883,0.0002747,0.0004578,0.0001526,0.0003052,0.0000305,0.0001526,-0.0001831,0.0000000,-0.0003967,-0.0001221,-0.0005188,-0.0002747,-0.0005493,-0.0005188,-0.0005493,-0.0006104,-0.0007019,-0.0005493,-0.0008240,-0.0006104,-0.0008240,-0.0007324,-0.0008240,-0.0008545,-0.0009155,-0.0009766,-0.0009766,-0.0010681,-0.0011292,-0.0012207,-0.0011902,-0.0013428,-0.0012207,-0.0014648,-0.0013733,-0.0015259,-0.0014954,-0.0015869,-0.0016174,-0.0017090,-0.0016785,-0.0018311,-0.0017395,-0.0019531,-0.0018311,-0.0021057,-0.0018921,-0.0021057,-0.0020447,-0.0020752,-0.0020752,-0.0020447,-0.0020142,-0.0020447,-0.0018921,-0.0019836,-0.0018311,-0.0018005,-0.0018921,-0.0016479,-0.0018921,-0.0016479,-0.0018311,-0.0017395,-0.0018311,-0.0018311,-0.0018921,-0.0018921,-0.0019836,-0.0019836,-0.0019836,-0.0020142,-0.0019531,-0.0019836,-0.0018921,-0.0019836,-0.0016479,-0.0018616,-0.0014954,-0.0017090,-0.0014038,-0.0014648,-0.0013123,-0.0013428,-0.0013428,-0.0011597,-0.0014343,-0.0011292,-0.0014648,-0.0011902,-0.0014954,-0.0013123,-0.0015564,-0.0012817,-0.0015564,-0.0013123,-0.0014648,-0.0012817,-0.0013733,-0.0010986,-0.0011597,-0.0010376,-0.0008850,-0.0009460,-0.0006714,-0.0007019,-0.0006714,-0.0005798,-0.0006104,-0.0004272,-0.0006104,-0.0004272,-0.0005798,-0.0005188,-0.0004272,-0.0006409,-0.0003967,-0.0006104,-0.0003662,-0.0004883,-0.0003357,-0.0003357,-0.0002136,-0.0001526,-0.0000305,0.0000000,0.0001526,0.0001831,0.0003052,0.0003967,0.0003967,0.0005493,0.0005493,0.0005493,0.0007019,0.0006409,0.0006714,0.0006714,0.0007019,0.0006714,0.0007935,0.0006104,0.0008240,0.0006409,0.0008545,0.0007019,0.0008240,0.0007629,0.0008850,0.0008545,0.0009155,0.0009460,0.0009766,0.0011292,0.0010681,0.0011902,0.0011597,0.0012817,0.0013123,0.0013123,0.0013123,0.0013733,0.0012817,0.0014343,0.0011902,0.0014343,0.0011902,0.0013123,0.0011597,0.0012512,0.0010986,0.0012817,0.0010376,0.0012512,0.0010376,0.0012207,0.0011292,0.0012817,0.0011902,0.0013123,0.0012817,0.0013428,0.0013123,0.0014038,0.0012817,0.0013733,0.0011902,0.0013123,0.0011597,0.0011902,0.0010071,0.0010071,0.0009155,0.0008240,0.0008850,0.0005798,0.0008545,0.0005493,0.0007935,0.0005493,0.0007629,0.0006104,0.0007324,0.0007629,0.0007019,0.0008240,0.0007629,0.0007935,0.0008240,0.0007935,0.0007019,0.0007324,0.0007019,0.0005798,0.0006409,0.0004578,0.0004272,0.0003662,0.0003052,0.0002747,0.0001831,0.0001526,0.0001221,0.0000610,0.0001221,0.0000000,0.0000305,0.0000916,0.0000000,0.0001221,-0.0000305,0.0001221,0.0000305,0.0001221,-0.0000610,0.0001221,0.0000000,-0.0000916,-0.0000305,-0.0001831,-0.0002136,-0.0002136,-0.0004272,-0.0003967,-0.0004578,-0.0006104,-0.0006104,-0.0007019,-0.0007324,-0.0008850,-0.0006714,-0.0010376,-0.0006714,-0.0009460,-0.0008240,-0.0007629,-0.0009460,-0.0006714,-0.0008850,-0.0006714,-0.0008545,-0.0007629,-0.0009155,-0.0007629,-0.0009766,-0.0008545,-0.0010071,-0.0010071,-0.0010376,-0.0010681,-0.0012207,-0.0010986,-0.0012207,-0.0011597,-0.0011902,-0.0012207,-0.0010681,-0.0011902,-0.0010071,-0.0011292,-0.0009766,-0.0010071,-0.0010071,-0.0009155,-0.0009766,-0.0009460,-0.0009460,-0.0009460,-0.0009766,-0.0009460,-0.0010376,-0.0009766,-0.0009766,-0.0009766,-0.0009766,-0.0010071,-0.0009460,-0.0009460,-0.0009155,-0.0009766,-0.0009155,-0.0009460,-0.0009766,-0.0008240,-0.0010986,-0.0008545,-0.0010986,-0.0008850,-0.0010986,-0.0008545,-0.0010986,-0.0009155,-0.0009460,-0.0009460,-0.0008850,-0.0007935,-0.0008545,-0.0007019,-0.0007324,-0.0007019,-0.0006104,-0.0006104,-0.0005798,-0.0006104,-0.0006409,-0.0005798,-0.0006714,-0.0007324,-0.0007019,-0.0008545,-0.0007935,-0.0009460,-0.0008545,-0.0010071,-0.0009155,-0.0010071,-0.0009155,-0.0009155,-0.0010071,-0.0007629,-0.0009766,-0.0007019,-0.0007935,-0.0007629,-0.0006714,-0.0007324,-0.0006104,-0.0007629,-0.0006409,-0.0007019,-0.0008240,-0.0006104,-0.0009460,-0.0007019,-0.0008545,-0.0008850,-0.0007629,-0.0009460,-0.0007324,-0.0009460,-0.0006104,-0.0009766,-0.0005493,-0.0007935,-0.0006714,-0.0005798,-0.0007324,-0.0005188,-0.0007629,-0.0005188,-0.0007629,-0.0006714,-0.0007019,-0.0009155,-0.0006714,-0.0010376,-0.0008240,-0.0010376,-0.0010376,-0.0009766,-0.0012207,-0.0009460,-0.0012207,-0.0009766,-0.0012207,-0.0010681,-0.0011292,-0.0010071,-0.0012512,-0.0009155,-0.0014038,-0.0008545,-0.0013733,-0.0010681,-0.0013428,-0.0013123,-0.0012512,-0.0015259,-0.0012817,-0.0016785,-0.0012817,-0.0017090,-0.0014343,-0.0016174,-0.0015259,-0.0015869,-0.0014954,-0.0015564,-0.0014954,-0.0014343,-0.0015259,-0.0014343,-0.0013733,-0.0014954,-0.0013428,-0.0015259,-0.0013733,-0.0015259,-0.0014954,-0.0015869,-0.0014954,-0.0016785,-0.0015259,-0.0017395,-0.0016174,-0.0016479,-0.0017090,-0.0016174,-0.0017090,-0.0016174,-0.0015869,-0.0016479,-0.0015259,-0.0015564,-0.0015564,-0.0015259,-0.0015564,-0.0015259,-0.0015564,-0.0016174,-0.0015564,-0.0016785,-0.0016785,-0.0017090,-0.0018311,-0.0016785,-0.0019531,-0.0018005,-0.0018921,-0.0018616,-0.0018616,-0.0018921,-0.0019226,-0.0017700,-0.0018005,-0.0018005,-0.0016174,-0.0018616,-0.0014954,-0.0016785,-0.0014648,-0.0017090,-0.0014038,-0.0017090,-0.0014648,-0.0016174,-0.0015869,-0.0016479,-0.0016479,-0.0017090,-0.0017700,-0.0015869,-0.0018921,-0.0016174,-0.0017700,-0.0016785,-0.0015564,-0.0017090,-0.0013733,-0.0015869,-0.0012817,-0.0013733,-0.0012512,-0.0011597,-0.0012817,-0.0009766,-0.0012817,-0.0010071,-0.0011902,-0.0011597,-0.0011597,-0.0012817,-0.0012207,-0.0013123,-0.0013428,-0.0012207,-0.0014343,-0.0010681,-0.0014648,-0.0009155,-0.0012817,-0.0007629,-0.0009766,-0.0006104,-0.0006714,-0.0005188,-0.0003662,-0.0003662,-0.0002441,-0.0001831,-0.0002136,-0.0001526,-0.0002441,-0.0001526,-0.0003662,-0.0002441,-0.0004883,-0.0003967,-0.0004883,-0.0006104,-0.0005188,-0.0006104,-0.0006409,-0.0004883,-0.0007019,-0.0003052,-0.0005798,-0.0003357,-0.0002747,-0.0003662,0.0000305,-0.0003357,0.0000610,-0.0001221,0.0000305,0.0000000,-0.0000305,-0.0000610,-0.0000305,-0.0001526,-0.0000916,-0.0002747,-0.0001831,-0.0003662,-0.0003357,-0.0003662,-0.0004272,-0.0003357,-0.0004883,-0.0003052,-0.0004272,-0.0002441,-0.0002747,-0.0002136,-0.0001526,-0.0001526,0.0000305,-0.0001526,0.0001831,-0.0000916,0.0001831,-0.0000610,0.0000305,-0.0000305,-0.0001526,-0.0001526,-0.0003052,-0.0003357,-0.0005188,-0.0005493,-0.0006104,-0.0007935,-0.0006409,-0.0010071,-0.0006409,-0.0009460,-0.0007629,-0.0006409,-0.0009155,-0.0003357,-0.0007935,-0.0003052,-0.0004883,-0.0003662,-0.0003662,-0.0002747,-0.0005188,-0.0002136,-0.0007324,-0.0003967,-0.0009155,-0.0006409,-0.0011597,-0.0009460,-0.0014038,-0.0012512,-0.0014343,-0.0014648,-0.0014954,-0.0014954,-0.0014343,-0.0013733,-0.0013123,-0.0011597,-0.0012207,-0.0008240,-0.0009766,-0.0007629,-0.0008240,-0.0007324,-0.0007324,-0.0008240,-0.0007935,-0.0010681,-0.0010071,-0.0012207,-0.0014038,-0.0014038,-0.0017090,-0.0016479,-0.0018311,-0.0018616,-0.0018005,-0.0018921,-0.0018005,-0.0016785,-0.0016785,-0.0015259,-0.0013733,-0.0014648,-0.0011292,-0.0012207,-0.0011292,-0.0010071,-0.0011902,-0.0009766,-0.0012207,-0.0011902,-0.0012512,-0.0014038,-0.0013428,-0.0015564,-0.0015259,-0.0016174,-0.0017090,-0.0015869,-0.0017700,-0.0015564,-0.0015869,-0.0015564,-0.0013428,-0.0014648,-0.0012512,-0.0012207,-0.0011902,-0.0010071,-0.0011597,-0.0009155,-0.0011292,-0.0009460,-0.0010376,-0.0011292,-0.0010376,-0.0011902,-0.0011902,-0.0012817,-0.0012512,-0.0013733,-0.0013428,-0.0014648,-0.0013733,-0.0014343,-0.0013428,-0.0014648,-0.0012512,-0.0014343,-0.0011292,-0.0013123,-0.0011597,-0.0010681,-0.0011597,-0.0008850,-0.0010071,-0.0009460,-0.0008240,-0.0010071,-0.0007019,-0.0010071,-0.0007629,-0.0008545,-0.0008850,-0.0008240,-0.0008850,-0.0008240,-0.0007324,-0.0008240,-0.0006714,-0.0006714,-0.0005493,-0.0004272,-0.0004272,-0.0001831,-0.0001526,0.0000305,0.0001221,0.0001831,0.0004272,0.0002747,0.0006104,0.0003967,0.0006714,0.0005188,0.0005493,0.0005798,0.0004883,0.0004883,0.0005188,0.0003967,0.0005188,0.0003967,0.0004883,0.0005798,0.0004883,0.0007935,0.0005493,0.0009766,0.0008850,0.0010376,0.0012207,0.0010986,0.0013428,0.0014038,0.0012512,0.0016174,0.0011597,0.0016785,0.0011902,0.0015259,0.0012207,0.0014343,0.0011292,0.0013123,0.0010986,0.0012817,0.0012817,0.0011292,0.0013733,0.0011902,0.0013733,0.0013733,0.0014954,0.0014038,0.0015564,0.0014343,0.0015564,0.0015259,0.0014954,0.0014954,0.0014648,0.0014954,0.0012817,0.0014954,0.0011597,0.0014648,0.0011292,0.0014038,0.0011597,0.0013428,0.0012207,0.0013123,0.0013428,0.0013428,0.0012817,0.0014648,0.0012207,0.0014038,0.0013123,0.0012512,0.0012207,0.0012512,0.0009766,0.0011902,0.0009155,0.0010071,0.0008545,0.0008545,0.0007324,0.0007935,0.0007324,0.0007629,0.0006104,0.0007935,0.0007019,0.0007324,0.0007324,0.0006714,0.0007629,0.0006104,0.0007324,0.0004883,0.0006714,0.0003967,0.0005798,0.0002136,0.0003967,0.0001221,0.0002136,0.0000610,0.0000916,-0.0000916,-0.0000610,-0.0001221,-0.0000916,-0.0001526,-0.0001831,-0.0002136,-0.0001526,-0.0002136,-0.0001831,-0.0003052,-0.0002747,-0.0003052,-0.0003662,-0.0004578,-0.0004883,-0.0005188,-0.0006714,-0.0006409,-0.0008240,-0.0007629,-0.0008850,-0.0008545,-0.0010376,-0.0009155,-0.0009460,-0.0010071,-0.0009766,-0.0010376,-0.0010071,-0.0009155,-0.0011292,-0.0009460,-0.0011292,-0.0009460,-0.0012207,-0.0010071,-0.0013428,-0.0010681,-0.0014038,-0.0012512,-0.0013733,-0.0014343,-0.0013428,-0.0014954,-0.0013733,-0.0015259,-0.0013733,-0.0015564,-0.0013123,-0.0014648,-0.0014038,-0.0014648,-0.0013428,-0.0015564,-0.0012512,-0.0016785,-0.0013428,-0.0016785,-0.0014648,-0.0017090,-0.0015259,-0.0018005,-0.0016174,-0.0017700,-0.0017395,-0.0016479,-0.0018311,-0.0016174,-0.0018005,-0.0015564,-0.0016785,-0.0015259,-0.0015564,-0.0014954,-0.0014648,-0.0013733,-0.0014954,-0.0013428,-0.0014038,-0.0013428,-0.0013123,-0.0014038,-0.0013428,-0.0013123,-0.0013428,-0.0013428,-0.0012817,-0.0012512,-0.0011597,-0.0012512,-0.0010376,-0.0010986,-0.0009460,-0.0008545,-0.0008545,-0.0006714,-0.0005493,-0.0006714,-0.0003357,-0.0004578,-0.0003357,-0.0002747,-0.0002747,-0.0002747,-0.0002136,-0.0002441,-0.0003052,-0.0002136,-0.0003662,-0.0002136,-0.0003967,-0.0002136,-0.0003357,-0.0001831,-0.0001831,-0.0000916,0.0000305,0.0001221,0.0001831,0.0004272,0.0003357,0.0007324,0.0005798,0.0007935,0.0007629,0.0009766,0.0007629,0.0010681,0.0007324,0.0009155,0.0007935,0.0007324,0.0007019,0.0005798,0.0006714,0.0004883,0.0007324,0.0003967,0.0008240,0.0005188,0.0010071,0.0007629,0.0011597,0.0010681,0.0013428,0.0014038,0.0014648,0.0016174,0.0016174,0.0016785,0.0017090,0.0016785,0.0016479,0.0015869,0.0015259,0.0013733,0.0014954,0.0013123,0.0012207,0.0013123,0.0010986,0.0013733,0.0010986,0.0014648,0.0011597,0.0016479,0.0013733,0.0016479,0.0016479,0.0017395,0.0018311,0.0018005,0.0018921,0.0018616,0.0018616,0.0018616,0.0017395,0.0018005,0.0016479,0.0016785,0.0015564,0.0015259,0.0014648,0.0014038,0.0014343,0.0013428,0.0013428,0.0014343,0.0012512,0.0015869,0.0012512,0.0015564,0.0014648,0.0015564,0.0014954,0.0016785,0.0014648,0.0017395,0.0015564,0.0015259,0.0016479,0.0014343,0.0016479,0.0013428,0.0015564,0.0012512,0.0014954,0.0012817,0.0012512,0.0013428,0.0011597,0.0012512,0.0011597,0.0012207,0.0011597,0.0013123,0.0010986,0.0014038,0.0010986,0.0014954,0.0012207,0.0014648,0.0013428,0.0014343,0.0013733,0.0014038,0.0014954,0.0013123,0.0014648,0.0012512,0.0014648,0.0011597,0.0014954,0.0010376,0.0014648,0.0010986,0.0012512,0.0012512,0.0011597,0.0012817,0.0012817,0.0012207,0.0014038,0.0013123,0.0014343,0.0015564,0.0014038,0.0016479,0.0015259,0.0016785,0.0016174,0.0017395,0.0015869,0.0016785,0.0015869,0.0015869,0.0015564,0.0015259,0.0013733,0.0014343,0.0011902,0.0014343,0.0010071,0.0013733,0.0009766,0.0012512,0.0010986,0.0012512,0.0011292,0.0013733,0.0012512,0.0013733,0.0014954,0.0014954,0.0015564,0.0016174,0.0015869,0.0016785,0.0016174,0.0016174,0.0015564,0.0015869,0.0014954,0.0014343,0.0013733,0.0012512,0.0013428,0.0011597,0.0012512,0.0010986,0.0012512,0.0011902,0.0012512,0.0013123,0.0013428,0.0014648,0.0015259,0.0015564,0.0017700,0.0016174,0.0020142,0.0016479,0.0020447,0.0017700,0.0020142,0.0017395,0.0020142,0.0016785,0.0018921,0.0017090,0.0016479,0.0017395,0.0015869,0.0015869,0.0016479,0.0015259,0.0016785,0.0015869,0.0016785,0.0017090,0.0017395,0.0017395,0.0019226,0.0017700,0.0020447,0.0017395,0.0021362,0.0017395,0.0020142,0.0018005,0.0018005,0.0018921,0.0016174,0.0018005,0.0014954,0.0017395,0.0014343,0.0016785,0.0014343,0.0017090,0.0013733,0.0018311,0.0013733,0.0018921,0.0016174,0.0017700,0.0018311,0.0016785,0.0018921,0.0017395,0.0018005,0.0016479,0.0017700,0.0015259,0.0016479,0.0013428,0.0015869,0.0011597,0.0014954,0.0011597,0.0013428,0.0012207,0.0013123,0.0012207,0.0014954,0.0011902,0.0016174,0.0013123,0.0015869,0.0014954,0.0014648,0.0014954,0.0014038,0.0014038,0.0012512,0.0012512,0.0010986,0.0008850,0.0010376,0.0006104,0.0007935,0.0005188,0.0006714,0.0004883,0.0005493,0.0005188,0.0005798,0.0006104,0.0005798,0.0006714,0.0006409,0.0007324,0.0006714,0.0006714,0.0005798,0.0006104,0.0004883,0.0003052,0.0002747,0.0001526,-0.0000305,-0.0000305,-0.0002136,-0.0003052,-0.0003052,-0.0004578,-0.0003967,-0.0004883,-0.0003967,-0.0004272,-0.0004578,-0.0002747,-0.0004272,-0.0001831,-0.0003357,-0.0001526,-0.0003357,-0.0001221,-0.0003967,-0.0001526,-0.0004883,-0.0002441,-0.0005798,-0.0004272,-0.0006104,-0.0006104,-0.0006104,-0.0007019,-0.0006409,-0.0007935,-0.0006409,-0.0007324,-0.0006409,-0.0006409,-0.0005798,-0.0006104,-0.0004883,-0.0005188,-0.0005188,-0.0003662,-0.0006104,-0.0002441,-0.0007019,-0.0001831,-0.0007019,-0.0003662,-0.0006714,-0.0004883,-0.0007019,-0.0004883,-0.0007935,-0.0006104,-0.0007019,-0.0007324,-0.0006409,-0.0007019,-0.0005798,-0.0005798,-0.0005493,-0.0005798,-0.0004272,-0.0004272,-0.0004578,-0.0002747,-0.0004578,-0.0002136,-0.0003662,-0.0002441,-0.0004272,-0.0001526,-0.0003967,-0.0002136,-0.0002747,-0.0003357,-0.0002441,-0.0002747,-0.0002136,-0.0002441,-0.0001526,-0.0002441,-0.0000305,-0.0001526,0.0000610,0.0000000,0.0000305,0.0001526,0.0001221,0.0001526,0.0003357,0.0002136,0.0003967,0.0003662,0.0004272,0.0004883,0.0005188,0.0006104,0.0006409,0.0007324,0.0008240,0.0007629,0.0010071,0.0008240,0.0011902,0.0009460,0.0012817,0.0010986,0.0012207,0.0013123,0.0013123,0.0012817,0.0014343,0.0012512,0.0014954,0.0013123,0.0014038,0.0013733,0.0013733,0.0014648,0.0014038,0.0014648,0.0014648,0.0015869,0.0014648,0.0016479,0.0017090,0.0015869,0.0019226,0.0017090,0.0018311,0.0019531,0.0018616,0.0020142,0.0018921,0.0020447,0.0018005,0.0020142,0.0018921,0.0018921,0.0018921,0.0018921,0.0017090,0.0020447,0.0016479,0.0020447,0.0017395,0.0019836,0.0018921,0.0020447,0.0019836,0.0021667,0.0019531,0.0022888,0.0020447,0.0022278,0.0021667,0.0020752,0.0021362,0.0020142,0.0021057,0.0018311,0.0020447,0.0017395,0.0018921,0.0016479,0.0018616,0.0015564,0.0019531,0.0015564,0.0019531,0.0017090,0.0019226,0.0019226,0.0019531,0.0020142,0.0020752,0.0019226,0.0021362,0.0018616,0.0021057,0.0017090,0.0018311,0.0015869,0.0015869,0.0014038,0.0013123,0.0011902,0.0010986,0.0009766,0.0009766,0.0008545,0.0008545,0.0008240,0.0007935,0.0008545,0.0007629,0.0009155,0.0007019,0.0010071,0.0006409,0.0008850,0.0007324,0.0006409,0.0006714,0.0005188,0.0004578,0.0003662,0.0003052,0.0000610,0.0001831,-0.0000305,-0.0001526,-0.0000305,-0.0003967,-0.0002136,-0.0003662,-0.0003662,-0.0003662,-0.0004272,-0.0003967,-0.0004272,-0.0003967,-0.0004578,-0.0003052,-0.0004883,-0.0002747,-0.0004883,-0.0003052,-0.0004272,-0.0003967,-0.0005188,-0.0004578,-0.0006714,-0.0005798,-0.0006714,-0.0009155,-0.0007324,-0.0010986,-0.0009766,-0.0011597,-0.0012207,-0.0012512,-0.0013428,-0.0013733,-0.0013428,-0.0014954,-0.0012817,-0.0015564,-0.0012207,-0.0014954,-0.0011597,-0.0014038,-0.0010986,-0.0014038,-0.0010681,-0.0013123,-0.0011597,-0.0012512,-0.0013733,-0.0013428,-0.0013733,-0.0016479,-0.0013733,-0.0018616,-0.0016174,-0.0018616,-0.0018005,-0.0018921,-0.0019531,-0.0018921,-0.0018921,-0.0019226,-0.0017700,-0.0018921,-0.0017090,-0.0016785,-0.0016174,-0.0014954,-0.0015259,-0.0013733,-0.0015259,-0.0012817,-0.0014038,-0.0014038,-0.0013123,-0.0014954,-0.0013428,-0.0015259,-0.0014648,-0.0015869,-0.0015259,-0.0016174,-0.0016174,-0.0015564,-0.0016174,-0.0015869,-0.0014648,-0.0015869,-0.0013123,-0.0013733,-0.0012817,-0.0011597,-0.0011597,-0.0010376,-0.0009460,-0.0009766,-0.0007629,-0.0009460,-0.0007019,-0.0008850,-0.0007629,-0.0007935,-0.0007935,-0.0008240,-0.0008545,-0.0008240,-0.0008545,-0.0008240,-0.0008240,-0.0007019,-0.0007935,-0.0004883,-0.0007019,-0.0003662,-0.0004272,-0.0002747,-0.0001831,-0.0001831,-0.0000305,-0.0000916,0.0001526,0.0000000,0.0001831,0.0000305,0.0001526,0.0001221,0.0001526,0.0000610,0.0001221,0.0000305,0.0001831,-0.0000305,0.0001831,0.0000305,0.0002136,0.0001526,0.0001221,0.0003967,0.0001526,0.0004272,0.0003967,0.0003967,0.0004883,0.0005493,0.0004578,0.0007324,0.0004883,0.0006714,0.0006714,0.0006409,0.0007019,0.0007629,0.0005493,0.0009155,0.0005493,0.0008545,0.0007019,0.0007629,0.0007935,0.0007019,0.0009155,0.0006714,0.0009155,0.0007629,0.0008545,0.0009460,0.0007629,0.0010376,0.0007629,0.0010376,0.0008545,0.0009155,0.0010071,0.0007935,0.0010376,0.0008850,0.0008850,0.0009155,0.0008240,0.0008545,0.0007935,0.0008240,0.0007629,0.0008240,0.0007324,0.0008240,0.0007324,0.0008545,0.0007629,0.0008545,0.0007629,0.0008545,0.0007935,0.0008850,0.0007935,0.0008240,0.0008240,0.0007019,0.0007935,0.0007019,0.0007019,0.0007324,0.0005188,0.0006714,0.0004883,0.0005798,0.0004883,0.0004272,0.0004272,0.0003662,0.0003662,0.0002747,0.0003357,0.0001221,0.0002747,0.0001526,0.0001221,0.0000916,0.0000610,-0.0000305,0.0000610,-0.0000916,-0.0000610,-0.0001221,-0.0000610,-0.0001831,-0.0000916,-0.0002747,-0.0001831,-0.0002136,-0.0002747,-0.0001831,-0.0003967,-0.0001831,-0.0004883,-0.0001831,-0.0005188,-0.0003052,-0.0005188,-0.0004272,-0.0005493,-0.0004883,-0.0005493,-0.0006104,-0.0006104,-0.0006409,-0.0007324,-0.0005798,-0.0008545,-0.0006104,-0.0008545,-0.0006714,-0.0008240,-0.0007935,-0.0007019,-0.0008545,-0.0007935,-0.0007629,-0.0008545,-0.0007629,-0.0008240,-0.0008240,-0.0008240,-0.0007935,-0.0009766,-0.0007935,-0.0009460,-0.0009460,-0.0009460,-0.0009766,-0.0010681,-0.0009155,-0.0011597,-0.0009460,-0.0010681,-0.0010681,-0.0009155,-0.0011597,-0.0008545,-0.0010376,-0.0009460,-0.0008850,-0.0009460,-0.0009460,-0.0008545,-0.0010071,-0.0007629,-0.0010986,-0.0008240,-0.0010681,-0.0009766,-0.0008850,-0.0011902,-0.0008545,-0.0011292,-0.0009766,-0.0010071,-0.0009460,-0.0010071,-0.0007935,-0.0009766,-0.0007324,-0.0008545,-0.0007629,-0.0007629,-0.0007935,-0.0007019,-0.0007324,-0.0008850,-0.0006104,-0.0009460,-0.0007324,-0.0007935,-0.0009155,-0.0007324,-0.0009460,-0.0007019,-0.0008545,-0.0006409,-0.0008850,-0.0005188,-0.0008240,-0.0005188,-0.0005798,-0.0006104,-0.0005188,-0.0005188,-0.0005188,-0.0006409,-0.0004272,-0.0007629,-0.0005188,-0.0007324,-0.0006714,-0.0007324,-0.0007629,-0.0007019,-0.0008240,-0.0007019,-0.0007324,-0.0006409,-0.0007019,-0.0005188,-0.0006714,-0.0003967,-0.0005798,-0.0004272,-0.0004883,-0.0005493,-0.0004272,-0.0006104,-0.0006104,-0.0006409,-0.0008545,-0.0007019,-0.0010071,-0.0007935,-0.0011292,-0.0009155,-0.0010986,-0.0009766,-0.0010986,-0.0009460,-0.0009460,-0.0009766,-0.0007324,-0.0009460,-0.0006104,-0.0007935,-0.0006104,-0.0007935,-0.0006104,-0.0007629,-0.0007629,-0.0007935,-0.0010071,-0.0009460,-0.0010376,-0.0012512,-0.0011292,-0.0014343,-0.0012512,-0.0014954,-0.0012817,-0.0014343,-0.0013733,-0.0012512,-0.0013123,-0.0011597,-0.0010681,-0.0010986,-0.0008850,-0.0009155,-0.0009155,-0.0008240,-0.0008850,-0.0008240,-0.0008850,-0.0010681,-0.0009155,-0.0013123,-0.0010376,-0.0014038,-0.0013733,-0.0013733,-0.0016785,-0.0014038,-0.0017090,-0.0015869,-0.0015564,-0.0016479,-0.0014343,-0.0015564,-0.0013428,-0.0013428,-0.0013428,-0.0011597,-0.0012512,-0.0011292,-0.0011292,-0.0012512,-0.0010681,-0.0013733,-0.0011292,-0.0014954,-0.0013123,-0.0015564,-0.0015564,-0.0016174,-0.0017395,-0.0017700,-0.0018616,-0.0018005,-0.0018921,-0.0018616,-0.0018616,-0.0018616,-0.0017395,-0.0017395,-0.0017700,-0.0016174,-0.0016479,-0.0014954,-0.0015869,-0.0015564,-0.0014954,-0.0015259,-0.0015564,-0.0015259,-0.0017090,-0.0015869,-0.0017700,-0.0016785,-0.0018616,-0.0018005,-0.0018311,-0.0019226,-0.0018311,-0.0018616,-0.0018616,-0.0016479,-0.0018921,-0.0015259,-0.0017090,-0.0014038,-0.0015259,-0.0013428,-0.0014343,-0.0011902,-0.0013123,-0.0011902,-0.0012207,-0.0013733,-0.0011902,-0.0013733,-0.0013123,-0.0014343,-0.0014648,-0.0013733,-0.0015564,-0.0014343,-0.0015259,-0.0014038,-0.0014343,-0.0013123,-0.0013428,-0.0011902,-0.0010986,-0.0011292,-0.0008850,-0.0010071,-0.0007629,-0.0007935,-0.0007324,-0.0006409,-0.0007019,-0.0006409,-0.0007324,-0.0005798,-0.0008240,-0.0005798,-0.0008240,-0.0007935,-0.0007324,-0.0009155,-0.0007629,-0.0008850,-0.0008240,-0.0007935,-0.0007935,-0.0007324,-0.0006409,-0.0007324,-0.0004883,-0.0006104,-0.0003967,-0.0004883,-0.0002441,-0.0003967,-0.0002136,-0.0001831,-0.0003052,-0.0000305,-0.0003052,-0.0001831,-0.0001831,-0.0003357,-0.0001831,-0.0004272,-0.0002441,-0.0004272,-0.0003357,-0.0004578,-0.0003357,-0.0004578,-0.0003357,-0.0003662,-0.0002747,-0.0002441,-0.0001831,-0.0001526,-0.0000916,-0.0000305,-0.0000305,0.0000916,-0.0000305,0.0000916,0.0000000,0.0000000,-0.0000610,-0.0001221,-0.0001221,-0.0003357,-0.0001526,-0.0004578,-0.0003052,-0.0004578,-0.0005188,-0.0005188,-0.0003967,-0.0005798,-0.0003052,-0.0006104,-0.0003357,-0.0004578,-0.0003357,-0.0003357,-0.0003662,-0.0003662,-0.0003662,-0.0003052,-0.0004578,-0.0003052,-0.0005188,-0.0004578,-0.0005188,-0.0006104,-0.0005493,-0.0006714,-0.0006714,-0.0006714,-0.0007324,-0.0006714,-0.0007019,-0.0007324,-0.0006104,-0.0007629,-0.0006104,-0.0006714,-0.0006409,-0.0005798,-0.0007019,-0.0006409,-0.0006104,-0.0007935,-0.0006409,-0.0008545,-0.0007629,-0.0008240,-0.0009155,-0.0008850,-0.0009766,-0.0009155,-0.0009460,-0.0010681,-0.0008850,-0.0010986,-0.0009155,-0.0010376,-0.0009460,-0.0010376,-0.0009155,-0.0010681,-0.0010071,-0.0009766,-0.0011292,-0.0010376,-0.0010986,-0.0012207,-0.0011597,-0.0012817,-0.0013428,-0.0012512,-0.0013733,-0.0013123,-0.0013428,-0.0013428,-0.0013123,-0.0012817,-0.0011902,-0.0013123,-0.0010986,-0.0011902,-0.0011597,-0.0010071,-0.0012512,-0.0010376,-0.0013123,-0.0010376,-0.0014038,-0.0011902,-0.0015564,-0.0013733,-0.0015564,-0.0015259,-0.0015869,-0.0016785,-0.0015259,-0.0016479,-0.0014648,-0.0015869,-0.0014038,-0.0013733,-0.0012817,-0.0011902,-0.0012207,-0.0010986,-0.0011597,-0.0010681,-0.0010376,-0.0012207,-0.0010376,-0.0012512,-0.0012207,-0.0012207,-0.0014038,-0.0013123,-0.0014343,-0.0013428,-0.0014648,-0.0012512,-0.0013733,-0.0011902,-0.0011902,-0.0011292,-0.0009766,-0.0008850,-0.0008240,-0.0007019,-0.0007629,-0.0005188,-0.0007019,-0.0003967,-0.0005798,-0.0004883,-0.0005493,-0.0005188,-0.0005493,-0.0004578,-0.0005798,-0.0005798,-0.0004272,-0.0006714,-0.0003357,-0.0006104,-0.0003052,-0.0004883,-0.0002136,-0.0003662,-0.0001221,-0.0001831,-0.0000916,-0.0000305,0.0000305,0.0000305,0.0002136,0.0000610,0.0003052,0.0001526,0.0003357,0.0003052,0.0003357,0.0002747,0.0004272,0.0002747,0.0005798,0.0003052,0.0005188,0.0004883,0.0004272,0.0006409,0.0005798,0.0006714,0.0007324,0.0007935,0.0008545,0.0009155,0.0010376,0.0009766,0.0011597,0.0011902,0.0010986,0.0011902,0.0012512,0.0011292,0.0012817,0.0011292,0.0011292,0.0012207,0.0010681,0.0011597,0.0009766,0.0012207,0.0010376,0.0011902,0.0010986,0.0012207,0.0012207,0.0013123,0.0013123,0.0014038,0.0013428,0.0015564,0.0012207,0.0016479,0.0012207,0.0015869,0.0012817,0.0014648,0.0012207,0.0013428,0.0012512,0.0011902,0.0013123,0.0010986,0.0014038,0.0010681,0.0013733,0.0012512,0.0014038,0.0013428,0.0014648,0.0013428,0.0015869,0.0013733,0.0015259,0.0014648,0.0013428,0.0014648,0.0011292,0.0013123,0.0011597,0.0010681,0.0009460,0.0009766,0.0007324,0.0008850,0.0006714,0.0007629,0.0006104,0.0007629,0.0005493,0.0007629,0.0006104,0.0007019,0.0006714,0.0007935,0.0006104,0.0007629,0.0005798,0.0007019,0.0004578,0.0007324,0.0001831,0.0006104,0.0001221,0.0002441,0.0001526,-0.0000305,0.0000916,-0.0001221,-0.0000610,-0.0001831,-0.0001221,-0.0002441,0.0000305,-0.0003662,0.0000916,-0.0003662,-0.0000305,-0.0001221,-0.0001526,-0.0001526,-0.0001831,-0.0002441,-0.0002441,-0.0002747,-0.0004272,-0.0004272,-0.0005493,-0.0005493,-0.0006104,-0.0006409,-0.0007019,-0.0007324,-0.0007019,-0.0007629,-0.0006409,-0.0008240,-0.0006104,-0.0007935,-0.0006104,-0.0007324,-0.0006714,-0.0007019,-0.0007935,-0.0007019,-0.0009155,-0.0008240,-0.0009766,-0.0010071,-0.0010071,-0.0011902,-0.0010986,-0.0013428,-0.0010986,-0.0014038,-0.0011292,-0.0014343,-0.0011292,-0.0013123,-0.0012207,-0.0012207,-0.0011292,-0.0012817,-0.0009460,-0.0013123,-0.0009460,-0.0011292,-0.0011292,-0.0010071,-0.0011292,-0.0011292,-0.0010986,-0.0011597,-0.0013123,-0.0010681,-0.0013428,-0.0013123,-0.0012817,-0.0014038,-0.0014038,-0.0013123,-0.0014954,-0.0013123,-0.0014954,-0.0012817,-0.0014648,-0.0012512,-0.0012512,-0.0012207,-0.0010986,-0.0011597,-0.0009766,-0.0010071,-0.0008545,-0.0009460,-0.0008240,-0.0008545,-0.0007629,-0.0008545,-0.0007019,-0.0009155,-0.0006714,-0.0008545,-0.0007935,-0.0006714,-0.0008545,-0.0005188,-0.0008545,-0.0004272,-0.0006104,-0.0004272,-0.0004272,-0.0002747,-0.0002441,-0.0001221,-0.0001221,0.0000000,-0.0000305,0.0002136,-0.0000610,0.0002747,0.0000916,0.0002136,0.0002747,0.0001221,0.0003967,0.0001221,0.0004578,0.0003357,0.0003967,0.0004272,0.0004578,0.0004578,0.0006714,0.0004272,0.0006104,0.0006409,0.0005188,0.0007324,0.0004883,0.0007019,0.0006714,0.0006104,0.0007629,0.0006104,0.0008545,0.0006714,0.0009460,0.0007935,0.0010376,0.0010071,0.0010376,0.0011902,0.0010376,0.0013123,0.0011292,0.0012207,0.0011902,0.0011292,0.0012207,0.0010681,0.0010986,0.0010376,0.0010376,0.0009155,0.0010376,0.0009766,0.0010071,0.0010681,0.0010681,0.0010376,0.0013123,0.0010986,0.0014648,0.0012207,0.0014343,0.0013428,0.0014343,0.0014648,0.0013123,0.0014648,0.0012817,0.0012817,0.0013428,0.0010681,0.0012817,0.0010986,0.0010681,0.0011292,0.0010681,0.0011292,0.0011292,0.0012207,0.0011292,0.0013428,0.0011902,0.0014954,0.0012207,0.0015869,0.0013123,0.0015564,0.0014038,0.0014343,0.0015259,0.0012512,0.0014648,0.0011902,0.0013123,0.0012207,0.0010376,0.0012512,0.0009155,0.0011292,0.0010376,0.0009766,0.0010681,0.0010376,0.0010986,0.0010986,0.0013123,0.0010376,0.0014648,0.0011292,0.0014954,0.0013733,0.0014648,0.0013733,0.0014954,0.0013733,0.0014954,0.0013733,0.0012817,0.0013733,0.0011597,0.0012817,0.0011292,0.0011292,0.0011292,0.0011597,0.0010681,0.0011292,0.0011292,0.0011597,0.0011597,0.0012512,0.0010986,0.0014038,0.0010986,0.0014343,0.0010986,0.0014343,0.0011597,0.0013733,0.0012512,0.0012512,0.0012207,0.0012817,0.0013123,0.0012207,0.0013428,0.0012817,0.0013733,0.0013733,0.0013733,0.0014038,0.0014343,0.0015259,0.0013123,0.0015869,0.0014038,0.0014648,0.0014954,0.0013428,0.0015259,0.0013733,0.0014954,0.0013428,0.0014648,0.0014648,0.0014648,0.0014954,0.0015259,0.0014648,0.0016479,0.0015869,0.0014954,0.0017700,0.0014648,0.0017395,0.0015259,0.0016785,0.0014954,0.0016479,0.0014954,0.0015564,0.0015564,0.0015259,0.0015564,0.0015564,0.0015869,0.0015869,0.0016479,0.0016479,0.0017395,0.0016785,0.0017395,0.0017700,0.0017395,0.0017700,0.0017395,0.0017395,0.0017090,0.0017090,0.0016785,0.0017090,0.0016174,0.0017090,0.0015259,0.0017395,0.0015869,0.0017700,0.0016479,0.0017700,0.0017090,0.0018311,0.0018616,0.0018616,0.0019531,0.0018921,0.0021057,0.0018616,0.0020752,0.0019226,0.0020752,0.0018921,0.0021057,0.0017700,0.0020142,0.0018005,0.0018005,0.0018005,0.0017700,0.0016479,0.0017090,0.0015869,0.0016785,0.0016479,0.0016479,0.0016174,0.0017090,0.0016785,0.0017395,0.0017700,0.0017700,0.0018005,0.0018921,0.0017700,0.0018311,0.0018311,0.0016479,0.0018616,0.0014954,0.0016785,0.0013733,0.0014954,0.0012512,0.0013428,0.0011902,0.0011292,0.0011902,0.0010071,0.0012512,0.0010376,0.0011597,0.0011902,0.0011902,0.0012817,0.0012207,0.0013428,0.0012512,0.0012817,0.0012512,0.0011902,0.0011292,0.0011902,0.0008545,0.0010071,0.0007935,0.0007324,0.0007324,0.0005188,0.0006714,0.0004578,0.0005493,0.0004578,0.0004883,0.0005493,0.0004272,0.0006104,0.0003967,0.0006714,0.0003967,0.0005798,0.0003662,0.0005188,0.0003052,0.0003662,0.0002136,0.0001831,0.0000916,0.0001221,-0.0001221,0.0000610,-0.0001831,-0.0000916,-0.0001526,-0.0000916,-0.0001526,-0.0001221,-0.0000610,-0.0001221,-0.0000305,-0.0001221,0.0000000,-0.0001526,0.0000610,-0.0002441,-0.0001221,-0.0001831,-0.0003967,-0.0001526,-0.0007019,-0.0002747,-0.0008240,-0.0004578,-0.0008545,-0.0007019,-0.0008850,-0.0007935,-0.0008545,-0.0007935,-0.0008240,-0.0007019,-0.0007324,-0.0006409,-0.0005188,-0.0006714,-0.0003662,-0.0005798,-0.0003967,-0.0005188,-0.0004883,-0.0004883,-0.0006104,-0.0004883,-0.0007324,-0.0007019,-0.0007324,-0.0009155,-0.0007324,-0.0008850,-0.0009155,-0.0007324,-0.0009155,-0.0006104,-0.0007935,-0.0004578,-0.0005493,-0.0003357,-0.0002747,-0.0003357,-0.0000305,-0.0002747,0.0000305,-0.0002136,0.0000305,-0.0002747,-0.0000305,-0.0003357,-0.0001831,-0.0003357,-0.0003662,-0.0003357,-0.0004578,-0.0003357,-0.0003662,-0.0003967,-0.0001526,-0.0003052,-0.0000305,-0.0000305,0.0000916,0.0002136,0.0003052,0.0003662,0.0005188,0.0004883,0.0006409,0.0005493,0.0007019,0.0006409,0.0006409,0.0007019,0.0005798,0.0007019,0.0006104,0.0005798,0.0007019,0.0005493,0.0007324,0.0005798,0.0008545,0.0005798,0.0010681,0.0006409,0.0012207,0.0008240,0.0012817,0.0010376,0.0013733,0.0011902,0.0015259,0.0012207,0.0016479,0.0012817,0.0016479,0.0014038,0.0015869,0.0013733,0.0016174,0.0013733,0.0015259,0.0014343,0.0014648,0.0014648,0.0014648,0.0015259,0.0014648,0.0016785,0.0015259,0.0017395,0.0017700,0.0017700,0.0019836,0.0018005,0.0021973,0.0018921,0.0022888,0.0020447,0.0022278,0.0021973,0.0021667,0.0021973,0.0021667,0.0020447,0.0021667,0.0019226,0.0020447,0.0018921,0.0019226,0.0018921,0.0017395,0.0018921,0.0016785,0.0019226,0.0017395,0.0018005,0.0019531,0.0017395,0.0020752,0.0017700,0.0020752,0.0019226,0.0021057,0.0019836,0.0020142,0.0020142,0.0020142,0.0019531,0.0018921,0.0018616,0.0017700,0.0017090,0.0016174,0.0014954,0.0014954,0.0014343,0.0012207,0.0013733,0.0010376,0.0013733,0.0009766,0.0013123,0.0010071,0.0012817,0.0011597,0.0011902,0.0012817,0.0012207,0.0012512,0.0013428,0.0011292,0.0012817,0.0010376,0.0011902,0.0008850,0.0009766,0.0007019,0.0008240,0.0004883,0.0005798,0.0003052,0.0004272,0.0001831,0.0003357,0.0000610,0.0002441,0.0001526,0.0001831,0.0001526,0.0002441,0.0001221,0.0003662,0.0001526,0.0002441,0.0002441,0.0001526,0.0001831,0.0001221,-0.0000916,0.0000610,-0.0002136,-0.0001221,-0.0004578,-0.0002441,-0.0006104,-0.0004883,-0.0006104,-0.0007019,-0.0006714,-0.0007629,-0.0008240,-0.0007935,-0.0007324,-0.0009766,-0.0006409,-0.0010376,-0.0005493,-0.0010681,-0.0007019,-0.0009460,-0.0008545,-0.0008850,-0.0009460,-0.0010376,-0.0010071,-0.0011597,-0.0012207,-0.0011902,-0.0014343,-0.0013428,-0.0015259,-0.0015564,-0.0016174,-0.0016174,-0.0017395,-0.0016174,-0.0017700,-0.0016174,-0.0016479,-0.0015869,-0.0015869,-0.0015259,-0.0014648,-0.0014648,-0.0013428,-0.0014038,-0.0013123,-0.0013733,-0.0013428,-0.0013428,-0.0014343,-0.0013428,-0.0014954,-0.0014343,-0.0015259,-0.0014648,-0.0016174,-0.0014343,-0.0015869,-0.0014954,-0.0014648,-0.0014343,-0.0014343,-0.0012512,-0.0013123,-0.0011597,-0.0012207,-0.0010986,-0.0010681,-0.0010376,-0.0009460,-0.0010071,-0.0009155,-0.0009766,-0.0008850,-0.0009766,-0.0009460,-0.0009460,-0.0010376,-0.0009460,-0.0009766,-0.0010071,-0.0009460,-0.0010681,-0.0009155,-0.0009460,-0.0009460,-0.0008545,-0.0009155,-0.0007629,-0.0008240,-0.0007629,-0.0007019,-0.0007019,-0.0006409,-0.0005188,-0.0006104,-0.0003967,-0.0006104,-0.0003357,-0.0004578,-0.0003357,-0.0004272,-0.0003357,-0.0003357,-0.0003357,-0.0003357,-0.0003052,-0.0003052,-0.0003357,-0.0002441,-0.0003357,-0.0002441,-0.0001526,-0.0002747,-0.0000610,-0.0001831,0.0000000,-0.0000610,0.0001221,0.0000000,0.0002747,0.0000916,0.0003052,0.0002136,0.0003357,0.0003357,0.0003662,0.0003967,0.0004272,0.0003662,0.0005188,0.0003662,0.0005493,0.0004272,0.0004883,0.0005493,0.0005798,0.0005493,0.0006409,0.0006104,0.0006104,0.0007629,0.0005798,0.0008240,0.0006714,0.0007324,0.0007629,0.0006409,0.0008240,0.0005493,0.0007629,0.0006104,0.0005493,0.0006409,0.0005188,0.0005188,0.0005798,0.0005188,0.0003967,0.0006409,0.0003967,0.0005493,0.0005493,0.0004578,0.0006409,0.0005493,0.0005798,0.0006409,0.0006104,0.0006409,0.0007019,0.0004883,0.0007324,0.0005493,0.0005493,0.0006104,0.0003967,0.0005493,0.0003357,0.0004578,0.0002747,0.0003662,0.0003357,0.0001831,0.0004272,0.0001831,0.0003662,0.0003052,0.0003662,0.0003052,0.0004578,0.0003052,0.0004272,0.0004578,0.0003967,0.0004272,0.0003967,0.0003357,0.0003662,0.0003357,0.0001831,0.0003052,0.0000610,0.0002747,-0.0000610,0.0001831,-0.0000916,0.0000305,0.0000000,-0.0001831,0.0000305,-0.0002136,-0.0000610,-0.0001526,-0.0000610,-0.0002136,-0.0000610,-0.0002441,-0.0000916,-0.0003052,-0.0001221,-0.0003967,-0.0002441,-0.0003357,-0.0005188,-0.0003662,-0.0006104,-0.0004883,-0.0006104,-0.0007019,-0.0006409,-0.0007935,-0.0006714,-0.0008240,-0.0007324,-0.0007935,-0.0007629,-0.0007324,-0.0007629,-0.0006409,-0.0007324,-0.0005798,-0.0007324,-0.0005188,-0.0007019,-0.0006104,-0.0006714,-0.0006714,-0.0007935,-0.0006714,-0.0009766,-0.0007324,-0.0010071,-0.0008850,-0.0010986,-0.0009155,-0.0011902,-0.0008240,-0.0012207,-0.0008545,-0.0010376,-0.0007935,-0.0008240,-0.0007324,-0.0007324,-0.0006104,-0.0006104,-0.0004578,-0.0005798,-0.0005493,-0.0005188,-0.0005493,-0.0006409,-0.0005798,-0.0009155,-0.0005798,-0.0010071,-0.0007019,-0.0010071,-0.0009155,-0.0009460,-0.0008850,-0.0009155,-0.0008240,-0.0008545,-0.0006714,-0.0006714,-0.0005798,-0.0005493,-0.0004883,-0.0003662,-0.0004578,-0.0003357,-0.0003967,-0.0004578,-0.0003662,-0.0006104,-0.0005188,-0.0007019,-0.0006409,-0.0009155,-0.0007324,-0.0010376,-0.0008545,-0.0010376,-0.0009766,-0.0009460,-0.0009766,-0.0009155,-0.0009155,-0.0007324,-0.0008545,-0.0006409,-0.0007019,-0.0005493,-0.0006104,-0.0004578,-0.0006104,-0.0004578,-0.0005493,-0.0006409,-0.0005188,-0.0007935,-0.0006104,-0.0008850,-0.0007629,-0.0010071,-0.0008545,-0.0011292,-0.0008850,-0.0010986,-0.0010681,-0.0009155,-0.0010376,-0.0008240,-0.0008545,-0.0008240,-0.0006714,-0.0007019,-0.0005188,-0.0006104,-0.0004578,-0.0005188,-0.0005493,-0.0004272,-0.0006714,-0.0005188,-0.0007629,-0.0006409,-0.0009155,-0.0007629,-0.0010376,-0.0008850,-0.0010986,-0.0010071,-0.0011292,-0.0009155,-0.0011597,-0.0008240,-0.0010986,-0.0007935,-0.0009155,-0.0007019,-0.0008545,-0.0005798,-0.0008545,-0.0006104,-0.0008545,-0.0007019,-0.0008850,-0.0008240,-0.0010071,-0.0010071,-0.0011292,-0.0011902,-0.0012512,-0.0013733,-0.0013428,-0.0014954,-0.0013123,-0.0017090,-0.0012512,-0.0016174,-0.0013733,-0.0014038,-0.0014648,-0.0011902,-0.0014343,-0.0011292,-0.0013733,-0.0011597,-0.0011902,-0.0011902,-0.0012207,-0.0011597,-0.0012512,-0.0012207,-0.0011902,-0.0013733,-0.0012512,-0.0014343,-0.0012817,-0.0014954,-0.0013428,-0.0015869,-0.0014343,-0.0014954,-0.0015259,-0.0015259,-0.0015869,-0.0014648,-0.0015869,-0.0013733,-0.0016479,-0.0013123,-0.0015869,-0.0013733,-0.0014954,-0.0014038,-0.0014343,-0.0014038,-0.0014954,-0.0013733,-0.0014343,-0.0014954,-0.0013428,-0.0016174,-0.0013733,-0.0015869,-0.0015564,-0.0015259,-0.0016174,-0.0015869,-0.0016785,-0.0016479,-0.0017700,-0.0016174,-0.0018005,-0.0016174,-0.0018311,-0.0016174,-0.0018311,-0.0016174,-0.0017700,-0.0015869,-0.0016785,-0.0015869,-0.0015869,-0.0015564,-0.0015259,-0.0014648,-0.0014954,-0.0015259,-0.0013733,-0.0015869,-0.0013428,-0.0015564,-0.0014954,-0.0014648,-0.0015564,-0.0014954,-0.0015564,-0.0015259,-0.0014954,-0.0015564,-0.0013733,-0.0015259,-0.0013428,-0.0013123,-0.0013123,-0.0011597,-0.0011902,-0.0010681,-0.0009766,-0.0011292,-0.0007629,-0.0011292,-0.0007324,-0.0010071,-0.0008240,-0.0009460,-0.0008850,-0.0009460,-0.0009155,-0.0009155,-0.0009460,-0.0009766,-0.0008545,-0.0009155,-0.0007935,-0.0008240,-0.0007324,-0.0006714,-0.0006104,-0.0005188,-0.0005493,-0.0003662,-0.0003967,-0.0003967,-0.0002136,-0.0003662,-0.0001526,-0.0003662,-0.0001526,-0.0003967,-0.0001526,-0.0003967,-0.0002747,-0.0003662,-0.0003662,-0.0003662,-0.0003662,-0.0003967,-0.0003967,-0.0003052,-0.0004883,-0.0002441,-0.0004578,-0.0002747,-0.0003357,-0.0003052,-0.0003357,-0.0002441,-0.0003052,-0.0002441,-0.0002747,-0.0002747,-0.0003052,-0.0002747,-0.0003052,-0.0003357,-0.0002747,-0.0004272,-0.0002747,-0.0005188,-0.0002747,-0.0004578,-0.0003967,-0.0003967,-0.0004578,-0.0003662,-0.0003052,-0.0004578,-0.0002136,-0.0003357,-0.0003052,-0.0001526,-0.0002747,-0.0001221,-0.0001221,-0.0002747,0.0000305,-0.0002747,-0.0000916,-0.0002136,-0.0002136,-0.0002441,-0.0003357,-0.0003662,-0.0003967,-0.0005493,-0.0004272,-0.0007324,-0.0004883,-0.0007629,-0.0005798,-0.0007935,-0.0005798,-0.0007629,-0.0006104,-0.0007019,-0.0006409,-0.0005798,-0.0006714,-0.0005493,-0.0006104,-0.0006104,-0.0007019,-0.0005493,-0.0009460,-0.0005188,-0.0010681,-0.0007019,-0.0010986,-0.0009460,-0.0011292,-0.0010986,-0.0011292,-0.0011597,-0.0011902,-0.0011292,-0.0012512,-0.0010376,-0.0010681,-0.0011292,-0.0008545,-0.0010986,-0.0008545,-0.0008850,-0.0008850,-0.0009155,-0.0008545,-0.0009460,-0.0009155,-0.0010071,-0.0010376,-0.0011292,-0.0010376,-0.0013733,-0.0010681,-0.0014648,-0.0011597,-0.0013733,-0.0013123,-0.0013428,-0.0012207,-0.0013733,-0.0010681,-0.0013123,-0.0010071,-0.0011597,-0.0009766,-0.0011292,-0.0009766,-0.0010071,-0.0010376,-0.0010681,-0.0010376,-0.0012817,-0.0009766,-0.0014343,-0.0011597,-0.0014038,-0.0012512,-0.0014343,-0.0013428,-0.0013123,-0.0014648,-0.0011597,-0.0014038,-0.0011597,-0.0012207,-0.0010986,-0.0010986,-0.0010071,-0.0010376,-0.0010071,-0.0009155,-0.0010071,-0.0009155,-0.0010376,-0.0009155,-0.0010986,-0.0010071,-0.0010986,-0.0010986,-0.0010986,-0.0011292,-0.0010681,-0.0011292,-0.0010071,-0.0010986,-0.0008850,-0.0009766,-0.0008850,-0.0007629,-0.0008850,-0.0005188,-0.0007629,-0.0006104,-0.0004883,-0.0007324,-0.0002747,-0.0007019,-0.0004578,-0.0005188,-0.0006104,-0.0004883,-0.0006104,-0.0004883,-0.0006104,-0.0004578,-0.0005798,-0.0003662,-0.0004883,-0.0002747,-0.0002136,-0.0001831,-0.0000305,0.0000610,0.0000610,0.0002747,0.0003052,0.0003662,0.0005798,0.0003357,0.0007019,0.0004578,0.0006714,0.0005798,0.0006104,0.0006409,0.0005188,0.0006104,0.0005493,0.0005493,0.0006104,0.0005798,0.0005798,0.0006714,0.0006409,0.0007629,0.0008240,0.0007629,0.0009766,0.0009460,0.0010071,0.0010986,0.0010376,0.0011902,0.0011597,0.0010681,0.0012207,0.0009766,0.0011597,0.0010376,0.0009155,0.0009766,0.0009766,0.0007019,0.0010681,0.0006409,0.0009766,0.0006714,0.0010071,0.0007019,0.0010681,0.0008240,0.0010681,0.0010071,0.0011902,0.0010986,0.0012817,0.0011597,0.0013428,0.0011597,0.0013733,0.0011292,0.0012512,0.0012207,0.0010376,0.0011292,0.0008850,0.0010071,0.0008240,0.0009460,0.0007019,0.0008545,0.0007324,0.0008240,0.0007935,0.0007629,0.0008850,0.0008545,0.0008545,0.0010376,0.0007935,0.0011292,0.0008545,0.0010681,0.0008545,0.0010376,0.0007935,0.0009155,0.0007629,0.0007324,0.0006714,0.0006104,0.0005188,0.0005493,0.0003967,0.0003967,0.0004883,0.0003052,0.0004578,0.0003357,0.0003662,0.0004578,0.0003357,0.0004272,0.0003357,0.0004883,0.0003052,0.0004272,0.0003662,0.0002136,0.0004272,0.0001221,0.0002747,0.0000916,0.0001221,0.0000916,-0.0000610,0.0000000,-0.0001526,-0.0000916,-0.0001831,-0.0003052,-0.0001221,-0.0003662,-0.0001831,-0.0003662,-0.0003357,-0.0003967,-0.0003357,-0.0004578,-0.0003967,-0.0004578,-0.0004883,-0.0005493,-0.0004272,-0.0006409,-0.0005798,-0.0005493,-0.0008240,-0.0004272,-0.0009155,-0.0006104,-0.0008850,-0.0006714,-0.0008850,-0.0007629,-0.0008850,-0.0008545,-0.0009155,-0.0008850,-0.0008850,-0.0009460,-0.0008240,-0.0010376,-0.0007935,-0.0010376,-0.0007935,-0.0010986,-0.0007935,-0.0009766,-0.0009155,-0.0008850,-0.0009460,-0.0008850,-0.0009155,-0.0009155,-0.0009460,-0.0009155,-0.0009766,-0.0009155,-0.0010071,-0.0009766,-0.0010986,-0.0010071,-0.0011292,-0.0009766,-0.0012207,-0.0010071,-0.0012512,-0.0010681,-0.0010986,-0.0011902,-0.0009155,-0.0012207,-0.0009155,-0.0010681,-0.0008850,-0.0009460,-0.0009460,-0.0007629,-0.0009766,-0.0006409,-0.0009766,-0.0007324,-0.0008545,-0.0007935,-0.0007935,-0.0007629,-0.0008850,-0.0006714,-0.0008240,-0.0007019,-0.0007019,-0.0005798,-0.0006409,-0.0004578,-0.0004578,-0.0003052,-0.0003357,-0.0001221,-0.0001831,0.0000000,0.0000000,0.0000000,0.0000916,0.0001221,0.0000305,0.0001831,0.0000305,0.0000305,0.0001526,-0.0000916,0.0001221,-0.0001221,0.0001221,-0.0000916,0.0001831,0.0000000,0.0001831,0.0000916,0.0003357,0.0002136,0.0004883,0.0003662,0.0005493,0.0005493,0.0005493,0.0007324,0.0006409,0.0007019,0.0007324,0.0006714,0.0007324,0.0007629,0.0006104,0.0007324,0.0006714,0.0006714,0.0007019,0.0006409,0.0007019,0.0007935,0.0006104,0.0008850,0.0007324,0.0009155,0.0007935,0.0009766,0.0008545,0.0010071,0.0010681,0.0009766,0.0010681,0.0011292,0.0010071,0.0011902,0.0010986,0.0010376,0.0012207,0.0010376,0.0010986,0.0010986,0.0010071,0.0010986,0.0010071,0.0010376,0.0009155,0.0010376,0.0009460,0.0009155,0.0009766,0.0009155,0.0008850,0.0010681,0.0008850,0.0010071,0.0010681,0.0009460,0.0011597,0.0011292,0.0010986,0.0013123,0.0009766,0.0013733,0.0011292,0.0012512,0.0011902,0.0011902,0.0011292,0.0011902,0.0010376,0.0010986,0.0010681,0.0009766,0.0009766,0.0009766,0.0009155,0.0009766,0.0009155,0.0009155,0.0009766,0.0009460,0.0009766,0.0010071,0.0010376,0.0009766,0.0011597,0.0009460,0.0011597,0.0010376,0.0010376,0.0011597,0.0009460,0.0010986,0.0009460,0.0009460,0.0010986,0.0007324,0.0012512,0.0006104,0.0012207,0.0007019,0.0011597,0.0008240,0.0010681,0.0009766,0.0009460,0.0012207,0.0008545,0.0013428,0.0008545,0.0013123,0.0010071,0.0011902,0.0010376,0.0011597,0.0010986,0.0010986,0.0010376,0.0011292,0.0010071,0.0011292,0.0010376,0.0010071,0.0011292,0.0010376,0.0010681,0.0010986,0.0010071,0.0012817,0.0009766,0.0011902,0.0011597,0.0010986,0.0012207,0.0011597,0.0011292,0.0012207,0.0011292,0.0012207,0.0010681,0.0012817,0.0010986,0.0012207,0.0011597,0.0011597,0.0012207,0.0011292,0.0013123,0.0011292,0.0013123,0.0012512,0.0012512,0.0013733,0.0012512,0.0014648,0.0013428,0.0015259,0.0014038,0.0015564,0.0014343,0.0016174,0.0015259,0.0015869,0.0015564,0.0015869,0.0015869,0.0014648,0.0017090,0.0013123,0.0016785,0.0013733,0.0015564,0.0014038,0.0016479,0.0013123,0.0016785,0.0013733,0.0016785,0.0014648,0.0017395,0.0014954,0.0017700,0.0015869,0.0018005,0.0016174,0.0018005,0.0016174,0.0018311,0.0015259,0.0017700,0.0015259,0.0017395,0.0015564,0.0016174,0.0015564,0.0016174,0.0016785,0.0015869,0.0018005,0.0016174,0.0019226,0.0017395,0.0019531,0.0019531,0.0018616,0.0021362,0.0018921,0.0020447,0.0019226,0.0019226,0.0018616,0.0018005,0.0017395,0.0016479,0.0015564,0.0015869,0.0014648,0.0014343,0.0015259,0.0013733,0.0014954,0.0016174,0.0013428,0.0018005,0.0014648,0.0018005,0.0017090,0.0018005,0.0017700,0.0018616,0.0017090,0.0018616,0.0016785,0.0016479,0.0016174,0.0014648,0.0013733,0.0013733,0.0011902,0.0011902,0.0010986,0.0010681,0.0010376,0.0010986,0.0009460,0.0011597,0.0010071,0.0012207,0.0011597,0.0011292,0.0013428,0.0011292,0.0012817,0.0012207,0.0011597,0.0011902,0.0009460,0.0011597,0.0007935,0.0009155,0.0007935,0.0005188,0.0007935,0.0004578,0.0004883,0.0005493,0.0003357,0.0005493,0.0004578,0.0003967,0.0005798,0.0004578,0.0005188,0.0006714,0.0004578,0.0006409,0.0005493,0.0004883,0.0005493,0.0004883,0.0003052,0.0004578,0.0001831,0.0002747,0.0000916,0.0000916,0.0000000,0.0000305,-0.0000610,-0.0001526,-0.0000916,-0.0001831,-0.0000610,-0.0001526,-0.0001221,-0.0001221,-0.0001221,-0.0000916,-0.0001221,-0.0001221,-0.0000916,-0.0002441,-0.0000610,-0.0003662,-0.0000305,-0.0004578,-0.0002136,-0.0004272,-0.0003662,-0.0004883,-0.0004578,-0.0005798,-0.0005188,-0.0005188,-0.0006104,-0.0005798,-0.0005798,-0.0005798,-0.0004883,-0.0005798,-0.0004272,-0.0005188,-0.0004272,-0.0004272,-0.0003662,-0.0003662,-0.0003662,-0.0003357,-0.0003662,-0.0002747,-0.0003357,-0.0003967,-0.0002441,-0.0005188,-0.0002441,-0.0004883,-0.0004272,-0.0003662,-0.0005798,-0.0003662,-0.0005493,-0.0003662,-0.0004883,-0.0003357,-0.0004578,-0.0003052,-0.0003052,-0.0003052,-0.0001221,-0.0002441,-0.0000610,-0.0001221,-0.0000305,0.0000610,-0.0000305,0.0001526,0.0000610,0.0002136,0.0001526,0.0002441,0.0001221,0.0003357,0.0001221,0.0003357,0.0002136,0.0003052,0.0002747,0.0002441,0.0003357,0.0002747,0.0003357,0.0003662,0.0003052,0.0004578,0.0003357,0.0004883,0.0005798,0.0004578,0.0007019,0.0006104,0.0007019,0.0009155,0.0007324,0.0009766,0.0009155,0.0010071,0.0010681,0.0009766,0.0012207,0.0009766,0.0012817,0.0010071,0.0012512,0.0010681,0.0012207,0.0011292,0.0011902,0.0011292,0.0012817,0.0010986,0.0013733,0.0011902,0.0014038,0.0013123,0.0014038,0.0014038,0.0015259,0.0015869,0.0014954,0.0017395,0.0015564,0.0017395,0.0017090,0.0016479,0.0018311,0.0016479,0.0017700,0.0017395,0.0016785,0.0017395,0.0017090,0.0016479,0.0017700,0.0016174,0.0017395,0.0016479,0.0017700,0.0016174,0.0018005,0.0016785,0.0018616,0.0017090,0.0018311,0.0018311,0.0017700,0.0019226,0.0017700,0.0017700,0.0018616,0.0017090,0.0018311,0.0016174,0.0017700,0.0015259,0.0017090,0.0014954,0.0015259,0.0015259,0.0014954,0.0014343,0.0014954,0.0014343,0.0014954,0.0014648,0.0014954,0.0014648,0.0015869,0.0014954,0.0014648,0.0015564,0.0014038,0.0016174,0.0012817,0.0015259,0.0011902,0.0013733,0.0011902,0.0012207,0.0010681,0.0010681,0.0009766,0.0009155,0.0009766,0.0007324,0.0009460,0.0007019,0.0008240,0.0007324,0.0007935,0.0006104,0.0007629,0.0005798,0.0007019,0.0005798,0.0006104,0.0004883,0.0005188,0.0004578,0.0003967,0.0004272,0.0002747,0.0002747,0.0002441,0.0001526,0.0000916,0.0001831,-0.0001221,0.0001221,-0.0001221,-0.0001221,-0.0000916,-0.0002441,-0.0001221,-0.0003662,-0.0001831,-0.0004272,-0.0002747,-0.0003967,-0.0003662,-0.0004578,-0.0003967,-0.0005188,-0.0003967,-0.0005493,-0.0005188,-0.0004883,-0.0007019,-0.0005493,-0.0006714,-0.0007324,-0.0007324,-0.0007935,-0.0008850,-0.0008240,-0.0009766,-0.0009155,-0.0010071,-0.0010071,-0.0010376,-0.0010681,-0.0010681,-0.0010986,-0.0010376,-0.0010986,-0.0010681,-0.0010376,-0.0011292,-0.0009766,-0.0011597,-0.0009460,-0.0011292,-0.0010071,-0.0010071,-0.0012207,-0.0008240,-0.0013733,-0.0007935,-0.0012817,-0.0010376,-0.0010986,-0.0011597,-0.0010986,-0.0011597,-0.0010986,-0.0011597,-0.0010986,-0.0011902,-0.0010376,-0.0012207,-0.0010986,-0.0010681,-0.0011597,-0.0010071,-0.0011597,-0.0010376,-0.0010681,-0.0010376,-0.0010376,-0.0009460,-0.0010986,-0.0008545,-0.0010681,-0.0008850,-0.0009460,-0.0009155,-0.0009155,-0.0008545,-0.0009155,-0.0007935,-0.0009155,-0.0007629,-0.0008240,-0.0007019,-0.0007324,-0.0007629,-0.0006104,-0.0007324,-0.0005798,-0.0005798,-0.0006104,-0.0004578,-0.0005798,-0.0004883,-0.0004578,-0.0004883,-0.0004272,-0.0004578,-0.0003662,-0.0005798,-0.0001526,-0.0006714,-0.0001831,-0.0005188,-0.0003662,-0.0003357,-0.0003662,-0.0004272,-0.0001831,-0.0004578,-0.0001221,-0.0003662,-0.0001221,-0.0002441,-0.0001221,-0.0000610,-0.0001221,-0.0000610,0.0000916,-0.0001831,0.0003662,-0.0001221,0.0002136,0.0001221,0.0001831,0.0002136,0.0002441,0.0001221,0.0003357,0.0001526,0.0002747,0.0002136,0.0002441,0.0002747,0.0001831,0.0003357,0.0002136,0.0003357,0.0003662,0.0002441,0.0004578,0.0003662,0.0004272,0.0005493,0.0004272,0.0006104,0.0004578,0.0006409,0.0005493,0.0006104,0.0005188,0.0006409,0.0004272,0.0007019,0.0003662,0.0006409,0.0004578,0.0004883,0.0004578,0.0005493,0.0003662,0.0005493,0.0004272,0.0004883,0.0005493,0.0003967,0.0005188,0.0004272,0.0004883,0.0004883,0.0004272,0.0005493,0.0003662,0.0005798,0.0003662,0.0005493,0.0003357,0.0006409,0.0003052,0.0006714,0.0003357,0.0006714,0.0003967,0.0005798,0.0004578,0.0005188,0.0004272,0.0005493,0.0003357,0.0004883,0.0002441,0.0003357,0.0002441,0.0001831,0.0001831,0.0001526,0.0000610,0.0001831,0.0000000,0.0001526,0.0000305,0.0001526,0.0001221,0.0001526,0.0001526,0.0001526,0.0001831,0.0001831,0.0001831,0.0001526,0.0001526,0.0000916,0.0000000,0.0000000,-0.0001526,-0.0000610,-0.0002747,-0.0002441,-0.0003052,-0.0003967,-0.0003662,-0.0003967,-0.0004883,-0.0003357,-0.0005188,-0.0003967,-0.0003967,-0.0004272,-0.0002441,-0.0005493,-0.0001526,-0.0005493,-0.0001221,-0.0005493,-0.0002136,-0.0004883,-0.0003967,-0.0004578,-0.0005188,-0.0004883,-0.0005798,-0.0006104,-0.0006714,-0.0006409,-0.0007324,-0.0007019,-0.0007019,-0.0006714,-0.0007019,-0.0006714,-0.0006104,-0.0007019,-0.0004883,-0.0006409,-0.0005493,-0.0004578,-0.0006714,-0.0003357,-0.0007935,-0.0003662,-0.0007019,-0.0005493,-0.0005493,-0.0007324,-0.0005493,-0.0007324,-0.0005493,-0.0007629,-0.0005798,-0.0007019,-0.0005493,-0.0006714,-0.0004883,-0.0006714,-0.0004883,-0.0005798,-0.0004883,-0.0005798,-0.0004883,-0.0005798,-0.0005798,-0.0005493,-0.0006104,-0.0006409,-0.0006409,-0.0006104,-0.0007324,-0.0005798,-0.0007324,-0.0005798,-0.0006714,-0.0005493,-0.0006409,-0.0005188,-0.0006104,-0.0004578,-0.0006104,-0.0004272,-0.0006714,-0.0004883,-0.0006409,-0.0005798,-0.0007019,-0.0005798,-0.0008850,-0.0005188,-0.0009460,-0.0005493,-0.0007629,-0.0007019,-0.0005188,-0.0007324,-0.0003967,-0.0005798,-0.0003662,-0.0004578,-0.0003052,-0.0003967,-0.0003357,-0.0003357,-0.0004883,-0.0003052,-0.0006714,-0.0003662,-0.0007935,-0.0005798,-0.0007935,-0.0007935,-0.0008240,-0.0008240,-0.0009155,-0.0007324,-0.0009766,-0.0006714,-0.0008240,-0.0006714,-0.0005493,-0.0007019,-0.0004272,-0.0005798,-0.0004272,-0.0005188,-0.0004272,-0.0005798,-0.0005188,-0.0006104,-0.0006714,-0.0007629,-0.0007935,-0.0008850,-0.0009766,-0.0009766,-0.0011292,-0.0010376,-0.0010681,-0.0011292,-0.0010071,-0.0011902,-0.0009155,-0.0010376,-0.0008850,-0.0008850,-0.0008240,-0.0008545,-0.0006714,-0.0007935,-0.0007019,-0.0007324,-0.0008850,-0.0006714,-0.0009766,-0.0008240,-0.0010986,-0.0009460,-0.0013123,-0.0010071,-0.0014038,-0.0012512,-0.0013123,-0.0014038,-0.0013428,-0.0012817,-0.0014343,-0.0010681,-0.0013123,-0.0010376,-0.0010681,-0.0010376,-0.0008545,-0.0009460,-0.0007629,-0.0009155,-0.0007935,-0.0009155,-0.0008850,-0.0009460,-0.0010681,-0.0010986,-0.0012817,-0.0011902,-0.0014648,-0.0013123,-0.0015869,-0.0014038,-0.0015869,-0.0014648,-0.0015259,-0.0014648,-0.0013733,-0.0013428,-0.0012817,-0.0012512,-0.0012207,-0.0010681,-0.0011597,-0.0010376,-0.0012207,-0.0011597,-0.0010986,-0.0014038,-0.0012207,-0.0015259,-0.0014954,-0.0015564,-0.0017700,-0.0016785,-0.0018311,-0.0018616,-0.0018005,-0.0018616,-0.0018005,-0.0017395,-0.0017700,-0.0016174,-0.0015869,-0.0016174,-0.0012817,-0.0015869,-0.0011902,-0.0014343,-0.0012512,-0.0013428,-0.0013123,-0.0013428,-0.0014343,-0.0013428,-0.0015259,-0.0015259,-0.0015564,-0.0016479,-0.0016174,-0.0015869,-0.0016785,-0.0015869,-0.0015869,-0.0015564,-0.0014343,-0.0014038,-0.0013123,-0.0012512,-0.0011597,-0.0011292,-0.0010376,-0.0009766,-0.0009766,-0.0009155,-0.0009766,-0.0009155,-0.0009460,-0.0010071,-0.0010376,-0.0010071,-0.0011292,-0.0009766,-0.0012512,-0.0009766,-0.0012207,-0.0009766,-0.0011292,-0.0010071,-0.0009460,-0.0009155,-0.0008545,-0.0007629,-0.0007019,-0.0007019,-0.0005493,-0.0006409,-0.0004883,-0.0005188,-0.0005798,-0.0003662,-0.0006409,-0.0003662,-0.0007324,-0.0004272,-0.0005798,-0.0006409,-0.0005188,-0.0006714,-0.0005188,-0.0006409,-0.0005493,-0.0005798,-0.0005493,-0.0004578,-0.0005493,-0.0004272,-0.0004578,-0.0003967,-0.0003967,-0.0003662,-0.0003052,-0.0004272,-0.0003052,-0.0003967,-0.0003052,-0.0004272,-0.0003967,-0.0004272,-0.0004578,-0.0003052,-0.0006104,-0.0003357,-0.0005493,-0.0005188,-0.0003357,-0.0006409,-0.0003052,-0.0005188,-0.0003357,-0.0004578,-0.0002136,-0.0004272,-0.0001221,-0.0003357,-0.0000916,-0.0003052,-0.0000305,-0.0003052,-0.0000610,-0.0002441,-0.0001221,-0.0003662,-0.0001526,-0.0004578,-0.0002136,-0.0005188,-0.0003052,-0.0005188,-0.0003967,-0.0004883,-0.0004272,-0.0004883,-0.0003967,-0.0004883,-0.0003662,-0.0003967,-0.0003967,-0.0003357,-0.0003662,-0.0003967,-0.0003357,-0.0004883,-0.0003662,-0.0004578,-0.0006104,-0.0004272,-0.0007629,-0.0005188,-0.0008545,-0.0006104,-0.0009155,-0.0007629,-0.0007935,-0.0009460,-0.0007324,-0.0009155,-0.0007935,-0.0007935,-0.0008240,-0.0007629,-0.0007629,-0.0007019,-0.0007629,-0.0006714,-0.0007935,-0.0006714,-0.0007629,-0.0007324,-0.0007935,-0.0007629,-0.0008850,-0.0008240,-0.0008545,-0.0010071,-0.0008850,-0.0010986,-0.0009460,-0.0010681,-0.0010376,-0.0010986,-0.0010071,-0.0011597,-0.0009766,-0.0011292,-0.0010071,-0.0010681,-0.0010071,-0.0010681,-0.0009460,-0.0010681,-0.0008850,-0.0011292,-0.0009155,-0.0010986,-0.0010071,-0.0010681,-0.0011292,-0.0010681,-0.0012817,-0.0010681,-0.0013733,-0.0011902,-0.0012817,-0.0013733,-0.0011902,-0.0014648,-0.0012512,-0.0013733,-0.0012207,-0.0013428,-0.0011902,-0.0013428,-0.0011902,-0.0011902,-0.0012512,-0.0011292,-0.0013428,-0.0010986,-0.0013123,-0.0011597,-0.0012512,-0.0013428,-0.0011902,-0.0013733,-0.0012512,-0.0013733,-0.0013123,-0.0012817,-0.0013733,-0.0011902,-0.0013428,-0.0011292,-0.0012817,-0.0010376,-0.0012512,-0.0009460,-0.0011292,-0.0009766,-0.0009155,-0.0010071,-0.0008850,-0.0008850,-0.0009460,-0.0007629,-0.0009155,-0.0008240,-0.0008240,-0.0008240,-0.0007324,-0.0007935,-0.0007019,-0.0007629,-0.0005493,-0.0006409,-0.0005188,-0.0005493,-0.0004578,-0.0003052,-0.0003967,-0.0002136,-0.0003052,-0.0000916,-0.0002136,-0.0000305,-0.0000610,-0.0000610,0.0000916,-0.0000610,0.0000610,0.0000916,0.0000305,0.0000916,0.0000916,0.0000305,0.0002441,-0.0000610,0.0002747,0.0000000,0.0002441,0.0001831,0.0001221,0.0002747,0.0000916,0.0003662,0.0000916,0.0004883,0.0000610,0.0005798,0.0001526,0.0005798,0.0003052,0.0005798,0.0004883,0.0005493,0.0006104,0.0006104,0.0007019,0.0007019,0.0006714,0.0007629,0.0006409,0.0008545,0.0006409,0.0007935,0.0007324,0.0006714,0.0008240,0.0006714,0.0007935,0.0007324,0.0008240,0.0008240,0.0008850,0.0009155,0.0009155,0.0010071,0.0010681,0.0010986,0.0010986,0.0011902,0.0010986,0.0012512,0.0010376,0.0011902,0.0009766,0.0011292,0.0008850,0.0009766,0.0008240,0.0007935,0.0007935,0.0007629,0.0007019,0.0007935,0.0007019,0.0007629,0.0008545,0.0007935,0.0010071,0.0009155,0.0010681,0.0010071,0.0012207,0.0009155,0.0013733,0.0008240,0.0013428,0.0007935,0.0010071,0.0009460,0.0006409,0.0009460,0.0003662,0.0007629,0.0004272,0.0003967,0.0005493,0.0002747,0.0004578,0.0003967,0.0003052,0.0004578,0.0004272,0.0004272,0.0004883,0.0004883,0.0004578,0.0006104,0.0003662,0.0006409,0.0002747,0.0005798,0.0002136,0.0005493,0.0000610,0.0003662,0.0000000,0.0001221,0.0000610,-0.0001221,-0.0000610,-0.0000916,-0.0002136,-0.0000916,-0.0002747,-0.0001221,-0.0001526,-0.0001831,-0.0001526,-0.0001221,-0.0000610,-0.0001221,-0.0000305,-0.0000305,-0.0001221,0.0000305,-0.0001831,-0.0000916,-0.0000916,-0.0003357,-0.0000916,-0.0004883,-0.0003357,-0.0005188,-0.0005188,-0.0005798,-0.0006714,-0.0007019,-0.0007324,-0.0007324,-0.0007629,-0.0007324,-0.0007935,-0.0006104,-0.0007019,-0.0005798,-0.0007019,-0.0005188,-0.0006104,-0.0005188,-0.0005798,-0.0005798,-0.0006104,-0.0006714,-0.0007019,-0.0008240,-0.0007935,-0.0009155,-0.0009766,-0.0010071,-0.0011292,-0.0010681,-0.0011597,-0.0010986,-0.0011292,-0.0011292,-0.0010681,-0.0010376,-0.0009460,-0.0009460,-0.0008240,-0.0008545,-0.0007935,-0.0006409,-0.0008545,-0.0005798,-0.0008545,-0.0005798,-0.0009460,-0.0005493,-0.0010071,-0.0006409,-0.0010071,-0.0007935,-0.0009460,-0.0008545,-0.0009460,-0.0008240,-0.0008850,-0.0007019,-0.0008850,-0.0005798,-0.0007629,-0.0004578,-0.0006104,-0.0003662,-0.0004272,-0.0003662,-0.0003357,-0.0003662,-0.0002747,-0.0003357,-0.0002441,-0.0004883,-0.0001221,-0.0005493,-0.0002747,-0.0003967,-0.0003967,-0.0003357,-0.0003662,-0.0003357,-0.0003052,-0.0002136,-0.0002441,-0.0000916,-0.0002136,0.0001221,-0.0002136,0.0002747,-0.0000610,0.0002441,0.0001526,0.0001526,0.0002747,0.0002136,0.0002747,0.0001831,0.0003357,0.0000916,0.0003967,0.0001221,0.0003662,0.0001221,0.0003662,0.0001831,0.0003967,0.0001526,0.0004578,0.0001831,0.0005188,0.0002441,0.0005188,0.0003662,0.0005188,0.0004578,0.0005188,0.0005493,0.0006104,0.0004883,0.0007935,0.0003967,0.0009155,0.0003967,0.0008240,0.0005798,0.0007019,0.0007019,0.0005798,0.0007324,0.0006409,0.0006714,0.0007019,0.0006409,0.0007324,0.0006714,0.0007019,0.0008240,0.0006714,0.0009155,0.0007629,0.0009460,0.0007935,0.0010071,0.0007935,0.0010986,0.0007935,0.0010681,0.0007629,0.0009766,0.0008240,0.0008240,0.0008545,0.0007019,0.0007935,0.0006714,0.0007935,0.0005798,0.0008240,0.0005493,0.0007935,0.0007019,0.0007324,0.0007935,0.0007935,0.0008545,0.0008240,0.0009155,0.0008545,0.0008545,0.0009460,0.0007935,0.0009460,0.0007629,0.0008545,0.0007629,0.0008240,0.0006409,0.0008850,0.0005798,0.0007629,0.0007324,0.0006104,0.0008545,0.0006104,0.0008240,0.0007324,0.0008240,0.0007935,0.0008850,0.0008240,0.0008545,0.0008850,0.0008545,0.0009155,0.0008240,0.0009155,0.0008240,0.0008850,0.0008240,0.0008240,0.0008545,0.0007629,0.0008545,0.0008240,0.0008545,0.0008545,0.0008850,0.0008545,0.0010376,0.0008240,0.0011292,0.0008240,0.0011292,0.0009460,0.0010681,0.0010071,0.0009766,0.0010071,0.0009460,0.0009766,0.0008545,0.0009460,0.0007935,0.0008850,0.0007324,0.0008545,0.0007324,0.0009155,0.0007629,0.0008850,0.0009155,0.0009155,0.0010071,0.0010681,0.0010681,0.0011292,0.0011902,0.0012207,0.0011902,0.0013428,0.0011292,0.0014038,0.0011292,0.0013733,0.0011292,0.0012817,0.0010986,0.0012207,0.0011597,0.0011292,0.0011902,0.0010681,0.0011902,0.0012512,0.0010986,0.0014343,0.0011292,0.0014954,0.0012512,0.0015869,0.0013733,0.0015869,0.0014954,0.0015259,0.0016479,0.0014343,0.0017090,0.0013733,0.0016785,0.0014343,0.0014954,0.0014648,0.0014648,0.0013428,0.0014954,0.0012512,0.0015564,0.0012512,0.0015869,0.0012817,0.0015869,0.0014038,0.0015564,0.0015869,0.0015259,0.0016785,0.0015869,0.0016785,0.0017090,0.0016174,0.0016479,0.0017395,0.0015259,0.0018005,0.0014954,0.0016174,0.0015869,0.0015564,0.0015564,0.0015259,0.0014954,0.0015564,0.0014954,0.0016174,0.0015259,0.0015869,0.0016174,0.0015869,0.0016785,0.0016479,0.0016174,0.0016174,0.0016479,0.0016479,0.0016174,0.0015869,0.0016174,0.0014954,0.0016174,0.0014648,0.0015259,0.0015259,0.0014038,0.0015564,0.0013733,0.0014954,0.0014954,0.0013733,0.0014954,0.0014038,0.0014343,0.0013733,0.0014038,0.0013123,0.0013733,0.0012817,0.0013123,0.0012207,0.0012207,0.0011902,0.0011597,0.0011292,0.0011292,0.0011292,0.0009766,0.0012207,0.0008545,0.0011292,0.0009155,0.0009766,0.0009460,0.0008545,0.0009155,0.0007324,0.0008545,0.0006409,0.0008240,0.0005188,0.0007629,0.0005493,0.0005798,0.0006409,0.0004272,0.0006714,0.0004883,0.0006409,0.0004578,0.0006714,0.0004578,0.0006104,0.0004883,0.0005188,0.0005188,0.0004578,0.0003967,0.0003662,0.0003052,0.0002136,0.0002747,0.0000916,0.0001831,0.0000000,0.0000610,-0.0000305,0.0000610,-0.0000916,0.0000610,-0.0001526,0.0001221,-0.0000916,0.0000916,0.0000000,-0.0000305,0.0000610,-0.0000610,0.0000916,-0.0001221,0.0000305,-0.0002136,-0.0001221,-0.0002136,-0.0003357,-0.0002136,-0.0004578,-0.0003967,-0.0004883,-0.0006104,-0.0004578,-0.0007019,-0.0004883,-0.0007019,-0.0005798,-0.0004883,-0.0007019,-0.0003052,-0.0006714,-0.0003357,-0.0004578,-0.0003967,-0.0003052,-0.0004272,-0.0003357,-0.0003967,-0.0004272,-0.0003357,-0.0005798,-0.0003662,-0.0005798,-0.0005188,-0.0005493,-0.0006409,-0.0004578,-0.0007019,-0.0003357,-0.0007324,-0.0002441,-0.0005493,-0.0002747,-0.0002441,-0.0002747,-0.0000610,-0.0001221,-0.0000610,0.0000916,-0.0000916,0.0001526,0.0000000,0.0000610,0.0000610,0.0000305,0.0000305,-0.0000610,0.0000305,-0.0000916,0.0000305,-0.0000610,0.0000305,-0.0000305,0.0000916,0.0000610,0.0001831,0.0002136,0.0002441,0.0003967,0.0003052,0.0005798,0.0003967,0.0006104,0.0004883,0.0006714,0.0006104,0.0007019,0.0006104,0.0006714,0.0006714,0.0006104,0.0006714,0.0005798,0.0007019,0.0005798,0.0007019,0.0005188,0.0007935,0.0005798,0.0007935,0.0007629,0.0007324,0.0009460,0.0008850,0.0009460,0.0010986,0.0010071,0.0012207,0.0011292,0.0012817,0.0011902,0.0013123,0.0013428,0.0012817,0.0013123,0.0013428,0.0013428,0.0013123,0.0013428,0.0012817,0.0012817,0.0014343,0.0011292,0.0015259,0.0011597,0.0015259,0.0012817,0.0014648,0.0014648,0.0014343,0.0015564,0.0015564,0.0015259,0.0016174,0.0016174,0.0016785,0.0017090,0.0016785,0.0017090,0.0017090,0.0017395,0.0017090,0.0017395,0.0017090,0.0016785,0.0017395,0.0015869,0.0017090,0.0015259,0.0015869,0.0015564,0.0015259,0.0014343,0.0014648,0.0014038,0.0014648,0.0013428,0.0014343,0.0012512,0.0014954,0.0012512,0.0014954,0.0013123,0.0014038,0.0013428,0.0014954,0.0013123,0.0014648,0.0013123,0.0013428,0.0013428,0.0013123,0.0011597,0.0012512,0.0010986,0.0011902,0.0009766,0.0010376,0.0009155,0.0009766,0.0008850,0.0009460,0.0008240,0.0008850,0.0008545,0.0008850,0.0008240,0.0008545,0.0009155,0.0007935,0.0008545,0.0008545,0.0006714,0.0008545,0.0007019,0.0006104,0.0007324,0.0005188,0.0005188,0.0005188,0.0004578,0.0003662,0.0004883,0.0001831,0.0004578,0.0001831,0.0003662,0.0001526,0.0003052,0.0001221,0.0002136,0.0001526,0.0001221,0.0000916,0.0000000,0.0000916,-0.0001221,0.0000000,-0.0001526,-0.0001831,-0.0001831,-0.0002747,-0.0002441,-0.0003357,-0.0003357,-0.0004272,-0.0003967,-0.0003662,-0.0004883,-0.0004272,-0.0005188,-0.0004883,-0.0004883,-0.0006104,-0.0004578,-0.0006714,-0.0004578,-0.0006714,-0.0006714,-0.0006104,-0.0006714,-0.0007324,-0.0006409,-0.0007935,-0.0008240,-0.0006104,-0.0010071,-0.0006714,-0.0008545,-0.0008850,-0.0007324,-0.0008850,-0.0007935,-0.0007935,-0.0008240,-0.0008240,-0.0007019,-0.0008850,-0.0006104,-0.0009766,-0.0005493,-0.0009766,-0.0006104,-0.0008545,-0.0008240,-0.0007629,-0.0008240,-0.0008850,-0.0007324,-0.0008240,-0.0009155,-0.0006409,-0.0009460,-0.0007935,-0.0007019,-0.0007935,-0.0007019,-0.0006714,-0.0007629,-0.0006104,-0.0006104,-0.0007019,-0.0005493,-0.0007019,-0.0004883,-0.0006714,-0.0005798,-0.0005798,-0.0006714,-0.0005188,-0.0006714,-0.0006409,-0.0005493,-0.0007019,-0.0005798,-0.0005798,-0.0006714,-0.0004578,-0.0006409,-0.0004883,-0.0004883,-0.0005493,-0.0003662,-0.0004578,-0.0003967,-0.0003357,-0.0004272,-0.0002136,-0.0004578,-0.0001526,-0.0004272,-0.0002441,-0.0002747,-0.0003357,-0.0002136,-0.0003052,-0.0003357,-0.0002747,-0.0003357,-0.0002441,-0.0003052,-0.0002441,-0.0002747,-0.0001831,-0.0001526,-0.0001526,-0.0000916,-0.0000305,-0.0000610,0.0000916,-0.0000610,0.0001526,0.0001526,0.0000610,0.0002441,0.0000305,0.0001831,0.0002136,0.0000000,0.0001831,0.0000610,0.0000610,0.0000610,0.0001221,-0.0000305,0.0002136,-0.0000305,0.0002136,0.0001221,0.0002441,0.0002747,0.0002747,0.0003967,0.0003662,0.0005188,0.0003662,0.0006409,0.0003967,0.0006409,0.0004272,0.0005188,0.0005493,0.0003357,0.0005798,0.0003357,0.0003662,0.0004578,0.0001221,0.0005798,0.0001221,0.0004578,0.0002136,0.0003662,0.0003052,0.0003967,0.0003357,0.0003967,0.0003967,0.0003967,0.0004578,0.0004272,0.0004272,0.0004578,0.0004272,0.0004272,0.0004272,0.0003967,0.0003967,0.0003967,0.0003357,0.0003052,0.0003967,0.0001831,0.0004578,0.0001221,0.0003967,0.0001526,0.0003662,0.0001526,0.0002747,0.0002136,0.0002747,0.0002136,0.0002747,0.0001526,0.0003662,0.0001526,0.0003357,0.0001221,0.0003662,0.0000916,0.0003967,0.0000305,0.0003052,0.0000305,0.0001831,0.0001221,-0.0000305,0.0001526,-0.0001526,0.0000916,-0.0001526,-0.0000610,-0.0001526,-0.0001221,-0.0002441,-0.0000916,-0.0003052,-0.0001831,-0.0002441,-0.0001831,-0.0003052,-0.0001526,-0.0002747,-0.0001831,-0.0002441,-0.0002136,-0.0002136,-0.0002441,-0.0001831,-0.0003357,-0.0002747,-0.0003357,-0.0003967,-0.0003662,-0.0004883,-0.0004272,-0.0006714,-0.0004578,-0.0007629,-0.0004883,-0.0007629,-0.0005798,-0.0006714,-0.0006714,-0.0005188,-0.0006714,-0.0004578,-0.0004883,-0.0004883,-0.0002747,-0.0003967,-0.0002441,-0.0003052,-0.0001831,-0.0002441,-0.0002441,-0.0002441,-0.0003662,-0.0002441,-0.0004272,-0.0004578,-0.0004272,-0.0006409,-0.0004272,-0.0006714,-0.0005188,-0.0006104,-0.0005798,-0.0004883,-0.0004883,-0.0004272,-0.0002747,-0.0003357,-0.0002136,-0.0000916,-0.0002441,0.0000610,-0.0001526,0.0000000,-0.0000610,-0.0001831,0.0000000,-0.0003357,-0.0001526,-0.0003357,-0.0004578,-0.0003357,-0.0006409,-0.0004272,-0.0006714,-0.0005188,-0.0006714,-0.0004883,-0.0006714,-0.0003967,-0.0005188,-0.0003662,-0.0003662,-0.0002747,-0.0003357,-0.0001831,-0.0002441,-0.0001831,-0.0002136,-0.0002747,-0.0003052,-0.0003357,-0.0003357,-0.0004578,-0.0005493,-0.0004578,-0.0007324,-0.0005188,-0.0007629,-0.0005798,-0.0007324,-0.0005798,-0.0007629,-0.0005188,-0.0005798,-0.0005188,-0.0003967,-0.0004883,-0.0003967,-0.0002747,-0.0004578,-0.0002136,-0.0003967,-0.0003357,-0.0004578,-0.0003662,-0.0004883,-0.0005188,-0.0005188,-0.0006714,-0.0005798,-0.0007019,-0.0006409,-0.0006714,-0.0007019,-0.0005188,-0.0007019,-0.0004272,-0.0006409,-0.0003662,-0.0005493,-0.0003052,-0.0004883,-0.0003662,-0.0003357,-0.0005798,-0.0003052,-0.0006104,-0.0004578,-0.0006409,-0.0006714,-0.0007324,-0.0007324,-0.0008850,-0.0007324,-0.0010681,-0.0006714,-0.0010681,-0.0006714,-0.0010376,-0.0007019,-0.0009155,-0.0007019,-0.0007324,-0.0007629,-0.0007324,-0.0006714,-0.0007935,-0.0005798,-0.0008850,-0.0007019,-0.0008240,-0.0008545,-0.0008545,-0.0009766,-0.0010376,-0.0008850,-0.0012207,-0.0009766,-0.0012207,-0.0010986,-0.0012512,-0.0010681,-0.0014038,-0.0009766,-0.0014038,-0.0010681,-0.0012512,-0.0012512,-0.0010071,-0.0013428,-0.0009766,-0.0013123,-0.0010376,-0.0012207,-0.0010681,-0.0011902,-0.0012207,-0.0011292,-0.0012512,-0.0011902,-0.0012512,-0.0013428,-0.0012512,-0.0012512,-0.0013428,-0.0012817,-0.0013733,-0.0013733,-0.0012817,-0.0013733,-0.0012512,-0.0013428,-0.0012817,-0.0012817,-0.0013123,-0.0012207,-0.0013733,-0.0011902,-0.0013733,-0.0013123,-0.0013123,-0.0014038,-0.0013123,-0.0014038,-0.0014343,-0.0014343,-0.0014343,-0.0014038,-0.0015259,-0.0013733,-0.0014343,-0.0014038,-0.0014038,-0.0013428,-0.0014648,-0.0011597,-0.0016174,-0.0010071,-0.0015869,-0.0010376,-0.0015259,-0.0011292,-0.0014648,-0.0012207,-0.0012817,-0.0014038,-0.0011902,-0.0013733,-0.0013428,-0.0012207,-0.0013428,-0.0012817,-0.0011597,-0.0014343,-0.0010071,-0.0013733,-0.0010986,-0.0011902,-0.0011292,-0.0011902,-0.0009155,-0.0013123,-0.0007935,-0.0012207,-0.0008240,-0.0010681,-0.0009155,-0.0009460,-0.0009460,-0.0008545,-0.0009155,-0.0008240,-0.0008545,-0.0008850,-0.0007629,-0.0008545,-0.0007629,-0.0007935,-0.0007629,-0.0007629,-0.0006714,-0.0007324,-0.0006714,-0.0006714,-0.0005798,-0.0006409,-0.0004883,-0.0006104,-0.0003967,-0.0006104,-0.0003052,-0.0005798,-0.0003052,-0.0004578,-0.0004578,-0.0003662,-0.0004883,-0.0003662,-0.0004883,-0.0004272,-0.0004578,-0.0004883,-0.0003357,-0.0006409,-0.0002441,-0.0005798,-0.0003052,-0.0004272,-0.0003967,-0.0003052,-0.0003967,-0.0002747,-0.0003967,-0.0002747,-0.0003662,-0.0002441,-0.0004578,-0.0001831,-0.0005493,-0.0002747,-0.0004272,-0.0004883,-0.0003357,-0.0005798,-0.0003357,-0.0005493,-0.0004578,-0.0004578,-0.0004883,-0.0003967,-0.0004272,-0.0004883,-0.0003357,-0.0004578,-0.0003052,-0.0004272,-0.0003052,-0.0004578,-0.0003357,-0.0003357,-0.0004883,-0.0002441,-0.0005493,-0.0003052,-0.0005188,-0.0002747,-0.0006104,-0.0002747,-0.0005188,-0.0004272,-0.0003967,-0.0005493,-0.0003662,-0.0004883,-0.0004272,-0.0003967,-0.0004883,-0.0004272,-0.0003967,-0.0005188,-0.0003662,-0.0004272,-0.0006104,-0.0002441,-0.0007324,-0.0003052,-0.0006104,-0.0004578,-0.0006409,-0.0004578,-0.0007019,-0.0005188,-0.0006714,-0.0006714,-0.0006714,-0.0007019,-0.0007019,-0.0007629,-0.0007324,-0.0008240,-0.0007629,-0.0008850,-0.0007935,-0.0009460,-0.0007935,-0.0010071,-0.0008545,-0.0010071,-0.0008850,-0.0008545,-0.0010681,-0.0007629,-0.0011292,-0.0007019,-0.0010986,-0.0007629,-0.0009460,-0.0008850,-0.0008850,-0.0008545,-0.0010071,-0.0007019,-0.0011597,-0.0008240,-0.0011292,-0.0009155,-0.0010681,-0.0010681,-0.0010986,-0.0011597,-0.0011902,-0.0010071,-0.0013123,-0.0010376,-0.0011902,-0.0011292,-0.0010376,-0.0010986,-0.0010376,-0.0010681,-0.0009155,-0.0011292,-0.0007629,-0.0011597,-0.0008240,-0.0010681,-0.0009155,-0.0010071,-0.0010681,-0.0010071,-0.0011597,-0.0010071,-0.0011292,-0.0012207,-0.0010681,-0.0013428,-0.0010376,-0.0012817,-0.0010986,-0.0012207,-0.0010376,-0.0012207,-0.0009460,-0.0010681,-0.0009766,-0.0009460,-0.0009766,-0.0008240,-0.0009460,-0.0008240,-0.0008850,-0.0008240,-0.0008850,-0.0007935,-0.0009460,-0.0008240,-0.0009155,-0.0009155,-0.0008545,-0.0009460,-0.0009155,-0.0008545,-0.0010071,-0.0006714,-0.0009460,-0.0006714,-0.0007629,-0.0006714,-0.0006409,-0.0003967,-0.0006409,-0.0002441,-0.0005798,-0.0002136,-0.0003357,-0.0002747,-0.0002747,-0.0002441,-0.0002747,-0.0002441,-0.0002747,-0.0002747,-0.0002441,-0.0003357,-0.0002136,-0.0003052,-0.0001221,-0.0002747,-0.0000916,-0.0001526,0.0000000,0.0000000,0.0000916,0.0001831,0.0001526,0.0003357,0.0002136,0.0005188,0.0001831,0.0006104,0.0001831,0.0005798,0.0003052,0.0004578,0.0003052,0.0004578,0.0002747,0.0004272,0.0002747,0.0004578,0.0002441,0.0005493,0.0002441,0.0005798,0.0003662,0.0006409,0.0004272,0.0007019,0.0005798,0.0006409,0.0007324,0.0007019,0.0006714,0.0008545,0.0005188,0.0009155,0.0005188,0.0007935,0.0006409,0.0005798,0.0006714,0.0005188,0.0006104,0.0005188,0.0005493,0.0005493,0.0005188,0.0006409,0.0005188,0.0006409,0.0005798,0.0007019,0.0006714,0.0007935,0.0007019,0.0008545,0.0007019,0.0009460,0.0007324,0.0009766,0.0007019,0.0009766,0.0006104,0.0009460,0.0006104,0.0007935,0.0006409,0.0006104,0.0006714,0.0004578,0.0007629,0.0003357,0.0007629,0.0003662,0.0006714,0.0005188,0.0005493,0.0006104,0.0004883,0.0006714,0.0005493,0.0005798,0.0005493,0.0005798,0.0005493,0.0005493,0.0004883,0.0003662,0.0006104,0.0002136,0.0005798,0.0001831,0.0003662,0.0002441,0.0002136,0.0002747,0.0001221,0.0003052,0.0000305,0.0001831,0.0001526,0.0000916,0.0000916,0.0001831,-0.0000305,0.0001526,0.0000000,0.0000610,0.0000305,0.0000000,0.0000000,0.0000000,-0.0001221,0.0000000,-0.0001831,-0.0001221,-0.0001526,-0.0002747,-0.0002136,-0.0003662,-0.0002441,-0.0004272,-0.0003357,-0.0004578,-0.0004272,-0.0004883,-0.0003967,-0.0006104,-0.0003967,-0.0006714,-0.0003052,-0.0007324,-0.0003357,-0.0006409,-0.0005493,-0.0004272,-0.0007019,-0.0004578,-0.0007019,-0.0005188,-0.0006714,-0.0005493,-0.0007324,-0.0005798,-0.0007019,-0.0007019,-0.0006714,-0.0007324,-0.0006714,-0.0007019,-0.0006714,-0.0007935,-0.0006104,-0.0008240,-0.0005493,-0.0008545,-0.0005493,-0.0008545,-0.0005188,-0.0007935,-0.0006714,-0.0006714,-0.0007019,-0.0006714,-0.0007019,-0.0007324,-0.0006409,-0.0007629,-0.0005798,-0.0008240,-0.0005493,-0.0008545,-0.0006104,-0.0007324,-0.0007324,-0.0007019,-0.0007019,-0.0007935,-0.0006104,-0.0008240,-0.0005493,-0.0008850,-0.0003662,-0.0009766,-0.0003357,-0.0008240,-0.0003967,-0.0006409,-0.0005188,-0.0005188,-0.0004883,-0.0004578,-0.0004578,-0.0005188,-0.0003052,-0.0006409,-0.0001831,-0.0006104,-0.0002747,-0.0004883,-0.0003052,-0.0004272,-0.0002441,-0.0003967,-0.0002136,-0.0003052,-0.0001831,-0.0002136,-0.0001526,-0.0000610,-0.0001526,0.0000610,-0.0001526,0.0001221,-0.0000610,0.0000916,0.0000610,0.0000305,0.0002441,-0.0001221,0.0003662,-0.0000610,0.0003357,0.0000610,0.0002747,0.0001221,0.0002747,0.0002747,0.0001831,0.0003357,0.0003052,0.0002747,0.0003662,0.0003052,0.0003357,0.0003967,0.0003357,0.0003357,0.0004883,0.0003357,0.0004883,0.0003662,0.0004883,0.0005188,0.0004272,0.0004883,0.0005188,0.0004578,0.0006104,0.0004272,0.0005798,0.0004578,0.0005188,0.0005493,0.0004578,0.0005798,0.0004272,0.0005798,0.0004883,0.0005188,0.0004578,0.0006104,0.0004272,0.0006714,0.0004883,0.0005493,0.0005493,0.0006409,0.0004883,0.0007019,0.0004578,0.0006409,0.0006104,0.0005188,0.0006104,0.0004578,0.0007019,0.0003967,0.0006714,0.0004272,0.0006409,0.0004883,0.0006409,0.0004883,0.0006714,0.0005188,0.0007019,0.0005188,0.0007935,0.0004578,0.0008850,0.0004272,0.0009155,0.0004883,0.0007935,0.0006409,0.0006409,0.0007019,0.0005493,0.0007019,0.0005188,0.0006409,0.0005798,0.0004883,0.0005798,0.0004883,0.0006104,0.0004883,0.0005493,0.0005798,0.0005188,0.0007324,0.0004578,0.0007324,0.0005188,0.0007935,0.0004883,0.0007935,0.0005188,0.0007019,0.0006104,0.0005493,0.0005798,0.0006104,0.0004883,0.0005798,0.0003967,0.0006104,0.0003662,0.0005493,0.0004272,0.0005493,0.0004578,0.0005798,0.0004272,0.0007324,0.0004272,0.0007019,0.0005798,0.0006104,0.0007324,0.0005188,0.0006409,0.0006714,0.0005798,0.0007019,0.0005493,0.0006714,0.0006104,0.0006714,0.0005798,0.0007324,0.0005798,0.0009155,0.0005493,0.0009460,0.0007019,0.0009155,0.0008240,0.0009766,0.0007935,0.0010071,0.0009155,0.0009460,0.0009766,0.0008850,0.0009766,0.0009155,0.0009766,0.0009155,0.0009766,0.0008850,0.0010071,0.0009460,0.0009766,0.0009766,0.0010681,0.0009460,0.0010986,0.0010986,0.0010071,0.0013123,0.0008850,0.0013733,0.0009766,0.0013733,0.0010681,0.0013123,0.0010681,0.0013428,0.0010681,0.0013733,0.0010681,0.0013123,0.0011597,0.0011597,0.0012207,0.0012512,0.0010681,0.0013428,0.0010681,0.0013123,0.0011902,0.0013428,0.0011902,0.0013733,0.0013428,0.0013123,0.0014954,0.0014038,0.0014648,0.0014648,0.0014954,0.0014648,0.0014343,0.0014954,0.0013428,0.0014648,0.0013123,0.0013428,0.0012512,0.0013123,0.0012207,0.0012512,0.0011902,0.0012817,0.0012512,0.0013428,0.0013733,0.0013123,0.0015564,0.0014343,0.0015869,0.0015564,0.0016479,0.0015564,0.0017395,0.0015564,0.0016479,0.0016174,0.0014648,0.0015869,0.0013428,0.0014648,0.0012512,0.0013733,0.0011902,0.0012817,0.0011597,0.0012207,0.0011902,0.0012512,0.0011597,0.0014038,0.0011597,0.0014954,0.0012207,0.0014954,0.0013123,0.0014343,0.0014038,0.0013428,0.0013123,0.0012817,0.0011902,0.0012207,0.0010376,0.0010681,0.0009155,0.0009460,0.0007935,0.0008545,0.0007324,0.0007324,0.0007324,0.0007019,0.0006714,0.0007324,0.0007019,0.0007019,0.0008545,0.0006104,0.0008850,0.0006714,0.0007629,0.0007935,0.0006104,0.0008240,0.0004578,0.0007935,0.0003967,0.0005798,0.0005188,0.0002136,0.0006104,0.0000610,0.0005188,0.0001526,0.0002441,0.0003052,0.0000916,0.0003967,0.0000610,0.0003357,0.0001221,0.0002747,0.0002441,0.0001221,0.0003662,0.0000305,0.0003357,0.0000305,0.0002747,0.0000610,0.0001221,0.0000916,-0.0000305,0.0000916,-0.0000916,0.0000000,-0.0001221,-0.0000305,-0.0002136,-0.0000916,-0.0003052,-0.0001221,-0.0003662,-0.0001526,-0.0005188,-0.0000916,-0.0005798,-0.0002136,-0.0004883,-0.0003052,-0.0004578,-0.0003052,-0.0004883,-0.0003357,-0.0003662,-0.0003967,-0.0003052,-0.0004272,-0.0003357,-0.0003357,-0.0003662,-0.0003662,-0.0004272,-0.0004272,-0.0004272,-0.0004272,-0.0005493,-0.0004272,-0.0005188,-0.0005493,-0.0004578,-0.0005493,-0.0003967,-0.0004272,-0.0003967,-0.0003357,-0.0002747,-0.0002747,-0.0001221,-0.0001831,-0.0001526,0.0000000,-0.0001526,-0.0000305,-0.0000305,-0.0001526,-0.0000305,-0.0001526,-0.0000610,-0.0002441,-0.0000305,-0.0002747,-0.0000610,-0.0001831,-0.0001526,-0.0000916,-0.0000305,0.0000305,0.0000610,0.0000916,0.0001526,0.0002747,0.0003052,0.0002747,0.0004883,0.0002441,0.0005798,0.0003357,0.0004883,0.0004272,0.0004272,0.0003967,0.0004578,0.0002747,0.0005493,0.0002136,0.0004272,0.0003967,0.0002441,0.0006104,0.0002136,0.0006104,0.0003967,0.0005798,0.0005798,0.0005798,0.0007019,0.0007324,0.0007019,0.0009155,0.0007019,0.0009766,0.0007935,0.0010681,0.0007935,0.0010681,0.0008545,0.0010071,0.0009766,0.0009155,0.0010681,0.0008850,0.0010986,0.0009155,0.0010986,0.0009460,0.0012207,0.0009766,0.0012817,0.0010681,0.0013123,0.0012817,0.0012817,0.0013733,0.0014038,0.0013733,0.0015564,0.0014038,0.0014954,0.0014648,0.0014343,0.0015564,0.0013123,0.0015564,0.0013428,0.0014343,0.0014038,0.0014038,0.0013428,0.0014648,0.0013428,0.0014343,0.0014343,0.0014038,0.0014954,0.0014038,0.0014954,0.0014648,0.0015259,0.0014343,0.0015259,0.0014038,0.0014343,0.0014038,0.0014038,0.0013428,0.0013428,0.0012512,0.0011902,0.0013733,0.0009460,0.0014343,0.0009155,0.0012207,0.0011292,0.0010681,0.0011292,0.0010681,0.0011292,0.0011292,0.0010986,0.0010681,0.0010986,0.0010681,0.0011597,0.0009766,0.0010681,0.0010071,0.0009460,0.0009460,0.0009766,0.0007935,0.0009155,0.0007935,0.0007935,0.0008240,0.0006714,0.0007935,0.0006409,0.0007935,0.0006104,0.0007629,0.0005798,0.0007019,0.0005798,0.0006104,0.0005798,0.0005188,0.0005188,0.0004272,0.0003967,0.0004578,0.0001526,0.0004272,0.0001526,0.0001831,0.0002441,-0.0000305,0.0002441,0.0000000,0.0000305,0.0001526,-0.0001831,0.0002136,-0.0001831,0.0000916,-0.0000916,-0.0001526,0.0000000,-0.0002136,-0.0001526,-0.0001526,-0.0003662,-0.0001526,-0.0004578,-0.0003052,-0.0003662,-0.0004883,-0.0003662,-0.0005188,-0.0004883,-0.0004578,-0.0005188,-0.0005188,-0.0004578,-0.0005493,-0.0004883,-0.0004578,-0.0005493,-0.0005188,-0.0004578,-0.0006104,-0.0004272,-0.0006104,-0.0004272,-0.0006714,-0.0004578,-0.0006409,-0.0005493,-0.0005493,-0.0005493,-0.0006409,-0.0005493,-0.0006409,-0.0005188,-0.0006409,-0.0006104,-0.0006409,-0.0006714,-0.0005188,-0.0007324,-0.0005188,-0.0007019,-0.0005798,-0.0005798,-0.0005493,-0.0005493,-0.0004883,-0.0004578,-0.0005493,-0.0003052,-0.0005493,-0.0002747,-0.0004272,-0.0003662,-0.0003662,-0.0004578,-0.0003052,-0.0006104,-0.0002747,-0.0006714,-0.0003967,-0.0006104,-0.0006104,-0.0005188,-0.0007019,-0.0004883,-0.0007324,-0.0004578,-0.0006714,-0.0004578,-0.0005493,-0.0003967,-0.0004578,-0.0003662,-0.0003662,-0.0002747,-0.0003662,-0.0001526,-0.0003967,-0.0001221,-0.0003662,-0.0002136,-0.0003662,-0.0002441,-0.0003357,-0.0003052,-0.0003967,-0.0003357,-0.0003967,-0.0003357,-0.0003357,-0.0003662,-0.0002441,-0.0003052,-0.0001526,-0.0002136,-0.0000916,-0.0000916,0.0000305,-0.0000305,0.0001526,0.0000305,0.0001831,0.0001221,0.0001221,0.0002441,0.0001526,0.0001221,0.0002441,0.0000000,0.0002441,0.0000305,0.0001526,0.0000610,0.0001221,0.0000916,0.0000916,0.0001831,0.0001526,0.0002441,0.0002441,0.0002747,0.0003662,0.0002747,0.0005493,0.0002441,0.0005798,0.0003662,0.0005493,0.0003662,0.0005188,0.0003052,0.0004883,0.0003357,0.0003662,0.0002747,0.0003662,0.0001526,0.0003967,0.0001526,0.0003662,0.0002747,0.0002747,0.0003967,0.0003052,0.0004578,0.0004578,0.0003967,0.0006104,0.0004272,0.0006104,0.0004883,0.0005493,0.0005188,0.0005188,0.0004272,0.0004578,0.0003357,0.0004272,0.0002747,0.0002136,0.0003052,0.0000916,0.0002441,0.0000916,0.0001526,0.0001526,0.0001221,0.0002136,0.0002441,0.0001221,0.0003967,0.0002441,0.0003967,0.0003967,0.0003662,0.0003662,0.0004883,0.0002441,0.0005188,0.0001831,0.0004578,0.0000610,0.0003357,-0.0000610,0.0002441,-0.0001221,0.0001221,-0.0001526,0.0000305,-0.0001831,0.0000916,-0.0002136,0.0000916,-0.0000610,0.0000610,0.0000916,0.0000610,0.0001221,0.0001526,0.0001526,0.0001831,0.0000916,0.0001221,0.0000916,0.0000000,-0.0000305,-0.0001221,-0.0000916,-0.0002136,-0.0002136,-0.0003662,-0.0002747,-0.0003967,-0.0002747,-0.0003967,-0.0003662,-0.0003357,-0.0003052,-0.0002747,-0.0003052,-0.0001526,-0.0003662,-0.0000610,-0.0003357,-0.0000916,-0.0002747,-0.0001221,-0.0003357,-0.0001831,-0.0003052,-0.0002441,-0.0003662,-0.0003052,-0.0004272,-0.0003052,-0.0003967,-0.0003662,-0.0003967,-0.0003662,-0.0003662,-0.0003357,-0.0003357,-0.0001831,-0.0004578,0.0000000,-0.0005188,0.0000305,-0.0003967,-0.0000916,-0.0002136,-0.0002441,-0.0001526,-0.0003052,-0.0001221,-0.0003357,-0.0001831,-0.0003357,-0.0002136,-0.0003357,-0.0002747,-0.0003052,-0.0003357,-0.0002441,-0.0003357,-0.0002747,-0.0002747,-0.0003357,-0.0002136,-0.0002441,-0.0002441,-0.0002441,-0.0002747,-0.0002136,-0.0002747,-0.0001831,-0.0003052,-0.0002136,-0.0003357,-0.0002441,-0.0002441,-0.0003662,-0.0002136,-0.0003357,-0.0003052,-0.0003052,-0.0002747,-0.0003967,-0.0001831,-0.0004272,-0.0001526,-0.0003662,-0.0002747,-0.0003052,-0.0002747,-0.0003662,-0.0002136,-0.0004883,-0.0002136,-0.0003662,-0.0003967,-0.0003052,-0.0003967,-0.0003967,-0.0002747,-0.0004272,-0.0003052,-0.0003357,-0.0003662,-0.0002747,-0.0003967,-0.0001831,-0.0004578,-0.0000916,-0.0005493,-0.0000916,-0.0004883,-0.0002747,-0.0003357,-0.0004272,-0.0003357,-0.0003967,-0.0004883,-0.0003967,-0.0004883,-0.0003967,-0.0004578,-0.0004578,-0.0004883,-0.0003967,-0.0004883,-0.0003662,-0.0004883,-0.0003357,-0.0004883,-0.0003662,-0.0004578,-0.0004272,-0.0003662,-0.0004883,-0.0004578,-0.0004578,-0.0005188,-0.0005188,-0.0005188,-0.0007019,-0.0005188,-0.0006714,-0.0007019,-0.0006104,-0.0008240,-0.0006104,-0.0008545,-0.0005798,-0.0009460,-0.0005798,-0.0008545,-0.0007324,-0.0007324,-0.0007629,-0.0007324,-0.0007019,-0.0007935,-0.0007935,-0.0007019,-0.0008240,-0.0007324,-0.0008545,-0.0008850,-0.0007935,-0.0009460,-0.0008240,-0.0009460,-0.0008850,-0.0009155,-0.0009766,-0.0008850,-0.0010071,-0.0008240,-0.0010376,-0.0008240,-0.0009766,-0.0009155,-0.0008545,-0.0010071,-0.0009155,-0.0009766,-0.0010071,-0.0009155,-0.0010986,-0.0009766,-0.0011597,-0.0010071,-0.0011597,-0.0010681,-0.0011597,-0.0010986,-0.0011902,-0.0010071,-0.0012207,-0.0010376,-0.0011597,-0.0010681,-0.0010681,-0.0010986,-0.0010986,-0.0011597,-0.0010986,-0.0011902,-0.0010986,-0.0013123,-0.0011597,-0.0013123,-0.0012512,-0.0013428,-0.0012817,-0.0014343,-0.0011902,-0.0015259,-0.0011902,-0.0014038,-0.0013123,-0.0012817,-0.0012817,-0.0012817,-0.0011597,-0.0012817,-0.0011597,-0.0011902,-0.0011902,-0.0010986,-0.0012207,-0.0010986,-0.0011902,-0.0011292,-0.0011902,-0.0010681,-0.0013123,-0.0009766,-0.0013428,-0.0010071,-0.0012817,-0.0010986,-0.0011902,-0.0011292,-0.0010681,-0.0011902,-0.0009460,-0.0011597,-0.0009766,-0.0009766,-0.0010376,-0.0008545,-0.0009155,-0.0008850,-0.0007324,-0.0009155,-0.0006104,-0.0008545,-0.0006409,-0.0007324,-0.0006714,-0.0007019,-0.0006714,-0.0007324,-0.0007324,-0.0007019,-0.0007324,-0.0007019,-0.0007324,-0.0007629,-0.0006409,-0.0007324,-0.0006104,-0.0006409,-0.0006409,-0.0004578,-0.0005798,-0.0004272,-0.0005493,-0.0002747,-0.0005798,-0.0002441,-0.0005188,-0.0004272,-0.0003662,-0.0005798,-0.0003662,-0.0006409,-0.0004272,-0.0007019,-0.0004578,-0.0006714,-0.0004883,-0.0005798,-0.0005798,-0.0004883,-0.0004272,-0.0004883,-0.0003052,-0.0004883,-0.0001526,-0.0003967,-0.0001526,-0.0003052,-0.0002441,-0.0002136,-0.0002747,-0.0003357,-0.0002441,-0.0004578,-0.0002747,-0.0004883,-0.0004578,-0.0004883,-0.0004578,-0.0005493,-0.0003357,-0.0006409,-0.0003052,-0.0004578,-0.0003357,-0.0003052,-0.0002747,-0.0001831,-0.0002136,-0.0000916,-0.0001526,-0.0000610,-0.0001526,-0.0000305,-0.0001831,-0.0001221,-0.0001831,-0.0002747,-0.0002441,-0.0003052,-0.0004578,-0.0003357,-0.0004578,-0.0004883,-0.0004578,-0.0005493,-0.0004272,-0.0005188,-0.0003357,-0.0006104,-0.0001831,-0.0005493,-0.0002136,-0.0003052,-0.0003662,-0.0001831,-0.0003662,-0.0002747,-0.0002441,-0.0004578,-0.0002747,-0.0004578,-0.0005493,-0.0003662,-0.0008240,-0.0003662,-0.0008850,-0.0005798,-0.0007935,-0.0007629,-0.0007324,-0.0008545,-0.0006714,-0.0008850,-0.0005798,-0.0008850,-0.0006104,-0.0007019,-0.0006714,-0.0006104,-0.0006714,-0.0006409,-0.0006409,-0.0006409,-0.0007324,-0.0006714,-0.0007935,-0.0007935,-0.0008240,-0.0008240,-0.0009460,-0.0008545,-0.0010376,-0.0009766,-0.0009766,-0.0010681,-0.0009460,-0.0011292,-0.0008850,-0.0011597,-0.0008545,-0.0010986,-0.0008240,-0.0010376,-0.0008240,-0.0009766,-0.0008240,-0.0008240,-0.0010071,-0.0007019,-0.0010986,-0.0007935,-0.0009460,-0.0010376,-0.0009460,-0.0010681,-0.0010681,-0.0011292,-0.0010986,-0.0011292,-0.0011292,-0.0011292,-0.0012207,-0.0010376,-0.0011597,-0.0010376,-0.0010681,-0.0009460,-0.0010376,-0.0009155,-0.0009460,-0.0009460,-0.0008545,-0.0009155,-0.0009460,-0.0008545,-0.0010376,-0.0008545,-0.0010986,-0.0009460,-0.0010986,-0.0009766,-0.0011902,-0.0009460,-0.0012207,-0.0009155,-0.0011292,-0.0009460,-0.0010376,-0.0008850,-0.0008850,-0.0008850,-0.0007324,-0.0007629,-0.0007629,-0.0006104,-0.0007324,-0.0005493,-0.0006104,-0.0005798,-0.0006104,-0.0004578,-0.0007019,-0.0003967,-0.0006409,-0.0004272,-0.0006104,-0.0004272,-0.0006104,-0.0003357,-0.0005798,-0.0003052,-0.0005798,-0.0002441,-0.0005188,-0.0002441,-0.0003662,-0.0002747,-0.0002136,-0.0002747,-0.0001221,-0.0002136,-0.0000610,-0.0001221,-0.0000305,-0.0000305,0.0000305,0.0000305,0.0000305,0.0000916,0.0000610,0.0000610,0.0001221,0.0000916,0.0001221,0.0000610,0.0001221,0.0000610,0.0001526,0.0000916,0.0002136,0.0000610,0.0003052,0.0000916,0.0003357,0.0002441,0.0002441,0.0003357,0.0003357,0.0003052,0.0004883,0.0001831,0.0005493,0.0002747,0.0003967,0.0003967,0.0003357,0.0003357,0.0004578,0.0002441,0.0004272,0.0003357,0.0004272,0.0003357,0.0005493,0.0003052,0.0006104,0.0003967,0.0006409,0.0005493,0.0006104,0.0006409,0.0006714,0.0006104,0.0007019,0.0006714,0.0006409,0.0007324,0.0005188,0.0007324,0.0004578,0.0007629,0.0004272,0.0006104,0.0005188,0.0004578,0.0005493,0.0004883,0.0005798,0.0003967,0.0006714,0.0003967,0.0006714,0.0005493,0.0006104,0.0005493,0.0006714,0.0005798,0.0006409,0.0005798,0.0005798,0.0005493,0.0005798,0.0004883,0.0005493,0.0003662,0.0005493,0.0003662,0.0003967,0.0004272,0.0002441,0.0003967,0.0003357,0.0002747,0.0003052,0.0003662,0.0001831,0.0004578,0.0001221,0.0004578,0.0001831,0.0003357,0.0002441,0.0002747,0.0002136,0.0002136,0.0002136,0.0001526,0.0001831,0.0000610,0.0001831,0.0000000,0.0001526,-0.0000610,0.0000916,-0.0000610,0.0000000,-0.0000610,-0.0000610,-0.0000610,-0.0001221,-0.0001221,-0.0001221,-0.0001831,-0.0000610,-0.0002441,-0.0001221,-0.0001831,-0.0002136,-0.0001526,-0.0001831,-0.0001831,-0.0002441,-0.0001526,-0.0002441,-0.0001831,-0.0002136,-0.0003357,-0.0001526,-0.0003967,-0.0002136,-0.0004578,-0.0003357,-0.0003967,-0.0005188,-0.0003357,-0.0006409,-0.0003662,-0.0006714,-0.0004883,-0.0005493,-0.0006409,-0.0004883,-0.0006409,-0.0004883,-0.0005798,-0.0006104,-0.0004272,-0.0007019,-0.0003967,-0.0007324,-0.0003662,-0.0006714,-0.0004883,-0.0006714,-0.0005493,-0.0006714,-0.0005493,-0.0007019,-0.0006409,-0.0007324,-0.0005493,-0.0008240,-0.0005493,-0.0007324,-0.0006714,-0.0005798,-0.0007019,-0.0005798,-0.0006714,-0.0005188,-0.0006714,-0.0004578,-0.0006409,-0.0005188,-0.0005188,-0.0005798,-0.0004578,-0.0006104,-0.0004883,-0.0005493,-0.0004883,-0.0005188,-0.0005493,-0.0004883,-0.0005188,-0.0005188,-0.0003357,-0.0005798,-0.0003662,-0.0004272,-0.0003967,-0.0003357,-0.0003052,-0.0003662,-0.0002441,-0.0002747,-0.0002441,-0.0002747,-0.0001526,-0.0003357,-0.0000916,-0.0003662,-0.0000916,-0.0003662,-0.0001221,-0.0003052,-0.0001526,-0.0002441,-0.0002136,-0.0001526,-0.0002136,-0.0000916,-0.0000916,-0.0001831,0.0000610,-0.0001221,0.0000916,-0.0000305,0.0000610,0.0000916,0.0000000,0.0001831,-0.0000610,0.0002136,-0.0000610,0.0001831,-0.0000305,0.0000916,0.0000305,0.0000916,0.0000916,0.0000000,0.0002136,0.0000305,0.0002747,0.0001526,0.0002136,0.0003052,0.0003052,0.0002747,0.0004272,0.0003052,0.0004272,0.0003662,0.0004272,0.0003052,0.0004883,0.0002441,0.0004578,0.0002747,0.0003662,0.0003357,0.0002747,0.0003052,0.0003662,0.0002136,0.0004883,0.0001526,0.0004883,0.0003052,0.0004272,0.0004272,0.0004272,0.0004883,0.0004883,0.0005493,0.0004578,0.0005798,0.0004883,0.0005493,0.0005493,0.0005188,0.0004578,0.0005188,0.0003662,0.0004883,0.0004272,0.0003052,0.0004578,0.0002136,0.0004272,0.0003052,0.0002747,0.0003662,0.0003052,0.0003662,0.0003357,0.0004272,0.0003662,0.0005188,0.0003357,0.0006104,0.0003662,0.0005493,0.0004883,0.0004272,0.0004883,0.0004883,0.0002747,0.0005798,0.0001831,0.0004578,0.0002136,0.0003052,0.0002441,0.0002136,0.0003052,0.0001831,0.0002441,0.0003662,0.0001526,0.0005493,0.0001526,0.0005493,0.0003662,0.0004578,0.0004883,0.0003967,0.0005798,0.0003967,0.0005493,0.0003967,0.0004883,0.0003357,0.0005188,0.0001831,0.0005188,0.0001526,0.0003967,0.0002441,0.0003357,0.0002136,0.0004272,0.0001831,0.0005493,0.0002747,0.0006409,0.0003357,0.0006714,0.0004883,0.0007324,0.0006104,0.0007019,0.0006714,0.0007019,0.0007019,0.0006714,0.0006409,0.0006409,0.0006409,0.0005493,0.0006104,0.0004883,0.0005798,0.0004883,0.0005493,0.0005493,0.0004883,0.0006104,0.0005798,0.0006409,0.0007019,0.0007019,0.0007629,0.0007935,0.0008545,0.0008240,0.0009155,0.0008240,0.0009460,0.0009155,0.0007935,0.0009460,0.0008545,0.0008240,0.0008850,0.0007629,0.0008240,0.0008240,0.0008545,0.0007935,0.0008850,0.0008240,0.0009155,0.0009766,0.0008850,0.0011597,0.0007935,0.0013428,0.0009155,0.0012207,0.0010986,0.0011597,0.0011902,0.0011597,0.0011597,0.0011292,0.0011902,0.0011292,0.0010986,0.0011597,0.0010376,0.0011597,0.0010681,0.0010986,0.0011292,0.0010681,0.0011292,0.0011597,0.0011292,0.0011902,0.0012512,0.0011597,0.0013428,0.0011902,0.0013428,0.0012207,0.0014648,0.0011292,0.0015259,0.0011597,0.0014038,0.0012817,0.0012817,0.0012817,0.0012817,0.0012512,0.0012512,0.0011292,0.0013123,0.0011292,0.0012512,0.0011292,0.0012207,0.0011597,0.0012512,0.0011902,0.0011902,0.0012512,0.0011597,0.0013123,0.0012207,0.0012207,0.0013123,0.0010986,0.0013733,0.0010986,0.0012817,0.0011292,0.0012207,0.0010986,0.0011902,0.0009766,0.0011597,0.0010071,0.0010681,0.0009766,0.0010071,0.0008850,0.0010071,0.0009155,0.0008850,0.0009766,0.0007629,0.0009766,0.0007629,0.0009460,0.0007629,0.0009460,0.0007324,0.0008850,0.0007935,0.0008240,0.0007629,0.0007935,0.0007629,0.0007629,0.0006409,0.0007935,0.0005188,0.0007324,0.0005188,0.0005493,0.0005493,0.0003967,0.0005493,0.0002441,0.0004883,0.0002747,0.0003357,0.0003357,0.0003052,0.0002136,0.0003967,0.0001526,0.0003967,0.0001831,0.0002747,0.0002441,0.0002136,0.0003052,0.0000916,0.0003052,0.0000305,0.0001526,0.0000916,0.0000305,-0.0000305,0.0000305,-0.0001221,-0.0000610,-0.0001526,-0.0000916,-0.0002441,0.0000000,-0.0004272,0.0000916,-0.0004272,0.0000305,-0.0003052,-0.0000916,-0.0002441,-0.0000916,-0.0002441,-0.0000610,-0.0003052,-0.0000916,-0.0002747,-0.0002136,-0.0002136,-0.0003357,-0.0002747,-0.0003357,-0.0003662,-0.0002747,-0.0004883,-0.0002747,-0.0004883,-0.0003662,-0.0003662,-0.0004272,-0.0003357,-0.0003662,-0.0004578,-0.0002747,-0.0003967,-0.0003357,-0.0002747,-0.0005188,-0.0001221,-0.0005188,-0.0002136,-0.0003357,-0.0004272,-0.0001831,-0.0005188,-0.0001221,-0.0005188,-0.0001526,-0.0003357,-0.0002747,-0.0002136,-0.0002441,-0.0002136,-0.0001221,-0.0001831,-0.0000610,-0.0001526,-0.0000305,-0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000305,0.0000916,-0.0001221,0.0001526,-0.0001526,0.0001221,-0.0000305,0.0000610,0.0000610,0.0000916,0.0000000,0.0002441,0.0000610,0.0002441,0.0001526,0.0002136,0.0003052,0.0002136,0.0003662,0.0002136,0.0003357,0.0002747,0.0003357,0.0003357,0.0002747,0.0003662,0.0003052,0.0003357,0.0004272,0.0002747,0.0004883,0.0003662,0.0005188,0.0005188,0.0004883,0.0006409,0.0006104,0.0006409,0.0007019,0.0007019,0.0006714,0.0008850,0.0006104,0.0009460,0.0006104,0.0009155,0.0007019,0.0008850,0.0007629,0.0008545,0.0007935,0.0009460,0.0007629,0.0010681,0.0007324,0.0011902,0.0008240,0.0011902,0.0009460,0.0011292,0.0011902,0.0010071,0.0013123,0.0009766,0.0013123,0.0010681,0.0013123,0.0010071,0.0013123,0.0010681,0.0011902,0.0011597,0.0011597,0.0011292,0.0011902,0.0011597,0.0012207,0.0010986,0.0013123,0.0011292,0.0012512,0.0012512,0.0011902,0.0012817,0.0012207,0.0012512,0.0012207,0.0012512,0.0012207,0.0011902,0.0011292,0.0012817,0.0009766,0.0013428,0.0009460,0.0011902,0.0010681,0.0009460,0.0012817,0.0007935,0.0012817,0.0008240,0.0011292,0.0009460,0.0010681,0.0009155,0.0010376,0.0009155,0.0009766,0.0010071,0.0008240,0.0010071,0.0007935,0.0009766,0.0008240,0.0007935,0.0009460,0.0006409,0.0009766,0.0006409,0.0007935,0.0007324,0.0007324,0.0007324,0.0006409,0.0007324,0.0005188,0.0007935,0.0004883,0.0007019,0.0005188,0.0005493,0.0005188,0.0005188,0.0004272,0.0006104,0.0002747,0.0005493,0.0003357,0.0004272,0.0003967,0.0003357,0.0003357,0.0003052,0.0003357,0.0002136,0.0003662,0.0000610,0.0003662,0.0000000,0.0001831,0.0001221,-0.0000305,0.0001526,-0.0001526,0.0000000,-0.0000916,-0.0000610,-0.0001526,-0.0001526,-0.0002136,-0.0001221,-0.0001831,-0.0002136,-0.0001831,-0.0002441,-0.0001831,-0.0002441,-0.0001831,-0.0003662,-0.0001526,-0.0003662,-0.0001831,-0.0004578,-0.0002136,-0.0005188,-0.0001831,-0.0005493,-0.0002747,-0.0005493,-0.0003052,-0.0005188,-0.0003967,-0.0004578,-0.0004578,-0.0003357,-0.0004883,-0.0003662,-0.0004272,-0.0004272,-0.0003662,-0.0003662,-0.0003357,-0.0004578,-0.0002441,-0.0005493,-0.0001526,-0.0005493,-0.0002441,-0.0003967,-0.0003662,-0.0004272,-0.0003662,-0.0004883,-0.0003357,-0.0004272,-0.0004883,-0.0003662,-0.0005188,-0.0003967,-0.0004272,-0.0004578,-0.0003967,-0.0004578,-0.0003662,-0.0004272,-0.0003357,-0.0003967,-0.0003357,-0.0003662,-0.0002747,-0.0003357,-0.0003052,-0.0002441,-0.0003662,-0.0002441,-0.0003357,-0.0003052,-0.0003662,-0.0002136,-0.0004272,-0.0002747,-0.0003662,-0.0003662,-0.0003052,-0.0003052,-0.0003357,-0.0002747,-0.0002441,-0.0003357,-0.0001221,-0.0002747,-0.0001831,-0.0000916,-0.0002747,0.0000916,-0.0003052,0.0000916,-0.0002136,0.0000305,-0.0000610,-0.0000305,0.0000000,-0.0001221,0.0001221,-0.0001831,0.0001831,-0.0001526,0.0001526,-0.0001221,0.0001831,-0.0000916,0.0001221,0.0000610,0.0000305,0.0002136,0.0000000,0.0001526,0.0001831,0.0000305,0.0003052,0.0000916,0.0002136,0.0002747,0.0001831,0.0002441,0.0003052,0.0001831,0.0003357,0.0002747,0.0003052,0.0002747,0.0003662,0.0001526,0.0004883,0.0000610,0.0004578,0.0001831,0.0003357,0.0002747,0.0002136,0.0003052,0.0002747,0.0003357,0.0003052,0.0002441,0.0004578,0.0001221,0.0005798,0.0001831,0.0004272,0.0003967,0.0002441,0.0005188,0.0002747,0.0005188,0.0002441,0.0004883,0.0003357,0.0004578,0.0003357,0.0003662,0.0003967,0.0003052,0.0004272,0.0002441,0.0003662,0.0002441,0.0003662,0.0002441,0.0002441,0.0002747,0.0001831,0.0003052,0.0002136,0.0002441,0.0002441,0.0002747,0.0001526,0.0004272,0.0001221,0.0004272,0.0002441,0.0002747,0.0003357,0.0002747,0.0002747,0.0002747,0.0002441,0.0002136,0.0002136,0.0000610,0.0002136,0.0001221,0.0000610,0.0000916,-0.0000610,0.0001221,-0.0000610,0.0001526,-0.0001831,0.0002747,-0.0002136,0.0002136,0.0000000,0.0000305,0.0001221,-0.0000305,0.0000610,0.0000305,0.0000000,-0.0000305,-0.0000916,-0.0000305,-0.0002441,-0.0000610,-0.0002136,-0.0002747,-0.0001831,-0.0002747,-0.0003357,-0.0001221,-0.0003357,-0.0001831,-0.0002441,-0.0002441,-0.0001221,-0.0001831,-0.0001221,-0.0001221,-0.0001526,0.0000000,-0.0001831,-0.0000916,-0.0001526,-0.0001221,-0.0001831,-0.0001221,-0.0002441,-0.0002136,-0.0002747,-0.0002441,-0.0002747,-0.0003052,-0.0002136,-0.0003662,-0.0001526,-0.0003357,-0.0001221,-0.0002747,-0.0000916,-0.0001526,-0.0001221,-0.0000305,-0.0000916,-0.0000916,0.0000916,-0.0001831,0.0000916,-0.0001526,-0.0000305,-0.0000916,-0.0000916,-0.0001526,-0.0001221,-0.0002441,-0.0001221,-0.0003357,-0.0000916,-0.0003052,-0.0001831,-0.0001831,-0.0002441,-0.0001221,-0.0001831,-0.0000610,-0.0001526,0.0000610,-0.0002136,0.0001221,-0.0001526,0.0000610,-0.0000610,-0.0000916,0.0000305,-0.0002136,0.0000000,-0.0002441,-0.0000916,-0.0003052,-0.0000916,-0.0004272,-0.0000610,-0.0003967,-0.0001526,-0.0003662,-0.0001831,-0.0001831,-0.0002747,-0.0001221,-0.0001831,-0.0002136,-0.0000610,-0.0001526,-0.0001831,-0.0000305,-0.0002136,-0.0001831,-0.0000305,-0.0003662,0.0000305,-0.0004272,-0.0000305,-0.0003967,-0.0001831,-0.0002747,-0.0003052,-0.0002441,-0.0002747,-0.0003662,-0.0001221,-0.0003357,-0.0001221,-0.0003357,-0.0001221,-0.0002747,-0.0000610,-0.0002747,-0.0000916,-0.0002747,-0.0000916,-0.0002441,-0.0001831,-0.0003052,-0.0001526,-0.0003357,-0.0002747,-0.0002747,-0.0003967,-0.0002747,-0.0003967,-0.0003052,-0.0003967,-0.0002441,-0.0004578,-0.0001831,-0.0004578,-0.0001221,-0.0004578,-0.0001526,-0.0003967,-0.0002136,-0.0002441,-0.0003967,-0.0002441,-0.0004578,-0.0002441,-0.0005798,-0.0002747,-0.0006409,-0.0004883,-0.0004883,-0.0007629,-0.0003662,-0.0008850,-0.0003662,-0.0008545,-0.0004578,-0.0007324,-0.0004883,-0.0006104,-0.0006104,-0.0004883,-0.0007019,-0.0003967,-0.0006714,-0.0005188,-0.0007019,-0.0005188,-0.0007935,-0.0005188,-0.0008545,-0.0007019,-0.0007935,-0.0008545,-0.0008240,-0.0008545,-0.0008240,-0.0009766,-0.0007629,-0.0010071,-0.0007629,-0.0008850,-0.0008850,-0.0007629,-0.0008850,-0.0007324,-0.0008850,-0.0007935,-0.0007629,-0.0008850,-0.0007935,-0.0009155,-0.0008850,-0.0008545,-0.0009766,-0.0009155,-0.0010376,-0.0009155,-0.0010986,-0.0009460,-0.0010986,-0.0009766,-0.0010681,-0.0009766,-0.0010376,-0.0010376,-0.0009460,-0.0010986,-0.0008545,-0.0009766,-0.0009766,-0.0008850,-0.0010071,-0.0008545,-0.0009460,-0.0010071,-0.0008240,-0.0010986,-0.0008240,-0.0011597,-0.0009155,-0.0010986,-0.0009766,-0.0010986,-0.0010986,-0.0010681,-0.0010986,-0.0010986,-0.0009766,-0.0012512,-0.0008545,-0.0011902,-0.0008850,-0.0010376,-0.0009460,-0.0009155,-0.0009155,-0.0008545,-0.0008545,-0.0008240,-0.0008545,-0.0008545,-0.0007935,-0.0008240,-0.0007935,-0.0008545,-0.0008545,-0.0008240,-0.0008850,-0.0008240,-0.0008850,-0.0007324,-0.0009460,-0.0007629,-0.0008240,-0.0008240,-0.0006714,-0.0007935,-0.0006714,-0.0007019,-0.0006409,-0.0005493,-0.0007019,-0.0004883,-0.0006104,-0.0004883,-0.0005188,-0.0005493,-0.0005188,-0.0005493,-0.0004883,-0.0006104,-0.0004578,-0.0006409,-0.0005493,-0.0005188,-0.0006409,-0.0005798,-0.0005188,-0.0006409,-0.0004578,-0.0006104,-0.0005188,-0.0003967,-0.0005798,-0.0003052,-0.0005188,-0.0003662,-0.0003357,-0.0004272,-0.0002441,-0.0004272,-0.0002136,-0.0004883,-0.0001526,-0.0004578,-0.0003052,-0.0003662,-0.0003967,-0.0003357,-0.0004272,-0.0003357,-0.0003967,-0.0004272,-0.0003052,-0.0005188,-0.0001831,-0.0004883,-0.0002441,-0.0003662,-0.0002747,-0.0002747,-0.0002747,-0.0003052,-0.0002136,-0.0003052,-0.0002747,-0.0002441,-0.0003662,-0.0001831,-0.0003662,-0.0002747,-0.0003662,-0.0003357,-0.0002747,-0.0004578,-0.0001831,-0.0005493,-0.0002136,-0.0004272,-0.0003967,-0.0003052,-0.0003662,-0.0003662,-0.0003052,-0.0003662,-0.0003357,-0.0002747,-0.0003357,-0.0002747,-0.0003662,-0.0002441,-0.0003662,-0.0002441,-0.0003662,-0.0002747,-0.0004272,-0.0002441,-0.0004883,-0.0003357,-0.0004883,-0.0003967,-0.0005188,-0.0004272,-0.0005188,-0.0005493,-0.0004272,-0.0006104,-0.0003967,-0.0006409,-0.0003357,-0.0006714,-0.0002441,-0.0006714,-0.0003357,-0.0004578,-0.0005798,-0.0003052,-0.0006714,-0.0003357,-0.0005798,-0.0005798,-0.0005493,-0.0007324,-0.0005188,-0.0007629,-0.0007324,-0.0007629,-0.0008240,-0.0007935,-0.0007935,-0.0008545,-0.0008240,-0.0007935,-0.0008545,-0.0007019,-0.0008545,-0.0006714,-0.0008240,-0.0006714,-0.0007019,-0.0007629,-0.0006104,-0.0007935,-0.0006104,-0.0008240,-0.0006104,-0.0008850,-0.0006409,-0.0009460,-0.0007324,-0.0009155,-0.0007629,-0.0010071,-0.0007935,-0.0010376,-0.0008545,-0.0009460,-0.0010071,-0.0008545,-0.0010071,-0.0008240,-0.0010071,-0.0008240,-0.0008850,-0.0008240,-0.0008240,-0.0008545,-0.0007019,-0.0009155,-0.0006104,-0.0009155,-0.0006714,-0.0008545,-0.0007324,-0.0008240,-0.0008240,-0.0008240,-0.0008545,-0.0008545,-0.0008850,-0.0009155,-0.0008240,-0.0009460,-0.0007629,-0.0010071,-0.0006714,-0.0009766,-0.0006104,-0.0008240,-0.0007019,-0.0006104,-0.0007019,-0.0005188,-0.0006714,-0.0005493,-0.0005188,-0.0006714,-0.0003662,-0.0007629,-0.0004272,-0.0006714,-0.0006409,-0.0005188,-0.0006714,-0.0005798,-0.0006104,-0.0006409,-0.0006409,-0.0004578,-0.0006409,-0.0003967,-0.0005798,-0.0003662,-0.0004883,-0.0002747,-0.0003662,-0.0003357,-0.0002136,-0.0002747,-0.0001526,-0.0003052,-0.0000610,-0.0003357,0.0000000,-0.0003052,-0.0000305,-0.0002441,-0.0000610,-0.0002136,-0.0000610,-0.0001526,-0.0000610,-0.0001526,0.0000305,-0.0001526,0.0001526,-0.0001221,0.0001831,0.0000000,0.0001831,0.0001221,0.0002136,0.0001526,0.0002747,0.0002747,0.0001831,0.0003662,0.0001831,0.0003052,0.0002747,0.0002441,0.0002441,0.0002136,0.0003052,0.0000916,0.0003967,0.0000610,0.0003662,0.0001221,0.0003662,0.0001831,0.0003967,0.0002441,0.0003967,0.0003357,0.0004883,0.0003052,0.0005188,0.0003967,0.0004883,0.0004272,0.0003967,0.0005493,0.0002441,0.0006409,0.0001526,0.0005493,0.0002747,0.0003662,0.0003662,0.0002747,0.0003967,0.0002747,0.0004272,0.0002747,0.0003967,0.0003967,0.0003662,0.0004578,0.0004272,0.0004272,0.0004578,0.0004883,0.0003967,0.0006104,0.0003052,0.0006104,0.0003052,0.0005188,0.0003357,0.0004272,0.0003357,0.0003357,0.0003967,0.0002747,0.0002747,0.0003357,0.0002441,0.0003052,0.0003662,0.0001526,0.0004578,0.0002136,0.0003662,0.0003662,0.0002136,0.0004883,0.0001221,0.0004883,0.0001221,0.0003662,0.0002136,0.0001831,0.0002441,0.0000610,0.0001831,0.0000305,0.0000916,0.0000610,-0.0000916,0.0001831,-0.0002441,0.0002441,-0.0002441,0.0001831,-0.0001221,0.0000305,0.0000916,-0.0000610,0.0000610,-0.0000305,0.0000000,0.0000305,-0.0000610,-0.0000305,-0.0001221,-0.0000916,-0.0002441,-0.0000305,-0.0003967,-0.0000916,-0.0004272,-0.0001526,-0.0004272,-0.0002136,-0.0004272,-0.0002441,-0.0003662,-0.0003052,-0.0002747,-0.0003357,-0.0002136,-0.0003662,-0.0001526,-0.0003357,-0.0002747,-0.0002136,-0.0003967,-0.0002136,-0.0003662,-0.0003662,-0.0003662,-0.0003662,-0.0004578,-0.0003967,-0.0004578,-0.0005188,-0.0004272,-0.0004883,-0.0005493,-0.0003357,-0.0006104,-0.0003662,-0.0004883,-0.0004272,-0.0004272,-0.0003357,-0.0005188,-0.0002441,-0.0004578,-0.0003357,-0.0003967,-0.0003662,-0.0004272,-0.0003662,-0.0004272,-0.0004578,-0.0004272,-0.0004883,-0.0004883,-0.0004578,-0.0005493,-0.0005188,-0.0004578,-0.0005493,-0.0004578,-0.0004578,-0.0005493,-0.0003662,-0.0004883,-0.0003662,-0.0003967,-0.0003357,-0.0003967,-0.0003052,-0.0004272,-0.0003052,-0.0003662,-0.0004272,-0.0002747,-0.0005493,-0.0003052,-0.0004883,-0.0004578,-0.0003357,-0.0005493,-0.0003357,-0.0004578,-0.0003662,-0.0003052,-0.0004578,-0.0001221,-0.0004272,-0.0000916,-0.0003052,-0.0000305,-0.0002747,0.0000305,-0.0002441,0.0000610,-0.0001831,0.0000000,-0.0001221,0.0000000,-0.0001221,-0.0000610,-0.0000916,-0.0000305,-0.0000916,-0.0000610,-0.0000916,0.0000305,-0.0000610,0.0000916,-0.0000916,0.0001526,0.0000610,0.0001221,0.0002136,0.0000916,0.0002747,0.0001526,0.0003052,0.0001221,0.0003662,0.0002441,0.0001831,0.0003662,0.0000610,0.0003662,0.0000610,0.0002747,0.0000916,0.0002441,0.0000916,0.0001831,0.0000610,0.0001221,0.0001831,0.0001221,0.0001526,0.0002136,0.0000916,0.0003357,0.0001526,0.0003357,0.0002441,0.0003052,0.0003357,0.0003052,0.0003357,0.0003967,0.0002747,0.0003967,0.0001831,0.0004578,0.0001221,0.0003967,0.0001526,0.0002136,0.0003052,0.0000916,0.0002747,0.0000305,0.0003052,0.0000610,0.0002747,0.0001526,0.0001526,0.0002747,0.0001526,0.0003357,0.0001831,0.0003662,0.0002136,0.0004272,0.0001831,0.0004883,0.0001831,0.0005188,0.0002441,0.0003357,0.0003662,0.0002747,0.0003357,0.0002441,0.0002747,0.0002747,0.0001526,0.0003357,0.0001526,0.0002441,0.0001831,0.0002441,0.0002441,0.0002441,0.0003052,0.0002441,0.0003052,0.0003662,0.0003052,0.0003662,0.0003357,0.0003967,0.0002441,0.0004578,0.0002136,0.0002747,0.0003662,0.0000916,0.0003357,0.0001221,0.0001526,0.0001526,0.0001831,0.0000305,0.0002441,0.0000610,0.0002136,0.0001831,0.0001831,0.0002136,0.0002136,0.0003052,0.0002747,0.0003052,0.0003052,0.0003052,0.0003357,0.0003357,0.0003357,0.0002441,0.0004272,0.0002136,0.0003967,0.0003052,0.0002747,0.0003357,0.0003662,0.0003662,0.0002747,0.0005188,0.0002747,0.0005188,0.0003967,0.0004883,0.0004883,0.0005188,0.0005188,0.0006104,0.0004578,0.0007629,0.0004578,0.0007019,0.0006104,0.0006714,0.0006104,0.0007019,0.0005798,0.0007629,0.0006104,0.0007629,0.0005493,0.0007935,0.0005493,0.0007935,0.0005493,0.0007629,0.0006104,0.0006104,0.0007935,0.0005188,0.0008240,0.0006104,0.0007629,0.0007324,0.0007935,0.0007629,0.0008240,0.0007935,0.0008850,0.0008240,0.0008850,0.0007935,0.0009766,0.0007629,0.0009766,0.0006714,0.0009460,0.0007019,0.0009155,0.0007019,0.0008545,0.0007629,0.0007935,0.0008545,0.0008850,0.0008240,0.0009460,0.0009460,0.0009766,0.0010376,0.0010376,0.0010681,0.0011292,0.0011292,0.0010376,0.0012512,0.0010071,0.0012512,0.0009460,0.0012207,0.0010071,0.0010681,0.0010681,0.0009766,0.0010376,0.0010071,0.0010681,0.0009155,0.0010986,0.0009766,0.0010986,0.0010986,0.0010986,0.0010681,0.0012207,0.0010986,0.0012512,0.0010986,0.0012512,0.0010681,0.0012512,0.0010681,0.0011597,0.0010681,0.0010071,0.0010986,0.0009460,0.0010071,0.0008545,0.0010376,0.0007935,0.0010071,0.0007629,0.0009766,0.0007935,0.0010376,0.0007629,0.0009766,0.0008850,0.0008850,0.0008850,0.0009766,0.0007324,0.0010071,0.0006714,0.0008850,0.0007629,0.0006409,0.0007935,0.0005493,0.0006714,0.0005798,0.0005493,0.0005493,0.0005493,0.0004272,0.0006409,0.0004272,0.0005493,0.0005188,0.0004578,0.0005798,0.0004883,0.0005188,0.0005493,0.0004272,0.0005798,0.0003662,0.0005188,0.0004272,0.0003357,0.0004578,0.0002136,0.0004578,0.0001831,0.0003357,0.0001526,0.0002441,0.0002136,0.0001526,0.0001831,0.0000916,0.0001526,0.0001221,0.0000916,0.0000610,0.0000916,0.0000916,0.0000305,0.0000305,0.0000305,-0.0000610,0.0001526,-0.0001831,0.0000610,-0.0000916,-0.0000916,-0.0000610,-0.0001221,-0.0001221,-0.0000610,-0.0002441,-0.0000916,-0.0002441,-0.0001221,-0.0002747,-0.0001526,-0.0002441,-0.0002747,-0.0002136,-0.0002747,-0.0003357,-0.0001831,-0.0004272,-0.0002441,-0.0003662,-0.0003967,-0.0002747,-0.0004883,-0.0002441,-0.0004578,-0.0003662,-0.0003662,-0.0004272,-0.0003357,-0.0003052,-0.0004578,-0.0002136,-0.0004578,-0.0001526,-0.0004578,-0.0001221,-0.0004272,-0.0001831,-0.0003357,-0.0002441,-0.0002747,-0.0002747,-0.0002441,-0.0003357,-0.0001831,-0.0003967,-0.0000916,-0.0004578,0.0000000,-0.0003662,-0.0001526,-0.0000916,-0.0002747,0.0000305,-0.0001831,-0.0000610,0.0000305,-0.0001221,0.0001221,-0.0000916,0.0000305,0.0000916,-0.0000916,0.0000916,0.0000000,0.0000305,0.0000610,-0.0000916,0.0001831,-0.0001526,0.0002747,-0.0001831,0.0002441,0.0000305,0.0001526,0.0001526,0.0001526,0.0001831,0.0002136,0.0002441,0.0001526,0.0003662,0.0001831,0.0003052,0.0002747,0.0002136,0.0004272,0.0001526,0.0004578,0.0001831,0.0004578,0.0002441,0.0005493,0.0002136,0.0006104,0.0003357,0.0004883,0.0005798,0.0003967,0.0006104,0.0005188,0.0005798,0.0005493,0.0006409,0.0005188,0.0006714,0.0005188,0.0006714,0.0005798,0.0006714,0.0006104,0.0006714,0.0007324,0.0006714,0.0008240,0.0006714,0.0009460,0.0006714,0.0010376,0.0007935,0.0010071,0.0009460,0.0009460,0.0010071,0.0010071,0.0009460,0.0011292,0.0008850,0.0010071,0.0010376,0.0008850,0.0011292,0.0008545,0.0009766,0.0010376,0.0008545,0.0011597,0.0008850,0.0010681,0.0009460,0.0010986,0.0010376,0.0010986,0.0010986,0.0009766,0.0012207,0.0009766,0.0011902,0.0009460,0.0011597,0.0009155,0.0010986,0.0009460,0.0009766,0.0009155,0.0009766,0.0007629,0.0010071,0.0007324,0.0009460,0.0007324,0.0008850,0.0007935,0.0007935,0.0008545,0.0007324,0.0008545,0.0007629,0.0008240,0.0007629,0.0008240,0.0006714,0.0008240,0.0006714,0.0007935,0.0006104,0.0006714,0.0006409,0.0005493,0.0007019,0.0004578,0.0006104,0.0005188,0.0004883,0.0005188,0.0005798,0.0004272,0.0005798,0.0004272,0.0004883,0.0005493,0.0004578,0.0004883,0.0004883,0.0003967,0.0004883,0.0003967,0.0004272,0.0003662,0.0003662,0.0002747,0.0003662,0.0002441,0.0002441,0.0002136,0.0001831,0.0001526,0.0001221,0.0001221,0.0000610,0.0000916,0.0000305,0.0000000,0.0000916,-0.0000916,0.0000916,-0.0001526,0.0000916,-0.0000916,-0.0000305,-0.0000610,-0.0000305,-0.0000916,-0.0000305,-0.0001526,-0.0000610,-0.0001221,-0.0001831,-0.0001221,-0.0002441,-0.0001221,-0.0003052,-0.0001831,-0.0003357,-0.0001831,-0.0003967,-0.0001831,-0.0003357,-0.0003357,-0.0002136,-0.0003662,-0.0002441,-0.0002747,-0.0003052,-0.0002441,-0.0002441,-0.0003052,-0.0001831,-0.0003357,-0.0002136,-0.0002747,-0.0002747,-0.0002441,-0.0003052,-0.0002441,-0.0003052,-0.0003052,-0.0003052,-0.0002747,-0.0003662,-0.0002747,-0.0003052,-0.0003357,-0.0002747,-0.0003662,-0.0002441,-0.0003052,-0.0003052,-0.0002136,-0.0003662,-0.0001831,-0.0002747,-0.0002441,-0.0001526,-0.0003357,-0.0001526,-0.0002441,-0.0001831,-0.0002441,-0.0001831,-0.0003662,-0.0000610,-0.0004272,-0.0001526,-0.0003662,-0.0003052,-0.0003052,-0.0003052,-0.0003662,-0.0002441,-0.0004272,-0.0003052,-0.0002441,-0.0003662,-0.0001526,-0.0003357,-0.0001831,-0.0002441,-0.0001221,-0.0001831,-0.0000916,-0.0001526,-0.0001221,-0.0000916,-0.0001526,-0.0001221,-0.0000916,-0.0002136,-0.0000610,-0.0002441,-0.0000916,-0.0002441,-0.0001526,-0.0001221,-0.0001831,-0.0000916,-0.0001526,-0.0000610,-0.0000610,0.0000610,-0.0000305,0.0001221,0.0000305,0.0001526,0.0001526,0.0002747,0.0000610,0.0003662,0.0000610,0.0003357,0.0001221,0.0002747,0.0001526,0.0001831,0.0002441,0.0000305,0.0003357,0.0000000,0.0003052,0.0000610,0.0002441,0.0001831,0.0001831,0.0003357,0.0001526,0.0004272,0.0002136,0.0004272,0.0003052,0.0003967,0.0003967,0.0003967,0.0003662,0.0003662,0.0004272,0.0002136,0.0004883,0.0001526,0.0003662,0.0001831,0.0002441,0.0001831,0.0002441,0.0001526,0.0001831,0.0002136,0.0001831,0.0001831,0.0003357,0.0000916,0.0004272,0.0001526,0.0004272,0.0002136,0.0004578,0.0001831,0.0004272,0.0002747,0.0002747,0.0003662,0.0001526,0.0003967,0.0000916,0.0003052,0.0000610,0.0002441,0.0000916,0.0002441,0.0000305,0.0002747,0.0000610,0.0002136,0.0002136,0.0001526,0.0002747,0.0001831,0.0002747,0.0001831,0.0003052,0.0000916,0.0003357,0.0001221,0.0001831,0.0002136,0.0000916,0.0001221,0.0001221,0.0000916,0.0000305,0.0000916,0.0000000,0.0001526,-0.0000916,0.0002136,-0.0001526,0.0002136,0.0000000,0.0000610,0.0000916,0.0001526,-0.0000610,0.0002441,-0.0000610,0.0001526,0.0000610,0.0000000,0.0000305,0.0000000,-0.0000305,-0.0000305,-0.0000610,-0.0000610,-0.0001831,-0.0000305,-0.0001831,-0.0000305,-0.0001526,-0.0001526,-0.0000610,-0.0000916,-0.0000610,-0.0000610,0.0000305,-0.0001221,0.0000916,-0.0000916,0.0000305,0.0000305,-0.0000610,0.0000610,-0.0001221,0.0000916,-0.0002136,0.0000305,-0.0001831,-0.0000610,-0.0001831,-0.0000916,-0.0002136,-0.0000610,-0.0002136,-0.0000610,-0.0001526,0.0000000,-0.0001526,0.0000305,-0.0000610,0.0000916,-0.0000916,0.0001831,-0.0001526,0.0001831,-0.0000305,0.0000305,0.0000305,-0.0000610,-0.0000305,-0.0000610,-0.0000610,-0.0001221,-0.0000610,-0.0001221,-0.0001221,-0.0000305,-0.0001831,0.0000000,-0.0000916,-0.0000610,0.0000305,-0.0000610,0.0000610,-0.0000305,0.0000305,0.0000000,0.0000610,-0.0000305,0.0000610,-0.0000610,0.0000000,-0.0000610,-0.0000305,-0.0000916,-0.0001526,0.0000000,-0.0002441,-0.0000916,-0.0000916,-0.0002747,0.0000000,-0.0001831,-0.0001221,-0.0000916,-0.0001221,-0.0000916,-0.0000305,-0.0000916,-0.0000610,-0.0000610,-0.0000610,-0.0000610,-0.0000610,-0.0000916,-0.0000916,-0.0000916,-0.0001831,-0.0000916,-0.0001526,-0.0002136,-0.0001526,-0.0002441,-0.0001526,-0.0001831,-0.0002136,-0.0001831,-0.0002441,-0.0000305,-0.0003052,0.0000610,-0.0003357,0.0000305,-0.0001831,-0.0000916,-0.0000916,-0.0001526,-0.0000916,-0.0002136,-0.0000916,-0.0002136,-0.0001831,-0.0002441,-0.0001831,-0.0002747,-0.0003052,-0.0002441,-0.0003357,-0.0002747,-0.0001831,-0.0004883,-0.0000916,-0.0004272,-0.0001831,-0.0003662,-0.0002747,-0.0002441,-0.0003357,-0.0002136,-0.0003967,-0.0003052,-0.0002747,-0.0004272,-0.0003052,-0.0003967,-0.0004578,-0.0003052,-0.0005798,-0.0003357,-0.0005493,-0.0004578,-0.0005493,-0.0004883,-0.0005493,-0.0005798,-0.0004883,-0.0007324,-0.0004272,-0.0007324,-0.0005188,-0.0006409,-0.0006409,-0.0005798,-0.0006409,-0.0005798,-0.0007019,-0.0005798,-0.0006714,-0.0005798,-0.0006409,-0.0006714,-0.0006409,-0.0006714,-0.0006409,-0.0007019,-0.0007324,-0.0007324,-0.0007324,-0.0007935,-0.0007019,-0.0009155,-0.0006714,-0.0009460,-0.0007019,-0.0008850,-0.0007324,-0.0008850,-0.0006409,-0.0008850,-0.0006714,-0.0007629,-0.0006714,-0.0007629,-0.0005493,-0.0008240,-0.0006104,-0.0007629,-0.0007019,-0.0007019,-0.0007019,-0.0008850,-0.0006104,-0.0010376,-0.0005798,-0.0009766,-0.0007935,-0.0008850,-0.0008240,-0.0009460,-0.0007324,-0.0009460,-0.0007935,-0.0008850,-0.0007324,-0.0009460,-0.0006104,-0.0009766,-0.0006104,-0.0008240,-0.0008240,-0.0006409,-0.0008545,-0.0007019,-0.0006714,-0.0009155,-0.0006104,-0.0008240,-0.0007629,-0.0007324,-0.0007324,-0.0008850,-0.0005798,-0.0009460,-0.0006104,-0.0008545,-0.0006104,-0.0008240,-0.0006409,-0.0007935,-0.0005493,-0.0007324,-0.0006104,-0.0005798,-0.0007019,-0.0003662,-0.0007935,-0.0003052,-0.0007935,-0.0002441,-0.0007019,-0.0003662,-0.0006104,-0.0003967,-0.0005493,-0.0003967,-0.0005798,-0.0004272,-0.0005188,-0.0003967,-0.0005188,-0.0003967,-0.0005493,-0.0003357,-0.0005188,-0.0003052,-0.0005188,-0.0002441,-0.0004883,-0.0002441,-0.0004272,-0.0003052,-0.0003052,-0.0003967,-0.0001831,-0.0003662,-0.0002441,-0.0003662,-0.0001831,-0.0003967,-0.0001831,-0.0003052,-0.0003662,-0.0001831,-0.0003967,-0.0002747,-0.0003357,-0.0002747,-0.0003662,-0.0003052,-0.0003052,-0.0003662,-0.0002747,-0.0003052,-0.0003052,-0.0002441,-0.0003052,-0.0002747,-0.0002441,-0.0002441,-0.0002441,-0.0001831,-0.0002747,-0.0001831,-0.0002441,-0.0003357,-0.0001221,-0.0003357,-0.0002441,-0.0002747,-0.0003052,-0.0003662,-0.0001526,-0.0004578,-0.0002441,-0.0002747,-0.0003662,-0.0002747,-0.0001831,-0.0004578,-0.0000610,-0.0004272,-0.0001526,-0.0002441,-0.0001831,-0.0002136,-0.0001831,-0.0002441,-0.0001526,-0.0002136,-0.0002136,-0.0002441,-0.0002136,-0.0002747,-0.0002441,-0.0003052,-0.0002747,-0.0003662,-0.0002747,-0.0004272,-0.0002136,-0.0004883,-0.0003052,-0.0003357,-0.0004272,-0.0003052,-0.0004272,-0.0003662,-0.0003357,-0.0003967,-0.0003662,-0.0004272,-0.0003357,-0.0004578,-0.0003357,-0.0004578,-0.0004578,-0.0004578,-0.0004578,-0.0004883,-0.0005493,-0.0005188,-0.0006104,-0.0005493,-0.0005798,-0.0006409,-0.0005798,-0.0007019,-0.0005493,-0.0007324,-0.0005493,-0.0006409,-0.0007019,-0.0004883,-0.0007324,-0.0004578,-0.0007019,-0.0005493,-0.0006409,-0.0005188,-0.0006409,-0.0006104,-0.0005493,-0.0007324,-0.0005493,-0.0008240,-0.0005798,-0.0007629,-0.0006714,-0.0007324,-0.0008240,-0.0006714,-0.0008240,-0.0006714,-0.0008545,-0.0006714,-0.0007935,-0.0006714,-0.0007324,-0.0007629,-0.0006409,-0.0007324,-0.0006409,-0.0007629,-0.0006714,-0.0007324,-0.0006714,-0.0007935,-0.0006714,-0.0008240,-0.0007324,-0.0007629,-0.0008240,-0.0007935,-0.0007019,-0.0009155,-0.0006409,-0.0008240,-0.0007324,-0.0007019,-0.0006714,-0.0007629,-0.0005493,-0.0007019,-0.0005493,-0.0006104,-0.0006104,-0.0006409,-0.0004883,-0.0007019,-0.0004578,-0.0007324,-0.0005493,-0.0006409,-0.0006409,-0.0007019,-0.0005493,-0.0007629,-0.0005188,-0.0007629,-0.0006104,-0.0006104,-0.0006409,-0.0005188,-0.0005493,-0.0006714,-0.0003357,-0.0006714,-0.0003052,-0.0005493,-0.0004272,-0.0004272,-0.0003967,-0.0004578,-0.0003662,-0.0003662,-0.0005188,-0.0002136,-0.0005188,-0.0002136,-0.0004578,-0.0002136,-0.0004272,-0.0002136,-0.0002441,-0.0003052,-0.0001221,-0.0002747,-0.0001221,-0.0001831,-0.0001221,-0.0000916,-0.0001831,0.0000305,-0.0002441,0.0000610,-0.0001831,-0.0000305,-0.0000305,-0.0001221,0.0000610,-0.0001831,0.0000610,0.0000000,-0.0000305,0.0001221,-0.0000305,0.0001221,0.0000916,0.0001221,0.0000916,0.0002441,0.0000305,0.0003052,0.0000610,0.0002441,0.0001221,0.0001831,0.0002136,0.0001221,0.0001831,0.0001526,0.0001831,0.0001221,0.0002136,0.0001221,0.0002136,0.0001526,0.0002136,0.0001831,0.0002441,0.0002136,0.0002441,0.0002747,0.0002441,0.0003052,0.0002747,0.0003052,0.0003662,0.0002136,0.0004578,0.0001831,0.0004883,0.0002136,0.0004272,0.0002441,0.0003967,0.0002441,0.0004272,0.0001526,0.0004272,0.0002747,0.0002136,0.0004883,0.0000000,0.0006104,0.0000916,0.0004578,0.0001831,0.0004272,0.0002441,0.0004272,0.0002136,0.0004883,0.0002136,0.0004578,0.0002747,0.0003052,0.0003662,0.0003052,0.0002747,0.0003052,0.0001831,0.0003052,0.0001831,0.0002441,0.0001831,0.0001831,0.0002441,0.0000916,0.0003052,0.0000916,0.0003052,0.0000916,0.0002747,0.0001831,0.0001526,0.0002747,0.0001526,0.0001526,0.0002747,0.0000000,0.0002441,0.0000610,0.0000916,0.0000916,0.0000305,0.0000610,-0.0000610,0.0000610,-0.0000916,0.0000305,0.0000000,-0.0001526,0.0000305,-0.0001221,-0.0000305,-0.0000305,-0.0000916,-0.0001526,0.0000610,-0.0002136,0.0000000,-0.0001221,-0.0001526,-0.0000916,-0.0001831,-0.0000916,-0.0002441,-0.0000916,-0.0003052,-0.0001221,-0.0003052,-0.0001221,-0.0003357,-0.0001526,-0.0003052,-0.0001831,-0.0003052,-0.0001831,-0.0002747,-0.0001831,-0.0002441,-0.0002747,-0.0000916,-0.0003662,-0.0000916,-0.0003357,-0.0001831,-0.0002747,-0.0002441,-0.0003357,-0.0001526,-0.0004578,-0.0001526,-0.0004883,-0.0002136,-0.0003967,-0.0003052,-0.0003967,-0.0003357,-0.0003967,-0.0003357,-0.0004272,-0.0003052,-0.0003967,-0.0003967,-0.0003357,-0.0004578,-0.0002747,-0.0004883,-0.0003052,-0.0004578,-0.0003357,-0.0004272,-0.0003357,-0.0004578,-0.0003357,-0.0003967,-0.0003357,-0.0003662,-0.0003662,-0.0003052,-0.0003967,-0.0002441,-0.0003967,-0.0002441,-0.0004272,-0.0001831,-0.0004578,-0.0001526,-0.0005188,-0.0001526,-0.0004883,-0.0001526,-0.0004272,-0.0002441,-0.0003357,-0.0003052,-0.0002441,-0.0002747,-0.0002136,-0.0003052,-0.0000610,-0.0003967,0.0000610,-0.0003662,0.0000000,-0.0002136,-0.0000916,-0.0001221,-0.0000916,-0.0001526,0.0000000,-0.0002136,0.0000305,-0.0002441,0.0000916,-0.0002136,0.0000305,-0.0001221,-0.0000610,0.0000305,-0.0001221,0.0001526,-0.0001526,0.0001221,-0.0000610,0.0001831,-0.0001221,0.0003052,-0.0001526,0.0002136,0.0000916,0.0000610,0.0001221,0.0001221,0.0000610,0.0001221,0.0001526,0.0000305,0.0001831,0.0000610,0.0001221,0.0000916,0.0001221,0.0001221,0.0001526,0.0000916,0.0001526,0.0000610,0.0003052,-0.0000305,0.0003662,0.0000000,0.0003357,0.0001526,0.0002136,0.0002136,0.0001831,0.0002747,0.0001831,0.0002747,0.0001221,0.0002747,0.0001831,0.0001526,0.0001831,0.0002136,0.0000610,0.0002441,0.0000610,0.0002136,0.0001221,0.0002136,0.0000916,0.0002441,0.0001831,0.0002441,0.0002441,0.0002136,0.0002747,0.0003052,0.0002441,0.0003052,0.0003052,0.0001831,0.0003967,0.0000916,0.0003357,0.0000916,0.0002747,0.0001221,0.0001831,0.0001221,0.0001221,0.0000610,0.0002136,0.0000000,0.0002136,0.0000610,0.0001831,0.0000916,0.0002136,0.0001221,0.0002441,0.0001831,0.0001831,0.0002136,0.0002441,0.0001526,0.0002441,0.0001221,0.0002136,0.0001526,0.0001221,0.0000916,0.0000916,0.0000305,0.0001526,-0.0000610,0.0001221,-0.0000610,0.0000916,0.0000305,0.0000610,0.0000610,0.0000916,0.0000916,0.0001526,0.0001831,0.0001221,0.0002441,0.0001221,0.0003052,0.0000916,0.0003662,0.0000916,0.0002747,0.0001831,0.0000916,0.0002747,0.0001221,0.0001526,0.0001526,0.0001221,0.0001526,0.0001526,0.0001526,0.0002136,0.0001221,0.0003357,0.0000916,0.0004272,0.0001526,0.0003967,0.0003052,0.0004272,0.0003052,0.0004883,0.0002747,0.0005493,0.0003357,0.0005188,0.0003662,0.0004578,0.0003967,0.0004272,0.0004578,0.0003357,0.0005188,0.0002441,0.0006409,0.0001831,0.0005798,0.0003967,0.0004272,0.0005493,0.0003052,0.0006409,0.0003967,0.0006409,0.0004883,0.0005188,0.0006104,0.0005188,0.0007019,0.0004578,0.0007019,0.0004883,0.0006714,0.0005798,0.0005493,0.0006714,0.0004883,0.0007019,0.0004883,0.0007019,0.0004883,0.0007324,0.0005798,0.0006409,0.0007324,0.0005493,0.0008240,0.0006714,0.0007324,0.0007629,0.0007019,0.0008240,0.0007935,0.0007629,0.0007935,0.0008545,0.0007019,0.0008850,0.0007629,0.0007935,0.0008545,0.0007935,0.0007629,0.0008545,0.0008545,0.0007935,0.0009460,0.0007324,0.0010071,0.0007629,0.0010681,0.0007935,0.0009460,0.0009155,0.0009460,0.0009460,0.0009460,0.0009460,0.0008850,0.0010376,0.0008850,0.0009766,0.0009155,0.0008850,0.0010071,0.0008240,0.0010376,0.0007629,0.0010376,0.0007324,0.0010071,0.0007629,0.0009766,0.0007019,0.0009460,0.0007629,0.0008545,0.0008240,0.0008545,0.0007629,0.0008545,0.0007935,0.0008850,0.0007629,0.0008545,0.0007629,0.0007935,0.0008850,0.0006409,0.0008850,0.0006409,0.0007629,0.0006409,0.0007629,0.0005188,0.0007935,0.0004272,0.0007019,0.0005188,0.0006104,0.0005493,0.0005188,0.0005798,0.0004883,0.0005798,0.0005493,0.0005188,0.0005798,0.0004883,0.0005798,0.0004272,0.0005798,0.0004578,0.0004578,0.0004578,0.0003357,0.0004883,0.0002441,0.0004272,0.0002441,0.0002441,0.0003357,0.0001221,0.0003052,0.0001831,0.0001221,0.0002136,0.0001221,0.0001526,0.0001831,0.0001526,0.0000916,0.0002747,-0.0000916,0.0002747,0.0000305,0.0001526,0.0000610,0.0000916,-0.0001221,0.0001831,-0.0001221,-0.0000305,-0.0000305,-0.0001831,-0.0000916,-0.0001221,-0.0001526,-0.0001831,-0.0001221,-0.0002441,-0.0001526,-0.0001221,-0.0002441,-0.0000916,-0.0001831,-0.0001526,-0.0000610,-0.0002441,-0.0000916,-0.0002441,-0.0000610,-0.0003052,-0.0000916,-0.0003052,-0.0002136,-0.0002441,-0.0003357,-0.0001831,-0.0003662,-0.0002747,-0.0003052,-0.0003357,-0.0002136,-0.0002747,-0.0002441,-0.0002441,-0.0001831,-0.0003052,0.0000000,-0.0003052,-0.0000610,-0.0001526,-0.0002441,-0.0000610,-0.0002441,-0.0001221,-0.0002136,-0.0002441,-0.0002136,-0.0002136,-0.0002747,-0.0002441,-0.0002441,-0.0002747,-0.0002136,-0.0002441,-0.0002136,-0.0001831,-0.0001526,-0.0001831,-0.0000916,-0.0000916,-0.0001221,-0.0000610,-0.0000610,-0.0001221,0.0000305,-0.0001526,0.0000000,-0.0000916,-0.0001221,-0.0000305,-0.0001221,-0.0000305,-0.0001221,-0.0000610,-0.0001526,0.0000916,-0.0001831,0.0000916,-0.0000305,-0.0000305,0.0001526,-0.0000610,0.0002747,-0.0000305,0.0002441,0.0001221,0.0001526,0.0002441,0.0001831,0.0001831,0.0002747,0.0001526,0.0002441,0.0003052,0.0001526,0.0003357,0.0001831,0.0003357,0.0002136,0.0004272,0.0001831,0.0004883,0.0003052,0.0005188,0.0003357,0.0005798,0.0004578,0.0004883,0.0007019,0.0003662,0.0007935,0.0004272,0.0006714,0.0006409,0.0005188,0.0007324,0.0004883,0.0007019,0.0005798,0.0005493,0.0007019,0.0004578,0.0007629,0.0004883,0.0006714,0.0006409,0.0006409,0.0006409,0.0006714,0.0006409,0.0007629,0.0006409,0.0007935,0.0006714,0.0007629,0.0007324,0.0007629,0.0007324,0.0008545,0.0007324,0.0007629,0.0008545,0.0005798,0.0010071,0.0006104,0.0008545,0.0007629,0.0006104,0.0008545,0.0006104,0.0007935,0.0006104,0.0007019,0.0007019,0.0006104,0.0007019,0.0006104,0.0007324,0.0005798,0.0008545,0.0004883,0.0008545,0.0006104,0.0008240,0.0006714,0.0007324,0.0007629,0.0006409,0.0007935,0.0006409,0.0007324,0.0005188,0.0007935,0.0003662,0.0007935,0.0003967,0.0005188,0.0005188,0.0004272,0.0004883,0.0004578,0.0003967,0.0005188,0.0003662,0.0005798,0.0003357,0.0006104,0.0003967,0.0005188,0.0005188,0.0004578,0.0004883,0.0004883,0.0003662,0.0004883,0.0003052,0.0004272,0.0002136,0.0004272,0.0000305,0.0004272,0.0000305,0.0002441,0.0001526,0.0000916,0.0001526,0.0001526,0.0000305,0.0002441,0.0000305,0.0002136,0.0000916,0.0001221,0.0001526,0.0001526,0.0000610,0.0001526,0.0000916,0.0000305,0.0001221,-0.0000610,0.0000000,0.0000610,-0.0002136,0.0000610,-0.0002136,-0.0000916,-0.0000916,-0.0002136,-0.0000610,-0.0001831,-0.0001221,-0.0000610,-0.0001831,-0.0000305,-0.0001526,-0.0000305,-0.0001526,0.0000000,-0.0001526,-0.0000610,-0.0000916,-0.0001221,-0.0002136,0.0000000,-0.0003662,0.0000000,-0.0003662,-0.0001831,-0.0002747,-0.0001831,-0.0003662,-0.0000916,-0.0004272,-0.0001221,-0.0003357,-0.0001221,-0.0002136,-0.0001221,-0.0002747,-0.0000305,-0.0002747,0.0000610,-0.0003052,-0.0000305,-0.0001526,-0.0002136,-0.0000305,-0.0002747,-0.0001526,-0.0001526,-0.0003052,-0.0001221,-0.0002747,-0.0002747,-0.0001526,-0.0003967,-0.0000916,-0.0003662,-0.0001526,-0.0002747,-0.0001526,-0.0002747,-0.0001221,-0.0002747,-0.0001221,-0.0001831,-0.0000916,-0.0001831,-0.0001221,-0.0001221,-0.0001221,-0.0001831,-0.0000305,-0.0002136,-0.0001221,-0.0000610,-0.0003052,0.0000610,-0.0002747,-0.0001526,-0.0000610,-0.0002747,-0.0000305,-0.0001831,-0.0000916,-0.0001526,-0.0000610,-0.0001221,-0.0000305,-0.0000305,-0.0000610,-0.0000305,0.0000000,0.0000610,-0.0000305,0.0000610,0.0000305,0.0000000,0.0000610,0.0000610,-0.0000305,0.0001221,-0.0000610,0.0000916,0.0000305,0.0000305,0.0001221,-0.0000305,0.0001221,0.0000916,0.0000916,0.0001831,0.0000610,0.0001526,0.0002136,0.0000610,0.0003967,-0.0000916,0.0004272,0.0000305,0.0003052,0.0000610,0.0002441,0.0001221,0.0001831,0.0002136,0.0000610,0.0002441,0.0000610,0.0002136,0.0000916,0.0002441,0.0000916,0.0002136,0.0001526,0.0001526,0.0002136,0.0001526,0.0002136,0.0001526,0.0001526,0.0002136,0.0002136,0.0000610,0.0003052,0.0000305,0.0002441,0.0002136,0.0000305,0.0003357,0.0000000,0.0003357,0.0000305,0.0003357,0.0000916,0.0003052,0.0000916,0.0002747,0.0001526,0.0001831,0.0002747,0.0001221,0.0002136,0.0000916,0.0002136,0.0000916,0.0001831,0.0001221,0.0000305,0.0001526,0.0000610,0.0000916,0.0001526,0.0000305,0.0001221,0.0000610,0.0001831,0.0000916,0.0000916,0.0001831,0.0000305,0.0002747,0.0000305,0.0001831,0.0000610,0.0001221,0.0001221,0.0000305,0.0001526,-0.0000916,0.0001526,-0.0000916,0.0000916,0.0000000,-0.0000916,0.0001221,-0.0001526,0.0001831,-0.0001221,0.0000916,-0.0000305,0.0000610,-0.0000305,0.0001221,-0.0000916,0.0001526,-0.0000916,0.0001221,-0.0000916,0.0000916,-0.0001221,0.0000000,0.0000610,-0.0001831,0.0000305,-0.0000916,-0.0001221,0.0000305,-0.0000916,-0.0001221,0.0000610,-0.0001831,0.0000916,-0.0001221,0.0000000,-0.0000305,-0.0000305,0.0000000,0.0000000,-0.0000610,0.0000610,-0.0001221,0.0000916,-0.0000916,0.0000610,-0.0000610,0.0000305,-0.0000610,0.0000916,-0.0000305,0.0000000,0.0000916,-0.0000916,0.0001526,-0.0000305,0.0000000,0.0001526,-0.0001831,0.0002136,-0.0001221,0.0000610,0.0000305,-0.0000305,0.0000000,0.0000000,0.0000000,0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000305,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000305,0.0000610,0.0000000,-0.0000610,0.0001526,-0.0001526,0.0001526,-0.0001221,0.0001221,-0.0001221,0.0001221,-0.0001831,0.0001526,-0.0000916,0.0000305,-0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000000,0.0000000,-0.0000610,0.0000916,-0.0001831,0.0002441,-0.0002441,0.0002136,-0.0000916,0.0000916,-0.0000305,0.0000916,-0.0001221,0.0002136,-0.0002136,0.0002441,-0.0002441,0.0001831,-0.0001831,0.0000000,0.0000000,-0.0001526,0.0000305,-0.0001831,-0.0000610,-0.0000610,-0.0001526,0.0000000,-0.0000916,-0.0001221,0.0000916,-0.0002747,0.0002136,-0.0001831,0.0000305,0.0000000,-0.0000305,-0.0000610,0.0000000,-0.0001831,0.0001221,-0.0003052,0.0001221,-0.0003662,0.0000305,-0.0002747,-0.0000610,-0.0003052,-0.0000916,-0.0002747,-0.0001221,-0.0002441,-0.0002136,-0.0001526,-0.0002441,-0.0000610,-0.0003357,-0.0000610,-0.0002136,-0.0001526,-0.0001831,-0.0002136,-0.0002136,-0.0002136,-0.0002136,-0.0002747,-0.0003052,-0.0002747,-0.0003662,-0.0003357,-0.0003662,-0.0004578,-0.0003052,-0.0004883,-0.0003662,-0.0004578,-0.0004272,-0.0003357,-0.0004883,-0.0002441,-0.0005188,-0.0002747,-0.0004578,-0.0002747,-0.0003967,-0.0003357,-0.0003662,-0.0004578,-0.0002747,-0.0005493,-0.0003357,-0.0005188,-0.0005188,-0.0003662,-0.0007019,-0.0004578,-0.0005188,-0.0007019,-0.0003357,-0.0007935,-0.0004272,-0.0006104,-0.0004883,-0.0006104,-0.0004578,-0.0005798,-0.0004578,-0.0005493,-0.0004883,-0.0005188,-0.0005493,-0.0004578,-0.0006409,-0.0005188,-0.0005798,-0.0006409,-0.0005493,-0.0007324,-0.0005798,-0.0007324,-0.0006104,-0.0007324,-0.0006104,-0.0007629,-0.0006409,-0.0007019,-0.0006409,-0.0006104,-0.0006714,-0.0005798,-0.0007019,-0.0004883,-0.0006714,-0.0004883,-0.0006714,-0.0004883,-0.0006409,-0.0005188,-0.0005493,-0.0006104,-0.0005798,-0.0005493,-0.0006714,-0.0005188,-0.0006714,-0.0006104,-0.0006409,-0.0006409,-0.0006714,-0.0006104,-0.0006714,-0.0006104,-0.0006409,-0.0006104,-0.0006104,-0.0005493,-0.0005493,-0.0005493,-0.0005188,-0.0004883,-0.0005188,-0.0003967,-0.0004883,-0.0004883,-0.0003662,-0.0005493,-0.0003662,-0.0004578,-0.0005188,-0.0003967,-0.0004883,-0.0004883,-0.0004883,-0.0005188,-0.0003967,-0.0005493,-0.0003357,-0.0005798,-0.0004272,-0.0002441,-0.0005798,-0.0001526,-0.0004883,-0.0002441,-0.0003357,-0.0001831,-0.0004272,-0.0000916,-0.0003662,-0.0002441,-0.0002136,-0.0003052,-0.0003357,-0.0001831,-0.0003967,-0.0002136,-0.0003967,-0.0003052,-0.0003662,-0.0002441,-0.0004272,-0.0002136,-0.0004272,-0.0002441,-0.0002747,-0.0003052,-0.0002136,-0.0002441,-0.0003052,-0.0001526,-0.0001526,-0.0003052,-0.0000305,-0.0003967,0.0000000,-0.0002747,-0.0001831,-0.0001831,-0.0002441,-0.0002136,-0.0002136,-0.0002747,-0.0002441,-0.0001831,-0.0003052,-0.0002441,-0.0001831,-0.0003357,-0.0000610,-0.0003662,-0.0000610,-0.0003052,-0.0000305,-0.0002747,0.0000000,-0.0002747,-0.0000610,-0.0001831,-0.0001221,-0.0000916,-0.0002136,-0.0001831,-0.0001526,-0.0002747,-0.0000610,-0.0003662,-0.0001221,-0.0003357,-0.0001526,-0.0003052,-0.0002136,-0.0002747,-0.0002441,-0.0001831,-0.0002441,-0.0002136,-0.0002747,-0.0001221,-0.0003662,-0.0000610,-0.0003357,-0.0002441,-0.0001526,-0.0003967,-0.0001526,-0.0003662,-0.0002136,-0.0004272,-0.0001831,-0.0004883,-0.0001526,-0.0005493,-0.0001831,-0.0004578,-0.0003357,-0.0003357,-0.0003967,-0.0003967,-0.0003052,-0.0003967,-0.0003967,-0.0003662,-0.0004578,-0.0003357,-0.0003967,-0.0003967,-0.0004272,-0.0004578,-0.0004272,-0.0004272,-0.0004883,-0.0003967,-0.0005798,-0.0003662,-0.0006104,-0.0003967,-0.0005493,-0.0005493,-0.0004272,-0.0005493,-0.0005188,-0.0005188,-0.0005798,-0.0004883,-0.0004883,-0.0006409,-0.0003662,-0.0007629,-0.0003052,-0.0007935,-0.0003052,-0.0007629,-0.0003967,-0.0006714,-0.0005493,-0.0005188,-0.0006714,-0.0004578,-0.0006714,-0.0005798,-0.0005493,-0.0006104,-0.0005798,-0.0004883,-0.0006714,-0.0005188,-0.0005188,-0.0007019,-0.0004272,-0.0006409,-0.0005188,-0.0005798,-0.0005493,-0.0006409,-0.0004578,-0.0006409,-0.0005798,-0.0005493,-0.0006104,-0.0005798,-0.0004883,-0.0007019,-0.0004272,-0.0007019,-0.0004272,-0.0006409,-0.0004578,-0.0006714,-0.0003967,-0.0007019,-0.0003357,-0.0007324,-0.0003662,-0.0006714,-0.0003967,-0.0006409,-0.0004578,-0.0004578,-0.0005798,-0.0003357,-0.0006714,-0.0002747,-0.0006409,-0.0002441,-0.0006104,-0.0003052,-0.0004883,-0.0003662,-0.0003967,-0.0003662,-0.0004578,-0.0003052,-0.0004272,-0.0003052,-0.0004272,-0.0002441,-0.0004578,-0.0002441,-0.0003357,-0.0003357,-0.0001526,-0.0004272,-0.0001526,-0.0002747,-0.0002136,-0.0002441,-0.0001526,-0.0002441,-0.0001526,-0.0001221,-0.0003052,0.0000305,-0.0003357,0.0000916,-0.0003662,0.0001526,-0.0003662,0.0001221,-0.0001526,-0.0000610,0.0000305,-0.0001221,0.0000305,0.0000000,0.0000000,0.0000305,0.0000610,-0.0000305,0.0001221,0.0000305,0.0000610,0.0000610,0.0000916,0.0000610,0.0001221,0.0000610,0.0000916,0.0001526,0.0000610,0.0002441,-0.0000305,0.0003052,0.0000610,0.0001526,0.0002747,0.0000000,0.0003357,0.0000610,0.0002441,0.0001526,0.0001831,0.0002136,0.0001526,0.0002136,0.0001526,0.0002136,0.0001831,0.0002441,0.0001221,0.0003357,0.0000916,0.0002747,0.0002441,0.0001221,0.0003967,0.0000305,0.0003662,0.0001221,0.0002747,0.0001831,0.0002441,0.0001526,0.0002441,0.0002441,0.0001221,0.0003662,0.0000610,0.0003662,0.0001221,0.0003662,0.0001526,0.0003662,0.0001221,0.0003357,0.0002136,0.0003052,0.0002136,0.0002441,0.0002136,0.0002136,0.0002441,0.0001526,0.0002747,0.0001526,0.0001831,0.0001831,0.0001526,0.0002747,0.0000916,0.0003052,0.0000610,0.0002441,0.0002136,0.0001526,0.0002747,0.0001221,0.0001526,0.0002441,0.0000916,0.0002441,0.0000610,0.0001831,0.0000305,0.0000916,0.0001221,-0.0000610,0.0001221,-0.0000305,0.0000000,0.0000610,-0.0000916,-0.0000305,0.0000305,-0.0000916,0.0000610,-0.0000610,0.0000305,0.0000000,-0.0000305,0.0000916,-0.0000916,0.0000916,0.0000000,-0.0000305,0.0000916,-0.0001526,0.0000916,-0.0001831,0.0000305,-0.0001831,-0.0000916,-0.0001221,-0.0002136,-0.0001221,-0.0002136,-0.0001831,-0.0001831,-0.0001831,-0.0001526,-0.0002441,-0.0000305,-0.0003052,0.0000305,-0.0002441,-0.0000610,-0.0001221,-0.0001526,-0.0000610,-0.0001831,-0.0001221,-0.0001831,-0.0001221,-0.0002441,-0.0002441,-0.0001831,-0.0003357,-0.0002136,-0.0003357,-0.0003052,-0.0002747,-0.0003357,-0.0003052,-0.0002747,-0.0003052,-0.0002136,-0.0002747,-0.0002747,-0.0001831,-0.0002747,-0.0001221,-0.0002441,-0.0001526,-0.0003052,-0.0000610,-0.0003052,-0.0001221,-0.0002747,-0.0002747,-0.0002441,-0.0002136,-0.0003357,-0.0002441,-0.0003662,-0.0002747,-0.0003357,-0.0003357,-0.0002747,-0.0003967,-0.0002136,-0.0003357,-0.0002747,-0.0001831,-0.0003967,-0.0000610,-0.0003662,-0.0000610,-0.0003052,-0.0000916,-0.0003052,-0.0000916,-0.0002441,-0.0000916,-0.0003357,-0.0000610,-0.0003357,-0.0001221,-0.0002747,-0.0001831,-0.0002136,-0.0002441,-0.0000916,-0.0003052,-0.0000305,-0.0001831,-0.0000916,-0.0000610,-0.0000916,0.0000305,-0.0000916,0.0001221,-0.0000610,0.0001221,-0.0000305,0.0000610,0.0000000,0.0000916,-0.0000305,0.0000916,-0.0000916,0.0000610,-0.0000610,0.0000610,-0.0000305,0.0000610,-0.0000305,0.0000000,0.0001526,-0.0000610,0.0002441,-0.0000610,0.0002136,0.0000916,0.0001221,0.0002136,0.0001221,0.0001526,0.0001831,0.0000916,0.0002441,0.0000000,0.0003357,-0.0000610,0.0002441,0.0000610,0.0000916,0.0001526,0.0000610,0.0001526,0.0000000,0.0002136,0.0000305,0.0001221,0.0000916,0.0001221,0.0000916,0.0001831,0.0000305,0.0002136,0.0000916,0.0001526,0.0001526,0.0000916,0.0002136,0.0001221,0.0001831,0.0001526,0.0001221,0.0001831,0.0001526,0.0001221,0.0002136,0.0000916,0.0001526,0.0001221,0.0000916,0.0001221,0.0001831,0.0000000,0.0002136,0.0000305,0.0000916,0.0002441,-0.0000305,0.0002747,0.0000000,0.0002747,0.0000610,0.0002136,0.0001221,0.0001526,0.0002136,0.0001526,0.0000916,0.0002136,0.0000305,0.0002136,0.0000916,0.0001526,0.0000610,0.0001526,-0.0000305,0.0002441,0.0000305,0.0001221,0.0001526,0.0000610,0.0001831,0.0000305,0.0002136,0.0000610,0.0002441,0.0001221,0.0001221,0.0001221,0.0002441,0.0000610,0.0002747,-0.0000610,0.0003052,-0.0000305,0.0002441,-0.0000610,0.0001831,0.0000610,0.0000000,0.0002136,-0.0001526,0.0002136,0.0000305,0.0000000,0.0003052,-0.0001526,0.0002747,0.0000916,0.0000916,0.0002747,0.0000916,0.0001831,0.0002136,0.0002136,0.0001526,0.0002747,0.0001221,0.0002441,0.0001831,0.0002441,0.0001221,0.0002747,0.0001526,0.0002136,0.0002136,0.0002136,0.0002441,0.0002747,0.0001526,0.0003662,0.0001831,0.0003967,0.0002136,0.0004272,0.0001221,0.0005493,0.0001831,0.0004883,0.0002136,0.0004578,0.0003052,0.0004272,0.0003662,0.0003357,0.0003662,0.0003967,0.0003967,0.0003662,0.0003967,0.0003662,0.0003662,0.0004578,0.0003357,0.0003967,0.0004883,0.0003357,0.0004578,0.0004272,0.0004578,0.0004578,0.0004578,0.0004883,0.0004578,0.0005188,0.0004883,0.0004883,0.0005798,0.0004578,0.0006104,0.0004578,0.0005493,0.0006104,0.0003967,0.0007324,0.0004272,0.0006409,0.0005188,0.0006104,0.0005493,0.0007019,0.0004883,0.0007324,0.0006104,0.0006714,0.0007019,0.0006714,0.0007019,0.0007019,0.0008240,0.0005798,0.0008545,0.0006409,0.0007629,0.0007629,0.0006409,0.0008850,0.0004883,0.0009766,0.0005188,0.0008850,0.0007019,0.0007324,0.0007629,0.0007324,0.0008545,0.0007629,0.0007935,0.0007629,0.0007935,0.0008240,0.0007324,0.0007935,0.0007629,0.0007019,0.0007629,0.0007019,0.0006104,0.0007935,0.0005493,0.0007019,0.0006409,0.0005798,0.0007019,0.0004883,0.0007629,0.0004578,0.0007019,0.0006104,0.0005493,0.0007019,0.0005188,0.0006409,0.0005798,0.0006104,0.0005798,0.0005798,0.0005798,0.0004883,0.0006409,0.0003357,0.0006714,0.0003967,0.0004883,0.0004883,0.0003357,0.0004883,0.0003357,0.0004272,0.0003662,0.0003967,0.0002747,0.0004883,0.0001526,0.0005493,0.0001221,0.0005188,0.0001831,0.0004272,0.0003052,0.0003052,0.0003052,0.0003052,0.0002747,0.0002747,0.0003357,0.0001526,0.0002747,0.0001831,0.0001831,0.0002136,0.0001526,0.0001831,0.0001221,0.0001221,0.0001221,0.0001221,0.0001221,0.0001221,0.0000916,0.0000916,0.0000610,0.0001221,0.0000000,0.0001526,-0.0000610,0.0001221,-0.0000916,0.0001221,-0.0001526,0.0000305,-0.0000610,-0.0000916,-0.0000610,-0.0001526,-0.0000916,-0.0001221,-0.0001221,-0.0001221,-0.0002441,0.0000000,-0.0003357,0.0000305,-0.0003052,-0.0001221,-0.0001526,-0.0002441,-0.0000610,-0.0002747,-0.0001526,-0.0001831,-0.0002747,-0.0000916,-0.0002747,-0.0001831,-0.0002136,-0.0002441,-0.0001526,-0.0002747,-0.0001831,-0.0002441,-0.0001831,-0.0002441,-0.0002441,-0.0001831,-0.0002747,-0.0001221,-0.0002747,-0.0001831,-0.0001831,-0.0002136,-0.0001221,-0.0001831,-0.0001526,-0.0001526,-0.0001526,-0.0000610,-0.0002136,0.0000000,-0.0002441,-0.0000610,-0.0000916,-0.0001831,-0.0000610,-0.0002136,-0.0000305,-0.0001526,-0.0001526,-0.0000610,-0.0002441,0.0000916,-0.0002441,-0.0000305,-0.0000610,-0.0001526,0.0000610,-0.0000610,-0.0001221,0.0001831,-0.0002747,0.0003052,-0.0002747,0.0002747,-0.0002136,0.0002136,-0.0001221,0.0001526,-0.0000916,0.0001831,-0.0002136,0.0003357,-0.0002136,0.0001831,0.0000305,-0.0000305,0.0002441,-0.0000610,0.0001526,0.0001221,0.0000000,0.0003357,0.0000305,0.0001831,0.0002441,0.0001221,0.0003357,0.0001526,0.0003052,0.0002441,0.0003052,0.0003052,0.0002747,0.0003662,0.0002747,0.0003662,0.0003052,0.0003357,0.0003967,0.0002441,0.0004272,0.0002441,0.0004272,0.0003357,0.0003662,0.0003967,0.0003052,0.0005188,0.0003662,0.0004578,0.0004578,0.0004578,0.0005188,0.0005493,0.0004578,0.0005493,0.0005798,0.0004272,0.0007019,0.0004578,0.0005493,0.0005798,0.0005188,0.0004883,0.0006714,0.0003357,0.0007019,0.0004578,0.0005798,0.0004578,0.0006409,0.0003967,0.0007324,0.0004272,0.0006409,0.0004883,0.0006409,0.0004883,0.0006409,0.0005188,0.0005798,0.0005798,0.0005188,0.0006104,0.0004578,0.0006714,0.0003967,0.0006409,0.0003967,0.0005798,0.0004883,0.0004883,0.0003967,0.0005493,0.0003662,0.0005798,0.0003052,0.0004578,0.0004883,0.0003662,0.0004578,0.0003967,0.0003967,0.0004578,0.0004578,0.0003357,0.0004272,0.0004883,0.0002441,0.0005798,0.0001831,0.0005493,0.0002136,0.0003967,0.0002747,0.0003357,0.0002747,0.0003052,0.0001831,0.0003357,0.0001831,0.0003052,0.0001831,0.0003052,0.0001221,0.0003357,0.0001526,0.0002136,0.0003357,0.0000916,0.0002747,0.0001526,0.0002136,0.0002136,0.0001526,0.0001831,0.0001221,0.0001831,0.0000610,0.0002747,-0.0000610,0.0003357,-0.0001221,0.0003052,-0.0000610,0.0001526,0.0000916,-0.0000610,0.0002136,-0.0001831,0.0002441,-0.0002136,0.0001526,-0.0001831,0.0000610,-0.0000610,-0.0001221,0.0000305,-0.0001526,0.0000610,-0.0001526,0.0000000,-0.0001221,0.0000305,-0.0000916,0.0000000,-0.0000916,-0.0000610,-0.0000305,-0.0000916,-0.0000916,-0.0000610,-0.0001526,-0.0000916,-0.0001831,-0.0001526,-0.0000916,-0.0003052,-0.0000610,-0.0002747,-0.0002136,-0.0000305,-0.0004578,0.0001221,-0.0003967,-0.0000610,-0.0001831,-0.0001831,-0.0000916,-0.0001526,-0.0001526,-0.0001526,-0.0000610,-0.0002441,-0.0000305,-0.0003052,0.0000305,-0.0003662,0.0000305,-0.0003967,-0.0000305,-0.0003052,-0.0000916,-0.0003052,-0.0000610,-0.0003662,-0.0000305,-0.0002747,-0.0000916,-0.0001526,-0.0002136,-0.0000610,-0.0002136,-0.0000305,-0.0002136,0.0000305,-0.0002136,0.0000000,-0.0001526,-0.0001526,-0.0000305,-0.0002136,0.0000000,-0.0002441,-0.0000610,-0.0002136,-0.0001526,-0.0001221,-0.0001526,-0.0001831,-0.0000916,-0.0002136,-0.0000610,-0.0001526,-0.0000610,-0.0001526,0.0000000,-0.0001526,0.0000000,-0.0000916,-0.0000610,0.0000305,-0.0001526,0.0000610,-0.0001831,0.0000610,-0.0001526,0.0000916,-0.0002136,0.0000610,-0.0000916,-0.0000916,0.0000610,-0.0000305,-0.0000916,0.0001526,-0.0001526,0.0001526,0.0000305,0.0000916,0.0000610,0.0001221,0.0000610,0.0000916,0.0001831,0.0000305,0.0002136,-0.0000305,0.0002441,-0.0000305,0.0002441,0.0000305,0.0000916,0.0001526,0.0000610,0.0000916,0.0001831,0.0000000,0.0001221,0.0001221,0.0001221,0.0000916,0.0001221,0.0001221,0.0000610,0.0001831,0.0001221,0.0000610,0.0002747,0.0000000,0.0001831,0.0002136,0.0000000,0.0003052,0.0000000,0.0001831,0.0001221,0.0000916,0.0000916,0.0001221,0.0000305,0.0001526,-0.0000610,0.0001831,-0.0000610,0.0001221,0.0000000,0.0000916,0.0000000,0.0000916,0.0000305,0.0000916,0.0000610,0.0000916,0.0000916,0.0000610,0.0000916,0.0001221,0.0000000,0.0002136,-0.0000916,0.0002441,-0.0001221,0.0001831,0.0000000,0.0000000,0.0001221,0.0000000,0.0000000,0.0001526,-0.0000610,0.0001221,0.0000305,0.0000916,-0.0000305,0.0002136,-0.0000916,0.0001831,0.0000305,0.0000000,0.0001221,-0.0000305,0.0000305,0.0000916,-0.0000610,0.0000610,-0.0000305,-0.0000305,0.0000610,0.0000000,-0.0000305,0.0000610,-0.0000305,0.0000610,0.0000610,0.0000000,0.0000610,0.0000305,0.0000610,0.0000610,0.0000000,0.0001221,-0.0000916,0.0001221,-0.0000610,0.0000305,0.0000000,-0.0000610,0.0000000,-0.0000610,0.0000000,-0.0000916,0.0000000,-0.0000610,-0.0000916,0.0001221,-0.0002136,0.0001831,-0.0002441,0.0002441,-0.0002441,0.0002747,-0.0001831,0.0001526,-0.0000610,0.0000916,-0.0000610,0.0000916,-0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000916,0.0000305,-0.0000305,-0.0000610,0.0000610,-0.0000916,0.0000305,-0.0000610,0.0000610,-0.0000305,0.0001221,-0.0000916,0.0001526,0.0000305,0.0000000,0.0002441,-0.0000916,0.0002136,-0.0000610,0.0002136,-0.0000610,0.0001831,-0.0000610,0.0000916,0.0000305,0.0000305,0.0000000,0.0000610,-0.0000610,0.0000610,-0.0000305,0.0000610,0.0000305,-0.0000305,0.0000610,0.0000305,0.0000610,0.0000305,0.0000916,0.0000000,0.0001526,-0.0000305,0.0001221,0.0000305,0.0000610,0.0000610,0.0000000,0.0000305,0.0000305,-0.0000305,0.0000000,0.0000000,-0.0000916,0.0000305,-0.0000610,0.0000000,-0.0001221,0.0000305,-0.0001221,0.0000916,-0.0000610,-0.0000916,0.0001221,-0.0001831,0.0002136,-0.0001221,0.0000610,0.0000000,-0.0000305,0.0000610,0.0000000,-0.0000916,0.0001221,-0.0002136,0.0001526,-0.0002441,0.0000610,-0.0001526,-0.0000916,0.0000000,-0.0002747,0.0000610,-0.0002441,0.0000305,-0.0001831,-0.0000610,-0.0000916,-0.0000610,-0.0000305,-0.0000916,-0.0000610,-0.0000305,-0.0000916,0.0000000,-0.0001221,-0.0001221,-0.0000305,-0.0001831,-0.0000305,-0.0001831,-0.0001526,-0.0001526,-0.0001831,-0.0002136,-0.0002136,-0.0001526,-0.0003052,-0.0000916,-0.0004272,-0.0000916,-0.0003662,-0.0001221,-0.0003052,-0.0002441,-0.0001831,-0.0003967,-0.0001221,-0.0003357,-0.0002747,-0.0001831,-0.0003662,-0.0002747,-0.0001831,-0.0005188,-0.0000610,-0.0006104,-0.0000610,-0.0006104,-0.0001831,-0.0005188,-0.0003357,-0.0003967,-0.0004578,-0.0003357,-0.0005798,-0.0002747,-0.0005188,-0.0003967,-0.0004272,-0.0004578,-0.0004272,-0.0003662,-0.0004272,-0.0004578,-0.0003052,-0.0005493,-0.0003052,-0.0004578,-0.0003967,-0.0004578,-0.0003662,-0.0005798,-0.0002747,-0.0006409,-0.0003357,-0.0005493,-0.0004578,-0.0004578,-0.0005188,-0.0003967,-0.0005798,-0.0003357,-0.0005188,-0.0004272,-0.0003662,-0.0005188,-0.0003052,-0.0004578,-0.0003662,-0.0004272,-0.0003967,-0.0003662,-0.0005188,-0.0002441,-0.0006104,-0.0002747,-0.0005493,-0.0004578,-0.0003662,-0.0006104,-0.0003357,-0.0005493,-0.0004272,-0.0004883,-0.0004578,-0.0004883,-0.0004272,-0.0004272,-0.0004578,-0.0003967,-0.0004883,-0.0003662,-0.0004272,-0.0003967,-0.0003967,-0.0004272,-0.0003662,-0.0004272,-0.0003967,-0.0004272,-0.0003967,-0.0003967,-0.0004883,-0.0003052,-0.0006104,-0.0002747,-0.0004883,-0.0004272,-0.0003662,-0.0004883,-0.0003662,-0.0003662,-0.0004578,-0.0002441,-0.0004883,-0.0002136,-0.0004272,-0.0002441,-0.0003357,-0.0002441,-0.0003662,-0.0001221,-0.0004578,-0.0000610,-0.0004578,-0.0001221,-0.0003052,-0.0002747,-0.0001831,-0.0003967,-0.0001221,-0.0003662,-0.0001831,-0.0002441,-0.0003357,-0.0001526,-0.0002747,-0.0002136,-0.0002136,-0.0001831,-0.0002747,-0.0001221,-0.0001831,-0.0001831,-0.0001221,-0.0002136,-0.0001221,-0.0001526,-0.0000916,-0.0001831,-0.0000610,-0.0002136,-0.0001221,-0.0000916,-0.0001831,-0.0000916,-0.0002136,-0.0000916,-0.0002136,-0.0000916,-0.0002136,-0.0001831,-0.0001526,-0.0002136,-0.0001526,-0.0001526,-0.0002747,-0.0000916,-0.0002136,-0.0001831,-0.0001221,-0.0002441,-0.0000916,-0.0001831,-0.0001221,-0.0002136,-0.0000610,-0.0002441,-0.0000916,-0.0001526,-0.0002441,-0.0000916,-0.0002136,-0.0001831,-0.0001526,-0.0002136,-0.0001831,-0.0002441,-0.0000916,-0.0002747,-0.0001221,-0.0002136,-0.0002136,-0.0001221,-0.0002136,-0.0001221,-0.0001831,-0.0001221,-0.0001526,-0.0001526,-0.0001831,-0.0001221,-0.0001526,-0.0001831,-0.0001221,-0.0001831,-0.0001831,-0.0001831,-0.0001831,-0.0002441,-0.0001526,-0.0002441,-0.0002441,-0.0001831,-0.0002747,-0.0002441,-0.0001831,-0.0003357,-0.0001526,-0.0003357,-0.0001831,-0.0003052,-0.0002441,-0.0002747,-0.0002747,-0.0002136,-0.0003662,-0.0002136,-0.0003357,-0.0002747,-0.0003052,-0.0003662,-0.0003052,-0.0003662,-0.0003662,-0.0003662,-0.0003967,-0.0003967,-0.0003967,-0.0004272,-0.0004272,-0.0003662,-0.0005188,-0.0002747,-0.0005188,-0.0003357,-0.0004272,-0.0004272,-0.0002747,-0.0004883,-0.0003052,-0.0004883,-0.0003357,-0.0003662,-0.0004578,-0.0003967,-0.0004272,-0.0005188,-0.0003357,-0.0005493,-0.0003967,-0.0005188,-0.0003967,-0.0005493,-0.0003357,-0.0006104,-0.0002747,-0.0005798,-0.0002747,-0.0005188,-0.0003357,-0.0004578,-0.0003052,-0.0005188,-0.0002747,-0.0005493,-0.0003052,-0.0004578,-0.0004883,-0.0003662,-0.0005188,-0.0004578,-0.0003967,-0.0005188,-0.0004272,-0.0003967,-0.0005493,-0.0003357,-0.0004578,-0.0003967,-0.0003357,-0.0004578,-0.0002747,-0.0004578,-0.0002747,-0.0003967,-0.0003052,-0.0003967,-0.0003052,-0.0004272,-0.0003052,-0.0004272,-0.0003662,-0.0003662,-0.0003662,-0.0004272,-0.0003357,-0.0004272,-0.0003967,-0.0002441,-0.0005188,-0.0001526,-0.0004883,-0.0002441,-0.0003052,-0.0003052,-0.0002441,-0.0002441,-0.0003052,-0.0001831,-0.0003357,-0.0001221,-0.0003052,-0.0001526,-0.0003052,-0.0001831,-0.0002136,-0.0002747,-0.0001221,-0.0003357,-0.0000916,-0.0002136,-0.0002441,-0.0000610,-0.0003052,0.0000305,-0.0002441,0.0000000,-0.0001526,-0.0000610,-0.0000610,0.0000000,0.0000000,0.0000000,0.0000305,-0.0000610,0.0001526,-0.0000610,0.0000916,0.0000000,0.0000610,0.0000305,0.0000305,0.0000305,0.0000000,0.0000610,0.0000000,0.0000305,0.0000610,0.0000000,0.0001221,-0.0000305,0.0001831,-0.0000305,0.0002441,-0.0000305,0.0002441,0.0000610,0.0001831,0.0000916,0.0002136,0.0000916,0.0001526,0.0001831,0.0000305,0.0002136,0.0000610,0.0000916,0.0000916,0.0000916,0.0001221,-0.0000305,0.0002441,-0.0000610,0.0002441,0.0000305,0.0001526,0.0001221,0.0001526,0.0001831,0.0001831,0.0001526,0.0002441,0.0001526,0.0001831,0.0002441,0.0001221,0.0002136,0.0001831,0.0000916,0.0001831,0.0000916,0.0001221,0.0001221,0.0001526,0.0000305,0.0002136,0.0000000,0.0002747,0.0000000,0.0003357,-0.0000305,0.0003967,-0.0000305,0.0003662,0.0001526,0.0002136,0.0002441,0.0001221,0.0002747,0.0001221,0.0002136,0.0000916,0.0001831,0.0000610,0.0001526,0.0000305,0.0001221,0.0000305,0.0000916,0.0000610,0.0000610,0.0000610,0.0000916,0.0001221,0.0000305,0.0001831,0.0000305,0.0001526,0.0000916,0.0000610,0.0001526,0.0000610,0.0000610,0.0000916,-0.0000305,0.0000916,-0.0000305,-0.0000305,0.0000916,-0.0001526,0.0000916,-0.0001831,0.0000916,-0.0001526,0.0000305,-0.0000916,0.0000000,-0.0001526,0.0001221,-0.0002441,0.0001831,-0.0002441,0.0000916,-0.0001221,0.0000305,-0.0001526,0.0000000,-0.0001221,0.0000000,-0.0000916,-0.0001221,-0.0000305,-0.0002136,0.0000610,-0.0001831,-0.0001221,-0.0000305,-0.0002747,0.0000610,-0.0002441,-0.0000305,-0.0002136,-0.0000610,-0.0001526,-0.0001221,-0.0000610,-0.0002136,-0.0000305,-0.0001831,0.0000000,-0.0002136,0.0000305,-0.0002136,-0.0000610,-0.0000610,-0.0001526,0.0000305,-0.0003052,0.0000610,-0.0001831,-0.0001221,-0.0000916,-0.0001831,-0.0001831,-0.0000916,-0.0001831,-0.0002136,-0.0000610,-0.0003357,-0.0000916,-0.0001831,-0.0002136,-0.0001221,-0.0001526,-0.0002747,0.0000305,-0.0003662,0.0000610,-0.0002747,-0.0000305,-0.0002441,0.0000000,-0.0002441,-0.0000305,-0.0001526,-0.0001526,-0.0000916,-0.0001526,-0.0001221,-0.0001526,-0.0000916,-0.0001526,-0.0001526,-0.0000610,-0.0001831,-0.0000305,-0.0001831,-0.0000305,-0.0001831,0.0000305,-0.0001526,0.0000000,-0.0000305,-0.0001221,0.0000610,-0.0000305,-0.0000916,0.0001221,-0.0000916,0.0000610,0.0000610,-0.0000610,0.0000610,0.0000000,0.0000305,0.0000000,0.0000305,0.0000610,-0.0000916,0.0001526,-0.0001526,0.0002136,-0.0000916,0.0000916,0.0000305,0.0000000,0.0000916,0.0001221,0.0000000,0.0001526,0.0000000,0.0001526,0.0000916,0.0001221,0.0000305,0.0000916,0.0001526,0.0000305,0.0000916,0.0000916,-0.0000610,0.0002441,-0.0001221,0.0002441,-0.0000916,0.0000916,0.0000916,0.0000305,0.0001831,-0.0000610,0.0001831,0.0000000,0.0002136,0.0000305,0.0002136,-0.0000305,0.0003052,-0.0001221,0.0003052,0.0000000,0.0001526,0.0000610,0.0001221,0.0000000,0.0001831,0.0000000,0.0000610,0.0001526,-0.0000305,0.0001526,0.0000305,0.0000916,0.0001221,0.0000610,0.0001831,0.0000610,0.0001526,0.0000916,0.0001831,0.0000916,0.0001831,0.0000916,0.0001221,0.0001831,0.0000305,0.0001526,0.0000610,0.0000916,0.0001526,0.0000000,0.0001221,0.0000305,0.0000916,0.0001221,0.0000916,0.0000610,0.0000610,0.0001221,0.0001221,0.0001221,0.0000916,0.0000916,0.0001221,0.0000916,0.0001526,0.0000610,0.0000916,0.0000610,0.0001221,0.0000000,0.0001526,0.0000000,0.0000916,0.0000305,0.0000610,0.0000610,0.0000000,0.0001526,-0.0000916,0.0002136,-0.0000916,0.0001526,0.0000000,0.0000916,0.0000305,0.0000916,0.0000000,0.0001526,0.0000000,0.0001221,0.0000610,0.0000610,0.0001221,0.0000305,0.0001526,0.0000000,0.0002136,0.0000305,0.0001221,0.0001526,0.0000000,0.0002747,0.0000000,0.0002136,0.0000305,0.0001831,0.0001221,0.0000916,0.0002747,-0.0000305,0.0003357,-0.0000305,0.0002747,0.0001831,0.0001831,0.0001526,0.0002747,0.0000916,0.0004272,0.0000610,0.0002747,0.0002747,0.0001831,0.0003052,0.0002441,0.0001831,0.0003357,0.0002136,0.0002136,0.0003662,0.0001221,0.0003967,0.0001831,0.0003357,0.0002136,0.0003662,0.0002136,0.0003662,0.0003357,0.0001831,0.0004883,0.0001221,0.0005493,0.0001221,0.0005188,0.0001526,0.0005188,0.0001831,0.0003967,0.0003357,0.0003357,0.0004272,0.0002441,0.0004578,0.0003052,0.0003967,0.0004272,0.0003662,0.0004272,0.0004272,0.0004272,0.0004272,0.0004883,0.0003967,0.0005188,0.0004272,0.0005188,0.0004272,0.0005493,0.0004578,0.0004578,0.0005188,0.0004578,0.0005188,0.0005493,0.0004272,0.0005493,0.0004883,0.0005493,0.0005188,0.0006104,0.0004272,0.0006714,0.0004578,0.0006104,0.0005493,0.0006104,0.0005188,0.0006409,0.0004883,0.0005798,0.0006409,0.0003967,0.0007935,0.0003662,0.0006714,0.0005493,0.0004272,0.0007324,0.0003967,0.0007019,0.0004272,0.0006104,0.0005188,0.0005188,0.0006409,0.0004578,0.0005493,0.0005798,0.0004883,0.0005493,0.0005188,0.0004883,0.0005493,0.0004883,0.0005188,0.0004578,0.0004883,0.0004883,0.0004272,0.0004578,0.0004883,0.0003357,0.0005188,0.0003357,0.0004578,0.0003662,0.0003662,0.0003662,0.0003967,0.0003662,0.0003662,0.0003357,0.0003662,0.0003357,0.0003967,0.0002747,0.0003967,0.0003052,0.0003967,0.0002747,0.0003052,0.0003357,0.0002136,0.0004578,0.0000916,0.0003967,0.0000916,0.0003662,0.0001526,0.0002136,0.0002136,0.0001831,0.0001526,0.0002136,0.0001526,0.0000916,0.0003052,0.0000305,0.0002747,0.0000610,0.0002747,0.0000000,0.0002747,0.0000916,0.0001221,0.0001526,0.0000610,0.0000305,0.0002441,-0.0001221,0.0001221,0.0000305,-0.0000916,0.0001221,-0.0001221,-0.0000305,0.0000305,-0.0001221,-0.0000305,-0.0000305,-0.0001221,0.0000000,-0.0000916,0.0000000,-0.0000916,-0.0000610,0.0000000,-0.0001221,0.0000000,-0.0000305,-0.0001221,0.0000000,-0.0000916,-0.0000610,-0.0001221,-0.0000610,-0.0000916,-0.0001831,0.0000305,-0.0002747,-0.0000305,-0.0002136,-0.0000916,-0.0002441,0.0000305,-0.0003052,0.0000000,-0.0002136,-0.0001221,-0.0000916,-0.0001526,-0.0000305,-0.0002136,0.0000305,-0.0001526,-0.0000916,-0.0000305,-0.0001221,-0.0000610,-0.0000916,-0.0000610,-0.0001526,0.0000305,-0.0002441,0.0000916,-0.0003052,0.0000305,-0.0002136,0.0000000,-0.0001831,-0.0000305,-0.0002136,-0.0000305,-0.0000610,-0.0001526,-0.0000305,-0.0000916,-0.0000305,-0.0000916,0.0000000,-0.0001221,0.0000610,-0.0000916,0.0000305,-0.0000610,-0.0000305,0.0000305,-0.0000305,-0.0000305,0.0000305,-0.0000916,0.0000916,-0.0001221,0.0001526,-0.0001831,0.0001526,-0.0000916,0.0000916,0.0000305,0.0000000,0.0000916,0.0000610,0.0000916,0.0000916,0.0000916,0.0001526,0.0000916,0.0002441,0.0000610,0.0001831,0.0001831,0.0001221,0.0002441,0.0001526,0.0001221,0.0002441,0.0001831,0.0001221,0.0002747,0.0001221,0.0002136,0.0003052,0.0000916,0.0003662,0.0001526,0.0003357,0.0002747,0.0002136,0.0004578,0.0001221,0.0005188,0.0002747,0.0003357,0.0003662,0.0003662,0.0003052,0.0004578,0.0002441,0.0003662,0.0003662,0.0003357,0.0002747,0.0004272,0.0001831,0.0004272,0.0002441,0.0003662,0.0002747,0.0003357,0.0002747,0.0004272,0.0002136,0.0004578,0.0002441,0.0003662,0.0003967,0.0003357,0.0003967,0.0003357,0.0003357,0.0003967,0.0003967,0.0002747,0.0004578,0.0002136,0.0004578,0.0002441,0.0003662,0.0003052,0.0003052,0.0003052,0.0002747,0.0002747,0.0003357,0.0002747,0.0003357,0.0002441,0.0003662,0.0001831,0.0005188,0.0001526,0.0004883,0.0002136,0.0003967,0.0003357,0.0003052,0.0003662,0.0002747,0.0003662,0.0002747,0.0002747,0.0002441,0.0003052,0.0001831,0.0003052,0.0001831,0.0001526,0.0003052,0.0000916,0.0002747,0.0001221,0.0002136,0.0002136,0.0001221,0.0002747,0.0000610,0.0003052,0.0001831,0.0001221,0.0003052,-0.0000305,0.0003967,0.0000000,0.0002747,0.0000000,0.0002441,0.0000000,0.0001831,0.0000610,0.0000610,0.0001221,-0.0000305,0.0001526,-0.0000305,0.0001526,-0.0000610,0.0001526,-0.0000610,0.0001221,0.0000000,0.0000916,0.0000000,0.0000610,0.0000305,0.0000000,0.0000916,-0.0000610,0.0000305,0.0000305,-0.0000610,0.0000610,-0.0001221,0.0000916,-0.0001831,0.0000916,-0.0001526,0.0000305,-0.0000916,-0.0000610,-0.0000305,-0.0001221,0.0000305,-0.0000305,-0.0001526,0.0001221,-0.0002747,0.0002136,-0.0002136,0.0000610,-0.0000916,-0.0000610,-0.0000305,-0.0000916,-0.0000610,-0.0000916,-0.0000305,-0.0001831,0.0000000,-0.0002136,-0.0000610,-0.0000610,-0.0002441,0.0000305,-0.0002441,-0.0000305,-0.0001221,-0.0001221,-0.0000916,-0.0000610,-0.0000916,-0.0000916,-0.0000305,-0.0001526,0.0000305,-0.0001831,0.0000305,-0.0001221,-0.0001221,0.0000000,-0.0002136,0.0000305,-0.0001831,-0.0000610,-0.0001526,-0.0000305,-0.0002441,0.0000610,-0.0002747,0.0000916,-0.0002136,-0.0000610,-0.0000305,-0.0002136,0.0001221,-0.0002441,0.0000916,-0.0002136,0.0000305,-0.0001526,0.0000000,-0.0000916,-0.0001526,0.0000000,-0.0001221,-0.0000610,-0.0000610,-0.0001526,0.0000000,-0.0001221,-0.0000305,-0.0000916,0.0000000,-0.0000610,0.0000000,-0.0000610,0.0000305,-0.0000305,0.0000000,0.0000610,-0.0000916,0.0001221,-0.0000610,0.0000305,0.0000000,-0.0000305,0.0000916,-0.0001526,0.0001526,-0.0001526,0.0000305,0.0000305,-0.0000916,0.0000305,0.0000000,-0.0000305,0.0000000,0.0000610,-0.0000610,0.0001221,-0.0000305,0.0001221,-0.0000305,0.0001221,0.0000305,0.0000916,0.0000916,0.0000000,0.0001221,0.0000000,0.0001526,-0.0000916,0.0001831,-0.0001221,0.0002136,-0.0001221,0.0000916,0.0000000,0.0000305,0.0000305,0.0000305,0.0000000,0.0000916,0.0000305,0.0000305,0.0000916,0.0000305,0.0000916,0.0000916,0.0000000,0.0001831,-0.0000610,0.0002136,-0.0000305,0.0000610,0.0000916,-0.0000305,0.0001221,-0.0000305,0.0000305,0.0000000,0.0000610,-0.0000916,0.0001526,-0.0001526,0.0001526,-0.0000916,0.0000610,-0.0000305,0.0000610,0.0000305,-0.0000305,0.0001526,-0.0001221,0.0001526,-0.0000305,0.0000610,0.0000305,0.0000610,-0.0000610,0.0000916,0.0000000,-0.0000610,0.0000916,-0.0000610,-0.0000305,0.0000610,-0.0001221,0.0000610,-0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000610,-0.0000610,0.0000610,-0.0000610,0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000610,0.0000305,-0.0000305,0.0000305,-0.0000610,0.0000000,-0.0000916,0.0000000,-0.0000305,-0.0000916,0.0000000,-0.0000916,-0.0000305,-0.0000916,0.0000000,-0.0000610,-0.0000610,0.0000305,-0.0001526,0.0001221,-0.0001526,0.0000610,0.0000000,-0.0000610,0.0000610,-0.0000916,0.0000000,0.0000305,-0.0000610,0.0000305,-0.0001526,0.0001221,-0.0002136,0.0000916,-0.0000916,-0.0000916,0.0000305,-0.0001221,0.0000305,-0.0000916,0.0000000,-0.0000916,0.0000610,-0.0000916,0.0000610,-0.0000610,0.0000305,0.0000305,-0.0000610,0.0000916,-0.0000305,0.0000916,0.0000000,0.0000000,0.0000610,0.0000000,0.0000610,-0.0000610,0.0001221,-0.0001526,0.0001831,-0.0001221,0.0000305,0.0000305,-0.0000916,0.0000916,-0.0000610,-0.0000305,0.0000305,-0.0000305,0.0000000,0.0001221,-0.0001526,0.0001526,-0.0000916,0.0001526,-0.0000610,0.0001526,-0.0000916,0.0001221,0.0000000,0.0000610,0.0000305,0.0000000,0.0000916,-0.0001221,0.0001831,-0.0001526,0.0001221,-0.0000305,0.0000000,0.0000000,-0.0000305,0.0000305,-0.0000305,0.0000305,0.0000305,-0.0000916,0.0001526,-0.0001221,0.0001221,-0.0000610,0.0000916,-0.0000305,0.0001221,-0.0000916,0.0000916,0.0000000,0.0000000,0.0000610,-0.0000305,0.0000000,0.0000000,0.0000305,-0.0000916,0.0000916,-0.0001221,0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000305,-0.0000916,0.0000610,-0.0001221,0.0001526,-0.0001831,0.0000916,-0.0000305,-0.0000610,0.0000305,-0.0000305,-0.0000610,0.0000000,-0.0000916,0.0000000,-0.0000916,-0.0000305,-0.0001221,-0.0000610,-0.0000610,-0.0001526,0.0000000,-0.0002441,0.0000305,-0.0001831,-0.0001221,-0.0000610,-0.0001831,-0.0000610,-0.0001221,-0.0001526,-0.0000305,-0.0002136,-0.0000916,-0.0000916,-0.0002136,-0.0000610,-0.0001526,-0.0001831,-0.0000916,-0.0002441,-0.0001221,-0.0002136,-0.0001526,-0.0001831,-0.0002441,-0.0001526,-0.0002441,-0.0002136,-0.0001831,-0.0003052,-0.0001526,-0.0002441,-0.0002441,-0.0001831,-0.0003052,-0.0002136,-0.0001526,-0.0003662,-0.0001221,-0.0003357,-0.0001831,-0.0002441,-0.0002441,-0.0003052,-0.0001831,-0.0003052,-0.0001831,-0.0003662,-0.0002136,-0.0003357,-0.0002136,-0.0003052,-0.0003052,-0.0003052,-0.0003052,-0.0003052,-0.0002441,-0.0003662,-0.0002747,-0.0003357,-0.0003052,-0.0002747,-0.0003662,-0.0002441,-0.0003662,-0.0001831,-0.0005188,-0.0000916,-0.0005188,-0.0001831,-0.0003357,-0.0003662,-0.0002441,-0.0003967,-0.0003052,-0.0003662,-0.0003052,-0.0003662,-0.0003052,-0.0004272,-0.0002441,-0.0004883,-0.0002441,-0.0003662,-0.0003967,-0.0002747,-0.0004272,-0.0002747,-0.0003052,-0.0003967,-0.0002747,-0.0003967,-0.0002136,-0.0003662,-0.0002747,-0.0003357,-0.0002747,-0.0003357,-0.0002441,-0.0003662,-0.0002441,-0.0003052,-0.0003357,-0.0002747,-0.0003052,-0.0003662,-0.0002441,-0.0003052,-0.0003052,-0.0002441,-0.0003357,-0.0002441,-0.0002747,-0.0002441,-0.0002441,-0.0002441,-0.0002441,-0.0001831,-0.0003052,-0.0001526,-0.0002136,-0.0002747,-0.0001831,-0.0002136,-0.0002747,-0.0000916,-0.0003662,-0.0000916,-0.0003357,-0.0001221,-0.0002441,-0.0002136,-0.0001526,-0.0002441,-0.0000916,-0.0003052,-0.0000305,-0.0002747,-0.0000610,-0.0001831,-0.0001526,-0.0001221,-0.0001526,-0.0001221,-0.0001526,-0.0001526,-0.0000916,-0.0002441,-0.0000610,-0.0001831,-0.0001526,-0.0001526,-0.0001526,-0.0001831,-0.0000610,-0.0002441,-0.0000916,-0.0001221,-0.0001831,-0.0000610,-0.0001831,-0.0001221,0.0000000,-0.0002747,0.0000610,-0.0002441,-0.0000610,-0.0000610,-0.0001831,-0.0000305,-0.0001831,-0.0000916,-0.0001221,-0.0000916,-0.0001831,-0.0001221,-0.0000610,-0.0002441,0.0000610,-0.0003357,0.0000305,-0.0002441,0.0000000,-0.0002136,0.0000000,-0.0002136,0.0000305,-0.0002441,0.0000000,-0.0001221,-0.0001221,-0.0000610,-0.0001526,-0.0000916,-0.0000916,-0.0001831,-0.0000610,-0.0002136,-0.0000305,-0.0002441,-0.0000305,-0.0002441,-0.0000305,-0.0002441,-0.0000305,-0.0002136,-0.0000916,-0.0001526,-0.0001221,-0.0001221,-0.0001526,-0.0000916,-0.0002441,0.0000000,-0.0002747,-0.0000916,-0.0001831,-0.0002441,-0.0000610,-0.0002747,-0.0001221,-0.0002747,-0.0001831,-0.0001831,-0.0002441,-0.0001221,-0.0002747,-0.0001831,-0.0001831,-0.0002441,-0.0001526,-0.0002441,-0.0001831,-0.0002441,-0.0001831,-0.0002747,-0.0001221,-0.0003662,-0.0001526,-0.0003052,-0.0002747,-0.0002441,-0.0002441,-0.0003662,-0.0001831,-0.0003967,-0.0002441,-0.0002747,-0.0003052,-0.0003052,-0.0002441,-0.0003357,-0.0002136,-0.0003052,-0.0002747,-0.0002747,-0.0002441,-0.0002747,-0.0002747,-0.0002136,-0.0003967,-0.0001526,-0.0003967,-0.0002136,-0.0003662,-0.0002441,-0.0003662,-0.0002747,-0.0003662,-0.0003052,-0.0003357,-0.0003357,-0.0003052,-0.0003052,-0.0003662,-0.0002747,-0.0003052,-0.0003662,-0.0001831,-0.0003662,-0.0002136,-0.0003662,-0.0002136,-0.0003357,-0.0001831,-0.0003967,-0.0001526,-0.0003967,-0.0002136,-0.0003662,-0.0002747,-0.0002747,-0.0003357,-0.0002441,-0.0004272,-0.0001831,-0.0004272,-0.0001831,-0.0003967,-0.0002136,-0.0003357,-0.0002747,-0.0003052,-0.0002747,-0.0002136,-0.0003052,-0.0002136,-0.0003052,-0.0002441,-0.0002441,-0.0002136,-0.0003052,-0.0001526,-0.0002747,-0.0002747,-0.0000916,-0.0004578,0.0000305,-0.0004578,0.0000000,-0.0003662,-0.0000916,-0.0003052,-0.0001221,-0.0001831,-0.0001831,-0.0001831,-0.0001221,-0.0001831,-0.0001526,-0.0000916,-0.0001831,-0.0000610,-0.0001831,-0.0000916,-0.0000916,-0.0001526,0.0000000,-0.0001831,-0.0000610,-0.0000916,-0.0000610,-0.0001221,0.0000000,-0.0001221,-0.0000916,0.0000000,-0.0000916,-0.0000610,0.0000305,-0.0000916,0.0000000,0.0000000,-0.0000305,0.0000000,0.0000610,-0.0000610,0.0000916,-0.0000916,0.0001526,-0.0000916,0.0000916,0.0000000,-0.0000305,0.0001221,-0.0000916,0.0001526,-0.0000916,0.0000916,-0.0000305,0.0000916,0.0000610,0.0000000,0.0000916,0.0000305,0.0000610,0.0000916,0.0000000,0.0001221,0.0000610,0.0000610,0.0000610,0.0000916,0.0000305,0.0000610,0.0000916,0.0000610,0.0000916,0.0000916,-0.0000305,0.0002136,-0.0000305,0.0001526,0.0000610,0.0001526,0.0000305,0.0001831,-0.0000305,0.0002136,0.0000916,0.0000916,0.0001221,0.0000916,0.0000610,0.0001831,0.0000610,0.0000916,0.0001526,0.0000305,0.0001526,0.0000916,0.0000305,0.0002441,-0.0000610,0.0002747,-0.0000305,0.0001831,0.0000610,0.0001526,0.0000610,0.0001831,0.0000000,0.0001831,0.0000610,0.0000916,0.0001221,0.0000610,0.0000610,0.0001221,0.0000305,0.0000610,0.0000916,0.0000000,0.0001221,0.0000000,0.0000305,0.0001221,0.0000000,0.0000916,0.0000000,0.0000916,0.0000305,0.0000916,0.0000305,0.0000305,0.0000916,0.0000610,-0.0000610,0.0001831,-0.0001526,0.0001831,-0.0000610,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000000,0.0000000,-0.0000305,-0.0000305,0.0000305,-0.0000610,0.0000305,-0.0000305,0.0000000,0.0000000,-0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000916,0.0000610,-0.0001526,0.0000916,-0.0000916,0.0000000,-0.0000916,-0.0000305,-0.0000916,0.0000000,-0.0000916,-0.0000610,0.0000000,-0.0001526,0.0000305,-0.0000610,-0.0000305,-0.0000916,0.0000000,-0.0000610,0.0000000,-0.0000610,-0.0000305,-0.0001221,0.0001221,-0.0002441,0.0000916,-0.0001831,-0.0000305,-0.0000610,-0.0001831,0.0000916,-0.0003357,0.0001526,-0.0003052,-0.0000305,-0.0001221,-0.0000916,-0.0001221,-0.0000610,-0.0001221,-0.0001526,0.0000305,-0.0002136,0.0000305,-0.0001831,0.0000000,-0.0001831,0.0000305,-0.0001526,-0.0000610,-0.0000305,-0.0001831,0.0000000,-0.0001526,-0.0000916,-0.0000305,-0.0001526,-0.0000305,-0.0001526,-0.0000610,-0.0001526,0.0000000,-0.0001526,0.0000000,-0.0001221,-0.0000610,-0.0000610,-0.0000305,-0.0000916,0.0000610,-0.0001221,0.0000610,-0.0001221,0.0000610,-0.0000610,0.0000610,-0.0000305,-0.0000610,0.0000916,-0.0001526,0.0001221,-0.0000610,0.0000000,0.0000305,-0.0000305,0.0000000,0.0000305,0.0000000,0.0000000,0.0000305,0.0000305,0.0000000,0.0001221,-0.0000610,0.0000610,0.0000916,0.0000000,0.0000610,0.0001221,-0.0000610,0.0001526,0.0000610,-0.0000305,0.0001831,-0.0000916,0.0002136,-0.0000305,0.0000916,0.0000610,0.0000000,0.0001221,0.0000305,0.0000610,0.0000610,0.0000305,0.0000916,-0.0000305,0.0001526,-0.0000610,0.0001831,-0.0000916,0.0002136,-0.0000916,0.0001526,0.0000305,0.0000610,0.0001221,0.0000000,0.0001221,0.0000305,0.0001221,0.0000000,0.0001221,0.0000305,0.0000305,0.0000916,0.0000610,0.0000000,0.0000916,0.0000000,0.0000610,0.0000610,0.0000305,0.0000610,0.0000305,0.0001221,-0.0000305,0.0001831,-0.0000305,0.0001221,0.0001221,-0.0000305,0.0002136,-0.0000305,0.0001221,0.0000916,0.0000916,-0.0000305,0.0001831,-0.0000305,0.0001221,0.0000610,0.0000305,0.0000610,0.0000916,0.0000305,0.0000916,0.0000000,0.0000916,0.0000610,0.0000916,0.0000305,0.0001221,-0.0000916,0.0002441,-0.0000916,0.0001526,0.0000000,0.0000305,0.0000916,-0.0000305,0.0001221,-0.0000610,0.0001221,-0.0000610,0.0001526,-0.0001221,0.0002136,-0.0001526,0.0002136,-0.0000610,0.0000916,0.0000610,0.0000000,0.0000916,0.0000610,0.0000610,0.0000916,0.0000305,0.0000305,0.0001221,0.0000305,0.0000610,0.0000610,0.0000305,0.0000610,0.0001221,-0.0000916,0.0002136,-0.0000916,0.0001831,-0.0000305,0.0001221,-0.0000305,0.0002136,-0.0000305,0.0001526,0.0000916,0.0000000,0.0002136,0.0000000,0.0001831,0.0000610,0.0001221,0.0000916,0.0000916,0.0001526,0.0000610,0.0001831,0.0000610,0.0000916,0.0001831,0.0000000,0.0002747,0.0000000,0.0001831,0.0001221,0.0001526,0.0001526,0.0001526,0.0001526,0.0001831,0.0001831,0.0001526,0.0002441,0.0000610,0.0003967,-0.0000305,0.0003967,0.0000610,0.0002441,0.0002136,0.0002136,0.0001526,0.0002747,0.0001831,0.0001831,0.0002441,0.0002441,0.0001831,0.0002747,0.0002136,0.0002136,0.0003052,0.0002136,0.0002747,0.0002136,0.0003662,0.0001831,0.0003357,0.0002441,0.0002747,0.0003662,0.0002441,0.0002747,0.0003662,0.0002747,0.0003052,0.0003662,0.0002441,0.0003967,0.0003052,0.0003357,0.0003357,0.0003052,0.0004578,0.0002441,0.0004578,0.0002747,0.0003967,0.0003662,0.0003662,0.0004578,0.0002747,0.0004883,0.0003052,0.0003967,0.0004578,0.0003662,0.0003662,0.0004578,0.0002747,0.0004883,0.0003662,0.0003662,0.0003967,0.0003662,0.0003967,0.0003662,0.0003662,0.0004883,0.0002441,0.0005188,0.0002441,0.0004883,0.0003662,0.0004272,0.0003357,0.0004272,0.0003357,0.0004883,0.0003052,0.0004272,0.0003357,0.0003357,0.0004272,0.0003357,0.0003052,0.0004272,0.0001831,0.0004578,0.0001831,0.0004272,0.0002136,0.0003357,0.0002747,0.0003052,0.0002747,0.0003357,0.0002136,0.0003662,0.0002136,0.0003662,0.0002136,0.0003662,0.0002136,0.0003052,0.0003052,0.0002136,0.0003052,0.0001831,0.0003052,0.0001831,0.0002441,0.0002441,0.0001221,0.0002747,0.0000916,0.0002441,0.0001221,0.0002136,0.0001526,0.0001526,0.0001831,0.0001526,0.0001526,0.0001526,0.0001831,0.0001221,0.0002136,0.0001221,0.0001526,0.0001221,0.0001831,0.0000610,0.0002441,-0.0000610,0.0002441,-0.0000305,0.0001526,0.0000916,0.0000000,0.0000916,0.0000305,0.0000305,0.0000916,-0.0000305,0.0001221,-0.0000305,0.0000610,0.0000305,0.0000305,0.0000610,0.0000000,0.0000305,0.0000610,0.0000000,0.0000305,0.0000000,-0.0000305,0.0000610,-0.0000916,0.0000610,-0.0001221,0.0000305,-0.0000916,-0.0000305,-0.0001221,0.0000000,-0.0001221,0.0000000,-0.0001526,-0.0000305,-0.0000916,-0.0000916,0.0000000,-0.0001526,-0.0000305,0.0000000,-0.0001831,0.0001221,-0.0002441,0.0000610,-0.0000916,-0.0000305,-0.0000610,-0.0000916,0.0000000,-0.0001526,0.0000305,-0.0001526,-0.0000305,-0.0001221,-0.0000305,-0.0001221,-0.0000916,-0.0000305,-0.0001526,0.0000000,-0.0001526,0.0000000,-0.0001526,0.0000916,-0.0001831,0.0000610,-0.0001221,0.0000305,-0.0000610,0.0000000,0.0000000,-0.0000916,0.0000610,-0.0000305,-0.0001221,0.0001526,-0.0002441,0.0001831,-0.0001831,0.0000610,-0.0000916,0.0000000,0.0000000,-0.0000916,0.0000610,-0.0000916,0.0000305,0.0000000,0.0000000,0.0000305,0.0000000,0.0000610,0.0000000,0.0000610,0.0000610,0.0000305,0.0000916,0.0000610,0.0000000,0.0001526,0.0000305,0.0000610,0.0000916,0.0000305,0.0001221,0.0000610,0.0000916,0.0000610,0.0000916,0.0000916,0.0001221,0.0000916,0.0001221,0.0001221,0.0001526,0.0001221,0.0001831,0.0000610,0.0002747,0.0000916,0.0002136,0.0002136,0.0001221,0.0002136,0.0001831,0.0001526,0.0002136,0.0002136,0.0001221,0.0002747,0.0001221,0.0002136,0.0001831,0.0002136,0.0001831,0.0002441,0.0001831,0.0002136,0.0002747,0.0001526,0.0003052,0.0001526,0.0003357,0.0001526,0.0003357,0.0001831,0.0002441,0.0002747,0.0001831,0.0003052,0.0002136,0.0002136,0.0002441,0.0002136,0.0002747,0.0001221,0.0003357,0.0001221,0.0003967,0.0000610,0.0003357,0.0001221,0.0003052,0.0003052,0.0000916,0.0003967,0.0000305,0.0004272,0.0001221,0.0002747,0.0001831,0.0002747,0.0001221,0.0003357,0.0000916,0.0002747,0.0001221,0.0002441,0.0001526,0.0002136,0.0001831,0.0001221,0.0002747,0.0000305,0.0003357,0.0000305,0.0002747,0.0001221,0.0001526,0.0001831,0.0001831,0.0000916,0.0003052,-0.0000305,0.0003052,0.0000916,0.0001526,0.0001831,0.0000610,0.0001831,0.0000916,0.0002136,0.0000610,0.0001221,0.0001221,0.0000000,0.0002747,-0.0000610,0.0002441,-0.0000610,0.0001831,0.0000305,0.0001221,0.0000305,0.0001526,-0.0000305,0.0001526,0.0000305,0.0000610,0.0000916,0.0000305,0.0000305,0.0000916,0.0000000,0.0001221,-0.0001221,0.0001831,-0.0000916,0.0001221,0.0000000,-0.0000305,0.0000305,0.0000305,-0.0000305,0.0000916,-0.0000610,0.0000610,-0.0000916,0.0001221,-0.0001526,0.0001526,-0.0001526,0.0000610,-0.0000610,0.0000305,-0.0000916,0.0000305,-0.0001221,0.0000000,0.0000000,-0.0000610,-0.0000610,-0.0000305,-0.0000916,0.0000305,-0.0001221,0.0000916,-0.0002136,0.0000610,-0.0000916,-0.0000610,0.0000305,-0.0001526,0.0000305,-0.0000916,0.0000000,-0.0001221,-0.0000305,-0.0000610,-0.0000610,-0.0000610,-0.0000305,-0.0001221,-0.0000305,-0.0000610,-0.0000916,-0.0000305,-0.0001221,0.0000000,-0.0001221,0.0000000,-0.0001221,0.0000000,-0.0001221,0.0000000,-0.0000916,-0.0000610,0.0000000,-0.0001221,0.0000305,-0.0001526,-0.0000305,0.0000000,-0.0001526,0.0000916,-0.0002441,0.0000610,-0.0000916,-0.0001221,0.0000916,-0.0001831,0.0000000,-0.0000610,-0.0000610,0.0000000,-0.0000916,0.0000000,-0.0000610,0.0000000,-0.0000305,-0.0000610,-0.0000305,-0.0000305,0.0000305,-0.0001221,0.0000916,-0.0001221,0.0000000,0.0000000,-0.0000916,0.0000610,-0.0001221,0.0001221,-0.0002136,0.0001526,-0.0001526,0.0000000,0.0000610,-0.0001526,0.0001221,-0.0001831,0.0000916,-0.0001221,0.0000916,-0.0001221,0.0000305,-0.0000305,-0.0000305,0.0000610,-0.0000610,0.0000610,-0.0000610,0.0000916,-0.0000916,0.0001831,-0.0001526,0.0001831,-0.0001221,0.0001221,-0.0000305,0.0000610,-0.0000610,0.0000916,-0.0000916,0.0001526,-0.0000916,0.0000610,-0.0000610,0.0000916,-0.0000305,0.0000305,0.0001221,-0.0001831,0.0003052,-0.0002441,0.0003052,-0.0001526,0.0001526,-0.0000305,0.0000610,0.0000610,0.0000000,0.0000305,0.0000305,0.0000000,0.0000610,-0.0000610,0.0000610,-0.0000610,0.0000305,0.0000305,-0.0000916,0.0001221,-0.0001526,0.0001221,-0.0000916,0.0000610,0.0000000,-0.0000610,0.0001221,-0.0001221,0.0001526,-0.0000916,0.0000610,0.0000305,-0.0000305,0.0000305,0.0000000,0.0000000,0.0000305,-0.0000610,0.0000305,-0.0000305,-0.0000305,0.0000000,-0.0000305,-0.0000305,-0.0000305,0.0000000,-0.0000610,0.0000000,0.0000000,-0.0000610,0.0000610,-0.0000610,0.0000610,-0.0000916,0.0001221,-0.0000610,0.0000305,0.0000305,-0.0000916,0.0001221,-0.0000610,0.0000610,-0.0000916,0.0000000,0.0000305,-0.0000610,0.0000610,-0.0001221,0.0000305,-0.0000916,0.0000916,-0.0001526,0.0000916,-0.0001526,0.0001221,-0.0001221,0.0000916,-0.0001221,0.0000305,0.0000610,-0.0001221,0.0001221,-0.0000916,0.0000000,0.0000610,-0.0001221,0.0001221,-0.0001526,0.0000916,-0.0000916,0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000916,0.0000916,-0.0001831,0.0000610,0.0000000,-0.0001221,0.0000916,-0.0001526,0.0000610,-0.0000916,0.0000610,-0.0000610,0.0000000,0.0000000,-0.0000305,0.0000305,0.0000000,-0.0000610,0.0001221,-0.0001831,0.0001831,-0.0001526,0.0000916,-0.0000610,0.0000305,-0.0001221,0.0001221,-0.0001831,0.0001526,-0.0001221,0.0000305,-0.0000305,0.0000000,0.0000000,0.0000305,-0.0000305,0.0000610,0.0000000,0.0000000,0.0000916,-0.0000305,0.0000916,-0.0000610,0.0001221,-0.0000610,0.0000916,-0.0000305,0.0000305,0.0000305,-0.0000305,0.0000000,0.0000610,-0.0000916,0.0000916,-0.0000916,0.0000916,-0.0000610,0.0000610,-0.0000610,0.0000916,-0.0000610,0.0000916,-0.0000305,0.0000305,0.0000305,0.0000000,0.0000305,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000610,-0.0000610,0.0000916,-0.0001526,0.0001221,-0.0001526,0.0000305,-0.0000305,-0.0000610,0.0000610,-0.0000916,0.0000000,0.0000000,-0.0000305,0.0000000,0.0000305,-0.0000610,0.0000610,-0.0000916,0.0001221,-0.0001526,0.0001526,-0.0001221,0.0000916,-0.0001526,0.0000916,-0.0001526,0.0000916,-0.0001526,0.0000610,-0.0001221,-0.0000916,0.0000610,-0.0001831,0.0000916,-0.0001831,0.0000610,-0.0001221,0.0000305,-0.0000610,-0.0000610,0.0000305,-0.0000916,0.0000000,-0.0000305,-0.0000610,0.0000000,-0.0000916,0.0000000,-0.0001221,0.0000000,-0.0000916,-0.0000916,-0.0000610,-0.0000610,-0.0001831,0.0000610,-0.0003052,0.0000916,-0.0002136,-0.0000610,-0.0000916,-0.0001221,-0.0000916,-0.0000610,-0.0001831,-0.0000610,-0.0001221,-0.0000916,-0.0001221,-0.0000916,-0.0002136,0.0000000,-0.0002747,-0.0000305,-0.0001831,-0.0001526,-0.0001526,-0.0001526,-0.0002136,-0.0000305,-0.0003052,-0.0000610,-0.0002136,-0.0001831,-0.0001221,-0.0001526,-0.0002136,-0.0000610,-0.0002747,-0.0001221,-0.0001221,-0.0002441,-0.0000305,-0.0003357,-0.0000305,-0.0002747,-0.0000916,-0.0002441,-0.0001526,-0.0001831,-0.0002136,-0.0001221,-0.0002747,-0.0000916,-0.0003052,-0.0000610,-0.0002747,-0.0001526,-0.0001526,-0.0002136,-0.0001221,-0.0002136,-0.0002441,-0.0000305,-0.0002747,-0.0001221,-0.0001526,-0.0002441,-0.0001221,-0.0001526,-0.0002747,-0.0000916,-0.0002441,-0.0001526,-0.0001831,-0.0002136,-0.0001831,-0.0001831,-0.0002136,-0.0001526,-0.0001831,-0.0002136,-0.0001221,-0.0002441,-0.0001526,-0.0001221,-0.0002136,-0.0001526,-0.0001831,-0.0001831,-0.0001526,-0.0001526,-0.0001831,-0.0001526,-0.0001831,-0.0002136,-0.0000916,-0.0002747,-0.0000916,-0.0002441,-0.0000610,-0.0002747,-0.0001526,-0.0001526,-0.0002136,-0.0000610,-0.0002136,-0.0001831,-0.0000916,-0.0002136,-0.0000610,-0.0002136,-0.0000610,-0.0002441,0.0000305,-0.0003052,0.0000000,-0.0002136,-0.0000610,-0.0001526,-0.0000916,-0.0001831,-0.0000916,-0.0001221,-0.0001221,-0.0001221,-0.0001221,-0.0000610,-0.0001831,-0.0000305,-0.0001526,-0.0000610,-0.0001221,-0.0000610,-0.0000916,-0.0001221,0.0000000,-0.0001526,-0.0000305,-0.0000916,-0.0001221,-0.0000610,-0.0000610,-0.0000916,-0.0000610,-0.0000916,-0.0001526,0.0000305,-0.0001526,-0.0000610,-0.0000305,-0.0001526,-0.0000305,-0.0000305,-0.0001831,0.0001221,-0.0002747,0.0001221,-0.0002136,0.0000305,-0.0000916,-0.0000916,-0.0000305,-0.0001221,-0.0000305,-0.0000916,-0.0000916,-0.0000610,-0.0001221,-0.0000305,-0.0001221,-0.0000305,-0.0001526,0.0000000,-0.0002136,0.0001221,-0.0002747,0.0000916,-0.0002441,0.0000916,-0.0002441,0.0001221,-0.0002747,0.0000610,-0.0001526,0.0000000,-0.0001526,-0.0000305,-0.0001221,-0.0000610,-0.0000916,-0.0000610,-0.0000916,-0.0000610,-0.0000916,-0.0001526,-0.0000305,-0.0000916,0.0000000,-0.0002136,0.0000610,-0.0002747,0.0000916,-0.0001831,-0.0000305,-0.0001526,0.0000000,-0.0002441,0.0000610,-0.0002441,-0.0000305,-0.0000610,-0.0002441,0.0000000,-0.0001526,-0.0001526,0.0000000,-0.0002441,-0.0000610,-0.0000916,-0.0001526,-0.0000916,-0.0001221,-0.0001831,-0.0000305,-0.0002136,-0.0000610,-0.0002441,0.0000305,-0.0003967,0.0001526,-0.0004578,0.0000305,-0.0002136,-0.0002441,0.0000000,-0.0003357,-0.0000610,-0.0002136,-0.0002136,-0.0000610,-0.0002747,-0.0000610,-0.0002441,-0.0001221,-0.0001831,-0.0001831,-0.0000916,-0.0002747,-0.0000610,-0.0002747,-0.0001221,-0.0002136,-0.0001221,-0.0002747,-0.0000610,-0.0003052,-0.0001221,-0.0002136,-0.0001526,-0.0001831,-0.0001831,-0.0001831,-0.0001526,-0.0001526,-0.0001526,-0.0002136,-0.0000610,-0.0003052,0.0000305,-0.0003662,0.0000305,-0.0002747,-0.0001221,-0.0001831,-0.0002136,-0.0001221,-0.0002441,-0.0000610,-0.0003052,-0.0000916,-0.0002441,-0.0000916,-0.0002441,-0.0000916,-0.0002136,-0.0001526,-0.0001526,-0.0001221,-0.0001831,-0.0000916,-0.0001831,-0.0001526,-0.0001221,-0.0001831,-0.0000916,-0.0001526,-0.0001831,-0.0000610,-0.0002747,-0.0000305,-0.0002136,-0.0000916,-0.0001831,-0.0001221,-0.0001526,-0.0000916,-0.0002136,-0.0000305,-0.0002136,-0.0000610,-0.0001221,-0.0001526,-0.0000610,-0.0000916,-0.0001831,0.0000610,-0.0002441,0.0000305,-0.0001221,-0.0001221,0.0000610,-0.0002136,0.0000610,-0.0001831,0.0000000,-0.0000610,-0.0000916,-0.0000916,-0.0000305,-0.0000916,0.0000000,-0.0001221,-0.0000610,0.0000000,-0.0001221,0.0000305,-0.0000305,-0.0000916,0.0000916,-0.0000916,0.0000305,0.0000305,0.0000000,-0.0000305,0.0001221,-0.0000916,0.0001221,-0.0000610,0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000916,0.0001221,-0.0001831,0.0001526,-0.0001526,0.0001221,-0.0000610,0.0000305,-0.0000610,0.0001221,-0.0000916,0.0001526,-0.0000610,0.0000305,0.0000610,0.0000305,0.0000610,-0.0000305,0.0001221,-0.0001221,0.0002136,-0.0001221,0.0000610,0.0000305,-0.0000305,0.0000610,-0.0000916,0.0000916,-0.0000305,-0.0000305,0.0001221,-0.0001831,0.0001831,-0.0000610,0.0000305,0.0000916,-0.0000610,0.0000916,0.0000305,0.0000305,0.0000916,-0.0000916,0.0002136,-0.0001526,0.0002441,-0.0001831,0.0002136,-0.0001526,0.0001831,-0.0001221,0.0001831,-0.0001526,0.0001831,-0.0000916,0.0000610,0.0000610,-0.0000305,0.0000916,0.0000000,0.0000610,0.0000610,-0.0000305,0.0001526,-0.0000610,0.0000916,0.0000305,0.0000305,0.0000610,-0.0000305,0.0001221,-0.0001221,0.0001221,0.0000000,-0.0000610,0.0001526,-0.0001221,0.0000305,0.0000610,-0.0000916,0.0001831,-0.0001831,0.0001221,-0.0000610,0.0000305,0.0000610,-0.0000916,0.0000916,-0.0000305,-0.0000305,0.0000610,-0.0000916,0.0000916,-0.0000610,0.0000305,-0.0000610,-0.0000305,0.0000610,-0.0000916,0.0000000,-0.0000305,-0.0000305,-0.0000305,0.0000000,-0.0000916,-0.0000305,0.0000000,-0.0000610,0.0000000,-0.0001221,0.0000610,-0.0001221,0.0000305,-0.0000305,-0.0000916,0.0000305,-0.0000916,0.0000305,-0.0000610,-0.0000305,-0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000305,-0.0001221,0.0000916,-0.0001526,0.0000610,-0.0001526,0.0000916,-0.0002136,0.0001526,-0.0001831,0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000610,0.0000000,-0.0000610,-0.0000305,0.0000610,-0.0001221,0.0000000,0.0000305,-0.0001831,0.0001221,-0.0001526,0.0000305,-0.0000610,-0.0000305,-0.0000610,-0.0000305,-0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000916,0.0000000,-0.0000305,-0.0000305,-0.0000610,0.0000000,-0.0000610,0.0000000,-0.0000610,-0.0000610,0.0000610,-0.0001526,0.0000916,-0.0001221,0.0000000,0.0000305,-0.0001221,0.0000916,-0.0001221,0.0000305,0.0000000,-0.0000610,0.0000305,-0.0000305,-0.0000610,0.0001221,-0.0001526,0.0001221,-0.0000916,0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000610,0.0000916,-0.0000610,0.0000610,0.0000000,0.0000305,0.0000000,0.0000305,0.0000000,0.0000305,0.0000305,-0.0000305,0.0000610,0.0000305,-0.0000610,0.0001526,-0.0001221,0.0001526,-0.0000305,0.0000305,0.0000000,0.0000916,-0.0000305,0.0001526,-0.0001526,0.0002441,-0.0001526,0.0002136,-0.0001221,0.0001526,-0.0000916,0.0001831,-0.0000916,0.0001221,-0.0000305,0.0000305,0.0000305,0.0000305,0.0000000,0.0000610,0.0000000,0.0000610,0.0000000,0.0000610,0.0000000,0.0000916,-0.0000610,0.0000916,0.0000305,0.0000305,0.0000305,0.0000610,-0.0000916,0.0002441,-0.0002136,0.0002136,-0.0000916,0.0000610,0.0000610,-0.0000305,0.0000610,-0.0000305,0.0001221,-0.0000916,0.0001831,-0.0001221,0.0001526,-0.0000610,0.0001221,0.0000000,0.0000305,0.0000916,-0.0000610,0.0001221,-0.0000305,0.0000916,-0.0000305,0.0001221,-0.0000610,0.0000610,0.0000305,0.0000000,0.0000610,0.0000000,0.0000000,0.0000610,0.0000000,0.0000000,0.0000916,-0.0000916,0.0001831,-0.0001221,0.0001221,-0.0000305,0.0000610,0.0000916,-0.0000610,0.0000916,-0.0000305,0.0000610,0.0000916,-0.0000916,0.0001221,-0.0000916,0.0001221,-0.0000610,0.0000610,-0.0000305,0.0000000,0.0000610,-0.0000610,0.0000916,-0.0000610,0.0000305,0.0000000,0.0000305,0.0000305,0.0000000,0.0000305,0.0000000,0.0000000,0.0001221,-0.0000916,0.0001221,-0.0000610,0.0000305,0.0001221,-0.0001221,0.0001831,-0.0001221,0.0000916,0.0000000,0.0000305,0.0000000,0.0000916,-0.0000610,0.0000610,0.0000305,0.0000305,0.0000305,0.0000305,0.0000610,0.0000610,0.0000000,0.0000916,-0.0000305,0.0001526,0.0000305,0.0000000,0.0001526,-0.0000610,0.0001831,-0.0000305,0.0001221,0.0000610,0.0000000,0.0001831,-0.0000916,0.0002441,-0.0000610,0.0001526,0.0000000,0.0001526,-0.0000305,0.0002136,-0.0000916,0.0002441,-0.0000305,0.0001831,0.0000610,0.0000610,0.0001526,0.0000610,0.0001526,0.0000916,0.0000916,0.0001831,-0.0000305,0.0002747,0.0000000,0.0001831,0.0001221,0.0000000,0.0003052,-0.0000916,0.0003052,0.0000305,0.0001526,0.0000916,0.0001831,0.0000305,0.0003052,0.0000000,0.0002441,0.0000916,0.0001221,0.0002136,0.0001526,0.0001526,0.0001831,0.0001526,0.0001221,0.0002441,0.0001221,0.0002136,0.0000916,0.0002747,0.0000610,0.0003052,0.0000610,0.0002136,0.0001526,0.0001831,0.0002136,0.0001526,0.0001526,0.0002441,0.0000916,0.0002747,0.0001221,0.0002136,0.0002136,0.0001526,0.0002441,0.0001526,0.0002136,0.0001831,0.0001831,0.0001831,0.0002747,0.0000305,0.0003662,0.0000000,0.0003052,0.0001526,0.0001221,0.0002441,0.0001526,0.0001526,0.0002136,0.0001221,0.0001831,0.0002441,0.0000610,0.0003052,0.0000000,0.0003357,0.0000305,0.0002747,0.0001526,0.0000610,0.0003357,0.0000000,0.0003052,0.0000916,0.0000916,0.0002747,0.0000000,0.0003357,0.0000000,0.0001831,0.0001221,0.0001526,0.0001221,0.0001526,0.0000916,0.0000916,0.0002136,0.0000610,0.0001221,0.0001831,0.0000000,0.0001831,0.0001221,0.0000916,0.0001221,0.0000916,0.0000916,0.0001221,0.0001221,0.0000305,0.0001221,0.0000916,0.0000610,0.0001831,-0.0001221,0.0002441,0.0000000,0.0000916,0.0000916,0.0000000,0.0000916,0.0001221,-0.0000305,0.0001526,-0.0000305,0.0000916,0.0000916,-0.0000305,0.0001526,-0.0000305,0.0001526,-0.0000916,0.0001526,-0.0000305,0.0000916,0.0000610,-0.0000305,0.0000610,0.0000000,0.0000610,0.0000000,0.0000305,-0.0000305,0.0000610,-0.0000305,0.0000305,0.0000000,0.0000000,0.0000000,0.0000000,-0.0000305,0.0000610,-0.0000305,0.0000000,-0.0000305,0.0000000,0.0000000,0.0000000,0.0000000,-0.0000610,0.0000916,-0.0001831,0.0002136,-0.0002136,0.0001221,-0.0000916,0.0000000,0.0000000,0.0000000,-0.0000610,-0.0000305,0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000305,-0.0000916,0.0000916,-0.0001526,0.0000610,-0.0000305,-0.0000610,0.0000916,-0.0000916,0.0000000,0.0000000,-0.0000610,0.0000610,-0.0000305,-0.0000610,0.0000916,-0.0001831,0.0001831,-0.0001221,0.0000000,0.0000305,-0.0000610,0.0000000,0.0000916,-0.0001831,0.0001831,-0.0001526,0.0000916,-0.0000305,0.0000000,0.0000305,-0.0000305,0.0000305,0.0000000,0.0000305,-0.0000305,0.0000610,-0.0000305,0.0000610,0.0000000,0.0000305,-0.0000305,0.0001221,-0.0000916,0.0001526,-0.0000916,0.0001221,-0.0000305,0.0000916,-0.0000610,0.0001221,0.0000000,0.0000305,0.0000305,0.0000610,-0.0000305,0.0001526,-0.0000305,0.0000610,0.0000916,-0.0000610,0.0002441,-0.0001526,0.0002747,-0.0001526,0.0002441,-0.0000305,0.0000916,0.0000916,0.0000610,0.0000610,0.0000916,0.0000000,0.0001831,-0.0000305,0.0001526,0.0000305,0.0000916,0.0000305,0.0001221,0.0000305,0.0001526,0.0000305,0.0000916,0.0000916,0.0000916,0.0000916,0.0001221,0.0000305,0.0001526,0.0000916,0.0000916,0.0000916,0.0000916,0.0001221,0.0000916,0.0000610,0.0001526,0.0000000,0.0002136,0.0000305,0.0000916,0.0001221,0.0000305,0.0001831,0.0000305,0.0001221,0.0000610,0.0001221,0.0000610,0.0001526,0.0000610,0.0000305,0.0002136,-0.0000305,0.0001831,0.0000000,0.0001831,0.0000000,0.0001831,0.0000305,0.0000305,0.0001831,-0.0000305,0.0002441,-0.0000916,0.0001831,0.0000000,0.0000916,0.0001526,-0.0000610,0.0001831,0.0000000,0.0000916,0.0000916,0.0000610,0.0000305,0.0001526,-0.0000305,0.0001831,0.0000000,0.0000610,0.0001221,-0.0000305,0.0002136,-0.0000916,0.0001526,0.0000305,0.0000610,0.0000610,0.0000305,0.0000916,-0.0000305,0.0001526,-0.0000305,0.0001221,-0.0000305,0.0000916,0.0000305,0.0000305,0.0000916,-0.0000305,0.0000610,0.0000610,0.0000000,0.0000610,0.0000000,0.0000305,0.0000610,0.0000000,0.0000305,0.0000610,-0.0000305,0.0000916,-0.0000916,0.0001831,-0.0001831,0.0002441,-0.0001526,0.0000916,0.0000000,0.0000000,0.0000000,0.0000916,-0.0000916,0.0000916,-0.0000305,-0.0000305,0.0000305,0.0000305,-0.0000305,-0.0000305,0.0000610,-0.0001221,0.0001526,-0.0000916,-0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000610,0.0000305,-0.0000610,0.0000000,0.0000610,-0.0001221,0.0001221,-0.0001221,0.0000610,-0.0000305,0.0000000,-0.0000305,0.0000305,-0.0000610,0.0000000,-0.0000305,0.0000305,-0.0000916,0.0000610,-0.0000916,0.0000000,0.0000610,-0.0001526,0.0001526,-0.0001831,0.0001221,-0.0001221,0.0000610,-0.0000916,0.0000610,-0.0000305,-0.0000916,0.0000916,-0.0001221,0.0000610,-0.0000916,0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000305,-0.0000916,0.0000610,-0.0001221,0.0000916,-0.0000916,0.0000305,0.0000000,-0.0000916,0.0000916,-0.0001221,0.0000916,-0.0000916,0.0000610,-0.0000610,0.0000000,0.0000000,-0.0000305,0.0000000,0.0000000,-0.0000305,-0.0000305,0.0000000,0.0000000,0.0000000,-0.0000305,-0.0000305,-0.0000305,0.0000916,-0.0001221,0.0000916,-0.0000916,0.0000305,0.0000305,-0.0000610,0.0000000,0.0000305,-0.0000305,0.0000305,0.0000000,-0.0000610,0.0000610,0.0000000,-0.0000305,0.0000305,-0.0000305,0.0000305,0.0000000,-0.0000305,0.0000305,0.0000305,-0.0000916,0.0001221,-0.0001221,0.0000916,-0.0000610,0.0000916,-0.0000610,0.0000000,0.0000610,-0.0001221,0.0001831,-0.0001221,0.0000305,0.0000610,-0.0000610,0.0000916,-0.0000916,0.0000916,-0.0000610,0.0000916,-0.0000610,0.0000610,-0.0000610,0.0000610,-0.0000610,0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000305,-0.0000305,0.0000916,-0.0001221,0.0001221,-0.0000916,0.0001221,-0.0000916,0.0000610,-0.0000305,0.0000305,0.0000610,-0.0000610,0.0000305,0.0000305,-0.0000305,0.0000916,-0.0000916,0.0000610,-0.0000916,0.0001221,-0.0000916,0.0000305,0.0000305,-0.0001221,0.0001526,-0.0001526,0.0000916,-0.0000305,0.0000000,-0.0000305,0.0000610,-0.0000916,0.0001221,-0.0000916,0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000610,0.0000916,-0.0001221,0.0001526,-0.0001831,0.0001526,-0.0001221,0.0000916,-0.0001221,0.0001221,-0.0000916,0.0000000,0.0000610,-0.0001526,0.0001526,-0.0000916,0.0000000,0.0000305,-0.0000610,0.0000610,-0.0000610,0.0000610,-0.0000610,0.0000305,0.0000000,-0.0000305,0.0000305,-0.0000305,0.0000000,0.0000305,-0.0000305,-0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000610,0.0000305,-0.0000610,0.0000305,-0.0000305,0.0000000,0.0000000,0.0000000,-0.0000610,0.0000916,-0.0000916,0.0000610,-0.0000305,-0.0000305,0.0000610,-0.0000305,0.0000000,0.0000000,-0.0000305,0.0000305,0.0000000,-0.0000305,0.0000000,0.0000000,-0.0000305,0.0000916,-0.0001221,0.0000610,-0.0000305,0.0000000,0.0000305,0.0000000,0.0000000,0.0000000,0.0000000,-0.0000305,0.0000610,-0.0000305,0.0000610,-0.0000305,0.0000305,-0.0000610,0.0001526,-0.0002136,0.0002747,-0.0002441,0.0002136,-0.0001526,0.0001221,-0.0000916,0.0001221,-0.0001221,0.0000916,-0.0000305,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000305,0.0000000,0.0000000,0.0000305,-0.0000305,0.0000916,-0.0001526,0.0001526,-0.0000916,0.0000305,0.0000610,-0.0001221,0.0001221,-0.0000610,0.0000000,0.0000610,-0.0000916,0.0000916,-0.0000305,-0.0000305,0.0000916,-0.0000916,0.0000610,-0.0000305,-0.0000305,0.0001221,-0.0001526,0.0001526,-0.0001526,0.0001221,-0.0000610,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000610,-0.0000916,0.0000916,-0.0000916,0.0000916,-0.0000916,0.0000610,-0.0000610,0.0000610,-0.0000916,0.0001221,-0.0001831,0.0001526,-0.0001221,0.0000916,-0.0000916,0.0000610,-0.0000610,0.0000000,0.0000610,-0.0001221,0.0000916,-0.0000916,0.0000610,-0.0000610,0.0000305,0.0000000,-0.0001221,0.0001221,-0.0001221,0.0000610,-0.0000305,-0.0000916,0.0001221,-0.0002136,0.0001831,-0.0002136,0.0001221,-0.0001221,0.0000610,-0.0000916,0.0000305,-0.0000916,0.0000305,-0.0000305,-0.0000305,0.0000000,-0.0000916,0.0000610,-0.0001221,0.0000610,-0.0000610,-0.0000610,0.0000305,-0.0000916,-0.0000305,0.0000305,-0.0001526,0.0001221,-0.0002136,0.0001221,-0.0001526,-0.0000305,0.0000305,-0.0001221,0.0000305,-0.0000610,-0.0000610,-0.0000305,0.0000000,-0.0001221,0.0000916,-0.0002136,0.0001526,-0.0001831,0.0000305,-0.0000610,-0.0000610,0.0000000,-0.0000610,-0.0000305,-0.0000916,0.0000305,-0.0001221,0.0000610,-0.0001831,0.0000610,-0.0000916,-0.0000305,-0.0000610,-0.0000305,-0.0000916,0.0000000,-0.0000610,-0.0000916,0.0000305,-0.0001221,-0.0000305,-0.0000305,-0.0000610,-0.0000305,-0.0000305,-0.0000610,-0.0000610,-0.0000305,-0.0000916,0.0000916,-0.0001831,0.0000305,-0.0001221,-0.0000305,0.0000305,-0.0001526,0.0000305,-0.0001221,0.0000000,-0.0000610,-0.0000305,-0.0001221,0.0000305,-0.0001221,0.0000610,-0.0001526,0.0000305,-0.0001221,-0.0000305,0.0000000,-0.0000610,-0.0000610,-0.0000305,-0.0000916,0.0000305,-0.0000916,0.0000000,-0.0001221,0.0000305,-0.0000610,-0.0000305,-0.0000610,0.0000000,-0.0001221,0.0000610,-0.0000916,0.0000000,-0.0000610,0.0000000,-0.0001221,0.0000610,-0.0000610,-0.0000305,-0.0000305,-0.0000305,-0.0000610,0.0000305,-0.0001221,0.0000916,-0.0001526,0.0000916,-0.0001221,0.0000000,-0.0000305,0.0000000,-0.0000610,0.0000000,0.0000000,-0.0001221,0.0001526,-0.0002136,0.0000916,-0.0000610,0.0000000,0.0000000,-0.0000610,0.0000000,-0.0000305,0.0000305,-0.0001221,0.0001221,-0.0001831,0.0000916,-0.0000305,-0.0001221,0.0001221,-0.0002136,0.0001831,-0.0001831,0.0000610,-0.0000610,-0.0000610,0.0000610,-0.0000916,0.0000305,-0.0000305,-0.0000305,-0.0000305,-0.0000305,0.0000000,0.0000305,-0.0000916,0.0000610,-0.0000916,0.0000000,0.0000000,-0.0000305,0.0000000,-0.0000305,0.0000000,-0.0000916,0.0000916,-0.0001526,0.0000610,-0.0000610,0.0000000,-0.0000305,-0.0000305,0.0000305,-0.0000916,0.0000916,-0.0001831,0.0001526,-0.0001526,0.0001526,-0.0001831,0.0001221,-0.0001526,0.0001221,-0.0001221,0.0000610,-0.0000610,-0.0000305,0.0000305,-0.0000916,0.0000305,-0.0000610,0.0000000,0.0000000,-0.0000916,0.0000305,-0.0000610,0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000305,0.0000305,-0.0000305,-0.0000305,-0.0000305,-0.0000305,0.0000000,0.0000000,-0.0000305,-0.0000610,0.0000000,-0.0000610,0.0000610,-0.0001831,0.0001526,-0.0002441,0.0001526,-0.0001221,0.0000000,-0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000305,-0.0000305,-0.0000610,0.0000610,-0.0001221,0.0000305,-0.0000610,-0.0000610,0.0000610,-0.0001526,0.0000916,-0.0001526,0.0000305,-0.0000916,0.0000305,-0.0001221,0.0000916,-0.0002136,0.0001221,-0.0001526,0.0000305,-0.0000610,-0.0000610,0.0000610,-0.0001221,0.0000000,-0.0000916,0.0000305,-0.0000305,-0.0000610,-0.0000305,-0.0000610,0.0000000,-0.0000305,-0.0000305,-0.0000916,0.0000305,-0.0001221,0.0000610,-0.0001221,0.0000305,-0.0000916,0.0000000,-0.0000610,-0.0000305,-0.0000305,-0.0000305,-0.0000305,-0.0000305,-0.0000305,-0.0000610,0.0000305,-0.0000916,-0.0000305,0.0000305,-0.0000916,0.0000000,-0.0000305,-0.0000610,0.0000305,-0.0000610,-0.0000916,0.0000916,-0.0001526,0.0000916,-0.0000916,-0.0000916,0.0000916,-0.0001221,0.0000305,-0.0000305,-0.0001221,0.0000916,-0.0000916,0.0000000,-0.0000305,-0.0000305,-0.0000305,0.0000305,-0.0001221,0.0001221,-0.0002441,0.0002441,-0.0002441,0.0001831,-0.0001831,0.0000610,-0.0000610,0.0000000,0.0000000,-0.0000305,0.0000000,-0.0000610,0.0000610,-0.0001221,0.0001221,-0.0001526,0.0000610,0.0000305,-0.0001526,0.0002136,-0.0003052,0.0002747,-0.0002136,0.0001221,-0.0000916,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000000,0.0000000,0.0000000,-0.0000305,0.0000000,0.0000000,-0.0000305,0.0000916,-0.0001526,0.0001221,-0.0000916,0.0000610,-0.0000610,0.0000610,-0.0000610,0.0000610,-0.0000916,0.0000916,-0.0000916,0.0000916,-0.0000610,0.0000000,0.0000000,0.0000305,-0.0000610,0.0000610,0.0000000,-0.0000610,0.0000610,-0.0000305,-0.0000305,0.0000916,-0.0001221,0.0001221,-0.0001221,0.0001221,-0.0001526,0.0001526,-0.0000916,0.0000610,-0.0000305,0.0000000,0.0000000,0.0000610,-0.0000916,0.0001221,-0.0001221,0.0001221,-0.0000610,0.0000000,0.0000000,0.0000305,-0.0000305,0.0000610,-0.0000610,0.0000305,0.0000305,-0.0000610,0.0000610,-0.0000305,0.0000305,0.0000305,-0.0000610,0.0000000,0.0000916,-0.0000916,0.0000916,-0.0000610,-0.0000305,0.0000916,-0.0000610,0.0000305,-0.0000305,0.0000305,0.0000000,0.0000000,0.0000305,-0.0000610,0.0000305,0.0000305,-0.0000305,0.0000916,-0.0001221,0.0000916,-0.0000916,0.0000916,-0.0000305,0.0000000,0.0000000,-0.0000610,0.0001221,-0.0001526,0.0001831,-0.0001831,0.0001221,-0.0000610,0.0000305,0.0000000,0.0000000,0.0000000,0.0000000,-0.0000305,0.0000610,-0.0000916,0.0000610,0.0000000,-0.0000610,0.0000610,-0.0000305,-0.0000305,0.0000305,0.0000305,-0.0001221,0.0001831,-0.0001831,0.0000916,0.0000305,-0.0000916,0.0000916,-0.0000916,0.0000305,0.0000305,-0.0000305,0.0000305,-0.0000610,0.0000305,0.0000305,-0.0000916,0.0000916,-0.0000610,0.0000000,0.0000610,-0.0001221,0.0000916,-0.0000610,0.0000305,-0.0000305,0.0000305,-0.0000305,0.0000000,0.0000000,-0.0000305,0.0000916,-0.0000916,0.0000610,-0.0000916,0.0000916,-0.0000610,0.0000610,-0.0000610,0.0000305,-0.0000305,0.0000000,0.0000305,-0.0000610,0.0000610,-0.0000305,-0.0000305,0.0000305,-0.0000610,0.0000916,-0.0000610,0.0000000,0.0000000,0.0000000,-0.0000305,0.0000916,-0.0001526,0.0001221,-0.0000610,0.0000000,0.0000000,0.0000305,-0.0000916,0.0001221,-0.0001526,0.0001526,-0.0001526,0.0001526,-0.0001526,0.0000916,-0.0000610,0.0000610,-0.0000305,0.0000000,0.0000000,-0.0000610,0.0001221,-0.0000916,0.0000305,0.0000000,-0.0000305,0.0000610,-0.0000610,0.0000305,0.0000000,0.0000000,-0.0000305,0.0000610,-0.0001221,0.0001831,-0.0001831,0.0001526,-0.0001221,0.0000916,-0.0000610,0.0000305,0.0000000,-0.0000610,0.0001221,-0.0001526,0.0001526,-0.0001221,0.0000305,0.0000610,-0.0000916,0.0000610,0.0000305,-0.0001221,0.0001831,-0.0001831,0.0001831,-0.0001831,0.0001831,-0.0001526,0.0001221,-0.0000916,0.0000305,0.0000305,-0.0000610,0.0000916,-0.0001221,0.0000610,0.0000305,-0.0000610,0.0000305,-0.0000305,0.0000610,-0.0000610,0.0000610,-0.0000916,0.0000610,0.0000000,0.0000000,-0.0000305,0.0000305,0.0000000,0.0000000,-0.0000305,0.0000305,0.0000305,-0.0000916,0.0001526,-0.0001831,0.0001526,-0.0000916,];
@@ -0,0 +1,478 @@
+use rustfft::num_complex::Complex;
+
+use super::convolution::{add_frames, partition, Convolver, ConvolverIr};
+use super::engine::Engine;
+use super::filter::TiltEq;
+use super::freeze::SpectralFreeze;
+use super::ir_decay::MAX_IR_SECONDS;
+use super::multiband::{linear_phase_latency, Crossover, CrossoverKind, MAX_BANDS};
+use super::{db_to_gain, ms_to_samples};
+use super::spring_impulse_response::SPRING_IMPULSE_RESPONSE;
+
+/*
+Early/late split
+  - the IR is cut in two at the split time, the first bounces (the "boing") and the wash after
+    them, each in its own pair of convolvers with its own level, tilt EQ and stereo width
+  - the cut is a raised cosine crossfade centred on the split, and the two halves always sum back
+    to the whole IR, so with everything flat the pair sounds just like one convolver
+  - both pairs hear the same input. the late half keeps its place in time, silence and all, so
+    the two line up sample for sample with no latency to compensate
+
+Band bank
+  - each half is really a bank of up to 4 convolver pairs, one per band of the multiband split,
+    each band playing its own IR slot at its own level. with one band it's the plain pair
+  - linkwitz-riley: the input is split here, and each band's convolvers hear only their band
+  - linear phase: every band hears the whole input, and the worker filters the IRs instead
+  - the band layout comes in along with the IRs that fill it, so a band never plays an IR that
+    was cut for some other layout
+  - runs up to `MAX_CHUNK` samples at a time through scratch buffers sized up front, and the
+    convolvers' histories are sized for the longest IR each half can be handed, so nothing on the
+    audio thread allocates. IRs swapped out are handed back through `take_retired`
+*/
+
+pub const FFT_SIZE: usize = 1024;
+// the raw convolver output is far too hot
+pub const WET_DIVISOR: f32 = 5000.;
+const DEFAULT_SPLIT_MS: f32 = 80.;
+// as far as the split parameter goes
+const MAX_SPLIT_MS: f32 = 500.;
+const SPLIT_CROSSFADE_MS: f32 = 20.;
+const FREEZE_FADE_MS: f32 = 150.;
+// longest stretch processed in one go
+const MAX_CHUNK: usize = 4096;
+
+/// A stereo IR, segmented and transformed off the audio thread, ready to hand to the convolvers.
+#[derive(Clone)]
+pub struct PartitionedIr {
+  pub left: ConvolverIr,
+  pub right: ConvolverIr,
+}
+
+/// A stereo IR cut into its early and late halves, both ready for the convolvers.
+#[derive(Clone)]
+pub struct SplitIr {
+  pub early: PartitionedIr,
+  pub late: PartitionedIr,
+}
+
+/// The split IR for every band in use, along with the crossover they were cut for.
+#[derive(Clone)]
+pub struct BandIrs {
+  pub bands: Vec<SplitIr>,
+  pub crossover: CrossoverKind,
+}
+
+/// The longest IR the worker hands over: a tail lengthened as far as it goes, or the recording if
+/// that's longer, held back by the linear phase crossover.
+pub fn max_ir_length(sample_rate: f32) -> usize {
+  let longest = ms_to_samples(MAX_IR_SECONDS * 1000., sample_rate).max(SPRING_IMPULSE_RESPONSE.len());
+  longest + linear_phase_latency(sample_rate)
+}
+
+// the longest early half, which ends with the split's crossfade
+fn max_early_length(sample_rate: f32) -> usize {
+  ms_to_samples(MAX_SPLIT_MS + SPLIT_CROSSFADE_MS / 2., sample_rate) + 1
+}
+
+/// Cuts one side of an IR at the split time, as (early, late).
+fn split_at(impulse_response: &[f32], split_ms: f32, sample_rate: f32) -> (Vec<f32>, Vec<f32>) {
+  let split = split_ms.clamp(0., MAX_SPLIT_MS) * 0.001 * sample_rate;
+  let crossfade = SPLIT_CROSSFADE_MS * 0.001 * sample_rate;
+  // the early half is silent past the end of the crossfade, and needs no partitions there
+  let early_length = ((split + crossfade / 2.).ceil() as usize).min(impulse_response.len());
+  let mut early = Vec::with_capacity(early_length);
+  let mut late = Vec::with_capacity(impulse_response.len());
+  for (i, sample) in impulse_response.iter().enumerate() {
+    let position = ((i as f32 - split) / crossfade + 0.5).clamp(0., 1.);
+    let late_window = 0.5 - 0.5 * (std::f32::consts::PI * position).cos();
+    if i < early_length {
+      early.push(sample * (1. - late_window));
+    }
+    late.push(sample * late_window);
+  }
+  (early, late)
+}
+
+/// Splits a stereo IR into its early and late halves. Heavy, so meant for use off the audio thread.
+pub fn split_early_late(left: &[f32], right: &[f32], split_ms: f32, sample_rate: f32) -> SplitIr {
+  let (early_l, late_l) = split_at(left, split_ms, sample_rate);
+  let (early_r, late_r) = split_at(right, split_ms, sample_rate);
+  SplitIr {
+    early: PartitionedIr {
+      left: partition(&early_l, FFT_SIZE),
+      right: partition(&early_r, FFT_SIZE),
+    },
+    late: PartitionedIr {
+      left: partition(&late_l, FFT_SIZE),
+      right: partition(&late_r, FFT_SIZE),
+    },
+  }
+}
+
+/// One half of the IR, a convolver pair per band, with its own level, tone and width.
+struct Section {
+  convolvers_l: Vec<Convolver>,
+  convolvers_r: Vec<Convolver>,
+  tone_l: TiltEq,
+  tone_r: TiltEq,
+  level: f32, // linear
+  width: f32, // 1 leaves the image alone, 0 folds it to mono
+  // scratch for the bands' sum, and each band's convolver output
+  convolved_l: Vec<f32>,
+  convolved_r: Vec<f32>,
+  band_out: Vec<f32>,
+}
+
+impl Section {
+  fn new(impulse_response: &[f32], sample_rate: f32) -> Self {
+    let bank = || (0..MAX_BANDS).map(|_| Convolver::new(impulse_response, FFT_SIZE)).collect();
+    Self {
+      convolvers_l: bank(),
+      convolvers_r: bank(),
+      tone_l: TiltEq::new(sample_rate),
+      tone_r: TiltEq::new(sample_rate),
+      level: 1.,
+      width: 1.,
+      convolved_l: vec![0.; MAX_CHUNK],
+      convolved_r: vec![0.; MAX_CHUNK],
+      band_out: vec![0.; MAX_CHUNK],
+    }
+  }
+
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.tone_l.set_sample_rate(sample_rate);
+    self.tone_r.set_sample_rate(sample_rate);
+  }
+
+  // makes room in every convolver's history for IRs up to `samples` long. allocates
+  fn set_max_ir_len(&mut self, samples: usize) {
+    for convolver in self.convolvers_l.iter_mut().chain(self.convolvers_r.iter_mut()) {
+      convolver.set_max_ir_len(samples);
+    }
+  }
+
+  fn take_retired(&mut self) -> Option<ConvolverIr> {
+    self.convolvers_l.iter_mut().chain(self.convolvers_r.iter_mut()).find_map(|convolver| convolver.take_retired())
+  }
+
+  fn set_tilt_db(&mut self, tilt_db: f32) {
+    self.tone_l.set_tilt_db(tilt_db);
+    self.tone_r.set_tilt_db(tilt_db);
+  }
+
+  fn set_impulse_response(&mut self, band: usize, impulse_response: PartitionedIr) {
+    self.convolvers_l[band].set_ir(impulse_response.left);
+    self.convolvers_r[band].set_ir(impulse_response.right);
+  }
+
+  fn reset_band(&mut self, band: usize) {
+    self.convolvers_l[band].reset();
+    self.convolvers_r[band].reset();
+  }
+
+  fn reset(&mut self) {
+    for band in 0..MAX_BANDS {
+      self.reset_band(band);
+    }
+    self.tone_l.reset();
+    self.tone_r.reset();
+  }
+
+  // adds the whole half's spectrum, over the bands in use, onto `spectrum`
+  fn add_spectrum(convolvers: &[Convolver], bands: usize, spectrum: &mut [Complex<f32>]) {
+    for convolver in convolvers.iter().take(bands) {
+      add_frames(spectrum, convolver.current_spectrum());
+    }
+  }
+
+  // convolves each band, shapes the sum, and adds it into the wet buffers. the bands' inputs are
+  // at least as long as the wet buffers
+  fn process(&mut self, bands_l: &[Vec<f32>], bands_r: &[Vec<f32>], wet_l: &mut [f32], wet_r: &mut [f32]) {
+    let len = wet_l.len();
+    let (convolved_l, convolved_r) = (&mut self.convolved_l[..len], &mut self.convolved_r[..len]);
+    let band_out = &mut self.band_out[..len];
+    convolved_l.iter_mut().for_each(|sample| *sample = 0.);
+    convolved_r.iter_mut().for_each(|sample| *sample = 0.);
+    for (band, (input_l, input_r)) in bands_l.iter().zip(bands_r).enumerate() {
+      self.convolvers_l[band].process(&input_l[..len], band_out);
+      convolved_l.iter_mut().zip(band_out.iter()).for_each(|(sum, sample)| *sum += sample);
+      self.convolvers_r[band].process(&input_r[..len], band_out);
+      convolved_r.iter_mut().zip(band_out.iter()).for_each(|(sum, sample)| *sum += sample);
+    }
+    for i in 0..len {
+      let left = self.tone_l.process(convolved_l[i]);
+      let right = self.tone_r.process(convolved_r[i]);
+      let mid = 0.5 * (left + right);
+      let side = 0.5 * (left - right) * self.width;
+      wet_l[i] += (mid + side) * self.level;
+      wet_r[i] += (mid - side) * self.level;
+    }
+  }
+}
+
+/// How the frozen tail is blended with the live convolvers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FreezeInput {
+  /// New input is faded out of the convolvers while frozen.
+  Mute,
+  /// New input keeps reverberating on top of the frozen tail.
+  Mix,
+}
+
+/// Fades the frozen tail in and out.
+struct FreezeState {
+  engaged: bool,
+  input: FreezeInput,
+  amount: f32,
+  step: f32,
+  // per sample amounts for the current block
+  ramp: Vec<f32>,
+}
+
+impl FreezeState {
+  fn new(sample_rate: f32) -> Self {
+    Self {
+      engaged: false,
+      input: FreezeInput::Mute,
+      amount: 0.,
+      step: freeze_step(sample_rate),
+      ramp: Vec::with_capacity(MAX_CHUNK),
+    }
+  }
+
+  fn is_active(&self) -> bool {
+    self.engaged || self.amount > 0.
+  }
+
+  fn fill_ramp(&mut self, len: usize) {
+    let target = if self.engaged { 1. } else { 0. };
+    self.ramp.clear();
+    for _ in 0..len {
+      if self.amount < target {
+        self.amount = (self.amount + self.step).min(target);
+      } else if self.amount > target {
+        self.amount = (self.amount - self.step).max(target);
+      }
+      self.ramp.push(self.amount);
+    }
+  }
+}
+
+fn freeze_step(sample_rate: f32) -> f32 {
+  1. / (FREEZE_FADE_MS * 0.001 * sample_rate)
+}
+
+/// The recorded spring: early and late banks of partitioned convolvers, the crossover that
+/// feeds their bands, plus the spectral freeze that grows out of them.
+pub struct SpringIr {
+  early: Section,
+  late: Section,
+  bands: usize,
+  crossover: CrossoverKind,
+  crossover_l: Crossover,
+  crossover_r: Crossover,
+  band_levels: [f32; MAX_BANDS], // linear
+  freeze_l: SpectralFreeze,
+  freeze_r: SpectralFreeze,
+  freeze: FreezeState,
+  // scratch for each band's input, the input faded out under the freeze, and the captured
+  // spectrum, kept so the audio thread never allocates
+  bands_l: Vec<Vec<f32>>,
+  bands_r: Vec<Vec<f32>>,
+  muted_l: Vec<f32>,
+  muted_r: Vec<f32>,
+  spectrum: Vec<Complex<f32>>,
+}
+
+impl SpringIr {
+  pub fn new(sample_rate: f32) -> Self {
+    let (early, late) = split_at(SPRING_IMPULSE_RESPONSE, DEFAULT_SPLIT_MS, sample_rate);
+    let mut spring_ir = Self {
+      early: Section::new(&early, sample_rate),
+      late: Section::new(&late, sample_rate),
+      bands: 1,
+      crossover: CrossoverKind::LinkwitzRiley,
+      crossover_l: Crossover::new(sample_rate),
+      crossover_r: Crossover::new(sample_rate),
+      band_levels: [1.; MAX_BANDS],
+      freeze_l: SpectralFreeze::new(FFT_SIZE, 1),
+      freeze_r: SpectralFreeze::new(FFT_SIZE, 2),
+      freeze: FreezeState::new(sample_rate),
+      bands_l: vec![vec![0.; MAX_CHUNK]; MAX_BANDS],
+      bands_r: vec![vec![0.; MAX_CHUNK]; MAX_BANDS],
+      muted_l: vec![0.; MAX_CHUNK],
+      muted_r: vec![0.; MAX_CHUNK],
+      spectrum: vec![Complex { re: 0., im: 0. }; FFT_SIZE],
+    };
+    spring_ir.make_room(sample_rate);
+    spring_ir
+  }
+
+  // sizes the convolvers' histories for the longest IRs the worker can hand each half
+  fn make_room(&mut self, sample_rate: f32) {
+    self.early.set_max_ir_len(max_early_length(sample_rate));
+    self.late.set_max_ir_len(max_ir_length(sample_rate));
+  }
+
+  /// IRs the convolvers are done with, one at a time, to be freed off the audio thread.
+  pub fn take_retired(&mut self) -> Option<ConvolverIr> {
+    self.early.take_retired().or_else(|| self.late.take_retired())
+  }
+
+  // crossfades each band to its new IR, taking up the layout they were cut for
+  pub fn set_impulse_response(&mut self, impulse_response: BandIrs) {
+    let bands = impulse_response.bands.len().clamp(1, MAX_BANDS);
+    // bands that drop out are cleared, so they don't come back in ringing with old input
+    for band in bands..self.bands {
+      self.early.reset_band(band);
+      self.late.reset_band(band);
+    }
+    self.bands = bands;
+    self.crossover = impulse_response.crossover;
+    self.crossover_l.set_band_count(bands);
+    self.crossover_r.set_band_count(bands);
+    for (band, split) in impulse_response.bands.into_iter().take(MAX_BANDS).enumerate() {
+      self.early.set_impulse_response(band, split.early);
+      self.late.set_impulse_response(band, split.late);
+    }
+  }
+
+  pub fn set_band_crossover(&mut self, index: usize, frequency: f32) {
+    self.crossover_l.set_frequency(index, frequency);
+    self.crossover_r.set_frequency(index, frequency);
+  }
+
+  pub fn set_band_level_db(&mut self, band: usize, level_db: f32) {
+    self.band_levels[band] = db_to_gain(level_db);
+  }
+
+  pub fn set_early_level_db(&mut self, level_db: f32) {
+    self.early.level = db_to_gain(level_db);
+  }
+
+  pub fn set_early_tilt_db(&mut self, tilt_db: f32) {
+    self.early.set_tilt_db(tilt_db);
+  }
+
+  pub fn set_early_width(&mut self, width: f32) {
+    self.early.width = width;
+  }
+
+  pub fn set_late_level_db(&mut self, level_db: f32) {
+    self.late.level = db_to_gain(level_db);
+  }
+
+  pub fn set_late_tilt_db(&mut self, tilt_db: f32) {
+    self.late.set_tilt_db(tilt_db);
+  }
+
+  pub fn set_late_width(&mut self, width: f32) {
+    self.late.width = width;
+  }
+
+  pub fn set_freeze(&mut self, engaged: bool) {
+    // only recapture when freshly frozen, so a half faded tail is not overwritten by itself
+    if engaged && !self.freeze.is_active() {
+      // every band of both halves finishes its frames together, so between them they hold the
+      // whole IR's
+      let (bands, spectrum) = (self.bands, &mut self.spectrum);
+      let mut whole = |early: &[Convolver], late: &[Convolver], freeze: &mut SpectralFreeze| {
+        spectrum.iter_mut().for_each(|bin| *bin = Complex { re: 0., im: 0. });
+        Section::add_spectrum(early, bands, spectrum);
+        Section::add_spectrum(late, bands, spectrum);
+        freeze.capture(spectrum);
+      };
+      whole(&self.early.convolvers_l, &self.late.convolvers_l, &mut self.freeze_l);
+      whole(&self.early.convolvers_r, &self.late.convolvers_r, &mut self.freeze_r);
+    }
+    self.freeze.engaged = engaged;
+  }
+
+  pub fn set_freeze_input(&mut self, input: FreezeInput) {
+    self.freeze.input = input;
+  }
+
+  /// Splits one channel into the inputs for each band in use, at each band's level, into the
+  /// front of `split_input`.
+  fn split_bands(crossover: &mut Crossover, split: bool, input: &[f32], split_input: &mut [Vec<f32>], levels: &[f32]) {
+    let len = input.len();
+    if split {
+      crossover.process(input, split_input);
+    } else {
+      for band in split_input.iter_mut() {
+        band[..len].copy_from_slice(input);
+      }
+    }
+    for (band, level) in split_input.iter_mut().zip(levels) {
+      for sample in band[..len].iter_mut() {
+        *sample *= level;
+      }
+    }
+  }
+
+  /// Runs both halves over up to `MAX_CHUNK` samples into the wet buffers, with the frozen tail
+  /// faded in on top when there is one.
+  fn render(&mut self, input_l: &[f32], input_r: &[f32], wet_l: &mut [f32], wet_r: &mut [f32]) {
+    let len = input_l.len();
+    let frozen = self.freeze.is_active();
+    if frozen {
+      self.freeze.fill_ramp(len);
+    }
+    let (input_l, input_r) = if frozen && self.freeze.input == FreezeInput::Mute {
+      let ramp = &self.freeze.ramp;
+      for (i, f) in ramp.iter().enumerate() {
+        self.muted_l[i] = input_l[i] * (1. - f);
+        self.muted_r[i] = input_r[i] * (1. - f);
+      }
+      (&self.muted_l[..len], &self.muted_r[..len])
+    } else {
+      (input_l, input_r)
+    };
+
+    wet_l.iter_mut().for_each(|sample| *sample = 0.);
+    wet_r.iter_mut().for_each(|sample| *sample = 0.);
+    // with the linear phase crossover the band IRs do the splitting
+    let split = self.bands > 1 && self.crossover == CrossoverKind::LinkwitzRiley;
+    let (bands_l, bands_r) = (&mut self.bands_l[..self.bands], &mut self.bands_r[..self.bands]);
+    Self::split_bands(&mut self.crossover_l, split, input_l, bands_l, &self.band_levels);
+    Self::split_bands(&mut self.crossover_r, split, input_r, bands_r, &self.band_levels);
+    self.early.process(bands_l, bands_r, wet_l, wet_r);
+    self.late.process(bands_l, bands_r, wet_l, wet_r);
+
+    if frozen {
+      for ((l, r), f) in wet_l.iter_mut().zip(wet_r.iter_mut()).zip(&self.freeze.ramp) {
+        *l += self.freeze_l.next_sample() * f;
+        *r += self.freeze_r.next_sample() * f;
+      }
+    }
+  }
+}
+
+impl Engine for SpringIr {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.freeze.step = freeze_step(sample_rate);
+    self.early.set_sample_rate(sample_rate);
+    self.late.set_sample_rate(sample_rate);
+    self.make_room(sample_rate);
+    self.crossover_l.set_sample_rate(sample_rate);
+    self.crossover_r.set_sample_rate(sample_rate);
+  }
+
+  fn reset(&mut self) {
+    self.early.reset();
+    self.late.reset();
+    self.crossover_l.reset();
+    self.crossover_r.reset();
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    for start in (0..input_l.len()).step_by(MAX_CHUNK) {
+      let end = (start + MAX_CHUNK).min(input_l.len());
+      let (wet_l, wet_r) = (&mut output_l[start..end], &mut output_r[start..end]);
+      self.render(&input_l[start..end], &input_r[start..end], wet_l, wet_r);
+      for sample in wet_l.iter_mut().chain(wet_r.iter_mut()) {
+        *sample /= WET_DIVISOR;
+      }
+    }
+  }
+}
@@ -0,0 +1,207 @@
+use super::delay_line::DelayLine;
+use super::engine::Engine;
+use super::filter::OnePoleLowPass;
+
+/*
+Parametric spring (after Valimaki, Parker & Abel)
+  - each spring is a feedback loop: dispersion -> delay -> damping -> back to the input
+  - dispersion is a long cascade of "stretched" first order allpasses,
+      H(z) = (a + z^-K) / (1 + a z^-K)
+    which delay low frequencies more than high ones, giving the descending chirp every echo has.
+    the stretch K sets the transition frequency (fs / 2K) above which the spring barely passes
+    anything
+  - tension maps to a transition frequency, not to K, so the chirp sounds the same at any sample
+    rate. the allpass history is sized for the longest stretch at the current rate
+  - a short, unstretched cascade in parallel adds the fainter high frequency chirps
+  - several springs of slightly different lengths are panned across the stereo field
+*/
+
+const MAX_SPRINGS: usize = 4;
+const LOW_CHIRP_STAGES: usize = 60;
+const HIGH_CHIRP_STAGES: usize = 20;
+// transition frequency at the slackest and the tightest tension
+const MIN_TRANSITION_HZ: f32 = 2000.;
+const MAX_TRANSITION_HZ: f32 = 6000.;
+const MAX_LENGTH_MS: f32 = 200.;
+const HIGH_CHIRP_LEVEL: f32 = 0.25;
+// length ratio and pan (-1 left .. 1 right) for each spring
+const SPRING_SPREAD: [(f32, f32); MAX_SPRINGS] = [(1., -0.6), (1.13, 0.6), (0.89, -0.2), (1.27, 0.2)];
+
+// longest stretch the tension can ask for at this sample rate
+fn max_stretch(sample_rate: f32) -> usize {
+  ((sample_rate / (2. * MIN_TRANSITION_HZ)).round() as usize).max(1)
+}
+
+/// First order allpass with its unit delay stretched to K samples.
+struct StretchedAllpass {
+  x_history: Vec<f32>,
+  y_history: Vec<f32>,
+  index: usize,
+}
+
+impl StretchedAllpass {
+  // holds up to max_stretch samples of delay
+  fn new(max_stretch: usize) -> Self {
+    Self {
+      x_history: vec![0.; max_stretch + 1],
+      y_history: vec![0.; max_stretch + 1],
+      index: 0,
+    }
+  }
+
+  fn reset(&mut self) {
+    self.x_history.iter_mut().for_each(|x| *x = 0.);
+    self.y_history.iter_mut().for_each(|y| *y = 0.);
+    self.index = 0;
+  }
+
+  fn process(&mut self, input: f32, coef: f32, stretch: usize) -> f32 {
+    let len = self.x_history.len();
+    let delayed = (self.index + len - stretch) % len;
+    let output = coef * input + self.x_history[delayed] - coef * self.y_history[delayed];
+    self.x_history[self.index] = input;
+    self.y_history[self.index] = output;
+    self.index = (self.index + 1) % len;
+    output
+  }
+}
+
+struct Spring {
+  low_chirp: Vec<StretchedAllpass>,
+  high_chirp: Vec<StretchedAllpass>,
+  delay: DelayLine,
+  damping: OnePoleLowPass,
+  feedback: f32,
+  length_samples: f32,
+}
+
+impl Spring {
+  fn new(sample_rate: f32) -> Self {
+    Self {
+      low_chirp: (0..LOW_CHIRP_STAGES).map(|_| StretchedAllpass::new(max_stretch(sample_rate))).collect(),
+      high_chirp: (0..HIGH_CHIRP_STAGES).map(|_| StretchedAllpass::new(1)).collect(),
+      delay: DelayLine::new((MAX_LENGTH_MS * 2. * 0.001 * sample_rate) as usize),
+      damping: OnePoleLowPass::new(4000., sample_rate),
+      feedback: 0.,
+      length_samples: 1.,
+    }
+  }
+
+  fn reset(&mut self) {
+    for stage in self.low_chirp.iter_mut().chain(self.high_chirp.iter_mut()) {
+      stage.reset();
+    }
+    self.delay.clear();
+    self.damping.reset();
+  }
+
+  fn process(&mut self, input: f32, coef: f32, stretch: usize) -> f32 {
+    let returned = self.delay.read_linear(self.length_samples);
+    let mut low = input + returned * self.feedback;
+    for stage in self.low_chirp.iter_mut() {
+      low = stage.process(low, coef, stretch);
+    }
+    let mut high = low;
+    for stage in self.high_chirp.iter_mut() {
+      high = stage.process(high, -coef, 1);
+    }
+    self.delay.push(self.damping.process(low));
+    low + high * HIGH_CHIRP_LEVEL
+  }
+}
+
+/// Algorithmic spring tank with length, tension, damping and number of springs.
+pub struct SpringModel {
+  sample_rate: f32,
+  length_ms: f32,
+  tension: f32, // 0..1
+  damping: f32, // 0..1
+  spring_count: usize,
+  coef: f32,
+  stretch: usize,
+  springs: Vec<Spring>,
+}
+
+impl SpringModel {
+  pub fn new(sample_rate: f32) -> Self {
+    let mut model = Self {
+      sample_rate,
+      length_ms: 60.,
+      tension: 0.5,
+      damping: 0.5,
+      spring_count: 2,
+      coef: 0.,
+      stretch: 1,
+      springs: (0..MAX_SPRINGS).map(|_| Spring::new(sample_rate)).collect(),
+    };
+    model.update();
+    model
+  }
+
+  pub fn set_length_ms(&mut self, length_ms: f32) {
+    self.length_ms = length_ms.min(MAX_LENGTH_MS);
+    self.update();
+  }
+
+  pub fn set_tension(&mut self, tension: f32) {
+    self.tension = tension;
+    self.update();
+  }
+
+  pub fn set_damping(&mut self, damping: f32) {
+    self.damping = damping;
+    self.update();
+  }
+
+  pub fn set_spring_count(&mut self, spring_count: usize) {
+    self.spring_count = spring_count.clamp(1, MAX_SPRINGS);
+  }
+
+  fn update(&mut self) {
+    // tighter springs chirp more steeply and carry higher frequencies
+    let transition_hz = MIN_TRANSITION_HZ + (MAX_TRANSITION_HZ - MIN_TRANSITION_HZ) * self.tension.clamp(0., 1.);
+    self.stretch = ((self.sample_rate / (2. * transition_hz)).round() as usize).clamp(1, max_stretch(self.sample_rate));
+    self.coef = 0.5 + 0.25 * self.tension;
+
+    // damping shortens the decay and darkens each round trip
+    let decay_seconds = 4. - 3.7 * self.damping;
+    let cutoff = 8000. - 6500. * self.damping;
+    for (spring, (ratio, _)) in self.springs.iter_mut().zip(SPRING_SPREAD) {
+      let length_seconds = self.length_ms * ratio * 0.001;
+      spring.length_samples = (length_seconds * self.sample_rate).max(1.);
+      spring.feedback = 10f32.powf(-3. * length_seconds / decay_seconds);
+      spring.damping.set_cutoff(cutoff, self.sample_rate);
+    }
+  }
+}
+
+impl Engine for SpringModel {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    self.springs = (0..MAX_SPRINGS).map(|_| Spring::new(sample_rate)).collect();
+    self.update();
+  }
+
+  fn reset(&mut self) {
+    for spring in self.springs.iter_mut() {
+      spring.reset();
+    }
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    let level = 1. / (self.spring_count as f32).sqrt();
+    for i in 0..input_l.len() {
+      let input = 0.5 * (input_l[i] + input_r[i]);
+      let (mut l, mut r) = (0., 0.);
+      for (spring, (_, pan)) in self.springs.iter_mut().zip(SPRING_SPREAD).take(self.spring_count) {
+        let out = spring.process(input, self.coef, self.stretch);
+        // a lone spring sits in the middle
+        let pan = if self.spring_count == 1 { 0. } else { pan };
+        l += out * (0.5 * (1. - pan)).sqrt();
+        r += out * (0.5 * (1. + pan)).sqrt();
+      }
+      output_l[i] = l * level;
+      output_r[i] = r * level;
+    }
+  }
+}
@@ -0,0 +1,69 @@
+/// Note lengths that times can lock to, in quarter notes. The first entry (`None`) is free
+/// running. Must line up with `plugin_state::SYNC_OPTIONS`.
+const DIVISIONS: &[Option<f32>] = &[
+  None,
+  Some(4.),        // 1/1
+  Some(2.),        // 1/2
+  Some(3.),        // 1/2 dotted
+  Some(4. / 3.),   // 1/2 triplet
+  Some(1.),        // 1/4
+  Some(1.5),       // 1/4 dotted
+  Some(2. / 3.),   // 1/4 triplet
+  Some(0.5),       // 1/8
+  Some(0.75),      // 1/8 dotted
+  Some(1. / 3.),   // 1/8 triplet
+  Some(0.25),      // 1/16
+  Some(0.375),     // 1/16 dotted
+  Some(1. / 6.),   // 1/16 triplet
+  Some(0.125),     // 1/32
+];
+
+pub const DEFAULT_TEMPO: f32 = 120.;
+
+fn quarter_notes(division: usize) -> Option<f32> {
+  DIVISIONS.get(division).copied().flatten()
+}
+
+/// How long a note division lasts at `tempo`, `None` for free running. For times with no free
+/// setting in milliseconds to fall back on.
+pub fn note_ms(division: usize, tempo: f32) -> Option<f32> {
+  quarter_notes(division).map(|quarter_notes| quarter_notes * 60000. / tempo)
+}
+
+/// A time that is either set freely in milliseconds or locked to a note division of the host's
+/// tempo.
+#[derive(Clone, Copy)]
+pub struct SyncedTime {
+  pub ms: f32,
+  pub division: usize,
+}
+
+impl SyncedTime {
+  pub fn new(ms: f32) -> Self {
+    Self { ms, division: 0 }
+  }
+
+  pub fn resolve_ms(&self, tempo: f32) -> f32 {
+    note_ms(self.division, tempo).unwrap_or(self.ms)
+  }
+}
+
+/// A rate that is either set freely in Hz or cycles once per note division of the host's tempo.
+#[derive(Clone, Copy)]
+pub struct SyncedRate {
+  pub hz: f32,
+  pub division: usize,
+}
+
+impl SyncedRate {
+  pub fn new(hz: f32) -> Self {
+    Self { hz, division: 0 }
+  }
+
+  pub fn resolve_hz(&self, tempo: f32) -> f32 {
+    match quarter_notes(self.division) {
+      Some(quarter_notes) => tempo / (60. * quarter_notes),
+      None => self.hz,
+    }
+  }
+}
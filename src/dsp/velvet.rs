@@ -0,0 +1,337 @@
+use super::engine::Engine;
+use super::filter::OnePoleLowPass;
+use super::noise::Noise;
+
+/*
+Velvet noise reverb (after Valimaki, Holm-Rasmussen, Alary & Lehtonen)
+  - velvet noise is a sparse sequence of +1/-1 pulses, one per grid period at a random spot in
+    the period. above ~1000 pulses per second it sounds as smooth as white noise
+  - convolving with it is just adding up a handful of delayed input samples with the right
+    signs, no multiplies and no FFT, so a diffuse tail costs a fraction of the convolvers
+  - the sequence is cut into short segments. each segment's sum goes through a two band split,
+    with its own gain per band following the band's decay envelope, so highs die away faster
+    than lows
+  - the pulses thin out along the tail, the envelope has already made them quiet
+  - runs a block at a time: every pulse adds a whole stretch of past input in one go, which
+    keeps the memory access in order and lets the adds vectorize
+  - laying out the pulses allocates, so new settings are built into a `VelvetTaps` off the audio
+    thread, by the IR worker, and the engine crossfades over to them. freeing them doesn't belong
+    on the audio thread either, so taps it's done with are kept for the DSP to send back
+*/
+
+const MAX_DECAY_SECONDS: f32 = 5.;
+const SEGMENT_MS: f32 = 50.;
+const CROSSOVER: f32 = 2000.;
+// the last segments get at least this share of the starting density
+const MIN_DENSITY_SHARE: f32 = 0.25;
+// longest stretch processed in one go
+const MAX_CHUNK: usize = 4096;
+// how long a swap to new taps is crossfaded for
+const TAPS_FADE_SAMPLES: usize = 2048;
+// taps done with and waiting to be sent back, past this many they're dropped in place after all
+const MAX_RETIRED: usize = 4;
+
+/// Input history kept in one straight run, so any delayed block of it is a plain slice.
+struct History {
+  buffer: Vec<f32>,
+  max_delay: usize,
+  end: usize,
+}
+
+impl History {
+  fn new(max_delay: usize) -> Self {
+    Self {
+      buffer: vec![0.; max_delay + MAX_CHUNK],
+      max_delay,
+      end: max_delay,
+    }
+  }
+
+  fn clear(&mut self) {
+    self.buffer.iter_mut().for_each(|sample| *sample = 0.);
+    self.end = self.max_delay;
+  }
+
+  // block can be up to MAX_CHUNK long
+  fn push(&mut self, block: &[f32]) {
+    if self.end + block.len() > self.buffer.len() {
+      // only the last max_delay samples can still be read
+      self.buffer.copy_within(self.end - self.max_delay..self.end, 0);
+      self.end = self.max_delay;
+    }
+    self.buffer[self.end..self.end + block.len()].copy_from_slice(block);
+    self.end += block.len();
+  }
+
+  // the last `len` samples pushed, delayed
+  fn delayed(&self, delay: usize, len: usize) -> &[f32] {
+    let end = self.end - delay.min(self.max_delay);
+    &self.buffer[end - len..end]
+  }
+}
+
+// a run of pulses sharing a spot on the decay envelope
+#[derive(Clone)]
+struct Segment {
+  pulses: Vec<(usize, f32)>, // delay, sign
+  low_gain: f32,
+  high_gain: f32,
+  split: OnePoleLowPass,
+}
+
+impl Segment {
+  fn reset(&mut self) {
+    self.split.reset();
+  }
+
+  // adds the segment's share of the tail onto output
+  fn process(&mut self, history: &History, sum: &mut [f32], output: &mut [f32]) {
+    sum.iter_mut().for_each(|sample| *sample = 0.);
+    for (delay, sign) in self.pulses.iter() {
+      let delayed = history.delayed(*delay, sum.len());
+      if *sign > 0. {
+        sum.iter_mut().zip(delayed).for_each(|(sample, x)| *sample += x);
+      } else {
+        sum.iter_mut().zip(delayed).for_each(|(sample, x)| *sample -= x);
+      }
+    }
+    for (out, sample) in output.iter_mut().zip(sum.iter()) {
+      let low = self.split.process(*sample);
+      *out += low * self.low_gain + (sample - low) * self.high_gain;
+    }
+  }
+}
+
+/// The shape of a velvet tail.
+#[derive(Clone, Copy)]
+pub struct VelvetDesign {
+  pub decay_seconds: f32,
+  pub damping: f32, // 0..1, how much faster the highs decay
+  pub density: f32, // pulses per second, at the start of the tail
+}
+
+impl Default for VelvetDesign {
+  fn default() -> Self {
+    Self {
+      decay_seconds: 1.5,
+      damping: 0.5,
+      density: 1500.,
+    }
+  }
+}
+
+/// Both sides' pulses and gains, laid out for one design at one sample rate.
+#[derive(Clone)]
+pub struct VelvetTaps {
+  left: Vec<Segment>,
+  right: Vec<Segment>,
+}
+
+impl VelvetDesign {
+  /// Lays out the pulses. Allocates, so it's meant for use off the audio thread.
+  pub fn build(&self, sample_rate: f32) -> VelvetTaps {
+    let design = VelvetDesign {
+      decay_seconds: self.decay_seconds.clamp(0.05, MAX_DECAY_SECONDS),
+      damping: self.damping.clamp(0., 1.),
+      density: self.density.max(1.),
+    };
+    VelvetTaps {
+      left: design.build_side(1, sample_rate),
+      right: design.build_side(2, sample_rate),
+    }
+  }
+
+  fn build_side(&self, seed: u32, sample_rate: f32) -> Vec<Segment> {
+    let mut noise = Noise::new(seed);
+    let length = (self.decay_seconds * sample_rate) as usize;
+    let segment_length = ((SEGMENT_MS * 0.001 * sample_rate) as usize).max(1);
+    let high_decay = self.decay_seconds * (1. - 0.9 * self.damping);
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    while start < length {
+      let time = start as f32 / sample_rate;
+      let progress = start as f32 / length as f32;
+      let density = self.density * (1. - (1. - MIN_DENSITY_SHARE) * progress);
+      // one pulse per grid period, somewhere inside it
+      let grid = (sample_rate / density).max(1.);
+      let end = (start + segment_length).min(length);
+      let mut pulses = Vec::new();
+      let mut period_start = start as f32;
+      while period_start < end as f32 {
+        let delay = (period_start + noise.next_unipolar() * (grid - 1.)) as usize;
+        let sign = if noise.next_unipolar() < 0.5 { 1. } else { -1. };
+        if delay < end {
+          pulses.push((delay, sign));
+        }
+        period_start += grid;
+      }
+      // thinner stretches make up for it with louder pulses, so the envelope stays put
+      let density_gain = (self.density / density).sqrt();
+      segments.push(Segment {
+        pulses,
+        low_gain: 10f32.powf(-3. * time / self.decay_seconds) * density_gain,
+        high_gain: 10f32.powf(-3. * time / high_decay) * density_gain,
+        split: OnePoleLowPass::new(CROSSOVER, sample_rate),
+      });
+      start = end;
+    }
+
+    // an impulse comes out with the same energy however long and dense the tail is
+    let energy: f32 = segments.iter().map(|segment| segment.pulses.len() as f32 * segment.low_gain.powi(2)).sum();
+    let normalize = if energy > 0. { energy.sqrt().recip() } else { 0. };
+    for segment in segments.iter_mut() {
+      segment.low_gain *= normalize;
+      segment.high_gain *= normalize;
+    }
+    segments
+  }
+}
+
+impl VelvetTaps {
+  fn reset(&mut self) {
+    for segment in self.left.iter_mut().chain(self.right.iter_mut()) {
+      segment.reset();
+    }
+  }
+
+  // writes this tail's share of the chunk already in `history` into the outputs
+  fn process(&mut self, history: &History, sum: &mut [f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    for (segments, output) in [(&mut self.left, output_l), (&mut self.right, output_r)] {
+      output.iter_mut().for_each(|sample| *sample = 0.);
+      for segment in segments.iter_mut() {
+        segment.process(history, sum, output);
+      }
+    }
+  }
+}
+
+// the outgoing taps while a swap is being crossfaded
+struct TapsFade {
+  taps: VelvetTaps,
+  remaining: usize,
+}
+
+/// Sparse, time domain convolution with shaped velvet noise. A cheap, smooth, diffuse tail.
+pub struct Velvet {
+  // kept up to date by the setters, for rebuilding at a new sample rate
+  design: VelvetDesign,
+  history: History,
+  taps: VelvetTaps,
+  fade: Option<TapsFade>,
+  pending: Option<VelvetTaps>, // waiting for the current fade to finish
+  retired: Vec<VelvetTaps>, // done with, for `take_retired`
+  // scratch for each segment's sum, and the outgoing taps' output while fading
+  sum: Vec<f32>,
+  mono: Vec<f32>,
+  fade_l: Vec<f32>,
+  fade_r: Vec<f32>,
+}
+
+impl Velvet {
+  pub fn new(sample_rate: f32) -> Self {
+    let design = VelvetDesign::default();
+    Self {
+      design,
+      history: History::new((MAX_DECAY_SECONDS * sample_rate) as usize),
+      taps: design.build(sample_rate),
+      fade: None,
+      pending: None,
+      retired: Vec::with_capacity(MAX_RETIRED),
+      sum: vec![0.; MAX_CHUNK],
+      mono: vec![0.; MAX_CHUNK],
+      fade_l: vec![0.; MAX_CHUNK],
+      fade_r: vec![0.; MAX_CHUNK],
+    }
+  }
+
+  /// The setters only note the new shape. The taps to match come separately, from the IR worker.
+  pub fn set_decay_seconds(&mut self, seconds: f32) {
+    self.design.decay_seconds = seconds;
+  }
+
+  pub fn set_damping(&mut self, damping: f32) {
+    self.design.damping = damping;
+  }
+
+  pub fn set_density(&mut self, density: f32) {
+    self.design.density = density;
+  }
+
+  /// Crossfades to new taps. Only one fade at a time, the latest taps wait their turn.
+  pub fn set_taps(&mut self, taps: VelvetTaps) {
+    if self.fade.is_some() {
+      if let Some(skipped) = self.pending.replace(taps) {
+        self.retire(skipped);
+      }
+      return;
+    }
+    let outgoing = std::mem::replace(&mut self.taps, taps);
+    self.fade = Some(TapsFade { taps: outgoing, remaining: TAPS_FADE_SAMPLES });
+  }
+
+  /// Taps the engine is done with, one at a time, to be freed off the audio thread.
+  pub fn take_retired(&mut self) -> Option<VelvetTaps> {
+    self.retired.pop()
+  }
+
+  fn retire(&mut self, taps: VelvetTaps) {
+    if self.retired.len() < MAX_RETIRED {
+      self.retired.push(taps);
+    }
+  }
+}
+
+impl Engine for Velvet {
+  fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.history = History::new((MAX_DECAY_SECONDS * sample_rate) as usize);
+    self.taps = self.design.build(sample_rate);
+    self.fade = None;
+    self.pending = None;
+  }
+
+  fn reset(&mut self) {
+    self.history.clear();
+    if let Some(taps) = self.pending.take() {
+      let outgoing = std::mem::replace(&mut self.taps, taps);
+      self.retire(outgoing);
+    }
+    self.taps.reset();
+    if let Some(fade) = self.fade.take() {
+      self.retire(fade.taps);
+    }
+  }
+
+  fn process(&mut self, input_l: &[f32], input_r: &[f32], output_l: &mut [f32], output_r: &mut [f32]) {
+    for start in (0..input_l.len()).step_by(MAX_CHUNK) {
+      let end = (start + MAX_CHUNK).min(input_l.len());
+      let len = end - start;
+      for (mono, (l, r)) in self.mono.iter_mut().zip(input_l[start..end].iter().zip(&input_r[start..end])) {
+        *mono = 0.5 * (l + r);
+      }
+      self.history.push(&self.mono[..len]);
+
+      let sum = &mut self.sum[..len];
+      let (output_l, output_r) = (&mut output_l[start..end], &mut output_r[start..end]);
+      self.taps.process(&self.history, sum, output_l, output_r);
+      if let Some(fade) = &mut self.fade {
+        let (fade_l, fade_r) = (&mut self.fade_l[..len], &mut self.fade_r[..len]);
+        fade.taps.process(&self.history, sum, fade_l, fade_r);
+        for i in 0..len {
+          let old_gain = fade.remaining.saturating_sub(i) as f32 / TAPS_FADE_SAMPLES as f32;
+          output_l[i] = output_l[i] * (1. - old_gain) + fade_l[i] * old_gain;
+          output_r[i] = output_r[i] * (1. - old_gain) + fade_r[i] * old_gain;
+        }
+        fade.remaining = fade.remaining.saturating_sub(len);
+        if fade.remaining == 0 {
+          if let Some(fade) = self.fade.take() {
+            self.retire(fade.taps);
+          }
+          if let Some(taps) = self.pending.take() {
+            self.set_taps(taps);
+          }
+        }
+      }
+    }
+  }
+}
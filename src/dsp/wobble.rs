@@ -0,0 +1,137 @@
+use std::f32::consts::TAU;
+use super::fractional_delay::FractionalDelay;
+use super::noise::Noise;
+use super::smoother::{Ramp, Smoother};
+
+/*
+Wobble
+  - chorus style time modulation of the wet signal, the way a tank that gets nudged swings its
+    springs about
+  - the modulator blends a sine with a smoothly interpolated random walk, picking a new random
+    point every cycle, so "random" goes from regular vibrato to an unsteady drift
+  - the right side runs a quarter cycle apart, and with its own random walk
+  - depth glides, so turning the wobble on and off doesn't jump the delay. the glide is linear,
+    an exponential one snaps its last bit onto the target, which at full swing is a jump of over
+    half a sample
+  - the read point needs a sample either side, so even at no depth the delay is one sample. once
+    it's off and the depth has glided out, the signal goes straight through instead, only keeping
+    the delay lines fed so switching back on doesn't read stale samples
+*/
+
+const MAX_DEPTH_MS: f32 = 5.;
+const DEPTH_GLIDE_MS: f32 = 100.;
+
+/// Modulated delay on the wet path.
+pub struct Wobble {
+  sample_rate: f32,
+  enabled: bool,
+  rate: f32, // Hz
+  depth_ms: f32,
+  randomness: f32, // 0..1
+  depth_samples: Smoother,
+  phase: f32,
+  noise: Noise,
+  // random walk points per side, the last one and the one being headed to
+  random_from: [f32; 2],
+  random_to: [f32; 2],
+  delays: [FractionalDelay; 2],
+}
+
+impl Wobble {
+  pub fn new(sample_rate: f32) -> Self {
+    let mut wobble = Self {
+      sample_rate,
+      enabled: false,
+      rate: 1.5,
+      depth_ms: 1.,
+      randomness: 0.3,
+      depth_samples: Smoother::new(Ramp::Linear, 0., sample_rate),
+      phase: 0.,
+      noise: Noise::new(7),
+      random_from: [0.; 2],
+      random_to: [0.; 2],
+      delays: [FractionalDelay::new(0), FractionalDelay::new(0)],
+    };
+    wobble.set_sample_rate(sample_rate);
+    wobble
+  }
+
+  pub fn set_sample_rate(&mut self, sample_rate: f32) {
+    self.sample_rate = sample_rate;
+    // the delay swings between none and twice the depth
+    let max_delay = (2. * MAX_DEPTH_MS * 0.001 * sample_rate) as usize + 2;
+    self.delays = [FractionalDelay::new(max_delay), FractionalDelay::new(max_delay)];
+    self.depth_samples.set_sample_rate(sample_rate);
+    self.depth_samples.set_ramp_ms(DEPTH_GLIDE_MS);
+    self.update_depth();
+  }
+
+  pub fn set_enabled(&mut self, enabled: bool) {
+    self.enabled = enabled;
+    self.update_depth();
+  }
+
+  pub fn set_rate_hz(&mut self, rate: f32) {
+    self.rate = rate;
+  }
+
+  pub fn set_depth_ms(&mut self, depth_ms: f32) {
+    self.depth_ms = depth_ms.clamp(0., MAX_DEPTH_MS);
+    self.update_depth();
+  }
+
+  fn update_depth(&mut self) {
+    let target = if self.enabled { self.depth_ms * 0.001 * self.sample_rate } else { 0. };
+    if target != self.depth_samples.target() {
+      self.depth_samples.set_target(target);
+    }
+  }
+
+  pub fn set_randomness(&mut self, randomness: f32) {
+    self.randomness = randomness.clamp(0., 1.);
+  }
+
+  // where a side's random walk is. both sides step on to new points together
+  fn random_walk(&self, side: usize) -> f32 {
+    // raised cosine between the points, so the drift never changes direction abruptly
+    let blend = 0.5 - 0.5 * (std::f32::consts::PI * self.phase).cos();
+    self.random_from[side] + (self.random_to[side] - self.random_from[side]) * blend
+  }
+
+  fn bypassed(&self) -> bool {
+    !self.enabled && !self.depth_samples.is_smoothing() && self.depth_samples.value() == 0.
+  }
+
+  pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) {
+    if self.bypassed() {
+      for (side, samples) in [&*left, &*right].into_iter().enumerate() {
+        for &sample in samples {
+          self.delays[side].push(sample);
+        }
+      }
+      return;
+    }
+
+    let step = self.rate / self.sample_rate;
+    for i in 0..left.len() {
+      let depth = self.depth_samples.next_value();
+
+      for (side, sample) in [&mut left[i], &mut right[i]].into_iter().enumerate() {
+        let phase = (self.phase + 0.25 * side as f32).fract();
+        let sine = (TAU * phase).sin();
+        let modulator = sine * (1. - self.randomness) + self.random_walk(side) * self.randomness;
+        let delay = 1. + depth * (1. + modulator);
+        *sample = self.delays[side].process(*sample, delay);
+      }
+
+      self.phase += step;
+      if self.phase >= 1. {
+        self.phase -= 1.;
+        for side in 0..2 {
+          self.random_from[side] = self.random_to[side];
+          self.random_to[side] = 2. * self.noise.next_unipolar() - 1.;
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,521 @@
+//! Impulse responses for the convolvers are designed and partitioned on a worker thread, since
+//! rendering, reshaping and transforming a couple of seconds of audio is far too slow for the
+//! audio processing thread. The worker listens for the parameters that shape the IR, and hands
+//! finished IRs to the DSP through the same message channel as every other state update, where
+//! the convolvers crossfade to them.
+//!
+//! With the multiband split, each band's slot is rendered on its own: the designed spring, or the
+//! plate or room engine at its defaults, recorded off an impulse. Every slot goes through the same
+//! decay, width, trim and reverse, and the linear phase crossover is cut into the band IRs here.
+//!
+//! The velvet engine's taps are laid out here too, since placing thousands of pulses allocates, and
+//! so are the physical spring engine's meshes. The host's tempo, which the velvet decay can lock
+//! to, isn't sent: the audio thread only stores it, and the worker checks it between messages.
+//!
+//! Freeing what the DSP swapped out is as much a job for this thread as allocating it was. The
+//! worker hands the DSP a bounded channel back when it starts, and drops whatever comes over it.
+
+use std::f32::consts::PI;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::{channel, sync_channel, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::dsp::convolution::ConvolverIr;
+use crate::dsp::decorrelate::decorrelate;
+use crate::dsp::engine::Engine;
+use crate::dsp::fdn::Fdn;
+use crate::dsp::hybrid::{split_impulse_response, EarlyTaps};
+use crate::dsp::ir_decay::{reshape_band_decay, BandDecay, MAX_DECAY_SCALE, MIN_DECAY_SCALE};
+use crate::dsp::ir_trim::{trim_impulse_response, FadeShape, Trim};
+use crate::dsp::ms_to_samples;
+use crate::dsp::multiband::{linear_phase_band, CrossoverKind, MAX_BANDS};
+use crate::dsp::physical_spring::{render_impulse_response, SpringPhysics};
+use crate::dsp::plate::Plate;
+use crate::dsp::reverse::{reverse_impulse_response, ReverseMode};
+use crate::dsp::spring_impulse_response::SPRING_IMPULSE_RESPONSE;
+use crate::dsp::spring_ir::{split_early_late, BandIrs};
+use crate::dsp::tempo::{SyncedTime, DEFAULT_TEMPO};
+use crate::dsp::velvet::{VelvetDesign, VelvetTaps};
+use crate::plugin_state::{Parameter, StateUpdate};
+
+/// Messages from the parameter bank to the worker.
+pub enum IrMessage {
+    SetParameter(Parameter, f32),
+    SetSampleRate(f32),
+}
+
+/// What the DSP is done with, sent back to be freed here rather than on the audio thread.
+pub enum Retired {
+    Ir(ConvolverIr),
+    EarlyTaps(EarlyTaps),
+    VelvetTaps(VelvetTaps),
+}
+
+/// What a message leaves needing a re-render. Ordered, since a new IR also needs a new tail.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Rerender {
+    Nothing,
+    HybridTail,
+    Everything,
+}
+
+/// Where the IR comes from. Must line up with `plugin_state::IR_SOURCE_OPTIONS`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum IrSource {
+    Recording,
+    Physical,
+}
+
+/// A rendered IR, as (left, right).
+type StereoIr = (Vec<f32>, Vec<f32>);
+
+/// What a band's IR slot plays. Must line up with `plugin_state::BAND_IR_OPTIONS`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum IrSlot {
+    Spring,
+    Plate,
+    Room,
+}
+
+impl IrSlot {
+    const ALL: [IrSlot; 3] = [IrSlot::Spring, IrSlot::Plate, IrSlot::Room];
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => IrSlot::Spring,
+            1 => IrSlot::Plate,
+            _ => IrSlot::Room,
+        }
+    }
+}
+
+// how much of the plate and room engines' ringing is kept as an IR, the last of it faded out
+const ENGINE_IR_SECONDS: f32 = 3.;
+const ENGINE_IR_FADE_MS: f32 = 500.;
+const ENGINE_IR_BLOCK: usize = 512;
+// how often the worker looks for a new host tempo, when no message wakes it first
+const TEMPO_POLL: Duration = Duration::from_millis(50);
+// how much the DSP can send back between polls. bounded, so sending never allocates
+const RETIRED_QUEUE: usize = 64;
+
+/// Everything that goes into rendering the IR.
+struct IrDesign {
+    source: IrSource,
+    physics: SpringPhysics,
+    hybrid_crossover_ms: f32,
+    decorrelation: f32, // 0..1
+    decay_scale: f32, // 1 keeps the IR's own decay
+    // on top of the overall scale
+    band_decay: BandDecay,
+    trim: Trim,
+    // only the spring convolvers play it backwards, the hybrid tail stays the right way round
+    reverse: ReverseMode,
+    reverse_length_ms: f32,
+    // where the early and late convolvers take over from each other
+    split_ms: f32,
+    bands: usize,
+    crossover: CrossoverKind,
+    crossovers: [f32; MAX_BANDS - 1],
+    slots: [IrSlot; MAX_BANDS],
+    // nothing to do with the IR, just also too heavy for the audio thread. the decay comes from
+    // `velvet_decay` instead, which may lock it to the tempo. the realtime meshes are built from
+    // `physics` too
+    velvet: VelvetDesign,
+    velvet_decay: SyncedTime,
+    tempo: f32,
+    sample_rate: f32,
+}
+
+impl IrDesign {
+    fn new() -> Self {
+        Self {
+            source: IrSource::Recording,
+            physics: SpringPhysics::default(),
+            hybrid_crossover_ms: Parameter::HybridCrossover.info().default,
+            decorrelation: Parameter::IrWidth.info().default / 100.,
+            decay_scale: Parameter::IrDecay.info().default / 100.,
+            band_decay: BandDecay::default(),
+            trim: Trim {
+                start: Parameter::IrStart.info().default,
+                length: Parameter::IrLength.info().default,
+                fade: Parameter::IrFade.info().default,
+                fade_shape: FadeShape::from_index(Parameter::IrFadeShape.info().default as usize),
+            },
+            reverse: ReverseMode::Off,
+            reverse_length_ms: Parameter::ReverseLength.info().default,
+            split_ms: Parameter::SplitTime.info().default,
+            bands: 1,
+            crossover: CrossoverKind::LinkwitzRiley,
+            crossovers: [
+                Parameter::BandCross1.info().default,
+                Parameter::BandCross2.info().default,
+                Parameter::BandCross3.info().default,
+            ],
+            slots: [IrSlot::Spring; MAX_BANDS],
+            velvet: VelvetDesign {
+                decay_seconds: Parameter::VelvetDecay.info().default,
+                damping: Parameter::VelvetDamping.info().default / 100.,
+                density: Parameter::VelvetDensity.info().default,
+            },
+            velvet_decay: SyncedTime::new(Parameter::VelvetDecay.info().default * 1000.),
+            tempo: DEFAULT_TEMPO,
+            sample_rate: 44100.,
+        }
+    }
+
+    /// Applies a message, returning what it leaves out of date.
+    fn apply(&mut self, message: IrMessage) -> Rerender {
+        let physical = if self.source == IrSource::Physical { Rerender::Everything } else { Rerender::Nothing };
+        let reversed = if self.reverse != ReverseMode::Off { Rerender::Everything } else { Rerender::Nothing };
+        let multiband = if self.bands > 1 { Rerender::Everything } else { Rerender::Nothing };
+        let linear_phase = if self.crossover == CrossoverKind::LinearPhase { multiband } else { Rerender::Nothing };
+        match message {
+            IrMessage::SetSampleRate(sample_rate) => {
+                self.sample_rate = sample_rate;
+                // the split is in samples, and so are the crossover, a reshaped decay, the trim
+                // and the reversed length
+                Rerender::Everything
+            }
+            IrMessage::SetParameter(param, value) => match param {
+                Parameter::IrSource => {
+                    self.source = if value as usize == 0 { IrSource::Recording } else { IrSource::Physical };
+                    Rerender::Everything
+                }
+                Parameter::PhysWireRadius => {
+                    self.physics.wire_radius_mm = value;
+                    physical
+                }
+                Parameter::PhysCoilRadius => {
+                    self.physics.coil_radius_mm = value;
+                    physical
+                }
+                Parameter::PhysLength => {
+                    self.physics.length_cm = value;
+                    physical
+                }
+                Parameter::PhysDecay => {
+                    self.physics.decay_seconds = value;
+                    physical
+                }
+                Parameter::IrWidth => {
+                    self.decorrelation = value / 100.;
+                    Rerender::Everything
+                }
+                Parameter::IrDecay => {
+                    self.decay_scale = value / 100.;
+                    Rerender::Everything
+                }
+                Parameter::IrDecayLow | Parameter::IrDecayMid | Parameter::IrDecayHigh => {
+                    let band = match param {
+                        Parameter::IrDecayLow => 0,
+                        Parameter::IrDecayMid => 1,
+                        _ => 2,
+                    };
+                    self.band_decay.scales[band] = value / 100.;
+                    Rerender::Everything
+                }
+                Parameter::IrDecayLowCross => {
+                    self.band_decay.crossovers[0] = value;
+                    Rerender::Everything
+                }
+                Parameter::IrDecayHighCross => {
+                    self.band_decay.crossovers[1] = value;
+                    Rerender::Everything
+                }
+                Parameter::IrStart => {
+                    self.trim.start = value;
+                    Rerender::Everything
+                }
+                Parameter::IrLength => {
+                    self.trim.length = value;
+                    Rerender::Everything
+                }
+                Parameter::IrFade => {
+                    self.trim.fade = value;
+                    Rerender::Everything
+                }
+                Parameter::IrFadeShape => {
+                    self.trim.fade_shape = FadeShape::from_index(value as usize);
+                    Rerender::Everything
+                }
+                Parameter::Reverse => {
+                    self.reverse = ReverseMode::from_index(value as usize);
+                    Rerender::Everything
+                }
+                Parameter::ReverseLength => {
+                    self.reverse_length_ms = value;
+                    reversed
+                }
+                Parameter::SplitTime => {
+                    self.split_ms = value;
+                    Rerender::Everything
+                }
+                Parameter::BandCount => {
+                    self.bands = value as usize + 1;
+                    Rerender::Everything
+                }
+                Parameter::BandCrossover => {
+                    self.crossover = CrossoverKind::from_index(value as usize);
+                    multiband
+                }
+                Parameter::BandCross1 | Parameter::BandCross2 | Parameter::BandCross3 => {
+                    let index = match param {
+                        Parameter::BandCross1 => 0,
+                        Parameter::BandCross2 => 1,
+                        _ => 2,
+                    };
+                    self.crossovers[index] = value;
+                    linear_phase
+                }
+                Parameter::BandIr1 | Parameter::BandIr2 | Parameter::BandIr3 | Parameter::BandIr4 => {
+                    let band = match param {
+                        Parameter::BandIr1 => 0,
+                        Parameter::BandIr2 => 1,
+                        Parameter::BandIr3 => 2,
+                        _ => 3,
+                    };
+                    self.slots[band] = IrSlot::from_index(value as usize);
+                    if band < self.bands { Rerender::Everything } else { Rerender::Nothing }
+                }
+                Parameter::HybridCrossover => {
+                    self.hybrid_crossover_ms = value;
+                    Rerender::HybridTail
+                }
+                // picked up by `moves_velvet`
+                Parameter::VelvetDecay => {
+                    self.velvet_decay.ms = value * 1000.;
+                    Rerender::Nothing
+                }
+                Parameter::VelvetDecaySync => {
+                    self.velvet_decay.division = value as usize;
+                    Rerender::Nothing
+                }
+                Parameter::VelvetDamping => {
+                    self.velvet.damping = value / 100.;
+                    Rerender::Nothing
+                }
+                Parameter::VelvetDensity => {
+                    self.velvet.density = value;
+                    Rerender::Nothing
+                }
+                _ => Rerender::Nothing,
+            },
+        }
+    }
+
+    /// Whether a message leaves the velvet taps needing laying out again.
+    fn moves_velvet(&self, message: &IrMessage) -> bool {
+        match message {
+            IrMessage::SetSampleRate(_) => true,
+            IrMessage::SetParameter(param, _) => matches!(
+                param,
+                Parameter::VelvetDecay | Parameter::VelvetDecaySync | Parameter::VelvetDamping | Parameter::VelvetDensity
+            ),
+        }
+    }
+
+    /// Whether a message leaves the physical spring engine's meshes needing building again.
+    fn moves_meshes(&self, message: &IrMessage) -> bool {
+        match message {
+            IrMessage::SetSampleRate(_) => true,
+            IrMessage::SetParameter(param, _) => matches!(
+                param,
+                Parameter::PhysWireRadius | Parameter::PhysCoilRadius | Parameter::PhysLength | Parameter::PhysDecay
+            ),
+        }
+    }
+
+    /// Takes the host's latest tempo, returning whether it leaves the velvet taps needing laying
+    /// out again. Only the velvet taps follow the tempo.
+    fn follow_tempo(&mut self, tempo: f32) -> bool {
+        let moved = tempo != self.tempo;
+        self.tempo = tempo;
+        moved && self.velvet_decay.division != 0
+    }
+
+    /// The velvet tail to lay out, with its decay locked to the tempo if it's synced. Held to the
+    /// engine's longest decay even then, see `VelvetDesign::build`.
+    fn velvet(&self) -> VelvetDesign {
+        VelvetDesign {
+            decay_seconds: self.velvet_decay.resolve_ms(self.tempo) * 0.001,
+            ..self.velvet
+        }
+    }
+
+    /// The mono IR a slot starts out from, at the level of the original recording.
+    fn source(&self, slot: IrSlot) -> Vec<f32> {
+        let mut rendered = match (slot, self.source) {
+            (IrSlot::Spring, IrSource::Recording) => return SPRING_IMPULSE_RESPONSE.to_vec(),
+            (IrSlot::Spring, IrSource::Physical) => {
+                let seconds = self.physics.decay_seconds.clamp(0.5, 4.);
+                render_impulse_response(&self.physics, self.sample_rate, seconds)
+            }
+            (IrSlot::Plate, _) => record_engine(&mut Plate::new(self.sample_rate), self.sample_rate),
+            (IrSlot::Room, _) => record_engine(&mut Fdn::new(self.sample_rate), self.sample_rate),
+        };
+        match_energy(&mut rendered, SPRING_IMPULSE_RESPONSE);
+        rendered
+    }
+
+    /// Renders a slot's stereo IR.
+    fn render(&self, slot: IrSlot) -> StereoIr {
+        let impulse_response = self.source(slot);
+        let bands = BandDecay {
+            // the overall and band decays each go from a quarter to four times, but together
+            // they still only reach as far as one of them can
+            scales: self
+                .band_decay
+                .scales
+                .map(|scale| (scale * self.decay_scale).clamp(MIN_DECAY_SCALE, MAX_DECAY_SCALE)),
+            ..self.band_decay
+        };
+        let impulse_response = reshape_band_decay(&impulse_response, &bands, self.sample_rate);
+        // each side gets its own randomization, so they drift apart from each other. trimmed
+        // after, so the decorrelation can't smear the fade
+        let side = |seed| {
+            let decorrelated = decorrelate(&impulse_response, self.decorrelation, seed);
+            trim_impulse_response(&decorrelated, &self.trim, self.sample_rate)
+        };
+        (side(1), side(2))
+    }
+
+    /// Every band's IR, split and ready for the convolvers. `spring` is the spring slot's render,
+    /// which is already done for the hybrid tail.
+    fn render_bands(&self, spring: &StereoIr) -> BandIrs {
+        // only the linear phase crossover needs cutting into the IRs, and only with bands to cut
+        let linear_phase = self.bands > 1 && self.crossover == CrossoverKind::LinearPhase;
+        let crossovers = &self.crossovers[..self.bands - 1];
+        let mut rendered: Vec<(IrSlot, StereoIr)> = vec![(IrSlot::Spring, spring.clone())];
+        // a slot shared by several bands is only rendered once
+        for slot in IrSlot::ALL {
+            if slot != IrSlot::Spring && self.slots[..self.bands].contains(&slot) {
+                rendered.push((slot, self.render(slot)));
+            }
+        }
+        let bands = (0..self.bands)
+            .map(|band| {
+                let (_, (left, right)) = rendered.iter().find(|(slot, _)| *slot == self.slots[band]).unwrap();
+                let side = |impulse_response: &[f32]| {
+                    let played = self.playback(impulse_response);
+                    if linear_phase {
+                        linear_phase_band(&played, band, crossovers, self.sample_rate)
+                    } else {
+                        played
+                    }
+                };
+                split_early_late(&side(left), &side(right), self.split_ms, self.sample_rate)
+            })
+            .collect();
+        BandIrs { bands, crossover: self.crossover }
+    }
+
+    /// One side of the rendered IR, the way the spring convolvers play it.
+    fn playback(&self, impulse_response: &[f32]) -> Vec<f32> {
+        match self.reverse {
+            ReverseMode::Off => impulse_response.to_vec(),
+            _ => reverse_impulse_response(impulse_response, self.reverse_length_ms, self.sample_rate),
+        }
+    }
+}
+
+/// Scales `signal` to carry the same energy as `reference`, so every IR plays at the level of the
+/// original recording.
+fn match_energy(signal: &mut [f32], reference: &[f32]) {
+    let energy = |s: &[f32]| s.iter().map(|x| x * x).sum::<f32>();
+    let signal_energy = energy(signal);
+    if signal_energy > 0. {
+        let gain = (energy(reference) / signal_energy).sqrt();
+        for sample in signal.iter_mut() {
+            *sample *= gain;
+        }
+    }
+}
+
+/// Records the left output of an engine, at its default settings, ringing out from an impulse.
+fn record_engine(engine: &mut dyn Engine, sample_rate: f32) -> Vec<f32> {
+    let length = (ENGINE_IR_SECONDS * sample_rate) as usize;
+    let mut recording = vec![0.; length];
+    let mut input = vec![0.; ENGINE_IR_BLOCK];
+    input[0] = 1.;
+    let mut discard = vec![0.; ENGINE_IR_BLOCK];
+    for block in recording.chunks_mut(ENGINE_IR_BLOCK) {
+        let len = block.len();
+        engine.process(&input[..len], &input[..len], block, &mut discard[..len]);
+        input[0] = 0.;
+    }
+    // the engines ring on past the end, so the cut is faded out
+    let fade = ms_to_samples(ENGINE_IR_FADE_MS, sample_rate).min(length);
+    for (i, sample) in recording[length - fade..].iter_mut().enumerate() {
+        *sample *= 0.5 + 0.5 * (PI * (i + 1) as f32 / fade as f32).cos();
+    }
+    recording
+}
+
+/// Starts the worker thread. It runs until the returned `Sender` is dropped.
+pub fn spawn(to_dsp: Sender<StateUpdate>, tempo: Arc<AtomicU32>) -> Sender<IrMessage> {
+    let (to_worker, messages) = channel();
+    let (return_path, retired) = sync_channel(RETIRED_QUEUE);
+    let _ = to_dsp.send(StateUpdate::SetReturnPath(return_path));
+    thread::spawn(move || run(messages, retired, to_dsp, tempo));
+    to_worker
+}
+
+/// `tempo` is the host's tempo as `f32` bits, kept up to date by the audio thread.
+fn run(messages: Receiver<IrMessage>, retired: Receiver<Retired>, to_dsp: Sender<StateUpdate>, tempo: Arc<AtomicU32>) {
+    let mut design = IrDesign::new();
+    let mut impulse_response = (Vec::new(), Vec::new());
+    // the DSP starts out on the plain recording, which the default design doesn't match
+    let mut rerender = Rerender::Everything;
+    // the DSP starts out with taps and meshes for the default design
+    let mut velvet_stale = false;
+    let mut meshes_stale = false;
+    loop {
+        // dropping it is all there is to freeing it
+        retired.try_iter().for_each(drop);
+        // only the latest design matters, skip renders that would be immediately replaced
+        while let Ok(message) = messages.try_recv() {
+            velvet_stale |= design.moves_velvet(&message);
+            meshes_stale |= design.moves_meshes(&message);
+            rerender = rerender.max(design.apply(message));
+        }
+        velvet_stale |= design.follow_tempo(f32::from_bits(tempo.load(Ordering::Relaxed)));
+        let mut updates = Vec::new();
+        if rerender == Rerender::Everything {
+            impulse_response = design.render(IrSlot::Spring);
+            let bands = design.render_bands(&impulse_response);
+            updates.push(StateUpdate::SetImpulseResponse(Box::new(bands)));
+        }
+        if rerender >= Rerender::HybridTail {
+            let (left, right) = &impulse_response;
+            let tail = split_impulse_response(left, right, design.hybrid_crossover_ms, design.sample_rate);
+            updates.push(StateUpdate::SwapHybridTail(Box::new(tail)));
+        }
+        if velvet_stale {
+            updates.push(StateUpdate::SwapVelvetTaps(Box::new(design.velvet().build(design.sample_rate))));
+        }
+        if meshes_stale {
+            let meshes = design.physics.build_realtime(design.sample_rate);
+            updates.push(StateUpdate::SwapSpringMeshes(Box::new(meshes)));
+        }
+        for update in updates {
+            if to_dsp.send(update).is_err() {
+                return;
+            }
+        }
+
+        // woken now and then even without messages, to catch up with the tempo
+        velvet_stale = false;
+        meshes_stale = false;
+        rerender = match messages.recv_timeout(TEMPO_POLL) {
+            Ok(message) => {
+                velvet_stale = design.moves_velvet(&message);
+                meshes_stale = design.moves_meshes(&message);
+                design.apply(message)
+            }
+            Err(RecvTimeoutError::Timeout) => Rerender::Nothing,
+            Err(RecvTimeoutError::Disconnected) => return,
+        };
+    }
+}
@@ -1,19 +1,27 @@
 use std::sync::{mpsc::channel, Arc};
 
 use vst::{
-    api::Supported,
+    api::{Events, Supported, TimeInfoFlags},
     buffer::AudioBuffer,
+    channels::{ChannelInfo, SpeakerArrangementType, StereoChannel, StereoConfig},
+    event::Event,
+    host::Host,
     plugin::{CanDo, HostCallback, Info, Plugin, PluginParameters},
 };
 
-mod dsp;
+pub mod dsp;
 use dsp::PluginDsp;
 
-mod plugin_state;
-use plugin_state::PluginState;
+mod ir_worker;
+
+pub mod plugin_state;
+use plugin_state::{Meters, Parameter, PluginState};
 
 /// Top level wrapper that exposes a full `vst::Plugin` implementation.
 struct ReverbVst {
+    /// Handle to the host, if there is one. Used to follow the host's tempo.
+    host: Option<HostCallback>,
+
     /// The `PluginDsp` handles all of the plugin's audio processing, and is only accessed from the
     /// audio processing thread.
     dsp: PluginDsp,
@@ -29,14 +37,33 @@ impl ReverbVst {
     fn new_maybe_host(maybe_host: Option<HostCallback>) -> Self {
         let host = maybe_host.unwrap_or_default();
         let (to_dsp, dsp_recv) = channel();
-        let state_handle = Arc::new(PluginState::new(host, to_dsp));
-        let dsp = PluginDsp::new(dsp_recv);
+        // the DSP writes the meters, the host reads them through the parameters
+        let meters = Arc::new(Meters::new());
+        let state_handle = Arc::new(PluginState::new(host, to_dsp, Arc::clone(&meters)));
+        let dsp = PluginDsp::new(dsp_recv, meters);
 
         Self {
+            host: maybe_host,
             dsp,
             state_handle,
         }
     }
+
+    /// Passes the host's current tempo on to the DSP and the IR worker, when the host reports one.
+    fn update_tempo(&mut self) {
+        let host = match self.host {
+            Some(host) => host,
+            None => return,
+        };
+        let time_info = match host.get_time_info(TimeInfoFlags::TEMPO_VALID.bits()) {
+            Some(time_info) => time_info,
+            None => return,
+        };
+        if TimeInfoFlags::from_bits_truncate(time_info.flags).contains(TimeInfoFlags::TEMPO_VALID) {
+            self.dsp.set_tempo(time_info.tempo as f32);
+            self.state_handle.set_tempo(time_info.tempo as f32);
+        }
+    }
 }
 
 /// `vst::plugin_main` requires a `Default` implementation.
@@ -68,21 +95,61 @@ impl Plugin for ReverbVst {
             name: "Reverb".to_string(),
             vendor: "Borden".to_string(),
             unique_id: *UNIQUE_ID,
-            inputs: 2,
+            // main stereo input, then the sidechain
+            inputs: 4,
             outputs: 2,
-            parameters: 0,
-            initial_delay: 0,
+            parameters: Parameter::ALL.len() as i32,
+            initial_delay: self.state_handle.latency() as i32,
             preset_chunks: true,
             ..Info::default()
         }
     }
 
+    fn get_input_info(&self, input: i32) -> ChannelInfo {
+        let (name, short_name) = match input {
+            0 => ("Main Left", "In L"),
+            1 => ("Main Right", "In R"),
+            2 => ("Sidechain Left", "SC L"),
+            _ => ("Sidechain Right", "SC R"),
+        };
+        let channel = if input % 2 == 0 { StereoChannel::Left } else { StereoChannel::Right };
+        ChannelInfo::new(
+            name.to_string(),
+            Some(short_name.to_string()),
+            true,
+            Some(SpeakerArrangementType::Stereo(StereoConfig::L_R, channel)),
+        )
+    }
+
+    fn set_sample_rate(&mut self, rate: f32) {
+        self.dsp.set_sample_rate(rate);
+        self.state_handle.set_sample_rate(rate);
+    }
+
     fn process(&mut self, buffer: &mut AudioBuffer<f32>) {
+        self.state_handle.report_latency();
+        self.update_tempo();
         self.dsp.process(buffer);
     }
 
-    fn can_do(&self, _can_do: CanDo) -> Supported {
-        Supported::Maybe
+    /// MIDI note ons kick the tank.
+    fn process_events(&mut self, events: &Events) {
+        for event in events.events() {
+            if let Event::Midi(midi) = event {
+                let [status, _note, velocity] = midi.data;
+                // a note on with no velocity is a note off
+                if status & 0xF0 == 0x90 && velocity > 0 {
+                    self.dsp.trigger_kick(midi.delta_frames.max(0) as usize, velocity as f32 / 127.);
+                }
+            }
+        }
+    }
+
+    fn can_do(&self, can_do: CanDo) -> Supported {
+        match can_do {
+            CanDo::ReceiveEvents | CanDo::ReceiveMidiEvent => Supported::Yes,
+            _ => Supported::Maybe,
+        }
     }
 
     fn get_parameter_object(&mut self) -> Arc<dyn PluginParameters> {
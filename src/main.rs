@@ -7,6 +7,7 @@ fn main() {
 }
 
 fn print_impulse(impulse_response: &[f32]) {
+  println!("#[allow(clippy::excessive_precision)]");
   print!("pub const SPRING_IMPULSE_RESPONSE: &[f32] = &[");
   for sample in impulse_response {
     print!("{:.7},", sample);
@@ -4,30 +4,624 @@
 //! synchronization overhead, and to reduce recalculation of derived parameters, the audio
 //! processing and UI threads subscribe to parameter updates through cross-thread message passing.
 //!
-//! This plugin's long-term state only consists of a single floating-point value (the value of the
-//! cutoff knob), but it should be simple to extend this scheme to work with multiple knobs,
-//! toggles, node locations, waveforms, user-defined labels, and so on.
+//! Every host-visible parameter is described once in the `Parameter` table below, which maps the
+//! host's normalized `0..=1` values onto real units. The audio thread only ever sees real units.
 
+use std::ptr;
 use std::sync::{
-    mpsc::Sender,
-    Mutex,
+    atomic::{AtomicU32, AtomicUsize, Ordering},
+    mpsc::{Sender, SyncSender},
+    Arc, Mutex,
 };
 
 use vst::{
+    host,
     plugin::{HostCallback, PluginParameters},
 };
 
+use crate::dsp::gate::gate_latency;
+use crate::dsp::hybrid::HybridTail;
+use crate::dsp::limiter::limiter_latency;
+use crate::dsp::multiband::{crossover_latency, CrossoverKind};
+use crate::dsp::oversampling::oversampling_latency;
+use crate::dsp::physical_spring::SpringMeshes;
+use crate::dsp::reverse::{true_reverse_latency, ReverseMode, MAX_REVERSE_MS};
+use crate::dsp::spring_ir::BandIrs;
+use crate::dsp::tempo::DEFAULT_TEMPO;
+use crate::dsp::velvet::VelvetTaps;
+use crate::ir_worker::{self, IrMessage, Retired};
+
 /// Describes a discrete operation that can update this plugin's long-term state.
 #[derive(Clone)]
 pub enum StateUpdate {
-    SetKnob(f32),
+    /// Sets a parameter to a new value, already mapped from the host's normalized range into the
+    /// parameter's real units.
+    SetParameter(Parameter, f32),
+    /// Crossfades the convolvers to a new impulse response for each band, prepared by the IR worker.
+    SetImpulseResponse(Box<BandIrs>),
+    /// Crossfades the hybrid engine to new early reflections and the late tail they hand over to,
+    /// prepared together by the IR worker.
+    SwapHybridTail(Box<HybridTail>),
+    /// Crossfades the velvet engine to new taps, laid out by the IR worker.
+    SwapVelvetTaps(Box<VelvetTaps>),
+    /// Crossfades the physical spring engine to new meshes, built by the IR worker.
+    SwapSpringMeshes(Box<SpringMeshes>),
+    /// Gives the DSP the way back to the IR worker, for what it swaps out. Sent once, by the worker
+    /// as it starts.
+    SetReturnPath(SyncSender<Retired>),
+}
+
+/// Readings the DSP reports back to the host, see `Parameter::meter`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Meter {
+    /// How far the dynamic mode has moved the wet level, in dB.
+    DynamicGain,
+    /// How hard the output limiter is pulling the level down, in dB.
+    LimiterReduction,
+}
+
+const METER_COUNT: usize = 2;
+
+/// The latest meter readings. Written by the audio thread and read by the UI thread, as plain
+/// atomics so neither side ever waits on the other.
+pub struct Meters {
+    readings: [AtomicU32; METER_COUNT],
+}
+
+impl Default for Meters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Meters {
+    pub fn new() -> Self {
+        Self {
+            readings: Default::default(),
+        }
+    }
+
+    pub fn set(&self, meter: Meter, value: f32) {
+        self.readings[meter as usize].store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn get(&self, meter: Meter) -> f32 {
+        f32::from_bits(self.readings[meter as usize].load(Ordering::Relaxed))
+    }
+}
+
+/// Every automatable parameter exposed to the host, in host index order. Hosts save automation and
+/// state by index, so new parameters only ever go on the end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parameter {
+    GateEnabled,
+    GateKey,
+    GateThreshold,
+    GateHold,
+    GateRelease,
+    GateLookahead,
+    GateRange,
+    Freeze,
+    FreezeInput,
+    DriveEnabled,
+    DriveType,
+    DriveAmount,
+    DriveOversampling,
+    ShimmerEnabled,
+    ShimmerPitch,
+    ShimmerFeedback,
+    ShimmerDamping,
+    GateHoldSync,
+    PreDelay,
+    PreDelaySync,
+    Engine,
+    SpringLength,
+    SpringTension,
+    SpringDamping,
+    SpringCount,
+    IrSource,
+    PhysWireRadius,
+    PhysCoilRadius,
+    PhysLength,
+    PhysDecay,
+    RoomSize,
+    RoomLines,
+    RoomMatrix,
+    RoomRt60Low,
+    RoomRt60Mid,
+    RoomRt60High,
+    RoomModRate,
+    RoomModDepth,
+    PlateDecay,
+    PlateDamping,
+    PlateBandwidth,
+    PlateDiffusion,
+    PlateExcursion,
+    PlateModRate,
+    HybridCrossover,
+    HybridBalance,
+    VelvetDecay,
+    VelvetDamping,
+    VelvetDensity,
+    WobbleEnabled,
+    WobbleRate,
+    WobbleRateSync,
+    WobbleDepth,
+    WobbleRandom,
+    KickType,
+    KickLevel,
+    KickDry,
+    Sidechain,
+    IrWidth,
+    DynamicMode,
+    DynamicThreshold,
+    DynamicRange,
+    DynamicAttack,
+    DynamicRelease,
+    DynamicMeter,
+    LimiterEnabled,
+    LimiterCeiling,
+    LimiterRelease,
+    LimiterReduction,
+    IrDecay,
+    IrDecayLow,
+    IrDecayMid,
+    IrDecayHigh,
+    IrDecayLowCross,
+    IrDecayHighCross,
+    Reverse,
+    ReverseLength,
+    IrStart,
+    IrLength,
+    IrFade,
+    IrFadeShape,
+    SplitTime,
+    EarlyLevel,
+    EarlyTone,
+    EarlyWidth,
+    LateLevel,
+    LateTone,
+    LateWidth,
+    BandCount,
+    BandCrossover,
+    BandCross1,
+    BandCross2,
+    BandCross3,
+    BandIr1,
+    BandIr2,
+    BandIr3,
+    BandIr4,
+    BandLevel1,
+    BandLevel2,
+    BandLevel3,
+    BandLevel4,
+    RoomDecaySync,
+    RoomModRateSync,
+    PlateDecaySync,
+    PlateModRateSync,
+    VelvetDecaySync,
+}
+
+/// How a normalized host value in `0..=1` maps onto a parameter's real value.
+pub enum Mapping {
+    Linear { min: f32, max: f32 },
+    /// For times and frequencies, where equal knob travel should mean equal ratios.
+    Exponential { min: f32, max: f32 },
+    /// `0.` for off, `1.` for on.
+    Toggle,
+    /// The real value is the index of the selected option.
+    Choice(&'static [&'static str]),
+}
+
+/// Static description of a single parameter.
+pub struct ParameterInfo {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub mapping: Mapping,
+    /// Default value in real units.
+    pub default: f32,
+}
+
+/// Must line up with `dsp::engine::EngineKind`.
+pub const ENGINE_OPTIONS: &[&str] = &["Spring (IR)", "Spring (Model)", "Spring (Physical)", "Room (FDN)", "Plate", "Hybrid", "Velvet"];
+pub const IR_SOURCE_OPTIONS: &[&str] = &["Recording", "Physical"];
+/// Must line up with `dsp::ir_trim::FadeShape`.
+pub const IR_FADE_OPTIONS: &[&str] = &["Linear", "Cosine", "Exponential"];
+/// Must line up with `dsp::reverse::ReverseMode`.
+pub const REVERSE_OPTIONS: &[&str] = &["Off", "Reverse", "True Reverse"];
+pub const BAND_COUNT_OPTIONS: &[&str] = &["1", "2", "3", "4"];
+/// Must line up with `dsp::multiband::CrossoverKind`.
+pub const CROSSOVER_OPTIONS: &[&str] = &["Linkwitz-Riley", "Linear Phase"];
+/// Must line up with `ir_worker::IrSlot`.
+pub const BAND_IR_OPTIONS: &[&str] = &["Spring", "Plate", "Room"];
+/// Must line up with `dsp::kick::KickType`.
+pub const KICK_TYPE_OPTIONS: &[&str] = &["Click", "Thump", "Noise"];
+/// Must line up with `dsp::SidechainMode`.
+pub const SIDECHAIN_OPTIONS: &[&str] = &["Off", "Replace", "Add"];
+pub const ROOM_LINES_OPTIONS: &[&str] = &["8", "16"];
+/// Must line up with `dsp::fdn::FeedbackMatrix`.
+pub const ROOM_MATRIX_OPTIONS: &[&str] = &["Hadamard", "Householder"];
+pub const SPRING_COUNT_OPTIONS: &[&str] = &["1", "2", "3", "4"];
+pub const GATE_KEY_OPTIONS: &[&str] = &["Input", "Wet"];
+pub const FREEZE_INPUT_OPTIONS: &[&str] = &["Mute", "Mix"];
+pub const DRIVE_TYPE_OPTIONS: &[&str] = &["Soft Clip", "Tube", "Transformer"];
+/// Option `n` oversamples by `2^n`.
+pub const OVERSAMPLING_OPTIONS: &[&str] = &["1x", "2x", "4x", "8x"];
+/// Note divisions for tempo synced times, see `dsp::tempo`.
+pub const SYNC_OPTIONS: &[&str] = &[
+    "Off", "1/1", "1/2", "1/2 D", "1/2 T", "1/4", "1/4 D", "1/4 T",
+    "1/8", "1/8 D", "1/8 T", "1/16", "1/16 D", "1/16 T", "1/32",
+];
+pub const SHIMMER_PITCH_OPTIONS: &[&str] = &["+12", "+7", "-12"];
+/// Must line up with `dsp::dynamic::DynamicMode`.
+pub const DYNAMIC_MODE_OPTIONS: &[&str] = &["Off", "Inverse", "Direct"];
+
+impl Parameter {
+    pub const ALL: &'static [Parameter] = &[
+        Parameter::GateEnabled,
+        Parameter::GateKey,
+        Parameter::GateThreshold,
+        Parameter::GateHold,
+        Parameter::GateRelease,
+        Parameter::GateLookahead,
+        Parameter::GateRange,
+        Parameter::Freeze,
+        Parameter::FreezeInput,
+        Parameter::DriveEnabled,
+        Parameter::DriveType,
+        Parameter::DriveAmount,
+        Parameter::DriveOversampling,
+        Parameter::ShimmerEnabled,
+        Parameter::ShimmerPitch,
+        Parameter::ShimmerFeedback,
+        Parameter::ShimmerDamping,
+        Parameter::GateHoldSync,
+        Parameter::PreDelay,
+        Parameter::PreDelaySync,
+        Parameter::Engine,
+        Parameter::SpringLength,
+        Parameter::SpringTension,
+        Parameter::SpringDamping,
+        Parameter::SpringCount,
+        Parameter::IrSource,
+        Parameter::PhysWireRadius,
+        Parameter::PhysCoilRadius,
+        Parameter::PhysLength,
+        Parameter::PhysDecay,
+        Parameter::RoomSize,
+        Parameter::RoomLines,
+        Parameter::RoomMatrix,
+        Parameter::RoomRt60Low,
+        Parameter::RoomRt60Mid,
+        Parameter::RoomRt60High,
+        Parameter::RoomModRate,
+        Parameter::RoomModDepth,
+        Parameter::PlateDecay,
+        Parameter::PlateDamping,
+        Parameter::PlateBandwidth,
+        Parameter::PlateDiffusion,
+        Parameter::PlateExcursion,
+        Parameter::PlateModRate,
+        Parameter::HybridCrossover,
+        Parameter::HybridBalance,
+        Parameter::VelvetDecay,
+        Parameter::VelvetDamping,
+        Parameter::VelvetDensity,
+        Parameter::WobbleEnabled,
+        Parameter::WobbleRate,
+        Parameter::WobbleRateSync,
+        Parameter::WobbleDepth,
+        Parameter::WobbleRandom,
+        Parameter::KickType,
+        Parameter::KickLevel,
+        Parameter::KickDry,
+        Parameter::Sidechain,
+        Parameter::IrWidth,
+        Parameter::DynamicMode,
+        Parameter::DynamicThreshold,
+        Parameter::DynamicRange,
+        Parameter::DynamicAttack,
+        Parameter::DynamicRelease,
+        Parameter::DynamicMeter,
+        Parameter::LimiterEnabled,
+        Parameter::LimiterCeiling,
+        Parameter::LimiterRelease,
+        Parameter::LimiterReduction,
+        Parameter::IrDecay,
+        Parameter::IrDecayLow,
+        Parameter::IrDecayMid,
+        Parameter::IrDecayHigh,
+        Parameter::IrDecayLowCross,
+        Parameter::IrDecayHighCross,
+        Parameter::Reverse,
+        Parameter::ReverseLength,
+        Parameter::IrStart,
+        Parameter::IrLength,
+        Parameter::IrFade,
+        Parameter::IrFadeShape,
+        Parameter::SplitTime,
+        Parameter::EarlyLevel,
+        Parameter::EarlyTone,
+        Parameter::EarlyWidth,
+        Parameter::LateLevel,
+        Parameter::LateTone,
+        Parameter::LateWidth,
+        Parameter::BandCount,
+        Parameter::BandCrossover,
+        Parameter::BandCross1,
+        Parameter::BandCross2,
+        Parameter::BandCross3,
+        Parameter::BandIr1,
+        Parameter::BandIr2,
+        Parameter::BandIr3,
+        Parameter::BandIr4,
+        Parameter::BandLevel1,
+        Parameter::BandLevel2,
+        Parameter::BandLevel3,
+        Parameter::BandLevel4,
+        Parameter::RoomDecaySync,
+        Parameter::RoomModRateSync,
+        Parameter::PlateDecaySync,
+        Parameter::PlateModRateSync,
+        Parameter::VelvetDecaySync,
+    ];
+
+    pub fn from_index(index: i32) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn info(self) -> ParameterInfo {
+        use Mapping::*;
+        let (name, label, mapping, default) = match self {
+            Parameter::Engine => ("Engine", "", Choice(ENGINE_OPTIONS), 0.),
+            Parameter::SpringLength => ("Spring Length", "ms", Exponential { min: 20., max: 200. }, 60.),
+            Parameter::SpringTension => ("Spring Tension", "%", Linear { min: 0., max: 100. }, 50.),
+            Parameter::SpringDamping => ("Spring Damping", "%", Linear { min: 0., max: 100. }, 50.),
+            Parameter::SpringCount => ("Springs", "", Choice(SPRING_COUNT_OPTIONS), 1.),
+            Parameter::IrSource => ("IR Source", "", Choice(IR_SOURCE_OPTIONS), 0.),
+            Parameter::PhysWireRadius => ("Wire Radius", "mm", Exponential { min: 0.1, max: 0.5 }, 0.2),
+            Parameter::PhysCoilRadius => ("Coil Radius", "mm", Exponential { min: 2., max: 8. }, 4.),
+            Parameter::PhysLength => ("Phys Length", "cm", Exponential { min: 5., max: 30. }, 15.),
+            Parameter::PhysDecay => ("Phys Decay", "s", Exponential { min: 0.5, max: 8. }, 2.5),
+            Parameter::IrWidth => ("IR Width", "%", Linear { min: 0., max: 100. }, 0.),
+            Parameter::IrDecay => ("IR Decay", "%", Exponential { min: 25., max: 400. }, 100.),
+            Parameter::IrDecayLow => ("Decay Low", "%", Exponential { min: 25., max: 400. }, 100.),
+            Parameter::IrDecayMid => ("Decay Mid", "%", Exponential { min: 25., max: 400. }, 100.),
+            Parameter::IrDecayHigh => ("Decay High", "%", Exponential { min: 25., max: 400. }, 100.),
+            Parameter::IrDecayLowCross => ("Decay X Low", "Hz", Exponential { min: 50., max: 2000. }, 300.),
+            Parameter::IrDecayHighCross => ("Decay X High", "Hz", Exponential { min: 1000., max: 16000. }, 4000.),
+            Parameter::IrStart => ("IR Start", "ms", Linear { min: 0., max: 1000. }, 0.),
+            // the top of the range is longer than any IR, so keeps the whole thing
+            Parameter::IrLength => ("IR Length", "ms", Exponential { min: 50., max: 10000. }, 10000.),
+            Parameter::IrFade => ("IR Fade", "ms", Exponential { min: 1., max: 2000. }, 50.),
+            Parameter::IrFadeShape => ("IR Fade Shape", "", Choice(IR_FADE_OPTIONS), 1.),
+            Parameter::Reverse => ("Reverse", "", Choice(REVERSE_OPTIONS), 0.),
+            Parameter::ReverseLength => ("Reverse Len", "ms", Exponential { min: 50., max: MAX_REVERSE_MS }, 1000.),
+            Parameter::SplitTime => ("Split", "ms", Exponential { min: 10., max: 500. }, 80.),
+            Parameter::EarlyLevel => ("Early Level", "dB", Linear { min: -48., max: 12. }, 0.),
+            Parameter::EarlyTone => ("Early Tone", "dB", Linear { min: -12., max: 12. }, 0.),
+            Parameter::EarlyWidth => ("Early Width", "%", Linear { min: 0., max: 200. }, 100.),
+            Parameter::LateLevel => ("Late Level", "dB", Linear { min: -48., max: 12. }, 0.),
+            Parameter::LateTone => ("Late Tone", "dB", Linear { min: -12., max: 12. }, 0.),
+            Parameter::LateWidth => ("Late Width", "%", Linear { min: 0., max: 200. }, 100.),
+            Parameter::BandCount => ("Bands", "", Choice(BAND_COUNT_OPTIONS), 0.),
+            Parameter::BandCrossover => ("Crossover Mode", "", Choice(CROSSOVER_OPTIONS), 0.),
+            Parameter::BandCross1 => ("Band Cross 1", "Hz", Exponential { min: 40., max: 16000. }, 200.),
+            Parameter::BandCross2 => ("Band Cross 2", "Hz", Exponential { min: 40., max: 16000. }, 1200.),
+            Parameter::BandCross3 => ("Band Cross 3", "Hz", Exponential { min: 40., max: 16000. }, 5000.),
+            Parameter::BandIr1 => ("Band 1 IR", "", Choice(BAND_IR_OPTIONS), 0.),
+            Parameter::BandIr2 => ("Band 2 IR", "", Choice(BAND_IR_OPTIONS), 0.),
+            Parameter::BandIr3 => ("Band 3 IR", "", Choice(BAND_IR_OPTIONS), 0.),
+            Parameter::BandIr4 => ("Band 4 IR", "", Choice(BAND_IR_OPTIONS), 0.),
+            Parameter::BandLevel1 => ("Band 1 Level", "dB", Linear { min: -48., max: 12. }, 0.),
+            Parameter::BandLevel2 => ("Band 2 Level", "dB", Linear { min: -48., max: 12. }, 0.),
+            Parameter::BandLevel3 => ("Band 3 Level", "dB", Linear { min: -48., max: 12. }, 0.),
+            Parameter::BandLevel4 => ("Band 4 Level", "dB", Linear { min: -48., max: 12. }, 0.),
+            Parameter::RoomDecaySync => ("Room Decay Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::RoomModRateSync => ("Room Mod Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::PlateDecaySync => ("Plate Decay Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::PlateModRateSync => ("Plate Mod Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::VelvetDecaySync => ("Velvet Decay Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::RoomSize => ("Room Size", "ms", Exponential { min: 10., max: 200. }, 80.),
+            Parameter::RoomLines => ("Room Lines", "", Choice(ROOM_LINES_OPTIONS), 0.),
+            Parameter::RoomMatrix => ("Room Matrix", "", Choice(ROOM_MATRIX_OPTIONS), 0.),
+            Parameter::RoomRt60Low => ("RT60 Low", "s", Exponential { min: 0.1, max: 20. }, 2.),
+            Parameter::RoomRt60Mid => ("RT60 Mid", "s", Exponential { min: 0.1, max: 20. }, 1.6),
+            Parameter::RoomRt60High => ("RT60 High", "s", Exponential { min: 0.1, max: 20. }, 0.8),
+            Parameter::RoomModRate => ("Room Mod Rate", "Hz", Exponential { min: 0.05, max: 5. }, 0.5),
+            Parameter::RoomModDepth => ("Room Mod Depth", "%", Linear { min: 0., max: 100. }, 20.),
+            Parameter::PlateDecay => ("Plate Decay", "%", Linear { min: 0., max: 99.9 }, 50.),
+            Parameter::PlateDamping => ("Plate Damping", "%", Linear { min: 0., max: 100. }, 10.),
+            Parameter::PlateBandwidth => ("Plate Bandwidth", "%", Linear { min: 5., max: 100. }, 100.),
+            Parameter::PlateDiffusion => ("Plate Diffusion", "%", Linear { min: 0., max: 100. }, 100.),
+            Parameter::PlateExcursion => ("Plate Excursion", "smp", Linear { min: 0., max: 32. }, 16.),
+            Parameter::PlateModRate => ("Plate Mod Rate", "Hz", Exponential { min: 0.1, max: 4. }, 1.),
+            Parameter::HybridCrossover => ("Crossover", "ms", Exponential { min: 10., max: 300. }, 80.),
+            Parameter::HybridBalance => ("Early/Late", "%", Linear { min: 0., max: 100. }, 50.),
+            Parameter::VelvetDecay => ("Velvet Decay", "s", Exponential { min: 0.1, max: 5. }, 1.5),
+            Parameter::VelvetDamping => ("Velvet Damping", "%", Linear { min: 0., max: 100. }, 50.),
+            Parameter::VelvetDensity => ("Velvet Density", "/s", Exponential { min: 200., max: 4000. }, 1500.),
+            Parameter::WobbleEnabled => ("Wobble", "", Toggle, 0.),
+            Parameter::WobbleRate => ("Wobble Rate", "Hz", Exponential { min: 0.1, max: 10. }, 1.5),
+            Parameter::WobbleRateSync => ("Wobble Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::WobbleDepth => ("Wobble Depth", "ms", Linear { min: 0., max: 5. }, 1.),
+            Parameter::WobbleRandom => ("Wobble Random", "%", Linear { min: 0., max: 100. }, 30.),
+            Parameter::KickType => ("Kick Type", "", Choice(KICK_TYPE_OPTIONS), 1.),
+            Parameter::KickLevel => ("Kick Level", "dB", Linear { min: -24., max: 12. }, 0.),
+            Parameter::KickDry => ("Kick Dry", "", Toggle, 0.),
+            Parameter::Sidechain => ("Sidechain", "", Choice(SIDECHAIN_OPTIONS), 0.),
+            Parameter::GateEnabled => ("Gate", "", Toggle, 0.),
+            Parameter::GateKey => ("Gate Key", "", Choice(GATE_KEY_OPTIONS), 0.),
+            Parameter::GateThreshold => ("Gate Thresh", "dB", Linear { min: -60., max: 0. }, -24.),
+            Parameter::GateHold => ("Gate Hold", "ms", Exponential { min: 1., max: 2000. }, 250.),
+            Parameter::GateHoldSync => ("Gate Hold Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::GateRelease => ("Gate Release", "ms", Exponential { min: 1., max: 1000. }, 40.),
+            Parameter::GateLookahead => ("Gate Lookahd", "ms", Linear { min: 0., max: 10. }, 2.),
+            Parameter::GateRange => ("Gate Range", "dB", Linear { min: -90., max: 0. }, -90.),
+            Parameter::Freeze => ("Freeze", "", Toggle, 0.),
+            Parameter::FreezeInput => ("Freeze Input", "", Choice(FREEZE_INPUT_OPTIONS), 0.),
+            Parameter::PreDelay => ("Pre-Delay", "ms", Linear { min: 0., max: 500. }, 0.),
+            Parameter::PreDelaySync => ("Pre-Delay Sync", "", Choice(SYNC_OPTIONS), 0.),
+            Parameter::DriveEnabled => ("Drive", "", Toggle, 0.),
+            Parameter::DriveType => ("Drive Type", "", Choice(DRIVE_TYPE_OPTIONS), 0.),
+            Parameter::DriveAmount => ("Drive Amount", "dB", Linear { min: 0., max: 36. }, 12.),
+            Parameter::DriveOversampling => ("Drive OS", "", Choice(OVERSAMPLING_OPTIONS), 2.),
+            Parameter::ShimmerEnabled => ("Shimmer", "", Toggle, 0.),
+            Parameter::ShimmerPitch => ("Shimmer Pitch", "st", Choice(SHIMMER_PITCH_OPTIONS), 0.),
+            Parameter::ShimmerFeedback => ("Shimmer Fdbk", "%", Linear { min: 0., max: 95. }, 50.),
+            Parameter::ShimmerDamping => ("Shimmer Damp", "Hz", Exponential { min: 1000., max: 20000. }, 6000.),
+            Parameter::DynamicMode => ("Dynamic", "", Choice(DYNAMIC_MODE_OPTIONS), 0.),
+            Parameter::DynamicThreshold => ("Dyn Thresh", "dB", Linear { min: -60., max: 0. }, -30.),
+            Parameter::DynamicRange => ("Dyn Range", "dB", Linear { min: 0., max: 36. }, 12.),
+            Parameter::DynamicAttack => ("Dyn Attack", "ms", Exponential { min: 0.1, max: 500. }, 10.),
+            Parameter::DynamicRelease => ("Dyn Release", "ms", Exponential { min: 10., max: 5000. }, 300.),
+            Parameter::DynamicMeter => ("Dyn Gain", "dB", Linear { min: -36., max: 0. }, 0.),
+            Parameter::LimiterEnabled => ("Limiter", "", Toggle, 0.),
+            Parameter::LimiterCeiling => ("Ceiling", "dBTP", Linear { min: -12., max: 0. }, -0.3),
+            Parameter::LimiterRelease => ("Lim Release", "ms", Exponential { min: 10., max: 1000. }, 100.),
+            Parameter::LimiterReduction => ("Lim GR", "dB", Linear { min: -24., max: 0. }, 0.),
+        };
+        ParameterInfo { name, label, mapping, default }
+    }
+
+    /// Whether the parameter changes the convolvers' impulse response, the velvet tail or the
+    /// physical spring's meshes, and so also needs to reach the IR worker.
+    pub fn shapes_ir(self) -> bool {
+        matches!(
+            self,
+            Parameter::IrSource
+                | Parameter::PhysWireRadius
+                | Parameter::PhysCoilRadius
+                | Parameter::PhysLength
+                | Parameter::PhysDecay
+                | Parameter::IrWidth
+                | Parameter::IrDecay
+                | Parameter::IrDecayLow
+                | Parameter::IrDecayMid
+                | Parameter::IrDecayHigh
+                | Parameter::IrDecayLowCross
+                | Parameter::IrDecayHighCross
+                | Parameter::IrStart
+                | Parameter::IrLength
+                | Parameter::IrFade
+                | Parameter::IrFadeShape
+                | Parameter::Reverse
+                | Parameter::ReverseLength
+                | Parameter::SplitTime
+                | Parameter::BandCount
+                | Parameter::BandCrossover
+                | Parameter::BandCross1
+                | Parameter::BandCross2
+                | Parameter::BandCross3
+                | Parameter::BandIr1
+                | Parameter::BandIr2
+                | Parameter::BandIr3
+                | Parameter::BandIr4
+                | Parameter::HybridCrossover
+                | Parameter::VelvetDecay
+                | Parameter::VelvetDamping
+                | Parameter::VelvetDensity
+                | Parameter::VelvetDecaySync
+        )
+    }
+
+    /// The switches that move the latency. Only switches do, so automating a knob never has the
+    /// host redo its delay compensation.
+    pub fn sets_latency(self) -> bool {
+        matches!(
+            self,
+            Parameter::Reverse
+                | Parameter::BandCount
+                | Parameter::BandCrossover
+                | Parameter::DriveEnabled
+                | Parameter::DriveOversampling
+                | Parameter::GateEnabled
+                | Parameter::LimiterEnabled
+        )
+    }
+
+    /// Read-only parameters that report a reading from the DSP instead of setting anything.
+    pub fn meter(self) -> Option<Meter> {
+        match self {
+            Parameter::DynamicMeter => Some(Meter::DynamicGain),
+            Parameter::LimiterReduction => Some(Meter::LimiterReduction),
+            _ => None,
+        }
+    }
+
+    /// Maps a normalized host value onto real units.
+    pub fn denormalize(self, value: f32) -> f32 {
+        let value = value.clamp(0., 1.);
+        match self.info().mapping {
+            Mapping::Linear { min, max } => min + value * (max - min),
+            Mapping::Exponential { min, max } => min * (max / min).powf(value),
+            Mapping::Toggle => if value >= 0.5 { 1. } else { 0. },
+            Mapping::Choice(options) => (value * (options.len() - 1) as f32).round(),
+        }
+    }
+
+    /// Maps a value in real units onto the host's normalized range.
+    pub fn normalize(self, value: f32) -> f32 {
+        let normalized = match self.info().mapping {
+            Mapping::Linear { min, max } => (value - min) / (max - min),
+            Mapping::Exponential { min, max } => (value / min).ln() / (max / min).ln(),
+            Mapping::Toggle => value,
+            Mapping::Choice(options) => value / (options.len() - 1) as f32,
+        };
+        normalized.clamp(0., 1.)
+    }
+
+    /// Human readable text for a value in real units.
+    pub fn display(self, value: f32) -> String {
+        match self.info().mapping {
+            Mapping::Toggle => if value >= 0.5 { "On" } else { "Off" }.to_string(),
+            Mapping::Choice(options) => options[value as usize].to_string(),
+            _ => format!("{:.2}", value),
+        }
+    }
+
+    /// Parses text typed by the user into real units.
+    pub fn parse(self, text: &str) -> Option<f32> {
+        let text = text.trim();
+        match self.info().mapping {
+            Mapping::Toggle => match text.to_lowercase().as_str() {
+                "on" | "1" => Some(1.),
+                "off" | "0" => Some(0.),
+                _ => None,
+            },
+            Mapping::Choice(options) => options
+                .iter()
+                .position(|option| option.eq_ignore_ascii_case(text))
+                .map(|index| index as f32),
+            Mapping::Linear { min, max } | Mapping::Exponential { min, max } => {
+                match text.parse::<f32>() {
+                    Ok(value) if (min..=max).contains(&value) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+    }
 }
 
-#[allow(dead_code)]
 pub struct PluginState {
     host: HostCallback,
     to_dsp: Mutex<Sender<StateUpdate>>,
+    to_ir_worker: Mutex<Sender<IrMessage>>,
+    /// Normalized values of every parameter, indexed by `Parameter::index`.
     state_record: Mutex<Vec<f32>>,
+    /// Readings from the DSP, shown through the meter parameters.
+    meters: Arc<Meters>,
+    /// The host's sample rate, which the latency is counted at.
+    sample_rate: Mutex<f32>,
+    /// The latency as of the last switch that moved it, and the latency the host was last told.
+    current_latency: AtomicUsize,
+    reported_latency: AtomicUsize,
+    /// The host's tempo, as `f32` bits. Shared with the IR worker, which picks up changes itself.
+    tempo: Arc<AtomicU32>,
 }
 
 /// VST-accessible long-term plugin state storage. This is accessed through the audio processing
@@ -37,11 +631,96 @@ impl PluginState {
     pub fn new(
         host: HostCallback,
         to_dsp: Sender<StateUpdate>,
+        meters: Arc<Meters>,
     ) -> Self {
-        Self {
+        let state_record = Parameter::ALL
+            .iter()
+            .map(|param| param.normalize(param.info().default))
+            .collect();
+        let tempo = Arc::new(AtomicU32::new(DEFAULT_TEMPO.to_bits()));
+        let to_ir_worker = ir_worker::spawn(to_dsp.clone(), Arc::clone(&tempo));
+        let state = Self {
             host,
             to_dsp: Mutex::new(to_dsp),
-            state_record: Mutex::new(vec![0.5, 0., 0., 0.]),
+            to_ir_worker: Mutex::new(to_ir_worker),
+            state_record: Mutex::new(state_record),
+            meters,
+            sample_rate: Mutex::new(44100.),
+            current_latency: AtomicUsize::new(0),
+            reported_latency: AtomicUsize::new(0),
+            tempo,
+        };
+        // the host picks up the starting latency from `Info::initial_delay`
+        state.current_latency.store(state.latency(), Ordering::Relaxed);
+        state.reported_latency.store(state.latency(), Ordering::Relaxed);
+        state
+    }
+
+    /// The IR worker renders at the host's sample rate.
+    pub fn set_sample_rate(&self, sample_rate: f32) {
+        // the worker only stops when this state is dropped
+        let _ = self.to_ir_worker.lock().unwrap().send(IrMessage::SetSampleRate(sample_rate));
+        *self.sample_rate.lock().unwrap() = sample_rate;
+        self.update_latency();
+    }
+
+    /// Passes the host's tempo on to the IR worker, for the velvet taps when their decay is synced.
+    /// Called from the audio thread every block, so it's only stored, for the worker to pick up.
+    pub fn set_tempo(&self, tempo: f32) {
+        self.tempo.store(tempo.to_bits(), Ordering::Relaxed);
+    }
+
+    /// How many samples the output trails the input by. True reverse, the linear phase crossover,
+    /// the drive's oversampling, the gate and the limiter add to it, and only switches move it, see
+    /// `Parameter::sets_latency`.
+    pub fn latency(&self) -> usize {
+        let sample_rate = *self.sample_rate.lock().unwrap();
+        let reverse = match ReverseMode::from_index(self.real_value(Parameter::Reverse) as usize) {
+            ReverseMode::TrueReverse => true_reverse_latency(sample_rate),
+            _ => 0,
+        };
+        let bands = self.real_value(Parameter::BandCount) as usize + 1;
+        let crossover = CrossoverKind::from_index(self.real_value(Parameter::BandCrossover) as usize);
+        let drive = if self.real_value(Parameter::DriveEnabled) >= 0.5 {
+            oversampling_latency(1 << self.real_value(Parameter::DriveOversampling) as usize)
+        } else {
+            0
+        };
+        let gate = if self.real_value(Parameter::GateEnabled) >= 0.5 { gate_latency(sample_rate) } else { 0 };
+        let limiter = if self.real_value(Parameter::LimiterEnabled) >= 0.5 { limiter_latency(sample_rate) } else { 0 };
+        reverse + crossover_latency(bands, crossover, sample_rate) + drive + gate + limiter
+    }
+
+    /// Notes the latency after a switch that may have moved it, for `report_latency` to pass on.
+    fn update_latency(&self) {
+        self.current_latency.store(self.latency(), Ordering::Relaxed);
+    }
+
+    /// Lets the host know the latency has moved since it was last told, so it can shift its delay
+    /// compensation. Parameter changes may come in on any thread, so they only note the latency
+    /// and this, called at the start of `process`, keeps the effect written from the audio thread.
+    pub fn report_latency(&self) {
+        let latency = self.current_latency.load(Ordering::Relaxed);
+        if self.reported_latency.swap(latency, Ordering::Relaxed) == latency {
+            return;
+        }
+        let effect = self.host.raw_effect();
+        let callback = match self.host.raw_callback() {
+            Some(callback) if !effect.is_null() => callback,
+            _ => return,
+        };
+        // `vst` only copies `Info::initial_delay` into the effect when the plugin is created, so
+        // later changes go straight into it before the host is told to read it again
+        unsafe {
+            (*effect).initialDelay = latency as i32;
+        }
+        callback(effect, host::OpCode::IOChanged as i32, 0, 0, ptr::null_mut(), 0.);
+    }
+
+    fn real_value(&self, param: Parameter) -> f32 {
+        match param.meter() {
+            Some(meter) => self.meters.get(meter),
+            None => param.denormalize(self.state_record.lock().unwrap()[param.index()]),
         }
     }
 }
@@ -49,51 +728,73 @@ impl PluginState {
 /// The DAW directly accesses the plugin state through the VST API to get reports on knob states.
 impl PluginParameters for PluginState {
     fn set_parameter(&self, index: i32, value: f32) {
-        let state_update = StateUpdate::SetKnob(value);
+        let param = match Parameter::from_index(index) {
+            Some(param) => param,
+            None => return,
+        };
+        // meters only ever come from the DSP
+        if param.meter().is_some() {
+            return;
+        }
+        let real_value = param.denormalize(value);
+        let state_update = StateUpdate::SetParameter(param, real_value);
         self.to_dsp.lock().unwrap().send(state_update).unwrap();
-        self.state_record.lock().unwrap()[index as usize] = value;
+        if param.shapes_ir() {
+            let message = IrMessage::SetParameter(param, real_value);
+            let _ = self.to_ir_worker.lock().unwrap().send(message);
+        }
+        self.state_record.lock().unwrap()[param.index()] = value;
+        if param.sets_latency() {
+            self.update_latency();
+        }
     }
 
     fn get_parameter(&self, index: i32) -> f32 {
-        self.state_record.lock().unwrap()[index as usize]
+        match Parameter::from_index(index) {
+            Some(param) => match param.meter() {
+                Some(meter) => param.normalize(self.meters.get(meter)),
+                None => self.state_record.lock().unwrap()[param.index()],
+            },
+            None => 0.,
+        }
+    }
+
+    fn can_be_automated(&self, index: i32) -> bool {
+        matches!(Parameter::from_index(index), Some(param) if param.meter().is_none())
     }
 
     fn get_parameter_label(&self, index: i32) -> String {
-        match index {
-            0 => "x".to_string(),
-            _ => unreachable!(),
+        match Parameter::from_index(index) {
+            Some(param) => param.info().label.to_string(),
+            None => String::new(),
         }
     }
 
     fn get_parameter_text(&self, index: i32) -> String {
-        match index {
-            0 => format!(
-                "{:.2}",
-                self.state_record.lock().unwrap()[index as usize]
-            ),
-            _ => unreachable!(),
+        match Parameter::from_index(index) {
+            Some(param) => param.display(self.real_value(param)),
+            None => String::new(),
         }
     }
 
     fn get_parameter_name(&self, index: i32) -> String {
-        match index {
-            0 => "Cutoff",
-            _ => unreachable!(),
+        match Parameter::from_index(index) {
+            Some(param) => param.info().name.to_string(),
+            None => String::new(),
         }
-        .to_string()
     }
 
     fn string_to_parameter(&self, index: i32, text: String) -> bool {
-        dbg!("Set string to parameter for {}, {}", index, &text);
-        match index {
-            0 => match text.parse::<f32>() {
-                Ok(value) if value <= 1. && value >= 0. => {
-                    self.set_parameter(index, value);
-                    true
-                }
-                _ => false,
-            },
-            _ => unreachable!(),
+        let param = match Parameter::from_index(index) {
+            Some(param) => param,
+            None => return false,
+        };
+        match param.parse(&text) {
+            Some(value) => {
+                self.set_parameter(index, param.normalize(value));
+                true
+            }
+            None => false,
         }
     }
 }
@@ -5,6 +5,7 @@ pub fn load_wav(file_name: &str) -> Vec<f32> {
   samples
 }
 
+#[allow(dead_code)]
 fn write_wav(buffer: &[f32], file_name: &str) {
   let spec = hound::WavSpec {
     channels: 1,
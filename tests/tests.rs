@@ -1,7 +1,1201 @@
 #[cfg(test)]
 mod tests {
+    use std::sync::{mpsc::channel, Arc};
+    use std::time::Duration;
+
+    use reverb::dsp::convolution::{mult_add_frames, partition, Convolver, IR_FADE_SAMPLES};
+    use reverb::dsp::decorrelate::decorrelate;
+    use reverb::dsp::drive::Drive;
+    use reverb::dsp::dynamic::{Dynamic, DynamicMode};
+    use reverb::dsp::engine::Engine;
+    use reverb::dsp::fdn::Fdn;
+    use reverb::dsp::ir_decay::{measure_decay, reshape_band_decay, reshape_decay, BandDecay};
+    use reverb::dsp::gate::{gate_latency, Gate};
+    use reverb::dsp::hybrid::{split_impulse_response, Hybrid};
+    use reverb::dsp::ir_trim::{trim_impulse_response, FadeShape, Trim};
+    use reverb::dsp::limiter::{limiter_latency, Limiter};
+    use reverb::dsp::multiband::{split_bands, Crossover};
+    use reverb::dsp::noise::Noise;
+    use reverb::dsp::physical_spring::{PhysicalSpring, SpringPhysics};
+    use reverb::dsp::oversampling::oversampling_latency;
+    use reverb::dsp::reverse::{reverse_impulse_response, reverse_latency, true_reverse_latency};
+    use reverb::dsp::spring_impulse_response::SPRING_IMPULSE_RESPONSE;
+    use reverb::dsp::spring_ir::{SpringIr, FFT_SIZE, WET_DIVISOR};
+    use reverb::dsp::spring_model::SpringModel;
+    use reverb::dsp::PluginDsp;
+    use reverb::plugin_state::{Meters, Parameter, PluginState, StateUpdate};
+    use reverb::dsp::smoother::{Ramp, Smoother};
+    use reverb::dsp::plate::Plate;
+    use reverb::dsp::velvet::{Velvet, VelvetDesign};
+    use reverb::dsp::wobble::Wobble;
+    use rustfft::{num_complex::Complex, FftPlanner};
+    use vst::plugin::{HostCallback, PluginParameters};
+
+    const SAMPLE_RATE: f32 = 44100.;
+    const BLOCK_SIZE: usize = 512;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    /// Runs a mono signal through an engine a block at a time, like a host would.
+    fn render(engine: &mut dyn Engine, input: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        let mut left = vec![0.; input.len()];
+        let mut right = vec![0.; input.len()];
+        for start in (0..input.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(input.len());
+            engine.process(
+                &input[start..end],
+                &input[start..end],
+                &mut left[start..end],
+                &mut right[start..end],
+            );
+        }
+        (left, right)
+    }
+
+    fn impulse(seconds: f32) -> Vec<f32> {
+        let mut signal = vec![0.; (seconds * SAMPLE_RATE) as usize];
+        signal[0] = 1.;
+        signal
+    }
+
+    fn energy(signal: &[f32]) -> f32 {
+        signal.iter().map(|sample| sample * sample).sum()
+    }
+
+    #[test]
+    fn plate_renders_identically_every_time() {
+        let input = impulse(2.);
+        let first = render(&mut Plate::new(SAMPLE_RATE), &input);
+        let second = render(&mut Plate::new(SAMPLE_RATE), &input);
+        assert!(energy(&first.0) > 0. && energy(&first.1) > 0.);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn plate_reset_replays_the_same_tail() {
+        let input = impulse(1.);
+        let mut plate = Plate::new(SAMPLE_RATE);
+        let first = render(&mut plate, &input);
+        plate.reset();
+        assert_eq!(render(&mut plate, &input), first);
+    }
+
+    #[test]
+    fn plate_stays_silent_without_input() {
+        let (left, right) = render(&mut Plate::new(SAMPLE_RATE), &vec![0.; 44100]);
+        assert!(left.iter().chain(right.iter()).all(|sample| *sample == 0.));
+    }
+
+    #[test]
+    fn plate_decay_sets_tail_length() {
+        let input = impulse(4.);
+        let late = |decay: f32| {
+            let mut plate = Plate::new(SAMPLE_RATE);
+            plate.set_decay(decay);
+            let (left, _) = render(&mut plate, &input);
+            let half_second = (SAMPLE_RATE / 2.) as usize;
+            energy(&left[left.len() - half_second..]) / energy(&left[..half_second])
+        };
+        let short = late(0.3);
+        let long = late(0.9);
+        assert!(short < 1e-6, "short tail still ringing: {}", short);
+        assert!(long > short * 1000., "long tail {} vs short {}", long, short);
+        assert!(long < 1.);
+    }
+
+    #[test]
+    fn limiter_holds_true_peaks_under_the_ceiling() {
+        // a loud tone at a quarter of the sample rate, sampled so every peak falls between two
+        // samples and the samples only reach 0.7 of it
+        let tone: Vec<f32> = (0..44100)
+            .map(|i| 4. * (i as f32 * std::f32::consts::FRAC_PI_2 + std::f32::consts::FRAC_PI_4).sin())
+            .collect();
+        let mut left = tone.clone();
+        let mut right = tone;
+        let mut limiter = Limiter::new(SAMPLE_RATE);
+        limiter.set_enabled(true);
+        limiter.set_ceiling_db(-1.);
+        for start in (0..left.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(left.len());
+            limiter.process(&mut left[start..end], &mut right[start..end]);
+        }
+        assert!(limiter.reduction_db() < -12., "reduction {}", limiter.reduction_db());
+
+        // measure the true peak of the settled output, 8x oversampled by sinc interpolation
+        let settled = &left[8192..16384];
+        let mut true_peak = 0f32;
+        for i in 256..settled.len() - 256 {
+            for step in 0..8 {
+                let t = i as f32 + step as f32 / 8.;
+                let mut value = 0.;
+                for (j, sample) in settled.iter().enumerate().take(i + 128).skip(i - 128) {
+                    let x = std::f32::consts::PI * (t - j as f32);
+                    let sinc = if x == 0. { 1. } else { x.sin() / x };
+                    let window = 0.5 + 0.5 * (x / 128.).cos();
+                    value += sample * sinc * window;
+                }
+                true_peak = true_peak.max(value.abs());
+            }
+        }
+        let ceiling = 10f32.powf(-1. / 20.);
+        assert!(true_peak < ceiling * 1.02, "true peak {} over ceiling {}", true_peak, ceiling);
+        assert!(true_peak > ceiling * 0.8, "limited too hard: {}", true_peak);
+    }
+
+    #[test]
+    fn smoothers_land_on_target_when_the_ramp_is_up() {
+        for ramp in [Ramp::Linear, Ramp::Exponential, Ramp::Multiplicative] {
+            let mut smoother = Smoother::new(ramp, 100., SAMPLE_RATE);
+            smoother.set_ramp_ms(10.);
+            smoother.set_target(400.);
+            // 441 samples in all, moved through both ways
+            let halfway = smoother.advance(220);
+            assert!(halfway > 100. && halfway < 400.);
+            for _ in 0..220 {
+                assert!(smoother.next_value() < 400.);
+            }
+            assert!(smoother.is_smoothing());
+            assert_eq!(smoother.next_value(), 400.);
+            assert!(!smoother.is_smoothing());
+        }
+
+        // equal ratios: any share of the time is the same share of the octaves
+        let mut smoother = Smoother::new(Ramp::Multiplicative, 100., SAMPLE_RATE);
+        smoother.set_ramp_ms(10.);
+        smoother.set_target(400.);
+        let expected = 100. * 4f32.powf(220. / 441.);
+        let halfway = smoother.advance(220);
+        assert!((halfway - expected).abs() < 0.01, "{} vs {}", halfway, expected);
+    }
+
+    /// Runs noise through the whole plugin with the same changes at the same samples, cut into
+    /// blocks of `block_size`. Parameter changes only land at the start of a block, so the
+    /// blocks are also cut where they happen, the way a host splits its blocks around automation.
+    fn render_automated(block_size: usize) -> (Vec<f32>, Vec<f32>) {
+        // (sample, parameter and value), with `None` for a kick
+        let automation = [
+            (1000, Some((Parameter::DriveEnabled, 1.))),
+            (3000, Some((Parameter::DriveAmount, 12.))),
+            (5003, None),
+            (7001, Some((Parameter::Engine, 4.))),
+            (9000, Some((Parameter::PlateDecay, 80.))),
+            (12345, Some((Parameter::Engine, 3.))),
+            (15000, Some((Parameter::RoomSize, 40.))),
+        ];
+        let mut noise = Noise::new(3);
+        let input: Vec<f32> = (0..20000).map(|_| 0.5 * noise.next_unipolar() - 0.25).collect();
+
+        let (to_dsp, from_params) = channel();
+        let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+        let mut left = vec![0.; input.len()];
+        let mut right = vec![0.; input.len()];
+        let mut start = 0;
+        while start < input.len() {
+            let mut end = (start + block_size).min(input.len());
+            for (sample, change) in automation.iter() {
+                match change {
+                    Some((param, value)) if *sample == start => {
+                        to_dsp.send(StateUpdate::SetParameter(*param, *value)).unwrap();
+                    }
+                    Some(_) if (start..end).contains(sample) => end = *sample,
+                    _ => (),
+                }
+            }
+            let kicks = automation.iter().filter(|(sample, change)| change.is_none() && (start..end).contains(sample));
+            for (sample, _) in kicks {
+                dsp.trigger_kick(sample - start, 1.);
+            }
+            let inputs = [&input[start..end], &input[start..end]];
+            dsp.process_block(&inputs, &mut left[start..end], &mut right[start..end]);
+            start = end;
+        }
+        (left, right)
+    }
+
+    #[test]
+    fn automation_renders_the_same_at_any_block_size() {
+        let expected = render_automated(BLOCK_SIZE);
+        assert!(energy(&expected.0[1000..]) > 0.);
+        for block_size in [441, 64, 17] {
+            assert!(render_automated(block_size) == expected, "block size {}", block_size);
+        }
+    }
+
+    /// Runs silence through the whole plugin, with clicks kicked at `kicks` (sample, velocity) and
+    /// the drive on, cut into blocks of `block_size`. Only the kicks are heard.
+    fn render_kicks(block_size: usize, kicks: &[(usize, f32)], dry: bool) -> Vec<f32> {
+        let (to_dsp, from_params) = channel();
+        let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+        let changes = [
+            (Parameter::DriveEnabled, 1.),
+            (Parameter::KickType, 0.),
+            (Parameter::KickDry, if dry { 1. } else { 0. }),
+        ];
+        for (param, value) in changes {
+            to_dsp.send(StateUpdate::SetParameter(param, value)).unwrap();
+        }
+        let input = vec![0.; 30000];
+        let (mut left, mut right) = (vec![0.; input.len()], vec![0.; input.len()]);
+        for start in (0..input.len()).step_by(block_size) {
+            let end = (start + block_size).min(input.len());
+            for (sample, velocity) in kicks.iter().filter(|(sample, _)| (start..end).contains(sample)) {
+                dsp.trigger_kick(sample - start, *velocity);
+            }
+            let block = &input[start..end];
+            dsp.process_block(&[block, block], &mut left[start..end], &mut right[start..end]);
+        }
+        left
+    }
+
+    #[test]
+    fn kicks_land_on_their_sample_and_scale_with_velocity_squared() {
+        let kicks = [(1001, 1.), (20007, 0.5)];
+        // the tank is fed the same either way, so what kick dry adds is the dry kick alone
+        let dry_kick = |block_size: usize| -> Vec<f32> {
+            let with = render_kicks(block_size, &kicks, true);
+            let without = render_kicks(block_size, &kicks, false);
+            assert!(energy(&without) > 0.);
+            with.iter().zip(&without).map(|(with, without)| with - without).collect()
+        };
+        let expected = dry_kick(BLOCK_SIZE);
+        // held back with everything that went through the drive's oversampling filters
+        let latency = oversampling_latency(4);
+        for (sample, velocity) in kicks {
+            let onset = sample + latency;
+            assert!(expected[onset - 100..onset].iter().all(|x| x.abs() < 1e-6), "kick at {} early", sample);
+            assert!((expected[onset] - velocity * velocity).abs() < 1e-4, "kick at {}: {}", sample, expected[onset]);
+        }
+        for block_size in [441, 64, 17, 1] {
+            let rendered = dry_kick(block_size);
+            let worst = rendered.iter().zip(&expected).map(|(a, b)| (a - b).abs()).fold(0., f32::max);
+            assert!(worst < 1e-5, "block size {}: off by {}", block_size, worst);
+        }
+    }
+
+    #[test]
+    fn a_crowded_block_keeps_only_its_latest_kicks() {
+        // all in one block, and more than can ring at once
+        let kicks: Vec<(usize, f32)> = (0..12).map(|k| (100 + 300 * k, 1.)).collect();
+        let crowded = render_kicks(4096, &kicks, true);
+        let latest = render_kicks(4096, &kicks[4..], true);
+        let worst = crowded.iter().zip(&latest).map(|(a, b)| (a - b).abs()).fold(0., f32::max);
+        assert!(worst < 1e-6, "the earliest kicks still played, off by {}", worst);
+    }
+
+    #[test]
+    fn shimmer_loop_is_the_same_length_at_any_block_size() {
+        let shimmer_with_blocks = |block_size: usize| {
+            let (to_dsp, from_params) = channel();
+            let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+            to_dsp.send(StateUpdate::SetParameter(Parameter::ShimmerEnabled, 1.)).unwrap();
+            // several trips round the loop, which is shorter than the longest blocks
+            let input = impulse(1.);
+            let (mut left, mut right) = (vec![0.; input.len()], vec![0.; input.len()]);
+            for start in (0..input.len()).step_by(block_size) {
+                let end = (start + block_size).min(input.len());
+                let block = &input[start..end];
+                dsp.process_block(&[block, block], &mut left[start..end], &mut right[start..end]);
+            }
+            left
+        };
+        let expected = shimmer_with_blocks(BLOCK_SIZE);
+        for block_size in [8192, 17] {
+            assert!(shimmer_with_blocks(block_size) == expected, "block size {}", block_size);
+        }
+    }
+
+    #[test]
+    fn reversed_ir_swells_up_to_the_latency() {
+        // a decaying tail, loudest at the start
+        let mut noise = Noise::new(5);
+        let tail: Vec<f32> = (0..SAMPLE_RATE as usize)
+            .map(|i| (2. * noise.next_unipolar() - 1.) * (-(i as f32) / 1500.).exp())
+            .collect();
+        let reversed = reverse_impulse_response(&tail, 150., SAMPLE_RATE);
+        let latency = reverse_latency(150., SAMPLE_RATE);
+        assert_eq!(reversed.len(), latency + 1);
+
+        let mut convolver = Convolver::new(&reversed, 1024);
+        let input = impulse(0.5);
+        let mut output = vec![0.; input.len()];
+        for (block, out) in input.chunks(BLOCK_SIZE).zip(output.chunks_mut(BLOCK_SIZE)) {
+            convolver.process(block, out);
+        }
+        let loudest = (0..output.len())
+            .max_by(|a, b| output[*a].abs().total_cmp(&output[*b].abs()))
+            .unwrap();
+        assert_eq!(loudest, latency);
+        // it builds up to the peak, and stops dead after it
+        assert!(energy(&output[latency / 2..latency]) > 10. * energy(&output[..latency / 2]));
+        assert!(energy(&output[latency + 1..]) < 1e-6 * energy(&output[..=latency]));
+    }
+
+    #[test]
+    fn true_reverse_latency_holds_still_and_lines_up() {
+        let (to_forward, from_state) = channel();
+        let state = PluginState::new(HostCallback::default(), to_forward, Arc::new(Meters::new()));
+        let set = |param: Parameter, value: f32| state.set_parameter(param.index() as i32, param.normalize(value));
+        set(Parameter::Reverse, 2.);
+        let latency = state.latency();
+        assert_eq!(latency, true_reverse_latency(SAMPLE_RATE));
+        // the length moves the swell, not the latency
+        set(Parameter::ReverseLength, 300.);
+        assert_eq!(state.latency(), latency);
+
+        // everything the state sends, the worker's reversed IR included, once it has settled
+        let (to_dsp, from_params) = channel();
+        let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+        while let Ok(update) = from_state.recv_timeout(Duration::from_secs(2)) {
+            to_dsp.send(update).unwrap();
+        }
+        // silence first, while the convolvers crossfade to the new IR
+        let silence = vec![0.; SAMPLE_RATE as usize / 2];
+        let (mut left, mut right) = (silence.clone(), silence.clone());
+        dsp.process_block(&[&silence, &silence], &mut left, &mut right);
+
+        let input = impulse(2.5);
+        let (mut left, mut right) = (vec![0.; input.len()], vec![0.; input.len()]);
+        for start in (0..input.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(input.len());
+            let block = &input[start..end];
+            dsp.process_block(&[block, block], &mut left[start..end], &mut right[start..end]);
+        }
+        // the reversed IR's loudest part sits just ahead of its end, which is padded onto the latency
+        let loudest = (0..left.len()).max_by(|a, b| left[*a].abs().total_cmp(&left[*b].abs())).unwrap();
+        assert!(loudest <= latency && loudest > latency - (0.02 * SAMPLE_RATE) as usize, "{} vs {}", loudest, latency);
+        assert!(energy(&left[..latency - (0.3 * SAMPLE_RATE) as usize]) < 1e-6 * energy(&left));
+        assert!(energy(&left[latency + 1..]) < 1e-6 * energy(&left));
+    }
+
+    #[test]
+    fn trimmed_ir_fades_out_and_drops_partitions() {
+        let ir = vec![1.; SAMPLE_RATE as usize];
+        for fade_shape in [FadeShape::Linear, FadeShape::Cosine, FadeShape::Exponential] {
+            let trim = Trim { start: 100., length: 200., fade: 50., fade_shape };
+            let trimmed = trim_impulse_response(&ir, &trim, SAMPLE_RATE);
+            assert_eq!(trimmed.len(), 8820);
+            // ramps in over the cut start, holds, then fades down onto silence
+            assert_eq!(trimmed[0], 0.);
+            assert_eq!(trimmed[4000], 1.);
+            assert!(trimmed[8820 - 2205..].windows(2).all(|pair| pair[1] <= pair[0]));
+            assert_eq!(trimmed[8819], 0.);
+            assert_eq!(partition(&trimmed, 1024).len(), 18);
+        }
+
+        // an IR that's already shorter is left to end by itself
+        let untouched = trim_impulse_response(&ir, &Trim::default(), SAMPLE_RATE);
+        assert_eq!(untouched, ir);
+    }
+
+    #[test]
+    fn early_level_turns_down_only_the_first_bounces() {
+        let input = impulse(1.);
+        let (whole, _) = render(&mut SpringIr::new(SAMPLE_RATE), &input);
+        let mut spring = SpringIr::new(SAMPLE_RATE);
+        spring.set_early_level_db(-48.);
+        let (late, _) = render(&mut spring, &input);
+
+        // the split is at 80ms, faded over 20ms
+        let early_end = (0.07 * SAMPLE_RATE) as usize;
+        let late_start = (0.09 * SAMPLE_RATE) as usize;
+        assert!(energy(&whole[..early_end]) > 0.);
+        assert!(energy(&late[..early_end]) < 1e-3 * energy(&whole[..early_end]));
+        let difference: Vec<f32> = late[late_start..].iter().zip(&whole[late_start..]).map(|(a, b)| a - b).collect();
+        assert!(energy(&difference) < 1e-6 * energy(&whole[late_start..]));
+    }
+
+    #[test]
+    fn crossover_bands_sum_back_flat() {
+        let input = impulse(0.5);
+        let mut crossover = Crossover::new(SAMPLE_RATE);
+        crossover.set_band_count(4);
+        let mut sum = vec![0.; input.len()];
+        for start in (0..input.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(input.len());
+            let mut bands = vec![vec![0.; end - start]; 4];
+            crossover.process(&input[start..end], &mut bands);
+            for band in bands {
+                for (out, sample) in sum[start..end].iter_mut().zip(band) {
+                    *out += sample;
+                }
+            }
+        }
+
+        // the bands are phase matched, so they add back up to an allpass
+        let mut spectrum: Vec<Complex<f32>> = sum.iter().map(|sample| Complex { re: *sample, im: 0. }).collect();
+        FftPlanner::new().plan_fft_forward(spectrum.len()).process(&mut spectrum);
+        for bin in &spectrum[1..spectrum.len() / 2] {
+            assert!((bin.norm() - 1.).abs() < 0.01);
+        }
+
+        // the linear phase split adds back up to exactly what went in
+        let mut noise = Noise::new(7);
+        let signal: Vec<f32> = (0..4096).map(|_| noise.next_unipolar() - 0.5).collect();
+        let bands = split_bands(&signal, &[200., 1200., 5000.], SAMPLE_RATE);
+        for (i, sample) in signal.iter().enumerate() {
+            let summed: f32 = bands.iter().map(|band| band[i]).sum();
+            assert!((summed - sample).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn parameters_keep_their_host_indices() {
+        for (index, param) in Parameter::ALL.iter().enumerate() {
+            assert_eq!(param.index(), index);
+        }
+        // the first and last of the original gate parameters, which must never move
+        assert_eq!(Parameter::GateEnabled.index(), 0);
+        assert_eq!(Parameter::GateRange.index(), 6);
+    }
+
+    #[test]
+    fn parameter_names_tell_them_apart() {
+        for (index, param) in Parameter::ALL.iter().enumerate() {
+            let name = param.info().name;
+            let clash = Parameter::ALL[..index].iter().find(|other| other.info().name == name);
+            assert!(clash.is_none(), "two parameters are called {:?}", name);
+        }
+    }
+
+    #[test]
+    fn gate_opens_ahead_of_a_transient_and_closes_after_the_hold() {
+        let mut gate = Gate::new(SAMPLE_RATE);
+        gate.set_enabled(true);
+        gate.set_threshold_db(-24.);
+        gate.set_lookahead_ms(2.);
+        gate.set_hold_ms(50.);
+        gate.set_release_ms(5.);
+        gate.set_range_db(-90.);
+        let latency = gate_latency(SAMPLE_RATE);
+        assert_eq!(gate.latency(), latency);
+
+        // a burst on the key a tenth of a second in, over a steady wet signal
+        let (onset, burst) = (4410, 441);
+        let key: Vec<f32> = (0..SAMPLE_RATE as usize).map(|i| if (onset..onset + burst).contains(&i) { 1. } else { 0. }).collect();
+        let (mut wet_l, mut wet_r) = (vec![0.5; key.len()], vec![0.5; key.len()]);
+        gate.process(&key, &key, &mut wet_l, &mut wet_r);
+
+        // the held back signal has the gate fully open by the time the burst comes out of it,
+        // having started opening a lookahead earlier
+        let (lookahead, hold) = (88, 2205);
+        let arrival = onset + latency;
+        assert!(wet_l[..arrival - lookahead].iter().all(|sample| *sample == 0.));
+        assert!(wet_l[arrival - lookahead / 2] > 0. && wet_l[arrival - lookahead / 2] < 0.5);
+        assert_eq!(wet_l[arrival], 0.5);
+        // and it holds a lookahead short of the hold past the burst, then lets go
+        let end = arrival + burst;
+        assert!(wet_l[arrival..end + hold - lookahead].iter().all(|sample| *sample == 0.5));
+        assert!(wet_l[end + hold - lookahead + 2] < 0.5);
+        assert!(wet_l[end + hold + 2205] < 1e-3);
+    }
+
+    /// The gate's gain on the whole plugin's output, after a burst a tenth of a second in, with the
+    /// drive at 8x or off.
+    fn gate_gain(drive: bool) -> Vec<f32> {
+        let render = |gate: bool| {
+            let (to_dsp, from_params) = channel();
+            let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+            let changes = [
+                (Parameter::DriveEnabled, if drive { 1. } else { 0. }),
+                (Parameter::DriveOversampling, 3.),
+                (Parameter::GateEnabled, if gate { 1. } else { 0. }),
+                (Parameter::GateHold, 50.),
+                (Parameter::GateRelease, 5.),
+            ];
+            for (param, value) in changes {
+                to_dsp.send(StateUpdate::SetParameter(param, value)).unwrap();
+            }
+            let mut noise = Noise::new(5);
+            let input: Vec<f32> = (0..SAMPLE_RATE as usize / 2)
+                .map(|i| if (4410..4851).contains(&i) { noise.next_unipolar() - 0.5 } else { 0. })
+                .collect();
+            let (mut left, mut right) = (vec![0.; input.len()], vec![0.; input.len()]);
+            for start in (0..input.len()).step_by(BLOCK_SIZE) {
+                let end = (start + BLOCK_SIZE).min(input.len());
+                let block = &input[start..end];
+                dsp.process_block(&[block, block], &mut left[start..end], &mut right[start..end]);
+            }
+            left
+        };
+        // the gate holds the wet back by its lookahead
+        let (gated, open) = (render(true), render(false));
+        let gated = &gated[gate_latency(SAMPLE_RATE)..];
+        gated.iter().zip(&open).map(|(gated, open)| if *open == 0. { 0. } else { gated / open }).collect()
+    }
+
+    #[test]
+    fn gate_key_waits_out_the_drive_oversampling() {
+        // the drive holds the tail back, so the gate has to let go that much later too
+        let letting_go = |gain: &[f32]| gain.iter().rposition(|gain| *gain > 0.5).unwrap();
+        let (dry, driven) = (letting_go(&gate_gain(false)), letting_go(&gate_gain(true)));
+        assert_eq!(driven - dry, oversampling_latency(8));
+    }
+
+    #[test]
+    fn limiter_delay_is_reported_as_latency() {
+        let mut limiter = Limiter::new(SAMPLE_RATE);
+        limiter.set_enabled(true);
+        let mut left = impulse(0.1);
+        left[0] = 0.5;
+        let mut right = left.clone();
+        limiter.process(&mut left, &mut right);
+        let latency = limiter_latency(SAMPLE_RATE);
+        assert_eq!(left.iter().position(|sample| *sample != 0.), Some(latency));
+
+        // off by default, so the plugin only reports it once it's on
+        let (to_dsp, _from_params) = channel();
+        let state = PluginState::new(HostCallback::default(), to_dsp, Arc::new(Meters::new()));
+        assert_eq!(state.latency(), 0);
+        let param = Parameter::LimiterEnabled;
+        state.set_parameter(param.index() as i32, 1.);
+        assert_eq!(state.latency(), latency);
+        state.set_parameter(param.index() as i32, 0.);
+        assert_eq!(state.latency(), 0);
+    }
+
+    #[test]
+    fn drive_oversampling_delay_is_reported_as_latency() {
+        let mut drive = Drive::new();
+        drive.set_enabled(true);
+        for factor in [1, 2, 4, 8] {
+            drive.set_oversampling(factor);
+            let mut left = impulse(0.05);
+            // quiet enough that the curve is close to a straight line
+            left[0] = 0.01;
+            let mut right = left.clone();
+            drive.process(&mut left, &mut right);
+            let peak = (0..left.len()).max_by(|&a, &b| left[a].abs().total_cmp(&left[b].abs()));
+            assert_eq!(drive.latency(), oversampling_latency(factor));
+            assert_eq!(peak, Some(drive.latency()));
+        }
+
+        // off by default, and reported at the 4x it starts at once it's on
+        let (to_dsp, _from_params) = channel();
+        let state = PluginState::new(HostCallback::default(), to_dsp, Arc::new(Meters::new()));
+        let base = state.latency();
+        state.set_parameter(Parameter::DriveEnabled.index() as i32, 1.);
+        assert_eq!(state.latency(), base + oversampling_latency(4));
+        state.set_parameter(Parameter::DriveOversampling.index() as i32, 0.);
+        assert_eq!(state.latency(), base);
+    }
+
+    #[test]
+    fn convolver_matches_direct_convolution() {
+        // the spectra multiply as complex numbers, one sign slip and it all goes wrong
+        let x = [Complex::new(0.5, -1.), Complex::new(-2., 0.25)];
+        let h = [Complex::new(1.5, 2.), Complex::new(0.75, -3.)];
+        let start = [Complex::new(0.25, 0.5), Complex::new(-1., 1.)];
+        let mut product = start;
+        mult_add_frames(&mut product, &x, &h);
+        for i in 0..x.len() {
+            assert!((product[i] - start[i] - x[i] * h[i]).norm() < 1e-6);
+        }
+
+        let mut noise = Noise::new(7);
+        let mut bipolar = |len: usize| (0..len).map(|_| noise.next_unipolar() * 2. - 1.).collect::<Vec<f32>>();
+        // a few segments long, so the head and the partitioned tail both take part
+        let ir = bipolar(700);
+        let input = bipolar(3000);
+        let fft_size = 256;
+        let mut convolver = Convolver::new(&ir, fft_size);
+        let mut output = vec![0.; input.len()];
+        for (block, out) in input.chunks(100).zip(output.chunks_mut(100)) {
+            convolver.process(block, out);
+        }
+
+        for (n, sample) in output.iter().enumerate() {
+            let direct: f32 = ir.iter().take(n + 1).enumerate().map(|(k, tap)| tap * input[n - k]).sum();
+            // the IFFT isn't normalized, so everything comes out `fft_size` times louder
+            let sample = sample / fft_size as f32;
+            assert!((sample - direct).abs() < 1e-3, "sample {} is {} not {}", n, sample, direct);
+        }
+    }
+
+    #[test]
+    fn convolver_reset_forgets_the_input_and_keeps_the_ir() {
+        let mut noise = Noise::new(3);
+        let mut bipolar = |len: usize| (0..len).map(|_| noise.next_unipolar() * 2. - 1.).collect::<Vec<f32>>();
+        let ir = bipolar(2000);
+        let input = bipolar(4000);
+        let mut convolver = Convolver::new(&ir, 256);
+        let mut fresh = vec![0.; input.len()];
+        convolver.process(&input, &mut fresh);
+
+        // stop part way into a segment, with the tail still ringing
+        let mut discard = vec![0.; 1000];
+        convolver.process(&input[..1000], &mut discard);
+        convolver.reset();
+        let mut after_reset = vec![0.; input.len()];
+        for (block, out) in input.chunks(100).zip(after_reset.chunks_mut(100)) {
+            convolver.process(block, out);
+        }
+        for (n, (a, b)) in fresh.iter().zip(&after_reset).enumerate() {
+            assert!((a - b).abs() < 1e-2, "sample {} is {} after a reset, not {}", n, b, a);
+        }
+    }
+
+    #[test]
+    fn convolver_swaps_in_a_longer_ir_over_the_whole_history_and_hands_the_old_one_back() {
+        let mut noise = Noise::new(11);
+        let mut bipolar = |len: usize| (0..len).map(|_| noise.next_unipolar() * 2. - 1.).collect::<Vec<f32>>();
+        let short = bipolar(300);
+        let long = bipolar(1500);
+        let input = bipolar(6000);
+        let fft_size = 256;
+        let mut convolver = Convolver::new(&short, fft_size);
+        convolver.set_max_ir_len(long.len());
+        let swap = 2000;
+        let mut output = vec![0.; input.len()];
+        convolver.process(&input[..swap], &mut output[..swap]);
+        convolver.set_ir(partition(&long, fft_size));
+        for (block, out) in input[swap..].chunks(100).zip(output[swap..].chunks_mut(100)) {
+            convolver.process(block, out);
+        }
+
+        let retired = convolver.take_retired().expect("the short IR wasn't handed back");
+        assert_eq!(retired.len(), partition(&short, fft_size).len());
+        assert!(convolver.take_retired().is_none());
+        // once the fade is done, the long IR rings out from input played before the swap too
+        for n in swap + IR_FADE_SAMPLES..input.len() {
+            let direct: f32 = long.iter().take(n + 1).enumerate().map(|(k, tap)| tap * input[n - k]).sum();
+            let sample = output[n] / fft_size as f32;
+            assert!((sample - direct).abs() < 1e-3, "sample {} is {} not {}", n, sample, direct);
+        }
+    }
+
+    /// A velvet engine's impulse response, on taps laid out for `design`.
+    fn velvet_impulse_response(design: VelvetDesign, seconds: f32) -> Vec<f32> {
+        let mut velvet = Velvet::new(SAMPLE_RATE);
+        velvet.set_taps(design.build(SAMPLE_RATE));
+        // skips the crossfade from the default taps
+        velvet.reset();
+        render(&mut velvet, &impulse(seconds)).0
+    }
+
+    #[test]
+    fn hybrid_hands_over_at_the_crossover_without_a_gap_or_a_step() {
+        let ir = SPRING_IMPULSE_RESPONSE;
+        // what the convolvers would make of the whole IR, see `SpringIr`
+        let scale = FFT_SIZE as f32 / WET_DIVISOR;
+        let window = (0.01 * SAMPLE_RATE) as usize;
+        for crossover_ms in [40., 80., 150., 300.] {
+            let mut hybrid = Hybrid::new(SAMPLE_RATE);
+            hybrid.set_tail(split_impulse_response(ir, ir, crossover_ms, SAMPLE_RATE));
+            // skips the crossfade from the default split
+            hybrid.reset();
+            let (output, _) = render(&mut hybrid, &impulse(0.5));
+            // at an even balance the early section stands in for the IR right up to the tail
+            let crossover = (crossover_ms * 0.001 * SAMPLE_RATE) as usize;
+            for start in (crossover - 3 * window..crossover + 3 * window).step_by(window) {
+                let expected = energy(&ir[start..start + window]) * scale * scale;
+                let level = 10. * (energy(&output[start..start + window]) / expected).log10();
+                assert!(level.abs() < 6., "{}dB off the IR {}ms into a {}ms crossover", level, start as f32 * 1000. / SAMPLE_RATE, crossover_ms);
+            }
+        }
+    }
+
+    #[test]
+    fn hybrid_swaps_its_reflections_and_tail_on_the_same_block() {
+        let ir = SPRING_IMPULSE_RESPONSE;
+        let mut hybrid = Hybrid::new(SAMPLE_RATE);
+        // the placeholders `new` swapped out
+        while hybrid.take_retired_ir().is_some() || hybrid.take_retired_taps().is_some() {}
+        hybrid.set_tail(split_impulse_response(ir, ir, 40., SAMPLE_RATE));
+        // lands mid-fade, so it waits for the next block after it
+        hybrid.set_tail(split_impulse_response(ir, ir, 150., SAMPLE_RATE));
+        let (mut irs, mut taps) = (0, 0);
+        // blocks that don't line up with the fades
+        let input = vec![0.; 500];
+        let (mut left, mut right) = (vec![0.; 500], vec![0.; 500]);
+        for block in 0..20 {
+            hybrid.process(&input, &input, &mut left, &mut right);
+            while hybrid.take_retired_ir().is_some() {
+                irs += 1;
+            }
+            while hybrid.take_retired_taps().is_some() {
+                taps += 1;
+            }
+            // both sides' IRs go with each set of reflections
+            assert_eq!(irs, 2 * taps, "the tail and the reflections fell out of step by block {}", block);
+        }
+        assert_eq!(taps, 2);
+    }
+
+    #[test]
+    fn velvet_decay_sets_the_tail_length() {
+        let window = (0.02 * SAMPLE_RATE) as usize;
+        for decay_seconds in [1., 2.] {
+            // without damping both bands share one envelope
+            let design = VelvetDesign { decay_seconds, damping: 0., density: 1500. };
+            let output = velvet_impulse_response(design, decay_seconds + 0.5);
+            let halfway = (decay_seconds * 0.5 * SAMPLE_RATE) as usize;
+            let drop = 10. * (energy(&output[halfway..halfway + window]) / energy(&output[..window])).log10();
+            // 60dB over the whole decay, so half way down
+            assert!((drop + 30.).abs() < 3., "{}s tail is {}dB down half way", decay_seconds, drop);
+            let end = (decay_seconds * SAMPLE_RATE) as usize;
+            assert!(output[end..].iter().all(|sample| *sample == 0.));
+        }
+    }
+
+    #[test]
+    fn velvet_density_sets_the_pulse_rate() {
+        let window = 0.05;
+        for density in [500., 2000.] {
+            let design = VelvetDesign { decay_seconds: 1.5, damping: 0., density };
+            let output = velvet_impulse_response(design, 0.1);
+            // every pulse lands on a sample of its own
+            let pulses = output[..(window * SAMPLE_RATE) as usize].iter().filter(|sample| **sample != 0.).count();
+            let expected = density * window;
+            assert!((pulses as f32 - expected).abs() <= expected * 0.1 + 1., "{} pulses at {}/s", pulses, density);
+        }
+    }
+
+    #[test]
+    fn velvet_hands_back_swapped_taps_and_resets_to_silence() {
+        let mut velvet = Velvet::new(SAMPLE_RATE);
+        let short = VelvetDesign { decay_seconds: 0.5, ..VelvetDesign::default() };
+        velvet.set_taps(short.build(SAMPLE_RATE));
+        // lands mid-fade, so it waits its turn
+        velvet.set_taps(short.build(SAMPLE_RATE));
+        render(&mut velvet, &vec![0.; 3 * 2048]);
+        // the default taps, then the first short ones, each once its fade was done
+        let mut retired = 0;
+        while velvet.take_retired().is_some() {
+            retired += 1;
+        }
+        assert_eq!(retired, 2);
+
+        let first = render(&mut velvet, &impulse(0.6));
+        velvet.reset();
+        let second = render(&mut velvet, &impulse(0.6));
+        assert!(first == second, "reset left the history ringing");
+        assert!(velvet.take_retired().is_none());
+    }
+
+    /// How long the whole plugin's tail takes to fall 60dB after an impulse, with `changes` made,
+    /// at `tempo`. Measured on the late tail, a second to two seconds in, past the early echoes.
+    fn synced_rt60(changes: &[(Parameter, f32)], tempo: f32) -> f32 {
+        let (to_dsp, from_params) = channel();
+        let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+        for (param, value) in changes {
+            to_dsp.send(StateUpdate::SetParameter(*param, *value)).unwrap();
+        }
+        dsp.set_tempo(tempo);
+        let input = impulse(2.1);
+        let (mut left, mut right) = (vec![0.; input.len()], vec![0.; input.len()]);
+        for start in (0..input.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(input.len());
+            let block = &input[start..end];
+            dsp.process_block(&[block, block], &mut left[start..end], &mut right[start..end]);
+        }
+        let window = |seconds: f32| {
+            let start = (seconds * SAMPLE_RATE) as usize;
+            energy(&left[start..start + BLOCK_SIZE * 8])
+        };
+        60. / (10. * (window(1.) / window(2.)).log10())
+    }
+
+    #[test]
+    fn synced_decays_follow_the_tempo() {
+        // locked to a whole note, 2s at 120bpm and 4s at 60bpm
+        // the room's low and high RT60s keep their ratio to the mid, so even them out to measure it
+        let room = [
+            (Parameter::Engine, 3.),
+            (Parameter::RoomRt60Low, 1.6),
+            (Parameter::RoomRt60High, 1.6),
+            (Parameter::RoomDecaySync, 1.),
+        ];
+        let plate = [(Parameter::Engine, 4.), (Parameter::PlateDamping, 0.), (Parameter::PlateDecaySync, 1.)];
+        for changes in [&room[..], &plate[..]] {
+            let (fast, slow) = (synced_rt60(changes, 120.), synced_rt60(changes, 60.));
+            assert!((fast - 2.).abs() < 0.3, "{}s at 120bpm", fast);
+            assert!((slow / fast - 2.).abs() < 0.2, "{}s at 60bpm, {}s at 120bpm", slow, fast);
+        }
+    }
+
+    #[test]
+    fn physical_spring_stays_finite_and_decays_at_the_extremes() {
+        // down at 8kHz the stiffest springs are past what the most steps per sample can carry
+        for sample_rate in [SAMPLE_RATE, 8000.] {
+            for wire_radius_mm in [0.1, 0.5] {
+                for coil_radius_mm in [2., 8.] {
+                    for length_cm in [5., 30.] {
+                        for decay_seconds in [0.5, 8.] {
+                            let physics = SpringPhysics { wire_radius_mm, coil_radius_mm, length_cm, decay_seconds };
+                            let mut spring = PhysicalSpring::new(sample_rate);
+                            spring.set_meshes(physics.build_realtime(sample_rate));
+                            // skips the crossfade from the default meshes
+                            spring.reset();
+                            let mut input = vec![0.; sample_rate as usize];
+                            input[0] = 1.;
+                            let (left, _) = render(&mut spring, &input);
+                            let quarter = left.len() / 4;
+                            assert!(left.iter().all(|sample| sample.is_finite()));
+                            assert!(
+                                energy(&left[3 * quarter..]) < energy(&left[..quarter]),
+                                "{}mm wire, {}mm coil, {}cm, {}s at {}Hz",
+                                wire_radius_mm,
+                                coil_radius_mm,
+                                length_cm,
+                                decay_seconds,
+                                sample_rate
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spring_model_chirp_follows_the_tension_at_any_sample_rate() {
+        // energy weighted arrival time of the first pass through the dispersion, before any echo
+        let first_pass_arrival = |sample_rate: f32, tension: f32| {
+            let mut spring = SpringModel::new(sample_rate);
+            spring.set_length_ms(200.);
+            spring.set_tension(tension);
+            let mut input = vec![0.; (3. * sample_rate) as usize];
+            input[0] = 1.;
+            let (left, right) = render(&mut spring, &input);
+            assert!(left.iter().chain(right.iter()).all(|sample| sample.is_finite()));
+            let half_second = (sample_rate / 2.) as usize;
+            let late = energy(&left[left.len() - half_second..]) / energy(&left[..half_second]);
+            assert!(late < 1e-3, "still ringing at {} Hz, tension {}: {}", sample_rate, tension, late);
+
+            let first_pass = &left[..(0.15 * sample_rate) as usize];
+            let weighted: f32 = first_pass.iter().enumerate().map(|(i, sample)| i as f32 * sample * sample).sum();
+            weighted / energy(first_pass) / sample_rate
+        };
+        // tighter springs chirp faster, and the chirp takes as long in seconds at any sample rate
+        let slack = first_pass_arrival(48000., 0.);
+        let tight = first_pass_arrival(48000., 1.);
+        assert!(tight < slack * 0.5, "tight {} vs slack {}", tight, slack);
+        for (tension, at_48k) in [(0., slack), (1., tight)] {
+            let at_192k = first_pass_arrival(192000., tension);
+            assert!((at_192k / at_48k - 1.).abs() < 0.15, "tension {}: {} at 192 kHz vs {} at 48 kHz", tension, at_192k, at_48k);
+        }
+    }
+
+    #[test]
+    fn physical_spring_keeps_ringing_through_a_mesh_swap() {
+        let mut spring = PhysicalSpring::new(SAMPLE_RATE);
+        let input = impulse(1.);
+        let swap = input.len() / 2;
+        let (before, _) = render(&mut spring, &input[..swap]);
+        let longer = SpringPhysics { length_cm: 20., ..SpringPhysics::default() };
+        spring.set_meshes(longer.build_realtime(SAMPLE_RATE));
+        let (after, _) = render(&mut spring, &input[swap..]);
+        // the old spring carries on under the fade, rather than dropping out on the swap
+        let window = 256;
+        let ratio = energy(&after[..window]) / energy(&before[swap - window..]);
+        assert!(ratio > 0.5 && ratio < 2., "{} times the energy over the swap", ratio);
+        assert!(after.iter().all(|sample| sample.is_finite()));
+    }
+
+    #[test]
+    fn wobble_passes_straight_through_once_off() {
+        let mut noise = Noise::new(3);
+        let input: Vec<f32> = (0..SAMPLE_RATE as usize / 2).map(|_| 2. * noise.next_unipolar() - 1.).collect();
+        let mut wobble = Wobble::new(SAMPLE_RATE);
+        let (mut left, mut right) = (input.clone(), input.clone());
+        wobble.process(&mut left, &mut right);
+        assert_eq!(left, input);
+        assert_eq!(right, input);
+
+        wobble.set_enabled(true);
+        wobble.process(&mut left, &mut right);
+        assert_ne!(left, input);
+
+        // once the depth has glided out, nothing is delayed at all
+        wobble.set_enabled(false);
+        let (mut left, mut right) = (input.clone(), input.clone());
+        wobble.process(&mut left, &mut right);
+        let (mut left, mut right) = (input.clone(), input.clone());
+        wobble.process(&mut left, &mut right);
+        assert_eq!(left, input);
+        assert_eq!(right, input);
+    }
+
+    // how far a sine of `omega` radians per sample, started at sample 0, trails behind at each
+    // sample of `output`, read off its phase. the first and last samples are left out. the phase
+    // only tells the delay to within a period, so it's followed on from no delay at the start
+    fn sine_delays(output: &[f32], omega: f32) -> Vec<f32> {
+        let period = std::f32::consts::TAU / omega;
+        let mut delay = 0.;
+        (1..output.len() - 1)
+            .map(|n| {
+                let cos = (output[n + 1] - output[n - 1]) / (2. * omega.sin());
+                let measured = n as f32 - output[n].atan2(cos) / omega;
+                delay += (measured - delay + 0.5 * period).rem_euclid(period) - 0.5 * period;
+                delay
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wobble_swings_the_delay_a_quarter_cycle_apart_and_glides_in() {
+        // a whole number of samples in a quarter of the default 1.5Hz cycle, and a plain sine with
+        // no random walk, so the swing can be checked against where it should be. the higher the
+        // tone, the less the swing's pitch bend throws off the delay read off it
+        let omega = std::f32::consts::TAU * 1000. / SAMPLE_RATE;
+        let input: Vec<f32> = (0..(2. * SAMPLE_RATE) as usize).map(|n| 0.5 * (omega * n as f32).sin()).collect();
+        let mut wobble = Wobble::new(SAMPLE_RATE);
+        wobble.set_randomness(0.);
+        let (mut left, mut right) = (input.clone(), input.clone());
+        let enable = SAMPLE_RATE as usize / 4;
+        wobble.process(&mut left[..enable], &mut right[..enable]);
+        wobble.set_enabled(true);
+        for start in (enable..input.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(input.len());
+            wobble.process(&mut left[start..end], &mut right[start..end]);
+        }
+
+        // no step where it comes on, the sine moves on no faster than it does anyway
+        let largest_step = |signal: &[f32]| signal.windows(2).map(|pair| (pair[1] - pair[0]).abs()).fold(0., f32::max);
+        let around = enable - 100..enable + 100;
+        assert!(largest_step(&left[around.clone()]) <= largest_step(&input) * 1.05);
+        assert!(largest_step(&right[around]) <= largest_step(&input) * 1.05);
+
+        let (delays_l, delays_r) = (sine_delays(&left, omega), sine_delays(&right, omega));
+        // from the one sample it needs to read between samples, the depth glides in and the delay
+        // never jumps
+        for delays in [&delays_l, &delays_r] {
+            assert!(delays[enable..enable + 10].iter().all(|delay| (delay - 1.).abs() < 0.2));
+            let jump = delays[enable..].windows(2).map(|pair| (pair[1] - pair[0]).abs()).fold(0., f32::max);
+            assert!(jump < 0.1, "delay jumps by {} samples", jump);
+        }
+
+        // once it has, the delay swings between 1 sample and 1 sample plus twice the 1ms depth
+        let depth = 0.001 * SAMPLE_RATE;
+        let settled = (SAMPLE_RATE as usize).min(delays_l.len());
+        for delays in [&delays_l[settled..], &delays_r[settled..]] {
+            let (shortest, longest) = delays.iter().fold((f32::MAX, f32::MIN), |(lo, hi), d| (lo.min(*d), hi.max(*d)));
+            assert!(shortest > 0.5 && shortest < 1.5, "shortest delay {}", shortest);
+            assert!(longest < 1. + 2. * depth + 0.5 && longest > 2. * depth + 0.5, "longest delay {}", longest);
+        }
+        // the right side is where the left will be a quarter cycle on
+        let quarter = (SAMPLE_RATE / 1.5 / 4.) as usize;
+        let apart = delays_r[settled..delays_r.len() - quarter]
+            .iter()
+            .zip(&delays_l[settled + quarter..])
+            .map(|(right, left)| (right - left).abs())
+            .fold(0., f32::max);
+        assert!(apart < 0.5, "sides off a quarter cycle by up to {} samples", apart);
+    }
+
+    // energy in octave bands from 172Hz up to nyquist. lower down a decorrelation frame only has
+    // a couple of bins per octave, so the energy there swings from seed to seed
+    fn octave_energies(signal: &[f32]) -> Vec<f32> {
+        let size = signal.len().next_power_of_two();
+        let mut spectrum: Vec<Complex<f32>> =
+            signal.iter().map(|&re| Complex { re, im: 0. }).collect();
+        spectrum.resize(size, Complex { re: 0., im: 0. });
+        FftPlanner::new().plan_fft_forward(size).process(&mut spectrum);
+        let mut edges = vec![size / 256];
+        while *edges.last().unwrap() < size / 2 {
+            edges.push(edges.last().unwrap() * 2);
+        }
+        edges
+            .windows(2)
+            .map(|band| spectrum[band[0]..band[1]].iter().map(|bin| bin.norm_sqr()).sum())
+            .collect()
+    }
+
+    #[test]
+    fn decorrelated_sides_keep_the_spectrum_and_the_decay() {
+        // dark, decaying noise, so there's a slope in both to keep
+        let mut noise = Noise::new(11);
+        let mut low_passed = 0.;
+        let ir: Vec<f32> = (0..SAMPLE_RATE as usize)
+            .map(|i| {
+                low_passed += 0.2 * (2. * noise.next_unipolar() - 1. - low_passed);
+                low_passed * (-(i as f32) / (0.2 * SAMPLE_RATE)).exp()
+            })
+            .collect();
+        let left = decorrelate(&ir, 1., 1);
+        let right = decorrelate(&ir, 1., 2);
+
+        let correlation = left.iter().zip(&right).map(|(l, r)| l * r).sum::<f32>()
+            / (energy(&left) * energy(&right)).sqrt();
+        assert!(correlation.abs() < 0.2, "correlation {}", correlation);
+
+        let db = |a: f32, b: f32| 10. * (a / b).log10();
+        for side in [&left, &right] {
+            for (band, (original, decorrelated)) in
+                octave_energies(&ir).into_iter().zip(octave_energies(side)).enumerate()
+            {
+                let difference = db(decorrelated, original);
+                assert!(difference.abs() < 1.5, "octave {} off by {}dB", band, difference);
+            }
+            let window = SAMPLE_RATE as usize / 10;
+            for (original, decorrelated) in ir.chunks(window).zip(side.chunks(window)).take(6) {
+                let difference = db(energy(decorrelated), energy(original));
+                assert!(difference.abs() < 1., "decay off by {}dB", difference);
+            }
+        }
+    }
+
+    // noise dying away at `rt60`, sitting on a floor 60dB down, so the real tail runs out part way
+    fn decaying_noise(rt60: f32, seconds: f32, seed: u32) -> Vec<f32> {
+        let mut noise = Noise::new(seed);
+        (0..(seconds * SAMPLE_RATE) as usize)
+            .map(|i| {
+                let level = 10f32.powf(-3. * i as f32 / SAMPLE_RATE / rt60) + 1e-3;
+                (2. * noise.next_unipolar() - 1.) * level
+            })
+            .collect()
+    }
+
+    // the decay rate measured straight from the level of two 100ms windows, in dB per second
+    fn decay_rate(signal: &[f32], from: f32, to: f32) -> f32 {
+        let window = |seconds: f32| {
+            let start = (seconds * SAMPLE_RATE) as usize;
+            energy(&signal[start..start + SAMPLE_RATE as usize / 10])
+        };
+        10. * (window(from) / window(to)).log10() / (to - from)
+    }
+
+    #[test]
+    fn reshaped_decay_scales_the_rt60() {
+        let ir = decaying_noise(1., 2., 13);
+        let rt60 = measure_decay(&ir, SAMPLE_RATE).unwrap().rt60;
+        assert!((rt60 - 1.).abs() < 0.1, "measured {}s", rt60);
+
+        let shorter = reshape_decay(&ir, 0.5, SAMPLE_RATE);
+        let rt60 = measure_decay(&shorter, SAMPLE_RATE).unwrap().rt60;
+        assert!((rt60 - 0.5).abs() < 0.05, "shortened to {}s", rt60);
+
+        // twice as long runs well past where the real tail sinks into the floor, so from about
+        // 0.7s on it's made up, and has to carry on along the same line
+        let longer = reshape_decay(&ir, 2., SAMPLE_RATE);
+        let rt60 = measure_decay(&longer, SAMPLE_RATE).unwrap().rt60;
+        assert!((rt60 - 2.).abs() < 0.2, "lengthened to {}s", rt60);
+        let rate = decay_rate(&longer, 1.2, 2.2);
+        assert!((rate - 30.).abs() < 3., "made up tail falls {}dB/s", rate);
+    }
+
+    #[test]
+    fn faster_high_band_only_shortens_the_highs() {
+        let ir = decaying_noise(1., 2., 17);
+        let bands = BandDecay {
+            scales: [1., 1., 0.5],
+            ..BandDecay::default()
+        };
+        let darker = reshape_band_decay(&ir, &bands, SAMPLE_RATE);
+        let rt60s = |signal: &[f32]| -> Vec<f32> {
+            split_bands(signal, &bands.crossovers, SAMPLE_RATE)
+                .iter()
+                .map(|band| measure_decay(band, SAMPLE_RATE).unwrap().rt60)
+                .collect()
+        };
+        let (before, after) = (rt60s(&ir), rt60s(&darker));
+        assert!((after[0] / before[0] - 1.).abs() < 0.05, "lows {}s to {}s", before[0], after[0]);
+        assert!((after[1] / before[1] - 1.).abs() < 0.05, "mids {}s to {}s", before[1], after[1]);
+        assert!((after[2] / before[2] - 0.5).abs() < 0.05, "highs {}s to {}s", before[2], after[2]);
+    }
+
+    #[test]
+    fn freeze_holds_the_spectrum_after_the_input_stops() {
+        let mut noise = Noise::new(19);
+        let input: Vec<f32> =
+            (0..SAMPLE_RATE as usize).map(|_| 0.5 * noise.next_unipolar() - 0.25).collect();
+        let silence = vec![0.; 3 * SAMPLE_RATE as usize];
+        let tail = |freeze: bool| {
+            let mut spring = SpringIr::new(SAMPLE_RATE);
+            let (live, _) = render(&mut spring, &input);
+            spring.set_freeze(freeze);
+            let (tail, _) = render(&mut spring, &silence);
+            (live, tail)
+        };
+        let (live, frozen) = tail(true);
+        let (_, released) = tail(false);
+
+        // the last half second of input against a second, long after it stopped
+        let live = &live[live.len() / 2..];
+        let frozen = &frozen[2 * SAMPLE_RATE as usize..];
+        let released = &released[2 * SAMPLE_RATE as usize..];
+        let db = |a: f32, b: f32| 10. * (a / b).log10();
+        let level = db(energy(frozen) / frozen.len() as f32, energy(live) / live.len() as f32);
+        assert!(level.abs() < 3., "frozen {}dB from live", level);
+        assert!(energy(released) < 1e-6 * energy(frozen));
+
+        let (live, frozen) = (octave_energies(live), octave_energies(frozen));
+        let (live_total, frozen_total): (f32, f32) = (live.iter().sum(), frozen.iter().sum());
+        // the random phased frames leak a little, so octaves far down in the spring's spectrum
+        // only have to stay far down
+        for (band, (live, frozen)) in live.into_iter().zip(frozen).enumerate() {
+            let (live, frozen) = (db(live, live_total), db(frozen, frozen_total));
+            if live > -30. {
+                assert!((frozen - live).abs() < 1.5, "octave {} {}dB, was {}dB", band, frozen, live);
+            } else {
+                assert!(frozen < -25., "octave {} up to {}dB", band, frozen);
+            }
+        }
+    }
+
+    #[test]
+    fn room_rt60_sets_the_tail_length() {
+        for rt60 in [0.8, 2.5] {
+            let mut room = Fdn::new(SAMPLE_RATE);
+            room.set_rt60([rt60; 3]);
+            let (left, _) = render(&mut room, &impulse(rt60 + 0.2));
+            // measured on the second half, the early echoes die away a little faster than the
+            // mixed tail
+            let measured = 60. / decay_rate(&left, 0.5 * rt60, rt60);
+            assert!((measured / rt60 - 1.).abs() < 0.07, "{}s set, {}s measured", rt60, measured);
+        }
+    }
+
+    /// Runs the whole plugin on a main input and a sidechain, with the sidechain set to `mode`.
+    fn render_sidechained(mode: f32, main: &[f32], sidechain: &[f32]) -> Vec<f32> {
+        let (to_dsp, from_params) = channel();
+        let mut dsp = PluginDsp::new(from_params, Arc::new(Meters::new()));
+        to_dsp.send(StateUpdate::SetParameter(Parameter::Sidechain, mode)).unwrap();
+        let (mut left, mut right) = (vec![0.; main.len()], vec![0.; main.len()]);
+        for start in (0..main.len()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(main.len());
+            let (main, sidechain) = (&main[start..end], &sidechain[start..end]);
+            let inputs = [main, main, sidechain, sidechain];
+            dsp.process_block(&inputs, &mut left[start..end], &mut right[start..end]);
+        }
+        left
+    }
+
+    #[test]
+    fn replacing_sidechain_ignores_the_main_input() {
+        let mut noise = Noise::new(23);
+        let mut signal = || -> Vec<f32> {
+            (0..20000).map(|_| 0.5 * noise.next_unipolar() - 0.25).collect()
+        };
+        let (main, other_main, sidechain) = (signal(), signal(), signal());
+
+        let replaced = render_sidechained(1., &main, &sidechain);
+        assert!(energy(&replaced) > 0.);
+        assert!(replaced == render_sidechained(1., &other_main, &sidechain));
+        // added, both are heard
+        let added = render_sidechained(2., &main, &sidechain);
+        assert!(added != render_sidechained(2., &other_main, &sidechain));
+    }
+
+    #[test]
+    fn inverse_dynamic_ducks_the_wet_by_up_to_the_range() {
+        // (input level, expected wet level) in dB, against a -30dB threshold and a 12dB range
+        let levels = [(-40., 0.), (-30., 0.), (-24., -6.), (-18., -12.), (0., -12.)];
+        for (input_db, expected_db) in levels {
+            let mut dynamic = Dynamic::new(SAMPLE_RATE);
+            dynamic.set_mode(DynamicMode::Inverse);
+            dynamic.set_threshold_db(-30.);
+            dynamic.set_range_db(12.);
+            // a steady level, long enough for the follower to settle on it
+            let key = vec![10f32.powf(input_db / 20.); SAMPLE_RATE as usize];
+            let (mut wet_l, mut wet_r) = (vec![1.; key.len()], vec![1.; key.len()]);
+            dynamic.process(&key, &key, &mut wet_l, &mut wet_r);
+            let wet_db = 20. * wet_l.last().unwrap().log10();
+            assert!((wet_db - expected_db).abs() < 0.1, "{}dB in, wet at {}dB", input_db, wet_db);
+            assert_eq!(wet_l, wet_r);
+        }
+    }
 }